@@ -0,0 +1,647 @@
+//! Shell completions and machine-readable help.
+//!
+//! Both are generated from the clap command tree. [`command_help`] describes
+//! it for `gpui help --json`, and [`completion_script`] turns that
+//! description into a script for `gpui completions <shell>`. Scripts
+//! complete subcommands, flags, enumerated values, and paths statically;
+//! component names are fetched while completing, from the hidden
+//! `gpui __components` command, so they always match the registry.
+
+use clap::{Arg, Command, ValueHint};
+use registry::plan::{ConflictResolution, LayoutKind};
+use registry::search::SearchField;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::SchemaTarget;
+use crate::logging::LogFormat;
+
+/// Name of the hidden subcommand that prints component names for completion.
+pub const COMPONENTS_COMMAND: &str = "__components";
+
+/// A shell `gpui completions` can generate a script for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+impl Shell {
+    /// All supported shells.
+    pub const ALL: [Shell; 4] = [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::Powershell];
+
+    /// The shell's name, as accepted by `gpui completions`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+            Shell::Powershell => "powershell",
+        }
+    }
+
+    /// Look up a shell by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|shell| shell.name() == name)
+    }
+}
+
+impl std::str::FromStr for Shell {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|shell| shell.name()).collect();
+            format!(
+                "unknown shell '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Command metadata
+// ---------------------------------------------------------------------------
+
+/// What kind of value an argument takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ValueKind {
+    /// Free-form text.
+    Text,
+    /// One of the listed `choices`.
+    Choice,
+    /// A registry component name, optionally with a version (`dialog@^0.1`).
+    Component,
+    /// A file or directory path.
+    Path,
+}
+
+/// The value an argument takes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArgValue {
+    /// Placeholder shown in usage, e.g. `TARGET_DIR`.
+    pub name: String,
+    pub kind: ValueKind,
+    /// Accepted values, for `choice` arguments.
+    pub choices: Vec<String>,
+    /// Value used when the argument is omitted.
+    pub default: Option<String>,
+    /// Whether the argument accepts several values.
+    pub multiple: bool,
+}
+
+/// One argument of a command.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArgHelp {
+    pub name: String,
+    /// Long flag without the leading `--`.
+    pub long: Option<String>,
+    /// Short flag without the leading `-`.
+    pub short: Option<char>,
+    pub help: Option<String>,
+    pub positional: bool,
+    pub required: bool,
+    /// Whether the argument is accepted by every subcommand.
+    pub global: bool,
+    /// `None` for flags that take no value.
+    pub value: Option<ArgValue>,
+}
+
+/// A command, its arguments, and its subcommands.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CommandHelp {
+    pub name: String,
+    /// Full invocation, e.g. `gpui docs site`.
+    pub command: String,
+    pub about: Option<String>,
+    pub args: Vec<ArgHelp>,
+    pub subcommands: Vec<CommandHelp>,
+}
+
+/// Describe `cmd` and its visible subcommands. `cmd` must be built
+/// ([`Command::build`]) so global and help arguments are propagated.
+pub fn command_help(cmd: &Command) -> CommandHelp {
+    CommandHelp {
+        name: cmd.get_name().to_string(),
+        command: cmd.get_bin_name().unwrap_or(cmd.get_name()).to_string(),
+        about: cmd.get_about().map(|about| about.to_string()),
+        args: cmd
+            .get_arguments()
+            .filter(|arg| !arg.is_hide_set())
+            .map(arg_help)
+            .collect(),
+        subcommands: cmd
+            .get_subcommands()
+            .filter(|sub| !sub.is_hide_set())
+            .map(command_help)
+            .collect(),
+    }
+}
+
+fn arg_help(arg: &Arg) -> ArgHelp {
+    let value = arg.get_action().takes_values().then(|| {
+        let (kind, choices) = value_kind(arg);
+        ArgValue {
+            name: arg
+                .get_value_names()
+                .and_then(|names| names.first())
+                .map_or_else(
+                    || arg.get_id().as_str().to_uppercase(),
+                    |name| name.to_string(),
+                ),
+            kind,
+            choices,
+            default: arg
+                .get_default_values()
+                .first()
+                .map(|value| value.to_string_lossy().into_owned()),
+            multiple: arg
+                .get_num_args()
+                .is_some_and(|range| range.max_values() > 1),
+        }
+    });
+    ArgHelp {
+        name: arg.get_id().as_str().to_string(),
+        long: arg.get_long().map(str::to_string),
+        short: arg.get_short(),
+        help: arg.get_help().map(|help| help.to_string()),
+        positional: arg.is_positional(),
+        required: arg.is_required_set(),
+        global: arg.is_global_set(),
+        value,
+    }
+}
+
+/// Classify an argument's value. Enumerated arguments parse with `FromStr`,
+/// so clap cannot list their values; they are looked up by argument name.
+fn value_kind(arg: &Arg) -> (ValueKind, Vec<String>) {
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+    let choices = match arg.get_id().as_str() {
+        "component" => return (ValueKind::Component, Vec::new()),
+        "layout" => names(&LayoutKind::ALL.map(LayoutKind::name)),
+        "on_conflict" => names(&ConflictResolution::ALL.map(ConflictResolution::name)),
+        "fields" => names(&SearchField::ALL.map(SearchField::name)),
+        "schema_type" => names(&SchemaTarget::names()),
+        "shell" => names(&Shell::ALL.map(Shell::name)),
+        "log_format" => names(&LogFormat::ALL.map(LogFormat::name)),
+        _ => arg
+            .get_possible_values()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| value.get_name().to_string())
+            .collect(),
+    };
+    if !choices.is_empty() {
+        return (ValueKind::Choice, choices);
+    }
+    match arg.get_value_hint() {
+        ValueHint::AnyPath | ValueHint::FilePath | ValueHint::DirPath => {
+            (ValueKind::Path, Vec::new())
+        }
+        _ => (ValueKind::Text, Vec::new()),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Completion scripts
+// ---------------------------------------------------------------------------
+
+/// A command flattened for completion, keyed by its path (`gpui__docs__site`).
+struct Node<'a> {
+    key: String,
+    help: &'a CommandHelp,
+}
+
+impl Node<'_> {
+    /// Every `--long` and `-s` spelling of the command's options.
+    fn flags(&self) -> Vec<String> {
+        let mut flags = Vec::new();
+        for arg in self.help.args.iter().filter(|arg| !arg.positional) {
+            flags.extend(arg.long.iter().map(|long| format!("--{long}")));
+            flags.extend(arg.short.iter().map(|short| format!("-{short}")));
+        }
+        flags
+    }
+
+    /// Options that take a value, with every spelling of each.
+    fn valued_options(&self) -> Vec<(Vec<String>, &ArgValue)> {
+        self.help
+            .args
+            .iter()
+            .filter(|arg| !arg.positional)
+            .filter_map(|arg| {
+                let spellings = arg
+                    .long
+                    .iter()
+                    .map(|long| format!("--{long}"))
+                    .chain(arg.short.iter().map(|short| format!("-{short}")))
+                    .collect();
+                Some((spellings, arg.value.as_ref()?))
+            })
+            .collect()
+    }
+
+    /// What the command's positional arguments complete to.
+    fn positional(&self) -> Option<&ArgValue> {
+        self.help
+            .args
+            .iter()
+            .filter(|arg| arg.positional)
+            .find_map(|arg| arg.value.as_ref())
+    }
+
+    fn subcommand_key(&self, name: &str) -> String {
+        format!("{}__{}", self.key, name)
+    }
+}
+
+fn nodes(help: &CommandHelp) -> Vec<Node<'_>> {
+    fn walk<'a>(help: &'a CommandHelp, key: String, nodes: &mut Vec<Node<'a>>) {
+        for sub in &help.subcommands {
+            walk(sub, format!("{key}__{}", sub.name), nodes);
+        }
+        nodes.push(Node { key, help });
+    }
+    let mut nodes = Vec::new();
+    walk(help, help.name.clone(), &mut nodes);
+    nodes.reverse();
+    nodes
+}
+
+/// Generate the completion script for `shell` from `help`, the description
+/// of the root command.
+pub fn completion_script(shell: Shell, help: &CommandHelp) -> String {
+    let nodes = nodes(help);
+    match shell {
+        Shell::Bash => bash_script(&help.name, &nodes),
+        Shell::Zsh => zsh_script(&help.name, &nodes),
+        Shell::Fish => fish_script(&help.name, &nodes),
+        Shell::Powershell => powershell_script(&help.name, &nodes),
+    }
+}
+
+/// Quote for POSIX shells: `it's` -> `'it'\''s'`.
+fn sh_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', r"'\''"))
+}
+
+/// Quote for fish: backslashes and single quotes are escaped.
+fn fish_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// Quote for PowerShell: single quotes are doubled.
+fn ps_quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// First line of a help text, for one-line descriptions.
+fn summary(text: Option<&String>) -> &str {
+    text.and_then(|text| text.lines().next()).unwrap_or("")
+}
+
+fn bash_script(bin: &str, nodes: &[Node]) -> String {
+    let mut transitions = String::new();
+    let mut values = String::new();
+    let mut words = String::new();
+    for node in nodes {
+        for sub in &node.help.subcommands {
+            transitions.push_str(&format!(
+                "            {})\n                path={} ;;\n",
+                sh_quote(&format!("{}:{}", node.key, sub.name)),
+                sh_quote(&node.subcommand_key(&sub.name)),
+            ));
+        }
+        for (spellings, value) in node.valued_options() {
+            let patterns: Vec<String> = spellings
+                .iter()
+                .map(|flag| sh_quote(&format!("{}:{flag}", node.key)))
+                .collect();
+            values.push_str(&format!(
+                "        {})\n            {}\n            return ;;\n",
+                patterns.join("|"),
+                bash_values(bin, value),
+            ));
+        }
+        let positional = match node.positional() {
+            Some(value) if node.help.subcommands.is_empty() => bash_values(bin, value),
+            _ => {
+                let names: Vec<&str> = node
+                    .help
+                    .subcommands
+                    .iter()
+                    .map(|sub| sub.name.as_str())
+                    .collect();
+                bash_words(&names.join(" "))
+            }
+        };
+        words.push_str(&format!(
+            "        {})\n            if [[ \"$cur\" == -* ]]; then\n                {}\n            else\n                {}\n            fi ;;\n",
+            sh_quote(&node.key),
+            bash_words(&node.flags().join(" ")),
+            positional,
+        ));
+    }
+
+    format!(
+        r#"# bash completion for {bin}
+# Install: {bin} completions bash > /etc/bash_completion.d/{bin}
+_{bin}() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}" prev="${{COMP_WORDS[COMP_CWORD-1]}}"
+    local path={root} word i
+    for ((i = 1; i < COMP_CWORD; i++)); do
+        word="${{COMP_WORDS[i]}}"
+        case "$path:$word" in
+{transitions}        esac
+    done
+
+    COMPREPLY=()
+    case "$path:$prev" in
+{values}    esac
+    case "$path" in
+{words}    esac
+}}
+complete -F _{bin} -o bashdefault -o default {bin}
+"#,
+        root = sh_quote(bin),
+    )
+}
+
+fn bash_words(words: &str) -> String {
+    format!("COMPREPLY=($(compgen -W {} -- \"$cur\"))", sh_quote(words))
+}
+
+/// Paths and free text fall back to bash's default (file) completion.
+fn bash_values(bin: &str, value: &ArgValue) -> String {
+    match value.kind {
+        ValueKind::Choice => bash_words(&value.choices.join(" ")),
+        ValueKind::Component => format!(
+            "COMPREPLY=($(compgen -W \"$({bin} {COMPONENTS_COMMAND} 2>/dev/null)\" -- \"$cur\"))"
+        ),
+        ValueKind::Path | ValueKind::Text => ":".to_string(),
+    }
+}
+
+fn zsh_script(bin: &str, nodes: &[Node]) -> String {
+    let mut transitions = String::new();
+    let mut values = String::new();
+    let mut words = String::new();
+    for node in nodes {
+        for sub in &node.help.subcommands {
+            transitions.push_str(&format!(
+                "            {})\n                cmd_path={} ;;\n",
+                sh_quote(&format!("{}:{}", node.key, sub.name)),
+                sh_quote(&node.subcommand_key(&sub.name)),
+            ));
+        }
+        for (spellings, value) in node.valued_options() {
+            let patterns: Vec<String> = spellings
+                .iter()
+                .map(|flag| sh_quote(&format!("{}:{flag}", node.key)))
+                .collect();
+            values.push_str(&format!(
+                "        {})\n            {}\n            return ;;\n",
+                patterns.join("|"),
+                zsh_values(bin, value),
+            ));
+        }
+        let positional = match node.positional() {
+            Some(value) if node.help.subcommands.is_empty() => zsh_values(bin, value),
+            _ => {
+                let described: Vec<String> = node
+                    .help
+                    .subcommands
+                    .iter()
+                    .map(|sub| sh_quote(&format!("{}:{}", sub.name, summary(sub.about.as_ref()))))
+                    .collect();
+                format!(
+                    "local -a subcommands=({})\n                _describe command subcommands",
+                    described.join(" ")
+                )
+            }
+        };
+        let flags: Vec<String> = node.flags().iter().map(|flag| sh_quote(flag)).collect();
+        words.push_str(&format!(
+            "        {})\n            if [[ \"$cur\" == -* ]]; then\n                compadd -- {}\n            else\n                {}\n            fi ;;\n",
+            sh_quote(&node.key),
+            flags.join(" "),
+            positional,
+        ));
+    }
+
+    format!(
+        r#"#compdef {bin}
+# zsh completion for {bin}
+# Install: {bin} completions zsh > "${{fpath[1]}}/_{bin}"
+_{bin}() {{
+    local cur="${{words[CURRENT]}}" prev="${{words[CURRENT-1]}}"
+    local cmd_path={root} word i
+    for ((i = 2; i < CURRENT; i++)); do
+        word="${{words[i]}}"
+        case "$cmd_path:$word" in
+{transitions}        esac
+    done
+
+    case "$cmd_path:$prev" in
+{values}    esac
+    case "$cmd_path" in
+{words}    esac
+}}
+
+if [[ "${{funcstack[1]}}" == "_{bin}" ]]; then
+    _{bin} "$@"
+else
+    compdef _{bin} {bin}
+fi
+"#,
+        root = sh_quote(bin),
+    )
+}
+
+fn zsh_values(bin: &str, value: &ArgValue) -> String {
+    match value.kind {
+        ValueKind::Choice => {
+            let choices: Vec<String> = value.choices.iter().map(|c| sh_quote(c)).collect();
+            format!("compadd -- {}", choices.join(" "))
+        }
+        ValueKind::Component => {
+            format!("compadd -- ${{(f)\"$({bin} {COMPONENTS_COMMAND} 2>/dev/null)\"}}")
+        }
+        ValueKind::Path => "_files".to_string(),
+        ValueKind::Text => ":".to_string(),
+    }
+}
+
+fn fish_script(bin: &str, nodes: &[Node]) -> String {
+    let mut transitions = String::new();
+    let mut completions = String::new();
+    for node in nodes {
+        let condition = fish_quote(&format!("__{bin}_at {}", node.key));
+        for sub in &node.help.subcommands {
+            transitions.push_str(&format!(
+                "            case {}\n                set path {}\n",
+                fish_quote(&format!("{}:{}", node.key, sub.name)),
+                fish_quote(&node.subcommand_key(&sub.name)),
+            ));
+            completions.push_str(&format!(
+                "complete -c {bin} -n {condition} -a {} -d {}\n",
+                fish_quote(&sub.name),
+                fish_quote(summary(sub.about.as_ref())),
+            ));
+        }
+        for arg in node.help.args.iter().filter(|arg| !arg.positional) {
+            let mut line = format!("complete -c {bin} -n {condition}");
+            if let Some(long) = &arg.long {
+                line.push_str(&format!(" -l {long}"));
+            }
+            if let Some(short) = arg.short {
+                line.push_str(&format!(" -s {short}"));
+            }
+            if let Some(value) = &arg.value {
+                line.push_str(&fish_values(bin, value));
+            }
+            line.push_str(&format!(" -d {}\n", fish_quote(summary(arg.help.as_ref()))));
+            completions.push_str(&line);
+        }
+        if node.help.subcommands.is_empty()
+            && let Some(value) = node.positional()
+        {
+            completions.push_str(&format!(
+                "complete -c {bin} -n {condition}{}\n",
+                fish_values(bin, value)
+            ));
+        }
+    }
+
+    format!(
+        r#"# fish completion for {bin}
+# Install: {bin} completions fish > ~/.config/fish/completions/{bin}.fish
+function __{bin}_at
+    set -l path {root}
+    for token in (commandline -opc)[2..-1]
+        switch "$path:$token"
+{transitions}        end
+    end
+    test "$path" = "$argv[1]"
+end
+
+complete -c {bin} -f
+{completions}"#,
+        root = fish_quote(bin),
+    )
+}
+
+fn fish_values(bin: &str, value: &ArgValue) -> String {
+    match value.kind {
+        ValueKind::Choice => format!(" -x -a {}", fish_quote(&value.choices.join(" "))),
+        ValueKind::Component => format!(
+            " -x -a {}",
+            fish_quote(&format!("({bin} {COMPONENTS_COMMAND} 2>/dev/null)"))
+        ),
+        ValueKind::Path => " -r -F".to_string(),
+        ValueKind::Text => " -x".to_string(),
+    }
+}
+
+fn powershell_script(bin: &str, nodes: &[Node]) -> String {
+    let mut transitions = String::new();
+    let mut values = String::new();
+    let mut flags = String::new();
+    let mut words = String::new();
+    for node in nodes {
+        for sub in &node.help.subcommands {
+            transitions.push_str(&format!(
+                "            {} {{ $path = {} }}\n",
+                ps_quote(&format!("{}:{}", node.key, sub.name)),
+                ps_quote(&node.subcommand_key(&sub.name)),
+            ));
+        }
+        for (spellings, value) in node.valued_options() {
+            for flag in spellings {
+                values.push_str(&format!(
+                    "        {} {{ {} }}\n",
+                    ps_quote(&format!("{}:{flag}", node.key)),
+                    powershell_values(bin, value),
+                ));
+            }
+        }
+        let node_flags: Vec<String> = node.flags().iter().map(|flag| ps_quote(flag)).collect();
+        flags.push_str(&format!(
+            "            {} {{ $candidates = @({}) }}\n",
+            ps_quote(&node.key),
+            node_flags.join(", "),
+        ));
+        let positional = match node.positional() {
+            Some(value) if node.help.subcommands.is_empty() => powershell_values(bin, value),
+            _ => {
+                let names: Vec<String> = node
+                    .help
+                    .subcommands
+                    .iter()
+                    .map(|sub| ps_quote(&sub.name))
+                    .collect();
+                format!("$candidates = @({})", names.join(", "))
+            }
+        };
+        words.push_str(&format!(
+            "            {} {{ {} }}\n",
+            ps_quote(&node.key),
+            positional,
+        ));
+    }
+
+    format!(
+        r#"# PowerShell completion for {bin}
+# Install: {bin} completions powershell >> $PROFILE
+Register-ArgumentCompleter -Native -CommandName {root} -ScriptBlock {{
+    param($wordToComplete, $commandAst, $cursorPosition)
+    $tokens = @($commandAst.CommandElements | Select-Object -Skip 1 | ForEach-Object {{ $_.ToString() }})
+    if ($wordToComplete -ne '') {{ $tokens = @($tokens | Select-Object -SkipLast 1) }}
+    $path = {root}
+    foreach ($token in $tokens) {{
+        switch -CaseSensitive ("${{path}}:$token") {{
+{transitions}        }}
+    }}
+    $prev = if ($tokens.Count -gt 0) {{ $tokens[-1] }} else {{ '' }}
+
+    # Paths and free text fall back to PowerShell's default (file) completion.
+    $candidates = $null
+    switch -CaseSensitive ("${{path}}:$prev") {{
+{values}    }}
+    if ($null -eq $candidates) {{
+        if ($wordToComplete.StartsWith('-')) {{
+            switch -CaseSensitive ($path) {{
+{flags}            }}
+        }} else {{
+            switch -CaseSensitive ($path) {{
+{words}            }}
+        }}
+    }}
+    $candidates | Where-Object {{ $_ -like "$wordToComplete*" }} | ForEach-Object {{
+        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+    }}
+}}
+"#,
+        root = ps_quote(bin),
+    )
+}
+
+fn powershell_values(bin: &str, value: &ArgValue) -> String {
+    match value.kind {
+        ValueKind::Choice => {
+            let choices: Vec<String> = value.choices.iter().map(|c| ps_quote(c)).collect();
+            format!("$candidates = @({})", choices.join(", "))
+        }
+        ValueKind::Component => {
+            format!("$candidates = @({bin} {COMPONENTS_COMMAND} 2>$null)")
+        }
+        ValueKind::Path | ValueKind::Text => "return".to_string(),
+    }
+}
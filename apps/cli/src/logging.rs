@@ -0,0 +1,220 @@
+//! Stderr logging for the CLI.
+//!
+//! Everything a command reports to people — progress, per-mutation events,
+//! timings, warnings — goes through the `log` macros to stderr, while the
+//! `CliOutput` envelope stays alone on stdout. `--verbose` and `--quiet`
+//! pick the level; `--log-format json` turns each event into one JSON object
+//! per line, with the record's key-values as `fields`, for agents that
+//! follow progress while waiting for the envelope.
+
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use log::kv::{Error as KvError, Key, Value, VisitSource};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+/// How log events are written to stderr.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines; info events are printed as bare messages.
+    Text,
+    /// One JSON object per event.
+    Json,
+}
+
+impl LogFormat {
+    /// All log formats.
+    pub const ALL: [LogFormat; 2] = [LogFormat::Text, LogFormat::Json];
+
+    /// The format's name, as accepted by `--log-format`.
+    pub fn name(self) -> &'static str {
+        match self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        }
+    }
+
+    /// Look up a log format by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.name() == name)
+    }
+}
+
+impl std::str::FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|format| format.name()).collect();
+            format!(
+                "unknown log format '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
+}
+
+/// The most detailed level to log: `--quiet` keeps warnings and errors,
+/// the default adds progress, `-v` adds per-mutation events and timings,
+/// and `-vv` everything.
+pub fn level_filter(verbose: u8, quiet: bool) -> LevelFilter {
+    match (quiet, verbose) {
+        (true, _) => LevelFilter::Warn,
+        (false, 0) => LevelFilter::Info,
+        (false, 1) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    }
+}
+
+struct StderrLogger {
+    format: LogFormat,
+}
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let line = format_record(self.format, record);
+            // Nothing sensible to do if stderr is gone.
+            let _ = writeln!(std::io::stderr().lock(), "{line}");
+        }
+    }
+
+    fn flush(&self) {
+        let _ = std::io::stderr().flush();
+    }
+}
+
+/// Install the stderr logger. Call once, before any command runs.
+pub fn init(level: LevelFilter, format: LogFormat) {
+    let logger: &'static StderrLogger = Box::leak(Box::new(StderrLogger { format }));
+    if log::set_logger(logger).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+/// Render one event as a line of `format`.
+fn format_record(format: LogFormat, record: &Record) -> String {
+    let mut fields = Fields::default();
+    let _ = record.key_values().visit(&mut fields);
+    match format {
+        LogFormat::Text => {
+            let prefix = match record.level() {
+                Level::Error => "error: ",
+                Level::Warn => "warning: ",
+                Level::Info => "",
+                Level::Debug => "debug: ",
+                Level::Trace => "trace: ",
+            };
+            let mut line = format!("{prefix}{}", record.args());
+            for (key, value) in &fields.0 {
+                match value {
+                    serde_json::Value::String(text) => line.push_str(&format!(" {key}={text}")),
+                    value => line.push_str(&format!(" {key}={value}")),
+                }
+            }
+            line
+        }
+        LogFormat::Json => {
+            let timestamp_ms = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_millis() as u64);
+            serde_json::json!({
+                "timestamp_ms": timestamp_ms,
+                "level": record.level().as_str().to_lowercase(),
+                "target": record.target(),
+                "message": record.args().to_string(),
+                "fields": serde_json::Map::from_iter(fields.0),
+            })
+            .to_string()
+        }
+    }
+}
+
+/// A record's key-values in order, with numbers and booleans kept as JSON
+/// scalars.
+#[derive(Default)]
+struct Fields(Vec<(String, serde_json::Value)>);
+
+impl<'kvs> VisitSource<'kvs> for Fields {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), KvError> {
+        let value = if let Some(flag) = value.to_bool() {
+            flag.into()
+        } else if let Some(n) = value.to_u64() {
+            n.into()
+        } else if let Some(n) = value.to_i64() {
+            n.into()
+        } else if let Some(n) = value.to_f64() {
+            n.into()
+        } else {
+            value.to_string().into()
+        };
+        self.0.push((key.as_str().to_string(), value));
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record_line(format: LogFormat, level: Level, message: &str) -> String {
+        let fields: [(&str, Value); 3] = [
+            ("path", Value::from("src/ui/dialog.rs")),
+            ("index", Value::from(2u64)),
+            ("force", Value::from(false)),
+        ];
+        format_record(
+            format,
+            &Record::builder()
+                .level(level)
+                .target("gpui::apply")
+                .args(format_args!("{message}"))
+                .key_values(&fields)
+                .build(),
+        )
+    }
+
+    #[test]
+    fn text_lines_prefix_levels_and_append_fields() {
+        assert_eq!(
+            record_line(LogFormat::Text, Level::Info, "Applied dialog"),
+            "Applied dialog path=src/ui/dialog.rs index=2 force=false"
+        );
+        assert!(record_line(LogFormat::Text, Level::Warn, "x").starts_with("warning: x "));
+        assert!(record_line(LogFormat::Text, Level::Debug, "x").starts_with("debug: x "));
+    }
+
+    #[test]
+    fn json_lines_keep_field_types() {
+        let line = record_line(LogFormat::Json, Level::Debug, "Applied mutation");
+        let event: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(event["level"], "debug");
+        assert_eq!(event["target"], "gpui::apply");
+        assert_eq!(event["message"], "Applied mutation");
+        assert_eq!(event["fields"]["path"], "src/ui/dialog.rs");
+        assert_eq!(event["fields"]["index"], 2);
+        assert_eq!(event["fields"]["force"], false);
+        assert!(event["timestamp_ms"].as_u64().unwrap() > 0);
+        assert!(!line.contains('\n'));
+    }
+
+    #[test]
+    fn flags_pick_the_level() {
+        assert_eq!(level_filter(0, false), LevelFilter::Info);
+        assert_eq!(level_filter(1, false), LevelFilter::Debug);
+        assert_eq!(level_filter(3, false), LevelFilter::Trace);
+        assert_eq!(level_filter(0, true), LevelFilter::Warn);
+        for format in LogFormat::ALL {
+            assert_eq!(format.name().parse::<LogFormat>(), Ok(format));
+        }
+    }
+}
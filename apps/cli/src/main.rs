@@ -1,13 +1,47 @@
+mod completions;
+mod logging;
+mod vfs;
+
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
 use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use anyhow::{Context, Result, bail};
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, Parser, Subcommand};
+use schemars::{JsonSchema, Schema, schema_for};
 use serde::{Deserialize, Serialize};
 
+use registry::acceptance::{AcceptanceLedger, ItemStatus, LEDGER_FILE, Readiness, readiness};
+use registry::compat::{CliCompatibility, RequirementSource};
+use registry::config::{CONFIG_FILE, Ownership, ProjectConfig, StudioConfig};
+use registry::diff::{InstallDiff, Recommendation, diff_install};
+use registry::interchange::{
+    CONTRACTS_DIR, ContractImport, ImportStatus, export_contracts, import_contract,
+};
+use registry::lint::lint_themes;
+use registry::manifest::{MANIFEST_FILE, Manifest};
 use registry::plan::{
-    ApplyFailureReport, DefaultLayout, FileAction, FileMutation, MutationStrategy, PlanContract,
-    generate_plan,
+    ApplyFailureReport, ChangeKind, Conflict, ConflictResolution, FileAction, FileMutation,
+    InstalledComponent, LayoutKind, MutationStrategy, PlanContract, PlanDiff, ProvenanceAction,
+    TemplateAdapter, generate_install_plan, generate_plan, generate_remove_plan,
+    generate_update_plan, replace_section, section_id, simple_checksum,
 };
+use registry::remote::RemoteRegistry;
+use registry::schema::SchemaType;
+use registry::search::SearchField;
+use registry::site::{SNAPSHOTS_DIR, builtin_themes, generate_site, snapshot_state};
+use registry::snapshot::{BASELINES_DIR, SnapshotReport, ThemeSnapshots, theme_dir};
+use registry::timing::{self, TimingReport};
+use registry::tokens::{TokenIssueKind, TokenSet, scan_token_usages};
+use registry::usage::usage_report;
+use registry::verify::{THEMES_DIR, project_theme_names, verify_project};
+use registry::version::VersionReq;
+use registry::{RegistryIndex, Stability};
+
+use crate::completions::{COMPONENTS_COMMAND, Shell, command_help, completion_script};
+use crate::logging::LogFormat;
+use crate::vfs::{ChangedFile, FileChange, MemoryFs, RealFs, Vfs};
 
 // ---------------------------------------------------------------------------
 // CLI output envelope (shared by all commands, FR-003)
@@ -17,15 +51,18 @@ use registry::plan::{
 ///
 /// Ensures schema consistency across `add --plan`, `plan`, `apply`, and
 /// future Phase 1 commands (`list`, `doctor`, `update`, `remove`).
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CliOutput<T: Serialize> {
     pub success: bool,
     pub data: T,
     pub errors: Vec<CliError>,
+    /// Where the command spent its time, with `--timings`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<TimingReport>,
 }
 
 /// A structured error in CLI output.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CliError {
     pub code: String,
     pub message: String,
@@ -37,6 +74,7 @@ impl<T: Serialize> CliOutput<T> {
             success: true,
             data,
             errors: Vec::new(),
+            timings: None,
         }
     }
 
@@ -45,11 +83,24 @@ impl<T: Serialize> CliOutput<T> {
             success: false,
             data,
             errors,
+            timings: None,
         }
     }
 
+    /// Serialize the envelope. With `--timings`, the recording is finished
+    /// here and its report attached, including this serialization.
     fn to_json(&self) -> Result<String> {
-        serde_json::to_string_pretty(self).context("Failed to serialize CLI output")
+        let (json, elapsed) = timing::measure(|| serde_json::to_value(self));
+        let mut json = json.context("Failed to serialize CLI output")?;
+        if let Some(elapsed) = elapsed {
+            timing::record_serialization(elapsed);
+            if let Some(report) = timing::finish() {
+                log_timings(&report);
+                json["timings"] =
+                    serde_json::to_value(report).context("Failed to serialize timings")?;
+            }
+        }
+        serde_json::to_string_pretty(&json).context("Failed to serialize CLI output")
     }
 }
 
@@ -62,9 +113,26 @@ impl<T: Serialize> CliOutput<T> {
 #[command(
     name = "gpui",
     version,
-    about = "GPUI component toolkit - install, plan, and manage UI components"
+    about = "GPUI component toolkit - install, plan, and manage UI components",
+    disable_help_subcommand = true
 )]
 struct Cli {
+    /// Target project layout (defaults to the layout recorded in `gpui-workbench.toml`)
+    #[arg(long, global = true)]
+    layout: Option<LayoutKind>,
+    /// Log per-mutation events and timings to stderr (`-vv` for everything)
+    #[arg(long, short = 'v', global = true, action = clap::ArgAction::Count, conflicts_with = "quiet")]
+    verbose: u8,
+    /// Only log warnings and errors
+    #[arg(long, short = 'q', global = true)]
+    quiet: bool,
+    /// Log format on stderr: text, or json for one event object per line
+    #[arg(long, global = true, value_name = "FORMAT", default_value = "text")]
+    log_format: LogFormat,
+    /// Attach a timing report to the JSON output: registry generation per
+    /// contract, plan generation per component, and serialization
+    #[arg(long, global = true)]
+    timings: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -73,542 +141,4457 @@ struct Cli {
 enum Commands {
     /// Add a component to your project
     Add {
-        /// Component name (e.g. dialog, select, tabs)
+        /// Component name (e.g. dialog, select, tabs), optionally with a version (dialog@0.2.0, dialog@^0.1)
         component: String,
         /// Output the mutation plan as JSON instead of applying
         #[arg(long)]
         plan: bool,
+        /// Remote registry URL serving `registry-index.json` (defaults to the built-in registry)
+        #[arg(long)]
+        registry: Option<String>,
+        /// Choose how to resolve each conflict with an existing file
+        #[arg(long, short = 'i', conflicts_with = "on_conflict")]
+        interactive: bool,
+        /// Resolve every conflict the same way: skip, overwrite, backup, or rename
+        #[arg(long, value_name = "POLICY")]
+        on_conflict: Option<ConflictResolution>,
         /// Target project directory (defaults to current directory)
         #[arg(long, short = 'd')]
         target_dir: Option<PathBuf>,
     },
     /// Generate a mutation plan for a component (alias for `add --plan`)
     Plan {
-        /// Component name (e.g. dialog, select, tabs)
+        /// Component name (e.g. dialog, select, tabs), optionally with a version (dialog@0.2.0, dialog@^0.1)
         component: String,
+        /// Print the plan as a tree with each mutation's rationale instead of JSON
+        #[arg(long)]
+        tree: bool,
+        /// Remote registry URL serving `registry-index.json` (defaults to the built-in registry)
+        #[arg(long)]
+        registry: Option<String>,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// List the components available in the registry
+    List {
+        /// Remote registry URL serving `registry-index.json` (defaults to the built-in registry)
+        #[arg(long)]
+        registry: Option<String>,
+        /// List the components installed in the target project instead
+        #[arg(long, conflicts_with = "registry")]
+        installed: bool,
+        /// List the behavior primitives components are built on instead
+        #[arg(long, conflicts_with_all = ["registry", "installed"])]
+        primitives: bool,
+        /// Only list these stability tiers, comma-separated: experimental, beta, stable, deprecated (defaults to all)
+        #[arg(long, value_delimiter = ',', conflicts_with_all = ["installed", "primitives"])]
+        stability: Vec<Stability>,
         /// Target project directory (defaults to current directory)
         #[arg(long, short = 'd')]
         target_dir: Option<PathBuf>,
     },
+    /// Search component names, variants, states, props, and token paths
+    Search {
+        /// Search terms; every term must match
+        query: String,
+        /// Fields to search, comma-separated: name, variants, states, props, tokens (defaults to all)
+        #[arg(long, value_delimiter = ',')]
+        fields: Vec<SearchField>,
+        /// Remote registry URL serving `registry-index.json` (defaults to the built-in registry)
+        #[arg(long)]
+        registry: Option<String>,
+    },
     /// Apply a previously generated mutation plan
     Apply {
         /// Path to the plan JSON file
         plan_file: PathBuf,
+        /// Simulate the apply in memory and report what would change, without touching disk
+        #[arg(long)]
+        dry_run: bool,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Update an installed component to the registry version
+    Update {
+        /// Component name (e.g. dialog, select, tabs)
+        component: String,
+        /// Output the update plan as JSON instead of applying
+        #[arg(long)]
+        plan: bool,
+        /// Overwrite files that were modified locally since install
+        #[arg(long)]
+        force: bool,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Remove an installed component from your project
+    Remove {
+        /// Component name (e.g. dialog, select, tabs)
+        component: String,
+        /// Output the removal plan as JSON instead of applying
+        #[arg(long)]
+        plan: bool,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Check installed components against the project manifest
+    Doctor {
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Check every theme for missing tokens, low contrast, and component token
+    /// coverage, and every contract's perf evidence against its perf budget
+    Verify {
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Compare two plan JSON files: mutations, conflicts, and checksums
+    Diff {
+        /// The older plan
+        plan_a: PathBuf,
+        /// The newer plan
+        plan_b: PathBuf,
+    },
+    /// Compare an installed component's files against the current registry sources
+    DiffInstall {
+        /// Component name (e.g. dialog, select, tabs)
+        component: String,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Inspect theme token usage in your project
+    Tokens {
+        #[command(subcommand)]
+        command: TokensCommand,
+    },
+    /// Create project themes
+    Theme {
+        #[command(subcommand)]
+        command: ThemeCommand,
+    },
+    /// Generate registry documentation
+    Docs {
+        #[command(subcommand)]
+        command: DocsCommand,
+    },
+    /// Exchange component contracts with design tools as JSON files
+    Contracts {
+        #[command(subcommand)]
+        command: ContractsCommand,
+    },
+    /// Compare captured story snapshots with their baselines, per theme
+    Snapshot {
+        /// Directory of captured snapshots with one subdirectory per theme
+        /// (`one-dark/button.png`); defaults to the studio's `snapshot_dir` in gpui.toml
+        #[arg(long)]
+        captured: Option<PathBuf>,
+        /// Baseline directory, laid out like the captures (defaults to `snapshots/`)
+        #[arg(long)]
+        baselines: Option<PathBuf>,
+        /// Check every registered theme, including `themes/*.json`, not only the built-ins
+        #[arg(long)]
+        all_themes: bool,
+        /// Replace the baselines with the captured snapshots
+        #[arg(long)]
+        update: bool,
         /// Target project directory (defaults to current directory)
         #[arg(long, short = 'd')]
         target_dir: Option<PathBuf>,
     },
+    /// Eject a component: mark it locally owned so registry checks skip it
+    Eject {
+        /// Component name (e.g. dialog, select, tabs)
+        component: String,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Sign off acceptance checklist items for a component
+    Accept {
+        /// Component name (e.g. dialog, select, tabs)
+        component: String,
+        /// Checklist items to sign off, comma-separated (e.g. has_story_coverage,has_interaction_tests)
+        #[arg(long, value_delimiter = ',', required = true)]
+        items: Vec<String>,
+        /// Who is signing off
+        #[arg(long)]
+        by: String,
+        /// Workbench directory holding the acceptance ledger (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Report each component's acceptance checklist: contract-proven items plus sign-offs
+    Readiness {
+        /// Component name (defaults to every component)
+        component: Option<String>,
+        /// Workbench directory holding the acceptance ledger (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Report the tokens and primitives each installed component pulls in, and their overlap
+    Report {
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Print the JSON Schema of a document the CLI emits or reads
+    Schema {
+        /// Document type: cli-output, plan-contract, registry-index, registry-entry, or component-contract
+        #[arg(value_name = "TYPE")]
+        schema_type: SchemaTarget,
+    },
+    /// Print help for a command, or its structured metadata with `--json`
+    Help {
+        /// Command path (e.g. `docs site`; defaults to the top-level command)
+        command: Vec<String>,
+        /// Emit arguments, value kinds, and subcommands as JSON for agents
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a shell completion script: bash, zsh, fish, or powershell
+    Completions {
+        /// Shell to generate completions for
+        shell: Shell,
+    },
+    /// Print registry component names, one per line (used by completion scripts)
+    #[command(name = COMPONENTS_COMMAND, hide = true)]
+    Components,
+    /// Check this CLI against the versions the registry and project require
+    #[command(name = "self")]
+    SelfCmd {
+        #[command(subcommand)]
+        command: SelfCommand,
+    },
+    /// Launch the workbench studio (accepts `--story`, `--theme`, and `--props` permalinks)
+    Studio {
+        /// Arguments passed through to the studio
+        #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+        args: Vec<String>,
+    },
 }
 
-// ---------------------------------------------------------------------------
-// Command implementations
-// ---------------------------------------------------------------------------
+/// A document `gpui schema` describes: the output envelope shared by every
+/// command, or a registry type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SchemaTarget {
+    CliOutput,
+    Registry(SchemaType),
+}
 
-/// Generate a plan for a component installation.
-fn cmd_plan(component: &str, target_dir: &Path) -> Result<()> {
-    let index = registry::generate_registry();
-    let entry = index.get(component).with_context(|| {
-        let available = index.names().join(", ");
-        format!(
-            "Component '{}' not found in registry. Available: {}",
-            component, available
-        )
-    })?;
+impl SchemaTarget {
+    /// Every accepted name: `cli-output`, then the registry schema types.
+    fn names() -> Vec<&'static str> {
+        std::iter::once("cli-output")
+            .chain(SchemaType::ALL.iter().map(|ty| ty.name()))
+            .collect()
+    }
 
-    let layout = DefaultLayout::new(target_dir);
+    fn schema(self) -> Schema {
+        match self {
+            // `data` varies by command, so the envelope leaves it open.
+            SchemaTarget::CliOutput => schema_for!(CliOutput<serde_json::Value>),
+            SchemaTarget::Registry(schema_type) => schema_type.schema(),
+        }
+    }
+}
 
-    // Detect existing files for conflict checking
-    let existing_files = scan_existing_files(target_dir, &entry.name);
+impl std::str::FromStr for SchemaTarget {
+    type Err = String;
 
-    let plan = generate_plan(entry, &layout, &existing_files);
-    let output = CliOutput::success(plan);
-    println!("{}", output.to_json()?);
-    Ok(())
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        if name == "cli-output" {
+            return Ok(SchemaTarget::CliOutput);
+        }
+        SchemaType::from_name(name)
+            .map(SchemaTarget::Registry)
+            .ok_or_else(|| {
+                format!(
+                    "unknown schema type '{}' (expected one of: {})",
+                    name,
+                    Self::names().join(", ")
+                )
+            })
+    }
 }
 
-/// Add a component to the target project.
-fn cmd_add(component: &str, target_dir: &Path) -> Result<()> {
-    let index = registry::generate_registry();
-    let entry = index.get(component).with_context(|| {
-        let available = index.names().join(", ");
-        format!(
-            "Component '{}' not found in registry. Available: {}",
-            component, available
-        )
-    })?;
+#[derive(Subcommand)]
+enum TokensCommand {
+    /// Report unknown or deprecated `theme.*` token accesses
+    Scan {
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+}
 
-    let layout = DefaultLayout::new(target_dir);
-    let existing_files = scan_existing_files(target_dir, &entry.name);
-    let plan = generate_plan(entry, &layout, &existing_files);
+#[derive(Subcommand)]
+enum SelfCommand {
+    /// Compare the CLI version with the `min_cli_version` of the registry and `gpui.toml`
+    Check {
+        /// Remote registry URL serving `registry-index.json` (defaults to the built-in registry)
+        #[arg(long)]
+        registry: Option<String>,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+}
 
-    if plan.has_conflicts() {
-        let conflict_msgs: Vec<String> = plan
-            .conflicts
-            .iter()
-            .map(|c| format!("{}: {}", c.file_path.display(), c.reason))
-            .collect();
+#[derive(Subcommand)]
+enum DocsCommand {
+    /// Emit a static HTML registry browser, publishable to GitHub Pages
+    Site {
+        /// Output directory
+        #[arg(long, short = 'o', default_value = "site")]
+        out: PathBuf,
+        /// Directory of captured story snapshots named `<component>[-<state>].png`
+        /// (defaults to the studio's `snapshot_dir` in gpui.toml)
+        #[arg(long)]
+        snapshots: Option<PathBuf>,
+        /// Remote registry URL serving `registry-index.json` (defaults to the built-in registry)
+        #[arg(long)]
+        registry: Option<String>,
+        /// Locale tag for translated prop descriptions, e.g. `de-DE`
+        /// (defaults to the studio's `locale` in gpui.toml)
+        #[arg(long)]
+        locale: Option<String>,
+    },
+}
 
-        let errors: Vec<CliError> = plan
-            .conflicts
-            .iter()
-            .map(|c| CliError {
-                code: "CONFLICT".to_string(),
-                message: format!("{}: {}", c.file_path.display(), c.reason),
-            })
-            .collect();
+#[derive(Subcommand)]
+enum ContractsCommand {
+    /// Write every component contract to `<component>.json`
+    Export {
+        /// Output directory
+        #[arg(long, default_value = CONTRACTS_DIR)]
+        dir: PathBuf,
+    },
+    /// Validate contract JSON files and diff them against the registry's contracts
+    Import {
+        /// Directory of `<component>.json` files
+        #[arg(long, default_value = CONTRACTS_DIR)]
+        dir: PathBuf,
+    },
+}
 
-        eprintln!(
-            "Conflicts detected for component '{}'. Use --plan to review.",
-            component
-        );
-        for msg in &conflict_msgs {
-            eprintln!("  - {}", msg);
-        }
+#[derive(Subcommand)]
+enum ThemeCommand {
+    /// Derive a theme from an image's dominant colors and add it to `themes/`
+    FromImage {
+        /// PNG or JPEG image to extract the palette from
+        path: PathBuf,
+        /// Theme name (defaults to the image file name)
+        #[arg(long)]
+        name: Option<String>,
+        /// Number of palette colors to extract
+        #[arg(long, default_value_t = 6)]
+        colors: usize,
+        /// Overwrite an existing theme with the same name
+        #[arg(long)]
+        force: bool,
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+    /// Replay a patch script of token edits (exported by the studio) onto a theme file
+    ApplyPatch {
+        /// Patch script: a JSON list of `set_token` operations
+        patch: PathBuf,
+        /// Exported theme JSON file to patch
+        theme: PathBuf,
+        /// Write the patched theme here instead of overwriting `theme`
+        #[arg(long, short = 'o')]
+        output: Option<PathBuf>,
+    },
+    /// Flag near-duplicate colors, translucent contrast tokens, and tokens no component uses
+    Lint {
+        /// Target project directory (defaults to current directory)
+        #[arg(long, short = 'd')]
+        target_dir: Option<PathBuf>,
+    },
+}
 
-        let output = CliOutput::failure(plan, errors);
-        println!("{}", output.to_json()?);
-        return Ok(());
-    }
+/// Theme seed written by `gpui theme from-image`.
+///
+/// The theme engine derives the full token set from `background` and
+/// `accent`; `palette` records every extracted color for reference.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ThemeSeedFile {
+    pub name: String,
+    /// `dark` or `light`, from the background's lightness.
+    pub appearance: String,
+    pub background: String,
+    pub accent: String,
+    /// Extracted colors, most common first.
+    pub palette: Vec<String>,
+}
 
-    // Apply the plan
-    match apply_plan(&plan, target_dir) {
-        Ok(()) => {
-            let output = CliOutput::success(plan);
-            println!("{}", output.to_json()?);
-            Ok(())
-        }
-        Err(boxed) => {
-            let (failed_index, error, plan_clone) = *boxed;
-            let report = ApplyFailureReport {
-                plan: plan_clone.clone(),
-                failed_at_index: failed_index,
-                error: error.to_string(),
-                completed_mutations: plan_clone.mutations[..failed_index].to_vec(),
-                remaining_mutations: plan_clone.mutations[failed_index..].to_vec(),
-            };
+/// Result of `gpui theme from-image`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ThemeFromImageResult {
+    /// Where the seed was written, under the project's `themes/` directory.
+    pub theme_file: PathBuf,
+    pub seed: ThemeSeedFile,
+}
 
-            let errors = vec![CliError {
-                code: "APPLY_FAILED".to_string(),
-                message: error.to_string(),
-            }];
+/// One operation of a theme patch script.
+///
+/// Mirrors the theme crate's `PatchOp`; the CLI edits theme files as plain
+/// JSON so it does not depend on the theme engine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ThemePatchOp {
+    SetToken { path: String, value: String },
+}
 
-            let output = CliOutput::failure(report, errors);
-            println!("{}", output.to_json()?);
-            bail!("Apply failed at mutation {}: {}", failed_index, error)
-        }
-    }
+/// Result of `gpui theme apply-patch`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ThemePatchResult {
+    /// The theme file that was written.
+    pub theme_file: PathBuf,
+    /// Number of operations applied.
+    pub applied: usize,
 }
 
-/// Apply a plan from a JSON file.
-fn cmd_apply(plan_file: &Path, target_dir: &Path) -> Result<()> {
-    let json = std::fs::read_to_string(plan_file)
-        .with_context(|| format!("Failed to read plan file: {}", plan_file.display()))?;
+/// Result of `gpui docs site`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocsSiteResult {
+    pub out_dir: PathBuf,
+    /// Generated pages and assets, relative to `out_dir`.
+    pub files: Vec<PathBuf>,
+    /// Snapshot images copied into the site, by file name.
+    pub snapshots: Vec<String>,
+}
 
-    // Parse the plan -- it may be wrapped in a CliOutput envelope or be a raw PlanContract
-    let plan: PlanContract =
-        if let Ok(envelope) = serde_json::from_str::<CliOutput<PlanContract>>(&json) {
-            envelope.data
-        } else {
-            PlanContract::from_json(&json).context(
-                "Failed to parse plan JSON. Expected PlanContract or CliOutput<PlanContract>",
-            )?
-        };
+/// Result of `gpui contracts export`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContractsExportResult {
+    pub dir: PathBuf,
+    /// Contract files written, relative to `dir`.
+    pub files: Vec<PathBuf>,
+}
 
-    match apply_plan(&plan, target_dir) {
-        Ok(()) => {
-            let output = CliOutput::success(&plan);
-            println!("{}", output.to_json()?);
-            Ok(())
-        }
-        Err(boxed) => {
-            let (failed_index, error, _) = *boxed;
-            let report = ApplyFailureReport {
-                plan: plan.clone(),
-                failed_at_index: failed_index,
-                error: error.to_string(),
-                completed_mutations: plan.mutations[..failed_index].to_vec(),
-                remaining_mutations: plan.mutations[failed_index..].to_vec(),
-            };
+/// Result of `gpui contracts import`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ContractsImportReport {
+    pub dir: PathBuf,
+    /// Every `.json` file in `dir`, by file name.
+    pub contracts: Vec<ContractImport>,
+}
 
-            let errors = vec![CliError {
-                code: "APPLY_FAILED".to_string(),
-                message: error.to_string(),
-            }];
+/// Result of `gpui apply --dry-run`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DryRunReport {
+    pub component_name: String,
+    /// Files the apply would create, modify, or delete, ordered by path.
+    pub files: Vec<ChangedFile>,
+    /// Mutations that would fail.
+    pub failures: Vec<DryRunFailure>,
+}
 
-            let output = CliOutput::failure(report, errors);
-            println!("{}", output.to_json()?);
-            bail!("Apply failed at mutation {}: {}", failed_index, error)
-        }
-    }
+/// A mutation that would fail during apply.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DryRunFailure {
+    /// Index of the mutation in the plan (0-based).
+    pub mutation_index: usize,
+    pub file_path: PathBuf,
+    pub error: String,
 }
 
-// ---------------------------------------------------------------------------
-// Plan execution (apply)
-// ---------------------------------------------------------------------------
+/// Health of one installed component, reported by `gpui doctor`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub version: String,
+    pub installed_on: String,
+    /// Whether the component is ejected; its files are not expected to match.
+    pub locally_owned: bool,
+    /// Newer registry version, if one is available.
+    pub update_available: Option<String>,
+    /// Recorded files that no longer exist, relative to the component directory.
+    pub missing_files: Vec<String>,
+    /// Recorded files whose checksum changed since install.
+    pub modified_files: Vec<String>,
+}
 
-/// Execute a plan's mutations against the filesystem.
+/// Result of `gpui doctor`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DoctorReport {
+    /// The manifest the report was checked against.
+    pub manifest_file: PathBuf,
+    pub components: Vec<ComponentHealth>,
+    /// Registry components present on disk that the manifest does not record.
+    pub untracked: Vec<String>,
+}
+
+/// Result of `gpui eject`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EjectResult {
+    pub component_name: String,
+    /// Registry version the component was ejected from.
+    pub ejected_from: String,
+    /// Whether the component was already locally owned.
+    pub already_ejected: bool,
+    /// The `gpui.toml` that records the decision.
+    pub config_file: PathBuf,
+    /// Provenance files rewritten to mark local ownership.
+    pub provenance_files: Vec<PathBuf>,
+}
+
+/// Result of `gpui self check`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SelfCheckReport {
+    #[serde(flatten)]
+    pub compatibility: CliCompatibility,
+    /// How to upgrade, when the CLI is older than a declared minimum.
+    pub upgrade: Option<String>,
+}
+
+// ---------------------------------------------------------------------------
+// Command implementations
+// ---------------------------------------------------------------------------
+
+/// Generate a plan for a component installation, printed as JSON or, with
+/// `tree`, as a tree showing each mutation's rationale.
+fn cmd_plan(
+    component: &str,
+    registry_url: Option<&str>,
+    layout: &dyn TemplateAdapter,
+    tree: bool,
+) -> Result<()> {
+    let (mut index, remote) = load_registry(registry_url)?;
+    ensure_cli_version(
+        Some(&index),
+        registry_url,
+        Some(layout.project_root()),
+        VersionGate::Warn,
+    )?;
+    let name = select_component(&mut index, component)?;
+    ensure_stability(&index, &name, layout.project_root(), VersionGate::Warn)?;
+
+    // Detect existing files for conflict checking
+    let existing_files = scan_install_files(&index, &name, layout)?;
+
+    let mut plan = generate_install_plan(&index, &name, layout, &existing_files)?;
+    if let Some(remote) = &remote {
+        use_published_sources(remote, &index, &mut plan, layout, &existing_files)?;
+    }
+    define_missing_tokens(&mut plan, &index, layout);
+    plan.detect_section_conflicts(|path| std::fs::read_to_string(path).ok());
+    if tree {
+        print!("{}", plan.to_tree());
+        return Ok(());
+    }
+    let output = CliOutput::success(plan);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Add a component to the target project.
+fn cmd_add(
+    component: &str,
+    registry_url: Option<&str>,
+    policy: ConflictPolicy,
+    layout: &dyn TemplateAdapter,
+) -> Result<()> {
+    let (mut index, remote) = load_registry(registry_url)?;
+    ensure_cli_version(
+        Some(&index),
+        registry_url,
+        Some(layout.project_root()),
+        VersionGate::Refuse,
+    )?;
+    let name = select_component(&mut index, component)?;
+    ensure_stability(&index, &name, layout.project_root(), VersionGate::Refuse)?;
+
+    let existing_files = scan_install_files(&index, &name, layout)?;
+    let mut plan = generate_install_plan(&index, &name, layout, &existing_files)?;
+    if let Some(remote) = &remote {
+        use_published_sources(remote, &index, &mut plan, layout, &existing_files)?;
+    }
+    define_missing_tokens(&mut plan, &index, layout);
+    plan.detect_section_conflicts(|path| std::fs::read_to_string(path).ok());
+    if !plan.dependencies.is_empty() {
+        log::info!(
+            "Installing dependencies of {}: {}",
+            name,
+            plan.dependencies.join(", ")
+        );
+    }
+
+    resolve_conflicts(
+        &mut plan,
+        policy,
+        &mut std::io::stdin().lock(),
+        &mut std::io::stderr(),
+    )?;
+    execute_plan(plan, &name, false, layout.project_root())?;
+    Ok(())
+}
+
+/// How `gpui add` handles conflicts with existing files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConflictPolicy {
+    /// Report the conflicts and apply nothing.
+    Report,
+    /// Ask for a resolution per conflict (`--interactive`).
+    Prompt,
+    /// Resolve every conflict the same way (`--on-conflict`).
+    Resolve(ConflictResolution),
+}
+
+/// Resolve a plan's conflicts according to `policy`, recording each decision
+/// in the plan. Prompts are written to `prompt` and answers read from `input`.
+fn resolve_conflicts(
+    plan: &mut PlanContract,
+    policy: ConflictPolicy,
+    input: &mut impl BufRead,
+    prompt: &mut impl Write,
+) -> Result<()> {
+    if policy == ConflictPolicy::Report {
+        return Ok(());
+    }
+    for conflict in plan.conflicts.clone() {
+        let resolution = match policy {
+            ConflictPolicy::Resolve(resolution) => resolution,
+            _ => ask_resolution(&conflict, input, prompt)?,
+        };
+        let existing = if resolution == ConflictResolution::Backup {
+            std::fs::read_to_string(&conflict.file_path)
+                .with_context(|| format!("Failed to back up {}", conflict.file_path.display()))?
+        } else {
+            String::new()
+        };
+        plan.resolve_conflict(&conflict.file_path, resolution, &existing);
+        writeln!(
+            prompt,
+            "Resolved ({}): {}",
+            resolution.name(),
+            conflict.file_path.display()
+        )?;
+    }
+    Ok(())
+}
+
+/// Ask how to resolve `conflict` until a recognized answer is given.
 ///
-/// Returns Ok(()) on success, or Err with the failed mutation index and error.
-fn apply_plan(
-    plan: &PlanContract,
-    _target_dir: &std::path::Path,
-) -> std::result::Result<(), Box<(usize, String, PlanContract)>> {
-    for (i, mutation) in plan.mutations.iter().enumerate() {
-        if let Err(e) = apply_mutation(mutation) {
-            return Err(Box::new((i, e.to_string(), plan.clone())));
+/// Each resolution is accepted by name or by its first letter.
+fn ask_resolution(
+    conflict: &Conflict,
+    input: &mut impl BufRead,
+    prompt: &mut impl Write,
+) -> Result<ConflictResolution> {
+    writeln!(
+        prompt,
+        "Conflict: {}: {}",
+        conflict.file_path.display(),
+        conflict.reason
+    )?;
+    loop {
+        write!(
+            prompt,
+            "  [s]kip, [o]verwrite, [b]ackup and overwrite, or [r]ename? "
+        )?;
+        prompt.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            bail!("No resolution given for {}", conflict.file_path.display());
+        }
+        let answer = line.trim().to_lowercase();
+        let chosen = ConflictResolution::ALL
+            .into_iter()
+            .find(|resolution| answer == resolution.name() || answer == resolution.name()[..1]);
+        match chosen {
+            Some(resolution) => return Ok(resolution),
+            None => writeln!(prompt, "  Unrecognized choice '{}'", line.trim())?,
         }
     }
+}
 
-    // Write provenance metadata
-    for pa in &plan.provenance_actions {
-        let provenance_path = pa.file_path.with_extension("provenance.json");
-        let provenance = serde_json::json!({
-            "source": pa.source,
-            "license": pa.license,
-            "modifications": pa.modifications,
-            "installed_by": "gpui-cli",
-        });
-        if let Ok(json) = serde_json::to_string_pretty(&provenance) {
-            // Best-effort provenance write -- don't fail the install if this fails
-            let _ = std::fs::write(&provenance_path, json);
+/// List the components available in the registry.
+fn cmd_list(registry_url: Option<&str>, stability: &[Stability]) -> Result<()> {
+    let (index, _) = load_registry(registry_url)?;
+    ensure_cli_version(Some(&index), registry_url, None, VersionGate::Warn)?;
+    let entries: Vec<_> = index
+        .list()
+        .into_iter()
+        .filter(|entry| stability.is_empty() || stability.contains(&entry.stability))
+        .collect();
+    let output = CliOutput::success(entries);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Search the registry and report hits, best first.
+fn cmd_search(query: &str, fields: &[SearchField], registry_url: Option<&str>) -> Result<()> {
+    if query.trim().is_empty() {
+        bail!("Search query is empty");
+    }
+    let (index, _) = load_registry(registry_url)?;
+    ensure_cli_version(Some(&index), registry_url, None, VersionGate::Warn)?;
+    let hits = index.search(query, fields);
+    for hit in &hits {
+        let matched: Vec<String> = hit
+            .matches
+            .iter()
+            .map(|m| format!("{} {}", m.field.name(), m.value))
+            .collect();
+        log::info!(
+            "{} v{} ({})",
+            hit.component,
+            hit.version,
+            matched.join(", ")
+        );
+    }
+    if hits.is_empty() {
+        log::info!("No components match '{}'", query);
+    }
+    let output = CliOutput::success(hits);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// List the primitive contracts.
+fn cmd_list_primitives() -> Result<()> {
+    let index = registry::primitives::generate_primitive_index();
+    let output = CliOutput::success(index.list());
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// List the components recorded in the project manifest.
+fn cmd_list_installed(target_dir: &Path) -> Result<()> {
+    let manifest = load_manifest(target_dir)?;
+    let output = CliOutput::success(manifest.installed());
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Verify installed components against the checksums in the project manifest.
+fn cmd_doctor(layout: &dyn TemplateAdapter) -> Result<()> {
+    let target_dir = layout.project_root();
+    let manifest = load_manifest(target_dir)?;
+    let index = registry::generate_registry();
+    let report = check_installed(&manifest, &load_config(target_dir)?, &index, layout);
+
+    for component in &report.components {
+        for file in &component.modified_files {
+            log::warn!("{}: {} modified since install", component.name, file);
         }
+        if let Some(version) = &component.update_available {
+            log::info!(
+                "{}: update available (v{} -> v{})",
+                component.name,
+                component.version,
+                version
+            );
+        }
+    }
+    for name in &report.untracked {
+        log::warn!("{}: installed but not recorded in {}", name, MANIFEST_FILE);
     }
 
+    let errors: Vec<CliError> = report
+        .components
+        .iter()
+        .filter(|component| !component.locally_owned)
+        .flat_map(|component| {
+            component.missing_files.iter().map(|file| CliError {
+                code: "MISSING_FILE".to_string(),
+                message: format!("{}: {} is missing", component.name, file),
+            })
+        })
+        .collect();
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
     Ok(())
 }
 
-/// Apply a single file mutation.
-fn apply_mutation(mutation: &FileMutation) -> Result<()> {
-    match mutation.action {
-        FileAction::Create => {
-            // Ensure parent directory exists
-            if let Some(parent) = mutation.file_path.parent() {
-                std::fs::create_dir_all(parent)
-                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
-            }
-            std::fs::write(&mutation.file_path, &mutation.content).with_context(|| {
-                format!("Failed to write file: {}", mutation.file_path.display())
-            })?;
+/// Verify the built-in themes and every project theme.
+fn cmd_verify(target_dir: &Path) -> Result<()> {
+    let index = registry::generate_registry();
+    let config = load_config(target_dir)?;
+    let report = verify_project(target_dir, &index, &config.policy)?;
+
+    let mut errors = Vec::new();
+    for section in &report.themes {
+        let status = if section.passed() { "ok" } else { "FAILED" };
+        log::info!("== {} ({}) ==", section.theme, status);
+        for path in &section.missing_tokens {
+            log::info!("  missing token `{}`", path);
+            errors.push(CliError {
+                code: "MISSING_TOKEN".to_string(),
+                message: format!("{}: `{}` is not defined", section.theme, path),
+            });
         }
-        FileAction::Modify => match mutation.strategy {
-            MutationStrategy::AppendExport => {
-                let existing = if mutation.file_path.exists() {
-                    std::fs::read_to_string(&mutation.file_path).with_context(|| {
-                        format!("Failed to read file: {}", mutation.file_path.display())
-                    })?
-                } else {
-                    // Create the file if it doesn't exist
-                    if let Some(parent) = mutation.file_path.parent() {
-                        std::fs::create_dir_all(parent)?;
-                    }
-                    String::new()
-                };
+        for violation in &section.contrast_violations {
+            log::info!(
+                "  `{}` on `{}`: {:.2}:1 (needs {}:1)",
+                violation.foreground,
+                violation.background,
+                violation.ratio,
+                violation.minimum
+            );
+            errors.push(CliError {
+                code: "LOW_CONTRAST".to_string(),
+                message: format!(
+                    "{}: `{}` on `{}` is {:.2}:1, below {}:1",
+                    section.theme,
+                    violation.foreground,
+                    violation.background,
+                    violation.ratio,
+                    violation.minimum
+                ),
+            });
+        }
+        for token in &section.uncovered_tokens {
+            log::info!(
+                "  `{}` needed by {} is not defined",
+                token.path,
+                token.components.join(", ")
+            );
+            errors.push(CliError {
+                code: "UNCOVERED_TOKEN".to_string(),
+                message: format!(
+                    "{}: `{}` (used by {}) is not defined",
+                    section.theme,
+                    token.path,
+                    token.components.join(", ")
+                ),
+            });
+        }
+    }
+    if !report.policy_violations.is_empty() {
+        log::info!("== Disposition policy (FAILED) ==");
+    }
+    for violation in &report.policy_violations {
+        log::info!(
+            "  {} breaks rule {}: {}",
+            violation.component,
+            violation.rule + 1,
+            violation.message
+        );
+        errors.push(CliError {
+            code: "POLICY_VIOLATION".to_string(),
+            message: format!(
+                "{}: rule {}: {}",
+                violation.component,
+                violation.rule + 1,
+                violation.message
+            ),
+        });
+    }
+    if !report.perf_budget_violations.is_empty() {
+        log::info!("== Performance budgets (FAILED) ==");
+    }
+    for violation in &report.perf_budget_violations {
+        for overrun in &violation.overruns {
+            log::info!(
+                "  {} `{}`: {}ms (budget {}ms)",
+                violation.component,
+                overrun.limit,
+                overrun.measured_ms,
+                overrun.budget_ms
+            );
+            errors.push(CliError {
+                code: "PERF_BUDGET_EXCEEDED".to_string(),
+                message: format!(
+                    "{}: measured {}ms, over its `{}` of {}ms",
+                    violation.component, overrun.measured_ms, overrun.limit, overrun.budget_ms
+                ),
+            });
+        }
+    }
 
-                // Check if export already exists (idempotent)
-                if !existing.contains(&mutation.content) {
-                    let new_content = if existing.is_empty() {
-                        format!("{}\n", mutation.content)
-                    } else if existing.ends_with('\n') {
-                        format!("{}{}\n", existing, mutation.content)
-                    } else {
-                        format!("{}\n{}\n", existing, mutation.content)
-                    };
-                    std::fs::write(&mutation.file_path, new_content).with_context(|| {
-                        format!("Failed to modify file: {}", mutation.file_path.display())
-                    })?;
-                }
-            }
-            MutationStrategy::InsertUse => {
-                let existing = std::fs::read_to_string(&mutation.file_path).with_context(|| {
-                    format!("Failed to read file: {}", mutation.file_path.display())
-                })?;
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
+    Ok(())
+}
 
-                if !existing.contains(&mutation.content) {
-                    let new_content = format!("{}\n{}", mutation.content, existing);
-                    std::fs::write(&mutation.file_path, new_content)?;
+/// Compare each manifest entry with the files on disk and the registry.
+fn check_installed(
+    manifest: &Manifest,
+    config: &ProjectConfig,
+    index: &RegistryIndex,
+    layout: &dyn TemplateAdapter,
+) -> DoctorReport {
+    let components = manifest
+        .installed()
+        .into_iter()
+        .map(|entry| {
+            let mut missing_files = Vec::new();
+            let mut modified_files = Vec::new();
+            for (file, checksum) in &entry.checksums {
+                match std::fs::read_to_string(layout.project_root().join(file)) {
+                    Ok(content) if simple_checksum(&content) != *checksum => {
+                        modified_files.push(file.clone())
+                    }
+                    Ok(_) => {}
+                    Err(_) => missing_files.push(file.clone()),
                 }
             }
-            _ => {
-                // WriteFile, ReplaceSection, DeleteFile handled elsewhere
-                std::fs::write(&mutation.file_path, &mutation.content)?;
-            }
-        },
-        FileAction::Delete => {
-            if mutation.file_path.exists() {
-                std::fs::remove_file(&mutation.file_path).with_context(|| {
-                    format!("Failed to delete file: {}", mutation.file_path.display())
-                })?;
+            ComponentHealth {
+                name: entry.name.clone(),
+                version: entry.version.clone(),
+                installed_on: entry.installed_on.clone(),
+                locally_owned: config.is_locally_owned(&entry.name),
+                update_available: index
+                    .get(&entry.name)
+                    .filter(|latest| latest.version != entry.version)
+                    .map(|latest| latest.version.clone()),
+                missing_files,
+                modified_files,
             }
+        })
+        .collect();
+
+    let untracked = index
+        .list()
+        .into_iter()
+        .filter(|entry| !manifest.is_installed(&entry.name))
+        .filter(|entry| layout.component_module_file(&entry.name).exists())
+        .map(|entry| entry.name.clone())
+        .collect();
+
+    DoctorReport {
+        manifest_file: layout.project_root().join(MANIFEST_FILE),
+        components,
+        untracked,
+    }
+}
+
+/// Update an installed component, rewriting only files that changed.
+fn cmd_update(
+    component: &str,
+    plan_only: bool,
+    force: bool,
+    layout: &dyn TemplateAdapter,
+) -> Result<()> {
+    let index = registry::generate_registry();
+    ensure_cli_version(
+        Some(&index),
+        None,
+        Some(layout.project_root()),
+        VersionGate::for_plan_only(plan_only),
+    )?;
+    let entry = index.get(component).with_context(|| {
+        let available = index.names().join(", ");
+        format!(
+            "Component '{}' not found in registry. Available: {}",
+            component, available
+        )
+    })?;
+
+    let target_dir = layout.project_root();
+    let component_dir = layout.component_dir(&entry.name);
+    let manifest = load_manifest(target_dir)?;
+    ensure_installed(&manifest, &entry.name, &component_dir)?;
+    if load_config(target_dir)?.is_locally_owned(&entry.name) {
+        bail!(
+            "Component '{}' is locally owned (ejected); update skipped",
+            entry.name
+        );
+    }
+
+    let mut installed = read_installed(&generate_plan(entry, layout, &[]));
+    if let Some(recorded) = manifest.get(&entry.name) {
+        installed.version = recorded.version.clone();
+        installed.recorded_checksums = recorded
+            .checksums
+            .iter()
+            .map(|(file, checksum)| (target_dir.join(file), checksum.clone()))
+            .collect();
+    }
+    let sources = collect_sources(&target_dir.join("src"), &component_dir);
+    let mut plan = generate_update_plan(entry, layout, &installed, &sources);
+    define_missing_tokens(&mut plan, &index, layout);
+    plan.detect_section_conflicts(|path| std::fs::read_to_string(path).ok());
+
+    for note in &plan.migration_notes {
+        log::info!("Migration: {}", note.message);
+        for location in &note.locations {
+            log::info!(
+                "  {}:{}: {}",
+                location.file_path.display(),
+                location.line,
+                location.text
+            );
         }
     }
+
+    if plan_only {
+        let output = CliOutput::success(plan);
+        println!("{}", output.to_json()?);
+        return Ok(());
+    }
+
+    execute_plan(plan, component, force, target_dir)?;
     Ok(())
 }
 
-// ---------------------------------------------------------------------------
-// Utilities
-// ---------------------------------------------------------------------------
+/// Remove an installed component from the target project.
+fn cmd_remove(component: &str, plan_only: bool, layout: &dyn TemplateAdapter) -> Result<()> {
+    let index = registry::generate_registry();
+    ensure_cli_version(
+        Some(&index),
+        None,
+        Some(layout.project_root()),
+        VersionGate::for_plan_only(plan_only),
+    )?;
+    let entry = index.get(component).with_context(|| {
+        let available = index.names().join(", ");
+        format!(
+            "Component '{}' not found in registry. Available: {}",
+            component, available
+        )
+    })?;
 
-/// Scan for existing files that would conflict with a component installation.
-fn scan_existing_files(target_dir: &std::path::Path, component_name: &str) -> Vec<PathBuf> {
-    let component_dir = target_dir
-        .join("src/shared/ui")
-        .join(component_name.to_lowercase());
+    let target_dir = layout.project_root();
+    let component_dir = layout.component_dir(&entry.name);
+    ensure_installed(&load_manifest(target_dir)?, &entry.name, &component_dir)?;
 
-    let mut existing = Vec::new();
-    if component_dir.exists()
-        && let Ok(entries) = std::fs::read_dir(&component_dir)
-    {
-        for entry in entries.flatten() {
-            existing.push(entry.path());
+    let existing_files = scan_existing_files(layout, &entry.name);
+    let mut plan = generate_remove_plan(entry, layout, &existing_files);
+    plan.detect_section_conflicts(|path| std::fs::read_to_string(path).ok());
+
+    if plan_only {
+        let output = CliOutput::success(plan);
+        println!("{}", output.to_json()?);
+        return Ok(());
+    }
+
+    if execute_plan(plan, component, false, target_dir)? {
+        // A removed component no longer has an ownership decision to record.
+        let mut config = load_config(target_dir)?;
+        if config
+            .components
+            .remove(&entry.name.to_lowercase())
+            .is_some()
+        {
+            save_config(target_dir, &config)?;
         }
     }
-    existing
+    Ok(())
 }
 
-// ---------------------------------------------------------------------------
-// Main
-// ---------------------------------------------------------------------------
+/// Apply a freshly generated plan, printing the result envelope.
+///
+/// Refuses to apply when the plan has conflicts unless `force` is set.
+/// Returns whether the plan was applied.
+fn execute_plan(
+    plan: PlanContract,
+    component: &str,
+    force: bool,
+    target_dir: &Path,
+) -> Result<bool> {
+    if plan.has_conflicts() && force {
+        for c in &plan.conflicts {
+            log::warn!("Overwriting (--force): {}", c.file_path.display());
+        }
+    } else if plan.has_conflicts() {
+        let conflict_msgs: Vec<String> = plan
+            .conflicts
+            .iter()
+            .map(|c| format!("{}: {}", c.file_path.display(), c.reason))
+            .collect();
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
-    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+        let errors: Vec<CliError> = plan
+            .conflicts
+            .iter()
+            .map(|c| CliError {
+                code: "CONFLICT".to_string(),
+                message: format!("{}: {}", c.file_path.display(), c.reason),
+            })
+            .collect();
 
-    match cli.command {
-        Commands::Add {
-            component,
-            plan,
-            target_dir,
-        } => {
-            let dir = target_dir.unwrap_or_else(|| cwd.clone());
-            if plan {
-                cmd_plan(&component, &dir)
-            } else {
-                cmd_add(&component, &dir)
-            }
-        }
-        Commands::Plan {
-            component,
-            target_dir,
-        } => {
-            let dir = target_dir.unwrap_or_else(|| cwd.clone());
-            cmd_plan(&component, &dir)
+        log::error!(
+            "Conflicts detected for component '{}'. Use --plan to review.",
+            component
+        );
+        for msg in &conflict_msgs {
+            log::info!("  - {}", msg);
         }
-        Commands::Apply {
-            plan_file,
-            target_dir,
-        } => {
-            let dir = target_dir.unwrap_or_else(|| cwd.clone());
-            cmd_apply(&plan_file, &dir)
+
+        let output = CliOutput::failure(plan, errors);
+        println!("{}", output.to_json()?);
+        return Ok(false);
+    }
+
+    // Apply the plan
+    match apply_plan(&plan, target_dir) {
+        Ok(()) => {
+            let output = CliOutput::success(plan);
+            println!("{}", output.to_json()?);
+            Ok(true)
         }
+        Err(boxed) => {
+            let (failed_index, error, plan_clone) = *boxed;
+            let report = ApplyFailureReport {
+                plan: plan_clone.clone(),
+                failed_at_index: failed_index,
+                error: error.to_string(),
+                completed_mutations: plan_clone.mutations[..failed_index].to_vec(),
+                remaining_mutations: plan_clone.mutations[failed_index..].to_vec(),
+            };
+
+            let errors = vec![CliError {
+                code: "APPLY_FAILED".to_string(),
+                message: error.to_string(),
+            }];
+
+            let output = CliOutput::failure(report, errors);
+            println!("{}", output.to_json()?);
+            bail!("Apply failed at mutation {}: {}", failed_index, error)
+        }
+    }
+}
+
+/// Apply a plan from a JSON file.
+fn cmd_apply(plan_file: &Path, target_dir: &Path) -> Result<()> {
+    ensure_cli_version(None, None, Some(target_dir), VersionGate::Refuse)?;
+    let plan = read_plan_file(plan_file)?;
+
+    match apply_plan(&plan, target_dir) {
+        Ok(()) => {
+            let output = CliOutput::success(&plan);
+            println!("{}", output.to_json()?);
+            Ok(())
+        }
+        Err(boxed) => {
+            let (failed_index, error, _) = *boxed;
+            let report = ApplyFailureReport {
+                plan: plan.clone(),
+                failed_at_index: failed_index,
+                error: error.to_string(),
+                completed_mutations: plan.mutations[..failed_index].to_vec(),
+                remaining_mutations: plan.mutations[failed_index..].to_vec(),
+            };
+
+            let errors = vec![CliError {
+                code: "APPLY_FAILED".to_string(),
+                message: error.to_string(),
+            }];
+
+            let output = CliOutput::failure(report, errors);
+            println!("{}", output.to_json()?);
+            bail!("Apply failed at mutation {}: {}", failed_index, error)
+        }
+    }
+}
+
+/// Show unified diffs between an installed component and the registry.
+fn cmd_diff_install(component: &str, layout: &dyn TemplateAdapter) -> Result<()> {
+    let index = registry::generate_registry();
+    let entry = index.get(component).with_context(|| {
+        let available = index.names().join(", ");
+        format!(
+            "Component '{}' not found in registry. Available: {}",
+            component, available
+        )
+    })?;
+
+    let component_dir = layout.component_dir(&entry.name);
+    if !component_dir.exists() {
+        bail!(
+            "Component '{}' is not installed (expected {})",
+            entry.name,
+            component_dir.display()
+        );
+    }
+
+    let mut diff = install_diff(&generate_plan(entry, layout, &[]));
+    if load_config(layout.project_root())?.is_locally_owned(&entry.name) {
+        log::info!(
+            "# {} is locally owned (ejected); registry drift is informational",
+            entry.name
+        );
+        diff.recommendation = Recommendation::Skip;
+    }
+
+    for file in diff.files.iter().filter(|f| !f.diff.is_empty()) {
+        if let Some(modifications) = &file.local_modifications {
+            log::info!("# local modifications: {}", modifications);
+        }
+        log::info!("{}", file.diff.trim_end());
+    }
+    log::info!(
+        "{} v{}: recommend {:?}",
+        diff.component_name,
+        diff.component_version,
+        diff.recommendation
+    );
+
+    let output = CliOutput::success(diff);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Report what applying a plan file would change, without touching disk.
+fn cmd_apply_dry_run(plan_file: &Path, target_dir: &Path) -> Result<()> {
+    ensure_cli_version(None, None, Some(target_dir), VersionGate::Warn)?;
+    let plan = read_plan_file(plan_file)?;
+    let report = dry_run_plan(&plan, target_dir)?;
+
+    for file in &report.files {
+        let marker = match file.change {
+            FileChange::Create => '+',
+            FileChange::Modify => '~',
+            FileChange::Delete => '-',
+        };
+        log::info!("{} {}", marker, file.file_path.display());
+    }
+    let errors: Vec<CliError> = report
+        .failures
+        .iter()
+        .map(|failure| CliError {
+            code: "MUTATION_WOULD_FAIL".to_string(),
+            message: format!(
+                "mutation {} ({}): {}",
+                failure.mutation_index,
+                failure.file_path.display(),
+                failure.error
+            ),
+        })
+        .collect();
+    for error in &errors {
+        log::warn!("{}", error.message);
+    }
+    log::info!(
+        "Dry run: {} files would change, {} mutations would fail",
+        report.files.len(),
+        report.failures.len()
+    );
+
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Compare two plan files.
+fn cmd_diff(plan_a: &Path, plan_b: &Path) -> Result<()> {
+    let diff = PlanDiff::between(&read_plan_file(plan_a)?, &read_plan_file(plan_b)?);
+
+    for (label, before, after) in [
+        (
+            "operation",
+            format!("{:?}", diff.before.operation),
+            format!("{:?}", diff.after.operation),
+        ),
+        (
+            "component",
+            diff.before.component_name.clone(),
+            diff.after.component_name.clone(),
+        ),
+        (
+            "version",
+            diff.before.component_version.clone(),
+            diff.after.component_version.clone(),
+        ),
+        (
+            "layout",
+            diff.before.target_layout.clone(),
+            diff.after.target_layout.clone(),
+        ),
+    ] {
+        if before != after {
+            log::info!("~ {}: {} -> {}", label, before, after);
+        }
+    }
+    for change in &diff.mutations {
+        let mutation = change.after.as_ref().or(change.before.as_ref());
+        let action = mutation.map(|m| format!("{:?}", m.action).to_lowercase());
+        log::info!(
+            "{} {} {} ({:?})",
+            change_marker(change.kind),
+            action.unwrap_or_default(),
+            change.file_path.display(),
+            change.strategy
+        );
+    }
+    for change in &diff.conflicts {
+        let reason = change.after.as_ref().or(change.before.as_ref());
+        log::info!(
+            "{} conflict {}: {}",
+            change_marker(change.kind),
+            change.file_path.display(),
+            reason.map(String::as_str).unwrap_or_default()
+        );
+    }
+    for change in &diff.checksums {
+        log::info!(
+            "{} checksum {}: {} -> {}",
+            change_marker(change.kind),
+            change.file_path.display(),
+            change.before.as_deref().unwrap_or("-"),
+            change.after.as_deref().unwrap_or("-")
+        );
+    }
+    if diff.is_empty() {
+        log::info!("Plans are identical");
+    }
+
+    let output = CliOutput::success(diff);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Diff-style marker for a change: `+` added, `-` removed, `~` changed.
+fn change_marker(kind: ChangeKind) -> char {
+    match kind {
+        ChangeKind::Added => '+',
+        ChangeKind::Removed => '-',
+        ChangeKind::Changed => '~',
+    }
+}
+
+/// Fail unless a component is recorded in the manifest or present on disk.
+///
+/// The directory check covers installs that predate the manifest.
+fn ensure_installed(manifest: &Manifest, name: &str, component_dir: &Path) -> Result<()> {
+    if manifest.is_installed(name) || component_dir.exists() {
+        return Ok(());
+    }
+    bail!(
+        "Component '{}' is not installed (expected {})",
+        name,
+        component_dir.display()
+    )
+}
+
+/// Read the installed state of the files an install plan owns.
+///
+/// The installed version and per-file checksums come from provenance; installs
+/// that predate version tracking report `0.0.0`.
+fn read_installed(install: &PlanContract) -> InstalledComponent {
+    let files = install
+        .mutations
+        .iter()
+        .filter(|m| {
+            matches!(
+                m.strategy,
+                MutationStrategy::WriteFile | MutationStrategy::ReplaceSection
+            )
+        })
+        .filter_map(|m| {
+            let content = std::fs::read_to_string(&m.file_path).ok()?;
+            Some((m.file_path.clone(), content))
+        })
+        .collect();
+
+    let provenance = read_provenance(install);
+    let version = provenance
+        .iter()
+        .find_map(|(_, p)| p.get("version")?.as_str())
+        .unwrap_or("0.0.0")
+        .to_string();
+    let recorded_checksums = provenance
+        .iter()
+        .filter_map(|(pa, p)| {
+            Some((
+                pa.file_path.clone(),
+                p.get("checksum")?.as_str()?.to_string(),
+            ))
+        })
+        .collect();
+
+    InstalledComponent {
+        version,
+        files,
+        recorded_checksums,
+    }
+}
+
+/// Scan the target project for token accesses the theme does not define.
+fn cmd_tokens_scan(layout: &dyn TemplateAdapter) -> Result<()> {
+    let tokens_file = layout.theme_tokens_file();
+    let tokens_rs = std::fs::read_to_string(&tokens_file)
+        .with_context(|| format!("No theme tokens file at {}", tokens_file.display()))?;
+    let tokens = TokenSet::parse(&tokens_rs);
+    if tokens.is_empty() {
+        bail!("No `ThemeTokens` struct found in {}", tokens_file.display());
+    }
+
+    let sources = collect_sources(&layout.project_root().join("src"), &tokens_file);
+    let report = scan_token_usages(&tokens, &sources);
+
+    for issue in &report.issues {
+        let kind = match issue.kind {
+            TokenIssueKind::Unknown => "unknown",
+            TokenIssueKind::Deprecated => "deprecated",
+        };
+        log::warn!(
+            "{}:{}: {} token `{}`{}",
+            issue.location.file_path.display(),
+            issue.location.line,
+            kind,
+            issue.path,
+            issue
+                .note
+                .as_ref()
+                .map(|note| format!(" ({})", note))
+                .unwrap_or_default()
+        );
+    }
+
+    let errors: Vec<CliError> = report
+        .issues
+        .iter()
+        .filter(|issue| issue.kind == TokenIssueKind::Unknown)
+        .map(|issue| CliError {
+            code: "UNKNOWN_TOKEN".to_string(),
+            message: format!(
+                "{}:{}: {}",
+                issue.location.file_path.display(),
+                issue.location.line,
+                issue.path
+            ),
+        })
+        .collect();
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Derive a theme seed from an image and write it to the project's `themes/` directory.
+fn cmd_theme_from_image(
+    path: &Path,
+    name: Option<&str>,
+    colors: usize,
+    force: bool,
+    target_dir: &Path,
+) -> Result<()> {
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+    if theme_slug(&name).is_empty() {
+        bail!(
+            "Theme name '{}' has no usable characters; pass --name",
+            name
+        );
+    }
+
+    let pixels = image_pixels(path)?;
+    let palette = extract_palette(&pixels, colors.max(2));
+    let Some(seed) = theme_seed(&name, &palette) else {
+        bail!("Image {} has no pixels", path.display());
+    };
+
+    let result = write_theme_seed(seed, force, target_dir)?;
+    log::info!(
+        "Theme '{}' ({}) written to {}",
+        result.seed.name,
+        result.seed.appearance,
+        result.theme_file.display()
+    );
+    let output = CliOutput::success(result);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Write a theme seed to `themes/<slug>.json`, refusing to overwrite unless `force`.
+fn write_theme_seed(
+    seed: ThemeSeedFile,
+    force: bool,
+    target_dir: &Path,
+) -> Result<ThemeFromImageResult> {
+    let theme_file = target_dir
+        .join(THEMES_DIR)
+        .join(format!("{}.json", theme_slug(&seed.name)));
+    if theme_file.exists() && !force {
+        bail!(
+            "Theme file {} already exists; use --force to overwrite",
+            theme_file.display()
+        );
+    }
+    if let Some(parent) = theme_file.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+    }
+    std::fs::write(&theme_file, serde_json::to_string_pretty(&seed)?)
+        .with_context(|| format!("Failed to write {}", theme_file.display()))?;
+    Ok(ThemeFromImageResult { theme_file, seed })
+}
+
+/// Replay a theme patch script onto an exported theme file.
+fn cmd_theme_apply_patch(patch: &Path, theme: &Path, output: Option<&Path>) -> Result<()> {
+    let patch_json = std::fs::read_to_string(patch)
+        .with_context(|| format!("Failed to read {}", patch.display()))?;
+    let ops: Vec<ThemePatchOp> = serde_json::from_str(&patch_json)
+        .with_context(|| format!("Invalid theme patch {}", patch.display()))?;
+    let theme_json = std::fs::read_to_string(theme)
+        .with_context(|| format!("Failed to read {}", theme.display()))?;
+    let mut tokens: serde_json::Value = serde_json::from_str(&theme_json)
+        .with_context(|| format!("Invalid theme file {}", theme.display()))?;
+
+    apply_theme_patch(&mut tokens, &ops)?;
+
+    let theme_file = output.unwrap_or(theme).to_path_buf();
+    std::fs::write(&theme_file, serde_json::to_string_pretty(&tokens)?)
+        .with_context(|| format!("Failed to write {}", theme_file.display()))?;
+    log::info!(
+        "Applied {} token edits to {}",
+        ops.len(),
+        theme_file.display()
+    );
+    let output = CliOutput::success(ThemePatchResult {
+        theme_file,
+        applied: ops.len(),
+    });
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Lint every theme's token values and report tokens no component uses.
+///
+/// Findings are advisory: they are logged as warnings and the command
+/// succeeds, so it can run alongside `gpui verify` without failing CI.
+fn cmd_theme_lint(target_dir: &Path) -> Result<()> {
+    let index = registry::generate_registry();
+    let report = lint_themes(target_dir, &index)?;
+
+    for theme in &report.themes {
+        log::info!("== {} ({} findings) ==", theme.theme, theme.finding_count());
+        for duplicate in &theme.near_duplicates {
+            log::warn!(
+                "  `{}` and `{}` are nearly identical ({:.2} apart); alias one to the other",
+                duplicate.first,
+                duplicate.second,
+                duplicate.distance
+            );
+        }
+        for token in &theme.translucent_tokens {
+            log::warn!(
+                "  `{}` has alpha {:.2} but is checked for contrast with {}",
+                token.path,
+                token.alpha,
+                token
+                    .paired_with
+                    .iter()
+                    .map(|path| format!("`{path}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+    if !report.unused_tokens.is_empty() {
+        log::warn!(
+            "{} tokens are not used by any component: {}",
+            report.unused_tokens.len(),
+            report.unused_tokens.join(", ")
+        );
+    }
+
+    let output = CliOutput::success(report);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Generate the static registry browser site.
+fn cmd_docs_site(
+    out: &Path,
+    snapshots: Option<&Path>,
+    registry_url: Option<&str>,
+    locale: &str,
+) -> Result<()> {
+    let (index, _) = load_registry(registry_url)?;
+    let result = write_docs_site(&index, out, snapshots, locale)?;
+    log::info!(
+        "Wrote {} files and {} snapshots to {}",
+        result.files.len(),
+        result.snapshots.len(),
+        out.display()
+    );
+    println!("{}", CliOutput::success(result).to_json()?);
+    Ok(())
+}
+
+/// Write the site for `index` in `locale` to `out`, copying in the snapshots
+/// that name a registry component.
+fn write_docs_site(
+    index: &RegistryIndex,
+    out: &Path,
+    snapshots: Option<&Path>,
+    locale: &str,
+) -> Result<DocsSiteResult> {
+    let mut snapshot_files = Vec::new();
+    if let Some(dir) = snapshots {
+        let entries = std::fs::read_dir(dir)
+            .with_context(|| format!("Failed to read snapshots from {}", dir.display()))?;
+        for entry in entries {
+            let name = entry?.file_name().to_string_lossy().into_owned();
+            if index
+                .list()
+                .iter()
+                .any(|component| snapshot_state(&component.name, &name).is_some())
+            {
+                snapshot_files.push(name);
+            }
+        }
+        snapshot_files.sort();
+    }
+
+    let files = generate_site(index, &builtin_themes(), &snapshot_files, locale);
+    for file in &files {
+        let path = out.join(&file.path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&path, &file.contents)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    if let Some(dir) = snapshots
+        && !snapshot_files.is_empty()
+    {
+        let snapshots_dir = out.join(SNAPSHOTS_DIR);
+        std::fs::create_dir_all(&snapshots_dir)
+            .with_context(|| format!("Failed to create {}", snapshots_dir.display()))?;
+        for name in &snapshot_files {
+            std::fs::copy(dir.join(name), snapshots_dir.join(name))
+                .with_context(|| format!("Failed to copy snapshot {}", name))?;
+        }
+    }
+
+    Ok(DocsSiteResult {
+        out_dir: out.to_path_buf(),
+        files: files.into_iter().map(|file| file.path).collect(),
+        snapshots: snapshot_files,
+    })
+}
+
+/// Write every catalog contract into `dir` as JSON.
+fn cmd_contracts_export(dir: &Path) -> Result<()> {
+    let result = write_contracts(dir)?;
+    log::info!(
+        "Wrote {} contracts to {}",
+        result.files.len(),
+        dir.display()
+    );
+    println!("{}", CliOutput::success(result).to_json()?);
+    Ok(())
+}
+
+fn write_contracts(dir: &Path) -> Result<ContractsExportResult> {
+    let files = export_contracts().context("Failed to serialize contracts")?;
+    std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    for file in &files {
+        let path = dir.join(&file.path);
+        std::fs::write(&path, &file.contents)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    Ok(ContractsExportResult {
+        dir: dir.to_path_buf(),
+        files: files.into_iter().map(|file| file.path).collect(),
+    })
+}
+
+/// Validate the contract files in `dir` and report how each differs from
+/// the registry. Invalid files fail the command; changes are only reported.
+fn cmd_contracts_import(dir: &Path) -> Result<()> {
+    let report = read_contracts(dir)?;
+
+    let mut errors = Vec::new();
+    for import in &report.contracts {
+        let component = import.component.as_deref().unwrap_or(&import.file);
+        match import.status {
+            ImportStatus::Unchanged => log::info!("{}: unchanged", component),
+            ImportStatus::New => log::info!("{}: new component", component),
+            ImportStatus::Changed => {
+                log::info!("{}: {} changes", component, import.changes.len());
+                for change in &import.changes {
+                    let show = |value: &Option<serde_json::Value>| {
+                        value
+                            .as_ref()
+                            .map_or_else(|| "(none)".to_string(), |value| value.to_string())
+                    };
+                    log::info!(
+                        "  {}: {} -> {}",
+                        change.path,
+                        show(&change.current),
+                        show(&change.proposed)
+                    );
+                }
+            }
+            ImportStatus::Invalid => {
+                log::info!("{}: invalid", import.file);
+                for error in &import.errors {
+                    log::info!("  {}", error);
+                    errors.push(CliError {
+                        code: "INVALID_CONTRACT".to_string(),
+                        message: format!("{}: {}", import.file, error),
+                    });
+                }
+            }
+        }
+    }
+
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+fn read_contracts(dir: &Path) -> Result<ContractsImportReport> {
+    let mut files = Vec::new();
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read contracts from {}", dir.display()))?;
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    files.sort();
+
+    let mut contracts = Vec::new();
+    for path in files {
+        let json = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let file = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        contracts.push(import_contract(&file, &json));
+    }
+    Ok(ContractsImportReport {
+        dir: dir.to_path_buf(),
+        contracts,
+    })
+}
+
+/// Compare the captured snapshots of each theme in `themes` with its
+/// baselines, or with `update`, make the captures the new baselines.
+fn cmd_snapshot(captured: &Path, baselines: &Path, themes: &[String], update: bool) -> Result<()> {
+    let index = registry::generate_registry();
+    let report = check_snapshots(&index, captured, baselines, themes, update)?;
+
+    let mut errors = Vec::new();
+    for theme in &report.uncaptured {
+        log::info!("== {} (not captured) ==", theme);
+        errors.push(CliError {
+            code: "THEME_NOT_CAPTURED".to_string(),
+            message: format!(
+                "{theme}: no snapshots in {}",
+                captured.join(theme_dir(theme)).display()
+            ),
+        });
+    }
+    for diff in &report.themes {
+        let status = if diff.passed() { "ok" } else { "FAILED" };
+        log::info!(
+            "== {} ({}, {} captured) ==",
+            diff.theme,
+            status,
+            diff.captured
+        );
+        for name in &diff.added {
+            log::info!("  new snapshot {}", name);
+        }
+        for name in &diff.changed {
+            log::info!("  changed {}", name);
+            errors.push(CliError {
+                code: "SNAPSHOT_CHANGED".to_string(),
+                message: format!("{}: {} differs from its baseline", diff.theme, name),
+            });
+        }
+        for name in &diff.missing {
+            log::info!("  missing {}", name);
+            errors.push(CliError {
+                code: "SNAPSHOT_MISSING".to_string(),
+                message: format!(
+                    "{}: {} has a baseline but was not captured",
+                    diff.theme, name
+                ),
+            });
+        }
+    }
+    for component in &report.theme_invariant {
+        log::warn!(
+            "{} renders identically in every theme; check it for hard-coded colors",
+            component
+        );
+    }
+
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Read each theme's captures and baselines, updating the baselines first
+/// with `update`, and compare them.
+fn check_snapshots(
+    index: &RegistryIndex,
+    captured: &Path,
+    baselines: &Path,
+    themes: &[String],
+    update: bool,
+) -> Result<SnapshotReport> {
+    let read = |root: &Path| -> Result<Vec<ThemeSnapshots>> {
+        themes
+            .iter()
+            .map(|theme| {
+                let dir = root.join(theme_dir(theme));
+                ThemeSnapshots::read_dir(theme.as_str(), &dir)
+                    .with_context(|| format!("Failed to read snapshots from {}", dir.display()))
+            })
+            .collect()
+    };
+    let captured_sets = read(captured)?;
+    let mut baseline_sets = read(baselines)?;
+    if update {
+        for theme in captured_sets.iter().filter(|theme| !theme.is_empty()) {
+            write_baselines(captured, baselines, theme, &baseline_sets)?;
+        }
+        baseline_sets = read(baselines)?;
+    }
+    Ok(SnapshotReport::compare(
+        &baseline_sets,
+        &captured_sets,
+        index,
+    ))
+}
+
+/// Copy a theme's captured snapshots over its baselines, removing baselines
+/// that were not captured.
+fn write_baselines(
+    captured: &Path,
+    baselines: &Path,
+    theme: &ThemeSnapshots,
+    baseline_sets: &[ThemeSnapshots],
+) -> Result<()> {
+    let from = captured.join(theme_dir(&theme.theme));
+    let to = baselines.join(theme_dir(&theme.theme));
+    std::fs::create_dir_all(&to).with_context(|| format!("Failed to create {}", to.display()))?;
+    for name in theme.files.keys() {
+        std::fs::copy(from.join(name), to.join(name))
+            .with_context(|| format!("Failed to copy snapshot {}", name))?;
+    }
+    let stale = baseline_sets
+        .iter()
+        .filter(|baseline| baseline.theme == theme.theme)
+        .flat_map(|baseline| baseline.files.keys())
+        .filter(|name| !theme.files.contains_key(*name));
+    for name in stale {
+        std::fs::remove_file(to.join(name))
+            .with_context(|| format!("Failed to remove baseline {}", name))?;
+    }
+    log::info!(
+        "Updated {} baselines for {}",
+        theme.files.len(),
+        theme.theme
+    );
+    Ok(())
+}
+
+/// Print the JSON Schema of a CLI document type.
+fn cmd_schema(target: SchemaTarget) -> Result<()> {
+    let output = CliOutput::success(target.schema());
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Print long help for a command, or its metadata as JSON.
+fn cmd_help(path: &[String], json: bool) -> Result<()> {
+    let mut cmd = Cli::command();
+    cmd.build();
+    let mut target = &mut cmd;
+    for name in path {
+        target = match target.find_subcommand_mut(name) {
+            Some(sub) if !sub.is_hide_set() => sub,
+            _ => bail!("Unknown command '{}'", path.join(" ")),
+        };
+    }
+    if json {
+        let output = CliOutput::success(command_help(target));
+        println!("{}", output.to_json()?);
+    } else {
+        print!("{}", target.render_long_help());
+    }
+    Ok(())
+}
+
+/// Print the completion script for `shell`.
+fn cmd_completions(shell: Shell) -> Result<()> {
+    let mut cmd = Cli::command();
+    cmd.build();
+    print!("{}", completion_script(shell, &command_help(&cmd)));
+    Ok(())
+}
+
+/// Print component names for shell completion, lowercase as users type them.
+fn cmd_components() -> Result<()> {
+    let index = registry::generate_registry();
+    for name in index.names() {
+        println!("{}", name.to_lowercase());
+    }
+    Ok(())
+}
+
+/// Mark an installed component as locally owned.
+fn cmd_eject(component: &str, layout: &dyn TemplateAdapter) -> Result<()> {
+    let index = registry::generate_registry();
+    let entry = index.get(component).with_context(|| {
+        let available = index.names().join(", ");
+        format!(
+            "Component '{}' not found in registry. Available: {}",
+            component, available
+        )
+    })?;
+
+    let component_dir = layout.component_dir(&entry.name);
+    if !component_dir.exists() {
+        bail!(
+            "Component '{}' is not installed (expected {})",
+            entry.name,
+            component_dir.display()
+        );
+    }
+
+    let result = eject(&generate_plan(entry, layout, &[]), layout.project_root())?;
+    let output = CliOutput::success(result);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Record sign-offs in the acceptance ledger and report the component's readiness.
+fn cmd_accept(component: &str, items: &[String], by: &str, target_dir: &Path) -> Result<()> {
+    let index = registry::generate_registry();
+    let entry = index.get(component).with_context(|| {
+        let available = index.names().join(", ");
+        format!(
+            "Component '{}' not found in registry. Available: {}",
+            component, available
+        )
+    })?;
+
+    let mut ledger = load_ledger(target_dir)?;
+    let commit = git_head(target_dir);
+    ledger.accept(
+        &entry.name,
+        items,
+        by,
+        &registry::manifest::today(),
+        commit.as_deref(),
+    )?;
+    save_ledger(target_dir, &ledger)?;
+    log::info!(
+        "{}: {} signed off by {}{}",
+        entry.name,
+        items.join(", "),
+        by,
+        commit
+            .map(|commit| format!(" at {}", commit))
+            .unwrap_or_default()
+    );
+
+    let output = CliOutput::success(readiness(entry, &ledger));
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Report acceptance readiness for one component or every component.
+fn cmd_readiness(component: Option<&str>, target_dir: &Path) -> Result<()> {
+    let index = registry::generate_registry();
+    let entries = match component {
+        Some(component) => vec![index.get(component).with_context(|| {
+            let available = index.names().join(", ");
+            format!(
+                "Component '{}' not found in registry. Available: {}",
+                component, available
+            )
+        })?],
+        None => index.list(),
+    };
+
+    let ledger = load_ledger(target_dir)?;
+    let reports: Vec<Readiness> = entries
+        .into_iter()
+        .map(|entry| readiness(entry, &ledger))
+        .collect();
+    for report in &reports {
+        let pending: Vec<&str> = report
+            .items
+            .iter()
+            .filter(|item| item.status == ItemStatus::Pending)
+            .map(|item| item.item.as_str())
+            .collect();
+        log::info!(
+            "{}: {}/{} items{}",
+            report.component,
+            report.completed,
+            report.items.len(),
+            if pending.is_empty() {
+                String::new()
+            } else {
+                format!(" (pending: {})", pending.join(", "))
+            }
+        );
+    }
+
+    let output = CliOutput::success(reports);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Report token and primitive usage across the installed components.
+fn cmd_report(target_dir: &Path) -> Result<()> {
+    let manifest = load_manifest(target_dir)?;
+    let index = registry::generate_registry();
+    let primitive_consumers: BTreeMap<String, Vec<String>> =
+        registry::primitives::generate_primitive_index()
+            .list()
+            .into_iter()
+            .map(|contract| (contract.name.clone(), contract.consumers.clone()))
+            .collect();
+    let report = usage_report(&manifest, &index, &primitive_consumers);
+
+    for name in &report.unknown {
+        log::warn!("{}: installed but not in the registry; not counted", name);
+    }
+    for usage in &report.components {
+        log::info!(
+            "{}: {} tokens ({} exclusive), {} primitives ({} exclusive)",
+            usage.component,
+            usage.tokens.len(),
+            usage.exclusive_tokens.len(),
+            usage.primitives.len(),
+            usage.exclusive_primitives.len()
+        );
+    }
+    log::info!(
+        "{} components use {} tokens ({} shared) and {} primitives ({} shared)",
+        report.components.len(),
+        report.tokens.len(),
+        report.shared_tokens(),
+        report.primitives.len(),
+        report.shared_primitives()
+    );
+
+    let output = CliOutput::success(report);
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// The short hash of `HEAD` in `dir`, if it is a git checkout.
+fn git_head(dir: &Path) -> Option<String> {
+    let output = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    let head = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !head.is_empty()).then_some(head)
+}
+
+/// Record the ejection in `gpui.toml` and flip each provenance file to local ownership.
+fn eject(plan: &PlanContract, target_dir: &Path) -> Result<EjectResult> {
+    let mut config = load_config(target_dir)?;
+    let already_ejected = !config.eject(&plan.component_name, &plan.component_version);
+
+    let mut provenance_files = Vec::new();
+    for pa in &plan.provenance_actions {
+        let provenance_path = provenance_path(&pa.file_path);
+        let Ok(json) = std::fs::read_to_string(&provenance_path) else {
+            continue;
+        };
+        let mut provenance: serde_json::Value = serde_json::from_str(&json)
+            .with_context(|| format!("Invalid provenance: {}", provenance_path.display()))?;
+        provenance["ownership"] = serde_json::json!(Ownership::Local);
+        provenance["modifications"] = serde_json::json!(format!(
+            "Ejected from registry v{}; locally owned",
+            plan.component_version
+        ));
+        std::fs::write(&provenance_path, serde_json::to_string_pretty(&provenance)?)
+            .with_context(|| format!("Failed to write {}", provenance_path.display()))?;
+        provenance_files.push(provenance_path);
+    }
+
+    let config_file = save_config(target_dir, &config)?;
+    Ok(EjectResult {
+        component_name: plan.component_name.clone(),
+        ejected_from: plan.component_version.clone(),
+        already_ejected,
+        config_file,
+        provenance_files,
+    })
+}
+
+/// Read the on-disk state of every file a plan writes and diff it against the plan.
+///
+/// A file counts as locally modified when its provenance `modifications`
+/// differ from what the install recorded.
+fn install_diff(plan: &PlanContract) -> InstallDiff {
+    let installed: BTreeMap<PathBuf, String> = plan
+        .mutations
+        .iter()
+        .filter_map(|m| {
+            let content = std::fs::read_to_string(&m.file_path).ok()?;
+            Some((m.file_path.clone(), content))
+        })
+        .collect();
+
+    let local_modifications: BTreeMap<PathBuf, String> = read_provenance(plan)
+        .into_iter()
+        .filter_map(|(pa, provenance)| {
+            let recorded = provenance.get("modifications")?.as_str()?;
+            (recorded != pa.modifications).then(|| (pa.file_path.clone(), recorded.to_string()))
+        })
+        .collect();
+
+    diff_install(plan, &installed, &local_modifications)
+}
+
+/// Report whether this CLI meets the `min_cli_version` of the registry and
+/// the project's `gpui.toml`.
+fn cmd_self_check(registry_url: Option<&str>, target_dir: &Path) -> Result<()> {
+    let (index, _) = load_registry(registry_url)?;
+    let compatibility = cli_compatibility(Some(&index), registry_url, Some(target_dir))?;
+
+    log::info!("gpui {}", compatibility.cli_version);
+    if compatibility.requirements.is_empty() {
+        log::info!(
+            "  no min_cli_version declared by the registry or {}",
+            CONFIG_FILE
+        );
+    }
+    let mut errors = Vec::new();
+    for requirement in &compatibility.requirements {
+        let status = if requirement.satisfied {
+            "ok"
+        } else {
+            "too old"
+        };
+        log::info!(
+            "  {} requires {} or later: {}",
+            requirement.source,
+            requirement.min_cli_version,
+            status
+        );
+        if !requirement.satisfied {
+            errors.push(CliError {
+                code: "CLI_TOO_OLD".to_string(),
+                message: format!(
+                    "{} requires gpui {} or later; this is {}",
+                    requirement.source, requirement.min_cli_version, compatibility.cli_version
+                ),
+            });
+        }
+    }
+    let upgrade = compatibility.upgrade_instructions();
+    if let Some(upgrade) = &upgrade {
+        log::warn!("{}", upgrade);
+    }
+
+    let report = SelfCheckReport {
+        compatibility,
+        upgrade,
+    };
+    let output = if errors.is_empty() {
+        CliOutput::success(report)
+    } else {
+        CliOutput::failure(report, errors)
+    };
+    println!("{}", output.to_json()?);
+    Ok(())
+}
+
+/// Launch the studio binary, passing `args` through, and wait for it to exit.
+///
+/// The studio is looked up next to this executable first, then on `PATH`.
+fn cmd_studio(args: &[String]) -> Result<()> {
+    let sibling = std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.parent()?.join("studio")))
+        .filter(|path| path.exists());
+    let program = sibling.unwrap_or_else(|| PathBuf::from("studio"));
+    let status = std::process::Command::new(&program)
+        .args(args)
+        .status()
+        .with_context(|| format!("Failed to launch {}", program.display()))?;
+    if !status.success() {
+        bail!("Studio exited with {}", status);
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Plan execution (apply)
+// ---------------------------------------------------------------------------
+
+/// Execute a plan's mutations against the filesystem.
+///
+/// Returns Ok(()) on success, or Err with the failed mutation index and error.
+fn apply_plan(
+    plan: &PlanContract,
+    target_dir: &std::path::Path,
+) -> std::result::Result<(), Box<(usize, String, PlanContract)>> {
+    apply_plan_in(&mut RealFs, plan, target_dir)
+}
+
+/// Apply a plan through `fs`, stopping at the first failed mutation.
+fn apply_plan_in(
+    fs: &mut dyn Vfs,
+    plan: &PlanContract,
+    target_dir: &std::path::Path,
+) -> std::result::Result<(), Box<(usize, String, PlanContract)>> {
+    let started = Instant::now();
+    let total = plan.mutations.len();
+    log::info!(
+        mutations = total;
+        "Applying {} v{}", plan.component_name, plan.component_version
+    );
+    for (i, mutation) in plan.mutations.iter().enumerate() {
+        let mutation_started = Instant::now();
+        if let Err(e) = apply_mutation(fs, mutation) {
+            return Err(Box::new((i, e.to_string(), plan.clone())));
+        }
+        let action = format!("{:?}", mutation.action).to_lowercase();
+        log::debug!(
+            index = i,
+            action = action.as_str(),
+            path:% = mutation.file_path.display(),
+            elapsed_us = mutation_started.elapsed().as_micros() as u64;
+            "Applied mutation {}/{}", i + 1, total
+        );
+    }
+    write_provenance(fs, plan);
+    log::debug!(files = plan.provenance_actions.len(); "Wrote provenance");
+
+    // The files are in place, so a manifest failure does not fail the apply.
+    if let Err(e) = record_in_manifest(fs, plan, target_dir) {
+        log::warn!("{:#}", e);
+    }
+
+    log::info!(
+        elapsed_ms = started.elapsed().as_millis() as u64;
+        "Applied {} v{}", plan.component_name, plan.component_version
+    );
+    Ok(())
+}
+
+/// The provenance file recorded next to an attributed file.
+fn provenance_path(file: &Path) -> PathBuf {
+    file.with_extension("provenance.json")
+}
+
+/// Read the provenance recorded next to each of a plan's attributed files,
+/// skipping files whose provenance is missing or not valid JSON.
+fn read_provenance(plan: &PlanContract) -> Vec<(&ProvenanceAction, serde_json::Value)> {
+    plan.provenance_actions
+        .iter()
+        .filter_map(|pa| {
+            let json = std::fs::read_to_string(provenance_path(&pa.file_path)).ok()?;
+            Some((pa, serde_json::from_str(&json).ok()?))
+        })
+        .collect()
+}
+
+/// Write provenance metadata next to each attributed file.
+fn write_provenance(fs: &mut dyn Vfs, plan: &PlanContract) {
+    for pa in &plan.provenance_actions {
+        let provenance_path = provenance_path(&pa.file_path);
+        let provenance = serde_json::json!({
+            "source": pa.source,
+            "license": pa.license,
+            "modifications": pa.modifications,
+            "installed_by": "gpui-cli",
+            "version": plan.component_version,
+            "checksum": plan.file_checksums.get(&pa.file_path),
+        });
+        if let Ok(json) = serde_json::to_string_pretty(&provenance) {
+            // Best-effort provenance write -- don't fail the install if this fails
+            let _ = fs.write(&provenance_path, &json);
+        }
+    }
+}
+
+/// Simulate applying a plan against an in-memory overlay of the target project.
+///
+/// Unlike a real apply, every mutation is attempted so all failures are
+/// reported. Creating a file that already exists counts as a failure unless
+/// its contents match the plan or the manifest's record of the install
+/// (so updates can overwrite untouched files but not local edits).
+fn dry_run_plan(plan: &PlanContract, target_dir: &Path) -> Result<DryRunReport> {
+    let manifest = load_manifest(target_dir)?;
+    let recorded: BTreeMap<PathBuf, &String> = manifest
+        .installed()
+        .into_iter()
+        .flat_map(|entry| &entry.checksums)
+        .map(|(path, checksum)| (target_dir.join(path), checksum))
+        .collect();
+
+    let mut fs = MemoryFs::new();
+    let mut failures = Vec::new();
+    for (i, mutation) in plan.mutations.iter().enumerate() {
+        if mutation.action == FileAction::Create
+            && let Ok(existing) = fs.read_to_string(&mutation.file_path)
+            && existing != mutation.content
+            && recorded.get(&mutation.file_path) != Some(&&simple_checksum(&existing))
+        {
+            failures.push(DryRunFailure {
+                mutation_index: i,
+                file_path: mutation.file_path.clone(),
+                error: "unexpected existing content would be overwritten".to_string(),
+            });
+        }
+        if let Err(e) = apply_mutation(&mut fs, mutation) {
+            failures.push(DryRunFailure {
+                mutation_index: i,
+                file_path: mutation.file_path.clone(),
+                error: format!("{:#}", e),
+            });
+        }
+    }
+    write_provenance(&mut fs, plan);
+    record_in_manifest(&mut fs, plan, target_dir)?;
+
+    Ok(DryRunReport {
+        component_name: plan.component_name.clone(),
+        files: fs.changes(),
+        failures,
+    })
+}
+
+/// Record an applied plan in the target project's manifest.
+fn record_in_manifest(fs: &mut dyn Vfs, plan: &PlanContract, target_dir: &Path) -> Result<()> {
+    let mut manifest = read_manifest(fs, target_dir)?;
+    let layout = LayoutKind::from_name(&plan.target_layout)
+        .unwrap_or_default()
+        .adapter(target_dir);
+    manifest.record_plan(plan, layout.as_ref(), &registry::manifest::today());
+    write_manifest(fs, target_dir, &manifest)?;
+    Ok(())
+}
+
+/// Apply a single file mutation.
+fn apply_mutation(fs: &mut dyn Vfs, mutation: &FileMutation) -> Result<()> {
+    let path = &mutation.file_path;
+    match mutation.action {
+        FileAction::Create => {
+            // Ensure parent directory exists
+            if let Some(parent) = path.parent() {
+                fs.create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+            fs.write(path, &mutation.content)
+                .with_context(|| format!("Failed to write file: {}", path.display()))?;
+        }
+        FileAction::Modify => match mutation.strategy {
+            MutationStrategy::AppendExport => {
+                let existing = if fs.exists(path) {
+                    fs.read_to_string(path)
+                        .with_context(|| format!("Failed to read file: {}", path.display()))?
+                } else {
+                    // Create the file if it doesn't exist
+                    if let Some(parent) = path.parent() {
+                        fs.create_dir_all(parent)?;
+                    }
+                    String::new()
+                };
+
+                // Check if export already exists (idempotent)
+                if !existing.contains(&mutation.content) {
+                    let new_content = if existing.is_empty() {
+                        format!("{}\n", mutation.content)
+                    } else if existing.ends_with('\n') {
+                        format!("{}{}\n", existing, mutation.content)
+                    } else {
+                        format!("{}\n{}\n", existing, mutation.content)
+                    };
+                    fs.write(path, &new_content)
+                        .with_context(|| format!("Failed to modify file: {}", path.display()))?;
+                }
+            }
+            MutationStrategy::InsertUse => {
+                let existing = fs
+                    .read_to_string(path)
+                    .with_context(|| format!("Failed to read file: {}", path.display()))?;
+
+                if !existing.contains(&mutation.content) {
+                    let new_content = format!("{}\n{}", mutation.content, existing);
+                    fs.write(path, &new_content)?;
+                }
+            }
+            MutationStrategy::ReplaceSection if section_id(&mutation.content).is_some() => {
+                let existing = if fs.exists(path) {
+                    fs.read_to_string(path)
+                        .with_context(|| format!("Failed to read file: {}", path.display()))?
+                } else {
+                    String::new()
+                };
+                let updated = replace_section(&existing, &mutation.content)
+                    .with_context(|| format!("Cannot replace section in {}", path.display()))?;
+                if updated != existing {
+                    if let Some(parent) = path.parent() {
+                        fs.create_dir_all(parent)?;
+                    }
+                    fs.write(path, &updated)
+                        .with_context(|| format!("Failed to modify file: {}", path.display()))?;
+                }
+            }
+            MutationStrategy::ReplaceSection => {
+                // Remove lines matching the section (idempotent; missing file is a no-op)
+                if let Ok(existing) = fs.read_to_string(path) {
+                    let section = mutation.content.trim();
+                    let kept: Vec<&str> = existing
+                        .lines()
+                        .filter(|line| line.trim() != section)
+                        .collect();
+                    let mut new_content = kept.join("\n");
+                    if !new_content.is_empty() {
+                        new_content.push('\n');
+                    }
+                    fs.write(path, &new_content)
+                        .with_context(|| format!("Failed to modify file: {}", path.display()))?;
+                }
+            }
+            _ => {
+                // WriteFile, DeleteFile handled elsewhere
+                fs.write(path, &mutation.content)?;
+            }
+        },
+        FileAction::Delete => {
+            if fs.exists(path) {
+                fs.remove_file(path)
+                    .with_context(|| format!("Failed to delete file: {}", path.display()))?;
+            }
+            // Drop the component directory once its last file is gone
+            if let Some(parent) = path.parent() {
+                fs.remove_dir_if_empty(parent);
+            }
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Utilities
+// ---------------------------------------------------------------------------
+
+/// Scan for existing files that would conflict with a component installation.
+///
+/// Covers the component directory and the component's module file, which
+/// some layouts keep outside that directory.
+fn scan_existing_files(layout: &dyn TemplateAdapter, component_name: &str) -> Vec<PathBuf> {
+    let component_dir = layout.component_dir(component_name);
+
+    let mut existing = Vec::new();
+    if component_dir.exists()
+        && let Ok(entries) = std::fs::read_dir(&component_dir)
+    {
+        for entry in entries.flatten() {
+            existing.push(entry.path());
+        }
+    }
+    let module_file = layout.component_module_file(component_name);
+    if module_file.exists() && !existing.contains(&module_file) {
+        existing.push(module_file);
+    }
+    existing
+}
+
+// ---------------------------------------------------------------------------
+// Palette extraction (theme from-image)
+// ---------------------------------------------------------------------------
+
+/// Longest image side sampled for palette extraction.
+const PALETTE_SAMPLE_SIZE: u32 = 128;
+
+/// Upper bound on k-means refinement passes.
+const PALETTE_MAX_ITERATIONS: usize = 20;
+
+/// A dominant image color and how many sampled pixels it represents.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Swatch {
+    rgb: [u8; 3],
+    population: usize,
+}
+
+/// Decode a PNG/JPEG and downsample it to at most `PALETTE_SAMPLE_SIZE` per side.
+fn image_pixels(path: &Path) -> Result<Vec<[u8; 3]>> {
+    let image =
+        image::open(path).with_context(|| format!("Failed to read image {}", path.display()))?;
+    let sample = if image.width().max(image.height()) > PALETTE_SAMPLE_SIZE {
+        image.thumbnail(PALETTE_SAMPLE_SIZE, PALETTE_SAMPLE_SIZE)
+    } else {
+        image
+    };
+    Ok(sample.to_rgb8().pixels().map(|pixel| pixel.0).collect())
+}
+
+/// Cluster `pixels` into at most `k` dominant colors with k-means.
+///
+/// Centroids are seeded deterministically: the mean color first, then
+/// repeatedly the pixel farthest from every centroid so far. Swatches are
+/// returned most common first.
+fn extract_palette(pixels: &[[u8; 3]], k: usize) -> Vec<Swatch> {
+    let k = k.min(pixels.len());
+    if k == 0 {
+        return Vec::new();
+    }
+    let points: Vec<[f32; 3]> = pixels.iter().map(|p| p.map(f32::from)).collect();
+
+    let mut mean = [0.0f32; 3];
+    for point in &points {
+        for channel in 0..3 {
+            mean[channel] += point[channel] / points.len() as f32;
+        }
+    }
+    let mut centroids = vec![mean];
+    while centroids.len() < k {
+        let nearest_centroid = |point: [f32; 3]| {
+            centroids
+                .iter()
+                .map(|c| distance(point, *c))
+                .fold(f32::MAX, f32::min)
+        };
+        let Some(farthest) = points
+            .iter()
+            .copied()
+            .max_by(|a, b| nearest_centroid(*a).total_cmp(&nearest_centroid(*b)))
+            .filter(|point| nearest_centroid(*point) > 0.0)
+        else {
+            break;
+        };
+        centroids.push(farthest);
+    }
+    let k = centroids.len();
+
+    let mut assignment = vec![usize::MAX; points.len()];
+    for _ in 0..PALETTE_MAX_ITERATIONS {
+        let mut changed = false;
+        for (point, cluster) in points.iter().zip(assignment.iter_mut()) {
+            let nearest = (0..k)
+                .min_by(|&a, &b| {
+                    distance(*point, centroids[a]).total_cmp(&distance(*point, centroids[b]))
+                })
+                .unwrap_or(0);
+            if *cluster != nearest {
+                *cluster = nearest;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![[0.0f32; 3]; k];
+        let mut counts = vec![0usize; k];
+        for (point, &cluster) in points.iter().zip(&assignment) {
+            for channel in 0..3 {
+                sums[cluster][channel] += point[channel];
+            }
+            counts[cluster] += 1;
+        }
+        for ((centroid, sum), &count) in centroids.iter_mut().zip(&sums).zip(&counts) {
+            if count > 0 {
+                *centroid = sum.map(|total| total / count as f32);
+            }
+        }
+    }
+
+    let mut swatches: Vec<Swatch> = centroids
+        .iter()
+        .enumerate()
+        .map(|(i, centroid)| Swatch {
+            rgb: centroid.map(|channel| channel.round() as u8),
+            population: assignment.iter().filter(|&&cluster| cluster == i).count(),
+        })
+        .filter(|swatch| swatch.population > 0)
+        .collect();
+    swatches.sort_by_key(|swatch| std::cmp::Reverse(swatch.population));
+    swatches
+}
+
+/// Pick a theme seed from a palette.
+///
+/// The most common color becomes the background and decides dark vs light;
+/// the accent is the most vivid remaining color, weighted by coverage.
+fn theme_seed(name: &str, palette: &[Swatch]) -> Option<ThemeSeedFile> {
+    let (background, rest) = palette.split_first()?;
+    let accent = rest
+        .iter()
+        .max_by(|a, b| accent_score(a).total_cmp(&accent_score(b)))
+        .unwrap_or(background);
+    let (background_lightness, _) = lightness_saturation(background.rgb);
+    Some(ThemeSeedFile {
+        name: name.to_string(),
+        appearance: if background_lightness < 0.5 {
+            "dark"
+        } else {
+            "light"
+        }
+        .to_string(),
+        background: hex_color(background.rgb),
+        accent: hex_color(accent.rgb),
+        palette: palette.iter().map(|swatch| hex_color(swatch.rgb)).collect(),
+    })
+}
+
+/// Accent suitability: saturation weighted by coverage, discounting near-black and near-white.
+fn accent_score(swatch: &Swatch) -> f32 {
+    let (lightness, saturation) = lightness_saturation(swatch.rgb);
+    let usable = if (0.15..=0.85).contains(&lightness) {
+        1.0
+    } else {
+        0.1
+    };
+    saturation * usable * (swatch.population as f32).sqrt()
+}
+
+/// HSL lightness and saturation (both `0.0..=1.0`) of an sRGB color.
+fn lightness_saturation(rgb: [u8; 3]) -> (f32, f32) {
+    let [r, g, b] = rgb.map(|channel| f32::from(channel) / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let lightness = (max + min) / 2.0;
+    let chroma = max - min;
+    let saturation = if chroma <= 0.0 {
+        0.0
+    } else {
+        chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+    };
+    (lightness, saturation)
+}
+
+fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum()
+}
+
+/// `#rrggbbff`, the theme engine's color format.
+fn hex_color([r, g, b]: [u8; 3]) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}ff")
+}
+
+/// File-name slug for a theme name: lowercase alphanumeric words joined by `-`.
+fn theme_slug(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+// ---------------------------------------------------------------------------
+// Theme patches (theme apply-patch)
+// ---------------------------------------------------------------------------
+
+/// Apply `ops` in order to a theme's token JSON.
+///
+/// Every operation is checked before any is applied: the path must name an
+/// existing color token of the theme (dot-separated, e.g.
+/// `status.error.foreground`) and the value must be a hex color. Theme seeds
+/// have no token paths, so they must be exported as full themes first.
+fn apply_theme_patch(tokens: &mut serde_json::Value, ops: &[ThemePatchOp]) -> Result<()> {
+    for op in ops {
+        let ThemePatchOp::SetToken { path, value } = op;
+        if !is_hex_color(value) {
+            bail!("Invalid color '{}' for token '{}'", value, path);
+        }
+        match token_slot(tokens, path) {
+            Some(slot) if slot.is_string() || slot.is_null() => {}
+            _ => bail!("Unknown token path '{}'", path),
+        }
+    }
+    for op in ops {
+        let ThemePatchOp::SetToken { path, value } = op;
+        if let Some(slot) = token_slot(tokens, path) {
+            *slot = serde_json::Value::String(value.clone());
+        }
+    }
+    Ok(())
+}
+
+/// The JSON value at a dot-separated token path, if the theme has one.
+fn token_slot<'a>(
+    tokens: &'a mut serde_json::Value,
+    path: &str,
+) -> Option<&'a mut serde_json::Value> {
+    let (category, rest) = path.split_once('.')?;
+    rest.split('.')
+        .try_fold(tokens.get_mut(category)?, |value, key| value.get_mut(key))
+}
+
+/// Whether `value` is a `#rgb`, `#rgba`, `#rrggbb`, or `#rrggbbaa` color.
+fn is_hex_color(value: &str) -> bool {
+    value.strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 4 | 6 | 8) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    })
+}
+
+/// The built-in registry, or the index published at `registry_url`.
+///
+/// A remote registry is returned alongside its index so install commands can
+/// fetch component sources from it.
+fn load_registry(registry_url: Option<&str>) -> Result<(RegistryIndex, Option<RemoteRegistry>)> {
+    let Some(url) = registry_url else {
+        return Ok((registry::generate_registry(), None));
+    };
+    let remote = RemoteRegistry::new(url, registry_cache_dir(url));
+    let index = remote
+        .fetch_index()
+        .with_context(|| format!("Failed to load registry from {url}"))?;
+    Ok((index, Some(remote)))
+}
+
+/// What a command does when this CLI is older than a declared `min_cli_version`,
+/// or when a protected project would get an experimental component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VersionGate {
+    /// Log a warning and continue: the command only reads.
+    Warn,
+    /// Fail before touching the project: the command writes to it.
+    Refuse,
+}
+
+impl VersionGate {
+    /// Warn for `--plan` previews; refuse for the changes themselves.
+    fn for_plan_only(plan_only: bool) -> Self {
+        if plan_only { Self::Warn } else { Self::Refuse }
+    }
+}
+
+/// Check this CLI against the `min_cli_version` of `index` (the registry at
+/// `registry_url`, or the built-in one) and of the `gpui.toml` in
+/// `project_root`.
+fn cli_compatibility(
+    index: Option<&RegistryIndex>,
+    registry_url: Option<&str>,
+    project_root: Option<&Path>,
+) -> Result<CliCompatibility> {
+    let mut minimums = Vec::new();
+    if let Some(minimum) = index.and_then(RegistryIndex::min_cli_version) {
+        minimums.push((
+            RequirementSource::Registry(registry_url.map(str::to_string)),
+            minimum.to_string(),
+        ));
+    }
+    if let Some(root) = project_root
+        && let Some(minimum) = load_config(root)?.min_cli_version
+    {
+        let path = root.join(CONFIG_FILE).display().to_string();
+        minimums.push((RequirementSource::Project(path), minimum));
+    }
+    Ok(CliCompatibility::check(
+        env!("CARGO_PKG_VERSION"),
+        minimums,
+    )?)
+}
+
+/// Warn about, or refuse to go on with, a CLI older than the registry or
+/// project requires.
+fn ensure_cli_version(
+    index: Option<&RegistryIndex>,
+    registry_url: Option<&str>,
+    project_root: Option<&Path>,
+    gate: VersionGate,
+) -> Result<()> {
+    let compatibility = cli_compatibility(index, registry_url, project_root)?;
+    let Some(upgrade) = compatibility.upgrade_instructions() else {
+        return Ok(());
+    };
+    let unmet: Vec<String> = compatibility
+        .unmet()
+        .map(|r| format!("{} requires {} or later", r.source, r.min_cli_version))
+        .collect();
+    let message = format!(
+        "This is gpui {}, but {}. {}",
+        compatibility.cli_version,
+        unmet.join(" and "),
+        upgrade
+    );
+    match gate {
+        VersionGate::Warn => {
+            log::warn!("{}", message);
+            Ok(())
+        }
+        VersionGate::Refuse => bail!("{}", message),
+    }
+}
+
+/// Check the stability of `name` and the dependencies it would install.
+///
+/// Deprecated components are always warned about. Experimental components
+/// are refused (or, with [`VersionGate::Warn`], warned about) when the
+/// project's `gpui.toml` sets `protected = true`.
+fn ensure_stability(
+    index: &RegistryIndex,
+    name: &str,
+    project_root: &Path,
+    gate: VersionGate,
+) -> Result<()> {
+    let config = load_config(project_root)?;
+    let entries = index.resolve(name)?;
+    for entry in &entries {
+        if entry.stability == Stability::Deprecated {
+            log::warn!("{} is deprecated and may be removed", entry.name);
+        }
+    }
+    let refused: Vec<&str> = entries
+        .iter()
+        .filter(|entry| config.refuses(entry.stability))
+        .map(|entry| entry.name.as_str())
+        .collect();
+    if refused.is_empty() {
+        return Ok(());
+    }
+    let message = format!(
+        "Experimental components are not allowed in this project ({} sets `protected = true`): {}",
+        CONFIG_FILE,
+        refused.join(", ")
+    );
+    match gate {
+        VersionGate::Warn => {
+            log::warn!("{}", message);
+            Ok(())
+        }
+        VersionGate::Refuse => bail!("{}", message),
+    }
+}
+
+/// Look up the component named by `spec`, returning its registry name.
+///
+/// `spec` is a name, optionally followed by `@` and a version requirement
+/// (`dialog@0.2.0`, `dialog@^0.1`); the highest matching version is pinned
+/// as the component's current entry so the plan installs it.
+fn select_component(index: &mut RegistryIndex, spec: &str) -> Result<String> {
+    let (component, requirement) = match spec.split_once('@') {
+        Some((component, requirement)) => (component, Some(VersionReq::parse(requirement)?)),
+        None => (spec, None),
+    };
+    let Some(entry) = index.get(component) else {
+        bail!(
+            "Component '{}' not found in registry. Available: {}",
+            component,
+            index.names().join(", ")
+        );
+    };
+    let name = entry.name.clone();
+    if let Some(requirement) = requirement
+        && index.pin(&name, &requirement).is_none()
+    {
+        let available: Vec<&str> = index
+            .versions(&name)
+            .iter()
+            .map(|entry| entry.version.as_str())
+            .collect();
+        bail!(
+            "No version of '{}' matches '{}'. Available: {}",
+            name,
+            requirement,
+            available.join(", ")
+        );
+    }
+    Ok(name)
+}
+
+/// Per-registry download cache under the user cache directory.
+fn registry_cache_dir(url: &str) -> PathBuf {
+    let cache_root = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .unwrap_or_else(std::env::temp_dir);
+    cache_root
+        .join("gpui-workbench/registry")
+        .join(simple_checksum(url))
+}
+
+/// Give `plan`'s token sections defaults for tokens the target theme lacks.
+///
+/// A missing theme tokens file defines nothing, so every dependency gets one.
+fn define_missing_tokens(
+    plan: &mut PlanContract,
+    index: &RegistryIndex,
+    layout: &dyn TemplateAdapter,
+) {
+    let tokens_rs = std::fs::read_to_string(layout.theme_tokens_file()).unwrap_or_default();
+    plan.define_missing_tokens(index, &TokenSet::parse(&tokens_rs), layout);
+}
+
+/// Swap generated sources in `plan` for the archives published by `remote`.
+///
+/// Components whose index entry has no archive keep their generated sources.
+fn use_published_sources(
+    remote: &RemoteRegistry,
+    index: &RegistryIndex,
+    plan: &mut PlanContract,
+    layout: &dyn TemplateAdapter,
+    existing_files: &[PathBuf],
+) -> Result<()> {
+    let components: Vec<String> = plan
+        .dependencies
+        .iter()
+        .chain([&plan.component_name])
+        .cloned()
+        .collect();
+    for name in components {
+        let Some(entry) = index.get(&name).filter(|entry| entry.archive.is_some()) else {
+            continue;
+        };
+        let sources = remote
+            .fetch_sources(entry)
+            .with_context(|| format!("Failed to fetch {name} from {}", remote.base_url()))?;
+        plan.use_sources(layout, &name, &sources, existing_files);
+    }
+    Ok(())
+}
+
+/// Existing files in the directories of a component and its dependencies.
+fn scan_install_files(
+    index: &RegistryIndex,
+    component: &str,
+    layout: &dyn TemplateAdapter,
+) -> Result<Vec<PathBuf>> {
+    Ok(index
+        .resolve(component)?
+        .iter()
+        .flat_map(|entry| scan_existing_files(layout, &entry.name))
+        .collect())
+}
+
+/// Collect Rust sources under `dir` (recursively), skipping `exclude`.
+fn collect_sources(dir: &Path, exclude: &Path) -> Vec<(PathBuf, String)> {
+    let mut sources = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return sources;
+    };
+    let mut paths: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+    for path in paths {
+        if path == exclude {
+            continue;
+        }
+        if path.is_dir() {
+            sources.extend(collect_sources(&path, exclude));
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+            && let Ok(content) = std::fs::read_to_string(&path)
+        {
+            sources.push((path, content));
+        }
+    }
+    sources
+}
+
+/// Read a plan file written by `gpui plan`: a `CliOutput` envelope or a raw `PlanContract`.
+fn read_plan_file(plan_file: &Path) -> Result<PlanContract> {
+    let json = std::fs::read_to_string(plan_file)
+        .with_context(|| format!("Failed to read plan file: {}", plan_file.display()))?;
+    match serde_json::from_str::<CliOutput<PlanContract>>(&json) {
+        Ok(envelope) => Ok(envelope.data),
+        Err(_) => PlanContract::from_json(&json).with_context(|| {
+            format!(
+                "Failed to parse plan JSON in {}. Expected PlanContract or CliOutput<PlanContract>",
+                plan_file.display()
+            )
+        }),
+    }
+}
+
+/// Load `gpui.toml` from the target project, or an empty config if absent.
+fn load_config(target_dir: &Path) -> Result<ProjectConfig> {
+    let path = target_dir.join(CONFIG_FILE);
+    if !path.exists() {
+        return Ok(ProjectConfig::default());
+    }
+    let toml_str = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    ProjectConfig::from_toml(&toml_str).with_context(|| format!("Invalid {}", path.display()))
+}
+
+/// Read `gpui-acceptance.toml` from the workbench (empty if absent).
+fn load_ledger(target_dir: &Path) -> Result<AcceptanceLedger> {
+    let path = target_dir.join(LEDGER_FILE);
+    if !path.exists() {
+        return Ok(AcceptanceLedger::default());
+    }
+    let toml_str = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    AcceptanceLedger::from_toml(&toml_str).with_context(|| format!("Invalid {}", path.display()))
+}
+
+/// Write `gpui-acceptance.toml` to the workbench.
+fn save_ledger(target_dir: &Path, ledger: &AcceptanceLedger) -> Result<()> {
+    let path = target_dir.join(LEDGER_FILE);
+    let toml_str = ledger
+        .to_toml()
+        .with_context(|| format!("Failed to serialize {}", LEDGER_FILE))?;
+    std::fs::write(&path, toml_str).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Write `gpui.toml` to the target project, returning its path.
+fn save_config(target_dir: &Path, config: &ProjectConfig) -> Result<PathBuf> {
+    let path = target_dir.join(CONFIG_FILE);
+    let toml_str = config.to_toml().context("Failed to serialize gpui.toml")?;
+    std::fs::write(&path, toml_str)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// The layout for a target project: `--layout` if given, else the one the
+/// manifest records, else the default layout.
+fn project_layout(
+    layout: Option<LayoutKind>,
+    target_dir: &Path,
+) -> Result<Box<dyn TemplateAdapter>> {
+    let kind = match layout {
+        Some(kind) => kind,
+        None => load_manifest(target_dir)?.layout.unwrap_or_default(),
+    };
+    Ok(kind.adapter(target_dir))
+}
+
+/// Load `gpui-workbench.toml` from the target project, or an empty manifest if absent.
+fn load_manifest(target_dir: &Path) -> Result<Manifest> {
+    read_manifest(&RealFs, target_dir)
+}
+
+/// Read `gpui-workbench.toml` through `fs` (empty if absent).
+fn read_manifest(fs: &dyn Vfs, target_dir: &Path) -> Result<Manifest> {
+    let path = target_dir.join(MANIFEST_FILE);
+    if !fs.exists(&path) {
+        return Ok(Manifest::default());
+    }
+    let toml_str = fs
+        .read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Manifest::from_toml(&toml_str).with_context(|| format!("Invalid {}", path.display()))
+}
+
+/// Write `gpui-workbench.toml` to the target project through `fs`.
+fn write_manifest(fs: &mut dyn Vfs, target_dir: &Path, manifest: &Manifest) -> Result<()> {
+    let path = target_dir.join(MANIFEST_FILE);
+    let toml_str = manifest
+        .to_toml()
+        .with_context(|| format!("Failed to serialize {}", MANIFEST_FILE))?;
+    fs.write(&path, &toml_str)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Main
+// ---------------------------------------------------------------------------
+
+/// Log a timing report to stderr: the registry and total time, a warning if
+/// registry generation went over budget, and with `-v` the slowest contracts
+/// and each planned component.
+fn log_timings(report: &TimingReport) {
+    let ms = |us: u64| us as f64 / 1000.0;
+    if let Some(us) = report.registry_us {
+        log::info!(
+            elapsed_us = us,
+            contracts = report.contracts.len();
+            "Generated the registry in {:.1} ms", ms(us)
+        );
+        if !report.within_budget {
+            log::warn!(
+                "Registry generation exceeded its {} ms budget",
+                report.registry_budget_ms
+            );
+        }
+    }
+    for contract in report.slowest_contracts(5) {
+        log::debug!(
+            validation_us = contract.validation_us,
+            index_us = contract.index_us;
+            "Contract {} took {:.2} ms", contract.component, ms(contract.total_us())
+        );
+    }
+    for plan in &report.plans {
+        log::debug!(
+            elapsed_us = plan.generation_us;
+            "Planned {} in {:.2} ms", plan.component, ms(plan.generation_us)
+        );
+    }
+    log::info!(elapsed_us = report.total_us; "Finished in {:.1} ms", ms(report.total_us));
+}
+
+fn main() {
+    let cli = Cli::parse();
+    logging::init(
+        logging::level_filter(cli.verbose, cli.quiet),
+        cli.log_format,
+    );
+    // Errors go through the log so `--log-format json` stays parseable.
+    let result = run(cli);
+    // Commands without a JSON envelope report their timings in the log.
+    if let Some(report) = timing::finish() {
+        log_timings(&report);
+    }
+    if let Err(e) = result {
+        log::error!("{:#}", e);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let cwd = std::env::current_dir().context("Failed to get current directory")?;
+    if cli.timings {
+        timing::start();
+    }
+
+    match cli.command {
+        Commands::Add {
+            component,
+            plan,
+            registry,
+            interactive,
+            on_conflict,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            let policy = match on_conflict {
+                Some(resolution) => ConflictPolicy::Resolve(resolution),
+                None if interactive => ConflictPolicy::Prompt,
+                None => ConflictPolicy::Report,
+            };
+            if plan {
+                cmd_plan(&component, registry.as_deref(), layout.as_ref(), false)
+            } else {
+                cmd_add(&component, registry.as_deref(), policy, layout.as_ref())
+            }
+        }
+        Commands::Plan {
+            component,
+            tree,
+            registry,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_plan(&component, registry.as_deref(), layout.as_ref(), tree)
+        }
+        Commands::List {
+            registry,
+            installed,
+            primitives,
+            stability,
+            target_dir,
+        } => {
+            if installed {
+                let dir = target_dir.unwrap_or_else(|| cwd.clone());
+                cmd_list_installed(&dir)
+            } else if primitives {
+                cmd_list_primitives()
+            } else {
+                cmd_list(registry.as_deref(), &stability)
+            }
+        }
+        Commands::Search {
+            query,
+            fields,
+            registry,
+        } => cmd_search(&query, &fields, registry.as_deref()),
+        Commands::Apply {
+            plan_file,
+            dry_run,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            if dry_run {
+                cmd_apply_dry_run(&plan_file, &dir)
+            } else {
+                cmd_apply(&plan_file, &dir)
+            }
+        }
+        Commands::Update {
+            component,
+            plan,
+            force,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_update(&component, plan, force, layout.as_ref())
+        }
+        Commands::Remove {
+            component,
+            plan,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_remove(&component, plan, layout.as_ref())
+        }
+        Commands::Doctor { target_dir } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_doctor(layout.as_ref())
+        }
+        Commands::Verify { target_dir } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            cmd_verify(&dir)
+        }
+        Commands::Diff { plan_a, plan_b } => cmd_diff(&plan_a, &plan_b),
+        Commands::DiffInstall {
+            component,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_diff_install(&component, layout.as_ref())
+        }
+        Commands::Tokens {
+            command: TokensCommand::Scan { target_dir },
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_tokens_scan(layout.as_ref())
+        }
+        Commands::Theme {
+            command:
+                ThemeCommand::FromImage {
+                    path,
+                    name,
+                    colors,
+                    force,
+                    target_dir,
+                },
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            cmd_theme_from_image(&path, name.as_deref(), colors, force, &dir)
+        }
+        Commands::Theme {
+            command:
+                ThemeCommand::ApplyPatch {
+                    patch,
+                    theme,
+                    output,
+                },
+        } => cmd_theme_apply_patch(&patch, &theme, output.as_deref()),
+        Commands::Theme {
+            command: ThemeCommand::Lint { target_dir },
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            cmd_theme_lint(&dir)
+        }
+        Commands::Docs {
+            command:
+                DocsCommand::Site {
+                    out,
+                    snapshots,
+                    registry,
+                    locale,
+                },
+        } => {
+            let studio = if snapshots.is_none() || locale.is_none() {
+                load_config(Path::new("."))?.studio
+            } else {
+                StudioConfig::default()
+            };
+            // Without --snapshots, use the studio's snapshot directory if it
+            // exists; without --locale, the studio's locale.
+            let snapshots =
+                snapshots.or_else(|| studio.snapshot_dir.clone().filter(|dir| dir.is_dir()));
+            let locale = locale.unwrap_or_else(|| studio.locale().to_string());
+            cmd_docs_site(&out, snapshots.as_deref(), registry.as_deref(), &locale)
+        }
+        Commands::Contracts {
+            command: ContractsCommand::Export { dir },
+        } => cmd_contracts_export(&dir),
+        Commands::Contracts {
+            command: ContractsCommand::Import { dir },
+        } => cmd_contracts_import(&dir),
+        Commands::Snapshot {
+            captured,
+            baselines,
+            all_themes,
+            update,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let captured = match captured {
+                Some(captured) => captured,
+                None => load_config(&dir)?.studio.snapshot_dir.context(
+                    "No captured snapshots: pass --captured or set snapshot_dir in [studio]",
+                )?,
+            };
+            let baselines = baselines.unwrap_or_else(|| dir.join(BASELINES_DIR));
+            let mut themes: Vec<String> = builtin_themes().into_iter().map(|t| t.name).collect();
+            if all_themes {
+                for name in project_theme_names(&dir)? {
+                    if !themes.contains(&name) {
+                        themes.push(name);
+                    }
+                }
+            }
+            cmd_snapshot(&captured, &baselines, &themes, update)
+        }
+        Commands::Eject {
+            component,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            let layout = project_layout(cli.layout, &dir)?;
+            cmd_eject(&component, layout.as_ref())
+        }
+        Commands::Accept {
+            component,
+            items,
+            by,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            cmd_accept(&component, &items, &by, &dir)
+        }
+        Commands::Readiness {
+            component,
+            target_dir,
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            cmd_readiness(component.as_deref(), &dir)
+        }
+        Commands::Report { target_dir } => cmd_report(&target_dir.unwrap_or_else(|| cwd.clone())),
+        Commands::Schema { schema_type } => cmd_schema(schema_type),
+        Commands::Help { command, json } => cmd_help(&command, json),
+        Commands::Completions { shell } => cmd_completions(shell),
+        Commands::Components => cmd_components(),
+        Commands::SelfCmd {
+            command:
+                SelfCommand::Check {
+                    registry,
+                    target_dir,
+                },
+        } => {
+            let dir = target_dir.unwrap_or_else(|| cwd.clone());
+            cmd_self_check(registry.as_deref(), &dir)
+        }
+        Commands::Studio { args } => cmd_studio(&args),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use registry::diff::FileStatus;
+    use registry::plan::DefaultLayout;
+    use std::fs;
+
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    fn temp_dir() -> PathBuf {
+        let id = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir = std::env::temp_dir().join(format!("gpui-cli-test-{}-{}", std::process::id(), id));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn cleanup(dir: &Path) {
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    // -- Plan generation tests --
+
+    #[test]
+    fn plan_dialog_produces_valid_json() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        let output = CliOutput::success(plan);
+        let json = output.to_json().unwrap();
+
+        assert!(json.contains("\"success\": true"));
+        assert!(json.contains("\"Dialog\""));
+        assert!(json.contains("\"mutations\""));
+
+        // Verify parseable
+        let parsed: CliOutput<PlanContract> = serde_json::from_str(&json).unwrap();
+        assert!(parsed.success);
+        assert_eq!(parsed.data.component_name, "Dialog");
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn timings_are_attached_to_the_envelope() {
+        let dir = temp_dir();
+        timing::start();
+        let index = registry::generate_registry();
+        let plan = generate_plan(index.get("tabs").unwrap(), &DefaultLayout::new(&dir), &[]);
+        let json = CliOutput::success(plan).to_json().unwrap();
+        assert!(!timing::is_recording());
+
+        let parsed: CliOutput<PlanContract> = serde_json::from_str(&json).unwrap();
+        let timings = parsed.timings.unwrap();
+        assert_eq!(timings.contracts.len(), index.len());
+        assert_eq!(timings.plans[0].component, "Tabs");
+        assert!(timings.total_us >= timings.serialization_us);
+
+        // Without --timings the envelope is unchanged.
+        let json = CliOutput::success(0).to_json().unwrap();
+        assert!(!json.contains("timings"));
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn plan_select_produces_valid_json() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("select").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        let json = serde_json::to_string_pretty(&plan).unwrap();
+        assert!(json.contains("\"Select\""));
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn plan_tabs_produces_valid_json() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("tabs").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        let json = serde_json::to_string_pretty(&plan).unwrap();
+        assert!(json.contains("\"Tabs\""));
+
+        cleanup(&dir);
+    }
+
+    // -- Apply tests --
+
+    #[test]
+    fn apply_creates_component_files() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        let result = apply_plan(&plan, &dir);
+        assert!(result.is_ok(), "Apply should succeed: {:?}", result.err());
+
+        // Verify files were created
+        let component_dir = dir.join("src/shared/ui/dialog");
+        assert!(component_dir.exists(), "Component directory should exist");
+
+        let mod_file = component_dir.join("mod.rs");
+        assert!(mod_file.exists(), "mod.rs should exist");
+
+        let readme = fs::read_to_string(component_dir.join("README.md")).unwrap();
+        assert!(
+            readme.contains("## Props"),
+            "README should carry the prop table"
+        );
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn apply_installs_dependencies_first() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        let existing = scan_install_files(&index, "select", &layout).unwrap();
+        let plan = generate_install_plan(&index, "select", &layout, &existing).unwrap();
+        apply_plan(&plan, &dir).unwrap();
+
+        for name in ["tooltip", "button", "emptystate", "select"] {
+            assert!(dir.join("src/shared/ui").join(name).join("mod.rs").exists());
+        }
+
+        // Installed dependencies are skipped on the next plan.
+        let existing = scan_install_files(&index, "select", &layout).unwrap();
+        let replan = generate_install_plan(&index, "select", &layout, &existing).unwrap();
+        assert!(replan.dependencies.is_empty());
+        assert!(
+            replan
+                .conflicts
+                .iter()
+                .all(|c| c.file_path.starts_with(dir.join("src/shared/ui/select")))
+        );
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn apply_creates_parent_module() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        apply_plan(&plan, &dir).unwrap();
+
+        let parent_mod = dir.join("src/shared/ui/mod.rs");
+        assert!(parent_mod.exists(), "Parent mod.rs should exist");
+
+        let content = fs::read_to_string(&parent_mod).unwrap();
+        assert!(
+            content.contains("pub mod dialog"),
+            "Parent mod.rs should export dialog"
+        );
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn apply_is_idempotent() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        // Apply twice
+        apply_plan(&plan, &dir).unwrap();
+        apply_plan(&plan, &dir).unwrap();
+
+        // Verify the export line isn't duplicated
+        let parent_mod = dir.join("src/shared/ui/mod.rs");
+        let content = fs::read_to_string(&parent_mod).unwrap();
+        let count = content.matches("pub mod dialog").count();
+        assert_eq!(count, 1, "Export should appear exactly once (idempotent)");
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn apply_writes_provenance() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+
+        apply_plan(&plan, &dir).unwrap();
+
+        // Check that at least one provenance file exists
+        let component_dir = dir.join("src/shared/ui/dialog");
+        let provenance_files: Vec<_> = fs::read_dir(&component_dir)
+            .unwrap()
+            .flatten()
+            .filter(|e| e.path().to_string_lossy().contains("provenance.json"))
+            .collect();
+
+        assert!(
+            !provenance_files.is_empty(),
+            "Provenance metadata should be written for installed files"
+        );
+
+        // Verify provenance content
+        let prov_content = fs::read_to_string(provenance_files[0].path()).unwrap();
+        let prov: serde_json::Value = serde_json::from_str(&prov_content).unwrap();
+        assert!(prov.get("source").is_some());
+        assert!(prov.get("license").is_some());
+        assert!(prov.get("installed_by").is_some());
+
+        cleanup(&dir);
+    }
+
+    // -- All 3 POC components apply --
+
+    #[test]
+    fn apply_all_poc_components() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+
+        for name in &["dialog", "select", "tabs"] {
+            let entry = index.get(name).unwrap();
+            let layout = DefaultLayout::new(&dir);
+            let plan = generate_plan(entry, &layout, &[]);
+            apply_plan(&plan, &dir).unwrap();
+        }
+
+        // Verify all component directories exist
+        assert!(dir.join("src/shared/ui/dialog").exists());
+        assert!(dir.join("src/shared/ui/select").exists());
+        assert!(dir.join("src/shared/ui/tabs").exists());
+
+        // Verify parent mod.rs exports all three
+        let parent_mod = dir.join("src/shared/ui/mod.rs");
+        let content = fs::read_to_string(&parent_mod).unwrap();
+        assert!(content.contains("pub mod dialog"));
+        assert!(content.contains("pub mod select"));
+        assert!(content.contains("pub mod tabs"));
+
+        cleanup(&dir);
+    }
+
+    // -- Update tests --
+
+    #[test]
+    fn update_rewrites_stale_files_and_flags_local_edits() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("select").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let install = generate_plan(entry, &layout, &[]);
+        apply_plan(&install, &dir).unwrap();
+
+        let installed = read_installed(&install);
+        assert_eq!(installed.version, "0.1.0");
+        let plan = generate_update_plan(entry, &layout, &installed, &[]);
+        assert!(plan.mutations.is_empty(), "fresh install is up to date");
+
+        // Deleted scaffolding is restored without conflicts.
+        fs::remove_file(dir.join("src/shared/ui/select/mod.rs")).unwrap();
+        let plan = generate_update_plan(entry, &layout, &read_installed(&install), &[]);
+        assert_eq!(plan.mutations.len(), 1);
+        assert!(!plan.has_conflicts());
+        apply_plan(&plan, &dir).unwrap();
+        assert!(dir.join("src/shared/ui/select/mod.rs").exists());
+
+        // Edits to a provenance-tracked file are conflicts.
+        let source = &install.provenance_actions[0].file_path;
+        let mut content = fs::read_to_string(source).unwrap();
+        content.push_str("// local edit\n");
+        fs::write(source, content).unwrap();
+        let plan = generate_update_plan(entry, &layout, &read_installed(&install), &[]);
+        assert_eq!(plan.conflicts.len(), 1);
+        assert_eq!(&plan.conflicts[0].file_path, source);
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn collect_sources_skips_component_dir() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        let install = generate_plan(index.get("tabs").unwrap(), &layout, &[]);
+        apply_plan(&install, &dir).unwrap();
+        fs::write(dir.join("src/main.rs"), "fn main() {}\n").unwrap();
+
+        let sources = collect_sources(&dir.join("src"), &layout.component_dir("Tabs"));
+        let paths: Vec<_> = sources.iter().map(|(p, _)| p.clone()).collect();
+        assert!(paths.contains(&dir.join("src/main.rs")));
+        assert!(paths.contains(&dir.join("src/shared/ui/mod.rs")));
+        assert!(
+            !paths
+                .iter()
+                .any(|p| p.starts_with(dir.join("src/shared/ui/tabs")))
+        );
+
+        cleanup(&dir);
+    }
+
+    // -- Remove tests --
+
+    #[test]
+    fn remove_reverses_install() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        for name in ["dialog", "tabs"] {
+            let plan = generate_plan(index.get(name).unwrap(), &layout, &[]);
+            apply_plan(&plan, &dir).unwrap();
+        }
+
+        let entry = index.get("dialog").unwrap();
+        let existing = scan_existing_files(&layout, &entry.name);
+        let plan = generate_remove_plan(entry, &layout, &existing);
+        assert!(!plan.has_conflicts(), "{:?}", plan.conflicts);
+        apply_plan(&plan, &dir).unwrap();
+
+        assert!(!dir.join("src/shared/ui/dialog").exists());
+        let parent_mod = fs::read_to_string(dir.join("src/shared/ui/mod.rs")).unwrap();
+        assert!(!parent_mod.contains("pub mod dialog"));
+        assert!(parent_mod.contains("pub mod tabs"));
+        let tokens = fs::read_to_string(layout.theme_tokens_file()).unwrap();
+        assert!(!tokens.contains("gpui:begin:dialog-tokens"));
+        assert!(tokens.contains("pub const TABS_TOKENS"));
+        let manifest = load_manifest(&dir).unwrap();
+        assert!(!manifest.is_installed("Dialog"));
+        assert!(manifest.is_installed("Tabs"));
+
+        // Re-applying is a no-op.
+        apply_plan(&plan, &dir).unwrap();
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn token_section_is_kept_in_place_and_guarded() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        let tokens_file = layout.theme_tokens_file();
+        fs::create_dir_all(tokens_file.parent().unwrap()).unwrap();
+        fs::write(&tokens_file, "pub struct ThemeTokens;\n").unwrap();
+
+        let plan = generate_plan(index.get("tooltip").unwrap(), &layout, &[]);
+        apply_plan(&plan, &dir).unwrap();
+        let installed = fs::read_to_string(&tokens_file).unwrap();
+        assert!(installed.starts_with("pub struct ThemeTokens;\n\n// gpui:begin:tooltip-tokens\n"));
+        apply_plan(&plan, &dir).unwrap();
+        assert_eq!(fs::read_to_string(&tokens_file).unwrap(), installed);
+
+        // A broken marker is a conflict at plan time and an error at apply time.
+        fs::write(
+            &tokens_file,
+            installed.replace("// gpui:end:tooltip-tokens", ""),
+        )
+        .unwrap();
+        let mut replan = generate_plan(index.get("tooltip").unwrap(), &layout, &[]);
+        replan.detect_section_conflicts(|path| fs::read_to_string(path).ok());
+        assert_eq!(replan.conflicts.len(), 1);
+        assert_eq!(replan.conflicts[0].file_path, tokens_file);
+        let failure = apply_plan(&plan, &dir).unwrap_err();
+        assert!(
+            failure.1.contains("Cannot replace section"),
+            "{}",
+            failure.1
+        );
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn install_appends_defaults_for_undefined_tokens() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+
+        // No tokens file yet: every dependency gets a default.
+        let mut plan = generate_install_plan(&index, "tooltip", &layout, &[]).unwrap();
+        define_missing_tokens(&mut plan, &index, &layout);
+        apply_plan(&plan, &dir).unwrap();
+        let installed = fs::read_to_string(layout.theme_tokens_file()).unwrap();
+        assert!(installed.contains("impl TooltipTokenDefaults for ThemeTokens {"));
+        for dependency in &index.get("tooltip").unwrap().token_dependencies {
+            let method = dependency.path.replace('.', "_");
+            assert!(installed.contains(&format!("fn {method}(&self) -> gpui::Hsla {{")));
+        }
+        let source_file = layout.component_dir("Tooltip").join("tooltip.rs");
+        let source = fs::read_to_string(&source_file).unwrap();
+        assert!(source.contains("use crate::shared::theme::tokens::TooltipTokenDefaults as _;"));
+
+        // Once the theme defines them, reinstalling drops the defaults.
+        let theme = "pub struct SurfaceTokens {\n    pub elevated_surface: Hsla,\n}\n\
+                     pub struct BorderTokens {\n    pub default: Hsla,\n}\n\
+                     pub struct TextTokens {\n    pub default: Hsla,\n}\n\
+                     pub struct ThemeTokens {\n    pub surface: SurfaceTokens,\n    \
+                     pub border: BorderTokens,\n    pub text: TextTokens,\n}\n";
+        fs::write(layout.theme_tokens_file(), format!("{theme}\n{installed}")).unwrap();
+        let mut replan = generate_install_plan(&index, "tooltip", &layout, &[]).unwrap();
+        define_missing_tokens(&mut replan, &index, &layout);
+        apply_plan(&replan, &dir).unwrap();
+        let reinstalled = fs::read_to_string(layout.theme_tokens_file()).unwrap();
+        assert!(reinstalled.contains("pub const TOOLTIP_TOKENS: &[&str]"));
+        assert!(!reinstalled.contains("TooltipTokenDefaults"));
+        assert!(
+            !fs::read_to_string(&source_file)
+                .unwrap()
+                .contains("TooltipTokenDefaults")
+        );
+
+        cleanup(&dir);
+    }
+
+    // -- Manifest and doctor tests --
+
+    #[test]
+    fn doctor_reports_missing_modified_and_untracked_files() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_install_plan(&index, "select", &layout, &[]).unwrap();
+        apply_plan(&plan, &dir).unwrap();
+
+        let manifest = load_manifest(&dir).unwrap();
+        assert_eq!(manifest.installed().len(), 1 + plan.dependencies.len());
+        let select = manifest.get("select").unwrap();
+        assert_eq!(select.version, plan.component_version);
+        assert_eq!(select.installed_on, registry::manifest::today());
+
+        fs::remove_file(dir.join("src/shared/ui/select/mod.rs")).unwrap();
+        fs::write(
+            dir.join("src/shared/ui/tooltip/tooltip.rs"),
+            "// local edit\n",
+        )
+        .unwrap();
+        fs::create_dir_all(dir.join("src/shared/ui/tabs")).unwrap();
+        fs::write(dir.join("src/shared/ui/tabs/mod.rs"), "pub mod tabs;\n").unwrap();
+
+        let report = check_installed(&manifest, &ProjectConfig::default(), &index, &layout);
+        let health = |name: &str| report.components.iter().find(|c| c.name == name).unwrap();
+        assert_eq!(
+            health("Select").missing_files,
+            ["src/shared/ui/select/mod.rs"]
+        );
+        assert!(health("Select").modified_files.is_empty());
+        assert_eq!(health("Tooltip").modified_files.len(), 1);
+        assert!(health("Button").missing_files.is_empty());
+        assert!(
+            report
+                .components
+                .iter()
+                .all(|c| c.update_available.is_none())
+        );
+        assert_eq!(report.untracked, ["Tabs"]);
+
+        cleanup(&dir);
+    }
+
+    // -- Layout tests --
+
+    #[test]
+    fn flat_layout_is_remembered_and_reversible() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = project_layout(Some(LayoutKind::Flat), &dir).unwrap();
+        let plan = generate_install_plan(&index, "dialog", layout.as_ref(), &[]).unwrap();
+        apply_plan(&plan, &dir).unwrap();
+
+        assert!(dir.join("src/ui/dialog.rs").exists());
+        assert!(dir.join("src/ui/dialog/dialog.rs").exists());
+        let exports = fs::read_to_string(dir.join("src/ui.rs")).unwrap();
+        assert!(exports.contains("pub mod dialog;"));
+
+        // Later commands pick the layout up from the manifest.
+        let layout = project_layout(None, &dir).unwrap();
+        assert_eq!(layout.name(), "flat");
+
+        let entry = index.get("dialog").unwrap();
+        let existing = scan_existing_files(layout.as_ref(), &entry.name);
+        assert!(existing.contains(&dir.join("src/ui/dialog.rs")));
+        let remove = generate_remove_plan(entry, layout.as_ref(), &existing);
+        assert!(!remove.has_conflicts(), "{:?}", remove.conflicts);
+        apply_plan(&remove, &dir).unwrap();
+        assert!(!dir.join("src/ui/dialog.rs").exists());
+        assert!(!dir.join("src/ui/dialog").exists());
+
+        cleanup(&dir);
+    }
+
+    // -- Diff-install tests --
+
+    #[test]
+    fn diff_install_flags_local_edits_recorded_in_provenance() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+        apply_plan(&plan, &dir).unwrap();
+
+        let clean = install_diff(&plan);
+        assert_eq!(clean.recommendation, Recommendation::Skip);
+
+        let source = &plan.provenance_actions[0].file_path;
+        let mut content = fs::read_to_string(source).unwrap();
+        content.push_str("// tuned spacing\n");
+        fs::write(source, content).unwrap();
+        let provenance = serde_json::json!({
+            "source": plan.provenance_actions[0].source,
+            "license": plan.provenance_actions[0].license,
+            "modifications": "Tuned spacing",
+            "installed_by": "gpui-cli",
+        });
+        fs::write(provenance_path(source), provenance.to_string()).unwrap();
+
+        let diff = install_diff(&plan);
+        let file = diff.files.iter().find(|f| &f.file_path == source).unwrap();
+        assert_eq!(file.status, FileStatus::Modified);
+        assert!(file.diff.contains("-// tuned spacing"));
+        assert_eq!(file.local_modifications.as_deref(), Some("Tuned spacing"));
+        assert_eq!(diff.recommendation, Recommendation::Fork);
+
+        cleanup(&dir);
+    }
+
+    #[test]
+    fn read_provenance_skips_missing_and_invalid_files() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let entry = index.get("dialog").unwrap();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_plan(entry, &layout, &[]);
+        apply_plan(&plan, &dir).unwrap();
+
+        let provenance = read_provenance(&plan);
+        assert_eq!(provenance.len(), plan.provenance_actions.len());
+        assert!(
+            provenance
+                .iter()
+                .all(|(_, p)| p["version"] == entry.version)
+        );
+
+        let first = &plan.provenance_actions[0].file_path;
+        fs::write(provenance_path(first), "not json").unwrap();
+        let provenance = read_provenance(&plan);
+        assert_eq!(provenance.len(), plan.provenance_actions.len() - 1);
+        assert!(provenance.iter().all(|(pa, _)| &pa.file_path != first));
+
+        fs::remove_file(provenance_path(first)).unwrap();
+        assert_eq!(read_provenance(&plan).len(), provenance.len());
+        cleanup(&dir);
+    }
+
+    // -- Docs site tests --
+
+    #[test]
+    fn docs_site_writes_pages_and_copies_snapshots() {
+        let dir = temp_dir();
+        let snapshots = dir.join("captured");
+        fs::create_dir_all(&snapshots).unwrap();
+        fs::write(snapshots.join("dialog-open.png"), b"png").unwrap();
+        fs::write(snapshots.join("notes.txt"), "not a snapshot").unwrap();
+        let out = dir.join("site");
+
+        let index = registry::generate_registry();
+        let result = write_docs_site(&index, &out, Some(&snapshots), "en-US").unwrap();
+
+        assert_eq!(result.snapshots, ["dialog-open.png"]);
+        assert_eq!(result.files.len(), index.len() + 5);
+        assert!(out.join("index.html").exists() && out.join(".nojekyll").exists());
+        assert_eq!(
+            fs::read(out.join("snapshots/dialog-open.png")).unwrap(),
+            b"png"
+        );
+        assert!(!out.join("snapshots/notes.txt").exists());
+        let dialog = fs::read_to_string(out.join("components/dialog.html")).unwrap();
+        assert!(dialog.contains("../snapshots/dialog-open.png"));
+        assert!(dialog.contains("One Dark"));
+
+        cleanup(&dir);
     }
-}
 
-// ---------------------------------------------------------------------------
-// Tests
-// ---------------------------------------------------------------------------
+    // -- Contract interchange tests --
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
+    #[test]
+    fn contracts_export_then_import_reports_edits() {
+        let dir = temp_dir();
+        let contracts = dir.join("contracts");
 
-    use std::sync::atomic::{AtomicU64, Ordering};
+        let result = write_contracts(&contracts).unwrap();
+        assert_eq!(result.files.len(), registry::generate_registry().len());
+        let report = read_contracts(&contracts).unwrap();
+        assert!(
+            report
+                .contracts
+                .iter()
+                .all(|import| import.status == ImportStatus::Unchanged)
+        );
 
-    static TEST_COUNTER: AtomicU64 = AtomicU64::new(0);
+        let kbd = contracts.join("kbd.json");
+        let mut contract: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&kbd).unwrap()).unwrap();
+        contract["version"] = serde_json::Value::from("0.2.0");
+        fs::write(&kbd, contract.to_string()).unwrap();
+        fs::write(contracts.join("broken.json"), "{").unwrap();
 
-    fn temp_dir() -> PathBuf {
-        let id = TEST_COUNTER.fetch_add(1, Ordering::SeqCst);
-        let dir = std::env::temp_dir().join(format!("gpui-cli-test-{}-{}", std::process::id(), id));
-        let _ = fs::remove_dir_all(&dir);
-        fs::create_dir_all(&dir).unwrap();
-        dir
+        let report = read_contracts(&contracts).unwrap();
+        let status = |file: &str| {
+            report
+                .contracts
+                .iter()
+                .find(|import| import.file == file)
+                .map(|import| import.status)
+        };
+        assert_eq!(status("kbd.json"), Some(ImportStatus::Changed));
+        assert_eq!(status("broken.json"), Some(ImportStatus::Invalid));
+        assert_eq!(status("button.json"), Some(ImportStatus::Unchanged));
+
+        cleanup(&dir);
     }
 
-    fn cleanup(dir: &Path) {
-        let _ = fs::remove_dir_all(dir);
+    #[test]
+    fn snapshot_compares_each_theme_and_updates_baselines() {
+        let dir = temp_dir();
+        let captured = dir.join("captured");
+        let baselines = dir.join("baselines");
+        for (theme, kbd) in [("one-dark", "caps"), ("one-light", "caps")] {
+            fs::create_dir_all(captured.join(theme)).unwrap();
+            fs::write(captured.join(theme).join("kbd.png"), kbd).unwrap();
+            fs::write(
+                captured.join(theme).join(format!("button-{theme}.png")),
+                theme,
+            )
+            .unwrap();
+        }
+        let themes: Vec<String> = builtin_themes().into_iter().map(|t| t.name).collect();
+        let index = registry::generate_registry();
+
+        // First run: every capture is new, nothing fails.
+        let report = check_snapshots(&index, &captured, &baselines, &themes, false).unwrap();
+        assert!(report.passed());
+        assert_eq!(report.themes[0].added.len(), 2);
+        assert_eq!(report.theme_invariant, ["Kbd"]);
+
+        let report = check_snapshots(&index, &captured, &baselines, &themes, true).unwrap();
+        assert!(report.themes.iter().all(|diff| diff.added.is_empty()));
+        assert!(baselines.join("one-light/kbd.png").exists());
+
+        // A changed capture and an extra theme without captures fail.
+        fs::write(captured.join("one-dark/kbd.png"), "changed").unwrap();
+        let mut all = themes.clone();
+        all.push("Nord".to_string());
+        let report = check_snapshots(&index, &captured, &baselines, &all, false).unwrap();
+        assert_eq!(report.themes[0].changed, ["kbd.png"]);
+        assert_eq!(report.uncaptured, ["Nord"]);
+        assert!(!report.passed());
+
+        cleanup(&dir);
     }
 
-    // -- Plan generation tests --
+    // -- Eject tests --
 
     #[test]
-    fn plan_dialog_produces_valid_json() {
+    fn eject_records_local_ownership() {
         let dir = temp_dir();
         let index = registry::generate_registry();
-        let entry = index.get("dialog").unwrap();
+        let entry = index.get("tabs").unwrap();
         let layout = DefaultLayout::new(&dir);
         let plan = generate_plan(entry, &layout, &[]);
+        apply_plan(&plan, &dir).unwrap();
 
-        let output = CliOutput::success(plan);
-        let json = output.to_json().unwrap();
+        let result = eject(&plan, &dir).unwrap();
+        assert!(!result.already_ejected);
+        assert_eq!(result.provenance_files.len(), plan.provenance_actions.len());
+        assert!(load_config(&dir).unwrap().is_locally_owned("Tabs"));
 
-        assert!(json.contains("\"success\": true"));
-        assert!(json.contains("\"Dialog\""));
-        assert!(json.contains("\"mutations\""));
+        let prov: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&result.provenance_files[0]).unwrap())
+                .unwrap();
+        assert_eq!(prov["ownership"], "local");
 
-        // Verify parseable
-        let parsed: CliOutput<PlanContract> = serde_json::from_str(&json).unwrap();
-        assert!(parsed.success);
-        assert_eq!(parsed.data.component_name, "Dialog");
+        // Ejected files read as locally modified against the registry.
+        let diff = install_diff(&plan);
+        assert!(diff.files.iter().any(|f| f.local_modifications.is_some()));
+
+        assert!(eject(&plan, &dir).unwrap().already_ejected);
 
         cleanup(&dir);
     }
 
+    // -- CLI version tests --
+
     #[test]
-    fn plan_select_produces_valid_json() {
+    fn older_cli_is_refused_by_project_min_version() {
         let dir = temp_dir();
-        let index = registry::generate_registry();
-        let entry = index.get("select").unwrap();
-        let layout = DefaultLayout::new(&dir);
-        let plan = generate_plan(entry, &layout, &[]);
+        let mut index = registry::generate_registry();
+        assert!(ensure_cli_version(Some(&index), None, Some(&dir), VersionGate::Refuse).is_ok());
 
-        let json = serde_json::to_string_pretty(&plan).unwrap();
-        assert!(json.contains("\"Select\""));
+        fs::write(dir.join(CONFIG_FILE), "min_cli_version = \"99.0.0\"\n").unwrap();
+        let error = ensure_cli_version(Some(&index), None, Some(&dir), VersionGate::Refuse)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("requires 99.0.0 or later"), "{error}");
+        assert!(error.contains("cargo install"), "{error}");
+        assert!(ensure_cli_version(Some(&index), None, Some(&dir), VersionGate::Warn).is_ok());
+
+        index.set_min_cli_version(Some("98.0".into()));
+        let compatibility =
+            cli_compatibility(Some(&index), Some("https://example.com/r"), Some(&dir)).unwrap();
+        assert_eq!(compatibility.unmet().count(), 2);
+        assert_eq!(
+            compatibility.requirements[0].source.to_string(),
+            "the registry at https://example.com/r"
+        );
+
+        fs::write(dir.join(CONFIG_FILE), "min_cli_version = \"soon\"\n").unwrap();
+        assert!(cli_compatibility(None, None, Some(&dir)).is_err());
 
         cleanup(&dir);
     }
 
     #[test]
-    fn plan_tabs_produces_valid_json() {
+    fn protected_project_refuses_experimental_components_and_dependencies() {
         let dir = temp_dir();
-        let index = registry::generate_registry();
-        let entry = index.get("tabs").unwrap();
-        let layout = DefaultLayout::new(&dir);
-        let plan = generate_plan(entry, &layout, &[]);
+        let mut index = registry::generate_registry();
+        let mut board = index.get("Dialog").unwrap().clone();
+        board.name = "Board".to_string();
+        board.dependencies = vec!["SortableList".to_string()];
+        index.insert(board);
+        assert!(ensure_stability(&index, "SortableList", &dir, VersionGate::Refuse).is_ok());
 
-        let json = serde_json::to_string_pretty(&plan).unwrap();
-        assert!(json.contains("\"Tabs\""));
+        fs::write(dir.join(CONFIG_FILE), "protected = true\n").unwrap();
+        assert!(ensure_stability(&index, "Dialog", &dir, VersionGate::Refuse).is_ok());
+        let error = ensure_stability(&index, "Board", &dir, VersionGate::Refuse)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.ends_with("`protected = true`): SortableList"),
+            "{error}"
+        );
+        assert!(ensure_stability(&index, "Board", &dir, VersionGate::Warn).is_ok());
 
         cleanup(&dir);
     }
 
-    // -- Apply tests --
+    // -- Acceptance tests --
 
     #[test]
-    fn apply_creates_component_files() {
+    fn accept_persists_sign_offs_in_ledger() {
         let dir = temp_dir();
         let index = registry::generate_registry();
-        let entry = index.get("dialog").unwrap();
-        let layout = DefaultLayout::new(&dir);
-        let plan = generate_plan(entry, &layout, &[]);
+        let items = vec!["has_story_coverage".to_string()];
 
-        let result = apply_plan(&plan, &dir);
-        assert!(result.is_ok(), "Apply should succeed: {:?}", result.err());
+        cmd_accept("tabs", &items, "ana", &dir).unwrap();
+        let ledger = load_ledger(&dir).unwrap();
+        let sign_off = ledger.sign_off("Tabs", "has_story_coverage").unwrap();
+        assert_eq!(sign_off.by, "ana");
+        assert_eq!(sign_off.on, registry::manifest::today());
+        assert_eq!(sign_off.commit, git_head(&dir));
 
-        // Verify files were created
-        let component_dir = dir.join("src/shared/ui/dialog");
-        assert!(component_dir.exists(), "Component directory should exist");
+        let report = readiness(index.get("tabs").unwrap(), &ledger);
+        let story = report
+            .items
+            .iter()
+            .find(|item| item.item == "has_story_coverage")
+            .unwrap();
+        assert_eq!(story.status, ItemStatus::SignedOff);
 
-        let mod_file = component_dir.join("mod.rs");
-        assert!(mod_file.exists(), "mod.rs should exist");
+        let unknown = vec!["is_pretty".to_string()];
+        assert!(cmd_accept("tabs", &unknown, "ana", &dir).is_err());
+        assert_eq!(load_ledger(&dir).unwrap(), ledger);
 
         cleanup(&dir);
     }
 
+    // -- Conflict resolution tests --
+
     #[test]
-    fn apply_creates_parent_module() {
+    fn interactive_backup_keeps_existing_file_and_applies() {
         let dir = temp_dir();
         let index = registry::generate_registry();
-        let entry = index.get("dialog").unwrap();
         let layout = DefaultLayout::new(&dir);
-        let plan = generate_plan(entry, &layout, &[]);
+        let module_file = layout.component_module_file("Tooltip");
+        fs::create_dir_all(module_file.parent().unwrap()).unwrap();
+        fs::write(&module_file, "// hand-written\n").unwrap();
+        let mut plan = generate_install_plan(
+            &index,
+            "tooltip",
+            &layout,
+            std::slice::from_ref(&module_file),
+        )
+        .unwrap();
+
+        let mut input = std::io::Cursor::new("merge\nb\n");
+        let mut prompt = Vec::new();
+        resolve_conflicts(&mut plan, ConflictPolicy::Prompt, &mut input, &mut prompt).unwrap();
+        let prompt = String::from_utf8(prompt).unwrap();
+        assert!(prompt.contains("Unrecognized choice 'merge'"));
+        assert!(!plan.has_conflicts());
+        assert_eq!(plan.resolutions[0].resolution, ConflictResolution::Backup);
 
         apply_plan(&plan, &dir).unwrap();
+        let backup = module_file.with_file_name("mod.rs.bak");
+        assert_eq!(fs::read_to_string(backup).unwrap(), "// hand-written\n");
+        assert_ne!(
+            fs::read_to_string(&module_file).unwrap(),
+            "// hand-written\n"
+        );
 
-        let parent_mod = dir.join("src/shared/ui/mod.rs");
-        assert!(parent_mod.exists(), "Parent mod.rs should exist");
+        cleanup(&dir);
+    }
 
-        let content = fs::read_to_string(&parent_mod).unwrap();
+    #[test]
+    fn on_conflict_policy_resolves_without_prompting() {
+        let dir = temp_dir();
+        let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        let module_file = layout.component_module_file("Tooltip");
+        let mut plan = generate_install_plan(
+            &index,
+            "tooltip",
+            &layout,
+            std::slice::from_ref(&module_file),
+        )
+        .unwrap();
+
+        let mut prompt = Vec::new();
+        resolve_conflicts(
+            &mut plan,
+            ConflictPolicy::Resolve(ConflictResolution::Skip),
+            &mut std::io::empty(),
+            &mut prompt,
+        )
+        .unwrap();
+        assert!(!plan.has_conflicts());
+        assert!(!plan.mutations.iter().any(|m| m.file_path == module_file));
+
+        let mut unresolved = generate_install_plan(
+            &index,
+            "tooltip",
+            &layout,
+            std::slice::from_ref(&module_file),
+        )
+        .unwrap();
+        let closed = resolve_conflicts(
+            &mut unresolved,
+            ConflictPolicy::Prompt,
+            &mut std::io::empty(),
+            &mut Vec::new(),
+        );
         assert!(
-            content.contains("pub mod dialog"),
-            "Parent mod.rs should export dialog"
+            closed.is_err(),
+            "closed input leaves the conflict unresolved"
         );
 
         cleanup(&dir);
     }
 
+    // -- Dry run tests --
+
     #[test]
-    fn apply_is_idempotent() {
+    fn dry_run_reports_changes_without_touching_disk() {
         let dir = temp_dir();
         let index = registry::generate_registry();
-        let entry = index.get("dialog").unwrap();
         let layout = DefaultLayout::new(&dir);
-        let plan = generate_plan(entry, &layout, &[]);
+        let plan = generate_install_plan(&index, "tooltip", &layout, &[]).unwrap();
 
-        // Apply twice
-        apply_plan(&plan, &dir).unwrap();
-        apply_plan(&plan, &dir).unwrap();
+        let report = dry_run_plan(&plan, &dir).unwrap();
+        assert!(report.failures.is_empty(), "{:?}", report.failures);
+        let module_file = layout.component_module_file("Tooltip");
+        assert!(
+            report
+                .files
+                .iter()
+                .any(|f| f.file_path == module_file && f.change == FileChange::Create)
+        );
+        assert!(
+            report
+                .files
+                .iter()
+                .any(|f| f.file_path == dir.join(MANIFEST_FILE))
+        );
+        assert!(!module_file.exists());
+        assert!(!dir.join(MANIFEST_FILE).exists());
 
-        // Verify the export line isn't duplicated
-        let parent_mod = dir.join("src/shared/ui/mod.rs");
-        let content = fs::read_to_string(&parent_mod).unwrap();
-        let count = content.matches("pub mod dialog").count();
-        assert_eq!(count, 1, "Export should appear exactly once (idempotent)");
+        // After a real apply, replanning the same install changes nothing but the manifest date.
+        apply_plan(&plan, &dir).unwrap();
+        let report = dry_run_plan(&plan, &dir).unwrap();
+        assert!(report.failures.is_empty(), "{:?}", report.failures);
+        assert!(
+            report
+                .files
+                .iter()
+                .all(|f| f.file_path == dir.join(MANIFEST_FILE))
+        );
 
         cleanup(&dir);
     }
 
     #[test]
-    fn apply_writes_provenance() {
+    fn dry_run_flags_unexpected_content_and_missing_files() {
         let dir = temp_dir();
         let index = registry::generate_registry();
-        let entry = index.get("dialog").unwrap();
         let layout = DefaultLayout::new(&dir);
-        let plan = generate_plan(entry, &layout, &[]);
-
-        apply_plan(&plan, &dir).unwrap();
+        let mut plan = generate_install_plan(&index, "tooltip", &layout, &[]).unwrap();
+        let module_file = layout.component_module_file("Tooltip");
+        fs::create_dir_all(module_file.parent().unwrap()).unwrap();
+        fs::write(&module_file, "// hand-written\n").unwrap();
+        plan.mutations.push(FileMutation {
+            action: FileAction::Modify,
+            file_path: dir.join("src/missing.rs"),
+            strategy: MutationStrategy::InsertUse,
+            content: "use crate::shared::ui::tooltip;".to_string(),
+            description: "Import tooltip".to_string(),
+            rationale: String::new(),
+        });
 
-        // Check that at least one provenance file exists
-        let component_dir = dir.join("src/shared/ui/dialog");
-        let provenance_files: Vec<_> = fs::read_dir(&component_dir)
-            .unwrap()
-            .flatten()
-            .filter(|e| e.path().to_string_lossy().contains("provenance.json"))
+        let report = dry_run_plan(&plan, &dir).unwrap();
+        let failed: Vec<&Path> = report
+            .failures
+            .iter()
+            .map(|f| f.file_path.as_path())
             .collect();
-
+        assert_eq!(failed, [module_file.as_path(), &dir.join("src/missing.rs")]);
         assert!(
-            !provenance_files.is_empty(),
-            "Provenance metadata should be written for installed files"
+            report.failures[0]
+                .error
+                .contains("unexpected existing content")
+        );
+        assert_eq!(
+            fs::read_to_string(&module_file).unwrap(),
+            "// hand-written\n"
         );
-
-        // Verify provenance content
-        let prov_content = fs::read_to_string(provenance_files[0].path()).unwrap();
-        let prov: serde_json::Value = serde_json::from_str(&prov_content).unwrap();
-        assert!(prov.get("source").is_some());
-        assert!(prov.get("license").is_some());
-        assert!(prov.get("installed_by").is_some());
 
         cleanup(&dir);
     }
 
-    // -- All 3 POC components apply --
+    // -- Plan diff tests --
 
     #[test]
-    fn apply_all_poc_components() {
+    fn read_plan_file_accepts_envelope_and_raw_plans() {
         let dir = temp_dir();
         let index = registry::generate_registry();
+        let layout = DefaultLayout::new(&dir);
+        let plan = generate_install_plan(&index, "tooltip", &layout, &[]).unwrap();
 
-        for name in &["dialog", "select", "tabs"] {
-            let entry = index.get(name).unwrap();
-            let layout = DefaultLayout::new(&dir);
-            let plan = generate_plan(entry, &layout, &[]);
-            apply_plan(&plan, &dir).unwrap();
-        }
+        let enveloped = dir.join("plan-a.json");
+        fs::write(&enveloped, CliOutput::success(&plan).to_json().unwrap()).unwrap();
+        let raw = dir.join("plan-b.json");
+        fs::write(&raw, plan.to_json().unwrap()).unwrap();
 
-        // Verify all component directories exist
-        assert!(dir.join("src/shared/ui/dialog").exists());
-        assert!(dir.join("src/shared/ui/select").exists());
-        assert!(dir.join("src/shared/ui/tabs").exists());
+        let diff = PlanDiff::between(
+            &read_plan_file(&enveloped).unwrap(),
+            &read_plan_file(&raw).unwrap(),
+        );
+        assert!(diff.is_empty());
 
-        // Verify parent mod.rs exports all three
-        let parent_mod = dir.join("src/shared/ui/mod.rs");
-        let content = fs::read_to_string(&parent_mod).unwrap();
-        assert!(content.contains("pub mod dialog"));
-        assert!(content.contains("pub mod select"));
-        assert!(content.contains("pub mod tabs"));
+        fs::write(&raw, "{}").unwrap();
+        assert!(read_plan_file(&raw).is_err());
+
+        cleanup(&dir);
+    }
+
+    // -- Theme from image tests --
+
+    #[test]
+    fn theme_from_image_seeds_background_and_accent() {
+        let dir = temp_dir();
+        let navy = [20, 30, 60];
+        let orange = [240, 120, 30];
+        let white = [250, 250, 250];
+        let image = image::RgbImage::from_fn(10, 10, |x, y| {
+            image::Rgb(match y * 10 + x {
+                0..70 => navy,
+                70..90 => orange,
+                _ => white,
+            })
+        });
+        let path = dir.join("brand.png");
+        image.save(&path).unwrap();
+
+        let pixels = image_pixels(&path).unwrap();
+        let palette = extract_palette(&pixels, 3);
+        let populations: Vec<usize> = palette.iter().map(|s| s.population).collect();
+        assert_eq!(populations, [70, 20, 10]);
+        assert_eq!(palette[0].rgb, navy);
+
+        let seed = theme_seed("Brand Kit", &palette).unwrap();
+        assert_eq!(seed.appearance, "dark");
+        assert_eq!(seed.background, "#141e3cff");
+        assert_eq!(seed.accent, "#f0781eff");
+
+        let result = write_theme_seed(seed, false, &dir).unwrap();
+        assert_eq!(result.theme_file, dir.join("themes/brand-kit.json"));
+        let written: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&result.theme_file).unwrap()).unwrap();
+        assert_eq!(written["palette"].as_array().unwrap().len(), 3);
+
+        let again = theme_seed("Brand Kit", &palette).unwrap();
+        assert!(write_theme_seed(again, false, &dir).is_err());
+        let again = theme_seed("Brand Kit", &palette).unwrap();
+        assert!(write_theme_seed(again, true, &dir).is_ok());
 
         cleanup(&dir);
     }
 
+    // -- Theme patch tests --
+
+    #[test]
+    fn apply_theme_patch_sets_existing_tokens_in_order() {
+        let mut tokens = serde_json::json!({
+            "name": "Brand",
+            "border": { "focused": "#000000ff" },
+            "status": { "error": { "foreground": "#000000ff" } },
+            "panel": { "background": "#000000ff", "focused_border": null },
+            "syntax": { "type": "#000000ff" },
+        });
+        let ops: Vec<ThemePatchOp> = serde_json::from_str(
+            r##"[
+                { "op": "set_token", "path": "border.focused", "value": "#ff0000ff" },
+                { "op": "set_token", "path": "border.focused", "value": "#00ff00ff" },
+                { "op": "set_token", "path": "status.error.foreground", "value": "#abc" },
+                { "op": "set_token", "path": "panel.focused_border", "value": "#0000ffff" },
+                { "op": "set_token", "path": "syntax.type", "value": "#e5c07bff" }
+            ]"##,
+        )
+        .unwrap();
+
+        apply_theme_patch(&mut tokens, &ops).unwrap();
+        assert_eq!(tokens["border"]["focused"], "#00ff00ff");
+        assert_eq!(tokens["status"]["error"]["foreground"], "#abc");
+        assert_eq!(tokens["panel"]["focused_border"], "#0000ffff");
+        assert_eq!(tokens["syntax"]["type"], "#e5c07bff");
+    }
+
+    #[test]
+    fn apply_theme_patch_rejects_bad_ops_without_partial_edits() {
+        let original = serde_json::json!({
+            "name": "Brand",
+            "text": { "accent": "#000000ff" },
+        });
+        let set = |path: &str, value: &str| ThemePatchOp::SetToken {
+            path: path.to_string(),
+            value: value.to_string(),
+        };
+
+        for bad in [
+            set("text.nope", "#ff0000ff"),
+            set("name", "#ff0000ff"),
+            set("text", "#ff0000ff"),
+            set("text.accent", "red"),
+            set("text.accent", "#ff000"),
+        ] {
+            let mut tokens = original.clone();
+            let ops = [set("text.accent", "#ff0000ff"), bad.clone()];
+            assert!(apply_theme_patch(&mut tokens, &ops).is_err(), "{bad:?}");
+            assert_eq!(tokens, original);
+        }
+    }
+
+    #[test]
+    fn extract_palette_handles_uniform_and_empty_images() {
+        let palette = extract_palette(&[[200, 200, 200]; 16], 6);
+        assert_eq!(
+            palette,
+            [Swatch {
+                rgb: [200, 200, 200],
+                population: 16
+            }]
+        );
+        assert_eq!(theme_seed("Grey", &palette).unwrap().appearance, "light");
+
+        assert!(extract_palette(&[], 6).is_empty());
+        assert!(theme_seed("Empty", &[]).is_none());
+    }
+
     // -- Plan JSON round-trip via file --
 
     #[test]
@@ -683,6 +4666,137 @@ mod tests {
         assert!(json.contains("\"Component not found\""));
     }
 
+    // -- Version selection tests --
+
+    #[test]
+    fn select_component_pins_requested_version() {
+        let mut index = registry::generate_registry();
+        let mut old = index.get("Dialog").unwrap().clone();
+        old.version = "0.0.9".to_string();
+        index.insert(old);
+        let current = index.get("Dialog").unwrap().version.clone();
+
+        assert_eq!(select_component(&mut index, "dialog").unwrap(), "Dialog");
+        assert_eq!(index.get("Dialog").unwrap().version, current);
+
+        assert_eq!(
+            select_component(&mut index, "dialog@0.0.9").unwrap(),
+            "Dialog"
+        );
+        let plan =
+            generate_install_plan(&index, "Dialog", &DefaultLayout::new("/app"), &[]).unwrap();
+        assert_eq!(plan.component_version, "0.0.9");
+
+        let err = select_component(&mut index, "dialog@^5").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("No version of 'Dialog' matches '^5'. Available: 0.0.9, {current}")
+        );
+        assert!(select_component(&mut index, "dialog@latest").is_err());
+        assert!(select_component(&mut index, "ghost@0.1.0").is_err());
+    }
+
+    // -- Schema tests --
+
+    #[test]
+    fn plan_output_validates_against_published_schemas() {
+        use registry::schema::validation_errors;
+
+        let index = registry::generate_registry();
+        let plan =
+            generate_install_plan(&index, "Dialog", &DefaultLayout::new("/app"), &[]).unwrap();
+        let output: serde_json::Value =
+            serde_json::from_str(&CliOutput::success(&plan).to_json().unwrap()).unwrap();
+
+        let envelope = "cli-output".parse::<SchemaTarget>().unwrap().schema();
+        assert_eq!(validation_errors(&envelope, &output), Vec::<String>::new());
+        let plan_schema = "plan-contract".parse::<SchemaTarget>().unwrap().schema();
+        assert_eq!(
+            validation_errors(&plan_schema, &output["data"]),
+            Vec::<String>::new()
+        );
+
+        let failure =
+            serde_json::json!({ "success": false, "data": null, "errors": [{ "code": 1 }] });
+        assert!(!validation_errors(&envelope, &failure).is_empty());
+        assert!(
+            "plan"
+                .parse::<SchemaTarget>()
+                .unwrap_err()
+                .contains("cli-output")
+        );
+    }
+
+    // -- Completion and help tests --
+
+    fn cli_help() -> completions::CommandHelp {
+        let mut cmd = Cli::command();
+        cmd.build();
+        completions::command_help(&cmd)
+    }
+
+    #[test]
+    fn help_json_describes_every_command() {
+        use completions::ValueKind;
+
+        let help = cli_help();
+        let names: Vec<&str> = help
+            .subcommands
+            .iter()
+            .map(|sub| sub.name.as_str())
+            .collect();
+        for name in ["add", "plan", "docs", "schema", "help", "completions"] {
+            assert!(names.contains(&name), "missing {name}: {names:?}");
+        }
+        assert!(!names.contains(&COMPONENTS_COMMAND));
+
+        let add = help
+            .subcommands
+            .iter()
+            .find(|sub| sub.name == "add")
+            .unwrap();
+        assert_eq!(add.command, "gpui add");
+        let arg = |name: &str| add.args.iter().find(|arg| arg.name == name).unwrap();
+        let component = arg("component");
+        assert!(component.positional && component.required);
+        assert_eq!(component.value.as_ref().unwrap().kind, ValueKind::Component);
+        let on_conflict = arg("on_conflict").value.clone().unwrap();
+        assert_eq!(on_conflict.kind, ValueKind::Choice);
+        assert_eq!(on_conflict.choices.len(), ConflictResolution::ALL.len());
+        assert_eq!(
+            arg("target_dir").value.as_ref().unwrap().kind,
+            ValueKind::Path
+        );
+        assert!(arg("layout").global);
+        assert!(arg("plan").value.is_none());
+
+        let site = help
+            .subcommands
+            .iter()
+            .find(|sub| sub.name == "docs")
+            .and_then(|docs| docs.subcommands.iter().find(|sub| sub.name == "site"))
+            .unwrap();
+        assert_eq!(site.command, "gpui docs site");
+
+        let json = CliOutput::success(&help).to_json().unwrap();
+        let parsed: CliOutput<completions::CommandHelp> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.data, help);
+    }
+
+    #[test]
+    fn completion_scripts_cover_commands_and_components() {
+        let help = cli_help();
+        for shell in Shell::ALL {
+            let script = completion_script(shell, &help);
+            assert!(script.contains("completions"), "{}", shell.name());
+            assert!(script.contains("gpui__docs__site"), "{}", shell.name());
+            assert!(script.contains("gpui __components"), "{}", shell.name());
+            assert!(script.contains("overwrite"), "{}", shell.name());
+            assert_eq!(shell.name().parse::<Shell>(), Ok(shell));
+        }
+        assert!("tcsh".parse::<Shell>().unwrap_err().contains("powershell"));
+    }
+
     // -- Error handling tests --
 
     #[test]
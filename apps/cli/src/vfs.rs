@@ -0,0 +1,335 @@
+//! Filesystem abstraction for applying plans.
+//!
+//! `apply` writes through [`Vfs`]: [`RealFs`] touches disk, while [`MemoryFs`]
+//! layers writes over a read-only view of disk so `apply --dry-run` can run
+//! the exact same mutations and report what would change.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// The filesystem operations plan mutations need.
+pub trait Vfs {
+    /// Whether `path` exists (file or directory).
+    fn exists(&self, path: &Path) -> bool;
+    /// Read a file as UTF-8.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+    /// Create or truncate a file. The parent directory must exist.
+    fn write(&mut self, path: &Path, contents: &str) -> io::Result<()>;
+    /// Create a directory and any missing ancestors.
+    fn create_dir_all(&mut self, path: &Path) -> io::Result<()>;
+    /// Delete a file.
+    fn remove_file(&mut self, path: &Path) -> io::Result<()>;
+    /// Delete a directory if it has no entries; otherwise do nothing.
+    fn remove_dir_if_empty(&mut self, path: &Path);
+}
+
+/// The real filesystem.
+pub struct RealFs;
+
+impl Vfs for RealFs {
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn write(&mut self, path: &Path, contents: &str) -> io::Result<()> {
+        std::fs::write(path, contents)
+    }
+
+    fn create_dir_all(&mut self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn remove_file(&mut self, path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn remove_dir_if_empty(&mut self, path: &Path) {
+        if std::fs::read_dir(path).is_ok_and(|mut entries| entries.next().is_none()) {
+            let _ = std::fs::remove_dir(path);
+        }
+    }
+}
+
+/// How a file differs from disk after a dry run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChange {
+    Create,
+    Modify,
+    Delete,
+}
+
+/// A file a dry run would change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedFile {
+    pub file_path: PathBuf,
+    pub change: FileChange,
+}
+
+/// An in-memory overlay on the real filesystem.
+///
+/// Reads fall through to disk until a path is written or removed; nothing
+/// is ever written to disk. Writes fail the way the real filesystem would:
+/// when the parent directory is missing, or when the file (or, for new
+/// files, its directory) is read-only on disk.
+#[derive(Debug, Default)]
+pub struct MemoryFs {
+    /// Written file contents; `None` marks a removed file.
+    files: BTreeMap<PathBuf, Option<String>>,
+    /// Directories created in memory.
+    dirs: BTreeSet<PathBuf>,
+    /// Directories removed in memory.
+    removed_dirs: BTreeSet<PathBuf>,
+}
+
+impl MemoryFs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Files whose final contents differ from disk, ordered by path.
+    pub fn changes(&self) -> Vec<ChangedFile> {
+        self.files
+            .iter()
+            .filter_map(|(path, contents)| {
+                let on_disk = path.is_file().then(|| std::fs::read_to_string(path).ok());
+                let change = match (on_disk, contents) {
+                    (None, Some(_)) => FileChange::Create,
+                    (Some(_), None) => FileChange::Delete,
+                    (Some(old), Some(new)) if old.as_ref() != Some(new) => FileChange::Modify,
+                    _ => return None,
+                };
+                Some(ChangedFile {
+                    file_path: path.clone(),
+                    change,
+                })
+            })
+            .collect()
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        !self.removed_dirs.contains(path) && (self.dirs.contains(path) || path.is_dir())
+    }
+
+    /// Whether `dir` still has entries in the overlay.
+    fn has_entries(&self, dir: &Path) -> bool {
+        let in_memory = self
+            .files
+            .iter()
+            .any(|(path, contents)| contents.is_some() && path.parent() == Some(dir))
+            || self.dirs.iter().any(|path| path.parent() == Some(dir));
+        let on_disk = std::fs::read_dir(dir)
+            .is_ok_and(|entries| entries.flatten().any(|entry| self.exists(&entry.path())));
+        in_memory || on_disk
+    }
+}
+
+/// Whether `path` exists on disk and is read-only.
+fn read_only_on_disk(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|metadata| metadata.permissions().readonly())
+}
+
+fn permission_denied(path: &Path) -> io::Error {
+    io::Error::new(
+        ErrorKind::PermissionDenied,
+        format!("{} is read-only", path.display()),
+    )
+}
+
+impl Vfs for MemoryFs {
+    fn exists(&self, path: &Path) -> bool {
+        match self.files.get(path) {
+            Some(contents) => contents.is_some(),
+            None => self.is_dir(path) || path.is_file(),
+        }
+    }
+
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        match self.files.get(path) {
+            Some(Some(contents)) => Ok(contents.clone()),
+            Some(None) => Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!("{} was deleted", path.display()),
+            )),
+            None => std::fs::read_to_string(path),
+        }
+    }
+
+    fn write(&mut self, path: &Path, contents: &str) -> io::Result<()> {
+        let parent = path.parent().unwrap_or(Path::new(""));
+        if !parent.as_os_str().is_empty() && !self.is_dir(parent) {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!("parent directory {} does not exist", parent.display()),
+            ));
+        }
+        if self.is_dir(path) {
+            return Err(io::Error::new(
+                ErrorKind::IsADirectory,
+                format!("{} is a directory", path.display()),
+            ));
+        }
+        if path.is_file() {
+            if read_only_on_disk(path) {
+                return Err(permission_denied(path));
+            }
+        } else if !self.dirs.contains(parent) && read_only_on_disk(parent) {
+            return Err(permission_denied(parent));
+        }
+        self.files
+            .insert(path.to_path_buf(), Some(contents.to_string()));
+        Ok(())
+    }
+
+    fn create_dir_all(&mut self, path: &Path) -> io::Result<()> {
+        let mut missing = Vec::new();
+        let mut current = Some(path);
+        while let Some(dir) = current.filter(|dir| !dir.as_os_str().is_empty()) {
+            if self.is_dir(dir) {
+                break;
+            }
+            if self.exists(dir) {
+                return Err(io::Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{} is a file", dir.display()),
+                ));
+            }
+            missing.push(dir.to_path_buf());
+            current = dir.parent();
+        }
+        if let Some(existing) = current.filter(|dir| !dir.as_os_str().is_empty())
+            && !missing.is_empty()
+            && !self.dirs.contains(existing)
+            && read_only_on_disk(existing)
+        {
+            return Err(permission_denied(existing));
+        }
+        for dir in missing {
+            self.removed_dirs.remove(&dir);
+            self.dirs.insert(dir);
+        }
+        Ok(())
+    }
+
+    fn remove_file(&mut self, path: &Path) -> io::Result<()> {
+        if !self.exists(path) || self.is_dir(path) {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!("{} does not exist", path.display()),
+            ));
+        }
+        let parent = path.parent().unwrap_or(Path::new(""));
+        if path.is_file() && !self.dirs.contains(parent) && read_only_on_disk(parent) {
+            return Err(permission_denied(parent));
+        }
+        self.files.insert(path.to_path_buf(), None);
+        Ok(())
+    }
+
+    fn remove_dir_if_empty(&mut self, path: &Path) {
+        if self.is_dir(path) && !self.has_entries(path) {
+            self.dirs.remove(path);
+            self.removed_dirs.insert(path.to_path_buf());
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("gpui-vfs-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn memory_fs_overlays_disk_without_touching_it() {
+        let dir = temp_dir("overlay");
+        std::fs::write(dir.join("keep.rs"), "keep").unwrap();
+        std::fs::write(dir.join("edit.rs"), "old").unwrap();
+        std::fs::write(dir.join("gone.rs"), "gone").unwrap();
+
+        let mut fs = MemoryFs::new();
+        fs.create_dir_all(&dir.join("ui/button")).unwrap();
+        fs.write(&dir.join("ui/button/mod.rs"), "new").unwrap();
+        fs.write(&dir.join("edit.rs"), "changed").unwrap();
+        fs.write(&dir.join("keep.rs"), "keep").unwrap();
+        fs.remove_file(&dir.join("gone.rs")).unwrap();
+
+        assert_eq!(fs.read_to_string(&dir.join("edit.rs")).unwrap(), "changed");
+        assert!(!fs.exists(&dir.join("gone.rs")));
+        assert_eq!(
+            fs.changes(),
+            [
+                ChangedFile {
+                    file_path: dir.join("edit.rs"),
+                    change: FileChange::Modify,
+                },
+                ChangedFile {
+                    file_path: dir.join("gone.rs"),
+                    change: FileChange::Delete,
+                },
+                ChangedFile {
+                    file_path: dir.join("ui/button/mod.rs"),
+                    change: FileChange::Create,
+                },
+            ]
+        );
+        assert!(!dir.join("ui").exists());
+        assert_eq!(std::fs::read_to_string(dir.join("edit.rs")).unwrap(), "old");
+        assert!(dir.join("gone.rs").exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn memory_fs_write_fails_like_disk() {
+        let dir = temp_dir("failures");
+        let mut fs = MemoryFs::new();
+
+        let missing_parent = fs.write(&dir.join("missing/mod.rs"), "").unwrap_err();
+        assert_eq!(missing_parent.kind(), ErrorKind::NotFound);
+
+        let locked = dir.join("locked.rs");
+        std::fs::write(&locked, "").unwrap();
+        let mut permissions = std::fs::metadata(&locked).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&locked, permissions).unwrap();
+        let denied = fs.write(&locked, "edit").unwrap_err();
+        assert_eq!(denied.kind(), ErrorKind::PermissionDenied);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn memory_fs_removes_emptied_directories() {
+        let dir = temp_dir("rmdir");
+        std::fs::create_dir_all(dir.join("select")).unwrap();
+        std::fs::write(dir.join("select/mod.rs"), "").unwrap();
+
+        let mut fs = MemoryFs::new();
+        fs.remove_dir_if_empty(&dir.join("select"));
+        assert!(fs.exists(&dir.join("select")));
+
+        fs.remove_file(&dir.join("select/mod.rs")).unwrap();
+        fs.remove_dir_if_empty(&dir.join("select"));
+        assert!(!fs.exists(&dir.join("select")));
+        assert!(dir.join("select/mod.rs").exists());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,478 @@
+//! Dockable panel arrangement: which studio panels sit in which dock, which
+//! are open, how big each dock is, and which dock is collapsed.
+//!
+//! The studio has three docks around the story: left, right, and bottom.
+//! Each dock lists its panels in tab order and shows one open panel at a
+//! time. Panels move between docks (or to another spot in the same dock) by
+//! dragging their tab, and docks resize by dragging their inner edge. The
+//! arrangement is saved to [`LAYOUT_FILE`] whenever
+//! it changes and restored on the next launch.
+//!
+//! This module is layout state only; `main.rs` renders it.
+
+use serde_json::{Map, Value, json};
+
+/// File the panel arrangement is saved to, relative to the working directory.
+pub const LAYOUT_FILE: &str = "studio-layout.json";
+
+/// Smallest width of a side dock, or height of the bottom dock, while
+/// expanded.
+pub const MIN_DOCK_SIZE: f32 = 160.0;
+
+/// A dock around the story area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockPosition {
+    Left,
+    Right,
+    Bottom,
+}
+
+impl DockPosition {
+    /// Every dock, in [`LAYOUT_FILE`] order.
+    pub const ALL: [DockPosition; 3] = [Self::Left, Self::Right, Self::Bottom];
+
+    /// Name used in [`LAYOUT_FILE`].
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+            Self::Bottom => "bottom",
+        }
+    }
+
+    /// Width of a side dock, or height of the bottom dock, until it is
+    /// resized.
+    pub fn default_size(self) -> f32 {
+        match self {
+            Self::Left => 220.0,
+            // NotificationCenter's own width plus the dock padding.
+            Self::Right => 336.0,
+            Self::Bottom => 300.0,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// A studio panel that can be docked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelId {
+    /// Component and primitive story list.
+    Stories,
+    /// Theme token editor.
+    Tokens,
+    /// Contract metadata of the selected story.
+    Metadata,
+    /// Closed toast history.
+    Notifications,
+}
+
+impl PanelId {
+    /// Every panel, in default tab order.
+    pub const ALL: [PanelId; 4] = [
+        Self::Stories,
+        Self::Tokens,
+        Self::Notifications,
+        Self::Metadata,
+    ];
+
+    /// Tab label.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Stories => "Stories",
+            Self::Tokens => "Tokens",
+            Self::Metadata => "Metadata",
+            Self::Notifications => "Notifications",
+        }
+    }
+
+    /// Name used in [`LAYOUT_FILE`].
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Stories => "stories",
+            Self::Tokens => "tokens",
+            Self::Metadata => "metadata",
+            Self::Notifications => "notifications",
+        }
+    }
+
+    /// Whether the panel can be closed. The story list can only be moved or
+    /// collapsed, so there is always a way to pick a story.
+    pub fn closable(self) -> bool {
+        self != Self::Stories
+    }
+
+    /// Dock the panel starts in.
+    fn default_dock(self) -> DockPosition {
+        match self {
+            Self::Stories => DockPosition::Left,
+            Self::Tokens | Self::Notifications => DockPosition::Right,
+            Self::Metadata => DockPosition::Bottom,
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|panel| panel.key() == key)
+    }
+}
+
+/// One dock's panels, in tab order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Dock {
+    /// Each panel with whether it is open.
+    panels: Vec<(PanelId, bool)>,
+    /// Last shown panel; may be closed, see [`Dock::active`].
+    active: Option<PanelId>,
+    collapsed: bool,
+    /// Size set by dragging the dock's edge; see [`DockLayout::size`].
+    size: Option<f32>,
+}
+
+impl Dock {
+    /// Open panels, in tab order.
+    pub fn open_panels(&self) -> impl Iterator<Item = PanelId> + '_ {
+        self.panels
+            .iter()
+            .filter(|(_, open)| *open)
+            .map(|(panel, _)| *panel)
+    }
+
+    /// The panel the dock shows: the last one shown if it is still open,
+    /// otherwise the first open panel.
+    pub fn active(&self) -> Option<PanelId> {
+        self.active
+            .filter(|active| self.open_panels().any(|panel| panel == *active))
+            .or_else(|| self.open_panels().next())
+    }
+
+    /// Whether the dock is collapsed to a strip of tabs.
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Whether the dock has nothing to show.
+    pub fn is_empty(&self) -> bool {
+        self.open_panels().next().is_none()
+    }
+}
+
+/// Where every studio panel is docked.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockLayout {
+    docks: [Dock; 3],
+}
+
+impl Default for DockLayout {
+    /// Stories open on the left; tokens and notifications on the right and
+    /// metadata at the bottom, all closed.
+    fn default() -> Self {
+        let mut layout = Self {
+            docks: Default::default(),
+        };
+        for panel in PanelId::ALL {
+            layout.docks[panel.default_dock().index()]
+                .panels
+                .push((panel, panel == PanelId::Stories));
+        }
+        layout
+    }
+}
+
+impl DockLayout {
+    /// The dock at `position`.
+    pub fn dock(&self, position: DockPosition) -> &Dock {
+        &self.docks[position.index()]
+    }
+
+    /// The dock holding `panel`.
+    pub fn position_of(&self, panel: PanelId) -> DockPosition {
+        DockPosition::ALL
+            .into_iter()
+            .find(|position| self.slot(*position, panel).is_some())
+            .unwrap_or(panel.default_dock())
+    }
+
+    /// Whether `panel` is on screen: open, its dock's active tab, and its
+    /// dock expanded.
+    pub fn is_visible(&self, panel: PanelId) -> bool {
+        let dock = self.dock(self.position_of(panel));
+        !dock.collapsed && dock.active() == Some(panel)
+    }
+
+    /// Open `panel`, bring its tab to the front, and expand its dock.
+    pub fn show(&mut self, panel: PanelId) {
+        let position = self.position_of(panel);
+        let Some(slot) = self.slot(position, panel) else {
+            return;
+        };
+        let dock = &mut self.docks[position.index()];
+        dock.panels[slot].1 = true;
+        dock.active = Some(panel);
+        dock.collapsed = false;
+    }
+
+    /// Close `panel`, unless it is not [`closable`](PanelId::closable).
+    pub fn close(&mut self, panel: PanelId) {
+        if !panel.closable() {
+            return;
+        }
+        let position = self.position_of(panel);
+        if let Some(slot) = self.slot(position, panel) {
+            self.docks[position.index()].panels[slot].1 = false;
+        }
+    }
+
+    /// Toolbar toggle: close `panel` if it is on screen, otherwise show it.
+    pub fn toggle(&mut self, panel: PanelId) {
+        if self.is_visible(panel) {
+            self.close(panel);
+        } else {
+            self.show(panel);
+        }
+    }
+
+    /// Collapse or expand the dock at `position`.
+    pub fn toggle_collapsed(&mut self, position: DockPosition) {
+        let dock = &mut self.docks[position.index()];
+        dock.collapsed = !dock.collapsed;
+    }
+
+    /// Width of the side dock, or height of the bottom dock, at `position`
+    /// while expanded.
+    pub fn size(&self, position: DockPosition) -> f32 {
+        self.dock(position).size.unwrap_or(position.default_size())
+    }
+
+    /// Resize the dock at `position` to `size` whole pixels, no smaller than
+    /// [`MIN_DOCK_SIZE`]. Returns false when the size is unchanged.
+    pub fn resize(&mut self, position: DockPosition, size: f32) -> bool {
+        let size = size.round().max(MIN_DOCK_SIZE);
+        if self.size(position) == size {
+            return false;
+        }
+        self.docks[position.index()].size = Some(size);
+        true
+    }
+
+    /// Move `panel` into the dock at `to`, before the tab of `before` (or
+    /// last if `before` is `None` or not in that dock). The moved panel is
+    /// opened and shown.
+    pub fn move_panel(&mut self, panel: PanelId, to: DockPosition, before: Option<PanelId>) {
+        if before == Some(panel) {
+            return;
+        }
+        let from = self.position_of(panel);
+        if let Some(slot) = self.slot(from, panel) {
+            self.docks[from.index()].panels.remove(slot);
+        }
+        let slot = before
+            .and_then(|before| self.slot(to, before))
+            .unwrap_or(self.docks[to.index()].panels.len());
+        self.docks[to.index()].panels.insert(slot, (panel, true));
+        self.show(panel);
+    }
+
+    /// Serialize for [`LAYOUT_FILE`].
+    pub fn to_json(&self) -> String {
+        let mut docks = Map::new();
+        for position in DockPosition::ALL {
+            let dock = self.dock(position);
+            let keys = |open_only: bool| -> Vec<&str> {
+                dock.panels
+                    .iter()
+                    .filter(|(_, open)| *open || !open_only)
+                    .map(|(panel, _)| panel.key())
+                    .collect()
+            };
+            docks.insert(
+                position.key().to_string(),
+                json!({
+                    "panels": keys(false),
+                    "open": keys(true),
+                    "active": dock.active.map(PanelId::key),
+                    "collapsed": dock.collapsed,
+                    "size": dock.size,
+                }),
+            );
+        }
+        serde_json::to_string_pretty(&json!({ "docks": docks }))
+            .expect("layout JSON is always serializable")
+    }
+
+    /// Parse a layout saved by [`DockLayout::to_json`].
+    ///
+    /// Unknown and repeated panel names are skipped; panels the file does not
+    /// mention go to their default dock, closed (the story list open). A
+    /// missing size keeps the dock's default; one below [`MIN_DOCK_SIZE`] is
+    /// raised to it.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let docks = value
+            .get("docks")
+            .and_then(Value::as_object)
+            .ok_or("layout has no `docks` object")?;
+
+        let mut layout = Self {
+            docks: Default::default(),
+        };
+        let mut placed = Vec::new();
+        for position in DockPosition::ALL {
+            let Some(saved) = docks.get(position.key()) else {
+                continue;
+            };
+            let names = |field: &str| -> Vec<PanelId> {
+                saved
+                    .get(field)
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|name| name.as_str().and_then(PanelId::from_key))
+                    .collect()
+            };
+            let open = names("open");
+            let dock = &mut layout.docks[position.index()];
+            for panel in names("panels") {
+                if !placed.contains(&panel) {
+                    placed.push(panel);
+                    let is_open = open.contains(&panel) || !panel.closable();
+                    dock.panels.push((panel, is_open));
+                }
+            }
+            dock.active = saved
+                .get("active")
+                .and_then(Value::as_str)
+                .and_then(PanelId::from_key);
+            dock.collapsed = saved
+                .get("collapsed")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            dock.size = saved
+                .get("size")
+                .and_then(Value::as_f64)
+                .filter(|size| size.is_finite())
+                .map(|size| (size as f32).round().max(MIN_DOCK_SIZE));
+        }
+        for panel in PanelId::ALL {
+            if !placed.contains(&panel) {
+                layout.docks[panel.default_dock().index()]
+                    .panels
+                    .push((panel, !panel.closable()));
+            }
+        }
+        Ok(layout)
+    }
+
+    /// Read [`LAYOUT_FILE`], falling back to the default arrangement when it
+    /// is missing or unreadable.
+    pub fn load() -> Self {
+        match std::fs::read_to_string(LAYOUT_FILE) {
+            Ok(json) => Self::from_json(&json).unwrap_or_else(|e| {
+                log::error!("Ignoring {}: {}", LAYOUT_FILE, e);
+                Self::default()
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::default(),
+            Err(e) => {
+                log::error!("Failed to read {}: {}", LAYOUT_FILE, e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Write the arrangement to [`LAYOUT_FILE`].
+    pub fn save(&self) {
+        if let Err(e) = std::fs::write(LAYOUT_FILE, self.to_json()) {
+            log::error!("Failed to save {}: {}", LAYOUT_FILE, e);
+        }
+    }
+
+    /// Index of `panel` in the dock at `position`.
+    fn slot(&self, position: DockPosition, panel: PanelId) -> Option<usize> {
+        self.docks[position.index()]
+            .panels
+            .iter()
+            .position(|(p, _)| *p == panel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_layout_opens_only_the_story_list() {
+        let layout = DockLayout::default();
+        assert!(layout.is_visible(PanelId::Stories));
+        for panel in [PanelId::Tokens, PanelId::Metadata, PanelId::Notifications] {
+            assert!(!layout.is_visible(panel), "{panel:?}");
+            assert_eq!(layout.position_of(panel), panel.default_dock());
+        }
+        for position in DockPosition::ALL {
+            assert_eq!(layout.size(position), position.default_size());
+        }
+    }
+
+    #[test]
+    fn json_round_trips_panels_and_sizes() {
+        let mut layout = DockLayout::default();
+        layout.move_panel(
+            PanelId::Metadata,
+            DockPosition::Left,
+            Some(PanelId::Stories),
+        );
+        layout.show(PanelId::Tokens);
+        layout.toggle_collapsed(DockPosition::Right);
+        assert!(layout.resize(DockPosition::Left, 301.4));
+        assert!(layout.resize(DockPosition::Bottom, 180.0));
+
+        let restored = DockLayout::from_json(&layout.to_json()).unwrap();
+        assert_eq!(restored, layout);
+        assert_eq!(restored.size(DockPosition::Left), 301.0);
+        assert_eq!(restored.size(DockPosition::Bottom), 180.0);
+        assert_eq!(
+            restored.size(DockPosition::Right),
+            DockPosition::Right.default_size()
+        );
+        assert_eq!(
+            restored
+                .dock(DockPosition::Left)
+                .open_panels()
+                .collect::<Vec<_>>(),
+            [PanelId::Metadata, PanelId::Stories]
+        );
+        assert!(restored.dock(DockPosition::Right).is_collapsed());
+    }
+
+    #[test]
+    fn resize_keeps_docks_usable() {
+        let mut layout = DockLayout::default();
+        assert!(layout.resize(DockPosition::Right, 40.0));
+        assert_eq!(layout.size(DockPosition::Right), MIN_DOCK_SIZE);
+        assert!(!layout.resize(DockPosition::Right, MIN_DOCK_SIZE));
+    }
+
+    #[test]
+    fn from_json_repairs_partial_files() {
+        let json = r#"{"docks": {
+            "right": {"panels": ["stories", "bogus", "stories"], "open": [], "size": 12},
+            "bottom": {"panels": ["tokens"], "open": ["tokens"], "size": "wide"}
+        }}"#;
+        let layout = DockLayout::from_json(json).unwrap();
+        // The story list cannot be closed, and is only placed once.
+        assert_eq!(layout.position_of(PanelId::Stories), DockPosition::Right);
+        assert!(layout.is_visible(PanelId::Stories));
+        assert_eq!(layout.dock(DockPosition::Right).open_panels().count(), 1);
+        assert_eq!(layout.size(DockPosition::Right), MIN_DOCK_SIZE);
+        assert_eq!(
+            layout.size(DockPosition::Bottom),
+            DockPosition::Bottom.default_size()
+        );
+        // Unmentioned panels go to their default dock, closed.
+        assert_eq!(layout.position_of(PanelId::Metadata), DockPosition::Bottom);
+        assert!(!layout.is_visible(PanelId::Metadata));
+
+        assert!(DockLayout::from_json("{}").is_err());
+        assert!(DockLayout::from_json("not json").is_err());
+    }
+}
@@ -4,115 +4,1169 @@
 //! the visual validation environment for the component library. It renders
 //! component stories, supports theme switching, and provides a live token editor.
 
+mod dock;
+mod permalink;
+mod recovery;
+mod settings;
+mod tour;
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use components::{
+    BadgeSize, BadgeStatus, Bar, BarChart, Button, ButtonVariant, DescriptionItem,
+    DescriptionLayout, DescriptionList, Dialog, EmptyState, EmptyStateSize, FileFilter, FilePicker,
+    InspectNode, InspectTree, Kbd, Markdown, NotificationCenter, NotificationHistory, Popover,
+    ScrollArea, ScrollAreaHandle, SearchInput, Sparkline, SplitPane, Stability, StatusBar,
+    StatusIndicator, StatusSegment, TRAFFIC_LIGHT_POSITION, TabItem, Tabs, TitleBar, ToastLayer,
+    TooltipView,
+};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
+use primitives::{
+    Locale, Orientation, Pane, PopoverPosition, ShortcutRegistry, format_decimal, is_escape_key,
+    is_typing,
+};
+use registry::acceptance::{AcceptanceLedger, LEDGER_FILE};
+use registry::config::ThemeMode;
+use registry::dashboard::{Badge, ComponentStatus, GateStatus};
+use registry::manifest::{MANIFEST_FILE, Manifest};
 use story::StoryRegistry;
-use theme::{ActiveTheme, Theme, ThemeAppearance};
+use theme::engine::get_token_by_path;
+use theme::patch::{color_hex, foreground_background_pairs};
+use theme::{
+    ActiveTheme, ColorVisionDeficiency, PatchOp, Theme, ThemeAppearance, ThemePatch, ThemeRegistry,
+    TokenEdit,
+};
+
+use crate::dock::{DockLayout, DockPosition, MIN_DOCK_SIZE, PanelId};
+use crate::permalink::StudioLink;
+use crate::recovery::{AUTOSAVE_INTERVAL, RecoveredEdits};
+use crate::settings::{
+    CopyLink, NextStory, PreviousStory, StudioSettings, ToggleDashboard, ToggleInspect,
+    ToggleMetadataPanel, TogglePerfHud, ToggleShortcuts, ToggleSpacingOverlay, ToggleTheme,
+    ToggleTokensPanel,
+};
+use crate::tour::{TourController, TourStep};
+
+// ---------------------------------------------------------------------------
+// PerfHistory — story render timings
+// ---------------------------------------------------------------------------
+
+/// Frame budget at 60fps, drawn as the HUD threshold.
+const FRAME_BUDGET_MS: f64 = 16.7;
+
+/// Spacing overlay: minor baseline grid step in pixels.
+const SPACING_GRID_MINOR: f32 = 4.0;
+
+/// Spacing overlay: major baseline grid step in pixels.
+const SPACING_GRID_MAJOR: f32 = 8.0;
+
+/// Smallest width or height the story area keeps while a dock is resized.
+const MIN_CONTENT_SIZE: f32 = 240.0;
+
+/// Thickness of a collapsed dock: the width of a side dock's strip of tab
+/// initials, or the height of the bottom dock's tab bar.
+const COLLAPSED_DOCK_SIZE: f32 = 28.0;
+
+/// Drag payload for a panel tab being moved to another dock.
+#[derive(Clone)]
+struct DraggedPanel {
+    panel: PanelId,
+}
+
+impl Render for DraggedPanel {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        div()
+            .px_2()
+            .py_0p5()
+            .rounded_sm()
+            .text_xs()
+            .text_color(theme.text.default)
+            .bg(theme.surface.elevated_surface)
+            .border_1()
+            .border_color(theme.border.focused)
+            .child(self.panel.label())
+    }
+}
+
+/// Rolling story render timings for the perf HUD and metadata panel, plus
+/// studio frame times for the status bar's frame rate.
+#[derive(Default)]
+struct PerfHistory {
+    /// Most recent samples across all stories, oldest first.
+    recent: VecDeque<f64>,
+    /// Most recent samples per story index, oldest first.
+    per_story: HashMap<usize, VecDeque<f64>>,
+    /// Studio frames rendered within the last second, oldest first.
+    frames: VecDeque<Instant>,
+}
+
+impl PerfHistory {
+    /// Samples kept in each rolling window.
+    const CAPACITY: usize = 120;
+
+    fn record(&mut self, story_index: usize, elapsed: Duration) {
+        let ms = elapsed.as_secs_f64() * 1000.0;
+        for samples in [
+            &mut self.recent,
+            self.per_story.entry(story_index).or_default(),
+        ] {
+            if samples.len() == Self::CAPACITY {
+                samples.pop_front();
+            }
+            samples.push_back(ms);
+        }
+    }
+
+    fn story(&self, story_index: usize) -> Option<&VecDeque<f64>> {
+        self.per_story.get(&story_index).filter(|s| !s.is_empty())
+    }
+
+    /// Record a studio frame rendered `at`, dropping frames more than a
+    /// second older.
+    fn record_frame(&mut self, at: Instant) {
+        while self
+            .frames
+            .front()
+            .is_some_and(|first| at.duration_since(*first) >= Duration::from_secs(1))
+        {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(at);
+    }
+
+    /// Frames rendered in the second up to the most recent frame.
+    fn fps(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// StoryTab — workspace tabs
+// ---------------------------------------------------------------------------
+
+/// What a workspace tab shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TabTarget {
+    /// A component story, by index into the StoryRegistry entries.
+    Story(usize),
+    /// A primitive doc story, by index into the StoryRegistry primitive docs.
+    Primitive(usize),
+}
+
+/// Width a story is laid out at, for checking how it responds to narrow
+/// and wide windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum StoryViewport {
+    /// The full width of the content area.
+    #[default]
+    Fill,
+    Phone,
+    Tablet,
+    Desktop,
+}
+
+impl StoryViewport {
+    const ALL: [StoryViewport; 4] = [Self::Fill, Self::Phone, Self::Tablet, Self::Desktop];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Fill => "Fill",
+            Self::Phone => "375",
+            Self::Tablet => "768",
+            Self::Desktop => "1280",
+        }
+    }
+
+    /// Fixed layout width, or `None` to fill the content area.
+    fn width(self) -> Option<Pixels> {
+        match self {
+            Self::Fill => None,
+            Self::Phone => Some(px(375.0)),
+            Self::Tablet => Some(px(768.0)),
+            Self::Desktop => Some(px(1280.0)),
+        }
+    }
+}
+
+/// An open workspace tab: the story it shows plus view state kept per tab,
+/// so switching tabs returns to each story as it was left.
+struct StoryTab {
+    target: TabTarget,
+    viewport: StoryViewport,
+    /// Story content scroll position.
+    scroll: ScrollHandle,
+}
+
+impl StoryTab {
+    fn new(target: TabTarget) -> Self {
+        Self {
+            target,
+            viewport: StoryViewport::default(),
+            scroll: ScrollHandle::new(),
+        }
+    }
+}
 
 // ---------------------------------------------------------------------------
 // StudioApp — the root view
 // ---------------------------------------------------------------------------
 
+/// File the token editor exports the session's patch script to, relative to
+/// the working directory.
+const THEME_PATCH_FILE: &str = "theme-patch.json";
+
+/// Where a dashboard badge leads when clicked.
+#[derive(Clone, Copy)]
+enum GateTarget {
+    /// The component's story, optionally with the metadata panel open.
+    Story { metadata: bool },
+    /// Copy the `gpui` subcommand that prints the gate's full report.
+    Report(&'static str),
+}
+
 /// The root workbench view, holding all application state.
 ///
 /// Implements `Render` (not `RenderOnce`) because it is a persistent stateful
 /// view that tracks selected story, theme state, and token editor visibility.
 struct StudioApp {
-    /// Index of the currently selected story in the StoryRegistry.
-    selected_story_index: Option<usize>,
-    /// Whether the token editor panel is visible.
-    show_token_editor: bool,
-    /// Whether the metadata panel is visible.
-    show_metadata: bool,
-    // search_query reserved for future story filtering (Phase 1)
+    /// Open workspace tabs, in tab bar order.
+    tabs: Vec<StoryTab>,
+    /// Index into `tabs` of the tab shown; ignored while `tabs` is empty.
+    active_tab: usize,
+    /// Whether the release readiness dashboard replaces the story view.
+    show_dashboard: bool,
+    /// Dashboard rows, reloaded each time the dashboard opens.
+    dashboard: Vec<ComponentStatus>,
+    /// Which panels are open, and which dock each one sits in.
+    layout: DockLayout,
+    /// Whether the performance HUD is visible.
+    show_perf_hud: bool,
+    /// Whether the baseline grid and element outlines are drawn over the story.
+    show_spacing_overlay: bool,
+    /// Whether inspect mode is on: hovering a component shows its size,
+    /// padding, and color tokens.
+    inspect_mode: bool,
+    /// Last pointer position seen while inspecting, in window coordinates.
+    inspect_pointer: Option<Point<Pixels>>,
+    /// Story render timings shown by the HUD and metadata panel.
+    perf: PerfHistory,
+    /// Sidebar: settled story search query, set by the debounced SearchInput.
+    story_query: String,
+    /// Sidebar: scroll position of the story list.
+    sidebar_scroll: ScrollAreaHandle,
+    /// Token editor: settled token path filter.
+    token_filter: String,
+    /// Token editor: scroll position of the token list.
+    token_scroll: ScrollAreaHandle,
     /// Token editor: which token path is being edited (if any).
     editing_token_path: Option<String>,
     /// Token editor: the hex value being typed.
     editing_token_value: String,
+    /// Token editor: every edit applied this session, in order.
+    theme_patch: ThemePatch,
+    /// Token editor: edits that can be undone, newest last, each with the
+    /// theme it was made on.
+    token_undo: Vec<(String, TokenEdit)>,
+    /// Token editor: theme that category "Copy" takes tokens from.
+    token_copy_source: Option<String>,
+    /// Token editor: outcome of the last applied edit, shown in the status bar.
+    last_apply: Option<Result<String, String>>,
+    /// Token editor: number of `theme_patch` edits already autosaved.
+    autosaved_edits: usize,
+    /// Edits a previous session left unsaved, while the restore prompt is open.
+    pending_recovery: Option<RecoveredEdits>,
+    /// Whether the keyboard shortcut cheat-sheet is open.
+    show_shortcuts: bool,
+    /// Focus of the open cheat-sheet, so Escape reaches it.
+    shortcuts_focus: FocusHandle,
+    /// First-run tour step showing, if any.
+    tour: TourController,
 }
 
 impl StudioApp {
-    fn new() -> Self {
+    /// Open the studio in the view described by `link` (the first story by default).
+    fn new(link: &StudioLink, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        // Redraw the toolbar badge whenever a toast closes into the history.
+        cx.observe_global::<NotificationHistory>(|_, cx| cx.notify())
+            .detach();
+        // Redraw with the new density when the settings change, and restart
+        // the tour when Settings asks to show it again.
+        cx.observe_global::<StudioSettings>(|this, cx| {
+            if !StudioSettings::get(cx).tour_completed && !this.tour.is_active() {
+                this.start_tour(cx);
+            }
+            cx.notify();
+        })
+        .detach();
+        // Autosave token edits so a crash does not lose them; a clean quit
+        // leaves nothing to recover.
+        cx.spawn(async move |this, cx| {
+            loop {
+                cx.background_executor().timer(AUTOSAVE_INTERVAL).await;
+                if this
+                    .update(cx, |this, cx| this.autosave_token_edits(cx))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        })
+        .detach();
+        cx.on_app_quit(|this, _cx| {
+            if this.pending_recovery.is_none() {
+                RecoveredEdits::discard();
+            }
+            async {}
+        })
+        .detach();
+        // The "System" theme mode follows the OS between light and dark.
+        cx.observe_window_appearance(window, |_, window, cx| {
+            let mode = StudioSettings::get(cx).theme_mode;
+            if mode == ThemeMode::System {
+                settings::apply_theme_mode(mode, window.appearance(), cx);
+                cx.notify();
+            }
+        })
+        .detach();
+
+        let target = match &link.story {
+            Some(name) => {
+                let registry = cx.global::<StoryRegistry>();
+                let story = registry
+                    .entries()
+                    .iter()
+                    .position(|entry| entry.name().eq_ignore_ascii_case(name));
+                let primitive = registry
+                    .primitive_docs()
+                    .iter()
+                    .position(|doc| doc.name().eq_ignore_ascii_case(name));
+                if story.is_none() && primitive.is_none() {
+                    log::error!("Unknown story '{}'", name);
+                }
+                match primitive {
+                    Some(index) if story.is_none() => TabTarget::Primitive(index),
+                    _ => TabTarget::Story(story.unwrap_or(0)),
+                }
+            }
+            None => TabTarget::Story(0),
+        };
+        let inspect_mode = link.is_on("inspect");
+        cx.global_mut::<InspectTree>()
+            .set_enabled(inspect_mode || link.is_on("grid"));
+        let show_dashboard = link.is_on("dashboard");
+        // Links open the panels they name on top of the saved arrangement.
+        let mut layout = DockLayout::load();
+        for (key, panel) in [("tokens", PanelId::Tokens), ("metadata", PanelId::Metadata)] {
+            if link.is_on(key) {
+                layout.show(panel);
+            }
+        }
+        // The tour opens on first launch, after any recovery prompt is answered.
+        let mut tour = TourController::default();
+        if !StudioSettings::get(cx).tour_completed {
+            tour.start();
+            if let Some(panel) = tour.current().and_then(TourStep::panel) {
+                layout.show(panel);
+            }
+        }
+
         Self {
-            selected_story_index: Some(0), // Select first story by default
-            show_token_editor: false,
-            show_metadata: false,
+            tabs: vec![StoryTab::new(target)],
+            active_tab: 0,
+            show_dashboard,
+            dashboard: if show_dashboard {
+                load_dashboard(cx)
+            } else {
+                Vec::new()
+            },
+            layout,
+            show_perf_hud: link.is_on("perf"),
+            show_spacing_overlay: link.is_on("grid"),
+            inspect_mode,
+            inspect_pointer: None,
+            perf: PerfHistory::default(),
+            story_query: String::new(),
+            sidebar_scroll: ScrollAreaHandle::new(),
+            token_filter: String::new(),
+            token_scroll: ScrollAreaHandle::new(),
             editing_token_path: None,
             editing_token_value: String::new(),
+            theme_patch: ThemePatch::new(),
+            token_undo: Vec::new(),
+            token_copy_source: None,
+            last_apply: None,
+            autosaved_edits: 0,
+            pending_recovery: RecoveredEdits::load(),
+            show_shortcuts: false,
+            shortcuts_focus: cx.focus_handle(),
+            tour,
+        }
+    }
+
+    /// Show the tour from its first step.
+    fn start_tour(&mut self, cx: &mut Context<Self>) {
+        self.tour.start();
+        self.reveal_tour_step(cx);
+    }
+
+    /// Move the tour a step forward (`true`) or back, finishing it after the
+    /// last step.
+    fn step_tour(&mut self, forward: bool, cx: &mut Context<Self>) {
+        if !forward {
+            self.tour.back();
+        } else if self.tour.advance() {
+            self.end_tour(cx);
+            return;
+        }
+        self.reveal_tour_step(cx);
+    }
+
+    /// Close the tour and remember not to open it at launch again.
+    fn end_tour(&mut self, cx: &mut Context<Self>) {
+        self.tour.dismiss();
+        StudioSettings::update(cx, |config| config.tour_completed = true);
+        cx.notify();
+    }
+
+    /// Open and expand the panel the current tour step points at, so its
+    /// callout has something to attach to.
+    fn reveal_tour_step(&mut self, cx: &mut Context<Self>) {
+        match self.tour.current().and_then(TourStep::panel) {
+            Some(panel) if !self.layout.is_visible(panel) => {
+                self.update_layout(cx, |layout| layout.show(panel));
+            }
+            _ => cx.notify(),
         }
     }
 
-    /// Toggle between One Dark and One Light themes.
+    /// Cycle through registered themes in name order (One Dark, One Light,
+    /// then any project themes loaded from `themes/` or with "Load theme…").
     fn toggle_theme(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
-        let current_appearance = cx.theme().appearance;
-        let target = match current_appearance {
-            ThemeAppearance::Dark => "One Light",
-            ThemeAppearance::Light => "One Dark",
+        let mut names: Vec<String> = ThemeRegistry::global(cx)
+            .names()
+            .map(str::to_string)
+            .collect();
+        names.sort();
+        let current = names.iter().position(|name| *name == cx.theme().name);
+        let target = match current {
+            Some(i) => names[(i + 1) % names.len()].clone(),
+            None => "One Dark".to_string(),
         };
-        if let Err(e) = Theme::change(target, cx) {
+        if let Err(e) = Theme::change(&target, cx) {
+            log::error!("Failed to switch theme: {}", e);
+        }
+        cx.notify();
+    }
+
+    /// Register the theme files chosen with "Load theme…" and switch to the
+    /// last one that loaded.
+    fn load_theme_files(&mut self, paths: &[PathBuf], cx: &mut Context<Self>) {
+        let mut loaded = None;
+        for path in paths {
+            match ThemeRegistry::global_mut(cx).load_file(path) {
+                Ok(name) => {
+                    log::info!("Loaded theme '{}' from {}", name, path.display());
+                    for warning in ThemeRegistry::global(cx).warnings(&name) {
+                        log::warn!("{}", warning);
+                    }
+                    loaded = Some(name);
+                }
+                Err(e) => log::error!("Failed to load theme: {}", e),
+            }
+        }
+        if let Some(name) = loaded
+            && let Err(e) = Theme::change(&name, cx)
+        {
             log::error!("Failed to switch theme: {}", e);
         }
-        cx.notify();
-    }
+        cx.notify();
+    }
+
+    /// Cycle the color vision simulation: off, then each deficiency in turn.
+    fn cycle_color_vision(&mut self, cx: &mut Context<Self>) {
+        let next = match cx.theme().color_vision() {
+            None => Some(ColorVisionDeficiency::ALL[0]),
+            Some(current) => ColorVisionDeficiency::ALL
+                .iter()
+                .skip_while(|kind| **kind != current)
+                .nth(1)
+                .copied(),
+        };
+        Theme::set_color_vision(next, cx);
+        cx.notify();
+    }
+
+    /// Turn inspect mode on or off.
+    fn toggle_inspect_mode(&mut self, cx: &mut Context<Self>) {
+        self.inspect_mode = !self.inspect_mode;
+        self.track_hovered_component(cx);
+    }
+
+    /// Show or hide the spacing overlay.
+    fn toggle_spacing_overlay(&mut self, cx: &mut Context<Self>) {
+        self.show_spacing_overlay = !self.show_spacing_overlay;
+        self.track_hovered_component(cx);
+    }
+
+    /// Record inspect metadata, and follow the pointer, while inspect mode
+    /// or the spacing overlay needs the hovered component; components record
+    /// nothing otherwise.
+    fn track_hovered_component(&mut self, cx: &mut Context<Self>) {
+        let tracking = self.inspect_mode || self.show_spacing_overlay;
+        if !tracking {
+            self.inspect_pointer = None;
+        }
+        cx.global_mut::<InspectTree>().set_enabled(tracking);
+        cx.notify();
+    }
+
+    /// A permalink reproducing the current story, theme, and playground state.
+    fn permalink(&self, cx: &App) -> StudioLink {
+        let theme = cx.theme();
+        let registry = cx.global::<StoryRegistry>();
+        let primitive = self
+            .selected_primitive_index()
+            .and_then(|idx| registry.primitive_docs().get(idx))
+            .map(|doc| doc.name().to_string());
+        let mut link = StudioLink {
+            story: self
+                .selected_story_index()
+                .and_then(|idx| registry.entries().get(idx))
+                .map(|entry| entry.name().to_string())
+                .or(primitive),
+            theme: Some(theme.name.to_string()),
+            ..StudioLink::default()
+        };
+        if let Some(kind) = theme.color_vision() {
+            link.props
+                .insert("vision".into(), kind.label().to_lowercase());
+        }
+        for (key, on) in [
+            ("dashboard", self.show_dashboard),
+            ("tokens", self.layout.is_visible(PanelId::Tokens)),
+            ("metadata", self.layout.is_visible(PanelId::Metadata)),
+            ("perf", self.show_perf_hud),
+            ("grid", self.show_spacing_overlay),
+            ("inspect", self.inspect_mode),
+        ] {
+            if on {
+                link.props.insert(key.into(), "on".into());
+            }
+        }
+        link
+    }
+
+    /// Open or close the notification center. Everything it showed counts as
+    /// read once it opens or closes.
+    fn toggle_notifications(&mut self, cx: &mut Context<Self>) {
+        self.update_layout(cx, |layout| layout.toggle(PanelId::Notifications));
+        cx.default_global::<NotificationHistory>().mark_all_read();
+    }
+
+    /// Change the panel arrangement and save it for the next launch.
+    fn update_layout(&mut self, cx: &mut Context<Self>, change: impl FnOnce(&mut DockLayout)) {
+        change(&mut self.layout);
+        self.layout.save();
+        cx.notify();
+    }
+
+    /// The workspace tab shown, if any is open.
+    fn active_tab(&self) -> Option<&StoryTab> {
+        self.tabs.get(self.active_tab)
+    }
+
+    /// Registry index of the component story in the active tab.
+    fn selected_story_index(&self) -> Option<usize> {
+        match self.active_tab()?.target {
+            TabTarget::Story(idx) => Some(idx),
+            TabTarget::Primitive(_) => None,
+        }
+    }
+
+    /// Index of the primitive doc story in the active tab.
+    fn selected_primitive_index(&self) -> Option<usize> {
+        match self.active_tab()?.target {
+            TabTarget::Primitive(idx) => Some(idx),
+            TabTarget::Story(_) => None,
+        }
+    }
+
+    /// Show `target`, switching to its tab if one is already open. Otherwise
+    /// it opens in a new tab when `new_tab` is set (or no tab is open), and
+    /// replaces the active tab's story when not.
+    fn open_target(&mut self, target: TabTarget, new_tab: bool, cx: &mut Context<Self>) {
+        if let Some(index) = self.tabs.iter().position(|tab| tab.target == target) {
+            self.active_tab = index;
+        } else if new_tab || self.tabs.is_empty() {
+            self.tabs.push(StoryTab::new(target));
+            self.active_tab = self.tabs.len() - 1;
+        } else {
+            self.tabs[self.active_tab] = StoryTab {
+                viewport: self.tabs[self.active_tab].viewport,
+                ..StoryTab::new(target)
+            };
+        }
+        self.show_dashboard = false;
+        self.reveal_in_sidebar(target, cx);
+        cx.notify();
+    }
+
+    /// Open the component story `delta` places from the selected one in the
+    /// active tab, wrapping around the registry. With no story selected,
+    /// stepping forward opens the first and back opens the last.
+    fn step_story(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let count = cx.global::<StoryRegistry>().len();
+        if count == 0 {
+            return;
+        }
+        let next = match self.selected_story_index() {
+            Some(idx) => (idx as isize + delta).rem_euclid(count as isize) as usize,
+            None if delta < 0 => count - 1,
+            None => 0,
+        };
+        self.open_target(TabTarget::Story(next), false, cx);
+    }
+
+    /// Scroll the sidebar so `target`'s entry is visible. Only the unfiltered
+    /// list is scrolled: a search keeps the user's place in its results.
+    fn reveal_in_sidebar(&self, target: TabTarget, cx: &Context<Self>) {
+        if !self.story_query.is_empty() {
+            return;
+        }
+        // Stories come first, then the PRIMITIVES heading and the primitives.
+        let item = match target {
+            TabTarget::Story(idx) => idx,
+            TabTarget::Primitive(idx) => cx.global::<StoryRegistry>().len() + 1 + idx,
+        };
+        self.sidebar_scroll.scroll_to_item(item);
+    }
+
+    /// Close the tab at `index`, keeping the active tab if it stays open and
+    /// otherwise activating its right-hand neighbour.
+    fn close_tab(&mut self, index: usize, cx: &mut Context<Self>) {
+        if index >= self.tabs.len() {
+            return;
+        }
+        self.tabs.remove(index);
+        if index < self.active_tab || self.active_tab == self.tabs.len() {
+            self.active_tab = self.active_tab.saturating_sub(1);
+        }
+        cx.notify();
+    }
+
+    /// Lay the active tab's story out at `viewport`.
+    fn set_viewport(&mut self, viewport: StoryViewport, cx: &mut Context<Self>) {
+        if let Some(tab) = self.tabs.get_mut(self.active_tab) {
+            tab.viewport = viewport;
+            cx.notify();
+        }
+    }
+
+    /// Show or hide the dashboard, reloading it on open so sign-offs and
+    /// installs made since it was last shown are picked up.
+    fn toggle_dashboard(&mut self, cx: &mut Context<Self>) {
+        self.show_dashboard = !self.show_dashboard;
+        if self.show_dashboard {
+            self.dashboard = load_dashboard(cx);
+        }
+        cx.notify();
+    }
+
+    /// Open or close the shortcut cheat-sheet, focusing it while open.
+    fn toggle_shortcuts(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.show_shortcuts = !self.show_shortcuts;
+        if self.show_shortcuts {
+            window.focus(&self.shortcuts_focus, cx);
+        }
+        cx.notify();
+    }
+
+    /// Follow a dashboard badge for `component` to its story or report.
+    fn open_gate(&mut self, component: &str, target: GateTarget, cx: &mut Context<Self>) {
+        match target {
+            GateTarget::Story { metadata } => {
+                let story = cx
+                    .global::<StoryRegistry>()
+                    .entries()
+                    .iter()
+                    .position(|entry| entry.contract().name.eq_ignore_ascii_case(component));
+                let Some(idx) = story else {
+                    log::error!("No story for {}", component);
+                    return;
+                };
+                self.open_target(TabTarget::Story(idx), false, cx);
+                if metadata {
+                    self.update_layout(cx, |layout| layout.show(PanelId::Metadata));
+                }
+            }
+            GateTarget::Report(command) => {
+                let command_line = format!("gpui {} {}", command, component.to_lowercase());
+                log::info!("Copied report command: {}", command_line);
+                cx.write_to_clipboard(ClipboardItem::new_string(command_line));
+            }
+        }
+    }
+
+    /// Copy the current view's permalink to the clipboard.
+    fn copy_link(&mut self, cx: &mut Context<Self>) {
+        let command_line = self.permalink(cx).to_command_line();
+        log::info!("Copied link: {}", command_line);
+        cx.write_to_clipboard(ClipboardItem::new_string(command_line));
+    }
+
+    /// Apply a token edit from the token editor.
+    fn apply_token_edit(&mut self, cx: &mut Context<Self>) {
+        if let Some(path) = self.editing_token_path.take() {
+            let hex = self.editing_token_value.trim();
+            if !hex.is_empty() {
+                let edit = TokenEdit::set(cx.theme().unfiltered_tokens(), &path, hex);
+                self.apply_edit(edit, cx);
+            }
+        }
+        self.editing_token_value.clear();
+        cx.notify();
+    }
+
+    /// Apply `edit` to the current theme, record it in the session's patch,
+    /// and make it undoable. An edit with an unknown path or invalid color
+    /// changes nothing.
+    fn apply_edit(&mut self, edit: TokenEdit, cx: &mut Context<Self>) {
+        if edit.is_empty() {
+            self.last_apply = Some(Ok(format!("{}: already up to date", edit.label)));
+            cx.notify();
+            return;
+        }
+        let patch = edit.patch();
+        if let Err(e) = patch.apply(&mut cx.theme().unfiltered_tokens().clone()) {
+            log::error!("{} failed: {}", edit.label, e);
+            self.last_apply = Some(Err(format!("{} failed: {e}", edit.label)));
+            cx.notify();
+            return;
+        }
+        self.record_patch(&patch, cx);
+        log::info!("{} ({} tokens)", edit.label, edit.changes.len());
+        self.last_apply = Some(Ok(edit.label.clone()));
+        self.token_undo.push((cx.theme().name.clone(), edit));
+        cx.notify();
+    }
+
+    /// Set each token of `patch` on the current theme and append it to the
+    /// session's patch.
+    fn record_patch(&mut self, patch: &ThemePatch, cx: &mut Context<Self>) {
+        for op in patch.ops() {
+            let PatchOp::SetToken { path, value } = op;
+            match Theme::set_token(path, value, cx) {
+                Ok(()) => self.theme_patch.set_token(path.clone(), value.clone()),
+                Err(e) => log::error!("Failed to set token '{}': {}", path, e),
+            }
+        }
+    }
+
+    /// Undo the latest token edit. Edits made on another theme are dropped
+    /// rather than applied to this one.
+    fn undo_token_edit(&mut self, cx: &mut Context<Self>) {
+        let current = cx.theme().name.clone();
+        self.token_undo.retain(|(theme, _)| *theme == current);
+        let Some((_, edit)) = self.token_undo.pop() else {
+            return;
+        };
+        // Undoing appends the previous values, so the exported patch still
+        // replays to what is shown.
+        self.record_patch(&edit.undo_patch(), cx);
+        self.last_apply = Some(Ok(format!("Undid: {}", edit.label)));
+        cx.notify();
+    }
+
+    /// Copy `category` from the chosen source theme onto the current one.
+    fn copy_token_category(&mut self, category: &str, cx: &mut Context<Self>) {
+        let Some(source_name) = self.token_copy_source.clone() else {
+            return;
+        };
+        let Some(source) = ThemeRegistry::global(cx).get(&source_name) else {
+            self.last_apply = Some(Err(format!("Theme '{source_name}' is not registered")));
+            cx.notify();
+            return;
+        };
+        let edit = TokenEdit::copy_category(
+            cx.theme().unfiltered_tokens(),
+            source,
+            category,
+            format!("Copy {category} from {source_name}"),
+        );
+        self.apply_edit(edit, cx);
+    }
+
+    /// Restore `category` to the current theme's registered values.
+    fn reset_token_category(&mut self, category: &str, cx: &mut Context<Self>) {
+        let name = cx.theme().name.clone();
+        let Some(registered) = ThemeRegistry::global(cx).get(&name) else {
+            return;
+        };
+        let edit = TokenEdit::copy_category(
+            cx.theme().unfiltered_tokens(),
+            registered,
+            category,
+            format!("Reset {category}"),
+        );
+        self.apply_edit(edit, cx);
+    }
+
+    /// Swap the foreground/background pairs of `category`.
+    fn swap_token_pairs(&mut self, category: &str, cx: &mut Context<Self>) {
+        let edit = TokenEdit::swap_pairs(cx.theme().unfiltered_tokens(), category);
+        self.apply_edit(edit, cx);
+    }
+
+    /// Choose the next registered theme, other than the current one, as the
+    /// source of category copies.
+    fn cycle_token_copy_source(&mut self, cx: &mut Context<Self>) {
+        let current = cx.theme().name.clone();
+        let mut names: Vec<String> = ThemeRegistry::global(cx)
+            .names()
+            .filter(|name| *name != current)
+            .map(str::to_string)
+            .collect();
+        names.sort();
+        let next = match &self.token_copy_source {
+            Some(source) => names
+                .iter()
+                .position(|name| name == source)
+                .map_or(0, |i| (i + 1) % names.len().max(1)),
+            None => 0,
+        };
+        self.token_copy_source = names.get(next).cloned();
+        cx.notify();
+    }
+
+    /// Save the token edits to the recovery file if any were made since the
+    /// last autosave.
+    fn autosave_token_edits(&mut self, cx: &mut Context<Self>) {
+        if self.pending_recovery.is_some() || self.theme_patch.len() == self.autosaved_edits {
+            return;
+        }
+        RecoveredEdits {
+            theme: cx.theme().name.clone(),
+            patch: self.theme_patch.clone(),
+        }
+        .save();
+        self.autosaved_edits = self.theme_patch.len();
+    }
+
+    /// Replay the edits a previous session left unsaved, on the theme they
+    /// were made on.
+    fn restore_recovered_edits(&mut self, cx: &mut Context<Self>) {
+        let Some(recovered) = self.pending_recovery.take() else {
+            return;
+        };
+        if cx.theme().name != recovered.theme
+            && let Err(e) = Theme::change(&recovered.theme, cx)
+        {
+            log::error!("Failed to switch theme: {}", e);
+        }
+        let mut restored = 0;
+        for op in recovered.patch.ops() {
+            let PatchOp::SetToken { path, value } = op;
+            match Theme::set_token(path, value, cx) {
+                Ok(()) => {
+                    self.theme_patch.set_token(path.clone(), value.clone());
+                    restored += 1;
+                }
+                Err(e) => log::error!("Failed to restore token '{}': {}", path, e),
+            }
+        }
+        self.last_apply = Some(Ok(format!("Restored {restored} token edits")));
+        // Rewritten at the next autosave, now with any failed edits dropped.
+        self.autosaved_edits = 0;
+        cx.notify();
+    }
+
+    /// Decline the previous session's edits and delete the recovery file.
+    fn discard_recovered_edits(&mut self, cx: &mut Context<Self>) {
+        self.pending_recovery = None;
+        RecoveredEdits::discard();
+        cx.notify();
+    }
+
+    /// Write this session's token edits to [`THEME_PATCH_FILE`], replayable
+    /// with `gpui theme apply-patch`.
+    fn export_theme_patch(&mut self) {
+        let result = self
+            .theme_patch
+            .to_json()
+            .map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(THEME_PATCH_FILE, json).map_err(|e| e.to_string()));
+        match result {
+            Ok(()) => log::info!(
+                "Exported {} token edits to {}",
+                self.theme_patch.len(),
+                THEME_PATCH_FILE
+            ),
+            Err(e) => log::error!("Failed to export theme patch: {}", e),
+        }
+    }
+
+    // -- Rendering helpers -------------------------------------------------
+
+    /// The "Restore previous session edits?" dialog, while it is pending.
+    fn render_recovery_prompt(
+        &self,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Option<Dialog> {
+        let recovered = self.pending_recovery.as_ref()?;
+        let count = recovered.patch.len();
+        let edits = if count == 1 { "edit" } else { "edits" };
+        Some(
+            Dialog::new("recovery-prompt", window, cx)
+                .title("Restore previous session edits?")
+                .description(format!(
+                    "The last session ended without quitting and left {count} unsaved token \
+                     {edits} on {}. Restoring replays them onto that theme.",
+                    recovered.theme
+                ))
+                .overlay_closable(false)
+                .close_button(false)
+                .action(
+                    Button::new("recovery-discard")
+                        .label("Discard")
+                        .variant(ButtonVariant::Ghost)
+                        .on_click(cx.listener(|this, _event, _window, cx| {
+                            this.discard_recovered_edits(cx);
+                        })),
+                )
+                .action(
+                    Button::new("recovery-restore")
+                        .label("Restore")
+                        .variant(ButtonVariant::Primary)
+                        .on_click(cx.listener(|this, _event, _window, cx| {
+                            this.restore_recovered_edits(cx);
+                        })),
+                ),
+        )
+    }
+
+    /// The keyboard shortcut cheat-sheet, while it is open: every shortcut
+    /// in the `ShortcutRegistry` with its current binding, then the selected
+    /// component's contract key bindings.
+    fn render_shortcuts_overlay(&self, cx: &Context<Self>) -> Option<impl IntoElement> {
+        if !self.show_shortcuts {
+            return None;
+        }
+        let theme = cx.theme();
+
+        let heading = |title: SharedString| {
+            div()
+                .pt_2()
+                .text_xs()
+                .font_weight(FontWeight::SEMIBOLD)
+                .text_color(theme.text.muted)
+                .child(title)
+        };
+        let row = |label: SharedString, keys: Vec<Kbd>| {
+            div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_3()
+                .py_0p5()
+                .child(
+                    div()
+                        .flex_1()
+                        .text_sm()
+                        .text_color(theme.text.default)
+                        .child(label),
+                )
+                .child(div().flex().flex_row().gap_1().children(keys))
+        };
+
+        let mut sheet = div()
+            .id("shortcuts-sheet")
+            .track_focus(&self.shortcuts_focus)
+            .flex()
+            .flex_col()
+            .gap_1()
+            .w(px(440.0))
+            .max_h(relative(0.8))
+            .overflow_y_scroll()
+            .p_4()
+            .bg(theme.surface.elevated_surface)
+            .border_1()
+            .border_color(theme.border.default)
+            .rounded_lg()
+            .shadow_lg()
+            // Clicks inside the sheet do not reach the backdrop.
+            .on_mouse_down(MouseButton::Left, |_event, _window, cx| {
+                cx.stop_propagation();
+            })
+            .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                if is_escape_key(event) {
+                    this.toggle_shortcuts(window, cx);
+                    cx.stop_propagation();
+                }
+            }))
+            .child(
+                div()
+                    .text_lg()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.text.default)
+                    .child("Keyboard Shortcuts"),
+            )
+            .child(heading("Studio".into()));
+        if let Some(shortcuts) = cx.try_global::<ShortcutRegistry>() {
+            for shortcut in shortcuts.shortcuts() {
+                sheet = sheet.child(row(
+                    shortcut.label().clone(),
+                    vec![Kbd::new(shortcut.keys().clone())],
+                ));
+            }
+        }
+
+        let registry = cx.global::<StoryRegistry>();
+        if let Some(entry) = self
+            .selected_story_index()
+            .and_then(|idx| registry.entries().get(idx))
+        {
+            let contract = entry.contract();
+            sheet = sheet.child(heading(contract.name.clone().into()));
+            if contract.key_bindings.is_empty() {
+                sheet = sheet.child(
+                    div()
+                        .text_sm()
+                        .text_color(theme.text.muted)
+                        .child("No keyboard bindings."),
+                );
+            }
+            for binding in &contract.key_bindings {
+                let keys = binding.keys.iter().cloned().map(Kbd::new).collect();
+                sheet = sheet.child(row(binding.action.clone().into(), keys));
+            }
+        }
+
+        Some(
+            div()
+                .id("shortcuts-overlay")
+                .absolute()
+                .inset_0()
+                .flex()
+                .justify_center()
+                .items_start()
+                .pt(px(80.0))
+                .bg(theme.surface.background.opacity(0.6))
+                .occlude()
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(|this, _event, window, cx| this.toggle_shortcuts(window, cx)),
+                )
+                .child(sheet),
+        )
+    }
+
+    /// The tour callout for `step`, while it is the step showing. Toolbar
+    /// callouts hang below its right end; panel callouts sit beside a side
+    /// dock or above the bottom dock. Callouts wait for the recovery prompt.
+    fn render_tour_callout(&self, step: TourStep, cx: &Context<Self>) -> Option<Popover> {
+        if self.tour.current() != Some(step) || self.pending_recovery.is_some() {
+            return None;
+        }
+        let theme = cx.theme();
+        let number = self.tour.number().unwrap_or_default();
+        let position = match step.panel().map(|panel| self.layout.position_of(panel)) {
+            None => PopoverPosition::below_right(),
+            Some(DockPosition::Left) => PopoverPosition::beside_right(),
+            Some(DockPosition::Right) => PopoverPosition::beside_left(),
+            Some(DockPosition::Bottom) => PopoverPosition::above_left(),
+        };
+
+        let mut actions = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .child(
+                div()
+                    .flex_1()
+                    .text_xs()
+                    .text_color(theme.text.muted)
+                    .child(format!("{number} of {}", TourStep::ALL.len())),
+            )
+            .child(
+                Button::new("tour-skip")
+                    .label("Skip tour")
+                    .variant(ButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _event, _window, cx| this.end_tour(cx))),
+            );
+        if !self.tour.is_first() {
+            actions = actions.child(
+                Button::new("tour-back")
+                    .label("Back")
+                    .variant(ButtonVariant::Ghost)
+                    .on_click(cx.listener(|this, _event, _window, cx| this.step_tour(false, cx))),
+            );
+        }
+        actions = actions.child(
+            Button::new("tour-next")
+                .label(if self.tour.is_last() { "Done" } else { "Next" })
+                .variant(ButtonVariant::Primary)
+                .on_click(cx.listener(|this, _event, _window, cx| this.step_tour(true, cx))),
+        );
 
-    /// Apply a token edit from the token editor.
-    fn apply_token_edit(&mut self, cx: &mut Context<Self>) {
-        if let Some(ref path) = self.editing_token_path {
-            let hex = self.editing_token_value.trim();
-            if !hex.is_empty() {
-                match Theme::set_token(path, hex, cx) {
-                    Ok(()) => {
-                        log::info!("Token '{}' set to '{}'", path, hex);
-                    }
-                    Err(e) => {
-                        log::error!("Failed to set token '{}': {}", path, e);
-                    }
-                }
-            }
-        }
-        self.editing_token_path = None;
-        self.editing_token_value.clear();
-        cx.notify();
+        Some(
+            Popover::new("tour-callout")
+                .position(position)
+                .anchor_to_parent()
+                .open(true)
+                .width(px(280.0))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .gap_2()
+                        .p_1()
+                        .child(
+                            div()
+                                .text_sm()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(theme.text.default)
+                                .child(step.title()),
+                        )
+                        .child(
+                            div()
+                                .text_xs()
+                                .text_color(theme.text.muted)
+                                .child(step.body()),
+                        )
+                        .child(actions),
+                ),
+        )
     }
 
-    // -- Rendering helpers -------------------------------------------------
-
     /// Render the top toolbar with theme toggle and panel toggles.
     fn render_toolbar(&self, cx: &Context<Self>) -> impl IntoElement {
         let theme = cx.theme();
         let theme_name: SharedString = theme.name.clone().into();
         let is_dark = theme.appearance == ThemeAppearance::Dark;
+        let color_vision = theme.color_vision();
+        let unread = cx
+            .try_global::<NotificationHistory>()
+            .map_or(0, NotificationHistory::unread_count);
 
-        div()
-            .id("toolbar")
-            .flex()
-            .flex_row()
-            .items_center()
-            .justify_between()
-            .w_full()
-            .h_10()
-            .px_4()
-            .bg(theme.chrome.title_bar_background)
-            .border_b_1()
-            .border_color(theme.border.default)
+        TitleBar::new("toolbar")
+            .height(px(40.0))
             // Left: App title
-            .child(
+            .start(
                 div()
-                    .flex()
-                    .flex_row()
-                    .items_center()
-                    .gap_2()
-                    .child(
-                        div()
-                            .text_sm()
-                            .font_weight(FontWeight::BOLD)
-                            .text_color(theme.text.default)
-                            .child("GPUI Workbench"),
-                    )
-                    .child(div().text_xs().text_color(theme.text.muted).child("v0.1.0")),
+                    .text_sm()
+                    .font_weight(FontWeight::BOLD)
+                    .text_color(theme.text.default)
+                    .child("GPUI Workbench"),
             )
+            .start(div().text_xs().text_color(theme.text.muted).child("v0.1.0"))
             // Right: Controls
-            .child(
+            .end(
                 div()
                     .flex()
                     .flex_row()
@@ -152,13 +1206,54 @@ impl StudioApp {
                                     .child(theme_name),
                             ),
                     )
+                    // Load a theme file; the picker stays empty so every pick fires.
+                    .child(
+                        FilePicker::new("load-theme")
+                            .label("Load theme…")
+                            .prompt("Load")
+                            .multiple(true)
+                            .filter(FileFilter::new("Theme", ["json"]))
+                            .value(Vec::<PathBuf>::new())
+                            .show_list(false)
+                            .on_change(cx.listener(|this, paths: &[PathBuf], _window, cx| {
+                                this.load_theme_files(paths, cx);
+                            })),
+                    )
+                    // Dashboard toggle
+                    .child(
+                        div()
+                            .id("dashboard-toggle")
+                            .px_3()
+                            .py_1()
+                            .bg(if self.show_dashboard {
+                                theme.element.selected
+                            } else {
+                                theme.element.background
+                            })
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.toggle_dashboard(cx);
+                                })
+                            })
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(theme.text.default)
+                                    .child("Dashboard"),
+                            ),
+                    )
                     // Token editor toggle
                     .child(
                         div()
                             .id("token-editor-toggle")
                             .px_3()
                             .py_1()
-                            .bg(if self.show_token_editor {
+                            .bg(if self.layout.is_visible(PanelId::Tokens) {
                                 theme.element.selected
                             } else {
                                 theme.element.background
@@ -170,8 +1265,7 @@ impl StudioApp {
                             .hover(|s| s.bg(theme.element.hover))
                             .on_mouse_down(MouseButton::Left, {
                                 cx.listener(|this, _event, _window, cx| {
-                                    this.show_token_editor = !this.show_token_editor;
-                                    cx.notify();
+                                    this.update_layout(cx, |layout| layout.toggle(PanelId::Tokens));
                                 })
                             })
                             .child(
@@ -187,7 +1281,7 @@ impl StudioApp {
                             .id("metadata-toggle")
                             .px_3()
                             .py_1()
-                            .bg(if self.show_metadata {
+                            .bg(if self.layout.is_visible(PanelId::Metadata) {
                                 theme.element.selected
                             } else {
                                 theme.element.background
@@ -199,8 +1293,9 @@ impl StudioApp {
                             .hover(|s| s.bg(theme.element.hover))
                             .on_mouse_down(MouseButton::Left, {
                                 cx.listener(|this, _event, _window, cx| {
-                                    this.show_metadata = !this.show_metadata;
-                                    cx.notify();
+                                    this.update_layout(cx, |layout| {
+                                        layout.toggle(PanelId::Metadata)
+                                    });
                                 })
                             })
                             .child(
@@ -209,24 +1304,187 @@ impl StudioApp {
                                     .text_color(theme.text.default)
                                     .child("Metadata"),
                             ),
+                    )
+                    // Perf HUD toggle
+                    .child(
+                        div()
+                            .id("perf-hud-toggle")
+                            .px_3()
+                            .py_1()
+                            .bg(if self.show_perf_hud {
+                                theme.element.selected
+                            } else {
+                                theme.element.background
+                            })
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.show_perf_hud = !this.show_perf_hud;
+                                    cx.notify();
+                                })
+                            })
+                            .child(div().text_xs().text_color(theme.text.default).child("Perf")),
+                    )
+                    // Spacing overlay toggle
+                    .child(
+                        div()
+                            .id("spacing-overlay-toggle")
+                            .px_3()
+                            .py_1()
+                            .bg(if self.show_spacing_overlay {
+                                theme.element.selected
+                            } else {
+                                theme.element.background
+                            })
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.toggle_spacing_overlay(cx);
+                                })
+                            })
+                            .child(div().text_xs().text_color(theme.text.default).child("Grid")),
+                    )
+                    // Inspect mode toggle
+                    .child(
+                        div()
+                            .id("inspect-toggle")
+                            .px_3()
+                            .py_1()
+                            .bg(if self.inspect_mode {
+                                theme.element.selected
+                            } else {
+                                theme.element.background
+                            })
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.toggle_inspect_mode(cx);
+                                })
+                            })
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(theme.text.default)
+                                    .child("Inspect"),
+                            ),
+                    )
+                    // Color vision simulation toggle
+                    .child(
+                        div()
+                            .id("color-vision-toggle")
+                            .px_3()
+                            .py_1()
+                            .bg(if color_vision.is_some() {
+                                theme.element.selected
+                            } else {
+                                theme.element.background
+                            })
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.cycle_color_vision(cx);
+                                })
+                            })
+                            .child(div().text_xs().text_color(theme.text.default).child(
+                                match color_vision {
+                                    Some(kind) => kind.label(),
+                                    None => "Vision",
+                                },
+                            )),
+                    )
+                    // Copy a permalink to the current view
+                    .child(
+                        div()
+                            .id("copy-link")
+                            .px_3()
+                            .py_1()
+                            .bg(theme.element.background)
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.copy_link(cx);
+                                })
+                            })
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(theme.text.default)
+                                    .child("Copy link"),
+                            ),
+                    )
+                    // Notification center toggle with unread badge
+                    .child(
+                        div()
+                            .id("notifications-toggle")
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .gap_1()
+                            .px_3()
+                            .py_1()
+                            .bg(if self.layout.is_visible(PanelId::Notifications) {
+                                theme.element.selected
+                            } else {
+                                theme.element.background
+                            })
+                            .border_1()
+                            .border_color(theme.border.default)
+                            .rounded_md()
+                            .cursor_pointer()
+                            .hover(|s| s.bg(theme.element.hover))
+                            .on_mouse_down(MouseButton::Left, {
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.toggle_notifications(cx);
+                                })
+                            })
+                            .child(
+                                div()
+                                    .text_xs()
+                                    .text_color(theme.text.default)
+                                    .child("Notifications"),
+                            )
+                            .when(unread > 0, |this| {
+                                this.child(
+                                    div()
+                                        .px_1()
+                                        .rounded_full()
+                                        .bg(theme.text.accent)
+                                        .text_xs()
+                                        .text_color(theme.surface.background)
+                                        .child(unread.to_string()),
+                                )
+                            }),
                     ),
             )
     }
 
-    /// Render the sidebar with component/story list.
+    /// Render the story list panel.
     fn render_sidebar(&self, cx: &Context<Self>) -> Div {
         let theme = cx.theme();
         let registry = cx.global::<StoryRegistry>();
+        let locale = StudioSettings::get(cx).locale();
 
-        let mut sidebar = div()
-            .flex()
-            .flex_col()
-            .w(px(220.0))
-            .flex_shrink_0()
-            .h_full()
-            .bg(theme.panel.background)
-            .border_r_1()
-            .border_color(theme.border.default);
+        let mut sidebar = div().flex().flex_col().size_full();
 
         // Sidebar header
         sidebar = sidebar.child(
@@ -252,74 +1510,124 @@ impl StudioApp {
                 ),
         );
 
-        // Story entries
-        let mut story_list = div()
-            .id("story-list")
-            .flex()
-            .flex_col()
-            .py_1()
-            .flex_1()
-            .overflow_y_scroll();
+        // Story search
+        let query = self.story_query.to_lowercase();
+        let matches: Vec<(usize, &story::StoryEntry)> = registry
+            .entries()
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| {
+                entry.name().to_lowercase().contains(&query)
+                    || entry
+                        .localized_description(locale)
+                        .to_lowercase()
+                        .contains(&query)
+            })
+            .collect();
+        let primitive_matches: Vec<(usize, &story::PrimitiveDoc)> = registry
+            .primitive_docs()
+            .iter()
+            .enumerate()
+            .filter(|(_, doc)| {
+                doc.name().to_lowercase().contains(&query)
+                    || doc.summary().to_lowercase().contains(&query)
+            })
+            .collect();
 
-        for (idx, entry) in registry.entries().iter().enumerate() {
-            let is_selected = self.selected_story_index == Some(idx);
-            let name: SharedString = entry.name().to_string().into();
-            let description: SharedString = entry.description().to_string().into();
+        let this = cx.weak_entity();
+        sidebar = sidebar.child(
+            div().px_2().py_2().child(
+                SearchInput::new("story-search")
+                    .placeholder("Search components")
+                    .full_width()
+                    .result_count(matches.len() + primitive_matches.len())
+                    .on_query(move |query, cx| {
+                        this.update(cx, |this, cx| {
+                            this.story_query = query.to_string();
+                            this.sidebar_scroll.scroll_to_top();
+                            cx.notify();
+                        })
+                        .ok();
+                    }),
+            ),
+        );
 
-            let item_bg = if is_selected {
-                theme.ghost_element.selected
-            } else {
-                Hsla::transparent_black()
-            };
+        // Story entries
+        let mut story_list = ScrollArea::new("story-list").track_scroll(&self.sidebar_scroll);
 
-            let item_text = if is_selected {
-                theme.text.default
-            } else {
-                theme.text.muted
-            };
+        if matches.is_empty() && primitive_matches.is_empty() {
+            story_list = story_list.child(
+                EmptyState::new("story-search-empty")
+                    .size(EmptyStateSize::Small)
+                    .title("No matching components"),
+            );
+        }
+
+        for (idx, entry) in matches {
+            let is_selected = self.selected_story_index() == Some(idx);
+            story_list = story_list.child(
+                self.render_nav_item(
+                    ElementId::Name(format!("story-nav-{}", idx).into()),
+                    entry.name(),
+                    entry.localized_description(locale),
+                    Some(entry.contract().stability),
+                    is_selected,
+                    cx,
+                )
+                .on_mouse_down(MouseButton::Left, {
+                    cx.listener(move |this, event: &MouseDownEvent, _window, cx| {
+                        let new_tab = event.modifiers.platform;
+                        this.open_target(TabTarget::Story(idx), new_tab, cx);
+                    })
+                })
+                .on_mouse_down(MouseButton::Middle, {
+                    cx.listener(move |this, _event, _window, cx| {
+                        this.open_target(TabTarget::Story(idx), true, cx);
+                    })
+                }),
+            );
+        }
 
+        // Primitive doc stories, grouped below the components
+        if !primitive_matches.is_empty() {
             story_list = story_list.child(
                 div()
-                    .id(ElementId::Name(format!("story-nav-{}", idx).into()))
-                    .flex()
-                    .flex_col()
                     .px_3()
-                    .py(px(6.0))
-                    .mx_1()
-                    .bg(item_bg)
-                    .rounded_md()
-                    .cursor_pointer()
-                    .hover(|s| s.bg(theme.ghost_element.hover))
-                    .on_mouse_down(MouseButton::Left, {
-                        cx.listener(move |this, _event, _window, cx| {
-                            this.selected_story_index = Some(idx);
-                            cx.notify();
-                        })
+                    .pt_3()
+                    .pb_1()
+                    .text_xs()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.text.muted)
+                    .child("PRIMITIVES"),
+            );
+        }
+
+        for (idx, doc) in primitive_matches {
+            let is_selected = self.selected_primitive_index() == Some(idx);
+            story_list = story_list.child(
+                self.render_nav_item(
+                    ("primitive-nav", idx).into(),
+                    doc.name(),
+                    doc.summary(),
+                    None,
+                    is_selected,
+                    cx,
+                )
+                .on_mouse_down(MouseButton::Left, {
+                    cx.listener(move |this, event: &MouseDownEvent, _window, cx| {
+                        let new_tab = event.modifiers.platform;
+                        this.open_target(TabTarget::Primitive(idx), new_tab, cx);
+                    })
+                })
+                .on_mouse_down(MouseButton::Middle, {
+                    cx.listener(move |this, _event, _window, cx| {
+                        this.open_target(TabTarget::Primitive(idx), true, cx);
                     })
-                    .child(
-                        div()
-                            .text_sm()
-                            .font_weight(if is_selected {
-                                FontWeight::MEDIUM
-                            } else {
-                                FontWeight::NORMAL
-                            })
-                            .text_color(item_text)
-                            .child(name),
-                    )
-                    .when(!description.is_empty(), |this| {
-                        this.child(
-                            div()
-                                .text_xs()
-                                .text_color(theme.text.placeholder)
-                                .overflow_x_hidden()
-                                .child(description),
-                        )
-                    }),
+                }),
             );
         }
 
-        sidebar = sidebar.child(story_list);
+        sidebar = sidebar.child(div().flex_1().min_h_0().py_1().child(story_list));
 
         // Theme info at bottom of sidebar
         sidebar = sidebar.child(
@@ -340,6 +1648,84 @@ impl StudioApp {
         sidebar
     }
 
+    /// A sidebar entry: the name, badged with its stability tier unless
+    /// stable, with an optional one-line description below.
+    fn render_nav_item(
+        &self,
+        id: ElementId,
+        name: &str,
+        description: &str,
+        stability: Option<Stability>,
+        is_selected: bool,
+        cx: &Context<Self>,
+    ) -> Stateful<Div> {
+        let theme = cx.theme();
+        let badge = stability.and_then(|stability| {
+            let status = match stability {
+                Stability::Experimental => BadgeStatus::Warning,
+                Stability::Beta => BadgeStatus::Info,
+                Stability::Deprecated => BadgeStatus::Error,
+                Stability::Stable => return None,
+            };
+            Some(
+                components::Badge::new(SharedString::from(format!("{}-stability", id)))
+                    .label(stability.name())
+                    .size(BadgeSize::Small)
+                    .status(status),
+            )
+        });
+        let name: SharedString = name.to_string().into();
+        let description: SharedString = description.to_string().into();
+
+        let item_bg = if is_selected {
+            theme.ghost_element.selected
+        } else {
+            Hsla::transparent_black()
+        };
+
+        let item_text = if is_selected {
+            theme.text.default
+        } else {
+            theme.text.muted
+        };
+
+        div()
+            .id(id)
+            .flex()
+            .flex_col()
+            .px_3()
+            .py(settings::nav_item_padding(StudioSettings::get(cx).density))
+            .mx_1()
+            .bg(item_bg)
+            .rounded_md()
+            .cursor_pointer()
+            .hover(|s| s.bg(theme.ghost_element.hover))
+            .child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap_1p5()
+                    .text_sm()
+                    .font_weight(if is_selected {
+                        FontWeight::MEDIUM
+                    } else {
+                        FontWeight::NORMAL
+                    })
+                    .text_color(item_text)
+                    .child(name)
+                    .children(badge),
+            )
+            .when(!description.is_empty(), |this| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.text.placeholder)
+                        .overflow_x_hidden()
+                        .child(description),
+                )
+            })
+    }
+
     /// Render the main content area with the selected story.
     fn render_content(&self, window: &mut Window, cx: &mut Context<Self>) -> Div {
         let theme = cx.theme();
@@ -347,6 +1733,7 @@ impl StudioApp {
         let border = theme.border.default;
         let text_default = theme.text.default;
         let text_muted = theme.text.muted;
+        let notes_bg = theme.panel.background;
 
         let mut content = div()
             .flex()
@@ -356,19 +1743,27 @@ impl StudioApp {
             .bg(bg)
             .overflow_y_hidden();
 
-        if let Some(idx) = self.selected_story_index {
+        if !self.show_dashboard && !self.tabs.is_empty() {
+            content = content.child(self.render_workspace_tabs(cx));
+        }
+
+        if self.show_dashboard {
+            content = content.child(self.render_dashboard(cx));
+        } else if let Some(idx) = self.selected_story_index() {
             // Extract metadata from registry (scoped borrow)
             let story_info = {
                 let registry = cx.global::<StoryRegistry>();
+                let locale = StudioSettings::get(cx).locale();
                 registry.entries().get(idx).map(|entry| {
                     (
                         SharedString::from(entry.name().to_string()),
-                        SharedString::from(entry.description().to_string()),
+                        SharedString::from(entry.localized_description(locale).to_string()),
+                        SharedString::from(entry.notes().to_string()),
                     )
                 })
             };
 
-            if let Some((story_name, story_desc)) = story_info {
+            if let Some((story_name, story_desc, story_notes)) = story_info {
                 // Content header
                 content = content.child(
                     div()
@@ -392,58 +1787,358 @@ impl StudioApp {
                                         .child(story_name),
                                 )
                                 .when(!story_desc.is_empty(), |this| {
-                                    this.child(
-                                        div().text_sm().text_color(text_muted).child(story_desc),
-                                    )
+                                    this.child(Markdown::new("story-description", story_desc))
                                 }),
-                        ),
+                        )
+                        .child(self.render_viewport_picker(cx)),
                 );
 
+                // Review notes
+                if !story_notes.is_empty() {
+                    content = content.child(
+                        div()
+                            .id("story-notes")
+                            .flex_none()
+                            .max_h(px(160.0))
+                            .overflow_y_scroll()
+                            .px_6()
+                            .py_3()
+                            .bg(notes_bg)
+                            .border_b_1()
+                            .border_color(border)
+                            .child(Markdown::new("story-notes-markdown", story_notes)),
+                    );
+                }
+
                 // Render the selected story directly (avoids holding registry borrow
                 // across the mutable cx access needed by render_story).
                 let story_element = render_story_by_index(idx, window, cx);
                 if let Some(element) = story_element {
-                    content =
-                        content.child(
-                            div()
-                                .id("story-content")
-                                .flex_1()
-                                .overflow_y_scroll()
-                                .p_4()
-                                .child(element),
-                        );
+                    let viewport = self
+                        .active_tab()
+                        .map(|tab| tab.viewport)
+                        .unwrap_or_default();
+                    let story = div()
+                        .relative()
+                        .when_some(viewport.width(), |this, width| {
+                            this.w(width)
+                                .flex_none()
+                                .border_1()
+                                .border_dashed()
+                                .border_color(border)
+                        })
+                        .child(element);
+                    // The hovered component's padding and margins are drawn by
+                    // render_spacing_overlay, over the whole window.
+                    let story = story.when(self.show_spacing_overlay, |this| {
+                        this.child(spacing_grid(border, cx.theme().border.focused))
+                    });
+                    let mut story_content = div()
+                        .id("story-content")
+                        .flex_1()
+                        .overflow_scroll()
+                        .p(settings::story_padding(StudioSettings::get(cx).density))
+                        .child(story);
+                    if let Some(tab) = self.active_tab() {
+                        story_content = story_content.track_scroll(&tab.scroll);
+                    }
+                    content = content.child(story_content);
                 }
             }
+        } else if let Some((name, doc)) = self.selected_primitive_index().and_then(|idx| {
+            let doc = cx.global::<StoryRegistry>().primitive_docs().get(idx)?;
+            Some((SharedString::from(doc.name().to_string()), doc.render_doc()))
+        }) {
+            // Primitive doc story: the contract rendered as documentation
+            content = content
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .px_6()
+                        .py_3()
+                        .border_b_1()
+                        .border_color(border)
+                        .child(
+                            div()
+                                .text_lg()
+                                .font_weight(FontWeight::BOLD)
+                                .text_color(text_default)
+                                .child(name),
+                        )
+                        .child(div().text_xs().text_color(text_muted).child("Primitive")),
+                )
+                .child(
+                    div()
+                        .id("primitive-doc")
+                        .flex_1()
+                        .overflow_y_scroll()
+                        .px_6()
+                        .py_4()
+                        .child(doc),
+                );
         } else {
             // No story selected
-            content = content.child(
-                div().flex().flex_1().justify_center().items_center().child(
-                    div()
-                        .text_color(text_muted)
-                        .child("Select a component from the sidebar"),
+            content = content.child(div().flex().flex_1().justify_center().items_center().child(
+                div().text_color(text_muted).child(
+                    "Select a component from the sidebar. Middle-click or \
+                         Cmd-click opens it in a new tab.",
                 ),
-            );
+            ));
         }
 
         content
     }
 
-    /// Render the token editor panel (right sidebar).
-    fn render_token_editor(&self, cx: &Context<Self>) -> Div {
+    /// Render the workspace tab bar: one tab per open story.
+    fn render_workspace_tabs(&self, cx: &mut Context<Self>) -> Tabs {
+        let registry = cx.global::<StoryRegistry>();
+        let items: Vec<TabItem> = self
+            .tabs
+            .iter()
+            .map(|tab| {
+                let name = match tab.target {
+                    TabTarget::Story(idx) => registry.entries().get(idx).map(|e| e.name()),
+                    TabTarget::Primitive(idx) => {
+                        registry.primitive_docs().get(idx).map(|doc| doc.name())
+                    }
+                };
+                TabItem::new(name.unwrap_or("Unknown").to_string())
+            })
+            .collect();
+        let activate = cx.listener(|this, index: &usize, _window, cx| {
+            this.active_tab = *index;
+            cx.notify();
+        });
+        let close = cx.listener(|this, index: &usize, _window, cx| this.close_tab(*index, cx));
+
+        Tabs::new("workspace-tabs", cx)
+            .tabs(items)
+            .active_index(self.active_tab)
+            .show_panel(false)
+            .on_change(move |index, window, cx| activate(&index, window, cx))
+            .on_close(move |index, window, cx| close(&index, window, cx))
+    }
+
+    /// Render the active tab's viewport width buttons.
+    fn render_viewport_picker(&self, cx: &Context<Self>) -> Div {
         let theme = cx.theme();
-        let all_paths = theme::engine::all_token_paths();
+        let current = self
+            .active_tab()
+            .map(|tab| tab.viewport)
+            .unwrap_or_default();
 
-        let mut panel = div()
+        let mut picker = div().flex().flex_row().items_center().gap_1();
+        for viewport in StoryViewport::ALL {
+            picker = picker.child(
+                div()
+                    .id(SharedString::from(format!("viewport-{}", viewport.label())))
+                    .px_2()
+                    .py_0p5()
+                    .rounded_md()
+                    .text_xs()
+                    .cursor_pointer()
+                    .text_color(if viewport == current {
+                        theme.text.default
+                    } else {
+                        theme.text.muted
+                    })
+                    .bg(if viewport == current {
+                        theme.element.selected
+                    } else {
+                        theme.element.background
+                    })
+                    .hover(|s| s.bg(theme.element.hover))
+                    .on_mouse_down(MouseButton::Left, {
+                        cx.listener(move |this, _event, _window, cx| {
+                            this.set_viewport(viewport, cx);
+                        })
+                    })
+                    .child(viewport.label()),
+            );
+        }
+        picker
+    }
+
+    /// Render the release readiness dashboard: one row per component with a
+    /// badge per gate.
+    fn render_dashboard(&self, cx: &Context<Self>) -> Stateful<Div> {
+        let theme = cx.theme();
+        let ready = self
+            .dashboard
+            .iter()
+            .filter(|status| status.overall() == Badge::Green)
+            .count();
+
+        let mut dashboard = div()
+            .id("dashboard")
             .flex()
             .flex_col()
-            .w(px(280.0))
-            .flex_shrink_0()
-            .h_full()
-            .bg(theme.panel.background)
-            .border_l_1()
-            .border_color(theme.border.default);
+            .flex_1()
+            .overflow_y_scroll()
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .px_6()
+                    .py_3()
+                    .border_b_1()
+                    .border_color(theme.border.default)
+                    .child(
+                        div()
+                            .text_lg()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(theme.text.default)
+                            .child("Release Readiness"),
+                    )
+                    .child(div().text_xs().text_color(theme.text.muted).child(format!(
+                        "{} of {} components ready",
+                        ready,
+                        self.dashboard.len()
+                    ))),
+            );
+
+        let mut header = div()
+            .flex()
+            .flex_row()
+            .px_6()
+            .py_2()
+            .text_xs()
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(theme.text.muted)
+            .child(div().w(px(140.0)).child("COMPONENT"));
+        for column in ["CONTRACT", "STORIES", "ACCEPTANCE", "PERF", "DRIFT"] {
+            header = header.child(div().w(px(170.0)).child(column));
+        }
+        dashboard = dashboard.child(header);
+
+        for (row, status) in self.dashboard.iter().enumerate() {
+            let stories = GateStatus {
+                badge: status.stories.badge,
+                summary: format!("{}% ({})", status.story_coverage, status.stories.summary),
+            };
+            let gates = [
+                (Some(&status.contract), GateTarget::Story { metadata: true }),
+                (Some(&stories), GateTarget::Story { metadata: false }),
+                (Some(&status.acceptance), GateTarget::Report("readiness")),
+                (Some(&status.perf), GateTarget::Story { metadata: true }),
+                (status.drift.as_ref(), GateTarget::Report("diff-install")),
+            ];
+
+            let mut line = div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .px_6()
+                .py_1()
+                .border_t_1()
+                .border_color(theme.border.variant)
+                .child(
+                    div()
+                        .w(px(140.0))
+                        .text_sm()
+                        .text_color(theme.text.default)
+                        .child(status.component.clone()),
+                );
+            for (column, (gate, target)) in gates.into_iter().enumerate() {
+                line = line.child(self.render_gate_badge(
+                    ("dashboard-gate", row * gates.len() + column).into(),
+                    &status.component,
+                    gate,
+                    target,
+                    cx,
+                ));
+            }
+            dashboard = dashboard.child(line);
+        }
+
+        dashboard
+    }
+
+    /// A dashboard cell: a colored badge that opens the gate's story or
+    /// report, or a muted dash for gates that do not apply.
+    fn render_gate_badge(
+        &self,
+        id: ElementId,
+        component: &str,
+        gate: Option<&GateStatus>,
+        target: GateTarget,
+        cx: &Context<Self>,
+    ) -> Div {
+        let theme = cx.theme();
+        let cell = div().w(px(170.0)).pr_2();
+        let Some(gate) = gate else {
+            return cell.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.text.placeholder)
+                    .child("-"),
+            );
+        };
+        let colors = match gate.badge {
+            Badge::Green => theme.status.success.clone(),
+            Badge::Yellow => theme.status.warning.clone(),
+            Badge::Red => theme.status.error.triplet(),
+        };
+        let component = component.to_string();
+
+        cell.child(
+            div()
+                .id(id)
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_1()
+                .px_2()
+                .py(px(2.0))
+                .bg(colors.background)
+                .border_1()
+                .border_color(colors.border)
+                .rounded_md()
+                .cursor_pointer()
+                .hover(|s| s.border_color(colors.foreground))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, _event, _window, cx| {
+                        this.open_gate(&component, target, cx);
+                    }),
+                )
+                .child(
+                    div()
+                        .size_2()
+                        .flex_none()
+                        .rounded_full()
+                        .bg(colors.foreground),
+                )
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.text.default)
+                        .overflow_x_hidden()
+                        .child(gate.summary.clone()),
+                ),
+        )
+    }
+
+    /// Render the token editor panel.
+    fn render_token_editor(&self, cx: &Context<Self>) -> Div {
+        let theme = cx.theme();
+        let all_paths = theme::engine::all_token_paths();
+        let filter = self.token_filter.to_lowercase();
+        let paths: Vec<&str> = all_paths
+            .iter()
+            .copied()
+            .filter(|path| path.to_lowercase().contains(&filter))
+            .collect();
+
+        let mut panel = div().flex().flex_col().size_full();
 
         // Panel header
+        let summary = format!(
+            "{} tokens, {} edits",
+            all_paths.len(),
+            self.theme_patch.len()
+        );
         panel = panel.child(
             div()
                 .px_3()
@@ -459,38 +2154,138 @@ impl StudioApp {
                 )
                 .child(
                     div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .justify_between()
+                        .text_xs()
+                        .child(div().text_color(theme.text.placeholder).child(summary))
+                        .when(!self.token_undo.is_empty(), |row| {
+                            row.child(token_link("token-undo", "Undo", theme).on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.undo_token_edit(cx);
+                                }),
+                            ))
+                        })
+                        .when(!self.theme_patch.is_empty(), |row| {
+                            row.child(
+                                token_link("token-export-patch", "Export patch", theme)
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(|this, _event, _window, _cx| {
+                                            this.export_theme_patch();
+                                        }),
+                                    ),
+                            )
+                        }),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
                         .text_xs()
-                        .text_color(theme.text.placeholder)
-                        .child(format!("{} tokens", all_paths.len())),
+                        .child(div().text_color(theme.text.placeholder).child("Copy from:"))
+                        .child(
+                            token_link(
+                                "token-copy-source",
+                                self.token_copy_source
+                                    .clone()
+                                    .unwrap_or_else(|| "choose theme".to_string()),
+                                theme,
+                            )
+                            .on_mouse_down(
+                                MouseButton::Left,
+                                cx.listener(|this, _event, _window, cx| {
+                                    this.cycle_token_copy_source(cx);
+                                }),
+                            ),
+                        ),
                 ),
         );
 
+        // Token filter
+        let this = cx.weak_entity();
+        panel = panel.child(
+            div().px_2().py_2().child(
+                SearchInput::new("token-filter")
+                    .placeholder("Filter tokens")
+                    .full_width()
+                    .result_count(paths.len())
+                    .on_query(move |query, cx| {
+                        this.update(cx, |this, cx| {
+                            this.token_filter = query.to_string();
+                            this.token_scroll.scroll_to_top();
+                            cx.notify();
+                        })
+                        .ok();
+                    }),
+            ),
+        );
+
         // Token list
-        let mut token_list = div()
-            .id("token-list")
-            .flex()
-            .flex_col()
-            .py_1()
-            .flex_1()
-            .overflow_y_scroll();
+        let mut token_list = ScrollArea::new("token-list").track_scroll(&self.token_scroll);
 
         // Group tokens by category (first segment before '.')
         let mut current_category = "";
 
-        for path in &all_paths {
+        for path in &paths {
             let category = path.split('.').next().unwrap_or("");
 
             if category != current_category {
                 current_category = category;
+                let copy = category.to_string();
+                let reset = category.to_string();
+                let swap = category.to_string();
                 token_list = token_list.child(
                     div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_1()
                         .px_3()
                         .pt_3()
                         .pb_1()
                         .text_xs()
-                        .font_weight(FontWeight::SEMIBOLD)
-                        .text_color(theme.text.muted)
-                        .child(SharedString::from(category.to_string())),
+                        .child(
+                            div()
+                                .flex_1()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(theme.text.muted)
+                                .child(SharedString::from(category.to_string())),
+                        )
+                        .when(self.token_copy_source.is_some(), |row| {
+                            row.child(
+                                token_link(format!("token-copy-{category}"), "Copy", theme)
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |this, _event, _window, cx| {
+                                            this.copy_token_category(&copy, cx);
+                                        }),
+                                    ),
+                            )
+                        })
+                        .child(
+                            token_link(format!("token-reset-{category}"), "Reset", theme)
+                                .on_mouse_down(
+                                    MouseButton::Left,
+                                    cx.listener(move |this, _event, _window, cx| {
+                                        this.reset_token_category(&reset, cx);
+                                    }),
+                                ),
+                        )
+                        .when(!foreground_background_pairs(category).is_empty(), |row| {
+                            row.child(
+                                token_link(format!("token-swap-{category}"), "Swap", theme)
+                                    .on_mouse_down(
+                                        MouseButton::Left,
+                                        cx.listener(move |this, _event, _window, cx| {
+                                            this.swap_token_pairs(&swap, cx);
+                                        }),
+                                    ),
+                            )
+                        }),
                 );
             }
 
@@ -498,7 +2293,7 @@ impl StudioApp {
             let is_editing = self.editing_token_path.as_deref() == Some(path_str);
 
             // Get the current color value for this token for the color swatch
-            let color_value = get_token_color(theme, path_str);
+            let color_value = get_token_by_path(theme.unfiltered_tokens(), path_str);
 
             let label: SharedString = path_str
                 .split('.')
@@ -552,15 +2347,10 @@ impl StudioApp {
                     cx.listener(move |this, _event, _window, cx| {
                         this.editing_token_path = Some(path_owned.clone());
                         // Pre-fill with current hex value
-                        if let Some(color) = get_token_color(cx.theme(), &path_owned) {
-                            let rgba: Rgba = color.into();
-                            this.editing_token_value = format!(
-                                "#{:02x}{:02x}{:02x}{:02x}",
-                                (rgba.r * 255.0) as u8,
-                                (rgba.g * 255.0) as u8,
-                                (rgba.b * 255.0) as u8,
-                                (rgba.a * 255.0) as u8,
-                            );
+                        if let Some(color) =
+                            get_token_by_path(cx.theme().unfiltered_tokens(), &path_owned)
+                        {
+                            this.editing_token_value = color_hex(color);
                         }
                         cx.notify();
                     })
@@ -638,11 +2428,435 @@ impl StudioApp {
             }
         }
 
-        panel = panel.child(token_list);
-        panel
+        panel = panel.child(div().flex_1().min_h_0().py_1().child(token_list));
+        panel
+    }
+
+    /// Render the metadata panel's prop rows. Hovering a row shows the prop's
+    /// documentation from the contract; clicking copies a snippet setting it.
+    fn render_prop_rows(
+        &self,
+        contract: &components::ComponentContract,
+        cx: &Context<Self>,
+    ) -> Stateful<Div> {
+        let theme = cx.theme();
+        let mut rows = div().id("metadata-props").flex().flex_col().w_full();
+
+        for (idx, prop) in contract.props.iter().enumerate() {
+            let snippet: SharedString = contract.prop_snippet(prop).into();
+            let mut doc = vec![prop.description.clone()];
+            doc.push(format!(
+                "Default: {}",
+                prop.default_value.as_deref().unwrap_or("none")
+            ));
+            doc.push(format!(
+                "Required: {}",
+                if prop.required { "yes" } else { "no" }
+            ));
+            if let Some(example) = contract.prop_example(prop) {
+                doc.push(format!("Example: {}", example));
+            }
+            doc.push(format!("Click to copy {}", snippet));
+            let doc: SharedString = doc.join("\n").into();
+            let required_tag = if prop.required { " *" } else { "" };
+
+            rows = rows.child(
+                div()
+                    .id(("metadata-prop", idx))
+                    .flex()
+                    .flex_col()
+                    .gap_0p5()
+                    .py_1()
+                    .px_1()
+                    .rounded_sm()
+                    .when(idx > 0, |el| {
+                        el.border_t_1().border_color(theme.border.variant)
+                    })
+                    .cursor_pointer()
+                    .hover(|s| s.bg(theme.ghost_element.hover))
+                    .tooltip(move |window, cx| TooltipView::build(doc.clone(), window, cx))
+                    .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                        log::info!("Copied snippet: {}", snippet);
+                        cx.write_to_clipboard(ClipboardItem::new_string(snippet.to_string()));
+                    })
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.text.muted)
+                            .child(format!("{}{}", prop.name, required_tag)),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.text.default)
+                            .child(prop.type_name.clone()),
+                    ),
+            );
+        }
+
+        rows
+    }
+
+    /// Render the metadata panel's render-time column: the contract's render
+    /// budget and PerfEvidence beside the timings recorded for this story in
+    /// the studio.
+    fn render_perf_history(
+        &self,
+        story_index: usize,
+        contract: &components::ComponentContract,
+        cx: &Context<Self>,
+    ) -> Div {
+        let theme = cx.theme();
+        let evidence = contract
+            .perf_evidence
+            .as_ref()
+            .and_then(|evidence| evidence.render_time_ms);
+        let samples = self.perf.story(story_index);
+
+        let budget = contract
+            .perf_budget
+            .as_ref()
+            .and_then(|budget| budget.max_render_ms);
+
+        let mut bars = Vec::new();
+        if let Some(ms) = budget {
+            bars.push(Bar::new("Budget", ms));
+        }
+        if let Some(ms) = evidence {
+            bars.push(Bar::new("Evidence", ms));
+        }
+        if let Some(samples) = samples {
+            let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+            let max = samples.iter().copied().fold(0.0, f64::max);
+            let avg = samples.iter().sum::<f64>() / samples.len() as f64;
+            bars.extend([
+                Bar::new("Min", min),
+                Bar::new("Avg", avg),
+                Bar::new("Max", max),
+            ]);
+        }
+
+        let mut column = div().flex().flex_col().gap_1().flex_none().child(
+            div()
+                .text_xs()
+                .font_weight(FontWeight::SEMIBOLD)
+                .text_color(theme.text.muted)
+                .child("Render Time"),
+        );
+        if bars.is_empty() {
+            return column.child(
+                div()
+                    .text_xs()
+                    .text_color(theme.text.muted)
+                    .child("No samples yet"),
+            );
+        }
+        column = column.child(
+            BarChart::new("metadata-perf-bars")
+                .unit("ms")
+                .height(px(60.0))
+                .bars(bars),
+        );
+        if let Some(samples) = samples {
+            column = column.child(Sparkline::new(
+                "metadata-perf-sparkline",
+                samples.iter().copied(),
+            ));
+        }
+        column
+    }
+
+    /// Render the status bar: active theme and selected story on the left,
+    /// frame rate and the last token edit on the right.
+    fn render_status_bar(&self, cx: &Context<Self>) -> StatusBar {
+        let registry = cx.global::<StoryRegistry>();
+        let view_name = if self.show_dashboard {
+            Some("Release dashboard".to_string())
+        } else if let Some(idx) = self.selected_story_index() {
+            registry
+                .entries()
+                .get(idx)
+                .map(|entry| entry.name().to_string())
+        } else {
+            self.selected_primitive_index()
+                .and_then(|idx| registry.primitive_docs().get(idx))
+                .map(|doc| doc.name().to_string())
+        };
+
+        let locale = Locale::global(cx);
+        let last_render = self.perf.recent.back().copied();
+        let (fps_indicator, fps_tooltip) = match last_render {
+            Some(ms) => (
+                if ms > FRAME_BUDGET_MS {
+                    StatusIndicator::Warning
+                } else {
+                    StatusIndicator::Success
+                },
+                format!(
+                    "Last story render {}ms (budget {}ms). Click to toggle the perf HUD.",
+                    format_decimal(ms, 2, locale),
+                    format_decimal(FRAME_BUDGET_MS, 1, locale)
+                ),
+            ),
+            None => (
+                StatusIndicator::Info,
+                "Click to toggle the perf HUD.".to_string(),
+            ),
+        };
+
+        let this = cx.weak_entity();
+        let mut bar = StatusBar::new("studio-status-bar").left(
+            StatusSegment::new("theme", cx.theme().name.clone())
+                .tooltip("Click to switch theme")
+                .on_click({
+                    let this = this.clone();
+                    move |window, cx| {
+                        this.update(cx, |this, cx| this.toggle_theme(window, cx))
+                            .ok();
+                    }
+                }),
+        );
+        if let Some(name) = view_name {
+            bar = bar.left(StatusSegment::new("story", name));
+        }
+        bar = bar.right(
+            StatusSegment::new("fps", format!("{} fps", self.perf.fps()))
+                .indicator(fps_indicator)
+                .tooltip(fps_tooltip)
+                .on_click({
+                    let this = this.clone();
+                    move |_window, cx| {
+                        this.update(cx, |this, cx| {
+                            this.show_perf_hud = !this.show_perf_hud;
+                            cx.notify();
+                        })
+                        .ok();
+                    }
+                }),
+        );
+        if let Some(result) = &self.last_apply {
+            let (indicator, message) = match result {
+                Ok(message) => (StatusIndicator::Success, message),
+                Err(message) => (StatusIndicator::Error, message),
+            };
+            bar = bar.right(
+                StatusSegment::new("last-apply", message.clone())
+                    .indicator(indicator)
+                    .tooltip("Click to open the token editor")
+                    .on_click(move |_window, cx| {
+                        this.update(cx, |this, cx| {
+                            this.update_layout(cx, |layout| layout.show(PanelId::Tokens));
+                        })
+                        .ok();
+                    }),
+            );
+        }
+        bar
+    }
+
+    /// Render the floating performance HUD over the story content.
+    fn render_perf_hud(&self, cx: &Context<Self>) -> Div {
+        let theme = cx.theme();
+        let samples: Vec<f64> = self.perf.recent.iter().copied().collect();
+        let last = samples.last().copied().unwrap_or_default();
+        let avg = if samples.is_empty() {
+            0.0
+        } else {
+            samples.iter().sum::<f64>() / samples.len() as f64
+        };
+        let locale = Locale::global(cx);
+        let ms = |value: f64| format_decimal(value, 2, locale);
+
+        div()
+            .absolute()
+            .top_2()
+            .right_2()
+            .flex()
+            .flex_col()
+            .gap_1()
+            .p_2()
+            .bg(theme.surface.elevated_surface)
+            .border_1()
+            .border_color(theme.border.default)
+            .rounded_md()
+            .shadow_md()
+            .child(
+                div()
+                    .text_xs()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.text.muted)
+                    .child(format!("Story render: {}ms (avg {}ms)", ms(last), ms(avg))),
+            )
+            .child(
+                Sparkline::new("perf-hud-sparkline", samples)
+                    .threshold(FRAME_BUDGET_MS)
+                    .size(px(200.0), px(36.0)),
+            )
+    }
+
+    /// Render the inspect overlay for the component under the pointer: its
+    /// bounds, padding and content boxes, and a label with its size, padding,
+    /// and color tokens.
+    fn render_inspect_overlay(&self, node: &InspectNode, cx: &Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let outline_color = theme.border.focused;
+        let padding_color = theme.status.success.foreground.opacity(0.25);
+        let content_color = theme.status.info.foreground.opacity(0.2);
+        let bounds = node.bounds;
+        let content = node.content_bounds();
+
+        let boxes = canvas(
+            |_, _, _| {},
+            move |_, _, window, _| {
+                // Padding as four strips around the content box.
+                for strip in ring(bounds, content) {
+                    window.paint_quad(fill(strip, padding_color));
+                }
+                window.paint_quad(fill(content, content_color));
+                window.paint_quad(outline(bounds, outline_color, BorderStyle::default()));
+            },
+        )
+        .absolute()
+        .size_full();
+
+        let padding = node.padding;
+        let label = div()
+            .absolute()
+            .left(bounds.left())
+            .top(bounds.bottom() + px(4.0))
+            .flex()
+            .flex_col()
+            .gap_0p5()
+            .p_2()
+            .bg(theme.surface.elevated_surface)
+            .border_1()
+            .border_color(theme.border.default)
+            .rounded_md()
+            .shadow_md()
+            .text_xs()
+            .text_color(theme.text.default)
+            .child(
+                div()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(format!("{} #{}", node.component, node.id)),
+            )
+            .child(format!(
+                "{:.0} × {:.0}",
+                f32::from(bounds.size.width),
+                f32::from(bounds.size.height)
+            ))
+            .child(format!(
+                "padding {:.0} {:.0} {:.0} {:.0}",
+                f32::from(padding.top),
+                f32::from(padding.right),
+                f32::from(padding.bottom),
+                f32::from(padding.left)
+            ))
+            .children(
+                node.color_tokens
+                    .iter()
+                    .map(|path| div().text_color(theme.text.muted).child(path.clone())),
+            );
+
+        deferred(
+            div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .child(boxes)
+                .child(label),
+        )
+        .with_priority(2)
+    }
+
+    /// Render the spacing overlay for the component under the pointer: its
+    /// margin and padding boxes, and a label with both, flagging sides that
+    /// are off the [`SPACING_GRID_MINOR`] baseline grid.
+    fn render_spacing_overlay(&self, node: &InspectNode, cx: &Context<Self>) -> impl IntoElement {
+        let theme = cx.theme();
+        let outline_color = theme.border.focused;
+        let margin_color = theme.status.warning.foreground.opacity(0.3);
+        let padding_color = theme.status.success.foreground.opacity(0.25);
+        let bounds = node.bounds;
+        let margin = node.margin_bounds();
+        let content = node.content_bounds();
+
+        let boxes = canvas(
+            |_, _, _| {},
+            move |_, _, window, _| {
+                for strip in ring(margin, bounds) {
+                    window.paint_quad(fill(strip, margin_color));
+                }
+                for strip in ring(bounds, content) {
+                    window.paint_quad(fill(strip, padding_color));
+                }
+                window.paint_quad(outline(bounds, outline_color, BorderStyle::default()));
+            },
+        )
+        .absolute()
+        .size_full();
+
+        let sides = |edges: Edges<Pixels>| {
+            let sides = [edges.top, edges.right, edges.bottom, edges.left];
+            let values = sides
+                .iter()
+                .map(|side| format!("{:.0}", f32::from(*side)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let off_grid = sides
+                .iter()
+                .any(|side| f32::from(*side) % SPACING_GRID_MINOR != 0.0);
+            if off_grid {
+                format!("{values} (off grid)")
+            } else {
+                values
+            }
+        };
+        let label = div()
+            .absolute()
+            .left(margin.left())
+            .top(margin.bottom() + px(4.0))
+            .flex()
+            .flex_col()
+            .gap_0p5()
+            .p_2()
+            .bg(theme.surface.elevated_surface)
+            .border_1()
+            .border_color(theme.border.default)
+            .rounded_md()
+            .shadow_md()
+            .text_xs()
+            .text_color(theme.text.default)
+            .child(
+                div()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .child(format!("{} #{}", node.component, node.id)),
+            )
+            .child(
+                div()
+                    .text_color(theme.status.warning.foreground)
+                    .child(format!("margin {}", sides(node.margin))),
+            )
+            .child(
+                div()
+                    .text_color(theme.status.success.foreground)
+                    .child(format!("padding {}", sides(node.padding))),
+            );
+
+        deferred(
+            div()
+                .absolute()
+                .top_0()
+                .left_0()
+                .size_full()
+                .child(boxes)
+                .child(label),
+        )
+        .with_priority(2)
     }
 
-    /// Render the component metadata panel (below content or in a sidebar).
+    /// Render the component metadata panel.
     fn render_metadata_panel(&self, cx: &Context<Self>) -> Stateful<Div> {
         let theme = cx.theme();
         let registry = cx.global::<StoryRegistry>();
@@ -650,17 +2864,13 @@ impl StudioApp {
         let mut panel = div()
             .flex()
             .flex_col()
-            .w_full()
+            .size_full()
             .id("metadata-panel")
-            .max_h(px(300.0))
-            .overflow_y_scroll()
-            .bg(theme.panel.background)
-            .border_t_1()
-            .border_color(theme.border.default);
+            .overflow_y_scroll();
 
-        if let Some(idx) = self.selected_story_index {
+        if let Some(idx) = self.selected_story_index() {
             if let Some(entry) = registry.entries().get(idx) {
-                let contract = entry.contract();
+                let contract = entry.contract().localized(StudioSettings::get(cx).locale());
 
                 // Metadata header
                 panel = panel.child(
@@ -681,89 +2891,76 @@ impl StudioApp {
                         ),
                 );
 
-                let mut info_row = div().flex().flex_row().gap_6().px_4().py_3();
-
-                // Props column
-                let mut props_col = div().flex().flex_col().gap_1().flex_1().child(
-                    div()
-                        .text_xs()
-                        .font_weight(FontWeight::SEMIBOLD)
-                        .text_color(theme.text.muted)
-                        .child("Props"),
-                );
-                for prop in &contract.props {
-                    let required_tag = if prop.required { " *" } else { "" };
-                    props_col = props_col.child(
-                        div()
-                            .text_xs()
-                            .text_color(theme.text.default)
-                            .child(format!("{}: {}{}", prop.name, prop.type_name, required_tag)),
+                let overview = DescriptionList::new("metadata-overview")
+                    .term_width(px(80.0))
+                    .item(DescriptionItem::new(
+                        "States",
+                        contract
+                            .states
+                            .iter()
+                            .map(|state| format!("{:?}", state))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    ))
+                    .when(!contract.variants.is_empty(), |list| {
+                        list.item(DescriptionItem::new(
+                            "Variants",
+                            contract.variants.join(", "),
+                        ))
+                    })
+                    .items(
+                        [
+                            ("Focus", &contract.interaction_checklist.focus_behavior),
+                            ("Keyboard", &contract.interaction_checklist.keyboard_model),
+                        ]
+                        .into_iter()
+                        .filter_map(|(term, text)| {
+                            text.as_ref()
+                                .map(|text| DescriptionItem::new(term, text.clone()))
+                        }),
+                    )
+                    .items(
+                        contract
+                            .required_files
+                            .iter()
+                            .map(|file| DescriptionItem::new("File", file.clone()).copyable()),
                     );
-                }
-                info_row = info_row.child(props_col);
 
-                // States column
-                let mut states_col = div().flex().flex_col().gap_1().flex_1().child(
-                    div()
-                        .text_xs()
-                        .font_weight(FontWeight::SEMIBOLD)
-                        .text_color(theme.text.muted)
-                        .child("States"),
-                );
-                for state in &contract.states {
-                    states_col = states_col.child(
-                        div()
-                            .text_xs()
-                            .text_color(theme.text.default)
-                            .child(format!("{:?}", state)),
-                    );
-                }
-                info_row = info_row.child(states_col);
+                let props = self.render_prop_rows(&contract, cx);
 
-                // Interaction checklist column
-                let mut interaction_col = div().flex().flex_col().gap_1().flex_1().child(
-                    div()
-                        .text_xs()
-                        .font_weight(FontWeight::SEMIBOLD)
-                        .text_color(theme.text.muted)
-                        .child("Interaction"),
-                );
-                let ic = &contract.interaction_checklist;
-                if let Some(ref focus) = ic.focus_behavior {
-                    interaction_col = interaction_col.child(
-                        div()
-                            .text_xs()
-                            .text_color(theme.text.default)
-                            .child(format!("Focus: {}", truncate_str(focus, 60))),
-                    );
-                }
-                if let Some(ref kb) = ic.keyboard_model {
-                    interaction_col = interaction_col.child(
-                        div()
-                            .text_xs()
-                            .text_color(theme.text.default)
-                            .child(format!("Keyboard: {}", truncate_str(kb, 60))),
-                    );
-                }
-                info_row = info_row.child(interaction_col);
+                let tokens = DescriptionList::new("metadata-tokens")
+                    .layout(DescriptionLayout::Stacked)
+                    .items(contract.token_dependencies.iter().map(|dep| {
+                        DescriptionItem::new(dep.usage.clone(), dep.path.clone()).copyable()
+                    }));
 
-                // Token deps column
-                let mut tokens_col = div().flex().flex_col().gap_1().flex_1().child(
+                let column = |title: &'static str, list: AnyElement| {
                     div()
-                        .text_xs()
-                        .font_weight(FontWeight::SEMIBOLD)
-                        .text_color(theme.text.muted)
-                        .child("Token Dependencies"),
-                );
-                for dep in &contract.token_dependencies {
-                    tokens_col = tokens_col.child(
-                        div()
-                            .text_xs()
-                            .text_color(theme.text.default)
-                            .child(dep.path.clone()),
-                    );
-                }
-                info_row = info_row.child(tokens_col);
+                        .flex()
+                        .flex_col()
+                        .gap_1()
+                        .flex_1()
+                        .min_w_0()
+                        .child(
+                            div()
+                                .text_xs()
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(theme.text.muted)
+                                .child(title),
+                        )
+                        .child(list)
+                };
+
+                let info_row = div()
+                    .flex()
+                    .flex_row()
+                    .gap_6()
+                    .px_4()
+                    .py_3()
+                    .child(column("Overview", overview.into_any_element()))
+                    .child(column("Props", props.into_any_element()))
+                    .child(column("Token Dependencies", tokens.into_any_element()))
+                    .child(self.render_perf_history(idx, &contract, cx));
 
                 panel = panel.child(info_row);
             }
@@ -780,10 +2977,293 @@ impl StudioApp {
 
         panel
     }
+
+    /// Render `panel`'s content, sized by its dock.
+    fn render_panel(&self, panel: PanelId, cx: &Context<Self>) -> AnyElement {
+        match panel {
+            PanelId::Stories => self.render_sidebar(cx).into_any_element(),
+            PanelId::Tokens => self.render_token_editor(cx).into_any_element(),
+            PanelId::Metadata => self.render_metadata_panel(cx).into_any_element(),
+            PanelId::Notifications => div()
+                .size_full()
+                .p_2()
+                .child(NotificationCenter::new("studio-notifications"))
+                .into_any_element(),
+        }
+    }
+
+    /// Render the dock at `position`: its tabs above the active panel, or
+    /// only the tabs while collapsed. Dropping a dragged tab on another tab
+    /// moves the panel before it; dropping it elsewhere on the dock moves it
+    /// last. An empty dock only appears, as a drop strip, during a drag.
+    fn render_dock(&self, position: DockPosition, cx: &Context<Self>) -> Option<AnyElement> {
+        let dock = self.layout.dock(position);
+        if dock.is_empty() && !cx.has_active_drag() {
+            return None;
+        }
+        let theme = cx.theme();
+        let hover_bg = theme.ghost_element.hover;
+        let drop_bg = theme.element.selected;
+        let active = dock.active();
+        let expanded = !dock.is_collapsed() && !dock.is_empty();
+        // Collapsed side docks stack their tabs as initials in a narrow strip.
+        let strip = !expanded && position != DockPosition::Bottom;
+
+        let mut tabs = div()
+            .flex()
+            .gap_1()
+            .p_1()
+            .when(strip, |el| el.flex_col().items_center())
+            .when(!strip, |el| el.flex_row().items_center().flex_1().min_w_0());
+        for panel in dock.open_panels() {
+            let selected = expanded && active == Some(panel);
+            let label = if strip {
+                &panel.label()[..1]
+            } else {
+                panel.label()
+            };
+            tabs = tabs.child(
+                div()
+                    .id(SharedString::from(format!("dock-tab-{}", panel.key())))
+                    .px_2()
+                    .py_0p5()
+                    .rounded_sm()
+                    .text_xs()
+                    .cursor_pointer()
+                    .text_color(if selected {
+                        theme.text.default
+                    } else {
+                        theme.text.muted
+                    })
+                    .when(selected, |el| el.bg(theme.tab.active_background))
+                    .hover(move |s| s.bg(hover_bg))
+                    .child(label)
+                    .on_click(cx.listener(move |this, _event, _window, cx| {
+                        this.update_layout(cx, |layout| layout.show(panel));
+                    }))
+                    .on_drag(DraggedPanel { panel }, |dragged, _offset, _window, cx| {
+                        cx.new(|_| dragged.clone())
+                    })
+                    .drag_over::<DraggedPanel>(move |style, _, _, _| style.bg(drop_bg))
+                    .on_drop(
+                        cx.listener(move |this, dragged: &DraggedPanel, _window, cx| {
+                            this.update_layout(cx, |layout| {
+                                layout.move_panel(dragged.panel, position, Some(panel));
+                            });
+                        }),
+                    ),
+            );
+        }
+
+        let control = |suffix: &str, glyph: &'static str| {
+            div()
+                .id(SharedString::from(format!(
+                    "dock-{}-{suffix}",
+                    position.key()
+                )))
+                .flex()
+                .items_center()
+                .justify_center()
+                .size(px(20.0))
+                .rounded_sm()
+                .text_xs()
+                .text_color(theme.text.muted)
+                .cursor_pointer()
+                .hover(move |s| s.bg(hover_bg))
+                .child(glyph)
+        };
+        // Arrows point the way the dock will move.
+        let collapse_glyph = match (position, expanded) {
+            (DockPosition::Left, true) | (DockPosition::Right, false) => "‹",
+            (DockPosition::Left, false) | (DockPosition::Right, true) => "›",
+            (DockPosition::Bottom, true) => "⌄",
+            (DockPosition::Bottom, false) => "⌃",
+        };
+        let mut controls = div()
+            .flex()
+            .items_center()
+            .gap_1()
+            .p_1()
+            .when(strip, |el| el.flex_col());
+        if let Some(panel) = active.filter(|panel| expanded && panel.closable()) {
+            controls = controls.child(control("close", "×").on_click(cx.listener(
+                move |this, _event, _window, cx| {
+                    this.update_layout(cx, |layout| layout.close(panel));
+                },
+            )));
+        }
+        if !dock.is_empty() {
+            controls = controls.child(control("collapse", collapse_glyph).on_click(cx.listener(
+                move |this, _event, _window, cx| {
+                    this.update_layout(cx, |layout| layout.toggle_collapsed(position));
+                },
+            )));
+        }
+
+        let header = div()
+            .flex()
+            .flex_shrink_0()
+            .when(strip, |el| el.flex_col().justify_between().h_full())
+            .when(!strip, |el| {
+                el.flex_row()
+                    .items_center()
+                    .border_b_1()
+                    .border_color(theme.border.default)
+            })
+            .bg(theme.tab.bar_background)
+            .child(tabs)
+            .child(controls);
+
+        let mut container = div()
+            .id(SharedString::from(format!("dock-{}", position.key())))
+            .flex()
+            .flex_col()
+            .flex_shrink_0()
+            .overflow_hidden()
+            .bg(theme.panel.background)
+            .border_color(theme.border.default)
+            .drag_over::<DraggedPanel>(move |style, _, _, _| style.bg(drop_bg))
+            .on_drop(
+                cx.listener(move |this, dragged: &DraggedPanel, _window, cx| {
+                    this.update_layout(cx, |layout| {
+                        layout.move_panel(dragged.panel, position, None)
+                    });
+                }),
+            )
+            .child(header);
+        // An expanded dock fills its SplitPane pane, whose divider is its edge.
+        container = match (position, expanded) {
+            (_, true) => container.size_full(),
+            (DockPosition::Left, false) => {
+                container.h_full().w(px(COLLAPSED_DOCK_SIZE)).border_r_1()
+            }
+            (DockPosition::Right, false) => {
+                container.h_full().w(px(COLLAPSED_DOCK_SIZE)).border_l_1()
+            }
+            (DockPosition::Bottom, false) => {
+                container.w_full().h(px(COLLAPSED_DOCK_SIZE)).border_t_1()
+            }
+        };
+        if let Some(panel) = active.filter(|_| expanded) {
+            container = container.child(
+                div()
+                    .flex_1()
+                    .min_h_0()
+                    .relative()
+                    .overflow_hidden()
+                    .child(self.render_panel(panel, cx))
+                    .children(
+                        TourStep::for_panel(panel)
+                            .and_then(|step| self.render_tour_callout(step, cx)),
+                    ),
+            );
+        }
+
+        Some(container.into_any_element())
+    }
+
+    /// Lay the dock at `position` out beside `main`. An expanded dock shares
+    /// a SplitPane with it, so dragging the divider resizes the dock and
+    /// saves its size; a collapsed dock, or an empty one shown as a drop
+    /// strip, keeps its fixed thickness.
+    fn dock_beside(
+        &self,
+        position: DockPosition,
+        main: impl IntoElement,
+        cx: &Context<Self>,
+    ) -> AnyElement {
+        let Some(dock) = self.render_dock(position, cx) else {
+            return main.into_any_element();
+        };
+        let expanded = {
+            let dock = self.layout.dock(position);
+            !dock.is_collapsed() && !dock.is_empty()
+        };
+        if !expanded {
+            let main = div()
+                .flex_1()
+                .min_w_0()
+                .min_h_0()
+                .overflow_hidden()
+                .child(main);
+            return div()
+                .flex()
+                .size_full()
+                .map(|el| match position {
+                    DockPosition::Left => el.flex_row().child(dock).child(main),
+                    DockPosition::Right => el.flex_row().child(main).child(dock),
+                    DockPosition::Bottom => el.flex_col().child(main).child(dock),
+                })
+                .into_any_element();
+        }
+
+        let dock_limits = (px(MIN_DOCK_SIZE), px(f32::INFINITY));
+        let main_limits = (px(MIN_CONTENT_SIZE), px(f32::INFINITY));
+        let split = SplitPane::new(SharedString::from(format!("dock-split-{}", position.key())))
+            .default_size(px(self.layout.size(position)))
+            .on_resize(cx.listener(move |this, size: &Pixels, _window, cx| {
+                // Drags report every pointer move; only save real changes.
+                if this.layout.resize(position, f32::from(*size)) {
+                    this.layout.save();
+                    cx.notify();
+                }
+            }));
+        let split = match position {
+            DockPosition::Left => split
+                .first(dock)
+                .second(main)
+                .first_limits(dock_limits.0, dock_limits.1)
+                .second_limits(main_limits.0, main_limits.1),
+            DockPosition::Right | DockPosition::Bottom => split
+                .first(main)
+                .second(dock)
+                .anchor(Pane::Second)
+                .first_limits(main_limits.0, main_limits.1)
+                .second_limits(dock_limits.0, dock_limits.1),
+        };
+        split
+            .when(position == DockPosition::Bottom, |split| {
+                split.orientation(Orientation::Vertical)
+            })
+            .into_any_element()
+    }
 }
 
 impl Render for StudioApp {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // Resolve the hovered component from the bounds recorded last frame,
+        // then drop them so components re-record (or vanish) as this frame lays out.
+        let hovered = self
+            .inspect_pointer
+            .and_then(|pointer| cx.global::<InspectTree>().hit_test(pointer).cloned());
+        cx.global_mut::<InspectTree>().clear();
+        let inspected = hovered.clone().filter(|_| self.inspect_mode);
+        let spaced = hovered.filter(|_| self.show_spacing_overlay);
+
+        // Time the story content so the perf HUD reflects this frame.
+        let render_started = Instant::now();
+        let content = self.render_content(window, cx);
+        if let Some(idx) = self.selected_story_index().filter(|_| !self.show_dashboard) {
+            self.perf.record(idx, render_started.elapsed());
+        }
+        self.perf.record_frame(render_started);
+
+        let center = div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .relative()
+            .overflow_hidden()
+            // Story content
+            .child(content)
+            // Perf HUD overlay (conditionally shown)
+            .when(self.show_perf_hud, |this| {
+                this.child(self.render_perf_hud(cx))
+            });
+        let center = self.dock_beside(DockPosition::Bottom, center, cx);
+        let center = self.dock_beside(DockPosition::Right, center, cx);
+        let main_area = self.dock_beside(DockPosition::Left, center, cx);
+
         let theme = cx.theme();
 
         div()
@@ -791,36 +3271,85 @@ impl Render for StudioApp {
             .flex_col()
             .size_full()
             .bg(theme.surface.background)
-            // Top toolbar
-            .child(self.render_toolbar(cx))
-            // Main area: sidebar + content + optional token editor
+            .on_mouse_move(cx.listener(|this, event: &MouseMoveEvent, _window, cx| {
+                if this.inspect_mode || this.show_spacing_overlay {
+                    this.inspect_pointer = Some(event.position);
+                    cx.notify();
+                }
+            }))
+            // Menu and keyboard commands; the toolbar buttons do the same.
+            .on_action(cx.listener(|this, _: &ToggleTheme, window, cx| {
+                this.toggle_theme(window, cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleDashboard, _window, cx| {
+                this.toggle_dashboard(cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleInspect, _window, cx| {
+                this.toggle_inspect_mode(cx);
+            }))
+            .on_action(cx.listener(|this, _: &TogglePerfHud, _window, cx| {
+                this.show_perf_hud = !this.show_perf_hud;
+                cx.notify();
+            }))
+            .on_action(cx.listener(|this, _: &ToggleSpacingOverlay, _window, cx| {
+                this.toggle_spacing_overlay(cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleTokensPanel, _window, cx| {
+                this.update_layout(cx, |layout| layout.toggle(PanelId::Tokens));
+            }))
+            .on_action(cx.listener(|this, _: &ToggleMetadataPanel, _window, cx| {
+                this.update_layout(cx, |layout| layout.toggle(PanelId::Metadata));
+            }))
+            .on_action(cx.listener(|this, _: &NextStory, _window, cx| {
+                this.step_story(1, cx);
+            }))
+            .on_action(cx.listener(|this, _: &PreviousStory, _window, cx| {
+                this.step_story(-1, cx);
+            }))
+            .on_action(cx.listener(|this, _: &CopyLink, _window, cx| {
+                this.copy_link(cx);
+            }))
+            .on_action(cx.listener(|this, _: &ToggleShortcuts, window, cx| {
+                // `?` belongs to a focused text field; let it be typed.
+                if is_typing(window) {
+                    cx.propagate();
+                    return;
+                }
+                this.toggle_shortcuts(window, cx);
+            }))
+            // Top toolbar, with its tour callout
+            .child(
+                div()
+                    .relative()
+                    .child(self.render_toolbar(cx))
+                    .children(self.render_tour_callout(TourStep::Toolbar, cx)),
+            )
+            // Main area: the story content, with the bottom dock below it, the
+            // right dock beside both, and the left dock beside all three
             .child(
                 div()
                     .flex()
-                    .flex_row()
                     .flex_1()
+                    .min_h_0()
                     .overflow_hidden()
-                    // Left sidebar: component list
-                    .child(self.render_sidebar(cx))
-                    // Center: content area (story + optional metadata below)
-                    .child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .flex_1()
-                            .overflow_hidden()
-                            // Story content
-                            .child(self.render_content(window, cx))
-                            // Metadata panel (conditionally shown)
-                            .when(self.show_metadata, |this| {
-                                this.child(self.render_metadata_panel(cx))
-                            }),
-                    )
-                    // Right sidebar: token editor (conditionally shown)
-                    .when(self.show_token_editor, |this| {
-                        this.child(self.render_token_editor(cx))
-                    }),
+                    .child(main_area),
             )
+            // Bottom status bar
+            .child(self.render_status_bar(cx))
+            // Inspect overlay for the hovered component
+            .when_some(inspected, |this, node| {
+                this.child(self.render_inspect_overlay(&node, cx))
+            })
+            // Spacing overlay for the hovered component
+            .when_some(spaced, |this, node| {
+                this.child(self.render_spacing_overlay(&node, cx))
+            })
+            // Live toasts from ToastManager::show
+            .child(ToastLayer::new("studio-toasts"))
+            // Keyboard shortcut cheat-sheet
+            .children(self.render_shortcuts_overlay(cx))
+            // Offer to restore token edits a crashed session left behind
+            .children(self.render_recovery_prompt(window, cx))
     }
 }
 
@@ -828,121 +3357,258 @@ impl Render for StudioApp {
 // Helpers
 // ---------------------------------------------------------------------------
 
+/// Small text link in the token editor, e.g. "Undo" or a category's "Reset".
+fn token_link(
+    id: impl Into<SharedString>,
+    label: impl Into<SharedString>,
+    theme: &Theme,
+) -> Stateful<Div> {
+    div()
+        .id(ElementId::Name(id.into()))
+        .text_color(theme.text.default)
+        .px_2()
+        .py(px(2.0))
+        .rounded_sm()
+        .cursor_pointer()
+        .hover(|s| s.bg(theme.ghost_element.hover))
+        .child(label.into())
+}
+
+/// The four strips between `outer` and `inner`, which it contains: top and
+/// bottom span the full width, left and right fill in between.
+fn ring(outer: Bounds<Pixels>, inner: Bounds<Pixels>) -> [Bounds<Pixels>; 4] {
+    [
+        Bounds::from_corners(outer.origin, point(outer.right(), inner.top())),
+        Bounds::from_corners(point(outer.left(), inner.bottom()), outer.bottom_right()),
+        Bounds::from_corners(
+            point(outer.left(), inner.top()),
+            point(inner.left(), inner.bottom()),
+        ),
+        Bounds::from_corners(
+            point(inner.right(), inner.top()),
+            point(outer.right(), inner.bottom()),
+        ),
+    ]
+}
+
+/// Baseline grid drawn over the story by the spacing overlay: a line every
+/// [`SPACING_GRID_MINOR`] pixels, stronger every [`SPACING_GRID_MAJOR`].
+///
+/// Absolutely positioned over its parent's top-left corner so the grid stays
+/// aligned with the story origin while scrolling. Paints no hitbox, so the
+/// story underneath stays interactive.
+fn spacing_grid(minor: Hsla, major: Hsla) -> impl IntoElement {
+    canvas(
+        |_, _, _| {},
+        move |bounds, _, window, _| {
+            let minor = minor.opacity(0.25);
+            let major = major.opacity(0.35);
+            let lines_per_major = (SPACING_GRID_MAJOR / SPACING_GRID_MINOR) as usize;
+
+            let columns = (f32::from(bounds.size.width) / SPACING_GRID_MINOR) as usize;
+            for i in 0..=columns {
+                let x = bounds.origin.x + px(i as f32 * SPACING_GRID_MINOR);
+                let color = if i % lines_per_major == 0 {
+                    major
+                } else {
+                    minor
+                };
+                window.paint_quad(fill(
+                    Bounds::new(point(x, bounds.origin.y), size(px(1.0), bounds.size.height)),
+                    color,
+                ));
+            }
+
+            let rows = (f32::from(bounds.size.height) / SPACING_GRID_MINOR) as usize;
+            for i in 0..=rows {
+                let y = bounds.origin.y + px(i as f32 * SPACING_GRID_MINOR);
+                let color = if i % lines_per_major == 0 {
+                    major
+                } else {
+                    minor
+                };
+                window.paint_quad(fill(
+                    Bounds::new(point(bounds.origin.x, y), size(bounds.size.width, px(1.0))),
+                    color,
+                ));
+            }
+        },
+    )
+    .absolute()
+    .top_0()
+    .left_0()
+    .size_full()
+}
+
 /// Render a story by index, using the concrete story types directly.
 ///
 /// This avoids the borrow conflict that would occur if we held a reference to
 /// the `StoryRegistry` global while also passing `&mut App` to `render_story`.
 fn render_story_by_index(idx: usize, window: &mut Window, cx: &mut App) -> Option<AnyElement> {
     use story::{
-        ButtonStory, CheckboxStory, DialogStory, DropdownMenuStory, InputStory, PopoverStory,
-        RadioStory, SelectStory, Story, TabsStory, TextareaStory, ToastStory, TooltipStory,
+        AvatarGroupStory, AvatarStory, BadgeStory, BarChartStory, BreadcrumbsStory, ButtonStory,
+        CardStory, CheckboxStory, CommandPaletteStory, ContextMenuStory, DataTableStory,
+        DescriptionListStory, DialogStory, DropdownMenuStory, EmptyStateStory, FilePickerStory,
+        FormStory, InputStory, KbdStory, LabelStory, LinkStory, MarkdownStory, MenuBarStory,
+        NotificationCenterStory, NumberInputStory, PasswordInputStory, PopoverStory,
+        ProgressBarStory, RadioStory, ScrollAreaStory, SearchInputStory, SelectStory,
+        SeparatorStory, SkeletonStory, SliderStory, SortableListStory, SparklineStory,
+        SpinnerStory, SplitPaneStory, StatusBarStory, Story, SwitchStory, TabsStory, TagStory,
+        TextStory, TextareaStory, TitleBarStory, ToastStory, TooltipStory,
     };
     match idx {
-        0 => Some(ButtonStory.render_story(window, cx)),
-        1 => Some(CheckboxStory.render_story(window, cx)),
-        2 => Some(DialogStory.render_story(window, cx)),
-        3 => Some(DropdownMenuStory.render_story(window, cx)),
-        4 => Some(InputStory.render_story(window, cx)),
-        5 => Some(PopoverStory.render_story(window, cx)),
-        6 => Some(RadioStory.render_story(window, cx)),
-        7 => Some(SelectStory.render_story(window, cx)),
-        8 => Some(TabsStory.render_story(window, cx)),
-        9 => Some(TextareaStory.render_story(window, cx)),
-        10 => Some(ToastStory.render_story(window, cx)),
-        11 => Some(TooltipStory.render_story(window, cx)),
+        0 => Some(AvatarStory.render_story(window, cx)),
+        1 => Some(AvatarGroupStory.render_story(window, cx)),
+        2 => Some(BadgeStory.render_story(window, cx)),
+        3 => Some(BarChartStory.render_story(window, cx)),
+        4 => Some(BreadcrumbsStory.render_story(window, cx)),
+        5 => Some(ButtonStory.render_story(window, cx)),
+        6 => Some(CardStory.render_story(window, cx)),
+        7 => Some(CheckboxStory.render_story(window, cx)),
+        8 => Some(CommandPaletteStory.render_story(window, cx)),
+        9 => Some(ContextMenuStory.render_story(window, cx)),
+        10 => Some(DataTableStory.render_story(window, cx)),
+        11 => Some(DescriptionListStory.render_story(window, cx)),
+        12 => Some(DialogStory.render_story(window, cx)),
+        13 => Some(DropdownMenuStory.render_story(window, cx)),
+        14 => Some(EmptyStateStory.render_story(window, cx)),
+        15 => Some(FilePickerStory.render_story(window, cx)),
+        16 => Some(FormStory.render_story(window, cx)),
+        17 => Some(InputStory.render_story(window, cx)),
+        18 => Some(KbdStory.render_story(window, cx)),
+        19 => Some(LabelStory.render_story(window, cx)),
+        20 => Some(LinkStory.render_story(window, cx)),
+        21 => Some(MarkdownStory.render_story(window, cx)),
+        22 => Some(MenuBarStory.render_story(window, cx)),
+        23 => Some(NotificationCenterStory.render_story(window, cx)),
+        24 => Some(NumberInputStory.render_story(window, cx)),
+        25 => Some(PasswordInputStory.render_story(window, cx)),
+        26 => Some(PopoverStory.render_story(window, cx)),
+        27 => Some(ProgressBarStory.render_story(window, cx)),
+        28 => Some(RadioStory.render_story(window, cx)),
+        29 => Some(ScrollAreaStory.render_story(window, cx)),
+        30 => Some(SearchInputStory.render_story(window, cx)),
+        31 => Some(SelectStory.render_story(window, cx)),
+        32 => Some(SeparatorStory.render_story(window, cx)),
+        33 => Some(SkeletonStory.render_story(window, cx)),
+        34 => Some(SliderStory.render_story(window, cx)),
+        35 => Some(SortableListStory.render_story(window, cx)),
+        36 => Some(SparklineStory.render_story(window, cx)),
+        37 => Some(SpinnerStory.render_story(window, cx)),
+        38 => Some(SplitPaneStory.render_story(window, cx)),
+        39 => Some(StatusBarStory.render_story(window, cx)),
+        40 => Some(SwitchStory.render_story(window, cx)),
+        41 => Some(TabsStory.render_story(window, cx)),
+        42 => Some(TagStory.render_story(window, cx)),
+        43 => Some(TextStory.render_story(window, cx)),
+        44 => Some(TextareaStory.render_story(window, cx)),
+        45 => Some(TitleBarStory.render_story(window, cx)),
+        46 => Some(ToastStory.render_story(window, cx)),
+        47 => Some(TooltipStory.render_story(window, cx)),
         _ => None,
     }
 }
 
-/// Truncate a string to a maximum length, appending "..." if truncated.
-fn truncate_str(s: &str, max: usize) -> String {
-    if s.len() <= max {
-        s.to_string()
-    } else {
-        format!("{}...", &s[..max.saturating_sub(3)])
+// ---------------------------------------------------------------------------
+// Application entry point
+// ---------------------------------------------------------------------------
+
+/// Register themes from `./themes/`, such as seeds written by `gpui theme from-image`.
+fn load_project_themes(cx: &mut App) {
+    let Ok(dir) = std::env::current_dir().map(|cwd| cwd.join("themes")) else {
+        return;
+    };
+    if !dir.is_dir() {
+        return;
+    }
+    match ThemeRegistry::global_mut(cx).load_dir(&dir) {
+        Ok(names) => {
+            log::info!("Loaded project themes: {}", names.join(", "));
+            for name in &names {
+                for warning in ThemeRegistry::global(cx).warnings(name) {
+                    log::warn!("{}", warning);
+                }
+            }
+        }
+        Err(e) => log::error!("Failed to load themes from {}: {}", dir.display(), e),
     }
 }
 
-/// Look up the current Hsla color value for a token path on the active theme.
-fn get_token_color(theme: &Theme, path: &str) -> Option<Hsla> {
-    match path {
-        "border.default" => Some(theme.border.default),
-        "border.variant" => Some(theme.border.variant),
-        "border.focused" => Some(theme.border.focused),
-        "border.selected" => Some(theme.border.selected),
-        "border.transparent" => Some(theme.border.transparent),
-        "border.disabled" => Some(theme.border.disabled),
-        "surface.background" => Some(theme.surface.background),
-        "surface.surface" => Some(theme.surface.surface),
-        "surface.elevated_surface" => Some(theme.surface.elevated_surface),
-        "element.background" => Some(theme.element.background),
-        "element.hover" => Some(theme.element.hover),
-        "element.active" => Some(theme.element.active),
-        "element.selected" => Some(theme.element.selected),
-        "element.disabled" => Some(theme.element.disabled),
-        "ghost_element.background" => Some(theme.ghost_element.background),
-        "ghost_element.hover" => Some(theme.ghost_element.hover),
-        "ghost_element.active" => Some(theme.ghost_element.active),
-        "ghost_element.selected" => Some(theme.ghost_element.selected),
-        "ghost_element.disabled" => Some(theme.ghost_element.disabled),
-        "text.default" => Some(theme.text.default),
-        "text.muted" => Some(theme.text.muted),
-        "text.placeholder" => Some(theme.text.placeholder),
-        "text.disabled" => Some(theme.text.disabled),
-        "text.accent" => Some(theme.text.accent),
-        "icon.default" => Some(theme.icon.default),
-        "icon.muted" => Some(theme.icon.muted),
-        "icon.disabled" => Some(theme.icon.disabled),
-        "icon.placeholder" => Some(theme.icon.placeholder),
-        "icon.accent" => Some(theme.icon.accent),
-        "status.error.foreground" => Some(theme.status.error.foreground),
-        "status.error.background" => Some(theme.status.error.background),
-        "status.error.border" => Some(theme.status.error.border),
-        "status.warning.foreground" => Some(theme.status.warning.foreground),
-        "status.warning.background" => Some(theme.status.warning.background),
-        "status.warning.border" => Some(theme.status.warning.border),
-        "status.info.foreground" => Some(theme.status.info.foreground),
-        "status.info.background" => Some(theme.status.info.background),
-        "status.info.border" => Some(theme.status.info.border),
-        "status.success.foreground" => Some(theme.status.success.foreground),
-        "status.success.background" => Some(theme.status.success.background),
-        "status.success.border" => Some(theme.status.success.border),
-        "status.hint.foreground" => Some(theme.status.hint.foreground),
-        "status.hint.background" => Some(theme.status.hint.background),
-        "status.hint.border" => Some(theme.status.hint.border),
-        "tab.bar_background" => Some(theme.tab.bar_background),
-        "tab.inactive_background" => Some(theme.tab.inactive_background),
-        "tab.active_background" => Some(theme.tab.active_background),
-        "panel.background" => Some(theme.panel.background),
-        "panel.focused_border" => theme.panel.focused_border,
-        "chrome.title_bar_background" => Some(theme.chrome.title_bar_background),
-        "chrome.status_bar_background" => Some(theme.chrome.status_bar_background),
-        "chrome.toolbar_background" => Some(theme.chrome.toolbar_background),
-        "scrollbar.thumb_background" => Some(theme.scrollbar.thumb_background),
-        "scrollbar.thumb_hover_background" => Some(theme.scrollbar.thumb_hover_background),
-        "scrollbar.thumb_border" => Some(theme.scrollbar.thumb_border),
-        "scrollbar.track_background" => Some(theme.scrollbar.track_background),
-        "scrollbar.track_border" => Some(theme.scrollbar.track_border),
-        "player.cursor" => Some(theme.player.cursor),
-        "player.background" => Some(theme.player.background),
-        "player.selection" => Some(theme.player.selection),
-        "link.hover" => Some(theme.link.hover),
-        _ => None,
-    }
+/// Compute the dashboard from the story registry plus the acceptance ledger
+/// and project manifest in the working directory, when present.
+fn load_dashboard(cx: &App) -> Vec<ComponentStatus> {
+    let story_states: BTreeMap<_, _> = cx
+        .global::<StoryRegistry>()
+        .entries()
+        .iter()
+        .map(|entry| {
+            let contract = entry.contract();
+            (contract.name.to_lowercase(), contract.states)
+        })
+        .collect();
+    let ledger = std::fs::read_to_string(LEDGER_FILE)
+        .ok()
+        .and_then(|contents| match AcceptanceLedger::from_toml(&contents) {
+            Ok(ledger) => Some(ledger),
+            Err(e) => {
+                log::error!("Failed to parse {}: {}", LEDGER_FILE, e);
+                None
+            }
+        })
+        .unwrap_or_default();
+    let manifest = std::fs::read_to_string(MANIFEST_FILE)
+        .ok()
+        .and_then(|contents| match Manifest::from_toml(&contents) {
+            Ok(manifest) => Some(manifest),
+            Err(e) => {
+                log::error!("Failed to parse {}: {}", MANIFEST_FILE, e);
+                None
+            }
+        })
+        .unwrap_or_default();
+    registry::dashboard::dashboard(
+        &story_states,
+        &ledger,
+        &manifest,
+        &registry::manifest::today(),
+    )
 }
 
-// ---------------------------------------------------------------------------
-// Application entry point
-// ---------------------------------------------------------------------------
+/// Switch to the theme and color vision filter a permalink names.
+fn apply_link_theme(link: &StudioLink, cx: &mut App) {
+    if let Some(name) = &link.theme
+        && let Err(e) = Theme::change(name, cx)
+    {
+        log::error!("Failed to switch theme: {}", e);
+    }
+    if let Some(vision) = link.prop("vision") {
+        match ColorVisionDeficiency::ALL
+            .into_iter()
+            .find(|kind| kind.label().eq_ignore_ascii_case(vision))
+        {
+            Some(kind) => Theme::set_color_vision(Some(kind), cx),
+            None => log::error!("Unknown color vision simulation '{}'", vision),
+        }
+    }
+}
 
 fn main() {
+    let link = StudioLink::from_args(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("studio: {e}");
+        eprintln!("usage: studio [--story NAME] [--theme NAME] [--props KEY=VALUE,...]");
+        std::process::exit(2);
+    });
+
     gpui_platform::application().run(move |cx| {
         // Initialize all crates in dependency order.
         assets::init(cx);
         theme::init(cx);
+        load_project_themes(cx);
         primitives::init(cx);
         components::init(cx);
         story::init(cx);
+        settings::init(cx);
+        apply_link_theme(&link, cx);
 
         cx.spawn(async move |cx| {
             cx.open_window(
@@ -954,9 +3620,19 @@ fn main() {
                             height: px(800.0),
                         },
                     })),
+                    // The toolbar is a TitleBar: hide the system title bar
+                    // and keep the traffic lights inside its leading inset.
+                    titlebar: Some(TitlebarOptions {
+                        title: Some("GPUI Workbench".into()),
+                        appears_transparent: true,
+                        traffic_light_position: Some(point(
+                            px(TRAFFIC_LIGHT_POSITION.0),
+                            px(TRAFFIC_LIGHT_POSITION.1),
+                        )),
+                    }),
                     ..Default::default()
                 },
-                |_window, cx| cx.new(|_cx| StudioApp::new()),
+                |window, cx| cx.new(|cx| StudioApp::new(&link, window, cx)),
             )?;
             Ok::<_, anyhow::Error>(())
         })
@@ -0,0 +1,210 @@
+//! Story permalinks: a `gpui studio` command line that reopens the studio in
+//! the current view.
+//!
+//! The link names the selected story and active theme, and encodes the
+//! playground state (color vision filter, overlays, open panels) as
+//! comma-separated `key=value` props:
+//!
+//! ```text
+//! gpui studio --story Button --theme 'One Light' --props grid=on,vision=deuteranopia
+//! ```
+//!
+//! A `,`, `=`, or `%` inside a prop key or value is percent-encoded (`%2C`,
+//! `%3D`, `%25`), so any text survives the round trip.
+
+use std::collections::BTreeMap;
+
+/// The studio view a permalink reproduces.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StudioLink {
+    /// Story name, as listed in the sidebar.
+    pub story: Option<String>,
+    /// Theme name.
+    pub theme: Option<String>,
+    /// Playground state, e.g. `grid=on` or `vision=tritanopia`.
+    pub props: BTreeMap<String, String>,
+}
+
+impl StudioLink {
+    /// Parse `--story`, `--theme`, and `--props` from command-line arguments
+    /// (without the program name). Both `--flag value` and `--flag=value` work.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut link = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let (flag, inline_value) = match arg.split_once('=') {
+                Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value)),
+                _ => (arg.clone(), None),
+            };
+            let mut value = || {
+                inline_value
+                    .map(str::to_string)
+                    .or_else(|| args.next())
+                    .ok_or_else(|| format!("{flag} expects a value"))
+            };
+            match flag.as_str() {
+                "--story" => link.story = Some(value()?),
+                "--theme" => link.theme = Some(value()?),
+                "--props" => {
+                    for prop in value()?.split(',').filter(|prop| !prop.is_empty()) {
+                        let (key, prop_value) = prop
+                            .split_once('=')
+                            .ok_or_else(|| format!("prop `{prop}` is not `key=value`"))?;
+                        link.props
+                            .insert(unescape_prop(key)?, unescape_prop(prop_value)?);
+                    }
+                }
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
+        Ok(link)
+    }
+
+    /// The playground prop `key`, if set.
+    pub fn prop(&self, key: &str) -> Option<&str> {
+        self.props.get(key).map(String::as_str)
+    }
+
+    /// Whether the boolean prop `key` is `on`.
+    pub fn is_on(&self, key: &str) -> bool {
+        self.prop(key) == Some("on")
+    }
+
+    /// Render the link as a shell command line.
+    pub fn to_command_line(&self) -> String {
+        let mut line = String::from("gpui studio");
+        if let Some(story) = &self.story {
+            line.push_str(&format!(" --story {}", shell_quote(story)));
+        }
+        if let Some(theme) = &self.theme {
+            line.push_str(&format!(" --theme {}", shell_quote(theme)));
+        }
+        if !self.props.is_empty() {
+            let props: Vec<String> = self
+                .props
+                .iter()
+                .map(|(key, value)| format!("{}={}", escape_prop(key), escape_prop(value)))
+                .collect();
+            line.push_str(&format!(" --props {}", shell_quote(&props.join(","))));
+        }
+        line
+    }
+}
+
+/// Quote `value` for a POSIX shell when it contains anything but safe characters.
+fn shell_quote(value: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_.,=/:".contains(c);
+    if !value.is_empty() && value.chars().all(safe) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// Percent-encode the characters that delimit props.
+fn escape_prop(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '%' => escaped.push_str("%25"),
+            ',' => escaped.push_str("%2C"),
+            '=' => escaped.push_str("%3D"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Decode a prop key or value written by [`escape_prop`].
+fn unescape_prop(text: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('%') {
+        unescaped.push_str(&rest[..start]);
+        let code = rest.get(start + 1..start + 3);
+        let c = match code.map(str::to_ascii_uppercase).as_deref() {
+            Some("25") => '%',
+            Some("2C") => ',',
+            Some("3D") => '=',
+            _ => return Err(format!("prop `{text}` has an unknown `%` escape")),
+        };
+        unescaped.push(c);
+        rest = &rest[start + 3..];
+    }
+    unescaped.push_str(rest);
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split(' ').map(str::to_string).collect()
+    }
+
+    #[test]
+    fn parses_both_flag_forms() {
+        let link = StudioLink::from_args(args(
+            "--story Button --theme=Dark --props grid=on,,vision=tritanopia",
+        ))
+        .unwrap();
+        assert_eq!(link.story.as_deref(), Some("Button"));
+        assert_eq!(link.theme.as_deref(), Some("Dark"));
+        assert!(link.is_on("grid"));
+        assert_eq!(link.prop("vision"), Some("tritanopia"));
+        assert_eq!(link.props.len(), 2);
+    }
+
+    #[test]
+    fn rejects_malformed_arguments() {
+        assert!(StudioLink::from_args(args("--story")).is_err());
+        assert!(StudioLink::from_args(args("--bogus x")).is_err());
+        assert!(StudioLink::from_args(args("--props grid")).is_err());
+        assert!(StudioLink::from_args(args("--props note=100%")).is_err());
+        assert!(StudioLink::from_args(args("--props note=%zz")).is_err());
+    }
+
+    #[test]
+    fn props_with_delimiters_round_trip() {
+        let mut link = StudioLink {
+            story: Some("Data Table".into()),
+            theme: Some("Rosé's Pine".into()),
+            ..Default::default()
+        };
+        link.props.insert("grid".into(), "on".into());
+        link.props.insert("label".into(), "a=b, c%d".into());
+        link.props.insert("k,e=y".into(), "".into());
+
+        let line = link.to_command_line();
+        assert!(line.contains("label=a%3Db%2C c%25d"), "{line}");
+
+        // Undo the shell quoting the way a POSIX shell would.
+        let words = shell_words(line.strip_prefix("gpui studio ").unwrap());
+        assert_eq!(StudioLink::from_args(words).unwrap(), link);
+    }
+
+    #[test]
+    fn escapes_are_case_insensitive() {
+        assert_eq!(unescape_prop("a%2cb%3db").unwrap(), "a,b=b");
+        assert_eq!(escape_prop("plain-text"), "plain-text");
+    }
+
+    /// Split a command line quoted by [`shell_quote`] into words.
+    fn shell_words(line: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        let mut word = String::new();
+        let mut quoted = false;
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '\'' => quoted = !quoted,
+                '\\' if !quoted => word.extend(chars.next()),
+                ' ' if !quoted => words.push(std::mem::take(&mut word)),
+                c => word.push(c),
+            }
+        }
+        words.push(word);
+        words
+    }
+}
@@ -0,0 +1,130 @@
+//! Crash recovery for token edits.
+//!
+//! The token editor's edits only live in memory until they are exported.
+//! While the studio runs, they are autosaved to [`RECOVERY_FILE`] every
+//! [`AUTOSAVE_INTERVAL`]; a clean quit removes the file. A file left behind
+//! means the last session ended without quitting, and the next launch offers
+//! to replay its edits.
+//!
+//! This module is the file format only; `main.rs` schedules the saves and
+//! shows the prompt.
+
+use std::time::Duration;
+
+use serde_json::{Value, json};
+use theme::ThemePatch;
+
+/// File unsaved token edits are autosaved to, relative to the working directory.
+pub const RECOVERY_FILE: &str = "studio-recovery.json";
+
+/// How often pending token edits are written to [`RECOVERY_FILE`].
+pub const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Token edits saved for recovery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveredEdits {
+    /// Theme that was active when the edits were saved.
+    pub theme: String,
+    /// The edits, in the order they were made.
+    pub patch: ThemePatch,
+}
+
+impl RecoveredEdits {
+    /// Serialize for [`RECOVERY_FILE`].
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&json!({
+            "theme": self.theme,
+            "edits": self.patch,
+        }))
+        .expect("recovery JSON is always serializable")
+    }
+
+    /// Parse edits saved by [`RecoveredEdits::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        let theme = value
+            .get("theme")
+            .and_then(Value::as_str)
+            .ok_or("recovery file has no `theme`")?
+            .to_string();
+        let edits = value
+            .get("edits")
+            .ok_or("recovery file has no `edits` list")?;
+        let patch = serde_json::from_value(edits.clone()).map_err(|e| e.to_string())?;
+        Ok(Self { theme, patch })
+    }
+
+    /// Read [`RECOVERY_FILE`], if the last session left edits behind.
+    pub fn load() -> Option<Self> {
+        match std::fs::read_to_string(RECOVERY_FILE) {
+            Ok(json) => match Self::from_json(&json) {
+                Ok(edits) => Some(edits).filter(|edits| !edits.patch.is_empty()),
+                Err(e) => {
+                    log::error!("Ignoring {}: {}", RECOVERY_FILE, e);
+                    None
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => {
+                log::error!("Failed to read {}: {}", RECOVERY_FILE, e);
+                None
+            }
+        }
+    }
+
+    /// Write the edits to [`RECOVERY_FILE`].
+    pub fn save(&self) {
+        if let Err(e) = std::fs::write(RECOVERY_FILE, self.to_json()) {
+            log::error!("Failed to save {}: {}", RECOVERY_FILE, e);
+        }
+    }
+
+    /// Remove [`RECOVERY_FILE`], once its edits are declined or the studio
+    /// quits cleanly.
+    pub fn discard() {
+        match std::fs::remove_file(RECOVERY_FILE) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => log::error!("Failed to remove {}: {}", RECOVERY_FILE, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edits() -> RecoveredEdits {
+        let mut patch = ThemePatch::new();
+        patch.set_token("border.default", "#ff0000ff");
+        patch.set_token("text.muted", "#00ff00ff");
+        patch.set_token("border.default", "#0000ffff");
+        RecoveredEdits {
+            theme: "One Light".into(),
+            patch,
+        }
+    }
+
+    #[test]
+    fn json_round_trips_edits_in_order() {
+        let edits = edits();
+        let json = edits.to_json();
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["theme"], "One Light");
+        assert_eq!(value["edits"].as_array().unwrap().len(), 3);
+
+        let restored = RecoveredEdits::from_json(&json).unwrap();
+        assert_eq!(restored, edits);
+    }
+
+    #[test]
+    fn from_json_rejects_incomplete_files() {
+        assert!(RecoveredEdits::from_json("not json").is_err());
+        assert!(RecoveredEdits::from_json(r#"{"edits": []}"#).is_err());
+        assert!(RecoveredEdits::from_json(r#"{"theme": "One Dark"}"#).is_err());
+        assert!(RecoveredEdits::from_json(r#"{"theme": "One Dark", "edits": {}}"#).is_err());
+
+        let empty = RecoveredEdits::from_json(r#"{"theme": "One Dark", "edits": []}"#).unwrap();
+        assert!(empty.patch.is_empty());
+    }
+}
@@ -0,0 +1,753 @@
+//! Studio preferences and the Settings window that edits them.
+//!
+//! Preferences are the `[studio]` table of [`CONFIG_FILE`] in the working
+//! directory, read and written through `registry::config` so the project's
+//! component settings in the same file are kept. [`StudioSettings`] holds the
+//! app-wide copy; every change is applied at once (motion, locale, history,
+//! key bindings, theme) and saved. Studio commands are registered in the
+//! `ShortcutRegistry`, which holds their current keys for menus, the
+//! cheat-sheet, and the Settings window.
+//!
+//! The Settings window is built only from workbench components. It opens
+//! from the app menu or with [`OpenSettings`] (`secondary-,` by default).
+
+use std::path::PathBuf;
+
+use components::{
+    Button, ButtonVariant, Input, Kbd, NotificationHistory, Select, SelectItem, Switch,
+};
+use gpui::*;
+use primitives::{Locale, MotionPreference, SECONDARY_IS_CMD, Shortcut, ShortcutRegistry};
+use registry::config::{CONFIG_FILE, Density, ProjectConfig, StudioConfig, ThemeMode};
+use theme::{ActiveTheme, Theme};
+
+actions!(
+    studio,
+    [
+        /// Open the Settings window.
+        OpenSettings,
+        /// Cycle through the registered themes.
+        ToggleTheme,
+        /// Show or hide the release readiness dashboard.
+        ToggleDashboard,
+        /// Turn inspect mode on or off.
+        ToggleInspect,
+        /// Show or hide the tokens panel.
+        ToggleTokensPanel,
+        /// Show or hide the metadata panel.
+        ToggleMetadataPanel,
+        /// Show or hide the performance HUD.
+        TogglePerfHud,
+        /// Show or hide the spacing overlay.
+        ToggleSpacingOverlay,
+        /// Open the next component story.
+        NextStory,
+        /// Open the previous component story.
+        PreviousStory,
+        /// Copy a permalink to the current view.
+        CopyLink,
+        /// Show or hide the keyboard shortcut cheat-sheet.
+        ToggleShortcuts,
+        /// Quit the studio.
+        Quit,
+    ]
+);
+
+/// A studio command that can be rebound in `[studio.keybindings]`.
+pub struct Command {
+    /// Name in `[studio.keybindings]`.
+    pub key: &'static str,
+    /// Name shown in menus and the Settings window.
+    pub label: &'static str,
+    /// Keystrokes used unless overridden.
+    pub default_keys: &'static str,
+    bind: fn(&str) -> KeyBinding,
+}
+
+impl Command {
+    /// The command as a [`Shortcut`] bound to its default keys.
+    fn shortcut(&self) -> Shortcut {
+        Shortcut::new(self.key, self.label, self.default_keys)
+    }
+}
+
+/// Every bindable command, in Settings window order.
+pub const COMMANDS: [Command; 13] = [
+    Command {
+        key: "open_settings",
+        label: "Open settings",
+        default_keys: "secondary-,",
+        bind: |keys| KeyBinding::new(keys, OpenSettings, None),
+    },
+    Command {
+        key: "toggle_theme",
+        label: "Next theme",
+        default_keys: "secondary-shift-t",
+        bind: |keys| KeyBinding::new(keys, ToggleTheme, None),
+    },
+    Command {
+        key: "toggle_dashboard",
+        label: "Toggle dashboard",
+        default_keys: "secondary-shift-d",
+        bind: |keys| KeyBinding::new(keys, ToggleDashboard, None),
+    },
+    Command {
+        key: "toggle_inspect",
+        label: "Toggle inspect mode",
+        default_keys: "secondary-shift-i",
+        bind: |keys| KeyBinding::new(keys, ToggleInspect, None),
+    },
+    Command {
+        key: "toggle_tokens_panel",
+        label: "Toggle tokens panel",
+        default_keys: "secondary-shift-k",
+        bind: |keys| KeyBinding::new(keys, ToggleTokensPanel, None),
+    },
+    Command {
+        key: "toggle_metadata_panel",
+        label: "Toggle metadata panel",
+        default_keys: "secondary-shift-m",
+        bind: |keys| KeyBinding::new(keys, ToggleMetadataPanel, None),
+    },
+    Command {
+        key: "toggle_perf_hud",
+        label: "Toggle performance HUD",
+        default_keys: "secondary-shift-p",
+        bind: |keys| KeyBinding::new(keys, TogglePerfHud, None),
+    },
+    Command {
+        key: "toggle_spacing_overlay",
+        label: "Toggle spacing overlay",
+        default_keys: "secondary-shift-g",
+        bind: |keys| KeyBinding::new(keys, ToggleSpacingOverlay, None),
+    },
+    Command {
+        key: "next_story",
+        label: "Next story",
+        default_keys: "secondary-]",
+        bind: |keys| KeyBinding::new(keys, NextStory, None),
+    },
+    Command {
+        key: "previous_story",
+        label: "Previous story",
+        default_keys: "secondary-[",
+        bind: |keys| KeyBinding::new(keys, PreviousStory, None),
+    },
+    Command {
+        key: "copy_link",
+        label: "Copy link",
+        default_keys: "secondary-shift-c",
+        bind: |keys| KeyBinding::new(keys, CopyLink, None),
+    },
+    Command {
+        key: "toggle_shortcuts",
+        label: "Keyboard shortcuts",
+        default_keys: "?",
+        bind: |keys| KeyBinding::new(keys, ToggleShortcuts, None),
+    },
+    Command {
+        key: "quit",
+        label: "Quit",
+        default_keys: "secondary-q",
+        bind: |keys| KeyBinding::new(keys, Quit, None),
+    },
+];
+
+/// Why `keys` is not a valid binding: each space-separated keystroke must
+/// parse, e.g. `ctrl-shift-i` or the chord `ctrl-k ctrl-s`.
+pub fn keys_error(keys: &str) -> Option<String> {
+    keys.split_whitespace()
+        .find_map(|keystroke| Keystroke::parse(keystroke).err())
+        .map(|e| e.to_string())
+}
+
+/// Vertical padding of a sidebar row.
+pub fn nav_item_padding(density: Density) -> Pixels {
+    match density {
+        Density::Compact => px(2.0),
+        Density::Comfortable => px(6.0),
+        Density::Spacious => px(10.0),
+    }
+}
+
+/// Padding around the rendered story.
+pub fn story_padding(density: Density) -> Pixels {
+    match density {
+        Density::Compact => px(8.0),
+        Density::Comfortable => px(16.0),
+        Density::Spacious => px(24.0),
+    }
+}
+
+/// Switch to the built-in theme `mode` calls for under the system's `appearance`.
+pub fn apply_theme_mode(mode: ThemeMode, appearance: WindowAppearance, cx: &mut App) {
+    let dark = match mode {
+        ThemeMode::System => matches!(
+            appearance,
+            WindowAppearance::Dark | WindowAppearance::VibrantDark
+        ),
+        ThemeMode::Light => false,
+        ThemeMode::Dark => true,
+    };
+    let name = if dark { "One Dark" } else { "One Light" };
+    if cx.theme().name != name
+        && let Err(e) = Theme::change(name, cx)
+    {
+        log::error!("Failed to switch theme: {}", e);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// StudioSettings
+// ---------------------------------------------------------------------------
+
+/// The app-wide studio preferences.
+#[derive(Default)]
+pub struct StudioSettings {
+    config: StudioConfig,
+}
+
+impl Global for StudioSettings {}
+
+impl StudioSettings {
+    /// The current preferences.
+    pub fn get(cx: &App) -> &StudioConfig {
+        &cx.global::<StudioSettings>().config
+    }
+
+    /// Change the preferences, apply what changed, and save them.
+    pub fn update(cx: &mut App, change: impl FnOnce(&mut StudioConfig)) {
+        let previous = Self::get(cx).clone();
+        cx.update_global::<StudioSettings, _>(|settings, _| change(&mut settings.config));
+        let config = Self::get(cx).clone();
+        if config == previous {
+            return;
+        }
+        apply(Some(&previous), &config, cx);
+        save(&config);
+    }
+}
+
+/// Load the preferences, apply them, and install the app menu and the
+/// app-wide actions.
+pub fn init(cx: &mut App) {
+    let config = load();
+    apply(None, &config, cx);
+    apply_theme_mode(config.theme_mode, cx.window_appearance(), cx);
+    cx.set_global(StudioSettings { config });
+
+    cx.on_action(|_: &OpenSettings, cx| open_settings_window(cx));
+    cx.on_action(|_: &Quit, cx| cx.quit());
+    cx.set_menus(vec![
+        Menu {
+            name: "GPUI Workbench".into(),
+            items: vec![
+                MenuItem::action("Settings…", OpenSettings),
+                MenuItem::separator(),
+                MenuItem::action("Quit", Quit),
+            ],
+        },
+        Menu {
+            name: "View".into(),
+            items: vec![
+                MenuItem::action("Next Theme", ToggleTheme),
+                MenuItem::action("Dashboard", ToggleDashboard),
+                MenuItem::separator(),
+                MenuItem::action("Tokens Panel", ToggleTokensPanel),
+                MenuItem::action("Metadata Panel", ToggleMetadataPanel),
+                MenuItem::separator(),
+                MenuItem::action("Next Story", NextStory),
+                MenuItem::action("Previous Story", PreviousStory),
+                MenuItem::separator(),
+                MenuItem::action("Inspect Mode", ToggleInspect),
+                MenuItem::action("Performance HUD", TogglePerfHud),
+                MenuItem::action("Spacing Overlay", ToggleSpacingOverlay),
+                MenuItem::separator(),
+                MenuItem::action("Copy Link", CopyLink),
+                MenuItem::separator(),
+                MenuItem::action("Keyboard Shortcuts", ToggleShortcuts),
+            ],
+        },
+    ]);
+}
+
+/// Apply `config`; `previous` limits the theme and key bindings to what changed.
+fn apply(previous: Option<&StudioConfig>, config: &StudioConfig, cx: &mut App) {
+    MotionPreference {
+        reduced: config.reduce_motion,
+    }
+    .set_global(cx);
+    // Tags without built-in formatting still select contract translations.
+    Locale::from_tag(config.locale())
+        .unwrap_or_default()
+        .set_global(cx);
+    cx.default_global::<NotificationHistory>()
+        .set_recording(config.keep_history);
+
+    if previous.is_none_or(|previous| previous.keybindings != config.keybindings) {
+        let mut bindings = Vec::with_capacity(COMMANDS.len());
+        for command in &COMMANDS {
+            ShortcutRegistry::register(command.shortcut(), cx);
+            let keys = match config.keybinding(command.key) {
+                Some(keys) if keys_error(keys).is_none() => {
+                    Some(SharedString::from(keys.to_string()))
+                }
+                Some(keys) => {
+                    log::error!(
+                        "Ignoring binding '{}' for {}: not a valid keystroke",
+                        keys,
+                        command.key
+                    );
+                    None
+                }
+                None => None,
+            };
+            ShortcutRegistry::rebind(command.key, keys, cx);
+            if let Some(keys) = ShortcutRegistry::keys_for(command.key, cx) {
+                bindings.push((command.bind)(&keys));
+            }
+        }
+        for conflict in cx.global::<ShortcutRegistry>().conflicts(SECONDARY_IS_CMD) {
+            log::warn!(
+                "'{}' is bound to more than one command: {}",
+                conflict.keys,
+                conflict.actions.join(", ")
+            );
+        }
+        cx.clear_key_bindings();
+        cx.bind_keys(bindings);
+    }
+    if previous.is_some_and(|previous| previous.theme_mode != config.theme_mode) {
+        apply_theme_mode(config.theme_mode, cx.window_appearance(), cx);
+    }
+    cx.refresh_windows();
+}
+
+/// Read `[studio]` from [`CONFIG_FILE`], falling back to the defaults when
+/// the file is missing or unreadable.
+fn load() -> StudioConfig {
+    match std::fs::read_to_string(CONFIG_FILE) {
+        Ok(toml_str) => match ProjectConfig::from_toml(&toml_str) {
+            Ok(config) => config.studio,
+            Err(e) => {
+                log::error!("Ignoring {}: {}", CONFIG_FILE, e);
+                StudioConfig::default()
+            }
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => StudioConfig::default(),
+        Err(e) => {
+            log::error!("Failed to read {}: {}", CONFIG_FILE, e);
+            StudioConfig::default()
+        }
+    }
+}
+
+/// Write `studio` into [`CONFIG_FILE`], keeping the rest of the file.
+/// Nothing is written if the existing file cannot be parsed.
+fn save(studio: &StudioConfig) {
+    let mut config = match std::fs::read_to_string(CONFIG_FILE) {
+        Ok(toml_str) => match ProjectConfig::from_toml(&toml_str) {
+            Ok(config) => config,
+            Err(e) => {
+                log::error!("Not saving settings over invalid {}: {}", CONFIG_FILE, e);
+                return;
+            }
+        },
+        Err(_) => ProjectConfig::default(),
+    };
+    config.studio = studio.clone();
+    let result = config
+        .to_toml()
+        .map_err(|e| e.to_string())
+        .and_then(|toml_str| std::fs::write(CONFIG_FILE, toml_str).map_err(|e| e.to_string()));
+    if let Err(e) = result {
+        log::error!("Failed to save {}: {}", CONFIG_FILE, e);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Settings window
+// ---------------------------------------------------------------------------
+
+/// The open Settings window, if any.
+#[derive(Default)]
+struct SettingsWindow(Option<WindowHandle<SettingsView>>);
+
+impl Global for SettingsWindow {}
+
+/// Bring the Settings window to the front, opening it if needed.
+fn open_settings_window(cx: &mut App) {
+    if let Some(handle) = cx.default_global::<SettingsWindow>().0
+        && handle
+            .update(cx, |_, window, _| window.activate_window())
+            .is_ok()
+    {
+        return;
+    }
+    let options = WindowOptions {
+        window_bounds: Some(WindowBounds::centered(size(px(560.0), px(680.0)), cx)),
+        titlebar: Some(TitlebarOptions {
+            title: Some("Settings".into()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    match cx.open_window(options, |_window, cx| cx.new(SettingsView::new)) {
+        Ok(handle) => cx.set_global(SettingsWindow(Some(handle))),
+        Err(e) => log::error!("Failed to open settings: {}", e),
+    }
+}
+
+/// Root view of the Settings window.
+struct SettingsView;
+
+impl SettingsView {
+    fn new(cx: &mut Context<Self>) -> Self {
+        cx.observe_global::<StudioSettings>(|_, cx| cx.notify())
+            .detach();
+        Self
+    }
+
+    /// A titled group of rows.
+    fn section(title: &'static str, cx: &App) -> Div {
+        let theme = cx.theme();
+        div()
+            .flex()
+            .flex_col()
+            .gap_3()
+            .pb_4()
+            .border_b_1()
+            .border_color(theme.border.default)
+            .child(
+                div()
+                    .text_sm()
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.text.default)
+                    .child(title),
+            )
+    }
+
+    /// A setting: label and description on the left, control on the right.
+    fn row(
+        label: &'static str,
+        description: &'static str,
+        control: impl IntoElement,
+        cx: &App,
+    ) -> Div {
+        let theme = cx.theme();
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .justify_between()
+            .gap_4()
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_1()
+                    .min_w_0()
+                    .child(div().text_sm().text_color(theme.text.default).child(label))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(theme.text.muted)
+                            .child(description),
+                    ),
+            )
+            .child(control)
+    }
+
+    fn render_appearance(&self, config: &StudioConfig, cx: &mut App) -> Div {
+        let theme_modes = ThemeMode::ALL
+            .iter()
+            .map(|mode| SelectItem::new(mode.label()))
+            .collect();
+        let theme_index = ThemeMode::ALL
+            .iter()
+            .position(|mode| *mode == config.theme_mode)
+            .unwrap_or_default();
+        let densities = Density::ALL
+            .iter()
+            .map(|density| SelectItem::new(density.label()))
+            .collect();
+        let density_index = Density::ALL
+            .iter()
+            .position(|density| *density == config.density)
+            .unwrap_or_default();
+
+        let locales = Locale::ALL
+            .iter()
+            .map(|locale| SelectItem::new(locale.tag))
+            .collect();
+        let locale_index = Locale::ALL
+            .iter()
+            .position(|locale| locale.tag.eq_ignore_ascii_case(config.locale()))
+            .unwrap_or_default();
+
+        let theme_select = Select::new("settings-theme-mode", theme_modes, cx)
+            .selected_index(theme_index)
+            .set_width(px(160.0))
+            .on_change(|index, _item, _window, cx| {
+                StudioSettings::update(cx, |config| config.theme_mode = ThemeMode::ALL[index]);
+            });
+        let density_select = Select::new("settings-density", densities, cx)
+            .selected_index(density_index)
+            .set_width(px(160.0))
+            .on_change(|index, _item, _window, cx| {
+                StudioSettings::update(cx, |config| config.density = Density::ALL[index]);
+            });
+
+        let locale_select = Select::new("settings-locale", locales, cx)
+            .selected_index(locale_index)
+            .set_width(px(160.0))
+            .on_change(|index, _item, _window, cx| {
+                StudioSettings::update(cx, |config| {
+                    config.locale = Some(Locale::ALL[index].tag.to_string())
+                });
+            });
+
+        Self::section("Appearance", cx)
+            .child(Self::row(
+                "Theme",
+                "One Light or One Dark at launch; System follows the OS appearance.",
+                theme_select,
+                cx,
+            ))
+            .child(Self::row(
+                "Density",
+                "Spacing of the sidebar and the story area.",
+                density_select,
+                cx,
+            ))
+            .child(Self::row(
+                "Language",
+                "Number formatting, and contract and story descriptions where translated.",
+                locale_select,
+                cx,
+            ))
+            .child(Self::row(
+                "Reduce motion",
+                "Skip fades and slides in components. Spinners keep turning.",
+                Switch::new("settings-reduce-motion")
+                    .checked(config.reduce_motion)
+                    .on_change(|checked, _window, cx| {
+                        StudioSettings::update(cx, |config| config.reduce_motion = checked);
+                    }),
+                cx,
+            ))
+    }
+
+    fn render_privacy(&self, config: &StudioConfig, cx: &App) -> Div {
+        Self::section("History", cx).child(Self::row(
+            "Keep notification history",
+            "Closed toasts stay in memory for the notification center. Nothing \
+             is recorded to disk or sent anywhere; turning this off forgets them.",
+            Switch::new("settings-keep-history")
+                .checked(config.keep_history)
+                .on_change(|checked, _window, cx| {
+                    StudioSettings::update(cx, |config| config.keep_history = checked);
+                }),
+            cx,
+        ))
+    }
+
+    fn render_tour(&self, config: &StudioConfig, cx: &App) -> Div {
+        Self::section("Tour", cx).child(Self::row(
+            "First-run tour",
+            "Walks through the sidebar, toolbar, token editor, and metadata panel.",
+            Button::new("settings-show-tour")
+                .label(if config.tour_completed {
+                    "Show again"
+                } else {
+                    "Showing"
+                })
+                .disabled(!config.tour_completed)
+                .on_click(|_event, _window, cx| {
+                    StudioSettings::update(cx, |config| config.tour_completed = false);
+                }),
+            cx,
+        ))
+    }
+
+    fn render_snapshots(&self, config: &StudioConfig, cx: &App) -> Div {
+        let value = config
+            .snapshot_dir
+            .as_ref()
+            .map(|dir| dir.display().to_string())
+            .unwrap_or_default();
+        Self::section("Snapshots", cx).child(Self::row(
+            "Snapshot directory",
+            "Story snapshots used by `gpui docs site` when --snapshots is not given.",
+            div().w(px(200.0)).child(
+                Input::new("settings-snapshot-dir")
+                    .placeholder("snapshots")
+                    .value(value)
+                    .full_width()
+                    .on_change(|text, _window, cx| {
+                        let dir = text.trim();
+                        StudioSettings::update(cx, |config| {
+                            config.snapshot_dir = (!dir.is_empty()).then(|| PathBuf::from(dir));
+                        });
+                    }),
+            ),
+            cx,
+        ))
+    }
+
+    fn render_keybindings(&self, config: &StudioConfig, cx: &App) -> Div {
+        let muted = cx.theme().text.muted;
+        let mut section = Self::section("Keyboard", cx).child(
+            div()
+                .text_xs()
+                .text_color(muted)
+                .child("Type a keystroke such as ctrl-shift-i to replace a default; clear it to restore the default."),
+        );
+        let shortcuts = cx.try_global::<ShortcutRegistry>();
+        let conflicts = shortcuts
+            .map(|shortcuts| shortcuts.conflicts(SECONDARY_IS_CMD))
+            .unwrap_or_default();
+        for command in &COMMANDS {
+            let keys = config
+                .keybindings
+                .get(command.key)
+                .cloned()
+                .unwrap_or_default();
+            // A valid binding may still clash with another command's.
+            let error = keys_error(&keys).or_else(|| {
+                let conflict = conflicts
+                    .iter()
+                    .find(|conflict| conflict.actions.iter().any(|a| a == command.key))?;
+                let others: Vec<&str> = conflict
+                    .actions
+                    .iter()
+                    .filter(|a| *a != command.key)
+                    .filter_map(|a| shortcuts?.get(a))
+                    .map(|shortcut| shortcut.label().as_ref())
+                    .collect();
+                Some(format!("Also bound to {}", others.join(", ")))
+            });
+            let key = command.key;
+            let mut input = Input::new(SharedString::from(format!("settings-keys-{key}")))
+                .placeholder(command.default_keys)
+                .value(keys)
+                .full_width()
+                .on_change(move |text, _window, cx| {
+                    StudioSettings::update(cx, |config| config.set_keybinding(key, text));
+                });
+            if let Some(error) = error {
+                input = input.error(true).error_message(error);
+            }
+            section = section.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        div()
+                            .flex_1()
+                            .text_sm()
+                            .text_color(cx.theme().text.default)
+                            .child(command.label),
+                    )
+                    .child(div().w(px(120.0)).child(Kbd::new(command.default_keys)))
+                    .child(div().w(px(180.0)).child(input)),
+            );
+        }
+        section
+    }
+}
+
+impl Render for SettingsView {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let config = StudioSettings::get(cx).clone();
+        let theme = cx.theme();
+        let background = theme.surface.background;
+        let text_color = theme.text.default;
+        let muted = theme.text.muted;
+
+        div()
+            .id("settings")
+            .size_full()
+            .overflow_y_scroll()
+            .bg(background)
+            .p_6()
+            .flex()
+            .flex_col()
+            .gap_4()
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .child(
+                        div()
+                            .text_lg()
+                            .font_weight(FontWeight::BOLD)
+                            .text_color(text_color)
+                            .child("Settings"),
+                    )
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(muted)
+                            .child(format!("Saved to the [studio] table of {CONFIG_FILE}.")),
+                    ),
+            )
+            .child(self.render_appearance(&config, cx))
+            .child(self.render_privacy(&config, cx))
+            .child(self.render_tour(&config, cx))
+            .child(self.render_snapshots(&config, cx))
+            .child(self.render_keybindings(&config, cx))
+            .child(
+                div().flex().flex_row().justify_end().child(
+                    Button::new("settings-restore-defaults")
+                        .label("Restore defaults")
+                        .variant(ButtonVariant::Ghost)
+                        .on_click(|_event, _window, cx| {
+                            StudioSettings::update(cx, |config| *config = StudioConfig::default());
+                        }),
+                ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commands_have_unique_names_and_valid_default_keys() {
+        let mut names = Vec::new();
+        let mut keys = Vec::new();
+        for command in &COMMANDS {
+            assert_eq!(keys_error(command.default_keys), None, "{}", command.key);
+            assert!(!names.contains(&command.key), "{} twice", command.key);
+            assert!(
+                !keys.contains(&command.default_keys),
+                "{} is bound twice",
+                command.default_keys
+            );
+            names.push(command.key);
+            keys.push(command.default_keys);
+        }
+    }
+
+    #[test]
+    fn keys_error_checks_every_keystroke_of_a_chord() {
+        assert_eq!(keys_error("ctrl-shift-i"), None);
+        assert_eq!(keys_error("ctrl-k ctrl-s"), None);
+        // Clearing a binding restores the default, so empty is valid.
+        assert_eq!(keys_error(""), None);
+        assert!(keys_error("i-ctrl").is_some());
+        assert!(keys_error("ctrl-k i-ctrl").is_some());
+    }
+
+    #[test]
+    fn spacing_grows_with_density() {
+        for pair in Density::ALL.windows(2) {
+            assert!(nav_item_padding(pair[0]) < nav_item_padding(pair[1]));
+            assert!(story_padding(pair[0]) < story_padding(pair[1]));
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! First-run tour of the studio.
+//!
+//! On a first launch the studio walks through its main areas with a
+//! Popover callout attached to each in turn: the story sidebar, the toolbar,
+//! the token editor, and the metadata panel. Finishing or skipping the tour
+//! records `tour_completed` in the `[studio]` settings; Settings can clear it
+//! to show the tour again.
+//!
+//! This module is the step sequence only; `main.rs` reveals each step's panel
+//! and renders the callouts.
+
+use crate::dock::PanelId;
+
+/// A stop on the tour, in the order the tour visits them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TourStep {
+    /// The story list.
+    Sidebar,
+    /// Theme, panel, and mode controls along the top.
+    Toolbar,
+    /// Live token editing.
+    TokenEditor,
+    /// Contract details of the selected story.
+    MetadataPanel,
+}
+
+impl TourStep {
+    /// Every step, in tour order.
+    pub const ALL: [TourStep; 4] = [
+        Self::Sidebar,
+        Self::Toolbar,
+        Self::TokenEditor,
+        Self::MetadataPanel,
+    ];
+
+    /// Callout heading.
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Sidebar => "Browse stories",
+            Self::Toolbar => "Switch themes and modes",
+            Self::TokenEditor => "Edit tokens live",
+            Self::MetadataPanel => "Read the contract",
+        }
+    }
+
+    /// Callout text.
+    pub fn body(self) -> &'static str {
+        match self {
+            Self::Sidebar => {
+                "Every component and primitive has a story. Search the list, \
+                 and open a story in a new tab with a middle click."
+            }
+            Self::Toolbar => {
+                "Cycle themes, open the dashboard, and turn on inspect mode, \
+                 the performance HUD, or the spacing overlay."
+            }
+            Self::TokenEditor => {
+                "Change a token's hex value and every story updates at once. \
+                 Edits are recorded as a patch you can export."
+            }
+            Self::MetadataPanel => {
+                "Props, states, key bindings, and tokens of the selected \
+                 component, straight from its contract."
+            }
+        }
+    }
+
+    /// The docked panel the step points at, shown before its callout opens.
+    /// The toolbar is always visible.
+    pub fn panel(self) -> Option<PanelId> {
+        match self {
+            Self::Sidebar => Some(PanelId::Stories),
+            Self::Toolbar => None,
+            Self::TokenEditor => Some(PanelId::Tokens),
+            Self::MetadataPanel => Some(PanelId::Metadata),
+        }
+    }
+
+    /// The step pointing at `panel`, if any.
+    pub fn for_panel(panel: PanelId) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|step| step.panel() == Some(panel))
+    }
+}
+
+/// Where the tour is: not running, or showing one of [`TourStep::ALL`].
+#[derive(Debug, Default)]
+pub struct TourController {
+    step: Option<usize>,
+}
+
+impl TourController {
+    /// Show the first step, from wherever the tour was.
+    pub fn start(&mut self) {
+        self.step = Some(0);
+    }
+
+    /// Whether a step is showing.
+    pub fn is_active(&self) -> bool {
+        self.step.is_some()
+    }
+
+    /// The step showing, if the tour is running.
+    pub fn current(&self) -> Option<TourStep> {
+        self.step.map(|ix| TourStep::ALL[ix])
+    }
+
+    /// One-based number of the step showing, for "2 of 4".
+    pub fn number(&self) -> Option<usize> {
+        self.step.map(|ix| ix + 1)
+    }
+
+    /// Whether the step showing is the first.
+    pub fn is_first(&self) -> bool {
+        self.step == Some(0)
+    }
+
+    /// Whether the step showing is the last.
+    pub fn is_last(&self) -> bool {
+        self.step == Some(TourStep::ALL.len() - 1)
+    }
+
+    /// Go to the next step. Returns `true` when that ends the tour.
+    pub fn advance(&mut self) -> bool {
+        self.step = self
+            .step
+            .map(|ix| ix + 1)
+            .filter(|ix| *ix < TourStep::ALL.len());
+        self.step.is_none()
+    }
+
+    /// Go back a step; stays on the first.
+    pub fn back(&mut self) {
+        self.step = self.step.map(|ix| ix.saturating_sub(1));
+    }
+
+    /// End the tour where it is.
+    pub fn dismiss(&mut self) {
+        self.step = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_every_step_in_order() {
+        let mut tour = TourController::default();
+        assert!(!tour.is_active());
+        assert_eq!(tour.current(), None);
+
+        tour.start();
+        assert!(tour.is_first());
+        for (ix, step) in TourStep::ALL.into_iter().enumerate() {
+            assert_eq!(tour.current(), Some(step));
+            assert_eq!(tour.number(), Some(ix + 1));
+            assert_eq!(tour.is_last(), ix == TourStep::ALL.len() - 1);
+            assert_eq!(tour.advance(), ix == TourStep::ALL.len() - 1);
+        }
+        assert!(!tour.is_active());
+    }
+
+    #[test]
+    fn back_stops_at_the_first_step_and_dismiss_ends_the_tour() {
+        let mut tour = TourController::default();
+        tour.start();
+        tour.back();
+        assert_eq!(tour.current(), Some(TourStep::Sidebar));
+
+        tour.advance();
+        tour.advance();
+        tour.back();
+        assert_eq!(tour.current(), Some(TourStep::Toolbar));
+
+        tour.dismiss();
+        assert_eq!(tour.number(), None);
+        tour.start();
+        assert_eq!(tour.current(), Some(TourStep::Sidebar));
+    }
+
+    #[test]
+    fn panel_steps_map_back_to_their_panel() {
+        for step in TourStep::ALL {
+            if let Some(panel) = step.panel() {
+                assert_eq!(TourStep::for_panel(panel), Some(step));
+            }
+        }
+        assert_eq!(TourStep::Toolbar.panel(), None);
+        assert_eq!(TourStep::for_panel(PanelId::Notifications), None);
+    }
+}
@@ -1,3 +1,21 @@
+use std::path::PathBuf;
+
+use gpui::{ImageSource, SharedUri};
+
 pub fn init(_cx: &mut gpui::App) {
     // Asset initialization will register fonts and icons here.
 }
+
+/// Resolve an image reference to a source GPUI loads in the background:
+/// `http://` and `https://` URLs are fetched with the app's HTTP client,
+/// anything else is read as a file path (relative to the working directory).
+///
+/// While the image loads, and if it fails, `img()` shows its
+/// `with_loading` and `with_fallback` elements instead.
+pub fn image_source(src: &str) -> ImageSource {
+    if src.starts_with("http://") || src.starts_with("https://") {
+        ImageSource::from(SharedUri::from(src.to_string()))
+    } else {
+        ImageSource::from(PathBuf::from(src))
+    }
+}
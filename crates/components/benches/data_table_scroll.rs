@@ -0,0 +1,35 @@
+//! Criterion benchmarks for DataTable scrolling.
+//!
+//! Times one scrolled frame, from scroll event to finished draw, over tables
+//! of increasing size. With row virtualization the cost stays flat as the row
+//! count grows. Run with `cargo bench -p components --bench data_table_scroll`.
+
+#[path = "../tests/support/mod.rs"]
+mod support;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use gpui::TestAppContext;
+
+/// Rows scrolled per frame.
+const PAGE_ROWS: f32 = 20.0;
+
+fn scroll_frame(c: &mut Criterion) {
+    let mut group = c.benchmark_group("data_table_scroll_frame");
+    for rows in [1_000, 10_000, 100_000] {
+        let mut cx = TestAppContext::single();
+        let cx = support::open_table(&mut cx, rows);
+        let page = PAGE_ROWS * support::ROW_HEIGHT;
+        // Alternate a page down and back up so every frame scrolls.
+        let mut down = false;
+        group.bench_function(BenchmarkId::from_parameter(rows), |b| {
+            b.iter(|| {
+                down = !down;
+                support::scroll_frame(cx, if down { page } else { -page })
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, scroll_frame);
+criterion_main!(benches);
@@ -0,0 +1,400 @@
+//! User avatars: an image with initials fallback and a presence dot, and
+//! `AvatarGroup` stacks with a `+N` overflow bubble.
+//!
+//! Rewrite disposition: written for the workbench on GPUI's `img()`, which
+//! loads the source in the background. Sources resolve through
+//! `assets::image_source` (URLs or file paths); the initials show while the
+//! image loads and stay if it fails, so an avatar never renders empty.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::{Locale, format_integer};
+use theme::ActiveTheme;
+
+/// Size preset of an [`Avatar`] or [`AvatarGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AvatarSize {
+    /// 24px.
+    Small,
+    /// 32px (default).
+    #[default]
+    Medium,
+    /// 40px.
+    Large,
+    /// 56px, for profile headers.
+    XLarge,
+}
+
+impl AvatarSize {
+    /// Diameter of the avatar.
+    pub fn diameter(self) -> f32 {
+        match self {
+            AvatarSize::Small => 24.0,
+            AvatarSize::Medium => 32.0,
+            AvatarSize::Large => 40.0,
+            AvatarSize::XLarge => 56.0,
+        }
+    }
+
+    /// Font size of the initials.
+    fn text_size(self) -> f32 {
+        (self.diameter() * 0.4).round()
+    }
+
+    /// Diameter of the presence dot.
+    fn dot_diameter(self) -> f32 {
+        (self.diameter() * 0.3).round().max(8.0)
+    }
+}
+
+/// Presence shown by an avatar's status dot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl AvatarStatus {
+    /// Label read out for the dot.
+    pub fn label(self) -> &'static str {
+        match self {
+            AvatarStatus::Online => "Online",
+            AvatarStatus::Away => "Away",
+            AvatarStatus::Busy => "Busy",
+            AvatarStatus::Offline => "Offline",
+        }
+    }
+
+    fn color(self, cx: &App) -> Hsla {
+        let theme = cx.theme();
+        match self {
+            AvatarStatus::Online => theme.status.success.foreground,
+            AvatarStatus::Away => theme.status.warning.foreground,
+            AvatarStatus::Busy => theme.status.error.foreground,
+            AvatarStatus::Offline => theme.icon.disabled,
+        }
+    }
+}
+
+/// Up to two uppercase initials for `name`: the first letters of its first
+/// and last words ("Ada Lovelace" → "AL", "grace" → "G"), or `?` if it has none.
+pub fn avatar_initials(name: &str) -> String {
+    let mut words = name
+        .split_whitespace()
+        .filter_map(|word| word.chars().find(|c| c.is_alphanumeric()));
+    let Some(first) = words.next() else {
+        return "?".to_string();
+    };
+    first
+        .to_uppercase()
+        .chain(words.last().into_iter().flat_map(char::to_uppercase))
+        .collect()
+}
+
+/// How many of `total` avatars a group shows and how many the `+N` bubble
+/// counts. A bubble would take the place of an avatar, so one hidden avatar
+/// is shown instead of a `+1`.
+pub fn avatar_group_split(total: usize, max_visible: usize) -> (usize, usize) {
+    let max_visible = max_visible.max(1);
+    if total <= max_visible + 1 {
+        (total, 0)
+    } else {
+        (max_visible, total - max_visible)
+    }
+}
+
+/// Circle showing `text` in the initials colors.
+fn initials_circle(text: SharedString, size: AvatarSize, colors: (Hsla, Hsla)) -> Div {
+    let (background, foreground) = colors;
+    div()
+        .flex()
+        .items_center()
+        .justify_center()
+        .size_full()
+        .rounded_full()
+        .bg(background)
+        .text_color(foreground)
+        .text_size(px(size.text_size()))
+        .font_weight(FontWeight::SEMIBOLD)
+        .child(text)
+}
+
+// ---------------------------------------------------------------------------
+// Avatar
+// ---------------------------------------------------------------------------
+
+/// A round user picture, falling back to the name's initials.
+///
+/// # Usage
+/// ```ignore
+/// Avatar::new("author", "Ada Lovelace")
+///     .src("https://example.com/ada.png")
+///     .size(AvatarSize::Large)
+///     .status(AvatarStatus::Online)
+/// ```
+#[derive(IntoElement)]
+pub struct Avatar {
+    id: ElementId,
+    name: SharedString,
+    src: Option<SharedString>,
+    size: AvatarSize,
+    status: Option<AvatarStatus>,
+    /// Ring drawn around the circle, set by [`AvatarGroup`] to separate
+    /// overlapping avatars.
+    ring: Option<Hsla>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Avatar {
+    /// Create an avatar for `name`, shown as initials until a source is set.
+    pub fn new(id: impl Into<ElementId>, name: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            src: None,
+            size: AvatarSize::default(),
+            status: None,
+            ring: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the image: an `http(s)://` URL or a file path.
+    pub fn src(mut self, src: impl Into<SharedString>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Set the size preset.
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Show a presence dot.
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Returns the component contract for Avatar.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::avatar()
+    }
+}
+
+impl WithIdentifiers for Avatar {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let description = match self.status {
+            Some(status) => SharedString::from(format!("{} ({})", self.name, status.label())),
+            None => self.name.clone(),
+        };
+        publish_identifiers(
+            "Avatar",
+            &self.id,
+            Some(&description),
+            &self.identifiers,
+            cx,
+        );
+
+        let theme = cx.theme();
+        let colors = (theme.element.selected, theme.text.default);
+        let dot_ring = theme.surface.background;
+        let size = self.size;
+        let initials = SharedString::from(avatar_initials(&self.name));
+
+        let picture = match self.src {
+            Some(src) => {
+                let loading = initials.clone();
+                let fallback = initials;
+                img(assets::image_source(&src))
+                    .size_full()
+                    .rounded_full()
+                    .object_fit(ObjectFit::Cover)
+                    .with_loading(move || {
+                        initials_circle(loading.clone(), size, colors).into_any_element()
+                    })
+                    .with_fallback(move || {
+                        initials_circle(fallback.clone(), size, colors).into_any_element()
+                    })
+                    .into_any_element()
+            }
+            None => initials_circle(initials, size, colors).into_any_element(),
+        };
+
+        let dot = self.status.map(|status| {
+            div()
+                .absolute()
+                .right_0()
+                .bottom_0()
+                .size(px(size.dot_diameter()))
+                .rounded_full()
+                .border_2()
+                .border_color(dot_ring)
+                .bg(status.color(cx))
+        });
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .flex_none()
+            .size(px(size.diameter()))
+            .rounded_full()
+            .when_some(self.ring, |this, ring| this.border_2().border_color(ring))
+            .child(
+                div()
+                    .size_full()
+                    .rounded_full()
+                    .overflow_hidden()
+                    .child(picture),
+            )
+            .children(dot)
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// AvatarGroup
+// ---------------------------------------------------------------------------
+
+/// Overlapping avatars, e.g. a document's collaborators. Past
+/// [`max_visible`](AvatarGroup::max_visible) the rest collapse into a `+N`
+/// bubble.
+///
+/// # Usage
+/// ```ignore
+/// AvatarGroup::new("reviewers")
+///     .avatars(people.iter().map(|p| Avatar::new(p.id, &p.name).src(&p.photo)))
+///     .max_visible(3)
+/// ```
+#[derive(IntoElement)]
+pub struct AvatarGroup {
+    id: ElementId,
+    avatars: Vec<Avatar>,
+    max_visible: usize,
+    size: AvatarSize,
+    identifiers: SharedIdentifiers,
+}
+
+impl AvatarGroup {
+    /// Create an empty group.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            avatars: Vec::new(),
+            max_visible: 4,
+            size: AvatarSize::default(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Add an avatar.
+    pub fn child(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    /// Add avatars, front to back.
+    pub fn avatars(mut self, avatars: impl IntoIterator<Item = Avatar>) -> Self {
+        self.avatars.extend(avatars);
+        self
+    }
+
+    /// Set how many avatars show before the `+N` bubble.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Set the size of every avatar in the group.
+    pub fn size(mut self, size: AvatarSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Returns the component contract for AvatarGroup.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::avatar_group()
+    }
+}
+
+impl WithIdentifiers for AvatarGroup {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for AvatarGroup {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let (shown, hidden) = avatar_group_split(self.avatars.len(), self.max_visible);
+        let description = (hidden > 0).then(|| SharedString::from(format!("{hidden} more")));
+        publish_identifiers(
+            "AvatarGroup",
+            &self.id,
+            description.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let theme = cx.theme();
+        let ring = theme.surface.background;
+        let bubble_colors = (theme.element.background, theme.text.muted);
+        let size = self.size;
+        // Each avatar after the first tucks a quarter under its neighbour.
+        let overlap = px(-(size.diameter() * 0.25).round());
+
+        let mut group = div().id(self.id.clone()).flex().flex_row().items_center();
+        for (index, mut avatar) in self.avatars.into_iter().take(shown).enumerate() {
+            avatar.size = size;
+            avatar.ring = Some(ring);
+            group = group.child(div().when(index > 0, |this| this.ml(overlap)).child(avatar));
+        }
+        if hidden > 0 {
+            let count = format_integer(hidden as i64, Locale::global(cx));
+            group = group.child(
+                div()
+                    .ml(overlap)
+                    .flex_none()
+                    .size(px(size.diameter()))
+                    .rounded_full()
+                    .border_2()
+                    .border_color(ring)
+                    .child(initials_circle(
+                        SharedString::from(format!("+{count}")),
+                        size,
+                        bubble_colors,
+                    )),
+            );
+        }
+
+        group.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,371 @@
+//! Status labels: status-colored `Badge` with an optional count, and `Tag`
+//! chips that can be removed.
+//!
+//! Rewrite disposition: token-styled labels written for the workbench,
+//! sharing the size and status presets. Colors come straight from the
+//! `status.*` triplets (background, foreground, border), so a theme's
+//! status palette restyles every badge and tag.
+
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{Locale, format_integer, is_activation_key};
+use theme::ActiveTheme;
+
+/// Size preset shared by [`Badge`] and [`Tag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgeSize {
+    /// 16px tall.
+    Small,
+    /// 20px tall (default).
+    #[default]
+    Medium,
+    /// 24px tall.
+    Large,
+}
+
+impl BadgeSize {
+    /// Height of the badge or tag.
+    pub fn height(self) -> f32 {
+        match self {
+            BadgeSize::Small => 16.0,
+            BadgeSize::Medium => 20.0,
+            BadgeSize::Large => 24.0,
+        }
+    }
+
+    /// Horizontal padding inside the badge or tag.
+    fn padding(self) -> f32 {
+        match self {
+            BadgeSize::Small => 4.0,
+            BadgeSize::Medium => 6.0,
+            BadgeSize::Large => 8.0,
+        }
+    }
+}
+
+/// Status color of a [`Badge`] or [`Tag`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BadgeStatus {
+    /// No status: plain element colors (default).
+    #[default]
+    Neutral,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl BadgeStatus {
+    /// (background, foreground, border) for this status.
+    fn colors(self, cx: &App) -> (Hsla, Hsla, Hsla) {
+        let theme = cx.theme();
+        let triplet = match self {
+            BadgeStatus::Neutral => {
+                return (
+                    theme.element.background,
+                    theme.text.muted,
+                    theme.border.default,
+                );
+            }
+            BadgeStatus::Info => theme.status.info.clone(),
+            BadgeStatus::Success => theme.status.success.clone(),
+            BadgeStatus::Warning => theme.status.warning.clone(),
+            BadgeStatus::Error => theme.status.error.triplet(),
+        };
+        (triplet.background, triplet.foreground, triplet.border)
+    }
+}
+
+/// Text shown for `count`: the count itself, or `max+` once it exceeds `max`.
+pub fn badge_count_label(count: u64, max: u64, locale: &Locale) -> String {
+    let shown = count.min(max) as i64;
+    let digits = format_integer(shown, locale);
+    if count > max {
+        format!("{digits}+")
+    } else {
+        digits
+    }
+}
+
+/// Pill shared by badges and tags.
+fn pill(id: ElementId, size: BadgeSize, colors: (Hsla, Hsla, Hsla)) -> Stateful<Div> {
+    let (background, foreground, border) = colors;
+    let pill = div()
+        .id(id)
+        .flex()
+        .flex_row()
+        .flex_none()
+        .items_center()
+        .gap_1()
+        .h(px(size.height()))
+        .px(px(size.padding()))
+        .rounded_full()
+        .border_1()
+        .border_color(border)
+        .bg(background)
+        .text_color(foreground)
+        .whitespace_nowrap();
+    match size {
+        BadgeSize::Large => pill.text_sm(),
+        BadgeSize::Small | BadgeSize::Medium => pill.text_xs(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Badge
+// ---------------------------------------------------------------------------
+
+/// A small status-colored label, optionally showing a count.
+///
+/// # Usage
+/// ```ignore
+/// Badge::new("errors").label("Errors").count(12).status(BadgeStatus::Error)
+/// Badge::new("unread").count(150) // shows "99+"
+/// ```
+#[derive(IntoElement)]
+pub struct Badge {
+    id: ElementId,
+    label: Option<SharedString>,
+    count: Option<u64>,
+    max_count: u64,
+    size: BadgeSize,
+    status: BadgeStatus,
+    identifiers: SharedIdentifiers,
+}
+
+impl Badge {
+    /// Create an empty neutral badge.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            label: None,
+            count: None,
+            max_count: 99,
+            size: BadgeSize::default(),
+            status: BadgeStatus::default(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the label text.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Show a count after the label.
+    pub fn count(mut self, count: u64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    /// Set the largest count shown before it reads `max+`.
+    pub fn max_count(mut self, max_count: u64) -> Self {
+        self.max_count = max_count;
+        self
+    }
+
+    /// Set the size preset.
+    pub fn size(mut self, size: BadgeSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the status color.
+    pub fn status(mut self, status: BadgeStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Returns the component contract for Badge.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::badge()
+    }
+}
+
+impl WithIdentifiers for Badge {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Badge {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Badge", &self.id, None, &self.identifiers, cx);
+
+        let mut badge = pill(self.id.clone(), self.size, self.status.colors(cx));
+        if let Some(label) = self.label {
+            badge = badge.child(label);
+        }
+        if let Some(count) = self.count {
+            let count = badge_count_label(count, self.max_count, Locale::global(cx));
+            badge = badge.child(div().font_weight(FontWeight::SEMIBOLD).child(count));
+        }
+
+        badge.inspectable(&self.id, Self::contract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tag
+// ---------------------------------------------------------------------------
+
+/// Callback when a tag's remove button is pressed.
+type RemoveCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// A chip naming a filter, label, or selection. With
+/// [`on_remove`](Tag::on_remove) it shows an × that removes it.
+///
+/// # Usage
+/// ```ignore
+/// Tag::new("tag-rust", "rust")
+///     .status(BadgeStatus::Info)
+///     .on_remove(|_window, _cx| remove_tag("rust"))
+/// ```
+#[derive(IntoElement)]
+pub struct Tag {
+    id: ElementId,
+    label: SharedString,
+    size: BadgeSize,
+    status: BadgeStatus,
+    disabled: bool,
+    on_remove: Option<RemoveCallback>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Tag {
+    /// Create a neutral tag.
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            size: BadgeSize::default(),
+            status: BadgeStatus::default(),
+            disabled: false,
+            on_remove: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the size preset.
+    pub fn size(mut self, size: BadgeSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the status color.
+    pub fn status(mut self, status: BadgeStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set whether the tag is muted and cannot be removed.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Make the tag removable; the parent drops it from its list in `handler`.
+    pub fn on_remove(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_remove = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns the component contract for Tag.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::tag()
+    }
+}
+
+impl WithIdentifiers for Tag {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Tag {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Tag", &self.id, None, &self.identifiers, cx);
+
+        let colors = if self.disabled {
+            let theme = cx.theme();
+            (
+                theme.element.disabled,
+                theme.text.disabled,
+                theme.border.disabled,
+            )
+        } else {
+            self.status.colors(cx)
+        };
+        let theme = cx.theme();
+        let hover_bg = theme.ghost_element.hover;
+        let focus_border = theme.border.focused;
+
+        let mut tag = pill(self.id.clone(), self.size, colors).child(self.label);
+
+        if let Some(on_remove) = self.on_remove.filter(|_| !self.disabled) {
+            let focus_handle = window
+                .use_keyed_state(
+                    SharedString::from(format!("{}-remove-focus", self.id)),
+                    cx,
+                    |_, cx| cx.focus_handle(),
+                )
+                .read(cx)
+                .clone();
+            let glyph_size = px(self.size.height() - 6.0);
+            let key_handler = on_remove.clone();
+            tag = tag.pr(px(2.0)).child(
+                div()
+                    .id(SharedString::from(format!("{}-remove", self.id)))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .size(glyph_size)
+                    .rounded_full()
+                    .border_1()
+                    .border_color(if focus_handle.is_focused(window) {
+                        focus_border
+                    } else {
+                        transparent_black()
+                    })
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(hover_bg))
+                    .track_focus(&focus_handle)
+                    .on_click(move |_event, window, cx| on_remove(window, cx))
+                    .on_key_down(move |event, window, cx| {
+                        let key = event.keystroke.key.as_str();
+                        if is_activation_key(event) || key == keys::BACKSPACE || key == keys::DELETE
+                        {
+                            key_handler(window, cx);
+                            cx.stop_propagation();
+                        }
+                    })
+                    .child("×"),
+            );
+        }
+
+        tag.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
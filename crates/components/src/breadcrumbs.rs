@@ -0,0 +1,469 @@
+//! Breadcrumbs component: a path of clickable segments that collapses its
+//! middle into an ellipsis menu when it gets long.
+//!
+//! Fork disposition: adapted from gpui-component's `breadcrumb.rs`.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - gpui-component `crates/ui/src/breadcrumb.rs` (MIT, Zed Industries)
+//! - Modifications: stateless RenderOnce over plain labels with a single
+//!   `on_select` index callback, middle-collapse into an overflow menu
+//!   ([`collapse_breadcrumbs`]), per-label truncation, keyed focus handles.
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{Orientation, classify_nav_key, is_activation_key, is_escape_key, navigate_index};
+use theme::ActiveTheme;
+
+/// What one visible position in a collapsed trail shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BreadcrumbSegment {
+    /// The item at this index.
+    Item(usize),
+    /// An ellipsis standing in for these items, listed in its menu.
+    Overflow(Range<usize>),
+}
+
+/// Lay out `count` items so at most `max_visible` of them show.
+///
+/// Short trails show every item. Longer ones keep the first item and the
+/// last `max_visible - 1`, with one [`BreadcrumbSegment::Overflow`] for the
+/// middle. `max_visible` below 2 is treated as 2, so the root and the
+/// current item always show.
+pub fn collapse_breadcrumbs(count: usize, max_visible: usize) -> Vec<BreadcrumbSegment> {
+    let max_visible = max_visible.max(2);
+    if count <= max_visible {
+        return (0..count).map(BreadcrumbSegment::Item).collect();
+    }
+    let tail_start = count - (max_visible - 1);
+    let mut segments = vec![
+        BreadcrumbSegment::Item(0),
+        BreadcrumbSegment::Overflow(1..tail_start),
+    ];
+    segments.extend((tail_start..count).map(BreadcrumbSegment::Item));
+    segments
+}
+
+/// Callback when a breadcrumb is chosen, with the item's index.
+type BreadcrumbCallback = Rc<dyn Fn(usize, &mut Window, &mut App) + 'static>;
+
+/// A navigation path such as `Workspace / crates / components / src`.
+///
+/// Every item but the last (the current location) is clickable. Trails
+/// longer than [`max_visible`](Breadcrumbs::max_visible) collapse their
+/// middle items into an ellipsis that opens a menu of them, and long labels
+/// truncate with an ellipsis.
+///
+/// # Usage
+/// ```ignore
+/// Breadcrumbs::new("path")
+///     .items(["Workspace", "crates", "components", "src", "lib.rs"])
+///     .max_visible(3)
+///     .on_select(|index, _window, _cx| navigate_to(index))
+/// ```
+#[derive(IntoElement)]
+pub struct Breadcrumbs {
+    id: ElementId,
+    items: Vec<SharedString>,
+    max_visible: usize,
+    max_label_width: Pixels,
+    separator: SharedString,
+    disabled: bool,
+    on_select: Option<BreadcrumbCallback>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Breadcrumbs {
+    /// Create an empty trail.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            max_visible: 4,
+            max_label_width: px(160.0),
+            separator: "/".into(),
+            disabled: false,
+            on_select: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Append an item; the last item added is the current location.
+    pub fn item(mut self, label: impl Into<SharedString>) -> Self {
+        self.items.push(label.into());
+        self
+    }
+
+    /// Append several items.
+    pub fn items(mut self, labels: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.items.extend(labels.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set how many items show before the middle collapses (at least 2).
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Set the width past which a label truncates.
+    pub fn max_label_width(mut self, width: Pixels) -> Self {
+        self.max_label_width = width;
+        self
+    }
+
+    /// Set the text drawn between items.
+    pub fn separator(mut self, separator: impl Into<SharedString>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Set whether the trail ignores clicks and keyboard input.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler called with the index of a chosen item, including
+    /// items chosen from the overflow menu.
+    pub fn on_select(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns the component contract for Breadcrumbs.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::breadcrumbs()
+    }
+}
+
+impl WithIdentifiers for Breadcrumbs {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+/// Colors shared by the segments and the overflow menu.
+#[derive(Clone, Copy)]
+struct CrumbColors {
+    link: Hsla,
+    current: Hsla,
+    disabled: Hsla,
+    separator: Hsla,
+    hover: Hsla,
+    focus: Hsla,
+    menu_background: Hsla,
+    menu_border: Hsla,
+}
+
+impl RenderOnce for Breadcrumbs {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Breadcrumbs", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let colors = CrumbColors {
+            link: theme.text.muted,
+            current: theme.text.default,
+            disabled: theme.text.disabled,
+            separator: theme.text.placeholder,
+            hover: theme.ghost_element.hover,
+            focus: theme.border.focused,
+            menu_background: theme.surface.elevated_surface,
+            menu_border: theme.border.default,
+        };
+
+        let items = Rc::new(self.items);
+        let last = items.len().saturating_sub(1);
+        // Without a handler nothing is clickable, so nothing takes focus.
+        let on_select = self.on_select.filter(|_| !self.disabled);
+
+        let mut trail = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_1()
+            .min_w_0()
+            .text_sm();
+
+        for (position, segment) in collapse_breadcrumbs(items.len(), self.max_visible)
+            .into_iter()
+            .enumerate()
+        {
+            if position > 0 {
+                trail = trail.child(
+                    div()
+                        .flex_shrink_0()
+                        .text_color(colors.separator)
+                        .child(self.separator.clone()),
+                );
+            }
+            let element = match segment {
+                BreadcrumbSegment::Item(index) => {
+                    let on_select = on_select.clone().filter(|_| index != last);
+                    render_item(
+                        &self.id,
+                        index,
+                        items[index].clone(),
+                        index == last,
+                        self.disabled,
+                        self.max_label_width,
+                        on_select,
+                        colors,
+                        window,
+                        cx,
+                    )
+                }
+                BreadcrumbSegment::Overflow(hidden) => render_overflow(
+                    &self.id,
+                    &items,
+                    hidden,
+                    self.disabled,
+                    self.max_label_width,
+                    on_select.clone(),
+                    colors,
+                    window,
+                    cx,
+                ),
+            };
+            trail = trail.child(element);
+        }
+
+        trail.inspectable(&self.id, Self::contract)
+    }
+}
+
+/// Base styling for a clickable segment, with a focus ring when `focused`.
+fn segment_base(id: SharedString, focused: bool, colors: CrumbColors) -> Stateful<Div> {
+    div()
+        .id(id)
+        .flex()
+        .items_center()
+        .flex_shrink_0()
+        .px_1()
+        .h(px(22.0))
+        .rounded_sm()
+        .border_1()
+        .border_color(if focused {
+            colors.focus
+        } else {
+            transparent_black()
+        })
+}
+
+/// Render one item: a link for ancestors, plain text for the current item.
+#[allow(clippy::too_many_arguments)]
+fn render_item(
+    trail_id: &ElementId,
+    index: usize,
+    label: SharedString,
+    current: bool,
+    disabled: bool,
+    max_label_width: Pixels,
+    on_select: Option<BreadcrumbCallback>,
+    colors: CrumbColors,
+    window: &mut Window,
+    cx: &mut App,
+) -> Stateful<Div> {
+    let key = |suffix: &str| SharedString::from(format!("{trail_id}-item-{index}-{suffix}"));
+    let text = div()
+        .max_w(max_label_width)
+        .overflow_hidden()
+        .whitespace_nowrap()
+        .text_ellipsis()
+        .child(label);
+
+    let Some(on_select) = on_select else {
+        let color = if disabled {
+            colors.disabled
+        } else if current {
+            colors.current
+        } else {
+            colors.link
+        };
+        return segment_base(key("segment"), false, colors)
+            .text_color(color)
+            .when(current, |el| el.font_weight(FontWeight::MEDIUM))
+            .child(text);
+    };
+
+    let focus_handle = window
+        .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+        .read(cx)
+        .clone();
+    let hover = colors.hover;
+    let current_color = colors.current;
+    let key_handler = on_select.clone();
+    segment_base(key("segment"), focus_handle.is_focused(window), colors)
+        .text_color(colors.link)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover).text_color(current_color))
+        .track_focus(&focus_handle)
+        .on_click(move |_event, window, cx| on_select(index, window, cx))
+        .on_key_down(move |event, window, cx| {
+            if is_activation_key(event) {
+                key_handler(index, window, cx);
+                cx.stop_propagation();
+            }
+        })
+        .child(text)
+}
+
+/// Render the ellipsis for the `hidden` items and, while open, their menu.
+#[allow(clippy::too_many_arguments)]
+fn render_overflow(
+    trail_id: &ElementId,
+    items: &Rc<Vec<SharedString>>,
+    hidden: Range<usize>,
+    disabled: bool,
+    max_label_width: Pixels,
+    on_select: Option<BreadcrumbCallback>,
+    colors: CrumbColors,
+    window: &mut Window,
+    cx: &mut App,
+) -> Stateful<Div> {
+    let key = |suffix: &str| SharedString::from(format!("{trail_id}-overflow-{suffix}"));
+    let Some(on_select) = on_select else {
+        return segment_base(key("segment"), false, colors)
+            .text_color(if disabled {
+                colors.disabled
+            } else {
+                colors.link
+            })
+            .child("…");
+    };
+
+    let focus_handle = window
+        .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+        .read(cx)
+        .clone();
+    // Highlighted row of the open menu, relative to `hidden.start`; `None`
+    // while closed.
+    let highlighted = window.use_keyed_state(key("menu"), cx, |_, _| None::<usize>);
+    let open_row = *highlighted.read(cx);
+    let count = hidden.len();
+    let first = hidden.start;
+
+    let set_highlighted = {
+        let highlighted = highlighted.clone();
+        move |row: Option<usize>, cx: &mut App| {
+            highlighted.update(cx, |highlighted, cx| {
+                *highlighted = row;
+                cx.notify();
+            });
+        }
+    };
+
+    let hover = colors.hover;
+    let current_color = colors.current;
+    let mut segment = segment_base(key("segment"), focus_handle.is_focused(window), colors)
+        .relative()
+        .text_color(colors.link)
+        .cursor_pointer()
+        .hover(move |s| s.bg(hover).text_color(current_color))
+        .when(open_row.is_some(), |el| el.bg(hover))
+        .track_focus(&focus_handle)
+        .on_click({
+            let set_highlighted = set_highlighted.clone();
+            let focus_handle = focus_handle.clone();
+            move |_event, window, cx| {
+                set_highlighted(if open_row.is_some() { None } else { Some(0) }, cx);
+                window.focus(&focus_handle, cx);
+            }
+        })
+        .on_key_down({
+            let set_highlighted = set_highlighted.clone();
+            let on_select = on_select.clone();
+            move |event, window, cx| match open_row {
+                None => {
+                    if is_activation_key(event) || event.keystroke.key == keys::ARROW_DOWN {
+                        set_highlighted(Some(0), cx);
+                        cx.stop_propagation();
+                    }
+                }
+                Some(row) => {
+                    if let Some(direction) = classify_nav_key(event, Orientation::Vertical) {
+                        set_highlighted(Some(navigate_index(row, direction, count, |_| false)), cx);
+                    } else if is_activation_key(event) {
+                        set_highlighted(None, cx);
+                        on_select(first + row, window, cx);
+                    } else if is_escape_key(event) {
+                        set_highlighted(None, cx);
+                    } else {
+                        return;
+                    }
+                    cx.stop_propagation();
+                }
+            }
+        })
+        .child("…");
+
+    if let Some(row) = open_row {
+        let mut menu = div()
+            .id(key("list"))
+            .absolute()
+            .top(relative(1.0))
+            .left_0()
+            .mt_1()
+            .flex()
+            .flex_col()
+            .py_1()
+            .min_w(px(140.0))
+            .bg(colors.menu_background)
+            .border_1()
+            .border_color(colors.menu_border)
+            .rounded_md()
+            .shadow_lg()
+            .on_mouse_down_out({
+                let set_highlighted = set_highlighted.clone();
+                move |_event, _window, cx| set_highlighted(None, cx)
+            });
+        for (offset, index) in hidden.enumerate() {
+            let set_highlighted = set_highlighted.clone();
+            let on_select = on_select.clone();
+            menu = menu.child(
+                div()
+                    .id(SharedString::from(format!(
+                        "{trail_id}-overflow-item-{index}"
+                    )))
+                    .px_3()
+                    .py_1()
+                    .text_color(colors.current)
+                    .whitespace_nowrap()
+                    .cursor_pointer()
+                    .when(offset == row, |el| el.bg(hover))
+                    .hover(move |s| s.bg(hover))
+                    .child(
+                        div()
+                            .max_w(max_label_width)
+                            .overflow_hidden()
+                            .text_ellipsis()
+                            .child(items[index].clone()),
+                    )
+                    .on_click(move |_event, window, cx| {
+                        set_highlighted(None, cx);
+                        on_select(index, window, cx);
+                    }),
+            );
+        }
+        segment = segment.child(deferred(menu).with_priority(1));
+    }
+
+    segment
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
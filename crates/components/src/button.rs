@@ -9,6 +9,9 @@
 //! - Modifications: Simplified to project scope, rewired to internal token system,
 //!   uses internal primitives for keyboard activation and state management.
 
+use crate::identifiers::{publish_icon_only_identifiers, publish_identifiers};
+use crate::tooltip::{TooltipPlacement, WithTooltip, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers};
 use gpui::*;
 use theme::ActiveTheme;
 
@@ -38,6 +41,17 @@ pub enum ButtonSize {
     Large,
 }
 
+impl ButtonSize {
+    /// Height of the button, and the side of an icon-only button.
+    pub fn height(self) -> f32 {
+        match self {
+            ButtonSize::Small => 24.0,
+            ButtonSize::Medium => 28.0,
+            ButtonSize::Large => 32.0,
+        }
+    }
+}
+
 /// Icon position relative to the label.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum IconPosition {
@@ -62,6 +76,9 @@ type OnClickCallback = Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>
 ///     .on_click(|_event, _window, _cx| {
 ///         println!("Clicked!");
 ///     })
+///
+/// // Square, showing only the icon; the label is read out and shown as the tooltip.
+/// Button::new("close-btn").icon("×").label("Close").icon_only()
 /// ```
 #[derive(IntoElement)]
 pub struct Button {
@@ -74,8 +91,11 @@ pub struct Button {
     disabled: bool,
     selected: bool,
     tooltip: Option<SharedString>,
+    tooltip_placement: TooltipPlacement,
+    identifiers: SharedIdentifiers,
     on_click: Option<OnClickCallback>,
     full_width: bool,
+    icon_only: bool,
 }
 
 impl Button {
@@ -91,8 +111,11 @@ impl Button {
             disabled: false,
             selected: false,
             tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
+            identifiers: SharedIdentifiers::default(),
             on_click: None,
             full_width: false,
+            icon_only: false,
         }
     }
 
@@ -139,6 +162,7 @@ impl Button {
     }
 
     /// Set a tooltip for the button.
+    #[deprecated(note = "use `WithTooltip::tooltip`")]
     pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
         self
@@ -159,108 +183,69 @@ impl Button {
         self
     }
 
+    /// Show only the icon in a square button sized by [`ButtonSize`].
+    ///
+    /// The label is not drawn but stays the accessible label, and is the
+    /// tooltip unless one is set. An icon-only button without a label is
+    /// reported by [`AccessibilityTree::unlabeled`](crate::AccessibilityTree::unlabeled).
+    pub fn icon_only(mut self) -> Self {
+        self.icon_only = true;
+        self
+    }
+
     /// Returns the component contract for Button.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Button", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the button")
-            .optional_prop("label", "Option<SharedString>", "None", "Button label text")
-            .optional_prop(
-                "icon",
-                "Option<SharedString>",
-                "None",
-                "Icon content (text glyph for POC)",
-            )
-            .optional_prop(
-                "icon_position",
-                "IconPosition",
-                "Start",
-                "Icon position relative to label",
-            )
-            .optional_prop(
-                "variant",
-                "ButtonVariant",
-                "Secondary",
-                "Visual variant: Primary, Secondary, Ghost, Danger",
-            )
-            .optional_prop(
-                "size",
-                "ButtonSize",
-                "Medium",
-                "Button size: Small, Medium, Large",
-            )
-            .optional_prop(
-                "disabled",
-                "bool",
-                "false",
-                "Whether the button is disabled",
-            )
-            .optional_prop(
-                "selected",
-                "bool",
-                "false",
-                "Whether the button is in selected state",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .optional_prop(
-                "full_width",
-                "bool",
-                "false",
-                "Whether the button takes full container width",
-            )
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Disabled)
-            .state(ComponentState::Selected)
-            .variant("Primary")
-            .variant("Secondary")
-            .variant("Ghost")
-            .variant("Danger")
-            .token_dep("element.background", "Secondary variant background")
-            .token_dep("element.hover", "Secondary variant hover background")
-            .token_dep("element.active", "Secondary/Primary active background")
-            .token_dep("element.selected", "Selected state background")
-            .token_dep("element.disabled", "Disabled state background")
-            .token_dep(
-                "ghost_element.background",
-                "Ghost variant background (transparent)",
-            )
-            .token_dep("ghost_element.hover", "Ghost variant hover background")
-            .token_dep("ghost_element.active", "Ghost variant active background")
-            .token_dep("text.default", "Label text color")
-            .token_dep("text.muted", "Ghost variant label color")
-            .token_dep("text.disabled", "Disabled label text color")
-            .token_dep("text.accent", "Primary variant label color")
-            .token_dep("icon.default", "Icon color")
-            .token_dep("icon.muted", "Ghost variant icon color")
-            .token_dep("icon.disabled", "Disabled icon color")
-            .token_dep("border.default", "Secondary variant border")
-            .token_dep("border.focused", "Focus ring border color")
-            .token_dep("border.disabled", "Disabled border color")
-            .token_dep("status.error.foreground", "Danger variant text color")
-            .token_dep("status.error.background", "Danger variant background")
-            .token_dep("status.error.border", "Danger variant border")
-            .focus_behavior("Tab/Shift-Tab navigates to/from button. Focus ring shown on focus.")
-            .keyboard_model("Enter or Space activates the button. No arrow key behavior.")
-            .pointer_behavior(
-                "Click activates. Hover shows hover state. Disabled blocks all interaction.",
-            )
-            .state_model(
-                "Stateless (RenderOnce). Disabled and selected are controlled props. \
-                 Hover/active/focused are CSS-driven interaction states.",
-            )
-            .disabled_behavior(
-                "Disabled buttons show reduced opacity, muted text, and ignore clicks.",
-            )
-            .required_file("crates/components/src/button.rs")
-            .build()
+        crate::catalog::button()
+    }
+}
+
+impl WithIdentifiers for Button {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl WithTooltip for Button {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
     }
 }
 
 impl RenderOnce for Button {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if self.icon_only {
+            // The label moves to the tooltip, which is also the description.
+            let label = self.label.take();
+            self.tooltip = self.tooltip.or(label);
+            publish_icon_only_identifiers(
+                "Button",
+                &self.id,
+                self.tooltip.as_ref(),
+                &self.identifiers,
+                cx,
+            );
+        } else {
+            publish_identifiers(
+                "Button",
+                &self.id,
+                self.tooltip.as_ref(),
+                &self.identifiers,
+                cx,
+            );
+        }
+
         let theme = cx.theme();
 
         // Resolve colors based on variant and state
@@ -309,9 +294,9 @@ impl RenderOnce for Button {
                     theme.border.transparent,
                 ),
                 ButtonVariant::Danger => (
-                    theme.status.error.background,
-                    theme.element.hover,
-                    theme.element.active,
+                    theme.status.error.element,
+                    theme.status.error.element_hover,
+                    theme.status.error.element_active,
                     theme.status.error.foreground,
                     theme.status.error.foreground,
                     theme.status.error.border,
@@ -321,15 +306,11 @@ impl RenderOnce for Button {
 
         let focus_border = theme.border.focused;
 
-        // Height based on size
-        let height = match self.size {
-            ButtonSize::Small => px(24.0),
-            ButtonSize::Medium => px(28.0),
-            ButtonSize::Large => px(32.0),
-        };
+        let height = px(self.size.height());
 
-        // Horizontal padding based on size
+        // Horizontal padding based on size; icon-only buttons are square.
         let h_padding = match self.size {
+            _ if self.icon_only => px(0.0),
             ButtonSize::Small => px(8.0),
             ButtonSize::Medium => px(12.0),
             ButtonSize::Large => px(16.0),
@@ -340,7 +321,7 @@ impl RenderOnce for Button {
 
         // Build the element
         let mut el = div()
-            .id(self.id)
+            .id(self.id.clone())
             .flex()
             .flex_row()
             .items_center()
@@ -359,8 +340,10 @@ impl RenderOnce for Button {
                 CursorStyle::PointingHand
             });
 
-        // Full width
-        if self.full_width {
+        // Width: square when icon-only, otherwise full width on request
+        if self.icon_only {
+            el = el.w(height).flex_none();
+        } else if self.full_width {
             el = el.w_full();
         }
 
@@ -435,6 +418,14 @@ impl RenderOnce for Button {
         // Focus ring -- border changes on focus-visible
         let _ = focus_border;
 
-        el
+        attach_tooltip(
+            el,
+            &self.id,
+            self.tooltip,
+            self.tooltip_placement,
+            window,
+            cx,
+        )
+        .inspectable(&self.id, Self::contract)
     }
 }
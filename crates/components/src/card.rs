@@ -0,0 +1,227 @@
+//! Card component: an elevated surface grouping related content.
+//!
+//! Rewrite disposition: written for the workbench so apps can group content
+//! on a themed surface without hand-rolling bordered divs. A card is body
+//! content on `surface.elevated_surface`, with optional header and footer
+//! slots divided from it by `border.variant` rules.
+//!
+//! Giving a card an `on_click` handler makes it interactive: it takes
+//! `element.hover` on hover, is focusable with a `border.focused` ring, and
+//! activates on click, Enter, or Space.
+
+use std::rc::Rc;
+
+use crate::tooltip::{TooltipPlacement, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::is_activation_key;
+use smallvec::SmallVec;
+use theme::ActiveTheme;
+
+/// Callback when an interactive card is activated.
+type CardCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// A surface with optional header and footer.
+///
+/// # Usage
+/// ```ignore
+/// Card::new("usage-card")
+///     .header(Text::new("usage-title", "Usage").weight(TextWeight::Semibold))
+///     .body(Text::new("usage-body", "12 of 20 seats in use"))
+///     .footer(Link::new("usage-manage", "Manage seats"))
+///
+/// Card::new("project-card")
+///     .body(Text::new("project-name", "gpui-workbench"))
+///     .on_click(|_window, _cx| { /* open the project */ })
+/// ```
+#[derive(IntoElement)]
+pub struct Card {
+    id: ElementId,
+    header: Option<AnyElement>,
+    footer: Option<AnyElement>,
+    children: SmallVec<[AnyElement; 2]>,
+    disabled: bool,
+    on_click: Option<CardCallback>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Card {
+    /// Create an empty, static card.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            header: None,
+            footer: None,
+            children: SmallVec::new(),
+            disabled: false,
+            on_click: None,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the header, shown above the body with a rule below it.
+    pub fn header(mut self, content: impl IntoElement) -> Self {
+        self.header = Some(content.into_any_element());
+        self
+    }
+
+    /// Set the footer, shown below the body with a rule above it.
+    pub fn footer(mut self, content: impl IntoElement) -> Self {
+        self.footer = Some(content.into_any_element());
+        self
+    }
+
+    /// Add body content.
+    pub fn body(mut self, content: impl IntoElement) -> Self {
+        self.children.push(content.into_any_element());
+        self
+    }
+
+    /// Set whether an interactive card is disabled: drawn with the disabled
+    /// text and border colors, and inert.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Make the card interactive, firing `handler` on click, or Enter or
+    /// Space while focused.
+    pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set a tooltip for the card.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for Card.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::card()
+    }
+}
+
+impl WithIdentifiers for Card {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Card {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Card",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let theme = cx.theme();
+        let bg = theme.surface.elevated_surface;
+        let hover_bg = theme.element.hover;
+        let border = theme.border.default;
+        let rule = theme.border.variant;
+        let focus_border = theme.border.focused;
+        let text_color = theme.text.default;
+        let disabled_text = theme.text.disabled;
+        let disabled_border = theme.border.disabled;
+
+        let mut card = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .rounded_lg()
+            .border_1()
+            .border_color(border)
+            .bg(bg)
+            .text_color(text_color)
+            .shadow_sm()
+            .overflow_hidden();
+        if let Some(header) = self.header {
+            card = card.child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .border_b_1()
+                    .border_color(rule)
+                    .child(header),
+            );
+        }
+        card = card.child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .px_4()
+                .py_3()
+                .children(self.children),
+        );
+        if let Some(footer) = self.footer {
+            card = card.child(
+                div()
+                    .px_4()
+                    .py_3()
+                    .border_t_1()
+                    .border_color(rule)
+                    .child(footer),
+            );
+        }
+
+        let card = match self.on_click {
+            Some(_) if self.disabled => {
+                card.text_color(disabled_text).border_color(disabled_border)
+            }
+            Some(on_click) => {
+                let focus_handle = window
+                    .use_keyed_state(
+                        SharedString::from(format!("{}-focus", self.id)),
+                        cx,
+                        |_, cx| cx.focus_handle(),
+                    )
+                    .read(cx)
+                    .clone();
+                let focused = focus_handle.is_focused(window);
+                let key_handler = on_click.clone();
+                card.when(focused, |el| el.border_color(focus_border))
+                    .cursor_pointer()
+                    .hover(move |s| s.bg(hover_bg))
+                    .track_focus(&focus_handle)
+                    .on_click(move |_event, window, cx| on_click(window, cx))
+                    .on_key_down(move |event, window, cx| {
+                        if is_activation_key(event) {
+                            key_handler(window, cx);
+                            cx.stop_propagation();
+                        }
+                    })
+            }
+            None => card,
+        };
+
+        attach_tooltip(
+            card,
+            &self.id,
+            self.tooltip,
+            TooltipPlacement::default(),
+            window,
+            cx,
+        )
+        .inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
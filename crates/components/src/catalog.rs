@@ -0,0 +1,3546 @@
+//! Contracts of every component, kept apart from their rendering code.
+//!
+//! Each component's `contract()` returns the contract defined here. Nothing
+//! in this module depends on gpui, so contract metadata (and everything the
+//! registry derives from it) is available in builds without the `gpui`
+//! feature, such as wasm32 or headless CI.
+
+use crate::{
+    ComponentContract, ComponentState, DEFAULT_FRAME_BUDGET_MS, Disposition, FrameBudgetEvidence,
+    PerfBudget, PerfEvidence, Stability,
+};
+
+/// Every component contract, ordered by component name.
+pub fn all() -> Vec<ComponentContract> {
+    vec![
+        avatar(),
+        avatar_group(),
+        badge(),
+        bar_chart(),
+        breadcrumbs(),
+        button(),
+        card(),
+        checkbox(),
+        command_palette(),
+        context_menu(),
+        data_table(),
+        description_list(),
+        dialog(),
+        dropdown_menu(),
+        empty_state(),
+        file_picker(),
+        form(),
+        input(),
+        kbd(),
+        label(),
+        link(),
+        markdown(),
+        menu_bar(),
+        notification_center(),
+        number_input(),
+        password_input(),
+        popover(),
+        progress_bar(),
+        radio(),
+        scroll_area(),
+        search_input(),
+        select(),
+        separator(),
+        skeleton(),
+        slider(),
+        sortable_list(),
+        sparkline(),
+        spinner(),
+        split_pane(),
+        status_bar(),
+        switch(),
+        tabs(),
+        tag(),
+        text(),
+        textarea(),
+        title_bar(),
+        toast(),
+        tooltip(),
+    ]
+}
+
+/// The Avatar contract.
+pub fn avatar() -> ComponentContract {
+    ComponentContract::builder("Avatar", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the avatar")
+        .required_prop(
+            "name",
+            "SharedString",
+            "Person's name; gives the initials and the accessible description",
+        )
+        .prop_example("name", r#""Ada Lovelace""#)
+        .optional_prop(
+            "src",
+            "Option<SharedString>",
+            "None",
+            "Image URL (http/https) or file path, loaded in the background",
+        )
+        .optional_prop(
+            "size",
+            "AvatarSize",
+            "Medium",
+            "Diameter: Small (24px), Medium (32px), Large (40px), XLarge (56px)",
+        )
+        .optional_prop(
+            "status",
+            "Option<AvatarStatus>",
+            "None",
+            "Presence dot: Online, Away, Busy, Offline",
+        )
+        // Avatars are display-only; Error covers an image that failed to load.
+        .state(ComponentState::Error)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .variant("XLarge")
+        .token_dep("element.selected", "Initials background")
+        .token_dep("text.default", "Initials text")
+        .token_dep("surface.background", "Ring around the status dot")
+        .token_dep("status.success.foreground", "Online dot")
+        .token_dep("status.warning.foreground", "Away dot")
+        .token_dep("status.error.foreground", "Busy dot")
+        .token_dep("icon.disabled", "Offline dot")
+        .focus_behavior("Not focusable; avatars are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless. The image loads in the background; the initials show while it \
+             loads and replace it if loading fails.",
+        )
+        .required_file("crates/components/src/avatar.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The AvatarGroup contract.
+pub fn avatar_group() -> ComponentContract {
+    ComponentContract::builder("AvatarGroup", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the group")
+        .optional_prop("avatars", "Vec<Avatar>", "[]", "Avatars, front to back")
+        .optional_prop(
+            "max_visible",
+            "usize",
+            "4",
+            "Avatars shown before the rest collapse into a +N bubble",
+        )
+        .optional_prop(
+            "size",
+            "AvatarSize",
+            "Medium",
+            "Size of every avatar in the group",
+        )
+        // Groups are display-only; Error covers members whose image failed.
+        .state(ComponentState::Error)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .variant("XLarge")
+        .token_dep("surface.background", "Ring separating overlapping avatars")
+        .token_dep("element.background", "Overflow bubble background")
+        .token_dep("text.muted", "Overflow bubble text")
+        .focus_behavior("Not focusable; groups are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless. A single hidden avatar is shown rather than a +1 bubble; \
+             the overflow count is formatted with the app locale.",
+        )
+        .required_file("crates/components/src/avatar.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Badge contract.
+pub fn badge() -> ComponentContract {
+    ComponentContract::builder("Badge", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the badge")
+        .optional_prop(
+            "label",
+            "Option<SharedString>",
+            "None",
+            "Text shown before the count",
+        )
+        .optional_prop(
+            "count",
+            "Option<u64>",
+            "None",
+            "Count shown after the label",
+        )
+        .optional_prop(
+            "max_count",
+            "u64",
+            "99",
+            "Largest count shown; higher counts read `max_count+`",
+        )
+        .optional_prop(
+            "size",
+            "BadgeSize",
+            "Medium",
+            "Height: Small (16px), Medium (20px), Large (24px)",
+        )
+        .optional_prop(
+            "status",
+            "BadgeStatus",
+            "Neutral",
+            "Color: Neutral, Info, Success, Warning, Error",
+        )
+        // Badges are display-only; Error covers the Error status.
+        .state(ComponentState::Error)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .token_dep("element.background", "Neutral background")
+        .token_dep("text.muted", "Neutral text")
+        .token_dep("border.default", "Neutral border")
+        .token_dep("status.info.background", "Info background")
+        .token_dep("status.info.foreground", "Info text")
+        .token_dep("status.info.border", "Info border")
+        .token_dep("status.success.background", "Success background")
+        .token_dep("status.success.foreground", "Success text")
+        .token_dep("status.success.border", "Success border")
+        .token_dep("status.warning.background", "Warning background")
+        .token_dep("status.warning.foreground", "Warning text")
+        .token_dep("status.warning.border", "Warning border")
+        .token_dep("status.error.background", "Error background")
+        .token_dep("status.error.foreground", "Error text")
+        .token_dep("status.error.border", "Error border")
+        .focus_behavior("Not focusable; badges are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model("Stateless. The count is formatted with the app locale on every render.")
+        .required_file("crates/components/src/badge.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The BarChart contract.
+pub fn bar_chart() -> ComponentContract {
+    ComponentContract::builder("BarChart", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the chart")
+        .optional_prop("bars", "Vec<Bar>", "[]", "Labeled values, left to right")
+        .optional_prop(
+            "max",
+            "Option<f64>",
+            "None",
+            "Value drawn at full height; defaults to the largest bar",
+        )
+        .optional_prop("unit", "SharedString", "\"\"", "Value label suffix")
+        .optional_prop("height", "Pixels", "80.0", "Plot area height")
+        // Charts are display-only; Hover is the only visual change (value emphasis).
+        .state(ComponentState::Hover)
+        .token_dep("icon.accent", "Bar fill")
+        .token_dep("element.hover", "Hovered bar fill")
+        .token_dep("element.background", "Bar track background")
+        .token_dep("text.default", "Value label color")
+        .token_dep("text.muted", "Bar label color")
+        .focus_behavior("Not focusable; charts are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .pointer_behavior("Hovering a bar highlights its track.")
+        .state_model("Stateless. Bar heights are value / max, clamped to the plot area.")
+        .required_file("crates/components/src/chart.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Breadcrumbs contract.
+pub fn breadcrumbs() -> ComponentContract {
+    ComponentContract::builder("Breadcrumbs", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the trail")
+        .optional_prop(
+            "items",
+            "Vec<SharedString>",
+            "[]",
+            "Path labels from the root; the last is the current location",
+        )
+        .optional_prop(
+            "max_visible",
+            "usize",
+            "4",
+            "Items shown before the middle collapses into an ellipsis menu (at least 2)",
+        )
+        .optional_prop(
+            "max_label_width",
+            "Pixels",
+            "160.0",
+            "Width past which a label truncates with an ellipsis",
+        )
+        .optional_prop(
+            "separator",
+            "SharedString",
+            "\"/\"",
+            "Text drawn between items",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the trail ignores clicks and keyboard input",
+        )
+        .optional_prop(
+            "on_select",
+            "Option<Rc<dyn Fn(usize, &mut Window, &mut App)>>",
+            "None",
+            "Called with the index of a chosen item; without it nothing is clickable",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Open)
+        .state(ComponentState::Disabled)
+        .token_dep("text.muted", "Ancestor item text color")
+        .token_dep("text.default", "Current item and hovered item text color")
+        .token_dep("text.disabled", "Disabled trail text color")
+        .token_dep("text.placeholder", "Separator color")
+        .token_dep(
+            "ghost_element.hover",
+            "Hovered item and open ellipsis background",
+        )
+        .token_dep("border.focused", "Focus ring on the focused item")
+        .token_dep("surface.elevated_surface", "Overflow menu background")
+        .token_dep("border.default", "Overflow menu border")
+        .focus_behavior(
+            "Clickable items and the ellipsis are focusable in path order. The \
+             current item is not focusable.",
+        )
+        .keyboard_model(
+            "Enter or Space activates the focused item. On the ellipsis, Enter, \
+             Space, or Arrow Down opens the overflow menu; Arrow Up/Down/Home/End \
+             move its highlight, Enter or Space chooses the highlighted item, and \
+             Escape closes it.",
+        )
+        .key_binding(&["enter", "space"], "Activate the focused item")
+        .key_binding(&["down"], "Open the overflow menu from the ellipsis")
+        .key_binding(&["up", "down"], "Move the overflow highlight")
+        .key_binding(&["home", "end"], "Jump to the first or last overflow item")
+        .key_binding(&["escape"], "Close the overflow menu")
+        .pointer_behavior(
+            "Click an ancestor to choose it. Click the ellipsis to open or close the \
+             overflow menu, click a menu row to choose it, or click outside to close it.",
+        )
+        .state_model(
+            "Stateless apart from keyed focus handles and the overflow menu's \
+             highlighted row. Items are plain labels rebuilt by the parent on every \
+             render; the parent navigates in on_select.",
+        )
+        .disabled_behavior(
+            "A disabled trail renders every label muted, takes no focus, and ignores \
+             clicks; the ellipsis does not open.",
+        )
+        .required_file("crates/components/src/breadcrumbs.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Button contract.
+pub fn button() -> ComponentContract {
+    ComponentContract::builder("Button", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the button")
+        .optional_prop("label", "Option<SharedString>", "None", "Button label text")
+        .optional_prop(
+            "icon",
+            "Option<SharedString>",
+            "None",
+            "Icon content (text glyph for POC)",
+        )
+        .optional_prop(
+            "icon_position",
+            "IconPosition",
+            "Start",
+            "Icon position relative to label",
+        )
+        .optional_prop(
+            "variant",
+            "ButtonVariant",
+            "Secondary",
+            "Visual variant: Primary, Secondary, Ghost, Danger",
+        )
+        .optional_prop(
+            "size",
+            "ButtonSize",
+            "Medium",
+            "Button size: Small, Medium, Large",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the button is disabled",
+        )
+        .optional_prop(
+            "selected",
+            "bool",
+            "false",
+            "Whether the button is in selected state",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .optional_prop(
+            "tooltip_placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Tooltip placement relative to the button: Top, Bottom, Left, Right",
+        )
+        .prop_example("tooltip_placement", "TooltipPlacement::Top")
+        .optional_prop(
+            "full_width",
+            "bool",
+            "false",
+            "Whether the button takes full container width",
+        )
+        .optional_prop(
+            "icon_only",
+            "bool",
+            "false",
+            "Show only the icon in a square button (side = size height); the label \
+             is then required as the accessible label and default tooltip",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Selected)
+        .variant("Primary")
+        .variant("Secondary")
+        .variant("Ghost")
+        .variant("Danger")
+        .token_dep("element.background", "Secondary variant background")
+        .token_dep("element.hover", "Secondary variant hover background")
+        .token_dep("element.active", "Secondary/Primary active background")
+        .token_dep("element.selected", "Selected state background")
+        .token_dep("element.disabled", "Disabled state background")
+        .token_dep(
+            "ghost_element.background",
+            "Ghost variant background (transparent)",
+        )
+        .token_dep("ghost_element.hover", "Ghost variant hover background")
+        .token_dep("ghost_element.active", "Ghost variant active background")
+        .token_dep("text.default", "Label text color")
+        .token_dep("text.muted", "Ghost variant label color")
+        .token_dep("text.disabled", "Disabled label text color")
+        .token_dep("text.accent", "Primary variant label color")
+        .token_dep("icon.default", "Icon color")
+        .token_dep("icon.muted", "Ghost variant icon color")
+        .token_dep("icon.disabled", "Disabled icon color")
+        .token_dep("border.default", "Secondary variant border")
+        .token_dep("border.focused", "Focus ring border color")
+        .token_dep("border.disabled", "Disabled border color")
+        .token_dep("status.error.foreground", "Danger variant text color")
+        .token_dep("status.error.element", "Danger variant background")
+        .token_dep(
+            "status.error.element_hover",
+            "Danger variant hover background",
+        )
+        .token_dep(
+            "status.error.element_active",
+            "Danger variant pressed background",
+        )
+        .token_dep("status.error.border", "Danger variant border")
+        .focus_behavior("Tab/Shift-Tab navigates to/from button. Focus ring shown on focus.")
+        .keyboard_model("Enter or Space activates the button. No arrow key behavior.")
+        .key_binding(&["enter", "space"], "Activate the button")
+        .pointer_behavior(
+            "Click activates. Hover shows hover state. Disabled blocks all interaction.",
+        )
+        .state_model(
+            "Stateless (RenderOnce). Disabled and selected are controlled props. \
+             Hover/active/focused are CSS-driven interaction states.",
+        )
+        .disabled_behavior("Disabled buttons show reduced opacity, muted text, and ignore clicks.")
+        .dependency("Tooltip")
+        .required_file("crates/components/src/button.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Card contract.
+pub fn card() -> ComponentContract {
+    ComponentContract::builder("Card", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the card")
+        .optional_prop(
+            "header",
+            "Option<AnyElement>",
+            "None",
+            "Content above the body, divided from it by a rule",
+        )
+        .optional_prop("body", "Vec<AnyElement>", "[]", "Body content, stacked")
+        .optional_prop(
+            "footer",
+            "Option<AnyElement>",
+            "None",
+            "Content below the body, divided from it by a rule",
+        )
+        .optional_prop(
+            "on_click",
+            "Option<Rc<dyn Fn(&mut Window, &mut App)>>",
+            "None",
+            "Makes the card interactive: called on click, or Enter or Space while focused",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether an interactive card is inert",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .variant("Static")
+        .variant("Interactive")
+        .token_dep("surface.elevated_surface", "Card background")
+        .token_dep("border.default", "Card border")
+        .token_dep("border.variant", "Header and footer rules")
+        .token_dep("text.default", "Card text")
+        .token_dep("element.hover", "Interactive card background on hover")
+        .token_dep("border.focused", "Interactive card border while focused")
+        .token_dep("text.disabled", "Disabled card text")
+        .token_dep("border.disabled", "Disabled card border")
+        .focus_behavior(
+            "Focusable only when interactive (on_click set) and enabled; \
+             static cards are skipped by Tab.",
+        )
+        .keyboard_model("Enter or Space on a focused interactive card calls on_click.")
+        .pointer_behavior(
+            "An interactive card takes element.hover and a pointer cursor on hover; \
+             click calls on_click. Static cards do not react.",
+        )
+        .state_model("Stateless apart from an interactive card's keyed focus handle.")
+        .disabled_behavior(
+            "A disabled interactive card uses the disabled text and border colors, \
+             is not focusable, and ignores clicks.",
+        )
+        .required_file("crates/components/src/card.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Checkbox contract.
+pub fn checkbox() -> ComponentContract {
+    ComponentContract::builder("Checkbox", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the checkbox")
+        .optional_prop("label", "Option<SharedString>", "None", "Label text")
+        .optional_prop(
+            "checked",
+            "bool",
+            "false",
+            "Whether the checkbox is checked (controlled)",
+        )
+        .optional_prop(
+            "default_checked",
+            "bool",
+            "false",
+            "Initial checked state when uncontrolled",
+        )
+        .optional_prop(
+            "indeterminate",
+            "bool",
+            "false",
+            "Whether the checkbox is in indeterminate state",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the checkbox is disabled",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .optional_prop(
+            "tooltip_placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Tooltip placement relative to the checkbox: Top, Bottom, Left, Right",
+        )
+        .prop_example("tooltip_placement", "TooltipPlacement::Top")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Selected)
+        .token_dep("element.background", "Unchecked checkbox background")
+        .token_dep("element.hover", "Checkbox hover background")
+        .token_dep("element.selected", "Checked checkbox background")
+        .token_dep("element.disabled", "Disabled checkbox background")
+        .token_dep("text.default", "Label text color")
+        .token_dep("text.accent", "Checkmark color")
+        .token_dep("text.disabled", "Disabled label text color")
+        .token_dep("border.default", "Checkbox border")
+        .token_dep("border.focused", "Focus ring border")
+        .token_dep("border.disabled", "Disabled border")
+        .focus_behavior("Tab/Shift-Tab navigates to/from checkbox. Focus ring shown.")
+        .keyboard_model("Space toggles the checked state. Enter does not activate.")
+        .key_binding(&["space"], "Toggle the checked state")
+        .pointer_behavior("Click toggles checked state. Hover shows hover state.")
+        .state_model(
+            "Dual-mode via ControlledState<bool>: controlled when checked is set, \
+             otherwise uncontrolled starting from default_checked. \
+             on_change fires on every committed change. \
+             Indeterminate is a visual-only state that still reports unchecked when toggled.",
+        )
+        .disabled_behavior("Disabled checkboxes show muted styling and ignore interaction.")
+        .dependency("Tooltip")
+        .required_file("crates/components/src/checkbox.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The CommandPalette contract.
+pub fn command_palette() -> ComponentContract {
+    ComponentContract::builder("CommandPalette", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the palette")
+        .optional_prop(
+            "commands",
+            "Vec<CommandItem>",
+            "[]",
+            "Commands to search, each with a label and optional category, shortcut, and handler",
+        )
+        .prop_example(
+            "commands",
+            r#"vec![CommandItem::new("theme.toggle", "Toggle Theme").category("View")]"#,
+        )
+        .optional_prop(
+            "placeholder",
+            "SharedString",
+            "\"Type a command…\"",
+            "Filter input placeholder",
+        )
+        .optional_prop(
+            "empty_message",
+            "SharedString",
+            "\"No matching commands\"",
+            "Text shown when no command matches",
+        )
+        .optional_prop("width", "Pixels", "560.0", "Panel width")
+        .optional_prop(
+            "max_visible_rows",
+            "usize",
+            "8",
+            "Result rows shown before the list scrolls",
+        )
+        .optional_prop(
+            "autofocus",
+            "bool",
+            "true",
+            "Whether the palette takes focus when it opens",
+        )
+        .state(ComponentState::Open)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Selected)
+        .state(ComponentState::Disabled)
+        .token_dep("surface.elevated_surface", "Panel background")
+        .token_dep("surface.background", "Overlay backdrop (with alpha)")
+        .token_dep("border.default", "Panel border and section dividers")
+        .token_dep("text.default", "Query and command text")
+        .token_dep("text.placeholder", "Placeholder and result count")
+        .token_dep("text.muted", "Empty message and footer hints")
+        .token_dep("text.disabled", "Disabled command text")
+        .token_dep("text.accent", "Matched characters")
+        .token_dep("icon.muted", "Search icon")
+        .token_dep("ghost_element.hover", "Row hover background")
+        .token_dep("ghost_element.selected", "Highlighted row background")
+        .focus_behavior(
+            "Focus is captured when the palette opens and returned on dismiss. \
+             The panel holds focus while open; typing goes to the filter.",
+        )
+        .keyboard_model(
+            "Typing filters the list; Backspace deletes. Up/Down move the highlight, \
+             skipping disabled commands, and Home/End jump to the ends. \
+             Enter runs the highlighted command. Escape dismisses.",
+        )
+        .key_binding(&["up", "down"], "Move the highlight")
+        .key_binding(&["home", "end"], "Jump to the first or last command")
+        .key_binding(&["enter"], "Run the highlighted command")
+        .key_binding(&["escape"], "Dismiss the palette")
+        .pointer_behavior(
+            "Click a command to run it. Clicking the backdrop dismisses; \
+             clicks on the panel stop propagation.",
+        )
+        .state_model(
+            "Query and highlight live in keyed state and reset each time the \
+             palette opens. Commands are fuzzy-matched against \"Category: Label\" \
+             (primitives FuzzyMatch), best first, with matched characters \
+             highlighted. Results render through uniform_list, so only rows in \
+             view are laid out. Running a command dismisses the palette first.",
+        )
+        .disabled_behavior(
+            "Disabled commands are listed in muted text but skipped by arrow keys \
+             and ignore Enter and clicks.",
+        )
+        // Primary feedback within one 60Hz frame (NFR-008).
+        .perf_budget(PerfBudget {
+            max_render_ms: None,
+            max_interaction_ms: Some(DEFAULT_FRAME_BUDGET_MS),
+        })
+        .dependency("Kbd")
+        .required_file("crates/components/src/command_palette.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The ContextMenu contract.
+pub fn context_menu() -> ComponentContract {
+    ComponentContract::builder("ContextMenu", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the menu")
+        .optional_prop(
+            "children",
+            "Vec<AnyElement>",
+            "[]",
+            "Trigger area content; right-clicking it opens the menu",
+        )
+        .optional_prop(
+            "items",
+            "Vec<ContextMenuItem>",
+            "[]",
+            "Menu items: actions, submenus, and separators, each with an \
+             optional shortcut hint",
+        )
+        .optional_prop(
+            "position",
+            "PopoverPosition",
+            "PopoverPosition::below_left()",
+            "Which corner of the menu (its attach corner) sits at the click point",
+        )
+        .optional_prop("width", "Pixels", "200.0", "Menu and submenu width")
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether right-clicking the trigger area is ignored",
+        )
+        .state(ComponentState::Open)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .token_dep("surface.elevated_surface", "Menu background")
+        .token_dep("border.default", "Menu border")
+        .token_dep("border.variant", "Separator line color")
+        .token_dep("text.default", "Item text color")
+        .token_dep("text.disabled", "Disabled item text color")
+        .token_dep("element.hover", "Highlighted item background")
+        .token_dep("icon.muted", "Submenu chevron color")
+        .token_dep(
+            "chrome.toolbar_background",
+            "Shortcut hint key cap background",
+        )
+        .focus_behavior(
+            "Opening the menu moves focus into it; closing it returns focus to \
+             where it was before.",
+        )
+        .keyboard_model(
+            "Arrow Up/Down/Home/End navigate the innermost open menu, skipping \
+             disabled items and separators. Arrow Right, Enter, or Space opens \
+             the highlighted submenu. Arrow Left closes a submenu. Enter or Space \
+             runs the highlighted item. Escape closes a submenu, then the menu.",
+        )
+        .key_binding(&["up", "down"], "Move the highlight")
+        .key_binding(&["home", "end"], "Jump to the first or last item")
+        .key_binding(&["right"], "Open the highlighted submenu")
+        .key_binding(&["left"], "Close the submenu")
+        .key_binding(&["enter", "space"], "Run the highlighted item")
+        .key_binding(&["escape"], "Close the submenu, then the menu")
+        .pointer_behavior(
+            "Right-click the trigger area to open the menu at the pointer. Hover \
+             highlights items and opens submenus. Click runs an item. A click \
+             outside the menus closes them.",
+        )
+        .state_model(
+            "Uncontrolled: the open position and the highlighted row per open menu \
+             (MenuPath) live in keyed state. Items are plain data rebuilt by the \
+             parent on every render.",
+        )
+        .disabled_behavior(
+            "Disabled items are muted, skipped by the arrow keys, and ignore clicks; \
+             a disabled submenu does not open. A disabled ContextMenu ignores \
+             right-clicks.",
+        )
+        .dependency("Kbd")
+        .required_file("crates/components/src/context_menu.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The DataTable contract.
+pub fn data_table() -> ComponentContract {
+    ComponentContract::builder("DataTable", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the table")
+        .required_prop(
+            "columns",
+            "Vec<Column>",
+            "Column definitions, left to right",
+        )
+        .prop_example("columns", r#"vec![Column::new("Name").sortable()]"#)
+        .required_prop(
+            "rows",
+            "Rc<Vec<TableRow>>",
+            "Rows of cell text in column order; pass a shared Rc to reuse the sorted order",
+        )
+        .prop_example("rows", r#"vec![TableRow::new(["Button", "0.1.0"])]"#)
+        .optional_prop(
+            "sort",
+            "Option<ColumnSort>",
+            "None",
+            "Column and direction to sort by (controlled)",
+        )
+        .prop_example("sort", "Some(ColumnSort::ascending(0))")
+        .optional_prop(
+            "default_sort",
+            "ColumnSort",
+            "None",
+            "Initial sort when uncontrolled",
+        )
+        .prop_example("default_sort", "ColumnSort::ascending(0)")
+        .optional_prop(
+            "selected_rows",
+            "BTreeSet<usize>",
+            "{}",
+            "Indices into rows of the selected rows (controlled)",
+        )
+        .prop_example("selected_rows", "[0]")
+        .optional_prop(
+            "default_selected_rows",
+            "BTreeSet<usize>",
+            "{}",
+            "Initially selected rows when uncontrolled",
+        )
+        .prop_example("default_selected_rows", "[0]")
+        .optional_prop(
+            "selection_mode",
+            "SelectionMode",
+            "Single",
+            "How many rows can be selected: None, Single, or Multiple",
+        )
+        .optional_prop("row_height", "Pixels", "28.0", "Height of every row")
+        .optional_prop(
+            "height",
+            "Pixels",
+            "320.0",
+            "Height of the scrolling body, excluding the header",
+        )
+        .optional_prop(
+            "empty_text",
+            "SharedString",
+            "\"No rows\"",
+            "Body text when there are no rows (rendered with EmptyState)",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .optional_prop(
+            "tooltip_placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Tooltip placement relative to the table: Top, Bottom, Left, Right",
+        )
+        .prop_example("tooltip_placement", "TooltipPlacement::Top")
+        .state(ComponentState::Focused)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Selected)
+        .state(ComponentState::Disabled)
+        .token_dep("element.background", "Header background")
+        .token_dep("text.muted", "Header text color")
+        .token_dep("text.accent", "Sort direction indicator")
+        .token_dep("text.default", "Cell text color")
+        .token_dep("text.disabled", "Disabled row text color")
+        .token_dep("border.default", "Table border and header divider")
+        .token_dep("border.variant", "Divider between rows")
+        .token_dep("border.focused", "Table border when focused")
+        .token_dep(
+            "ghost_element.hover",
+            "Hovered or highlighted row, hovered sortable header",
+        )
+        .token_dep("ghost_element.selected", "Selected row background")
+        .focus_behavior(
+            "The table receives focus via Tab or a click anywhere in it. \
+             The highlighted row is tracked separately from the selection.",
+        )
+        .keyboard_model(
+            "Up/Down arrows move the highlighted row in display order (wrapping). \
+             Home/End jump to the first/last row. \
+             Enter/Space selects the highlighted row; in Multiple mode they toggle it. \
+             Disabled rows are skipped during navigation. \
+             The body scrolls to keep the highlighted row in view.",
+        )
+        .key_binding(&["up", "down"], "Move the highlighted row")
+        .key_binding(&["home", "end"], "Jump to the first or last row")
+        .key_binding(
+            &["enter", "space"],
+            "Select (or toggle) the highlighted row",
+        )
+        .pointer_behavior(
+            "Click a row to select it; with the platform modifier (Cmd/Ctrl), \
+             click toggles it instead. Click a sortable header to cycle \
+             ascending, descending, and unsorted.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState for both the sort (Option<ColumnSort>) \
+             and the selection (BTreeSet<usize>): controlled when sort or \
+             selected_rows is set, otherwise uncontrolled from the defaults. \
+             on_sort and on_selection_change fire on every committed change. \
+             Selection is by index into rows, so it survives re-sorting. \
+             Only rows in view are rendered (uniform_list), and the sorted order \
+             is cached until the rows or the sort change.",
+        )
+        .disabled_behavior(
+            "Disabled rows are visually dimmed, skip during keyboard navigation, \
+             and cannot be selected.",
+        )
+        // Primary feedback within one 60Hz frame (NFR-008).
+        .perf_budget(PerfBudget {
+            max_render_ms: None,
+            max_interaction_ms: Some(DEFAULT_FRAME_BUDGET_MS),
+        })
+        // Enforced by tests/frame_budget.rs: every frame scrolling this many
+        // rows must fit worst_frame_ms, or the release-mode test fails.
+        .perf_evidence(PerfEvidence {
+            render_time_ms: None,
+            interaction_latency_ms: None,
+            notes: "Scrolls 100k rows top to bottom in 120 frames, each timed from \
+                    scroll event to finished draw (tests/frame_budget.rs, release); \
+                    per-frame cost by row count in benches/data_table_scroll.rs"
+                .into(),
+            measured_on: None,
+            frame_budget: Some(FrameBudgetEvidence {
+                rows: 100_000,
+                budget_ms: DEFAULT_FRAME_BUDGET_MS,
+                worst_frame_ms: DEFAULT_FRAME_BUDGET_MS,
+            }),
+        })
+        .dependency("EmptyState")
+        .dependency("Tooltip")
+        .required_file("crates/components/src/data_table.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The DescriptionList contract.
+pub fn description_list() -> ComponentContract {
+    ComponentContract::builder("DescriptionList", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the list")
+        .optional_prop(
+            "items",
+            "Vec<DescriptionItem>",
+            "[]",
+            "Term/definition pairs; each may be copyable",
+        )
+        .optional_prop(
+            "layout",
+            "DescriptionLayout",
+            "Horizontal",
+            "Layout: Horizontal (term column) or Stacked (term above definition)",
+        )
+        .optional_prop(
+            "term_width",
+            "Pixels",
+            "120.0",
+            "Term column width in the horizontal layout",
+        )
+        // Interactive states belong to copy buttons.
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .variant("Horizontal")
+        .variant("Stacked")
+        .token_dep("text.muted", "Term text color")
+        .token_dep("text.default", "Definition text color")
+        .token_dep("border.variant", "Divider between pairs")
+        .token_dep("icon.muted", "Copy button color")
+        .token_dep("ghost_element.hover", "Copy button hover background")
+        .focus_behavior("The list is not focusable. Copy buttons are pointer targets.")
+        .keyboard_model("No keyboard interaction; definitions are static text.")
+        .pointer_behavior(
+            "Click the copy button beside a copyable definition to write it \
+             to the clipboard.",
+        )
+        .state_model("Stateless. Pairs render in the order given.")
+        .required_file("crates/components/src/description_list.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Dialog contract.
+pub fn dialog() -> ComponentContract {
+    ComponentContract::builder("Dialog", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop(
+            "id",
+            "ElementId",
+            "Unique identifier for the dialog instance",
+        )
+        .optional_prop("title", "Option<SharedString>", "None", "Dialog title text")
+        .optional_prop(
+            "description",
+            "Option<SharedString>",
+            "None",
+            "Dialog description text",
+        )
+        .optional_prop(
+            "overlay_state",
+            "OverlayStateMachine",
+            "Open",
+            "Open/close lifecycle phase driving enter/exit animation",
+        )
+        .prop_example("overlay_state", "OverlayStateMachine::new()")
+        .optional_prop("width", "Pixels", "480.0", "Dialog width in pixels")
+        .optional_prop(
+            "overlay_closable",
+            "bool",
+            "true",
+            "Whether clicking backdrop closes the dialog",
+        )
+        .optional_prop(
+            "show_close_button",
+            "bool",
+            "true",
+            "Whether to show the X close button",
+        )
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Open)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .token_dep("surface.elevated_surface", "Dialog panel background")
+        .token_dep("border.default", "Dialog panel border")
+        .token_dep("text.default", "Dialog title and body text")
+        .token_dep("text.muted", "Dialog description text")
+        .token_dep("surface.background", "Overlay backdrop (with alpha)")
+        .token_dep("ghost_element.hover", "Close button hover state")
+        .focus_behavior(
+            "Focus trap: Tab/Shift-Tab cycle within dialog. \
+             Focus captured on open, returned to trigger on close.",
+        )
+        .keyboard_model(
+            "Escape dismisses the dialog when it is the topmost layer in the \
+             LayerManager stack. Enter is not bound by default (action buttons \
+             handle their own activation).",
+        )
+        .key_binding(&["escape"], "Dismiss the dialog")
+        .pointer_behavior(
+            "Click on backdrop dismisses (if overlay_closable). \
+             Click on close button dismisses. \
+             Mouse events on dialog panel stop propagation to backdrop.",
+        )
+        .state_model(
+            "Controlled open/close via OverlayStateMachine \
+             (Closed -> Opening -> Open -> Closing -> Closed). \
+             Dialog is created settled Open; Opening/Closing fade the overlay in/out. \
+             Dismissal during Opening moves straight to Closing. Closing returns focus. \
+             While visible the dialog is a Modal layer in the LayerManager stack: \
+             stacked dialogs paint in open order, only the topmost draws the scrim, \
+             and a dialog below another modal ignores input.",
+        )
+        .required_file("crates/components/src/dialog.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The DropdownMenu contract.
+pub fn dropdown_menu() -> ComponentContract {
+    ComponentContract::builder("DropdownMenu", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the menu")
+        .required_prop(
+            "items",
+            "Vec<MenuItem>",
+            "Menu items to display: actions, checkbox and radio items, \
+             optionally labeled separators, each with an optional icon \
+             and shortcut hint",
+        )
+        .optional_prop(
+            "trigger_label",
+            "SharedString",
+            "\"Menu\"",
+            "Trigger button label",
+        )
+        .optional_prop("open", "bool", "false", "Whether the menu is open")
+        .optional_prop("disabled", "bool", "false", "Whether the menu is disabled")
+        .optional_prop("width", "Pixels", "180.0", "Menu dropdown width")
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Open)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Selected)
+        .token_dep("element.background", "Trigger button background")
+        .token_dep("element.hover", "Trigger and item hover background")
+        .token_dep("element.active", "Active item background")
+        .token_dep("element.disabled", "Disabled state background")
+        .token_dep("surface.elevated_surface", "Menu dropdown background")
+        .token_dep("text.default", "Item text color")
+        .token_dep("text.muted", "Trigger and separator label text color")
+        .token_dep("text.disabled", "Disabled item text color")
+        .token_dep("border.default", "Menu dropdown border")
+        .token_dep("border.variant", "Separator line color")
+        .token_dep("icon.default", "Item icon and check indicator color")
+        .token_dep(
+            "chrome.toolbar_background",
+            "Shortcut hint key cap background",
+        )
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to/from trigger. Focus moves into menu when opened.",
+        )
+        .keyboard_model(
+            "Enter/Space opens menu. Arrow Up/Down navigates items, skipping disabled \
+             items and separators. Enter selects the highlighted item. Space toggles a \
+             highlighted checkbox or radio item (radio items uncheck the rest of their \
+             group) and otherwise selects. Escape closes the menu when it is the \
+             topmost layer.",
+        )
+        .key_binding(&["enter", "space"], "Open the menu")
+        .key_binding(&["up", "down"], "Move the highlight")
+        .key_binding(&["enter"], "Select the highlighted item")
+        .key_binding(&["space"], "Toggle a checkbox or radio item")
+        .key_binding(&["escape"], "Close the menu")
+        .pointer_behavior(
+            "Click trigger toggles menu. Hover highlights items. Click selects; \
+             clicking a checkbox or radio item toggles it.",
+        )
+        .state_model(
+            "Controlled open/close. Highlighted index tracks keyboard focus within menu. \
+             Checked state lives on the caller-owned MenuItem list: on_select receives \
+             the item after toggle_item has been applied. While open the menu is a \
+             Menu layer in the LayerManager stack, which sets its paint order.",
+        )
+        .disabled_behavior("Disabled menu ignores all interaction.")
+        .dependency("Kbd")
+        .required_file("crates/components/src/dropdown_menu.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The EmptyState contract.
+pub fn empty_state() -> ComponentContract {
+    ComponentContract::builder("EmptyState", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the empty state")
+        .optional_prop("title", "SharedString", "\"\"", "Headline text")
+        .optional_prop(
+            "description",
+            "Option<SharedString>",
+            "None",
+            "Supporting text below the title",
+        )
+        .optional_prop(
+            "icon",
+            "Option<SharedString>",
+            "None",
+            "Icon above the title (hidden at Small size)",
+        )
+        .optional_prop(
+            "action",
+            "Option<(SharedString, OnActionCallback)>",
+            "None",
+            "Primary action rendered as a Button",
+        )
+        .optional_prop(
+            "size",
+            "EmptyStateSize",
+            "Medium",
+            "Size: Small, Medium, Large",
+        )
+        // Interactive states belong to the action button.
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .token_dep("text.default", "Title text color")
+        .token_dep("text.muted", "Description text color")
+        .token_dep("icon.muted", "Icon color")
+        .focus_behavior(
+            "The empty state itself is not focusable. The action button \
+             joins the Tab order like any Button.",
+        )
+        .keyboard_model("Action button responds to Enter/Space.")
+        .key_binding(&["enter", "space"], "Activate the action button")
+        .pointer_behavior("Click the action button to trigger the primary action.")
+        .state_model("Stateless. Rendered in place of content when a surface has no data.")
+        .dependency("Button")
+        .required_file("crates/components/src/empty_state.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The FilePicker contract.
+pub fn file_picker() -> ComponentContract {
+    ComponentContract::builder("FilePicker", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the picker")
+        .optional_prop("value", "Vec<PathBuf>", "[]", "Selected files (controlled)")
+        .optional_prop(
+            "default_value",
+            "Vec<PathBuf>",
+            "[]",
+            "Initially selected files when uncontrolled",
+        )
+        .optional_prop(
+            "filters",
+            "Vec<FileFilter>",
+            "[]",
+            "Accepted extensions; empty accepts every file",
+        )
+        .optional_prop(
+            "multiple",
+            "bool",
+            "false",
+            "Allow several files; new picks are added to the selection",
+        )
+        .optional_prop(
+            "trigger",
+            "FilePickerTrigger",
+            "Button",
+            "Trigger: Button or DropZone (set with drop_zone)",
+        )
+        .optional_prop(
+            "label",
+            "Option<SharedString>",
+            "None",
+            "Trigger text; defaults to \"Choose file…\" or the drop prompt",
+        )
+        .optional_prop(
+            "prompt",
+            "Option<SharedString>",
+            "None",
+            "Dialog confirm button text, where the platform supports it",
+        )
+        .optional_prop(
+            "show_list",
+            "bool",
+            "true",
+            "Show the selected files with remove buttons",
+        )
+        .optional_prop(
+            "dialog",
+            "Rc<dyn FileDialog>",
+            "PlatformFileDialog",
+            "Dialog backend; MockFileDialog answers with canned paths",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the picker is disabled",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Error)
+        .variant("Button")
+        .variant("DropZone")
+        .token_dep("element.background", "Trigger background")
+        .token_dep("element.hover", "Trigger hover and drag-over background")
+        .token_dep("element.disabled", "Disabled trigger background")
+        .token_dep("border.default", "Trigger border")
+        .token_dep(
+            "border.focused",
+            "Trigger border while focused, dialog open, or dragged over",
+        )
+        .token_dep("border.disabled", "Disabled trigger border")
+        .token_dep("text.default", "Trigger text and file names")
+        .token_dep("text.muted", "Filter hint, directories, and remove buttons")
+        .token_dep("text.disabled", "Disabled text")
+        .token_dep("ghost_element.hover", "Remove button hover background")
+        .token_dep("status.error.foreground", "Skipped-file message")
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to/from the trigger. Focus ring shown while \
+             focused and while the dialog is open.",
+        )
+        .keyboard_model("Enter/Space on the focused trigger opens the file dialog.")
+        .key_binding(&["enter", "space"], "Open the file dialog")
+        .pointer_behavior(
+            "Click the trigger to open the file dialog; presses while it is open are \
+             ignored. The drop zone also accepts files dragged from the desktop. \
+             Click × to remove a file from the selection.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<Vec<PathBuf>>: controlled when value is set, \
+             otherwise uncontrolled starting from default_value. Picked and dropped \
+             paths failing the filters are skipped and reported; on_change fires with \
+             the new selection after a pick, drop, or removal.",
+        )
+        .disabled_behavior(
+            "Disabled pickers show muted styling and ignore clicks, keys, and drops; \
+             their remove buttons do nothing.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/file_picker.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Form contract.
+pub fn form() -> ComponentContract {
+    ComponentContract::builder("Form", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the form")
+        .optional_prop(
+            "fields",
+            "Vec<FormField>",
+            "[]",
+            "Labeled fields, each with a control, help text, and validation state",
+        )
+        .optional_prop(
+            "on_submit",
+            "Option<Rc<dyn Fn(&mut Window, &mut App)>>",
+            "None",
+            "Called by submit while every field is valid",
+        )
+        .optional_prop(
+            "on_reset",
+            "Option<Rc<dyn Fn(&mut Window, &mut App)>>",
+            "None",
+            "Called by reset; the reset button is shown only when set",
+        )
+        .optional_prop(
+            "submit_label",
+            "SharedString",
+            "\"Submit\"",
+            "Submit button label",
+        )
+        .optional_prop(
+            "reset_label",
+            "SharedString",
+            "\"Reset\"",
+            "Reset button label",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Error)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .token_dep("text.default", "Field labels")
+        .token_dep("text.muted", "Help text")
+        .token_dep(
+            "status.error.foreground",
+            "Required marker, field error messages, and the error summary",
+        )
+        .token_dep("status.error.background", "Error summary background")
+        .token_dep("status.error.border", "Error summary border")
+        .focus_behavior(
+            "The form takes no focus itself; Tab moves through the field controls in \
+             order, then the reset and submit buttons.",
+        )
+        .keyboard_model(
+            "Controls keep their own keys. Enter or Space on the submit or reset \
+             button activates it.",
+        )
+        .key_binding(
+            &["enter", "space"],
+            "Activate the focused submit or reset button",
+        )
+        .pointer_behavior(
+            "Clicking submit while any field is in error lists those fields in a summary \
+             above the buttons instead of calling on_submit. Clicking reset clears the \
+             summary and calls on_reset.",
+        )
+        .state_model(
+            "Controlled fields: values and validation come from the owner, and the form \
+             is in error when any field is. Whether a blocked submit was attempted lives \
+             in keyed state.",
+        )
+        .dependency("Button")
+        .required_file("crates/components/src/form.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Input contract.
+pub fn input() -> ComponentContract {
+    ComponentContract::builder("Input", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the input")
+        .optional_prop(
+            "value",
+            "SharedString",
+            "\"\"",
+            "Current input value (controlled)",
+        )
+        .optional_prop(
+            "default_value",
+            "SharedString",
+            "\"\"",
+            "Initial value when uncontrolled",
+        )
+        .optional_prop(
+            "placeholder",
+            "SharedString",
+            "\"\"",
+            "Placeholder text when empty",
+        )
+        .optional_prop(
+            "size",
+            "InputSize",
+            "Medium",
+            "Input size: Small, Medium, Large",
+        )
+        .optional_prop("disabled", "bool", "false", "Whether the input is disabled")
+        .optional_prop(
+            "readonly",
+            "bool",
+            "false",
+            "Whether the input is read-only",
+        )
+        .optional_prop(
+            "error",
+            "bool",
+            "false",
+            "Whether the input is in error state",
+        )
+        .optional_prop(
+            "error_message",
+            "Option<SharedString>",
+            "None",
+            "Error message displayed below input",
+        )
+        .optional_prop("prefix", "Option<SharedString>", "None", "Prefix label")
+        .optional_prop("suffix", "Option<SharedString>", "None", "Suffix label")
+        .optional_prop(
+            "on_composition",
+            "Option<CompositionHandler>",
+            "None",
+            "Handler for IME composition updates, commits, and cancels",
+        )
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .optional_prop("full_width", "bool", "false", "Take full container width")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Error)
+        .state(ComponentState::Readonly)
+        .token_dep("element.background", "Input background")
+        .token_dep("element.hover", "Input hover background")
+        .token_dep("element.disabled", "Disabled input background")
+        .token_dep("text.default", "Input text color")
+        .token_dep("text.placeholder", "Placeholder text color")
+        .token_dep("text.disabled", "Disabled text color")
+        .token_dep("text.muted", "Prefix/suffix text color")
+        .token_dep("border.default", "Input border")
+        .token_dep("border.focused", "Focused input border")
+        .token_dep("border.disabled", "Disabled input border")
+        .token_dep("status.error.foreground", "Error message text color")
+        .token_dep("status.error.border", "Error state border color")
+        .token_dep("text.accent", "Text cursor color")
+        .token_dep("element.selected", "Text selection background")
+        .focus_behavior("Tab/Shift-Tab navigates to/from input. Focus shows focused border.")
+        .keyboard_model(
+            "Text arrives through the platform input handler, so printable keys \
+             and input methods enter text. Left/Right move the cursor, Home/End \
+             jump to the ends, Shift extends the selection, Backspace/Delete remove \
+             text, and Cmd/Ctrl-A/C/X/V select all, copy, cut, and paste. \
+             While an IME is composing, preedit text is shown underlined and the \
+             IME owns the keyboard: Enter, arrows, and Backspace go to the IME. \
+             The IME commits the preedit; Escape cancels it and removes the preedit \
+             text. Escape is left to enclosing overlays when nothing is composing.",
+        )
+        .key_binding(&["left", "right"], "Move the cursor")
+        .key_binding(&["home", "end"], "Jump to the start or end")
+        .key_binding(&["shift-left", "shift-right"], "Extend the selection")
+        .key_binding(&["backspace", "delete"], "Delete text")
+        .key_binding(&["secondary-a"], "Select all")
+        .key_binding(
+            &["secondary-c", "secondary-x", "secondary-v"],
+            "Copy, cut, paste",
+        )
+        .key_binding(&["escape"], "Cancel IME composition")
+        .pointer_behavior(
+            "Click focuses the input and places the cursor; Shift-click extends \
+             the selection. Hover shows hover state.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<SharedString>: controlled when value \
+             is set, otherwise uncontrolled starting from default_value. \
+             on_change fires on every committed change, never with preedit text; \
+             on_composition reports composition updates, commits, and cancels. \
+             A controlled value is not applied while a composition is in progress. \
+             Error state shows error border and message. \
+             Readonly allows focus and selection but not editing.",
+        )
+        .disabled_behavior("Disabled inputs show muted styling and cannot be focused.")
+        .readonly_behavior("Readonly inputs can be focused and selected but not edited.")
+        .required_file("crates/components/src/input.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Kbd contract.
+pub fn kbd() -> ComponentContract {
+    ComponentContract::builder("Kbd", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop(
+            "keystroke",
+            "SharedString",
+            "Shortcut in GPUI binding syntax, chords separated by spaces",
+        )
+        .optional_prop(
+            "platform",
+            "KeyPlatform",
+            "KeyPlatform::current()",
+            "Naming conventions: Mac symbols, or Linux/Windows words",
+        )
+        .optional_prop("disabled", "bool", "false", "Render with disabled text")
+        .state(ComponentState::Disabled)
+        .variant("Mac")
+        .variant("Linux")
+        .variant("Windows")
+        .token_dep("chrome.toolbar_background", "Key cap background")
+        .token_dep("border.default", "Key cap border")
+        .token_dep("text.muted", "Key label color")
+        .token_dep("text.disabled", "Disabled key label color")
+        .focus_behavior("Not focusable; key caps are static labels.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless. Labels derive from the keystroke and platform; Kbd::for_action \
+             reads the keystroke bound to an action in the ShortcutRegistry.",
+        )
+        .disabled_behavior("Disabled key caps use the disabled text color.")
+        .required_file("crates/components/src/kbd.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Label contract.
+pub fn label() -> ComponentContract {
+    ComponentContract::builder("Label", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .stability(Stability::Beta)
+        .required_prop("id", "ElementId", "Unique identifier for the label")
+        .required_prop("text", "SharedString", "Label text")
+        .prop_example("text", r#""Email""#)
+        .optional_prop(
+            "required",
+            "bool",
+            "false",
+            "Whether the labeled control is required; shows a `*`",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the labeled control is disabled",
+        )
+        .optional_prop(
+            "for_input",
+            "Option<(ElementId, Option<FocusHandle>)>",
+            "None",
+            "Element id of the labeled control, and its focus handle to focus on click",
+        )
+        .state(ComponentState::Disabled)
+        .token_dep("text.default", "Label text")
+        .token_dep("text.disabled", "Disabled label text")
+        .token_dep("status.error.foreground", "Required asterisk")
+        .focus_behavior(
+            "Not focusable; clicking a label associated with a focus handle focuses \
+             its control.",
+        )
+        .keyboard_model("No keyboard interaction; the labeled control handles keys.")
+        .pointer_behavior("Click focuses the associated control, if a focus handle was given.")
+        .state_model(
+            "Stateless. The association is published to the accessibility tree as the \
+             `for` metadata key naming the control's element id.",
+        )
+        .disabled_behavior(
+            "A disabled label uses the disabled text color and no longer focuses its control.",
+        )
+        .required_file("crates/components/src/typography.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Link contract.
+pub fn link() -> ComponentContract {
+    ComponentContract::builder("Link", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .stability(Stability::Beta)
+        .required_prop("id", "ElementId", "Unique identifier for the link")
+        .required_prop("label", "SharedString", "Link text")
+        .prop_example("label", r#""Read the docs""#)
+        .optional_prop(
+            "on_click",
+            "Option<Rc<dyn Fn(&mut Window, &mut App)>>",
+            "None",
+            "Called on click, or Enter or Space while focused; without it the link is inert",
+        )
+        .optional_prop("disabled", "bool", "false", "Whether the link is inert")
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text, e.g. the URL (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .token_dep("text.accent", "Link text")
+        .token_dep("link.hover", "Link text while hovered or focused")
+        .token_dep("text.disabled", "Disabled link text")
+        .token_dep("border.focused", "Focus ring")
+        .focus_behavior("Focusable when on_click is set and the link is enabled.")
+        .keyboard_model("Enter or Space on the focused link calls on_click.")
+        .pointer_behavior(
+            "Hover underlines the link in the link.hover color; click calls on_click.",
+        )
+        .state_model("Stateless apart from its keyed focus handle.")
+        .disabled_behavior("A disabled link uses the disabled text color and is not focusable.")
+        .required_file("crates/components/src/typography.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Markdown contract.
+pub fn markdown() -> ComponentContract {
+    ComponentContract::builder("Markdown", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the view")
+        .required_prop("source", "SharedString", "Markdown source text")
+        // Only links are interactive.
+        .state(ComponentState::Hover)
+        .variant("Heading")
+        .variant("Paragraph")
+        .variant("List")
+        .variant("CodeBlock")
+        .token_dep("text.default", "Body and heading text")
+        .token_dep("text.muted", "List markers and code block language tag")
+        .token_dep("text.accent", "Link text and underline")
+        .token_dep("element.background", "Code span and code block background")
+        .token_dep("border.variant", "Code block border")
+        .token_dep("syntax.keyword", "Code keywords")
+        .token_dep("syntax.string", "Code strings and inline code spans")
+        .token_dep("syntax.number", "Code numbers")
+        .token_dep("syntax.comment", "Code comments")
+        .token_dep("syntax.function", "Code function and macro calls")
+        .token_dep("syntax.type", "Code type names")
+        .focus_behavior("Not focusable; links are pointer targets.")
+        .keyboard_model("No keyboard interaction.")
+        .pointer_behavior(
+            "Clicking a link opens http, https, and mailto URLs; other schemes \
+             are ignored.",
+        )
+        .state_model("Stateless. The source is parsed on each render.")
+        .required_file("crates/components/src/markdown.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The MenuBar contract.
+pub fn menu_bar() -> ComponentContract {
+    ComponentContract::builder("MenuBar", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the menu bar")
+        .optional_prop(
+            "menus",
+            "Vec<MenuBarMenu>",
+            "[]",
+            "Titled menus, left to right; each holds ContextMenuItems (actions, \
+             submenus, and separators) and may be disabled",
+        )
+        .prop_example(
+            "menus",
+            r#"vec![MenuBarMenu::new("File", vec![ContextMenuItem::new("New")])]"#,
+        )
+        .optional_prop("width", "Pixels", "200.0", "Menu and submenu width")
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the whole bar ignores the pointer and keyboard",
+        )
+        .state(ComponentState::Open)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .token_dep("text.default", "Title and item text color")
+        .token_dep("text.disabled", "Disabled title and item text color")
+        .token_dep("ghost_element.hover", "Hovered title background")
+        .token_dep(
+            "element.hover",
+            "Open title and highlighted item background",
+        )
+        .token_dep("border.focused", "Focus ring of the active title")
+        .token_dep("surface.elevated_surface", "Menu background")
+        .token_dep("border.default", "Menu border")
+        .token_dep("border.variant", "Separator line color")
+        .token_dep("icon.muted", "Submenu chevron color")
+        .token_dep(
+            "chrome.toolbar_background",
+            "Shortcut hint key cap background",
+        )
+        .focus_behavior(
+            "The bar is a single focus stop; the active title shows a focus ring. \
+             Opening a menu with the pointer moves focus to the bar; closing it \
+             returns focus to where it was before.",
+        )
+        .keyboard_model(
+            "With no menu open, Arrow Left/Right/Home/End move between titles, \
+             skipping disabled menus, and Arrow Down, Enter, or Space opens the \
+             active menu (Arrow Up opens it at the last item). In an open menu, \
+             Arrow Up/Down/Home/End move the highlight; Arrow Right opens the \
+             highlighted submenu or moves to the next menu; Arrow Left closes a \
+             submenu or moves to the previous menu; Enter or Space runs the \
+             highlighted item; Escape closes a submenu, then the menu.",
+        )
+        .key_binding(&["left", "right"], "Move between menus")
+        .key_binding(&["down", "up"], "Open the menu, then move the highlight")
+        .key_binding(&["home", "end"], "Jump to the first or last title or item")
+        .key_binding(
+            &["enter", "space"],
+            "Open the menu or run the highlighted item",
+        )
+        .key_binding(&["escape"], "Close the submenu, then the menu")
+        .pointer_behavior(
+            "Click a title to open its menu below it, and again to close it. While \
+             a menu is open, hovering another title opens that menu instead. Hover \
+             highlights items and opens submenus; click runs an item. A click \
+             outside the menus closes them.",
+        )
+        .state_model(
+            "Uncontrolled: the active title and the open menu's MenuPath live in \
+             keyed state (MenuBarState). Menus are plain data rebuilt by the \
+             parent on every render.",
+        )
+        .disabled_behavior(
+            "Disabled menus have muted titles, are skipped by the arrow keys, and \
+             do not open. Disabled items behave as in ContextMenu. A disabled \
+             MenuBar is muted, not focusable, and closes any open menu.",
+        )
+        .dependency("ContextMenu")
+        .dependency("Kbd")
+        .required_file("crates/components/src/menu_bar.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The NotificationCenter contract.
+pub fn notification_center() -> ComponentContract {
+    ComponentContract::builder("NotificationCenter", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the panel")
+        .optional_prop("width", "Pixels", "320.0", "Panel width")
+        .optional_prop(
+            "empty_message",
+            "SharedString",
+            "\"No notifications\"",
+            "Text shown while the history is empty",
+        )
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .token_dep("surface.elevated_surface", "Panel background")
+        .token_dep("border.default", "Panel and header border")
+        .token_dep("text.default", "Header and notification titles")
+        .token_dep(
+            "text.muted",
+            "Day headings, descriptions, times, and buttons",
+        )
+        .token_dep("text.accent", "Unread dot and unread count")
+        .token_dep("ghost_element.hover", "Row and button hover background")
+        .token_dep("status.info.foreground", "Info notification icon")
+        .token_dep("status.success.foreground", "Success notification icon")
+        .token_dep("status.warning.foreground", "Warning notification icon")
+        .token_dep("status.error.foreground", "Error notification icon")
+        .focus_behavior("Not focusable; rows and buttons are pointer targets.")
+        .keyboard_model("No keyboard interaction; the list scrolls with the wheel.")
+        .pointer_behavior(
+            "Click a row to mark it read. Click its action to run the toast's action \
+             again, or × to remove it. Clear all empties the history.",
+        )
+        .state_model(
+            "Renders the app-wide NotificationHistory global, which keeps up to 100 \
+             closed toasts (newest first) with a read flag each. Owners forward the \
+             toasts ToastManager::finish_transitions returns. Notifications are \
+             grouped by the UTC day they were raised: Today, Yesterday, then ISO \
+             dates. The owner marks everything read when it opens the panel.",
+        )
+        .required_file("crates/components/src/notification_center.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The NumberInput contract.
+pub fn number_input() -> ComponentContract {
+    ComponentContract::builder("NumberInput", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the input")
+        .optional_prop("value", "f64", "0.0", "Current value (controlled)")
+        .optional_prop(
+            "default_value",
+            "f64",
+            "0.0",
+            "Initial value when uncontrolled",
+        )
+        .optional_prop(
+            "min",
+            "f64",
+            "f64::NEG_INFINITY",
+            "Smallest accepted value; reversed bounds are swapped",
+        )
+        .optional_prop("max", "f64", "f64::INFINITY", "Largest accepted value")
+        .optional_prop(
+            "step",
+            "f64",
+            "1.0",
+            "Increment of the stepper buttons and Up/Down; non-positive steps use 1.0",
+        )
+        .optional_prop(
+            "placeholder",
+            "SharedString",
+            "\"\"",
+            "Placeholder text when empty",
+        )
+        .optional_prop(
+            "error_message",
+            "Option<SharedString>",
+            "None",
+            "External error shown instead of range errors",
+        )
+        .optional_prop("disabled", "bool", "false", "Whether the input is disabled")
+        .optional_prop("full_width", "bool", "false", "Take full container width")
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Error)
+        .token_dep("element.background", "Field background")
+        .token_dep("element.disabled", "Disabled field background")
+        .token_dep("element.selected", "Text selection background")
+        .token_dep("text.default", "Number text color")
+        .token_dep("text.placeholder", "Placeholder text color")
+        .token_dep(
+            "text.disabled",
+            "Disabled text and unavailable stepper color",
+        )
+        .token_dep("text.muted", "Stepper button glyphs")
+        .token_dep("text.accent", "Text cursor color")
+        .token_dep("border.default", "Field border")
+        .token_dep("border.focused", "Focused and hovered field border")
+        .token_dep("border.disabled", "Disabled field border")
+        .token_dep("border.variant", "Divider before each stepper button")
+        .token_dep("ghost_element.hover", "Stepper button hover background")
+        .token_dep("status.error.foreground", "Error message color")
+        .token_dep("status.error.border", "Error state border color")
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to the field; the stepper buttons are not tab stops. \
+             Click focuses the field. Focus shows the focused border.",
+        )
+        .keyboard_model(
+            "Text editing as in Input. Up/Down step the value up or down by one step. \
+             Enter clamps a typed number into the range, or reverts text that is not a number.",
+        )
+        .key_binding(&["up"], "Step the value up")
+        .key_binding(&["down"], "Step the value down")
+        .key_binding(&["enter"], "Clamp the typed number into the range")
+        .pointer_behavior(
+            "Click focuses the field and places the cursor. The − and + buttons step \
+             the value and focus the field; a button that cannot move the value is muted.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<f64>, with the text as a draft over it. \
+             Typing a number inside min..=max fires on_change; other text sets \
+             ValidationState::Error with a message and leaves the value alone. \
+             Stepping snaps to the grid of min + n * step and clamps to the range. \
+             error_message overrides the range message.",
+        )
+        .disabled_behavior(
+            "Disabled number inputs show muted styling, ignore input, and mute \
+             the stepper buttons.",
+        )
+        .required_file("crates/components/src/number_input.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The PasswordInput contract.
+pub fn password_input() -> ComponentContract {
+    ComponentContract::builder("PasswordInput", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the input")
+        .optional_prop(
+            "value",
+            "SharedString",
+            "\"\"",
+            "Current password (controlled)",
+        )
+        .optional_prop(
+            "default_value",
+            "SharedString",
+            "\"\"",
+            "Initial password when uncontrolled",
+        )
+        .optional_prop(
+            "revealed",
+            "bool",
+            "false",
+            "Whether the password is shown in plain text (controlled)",
+        )
+        .optional_prop(
+            "default_revealed",
+            "bool",
+            "false",
+            "Initial reveal state when uncontrolled",
+        )
+        .optional_prop(
+            "placeholder",
+            "SharedString",
+            "\"Password\"",
+            "Placeholder text when empty",
+        )
+        .optional_prop(
+            "rules",
+            "Vec<PasswordRule>",
+            "PasswordRule::DEFAULT",
+            "Strength and validation rules: MinLength, Lowercase, Uppercase, Digit, Symbol",
+        )
+        .optional_prop("show_meter", "bool", "true", "Show the strength meter")
+        .optional_prop(
+            "error_message",
+            "Option<SharedString>",
+            "None",
+            "External error shown instead of rule errors",
+        )
+        .optional_prop("disabled", "bool", "false", "Whether the input is disabled")
+        .optional_prop("full_width", "bool", "false", "Take full container width")
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Error)
+        .token_dep("element.background", "Field background")
+        .token_dep("element.disabled", "Disabled field background")
+        .token_dep("element.selected", "Text selection background")
+        .token_dep("text.default", "Password text and mask color")
+        .token_dep("text.placeholder", "Placeholder text color")
+        .token_dep("text.disabled", "Disabled text color")
+        .token_dep("text.muted", "Show/Hide toggle color")
+        .token_dep("text.accent", "Text cursor color")
+        .token_dep("border.default", "Field border")
+        .token_dep("border.focused", "Focused and hovered field border")
+        .token_dep("border.disabled", "Disabled field border")
+        .token_dep("border.variant", "Empty strength meter segments")
+        .token_dep("ghost_element.hover", "Show/Hide toggle hover background")
+        .token_dep(
+            "status.error.foreground",
+            "Weak strength and error message color",
+        )
+        .token_dep("status.error.border", "Error state border color")
+        .token_dep("status.warning.foreground", "Fair strength color")
+        .token_dep("status.info.foreground", "Good strength color")
+        .token_dep("status.success.foreground", "Strong strength color")
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to the field, then the Show/Hide toggle. \
+             Click focuses the field. Focus shows the focused border.",
+        )
+        .keyboard_model(
+            "Text editing as in Input (arrows, Home/End, Backspace/Delete, \
+             Cmd/Ctrl-A and paste), except that masked text cannot be copied or cut. \
+             Enter or Space on the focused Show/Hide toggle reveals or masks the password.",
+        )
+        .key_binding(&["left", "right"], "Move the cursor")
+        .key_binding(&["home", "end"], "Jump to the start or end")
+        .key_binding(&["backspace", "delete"], "Delete text")
+        .key_binding(&["secondary-a"], "Select all")
+        .key_binding(&["secondary-v"], "Paste")
+        .key_binding(
+            &["enter", "space"],
+            "Show or hide the password from the toggle",
+        )
+        .pointer_behavior(
+            "Click focuses the field and places the cursor. Clicking Show/Hide \
+             toggles masking without moving focus from the field.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<SharedString> for the password and \
+             ControlledState<bool> for reveal. The strength meter fills one to four \
+             segments (Weak, Fair, Good, Strong) by the share of rules met; failing \
+             a MinLength rule is always Weak. Once the password is non-empty, unmet \
+             rules set ValidationState::Error and are listed in the error message; \
+             error_message overrides the rule message.",
+        )
+        .disabled_behavior(
+            "Disabled password inputs show muted styling, ignore input, and hide \
+             the toggle's interaction.",
+        )
+        .required_file("crates/components/src/password_input.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Popover contract.
+pub fn popover() -> ComponentContract {
+    ComponentContract::builder("Popover", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the popover")
+        .optional_prop("open", "bool", "false", "Whether the popover is visible")
+        .optional_prop(
+            "overlay_state",
+            "OverlayStateMachine",
+            "Closed",
+            "Open/close lifecycle phase driving enter/exit animation",
+        )
+        .prop_example("overlay_state", "OverlayStateMachine::opened()")
+        .optional_prop(
+            "position",
+            "PopoverPosition",
+            "Below",
+            "Placement relative to trigger",
+        )
+        .optional_prop("width", "Option<Pixels>", "None", "Popover width")
+        .optional_prop("max_height", "Pixels", "320.0", "Maximum popover height")
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Open)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .token_dep("surface.elevated_surface", "Popover background")
+        .token_dep("border.default", "Popover border")
+        .token_dep("text.default", "Popover content text")
+        .focus_behavior(
+            "Focus moves into popover when opened. \
+             Tab/Shift-Tab cycles within popover content.",
+        )
+        .keyboard_model("Escape dismisses the popover when it is the topmost layer.")
+        .key_binding(&["escape"], "Dismiss the popover")
+        .pointer_behavior("Outside click dismisses the popover.")
+        .state_model(
+            "Controlled open/close via OverlayStateMachine \
+             (Closed -> Opening -> Open -> Closing -> Closed); the open prop sets a \
+             settled phase. Opening/Closing fade the panel in/out, and dismissal \
+             during Opening moves straight to Closing. While visible the popover is a \
+             Popover layer in the LayerManager stack, which sets its paint order.",
+        )
+        .required_file("crates/components/src/popover.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The ProgressBar contract.
+pub fn progress_bar() -> ComponentContract {
+    ComponentContract::builder("ProgressBar", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the bar")
+        .optional_prop(
+            "value",
+            "f32",
+            "0.0",
+            "Completed fraction, clamped to 0.0..=1.0",
+        )
+        .optional_prop(
+            "size",
+            "ProgressSize",
+            "Medium",
+            "Track height: Small (4px), Medium (8px), Large (12px)",
+        )
+        .optional_prop(
+            "status",
+            "ProgressStatus",
+            "Info",
+            "Fill color: Info, Success, Warning, Error",
+        )
+        .optional_prop(
+            "label",
+            "Option<SharedString>",
+            "None",
+            "Caption shown above the bar",
+        )
+        .optional_prop(
+            "show_percent",
+            "bool",
+            "false",
+            "Show the value as a percentage above the bar",
+        )
+        .optional_prop(
+            "width",
+            "Option<Pixels>",
+            "None",
+            "Fixed width; defaults to the full available width",
+        )
+        // Progress bars are display-only; Error covers the Error status.
+        .state(ComponentState::Error)
+        .variant("Info")
+        .variant("Success")
+        .variant("Warning")
+        .variant("Error")
+        .token_dep("element.background", "Track background")
+        .token_dep("status.info.foreground", "Info fill")
+        .token_dep("status.success.foreground", "Success fill")
+        .token_dep("status.warning.foreground", "Warning fill")
+        .token_dep("status.error.foreground", "Error fill")
+        .token_dep("text.default", "Caption text")
+        .token_dep("text.muted", "Percentage text")
+        .focus_behavior("Not focusable; progress bars are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless apart from the fill transition: when the value changes between \
+             renders the fill eases from the previous value to the new one over 200ms \
+             (jumps when MotionPreference is reduced).",
+        )
+        .required_file("crates/components/src/progress.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Radio contract.
+pub fn radio() -> ComponentContract {
+    ComponentContract::builder("Radio", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the radio group")
+        .required_prop("items", "Vec<RadioItem>", "Radio options to display")
+        .prop_example("items", r#"vec![RadioItem::new("Email")]"#)
+        .optional_prop(
+            "selected_index",
+            "Option<usize>",
+            "None",
+            "Currently selected option index (controlled)",
+        )
+        .optional_prop(
+            "default_selected_index",
+            "Option<usize>",
+            "None",
+            "Initially selected option index when uncontrolled",
+        )
+        .optional_prop("disabled", "bool", "false", "Disable the entire group")
+        .optional_prop(
+            "orientation",
+            "Orientation",
+            "Vertical",
+            "Layout: Vertical or Horizontal",
+        )
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Selected)
+        .token_dep("element.background", "Unselected radio circle background")
+        .token_dep("element.hover", "Radio hover background")
+        .token_dep("element.selected", "Selected radio indicator")
+        .token_dep("element.disabled", "Disabled radio background")
+        .token_dep("text.default", "Label text color")
+        .token_dep("text.accent", "Selected indicator color")
+        .token_dep("text.disabled", "Disabled label text color")
+        .token_dep("border.default", "Radio circle border")
+        .token_dep("border.focused", "Focus ring border")
+        .token_dep("border.disabled", "Disabled border")
+        .focus_behavior(
+            "Tab/Shift-Tab moves focus to/from the radio group. \
+             Only the selected (or first) radio receives tab focus.",
+        )
+        .keyboard_model(
+            "Arrow keys (Up/Down for vertical, Left/Right for horizontal) \
+             navigate between options, skipping disabled items. \
+             Space selects the focused option.",
+        )
+        .key_binding(&["up", "down", "left", "right"], "Move between options")
+        .key_binding(&["space"], "Select the focused option")
+        .pointer_behavior("Click selects an option. Hover shows hover state on individual items.")
+        .state_model(
+            "Dual-mode via ControlledState<Option<usize>>: controlled when \
+             selected_index is set, otherwise uncontrolled starting from \
+             default_selected_index. on_change fires on every committed change. \
+             Individual items and entire group can be disabled independently.",
+        )
+        .disabled_behavior(
+            "Disabled group: all items show muted styling. \
+             Disabled individual items: skip during keyboard navigation.",
+        )
+        .required_file("crates/components/src/radio.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The ScrollArea contract.
+pub fn scroll_area() -> ComponentContract {
+    ComponentContract::builder("ScrollArea", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the area")
+        .optional_prop(
+            "axes",
+            "ScrollAxes",
+            "Vertical",
+            "Axes that scroll: Vertical, Horizontal, or Both",
+        )
+        .optional_prop(
+            "track_scroll",
+            "Option<ScrollAreaHandle>",
+            "None",
+            "Handle for scrolling from code: scroll_to, scroll_by, scroll_to_top, \
+             scroll_to_bottom, scroll_to_item",
+        )
+        .optional_prop(
+            "children",
+            "Vec<AnyElement>",
+            "[]",
+            "Scrolled content, stacked vertically",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .token_dep("scrollbar.track_background", "Scrollbar track fill")
+        .token_dep(
+            "scrollbar.track_border",
+            "Line between the track and the content",
+        )
+        .token_dep("scrollbar.thumb_background", "Thumb fill")
+        .token_dep(
+            "scrollbar.thumb_hover_background",
+            "Thumb fill while hovered or dragged",
+        )
+        .token_dep("scrollbar.thumb_border", "Thumb outline")
+        .focus_behavior(
+            "Not focusable; focusable content keeps its own focus and scrolls with the wheel.",
+        )
+        .keyboard_model(
+            "No keys of its own. Owners scroll from code through ScrollAreaHandle, for \
+             example to keep a keyboard selection in view with scroll_to_item.",
+        )
+        .pointer_behavior(
+            "The wheel and trackpad scroll the content. Dragging the thumb scrolls \
+             proportionally and follows the pointer outside the area until release; \
+             pressing the track pages one viewport toward the press. The thumb switches \
+             to scrollbar.thumb_hover_background while hovered or dragged.",
+        )
+        .state_model(
+            "The offset lives in the ScrollAreaHandle (keyed per id when none is \
+             passed). Scrollbars appear only on axes with at least a pixel of \
+             overflow, sized from the last layout.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/scroll_area.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The SearchInput contract.
+pub fn search_input() -> ComponentContract {
+    ComponentContract::builder("SearchInput", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the search input")
+        .optional_prop(
+            "value",
+            "SharedString",
+            "\"\"",
+            "Current query (controlled)",
+        )
+        .optional_prop(
+            "default_value",
+            "SharedString",
+            "\"\"",
+            "Initial query when uncontrolled",
+        )
+        .optional_prop(
+            "placeholder",
+            "SharedString",
+            "\"Search...\"",
+            "Placeholder text when empty",
+        )
+        .optional_prop(
+            "result_count",
+            "Option<usize>",
+            "None",
+            "Result count suffix shown while a query is entered",
+        )
+        .optional_prop(
+            "debounce",
+            "Duration",
+            "150ms",
+            "Quiet period before on_query fires (SEARCH_DEBOUNCE)",
+        )
+        .optional_prop(
+            "on_query",
+            "Option<OnQueryCallback>",
+            "None",
+            "Debounced handler receiving the settled query",
+        )
+        .optional_prop("disabled", "bool", "false", "Whether the input is disabled")
+        .optional_prop("full_width", "bool", "false", "Take full container width")
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .token_dep("element.background", "Field background")
+        .token_dep("element.disabled", "Disabled field background")
+        .token_dep("border.default", "Field border")
+        .token_dep("border.focused", "Focused and hovered field border")
+        .token_dep("text.default", "Query text color")
+        .token_dep(
+            "text.placeholder",
+            "Placeholder and result count text color",
+        )
+        .token_dep("text.disabled", "Disabled text color")
+        .token_dep("icon.muted", "Magnifier and clear button color")
+        .token_dep("ghost_element.hover", "Clear button hover background")
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to/from the field. Click focuses it. \
+             Focus shows the focused border.",
+        )
+        .keyboard_model(
+            "Printable keys append to the query, Backspace removes the last character. \
+             Escape clears a non-empty query (and otherwise propagates). \
+             Enter fires on_query immediately.",
+        )
+        .key_binding(&["backspace"], "Remove the last character")
+        .key_binding(&["escape"], "Clear the query")
+        .key_binding(&["enter"], "Search immediately")
+        .pointer_behavior(
+            "Click focuses the field. The clear button (shown while a query is \
+             entered) resets the query.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<SharedString>: controlled when value is \
+             set, otherwise uncontrolled starting from default_value. on_change \
+             fires on every keystroke; on_query is debounced by a per-instance \
+             Debouncer and fires immediately on clear and Enter.",
+        )
+        .disabled_behavior("Disabled search inputs show muted styling and ignore input.")
+        .required_file("crates/components/src/search_input.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Select contract.
+pub fn select() -> ComponentContract {
+    ComponentContract::builder("Select", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop(
+            "id",
+            "ElementId",
+            "Unique identifier for the select instance",
+        )
+        .required_prop("items", "Vec<SelectItem>", "List of selectable items")
+        .prop_example("items", r#"vec![SelectItem::new("Apple")]"#)
+        .optional_prop(
+            "selected_index",
+            "Option<usize>",
+            "None",
+            "Currently selected item index (controlled)",
+        )
+        .optional_prop(
+            "default_selected_index",
+            "Option<usize>",
+            "None",
+            "Initially selected item index when uncontrolled",
+        )
+        .optional_prop(
+            "placeholder",
+            "SharedString",
+            "Select...",
+            "Text shown when no item is selected",
+        )
+        .optional_prop(
+            "empty_text",
+            "SharedString",
+            "No options",
+            "Dropdown body text when there are no items (rendered with EmptyState)",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the select is disabled",
+        )
+        .optional_prop("width", "Pixels", "200.0", "Select trigger width")
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip on the trigger (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .optional_prop(
+            "tooltip_placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Tooltip placement relative to the trigger: Top, Bottom, Left, Right",
+        )
+        .prop_example("tooltip_placement", "TooltipPlacement::Top")
+        .state(ComponentState::Open)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Selected)
+        .state(ComponentState::Disabled)
+        .token_dep("element.background", "Trigger button background")
+        .token_dep("element.hover", "Trigger button hover background")
+        .token_dep("border.default", "Trigger and popover border")
+        .token_dep("text.default", "Selected item text")
+        .token_dep("text.placeholder", "Placeholder text")
+        .token_dep("text.disabled", "Disabled item text")
+        .token_dep("surface.elevated_surface", "Popover dropdown background")
+        .token_dep("ghost_element.hover", "Dropdown item hover background")
+        .token_dep(
+            "ghost_element.selected",
+            "Selected dropdown item background",
+        )
+        .focus_behavior(
+            "Trigger receives focus via Tab. Arrow keys navigate items. \
+             Focus returns to trigger on close.",
+        )
+        .keyboard_model(
+            "Enter/Space opens dropdown and selects highlighted item. \
+             Up/Down arrows navigate through items (wrapping). \
+             Escape closes dropdown. Home/End jump to first/last.",
+        )
+        .key_binding(&["enter", "space"], "Open, or choose the highlighted item")
+        .key_binding(&["up", "down"], "Move the highlight")
+        .key_binding(&["home", "end"], "Jump to the first or last item")
+        .key_binding(&["escape"], "Close the dropdown")
+        .pointer_behavior(
+            "Click on trigger toggles dropdown. \
+             Click on item selects it. \
+             Click outside dismisses dropdown.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<Option<usize>>: controlled when \
+             selected_index is set, otherwise uncontrolled starting from \
+             default_selected_index. on_change fires on every committed change. \
+             OpenState tracks popover visibility.",
+        )
+        .disabled_behavior(
+            "Disabled state blocks all interaction, shows reduced-opacity text, \
+             prevents dropdown from opening.",
+        )
+        .dependency("EmptyState")
+        .dependency("Tooltip")
+        .required_file("crates/components/src/select.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Separator contract.
+pub fn separator() -> ComponentContract {
+    ComponentContract::builder("Separator", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the separator")
+        .optional_prop(
+            "orientation",
+            "Orientation",
+            "Horizontal",
+            "Horizontal spans the container's width; Vertical spans its height",
+        )
+        .optional_prop(
+            "label",
+            "Option<SharedString>",
+            "None",
+            "Text shown in a gap at the middle of the rule",
+        )
+        .prop_example("label", r#""or""#)
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the separator belongs to a disabled group",
+        )
+        // Separators are display-only; Disabled follows the group they divide.
+        .state(ComponentState::Disabled)
+        .variant("Horizontal")
+        .variant("Vertical")
+        .token_dep("border.variant", "Rule color")
+        .token_dep("text.muted", "Label text")
+        .token_dep("border.disabled", "Disabled rule color")
+        .token_dep("text.disabled", "Disabled label text")
+        .focus_behavior("Not focusable; separators are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model("Stateless.")
+        .disabled_behavior("A disabled separator uses the disabled border and text colors.")
+        .required_file("crates/components/src/separator.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Skeleton contract.
+pub fn skeleton() -> ComponentContract {
+    ComponentContract::builder("Skeleton", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the skeleton")
+        .optional_prop(
+            "shape",
+            "SkeletonShape",
+            "Line",
+            "Placeholder shape: Line (12px tall), Circle (32px), Rect (64px tall)",
+        )
+        .optional_prop(
+            "width",
+            "Option<Pixels>",
+            "None",
+            "Width, or a circle's diameter; lines and rectangles fill their container",
+        )
+        .optional_prop(
+            "height",
+            "Option<Pixels>",
+            "None",
+            "Height in place of the shape's default; ignored by circles",
+        )
+        // Skeletons are display-only; Active is the running shimmer.
+        .state(ComponentState::Active)
+        .variant("Line")
+        .variant("Circle")
+        .variant("Rect")
+        .token_dep("surface.elevated_surface", "Placeholder fill")
+        .token_dep("element.hover", "Shimmer highlight")
+        .focus_behavior("Not focusable; skeletons are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless. A highlight fades in and out over the shape once every 1500ms \
+             for as long as it is rendered; with reduced motion the shape stays still.",
+        )
+        .required_file("crates/components/src/skeleton.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Slider contract.
+pub fn slider() -> ComponentContract {
+    ComponentContract::builder("Slider", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the slider")
+        .optional_prop("value", "f32", "0.0", "Current value (controlled)")
+        .optional_prop(
+            "default_value",
+            "f32",
+            "0.0",
+            "Initial value when uncontrolled",
+        )
+        .optional_prop(
+            "range",
+            "(f32, f32)",
+            "(0.0, 100.0)",
+            "Smallest and largest values; reversed bounds are swapped",
+        )
+        .optional_prop(
+            "step",
+            "f32",
+            "1.0",
+            "Increment between values; 0.0 allows any value",
+        )
+        .optional_prop(
+            "orientation",
+            "Orientation",
+            "Horizontal",
+            "Track direction; vertical sliders put the maximum at the top",
+        )
+        .optional_prop(
+            "length",
+            "Pixels",
+            "200.0",
+            "Track length along the orientation",
+        )
+        .optional_prop(
+            "show_value",
+            "bool",
+            "false",
+            "Show the current value next to the track",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the slider is disabled",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .token_dep("border.variant", "Track background")
+        .token_dep("text.accent", "Filled track and thumb border")
+        .token_dep("element.background", "Thumb background")
+        .token_dep("element.hover", "Thumb hover background")
+        .token_dep("border.focused", "Thumb border while focused or dragging")
+        .token_dep("element.disabled", "Disabled track background")
+        .token_dep("text.disabled", "Disabled fill and value label")
+        .token_dep("border.disabled", "Disabled thumb border")
+        .token_dep("text.muted", "Value label")
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to/from the slider. Pressing the track focuses it. \
+             The thumb border switches to border.focused while focused.",
+        )
+        .keyboard_model(
+            "Right/Up increase and Left/Down decrease by one step in either orientation. \
+             Page Up/Page Down move ten steps. Home/End jump to the minimum/maximum.",
+        )
+        .key_binding(&["right", "up"], "Increase by one step")
+        .key_binding(&["left", "down"], "Decrease by one step")
+        .key_binding(&["pageup", "pagedown"], "Move ten steps")
+        .key_binding(&["home", "end"], "Jump to the minimum or maximum")
+        .pointer_behavior(
+            "Press anywhere on the track to jump the value there and start dragging. \
+             The drag follows the pointer outside the slider until the button is released.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<f32>: controlled when value is set, \
+             otherwise uncontrolled starting from default_value. Values are clamped \
+             to the range and snapped to the step before on_change fires.",
+        )
+        .disabled_behavior(
+            "Disabled sliders use muted track and thumb colors, are skipped by Tab, \
+             and ignore pointer and keyboard input.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/slider.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The SortableList contract.
+pub fn sortable_list() -> ComponentContract {
+    ComponentContract::builder("SortableList", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .stability(Stability::Experimental)
+        .required_prop("id", "ElementId", "Unique identifier for the list")
+        .optional_prop(
+            "items",
+            "Vec<SortableItem>",
+            "[]",
+            "Rows in their current order, each with a label and optional description",
+        )
+        .optional_prop(
+            "on_reorder",
+            "Option<Rc<dyn Fn(&Reorder, &mut Window, &mut App)>>",
+            "None",
+            "Called with each completed move; the owner applies it to its items",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Selected)
+        .token_dep("element.background", "Row background")
+        .token_dep("ghost_element.hover", "Hovered row background")
+        .token_dep("element.selected", "Row grabbed from the keyboard")
+        .token_dep("border.variant", "List outline and row separators")
+        .token_dep("border.focused", "Focused row outline and drop indicator")
+        .token_dep("text.default", "Row label")
+        .token_dep("text.muted", "Drag handle and row description")
+        .focus_behavior(
+            "The list takes focus as a whole; pressing a row focuses the list and that \
+             row. The focused row is outlined with border.focused.",
+        )
+        .keyboard_model(
+            "Up/Down move focus between rows. Space grabs the focused row; while \
+             grabbed, Up/Down move it one place and Home/End to either end, Space or \
+             Enter drops it, and Escape puts it back. Escape also cancels a pointer drag.",
+        )
+        .key_binding(&["up", "down"], "Move focus, or move the grabbed row")
+        .key_binding(&["space"], "Grab or drop the focused row")
+        .key_binding(&["enter"], "Drop the grabbed row")
+        .key_binding(&["home", "end"], "Move the grabbed row to either end")
+        .key_binding(&["escape"], "Cancel the drag")
+        .pointer_behavior(
+            "Press a row and move the pointer 4px to start dragging it; the row dims and \
+             a drop indicator marks the gap it would land in. Releasing drops it; \
+             releasing beside its old place, or before the drag starts, moves nothing.",
+        )
+        .state_model(
+            "Controlled order: rows render in the order given and each move is reported \
+             through on_reorder. The drag state, focused row, and measured row bounds \
+             live in keyed state.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/sortable_list.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Sparkline contract.
+pub fn sparkline() -> ComponentContract {
+    ComponentContract::builder("Sparkline", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the chart")
+        .required_prop("values", "Vec<f64>", "Series to plot, oldest first")
+        .prop_example("values", "vec![3.0, 5.0, 4.0]")
+        .optional_prop(
+            "threshold",
+            "Option<f64>",
+            "None",
+            "Reference line value; the series scale extends to include it",
+        )
+        .optional_prop("width", "Pixels", "120.0", "Chart width")
+        .optional_prop("height", "Pixels", "24.0", "Chart height")
+        // Charts are display-only; Error covers values above the threshold.
+        .state(ComponentState::Error)
+        .token_dep("icon.accent", "Series line color")
+        .token_dep(
+            "status.warning.foreground",
+            "Threshold line and over-budget line",
+        )
+        .token_dep("element.background", "Chart background")
+        .focus_behavior("Not focusable; charts are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless. The series is scaled between its minimum and maximum \
+             (including the threshold) and drawn as a single stroked path.",
+        )
+        .required_file("crates/components/src/chart.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Spinner contract.
+pub fn spinner() -> ComponentContract {
+    ComponentContract::builder("Spinner", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the spinner")
+        .optional_prop(
+            "size",
+            "ProgressSize",
+            "Medium",
+            "Diameter: Small (12px), Medium (16px), Large (24px)",
+        )
+        .optional_prop(
+            "status",
+            "ProgressStatus",
+            "Info",
+            "Arc color: Info, Success, Warning, Error",
+        )
+        .optional_prop(
+            "label",
+            "Option<SharedString>",
+            "None",
+            "Text shown after the spinner",
+        )
+        // Spinners are display-only; Error covers the Error status.
+        .state(ComponentState::Error)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .token_dep("element.background", "Ring track")
+        .token_dep("status.info.foreground", "Info arc")
+        .token_dep("status.success.foreground", "Success arc")
+        .token_dep("status.warning.foreground", "Warning arc")
+        .token_dep("status.error.foreground", "Error arc")
+        .token_dep("text.muted", "Label text")
+        .focus_behavior("Not focusable; spinners are display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model(
+            "Stateless. A quarter-circle arc rotates around a faint ring once every \
+             900ms for as long as the spinner is rendered.",
+        )
+        .required_file("crates/components/src/progress.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The SplitPane contract.
+pub fn split_pane() -> ComponentContract {
+    ComponentContract::builder("SplitPane", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the split")
+        .optional_prop(
+            "orientation",
+            "Orientation",
+            "Horizontal",
+            "Horizontal places the panes side by side; vertical stacks them",
+        )
+        .optional_prop(
+            "first",
+            "Option<AnyElement>",
+            "None",
+            "Left (or top) pane content",
+        )
+        .optional_prop(
+            "second",
+            "Option<AnyElement>",
+            "None",
+            "Right (or bottom) pane content",
+        )
+        .optional_prop(
+            "anchor",
+            "Pane",
+            "Pane::First",
+            "Pane that keeps its size as the split grows; the other takes the rest",
+        )
+        .prop_example("anchor", "Pane::Second")
+        .optional_prop(
+            "default_size",
+            "Pixels",
+            "240.0",
+            "Initial size of the anchored pane",
+        )
+        .optional_prop(
+            "first_limits",
+            "(Pixels, Pixels)",
+            "(0.0, INFINITY)",
+            "Smallest and largest first pane while it is open",
+        )
+        .prop_example("first_limits", "px(160.0), px(400.0)")
+        .optional_prop(
+            "second_limits",
+            "(Pixels, Pixels)",
+            "(0.0, INFINITY)",
+            "Smallest and largest second pane while it is open",
+        )
+        .prop_example("second_limits", "px(200.0), px(f32::INFINITY)")
+        .optional_prop(
+            "collapsible",
+            "Pane",
+            "None",
+            "Pane that may collapse to zero; call once per pane",
+        )
+        .prop_example("collapsible", "Pane::First")
+        .optional_prop(
+            "on_resize",
+            "Option<Rc<dyn Fn(&Pixels, &mut Window, &mut App)>>",
+            "None",
+            "Called with the anchored pane's size after each drag, key, or collapse",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .token_dep("border.variant", "Divider line")
+        .token_dep(
+            "border.focused",
+            "Divider line while hovered, focused, or dragging",
+        )
+        .focus_behavior(
+            "Pressing the divider focuses it. The divider line switches to \
+             border.focused while focused.",
+        )
+        .keyboard_model(
+            "With the divider focused, Left/Right (horizontal) or Up/Down (vertical) \
+             move it 10px, 50px with Shift. Home/End shrink or grow the first pane to \
+             its limit. Enter collapses or restores the collapsible pane.",
+        )
+        .key_binding(&["left", "right", "up", "down"], "Move the divider 10px")
+        .key_binding(
+            &["shift-left", "shift-right", "shift-up", "shift-down"],
+            "Move the divider 50px",
+        )
+        .key_binding(
+            &["home", "end"],
+            "Shrink or grow the first pane to its limit",
+        )
+        .key_binding(&["enter"], "Collapse or restore the collapsible pane")
+        .pointer_behavior(
+            "Drag the divider to resize; the drag follows the pointer outside the split \
+             until the button is released. Dragging a collapsible pane below half its \
+             minimum collapses it. Double-click the divider to collapse or restore.",
+        )
+        .state_model(
+            "Uncontrolled: the first pane's requested size and the collapsed pane live in \
+             keyed SplitState. Sizes are clamped to both panes' limits against the \
+             measured bounds every frame, and restoring a pane returns its last size.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/split_pane.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The StatusBar contract.
+pub fn status_bar() -> ComponentContract {
+    ComponentContract::builder("StatusBar", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the bar")
+        .optional_prop(
+            "left",
+            "Vec<StatusSegment>",
+            "[]",
+            "Left-aligned segments, in insertion order",
+        )
+        .optional_prop(
+            "center",
+            "Vec<StatusSegment>",
+            "[]",
+            "Centered segments, in insertion order",
+        )
+        .optional_prop(
+            "right",
+            "Vec<StatusSegment>",
+            "[]",
+            "Right-aligned segments, in insertion order",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .token_dep("chrome.status_bar_background", "Bar background")
+        .token_dep("border.default", "Top border")
+        .token_dep("text.muted", "Segment text")
+        .token_dep(
+            "ghost_element.hover",
+            "Interactive segment hover background",
+        )
+        .token_dep("border.focused", "Interactive segment border while focused")
+        .token_dep("status.info.foreground", "Info indicator dot")
+        .token_dep("status.success.foreground", "Success indicator dot")
+        .token_dep("status.warning.foreground", "Warning indicator dot")
+        .token_dep("status.error.foreground", "Error indicator dot")
+        .focus_behavior(
+            "Segments with on_click are focusable in insertion order, left slot \
+             first. Plain segments are skipped.",
+        )
+        .keyboard_model("Space or Enter activates the focused segment.")
+        .key_binding(&["enter", "space"], "Activate the focused segment")
+        .pointer_behavior("Click an interactive segment to run its handler.")
+        .state_model(
+            "Stateless. Segments are plain data rebuilt by the parent on every \
+             render; only the focus handles of interactive segments persist.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/status_bar.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Switch contract.
+pub fn switch() -> ComponentContract {
+    ComponentContract::builder("Switch", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the switch")
+        .optional_prop("label", "Option<SharedString>", "None", "Label text")
+        .optional_prop(
+            "checked",
+            "bool",
+            "false",
+            "Whether the switch is on (controlled)",
+        )
+        .optional_prop(
+            "default_checked",
+            "bool",
+            "false",
+            "Initial on/off state when uncontrolled",
+        )
+        .optional_prop("size", "SwitchSize", "Medium", "Size: Small, Medium, Large")
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the switch is disabled",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip text (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .optional_prop(
+            "tooltip_placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Tooltip placement relative to the switch: Top, Bottom, Left, Right",
+        )
+        .prop_example("tooltip_placement", "TooltipPlacement::Top")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Selected)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .token_dep("element.background", "Off track background")
+        .token_dep("element.hover", "Off track hover background")
+        .token_dep("element.disabled", "Disabled track background")
+        .token_dep("text.accent", "On track background")
+        .token_dep("surface.background", "On thumb color")
+        .token_dep("text.muted", "Off thumb color")
+        .token_dep("text.default", "Label text color")
+        .token_dep("text.disabled", "Disabled thumb and label color")
+        .token_dep("border.default", "Off track border")
+        .token_dep("border.selected", "On track border")
+        .token_dep("border.focused", "Track border while focused")
+        .token_dep("border.disabled", "Disabled track border")
+        .focus_behavior(
+            "Tab/Shift-Tab navigates to/from the switch. Clicking focuses it. \
+             The track border switches to border.focused while focused.",
+        )
+        .keyboard_model("Space or Enter toggles the switch.")
+        .key_binding(&["enter", "space"], "Toggle the switch")
+        .pointer_behavior(
+            "Click the track or label to toggle. The thumb slides over \
+             SWITCH_TRANSITION (120ms) with ease-in-out, or jumps when \
+             MotionPreference is reduced.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<bool>: controlled when checked is set, \
+             otherwise uncontrolled starting from default_checked. \
+             on_change fires on every committed change. Only user toggles animate; \
+             the initial state and parent-driven changes render in place.",
+        )
+        .disabled_behavior("Disabled switches show muted styling and ignore interaction.")
+        .dependency("Tooltip")
+        .required_file("crates/components/src/switch.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Tabs contract.
+pub fn tabs() -> ComponentContract {
+    ComponentContract::builder("Tabs", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the tabs instance")
+        .required_prop("tabs", "Vec<TabItem>", "List of tab definitions")
+        .prop_example("tabs", r#"vec![TabItem::new("Overview")]"#)
+        .optional_prop(
+            "active_index",
+            "usize",
+            "0",
+            "Index of the currently active tab (controlled)",
+        )
+        .optional_prop(
+            "default_active_index",
+            "usize",
+            "0",
+            "Initially active tab index when uncontrolled",
+        )
+        .optional_prop(
+            "tooltip",
+            "Option<SharedString>",
+            "None",
+            "Hover tooltip for tab triggers without their own TabItem::tooltip \
+             (shown after TOOLTIP_HOVER_DELAY)",
+        )
+        .optional_prop(
+            "tooltip_placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Tooltip placement relative to the tab: Top, Bottom, Left, Right",
+        )
+        .prop_example("tooltip_placement", "TooltipPlacement::Top")
+        .optional_prop(
+            "on_close",
+            "Option<Rc<dyn Fn(usize, &mut Window, &mut App)>>",
+            "None",
+            "Makes enabled tabs closable; called with the closed tab's index",
+        )
+        .optional_prop(
+            "show_panel",
+            "bool",
+            "true",
+            "Whether the active tab's content panel renders below the bar",
+        )
+        .state(ComponentState::Focused)
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Selected)
+        .state(ComponentState::Disabled)
+        .token_dep("tab.bar_background", "Tab bar background color")
+        .token_dep("tab.active_background", "Active tab background color")
+        .token_dep("tab.inactive_background", "Inactive tab background color")
+        .token_dep("border.default", "Tab bar bottom border")
+        .token_dep("border.selected", "Active tab indicator")
+        .token_dep("text.default", "Active tab text color")
+        .token_dep("text.muted", "Inactive tab text color")
+        .token_dep("text.disabled", "Disabled tab text color")
+        .token_dep("ghost_element.hover", "Tab hover background")
+        .focus_behavior(
+            "Tab bar receives focus via Tab key. \
+             Left/Right arrows navigate between tabs. \
+             Tab/Shift-Tab moves focus out of the tab bar.",
+        )
+        .keyboard_model(
+            "Left/Right arrows move between tabs (wrapping). \
+             Home/End jump to first/last tab. \
+             Enter/Space activates the focused tab. \
+             Disabled tabs are skipped during navigation.",
+        )
+        .key_binding(&["left", "right"], "Move between tabs")
+        .key_binding(&["home", "end"], "Jump to the first or last tab")
+        .key_binding(&["enter", "space"], "Activate the focused tab")
+        .pointer_behavior(
+            "Click on a tab activates it. \
+             Hover shows highlight and, after TOOLTIP_HOVER_DELAY, the tab's tooltip. \
+             Disabled tabs do not respond to click. \
+             With on_close, enabled tabs show a close button and middle-click \
+             closes them.",
+        )
+        .state_model(
+            "Dual-mode via ControlledState<usize>: controlled when active_index \
+             is set, otherwise uncontrolled starting from default_active_index. \
+             on_change fires on every committed change. \
+             Each tab has its own disabled state.",
+        )
+        .disabled_behavior(
+            "Disabled tabs are visually dimmed, \
+             skip during keyboard navigation, \
+             and do not respond to click events.",
+        )
+        .dependency("Tooltip")
+        .required_file("crates/components/src/tabs.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Tag contract.
+pub fn tag() -> ComponentContract {
+    ComponentContract::builder("Tag", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .required_prop("id", "ElementId", "Unique identifier for the tag")
+        .required_prop("label", "SharedString", "Tag text")
+        .prop_example("label", r#""rust""#)
+        .optional_prop(
+            "size",
+            "BadgeSize",
+            "Medium",
+            "Height: Small (16px), Medium (20px), Large (24px)",
+        )
+        .optional_prop(
+            "status",
+            "BadgeStatus",
+            "Neutral",
+            "Color: Neutral, Info, Success, Warning, Error",
+        )
+        .optional_prop(
+            "disabled",
+            "bool",
+            "false",
+            "Whether the tag is muted and cannot be removed",
+        )
+        .optional_prop(
+            "on_remove",
+            "Option<Rc<dyn Fn(&mut Window, &mut App)>>",
+            "None",
+            "Called when the remove button is pressed; without it no button shows",
+        )
+        .state(ComponentState::Hover)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Error)
+        .variant("Small")
+        .variant("Medium")
+        .variant("Large")
+        .token_dep("element.background", "Neutral background")
+        .token_dep("text.muted", "Neutral text")
+        .token_dep("border.default", "Neutral border")
+        .token_dep("status.info.background", "Info background")
+        .token_dep("status.info.foreground", "Info text")
+        .token_dep("status.info.border", "Info border")
+        .token_dep("status.success.background", "Success background")
+        .token_dep("status.success.foreground", "Success text")
+        .token_dep("status.success.border", "Success border")
+        .token_dep("status.warning.background", "Warning background")
+        .token_dep("status.warning.foreground", "Warning text")
+        .token_dep("status.warning.border", "Warning border")
+        .token_dep("status.error.background", "Error background")
+        .token_dep("status.error.foreground", "Error text")
+        .token_dep("status.error.border", "Error border")
+        .token_dep("element.disabled", "Disabled background")
+        .token_dep("text.disabled", "Disabled text")
+        .token_dep("border.disabled", "Disabled border")
+        .token_dep("ghost_element.hover", "Remove button hover background")
+        .token_dep("border.focused", "Remove button border while focused")
+        .focus_behavior(
+            "The remove button is focusable when on_remove is set; the tag itself \
+             is not.",
+        )
+        .keyboard_model(
+            "Enter, Space, Backspace, or Delete on the focused remove button calls \
+             on_remove.",
+        )
+        .key_binding(
+            &["enter", "space", "backspace", "delete"],
+            "Remove the tag from its remove button",
+        )
+        .pointer_behavior("Click the × to call on_remove.")
+        .state_model(
+            "Stateless apart from the remove button's keyed focus handle. The parent \
+             owns the list of tags and drops the removed one in on_remove.",
+        )
+        .disabled_behavior(
+            "A disabled tag renders with disabled colors and hides its remove button.",
+        )
+        .required_file("crates/components/src/badge.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Text contract.
+pub fn text() -> ComponentContract {
+    ComponentContract::builder("Text", "0.1.0")
+        .disposition(Disposition::Rewrite)
+        .stability(Stability::Beta)
+        .required_prop("id", "ElementId", "Unique identifier for the text")
+        .required_prop("content", "SharedString", "Text to show")
+        .prop_example("content", r#""Changes apply immediately.""#)
+        .optional_prop(
+            "size",
+            "TextSize",
+            "Small",
+            "Type scale: XSmall (12px), Small (14px), Base (16px), Large (18px), XLarge (20px)",
+        )
+        .optional_prop(
+            "weight",
+            "TextWeight",
+            "Normal",
+            "Font weight: Normal, Medium, Semibold, Bold",
+        )
+        .optional_prop(
+            "muted",
+            "bool",
+            "false",
+            "Use the muted color, for secondary copy",
+        )
+        .optional_prop("disabled", "bool", "false", "Use the disabled color")
+        .state(ComponentState::Disabled)
+        .variant("XSmall")
+        .variant("Small")
+        .variant("Base")
+        .variant("Large")
+        .variant("XLarge")
+        .token_dep("text.default", "Text color")
+        .token_dep("text.muted", "Muted text color")
+        .token_dep("text.disabled", "Disabled text color")
+        .focus_behavior("Not focusable; text is display-only.")
+        .keyboard_model("No keyboard interaction.")
+        .state_model("Stateless.")
+        .disabled_behavior("Disabled text uses the disabled text color.")
+        .required_file("crates/components/src/typography.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Textarea contract.
+pub fn textarea() -> ComponentContract {
+    ComponentContract::builder("Textarea", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the textarea")
+        .optional_prop(
+            "value",
+            "SharedString",
+            "\"\"",
+            "Current text value (controlled)",
+        )
+        .optional_prop(
+            "default_value",
+            "SharedString",
+            "\"\"",
+            "Initial value when uncontrolled",
+        )
+        .optional_prop("placeholder", "SharedString", "\"\"", "Placeholder text")
+        .optional_prop("rows", "u32", "3", "Number of visible rows")
+        .optional_prop("disabled", "bool", "false", "Whether disabled")
+        .optional_prop("readonly", "bool", "false", "Whether read-only")
+        .optional_prop("error", "bool", "false", "Whether in error state")
+        .optional_prop(
+            "error_message",
+            "Option<SharedString>",
+            "None",
+            "Error message below textarea",
+        )
+        .optional_prop(
+            "on_composition",
+            "Option<CompositionHandler>",
+            "None",
+            "Handler for IME composition updates, commits, and cancels",
+        )
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .optional_prop("full_width", "bool", "false", "Take full container width")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .state(ComponentState::Focused)
+        .state(ComponentState::Disabled)
+        .state(ComponentState::Error)
+        .state(ComponentState::Readonly)
+        .token_dep("element.background", "Textarea background")
+        .token_dep("element.hover", "Textarea hover background")
+        .token_dep("element.disabled", "Disabled textarea background")
+        .token_dep("text.default", "Textarea text color")
+        .token_dep("text.placeholder", "Placeholder text color")
+        .token_dep("text.disabled", "Disabled text color")
+        .token_dep("border.default", "Textarea border")
+        .token_dep("border.focused", "Focused textarea border")
+        .token_dep("border.disabled", "Disabled textarea border")
+        .token_dep("status.error.foreground", "Error message color")
+        .token_dep("status.error.border", "Error state border")
+        .token_dep("text.accent", "Text cursor color")
+        .token_dep("element.selected", "Text selection background")
+        .focus_behavior("Tab/Shift-Tab navigates to/from textarea.")
+        .keyboard_model(
+            "Standard multiline text input through the platform input handler. \
+             Enter creates newline. Left/Right move the cursor, Home/End jump to \
+             the line's ends, Shift extends the selection, Backspace/Delete remove \
+             text, and Cmd/Ctrl-A/C/X/V select all, copy, cut, and paste. \
+             While an IME is composing, preedit text is shown underlined and the \
+             IME owns the keyboard, including Enter. The IME commits the preedit; \
+             Escape cancels it and removes the preedit text.",
+        )
+        .key_binding(&["enter"], "Insert a newline")
+        .key_binding(&["left", "right"], "Move the cursor")
+        .key_binding(&["home", "end"], "Jump to the line's start or end")
+        .key_binding(&["backspace", "delete"], "Delete text")
+        .key_binding(&["secondary-a"], "Select all")
+        .key_binding(
+            &["secondary-c", "secondary-x", "secondary-v"],
+            "Copy, cut, paste",
+        )
+        .key_binding(&["escape"], "Cancel IME composition")
+        .pointer_behavior("Click focuses and places the cursor. Hover shows hover state.")
+        .state_model(
+            "Dual-mode via ControlledState<SharedString>: controlled when value \
+             is set, otherwise uncontrolled starting from default_value. \
+             on_change fires on committed changes, never with preedit text; \
+             on_composition reports composition updates, commits, and cancels. \
+             Error state shows error border/message. \
+             Readonly allows focus but not editing.",
+        )
+        .disabled_behavior("Disabled textareas show muted styling and cannot be focused.")
+        .readonly_behavior("Readonly textareas can be focused and selected but not edited.")
+        .required_file("crates/components/src/textarea.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The TitleBar contract.
+pub fn title_bar() -> ComponentContract {
+    ComponentContract::builder("TitleBar", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the bar")
+        .optional_prop(
+            "platform",
+            "TitleBarPlatform",
+            "TitleBarPlatform::current()",
+            "Window-control layout to follow: Mac, Linux, Windows",
+        )
+        .optional_prop("height", "Pixels", "32.0", "Bar height")
+        .optional_prop(
+            "start",
+            "Vec<AnyElement>",
+            "[]",
+            "Leading content, after the traffic-light inset",
+        )
+        .optional_prop(
+            "end",
+            "Vec<AnyElement>",
+            "[]",
+            "Trailing content, before the caption buttons",
+        )
+        // Only the caption buttons change on hover; the bar itself is a drag region.
+        .state(ComponentState::Hover)
+        .variant("Mac")
+        .variant("Linux")
+        .variant("Windows")
+        .token_dep("chrome.title_bar_background", "Bar background")
+        .token_dep("border.default", "Bottom border")
+        .token_dep("text.muted", "Caption button glyphs")
+        .token_dep("ghost_element.hover", "Caption button hover background")
+        .token_dep(
+            "status.error.element_hover",
+            "Close button hover background",
+        )
+        .focus_behavior("Not focusable. Slot content keeps its own focus behavior.")
+        .keyboard_model("None. Window controls are pointer-only, as in the system title bar.")
+        .pointer_behavior(
+            "Drag the bar to move the window; double-click to zoom (macOS uses the \
+             system double-click action). Caption buttons minimize, maximize, or close.",
+        )
+        .state_model(
+            "Stateless apart from a keyed flag that tracks a pending window drag \
+             between mouse down and the first mouse move.",
+        )
+        .required_file("crates/components/src/title_bar.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Toast contract.
+pub fn toast() -> ComponentContract {
+    ComponentContract::builder("Toast", "0.1.0")
+        .disposition(Disposition::Fork)
+        .required_prop("id", "ElementId", "Unique identifier for the toast")
+        .optional_prop("title", "SharedString", "\"\"", "Toast title text")
+        .optional_prop(
+            "description",
+            "Option<SharedString>",
+            "None",
+            "Toast description text",
+        )
+        .optional_prop(
+            "variant",
+            "ToastVariant",
+            "Info",
+            "Variant: Info, Success, Warning, Error",
+        )
+        .optional_prop(
+            "action_label",
+            "Option<SharedString>",
+            "None",
+            "Action button label",
+        )
+        .optional_prop(
+            "on_action",
+            "Option<ToastCallback>",
+            "None",
+            "Handler invoked when the action button is clicked",
+        )
+        .optional_prop(
+            "on_dismiss",
+            "Option<ToastCallback>",
+            "None",
+            "Handler invoked when the dismiss button is clicked",
+        )
+        .optional_prop(
+            "progress",
+            "Option<f32>",
+            "None",
+            "Background task completion (0.0..=1.0); renders a progress bar",
+        )
+        .optional_prop(
+            "timestamp",
+            "Option<SystemTime>",
+            "None",
+            "When the toast was raised; shown as a relative time in the app's locale",
+        )
+        .optional_prop(
+            "show_dismiss",
+            "bool",
+            "true",
+            "Whether to show dismiss button",
+        )
+        .optional_prop(
+            "overlay_state",
+            "OverlayStateMachine",
+            "Open",
+            "Open/close lifecycle phase driving enter/exit animation",
+        )
+        .prop_example("overlay_state", "OverlayStateMachine::new()")
+        .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
+        .state(ComponentState::Hover)
+        .state(ComponentState::Active)
+        .variant("Info")
+        .variant("Success")
+        .variant("Warning")
+        .variant("Error")
+        .token_dep("surface.elevated_surface", "Toast background")
+        .token_dep("border.default", "Toast default border")
+        .token_dep("text.default", "Toast title text")
+        .token_dep("text.muted", "Toast description text")
+        .token_dep("ghost_element.hover", "Dismiss and action button hover")
+        .token_dep("element.background", "Progress bar track")
+        .token_dep("status.info.foreground", "Info variant accent")
+        .token_dep("status.info.border", "Info variant border")
+        .token_dep("status.success.foreground", "Success variant accent")
+        .token_dep("status.success.border", "Success variant border")
+        .token_dep("status.warning.foreground", "Warning variant accent")
+        .token_dep("status.warning.border", "Warning variant border")
+        .token_dep("status.error.foreground", "Error variant accent")
+        .token_dep("status.error.border", "Error variant border")
+        .focus_behavior("Toasts are not focusable by default. Action buttons receive focus.")
+        .keyboard_model(
+            "Escape may dismiss the topmost toast. Action button responds to Enter/Space.",
+        )
+        .key_binding(&["escape"], "Dismiss the topmost toast")
+        .key_binding(&["enter", "space"], "Activate the action button")
+        .pointer_behavior(
+            "Click dismiss button to close. Click action button to trigger action. \
+             Hovering a ToastLayer's stack pauses its auto-dismiss countdowns.",
+        )
+        .state_model(
+            "Toasts support multiple concurrent instances, stacked by ToastManager: \
+             newest first, at most max_visible shown (default 3), overflow queued FIFO \
+             and promoted as visible toasts close, and pushing an existing id updates \
+             that toast in place. Each toast's lifecycle is an OverlayStateMachine: \
+             Opening/Closing fade it in/out, and dismissal during Opening moves straight \
+             to Closing. Info/Success toasts auto-dismiss after 5s; Warning/Error and \
+             in-progress toasts stay until dismissed. The app-wide ToastManager is a \
+             global: ToastManager::show/hide run its transitions and pausable timers, \
+             and a ToastLayer at the window root draws it in a configurable corner.",
+        )
+        .required_file("crates/components/src/toast.rs")
+        .docs_file("README.md")
+        .build()
+}
+
+/// The Tooltip contract.
+pub fn tooltip() -> ComponentContract {
+    ComponentContract::builder("Tooltip", "0.1.0")
+        .disposition(Disposition::Reuse)
+        .required_prop("id", "ElementId", "Unique identifier for the tooltip")
+        .optional_prop("text", "SharedString", "\"\"", "Tooltip text content")
+        .optional_prop(
+            "placement",
+            "TooltipPlacement",
+            "Bottom",
+            "Placement relative to trigger: Top, Bottom, Left, Right",
+        )
+        .optional_prop(
+            "max_width",
+            "Pixels",
+            "250.0",
+            "Maximum width of the tooltip",
+        )
+        .optional_prop(
+            "overlay_state",
+            "OverlayStateMachine",
+            "Open",
+            "Open/close lifecycle phase driving enter/exit animation",
+        )
+        .prop_example("overlay_state", "OverlayStateMachine::new()")
+        .state(ComponentState::Hover)
+        .token_dep("surface.elevated_surface", "Tooltip background")
+        .token_dep("border.default", "Tooltip border")
+        .token_dep("text.default", "Tooltip text color")
+        .focus_behavior("Tooltips are not focusable. They appear on hover only.")
+        .keyboard_model("No keyboard interaction. Tooltip hides when trigger loses focus.")
+        .pointer_behavior(
+            "Appears after a 500ms hover over the trigger (TOOLTIP_HOVER_DELAY), \
+             attached to the trigger's side named by its placement and switched \
+             to the opposite side when it would leave the window; \
+             disappears on mouse leave.",
+        )
+        .state_model(
+            "Visibility controlled by hover state of the trigger element, \
+             expressed as an OverlayStateMachine phase: Opening/Closing fade \
+             the tooltip in/out, Closed renders nothing.",
+        )
+        .required_file("crates/components/src/tooltip.rs")
+        .docs_file("README.md")
+        .build()
+}
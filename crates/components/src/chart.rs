@@ -0,0 +1,364 @@
+//! Chart primitives: `Sparkline` and `BarChart`.
+//!
+//! Rewrite disposition: minimal token-styled charts written for the workbench
+//! performance HUD and PerfEvidence views. No external charting dependency;
+//! the sparkline strokes a GPUI path on a canvas and bars are plain divs.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Scale `values` into `0.0..=1.0` between their minimum and maximum.
+///
+/// A flat series maps to `0.5` so it draws as a centered line. Non-finite
+/// values are treated as the series minimum.
+pub fn normalize_range(values: &[f64]) -> Vec<f32> {
+    let finite = values.iter().copied().filter(|v| v.is_finite());
+    let min = finite.clone().fold(f64::INFINITY, f64::min);
+    let max = finite.fold(f64::NEG_INFINITY, f64::max);
+    if !min.is_finite() || max - min <= f64::EPSILON {
+        return vec![0.5; values.len()];
+    }
+    values
+        .iter()
+        .map(|v| {
+            let v = if v.is_finite() { *v } else { min };
+            ((v - min) / (max - min)) as f32
+        })
+        .collect()
+}
+
+/// Scale `values` into `0.0..=1.0` relative to `max` (bars grow from zero).
+///
+/// Negative and non-finite values map to `0.0`.
+pub fn bar_fractions(values: &[f64], max: f64) -> Vec<f32> {
+    values
+        .iter()
+        .map(|v| {
+            if max > 0.0 && v.is_finite() {
+                (v / max).clamp(0.0, 1.0) as f32
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Sparkline
+// ---------------------------------------------------------------------------
+
+/// A compact line chart of a numeric series, with an optional threshold line.
+///
+/// # Usage
+/// ```ignore
+/// Sparkline::new("frame-times", samples)
+///     .threshold(16.7)
+///     .size(px(160.0), px(32.0))
+/// ```
+#[derive(IntoElement)]
+pub struct Sparkline {
+    id: ElementId,
+    values: Vec<f64>,
+    threshold: Option<f64>,
+    width: Pixels,
+    height: Pixels,
+    identifiers: SharedIdentifiers,
+}
+
+impl Sparkline {
+    /// Create a sparkline for `values`, oldest first.
+    pub fn new(id: impl Into<ElementId>, values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            id: id.into(),
+            values: values.into_iter().collect(),
+            threshold: None,
+            width: px(120.0),
+            height: px(24.0),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Draw a horizontal reference line (e.g. a frame budget) at `value`.
+    pub fn threshold(mut self, value: f64) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Set the chart size.
+    pub fn size(mut self, width: Pixels, height: Pixels) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// Returns the component contract for Sparkline.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::sparkline()
+    }
+}
+
+impl WithIdentifiers for Sparkline {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Sparkline {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Sparkline", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let background = theme.element.background;
+        let over_budget = self
+            .threshold
+            .zip(self.values.last())
+            .is_some_and(|(threshold, last)| *last > threshold);
+        let line_color = if over_budget {
+            theme.status.warning.foreground
+        } else {
+            theme.icon.accent
+        };
+        let threshold_color = theme.status.warning.foreground.opacity(0.6);
+
+        // Scale the threshold together with the series so both share one axis.
+        let mut scaled = self.values.clone();
+        scaled.extend(self.threshold);
+        let mut fractions = normalize_range(&scaled);
+        let threshold_fraction = self.threshold.and_then(|_| fractions.pop());
+
+        div()
+            .id(self.id.clone())
+            .w(self.width)
+            .h(self.height)
+            .flex_none()
+            .rounded_sm()
+            .bg(background)
+            .child(
+                canvas(
+                    |_, _, _| {},
+                    move |bounds, _, window, _| {
+                        if let Some(fraction) = threshold_fraction {
+                            paint_threshold(bounds, fraction, threshold_color, window);
+                        }
+                        paint_series(bounds, &fractions, line_color, window);
+                    },
+                )
+                .size_full(),
+            )
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+/// Y coordinate for a `0.0..=1.0` fraction, with 1.0 at the top of `bounds`.
+fn y_at(bounds: Bounds<Pixels>, fraction: f32) -> Pixels {
+    bounds.origin.y + bounds.size.height * (1.0 - fraction)
+}
+
+/// Stroke a dashed horizontal reference line across `bounds`.
+fn paint_threshold(bounds: Bounds<Pixels>, fraction: f32, color: Hsla, window: &mut Window) {
+    let y = y_at(bounds, fraction);
+    let mut builder = PathBuilder::stroke(px(1.0)).dash_array(&[px(3.0)]);
+    builder.move_to(point(bounds.origin.x, y));
+    builder.line_to(point(bounds.origin.x + bounds.size.width, y));
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Stroke the series as a polyline spread evenly across `bounds`.
+fn paint_series(bounds: Bounds<Pixels>, fractions: &[f32], color: Hsla, window: &mut Window) {
+    if fractions.len() < 2 {
+        return;
+    }
+    let steps = (fractions.len() - 1) as f32;
+    let mut builder = PathBuilder::stroke(px(1.5));
+    for (index, fraction) in fractions.iter().enumerate() {
+        let at = point(
+            bounds.origin.x + bounds.size.width * (index as f32 / steps),
+            y_at(bounds, *fraction),
+        );
+        if index == 0 {
+            builder.move_to(at);
+        } else {
+            builder.line_to(at);
+        }
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// BarChart
+// ---------------------------------------------------------------------------
+
+/// A labeled bar in a [`BarChart`].
+#[derive(Debug, Clone)]
+pub struct Bar {
+    /// Label shown under the bar.
+    pub label: SharedString,
+    /// Bar value.
+    pub value: f64,
+}
+
+impl Bar {
+    /// Create a bar.
+    pub fn new(label: impl Into<SharedString>, value: f64) -> Self {
+        Self {
+            label: label.into(),
+            value,
+        }
+    }
+}
+
+/// A vertical bar chart with labels and values.
+///
+/// # Usage
+/// ```ignore
+/// BarChart::new("render-times")
+///     .bar(Bar::new("Min", 2.1))
+///     .bar(Bar::new("Avg", 3.4))
+///     .unit("ms")
+/// ```
+#[derive(IntoElement)]
+pub struct BarChart {
+    id: ElementId,
+    bars: Vec<Bar>,
+    max: Option<f64>,
+    unit: SharedString,
+    height: Pixels,
+    identifiers: SharedIdentifiers,
+}
+
+impl BarChart {
+    /// Create an empty bar chart.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            bars: Vec::new(),
+            max: None,
+            unit: SharedString::default(),
+            height: px(80.0),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Append a bar.
+    pub fn bar(mut self, bar: Bar) -> Self {
+        self.bars.push(bar);
+        self
+    }
+
+    /// Append several bars.
+    pub fn bars(mut self, bars: impl IntoIterator<Item = Bar>) -> Self {
+        self.bars.extend(bars);
+        self
+    }
+
+    /// Fix the value at full bar height (defaults to the largest value).
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Unit suffix for value labels (e.g. `"ms"`).
+    pub fn unit(mut self, unit: impl Into<SharedString>) -> Self {
+        self.unit = unit.into();
+        self
+    }
+
+    /// Set the height of the plot area.
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Returns the component contract for BarChart.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::bar_chart()
+    }
+}
+
+impl WithIdentifiers for BarChart {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for BarChart {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("BarChart", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let fill = theme.icon.accent;
+        let track = theme.element.background;
+        let track_hover = theme.element.hover;
+        let value_color = theme.text.default;
+        let label_color = theme.text.muted;
+
+        let values: Vec<f64> = self.bars.iter().map(|bar| bar.value).collect();
+        let max = self
+            .max
+            .unwrap_or_else(|| values.iter().copied().fold(0.0, f64::max));
+        let fractions = bar_fractions(&values, max);
+
+        let mut chart = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_end()
+            .gap_2();
+
+        for (idx, (bar, fraction)) in self.bars.into_iter().zip(fractions).enumerate() {
+            let value_label = format!("{:.1}{}", bar.value, self.unit);
+            chart = chart.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .items_center()
+                    .gap_1()
+                    .min_w(px(32.0))
+                    .child(div().text_xs().text_color(value_color).child(value_label))
+                    .child(
+                        div()
+                            .id(SharedString::from(format!("{}-bar-{idx}", self.id)))
+                            .w(px(20.0))
+                            .h(self.height)
+                            .flex()
+                            .flex_col()
+                            .justify_end()
+                            .rounded_sm()
+                            .bg(track)
+                            .hover(move |s| s.bg(track_hover))
+                            .child(
+                                div()
+                                    .w_full()
+                                    .h(self.height * fraction)
+                                    .rounded_sm()
+                                    .bg(fill),
+                            ),
+                    )
+                    .child(div().text_xs().text_color(label_color).child(bar.label)),
+            );
+        }
+
+        chart.inspectable(&self.id, Self::contract)
+    }
+}
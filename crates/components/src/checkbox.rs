@@ -7,14 +7,14 @@
 //! - Zed `crates/ui/src/components/` toggle patterns (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - gpui-component checkbox patterns (MIT, Zed Industries)
 //! - Modifications: Simplified to internal token system, uses internal primitives
-//!   for keyboard activation and state management.
+//!   for keyboard activation and controlled/uncontrolled state (ControlledState).
 
+use crate::tooltip::{TooltipPlacement, WithTooltip, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::*;
+use primitives::ControlledState;
 use theme::ActiveTheme;
 
-/// Callback when the checked state changes.
-type OnChangeCallback = Box<dyn Fn(bool, &mut Window, &mut App) + 'static>;
-
 /// A checkbox component with label, checked/unchecked/indeterminate states,
 /// and builder-pattern API mapped to frozen design tokens.
 ///
@@ -31,11 +31,12 @@ type OnChangeCallback = Box<dyn Fn(bool, &mut Window, &mut App) + 'static>;
 pub struct Checkbox {
     id: ElementId,
     label: Option<SharedString>,
-    checked: bool,
+    checked: ControlledState<bool>,
     indeterminate: bool,
     disabled: bool,
-    on_change: Option<OnChangeCallback>,
     tooltip: Option<SharedString>,
+    tooltip_placement: TooltipPlacement,
+    identifiers: SharedIdentifiers,
 }
 
 impl Checkbox {
@@ -44,11 +45,12 @@ impl Checkbox {
         Self {
             id: id.into(),
             label: None,
-            checked: false,
+            checked: ControlledState::new(),
             indeterminate: false,
             disabled: false,
-            on_change: None,
             tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
+            identifiers: SharedIdentifiers::default(),
         }
     }
 
@@ -58,9 +60,15 @@ impl Checkbox {
         self
     }
 
-    /// Set the checked state.
+    /// Control the checked state from the parent.
     pub fn checked(mut self, checked: bool) -> Self {
-        self.checked = checked;
+        self.checked = self.checked.value(checked);
+        self
+    }
+
+    /// Set the initial checked state for uncontrolled use.
+    pub fn default_checked(mut self, checked: bool) -> Self {
+        self.checked = self.checked.default_value(checked);
         self
     }
 
@@ -78,11 +86,14 @@ impl Checkbox {
 
     /// Set the change handler.
     pub fn on_change(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.checked = self
+            .checked
+            .on_change(move |checked, window, cx| handler(*checked, window, cx));
         self
     }
 
     /// Set a tooltip.
+    #[deprecated(note = "use `WithTooltip::tooltip`")]
     pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
         self
@@ -90,60 +101,47 @@ impl Checkbox {
 
     /// Returns the component contract for Checkbox.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Checkbox", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the checkbox")
-            .optional_prop("label", "Option<SharedString>", "None", "Label text")
-            .optional_prop(
-                "checked",
-                "bool",
-                "false",
-                "Whether the checkbox is checked",
-            )
-            .optional_prop(
-                "indeterminate",
-                "bool",
-                "false",
-                "Whether the checkbox is in indeterminate state",
-            )
-            .optional_prop(
-                "disabled",
-                "bool",
-                "false",
-                "Whether the checkbox is disabled",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Disabled)
-            .state(ComponentState::Selected)
-            .token_dep("element.background", "Unchecked checkbox background")
-            .token_dep("element.hover", "Checkbox hover background")
-            .token_dep("element.selected", "Checked checkbox background")
-            .token_dep("element.disabled", "Disabled checkbox background")
-            .token_dep("text.default", "Label text color")
-            .token_dep("text.accent", "Checkmark color")
-            .token_dep("text.disabled", "Disabled label text color")
-            .token_dep("border.default", "Checkbox border")
-            .token_dep("border.focused", "Focus ring border")
-            .token_dep("border.disabled", "Disabled border")
-            .focus_behavior("Tab/Shift-Tab navigates to/from checkbox. Focus ring shown.")
-            .keyboard_model("Space toggles the checked state. Enter does not activate.")
-            .pointer_behavior("Click toggles checked state. Hover shows hover state.")
-            .state_model(
-                "Controlled checked state via prop. Indeterminate is a visual-only state \
-                 that still reports unchecked when toggled.",
-            )
-            .disabled_behavior("Disabled checkboxes show muted styling and ignore interaction.")
-            .required_file("crates/components/src/checkbox.rs")
-            .build()
+        crate::catalog::checkbox()
+    }
+}
+
+impl WithIdentifiers for Checkbox {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl WithTooltip for Checkbox {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
     }
 }
 
 impl RenderOnce for Checkbox {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Checkbox",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let checked_state = self.checked.bind(self.id.clone(), window, cx);
+        let checked = *checked_state.current();
+
         let theme = cx.theme();
 
         let (box_bg, box_border, label_color, indicator_color) = if self.disabled {
@@ -153,7 +151,7 @@ impl RenderOnce for Checkbox {
                 theme.text.disabled,
                 theme.text.disabled,
             )
-        } else if self.checked || self.indeterminate {
+        } else if checked || self.indeterminate {
             (
                 theme.element.selected,
                 theme.border.selected,
@@ -171,9 +169,7 @@ impl RenderOnce for Checkbox {
 
         let hover_bg = theme.element.hover;
         let disabled = self.disabled;
-        let checked = self.checked;
         let indeterminate = self.indeterminate;
-        let on_change = self.on_change;
 
         // Checkbox indicator character
         let indicator = if indeterminate {
@@ -206,7 +202,7 @@ impl RenderOnce for Checkbox {
 
         // Container with click handler
         let mut container = div()
-            .id(self.id)
+            .id(self.id.clone())
             .flex()
             .flex_row()
             .items_center()
@@ -217,18 +213,18 @@ impl RenderOnce for Checkbox {
                 CursorStyle::PointingHand
             });
 
-        if let Some(handler) = on_change
-            && !disabled
-        {
-            container = container.on_click(move |_event, window, cx| {
-                handler(!checked, window, cx);
+        if !disabled {
+            container = container.on_click({
+                let checked_state = checked_state.clone();
+                move |_event, window, cx| {
+                    checked_state.set(!checked, window, cx);
+                }
             });
-        }
 
-        // Key handler: Space toggles
-        if !disabled {
-            container = container.on_key_down(move |event, _window, cx| {
+            // Key handler: Space toggles
+            container = container.on_key_down(move |event, window, cx| {
                 if event.keystroke.key.as_str() == " " {
+                    checked_state.set(!checked, window, cx);
                     cx.stop_propagation();
                 }
             });
@@ -241,6 +237,14 @@ impl RenderOnce for Checkbox {
             container = container.child(div().text_sm().text_color(label_color).child(label));
         }
 
-        container
+        attach_tooltip(
+            container,
+            &self.id,
+            self.tooltip,
+            self.tooltip_placement,
+            window,
+            cx,
+        )
+        .inspectable(&self.id, Self::contract)
     }
 }
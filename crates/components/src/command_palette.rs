@@ -0,0 +1,522 @@
+//! CommandPalette component: modal command search with fuzzy filtering.
+//!
+//! Fork disposition: adapted from Zed's command palette and picker.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - Zed `crates/command_palette/src/command_palette.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Zed `crates/picker/src/picker.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Modifications: stateless RenderOnce over data-only commands instead of
+//!   actions, matching via the internal `fuzzy` primitive, `uniform_list`
+//!   results, focus capture/return and arrow navigation via internal primitives.
+
+use std::rc::Rc;
+
+use crate::kbd::Kbd;
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{
+    FocusReturn, FuzzyMatch, Orientation, classify_nav_key, fuzzy_filter, is_escape_key,
+    navigate_index,
+};
+use theme::ActiveTheme;
+
+/// Callback for running a command or dismissing the palette.
+pub type PaletteCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// One entry in a [`CommandPalette`].
+#[derive(Clone)]
+pub struct CommandItem {
+    /// Stable identifier, e.g. `"theme.toggle"`.
+    pub id: SharedString,
+    /// Display name, e.g. `"Toggle Theme"`.
+    pub label: SharedString,
+    /// Optional group shown before the label, e.g. `"View"`.
+    pub category: Option<SharedString>,
+    /// Optional keystroke shown on the right, e.g. `"cmd-k"`.
+    pub shortcut: Option<SharedString>,
+    /// Disabled commands are listed but cannot be run.
+    pub disabled: bool,
+    on_run: Option<PaletteCallback>,
+}
+
+impl CommandItem {
+    /// Create an enabled command with no handler.
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            category: None,
+            shortcut: None,
+            disabled: false,
+            on_run: None,
+        }
+    }
+
+    /// Set the category shown before the label.
+    pub fn category(mut self, category: impl Into<SharedString>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    /// Set the keystroke shown on the right.
+    pub fn shortcut(mut self, keystroke: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(keystroke.into());
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler run when the command is chosen.
+    pub fn on_run(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_run = Some(Rc::new(handler));
+        self
+    }
+
+    /// Text the query is matched against and the row displays:
+    /// `"Category: Label"`, or just the label.
+    pub fn search_text(&self) -> String {
+        match &self.category {
+            Some(category) => format!("{category}: {}", self.label),
+            None => self.label.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Debug for CommandItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommandItem")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("category", &self.category)
+            .field("shortcut", &self.shortcut)
+            .field("disabled", &self.disabled)
+            .finish()
+    }
+}
+
+/// Commands matching `query`, best first, as `(command index, match)` pairs.
+pub fn command_matches(query: &str, commands: &[CommandItem]) -> Vec<(usize, FuzzyMatch)> {
+    fuzzy_filter(query, commands.iter().map(CommandItem::search_text))
+}
+
+/// A modal overlay for finding and running commands by typing part of their
+/// name.
+///
+/// The palette takes focus when it first renders and hands it back when
+/// dismissed, so render it only while open and close it from `on_dismiss`.
+/// Running a command also dismisses the palette.
+///
+/// # Usage
+/// ```ignore
+/// CommandPalette::new("palette")
+///     .command(CommandItem::new("theme.toggle", "Toggle Theme").category("View"))
+///     .on_dismiss(move |_window, cx| {
+///         view.update(cx, |view, cx| {
+///             view.palette_open = false;
+///             cx.notify();
+///         });
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct CommandPalette {
+    id: ElementId,
+    commands: Vec<CommandItem>,
+    placeholder: SharedString,
+    empty_message: SharedString,
+    on_dismiss: Option<PaletteCallback>,
+    width: Pixels,
+    row_height: Pixels,
+    max_visible_rows: usize,
+    autofocus: bool,
+    identifiers: SharedIdentifiers,
+}
+
+impl CommandPalette {
+    /// Create an empty palette.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            commands: Vec::new(),
+            placeholder: "Type a command…".into(),
+            empty_message: "No matching commands".into(),
+            on_dismiss: None,
+            width: px(560.0),
+            row_height: px(32.0),
+            max_visible_rows: 8,
+            autofocus: true,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Add a command.
+    pub fn command(mut self, command: CommandItem) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    /// Add several commands.
+    pub fn commands(mut self, commands: impl IntoIterator<Item = CommandItem>) -> Self {
+        self.commands.extend(commands);
+        self
+    }
+
+    /// Set the filter input placeholder.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the text shown when no command matches.
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    /// Set the handler fired on Escape, a backdrop click, or after a command runs.
+    pub fn on_dismiss(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the panel width.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set how many result rows show before the list scrolls.
+    pub fn max_visible_rows(mut self, rows: usize) -> Self {
+        self.max_visible_rows = rows.max(1);
+        self
+    }
+
+    /// Whether the palette takes focus when it opens (default `true`).
+    /// Previews that render several palettes at once turn this off.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    /// Returns the component contract for CommandPalette.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::command_palette()
+    }
+}
+
+impl WithIdentifiers for CommandPalette {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for CommandPalette {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("CommandPalette", &self.id, None, &self.identifiers, cx);
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        // Created when the palette opens: remember where focus was, then take it.
+        let autofocus = self.autofocus;
+        let (focus_handle, focus_return) = window
+            .use_keyed_state(key("focus"), cx, move |window, cx| {
+                let focus_return = FocusReturn::capture(window, cx);
+                let handle = cx.focus_handle();
+                if autofocus {
+                    let focus = handle.clone();
+                    window.defer(cx, move |window, cx| window.focus(&focus, cx));
+                }
+                (handle, focus_return)
+            })
+            .read(cx)
+            .clone();
+        let query = window.use_keyed_state(key("query"), cx, |_, _| SharedString::default());
+        // Highlighted result position; None means the first enabled result.
+        let highlight = window.use_keyed_state(key("highlight"), cx, |_, _| None::<usize>);
+        let scroll_handle = window
+            .use_keyed_state(key("scroll"), cx, |_, _| UniformListScrollHandle::new())
+            .read(cx)
+            .clone();
+
+        let query_text = query.read(cx).clone();
+        let commands = Rc::new(self.commands);
+        let results = Rc::new(command_matches(&query_text, &commands));
+        let count = results.len();
+        let is_disabled = {
+            let commands = commands.clone();
+            let results = results.clone();
+            move |position: usize| commands[results[position].0].disabled
+        };
+        let highlighted = highlight
+            .read(cx)
+            .filter(|&position| position < count)
+            .or_else(|| (0..count).find(|&position| !is_disabled(position)));
+
+        let dismiss: PaletteCallback = {
+            let on_dismiss = self.on_dismiss;
+            Rc::new(move |window: &mut Window, cx: &mut App| {
+                focus_return.restore(window, cx);
+                if let Some(on_dismiss) = &on_dismiss {
+                    on_dismiss(window, cx);
+                }
+            })
+        };
+        let run: Rc<dyn Fn(usize, &mut Window, &mut App)> = {
+            let commands = commands.clone();
+            let results = results.clone();
+            let dismiss = dismiss.clone();
+            Rc::new(move |position: usize, window: &mut Window, cx: &mut App| {
+                let command = &commands[results[position].0];
+                if command.disabled {
+                    return;
+                }
+                dismiss(window, cx);
+                if let Some(on_run) = &command.on_run {
+                    on_run(window, cx);
+                }
+            })
+        };
+
+        let theme = cx.theme();
+        let backdrop_rgba: Rgba = theme.surface.background.into();
+        let backdrop_color = Hsla::from(Rgba {
+            a: 0.6,
+            ..backdrop_rgba
+        });
+        let panel_bg = theme.surface.elevated_surface;
+        let border_color = theme.border.default;
+        let text_color = theme.text.default;
+        let muted_color = theme.text.muted;
+        let disabled_color = theme.text.disabled;
+        let placeholder_color = theme.text.placeholder;
+        let icon_color = theme.icon.muted;
+        let match_color = theme.text.accent;
+        let row_hover = theme.ghost_element.hover;
+        let row_selected = theme.ghost_element.selected;
+
+        // Filter input
+        let input = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .h(px(40.0))
+            .px_3()
+            .border_b_1()
+            .border_color(border_color)
+            .text_sm()
+            .child(div().flex_shrink_0().text_color(icon_color).child("⌕"))
+            .child(div().flex_1().overflow_x_hidden().map(|el| {
+                if query_text.is_empty() {
+                    el.text_color(placeholder_color).child(self.placeholder)
+                } else {
+                    el.text_color(text_color).child(query_text.clone())
+                }
+            }))
+            .when(!query_text.is_empty(), |el| {
+                el.child(
+                    div()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .text_color(placeholder_color)
+                        .child(if count == 1 {
+                            "1 result".to_string()
+                        } else {
+                            format!("{count} results")
+                        }),
+                )
+            });
+
+        // Results
+        let row_height = self.row_height;
+        let list = if count == 0 {
+            div()
+                .h(row_height * 2.0)
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_sm()
+                .text_color(muted_color)
+                .child(self.empty_message)
+                .into_any_element()
+        } else {
+            let commands = commands.clone();
+            let results = results.clone();
+            let run = run.clone();
+            let visible_rows = count.min(self.max_visible_rows);
+            uniform_list(key("results"), count, move |range, _window, _cx| {
+                range
+                    .map(|position| {
+                        let (index, found) = &results[position];
+                        let command = &commands[*index];
+                        let text = command.search_text();
+                        let highlights = found.ranges(&text).into_iter().map(|range| {
+                            (
+                                range,
+                                HighlightStyle {
+                                    color: Some(match_color),
+                                    font_weight: Some(FontWeight::BOLD),
+                                    ..Default::default()
+                                },
+                            )
+                        });
+                        let label = StyledText::new(text).with_highlights(highlights);
+
+                        let mut row = div()
+                            .id(("command-palette-row", *index))
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .justify_between()
+                            .gap_2()
+                            .h(row_height)
+                            .px_3()
+                            .text_sm()
+                            .text_color(if command.disabled {
+                                disabled_color
+                            } else {
+                                text_color
+                            })
+                            .when(highlighted == Some(position), |el| el.bg(row_selected))
+                            .child(div().flex_1().overflow_x_hidden().child(label))
+                            .when_some(command.shortcut.clone(), |el, shortcut| {
+                                el.child(Kbd::new(shortcut).disabled(command.disabled))
+                            });
+                        if command.disabled {
+                            row = row.opacity(0.5).cursor_default();
+                        } else {
+                            let run = run.clone();
+                            row = row
+                                .cursor_pointer()
+                                .hover(move |s| s.bg(row_hover))
+                                .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                                    run(position, window, cx);
+                                });
+                        }
+                        row
+                    })
+                    .collect()
+            })
+            .h(row_height * visible_rows as f32)
+            .track_scroll(scroll_handle.clone())
+            .into_any_element()
+        };
+
+        // Footer hints
+        let footer = div()
+            .flex()
+            .flex_row()
+            .gap_3()
+            .px_3()
+            .py_1p5()
+            .border_t_1()
+            .border_color(border_color)
+            .text_xs()
+            .text_color(muted_color)
+            .child("↑↓ navigate")
+            .child("↵ run")
+            .child("esc close");
+
+        let panel = div()
+            .id(self.id.clone())
+            .track_focus(&focus_handle)
+            .flex()
+            .flex_col()
+            .w(self.width)
+            .overflow_hidden()
+            .bg(panel_bg)
+            .border_1()
+            .border_color(border_color)
+            .rounded_lg()
+            .shadow_lg()
+            // Stop click propagation so the backdrop handler doesn't fire
+            .on_mouse_down(MouseButton::Left, |_event, _window, cx| {
+                cx.stop_propagation();
+            })
+            .on_key_down({
+                let dismiss = dismiss.clone();
+                move |event, window, cx| {
+                    let keystroke = &event.keystroke;
+                    let set_query = |next: SharedString, cx: &mut App| {
+                        query.update(cx, |query, cx| {
+                            *query = next;
+                            cx.notify();
+                        });
+                        highlight.update(cx, |highlight, _| *highlight = None);
+                        scroll_handle.scroll_to_item(0, ScrollStrategy::Top);
+                    };
+                    if is_escape_key(event) {
+                        dismiss(window, cx);
+                        cx.stop_propagation();
+                    } else if let Some(direction) = classify_nav_key(event, Orientation::Vertical) {
+                        if count > 0 {
+                            let current = highlighted.unwrap_or(0);
+                            let next = navigate_index(current, direction, count, &is_disabled);
+                            highlight.update(cx, |highlight, cx| {
+                                *highlight = Some(next);
+                                cx.notify();
+                            });
+                            scroll_handle.scroll_to_item(next, ScrollStrategy::Center);
+                        }
+                        cx.stop_propagation();
+                    } else if keystroke.key == keys::ENTER {
+                        if let Some(position) = highlighted {
+                            run(position, window, cx);
+                        }
+                        cx.stop_propagation();
+                    } else if keystroke.key == keys::BACKSPACE {
+                        let mut next = query_text.to_string();
+                        next.pop();
+                        set_query(next.into(), cx);
+                        cx.stop_propagation();
+                    } else if !keystroke.modifiers.control
+                        && !keystroke.modifiers.platform
+                        && let Some(typed) = keystroke
+                            .key_char
+                            .as_ref()
+                            .filter(|s| !s.chars().any(char::is_control))
+                    {
+                        set_query(format!("{query_text}{typed}").into(), cx);
+                        cx.stop_propagation();
+                    }
+                }
+            })
+            .child(input)
+            .child(list)
+            .child(footer);
+
+        // Full-screen overlay with backdrop
+        let overlay = div()
+            .id(key("overlay"))
+            .absolute()
+            .inset_0()
+            .flex()
+            .justify_center()
+            .items_start()
+            .pt(px(80.0))
+            .bg(backdrop_color)
+            .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                dismiss(window, cx);
+            })
+            .child(panel.inspectable(&self.id, Self::contract));
+
+        // Use deferred rendering so the palette paints on top
+        deferred(overlay).with_priority(1)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
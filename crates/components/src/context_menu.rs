@@ -0,0 +1,675 @@
+//! ContextMenu component: right-click menu with nested submenus.
+//!
+//! Fork disposition: adapted from Zed's context menu and right-click menu.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - Zed `crates/ui/src/components/context_menu.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Zed `crates/ui/src/components/right_click_menu.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Modifications: stateless RenderOnce over data-only items with keyed open
+//!   state, placement via the internal `PopoverPosition` primitive, focus
+//!   capture/return and arrow navigation via internal primitives.
+
+use std::rc::Rc;
+
+use crate::kbd::Kbd;
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{
+    FocusReturn, NavDirection, Orientation, PopoverPosition, classify_nav_key, is_activation_key,
+    is_escape_key, navigate_index,
+};
+use smallvec::SmallVec;
+use theme::ActiveTheme;
+
+/// Callback run when a context menu item is chosen.
+pub type ContextMenuCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// Callback run when the pointer moves onto row `index` of the menu at `depth`.
+pub(crate) type MenuHoverCallback = Rc<dyn Fn(usize, usize, &mut App) + 'static>;
+
+/// What kind of entry a [`ContextMenuItem`] is.
+#[derive(Debug, Clone)]
+pub enum ContextMenuItemKind {
+    /// Runs its handler and closes the menu.
+    Action,
+    /// Opens a nested menu of further items.
+    Submenu(Vec<ContextMenuItem>),
+    /// Visual divider.
+    Separator,
+}
+
+/// One entry in a [`ContextMenu`].
+#[derive(Clone)]
+pub struct ContextMenuItem {
+    /// Display label.
+    pub label: SharedString,
+    /// Optional keystroke shown on the right, e.g. `"cmd-c"`.
+    pub shortcut: Option<SharedString>,
+    /// Disabled items are shown but cannot be chosen or opened.
+    pub disabled: bool,
+    /// Item kind (action, submenu, or separator).
+    pub kind: ContextMenuItemKind,
+    pub(crate) on_select: Option<ContextMenuCallback>,
+}
+
+impl ContextMenuItem {
+    /// Create an enabled action item with no handler.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            shortcut: None,
+            disabled: false,
+            kind: ContextMenuItemKind::Action,
+            on_select: None,
+        }
+    }
+
+    /// Create an item that opens a nested menu.
+    pub fn submenu(label: impl Into<SharedString>, items: Vec<ContextMenuItem>) -> Self {
+        Self {
+            kind: ContextMenuItemKind::Submenu(items),
+            ..Self::new(label)
+        }
+    }
+
+    /// Create a separator item.
+    pub fn separator() -> Self {
+        Self {
+            kind: ContextMenuItemKind::Separator,
+            ..Self::new(SharedString::default())
+        }
+    }
+
+    /// Set the keystroke shown on the right.
+    pub fn shortcut(mut self, keystroke: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(keystroke.into());
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler run when the item is chosen.
+    pub fn on_select(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+
+    /// Whether this item is a separator.
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, ContextMenuItemKind::Separator)
+    }
+
+    /// The nested items of a submenu; empty for other kinds.
+    pub fn submenu_items(&self) -> &[ContextMenuItem] {
+        match &self.kind {
+            ContextMenuItemKind::Submenu(items) => items,
+            _ => &[],
+        }
+    }
+
+    /// Whether keyboard navigation and clicks skip this item.
+    pub(crate) fn is_inert(&self) -> bool {
+        self.disabled || self.is_separator()
+    }
+
+    /// Whether highlighting this item opens a submenu.
+    pub(crate) fn opens_submenu(&self) -> bool {
+        !self.disabled && matches!(self.kind, ContextMenuItemKind::Submenu(_))
+    }
+}
+
+impl std::fmt::Debug for ContextMenuItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ContextMenuItem")
+            .field("label", &self.label)
+            .field("shortcut", &self.shortcut)
+            .field("disabled", &self.disabled)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+/// Which rows are highlighted and which submenus are open in a
+/// [`ContextMenu`].
+///
+/// Entry `k` is the highlighted row of the menu at depth `k` (the root menu
+/// is depth 0). Every entry but the last is the submenu row that opened the
+/// next menu, so the number of entries is the number of open menus.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuPath {
+    levels: Vec<Option<usize>>,
+}
+
+impl Default for MenuPath {
+    fn default() -> Self {
+        Self { levels: vec![None] }
+    }
+}
+
+impl MenuPath {
+    /// Only the root menu open, nothing highlighted.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of open menus, including the root.
+    pub fn depth(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Highlighted row of the menu at `depth`, if that menu is open.
+    pub fn highlighted(&self, depth: usize) -> Option<usize> {
+        self.levels.get(depth).copied().flatten()
+    }
+
+    /// The highlighted item of the innermost open menu.
+    pub fn highlighted_item<'a>(
+        &self,
+        items: &'a [ContextMenuItem],
+    ) -> Option<&'a ContextMenuItem> {
+        let depth = self.levels.len() - 1;
+        self.items_at(items, depth).get(self.highlighted(depth)?)
+    }
+
+    /// The items of the open menu at `depth` (empty if it is not open).
+    pub fn items_at<'a>(
+        &self,
+        items: &'a [ContextMenuItem],
+        depth: usize,
+    ) -> &'a [ContextMenuItem] {
+        let mut level = items;
+        for index in self.levels.iter().take(depth) {
+            level = match index.and_then(|index| level.get(index)) {
+                Some(item) => item.submenu_items(),
+                None => return &[],
+            };
+        }
+        level
+    }
+
+    /// Pointer moved onto row `index` of the menu at `depth`: highlight it,
+    /// close deeper menus, and open its submenu if it has one.
+    pub fn hover(&mut self, items: &[ContextMenuItem], depth: usize, index: usize) {
+        if depth >= self.levels.len() {
+            return;
+        }
+        let item = self.items_at(items, depth).get(index);
+        self.levels.truncate(depth + 1);
+        self.levels[depth] = item.filter(|item| !item.is_inert()).map(|_| index);
+        if item.is_some_and(ContextMenuItem::opens_submenu) {
+            self.levels.push(None);
+        }
+    }
+
+    /// Move the highlight in the innermost open menu, skipping separators
+    /// and disabled items.
+    pub fn navigate(&mut self, items: &[ContextMenuItem], direction: NavDirection) {
+        let depth = self.levels.len() - 1;
+        let level = self.items_at(items, depth);
+        if level.iter().all(ContextMenuItem::is_inert) {
+            return;
+        }
+        // With nothing highlighted, Next starts at the top and Previous at the bottom.
+        let current = self.levels[depth].unwrap_or(match direction {
+            NavDirection::Next => level.len() - 1,
+            _ => 0,
+        });
+        let next = navigate_index(current, direction, level.len(), |i| level[i].is_inert());
+        self.levels[depth] = Some(next);
+    }
+
+    /// Move into a submenu: the highlighted submenu row's menu, or a submenu
+    /// opened by hovering. Highlights its first enabled item.
+    ///
+    /// Returns false if there is no submenu to enter.
+    pub fn enter_submenu(&mut self, items: &[ContextMenuItem]) -> bool {
+        let depth = self.levels.len() - 1;
+        if depth > 0 && self.levels[depth].is_none() {
+            // Opened by hover but not entered yet.
+            self.navigate(items, NavDirection::First);
+            return self.levels[depth].is_some();
+        }
+        let Some(item) = self
+            .highlighted_item(items)
+            .filter(|item| item.opens_submenu())
+        else {
+            return false;
+        };
+        let Some(first) = item
+            .submenu_items()
+            .iter()
+            .position(|item| !item.is_inert())
+        else {
+            return false;
+        };
+        self.levels.push(Some(first));
+        true
+    }
+
+    /// Close the innermost submenu, keeping its row highlighted in the parent.
+    ///
+    /// Returns false if only the root menu is open.
+    pub fn close_submenu(&mut self) -> bool {
+        if self.levels.len() > 1 {
+            self.levels.pop();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// An open context menu: where it opened and where focus goes back to.
+struct OpenMenu {
+    anchor: Point<Pixels>,
+    path: MenuPath,
+    focus_return: FocusReturn,
+}
+
+/// Colors shared by every menu level.
+#[derive(Clone, Copy)]
+pub(crate) struct MenuColors {
+    background: Hsla,
+    border: Hsla,
+    separator: Hsla,
+    text: Hsla,
+    disabled: Hsla,
+    highlight: Hsla,
+    chevron: Hsla,
+}
+
+impl MenuColors {
+    /// The menu colors of the active theme.
+    pub(crate) fn new(cx: &App) -> Self {
+        let theme = cx.theme();
+        Self {
+            background: theme.surface.elevated_surface,
+            border: theme.border.default,
+            separator: theme.border.variant,
+            text: theme.text.default,
+            disabled: theme.text.disabled,
+            highlight: theme.element.hover,
+            chevron: theme.icon.muted,
+        }
+    }
+}
+
+/// A right-click menu over a trigger area, with nested submenus, separators,
+/// disabled items, and keyboard navigation.
+///
+/// The menu opens at the pointer with the [`PopoverPosition::attach`] corner
+/// of the menu at the click point (by default its top-left corner), and
+/// flips when it would overflow the window. Escape or a click outside closes
+/// it and returns focus to where it was.
+///
+/// # Usage
+/// ```ignore
+/// ContextMenu::new("editor-menu")
+///     .child(div().size_full().child("Right-click here"))
+///     .item(ContextMenuItem::new("Copy").shortcut("cmd-c").on_select(|_, _| copy()))
+///     .item(ContextMenuItem::separator())
+///     .item(ContextMenuItem::submenu("Sort By", vec![
+///         ContextMenuItem::new("Name"),
+///         ContextMenuItem::new("Date"),
+///     ]))
+/// ```
+#[derive(IntoElement)]
+pub struct ContextMenu {
+    id: ElementId,
+    children: SmallVec<[AnyElement; 2]>,
+    items: Vec<ContextMenuItem>,
+    position: PopoverPosition,
+    width: Pixels,
+    disabled: bool,
+    identifiers: SharedIdentifiers,
+}
+
+impl ContextMenu {
+    /// Create a context menu with an empty trigger area and no items.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            children: SmallVec::new(),
+            items: Vec::new(),
+            position: PopoverPosition::below_left(),
+            width: px(200.0),
+            disabled: false,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Add content to the trigger area; right-clicking it opens the menu.
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.children.push(child.into_any_element());
+        self
+    }
+
+    /// Add a menu item.
+    pub fn item(mut self, item: ContextMenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Add several menu items.
+    pub fn items(mut self, items: impl IntoIterator<Item = ContextMenuItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Set which corner of the menu sits at the click point. Only the
+    /// [`PopoverPosition::attach`] corner is used: there is no trigger
+    /// element to anchor to.
+    pub fn position(mut self, position: PopoverPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Set the width of the menu and its submenus.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set whether right-clicking the trigger area is ignored.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the component contract for ContextMenu.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::context_menu()
+    }
+}
+
+impl WithIdentifiers for ContextMenu {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for ContextMenu {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("ContextMenu", &self.id, None, &self.identifiers, cx);
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        let state = window.use_keyed_state(key("state"), cx, |_, _| None::<OpenMenu>);
+
+        let mut trigger = div().id(self.id.clone());
+        if !self.disabled {
+            let state = state.clone();
+            let focus_handle = focus_handle.clone();
+            trigger = trigger.on_mouse_down(MouseButton::Right, move |event, window, cx| {
+                let focus_return = FocusReturn::capture(window, cx);
+                state.update(cx, |state, cx| {
+                    // Reopening keeps the focus captured by the first open.
+                    let focus_return = state.take().map_or(focus_return, |open| open.focus_return);
+                    *state = Some(OpenMenu {
+                        anchor: event.position,
+                        path: MenuPath::new(),
+                        focus_return,
+                    });
+                    cx.notify();
+                });
+                window.focus(&focus_handle, cx);
+                cx.stop_propagation();
+            });
+        }
+        for child in self.children {
+            trigger = trigger.child(child);
+        }
+
+        let Some((anchor, path)) = state
+            .read(cx)
+            .as_ref()
+            .map(|open| (open.anchor, open.path.clone()))
+        else {
+            return trigger.inspectable(&self.id, Self::contract);
+        };
+
+        let items = Rc::new(self.items);
+        let dismiss: ContextMenuCallback = {
+            let state = state.clone();
+            Rc::new(move |window: &mut Window, cx: &mut App| {
+                if let Some(open) = state.update(cx, |state, cx| {
+                    cx.notify();
+                    state.take()
+                }) {
+                    open.focus_return.restore(window, cx);
+                }
+            })
+        };
+        let update_path = {
+            let state = state.clone();
+            move |cx: &mut App, f: &dyn Fn(&mut MenuPath)| {
+                state.update(cx, |state, cx| {
+                    if let Some(open) = state {
+                        f(&mut open.path);
+                        cx.notify();
+                    }
+                });
+            }
+        };
+
+        let on_hover: MenuHoverCallback = {
+            let state = state.clone();
+            let root = items.clone();
+            Rc::new(move |depth, index, cx| {
+                state.update(cx, |state, cx| {
+                    if let Some(open) = state {
+                        open.path.hover(&root, depth, index);
+                        cx.notify();
+                    }
+                });
+            })
+        };
+
+        let menu = render_menu(&MenuLevel {
+            id: key("menu"),
+            root: items.clone(),
+            depth: 0,
+            path: &path,
+            on_hover: &on_hover,
+            dismiss: &dismiss,
+            width: self.width,
+            colors: MenuColors::new(cx),
+        });
+        let menu = menu.track_focus(&focus_handle).on_key_down({
+            let dismiss = dismiss.clone();
+            move |event, window, cx| {
+                cx.stop_propagation();
+                if let Some(direction) = classify_nav_key(event, Orientation::Vertical) {
+                    update_path(cx, &|path| path.navigate(&items, direction));
+                } else if event.keystroke.key == keys::ARROW_RIGHT {
+                    update_path(cx, &|path| {
+                        path.enter_submenu(&items);
+                    });
+                } else if event.keystroke.key == keys::ARROW_LEFT {
+                    update_path(cx, &|path| {
+                        path.close_submenu();
+                    });
+                } else if is_escape_key(event) {
+                    if path.depth() > 1 {
+                        update_path(cx, &|path| {
+                            path.close_submenu();
+                        });
+                    } else {
+                        dismiss(window, cx);
+                    }
+                } else if is_activation_key(event) {
+                    match path.highlighted_item(&items) {
+                        Some(item) if item.opens_submenu() => update_path(cx, &|path| {
+                            path.enter_submenu(&items);
+                        }),
+                        Some(item) if !item.is_inert() => {
+                            dismiss(window, cx);
+                            if let Some(on_select) = &item.on_select {
+                                on_select(window, cx);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        });
+
+        // Transparent window-sized layer: a click anywhere outside the menus
+        // closes them without reaching the content underneath.
+        let layer = anchored().position(Point::default()).child(
+            div()
+                .id(key("backdrop"))
+                .size(window.viewport_size())
+                .occlude()
+                .on_any_mouse_down(move |_event, window, cx| dismiss(window, cx))
+                .child(
+                    anchored()
+                        .position(anchor)
+                        .anchor(self.position.attach)
+                        .snap_to_window_with_margin(px(8.0))
+                        .child(menu),
+                ),
+        );
+
+        trigger
+            .child(deferred(layer).with_priority(1))
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+/// Everything needed to render one menu level.
+pub(crate) struct MenuLevel<'a> {
+    pub id: SharedString,
+    pub root: Rc<Vec<ContextMenuItem>>,
+    pub depth: usize,
+    pub path: &'a MenuPath,
+    pub on_hover: &'a MenuHoverCallback,
+    pub dismiss: &'a ContextMenuCallback,
+    pub width: Pixels,
+    pub colors: MenuColors,
+}
+
+/// Render the menu at `level.depth`, with its open submenu (if any) beside
+/// the row that opened it.
+pub(crate) fn render_menu(level: &MenuLevel) -> Stateful<Div> {
+    let MenuLevel {
+        depth,
+        path,
+        colors,
+        ..
+    } = *level;
+    let highlighted = path.highlighted(depth);
+    let open_submenu = (depth + 1 < path.depth()).then_some(highlighted).flatten();
+
+    let mut menu = div()
+        .id(SharedString::from(format!("{}-{depth}", level.id)))
+        .flex()
+        .flex_col()
+        .w(level.width)
+        .py_1()
+        .bg(colors.background)
+        .border_1()
+        .border_color(colors.border)
+        .rounded_md()
+        .shadow_lg()
+        // Stop click propagation so the backdrop handler doesn't fire
+        .on_any_mouse_down(|_event, _window, cx| cx.stop_propagation());
+
+    for (index, item) in path.items_at(&level.root, depth).iter().enumerate() {
+        if item.is_separator() {
+            menu = menu.child(div().h(px(1.0)).mx_2().my_1().bg(colors.separator));
+            continue;
+        }
+
+        let is_submenu = matches!(item.kind, ContextMenuItemKind::Submenu(_));
+        let mut row = div()
+            .id(SharedString::from(format!("{}-{depth}-{index}", level.id)))
+            .relative()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .h(px(26.0))
+            .px_3()
+            .text_sm()
+            .text_color(if item.disabled {
+                colors.disabled
+            } else {
+                colors.text
+            })
+            .when(highlighted == Some(index), |el| el.bg(colors.highlight))
+            .child(div().flex_1().overflow_x_hidden().child(item.label.clone()))
+            .when_some(item.shortcut.clone(), |el, shortcut| {
+                el.child(Kbd::new(shortcut).disabled(item.disabled))
+            })
+            .when(is_submenu, |el| {
+                el.child(div().text_xs().text_color(colors.chevron).child("▸"))
+            })
+            .on_hover({
+                let on_hover = level.on_hover.clone();
+                move |hovered, _window, cx| {
+                    if *hovered {
+                        on_hover(depth, index, cx);
+                    }
+                }
+            });
+
+        if item.disabled {
+            row = row.cursor_default();
+        } else if !is_submenu {
+            let dismiss = level.dismiss.clone();
+            let on_select = item.on_select.clone();
+            row =
+                row.cursor_pointer()
+                    .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                        dismiss(window, cx);
+                        if let Some(on_select) = &on_select {
+                            on_select(window, cx);
+                        }
+                    });
+        }
+
+        if open_submenu == Some(index) {
+            let submenu = render_menu(&MenuLevel {
+                depth: depth + 1,
+                id: level.id.clone(),
+                root: level.root.clone(),
+                ..*level
+            });
+            // Top-aligned with the row, just past the menu's right border.
+            row = row.child(
+                div().absolute().top(px(-5.0)).left(relative(1.0)).child(
+                    anchored()
+                        .offset(point(px(2.0), px(0.0)))
+                        .snap_to_window_with_margin(px(8.0))
+                        .child(submenu),
+                ),
+            );
+        }
+
+        menu = menu.child(row);
+    }
+
+    menu
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -2,18 +2,19 @@
 //!
 //! A `ComponentContract` captures the full specification of a UI component:
 //! its props, variants, states, token dependencies, interaction model,
-//! acceptance criteria, and performance evidence. Contracts are constructed
+//! acceptance criteria, and performance evidence and budgets. Contracts are constructed
 //! via the builder pattern and can be serialized to JSON for tooling.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // ---------------------------------------------------------------------------
 // Core types
 // ---------------------------------------------------------------------------
 
 /// The full contract for a single UI component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ComponentContract {
     /// Human-readable component name (e.g. "Button", "Dialog").
     pub name: String,
@@ -21,6 +22,9 @@ pub struct ComponentContract {
     pub version: String,
     /// Whether this component is reused, forked, or rewritten.
     pub disposition: Disposition,
+    /// How settled the component's API is.
+    #[serde(default)]
+    pub stability: Stability,
     /// Prop definitions describing the component's public API surface.
     pub props: Vec<PropDef>,
     /// Named visual variants the component supports.
@@ -35,14 +39,35 @@ pub struct ComponentContract {
     pub acceptance_checklist: AcceptanceChecklist,
     /// Optional performance evidence collected in release mode.
     pub perf_evidence: Option<PerfEvidence>,
+    /// Upper bounds the recorded `perf_evidence` must stay within.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub perf_budget: Option<PerfBudget>,
     /// File paths required for the component implementation.
     pub required_files: Vec<String>,
+    /// Other registry components this one renders (e.g. Select uses EmptyState).
+    /// Installing the component installs these first.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Props renamed across versions, used to guide migrations on update.
+    #[serde(default)]
+    pub prop_renames: Vec<PropRename>,
+    /// Keys the component handles, structured for cheat-sheets; the
+    /// interaction checklist's `keyboard_model` stays the full narrative.
+    #[serde(default)]
+    pub key_bindings: Vec<KeyBindingDef>,
+    /// Docs file written next to the installed sources (e.g. `README.md`).
+    /// Its contents are generated from this contract at plan time.
+    pub docs_file: Option<String>,
     /// Shared identifiers available on all component instances.
     pub shared_identifiers: SharedIdentifiers,
 }
 
 /// Shared identifiers that every component instance may carry.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// Applied to rendered elements through the `WithIdentifiers` builder mixin:
+/// `id` overrides the element id, `tooltip` fills the tooltip slot, and
+/// `metadata` is published to the accessibility tree.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct SharedIdentifiers {
     /// Unique identifier for the component instance.
     pub id: Option<String>,
@@ -52,8 +77,29 @@ pub struct SharedIdentifiers {
     pub metadata: HashMap<String, String>,
 }
 
+/// A prop rename introduced by a contract version.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PropRename {
+    /// Prop name before the rename.
+    pub from: String,
+    /// Prop name after the rename.
+    pub to: String,
+    /// Contract version that introduced the rename.
+    pub since: String,
+}
+
+/// A key binding a component handles while focused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct KeyBindingDef {
+    /// Keystrokes that trigger the action, in GPUI binding syntax
+    /// (`"enter"`, `"shift-tab"`, `"cmd-a"`); any one of them works.
+    pub keys: Vec<String>,
+    /// What the keys do, e.g. "Activate the button".
+    pub action: String,
+}
+
 /// A single prop definition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PropDef {
     /// Prop name as it appears in code.
     pub name: String,
@@ -65,10 +111,30 @@ pub struct PropDef {
     pub default_value: Option<String>,
     /// Human-readable description of the prop's purpose.
     pub description: String,
+    /// Example value as Rust code, for props whose example cannot be
+    /// derived from the type (see [`ComponentContract::prop_example`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub example: Option<String>,
+    /// `description` in other languages, keyed by locale tag (`"de-DE"`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub translations: BTreeMap<String, String>,
+}
+
+impl PropDef {
+    /// The description in `locale`, falling back to the English original.
+    pub fn localized_description(&self, locale: &str) -> &str {
+        match_locale(
+            self.translations
+                .iter()
+                .map(|(tag, text)| (tag.as_str(), text)),
+            locale,
+        )
+        .map_or(&self.description, |text| text)
+    }
 }
 
 /// Interactive and visual states a component can enter.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ComponentState {
     Hover,
@@ -98,7 +164,7 @@ impl ComponentState {
 }
 
 /// A reference to a design token used by a component.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TokenRef {
     /// Dot-separated token path (e.g. `"border.default"`).
     pub path: String,
@@ -107,7 +173,7 @@ pub struct TokenRef {
 }
 
 /// Narrative descriptions of how the component handles interactions.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct InteractionChecklist {
     /// How the component participates in focus navigation.
     pub focus_behavior: Option<String>,
@@ -121,10 +187,86 @@ pub struct InteractionChecklist {
     pub disabled_behavior: Option<String>,
     /// Behavior when the component is read-only.
     pub readonly_behavior: Option<String>,
+    /// Narratives in other languages, keyed by locale tag and then by field
+    /// name (`{"de-DE": {"keyboard_model": "..."}}`). Untranslated fields
+    /// fall back to the English original.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub translations: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl InteractionChecklist {
+    /// The narrative for `field`, or `None` if it is not described or not a
+    /// field name.
+    pub fn get(&self, field: &str) -> Option<&str> {
+        let text = match field {
+            "focus_behavior" => &self.focus_behavior,
+            "keyboard_model" => &self.keyboard_model,
+            "pointer_behavior" => &self.pointer_behavior,
+            "state_model" => &self.state_model,
+            "disabled_behavior" => &self.disabled_behavior,
+            "readonly_behavior" => &self.readonly_behavior,
+            _ => return None,
+        };
+        text.as_deref()
+    }
+
+    /// The checklist with every translated narrative in `locale` swapped in.
+    pub fn localized(&self, locale: &str) -> InteractionChecklist {
+        let mut localized = self.clone();
+        let Some(translated) = match_locale(
+            self.translations
+                .iter()
+                .map(|(tag, texts)| (tag.as_str(), texts)),
+            locale,
+        ) else {
+            return localized;
+        };
+        let slots = [
+            ("focus_behavior", &mut localized.focus_behavior),
+            ("keyboard_model", &mut localized.keyboard_model),
+            ("pointer_behavior", &mut localized.pointer_behavior),
+            ("state_model", &mut localized.state_model),
+            ("disabled_behavior", &mut localized.disabled_behavior),
+            ("readonly_behavior", &mut localized.readonly_behavior),
+        ];
+        for (field, slot) in slots {
+            if let (Some(original), Some(text)) = (slot.as_mut(), translated.get(field)) {
+                *original = text.clone();
+            }
+        }
+        localized
+    }
+}
+
+/// The value for `locale` among `(tag, value)` entries.
+///
+/// Tags match case-insensitively (`"de-DE"` finds `"de-de"`); without an
+/// exact match, an entry for the same language (`"de"` or `"de-AT"`) is used.
+pub fn match_locale<'a, T>(
+    entries: impl IntoIterator<Item = (&'a str, T)>,
+    locale: &str,
+) -> Option<T> {
+    let language = |tag: &str| {
+        tag.split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+    };
+    let wanted = language(locale);
+    let mut fallback = None;
+    for (tag, value) in entries {
+        if tag.eq_ignore_ascii_case(locale) {
+            return Some(value);
+        }
+        if fallback.is_none() && !wanted.is_empty() && language(tag) == wanted {
+            fallback = Some(value);
+        }
+    }
+    fallback
 }
 
 /// Boolean acceptance checklist for component sign-off.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct AcceptanceChecklist {
     // -- Contract checks --
     /// Focus behavior is documented.
@@ -161,8 +303,56 @@ pub struct AcceptanceChecklist {
     pub has_provenance_metadata: bool,
 }
 
+impl AcceptanceChecklist {
+    /// Item names (the serialized field names), in declaration order.
+    pub const ITEMS: [&'static str; 13] = [
+        "has_focus_behavior",
+        "has_keyboard_model",
+        "has_pointer_behavior",
+        "has_state_model",
+        "has_disabled_semantics",
+        "surfaces_mapped_to_tokens",
+        "no_hardcoded_colors",
+        "has_release_mode_evidence",
+        "no_unapproved_regressions",
+        "bounded_rendering_verified",
+        "has_story_coverage",
+        "has_interaction_tests",
+        "has_provenance_metadata",
+    ];
+
+    /// Whether `item` is checked, or `None` for an unknown item name.
+    pub fn get(&self, item: &str) -> Option<bool> {
+        let checked = match item {
+            "has_focus_behavior" => self.has_focus_behavior,
+            "has_keyboard_model" => self.has_keyboard_model,
+            "has_pointer_behavior" => self.has_pointer_behavior,
+            "has_state_model" => self.has_state_model,
+            "has_disabled_semantics" => self.has_disabled_semantics,
+            "surfaces_mapped_to_tokens" => self.surfaces_mapped_to_tokens,
+            "no_hardcoded_colors" => self.no_hardcoded_colors,
+            "has_release_mode_evidence" => self.has_release_mode_evidence,
+            "no_unapproved_regressions" => self.no_unapproved_regressions,
+            "bounded_rendering_verified" => self.bounded_rendering_verified,
+            "has_story_coverage" => self.has_story_coverage,
+            "has_interaction_tests" => self.has_interaction_tests,
+            "has_provenance_metadata" => self.has_provenance_metadata,
+            _ => return None,
+        };
+        Some(checked)
+    }
+
+    /// Number of checked items.
+    pub fn completed(&self) -> usize {
+        Self::ITEMS
+            .iter()
+            .filter(|item| self.get(item) == Some(true))
+            .count()
+    }
+}
+
 /// Disposition rule describing how the component was sourced.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Disposition {
     /// Re-used from an upstream library without modification.
@@ -173,8 +363,65 @@ pub enum Disposition {
     Rewrite,
 }
 
+/// How settled a component's API is.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Stability {
+    /// New and may change in any release; protected projects refuse it.
+    Experimental,
+    /// Feature-complete, but props may still be renamed.
+    Beta,
+    /// Changes follow the contract version.
+    #[default]
+    Stable,
+    /// Kept for existing users and slated for removal.
+    Deprecated,
+}
+
+impl Stability {
+    /// All tiers, from least to most settled, then deprecated.
+    pub const ALL: [Stability; 4] = [
+        Stability::Experimental,
+        Stability::Beta,
+        Stability::Stable,
+        Stability::Deprecated,
+    ];
+
+    /// The tier's name, as accepted by `gpui list --stability`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Stability::Experimental => "experimental",
+            Stability::Beta => "beta",
+            Stability::Stable => "stable",
+            Stability::Deprecated => "deprecated",
+        }
+    }
+
+    /// Look up a tier by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tier| tier.name() == name)
+    }
+}
+
+impl std::str::FromStr for Stability {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|tier| tier.name()).collect();
+            format!(
+                "unknown stability '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
+}
+
 /// Performance evidence collected in release mode.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PerfEvidence {
     /// Time to first render in milliseconds.
     pub render_time_ms: Option<f64>,
@@ -182,6 +429,100 @@ pub struct PerfEvidence {
     pub interaction_latency_ms: Option<f64>,
     /// Free-form notes about the measurement.
     pub notes: String,
+    /// UTC date (`YYYY-MM-DD`) the measurement was taken, if recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub measured_on: Option<String>,
+    /// Frame times from scrolling a large dataset, for virtualized components.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frame_budget: Option<FrameBudgetEvidence>,
+}
+
+/// Frame budget for one frame at 60 Hz, in milliseconds.
+pub const DEFAULT_FRAME_BUDGET_MS: f64 = 1000.0 / 60.0;
+
+/// A scrolling benchmark over a large dataset. Rendering is bounded when the
+/// slowest frame stays within the budget, whatever the row count.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FrameBudgetEvidence {
+    /// Rows in the benchmarked dataset.
+    pub rows: u64,
+    /// Budget each frame was held to, in milliseconds.
+    pub budget_ms: f64,
+    /// Slowest frame observed while scrolling, in milliseconds.
+    pub worst_frame_ms: f64,
+}
+
+impl FrameBudgetEvidence {
+    /// Whether every frame fit in the budget.
+    pub fn within_budget(&self) -> bool {
+        self.worst_frame_ms <= self.budget_ms
+    }
+}
+
+/// Per-component performance budget: the slowest [`PerfEvidence`]
+/// measurements the component may record. Unset limits are not enforced.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PerfBudget {
+    /// Longest allowed time to first render, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_render_ms: Option<f64>,
+    /// Longest allowed interaction-to-visual-update latency, in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_interaction_ms: Option<f64>,
+}
+
+/// A recorded measurement over its [`PerfBudget`] limit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BudgetOverrun {
+    /// The limit exceeded, e.g. `max_render_ms`.
+    pub limit: String,
+    pub budget_ms: f64,
+    pub measured_ms: f64,
+}
+
+impl PerfBudget {
+    /// Each limit by name, with the matching measurement from `evidence`.
+    fn limits(
+        &self,
+        evidence: Option<&PerfEvidence>,
+    ) -> [(&'static str, Option<f64>, Option<f64>); 2] {
+        [
+            (
+                "max_render_ms",
+                self.max_render_ms,
+                evidence.and_then(|evidence| evidence.render_time_ms),
+            ),
+            (
+                "max_interaction_ms",
+                self.max_interaction_ms,
+                evidence.and_then(|evidence| evidence.interaction_latency_ms),
+            ),
+        ]
+    }
+
+    /// The measurements in `evidence` over their limit.
+    pub fn overruns(&self, evidence: &PerfEvidence) -> Vec<BudgetOverrun> {
+        self.limits(Some(evidence))
+            .into_iter()
+            .filter_map(|(limit, budget_ms, measured_ms)| {
+                let (budget_ms, measured_ms) = (budget_ms?, measured_ms?);
+                (measured_ms > budget_ms).then(|| BudgetOverrun {
+                    limit: limit.to_string(),
+                    budget_ms,
+                    measured_ms,
+                })
+            })
+            .collect()
+    }
+
+    /// Names of the set limits `evidence` has no measurement for.
+    pub fn unmeasured(&self, evidence: Option<&PerfEvidence>) -> Vec<&'static str> {
+        self.limits(evidence)
+            .into_iter()
+            .filter(|(_, budget_ms, measured_ms)| budget_ms.is_some() && measured_ms.is_none())
+            .map(|(limit, ..)| limit)
+            .collect()
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -212,6 +553,11 @@ impl ComponentContract {
     /// - At least one prop must be defined.
     /// - At least one state must be listed.
     /// - Required props must not have a default value (they are caller-supplied).
+    /// - Prop renames must point at a prop the contract defines.
+    /// - A component must not list itself as a dependency.
+    /// - Every key binding must name at least one keystroke and an action.
+    /// - Translations must be keyed by a locale tag, and checklist translations
+    ///   must name a narrative the checklist describes.
     /// - All required interaction-checklist fields for the declared states must
     ///   be filled in (e.g. if `Disabled` is listed, `disabled_behavior` must
     ///   be `Some`).
@@ -259,8 +605,102 @@ impl ComponentContract {
             }
         }
 
-        // State-dependent interaction checklist validation.
+        for (i, rename) in self.prop_renames.iter().enumerate() {
+            if !self.props.iter().any(|p| p.name == rename.to) {
+                errors.push(ValidationError {
+                    field: format!("prop_renames[{}].to", i),
+                    message: format!(
+                        "Prop '{}' is renamed to '{}', which is not defined",
+                        rename.from, rename.to
+                    ),
+                });
+            }
+        }
+
+        if let Some(budget) = self
+            .perf_evidence
+            .as_ref()
+            .and_then(|evidence| evidence.frame_budget.as_ref())
+            && (budget.budget_ms.is_nan() || budget.budget_ms <= 0.0)
+        {
+            errors.push(ValidationError {
+                field: "perf_evidence.frame_budget.budget_ms".into(),
+                message: format!("Frame budget must be positive, got {}ms", budget.budget_ms),
+            });
+        }
+
+        for (limit, budget_ms, _) in self
+            .perf_budget
+            .as_ref()
+            .map(|budget| budget.limits(None))
+            .into_iter()
+            .flatten()
+        {
+            if let Some(budget_ms) = budget_ms
+                && (budget_ms.is_nan() || budget_ms <= 0.0)
+            {
+                errors.push(ValidationError {
+                    field: format!("perf_budget.{limit}"),
+                    message: format!("Budget must be positive, got {budget_ms}ms"),
+                });
+            }
+        }
+
+        for (i, dependency) in self.dependencies.iter().enumerate() {
+            if dependency.eq_ignore_ascii_case(&self.name) {
+                errors.push(ValidationError {
+                    field: format!("dependencies[{}]", i),
+                    message: format!("Component '{}' depends on itself", self.name),
+                });
+            }
+        }
+
+        for (i, binding) in self.key_bindings.iter().enumerate() {
+            if binding.keys.is_empty() || binding.keys.iter().any(|k| k.trim().is_empty()) {
+                errors.push(ValidationError {
+                    field: format!("key_bindings[{}].keys", i),
+                    message: format!("Key binding '{}' has an empty keystroke", binding.action),
+                });
+            }
+            if binding.action.trim().is_empty() {
+                errors.push(ValidationError {
+                    field: format!("key_bindings[{}].action", i),
+                    message: "Key binding action must not be empty".into(),
+                });
+            }
+        }
+
+        for (i, prop) in self.props.iter().enumerate() {
+            if prop.translations.keys().any(|tag| tag.trim().is_empty()) {
+                errors.push(ValidationError {
+                    field: format!("props[{}].translations", i),
+                    message: format!("Prop '{}' has a translation without a locale", prop.name),
+                });
+            }
+        }
+
         let ic = &self.interaction_checklist;
+        for (tag, texts) in &ic.translations {
+            if tag.trim().is_empty() {
+                errors.push(ValidationError {
+                    field: "interaction_checklist.translations".into(),
+                    message: "Checklist translation has no locale".into(),
+                });
+            }
+            for field in texts.keys() {
+                if ic.get(field).is_none() {
+                    errors.push(ValidationError {
+                        field: format!("interaction_checklist.translations.{}", tag),
+                        message: format!(
+                            "'{}' is translated but is not a described checklist field",
+                            field
+                        ),
+                    });
+                }
+            }
+        }
+
+        // State-dependent interaction checklist validation.
         if self.states.contains(&ComponentState::Disabled) && ic.disabled_behavior.is_none() {
             errors.push(ValidationError {
                 field: "interaction_checklist.disabled_behavior".into(),
@@ -295,6 +735,7 @@ impl ComponentContract {
             name: name.into(),
             version: version.into(),
             disposition: Disposition::Rewrite,
+            stability: Stability::Stable,
             props: Vec::new(),
             variants: Vec::new(),
             states: Vec::new(),
@@ -302,12 +743,193 @@ impl ComponentContract {
             interaction_checklist: InteractionChecklist::default(),
             acceptance_checklist: AcceptanceChecklist::default(),
             perf_evidence: None,
+            perf_budget: None,
             required_files: Vec::new(),
+            dependencies: Vec::new(),
+            prop_renames: Vec::new(),
+            key_bindings: Vec::new(),
+            docs_file: None,
             shared_identifiers: SharedIdentifiers::default(),
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Acceptance evaluation
+// ---------------------------------------------------------------------------
+
+impl ComponentContract {
+    /// The acceptance checklist with every item the contract itself proves
+    /// checked.
+    ///
+    /// Documentation items follow the interaction checklist, token mapping
+    /// follows `token_dependencies`, release-mode evidence follows
+    /// `perf_evidence`, and bounded rendering follows a frame-budget benchmark
+    /// that stayed within budget. Items the contract declares checked stay
+    /// checked; the
+    /// rest (story coverage, interaction tests, regressions, ...) need a
+    /// human sign-off.
+    pub fn evaluated_checklist(&self) -> AcceptanceChecklist {
+        let declared = &self.acceptance_checklist;
+        let interaction = &self.interaction_checklist;
+        AcceptanceChecklist {
+            has_focus_behavior: declared.has_focus_behavior || interaction.focus_behavior.is_some(),
+            has_keyboard_model: declared.has_keyboard_model || interaction.keyboard_model.is_some(),
+            has_pointer_behavior: declared.has_pointer_behavior
+                || interaction.pointer_behavior.is_some(),
+            has_state_model: declared.has_state_model || interaction.state_model.is_some(),
+            has_disabled_semantics: declared.has_disabled_semantics
+                || interaction.disabled_behavior.is_some(),
+            surfaces_mapped_to_tokens: declared.surfaces_mapped_to_tokens
+                || !self.token_dependencies.is_empty(),
+            has_release_mode_evidence: declared.has_release_mode_evidence
+                || self.perf_evidence.is_some(),
+            bounded_rendering_verified: declared.bounded_rendering_verified
+                || self
+                    .perf_evidence
+                    .as_ref()
+                    .and_then(|evidence| evidence.frame_budget.as_ref())
+                    .is_some_and(FrameBudgetEvidence::within_budget),
+            ..declared.clone()
+        }
+    }
+
+    /// Recorded measurements over the contract's `perf_budget`; empty when
+    /// either the budget or the evidence is missing.
+    pub fn budget_overruns(&self) -> Vec<BudgetOverrun> {
+        match (&self.perf_budget, &self.perf_evidence) {
+            (Some(budget), Some(evidence)) => budget.overruns(evidence),
+            _ => Vec::new(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Localization
+// ---------------------------------------------------------------------------
+
+impl ComponentContract {
+    /// The contract with prop descriptions and interaction narratives in
+    /// `locale` wherever a translation exists; the rest stay English.
+    pub fn localized(&self, locale: &str) -> ComponentContract {
+        let mut localized = self.clone();
+        for prop in &mut localized.props {
+            prop.description = prop.localized_description(locale).to_string();
+        }
+        localized.interaction_checklist = self.interaction_checklist.localized(locale);
+        localized
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Prop documentation
+// ---------------------------------------------------------------------------
+
+impl ComponentContract {
+    /// An example value for `prop`, as Rust code.
+    ///
+    /// Uses the prop's declared example, otherwise derives one from its type:
+    /// the non-default value of a `bool`, a label for strings, a contract
+    /// variant other than the default for the `variant` prop, and the default
+    /// value for other enums. Returns `None` for types with no obvious example
+    /// (callbacks, collections) unless the contract declares one.
+    pub fn prop_example(&self, prop: &PropDef) -> Option<String> {
+        if let Some(example) = &prop.example {
+            return Some(example.clone());
+        }
+        let type_name = prop
+            .type_name
+            .strip_prefix("Option<")
+            .and_then(|inner| inner.strip_suffix('>'))
+            .unwrap_or(&prop.type_name);
+        let default = prop
+            .default_value
+            .as_deref()
+            .filter(|default| *default != "None");
+        let example = match type_name {
+            "bool" => (if default == Some("true") {
+                "false"
+            } else {
+                "true"
+            })
+            .to_string(),
+            "SharedString" | "String" => format!("{:?}", sentence_case(&prop.name)),
+            "ElementId" => format!("{:?}", kebab_case(&self.name)),
+            "u32" | "u64" | "usize" => default
+                .and_then(|default| default.parse::<u64>().ok())
+                .map_or(1, |default| default + 1)
+                .to_string(),
+            "f32" | "f64" => "0.5".to_string(),
+            "Pixels" => format!(
+                "px({})",
+                default
+                    .filter(|default| default.parse::<f32>().is_ok())
+                    .unwrap_or("240.0")
+            ),
+            "Duration" => "Duration::from_millis(300)".to_string(),
+            _ if type_name.chars().all(char::is_alphanumeric)
+                && type_name.starts_with(char::is_uppercase) =>
+            {
+                let variant = if prop.name == "variant" {
+                    self.variants
+                        .iter()
+                        .map(String::as_str)
+                        .find(|variant| Some(*variant) != default)
+                        .or(default)
+                } else {
+                    default
+                }?;
+                if variant.contains("::") {
+                    variant.to_string()
+                } else {
+                    format!("{type_name}::{variant}")
+                }
+            }
+            _ => return None,
+        };
+        Some(example)
+    }
+
+    /// Code that sets `prop`, for pasting into a component call site.
+    ///
+    /// Optional props become a builder call such as
+    /// `.variant(ButtonVariant::Primary)`. Required props are constructor
+    /// arguments, so the snippet is the example argument alone. Without an
+    /// example the argument is a `/* Type */` placeholder.
+    pub fn prop_snippet(&self, prop: &PropDef) -> String {
+        let argument = self
+            .prop_example(prop)
+            .unwrap_or_else(|| format!("/* {} */", prop.type_name));
+        if prop.required {
+            argument
+        } else {
+            format!(".{}({})", prop.name, argument)
+        }
+    }
+}
+
+/// `snake_case` prop name as a sentence: `error_message` -> `Error message`.
+fn sentence_case(name: &str) -> String {
+    let words = name.replace('_', " ");
+    let mut chars = words.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+/// `PascalCase` component name in kebab case: `EmptyState` -> `empty-state`.
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            kebab.push('-');
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
 // ---------------------------------------------------------------------------
 // Builder
 // ---------------------------------------------------------------------------
@@ -318,6 +940,7 @@ pub struct ContractBuilder {
     name: String,
     version: String,
     disposition: Disposition,
+    stability: Stability,
     props: Vec<PropDef>,
     variants: Vec<String>,
     states: Vec<ComponentState>,
@@ -325,7 +948,12 @@ pub struct ContractBuilder {
     interaction_checklist: InteractionChecklist,
     acceptance_checklist: AcceptanceChecklist,
     perf_evidence: Option<PerfEvidence>,
+    perf_budget: Option<PerfBudget>,
     required_files: Vec<String>,
+    dependencies: Vec<String>,
+    prop_renames: Vec<PropRename>,
+    key_bindings: Vec<KeyBindingDef>,
+    docs_file: Option<String>,
     shared_identifiers: SharedIdentifiers,
 }
 
@@ -336,6 +964,12 @@ impl ContractBuilder {
         self
     }
 
+    /// Set the stability tier. Contracts are stable unless set.
+    pub fn stability(mut self, stability: Stability) -> Self {
+        self.stability = stability;
+        self
+    }
+
     /// Add a prop definition.
     pub fn prop(mut self, prop: PropDef) -> Self {
         self.props.push(prop);
@@ -355,6 +989,8 @@ impl ContractBuilder {
             required: true,
             default_value: None,
             description: description.into(),
+            example: None,
+            translations: BTreeMap::new(),
         })
     }
 
@@ -372,9 +1008,33 @@ impl ContractBuilder {
             required: false,
             default_value: Some(default_value.into()),
             description: description.into(),
+            example: None,
+            translations: BTreeMap::new(),
         })
     }
 
+    /// Set the example value of an already added prop, as Rust code.
+    pub fn prop_example(mut self, name: &str, example: impl Into<String>) -> Self {
+        if let Some(prop) = self.props.iter_mut().find(|prop| prop.name == name) {
+            prop.example = Some(example.into());
+        }
+        self
+    }
+
+    /// Set the description of an already added prop in `locale` (a tag
+    /// such as `"de-DE"`).
+    pub fn prop_translation(
+        mut self,
+        name: &str,
+        locale: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        if let Some(prop) = self.props.iter_mut().find(|prop| prop.name == name) {
+            prop.translations.insert(locale.into(), description.into());
+        }
+        self
+    }
+
     /// Add a named variant.
     pub fn variant(mut self, variant: impl Into<String>) -> Self {
         self.variants.push(variant.into());
@@ -450,6 +1110,22 @@ impl ContractBuilder {
         self
     }
 
+    /// Set the interaction narrative `field` (e.g. `"keyboard_model"`) in
+    /// `locale`.
+    pub fn checklist_translation(
+        mut self,
+        locale: impl Into<String>,
+        field: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Self {
+        self.interaction_checklist
+            .translations
+            .entry(locale.into())
+            .or_default()
+            .insert(field.into(), text.into());
+        self
+    }
+
     /// Set the acceptance checklist.
     pub fn acceptance_checklist(mut self, checklist: AcceptanceChecklist) -> Self {
         self.acceptance_checklist = checklist;
@@ -462,12 +1138,54 @@ impl ContractBuilder {
         self
     }
 
+    /// Set the performance budget the evidence must stay within.
+    pub fn perf_budget(mut self, budget: PerfBudget) -> Self {
+        self.perf_budget = Some(budget);
+        self
+    }
+
     /// Add a required file path.
     pub fn required_file(mut self, file: impl Into<String>) -> Self {
         self.required_files.push(file.into());
         self
     }
 
+    /// Add a registry component this one depends on.
+    pub fn dependency(mut self, component: impl Into<String>) -> Self {
+        self.dependencies.push(component.into());
+        self
+    }
+
+    /// Record that prop `from` was renamed to `to` in version `since`.
+    pub fn renamed_prop(
+        mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        since: impl Into<String>,
+    ) -> Self {
+        self.prop_renames.push(PropRename {
+            from: from.into(),
+            to: to.into(),
+            since: since.into(),
+        });
+        self
+    }
+
+    /// Add a key binding: any of `keys` (GPUI binding syntax) performs `action`.
+    pub fn key_binding(mut self, keys: &[&str], action: impl Into<String>) -> Self {
+        self.key_bindings.push(KeyBindingDef {
+            keys: keys.iter().map(|k| k.to_string()).collect(),
+            action: action.into(),
+        });
+        self
+    }
+
+    /// Declare a docs file to bundle into install plans.
+    pub fn docs_file(mut self, file: impl Into<String>) -> Self {
+        self.docs_file = Some(file.into());
+        self
+    }
+
     /// Set the component instance id.
     pub fn id(mut self, id: impl Into<String>) -> Self {
         self.shared_identifiers.id = Some(id.into());
@@ -494,6 +1212,7 @@ impl ContractBuilder {
             name: self.name,
             version: self.version,
             disposition: self.disposition,
+            stability: self.stability,
             props: self.props,
             variants: self.variants,
             states: self.states,
@@ -501,7 +1220,12 @@ impl ContractBuilder {
             interaction_checklist: self.interaction_checklist,
             acceptance_checklist: self.acceptance_checklist,
             perf_evidence: self.perf_evidence,
+            perf_budget: self.perf_budget,
             required_files: self.required_files,
+            dependencies: self.dependencies,
+            prop_renames: self.prop_renames,
+            key_bindings: self.key_bindings,
+            docs_file: self.docs_file,
             shared_identifiers: self.shared_identifiers,
         }
     }
@@ -540,6 +1264,7 @@ mod tests {
             .state_model("Uncontrolled; fires on_click callback")
             .disabled_behavior("Ignores pointer and keyboard events; reduced opacity")
             .required_file("crates/components/src/button.rs")
+            .docs_file("README.md")
             .id("btn-primary")
             .tooltip("Click me")
             .metadata("provenance", "custom")
@@ -558,6 +1283,7 @@ mod tests {
         assert_eq!(contract.states.len(), 4);
         assert_eq!(contract.token_dependencies.len(), 2);
         assert_eq!(contract.required_files.len(), 1);
+        assert_eq!(contract.docs_file.as_deref(), Some("README.md"));
         assert_eq!(
             contract.shared_identifiers.id.as_deref(),
             Some("btn-primary")
@@ -597,6 +1323,7 @@ mod tests {
         assert_eq!(deserialized.props.len(), contract.props.len());
         assert_eq!(deserialized.states.len(), contract.states.len());
         assert_eq!(deserialized.variants, contract.variants);
+        assert_eq!(deserialized.docs_file, contract.docs_file);
     }
 
     #[test]
@@ -653,6 +1380,8 @@ mod tests {
                 required: true,
                 default_value: Some("42".into()),
                 description: "bad prop".into(),
+                example: None,
+                translations: BTreeMap::new(),
             })
             .state(ComponentState::Active)
             .build();
@@ -660,6 +1389,53 @@ mod tests {
         assert!(errors.iter().any(|e| e.field == "props[0].default_value"));
     }
 
+    #[test]
+    fn test_validation_rename_to_unknown_prop() {
+        let mut contract = sample_contract();
+        contract.prop_renames.push(PropRename {
+            from: "text".into(),
+            to: "caption".into(),
+            since: "0.2.0".into(),
+        });
+        let errors = contract.validate();
+        assert!(errors.iter().any(|e| e.field == "prop_renames[0].to"));
+
+        let renamed = ComponentContract::builder("Button", "0.2.0")
+            .required_prop("label", "SharedString", "Button label text")
+            .renamed_prop("text", "label", "0.2.0")
+            .state(ComponentState::Active)
+            .build();
+        assert!(renamed.validate().is_empty());
+    }
+
+    #[test]
+    fn test_validation_self_dependency() {
+        let contract = ComponentContract::builder("Select", "0.1.0")
+            .required_prop("id", "ElementId", "Unique identifier")
+            .state(ComponentState::Active)
+            .dependency("EmptyState")
+            .dependency("select")
+            .build();
+        let errors = contract.validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "dependencies[1]");
+    }
+
+    #[test]
+    fn test_validation_empty_key_binding() {
+        let contract = ComponentContract::builder("Slider", "0.1.0")
+            .required_prop("id", "ElementId", "Unique identifier")
+            .state(ComponentState::Active)
+            .key_binding(&["right", "up"], "Increase by one step")
+            .key_binding(&[], "Jump to the minimum")
+            .key_binding(&["end"], " ")
+            .build();
+        let errors = contract.validate();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, ["key_bindings[1].keys", "key_bindings[2].action"]);
+        assert_eq!(contract.key_bindings[0].keys, ["right", "up"]);
+    }
+
     #[test]
     fn test_validation_disabled_without_behavior() {
         let contract = ComponentContract::builder("Foo", "0.1.0")
@@ -763,6 +1539,8 @@ mod tests {
                 render_time_ms: Some(2.5),
                 interaction_latency_ms: Some(16.0),
                 notes: "Measured on M1 MacBook Pro".into(),
+                measured_on: Some("2026-01-02".into()),
+                frame_budget: None,
             })
             .build();
 
@@ -770,6 +1548,7 @@ mod tests {
         assert_eq!(evidence.render_time_ms, Some(2.5));
         assert_eq!(evidence.interaction_latency_ms, Some(16.0));
         assert_eq!(evidence.notes, "Measured on M1 MacBook Pro");
+        assert_eq!(evidence.measured_on.as_deref(), Some("2026-01-02"));
     }
 
     #[test]
@@ -790,6 +1569,137 @@ mod tests {
         assert!(!checklist.has_provenance_metadata);
     }
 
+    #[test]
+    fn test_acceptance_checklist_items_match_fields() {
+        let json = serde_json::to_value(AcceptanceChecklist::default()).unwrap();
+        let mut fields: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(|k| k.as_str())
+            .collect();
+        let mut items = AcceptanceChecklist::ITEMS.to_vec();
+        items.sort();
+        fields.sort();
+        assert_eq!(items, fields);
+
+        let checklist = AcceptanceChecklist {
+            has_story_coverage: true,
+            ..Default::default()
+        };
+        assert_eq!(checklist.get("has_story_coverage"), Some(true));
+        assert_eq!(checklist.get("has_interaction_tests"), Some(false));
+        assert_eq!(checklist.get("nope"), None);
+        assert_eq!(checklist.completed(), 1);
+    }
+
+    #[test]
+    fn test_evaluated_checklist_follows_contract() {
+        let checklist = sample_contract().evaluated_checklist();
+        assert!(checklist.has_focus_behavior);
+        assert!(checklist.has_disabled_semantics);
+        assert!(checklist.surfaces_mapped_to_tokens);
+        assert!(!checklist.has_release_mode_evidence);
+        assert!(!checklist.has_story_coverage);
+
+        let bare = ComponentContract::builder("Foo", "0.1.0")
+            .required_prop("label", "SharedString", "Label")
+            .state(ComponentState::Hover)
+            .acceptance_checklist(AcceptanceChecklist {
+                has_story_coverage: true,
+                ..Default::default()
+            })
+            .build()
+            .evaluated_checklist();
+        assert!(!bare.has_focus_behavior);
+        assert!(bare.has_story_coverage, "declared items stay checked");
+    }
+
+    #[test]
+    fn test_frame_budget_verifies_bounded_rendering() {
+        let with_budget = |worst_frame_ms: f64, budget_ms: f64| {
+            ComponentContract::builder("X", "1.0.0")
+                .required_prop("x", "u32", "a prop")
+                .state(ComponentState::Active)
+                .perf_evidence(PerfEvidence {
+                    render_time_ms: None,
+                    interaction_latency_ms: None,
+                    notes: "Scrolled 100k rows".into(),
+                    measured_on: None,
+                    frame_budget: Some(FrameBudgetEvidence {
+                        rows: 100_000,
+                        budget_ms,
+                        worst_frame_ms,
+                    }),
+                })
+                .build()
+        };
+
+        let fast = with_budget(9.5, DEFAULT_FRAME_BUDGET_MS);
+        assert!(fast.evaluated_checklist().bounded_rendering_verified);
+        assert!(fast.validate().is_empty());
+        let slow = with_budget(21.0, DEFAULT_FRAME_BUDGET_MS);
+        assert!(!slow.evaluated_checklist().bounded_rendering_verified);
+        assert!(slow.evaluated_checklist().has_release_mode_evidence);
+        assert!(
+            !sample_contract()
+                .evaluated_checklist()
+                .bounded_rendering_verified
+        );
+
+        let errors = with_budget(1.0, 0.0).validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "perf_evidence.frame_budget.budget_ms");
+    }
+
+    #[test]
+    fn test_perf_budget_overruns() {
+        let measured = |render_time_ms: f64| {
+            ComponentContract::builder("X", "1.0.0")
+                .required_prop("x", "u32", "a prop")
+                .state(ComponentState::Active)
+                .perf_evidence(PerfEvidence {
+                    render_time_ms: Some(render_time_ms),
+                    interaction_latency_ms: None,
+                    notes: String::new(),
+                    measured_on: None,
+                    frame_budget: None,
+                })
+                .perf_budget(PerfBudget {
+                    max_render_ms: Some(4.0),
+                    max_interaction_ms: Some(DEFAULT_FRAME_BUDGET_MS),
+                })
+                .build()
+        };
+
+        assert!(measured(3.5).budget_overruns().is_empty());
+        let slow = measured(6.0);
+        assert_eq!(
+            slow.budget_overruns(),
+            [BudgetOverrun {
+                limit: "max_render_ms".into(),
+                budget_ms: 4.0,
+                measured_ms: 6.0,
+            }]
+        );
+        let budget = slow.perf_budget.as_ref().unwrap();
+        assert_eq!(
+            budget.unmeasured(slow.perf_evidence.as_ref()),
+            ["max_interaction_ms"]
+        );
+        assert_eq!(budget.unmeasured(None).len(), 2);
+        assert!(sample_contract().budget_overruns().is_empty());
+
+        let mut invalid = measured(1.0);
+        invalid.perf_budget = Some(PerfBudget {
+            max_render_ms: Some(0.0),
+            max_interaction_ms: None,
+        });
+        let errors = invalid.validate();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "perf_budget.max_render_ms");
+    }
+
     #[test]
     fn test_json_state_names() {
         let json = serde_json::to_string(&ComponentState::Hover).unwrap();
@@ -807,4 +1717,129 @@ mod tests {
         let json = serde_json::to_string(&Disposition::Rewrite).unwrap();
         assert_eq!(json, "\"rewrite\"");
     }
+
+    #[test]
+    fn test_stability_defaults_to_stable_and_parses_names() {
+        assert_eq!(sample_contract().stability, Stability::Stable);
+        let json = serde_json::to_string(&Stability::Experimental).unwrap();
+        assert_eq!(json, "\"experimental\"");
+
+        let mut value = serde_json::to_value(sample_contract()).unwrap();
+        value.as_object_mut().unwrap().remove("stability");
+        let contract: ComponentContract = serde_json::from_value(value).unwrap();
+        assert_eq!(contract.stability, Stability::Stable);
+
+        assert_eq!("beta".parse::<Stability>(), Ok(Stability::Beta));
+        let err = "alpha".parse::<Stability>().unwrap_err();
+        assert!(err.contains("experimental, beta, stable, deprecated"));
+    }
+
+    #[test]
+    fn test_prop_snippets_are_derived_from_contract() {
+        let contract = ComponentContract::builder("EmptyState", "0.1.0")
+            .required_prop("id", "ElementId", "Unique identifier")
+            .optional_prop("variant", "ButtonVariant", "Secondary", "Visual variant")
+            .optional_prop("size", "ButtonSize", "Medium", "Size")
+            .optional_prop("disabled", "bool", "false", "Whether disabled")
+            .optional_prop(
+                "error_message",
+                "Option<SharedString>",
+                "None",
+                "Error text",
+            )
+            .optional_prop("rows", "u32", "3", "Visible rows")
+            .optional_prop("width", "Pixels", "480.0", "Width in pixels")
+            .optional_prop("on_query", "Option<OnQueryCallback>", "None", "Callback")
+            .optional_prop("items", "Vec<SelectItem>", "[]", "Items")
+            .prop_example("items", r#"vec![SelectItem::new("Apple")]"#)
+            .variant("Secondary")
+            .variant("Primary")
+            .state(ComponentState::Active)
+            .build();
+        let snippet = |name: &str| {
+            let prop = contract.props.iter().find(|p| p.name == name).unwrap();
+            contract.prop_snippet(prop)
+        };
+
+        assert_eq!(snippet("id"), r#""empty-state""#);
+        assert_eq!(snippet("variant"), ".variant(ButtonVariant::Primary)");
+        assert_eq!(snippet("size"), ".size(ButtonSize::Medium)");
+        assert_eq!(snippet("disabled"), ".disabled(true)");
+        assert_eq!(
+            snippet("error_message"),
+            r#".error_message("Error message")"#
+        );
+        assert_eq!(snippet("rows"), ".rows(4)");
+        assert_eq!(snippet("width"), ".width(px(480.0))");
+        assert_eq!(
+            snippet("on_query"),
+            ".on_query(/* Option<OnQueryCallback> */)"
+        );
+        assert_eq!(
+            snippet("items"),
+            r#".items(vec![SelectItem::new("Apple")])"#
+        );
+    }
+
+    #[test]
+    fn test_prop_example_is_omitted_from_json_when_unset() {
+        let json = serde_json::to_value(sample_contract()).unwrap();
+        assert!(json["props"][0].get("example").is_none());
+        assert!(json["props"][0].get("translations").is_none());
+        assert!(json["interaction_checklist"].get("translations").is_none());
+    }
+
+    #[test]
+    fn test_localized_contract_falls_back_to_english() {
+        let contract = ComponentContract::builder("Button", "0.1.0")
+            .required_prop("label", "SharedString", "Button label text")
+            .prop_translation("label", "de-DE", "Beschriftung der Schaltfläche")
+            .state(ComponentState::Focused)
+            .focus_behavior("Receives focus via Tab key")
+            .keyboard_model("Enter/Space activates the button")
+            .checklist_translation("de", "keyboard_model", "Enter/Leertaste löst aus")
+            .build();
+        assert!(contract.validate().is_empty());
+
+        let german = contract.localized("de-DE");
+        assert_eq!(german.props[0].description, "Beschriftung der Schaltfläche");
+        // "de" covers every German region.
+        assert_eq!(
+            german.interaction_checklist.keyboard_model.as_deref(),
+            Some("Enter/Leertaste löst aus")
+        );
+        assert_eq!(
+            german.interaction_checklist.focus_behavior.as_deref(),
+            Some("Receives focus via Tab key")
+        );
+        assert_eq!(
+            contract.localized("DE-at").props[0].description,
+            "Beschriftung der Schaltfläche"
+        );
+
+        let french = contract.localized("fr-FR");
+        assert_eq!(french.props[0].description, "Button label text");
+        assert_eq!(
+            french.interaction_checklist.keyboard_model.as_deref(),
+            Some("Enter/Space activates the button")
+        );
+    }
+
+    #[test]
+    fn test_validation_translation_of_undescribed_field() {
+        let contract = ComponentContract::builder("Foo", "0.1.0")
+            .required_prop("id", "ElementId", "Identifier")
+            .prop_translation("id", " ", "Kennung")
+            .state(ComponentState::Active)
+            .checklist_translation("de-DE", "readonly_behavior", "Schreibgeschützt")
+            .build();
+        let fields: Vec<String> = contract.validate().into_iter().map(|e| e.field).collect();
+        assert_eq!(
+            fields,
+            [
+                "props[0].translations",
+                "interaction_checklist.translations.de-DE"
+            ]
+        );
+    }
 }
@@ -0,0 +1,689 @@
+//! DataTable component: column-defined rows with sorting, selection, and
+//! keyboard navigation, virtualized for large data sets.
+//!
+//! Fork disposition: adapted from gpui-component `table` and Zed's
+//! `uniform_list`-backed pickers. Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - gpui-component `crates/ui/src/table/mod.rs` (MIT, Zed Industries)
+//! - Modifications: Stateless RenderOnce rewrite over `uniform_list`, so only the
+//!   visible rows are laid out. Sorting and selection use ControlledState; arrow-key
+//!   row navigation uses the keyboard primitives. Column resizing, reordering, and
+//!   cell editing were dropped from POC scope.
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+use crate::empty_state::{EmptyState, EmptyStateSize};
+use crate::tooltip::{TooltipPlacement, WithTooltip, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::{
+    ControlledState, NavDirection, Orientation, classify_nav_key, is_activation_key, navigate_index,
+};
+use theme::ActiveTheme;
+
+/// Horizontal alignment of a column's header and cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnAlign {
+    /// Left-aligned (default).
+    #[default]
+    Start,
+    /// Right-aligned, for numbers.
+    End,
+}
+
+/// A column definition.
+#[derive(Debug, Clone)]
+pub struct Column {
+    /// Header label.
+    pub label: SharedString,
+    /// Fixed width; columns without one share the remaining space.
+    pub width: Option<Pixels>,
+    /// Whether clicking the header sorts by this column.
+    pub sortable: bool,
+    /// Header and cell alignment.
+    pub align: ColumnAlign,
+}
+
+impl Column {
+    /// Create a flexible-width, unsortable column.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            width: None,
+            sortable: false,
+            align: ColumnAlign::Start,
+        }
+    }
+
+    /// Give the column a fixed width.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Allow sorting by this column.
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// Set the alignment.
+    pub fn align(mut self, align: ColumnAlign) -> Self {
+        self.align = align;
+        self
+    }
+}
+
+/// A row of cell values, one per column.
+#[derive(Debug, Clone, Default)]
+pub struct TableRow {
+    /// Cell text in column order. Missing cells render empty.
+    pub cells: Vec<SharedString>,
+    /// Whether this row is disabled.
+    pub disabled: bool,
+}
+
+impl TableRow {
+    /// Create an enabled row.
+    pub fn new(cells: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        Self {
+            cells: cells.into_iter().map(Into::into).collect(),
+            disabled: false,
+        }
+    }
+
+    /// Set the row as disabled.
+    pub fn set_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// The cell in `column`, or an empty string if the row is short.
+    pub fn cell(&self, column: usize) -> &str {
+        self.cells.get(column).map_or("", |cell| cell.as_ref())
+    }
+}
+
+/// Sort direction of a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// The column the table is sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnSort {
+    /// Column index.
+    pub column: usize,
+    /// Sort direction.
+    pub direction: SortDirection,
+}
+
+impl ColumnSort {
+    /// Sort ascending by `column`.
+    pub fn ascending(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Ascending,
+        }
+    }
+
+    /// Sort descending by `column`.
+    pub fn descending(column: usize) -> Self {
+        Self {
+            column,
+            direction: SortDirection::Descending,
+        }
+    }
+}
+
+/// How many rows can be selected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelectionMode {
+    /// Rows are not selectable.
+    None,
+    /// At most one selected row (default).
+    #[default]
+    Single,
+    /// Any number of selected rows; click with the platform modifier or press
+    /// Space/Enter to toggle a row.
+    Multiple,
+}
+
+/// The sort after clicking the header of `column`: ascending, then descending,
+/// then unsorted. Clicking a different column starts it ascending.
+pub fn next_sort(current: Option<ColumnSort>, column: usize) -> Option<ColumnSort> {
+    match current {
+        Some(sort) if sort.column == column => match sort.direction {
+            SortDirection::Ascending => Some(ColumnSort::descending(column)),
+            SortDirection::Descending => None,
+        },
+        _ => Some(ColumnSort::ascending(column)),
+    }
+}
+
+/// Compare two cells: numerically when both parse as numbers, otherwise
+/// case-insensitively with a case-sensitive tie-break.
+pub fn compare_cells(a: &str, b: &str) -> Ordering {
+    if let (Ok(x), Ok(y)) = (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        return x.total_cmp(&y);
+    }
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .cmp(b.chars().flat_map(char::to_lowercase))
+        .then_with(|| a.cmp(b))
+}
+
+/// Indices into `rows` in display order. Sorting is stable, so rows with
+/// equal cells keep their original order in both directions.
+pub fn sorted_row_order(rows: &[TableRow], sort: Option<ColumnSort>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    if let Some(sort) = sort {
+        order.sort_by(|&a, &b| {
+            let ordering = compare_cells(rows[a].cell(sort.column), rows[b].cell(sort.column));
+            match sort.direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+    order
+}
+
+/// The selection after activating `row`. With `toggle`, a selected row is
+/// deselected and, in multiple mode, an unselected row is added; without it,
+/// `row` replaces the selection.
+pub fn select_row(
+    selected: &BTreeSet<usize>,
+    row: usize,
+    mode: SelectionMode,
+    toggle: bool,
+) -> BTreeSet<usize> {
+    match mode {
+        SelectionMode::None => selected.clone(),
+        _ if toggle && selected.contains(&row) => {
+            let mut next = selected.clone();
+            next.remove(&row);
+            next
+        }
+        SelectionMode::Multiple if toggle => {
+            let mut next = selected.clone();
+            next.insert(row);
+            next
+        }
+        _ => BTreeSet::from([row]),
+    }
+}
+
+/// Display order computed for one rows/sort pair, reused across frames.
+struct RowOrder {
+    rows: Rc<Vec<TableRow>>,
+    sort: Option<ColumnSort>,
+    order: Rc<Vec<usize>>,
+}
+
+/// A table of rows under column headers, with sortable columns, row
+/// selection, and arrow-key row navigation.
+///
+/// Rows render through `uniform_list`, so only the rows in view are laid out
+/// and thousands of rows cost the same per frame as a screenful. Pass rows as
+/// an `Rc` that outlives the frame to also keep sorting out of the frame: the
+/// sorted order is cached until the rows or the sort change.
+///
+/// Selection is by index into `rows`, so it survives re-sorting.
+///
+/// # Usage
+/// ```ignore
+/// DataTable::new(
+///     "components",
+///     vec![Column::new("Name").sortable(), Column::new("Tokens").sortable()],
+///     rows,
+/// )
+/// .selection_mode(SelectionMode::Multiple)
+/// .on_selection_change(|rows, _window, _cx| println!("{rows:?}"))
+/// ```
+#[derive(IntoElement)]
+pub struct DataTable {
+    id: ElementId,
+    columns: Rc<Vec<Column>>,
+    rows: Rc<Vec<TableRow>>,
+    sort: ControlledState<Option<ColumnSort>>,
+    selection: ControlledState<BTreeSet<usize>>,
+    selection_mode: SelectionMode,
+    row_height: Pixels,
+    height: Pixels,
+    empty_text: SharedString,
+    tooltip: Option<SharedString>,
+    tooltip_placement: TooltipPlacement,
+    identifiers: SharedIdentifiers,
+}
+
+impl DataTable {
+    /// Create a table with the given columns and rows.
+    pub fn new(
+        id: impl Into<ElementId>,
+        columns: Vec<Column>,
+        rows: impl Into<Rc<Vec<TableRow>>>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            columns: Rc::new(columns),
+            rows: rows.into(),
+            sort: ControlledState::new(),
+            selection: ControlledState::new(),
+            selection_mode: SelectionMode::Single,
+            row_height: px(28.0),
+            height: px(320.0),
+            empty_text: "No rows".into(),
+            tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Control the sort from the parent (`None` renders rows in the given order).
+    pub fn sort(mut self, sort: Option<ColumnSort>) -> Self {
+        self.sort = self.sort.value(sort);
+        self
+    }
+
+    /// Set the initial sort for uncontrolled use.
+    pub fn default_sort(mut self, sort: ColumnSort) -> Self {
+        self.sort = self.sort.default_value(Some(sort));
+        self
+    }
+
+    /// Set the sort change callback, fired when a sortable header is clicked.
+    pub fn on_sort(
+        mut self,
+        handler: impl Fn(Option<ColumnSort>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.sort = self
+            .sort
+            .on_change(move |sort, window, cx| handler(*sort, window, cx));
+        self
+    }
+
+    /// Control the selected row indices from the parent.
+    pub fn selected_rows(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = self.selection.value(rows.into_iter().collect());
+        self
+    }
+
+    /// Set the initially selected row indices for uncontrolled use.
+    pub fn default_selected_rows(mut self, rows: impl IntoIterator<Item = usize>) -> Self {
+        self.selection = self.selection.default_value(rows.into_iter().collect());
+        self
+    }
+
+    /// Set the selection change callback.
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(&BTreeSet<usize>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.selection = self.selection.on_change(handler);
+        self
+    }
+
+    /// Set how many rows can be selected.
+    pub fn selection_mode(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = mode;
+        self
+    }
+
+    /// Set the height of every row.
+    pub fn row_height(mut self, height: Pixels) -> Self {
+        self.row_height = height;
+        self
+    }
+
+    /// Set the height of the scrolling body (excluding the header).
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Set the text shown when there are no rows.
+    pub fn empty_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for DataTable.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::data_table()
+    }
+}
+
+impl WithIdentifiers for DataTable {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl WithTooltip for DataTable {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
+    }
+}
+
+/// The display order for `rows` under `sort`, from `cache` when neither changed.
+fn cached_row_order(
+    cache: &Entity<Option<RowOrder>>,
+    rows: &Rc<Vec<TableRow>>,
+    sort: Option<ColumnSort>,
+    cx: &mut App,
+) -> Rc<Vec<usize>> {
+    if let Some(cached) = cache.read(cx).as_ref()
+        && Rc::ptr_eq(&cached.rows, rows)
+        && cached.sort == sort
+    {
+        return cached.order.clone();
+    }
+    let order = Rc::new(sorted_row_order(rows, sort));
+    cache.update(cx, |cache, _| {
+        *cache = Some(RowOrder {
+            rows: rows.clone(),
+            sort,
+            order: order.clone(),
+        });
+    });
+    order
+}
+
+/// Lay out a cell or header label in its column.
+fn column_cell(column: &Column, content: impl IntoElement) -> Div {
+    div()
+        .flex()
+        .flex_row()
+        .items_center()
+        .px_2()
+        .overflow_hidden()
+        .whitespace_nowrap()
+        .text_ellipsis()
+        .map(|el| match column.width {
+            Some(width) => el.w(width).flex_shrink_0(),
+            None => el.flex_1().min_w_0(),
+        })
+        .when(column.align == ColumnAlign::End, |el| el.justify_end())
+        .child(content)
+}
+
+impl RenderOnce for DataTable {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "DataTable",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let sort_state = self.sort.bind(key("sort"), window, cx);
+        let selection_state = self.selection.bind(key("selection"), window, cx);
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        let scroll_handle = window
+            .use_keyed_state(key("scroll"), cx, |_, _| UniformListScrollHandle::new())
+            .read(cx)
+            .clone();
+        let highlight = window.use_keyed_state(key("highlight"), cx, |_, _| None::<usize>);
+        let order_cache = window.use_keyed_state(key("order"), cx, |_, _| None::<RowOrder>);
+
+        let sort = *sort_state.current();
+        let rows = self.rows;
+        let order = cached_row_order(&order_cache, &rows, sort, cx);
+        // Display position of the highlighted row, if it is still in the table.
+        let highlighted_position = highlight
+            .read(cx)
+            .and_then(|row| order.iter().position(|&r| r == row));
+        let selected = Rc::new(selection_state.current().clone());
+
+        let theme = cx.theme();
+
+        let header_bg = theme.element.background;
+        let header_text = theme.text.muted;
+        let border_color = theme.border.default;
+        let divider_color = theme.border.variant;
+        let focus_border = theme.border.focused;
+        let text_color = theme.text.default;
+        let disabled_color = theme.text.disabled;
+        let sort_indicator = theme.text.accent;
+        let row_hover = theme.ghost_element.hover;
+        let row_selected = theme.ghost_element.selected;
+
+        let is_focused = focus_handle.is_focused(window);
+        let mode = self.selection_mode;
+        let row_height = self.row_height;
+        let columns = self.columns;
+
+        // Header
+        let mut header = div()
+            .flex()
+            .flex_row()
+            .w_full()
+            .h(row_height)
+            .bg(header_bg)
+            .border_b_1()
+            .border_color(border_color)
+            .text_xs()
+            .font_weight(FontWeight::MEDIUM)
+            .text_color(header_text);
+
+        for (idx, column) in columns.iter().enumerate() {
+            let indicator = match sort {
+                Some(sort) if sort.column == idx => match sort.direction {
+                    SortDirection::Ascending => Some("↑"),
+                    SortDirection::Descending => Some("↓"),
+                },
+                _ => None,
+            };
+            let mut label = div()
+                .id(key(&format!("header-{idx}")))
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_1()
+                .h_full()
+                .child(column.label.clone())
+                .when_some(indicator, |el, indicator| {
+                    el.child(div().text_color(sort_indicator).child(indicator))
+                });
+            if column.sortable {
+                let sort_state = sort_state.clone();
+                label = label.cursor_pointer().on_mouse_down(
+                    MouseButton::Left,
+                    move |_event, window, cx| {
+                        sort_state.set(next_sort(sort, idx), window, cx);
+                    },
+                );
+            }
+            header = header.child(
+                column_cell(column, label)
+                    .h_full()
+                    .when(column.sortable, |el| el.hover(|s| s.bg(row_hover))),
+            );
+        }
+
+        // Body
+        let body = if rows.is_empty() {
+            div()
+                .h(self.height)
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    EmptyState::new(key("empty"))
+                        .size(EmptyStateSize::Small)
+                        .title(self.empty_text),
+                )
+                .into_any_element()
+        } else {
+            let row_count = order.len();
+            let rows = rows.clone();
+            let order = order.clone();
+            let columns = columns.clone();
+            let selection_state = selection_state.clone();
+            let highlight = highlight.clone();
+            let list_id = key("rows");
+            uniform_list(list_id, row_count, move |range, _window, _cx| {
+                range
+                    .map(|position| {
+                        let row_ix = order[position];
+                        let row = &rows[row_ix];
+                        let is_selected = selected.contains(&row_ix);
+                        let is_highlighted = is_focused && highlighted_position == Some(position);
+
+                        let mut row_el = div()
+                            .id(("data-table-row", row_ix))
+                            .flex()
+                            .flex_row()
+                            .w_full()
+                            .h(row_height)
+                            .text_sm()
+                            .text_color(if row.disabled {
+                                disabled_color
+                            } else {
+                                text_color
+                            })
+                            .when(position > 0, |el| {
+                                el.border_t_1().border_color(divider_color)
+                            })
+                            .when(is_selected, |el| el.bg(row_selected))
+                            .when(is_highlighted && !is_selected, |el| el.bg(row_hover))
+                            .when(row.disabled, |el| el.opacity(0.5).cursor_default());
+
+                        if !row.disabled {
+                            let selection_state = selection_state.clone();
+                            let highlight = highlight.clone();
+                            row_el = row_el
+                                .cursor_pointer()
+                                .hover(|s| s.bg(row_hover))
+                                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                                    highlight.update(cx, |highlight, cx| {
+                                        *highlight = Some(row_ix);
+                                        cx.notify();
+                                    });
+                                    let next = select_row(
+                                        selection_state.current(),
+                                        row_ix,
+                                        mode,
+                                        event.modifiers.secondary(),
+                                    );
+                                    selection_state.set(next, window, cx);
+                                });
+                        }
+
+                        for (idx, column) in columns.iter().enumerate() {
+                            let cell = row.cells.get(idx).cloned().unwrap_or_default();
+                            row_el = row_el.child(column_cell(column, cell));
+                        }
+                        row_el
+                    })
+                    .collect()
+            })
+            .h(self.height)
+            .track_scroll(scroll_handle.clone())
+            .into_any_element()
+        };
+
+        let table = div()
+            .id(self.id.clone())
+            .track_focus(&focus_handle)
+            .flex()
+            .flex_col()
+            .w_full()
+            .overflow_hidden()
+            .border_1()
+            .border_color(if is_focused {
+                focus_border
+            } else {
+                border_color
+            })
+            .rounded_md()
+            .on_mouse_down(MouseButton::Left, {
+                let focus_handle = focus_handle.clone();
+                move |_event, window, cx| window.focus(&focus_handle, cx)
+            })
+            .on_key_down({
+                let rows = rows.clone();
+                let order = order.clone();
+                move |event, window, cx| {
+                    let count = order.len();
+                    if count == 0 {
+                        return;
+                    }
+                    if let Some(direction) = classify_nav_key(event, Orientation::Vertical) {
+                        // With nothing highlighted, Down lands on the first row and Up on the last.
+                        let current = highlighted_position.unwrap_or(match direction {
+                            NavDirection::Previous => 0,
+                            _ => count - 1,
+                        });
+                        let next = navigate_index(current, direction, count, |position| {
+                            rows[order[position]].disabled
+                        });
+                        if !rows[order[next]].disabled {
+                            highlight.update(cx, |highlight, cx| {
+                                *highlight = Some(order[next]);
+                                cx.notify();
+                            });
+                            scroll_handle.scroll_to_item(next, ScrollStrategy::Top);
+                        }
+                        cx.stop_propagation();
+                    } else if is_activation_key(event)
+                        && let Some(position) = highlighted_position
+                    {
+                        let next = select_row(
+                            selection_state.current(),
+                            order[position],
+                            mode,
+                            mode == SelectionMode::Multiple,
+                        );
+                        selection_state.set(next, window, cx);
+                        cx.stop_propagation();
+                    }
+                }
+            })
+            .child(header)
+            .child(body);
+
+        attach_tooltip(
+            table,
+            &self.id,
+            self.tooltip,
+            self.tooltip_placement,
+            window,
+            cx,
+        )
+        .inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,201 @@
+//! DescriptionList component: term/definition pairs for metadata display.
+//!
+//! Rewrite disposition: written for the workbench; replaces ad hoc label/value
+//! layouts such as the studio metadata panel.
+//!
+//! Supports a horizontal layout (term column beside the definition) and a
+//! stacked layout (term above the definition). Individual definitions can be
+//! made copyable, which adds a copy affordance that writes the value to the
+//! system clipboard.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Layout of term/definition pairs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DescriptionLayout {
+    /// Term in a fixed-width column, definition beside it (default).
+    #[default]
+    Horizontal,
+    /// Term above its definition.
+    Stacked,
+}
+
+/// A single term/definition pair.
+#[derive(Debug, Clone)]
+pub struct DescriptionItem {
+    /// The term (label).
+    pub term: SharedString,
+    /// The definition (value).
+    pub definition: SharedString,
+    /// Whether the definition can be copied to the clipboard.
+    pub copyable: bool,
+}
+
+impl DescriptionItem {
+    /// Create a new pair.
+    pub fn new(term: impl Into<SharedString>, definition: impl Into<SharedString>) -> Self {
+        Self {
+            term: term.into(),
+            definition: definition.into(),
+            copyable: false,
+        }
+    }
+
+    /// Make the definition copyable.
+    pub fn copyable(mut self) -> Self {
+        self.copyable = true;
+        self
+    }
+}
+
+/// A list of term/definition pairs with horizontal or stacked layout.
+///
+/// # Usage
+/// ```ignore
+/// DescriptionList::new("button-meta")
+///     .item(DescriptionItem::new("Version", "0.1.0"))
+///     .item(DescriptionItem::new("File", "crates/components/src/button.rs").copyable())
+///     .layout(DescriptionLayout::Horizontal)
+/// ```
+#[derive(IntoElement)]
+pub struct DescriptionList {
+    id: ElementId,
+    items: Vec<DescriptionItem>,
+    layout: DescriptionLayout,
+    term_width: Pixels,
+    identifiers: SharedIdentifiers,
+}
+
+impl DescriptionList {
+    /// Create an empty description list.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            layout: DescriptionLayout::Horizontal,
+            term_width: px(120.0),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Append a pair.
+    pub fn item(mut self, item: DescriptionItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Append several pairs.
+    pub fn items(mut self, items: impl IntoIterator<Item = DescriptionItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Set the layout.
+    pub fn layout(mut self, layout: DescriptionLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the term column width used by the horizontal layout.
+    pub fn term_width(mut self, width: Pixels) -> Self {
+        self.term_width = width;
+        self
+    }
+
+    /// Returns the component contract for DescriptionList.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::description_list()
+    }
+}
+
+impl WithIdentifiers for DescriptionList {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for DescriptionList {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("DescriptionList", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+
+        let term_color = theme.text.muted;
+        let definition_color = theme.text.default;
+        let divider_color = theme.border.variant;
+        let icon_color = theme.icon.muted;
+        let copy_hover = theme.ghost_element.hover;
+
+        let mut list = div().id(self.id.clone()).flex().flex_col().w_full();
+
+        for (idx, item) in self.items.into_iter().enumerate() {
+            let term = div()
+                .text_xs()
+                .text_color(term_color)
+                .when(self.layout == DescriptionLayout::Horizontal, |el| {
+                    el.w(self.term_width).flex_shrink_0()
+                })
+                .child(item.term);
+
+            let mut definition = div()
+                .flex()
+                .flex_row()
+                .items_start()
+                .gap_1()
+                .flex_1()
+                .min_w_0()
+                .child(
+                    div()
+                        .flex_1()
+                        .text_xs()
+                        .text_color(definition_color)
+                        .child(item.definition.clone()),
+                );
+
+            if item.copyable {
+                let value = item.definition;
+                definition = definition.child(
+                    div()
+                        .id(SharedString::from(format!("{}-copy-{idx}", self.id)))
+                        .flex_shrink_0()
+                        .rounded_sm()
+                        .px_1()
+                        .text_xs()
+                        .text_color(icon_color)
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(copy_hover))
+                        .on_mouse_down(MouseButton::Left, move |_event, _window, cx| {
+                            cx.write_to_clipboard(ClipboardItem::new_string(value.to_string()));
+                        })
+                        .child("⧉"),
+                );
+            }
+
+            let row = div()
+                .flex()
+                .py_1()
+                .when(idx > 0, |el| el.border_t_1().border_color(divider_color))
+                .map(|el| match self.layout {
+                    DescriptionLayout::Horizontal => el.flex_row().items_start().gap_3(),
+                    DescriptionLayout::Stacked => el.flex_col().gap_0p5(),
+                })
+                .child(term)
+                .child(definition);
+
+            list = list.child(row);
+        }
+
+        list.inspectable(&self.id, Self::contract)
+    }
+}
@@ -7,11 +7,16 @@
 //! - gpui-component `crates/ui/src/dialog.rs` (MIT, Zed Industries)
 //! - Zed `crates/ui/src/components/modal.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - Modifications: Simplified to POC scope, rewired to internal token system,
-//!   uses internal primitives for focus trap/return/keyboard/state.
+//!   uses internal primitives for focus trap/return/keyboard/state, the
+//!   shared OverlayStateMachine for enter/exit transitions, and the
+//!   LayerManager stack for z-order, scrim, and Escape routing.
 
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
-use primitives::{FocusReturn, FocusTrap, OpenState};
+use primitives::{
+    FocusReturn, FocusTrap, Layer, LayerKind, LayerManager, MotionPreference, OverlayStateMachine,
+};
 use smallvec::SmallVec;
 use theme::ActiveTheme;
 
@@ -41,7 +46,7 @@ pub struct Dialog {
     #[allow(dead_code)]
     focus_trap: FocusTrap,
     focus_return: FocusReturn,
-    open_state: OpenState,
+    overlay: OverlayStateMachine,
     title: Option<SharedString>,
     description: Option<SharedString>,
     actions: SmallVec<[AnyElement; 2]>,
@@ -53,6 +58,7 @@ pub struct Dialog {
     overlay_closable: bool,
     show_close_button: bool,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
 }
 
 impl Dialog {
@@ -67,7 +73,7 @@ impl Dialog {
             focus_handle,
             focus_trap,
             focus_return,
-            open_state: OpenState::Open,
+            overlay: OverlayStateMachine::opened(),
             title: None,
             description: None,
             actions: SmallVec::new(),
@@ -79,9 +85,19 @@ impl Dialog {
             overlay_closable: true,
             show_close_button: true,
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
         }
     }
 
+    /// Drive the dialog from an owner-held overlay state machine.
+    ///
+    /// Defaults to settled `Open`. During `Opening`/`Closing` the dialog plays its
+    /// enter/exit fade; while `Closing` dismiss handlers are inert.
+    pub fn overlay_state(mut self, overlay: OverlayStateMachine) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
     /// Set the dialog title.
     pub fn title(mut self, title: impl Into<SharedString>) -> Self {
         self.title = Some(title.into());
@@ -153,69 +169,34 @@ impl Dialog {
 
     /// Returns the component contract for Dialog.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Dialog", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop(
-                "id",
-                "ElementId",
-                "Unique identifier for the dialog instance",
-            )
-            .optional_prop("title", "Option<SharedString>", "None", "Dialog title text")
-            .optional_prop(
-                "description",
-                "Option<SharedString>",
-                "None",
-                "Dialog description text",
-            )
-            .optional_prop("width", "Pixels", "480.0", "Dialog width in pixels")
-            .optional_prop(
-                "overlay_closable",
-                "bool",
-                "true",
-                "Whether clicking backdrop closes the dialog",
-            )
-            .optional_prop(
-                "show_close_button",
-                "bool",
-                "true",
-                "Whether to show the X close button",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Open)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .token_dep("surface.elevated_surface", "Dialog panel background")
-            .token_dep("border.default", "Dialog panel border")
-            .token_dep("text.default", "Dialog title and body text")
-            .token_dep("text.muted", "Dialog description text")
-            .token_dep("surface.background", "Overlay backdrop (with alpha)")
-            .token_dep("ghost_element.hover", "Close button hover state")
-            .focus_behavior(
-                "Focus trap: Tab/Shift-Tab cycle within dialog. \
-                 Focus captured on open, returned to trigger on close.",
-            )
-            .keyboard_model(
-                "Escape dismisses the dialog. Enter is not bound by default \
-                 (action buttons handle their own activation).",
-            )
-            .pointer_behavior(
-                "Click on backdrop dismisses (if overlay_closable). \
-                 Click on close button dismisses. \
-                 Mouse events on dialog panel stop propagation to backdrop.",
-            )
-            .state_model(
-                "Controlled open/close via OpenState. \
-                 Dialog is created in Open state; closing returns focus.",
-            )
-            .required_file("crates/components/src/dialog.rs")
-            .build()
+        crate::catalog::dialog()
+    }
+}
+
+impl WithIdentifiers for Dialog {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Dialog {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Dialog",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
         let theme = cx.theme();
 
         // Overlay backdrop color: surface background with reduced alpha
@@ -237,11 +218,29 @@ impl RenderOnce for Dialog {
         let overlay_closable = self.overlay_closable;
         let focus_return = self.focus_return;
         let _on_close = self.on_close;
+        let phase = self.overlay.phase();
+        let animation_id = self.id.clone();
+        let layer_id = SharedString::from(self.id.to_string());
 
-        if self.open_state.is_closed() {
+        if !self.overlay.is_visible() {
+            LayerManager::unregister(&layer_id, cx);
             return div().into_any_element();
         }
 
+        // Join the overlay stack; a modal opened above this one blocks it.
+        LayerManager::register(
+            Layer::new(layer_id.clone(), LayerKind::Modal).focus_return(focus_return),
+            cx,
+        );
+        let layer = LayerManager::state(&layer_id, cx);
+        let accepts_input = self.overlay.accepts_input() && !layer.blocked;
+        let dismiss = {
+            let layer_id = layer_id.clone();
+            move |window: &mut Window, cx: &mut App| {
+                LayerManager::dismiss(&layer_id, window, cx);
+            }
+        };
+
         // Build the dialog panel
         let mut panel = div()
             .id(self.id.clone())
@@ -260,14 +259,20 @@ impl RenderOnce for Dialog {
             .gap_3()
             // Stop click propagation so backdrop handler doesn't fire
             .on_mouse_down(MouseButton::Left, |_event, _window, _cx| {})
-            // Escape key dismissal
-            .on_key_down({
-                let focus_return = focus_return.clone();
-                move |event, window, cx| {
-                    if primitives::is_escape_key(event) {
-                        focus_return.restore(window, cx);
+            // Escape key dismissal, routed to the topmost layer
+            .when(accepts_input, |this| {
+                this.on_key_down({
+                    let layer_id = layer_id.clone();
+                    let dismiss = dismiss.clone();
+                    move |event, window, cx| {
+                        if primitives::is_escape_key(event)
+                            && LayerManager::state(&layer_id, cx).escape_target
+                        {
+                            dismiss(window, cx);
+                            cx.stop_propagation();
+                        }
                     }
-                }
+                })
             });
 
         // Title
@@ -294,11 +299,11 @@ impl RenderOnce for Dialog {
                                 .p_1()
                                 .text_color(desc_color)
                                 .hover(|s| s.bg(close_hover))
-                                .on_mouse_down(MouseButton::Left, {
-                                    let focus_return = focus_return.clone();
-                                    move |_event, window, cx| {
-                                        focus_return.restore(window, cx);
-                                    }
+                                .when(accepts_input, |this| {
+                                    this.on_mouse_down(MouseButton::Left, {
+                                        let dismiss = dismiss.clone();
+                                        move |_event, window, cx| dismiss(window, cx)
+                                    })
                                 })
                                 .child("X"),
                         )
@@ -334,19 +339,32 @@ impl RenderOnce for Dialog {
             .justify_center()
             .items_start()
             .pt(px(80.0))
-            .bg(backdrop_color)
-            .when(overlay_closable, |this| {
-                this.on_mouse_down(MouseButton::Left, {
-                    let focus_return = focus_return.clone();
-                    move |_event, window, cx| {
-                        focus_return.restore(window, cx);
-                    }
+            // Stacked modals share the topmost one's scrim
+            .when(layer.shows_scrim, |this| this.bg(backdrop_color))
+            .when(overlay_closable && accepts_input, |this| {
+                this.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    dismiss(window, cx)
                 })
             })
-            .child(panel);
-
-        // Use deferred rendering so dialog paints on top
-        deferred(overlay).with_priority(1).into_any_element()
+            .child(panel.inspectable(&animation_id, Self::contract));
+
+        // Enter/exit fade while transitioning, unless motion is reduced
+        let overlay = if MotionPreference::global(cx).animates(phase.transition_duration()) {
+            overlay
+                .with_animation(
+                    ElementId::Name(format!("{}-{:?}", animation_id, phase).into()),
+                    Animation::new(phase.transition_duration()).with_easing(ease_in_out),
+                    move |this, delta| this.opacity(phase.opacity(delta)),
+                )
+                .into_any_element()
+        } else {
+            overlay.into_any_element()
+        };
+
+        // Use deferred rendering so dialog paints at its place in the stack
+        deferred(overlay)
+            .with_priority(layer.priority)
+            .into_any_element()
     }
 }
 
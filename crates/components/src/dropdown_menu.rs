@@ -7,13 +7,35 @@
 //! - Zed `crates/ui/src/components/dropdown_menu.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - gpui-component menu patterns (MIT, Zed Industries)
 //! - Modifications: Simplified to internal token system, uses internal primitives
-//!   for keyboard navigation and popover positioning.
+//!   for keyboard navigation and popover positioning, and joins the
+//!   LayerManager stack while open.
 
+use std::rc::Rc;
+
+use crate::kbd::Kbd;
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
-use primitives::{Orientation, classify_nav_key, is_activation_key, navigate_index};
+use primitives::keyboard::keys;
+use primitives::{
+    Layer, LayerKind, LayerManager, Orientation, classify_nav_key, is_activation_key,
+    navigate_index,
+};
 use theme::ActiveTheme;
 
+/// What kind of entry a [`MenuItem`] is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MenuItemKind {
+    /// Plain action item.
+    Action,
+    /// Independently toggleable item with a check indicator.
+    Checkbox { checked: bool },
+    /// Mutually exclusive item: at most one item per `group` is checked.
+    Radio { group: SharedString, checked: bool },
+    /// Visual divider, optionally introducing a labeled section.
+    Separator { label: Option<SharedString> },
+}
+
 /// A single item in a dropdown menu.
 #[derive(Debug, Clone)]
 pub struct MenuItem {
@@ -21,8 +43,12 @@ pub struct MenuItem {
     pub label: SharedString,
     /// Whether this item is disabled.
     pub disabled: bool,
-    /// Whether this item is a separator (visual divider).
-    pub separator: bool,
+    /// Item kind (action, checkbox, radio, or separator).
+    pub kind: MenuItemKind,
+    /// Optional leading icon (rendered as text for POC, like Button icons).
+    pub icon: Option<SharedString>,
+    /// Optional shortcut hint in GPUI binding syntax, rendered as [`Kbd`] caps.
+    pub shortcut: Option<SharedString>,
 }
 
 impl MenuItem {
@@ -31,17 +57,15 @@ impl MenuItem {
         Self {
             label: label.into(),
             disabled: false,
-            separator: false,
+            kind: MenuItemKind::Action,
+            icon: None,
+            shortcut: None,
         }
     }
 
     /// Create a disabled menu item.
     pub fn disabled(label: impl Into<SharedString>) -> Self {
-        Self {
-            label: label.into(),
-            disabled: true,
-            separator: false,
-        }
+        Self::new(label).set_disabled(true)
     }
 
     /// Create a separator item.
@@ -49,13 +73,138 @@ impl MenuItem {
         Self {
             label: SharedString::default(),
             disabled: true,
-            separator: true,
+            kind: MenuItemKind::Separator { label: None },
+            icon: None,
+            shortcut: None,
+        }
+    }
+
+    /// Create a separator that introduces a labeled section.
+    pub fn labeled_separator(label: impl Into<SharedString>) -> Self {
+        let label = label.into();
+        Self {
+            label: label.clone(),
+            disabled: true,
+            kind: MenuItemKind::Separator { label: Some(label) },
+            icon: None,
+            shortcut: None,
+        }
+    }
+
+    /// Create a checkbox item.
+    pub fn checkbox(label: impl Into<SharedString>, checked: bool) -> Self {
+        Self {
+            kind: MenuItemKind::Checkbox { checked },
+            ..Self::new(label)
+        }
+    }
+
+    /// Create a radio item belonging to `group`.
+    pub fn radio(
+        label: impl Into<SharedString>,
+        group: impl Into<SharedString>,
+        checked: bool,
+    ) -> Self {
+        Self {
+            kind: MenuItemKind::Radio {
+                group: group.into(),
+                checked,
+            },
+            ..Self::new(label)
+        }
+    }
+
+    /// Set the leading icon.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the trailing shortcut hint (e.g. `"cmd-s"`).
+    pub fn shortcut(mut self, keystroke: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(keystroke.into());
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn set_disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether this item is a separator (visual divider).
+    pub fn is_separator(&self) -> bool {
+        matches!(self.kind, MenuItemKind::Separator { .. })
+    }
+
+    /// Whether this item carries checked state (checkbox or radio).
+    pub fn is_checkable(&self) -> bool {
+        matches!(
+            self.kind,
+            MenuItemKind::Checkbox { .. } | MenuItemKind::Radio { .. }
+        )
+    }
+
+    /// Checked state for checkbox and radio items; `false` otherwise.
+    pub fn checked(&self) -> bool {
+        match self.kind {
+            MenuItemKind::Checkbox { checked } | MenuItemKind::Radio { checked, .. } => checked,
+            _ => false,
         }
     }
+
+    /// Whether keyboard navigation and clicks skip this item.
+    fn is_inert(&self) -> bool {
+        self.disabled || self.is_separator()
+    }
+}
+
+/// Apply activation of the item at `index` to `items`.
+///
+/// Checkbox items flip their checked state. Radio items become checked and
+/// every other radio in the same group is unchecked. Action items, separators,
+/// disabled items, and out-of-range indices are left untouched.
+///
+/// Returns true if any checked state changed.
+pub fn toggle_item(items: &mut [MenuItem], index: usize) -> bool {
+    let Some(item) = items.get_mut(index) else {
+        return false;
+    };
+    if item.disabled {
+        return false;
+    }
+    match &mut item.kind {
+        MenuItemKind::Checkbox { checked } => {
+            *checked = !*checked;
+            true
+        }
+        MenuItemKind::Radio { group, checked } => {
+            let group = group.clone();
+            let mut changed = !*checked;
+            *checked = true;
+            for (i, other) in items.iter_mut().enumerate() {
+                if i == index {
+                    continue;
+                }
+                if let MenuItemKind::Radio {
+                    group: other_group,
+                    checked,
+                } = &mut other.kind
+                    && *other_group == group
+                    && *checked
+                {
+                    *checked = false;
+                    changed = true;
+                }
+            }
+            changed
+        }
+        MenuItemKind::Action | MenuItemKind::Separator { .. } => false,
+    }
 }
 
 /// Callback when a menu item is selected.
-type OnSelectCallback = Box<dyn Fn(usize, &MenuItem, &mut Window, &mut App) + 'static>;
+type OnSelectCallback = Rc<dyn Fn(usize, &MenuItem, &mut Window, &mut App) + 'static>;
 
 /// A dropdown menu component with trigger button, popover menu list,
 /// keyboard navigation, and builder-pattern API.
@@ -66,7 +215,11 @@ type OnSelectCallback = Box<dyn Fn(usize, &MenuItem, &mut Window, &mut App) + 's
 ///     MenuItem::new("New File"),
 ///     MenuItem::new("Open..."),
 ///     MenuItem::separator(),
-///     MenuItem::new("Save"),
+///     MenuItem::new("Save").icon("💾").shortcut("cmd-s"),
+///     MenuItem::labeled_separator("View"),
+///     MenuItem::checkbox("Show Minimap", true),
+///     MenuItem::radio("Tabs", "indent", true),
+///     MenuItem::radio("Spaces", "indent", false),
 /// ])
 ///     .trigger_label("File")
 ///     .on_select(|idx, item, _window, _cx| {
@@ -83,6 +236,7 @@ pub struct DropdownMenu {
     disabled: bool,
     on_select: Option<OnSelectCallback>,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
     width: Pixels,
 }
 
@@ -98,6 +252,7 @@ impl DropdownMenu {
             disabled: false,
             on_select: None,
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
             width: px(180.0),
         }
     }
@@ -127,11 +282,15 @@ impl DropdownMenu {
     }
 
     /// Set the select handler.
+    ///
+    /// For checkbox and radio items the handler receives the item with its
+    /// checked state already toggled (see [`toggle_item`]); the caller owns the
+    /// items and applies the change on its next render.
     pub fn on_select(
         mut self,
         handler: impl Fn(usize, &MenuItem, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_select = Some(Box::new(handler));
+        self.on_select = Some(Rc::new(handler));
         self
     }
 
@@ -149,55 +308,34 @@ impl DropdownMenu {
 
     /// Returns the component contract for DropdownMenu.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("DropdownMenu", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the menu")
-            .required_prop("items", "Vec<MenuItem>", "Menu items to display")
-            .optional_prop(
-                "trigger_label",
-                "SharedString",
-                "\"Menu\"",
-                "Trigger button label",
-            )
-            .optional_prop("open", "bool", "false", "Whether the menu is open")
-            .optional_prop("disabled", "bool", "false", "Whether the menu is disabled")
-            .optional_prop("width", "Pixels", "180.0", "Menu dropdown width")
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Open)
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Disabled)
-            .token_dep("element.background", "Trigger button background")
-            .token_dep("element.hover", "Trigger and item hover background")
-            .token_dep("element.active", "Active item background")
-            .token_dep("element.disabled", "Disabled state background")
-            .token_dep("surface.elevated_surface", "Menu dropdown background")
-            .token_dep("text.default", "Item text color")
-            .token_dep("text.muted", "Trigger text color")
-            .token_dep("text.disabled", "Disabled item text color")
-            .token_dep("border.default", "Menu dropdown border")
-            .token_dep("border.variant", "Separator line color")
-            .focus_behavior(
-                "Tab/Shift-Tab navigates to/from trigger. Focus moves into menu when opened.",
-            )
-            .keyboard_model(
-                "Enter/Space opens menu. Arrow Up/Down navigates items, skipping disabled. \
-                 Enter/Space selects item. Escape closes menu.",
-            )
-            .pointer_behavior("Click trigger toggles menu. Hover highlights items. Click selects.")
-            .state_model(
-                "Controlled open/close. Highlighted index tracks keyboard focus within menu.",
-            )
-            .disabled_behavior("Disabled menu ignores all interaction.")
-            .required_file("crates/components/src/dropdown_menu.rs")
-            .build()
+        crate::catalog::dropdown_menu()
+    }
+}
+
+impl WithIdentifiers for DropdownMenu {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for DropdownMenu {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "DropdownMenu",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
         let theme = cx.theme();
 
         let trigger_bg = theme.element.background;
@@ -240,13 +378,21 @@ impl RenderOnce for DropdownMenu {
         let mut container = div().id(self.id.clone()).flex().flex_col();
         container = container.child(trigger);
 
-        // Dropdown panel (when open)
+        // Dropdown panel (when open), registered in the overlay stack
+        let layer_id = SharedString::from(format!("{}-menu", self.id));
+        if !self.open || disabled {
+            LayerManager::unregister(&layer_id, cx);
+        }
         if self.open && !disabled {
+            LayerManager::register(Layer::new(layer_id.clone(), LayerKind::Menu), cx);
+            let layer = LayerManager::state(&layer_id, cx);
+            let theme = cx.theme();
             let menu_bg = theme.surface.elevated_surface;
             let menu_border = theme.border.default;
             let item_text = theme.text.default;
             let separator_color = theme.border.variant;
             let highlight_bg = theme.element.hover;
+            let icon_color = theme.icon.default;
             let highlighted = self.highlighted_index;
 
             let mut menu = div()
@@ -261,39 +407,82 @@ impl RenderOnce for DropdownMenu {
                 .py_1()
                 .overflow_hidden();
 
+            let on_select = self.on_select.clone();
+
             // Keyboard navigation
             let items_for_nav = self.items.clone();
             let item_count = items_for_nav.len();
-            menu = menu.on_key_down(move |event, _window, cx| {
+            let on_select_key = on_select.clone();
+            menu = menu.on_key_down(move |event, window, cx| {
                 if primitives::is_escape_key(event) {
-                    cx.stop_propagation();
+                    // Only the topmost layer closes; others let Escape through.
+                    if LayerManager::state(&layer_id, cx).escape_target {
+                        LayerManager::dismiss(&layer_id, window, cx);
+                        cx.stop_propagation();
+                    }
                     return;
                 }
                 let nav = classify_nav_key(event, Orientation::Vertical);
                 if let Some(dir) = nav {
                     let _next = navigate_index(highlighted, dir, item_count, |i| {
-                        items_for_nav
-                            .get(i)
-                            .is_some_and(|item| item.disabled || item.separator)
+                        items_for_nav.get(i).is_some_and(MenuItem::is_inert)
                     });
                     cx.stop_propagation();
                 }
                 if is_activation_key(event) {
                     cx.stop_propagation();
+                    let Some(item) = items_for_nav.get(highlighted) else {
+                        return;
+                    };
+                    if item.is_inert() {
+                        return;
+                    }
+                    // Enter on a checkable item selects without toggling;
+                    // Space toggles it.
+                    let toggle = event.keystroke.key.as_str() == keys::SPACE;
+                    let item = activated_item(&items_for_nav, highlighted, toggle);
+                    if let Some(handler) = &on_select_key {
+                        handler(highlighted, &item, window, cx);
+                    }
                 }
             });
 
+            let has_checkable = self.items.iter().any(MenuItem::is_checkable);
+
             for (idx, item) in self.items.iter().enumerate() {
-                if item.separator {
-                    menu = menu.child(div().h(px(1.0)).mx_2().my_1().bg(separator_color));
+                if let MenuItemKind::Separator { label } = &item.kind {
+                    if idx > 0 {
+                        menu = menu.child(div().h(px(1.0)).mx_2().my_1().bg(separator_color));
+                    }
+                    if let Some(label) = label {
+                        menu = menu.child(
+                            div()
+                                .px_3()
+                                .py_0p5()
+                                .text_xs()
+                                .font_weight(FontWeight::MEDIUM)
+                                .text_color(theme.text.muted)
+                                .child(label.clone()),
+                        );
+                    }
                     continue;
                 }
 
                 let is_highlighted = idx == self.highlighted_index;
                 let item_disabled = item.disabled;
 
-                let item_el = div()
+                let indicator = match &item.kind {
+                    MenuItemKind::Checkbox { checked: true } => "✓",
+                    MenuItemKind::Radio { checked: true, .. } => "●",
+                    _ => "",
+                };
+
+                let mut item_el = div()
                     .id(SharedString::from(format!("{}-item-{idx}", self.id)))
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
                     .px_3()
                     .py_1()
                     .text_sm()
@@ -311,14 +500,50 @@ impl RenderOnce for DropdownMenu {
                     .when(!item_disabled, move |el| {
                         el.hover(move |s| s.bg(highlight_bg))
                     })
-                    .child(item.label.clone());
+                    .when(has_checkable, |el| {
+                        el.child(
+                            div()
+                                .w_3()
+                                .flex_none()
+                                .text_xs()
+                                .text_color(icon_color)
+                                .child(indicator),
+                        )
+                    })
+                    .when_some(item.icon.clone(), |el, icon| {
+                        el.child(div().flex_none().text_color(icon_color).child(icon))
+                    })
+                    .child(div().flex_1().child(item.label.clone()))
+                    .when_some(item.shortcut.clone(), |el, shortcut| {
+                        el.child(Kbd::new(shortcut).disabled(item_disabled))
+                    });
+
+                if !item_disabled && let Some(handler) = on_select.clone() {
+                    let items = self.items.clone();
+                    item_el =
+                        item_el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                            let item = activated_item(&items, idx, true);
+                            handler(idx, &item, window, cx);
+                        });
+                }
 
                 menu = menu.child(item_el);
             }
 
-            container = container.child(deferred(menu).with_priority(1));
+            container = container.child(deferred(menu).with_priority(layer.priority));
         }
 
-        container
+        container.inspectable(&self.id, Self::contract)
+    }
+}
+
+/// The item at `index` as reported to `on_select`, toggled if requested.
+fn activated_item(items: &[MenuItem], index: usize, toggle: bool) -> MenuItem {
+    if toggle {
+        let mut items = items.to_vec();
+        toggle_item(&mut items, index);
+        items.swap_remove(index)
+    } else {
+        items[index].clone()
     }
 }
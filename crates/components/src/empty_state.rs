@@ -0,0 +1,215 @@
+//! EmptyState component: placeholder for surfaces with no data to show.
+//!
+//! Rewrite disposition: written for the workbench; neither Zed nor gpui-component
+//! ships a standalone empty-state component.
+//!
+//! Used for zero-data surfaces such as empty lists, tables, trees, and the
+//! body of a Select dropdown with no items. Composes an optional icon, a title,
+//! a description, and a primary action rendered with [`Button`].
+
+use crate::button::{Button, ButtonSize, ButtonVariant};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Size variant controlling spacing and text scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyStateSize {
+    /// Compact layout for inline bodies such as dropdowns. Hides the icon.
+    Small,
+    /// Default layout for panels and lists.
+    #[default]
+    Medium,
+    /// Spacious layout for full-page surfaces.
+    Large,
+}
+
+/// Callback for the primary action button.
+type OnActionCallback = Box<dyn Fn(&ClickEvent, &mut Window, &mut App) + 'static>;
+
+/// A centered placeholder shown when a surface has no data.
+///
+/// # Usage
+/// ```ignore
+/// EmptyState::new("no-results")
+///     .icon("⌕")
+///     .title("No results")
+///     .description("Try a different search term.")
+///     .action("Clear search", |_event, _window, _cx| {})
+/// ```
+#[derive(IntoElement)]
+pub struct EmptyState {
+    id: ElementId,
+    icon: Option<SharedString>,
+    title: SharedString,
+    description: Option<SharedString>,
+    action_label: Option<SharedString>,
+    on_action: Option<OnActionCallback>,
+    size: EmptyStateSize,
+    identifiers: SharedIdentifiers,
+}
+
+impl EmptyState {
+    /// Create a new empty state.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            icon: None,
+            title: SharedString::default(),
+            description: None,
+            action_label: None,
+            on_action: None,
+            size: EmptyStateSize::Medium,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set an icon (rendered as text for POC, like Button icons).
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the title.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Set the description shown below the title.
+    pub fn description(mut self, desc: impl Into<SharedString>) -> Self {
+        self.description = Some(desc.into());
+        self
+    }
+
+    /// Set the primary action button.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action_label = Some(label.into());
+        self.on_action = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the size variant.
+    pub fn size(mut self, size: EmptyStateSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Returns the component contract for EmptyState.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::empty_state()
+    }
+}
+
+impl WithIdentifiers for EmptyState {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "EmptyState",
+            &self.id,
+            self.description.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let theme = cx.theme();
+
+        let title_color = theme.text.default;
+        let desc_color = theme.text.muted;
+        let icon_color = theme.icon.muted;
+        let size = self.size;
+
+        let mut container = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .w_full()
+            .text_center();
+
+        container = match size {
+            EmptyStateSize::Small => container.gap_1().px_3().py_2(),
+            EmptyStateSize::Medium => container.gap_2().p_6(),
+            EmptyStateSize::Large => container.gap_3().p_12(),
+        };
+
+        // Icon
+        if let Some(icon) = self.icon
+            && size != EmptyStateSize::Small
+        {
+            container = container.child(
+                div()
+                    .text_color(icon_color)
+                    .when(size == EmptyStateSize::Medium, |el| el.text_2xl())
+                    .when(size == EmptyStateSize::Large, |el| el.text_3xl())
+                    .child(icon),
+            );
+        }
+
+        // Title
+        if !self.title.is_empty() {
+            container = container.child(
+                div()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(title_color)
+                    .map(|el| match size {
+                        EmptyStateSize::Small => el.text_sm(),
+                        EmptyStateSize::Medium => el.text_base(),
+                        EmptyStateSize::Large => el.text_lg(),
+                    })
+                    .child(self.title),
+            );
+        }
+
+        // Description
+        if let Some(desc) = self.description {
+            container = container.child(
+                div()
+                    .max_w(px(360.0))
+                    .text_color(desc_color)
+                    .map(|el| match size {
+                        EmptyStateSize::Small => el.text_xs(),
+                        EmptyStateSize::Medium | EmptyStateSize::Large => el.text_sm(),
+                    })
+                    .child(desc),
+            );
+        }
+
+        // Primary action
+        if let Some(label) = self.action_label {
+            let mut button = Button::new(SharedString::from(format!("{}-action", self.id)))
+                .label(label)
+                .variant(ButtonVariant::Primary)
+                .size(match size {
+                    EmptyStateSize::Small => ButtonSize::Small,
+                    EmptyStateSize::Medium => ButtonSize::Medium,
+                    EmptyStateSize::Large => ButtonSize::Large,
+                });
+            if let Some(handler) = self.on_action {
+                button = button.on_click(move |event, window, cx| handler(event, window, cx));
+            }
+            container = container.child(div().mt_2().child(button));
+        }
+
+        container.inspectable(&self.id, Self::contract)
+    }
+}
@@ -0,0 +1,628 @@
+//! FilePicker component: choose files through the platform file dialog or by
+//! dropping them, with extension filters and a removable selection list.
+//!
+//! Rewrite disposition: composed for the workbench on top of gpui's
+//! `App::prompt_for_paths` and external path drops.
+//!
+//! Provenance:
+//! - Trigger styling follows the internal Button and Input components.
+//! - Modifications: dialogs go through the [`FileDialog`] trait so stories and
+//!   tests can swap in [`MockFileDialog`]; extension filters are applied to
+//!   picked and dropped paths, since gpui's path prompt has no filter option.
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::{ControlledState, is_activation_key};
+use theme::ActiveTheme;
+
+/// A named set of file extensions, e.g. "Theme" for `.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFilter {
+    /// Name shown in hints and rejection messages.
+    pub name: SharedString,
+    /// Lowercase extensions without the leading dot.
+    pub extensions: Vec<SharedString>,
+}
+
+impl FileFilter {
+    /// Create a filter; extensions may be given with or without a leading dot.
+    pub fn new(
+        name: impl Into<SharedString>,
+        extensions: impl IntoIterator<Item = impl AsRef<str>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            extensions: extensions
+                .into_iter()
+                .map(|ext| SharedString::from(ext.as_ref().trim_start_matches('.').to_lowercase()))
+                .collect(),
+        }
+    }
+
+    /// Returns true if `path` has one of the filter's extensions, ignoring case.
+    pub fn matches(&self, path: &Path) -> bool {
+        let Some(ext) = path.extension().and_then(|ext| ext.to_str()) else {
+            return false;
+        };
+        self.extensions
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(ext))
+    }
+
+    /// The filter as shown to users, e.g. "Theme (.json)".
+    pub fn label(&self) -> String {
+        let extensions: Vec<String> = self
+            .extensions
+            .iter()
+            .map(|ext| format!(".{ext}"))
+            .collect();
+        format!("{} ({})", self.name, extensions.join(", "))
+    }
+}
+
+/// Returns true if `path` passes `filters`; an empty filter list accepts everything.
+pub fn accepts_path(filters: &[FileFilter], path: &Path) -> bool {
+    filters.is_empty() || filters.iter().any(|filter| filter.matches(path))
+}
+
+/// The outcome of adding picked or dropped paths to a selection.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PickResult {
+    /// The new selection.
+    pub selected: Vec<PathBuf>,
+    /// Paths that did not pass the filters, in the order given.
+    pub rejected: Vec<PathBuf>,
+}
+
+/// Add `picked` to `current`.
+///
+/// Paths failing `filters` are rejected. With `multiple` the accepted paths
+/// are appended, skipping ones already selected; otherwise the last accepted
+/// path replaces the selection, which is kept if nothing was accepted.
+pub fn merge_picked(
+    current: &[PathBuf],
+    picked: impl IntoIterator<Item = PathBuf>,
+    filters: &[FileFilter],
+    multiple: bool,
+) -> PickResult {
+    let (accepted, rejected): (Vec<PathBuf>, Vec<PathBuf>) = picked
+        .into_iter()
+        .partition(|path| accepts_path(filters, path));
+    let selected = if multiple {
+        let mut selected = current.to_vec();
+        for path in accepted {
+            if !selected.contains(&path) {
+                selected.push(path);
+            }
+        }
+        selected
+    } else {
+        match accepted.into_iter().last() {
+            Some(path) => vec![path],
+            None => current.to_vec(),
+        }
+    };
+    PickResult { selected, rejected }
+}
+
+/// Message listing rejected paths, e.g. "Skipped notes.txt: expected Theme (.json)".
+pub fn rejection_message(rejected: &[PathBuf], filters: &[FileFilter]) -> Option<String> {
+    let skipped = match rejected {
+        [] => return None,
+        [only] => display_name(only),
+        _ => format!("{} files", rejected.len()),
+    };
+    let expected: Vec<String> = filters.iter().map(FileFilter::label).collect();
+    Some(format!(
+        "Skipped {skipped}: expected {}",
+        expected.join(" or ")
+    ))
+}
+
+/// The dialog options for a picker; the platform prompt only ever selects files.
+pub fn prompt_options(multiple: bool, prompt: Option<SharedString>) -> PathPromptOptions {
+    PathPromptOptions {
+        files: true,
+        directories: false,
+        multiple,
+        prompt,
+    }
+}
+
+/// The file name of `path`, or the whole path when it has none.
+fn display_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Shows a file dialog. Swapped for [`MockFileDialog`] in stories and tests.
+pub trait FileDialog {
+    /// Ask for paths; resolves to `None` when the dialog is cancelled.
+    fn prompt(&self, options: PathPromptOptions, cx: &App) -> Task<Option<Vec<PathBuf>>>;
+}
+
+/// The platform's native file dialog.
+///
+/// A dialog that fails to open (possible on Linux) counts as cancelled.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PlatformFileDialog;
+
+impl FileDialog for PlatformFileDialog {
+    fn prompt(&self, options: PathPromptOptions, cx: &App) -> Task<Option<Vec<PathBuf>>> {
+        let receiver = cx.prompt_for_paths(options);
+        cx.spawn(async move |_| receiver.await.ok().and_then(Result::ok).flatten())
+    }
+}
+
+/// A file dialog that answers immediately with queued responses.
+///
+/// Each prompt takes the next response; the last one repeats once the queue
+/// is down to it. With no responses every prompt is cancelled.
+#[derive(Debug, Default)]
+pub struct MockFileDialog {
+    responses: RefCell<VecDeque<Option<Vec<PathBuf>>>>,
+    requests: RefCell<Vec<PathPromptOptions>>,
+}
+
+impl MockFileDialog {
+    /// Create a mock dialog with no responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a response choosing `paths`.
+    pub fn respond_with(self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.responses
+            .borrow_mut()
+            .push_back(Some(paths.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Queue a cancelled response.
+    pub fn cancel(self) -> Self {
+        self.responses.borrow_mut().push_back(None);
+        self
+    }
+
+    /// Record `options` and return the next response.
+    pub fn next_response(&self, options: PathPromptOptions) -> Option<Vec<PathBuf>> {
+        self.requests.borrow_mut().push(options);
+        let mut responses = self.responses.borrow_mut();
+        if responses.len() > 1 {
+            responses.pop_front().flatten()
+        } else {
+            responses.front().cloned().flatten()
+        }
+    }
+
+    /// Options of every prompt shown so far.
+    pub fn requests(&self) -> Vec<PathPromptOptions> {
+        self.requests.borrow().clone()
+    }
+}
+
+impl FileDialog for MockFileDialog {
+    fn prompt(&self, options: PathPromptOptions, _cx: &App) -> Task<Option<Vec<PathBuf>>> {
+        Task::ready(self.next_response(options))
+    }
+}
+
+/// How the picker is opened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FilePickerTrigger {
+    /// A compact button.
+    #[default]
+    Button,
+    /// A dashed drop area that also accepts files dragged from the desktop.
+    DropZone,
+}
+
+/// A file chooser that opens a [`FileDialog`] and lists the chosen files.
+///
+/// # Usage
+/// ```ignore
+/// FilePicker::new("theme-file")
+///     .filter(FileFilter::new("Theme", ["json"]))
+///     .on_change(|paths, _window, _cx| {
+///         println!("{} files", paths.len());
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct FilePicker {
+    id: ElementId,
+    value: ControlledState<Vec<PathBuf>>,
+    filters: Vec<FileFilter>,
+    multiple: bool,
+    trigger: FilePickerTrigger,
+    label: Option<SharedString>,
+    prompt: Option<SharedString>,
+    show_list: bool,
+    disabled: bool,
+    dialog: Rc<dyn FileDialog>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl FilePicker {
+    /// Create a single-file button picker using the platform dialog.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: ControlledState::new(),
+            filters: Vec::new(),
+            multiple: false,
+            trigger: FilePickerTrigger::default(),
+            label: None,
+            prompt: None,
+            show_list: true,
+            disabled: false,
+            dialog: Rc::new(PlatformFileDialog),
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Control the selected files from the parent.
+    pub fn value(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.value = self
+            .value
+            .value(paths.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the initially selected files for uncontrolled use.
+    pub fn default_value(mut self, paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        self.value = self
+            .value
+            .default_value(paths.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the handler fired with the new selection after a pick, drop, or removal.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&[PathBuf], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.value = self
+            .value
+            .on_change(move |paths, window, cx| handler(paths, window, cx));
+        self
+    }
+
+    /// Accept files matching `filter`. With no filters every file is accepted.
+    pub fn filter(mut self, filter: FileFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Allow choosing several files; new picks are added to the selection.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Open from a drop area instead of a button.
+    pub fn drop_zone(mut self) -> Self {
+        self.trigger = FilePickerTrigger::DropZone;
+        self
+    }
+
+    /// Set the trigger text.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the confirm button text of the dialog, where the platform supports it.
+    pub fn prompt(mut self, prompt: impl Into<SharedString>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// Show or hide the selected file list (shown by default).
+    pub fn show_list(mut self, show: bool) -> Self {
+        self.show_list = show;
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Use `dialog` instead of the platform file dialog.
+    pub fn dialog(mut self, dialog: impl FileDialog + 'static) -> Self {
+        self.dialog = Rc::new(dialog);
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for FilePicker.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::file_picker()
+    }
+}
+
+impl WithIdentifiers for FilePicker {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for FilePicker {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "FilePicker",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let value = self.value.bind(self.id.clone(), window, cx);
+        let selected = value.current().clone();
+        let rejected = window.use_keyed_state(key("rejected"), cx, |_, _| Vec::<PathBuf>::new());
+        // True while the dialog is open, so repeated presses don't stack dialogs.
+        let pending = window.use_keyed_state(key("pending"), cx, |_, _| false);
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        let disabled = self.disabled;
+        let is_pending = *pending.read(cx);
+        let message = rejection_message(rejected.read(cx), &self.filters);
+        let filters = Rc::new(self.filters);
+        let multiple = self.multiple;
+
+        // Merge picked or dropped paths into the selection.
+        let apply: Rc<dyn Fn(Vec<PathBuf>, &mut Window, &mut App)> = {
+            let value = value.clone();
+            let rejected = rejected.clone();
+            let filters = filters.clone();
+            Rc::new(move |picked, window, cx| {
+                let result = merge_picked(value.current(), picked, &filters, multiple);
+                rejected.update(cx, |rejected, cx| {
+                    *rejected = result.rejected;
+                    cx.notify();
+                });
+                value.set(result.selected, window, cx);
+            })
+        };
+        let open: Rc<dyn Fn(&mut Window, &mut App)> = {
+            let apply = apply.clone();
+            let pending = pending.clone();
+            let dialog = self.dialog.clone();
+            let prompt = self.prompt.clone();
+            Rc::new(move |window, cx| {
+                if *pending.read(cx) {
+                    return;
+                }
+                pending.update(cx, |pending, cx| {
+                    *pending = true;
+                    cx.notify();
+                });
+                let task = dialog.prompt(prompt_options(multiple, prompt.clone()), cx);
+                let apply = apply.clone();
+                let pending = pending.clone();
+                window
+                    .spawn(cx, async move |cx| {
+                        let picked = task.await;
+                        cx.update(|window, cx| {
+                            pending.update(cx, |pending, cx| {
+                                *pending = false;
+                                cx.notify();
+                            });
+                            if let Some(picked) = picked {
+                                apply(picked, window, cx);
+                            }
+                        })
+                        .ok();
+                    })
+                    .detach();
+            })
+        };
+
+        let theme = cx.theme();
+        let is_focused = focus_handle.is_focused(window);
+        let (bg, text_color, border_color) = if disabled {
+            (
+                theme.element.disabled,
+                theme.text.disabled,
+                theme.border.disabled,
+            )
+        } else if is_focused || is_pending {
+            (
+                theme.element.background,
+                theme.text.default,
+                theme.border.focused,
+            )
+        } else {
+            (
+                theme.element.background,
+                theme.text.default,
+                theme.border.default,
+            )
+        };
+        let hover_bg = theme.element.hover;
+        let focused_border = theme.border.focused;
+        let muted_color = if disabled {
+            theme.text.disabled
+        } else {
+            theme.text.muted
+        };
+        let remove_hover = theme.ghost_element.hover;
+        let error_color = theme.status.error.foreground;
+        let hint = (!filters.is_empty()).then(|| {
+            filters
+                .iter()
+                .map(FileFilter::label)
+                .collect::<Vec<_>>()
+                .join(", ")
+        });
+
+        let mut trigger = div()
+            .id(key("trigger"))
+            .flex()
+            .bg(bg)
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .text_color(text_color);
+        trigger = match self.trigger {
+            FilePickerTrigger::Button => trigger
+                .flex_row()
+                .items_center()
+                .h(px(28.0))
+                .px_3()
+                .text_xs()
+                .child(self.label.unwrap_or_else(|| {
+                    if multiple {
+                        "Choose files…".into()
+                    } else {
+                        "Choose file…".into()
+                    }
+                })),
+            FilePickerTrigger::DropZone => trigger
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .gap_1()
+                .w_full()
+                .min_h(px(72.0))
+                .p_4()
+                .border_dashed()
+                .text_sm()
+                .child(self.label.unwrap_or_else(|| {
+                    if multiple {
+                        "Drop files here or click to browse".into()
+                    } else {
+                        "Drop a file here or click to browse".into()
+                    }
+                }))
+                .when_some(hint, |el, hint| {
+                    el.child(div().text_xs().text_color(muted_color).child(hint))
+                }),
+        };
+
+        if !disabled {
+            let click_open = open.clone();
+            let handle = focus_handle.clone();
+            trigger = trigger
+                .track_focus(&focus_handle)
+                .cursor_pointer()
+                .hover(move |s| s.bg(hover_bg))
+                .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    window.focus(&handle, cx);
+                    click_open(window, cx);
+                })
+                .on_key_down(move |event, window, cx| {
+                    if is_activation_key(event) {
+                        cx.stop_propagation();
+                        open(window, cx);
+                    }
+                });
+            if self.trigger == FilePickerTrigger::DropZone {
+                trigger = trigger
+                    .drag_over::<ExternalPaths>(move |style, _, _, _| {
+                        style.border_color(focused_border).bg(hover_bg)
+                    })
+                    .on_drop(move |paths: &ExternalPaths, window, cx| {
+                        apply(paths.paths().to_vec(), window, cx);
+                    });
+            }
+        }
+
+        let mut wrapper = div().flex().flex_col().gap_1();
+        if self.trigger == FilePickerTrigger::DropZone {
+            wrapper = wrapper.w_full();
+        }
+        wrapper = wrapper.child(trigger);
+
+        if self.show_list && !selected.is_empty() {
+            let mut list = div().flex().flex_col().gap_1();
+            for (index, path) in selected.iter().enumerate() {
+                let parent = path
+                    .parent()
+                    .map(|parent| parent.display().to_string())
+                    .unwrap_or_default();
+                let mut remove = div()
+                    .id(SharedString::from(format!("{}-remove-{index}", self.id)))
+                    .flex_shrink_0()
+                    .px_1()
+                    .rounded_sm()
+                    .text_color(muted_color)
+                    .child("×");
+                if !disabled {
+                    let remove_state = value.clone();
+                    let remaining: Vec<PathBuf> = selected
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| *i != index)
+                        .map(|(_, path)| path.clone())
+                        .collect();
+                    remove = remove
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(remove_hover))
+                        .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                            cx.stop_propagation();
+                            remove_state.set(remaining.clone(), window, cx);
+                        });
+                }
+                list = list.child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_2()
+                        .text_xs()
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_color(text_color)
+                                .child(display_name(path)),
+                        )
+                        .child(
+                            div()
+                                .flex_1()
+                                .overflow_x_hidden()
+                                .text_color(muted_color)
+                                .child(parent),
+                        )
+                        .child(remove),
+                );
+            }
+            wrapper = wrapper.child(list);
+        }
+
+        if let Some(message) = message {
+            wrapper = wrapper.child(div().text_xs().text_color(error_color).child(message));
+        }
+
+        wrapper.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,355 @@
+//! Form component: labeled fields laid out consistently, with validation
+//! aggregated across fields and submit/reset actions.
+//!
+//! Rewrite disposition: written for the workbench; gpui-component's form
+//! layout ties fields to its own input state, while these fields wrap any
+//! control (Input, Select, Checkbox, Radio, ...).
+//!
+//! Each [`FormField`] carries its control and its `ValidationState`. The
+//! form stacks label, control, and help or error text the same way for every
+//! field, and blocks submit while any field is in error.
+
+use std::rc::Rc;
+
+use crate::button::{Button, ButtonVariant};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::ValidationState;
+use theme::ActiveTheme;
+
+/// Callback for the submit and reset actions.
+type FormCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// One labeled field of a [`Form`].
+pub struct FormField {
+    label: SharedString,
+    required: bool,
+    help: Option<SharedString>,
+    validation: ValidationState,
+    error_message: Option<SharedString>,
+    control: Option<AnyElement>,
+}
+
+impl FormField {
+    /// Create a field with a label.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            required: false,
+            help: None,
+            validation: ValidationState::None,
+            error_message: None,
+            control: None,
+        }
+    }
+
+    /// Mark the field required; its label gets a `*`.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Set help text shown under the control while the field is valid.
+    pub fn help(mut self, help: impl Into<SharedString>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Put the field in error, with a message shown in place of the help.
+    pub fn error(mut self, message: impl Into<SharedString>) -> Self {
+        self.validation = ValidationState::Error;
+        self.error_message = Some(message.into());
+        self
+    }
+
+    /// Set the validation state and message, e.g. from `validate_password`.
+    pub fn validation(
+        mut self,
+        state: ValidationState,
+        message: Option<impl Into<SharedString>>,
+    ) -> Self {
+        self.validation = state;
+        self.error_message = message.map(Into::into);
+        self
+    }
+
+    /// Set the control the field labels.
+    pub fn control(mut self, control: impl IntoElement) -> Self {
+        self.control = Some(control.into_any_element());
+        self
+    }
+
+    /// The field's label.
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+
+    /// The field's validation state.
+    pub fn validation_state(&self) -> ValidationState {
+        self.validation
+    }
+}
+
+/// The form's validation: `Error` if any field is in error.
+pub fn aggregate_validation(states: impl IntoIterator<Item = ValidationState>) -> ValidationState {
+    if states.into_iter().any(|state| state.is_error()) {
+        ValidationState::Error
+    } else {
+        ValidationState::None
+    }
+}
+
+/// The summary shown when submit is blocked, naming the fields in error
+/// (e.g. "Fix 2 fields: Name and Email"), or `None` when there are none.
+pub fn error_summary(labels: &[&str]) -> Option<String> {
+    let names = match labels {
+        [] => return None,
+        [only] => only.to_string(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    };
+    let fields = if labels.len() == 1 { "field" } else { "fields" };
+    Some(format!("Fix {} {fields}: {names}", labels.len()))
+}
+
+/// A vertical form of labeled fields with submit and reset actions.
+///
+/// The form is controlled: fields show the values and validation the owner
+/// gives them. Submit calls `on_submit` only while every field is valid;
+/// otherwise it lists the fields in error above the actions. Reset calls
+/// `on_reset` so the owner can restore its values.
+///
+/// # Usage
+/// ```ignore
+/// Form::new("signup")
+///     .field(
+///         FormField::new("Email")
+///             .required()
+///             .help("We never share it.")
+///             .control(Input::new("email").value(email.clone())),
+///     )
+///     .on_submit(|_window, _cx| save())
+///     .on_reset(|_window, _cx| clear())
+/// ```
+#[derive(IntoElement)]
+pub struct Form {
+    id: ElementId,
+    fields: Vec<FormField>,
+    submit_label: SharedString,
+    reset_label: SharedString,
+    on_submit: Option<FormCallback>,
+    on_reset: Option<FormCallback>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Form {
+    /// Create an empty form.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            fields: Vec::new(),
+            submit_label: "Submit".into(),
+            reset_label: "Reset".into(),
+            on_submit: None,
+            on_reset: None,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Add a field.
+    pub fn field(mut self, field: FormField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Add fields.
+    pub fn fields(mut self, fields: impl IntoIterator<Item = FormField>) -> Self {
+        self.fields.extend(fields);
+        self
+    }
+
+    /// Set the submit button label.
+    pub fn submit_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Set the reset button label.
+    pub fn reset_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.reset_label = label.into();
+        self
+    }
+
+    /// Set the handler fired by submit while every field is valid.
+    pub fn on_submit(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the reset handler; the reset button is shown once one is set.
+    pub fn on_reset(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_reset = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// The form's validation, aggregated from its fields.
+    pub fn validation(&self) -> ValidationState {
+        aggregate_validation(self.fields.iter().map(FormField::validation_state))
+    }
+
+    /// Returns the component contract for Form.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::form()
+    }
+}
+
+impl WithIdentifiers for Form {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Form {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Form",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let invalid = self.validation().is_error();
+        let summary = {
+            let labels: Vec<&str> = self
+                .fields
+                .iter()
+                .filter(|field| field.validation.is_error())
+                .map(|field| field.label.as_ref())
+                .collect();
+            error_summary(&labels)
+        };
+        // Set by a blocked submit; the summary shows until the form is valid
+        // or reset.
+        let attempted = window.use_keyed_state(
+            SharedString::from(format!("{}-attempted", self.id)),
+            cx,
+            |_, _| false,
+        );
+        let show_summary = *attempted.read(cx) && invalid;
+
+        let theme = cx.theme();
+        let label_color = theme.text.default;
+        let help_color = theme.text.muted;
+        let error_color = theme.status.error.foreground;
+        let summary_bg = theme.status.error.background;
+        let summary_border = theme.status.error.border;
+
+        let mut form = div().id(self.id.clone()).flex().flex_col().gap_4().w_full();
+
+        for field in self.fields {
+            let in_error = field.validation.is_error();
+            let below = match (in_error, field.error_message, field.help) {
+                (true, Some(message), _) => Some((message, error_color)),
+                (_, _, Some(help)) => Some((help, help_color)),
+                _ => None,
+            };
+            form = form.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .flex()
+                            .flex_row()
+                            .gap_0p5()
+                            .text_sm()
+                            .text_color(label_color)
+                            .child(field.label)
+                            .when(field.required, |el| {
+                                el.child(div().text_color(error_color).child("*"))
+                            }),
+                    )
+                    .children(field.control)
+                    .children(
+                        below.map(|(text, color)| div().text_xs().text_color(color).child(text)),
+                    ),
+            );
+        }
+
+        if show_summary && let Some(summary) = summary {
+            form = form.child(
+                div()
+                    .px_3()
+                    .py_2()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(summary_border)
+                    .bg(summary_bg)
+                    .text_sm()
+                    .text_color(error_color)
+                    .child(summary),
+            );
+        }
+
+        let submit = Button::new(SharedString::from(format!("{}-submit", self.id)))
+            .label(self.submit_label)
+            .variant(ButtonVariant::Primary)
+            .on_click({
+                let attempted = attempted.clone();
+                let on_submit = self.on_submit.clone();
+                move |_, window, cx| {
+                    attempted.update(cx, |attempted, cx| {
+                        *attempted = invalid;
+                        cx.notify();
+                    });
+                    if !invalid && let Some(on_submit) = &on_submit {
+                        on_submit(window, cx);
+                    }
+                }
+            });
+        let reset = self.on_reset.map(|on_reset| {
+            Button::new(SharedString::from(format!("{}-reset", self.id)))
+                .label(self.reset_label)
+                .variant(ButtonVariant::Ghost)
+                .on_click(move |_, window, cx| {
+                    attempted.update(cx, |attempted, cx| {
+                        *attempted = false;
+                        cx.notify();
+                    });
+                    on_reset(window, cx);
+                })
+        });
+        form = form.child(
+            div()
+                .flex()
+                .flex_row()
+                .justify_end()
+                .gap_2()
+                .children(reset)
+                .child(submit),
+        );
+
+        form.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
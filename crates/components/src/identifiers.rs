@@ -0,0 +1,169 @@
+//! Shared identifiers plumbing: applies contract `SharedIdentifiers` to rendered elements.
+//!
+//! Every component implements [`WithIdentifiers`], which adds an `.identifiers(...)`
+//! builder method. Identifiers are applied as follows:
+//! - `id` overrides the element id passed to the component constructor.
+//! - `tooltip` fills the component's tooltip slot (overriding `set_tooltip`).
+//! - `metadata` is published, together with the id and tooltip, to the
+//!   [`AccessibilityTree`] global when the component renders.
+//!
+//! GPUI has no platform accessibility API at the pinned revision, so the
+//! accessibility tree is the workbench's a11y layer: the studio and tests read
+//! it to inspect what assistive tooling would be told about each element.
+
+use std::collections::{BTreeMap, HashMap};
+
+use gpui::{App, ElementId, Global, SharedString};
+
+use crate::SharedIdentifiers;
+
+/// Builder mixin for applying [`SharedIdentifiers`] to a component.
+///
+/// Implementors expose their id, tooltip, and identifier slots; the provided
+/// methods handle merging so every component behaves identically.
+pub trait WithIdentifiers: Sized {
+    /// The component's element id slot.
+    fn element_id_mut(&mut self) -> &mut ElementId;
+
+    /// The component's tooltip slot, or `None` for components without tooltips.
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>>;
+
+    /// The stored identifiers, published to the accessibility tree on render.
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers;
+
+    /// Apply shared identifiers: id, tooltip, and accessibility metadata.
+    fn identifiers(mut self, identifiers: SharedIdentifiers) -> Self {
+        if let Some(id) = &identifiers.id {
+            *self.element_id_mut() = ElementId::Name(id.clone().into());
+        }
+        if let Some(tooltip) = &identifiers.tooltip
+            && let Some(slot) = self.tooltip_mut()
+        {
+            *slot = Some(tooltip.clone().into());
+        }
+        *self.identifiers_mut() = identifiers;
+        self
+    }
+
+    /// Add a single metadata entry exposed to the accessibility layer.
+    fn identifier_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.identifiers_mut()
+            .metadata
+            .insert(key.into(), value.into());
+        self
+    }
+}
+
+/// What the accessibility layer knows about one rendered component instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessibilityNode {
+    /// Component name from its contract (e.g. `"Button"`), used as the role.
+    pub component: &'static str,
+    /// Resolved element id.
+    pub id: String,
+    /// Accessible description, taken from the tooltip.
+    pub description: Option<String>,
+    /// The element shows no text (e.g. an icon-only button), so
+    /// `description` is its only label.
+    pub needs_label: bool,
+    /// Arbitrary key-value metadata from [`SharedIdentifiers::metadata`].
+    pub metadata: HashMap<String, String>,
+}
+
+/// Accessibility metadata for rendered components, keyed by element id.
+///
+/// Populated during render; re-rendering an element replaces its node.
+#[derive(Debug, Default)]
+pub struct AccessibilityTree {
+    nodes: BTreeMap<String, AccessibilityNode>,
+}
+
+impl Global for AccessibilityTree {}
+
+impl AccessibilityTree {
+    /// Insert or replace a node.
+    pub fn record(&mut self, node: AccessibilityNode) {
+        self.nodes.insert(node.id.clone(), node);
+    }
+
+    /// Look up a node by element id.
+    pub fn get(&self, id: &str) -> Option<&AccessibilityNode> {
+        self.nodes.get(id)
+    }
+
+    /// All nodes, ordered by element id.
+    pub fn nodes(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        self.nodes.values()
+    }
+
+    /// Number of recorded nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Nodes that show no text and have no description: elements assistive
+    /// tooling could only announce by role. This is the a11y check for
+    /// icon-only controls.
+    pub fn unlabeled(&self) -> impl Iterator<Item = &AccessibilityNode> {
+        self.nodes.values().filter(|node| {
+            node.needs_label
+                && node
+                    .description
+                    .as_deref()
+                    .is_none_or(|label| label.trim().is_empty())
+        })
+    }
+
+    /// Remove all nodes.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+}
+
+/// Publish a component's identifiers to the accessibility tree.
+///
+/// Called at the start of each component's render.
+pub fn publish_identifiers(
+    component: &'static str,
+    id: &ElementId,
+    tooltip: Option<&SharedString>,
+    identifiers: &SharedIdentifiers,
+    cx: &mut App,
+) {
+    record_node(component, id, tooltip, false, identifiers, cx);
+}
+
+/// Publish the identifiers of an element that shows only an icon, whose
+/// `label` is therefore required; see [`AccessibilityTree::unlabeled`].
+pub fn publish_icon_only_identifiers(
+    component: &'static str,
+    id: &ElementId,
+    label: Option<&SharedString>,
+    identifiers: &SharedIdentifiers,
+    cx: &mut App,
+) {
+    record_node(component, id, label, true, identifiers, cx);
+}
+
+fn record_node(
+    component: &'static str,
+    id: &ElementId,
+    description: Option<&SharedString>,
+    needs_label: bool,
+    identifiers: &SharedIdentifiers,
+    cx: &mut App,
+) {
+    let node = AccessibilityNode {
+        component,
+        id: id.to_string(),
+        description: description.map(|d| d.to_string()),
+        needs_label,
+        metadata: identifiers.metadata.clone(),
+    };
+    cx.default_global::<AccessibilityTree>().record(node);
+}
@@ -7,9 +7,17 @@
 //! - gpui-component `crates/ui/src/input/input.rs` (MIT, Zed Industries)
 //! - Zed focus/keyboard patterns (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - Modifications: Simplified to internal token system, stateless RenderOnce for Phase 1,
-//!   uses internal primitives for state management.
+//!   uses internal primitives for controlled/uncontrolled value (ControlledState) and
+//!   text editing with IME composition (TextInput).
 
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::*;
+use primitives::{
+    CompositionEvent, CompositionHandler, ControlledState, TEXT_INPUT_KEY_CONTEXT, TextInput,
+    TextInputColors, TextInputElement,
+};
 use theme::ActiveTheme;
 
 /// Input size controlling height and text size.
@@ -24,12 +32,13 @@ pub enum InputSize {
     Large,
 }
 
-/// Callback when the input value changes.
-type OnChangeCallback = Box<dyn Fn(&str, &mut Window, &mut App) + 'static>;
-
 /// A single-line text input component with placeholder, validation states,
 /// and builder-pattern API mapped to frozen design tokens.
 ///
+/// Text arrives through the platform input handler, so input methods can
+/// compose CJK and other text: preedit text is shown underlined and only
+/// reaches `on_change` once committed.
+///
 /// # Usage
 /// ```ignore
 /// Input::new("email-input")
@@ -42,7 +51,7 @@ type OnChangeCallback = Box<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 #[derive(IntoElement)]
 pub struct Input {
     id: ElementId,
-    value: SharedString,
+    value: ControlledState<SharedString>,
     placeholder: SharedString,
     size: InputSize,
     disabled: bool,
@@ -51,8 +60,9 @@ pub struct Input {
     error_message: Option<SharedString>,
     prefix: Option<SharedString>,
     suffix: Option<SharedString>,
-    on_change: Option<OnChangeCallback>,
+    on_composition: Option<CompositionHandler>,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
     full_width: bool,
 }
 
@@ -61,7 +71,7 @@ impl Input {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            value: SharedString::default(),
+            value: ControlledState::new(),
             placeholder: SharedString::default(),
             size: InputSize::Medium,
             disabled: false,
@@ -70,15 +80,22 @@ impl Input {
             error_message: None,
             prefix: None,
             suffix: None,
-            on_change: None,
+            on_composition: None,
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
             full_width: false,
         }
     }
 
-    /// Set the input value.
+    /// Control the input value from the parent.
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
-        self.value = value.into();
+        self.value = self.value.value(value.into());
+        self
+    }
+
+    /// Set the initial value for uncontrolled use.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = self.value.default_value(value.into());
         self
     }
 
@@ -133,7 +150,18 @@ impl Input {
 
     /// Set the change handler.
     pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.value = self
+            .value
+            .on_change(move |value, window, cx| handler(value, window, cx));
+        self
+    }
+
+    /// Set a handler for input method composition updates, commits, and cancels.
+    pub fn on_composition(
+        mut self,
+        handler: impl Fn(&CompositionEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_composition = Some(Rc::new(handler));
         self
     }
 
@@ -151,83 +179,54 @@ impl Input {
 
     /// Returns the component contract for Input.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Input", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the input")
-            .optional_prop("value", "SharedString", "\"\"", "Current input value")
-            .optional_prop(
-                "placeholder",
-                "SharedString",
-                "\"\"",
-                "Placeholder text when empty",
-            )
-            .optional_prop(
-                "size",
-                "InputSize",
-                "Medium",
-                "Input size: Small, Medium, Large",
-            )
-            .optional_prop("disabled", "bool", "false", "Whether the input is disabled")
-            .optional_prop(
-                "readonly",
-                "bool",
-                "false",
-                "Whether the input is read-only",
-            )
-            .optional_prop(
-                "error",
-                "bool",
-                "false",
-                "Whether the input is in error state",
-            )
-            .optional_prop(
-                "error_message",
-                "Option<SharedString>",
-                "None",
-                "Error message displayed below input",
-            )
-            .optional_prop("prefix", "Option<SharedString>", "None", "Prefix label")
-            .optional_prop("suffix", "Option<SharedString>", "None", "Suffix label")
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .optional_prop("full_width", "bool", "false", "Take full container width")
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Disabled)
-            .state(ComponentState::Error)
-            .state(ComponentState::Readonly)
-            .token_dep("element.background", "Input background")
-            .token_dep("element.hover", "Input hover background")
-            .token_dep("element.disabled", "Disabled input background")
-            .token_dep("text.default", "Input text color")
-            .token_dep("text.placeholder", "Placeholder text color")
-            .token_dep("text.disabled", "Disabled text color")
-            .token_dep("text.muted", "Prefix/suffix text color")
-            .token_dep("border.default", "Input border")
-            .token_dep("border.focused", "Focused input border")
-            .token_dep("border.disabled", "Disabled input border")
-            .token_dep("status.error.foreground", "Error message text color")
-            .token_dep("status.error.border", "Error state border color")
-            .focus_behavior("Tab/Shift-Tab navigates to/from input. Focus shows focused border.")
-            .keyboard_model(
-                "Standard text input keyboard behavior. \
-                 All printable keys enter text. Backspace/Delete remove text.",
-            )
-            .pointer_behavior("Click focuses the input. Hover shows hover state.")
-            .state_model(
-                "Controlled value via prop. Error state shows error border and message. \
-                 Readonly allows focus and selection but not editing.",
-            )
-            .disabled_behavior("Disabled inputs show muted styling and cannot be focused.")
-            .readonly_behavior("Readonly inputs can be focused and selected but not edited.")
-            .required_file("crates/components/src/input.rs")
-            .build()
+        crate::catalog::input()
+    }
+}
+
+impl WithIdentifiers for Input {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Input {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Input",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let value = self.value.bind(self.id.clone(), window, cx);
+        let editable = !self.disabled && !self.readonly;
+        let text_input = window.use_keyed_state(
+            SharedString::from(format!("{}-text", self.id)),
+            cx,
+            |_, cx| TextInput::new(cx),
+        );
+        text_input.update(cx, |input, _| {
+            let bound = value.clone();
+            input.sync(
+                value.current(),
+                editable,
+                Some(Rc::new(move |text, window, cx| {
+                    bound.set(SharedString::from(text.to_string()), window, cx)
+                })),
+                self.on_composition.clone(),
+            );
+        });
+        let focus_handle = text_input.read(cx).focus_handle(cx);
+
         let theme = cx.theme();
 
         let (bg, border_color, text_color, placeholder_color) = if self.disabled {
@@ -259,6 +258,12 @@ impl RenderOnce for Input {
             theme.border.focused
         };
         let affix_color = theme.text.muted;
+        let colors = TextInputColors {
+            text: text_color,
+            placeholder: placeholder_color,
+            cursor: theme.text.accent,
+            selection: theme.element.selected,
+        };
         let error_text_color = theme.status.error.foreground;
 
         let height = match self.size {
@@ -289,7 +294,24 @@ impl RenderOnce for Input {
         }
 
         if !disabled {
-            field = field.hover(move |s| s.border_color(hover_border));
+            let handle = focus_handle.clone();
+            let mouse_input = text_input.clone();
+            let key_input = text_input.clone();
+            field = field
+                .track_focus(&focus_handle)
+                .key_context(TEXT_INPUT_KEY_CONTEXT)
+                .hover(move |s| s.border_color(hover_border))
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    window.focus(&handle, cx);
+                    mouse_input.update(cx, |input, cx| {
+                        input.move_to_point(event.position, event.modifiers.shift, cx)
+                    });
+                })
+                .on_key_down(move |event, window, cx| {
+                    if key_input.update(cx, |input, cx| input.handle_key_down(event, window, cx)) {
+                        cx.stop_propagation();
+                    }
+                });
         }
 
         // Text size
@@ -310,17 +332,13 @@ impl RenderOnce for Input {
             );
         }
 
-        // Value or placeholder
-        if self.value.is_empty() {
-            field = field.child(
-                div()
-                    .flex_1()
-                    .text_color(placeholder_color)
-                    .child(self.placeholder),
-            );
-        } else {
-            field = field.child(div().flex_1().text_color(text_color).child(self.value));
-        }
+        // Editable text, preedit, and placeholder
+        field = field.child(
+            div()
+                .flex_1()
+                .overflow_x_hidden()
+                .child(TextInputElement::new(text_input, self.placeholder, colors)),
+        );
 
         // Suffix
         if let Some(suffix) = self.suffix {
@@ -351,6 +369,6 @@ impl RenderOnce for Input {
             );
         }
 
-        wrapper
+        wrapper.inspectable(&self.id, Self::contract)
     }
 }
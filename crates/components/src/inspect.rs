@@ -0,0 +1,248 @@
+//! Inspect-mode debug metadata: rendered size, padding, margins, and color
+//! tokens.
+//!
+//! Every component wraps its root element with [`Inspectable::inspectable`].
+//! While the [`InspectTree`] global is enabled (the studio's inspect mode),
+//! the wrapper records the root's laid-out bounds and resolved padding and
+//! margins,
+//! together with the color token paths declared in the component's contract,
+//! so tooling can show devtools-style details for the element under the
+//! pointer. The wrapper is transparent to layout and records nothing while
+//! inspection is disabled.
+
+use std::collections::BTreeMap;
+
+use gpui::{
+    AbsoluteLength, AnyElement, App, Bounds, DefiniteLength, Edges, Element, ElementId, Global,
+    GlobalElementId, InspectorElementId, IntoElement, LayoutId, Length, Pixels, Point, Refineable,
+    StyleRefinement, Styled, Window, px,
+};
+
+use crate::ComponentContract;
+
+/// What inspect mode knows about one rendered component instance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectNode {
+    /// Component name from its contract (e.g. `"Button"`).
+    pub component: String,
+    /// Resolved element id.
+    pub id: String,
+    /// Window-space bounds of the component's root element.
+    pub bounds: Bounds<Pixels>,
+    /// Padding of the root element, in pixels.
+    pub padding: Edges<Pixels>,
+    /// Margins of the root element, in pixels; `auto` margins count as zero.
+    pub margin: Edges<Pixels>,
+    /// Color token paths the component declares (e.g. `"element.background"`).
+    pub color_tokens: Vec<String>,
+}
+
+impl InspectNode {
+    /// Bounds of the content box: the root bounds minus padding.
+    pub fn content_bounds(&self) -> Bounds<Pixels> {
+        let mut content = self.bounds;
+        content.origin.x += self.padding.left;
+        content.origin.y += self.padding.top;
+        content.size.width =
+            (content.size.width - self.padding.left - self.padding.right).max(Pixels::ZERO);
+        content.size.height =
+            (content.size.height - self.padding.top - self.padding.bottom).max(Pixels::ZERO);
+        content
+    }
+
+    /// Bounds of the margin box: the root bounds plus margins.
+    pub fn margin_bounds(&self) -> Bounds<Pixels> {
+        self.bounds
+            .extend(self.margin.map(|margin| (*margin).max(Pixels::ZERO)))
+    }
+}
+
+/// Inspect metadata for rendered components, keyed by element id.
+///
+/// Populated during prepaint while enabled; re-rendering an element replaces
+/// its node.
+#[derive(Debug, Default)]
+pub struct InspectTree {
+    enabled: bool,
+    nodes: BTreeMap<String, InspectNode>,
+}
+
+impl Global for InspectTree {}
+
+impl InspectTree {
+    /// Whether components record inspect metadata.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Start or stop recording. Disabling clears recorded nodes.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        if !enabled {
+            self.nodes.clear();
+        }
+    }
+
+    /// Insert or replace a node.
+    pub fn record(&mut self, node: InspectNode) {
+        self.nodes.insert(node.id.clone(), node);
+    }
+
+    /// Look up a node by element id.
+    pub fn get(&self, id: &str) -> Option<&InspectNode> {
+        self.nodes.get(id)
+    }
+
+    /// All nodes, ordered by element id.
+    pub fn nodes(&self) -> impl Iterator<Item = &InspectNode> {
+        self.nodes.values()
+    }
+
+    /// The innermost node under `point`: the smallest one whose bounds contain it.
+    pub fn hit_test(&self, point: Point<Pixels>) -> Option<&InspectNode> {
+        self.nodes
+            .values()
+            .filter(|node| node.bounds.contains(&point))
+            .min_by(|a, b| {
+                let area = |node: &InspectNode| {
+                    f32::from(node.bounds.size.width) * f32::from(node.bounds.size.height)
+                };
+                area(a).total_cmp(&area(b))
+            })
+    }
+
+    /// Remove all nodes, keeping the enabled state.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+}
+
+/// Builder mixin wrapping a component's root element for inspect mode.
+pub trait Inspectable: Styled + IntoElement + Sized + 'static {
+    /// Wrap this element so inspect mode can report it as `id`, with color
+    /// tokens taken from `contract`. The contract is only built while
+    /// inspection is enabled.
+    fn inspectable(self, id: &ElementId, contract: fn() -> ComponentContract) -> Inspected<Self> {
+        Inspected {
+            id: id.to_string(),
+            contract,
+            padding: Edges::default(),
+            margin: Edges::default(),
+            element: Some(self),
+        }
+    }
+}
+
+impl<E: Styled + IntoElement + 'static> Inspectable for E {}
+
+/// A layout-transparent wrapper recording its element's bounds into the [`InspectTree`].
+///
+/// Styling calls are forwarded to the wrapped element, so it can still be
+/// animated after wrapping.
+pub struct Inspected<E> {
+    id: String,
+    contract: fn() -> ComponentContract,
+    padding: Edges<DefiniteLength>,
+    margin: Edges<Length>,
+    element: Option<E>,
+}
+
+impl<E: Styled> Styled for Inspected<E> {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.element
+            .as_mut()
+            .expect("inspected element is styled before layout")
+            .style()
+    }
+}
+
+impl<E: Styled + IntoElement + 'static> IntoElement for Inspected<E> {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl<E: Styled + IntoElement + 'static> Element for Inspected<E> {
+    type RequestLayoutState = AnyElement;
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut element = self
+            .element
+            .take()
+            .expect("inspected element is laid out once");
+        self.padding.refine(&element.style().padding);
+        self.margin.refine(&element.style().margin);
+        let mut child = element.into_any_element();
+        // Reuse the child's layout node so wrapping never changes layout.
+        (child.request_layout(window, cx), child)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        child: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if cx
+            .try_global::<InspectTree>()
+            .is_some_and(InspectTree::is_enabled)
+        {
+            let contract = (self.contract)();
+            // Relative padding resolves against the element's own size.
+            let size = bounds.size.map(AbsoluteLength::Pixels);
+            let node = InspectNode {
+                component: contract.name,
+                id: self.id.clone(),
+                bounds,
+                padding: self.padding.to_pixels(size, window.rem_size()),
+                margin: self
+                    .margin
+                    .map(|margin| match margin {
+                        Length::Definite(length) => *length,
+                        Length::Auto => px(0.0).into(),
+                    })
+                    .to_pixels(size, window.rem_size()),
+                color_tokens: contract
+                    .token_dependencies
+                    .into_iter()
+                    .map(|token| token.path)
+                    .collect(),
+            };
+            cx.global_mut::<InspectTree>().record(node);
+        }
+        child.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        child: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        child.paint(window, cx);
+    }
+}
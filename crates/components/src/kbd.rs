@@ -0,0 +1,224 @@
+//! Kbd component: key caps for displaying keyboard shortcuts.
+//!
+//! Rewrite disposition: written for the workbench so shortcut hints in menus,
+//! stories, and reference panels share one rendering.
+//!
+//! Keystrokes use GPUI's binding syntax (`"cmd-shift-p"`, `"ctrl--"`, chords
+//! separated by spaces such as `"cmd-k cmd-s"`). Modifier and named keys are
+//! normalized to platform conventions: symbols on macOS (`⌘⇧P`), words
+//! elsewhere (`Ctrl` `Shift` `P`). [`Kbd::for_action`] shows whatever is
+//! currently bound to an action in the `ShortcutRegistry`.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use primitives::ShortcutRegistry;
+use theme::ActiveTheme;
+
+/// Platform whose key naming conventions a [`Kbd`] follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyPlatform {
+    /// Symbol glyphs (⌘ ⌥ ⌃ ⇧).
+    Mac,
+    /// Words, with the platform key shown as `Super`.
+    Linux,
+    /// Words, with the platform key shown as `Win`.
+    Windows,
+}
+
+impl KeyPlatform {
+    /// The platform this binary was compiled for.
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Mac
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+}
+
+/// Split a single keystroke (`"cmd-shift-p"`) into display labels for each key.
+///
+/// A trailing `-` names the minus key, so `"ctrl--"` yields `Ctrl` and `-`.
+pub fn keystroke_labels(keystroke: &str, platform: KeyPlatform) -> Vec<SharedString> {
+    let mut keys = Vec::new();
+    let mut rest = keystroke;
+    while let Some((modifier, tail)) = rest.split_once('-') {
+        if modifier.is_empty() || tail.is_empty() {
+            break;
+        }
+        keys.push(modifier);
+        rest = tail;
+    }
+    keys.push(rest);
+    keys.into_iter()
+        .map(|key| key_label(key, platform))
+        .collect()
+}
+
+/// Display label for one key or modifier name.
+pub fn key_label(key: &str, platform: KeyPlatform) -> SharedString {
+    let pick = |mac: &'static str, other: &'static str| {
+        if platform == KeyPlatform::Mac {
+            mac
+        } else {
+            other
+        }
+    };
+    let label = match key.to_ascii_lowercase().as_str() {
+        "cmd" | "super" | "platform" | "win" => match platform {
+            KeyPlatform::Mac => "⌘",
+            KeyPlatform::Linux => "Super",
+            KeyPlatform::Windows => "Win",
+        },
+        // GPUI's portable modifier: cmd on macOS, ctrl elsewhere.
+        "secondary" => pick("⌘", "Ctrl"),
+        "ctrl" | "control" => pick("⌃", "Ctrl"),
+        "alt" | "option" => pick("⌥", "Alt"),
+        "shift" => pick("⇧", "Shift"),
+        "fn" => "Fn",
+        "enter" => pick("↵", "Enter"),
+        "backspace" => pick("⌫", "Backspace"),
+        "delete" => pick("⌦", "Del"),
+        "tab" => pick("⇥", "Tab"),
+        "escape" => "Esc",
+        "space" => "Space",
+        "up" => "↑",
+        "down" => "↓",
+        "left" => "←",
+        "right" => "→",
+        "home" => "Home",
+        "end" => "End",
+        "pageup" => "PgUp",
+        "pagedown" => "PgDn",
+        _ => return capitalize_key(key),
+    };
+    SharedString::new_static(label)
+}
+
+/// Uppercase single characters and function keys, capitalize other names.
+fn capitalize_key(key: &str) -> SharedString {
+    let mut chars = key.chars();
+    let Some(first) = chars.next() else {
+        return SharedString::default();
+    };
+    let rest = chars.as_str();
+    let is_function_key = first.eq_ignore_ascii_case(&'f')
+        && !rest.is_empty()
+        && rest.chars().all(|c| c.is_ascii_digit());
+    if rest.is_empty() || is_function_key {
+        key.to_uppercase().into()
+    } else {
+        format!("{}{rest}", first.to_uppercase()).into()
+    }
+}
+
+/// Key caps for a keyboard shortcut.
+///
+/// # Usage
+/// ```ignore
+/// Kbd::new("cmd-shift-p")
+/// Kbd::new("cmd-k cmd-s").platform(KeyPlatform::Linux)
+/// Kbd::for_action("toggle_theme", cx)
+/// ```
+#[derive(IntoElement)]
+pub struct Kbd {
+    id: ElementId,
+    keystroke: SharedString,
+    platform: KeyPlatform,
+    disabled: bool,
+    identifiers: SharedIdentifiers,
+}
+
+impl Kbd {
+    /// Create key caps for a keystroke in GPUI binding syntax.
+    pub fn new(keystroke: impl Into<SharedString>) -> Self {
+        let keystroke = keystroke.into();
+        Self {
+            id: ElementId::Name(format!("kbd-{keystroke}").into()),
+            keystroke,
+            platform: KeyPlatform::current(),
+            disabled: false,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Key caps for the keys currently bound to a registered action, or
+    /// `None` if the action is not in the `ShortcutRegistry`.
+    pub fn for_action(action: &str, cx: &App) -> Option<Self> {
+        ShortcutRegistry::keys_for(action, cx).map(Self::new)
+    }
+
+    /// Override the platform naming conventions.
+    pub fn platform(mut self, platform: KeyPlatform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Render with muted, disabled text (e.g. beside a disabled menu item).
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the component contract for Kbd.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::kbd()
+    }
+}
+
+impl WithIdentifiers for Kbd {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Kbd", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let cap_bg = theme.chrome.toolbar_background;
+        let cap_border = theme.border.default;
+        let label_color = if self.disabled {
+            theme.text.disabled
+        } else {
+            theme.text.muted
+        };
+
+        let mut row = div().flex().flex_row().items_center().gap_1().flex_none();
+        for chord in self.keystroke.split_whitespace() {
+            let mut caps = div().flex().flex_row().items_center().gap_0p5();
+            for label in keystroke_labels(chord, self.platform) {
+                caps = caps.child(
+                    div()
+                        .min_w(px(18.0))
+                        .h(px(18.0))
+                        .px_1()
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .bg(cap_bg)
+                        .border_1()
+                        .border_color(cap_border)
+                        .rounded_sm()
+                        .text_xs()
+                        .text_color(label_color)
+                        .child(label),
+                );
+            }
+            row = row.child(caps);
+        }
+
+        row.inspectable(&self.id, Self::contract)
+    }
+}
@@ -1,36 +1,225 @@
 #![recursion_limit = "2048"]
 
+#[cfg(feature = "gpui")]
+pub mod avatar;
+#[cfg(feature = "gpui")]
+pub mod badge;
+#[cfg(feature = "gpui")]
+pub mod breadcrumbs;
+#[cfg(feature = "gpui")]
 pub mod button;
+#[cfg(feature = "gpui")]
+pub mod card;
+pub mod catalog;
+#[cfg(feature = "gpui")]
+pub mod chart;
+#[cfg(feature = "gpui")]
 pub mod checkbox;
+#[cfg(feature = "gpui")]
+pub mod command_palette;
+#[cfg(feature = "gpui")]
+pub mod context_menu;
 pub mod contracts;
+#[cfg(feature = "gpui")]
+pub mod data_table;
+#[cfg(feature = "gpui")]
+pub mod description_list;
+#[cfg(feature = "gpui")]
 pub mod dialog;
+#[cfg(feature = "gpui")]
 pub mod dropdown_menu;
+#[cfg(feature = "gpui")]
+pub mod empty_state;
+#[cfg(feature = "gpui")]
+pub mod file_picker;
+#[cfg(feature = "gpui")]
+pub mod form;
+#[cfg(feature = "gpui")]
+pub mod identifiers;
+#[cfg(feature = "gpui")]
 pub mod input;
+#[cfg(feature = "gpui")]
+pub mod inspect;
+#[cfg(feature = "gpui")]
+pub mod kbd;
+#[cfg(feature = "gpui")]
+pub mod markdown;
+#[cfg(feature = "gpui")]
+pub mod menu_bar;
+#[cfg(feature = "gpui")]
+pub mod notification_center;
+#[cfg(feature = "gpui")]
+pub mod number_input;
+#[cfg(feature = "gpui")]
+pub mod password_input;
+#[cfg(feature = "gpui")]
 pub mod popover;
+#[cfg(feature = "gpui")]
+pub mod progress;
+#[cfg(feature = "gpui")]
 pub mod radio;
+#[cfg(feature = "gpui")]
+pub mod scroll_area;
+#[cfg(feature = "gpui")]
+pub mod search_input;
+#[cfg(feature = "gpui")]
 pub mod select;
+#[cfg(feature = "gpui")]
+pub mod separator;
+#[cfg(feature = "gpui")]
+pub mod skeleton;
+#[cfg(feature = "gpui")]
+pub mod slider;
+#[cfg(feature = "gpui")]
+pub mod sortable_list;
+#[cfg(feature = "gpui")]
+pub mod split_pane;
+#[cfg(feature = "gpui")]
+pub mod status_bar;
+#[cfg(feature = "gpui")]
+pub mod switch;
+#[cfg(feature = "gpui")]
 pub mod tabs;
+#[cfg(feature = "gpui")]
 pub mod textarea;
+#[cfg(feature = "gpui")]
+pub mod title_bar;
+#[cfg(feature = "gpui")]
 pub mod toast;
+#[cfg(feature = "gpui")]
 pub mod tooltip;
+#[cfg(feature = "gpui")]
+pub mod typography;
 
+#[cfg(feature = "gpui")]
+pub use avatar::{
+    Avatar, AvatarGroup, AvatarSize, AvatarStatus, avatar_group_split, avatar_initials,
+};
+#[cfg(feature = "gpui")]
+pub use badge::{Badge, BadgeSize, BadgeStatus, Tag, badge_count_label};
+#[cfg(feature = "gpui")]
+pub use breadcrumbs::{BreadcrumbSegment, Breadcrumbs, collapse_breadcrumbs};
+#[cfg(feature = "gpui")]
 pub use button::{Button, ButtonSize, ButtonVariant, IconPosition};
+#[cfg(feature = "gpui")]
+pub use card::Card;
+#[cfg(feature = "gpui")]
+pub use chart::{Bar, BarChart, Sparkline, bar_fractions, normalize_range};
+#[cfg(feature = "gpui")]
 pub use checkbox::Checkbox;
+#[cfg(feature = "gpui")]
+pub use command_palette::{CommandItem, CommandPalette, PaletteCallback, command_matches};
+#[cfg(feature = "gpui")]
+pub use context_menu::{
+    ContextMenu, ContextMenuCallback, ContextMenuItem, ContextMenuItemKind, MenuPath,
+};
 pub use contracts::{
-    AcceptanceChecklist, ComponentContract, ComponentState, ContractBuilder, Disposition,
-    InteractionChecklist, PerfEvidence, PropDef, SharedIdentifiers, TokenRef, ValidationError,
+    AcceptanceChecklist, BudgetOverrun, ComponentContract, ComponentState, ContractBuilder,
+    DEFAULT_FRAME_BUDGET_MS, Disposition, FrameBudgetEvidence, InteractionChecklist, KeyBindingDef,
+    PerfBudget, PerfEvidence, PropDef, PropRename, SharedIdentifiers, Stability, TokenRef,
+    ValidationError, match_locale,
+};
+#[cfg(feature = "gpui")]
+pub use data_table::{
+    Column, ColumnAlign, ColumnSort, DataTable, SelectionMode, SortDirection, TableRow,
+    compare_cells, next_sort, select_row, sorted_row_order,
 };
+#[cfg(feature = "gpui")]
+pub use description_list::{DescriptionItem, DescriptionLayout, DescriptionList};
+#[cfg(feature = "gpui")]
 pub use dialog::Dialog;
-pub use dropdown_menu::{DropdownMenu, MenuItem};
+#[cfg(feature = "gpui")]
+pub use dropdown_menu::{DropdownMenu, MenuItem, MenuItemKind, toggle_item};
+#[cfg(feature = "gpui")]
+pub use empty_state::{EmptyState, EmptyStateSize};
+#[cfg(feature = "gpui")]
+pub use file_picker::{
+    FileDialog, FileFilter, FilePicker, FilePickerTrigger, MockFileDialog, PickResult,
+    PlatformFileDialog, accepts_path, merge_picked, prompt_options, rejection_message,
+};
+#[cfg(feature = "gpui")]
+pub use form::{Form, FormField, aggregate_validation, error_summary};
+#[cfg(feature = "gpui")]
+pub use identifiers::{AccessibilityNode, AccessibilityTree, WithIdentifiers};
+#[cfg(feature = "gpui")]
 pub use input::{Input, InputSize};
+#[cfg(feature = "gpui")]
+pub use inspect::{InspectNode, InspectTree, Inspectable};
+#[cfg(feature = "gpui")]
+pub use kbd::{Kbd, KeyPlatform, key_label, keystroke_labels};
+#[cfg(feature = "gpui")]
+pub use markdown::{
+    CodeHighlight, Markdown, MarkdownBlock, MarkdownInline, highlight_code, is_safe_url,
+    parse_inlines, parse_markdown,
+};
+#[cfg(feature = "gpui")]
+pub use menu_bar::{MenuBar, MenuBarMenu, MenuBarState};
+#[cfg(feature = "gpui")]
+pub use notification_center::{
+    Notification, NotificationCenter, NotificationHistory, day_label, day_number,
+};
+#[cfg(feature = "gpui")]
+pub use number_input::{NumberInput, NumberRange, parse_number, validate_number};
+#[cfg(feature = "gpui")]
+pub use password_input::{
+    PasswordInput, PasswordRule, PasswordStrength, password_strength, unmet_rules,
+    validate_password,
+};
+#[cfg(feature = "gpui")]
 pub use popover::Popover;
+#[cfg(feature = "gpui")]
+pub use progress::{
+    PROGRESS_TRANSITION, ProgressBar, ProgressSize, ProgressStatus, SPINNER_PERIOD, Spinner,
+    arc_points, progress_fraction, progress_percent,
+};
+#[cfg(feature = "gpui")]
 pub use radio::{Radio, RadioItem};
+#[cfg(feature = "gpui")]
+pub use scroll_area::{SCROLLBAR_SIZE, ScrollArea, ScrollAreaHandle, ScrollAxes};
+#[cfg(feature = "gpui")]
+pub use search_input::{SEARCH_DEBOUNCE, SearchInput, result_count_label};
+#[cfg(feature = "gpui")]
 pub use select::{Select, SelectItem};
+#[cfg(feature = "gpui")]
+pub use separator::Separator;
+#[cfg(feature = "gpui")]
+pub use skeleton::{SKELETON_PERIOD, Skeleton, SkeletonShape, shimmer_opacity};
+#[cfg(feature = "gpui")]
+pub use slider::{SLIDER_PAGE_STEPS, Slider, SliderRange, fraction_at};
+#[cfg(feature = "gpui")]
+pub use sortable_list::{SortableItem, SortableList};
+#[cfg(feature = "gpui")]
+pub use split_pane::{DIVIDER_SIZE, SplitPane};
+#[cfg(feature = "gpui")]
+pub use status_bar::{StatusBar, StatusBarSlot, StatusCallback, StatusIndicator, StatusSegment};
+#[cfg(feature = "gpui")]
+pub use switch::{SWITCH_TRANSITION, Switch, SwitchSize, thumb_offset};
+#[cfg(feature = "gpui")]
 pub use tabs::{TabItem, Tabs};
+#[cfg(feature = "gpui")]
 pub use textarea::Textarea;
-pub use toast::{Toast, ToastVariant};
-pub use tooltip::{Tooltip, TooltipPlacement};
+#[cfg(feature = "gpui")]
+pub use title_bar::{TRAFFIC_LIGHT_INSET, TRAFFIC_LIGHT_POSITION, TitleBar, TitleBarPlatform};
+#[cfg(feature = "gpui")]
+pub use toast::{
+    TOAST_AUTO_DISMISS, Toast, ToastCallback, ToastEntry, ToastLayer, ToastManager, ToastPosition,
+    ToastVariant,
+};
+#[cfg(feature = "gpui")]
+pub use tooltip::{
+    TOOLTIP_HOVER_DELAY, Tooltip, TooltipHover, TooltipPlacement, TooltipView, WithTooltip,
+};
+#[cfg(feature = "gpui")]
+pub use typography::{LABEL_FOR_KEY, Label, Link, Text, TextSize, TextWeight};
 
-pub fn init(_cx: &mut gpui::App) {
-    // Component initialization will register all components here.
+#[cfg(feature = "gpui")]
+pub fn init(cx: &mut gpui::App) {
+    // Components publish SharedIdentifiers metadata here as they render.
+    cx.set_global(AccessibilityTree::default());
+    // Components record size and padding here while the studio's inspect mode is on.
+    cx.set_global(InspectTree::default());
+    // Closed toasts are kept here for the NotificationCenter panel.
+    cx.set_global(NotificationHistory::default());
+    // App-wide toasts, shown with `ToastManager::show` and drawn by a `ToastLayer`.
+    cx.set_global(ToastManager::default());
 }
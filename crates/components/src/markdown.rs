@@ -0,0 +1,534 @@
+//! Markdown component: renders a safe Markdown subset for notes and docs.
+//!
+//! Rewrite disposition: written for the workbench; no external Markdown or
+//! highlighting dependency.
+//!
+//! Supported constructs: ATX headings (`#` to `######`), paragraphs,
+//! `**strong**`, `*emphasis*`/`_emphasis_`, `` `code` `` spans, fenced code
+//! blocks, ordered and unordered lists, and `[links](https://...)`. Anything
+//! else (raw HTML, images, tables) renders as literal text. Links only open
+//! for `http`, `https`, and `mailto` URLs.
+
+use std::ops::Range;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Font family for code spans and blocks.
+const CODE_FONT: &str = "monospace";
+
+/// An inline run of Markdown text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownInline {
+    /// Plain text.
+    Text(String),
+    /// `**strong**` text.
+    Strong(String),
+    /// `*emphasis*` text.
+    Emphasis(String),
+    /// `` `code` `` span.
+    Code(String),
+    /// `[text](url)` link.
+    Link { text: String, url: String },
+}
+
+impl MarkdownInline {
+    /// The visible text of this run.
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Text(text) | Self::Strong(text) | Self::Emphasis(text) | Self::Code(text) => text,
+            Self::Link { text, .. } => text,
+        }
+    }
+}
+
+/// A block-level Markdown element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkdownBlock {
+    /// `#` heading with level 1-6.
+    Heading {
+        level: u8,
+        content: Vec<MarkdownInline>,
+    },
+    /// Paragraph of inline content; consecutive lines are joined with spaces.
+    Paragraph(Vec<MarkdownInline>),
+    /// Ordered (`1.`) or unordered (`-`, `*`, `+`) list.
+    List {
+        ordered: bool,
+        items: Vec<Vec<MarkdownInline>>,
+    },
+    /// Fenced code block with an optional language tag.
+    CodeBlock {
+        language: Option<String>,
+        code: String,
+    },
+}
+
+/// Parse Markdown source into blocks.
+pub fn parse_markdown(source: &str) -> Vec<MarkdownBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut lines = source.lines();
+
+    let flush = |paragraph: &mut Vec<&str>, blocks: &mut Vec<MarkdownBlock>| {
+        if !paragraph.is_empty() {
+            blocks.push(MarkdownBlock::Paragraph(parse_inlines(
+                &paragraph.join(" "),
+            )));
+            paragraph.clear();
+        }
+    };
+
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+
+        if let Some(info) = trimmed.strip_prefix("```") {
+            flush(&mut paragraph, &mut blocks);
+            let language = Some(info.trim())
+                .filter(|l| !l.is_empty())
+                .map(String::from);
+            let code: Vec<&str> = lines.by_ref().take_while(|l| l.trim() != "```").collect();
+            blocks.push(MarkdownBlock::CodeBlock {
+                language,
+                code: code.join("\n"),
+            });
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if let Some((level, text)) = heading(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(MarkdownBlock::Heading {
+                level,
+                content: parse_inlines(text),
+            });
+        } else if let Some((ordered, text)) = list_item(trimmed) {
+            flush(&mut paragraph, &mut blocks);
+            let item = parse_inlines(text);
+            match blocks.last_mut() {
+                Some(MarkdownBlock::List {
+                    ordered: current,
+                    items,
+                }) if *current == ordered => items.push(item),
+                _ => blocks.push(MarkdownBlock::List {
+                    ordered,
+                    items: vec![item],
+                }),
+            }
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+/// `# Title` -> `(1, "Title")`.
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then(|| (level as u8, text.trim()))
+}
+
+/// `- item` -> `(false, "item")`, `2. item` -> `(true, "item")`.
+fn list_item(line: &str) -> Option<(bool, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(text) = line.strip_prefix(marker) {
+            return Some((false, text.trim()));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .map(|text| (true, text.trim()))
+}
+
+/// Parse inline runs. Unclosed markers are kept as literal text.
+pub fn parse_inlines(text: &str) -> Vec<MarkdownInline> {
+    let mut inlines = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut previous: Option<char> = None;
+
+    while let Some(c) = rest.chars().next() {
+        let parsed = match c {
+            '\\' => {
+                let mut chars = rest[1..].chars();
+                let escaped = chars.next().unwrap_or('\\');
+                plain.push(escaped);
+                previous = Some(escaped);
+                rest = chars.as_str();
+                continue;
+            }
+            '`' => delimited(rest, "`").map(|(code, tail)| (MarkdownInline::Code(code), tail)),
+            '*' if rest.starts_with("**") => {
+                delimited(rest, "**").map(|(text, tail)| (MarkdownInline::Strong(text), tail))
+            }
+            // `snake_case` words are not emphasis.
+            '_' if previous.is_some_and(char::is_alphanumeric) => None,
+            '*' | '_' => delimited(rest, &rest[..1])
+                .map(|(text, tail)| (MarkdownInline::Emphasis(text), tail)),
+            '[' => link(rest),
+            _ => None,
+        };
+        match parsed {
+            Some((inline, tail)) => {
+                if !plain.is_empty() {
+                    inlines.push(MarkdownInline::Text(std::mem::take(&mut plain)));
+                }
+                inlines.push(inline);
+                rest = tail;
+                previous = None;
+            }
+            None => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+                previous = Some(c);
+            }
+        }
+    }
+    if !plain.is_empty() {
+        inlines.push(MarkdownInline::Text(plain));
+    }
+    inlines
+}
+
+/// Split `"<d>inner<d>tail"` into `("inner", "tail")` for a non-empty inner.
+fn delimited<'a>(text: &'a str, delimiter: &str) -> Option<(String, &'a str)> {
+    let body = &text[delimiter.len()..];
+    let end = body.find(delimiter).filter(|end| *end > 0)?;
+    Some((body[..end].to_string(), &body[end + delimiter.len()..]))
+}
+
+/// Parse `[text](url)` at the start of `text`.
+fn link(text: &str) -> Option<(MarkdownInline, &str)> {
+    let close = text.find("](")?;
+    let url_end = text[close + 2..].find(')')? + close + 2;
+    let inline = MarkdownInline::Link {
+        text: text[1..close].to_string(),
+        url: text[close + 2..url_end].trim().to_string(),
+    };
+    Some((inline, &text[url_end + 1..]))
+}
+
+/// Whether a link URL may be opened (no `javascript:`, `file:`, etc.).
+pub fn is_safe_url(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    ["https://", "http://", "mailto:"]
+        .iter()
+        .any(|scheme| lower.starts_with(scheme))
+}
+
+// ---------------------------------------------------------------------------
+// Code highlighting
+// ---------------------------------------------------------------------------
+
+/// Highlight class for a range of code, mapped to a `syntax.*` token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodeHighlight {
+    Keyword,
+    String,
+    Number,
+    Comment,
+    Function,
+    Type,
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while",
+];
+
+/// Lexically classify ranges of `code` for highlighting.
+///
+/// A small C-family lexer (line comments, double-quoted strings, numbers,
+/// Rust keywords, calls, and capitalized type names); unclassified text is
+/// omitted from the result.
+pub fn highlight_code(code: &str) -> Vec<(Range<usize>, CodeHighlight)> {
+    let bytes = code.as_bytes();
+    let mut highlights = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i];
+        if c == b'/' && bytes.get(i + 1) == Some(&b'/') {
+            i = code[i..].find('\n').map_or(bytes.len(), |end| i + end);
+            highlights.push((start..i, CodeHighlight::Comment));
+        } else if c == b'"' {
+            i += 1;
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(bytes.len());
+            highlights.push((start..i, CodeHighlight::String));
+        } else if c.is_ascii_digit() {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'.') {
+                i += 1;
+            }
+            highlights.push((start..i, CodeHighlight::Number));
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            let word = &code[start..i];
+            let kind = if KEYWORDS.contains(&word) {
+                Some(CodeHighlight::Keyword)
+            } else if bytes.get(i) == Some(&b'(') || bytes.get(i) == Some(&b'!') {
+                Some(CodeHighlight::Function)
+            } else if c.is_ascii_uppercase() {
+                Some(CodeHighlight::Type)
+            } else {
+                None
+            };
+            if let Some(kind) = kind {
+                highlights.push((start..i, kind));
+            }
+        } else {
+            // Skip a whole UTF-8 character so ranges stay on char boundaries.
+            i += code[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    highlights
+}
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Renders a safe Markdown subset with theme tokens.
+///
+/// # Usage
+/// ```ignore
+/// Markdown::new("story-notes", "## Review\n\n- Focus ring uses `border.focused`")
+/// ```
+#[derive(IntoElement)]
+pub struct Markdown {
+    id: ElementId,
+    source: SharedString,
+    identifiers: SharedIdentifiers,
+}
+
+impl Markdown {
+    /// Create a Markdown view of `source`.
+    pub fn new(id: impl Into<ElementId>, source: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            source: source.into(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Returns the component contract for Markdown.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::markdown()
+    }
+}
+
+impl WithIdentifiers for Markdown {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+/// Colors used while rendering, resolved once from the theme.
+#[derive(Clone, Copy)]
+struct MarkdownColors {
+    text: Hsla,
+    muted: Hsla,
+    link: Hsla,
+    code_background: Hsla,
+    code_border: Hsla,
+    keyword: Hsla,
+    string: Hsla,
+    number: Hsla,
+    comment: Hsla,
+    function: Hsla,
+    type_name: Hsla,
+}
+
+impl MarkdownColors {
+    fn highlight(&self, kind: CodeHighlight) -> Hsla {
+        match kind {
+            CodeHighlight::Keyword => self.keyword,
+            CodeHighlight::String => self.string,
+            CodeHighlight::Number => self.number,
+            CodeHighlight::Comment => self.comment,
+            CodeHighlight::Function => self.function,
+            CodeHighlight::Type => self.type_name,
+        }
+    }
+}
+
+/// Render inline runs as one styled text, with clickable link ranges.
+fn render_inlines(
+    id: ElementId,
+    inlines: &[MarkdownInline],
+    colors: MarkdownColors,
+) -> InteractiveText {
+    let mut text = String::new();
+    let mut highlights = Vec::new();
+    let mut links: Vec<(Range<usize>, String)> = Vec::new();
+
+    for inline in inlines {
+        let start = text.len();
+        text.push_str(inline.text());
+        let range = start..text.len();
+        let style = match inline {
+            MarkdownInline::Text(_) => continue,
+            MarkdownInline::Strong(_) => HighlightStyle {
+                font_weight: Some(FontWeight::BOLD),
+                ..Default::default()
+            },
+            MarkdownInline::Emphasis(_) => HighlightStyle {
+                font_style: Some(FontStyle::Italic),
+                ..Default::default()
+            },
+            MarkdownInline::Code(_) => HighlightStyle {
+                color: Some(colors.string),
+                background_color: Some(colors.code_background),
+                ..Default::default()
+            },
+            MarkdownInline::Link { url, .. } => {
+                links.push((range.clone(), url.clone()));
+                HighlightStyle {
+                    color: Some(colors.link),
+                    underline: Some(UnderlineStyle {
+                        thickness: px(1.0),
+                        color: Some(colors.link),
+                        wavy: false,
+                    }),
+                    ..Default::default()
+                }
+            }
+        };
+        highlights.push((range, style));
+    }
+
+    let styled = StyledText::new(text).with_highlights(highlights);
+    let (ranges, urls): (Vec<_>, Vec<_>) = links.into_iter().unzip();
+    InteractiveText::new(id, styled).on_click(ranges, move |index, _window, cx| {
+        if let Some(url) = urls.get(index).filter(|url| is_safe_url(url)) {
+            cx.open_url(url);
+        }
+    })
+}
+
+impl RenderOnce for Markdown {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Markdown", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let colors = MarkdownColors {
+            text: theme.text.default,
+            muted: theme.text.muted,
+            link: theme.text.accent,
+            code_background: theme.element.background,
+            code_border: theme.border.variant,
+            keyword: theme.syntax.keyword,
+            string: theme.syntax.string,
+            number: theme.syntax.number,
+            comment: theme.syntax.comment,
+            function: theme.syntax.function,
+            type_name: theme.syntax.type_name,
+        };
+
+        let block_id = |part: String| ElementId::Name(format!("{}-{part}", self.id).into());
+
+        let mut view = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .gap_2()
+            .text_sm()
+            .text_color(colors.text);
+
+        for (idx, block) in parse_markdown(&self.source).into_iter().enumerate() {
+            view = match block {
+                MarkdownBlock::Heading { level, content } => {
+                    let heading = div().font_weight(FontWeight::BOLD).child(render_inlines(
+                        block_id(format!("h-{idx}")),
+                        &content,
+                        colors,
+                    ));
+                    view.child(match level {
+                        1 => heading.text_xl(),
+                        2 => heading.text_lg(),
+                        3 => heading.text_base(),
+                        _ => heading.text_sm(),
+                    })
+                }
+                MarkdownBlock::Paragraph(content) => view.child(render_inlines(
+                    block_id(format!("p-{idx}")),
+                    &content,
+                    colors,
+                )),
+                MarkdownBlock::List { ordered, items } => {
+                    let mut list = div().flex().flex_col().gap_1().pl_2();
+                    for (item_idx, item) in items.iter().enumerate() {
+                        let marker = if ordered {
+                            format!("{}.", item_idx + 1)
+                        } else {
+                            "•".to_string()
+                        };
+                        list = list.child(
+                            div()
+                                .flex()
+                                .flex_row()
+                                .gap_2()
+                                .child(div().flex_none().text_color(colors.muted).child(marker))
+                                .child(render_inlines(
+                                    block_id(format!("li-{idx}-{item_idx}")),
+                                    item,
+                                    colors,
+                                )),
+                        );
+                    }
+                    view.child(list)
+                }
+                MarkdownBlock::CodeBlock { language, code } => {
+                    let highlights = highlight_code(&code).into_iter().map(|(range, kind)| {
+                        let style = HighlightStyle {
+                            color: Some(colors.highlight(kind)),
+                            ..Default::default()
+                        };
+                        (range, style)
+                    });
+                    let styled = StyledText::new(code.clone()).with_highlights(highlights);
+                    view.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .gap_1()
+                            .p_2()
+                            .rounded_md()
+                            .bg(colors.code_background)
+                            .border_1()
+                            .border_color(colors.code_border)
+                            .font_family(CODE_FONT)
+                            .text_xs()
+                            .when_some(language, |el, language| {
+                                el.child(div().text_color(colors.muted).child(language))
+                            })
+                            .child(styled),
+                    )
+                }
+            };
+        }
+
+        view.inspectable(&self.id, Self::contract)
+    }
+}
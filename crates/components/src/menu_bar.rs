@@ -0,0 +1,511 @@
+//! MenuBar component: a row of menu titles, each opening a dropdown menu
+//! with nested submenus.
+//!
+//! Rewrite disposition: written for the workbench to give apps a File/Edit/
+//! View menu bar. Menus reuse the ContextMenu item model ([`ContextMenuItem`],
+//! [`MenuPath`]) and its menu rendering, so actions, separators, shortcut
+//! hints, and nested submenus look and behave the same in both; DropdownMenu
+//! items are flat and cannot nest.
+//!
+//! The bar is a single focus stop. Arrow Left/Right move between titles, and
+//! while a menu is open they move the open menu along the bar, so one menu
+//! is open at a time. Once a menu is open, hovering another title opens that
+//! one instead.
+
+use std::rc::Rc;
+
+use crate::context_menu::{
+    ContextMenuCallback, ContextMenuItem, MenuColors, MenuHoverCallback, MenuLevel, MenuPath,
+    render_menu,
+};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{
+    FocusReturn, NavDirection, Orientation, classify_nav_key, is_activation_key, is_escape_key,
+    navigate_index,
+};
+use theme::ActiveTheme;
+
+/// One titled menu in a [`MenuBar`].
+#[derive(Debug, Clone)]
+pub struct MenuBarMenu {
+    /// Title shown in the bar.
+    pub title: SharedString,
+    /// Menu items: actions, submenus, and separators.
+    pub items: Vec<ContextMenuItem>,
+    /// Disabled menus are shown but cannot be opened or focused.
+    pub disabled: bool,
+}
+
+impl MenuBarMenu {
+    /// Create an enabled menu.
+    pub fn new(title: impl Into<SharedString>, items: Vec<ContextMenuItem>) -> Self {
+        Self {
+            title: title.into(),
+            items,
+            disabled: false,
+        }
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Which title of a [`MenuBar`] is active and, if its menu is open, which
+/// rows are highlighted and which submenus are open.
+///
+/// The active title is where keyboard focus rests on the bar; only the
+/// active title's menu can be open.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MenuBarState {
+    active: usize,
+    open: Option<MenuPath>,
+}
+
+impl MenuBarState {
+    /// No menu open, the first title active.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the active title.
+    pub fn active(&self) -> usize {
+        self.active
+    }
+
+    /// Index of the open menu, if any.
+    pub fn open_menu(&self) -> Option<usize> {
+        self.open.as_ref().map(|_| self.active)
+    }
+
+    /// Highlighted rows and open submenus of the open menu.
+    pub fn path(&self) -> Option<&MenuPath> {
+        self.open.as_ref()
+    }
+
+    /// Open menu `index` with nothing highlighted, closing any other.
+    /// Disabled menus do not open.
+    pub fn open(&mut self, menus: &[MenuBarMenu], index: usize) {
+        if menus.get(index).is_some_and(|menu| !menu.disabled) {
+            self.active = index;
+            self.open = Some(MenuPath::new());
+        }
+    }
+
+    /// Close the open menu, keeping its title active.
+    pub fn close(&mut self) {
+        self.open = None;
+    }
+
+    /// Pointer moved onto title `index`: while a menu is open, open that
+    /// title's menu instead.
+    pub fn hover_title(&mut self, menus: &[MenuBarMenu], index: usize) {
+        if self.open.is_some() && self.active != index {
+            self.open(menus, index);
+        }
+    }
+
+    /// Move the active title, skipping disabled menus. An open menu moves
+    /// along with it, opening with its first item highlighted.
+    pub fn move_to(&mut self, menus: &[MenuBarMenu], direction: NavDirection) {
+        if menus.iter().all(|menu| menu.disabled) {
+            return;
+        }
+        let next = navigate_index(self.active, direction, menus.len(), |i| menus[i].disabled);
+        if self.open.is_some() {
+            self.open(menus, next);
+            self.navigate(menus, NavDirection::First);
+        } else {
+            self.active = next;
+        }
+    }
+
+    /// Pointer moved onto row `index` of the open menu at `depth`.
+    pub fn hover_item(&mut self, menus: &[MenuBarMenu], depth: usize, index: usize) {
+        if let (Some(path), Some(menu)) = (&mut self.open, menus.get(self.active)) {
+            path.hover(&menu.items, depth, index);
+        }
+    }
+
+    /// Move the highlight in the innermost open menu.
+    pub fn navigate(&mut self, menus: &[MenuBarMenu], direction: NavDirection) {
+        if let (Some(path), Some(menu)) = (&mut self.open, menus.get(self.active)) {
+            path.navigate(&menu.items, direction);
+        }
+    }
+
+    /// Move into the highlighted submenu of the open menu, highlighting its
+    /// first enabled item.
+    ///
+    /// Returns false if there is no submenu to enter.
+    pub fn enter_submenu(&mut self, menus: &[MenuBarMenu]) -> bool {
+        match (&mut self.open, menus.get(self.active)) {
+            (Some(path), Some(menu)) => path.enter_submenu(&menu.items),
+            _ => false,
+        }
+    }
+
+    /// Arrow Right: enter the highlighted submenu, otherwise move to the
+    /// next title.
+    pub fn right(&mut self, menus: &[MenuBarMenu]) {
+        if !self.enter_submenu(menus) {
+            self.move_to(menus, NavDirection::Next);
+        }
+    }
+
+    /// Arrow Left: close the innermost submenu, otherwise move to the
+    /// previous title.
+    pub fn left(&mut self, menus: &[MenuBarMenu]) {
+        if !self.close_submenu() {
+            self.move_to(menus, NavDirection::Previous);
+        }
+    }
+
+    /// Close the innermost submenu of the open menu.
+    ///
+    /// Returns false if no submenu is open.
+    pub fn close_submenu(&mut self) -> bool {
+        self.open.as_mut().is_some_and(MenuPath::close_submenu)
+    }
+
+    /// The highlighted item of the innermost open menu.
+    pub fn highlighted_item<'a>(&self, menus: &'a [MenuBarMenu]) -> Option<&'a ContextMenuItem> {
+        self.open
+            .as_ref()?
+            .highlighted_item(&menus.get(self.active)?.items)
+    }
+}
+
+/// Open state plus where focus goes back to when the menu closes.
+#[derive(Default)]
+struct BarState {
+    bar: MenuBarState,
+    focus_return: Option<FocusReturn>,
+}
+
+/// Apply `f` to the bar state and re-render.
+fn update_bar(state: &Entity<BarState>, cx: &mut App, f: impl FnOnce(&mut MenuBarState)) {
+    state.update(cx, |state, cx| {
+        f(&mut state.bar);
+        cx.notify();
+    });
+}
+
+/// A horizontal row of menu titles, each opening a dropdown menu with
+/// nested submenus, separators, disabled items, and shortcut hints.
+///
+/// Clicking a title opens its menu below it; while a menu is open, hovering
+/// another title switches to it. The bar is one focus stop: arrow keys move
+/// between titles and through the open menu. Escape or a click outside
+/// closes the menu and returns focus to where it was.
+///
+/// # Usage
+/// ```ignore
+/// MenuBar::new("app-menu-bar")
+///     .menu(MenuBarMenu::new("File", vec![
+///         ContextMenuItem::new("New").shortcut("cmd-n").on_select(|_, _| new_file()),
+///         ContextMenuItem::submenu("Open Recent", recent_items()),
+///         ContextMenuItem::separator(),
+///         ContextMenuItem::new("Quit").shortcut("cmd-q"),
+///     ]))
+///     .menu(MenuBarMenu::new("Edit", edit_items()))
+/// ```
+#[derive(IntoElement)]
+pub struct MenuBar {
+    id: ElementId,
+    menus: Vec<MenuBarMenu>,
+    width: Pixels,
+    disabled: bool,
+    identifiers: SharedIdentifiers,
+}
+
+impl MenuBar {
+    /// Create an empty menu bar.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            menus: Vec::new(),
+            width: px(200.0),
+            disabled: false,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Add a menu to the right of the existing ones.
+    pub fn menu(mut self, menu: MenuBarMenu) -> Self {
+        self.menus.push(menu);
+        self
+    }
+
+    /// Add several menus.
+    pub fn menus(mut self, menus: impl IntoIterator<Item = MenuBarMenu>) -> Self {
+        self.menus.extend(menus);
+        self
+    }
+
+    /// Set the width of every menu and submenu.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set whether the whole bar is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the component contract for MenuBar.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::menu_bar()
+    }
+}
+
+impl WithIdentifiers for MenuBar {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for MenuBar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("MenuBar", &self.id, None, &self.identifiers, cx);
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        let state = window.use_keyed_state(key("state"), cx, |_, _| BarState::default());
+        if self.disabled && state.read(cx).bar.path().is_some() {
+            update_bar(&state, cx, MenuBarState::close);
+        }
+        let snapshot = state.read(cx).bar.clone();
+        let focused = focus_handle.is_focused(window);
+
+        let theme = cx.theme();
+        let text = theme.text.default;
+        let disabled_text = theme.text.disabled;
+        let hover_bg = theme.ghost_element.hover;
+        let open_bg = theme.element.hover;
+        let focus_border = theme.border.focused;
+        let colors = MenuColors::new(cx);
+
+        let menus = Rc::new(self.menus);
+        let dismiss: ContextMenuCallback = {
+            let state = state.clone();
+            Rc::new(move |window: &mut Window, cx: &mut App| {
+                let focus_return = state.update(cx, |state, cx| {
+                    state.bar.close();
+                    cx.notify();
+                    state.focus_return.take()
+                });
+                if let Some(focus_return) = focus_return {
+                    focus_return.restore(window, cx);
+                }
+            })
+        };
+
+        let mut bar = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_0p5()
+            .h_7();
+
+        for (index, menu) in menus.iter().enumerate() {
+            let inert = self.disabled || menu.disabled;
+            let is_open = snapshot.open_menu() == Some(index);
+            let is_active = focused && snapshot.active() == index;
+
+            let mut title = div()
+                .id(key(&format!("title-{index}")))
+                .relative()
+                .flex()
+                .items_center()
+                .h_full()
+                .px_2()
+                .rounded_sm()
+                .border_1()
+                .border_color(if is_active && !is_open {
+                    focus_border
+                } else {
+                    Hsla::transparent_black()
+                })
+                .text_sm()
+                .text_color(if inert { disabled_text } else { text })
+                .when(is_open, |el| el.bg(open_bg))
+                .child(menu.title.clone());
+
+            if inert {
+                title = title.cursor_default();
+            } else {
+                title = title
+                    .cursor_pointer()
+                    .when(!is_open, |el| el.hover(move |s| s.bg(hover_bg)))
+                    .on_mouse_down(MouseButton::Left, {
+                        let state = state.clone();
+                        let menus = menus.clone();
+                        let dismiss = dismiss.clone();
+                        let focus_handle = focus_handle.clone();
+                        move |_event, window, cx| {
+                            cx.stop_propagation();
+                            if is_open {
+                                dismiss(window, cx);
+                                return;
+                            }
+                            let focus_return = FocusReturn::capture(window, cx);
+                            state.update(cx, |state, cx| {
+                                // Switching menus keeps the focus captured by the first open.
+                                if state.focus_return.is_none() {
+                                    state.focus_return = Some(focus_return);
+                                }
+                                state.bar.open(&menus, index);
+                                cx.notify();
+                            });
+                            window.focus(&focus_handle, cx);
+                        }
+                    })
+                    .on_hover({
+                        let state = state.clone();
+                        let menus = menus.clone();
+                        move |hovered, _window, cx| {
+                            if *hovered {
+                                update_bar(&state, cx, |bar| bar.hover_title(&menus, index));
+                            }
+                        }
+                    });
+            }
+
+            if let Some(path) = snapshot.path().filter(|_| is_open) {
+                let on_hover: MenuHoverCallback = {
+                    let state = state.clone();
+                    let menus = menus.clone();
+                    Rc::new(move |depth, row, cx| {
+                        update_bar(&state, cx, |bar| bar.hover_item(&menus, depth, row));
+                    })
+                };
+                let panel = render_menu(&MenuLevel {
+                    id: key("menu"),
+                    root: Rc::new(menu.items.clone()),
+                    depth: 0,
+                    path,
+                    on_hover: &on_hover,
+                    dismiss: &dismiss,
+                    width: self.width,
+                    colors,
+                });
+                // Left-aligned with the title, just below the bar.
+                title = title.child(
+                    div().absolute().top(relative(1.0)).left_0().child(
+                        deferred(
+                            anchored()
+                                .offset(point(px(0.0), px(2.0)))
+                                .snap_to_window_with_margin(px(8.0))
+                                .child(panel),
+                        )
+                        .with_priority(2),
+                    ),
+                );
+            }
+
+            bar = bar.child(title);
+        }
+
+        if self.disabled {
+            return bar.inspectable(&self.id, Self::contract);
+        }
+
+        bar = bar.track_focus(&focus_handle).on_key_down({
+            let state = state.clone();
+            let menus = menus.clone();
+            let dismiss = dismiss.clone();
+            move |event, window, cx| {
+                let current = state.read(cx).bar.clone();
+                let key = event.keystroke.key.as_str();
+                let vertical = classify_nav_key(event, Orientation::Vertical);
+                if current.path().is_none() {
+                    if key == keys::ARROW_DOWN || key == keys::ARROW_UP || is_activation_key(event)
+                    {
+                        let highlight = if key == keys::ARROW_UP {
+                            NavDirection::Last
+                        } else {
+                            NavDirection::First
+                        };
+                        update_bar(&state, cx, |bar| {
+                            bar.open(&menus, bar.active());
+                            bar.navigate(&menus, highlight);
+                        });
+                    } else if let Some(direction) = classify_nav_key(event, Orientation::Horizontal)
+                    {
+                        update_bar(&state, cx, |bar| bar.move_to(&menus, direction));
+                    } else {
+                        return;
+                    }
+                } else if let Some(direction) = vertical {
+                    update_bar(&state, cx, |bar| bar.navigate(&menus, direction));
+                } else if key == keys::ARROW_RIGHT {
+                    update_bar(&state, cx, |bar| bar.right(&menus));
+                } else if key == keys::ARROW_LEFT {
+                    update_bar(&state, cx, |bar| bar.left(&menus));
+                } else if is_escape_key(event) {
+                    let mut closed_submenu = false;
+                    update_bar(&state, cx, |bar| closed_submenu = bar.close_submenu());
+                    if !closed_submenu {
+                        dismiss(window, cx);
+                    }
+                } else if is_activation_key(event) {
+                    match current.highlighted_item(&menus) {
+                        Some(item) if item.opens_submenu() => {
+                            update_bar(&state, cx, |bar| {
+                                bar.enter_submenu(&menus);
+                            });
+                        }
+                        Some(item) if !item.is_inert() => {
+                            dismiss(window, cx);
+                            if let Some(on_select) = &item.on_select {
+                                on_select(window, cx);
+                            }
+                        }
+                        _ => {}
+                    }
+                } else {
+                    return;
+                }
+                cx.stop_propagation();
+            }
+        });
+
+        if snapshot.path().is_some() {
+            // Window-sized layer under the open menu: a click anywhere else
+            // closes it. It does not occlude, so titles still take hover and
+            // clicks; their handlers reopen the menu they belong to.
+            let backdrop = anchored().position(Point::default()).child(
+                div()
+                    .id(key("backdrop"))
+                    .size(window.viewport_size())
+                    .on_any_mouse_down(move |_event, window, cx| dismiss(window, cx)),
+            );
+            bar = bar.child(deferred(backdrop).with_priority(1));
+        }
+
+        bar.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,539 @@
+//! NotificationCenter component: reviewable history of dismissed toasts.
+//!
+//! Rewrite disposition: no upstream notification history exists, so this is
+//! built directly on internal tokens and the Toast data model.
+//!
+//! [`NotificationHistory`] is an app-wide global that retains toasts once they
+//! close. Owners forward the entries returned by
+//! [`ToastManager::finish_transitions`](crate::ToastManager::finish_transitions)
+//! with [`NotificationHistory::record_closed`]. [`NotificationCenter`] renders
+//! the history as a panel grouped by day, with clear-all, per-item actions,
+//! and per-item removal.
+
+use std::cmp::Reverse;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::toast::{ToastEntry, ToastVariant};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::{Locale, format_relative_time};
+use theme::ActiveTheme;
+
+/// Seconds in a day; history is grouped by UTC calendar day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// A closed toast kept in the [`NotificationHistory`].
+#[derive(Clone, Debug)]
+pub struct Notification {
+    /// Unique within the history; toast ids repeat (e.g. progress updates).
+    pub key: u64,
+    /// The toast as it was when it closed.
+    pub entry: ToastEntry,
+    /// When the toast was raised: its timestamp, or when it was recorded.
+    pub raised_at: SystemTime,
+    /// Whether the notification has been seen in the panel.
+    pub read: bool,
+}
+
+/// App-wide history of closed toasts, newest first.
+///
+/// Keeps at most `capacity` notifications; the oldest are dropped first.
+/// The history lives in memory only and can be turned off with
+/// [`set_recording`](Self::set_recording).
+#[derive(Debug)]
+pub struct NotificationHistory {
+    items: Vec<Notification>,
+    next_key: u64,
+    capacity: usize,
+    recording: bool,
+}
+
+impl Global for NotificationHistory {}
+
+impl Default for NotificationHistory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NotificationHistory {
+    /// Default number of retained notifications.
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// Create an empty history retaining up to 100 notifications.
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            next_key: 0,
+            capacity: Self::DEFAULT_CAPACITY,
+            recording: true,
+        }
+    }
+
+    /// Set how many notifications are retained (at least one).
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Record closed toasts in the app-wide history.
+    ///
+    /// Does nothing (and notifies no observers) if `entries` is empty or
+    /// recording is off.
+    pub fn record_closed(entries: impl IntoIterator<Item = ToastEntry>, cx: &mut App) {
+        let mut entries = entries.into_iter().peekable();
+        let recording = cx
+            .try_global::<NotificationHistory>()
+            .is_none_or(|history| history.recording);
+        if !recording || entries.peek().is_none() {
+            return;
+        }
+        let now = SystemTime::now();
+        let history = cx.default_global::<NotificationHistory>();
+        for entry in entries {
+            history.record(entry, now);
+        }
+    }
+
+    /// Add an unread notification for a closed toast.
+    pub fn record(&mut self, entry: ToastEntry, now: SystemTime) -> u64 {
+        let key = self.next_key;
+        self.next_key += 1;
+        let raised_at = entry.timestamp.unwrap_or(now);
+        self.items.insert(
+            0,
+            Notification {
+                key,
+                entry,
+                raised_at,
+                read: false,
+            },
+        );
+        self.items.truncate(self.capacity);
+        key
+    }
+
+    /// Whether [`record_closed`](Self::record_closed) keeps closed toasts.
+    pub fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Turn recording on or off. Turning it off also forgets every
+    /// notification already kept.
+    pub fn set_recording(&mut self, recording: bool) {
+        self.recording = recording;
+        if !recording {
+            self.items.clear();
+        }
+    }
+
+    /// Notifications, newest recorded first.
+    pub fn iter(&self) -> impl Iterator<Item = &Notification> {
+        self.items.iter()
+    }
+
+    /// Look up a notification by key.
+    pub fn get(&self, key: u64) -> Option<&Notification> {
+        self.items.iter().find(|n| n.key == key)
+    }
+
+    /// Number of notifications not yet seen.
+    pub fn unread_count(&self) -> usize {
+        self.items.iter().filter(|n| !n.read).count()
+    }
+
+    /// Mark one notification as seen. Returns false if the key is unknown.
+    pub fn mark_read(&mut self, key: u64) -> bool {
+        match self.items.iter_mut().find(|n| n.key == key) {
+            Some(notification) => {
+                notification.read = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Mark every notification as seen.
+    pub fn mark_all_read(&mut self) {
+        for notification in &mut self.items {
+            notification.read = true;
+        }
+    }
+
+    /// Remove one notification. Returns false if the key is unknown.
+    pub fn remove(&mut self, key: u64) -> bool {
+        let len = self.items.len();
+        self.items.retain(|n| n.key != key);
+        self.items.len() != len
+    }
+
+    /// Remove every notification.
+    pub fn clear_all(&mut self) {
+        self.items.clear();
+    }
+
+    /// Number of retained notifications.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if no notifications are retained.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Notifications grouped by the day they were raised ([`day_number`]),
+    /// newest day first and newest first within each day.
+    pub fn by_day(&self) -> Vec<(u64, Vec<&Notification>)> {
+        let mut sorted: Vec<&Notification> = self.items.iter().collect();
+        sorted.sort_by_key(|n| Reverse(n.raised_at));
+        let mut groups: Vec<(u64, Vec<&Notification>)> = Vec::new();
+        for notification in sorted {
+            let day = day_number(notification.raised_at);
+            match groups.last_mut() {
+                Some((last, items)) if *last == day => items.push(notification),
+                _ => groups.push((day, vec![notification])),
+            }
+        }
+        groups
+    }
+}
+
+/// UTC calendar day of `at`, counted from 1970-01-01 (earlier times count
+/// as day 0).
+pub fn day_number(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+        / SECONDS_PER_DAY
+}
+
+/// Heading for a day group: "Today", "Yesterday", or the date as
+/// `YYYY-MM-DD`. Days after `today` (a clock set back) count as today.
+pub fn day_label(day: u64, today: u64) -> String {
+    match today.saturating_sub(day) {
+        0 => "Today".to_string(),
+        1 => "Yesterday".to_string(),
+        _ => civil_date(day),
+    }
+}
+
+/// Format a count of days since 1970-01-01 as a proleptic Gregorian date.
+fn civil_date(days: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm, shifted to March-based years.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Panel listing the app's [`NotificationHistory`], grouped by day.
+///
+/// Reads and edits the global history directly, so every panel in the app
+/// shows the same notifications. Rendering does not mark anything read;
+/// the owner calls [`NotificationHistory::mark_all_read`] when it opens the
+/// panel.
+///
+/// # Usage
+/// ```ignore
+/// NotificationCenter::new("notifications").width(px(320.0))
+/// ```
+#[derive(IntoElement)]
+pub struct NotificationCenter {
+    id: ElementId,
+    width: Pixels,
+    empty_message: SharedString,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl NotificationCenter {
+    /// Create a notification center panel.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            width: px(320.0),
+            empty_message: "No notifications".into(),
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the panel width.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the text shown while the history is empty.
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.empty_message = message.into();
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for NotificationCenter.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::notification_center()
+    }
+}
+
+impl WithIdentifiers for NotificationCenter {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+/// Apply `edit` to the global history and redraw the window.
+fn edit_history(window: &mut Window, cx: &mut App, edit: impl FnOnce(&mut NotificationHistory)) {
+    edit(cx.default_global::<NotificationHistory>());
+    window.refresh();
+}
+
+impl RenderOnce for NotificationCenter {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "NotificationCenter",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let theme = cx.theme();
+        let title_color = theme.text.default;
+        let muted_color = theme.text.muted;
+        let unread_color = theme.text.accent;
+        let hover_bg = theme.ghost_element.hover;
+        let key = |suffix: String| SharedString::from(format!("{}-{suffix}", self.id));
+
+        let history = cx.try_global::<NotificationHistory>();
+        let unread = history.map_or(0, NotificationHistory::unread_count);
+        let groups = history.map(NotificationHistory::by_day).unwrap_or_default();
+        let now = SystemTime::now();
+        let today = day_number(now);
+        let locale = Locale::global(cx);
+
+        // Header: title, unread count, clear all
+        let mut header = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .px_3()
+            .py_2()
+            .border_b_1()
+            .border_color(theme.border.default)
+            .child(
+                div()
+                    .flex_1()
+                    .text_sm()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(title_color)
+                    .child("Notifications"),
+            );
+        if unread > 0 {
+            header = header.child(
+                div()
+                    .text_xs()
+                    .text_color(unread_color)
+                    .child(format!("{unread} unread")),
+            );
+        }
+        if !groups.is_empty() {
+            header = header.child(
+                div()
+                    .id(key("clear-all".into()))
+                    .cursor_pointer()
+                    .rounded_sm()
+                    .px_1()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .hover(move |s| s.bg(hover_bg))
+                    .on_click(|_event, window, cx| {
+                        edit_history(window, cx, NotificationHistory::clear_all);
+                    })
+                    .child("Clear all"),
+            );
+        }
+
+        let mut list = div()
+            .id(key("list".into()))
+            .flex()
+            .flex_col()
+            .flex_1()
+            .overflow_y_scroll();
+
+        if groups.is_empty() {
+            list = list.child(
+                div()
+                    .p_4()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child(self.empty_message),
+            );
+        }
+
+        for (day, notifications) in groups {
+            list = list.child(
+                div()
+                    .px_3()
+                    .pt_3()
+                    .pb_1()
+                    .text_xs()
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(muted_color)
+                    .child(day_label(day, today)),
+            );
+            for notification in notifications {
+                let entry = &notification.entry;
+                let item_key = notification.key;
+                let accent = match entry.variant {
+                    ToastVariant::Info => theme.status.info.foreground,
+                    ToastVariant::Success => theme.status.success.foreground,
+                    ToastVariant::Warning => theme.status.warning.foreground,
+                    ToastVariant::Error => theme.status.error.foreground,
+                };
+
+                let mut content = div().flex().flex_col().flex_1().gap_1().child(
+                    div()
+                        .text_sm()
+                        .text_color(title_color)
+                        .when(!notification.read, |el| el.font_weight(FontWeight::MEDIUM))
+                        .child(entry.title.clone()),
+                );
+                if let Some(desc) = &entry.description {
+                    content =
+                        content.child(div().text_xs().text_color(muted_color).child(desc.clone()));
+                }
+                // A clock set back counts as just now.
+                let elapsed = now
+                    .duration_since(notification.raised_at)
+                    .unwrap_or(Duration::ZERO);
+                content = content.child(
+                    div()
+                        .text_xs()
+                        .text_color(muted_color)
+                        .child(format_relative_time(elapsed, locale)),
+                );
+                if let Some((label, handler)) = entry.action.clone() {
+                    content = content.child(
+                        div()
+                            .id(key(format!("action-{item_key}")))
+                            .cursor_pointer()
+                            .self_start()
+                            .rounded_sm()
+                            .px_1()
+                            .text_xs()
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(accent)
+                            .hover(move |s| s.bg(hover_bg))
+                            .on_click(move |_event, window, cx| {
+                                cx.stop_propagation();
+                                handler(window, cx);
+                                edit_history(window, cx, |history| {
+                                    history.mark_read(item_key);
+                                });
+                            })
+                            .child(label),
+                    );
+                }
+
+                let row = div()
+                    .id(key(format!("item-{item_key}")))
+                    .flex()
+                    .flex_row()
+                    .items_start()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .hover(move |s| s.bg(hover_bg))
+                    .on_click(move |_event, window, cx| {
+                        edit_history(window, cx, |history| {
+                            history.mark_read(item_key);
+                        });
+                    })
+                    .child(
+                        div()
+                            .w(px(6.0))
+                            .h(px(6.0))
+                            .mt(px(7.0))
+                            .flex_shrink_0()
+                            .rounded_full()
+                            .when(!notification.read, |el| el.bg(unread_color)),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(accent)
+                            .font_weight(FontWeight::BOLD)
+                            .flex_shrink_0()
+                            .child(entry.variant.icon()),
+                    )
+                    .child(content)
+                    .child(
+                        div()
+                            .id(key(format!("remove-{item_key}")))
+                            .cursor_pointer()
+                            .rounded_sm()
+                            .p(px(2.0))
+                            .flex_shrink_0()
+                            .text_xs()
+                            .text_color(muted_color)
+                            .hover(move |s| s.bg(hover_bg))
+                            .on_click(move |_event, window, cx| {
+                                cx.stop_propagation();
+                                edit_history(window, cx, |history| {
+                                    history.remove(item_key);
+                                });
+                            })
+                            .child("✕"),
+                    );
+                list = list.child(row);
+            }
+        }
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .w(self.width)
+            .max_h_full()
+            .bg(theme.surface.elevated_surface)
+            .border_1()
+            .border_color(theme.border.default)
+            .rounded_md()
+            .overflow_hidden()
+            .child(header)
+            .child(list)
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,533 @@
+//! NumberInput component: numeric field with stepper buttons, keyboard
+//! stepping, and range validation.
+//!
+//! Rewrite disposition: composed for the workbench from the Input visuals plus
+//! the shared text editing and state primitives.
+//!
+//! Provenance:
+//! - Visual treatment follows the internal Input component.
+//! - Modifications: the text is a draft over a `ControlledState<f64>`; only
+//!   numbers inside the range reach `on_change`, others set
+//!   `ValidationState::Error`. Stepping (buttons, Up/Down) snaps to the step
+//!   and clamps to the range; pure math lives in `NumberRange`.
+
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{
+    ControlledState, TEXT_INPUT_KEY_CONTEXT, TextInput, TextInputColors, TextInputElement,
+    ValidationState,
+};
+use theme::ActiveTheme;
+
+/// Most decimal places a value is shown with.
+const MAX_DECIMALS: usize = 6;
+
+/// Decimal places needed to show `value`, up to [`MAX_DECIMALS`].
+fn decimals_of(value: f64) -> usize {
+    let mut decimals = 0;
+    let mut scaled = value;
+    while decimals < MAX_DECIMALS && (scaled - scaled.round()).abs() > 1e-6 {
+        scaled *= 10.0;
+        decimals += 1;
+    }
+    decimals
+}
+
+/// Round `value` to `decimals` places, dropping the float noise of repeated
+/// steps (`0.1 + 0.2`).
+fn round_to(value: f64, decimals: usize) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    (value * scale).round() / scale
+}
+
+/// The values a [`NumberInput`] accepts: `min..=max`, stepped by `step`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberRange {
+    /// Smallest value; `f64::NEG_INFINITY` for none.
+    pub min: f64,
+    /// Largest value; `f64::INFINITY` for none.
+    pub max: f64,
+    /// Increment of the stepper buttons and Up/Down.
+    pub step: f64,
+}
+
+impl Default for NumberRange {
+    fn default() -> Self {
+        Self::new(f64::NEG_INFINITY, f64::INFINITY, 1.0)
+    }
+}
+
+impl NumberRange {
+    /// Create a range, swapping reversed bounds and treating a step that is
+    /// not positive as `1.0`.
+    pub fn new(min: f64, max: f64, step: f64) -> Self {
+        Self {
+            min: min.min(max),
+            max: max.max(min),
+            step: if step > 0.0 && step.is_finite() {
+                step
+            } else {
+                1.0
+            },
+        }
+    }
+
+    /// Clamp `value` into the range.
+    pub fn clamp(&self, value: f64) -> f64 {
+        value.clamp(self.min, self.max)
+    }
+
+    /// Decimal places of the step grid, e.g. 2 for a step of `0.25`.
+    pub fn decimals(&self) -> usize {
+        let base = if self.min.is_finite() { self.min } else { 0.0 };
+        decimals_of(self.step).max(decimals_of(base))
+    }
+
+    /// Move `value` by `steps` increments on the grid of `min + n * step`
+    /// (or `n * step` without a minimum), clamped to the range.
+    ///
+    /// A value between grid points moves to the next one in the step's
+    /// direction: with a step of 1, `1.25` steps up to `2` and down to `1`.
+    pub fn step_by(&self, value: f64, steps: i32) -> f64 {
+        let base = if self.min.is_finite() { self.min } else { 0.0 };
+        let position = (value - base) / self.step;
+        let from = if (position - position.round()).abs() < 1e-9 {
+            position.round()
+        } else if steps > 0 {
+            position.floor()
+        } else {
+            position.ceil()
+        };
+        let stepped = base + (from + steps as f64) * self.step;
+        self.clamp(round_to(stepped, self.decimals()))
+    }
+
+    /// Whether stepping `value` by `steps` would change it.
+    pub fn can_step(&self, value: f64, steps: i32) -> bool {
+        self.step_by(value, steps) != value
+    }
+
+    /// Text for `value`: the step's decimal places, or more if `value` needs them.
+    pub fn format(&self, value: f64) -> String {
+        let decimals = self.decimals().max(decimals_of(value));
+        // `+ 0.0` turns `-0.0` into `0.0`, so zero never shows as "-0".
+        format!("{:.*}", decimals, round_to(value, decimals) + 0.0)
+    }
+}
+
+/// The number `text` holds, if any. Surrounding whitespace is ignored and
+/// `.` is the decimal separator; infinities and NaN are not numbers.
+pub fn parse_number(text: &str) -> Option<f64> {
+    text.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+/// Validate `text` against `range`.
+///
+/// Empty text is not validated: the field keeps its last value. Text that is
+/// not a number or falls outside the range is an error, with a message such
+/// as "Must be at most 10".
+pub fn validate_number(text: &str, range: &NumberRange) -> (ValidationState, Option<String>) {
+    if text.trim().is_empty() {
+        return (ValidationState::None, None);
+    }
+    let message = match parse_number(text) {
+        None => "Enter a number".to_string(),
+        Some(value) if value < range.min => format!("Must be at least {}", range.format(range.min)),
+        Some(value) if value > range.max => format!("Must be at most {}", range.format(range.max)),
+        Some(_) => return (ValidationState::None, None),
+    };
+    (ValidationState::Error, Some(message))
+}
+
+/// A numeric field with −/+ stepper buttons, Up/Down stepping, and
+/// validation against a min/max range.
+///
+/// Typing updates the value whenever the text is a number in range; anything
+/// else shows an error and leaves the value alone. Enter (or a step) rewrites
+/// the text from the value, clamping a number typed outside the range.
+///
+/// # Usage
+/// ```ignore
+/// NumberInput::new("quantity")
+///     .min(1.0)
+///     .max(99.0)
+///     .value(quantity)
+///     .on_change(|value, _window, _cx| {
+///         println!("Quantity: {value}");
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct NumberInput {
+    id: ElementId,
+    value: ControlledState<f64>,
+    min: f64,
+    max: f64,
+    step: f64,
+    placeholder: SharedString,
+    error_message: Option<SharedString>,
+    disabled: bool,
+    full_width: bool,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl NumberInput {
+    /// Create a new unbounded number input stepping by 1.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: ControlledState::new(),
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            placeholder: SharedString::default(),
+            error_message: None,
+            disabled: false,
+            full_width: false,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Control the value from the parent.
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = self.value.value(value);
+        self
+    }
+
+    /// Set the initial value for uncontrolled use.
+    pub fn default_value(mut self, value: f64) -> Self {
+        self.value = self.value.default_value(value);
+        self
+    }
+
+    /// Set the change handler.
+    pub fn on_change(mut self, handler: impl Fn(&f64, &mut Window, &mut App) + 'static) -> Self {
+        self.value = self.value.on_change(handler);
+        self
+    }
+
+    /// Set the smallest accepted value.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the largest accepted value.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the increment of the stepper buttons and Up/Down.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the placeholder text shown while the field is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set an error message, e.g. from the server, shown instead of range errors.
+    pub fn error_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.error_message = Some(message.into());
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Make the input take full width.
+    pub fn full_width(mut self) -> Self {
+        self.full_width = true;
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for NumberInput.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::number_input()
+    }
+}
+
+impl WithIdentifiers for NumberInput {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+/// Callback moving the value by a number of steps.
+type StepCallback = Rc<dyn Fn(i32, &mut Window, &mut App)>;
+
+impl RenderOnce for NumberInput {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "NumberInput",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let range = NumberRange::new(self.min, self.max, self.step);
+        let value = self.value.bind(self.id.clone(), window, cx);
+        let current = *value.current();
+        let disabled = self.disabled;
+
+        // Text typed since the last step or Enter. It is dropped once the
+        // value moves away from it, e.g. when a controlled parent rejects it.
+        let draft = window.use_keyed_state(key("draft"), cx, |_, _| None::<SharedString>);
+        let text = match draft.read(cx).clone() {
+            Some(text)
+                if validate_number(&text, &range).0.is_error()
+                    || parse_number(&text).is_none_or(|typed| typed == current) =>
+            {
+                text
+            }
+            _ => SharedString::from(range.format(current)),
+        };
+
+        let (validation, range_message) = validate_number(&text, &range);
+        let error = self.error_message.is_some() || validation.is_error();
+        let message = self
+            .error_message
+            .or_else(|| range_message.map(SharedString::from));
+
+        let text_input = window.use_keyed_state(key("text"), cx, |_, cx| TextInput::new(cx));
+        text_input.update(cx, |input, _| {
+            let bound = value.clone();
+            let draft = draft.clone();
+            input.sync(
+                &text,
+                !disabled,
+                Some(Rc::new(move |text, window, cx| {
+                    draft.update(cx, |draft, cx| {
+                        *draft = Some(SharedString::from(text.to_string()));
+                        cx.notify();
+                    });
+                    if !validate_number(text, &range).0.is_error()
+                        && let Some(typed) = parse_number(text)
+                    {
+                        bound.set(typed, window, cx);
+                    }
+                })),
+                None,
+            );
+        });
+        let focus_handle = text_input.read(cx).focus_handle(cx);
+
+        // Stepping starts from the typed number, if any, and clears the draft.
+        let from = parse_number(&text).unwrap_or(current);
+        let step: StepCallback = {
+            let bound = value.clone();
+            let draft = draft.clone();
+            Rc::new(move |steps, window, cx| {
+                draft.update(cx, |draft, cx| {
+                    *draft = None;
+                    cx.notify();
+                });
+                bound.set(range.step_by(from, steps), window, cx);
+            })
+        };
+        // Enter clamps a typed number into the range; other text is reverted.
+        let commit = {
+            let bound = value.clone();
+            let draft = draft.clone();
+            move |window: &mut Window, cx: &mut App| {
+                draft.update(cx, |draft, cx| {
+                    *draft = None;
+                    cx.notify();
+                });
+                bound.set(range.clamp(from), window, cx);
+            }
+        };
+
+        let theme = cx.theme();
+        let is_focused = focus_handle.is_focused(window);
+        let (bg, text_color, placeholder_color) = if disabled {
+            (
+                theme.element.disabled,
+                theme.text.disabled,
+                theme.text.disabled,
+            )
+        } else {
+            (
+                theme.element.background,
+                theme.text.default,
+                theme.text.placeholder,
+            )
+        };
+        let border_color = if disabled {
+            theme.border.disabled
+        } else if error {
+            theme.status.error.border
+        } else if is_focused {
+            theme.border.focused
+        } else {
+            theme.border.default
+        };
+        let hover_border = if error {
+            theme.status.error.border
+        } else {
+            theme.border.focused
+        };
+        let colors = TextInputColors {
+            text: text_color,
+            placeholder: placeholder_color,
+            cursor: theme.text.accent,
+            selection: theme.element.selected,
+        };
+        let stepper_color = theme.text.muted;
+        let stepper_disabled = theme.text.disabled;
+        let stepper_hover = theme.ghost_element.hover;
+        let divider_color = theme.border.variant;
+        let error_text_color = theme.status.error.foreground;
+
+        let mut field = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .h(px(32.0))
+            .pl_3()
+            .bg(bg)
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .text_sm()
+            .map(|el| {
+                if self.full_width {
+                    el.w_full()
+                } else {
+                    el.min_w(px(160.0))
+                }
+            });
+
+        if !disabled {
+            let handle = focus_handle.clone();
+            let mouse_input = text_input.clone();
+            let key_input = text_input.clone();
+            let key_step = step.clone();
+            field = field
+                .track_focus(&focus_handle)
+                .key_context(TEXT_INPUT_KEY_CONTEXT)
+                .hover(move |s| s.border_color(hover_border))
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    window.focus(&handle, cx);
+                    mouse_input.update(cx, |input, cx| {
+                        input.move_to_point(event.position, event.modifiers.shift, cx)
+                    });
+                })
+                .on_key_down(move |event, window, cx| {
+                    // While composing, the input method owns every key.
+                    if !key_input.read(cx).state().is_composing() {
+                        let handled = match event.keystroke.key.as_str() {
+                            keys::ARROW_UP => {
+                                key_step(1, window, cx);
+                                true
+                            }
+                            keys::ARROW_DOWN => {
+                                key_step(-1, window, cx);
+                                true
+                            }
+                            keys::ENTER => {
+                                commit(window, cx);
+                                true
+                            }
+                            _ => false,
+                        };
+                        if handled {
+                            cx.stop_propagation();
+                            return;
+                        }
+                    }
+                    if key_input.update(cx, |input, cx| input.handle_key_down(event, window, cx)) {
+                        cx.stop_propagation();
+                    }
+                });
+        }
+
+        field = field.child(
+            div()
+                .flex_1()
+                .overflow_x_hidden()
+                .child(TextInputElement::new(text_input, self.placeholder, colors)),
+        );
+
+        // Stepper buttons. They are not tab stops: Up/Down step from the field.
+        let stepper = |suffix: &str, glyph: &'static str, steps: i32| {
+            let enabled = !disabled && range.can_step(from, steps);
+            let step = step.clone();
+            let handle = focus_handle.clone();
+            div()
+                .id(key(suffix))
+                .flex()
+                .items_center()
+                .justify_center()
+                .w(px(24.0))
+                .h_full()
+                .border_l_1()
+                .border_color(divider_color)
+                .text_color(if enabled {
+                    stepper_color
+                } else {
+                    stepper_disabled
+                })
+                .child(glyph)
+                .when(enabled, |el| {
+                    el.cursor_pointer()
+                        .hover(move |s| s.bg(stepper_hover))
+                        .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                            cx.stop_propagation();
+                            window.focus(&handle, cx);
+                            step(steps, window, cx);
+                        })
+                })
+        };
+        field = field
+            .child(stepper("decrement", "−", -1))
+            .child(stepper("increment", "+", 1));
+
+        let mut wrapper = div().flex().flex_col().gap_1();
+        if self.full_width {
+            wrapper = wrapper.w_full();
+        }
+        wrapper = wrapper.child(field);
+
+        if let Some(message) = message {
+            wrapper = wrapper.child(div().text_xs().text_color(error_text_color).child(message));
+        }
+
+        wrapper.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,534 @@
+//! PasswordInput component: masked single-line input with reveal toggle,
+//! strength meter, and rule-based validation.
+//!
+//! Rewrite disposition: composed for the workbench from the Input visuals plus
+//! the shared text editing and state primitives.
+//!
+//! Provenance:
+//! - Visual treatment follows the internal Input component.
+//! - Modifications: masks the text through the `TextInput` primitive, adds a
+//!   Show/Hide toggle, a four-segment strength meter mapped to status tokens,
+//!   and validation messages listing the unmet `PasswordRule`s.
+
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::{
+    ControlledState, TEXT_INPUT_KEY_CONTEXT, TextInput, TextInputColors, TextInputElement,
+    ValidationState, is_activation_key,
+};
+use theme::ActiveTheme;
+
+/// Character painted in place of each password character.
+const MASK: char = '•';
+
+/// A requirement a password is checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordRule {
+    /// At least this many characters.
+    MinLength(usize),
+    /// At least one lowercase letter.
+    Lowercase,
+    /// At least one uppercase letter.
+    Uppercase,
+    /// At least one ASCII digit.
+    Digit,
+    /// At least one character that is not a letter, digit, or whitespace.
+    Symbol,
+}
+
+impl PasswordRule {
+    /// The rules a PasswordInput checks unless configured otherwise.
+    pub const DEFAULT: [PasswordRule; 5] = [
+        PasswordRule::MinLength(8),
+        PasswordRule::Lowercase,
+        PasswordRule::Uppercase,
+        PasswordRule::Digit,
+        PasswordRule::Symbol,
+    ];
+
+    /// Returns true if `password` satisfies the rule.
+    pub fn is_met(self, password: &str) -> bool {
+        match self {
+            PasswordRule::MinLength(min) => password.chars().count() >= min,
+            PasswordRule::Lowercase => password.chars().any(char::is_lowercase),
+            PasswordRule::Uppercase => password.chars().any(char::is_uppercase),
+            PasswordRule::Digit => password.chars().any(|c| c.is_ascii_digit()),
+            PasswordRule::Symbol => password
+                .chars()
+                .any(|c| !c.is_alphanumeric() && !c.is_whitespace()),
+        }
+    }
+
+    /// What the rule asks for, as used in validation messages.
+    pub fn label(self) -> String {
+        match self {
+            PasswordRule::MinLength(min) => format!("at least {min} characters"),
+            PasswordRule::Lowercase => "a lowercase letter".into(),
+            PasswordRule::Uppercase => "an uppercase letter".into(),
+            PasswordRule::Digit => "a digit".into(),
+            PasswordRule::Symbol => "a symbol".into(),
+        }
+    }
+}
+
+/// How many of its rules a password meets, in four steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    /// Fewer than half the rules met, or too short.
+    Weak,
+    /// At least half the rules met.
+    Fair,
+    /// At least three quarters of the rules met.
+    Good,
+    /// Every rule met.
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Label shown next to the meter.
+    pub fn label(self) -> &'static str {
+        match self {
+            PasswordStrength::Weak => "Weak",
+            PasswordStrength::Fair => "Fair",
+            PasswordStrength::Good => "Good",
+            PasswordStrength::Strong => "Strong",
+        }
+    }
+
+    /// Number of filled meter segments, 1 through 4.
+    pub fn segments(self) -> usize {
+        self as usize + 1
+    }
+}
+
+/// Rate `password` against `rules`; `None` while it is empty.
+///
+/// A password that fails a [`PasswordRule::MinLength`] rule is `Weak` however
+/// many other rules it meets.
+pub fn password_strength(password: &str, rules: &[PasswordRule]) -> Option<PasswordStrength> {
+    if password.is_empty() {
+        return None;
+    }
+    let unmet = unmet_rules(password, rules);
+    if unmet.is_empty() {
+        return Some(PasswordStrength::Strong);
+    }
+    if unmet
+        .iter()
+        .any(|rule| matches!(rule, PasswordRule::MinLength(_)))
+    {
+        return Some(PasswordStrength::Weak);
+    }
+    let met = (rules.len() - unmet.len()) as f32 / rules.len() as f32;
+    Some(if met >= 0.75 {
+        PasswordStrength::Good
+    } else if met >= 0.5 {
+        PasswordStrength::Fair
+    } else {
+        PasswordStrength::Weak
+    })
+}
+
+/// The rules `password` does not meet, in order.
+pub fn unmet_rules(password: &str, rules: &[PasswordRule]) -> Vec<PasswordRule> {
+    rules
+        .iter()
+        .copied()
+        .filter(|rule| !rule.is_met(password))
+        .collect()
+}
+
+/// Validate `password` against `rules`.
+///
+/// Empty passwords are not validated: the error appears once typing starts.
+/// The message lists every unmet rule, e.g. "Password needs an uppercase
+/// letter and a digit".
+pub fn validate_password(
+    password: &str,
+    rules: &[PasswordRule],
+) -> (ValidationState, Option<String>) {
+    if password.is_empty() {
+        return (ValidationState::None, None);
+    }
+    let labels: Vec<String> = unmet_rules(password, rules)
+        .into_iter()
+        .map(PasswordRule::label)
+        .collect();
+    let needs = match labels.as_slice() {
+        [] => return (ValidationState::None, None),
+        [only] => only.clone(),
+        [rest @ .., last] => format!("{} and {last}", rest.join(", ")),
+    };
+    (
+        ValidationState::Error,
+        Some(format!("Password needs {needs}")),
+    )
+}
+
+/// A masked password field with a Show/Hide toggle, a strength meter, and
+/// validation against configurable rules.
+///
+/// # Usage
+/// ```ignore
+/// PasswordInput::new("new-password")
+///     .placeholder("New password")
+///     .rules([PasswordRule::MinLength(12), PasswordRule::Digit])
+///     .on_change(|password, _window, _cx| {
+///         println!("{} characters", password.len());
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct PasswordInput {
+    id: ElementId,
+    value: ControlledState<SharedString>,
+    revealed: ControlledState<bool>,
+    placeholder: SharedString,
+    rules: Vec<PasswordRule>,
+    show_meter: bool,
+    error_message: Option<SharedString>,
+    disabled: bool,
+    full_width: bool,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl PasswordInput {
+    /// Create a new empty password input checking [`PasswordRule::DEFAULT`].
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: ControlledState::new(),
+            revealed: ControlledState::new(),
+            placeholder: "Password".into(),
+            rules: PasswordRule::DEFAULT.to_vec(),
+            show_meter: true,
+            error_message: None,
+            disabled: false,
+            full_width: false,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Control the password from the parent.
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = self.value.value(value.into());
+        self
+    }
+
+    /// Set the initial password for uncontrolled use.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = self.value.default_value(value.into());
+        self
+    }
+
+    /// Set the change handler.
+    pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.value = self
+            .value
+            .on_change(move |value, window, cx| handler(value, window, cx));
+        self
+    }
+
+    /// Control whether the password is shown in plain text.
+    pub fn revealed(mut self, revealed: bool) -> Self {
+        self.revealed = self.revealed.value(revealed);
+        self
+    }
+
+    /// Set whether the password starts revealed for uncontrolled use.
+    pub fn default_revealed(mut self, revealed: bool) -> Self {
+        self.revealed = self.revealed.default_value(revealed);
+        self
+    }
+
+    /// Set the handler fired when the Show/Hide toggle is used.
+    pub fn on_reveal_change(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.revealed = self.revealed.on_change(handler);
+        self
+    }
+
+    /// Set the placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the rules the password is rated and validated against.
+    pub fn rules(mut self, rules: impl IntoIterator<Item = PasswordRule>) -> Self {
+        self.rules = rules.into_iter().collect();
+        self
+    }
+
+    /// Show or hide the strength meter (shown by default).
+    pub fn show_meter(mut self, show: bool) -> Self {
+        self.show_meter = show;
+        self
+    }
+
+    /// Set an error message, e.g. from the server, shown instead of rule errors.
+    pub fn error_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.error_message = Some(message.into());
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Make the input take full width.
+    pub fn full_width(mut self) -> Self {
+        self.full_width = true;
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for PasswordInput.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::password_input()
+    }
+}
+
+impl WithIdentifiers for PasswordInput {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for PasswordInput {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "PasswordInput",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let value = self.value.bind(self.id.clone(), window, cx);
+        let revealed_state = self.revealed.bind(key("revealed"), window, cx);
+        let revealed = *revealed_state.current();
+        let password = value.current().clone();
+        let disabled = self.disabled;
+
+        let text_input = window.use_keyed_state(key("text"), cx, |_, cx| TextInput::new(cx));
+        text_input.update(cx, |input, cx| {
+            let bound = value.clone();
+            input.sync(
+                &password,
+                !disabled,
+                Some(Rc::new(move |text, window, cx| {
+                    bound.set(SharedString::from(text.to_string()), window, cx)
+                })),
+                None,
+            );
+            input.set_mask((!revealed).then_some(MASK), cx);
+        });
+        let focus_handle = text_input.read(cx).focus_handle(cx);
+        let toggle_focus = window
+            .use_keyed_state(key("reveal-focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+
+        let strength = password_strength(&password, &self.rules);
+        let (validation, rule_message) = validate_password(&password, &self.rules);
+        let error = self.error_message.is_some() || validation.is_error();
+        let message = self
+            .error_message
+            .or_else(|| rule_message.map(SharedString::from));
+
+        let theme = cx.theme();
+        let is_focused = focus_handle.is_focused(window);
+        let (bg, text_color, placeholder_color) = if disabled {
+            (
+                theme.element.disabled,
+                theme.text.disabled,
+                theme.text.disabled,
+            )
+        } else {
+            (
+                theme.element.background,
+                theme.text.default,
+                theme.text.placeholder,
+            )
+        };
+        let border_color = if disabled {
+            theme.border.disabled
+        } else if error {
+            theme.status.error.border
+        } else if is_focused {
+            theme.border.focused
+        } else {
+            theme.border.default
+        };
+        let hover_border = if error {
+            theme.status.error.border
+        } else {
+            theme.border.focused
+        };
+        let colors = TextInputColors {
+            text: text_color,
+            placeholder: placeholder_color,
+            cursor: theme.text.accent,
+            selection: theme.element.selected,
+        };
+        let toggle_color = theme.text.muted;
+        let toggle_hover = theme.ghost_element.hover;
+        let muted_color = theme.text.muted;
+        let error_text_color = theme.status.error.foreground;
+        let empty_segment = theme.border.variant;
+        let meter_color = strength.map(|strength| match strength {
+            PasswordStrength::Weak => theme.status.error.foreground,
+            PasswordStrength::Fair => theme.status.warning.foreground,
+            PasswordStrength::Good => theme.status.info.foreground,
+            PasswordStrength::Strong => theme.status.success.foreground,
+        });
+
+        let mut field = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .h(px(32.0))
+            .px_3()
+            .bg(bg)
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .text_sm()
+            .map(|el| {
+                if self.full_width {
+                    el.w_full()
+                } else {
+                    el.min_w(px(240.0))
+                }
+            });
+
+        if !disabled {
+            let handle = focus_handle.clone();
+            let key_handle = focus_handle.clone();
+            let mouse_input = text_input.clone();
+            let key_input = text_input.clone();
+            field = field
+                .track_focus(&focus_handle)
+                .key_context(TEXT_INPUT_KEY_CONTEXT)
+                .hover(move |s| s.border_color(hover_border))
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    window.focus(&handle, cx);
+                    mouse_input.update(cx, |input, cx| {
+                        input.move_to_point(event.position, event.modifiers.shift, cx)
+                    });
+                })
+                .on_key_down(move |event, window, cx| {
+                    // Keys bubbling up from the focused toggle are not edits.
+                    if !key_handle.is_focused(window) {
+                        return;
+                    }
+                    if key_input.update(cx, |input, cx| input.handle_key_down(event, window, cx)) {
+                        cx.stop_propagation();
+                    }
+                });
+        }
+
+        field = field.child(
+            div()
+                .flex_1()
+                .overflow_x_hidden()
+                .child(TextInputElement::new(text_input, self.placeholder, colors)),
+        );
+
+        // Show/Hide toggle
+        field = field.child(
+            div()
+                .id(key("reveal"))
+                .flex_shrink_0()
+                .rounded_sm()
+                .px_1()
+                .text_xs()
+                .text_color(toggle_color)
+                .child(if revealed { "Hide" } else { "Show" })
+                .when(!disabled, |el| {
+                    let click_state = revealed_state.clone();
+                    el.track_focus(&toggle_focus)
+                        .cursor_pointer()
+                        .hover(move |s| s.bg(toggle_hover))
+                        .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                            cx.stop_propagation();
+                            click_state.set(!revealed, window, cx);
+                        })
+                        .on_key_down(move |event, window, cx| {
+                            if is_activation_key(event) {
+                                cx.stop_propagation();
+                                revealed_state.set(!revealed, window, cx);
+                            }
+                        })
+                }),
+        );
+
+        let mut wrapper = div().flex().flex_col().gap_1();
+        if self.full_width {
+            wrapper = wrapper.w_full();
+        }
+        wrapper = wrapper.child(field);
+
+        // Strength meter: four segments filled by strength, then its label.
+        if self.show_meter && !self.rules.is_empty() {
+            let filled = strength.map_or(0, PasswordStrength::segments);
+            let mut meter = div().flex().flex_row().gap_1().flex_1();
+            for segment in 0..4 {
+                let color = match meter_color {
+                    Some(color) if segment < filled => color,
+                    _ => empty_segment,
+                };
+                meter = meter.child(div().flex_1().h(px(4.0)).rounded_full().bg(color));
+            }
+            wrapper = wrapper.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .child(meter)
+                    .child(
+                        div()
+                            .w(px(48.0))
+                            .text_xs()
+                            .text_color(meter_color.unwrap_or(muted_color))
+                            .child(strength.map_or("", PasswordStrength::label)),
+                    ),
+            );
+        }
+
+        if let Some(message) = message {
+            wrapper = wrapper.child(div().text_xs().text_color(error_text_color).child(message));
+        }
+
+        wrapper.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -7,10 +7,20 @@
 //! - Zed `crates/ui/src/components/popover.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - gpui-component popover patterns (MIT, Zed Industries)
 //! - Modifications: Simplified to internal token system, uses internal primitives
-//!   for popover positioning and outside-click dismiss.
+//!   for popover positioning, outside-click dismiss, the shared
+//!   OverlayStateMachine for enter/exit transitions, and the LayerManager
+//!   stack for z-order and Escape routing.
+//!
+//! By default the popover paints where it is laid out. `anchor_to_parent`
+//! floats it out of the layout instead: its `position.attach` corner sits on
+//! its (relatively positioned) parent's `position.anchor` corner, kept inside
+//! the window.
 
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::*;
-use primitives::PopoverPosition;
+use primitives::{
+    Layer, LayerKind, LayerManager, MotionPreference, OverlayStateMachine, PopoverPosition,
+};
 use theme::ActiveTheme;
 
 /// Callback when the popover is dismissed.
@@ -25,17 +35,27 @@ type OnCloseCallback = Box<dyn FnOnce(&mut Window, &mut App) + 'static>;
 ///     .position(PopoverPosition::Below)
 ///     .open(true)
 ///     .child(div().child("Popover content"))
+///
+/// div().relative().child(trigger).child(
+///     Popover::new("callout")
+///         .position(PopoverPosition::beside_right())
+///         .anchor_to_parent()
+///         .open(true)
+///         .child("Attached to the trigger's top-right corner"),
+/// )
 /// ```
 #[derive(IntoElement)]
 pub struct Popover {
     id: ElementId,
-    open: bool,
+    overlay: OverlayStateMachine,
     position: PopoverPosition,
+    anchor_to_parent: bool,
     children: Vec<AnyElement>,
     on_close: Option<OnCloseCallback>,
     width: Option<Pixels>,
     max_height: Pixels,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
 }
 
 impl Popover {
@@ -43,19 +63,28 @@ impl Popover {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            open: false,
+            overlay: OverlayStateMachine::new(),
             position: PopoverPosition::below_left(),
+            anchor_to_parent: false,
             children: Vec::new(),
             on_close: None,
             width: None,
             max_height: px(320.0),
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
         }
     }
 
-    /// Set whether the popover is open.
+    /// Set whether the popover is open (settled, without transition).
     pub fn open(mut self, open: bool) -> Self {
-        self.open = open;
+        self.overlay = OverlayStateMachine::settled(open);
+        self
+    }
+
+    /// Drive the popover from an owner-held overlay state machine, enabling
+    /// enter/exit animation during `Opening`/`Closing`.
+    pub fn overlay_state(mut self, overlay: OverlayStateMachine) -> Self {
+        self.overlay = overlay;
         self
     }
 
@@ -65,6 +94,14 @@ impl Popover {
         self
     }
 
+    /// Attach the popover to its parent, which must be `relative()`, at the
+    /// corners given by [`position`](Self::position), instead of painting it
+    /// where it is laid out.
+    pub fn anchor_to_parent(mut self) -> Self {
+        self.anchor_to_parent = true;
+        self
+    }
+
     /// Add a child element to the popover content.
     pub fn child(mut self, child: impl IntoElement) -> Self {
         self.children.push(child.into_any_element());
@@ -97,43 +134,43 @@ impl Popover {
 
     /// Returns the component contract for Popover.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Popover", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the popover")
-            .optional_prop("open", "bool", "false", "Whether the popover is visible")
-            .optional_prop(
-                "position",
-                "PopoverPosition",
-                "Below",
-                "Placement relative to trigger",
-            )
-            .optional_prop("width", "Option<Pixels>", "None", "Popover width")
-            .optional_prop("max_height", "Pixels", "320.0", "Maximum popover height")
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Open)
-            .state(ComponentState::Hover)
-            .state(ComponentState::Focused)
-            .token_dep("surface.elevated_surface", "Popover background")
-            .token_dep("border.default", "Popover border")
-            .token_dep("text.default", "Popover content text")
-            .focus_behavior(
-                "Focus moves into popover when opened. \
-                 Tab/Shift-Tab cycles within popover content.",
-            )
-            .keyboard_model("Escape dismisses the popover.")
-            .pointer_behavior("Outside click dismisses the popover.")
-            .state_model("Controlled open/close via open prop.")
-            .required_file("crates/components/src/popover.rs")
-            .build()
+        crate::catalog::popover()
+    }
+}
+
+impl WithIdentifiers for Popover {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Popover {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        if !self.open {
+        publish_identifiers(
+            "Popover",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let layer_id = SharedString::from(self.id.to_string());
+        if !self.overlay.is_visible() {
+            LayerManager::unregister(&layer_id, cx);
             return div().into_any_element();
         }
+        let phase = self.overlay.phase();
+        let animation_id = self.id.clone();
+        LayerManager::register(Layer::new(layer_id.clone(), LayerKind::Popover), cx);
+        let layer = LayerManager::state(&layer_id, cx);
 
         let theme = cx.theme();
         let bg = theme.surface.elevated_surface;
@@ -154,18 +191,64 @@ impl RenderOnce for Popover {
             panel = panel.w(w);
         }
 
-        // Escape key dismiss
-        panel = panel.on_key_down(move |event, _window, cx| {
-            if primitives::is_escape_key(event) {
-                cx.stop_propagation();
-            }
-        });
+        // Escape key dismiss when this is the topmost layer (inert while the
+        // exit animation plays or a modal above blocks it)
+        if self.overlay.accepts_input() && !layer.blocked {
+            panel = panel.on_key_down(move |event, window, cx| {
+                if primitives::is_escape_key(event)
+                    && LayerManager::state(&layer_id, cx).escape_target
+                {
+                    LayerManager::dismiss(&layer_id, window, cx);
+                    cx.stop_propagation();
+                }
+            });
+        }
 
         for child in self.children {
             panel = panel.child(child);
         }
 
-        // Use deferred rendering so popover paints on top
-        deferred(panel).with_priority(1).into_any_element()
+        let panel = panel.inspectable(&animation_id, Self::contract);
+
+        // Enter/exit fade while transitioning, unless motion is reduced
+        let panel = if MotionPreference::global(cx).animates(phase.transition_duration()) {
+            panel
+                .with_animation(
+                    ElementId::Name(format!("{}-{:?}", animation_id, phase).into()),
+                    Animation::new(phase.transition_duration()).with_easing(ease_in_out),
+                    move |this, delta| this.opacity(phase.opacity(delta)),
+                )
+                .into_any_element()
+        } else {
+            panel.into_any_element()
+        };
+
+        if !self.anchor_to_parent {
+            // Use deferred rendering so popover paints at its place in the stack
+            return deferred(panel)
+                .with_priority(layer.priority)
+                .into_any_element();
+        }
+
+        // A zero-size point on the parent's anchor corner, with the panel
+        // attached to it by the opposite corner.
+        let anchor = div().absolute().size_0();
+        let anchor = match self.position.anchor {
+            Corner::TopLeft => anchor.top_0().left_0(),
+            Corner::TopRight => anchor.top_0().right_0(),
+            Corner::BottomLeft => anchor.bottom_0().left_0(),
+            Corner::BottomRight => anchor.bottom_0().right_0(),
+        };
+        anchor
+            .child(
+                deferred(
+                    anchored()
+                        .anchor(self.position.attach)
+                        .snap_to_window_with_margin(px(8.0))
+                        .child(panel),
+                )
+                .with_priority(layer.priority),
+            )
+            .into_any_element()
     }
 }
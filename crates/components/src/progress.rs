@@ -0,0 +1,430 @@
+//! Progress indicators: determinate `ProgressBar` and indeterminate `Spinner`.
+//!
+//! Rewrite disposition: token-styled indicators written for the workbench,
+//! sharing the size and status presets. The bar fill is a plain div whose
+//! width eases between values; the spinner strokes an arc on a canvas and
+//! rotates it with a repeating animation.
+
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use primitives::{Locale, MotionPreference, format_integer};
+use theme::ActiveTheme;
+
+/// Duration of the bar fill easing to a new value.
+pub const PROGRESS_TRANSITION: Duration = Duration::from_millis(200);
+
+/// Time for one spinner revolution.
+pub const SPINNER_PERIOD: Duration = Duration::from_millis(900);
+
+/// Fraction of the circle covered by the spinner arc.
+const SPINNER_SWEEP: f32 = 0.25;
+
+/// Line segments per full circle when stroking spinner arcs.
+const SPINNER_SEGMENTS: usize = 48;
+
+/// Size preset shared by [`ProgressBar`] and [`Spinner`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressSize {
+    /// 4px bar, 12px spinner.
+    Small,
+    /// 8px bar, 16px spinner (default).
+    #[default]
+    Medium,
+    /// 12px bar, 24px spinner.
+    Large,
+}
+
+impl ProgressSize {
+    /// Height of the progress bar track.
+    pub fn bar_height(self) -> f32 {
+        match self {
+            ProgressSize::Small => 4.0,
+            ProgressSize::Medium => 8.0,
+            ProgressSize::Large => 12.0,
+        }
+    }
+
+    /// Diameter of the spinner.
+    pub fn spinner_diameter(self) -> f32 {
+        match self {
+            ProgressSize::Small => 12.0,
+            ProgressSize::Medium => 16.0,
+            ProgressSize::Large => 24.0,
+        }
+    }
+}
+
+/// Status color of a progress indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressStatus {
+    /// Work in progress (default).
+    #[default]
+    Info,
+    /// Completed successfully.
+    Success,
+    /// Needs attention but still running.
+    Warning,
+    /// Failed.
+    Error,
+}
+
+impl ProgressStatus {
+    /// Fill or arc color for this status.
+    fn color(self, cx: &App) -> Hsla {
+        let status = &cx.theme().status;
+        match self {
+            ProgressStatus::Info => status.info.foreground,
+            ProgressStatus::Success => status.success.foreground,
+            ProgressStatus::Warning => status.warning.foreground,
+            ProgressStatus::Error => status.error.foreground,
+        }
+    }
+}
+
+/// Clamp a progress value to `0.0..=1.0`; NaN counts as no progress.
+pub fn progress_fraction(value: f32) -> f32 {
+    if value.is_nan() {
+        0.0
+    } else {
+        value.clamp(0.0, 1.0)
+    }
+}
+
+/// Whole percent shown for a progress value, rounded to nearest.
+pub fn progress_percent(value: f32) -> i64 {
+    (progress_fraction(value) * 100.0).round() as i64
+}
+
+/// Points along an arc of the unit circle, starting `start` turns clockwise
+/// from the top and covering `sweep` turns.
+///
+/// Returns `(x, y)` offsets from the center with y pointing down, so they
+/// scale directly into window coordinates.
+pub fn arc_points(start: f32, sweep: f32) -> Vec<(f32, f32)> {
+    let segments = ((SPINNER_SEGMENTS as f32 * sweep.abs()).ceil() as usize).max(1);
+    (0..=segments)
+        .map(|i| {
+            let angle = (start + sweep * i as f32 / segments as f32) * TAU;
+            (angle.sin(), -angle.cos())
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// ProgressBar
+// ---------------------------------------------------------------------------
+
+/// A determinate progress bar for a value between 0 and 1.
+///
+/// Changing the value eases the fill to its new width.
+///
+/// # Usage
+/// ```ignore
+/// ProgressBar::new("export-progress")
+///     .value(0.4)
+///     .label("Exporting tokens")
+///     .show_percent(true)
+/// ```
+#[derive(IntoElement)]
+pub struct ProgressBar {
+    id: ElementId,
+    value: f32,
+    size: ProgressSize,
+    status: ProgressStatus,
+    label: Option<SharedString>,
+    show_percent: bool,
+    width: Option<Pixels>,
+    identifiers: SharedIdentifiers,
+}
+
+impl ProgressBar {
+    /// Create an empty progress bar.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: 0.0,
+            size: ProgressSize::default(),
+            status: ProgressStatus::default(),
+            label: None,
+            show_percent: false,
+            width: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the completed fraction; clamped to `0.0..=1.0`.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = progress_fraction(value);
+        self
+    }
+
+    /// Set the size preset.
+    pub fn size(mut self, size: ProgressSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the status color.
+    pub fn status(mut self, status: ProgressStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set a caption shown above the bar.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Show the value as a percentage above the bar.
+    pub fn show_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
+    }
+
+    /// Set a fixed width (defaults to the full available width).
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Returns the component contract for ProgressBar.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::progress_bar()
+    }
+}
+
+impl WithIdentifiers for ProgressBar {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for ProgressBar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("ProgressBar", &self.id, None, &self.identifiers, cx);
+
+        // (generation, from, to): bumped whenever the value changes between
+        // renders, so the fill eases from the previous value instead of jumping.
+        let value = self.value;
+        let motion = window.use_keyed_state(
+            SharedString::from(format!("{}-motion", self.id)),
+            cx,
+            |_, _| (0usize, value, value),
+        );
+        let (mut generation, mut from, to) = *motion.read(cx);
+        if to != value {
+            generation += 1;
+            from = to;
+            motion.update(cx, |motion, _| *motion = (generation, from, value));
+        }
+
+        let fill_color = self.status.color(cx);
+        let theme = cx.theme();
+        let track_color = theme.element.background;
+        let label_color = theme.text.default;
+        let percent_color = theme.text.muted;
+
+        let fill = div().h_full().rounded_full().bg(fill_color);
+        let fill = if generation > 0
+            && from != value
+            && MotionPreference::global(cx).animates(PROGRESS_TRANSITION)
+        {
+            fill.with_animation(
+                ElementId::Name(format!("{}-fill-{generation}", self.id).into()),
+                Animation::new(PROGRESS_TRANSITION).with_easing(ease_in_out),
+                move |fill, delta| fill.w(relative(from + (value - from) * delta)),
+            )
+            .into_any_element()
+        } else {
+            fill.w(relative(value)).into_any_element()
+        };
+
+        let track = div()
+            .w_full()
+            .h(px(self.size.bar_height()))
+            .rounded_full()
+            .bg(track_color)
+            .overflow_hidden()
+            .child(fill);
+
+        let mut bar = div().id(self.id.clone()).flex().flex_col().gap_1();
+        bar = match self.width {
+            Some(width) => bar.w(width),
+            None => bar.w_full(),
+        };
+
+        if self.label.is_some() || self.show_percent {
+            let mut header = div().flex().flex_row().justify_between().gap_2();
+            header = header.child(
+                div()
+                    .text_xs()
+                    .text_color(label_color)
+                    .child(self.label.unwrap_or_default()),
+            );
+            if self.show_percent {
+                let percent = format_integer(progress_percent(value), Locale::global(cx));
+                header = header.child(
+                    div()
+                        .text_xs()
+                        .text_color(percent_color)
+                        .child(format!("{percent}%")),
+                );
+            }
+            bar = bar.child(header);
+        }
+
+        bar.child(track).inspectable(&self.id, Self::contract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Spinner
+// ---------------------------------------------------------------------------
+
+/// An indeterminate, continuously rotating progress indicator.
+///
+/// # Usage
+/// ```ignore
+/// Spinner::new("loading").label("Loading themes…")
+/// ```
+#[derive(IntoElement)]
+pub struct Spinner {
+    id: ElementId,
+    size: ProgressSize,
+    status: ProgressStatus,
+    label: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Spinner {
+    /// Create a spinner.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            size: ProgressSize::default(),
+            status: ProgressStatus::default(),
+            label: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the size preset.
+    pub fn size(mut self, size: ProgressSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the status color.
+    pub fn status(mut self, status: ProgressStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set a label shown after the spinner.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Returns the component contract for Spinner.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::spinner()
+    }
+}
+
+impl WithIdentifiers for Spinner {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Spinner {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Spinner", &self.id, None, &self.identifiers, cx);
+
+        let arc_color = self.status.color(cx);
+        let theme = cx.theme();
+        let track_color = theme.element.background;
+        let label_color = theme.text.muted;
+
+        let diameter = self.size.spinner_diameter();
+        let stroke = (diameter / 8.0).max(2.0);
+        let wheel = div().size(px(diameter)).flex_none().with_animation(
+            ElementId::Name(format!("{}-spin", self.id).into()),
+            Animation::new(SPINNER_PERIOD).repeat(),
+            move |wheel, delta| {
+                wheel.child(
+                    canvas(
+                        |_, _, _| {},
+                        move |bounds, _, window, _| {
+                            paint_arc(bounds, 0.0, 1.0, stroke, track_color, window);
+                            paint_arc(bounds, delta, SPINNER_SWEEP, stroke, arc_color, window);
+                        },
+                    )
+                    .size_full(),
+                )
+            },
+        );
+
+        let mut spinner = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .child(wheel);
+        if let Some(label) = self.label {
+            spinner = spinner.child(div().text_sm().text_color(label_color).child(label));
+        }
+
+        spinner.inspectable(&self.id, Self::contract)
+    }
+}
+
+/// Stroke an arc of the circle inscribed in `bounds`, inset by half the stroke.
+fn paint_arc(
+    bounds: Bounds<Pixels>,
+    start: f32,
+    sweep: f32,
+    stroke: f32,
+    color: Hsla,
+    window: &mut Window,
+) {
+    let center = bounds.center();
+    let radius = (f32::from(bounds.size.width.min(bounds.size.height)) - stroke) / 2.0;
+    let mut builder = PathBuilder::stroke(px(stroke));
+    for (index, (x, y)) in arc_points(start, sweep).into_iter().enumerate() {
+        let at = point(center.x + px(x * radius), center.y + px(y * radius));
+        if index == 0 {
+            builder.move_to(at);
+        } else {
+            builder.line_to(at);
+        }
+    }
+    if let Ok(path) = builder.build() {
+        window.paint_path(path, color);
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -6,10 +6,12 @@
 //! Provenance:
 //! - gpui-component `crates/ui/src/radio.rs` (MIT, Zed Industries)
 //! - Modifications: Simplified to internal token system, checklist-driven
-//!   keyboard model with arrow key navigation within group.
+//!   keyboard model with arrow key navigation within group, controlled/uncontrolled
+//!   selection via ControlledState.
 
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::*;
-use primitives::Orientation;
+use primitives::{ControlledState, Orientation};
 use theme::ActiveTheme;
 
 /// A single radio option within a group.
@@ -39,9 +41,6 @@ impl RadioItem {
     }
 }
 
-/// Callback when the selected radio changes.
-type OnChangeCallback = Box<dyn Fn(usize, &RadioItem, &mut Window, &mut App) + 'static>;
-
 /// A radio group component with arrow-key navigation and single selection.
 ///
 /// # Usage
@@ -60,11 +59,11 @@ type OnChangeCallback = Box<dyn Fn(usize, &RadioItem, &mut Window, &mut App) + '
 pub struct Radio {
     id: ElementId,
     items: Vec<RadioItem>,
-    selected_index: Option<usize>,
+    selected: ControlledState<Option<usize>>,
     disabled: bool,
     orientation: Orientation,
-    on_change: Option<OnChangeCallback>,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
 }
 
 impl Radio {
@@ -73,17 +72,23 @@ impl Radio {
         Self {
             id: id.into(),
             items,
-            selected_index: None,
+            selected: ControlledState::new(),
             disabled: false,
             orientation: Orientation::Vertical,
-            on_change: None,
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
         }
     }
 
-    /// Set the selected index.
+    /// Control the selected index from the parent.
     pub fn selected_index(mut self, index: usize) -> Self {
-        self.selected_index = Some(index);
+        self.selected = self.selected.value(Some(index));
+        self
+    }
+
+    /// Set the initially selected index for uncontrolled use.
+    pub fn default_selected_index(mut self, index: usize) -> Self {
+        self.selected = self.selected.default_value(Some(index));
         self
     }
 
@@ -104,7 +109,14 @@ impl Radio {
         mut self,
         handler: impl Fn(usize, &RadioItem, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_change = Some(Box::new(handler));
+        let items = self.items.clone();
+        self.selected = self.selected.on_change(move |selected, window, cx| {
+            if let Some(idx) = *selected
+                && let Some(item) = items.get(idx)
+            {
+                handler(idx, item, window, cx);
+            }
+        });
         self
     }
 
@@ -116,73 +128,41 @@ impl Radio {
 
     /// Returns the component contract for Radio.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Radio", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the radio group")
-            .required_prop("items", "Vec<RadioItem>", "Radio options to display")
-            .optional_prop(
-                "selected_index",
-                "Option<usize>",
-                "None",
-                "Currently selected option index",
-            )
-            .optional_prop("disabled", "bool", "false", "Disable the entire group")
-            .optional_prop(
-                "orientation",
-                "Orientation",
-                "Vertical",
-                "Layout: Vertical or Horizontal",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Disabled)
-            .state(ComponentState::Selected)
-            .token_dep("element.background", "Unselected radio circle background")
-            .token_dep("element.hover", "Radio hover background")
-            .token_dep("element.selected", "Selected radio indicator")
-            .token_dep("element.disabled", "Disabled radio background")
-            .token_dep("text.default", "Label text color")
-            .token_dep("text.accent", "Selected indicator color")
-            .token_dep("text.disabled", "Disabled label text color")
-            .token_dep("border.default", "Radio circle border")
-            .token_dep("border.focused", "Focus ring border")
-            .token_dep("border.disabled", "Disabled border")
-            .focus_behavior(
-                "Tab/Shift-Tab moves focus to/from the radio group. \
-                 Only the selected (or first) radio receives tab focus.",
-            )
-            .keyboard_model(
-                "Arrow keys (Up/Down for vertical, Left/Right for horizontal) \
-                 navigate between options, skipping disabled items. \
-                 Space selects the focused option.",
-            )
-            .pointer_behavior(
-                "Click selects an option. Hover shows hover state on individual items.",
-            )
-            .state_model(
-                "Controlled selection via selected_index prop. \
-                 Individual items and entire group can be disabled independently.",
-            )
-            .disabled_behavior(
-                "Disabled group: all items show muted styling. \
-                 Disabled individual items: skip during keyboard navigation.",
-            )
-            .required_file("crates/components/src/radio.rs")
-            .build()
+        crate::catalog::radio()
+    }
+}
+
+impl WithIdentifiers for Radio {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Radio {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Radio",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let selected_state = self.selected.bind(self.id.clone(), window, cx);
+        let selected_index = *selected_state.current();
+
         let theme = cx.theme();
 
         let group_disabled = self.disabled;
-        let selected_index = self.selected_index;
         let items = self.items;
-        let on_change = self.on_change;
         let item_count = items.len();
 
         let mut container = div().id(self.id.clone()).flex().gap_2();
@@ -196,13 +176,15 @@ impl RenderOnce for Radio {
         if !group_disabled {
             let orientation = self.orientation;
             let items_for_nav = items.clone();
-            container = container.on_key_down(move |event, _window, cx| {
+            let selected_state = selected_state.clone();
+            container = container.on_key_down(move |event, window, cx| {
                 let nav = primitives::classify_nav_key(event, orientation);
                 if let Some(dir) = nav {
                     if let Some(current) = selected_index {
-                        let _next = primitives::navigate_index(current, dir, item_count, |i| {
+                        let next = primitives::navigate_index(current, dir, item_count, |i| {
                             items_for_nav.get(i).is_some_and(|item| item.disabled)
                         });
+                        selected_state.set(Some(next), window, cx);
                     }
                     cx.stop_propagation();
                 }
@@ -276,18 +258,9 @@ impl RenderOnce for Radio {
 
             // Click handler for this item
             if !item_disabled {
-                let item_clone = item.clone();
-                let on_change_ref = on_change.as_ref();
-                if let Some(handler) = on_change_ref {
-                    // Clone the handler reference for the closure
-                    let _ = handler;
-                }
-                row = row.on_mouse_down(MouseButton::Left, {
-                    let _ = item_clone;
-                    move |_event, _window, _cx| {
-                        // Selection change is handled via on_change callback
-                        // In a real stateful component, this would update selected_index
-                    }
+                let selected_state = selected_state.clone();
+                row = row.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    selected_state.set(Some(idx), window, cx);
                 });
             }
 
@@ -297,6 +270,6 @@ impl RenderOnce for Radio {
             container = container.child(row);
         }
 
-        container
+        container.inspectable(&self.id, Self::contract)
     }
 }
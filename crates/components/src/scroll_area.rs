@@ -0,0 +1,430 @@
+//! ScrollArea component: overflow content with themed scrollbars.
+//!
+//! Rewrite disposition: GPUI scrolls overflowing content but draws no
+//! scrollbars. ScrollArea overlays scrollbars painted with the `scrollbar.*`
+//! tokens, takes their geometry from the `scroll` primitive, and exposes
+//! programmatic scrolling through [`ScrollAreaHandle`].
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::ScrollMetrics;
+use theme::ActiveTheme;
+
+/// Thickness of a scrollbar track.
+pub const SCROLLBAR_SIZE: f32 = 8.0;
+
+/// Which axes of a [`ScrollArea`] scroll.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAxes {
+    #[default]
+    Vertical,
+    Horizontal,
+    Both,
+}
+
+impl ScrollAxes {
+    fn scrolls(self, axis: Axis) -> bool {
+        matches!(
+            (self, axis),
+            (Self::Both, _)
+                | (Self::Vertical, Axis::Vertical)
+                | (Self::Horizontal, Axis::Horizontal)
+        )
+    }
+}
+
+/// Scrolls a [`ScrollArea`] from code.
+///
+/// Keep one in the owning view and pass it to [`ScrollArea::track_scroll`].
+/// Offsets are positive distances from the start of the content. Scrolling
+/// takes effect on the next frame, so notify the owning view afterwards.
+#[derive(Debug, Clone, Default)]
+pub struct ScrollAreaHandle(ScrollHandle);
+
+impl ScrollAreaHandle {
+    /// Create a handle scrolled to the top.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How far the content is scrolled.
+    pub fn offset(&self) -> Point<Pixels> {
+        let offset = self.0.offset();
+        point(-offset.x, -offset.y)
+    }
+
+    /// The furthest the content could scroll at the last layout.
+    pub fn max_offset(&self) -> Size<Pixels> {
+        self.0.max_offset()
+    }
+
+    /// Scroll to `offset`, clamped to the content.
+    pub fn scroll_to(&self, offset: Point<Pixels>) {
+        self.scroll_axis_to(Axis::Horizontal, f32::from(offset.x));
+        self.scroll_axis_to(Axis::Vertical, f32::from(offset.y));
+    }
+
+    /// Scroll by `delta`, clamped to the content.
+    pub fn scroll_by(&self, delta: Point<Pixels>) {
+        let offset = self.offset();
+        self.scroll_to(point(offset.x + delta.x, offset.y + delta.y));
+    }
+
+    /// Scroll back to the top, keeping the horizontal offset.
+    pub fn scroll_to_top(&self) {
+        self.scroll_axis_to(Axis::Vertical, 0.0);
+    }
+
+    /// Scroll to the bottom once the content has been laid out.
+    pub fn scroll_to_bottom(&self) {
+        self.0.scroll_to_bottom();
+    }
+
+    /// Scroll the least amount that shows the `ix`th child of the area.
+    pub fn scroll_to_item(&self, ix: usize) {
+        self.0.scroll_to_item(ix);
+    }
+
+    /// Metrics of `axis` as of the last layout.
+    fn metrics(&self, axis: Axis) -> ScrollMetrics {
+        let size = self.0.bounds().size;
+        let max = self.0.max_offset();
+        let offset = self.offset();
+        match axis {
+            Axis::Vertical => ScrollMetrics::new(
+                f32::from(size.height),
+                f32::from(size.height + max.height),
+                f32::from(offset.y),
+            ),
+            Axis::Horizontal => ScrollMetrics::new(
+                f32::from(size.width),
+                f32::from(size.width + max.width),
+                f32::from(offset.x),
+            ),
+        }
+    }
+
+    /// Scroll `axis` to `offset`, clamped to the content.
+    fn scroll_axis_to(&self, axis: Axis, offset: f32) {
+        let offset = px(-self.metrics(axis).clamp(offset));
+        let mut current = self.0.offset();
+        match axis {
+            Axis::Vertical => current.y = offset,
+            Axis::Horizontal => current.x = offset,
+        }
+        self.0.set_offset(current);
+    }
+}
+
+/// A thumb drag in progress.
+#[derive(Debug, Clone, Copy)]
+struct ThumbDrag {
+    axis: Axis,
+    /// Pointer position along the axis when the drag started.
+    pointer: f32,
+    /// Thumb start along the track when the drag started.
+    thumb_start: f32,
+}
+
+/// Position of `point` along `axis`.
+fn along(point: Point<Pixels>, axis: Axis) -> f32 {
+    f32::from(match axis {
+        Axis::Vertical => point.y,
+        Axis::Horizontal => point.x,
+    })
+}
+
+/// Scrollable content with themed scrollbars.
+///
+/// The area fills its parent; children are stacked vertically inside it.
+/// Scrollbars appear only on axes whose content overflows.
+///
+/// # Usage
+/// ```ignore
+/// ScrollArea::new("token-list")
+///     .track_scroll(&self.token_scroll)
+///     .children(rows)
+/// ```
+#[derive(IntoElement)]
+pub struct ScrollArea {
+    id: ElementId,
+    axes: ScrollAxes,
+    handle: Option<ScrollAreaHandle>,
+    children: Vec<AnyElement>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl ScrollArea {
+    /// Create an empty area that scrolls vertically.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            axes: ScrollAxes::default(),
+            handle: None,
+            children: Vec::new(),
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set which axes scroll.
+    pub fn axes(mut self, axes: ScrollAxes) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Scroll with `handle`, so the owning view can scroll the area from code.
+    pub fn track_scroll(mut self, handle: &ScrollAreaHandle) -> Self {
+        self.handle = Some(handle.clone());
+        self
+    }
+
+    /// Add a child to the scrolled content.
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.children.push(child.into_any_element());
+        self
+    }
+
+    /// Add children to the scrolled content.
+    pub fn children(mut self, children: impl IntoIterator<Item = impl IntoElement>) -> Self {
+        self.children
+            .extend(children.into_iter().map(IntoElement::into_any_element));
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for ScrollArea.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::scroll_area()
+    }
+}
+
+impl WithIdentifiers for ScrollArea {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for ScrollArea {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "ScrollArea",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let handle = match self.handle {
+            Some(handle) => handle,
+            None => window
+                .use_keyed_state(key("scroll"), cx, |_, _| ScrollAreaHandle::new())
+                .read(cx)
+                .clone(),
+        };
+        let drag = window.use_keyed_state(key("drag"), cx, |_, _| None::<ThumbDrag>);
+        // Viewport bounds and scroll range from the last prepaint; a change
+        // re-renders so the bars follow the content after layout.
+        let measured = window.use_keyed_state(key("measured"), cx, |_, _| {
+            (Bounds::<Pixels>::default(), Size::<Pixels>::default())
+        });
+
+        let axes = self.axes;
+        let both = axes == ScrollAxes::Both;
+        let active_drag = *drag.read(cx);
+        let viewport = handle.0.bounds();
+
+        let theme = cx.theme();
+        let track_background = theme.scrollbar.track_background;
+        let track_border = theme.scrollbar.track_border;
+        let thumb_background = theme.scrollbar.thumb_background;
+        let thumb_hover = theme.scrollbar.thumb_hover_background;
+        let thumb_border = theme.scrollbar.thumb_border;
+
+        // Re-renders once layout changes the scroll range and, while a thumb
+        // is dragged, follows the pointer anywhere in the window.
+        let tracker = canvas(
+            {
+                let handle = handle.clone();
+                move |_, _, cx| {
+                    let current = (handle.0.bounds(), handle.0.max_offset());
+                    measured.update(cx, |measured, cx| {
+                        if *measured != current {
+                            *measured = current;
+                            cx.notify();
+                        }
+                    });
+                }
+            },
+            {
+                let handle = handle.clone();
+                let drag = drag.clone();
+                move |_, _, window, _| {
+                    let Some(active) = active_drag else {
+                        return;
+                    };
+                    let metrics = handle.metrics(active.axis);
+                    let track = metrics.viewport - if both { SCROLLBAR_SIZE } else { 0.0 };
+                    window.on_mouse_event(move |event: &MouseMoveEvent, phase, window, _| {
+                        if phase == DispatchPhase::Bubble
+                            && event.pressed_button == Some(MouseButton::Left)
+                        {
+                            let thumb_start = active.thumb_start
+                                + along(event.position, active.axis)
+                                - active.pointer;
+                            handle.scroll_axis_to(
+                                active.axis,
+                                metrics.offset_for_thumb(thumb_start, track),
+                            );
+                            window.refresh();
+                        }
+                    });
+                    window.on_mouse_event(move |_: &MouseUpEvent, phase, _, cx| {
+                        if phase == DispatchPhase::Bubble {
+                            drag.update(cx, |drag, cx| {
+                                *drag = None;
+                                cx.notify();
+                            });
+                        }
+                    });
+                }
+            },
+        )
+        .absolute()
+        .size_full();
+
+        // A bar for `axis`, when it scrolls and its content overflows.
+        let scrollbar = |axis: Axis| {
+            if !axes.scrolls(axis) {
+                return None;
+            }
+            let metrics = handle.metrics(axis);
+            let track = metrics.viewport - if both { SCROLLBAR_SIZE } else { 0.0 };
+            let (thumb_start, thumb_length) = metrics.thumb(track)?;
+            let vertical = axis == Axis::Vertical;
+            let dragging = active_drag.is_some_and(|drag| drag.axis == axis);
+            let track_origin = match axis {
+                Axis::Vertical => f32::from(viewport.top()),
+                Axis::Horizontal => f32::from(viewport.left()),
+            };
+
+            let thumb = div()
+                .id(key(if vertical { "thumb-y" } else { "thumb-x" }))
+                .absolute()
+                .rounded_full()
+                .border_1()
+                .border_color(thumb_border)
+                .bg(if dragging {
+                    thumb_hover
+                } else {
+                    thumb_background
+                })
+                .hover(move |s| s.bg(thumb_hover))
+                .map(|el| {
+                    if vertical {
+                        el.top(px(thumb_start))
+                            .h(px(thumb_length))
+                            .left(px(1.0))
+                            .right(px(1.0))
+                    } else {
+                        el.left(px(thumb_start))
+                            .w(px(thumb_length))
+                            .top(px(1.0))
+                            .bottom(px(1.0))
+                    }
+                })
+                .on_mouse_down(MouseButton::Left, {
+                    let drag = drag.clone();
+                    move |event, _, cx| {
+                        cx.stop_propagation();
+                        drag.update(cx, |drag, cx| {
+                            *drag = Some(ThumbDrag {
+                                axis,
+                                pointer: along(event.position, axis),
+                                thumb_start,
+                            });
+                            cx.notify();
+                        });
+                    }
+                });
+
+            let bar = div()
+                .id(key(if vertical { "track-y" } else { "track-x" }))
+                .absolute()
+                .bg(track_background)
+                .border_color(track_border)
+                .map(|el| {
+                    if vertical {
+                        el.top_0()
+                            .right_0()
+                            .w(px(SCROLLBAR_SIZE))
+                            .h(px(track))
+                            .border_l_1()
+                    } else {
+                        el.bottom_0()
+                            .left_0()
+                            .h(px(SCROLLBAR_SIZE))
+                            .w(px(track))
+                            .border_t_1()
+                    }
+                })
+                .on_mouse_down(MouseButton::Left, {
+                    let handle = handle.clone();
+                    move |event, window, cx| {
+                        cx.stop_propagation();
+                        let position = along(event.position, axis) - track_origin;
+                        handle
+                            .scroll_axis_to(axis, metrics.offset_for_track_press(position, track));
+                        window.refresh();
+                    }
+                })
+                .child(thumb);
+            Some(bar)
+        };
+        let vertical_bar = scrollbar(Axis::Vertical);
+        let horizontal_bar = scrollbar(Axis::Horizontal);
+
+        let content = div()
+            .id(key("viewport"))
+            .size_full()
+            .flex()
+            .flex_col()
+            .map(|el| match axes {
+                ScrollAxes::Vertical => el.overflow_y_scroll(),
+                ScrollAxes::Horizontal => el.overflow_x_scroll(),
+                ScrollAxes::Both => el.overflow_scroll(),
+            })
+            .track_scroll(&handle.0)
+            .children(self.children);
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .child(content)
+            .child(tracker)
+            .children(vertical_bar)
+            .children(horizontal_bar)
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,339 @@
+//! SearchInput component: single-line query field with clear button and result count.
+//!
+//! Rewrite disposition: composed for the workbench from the Input visuals plus
+//! the shared timing and state primitives.
+//!
+//! Provenance:
+//! - Visual treatment follows the internal Input component.
+//! - Modifications: adds a magnifier affordance, Escape/clear-button reset,
+//!   a debounced `on_query` callback (primitives `Debouncer`), and an optional
+//!   result-count suffix.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{ControlledState, Debouncer, Locale, format_integer, is_escape_key};
+use theme::ActiveTheme;
+
+/// Default quiet period before `on_query` fires.
+pub const SEARCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+/// Callback receiving the settled query. Fired from a timer, so no `Window`.
+type OnQueryCallback = Rc<dyn Fn(&str, &mut App) + 'static>;
+
+/// A search field with magnifier icon, Escape-to-clear, debounced query
+/// callback, and optional result count.
+///
+/// # Usage
+/// ```ignore
+/// SearchInput::new("story-search")
+///     .placeholder("Search components")
+///     .result_count(matches.len())
+///     .on_query(|query, cx| {
+///         println!("Search: {query}");
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct SearchInput {
+    id: ElementId,
+    query: ControlledState<SharedString>,
+    placeholder: SharedString,
+    result_count: Option<usize>,
+    debounce: Duration,
+    on_query: Option<OnQueryCallback>,
+    disabled: bool,
+    full_width: bool,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl SearchInput {
+    /// Create a new empty search input.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            query: ControlledState::new(),
+            placeholder: "Search...".into(),
+            result_count: None,
+            debounce: SEARCH_DEBOUNCE,
+            on_query: None,
+            disabled: false,
+            full_width: false,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Control the query text from the parent.
+    pub fn value(mut self, query: impl Into<SharedString>) -> Self {
+        self.query = self.query.value(query.into());
+        self
+    }
+
+    /// Set the initial query for uncontrolled use.
+    pub fn default_value(mut self, query: impl Into<SharedString>) -> Self {
+        self.query = self.query.default_value(query.into());
+        self
+    }
+
+    /// Set the placeholder text.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Show the number of results as a suffix while a query is entered.
+    pub fn result_count(mut self, count: usize) -> Self {
+        self.result_count = Some(count);
+        self
+    }
+
+    /// Set the quiet period before `on_query` fires.
+    pub fn debounce(mut self, delay: Duration) -> Self {
+        self.debounce = delay;
+        self
+    }
+
+    /// Set the immediate change handler, fired on every keystroke.
+    pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.query = self
+            .query
+            .on_change(move |query, window, cx| handler(query, window, cx));
+        self
+    }
+
+    /// Set the debounced query handler.
+    ///
+    /// Fires once typing pauses for the debounce delay, and immediately when
+    /// the query is cleared or Enter is pressed.
+    pub fn on_query(mut self, handler: impl Fn(&str, &mut App) + 'static) -> Self {
+        self.on_query = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Make the input take full width.
+    pub fn full_width(mut self) -> Self {
+        self.full_width = true;
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for SearchInput.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::search_input()
+    }
+}
+
+impl WithIdentifiers for SearchInput {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+/// Result count shown after the query, e.g. "1,024 results".
+pub fn result_count_label(count: usize, locale: &Locale) -> String {
+    let digits = format_integer(count as i64, locale);
+    if count == 1 {
+        format!("{digits} result")
+    } else {
+        format!("{digits} results")
+    }
+}
+
+impl RenderOnce for SearchInput {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "SearchInput",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let query_state = self.query.bind(self.id.clone(), window, cx);
+        let query = query_state.current().clone();
+
+        let focus_handle = window
+            .use_keyed_state(
+                SharedString::from(format!("{}-focus", self.id)),
+                cx,
+                |_, cx| cx.focus_handle(),
+            )
+            .read(cx)
+            .clone();
+        let debounce = self.debounce;
+        let debouncer = window.use_keyed_state(
+            SharedString::from(format!("{}-debounce", self.id)),
+            cx,
+            move |_, _| Debouncer::new(debounce),
+        );
+
+        // Commit a new query: update state now, deliver on_query now or after the debounce.
+        let on_query = self.on_query;
+        let commit = Rc::new(
+            move |next: SharedString, immediate: bool, window: &mut Window, cx: &mut App| {
+                query_state.set(next.clone(), window, cx);
+                let Some(on_query) = on_query.clone() else {
+                    return;
+                };
+                debouncer.update(cx, |debouncer, cx| {
+                    if immediate {
+                        debouncer.cancel();
+                        on_query(&next, cx);
+                    } else {
+                        debouncer.schedule(cx, move |_, cx| on_query(&next, cx));
+                    }
+                });
+            },
+        );
+
+        let theme = cx.theme();
+
+        let disabled = self.disabled;
+        let is_focused = focus_handle.is_focused(window);
+        let (bg, text_color) = if disabled {
+            (theme.element.disabled, theme.text.disabled)
+        } else {
+            (theme.element.background, theme.text.default)
+        };
+        let border_color = if is_focused && !disabled {
+            theme.border.focused
+        } else {
+            theme.border.default
+        };
+        let hover_border = theme.border.focused;
+        let placeholder_color = theme.text.placeholder;
+        let icon_color = theme.icon.muted;
+        let clear_hover = theme.ghost_element.hover;
+
+        let mut field = div()
+            .id(self.id.clone())
+            .track_focus(&focus_handle)
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .h(px(28.0))
+            .px_2()
+            .bg(bg)
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .text_sm()
+            .map(|el| {
+                if self.full_width {
+                    el.w_full()
+                } else {
+                    el.min_w(px(200.0))
+                }
+            })
+            .child(div().flex_shrink_0().text_color(icon_color).child("⌕"));
+
+        if !disabled {
+            let handle = focus_handle.clone();
+            field = field
+                .hover(move |s| s.border_color(hover_border))
+                .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    window.focus(&handle, cx);
+                })
+                .on_key_down({
+                    let query = query.clone();
+                    let commit = commit.clone();
+                    move |event, window, cx| {
+                        let keystroke = &event.keystroke;
+                        if is_escape_key(event) {
+                            // Leave Escape to enclosing overlays when there is nothing to clear.
+                            if !query.is_empty() {
+                                commit(SharedString::default(), true, window, cx);
+                                cx.stop_propagation();
+                            }
+                        } else if keystroke.key == keys::ENTER {
+                            commit(query.clone(), true, window, cx);
+                            cx.stop_propagation();
+                        } else if keystroke.key == keys::BACKSPACE {
+                            let mut next = query.to_string();
+                            next.pop();
+                            commit(next.into(), false, window, cx);
+                            cx.stop_propagation();
+                        } else if !keystroke.modifiers.control
+                            && !keystroke.modifiers.platform
+                            && let Some(typed) = keystroke
+                                .key_char
+                                .as_ref()
+                                .filter(|s| !s.chars().any(char::is_control))
+                        {
+                            commit(format!("{query}{typed}").into(), false, window, cx);
+                            cx.stop_propagation();
+                        }
+                    }
+                });
+        }
+
+        // Query text or placeholder
+        field = field.child(div().flex_1().overflow_x_hidden().map(|el| {
+            if query.is_empty() {
+                el.text_color(placeholder_color).child(self.placeholder)
+            } else {
+                el.text_color(text_color).child(query.clone())
+            }
+        }));
+
+        // Result count and clear button while a query is entered
+        if !query.is_empty() {
+            if let Some(count) = self.result_count {
+                let label = result_count_label(count, Locale::global(cx));
+                field = field.child(
+                    div()
+                        .flex_shrink_0()
+                        .text_xs()
+                        .text_color(placeholder_color)
+                        .child(label),
+                );
+            }
+
+            field = field.child(
+                div()
+                    .id(SharedString::from(format!("{}-clear", self.id)))
+                    .flex_shrink_0()
+                    .rounded_sm()
+                    .px_1()
+                    .text_xs()
+                    .text_color(icon_color)
+                    .when(!disabled, |el| {
+                        el.cursor_pointer()
+                            .hover(move |s| s.bg(clear_hover))
+                            .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                                cx.stop_propagation();
+                                commit(SharedString::default(), true, window, cx);
+                            })
+                    })
+                    .child("✕"),
+            );
+        }
+
+        field.inspectable(&self.id, Self::contract)
+    }
+}
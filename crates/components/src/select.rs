@@ -7,11 +7,17 @@
 //! - gpui-component `crates/ui/src/select.rs` (MIT, Zed Industries)
 //! - Zed `crates/ui/src/components/dropdown_menu.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - Modifications: Simplified to POC scope, rewired to internal token system,
-//!   uses internal primitives for keyboard nav, popover positioning, state management.
+//!   uses internal primitives for keyboard nav, popover positioning, and
+//!   controlled/uncontrolled selection (ControlledState).
 
+use crate::empty_state::{EmptyState, EmptyStateSize};
+use crate::tooltip::{TooltipPlacement, WithTooltip, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
-use primitives::{FocusReturn, OpenState, Orientation, classify_nav_key, is_activation_key};
+use primitives::{
+    ControlledState, FocusReturn, OpenState, Orientation, classify_nav_key, is_activation_key,
+};
 use theme::ActiveTheme;
 
 /// A single item in a select dropdown.
@@ -41,9 +47,6 @@ impl SelectItem {
     }
 }
 
-/// Callback when the selection changes.
-type OnChangeCallback = Box<dyn Fn(usize, &SelectItem, &mut Window, &mut App) + 'static>;
-
 /// A select dropdown component with trigger button, popover list,
 /// arrow-key navigation, and controlled/uncontrolled selection.
 ///
@@ -60,13 +63,15 @@ type OnChangeCallback = Box<dyn Fn(usize, &SelectItem, &mut Window, &mut App) +
 pub struct Select {
     id: ElementId,
     items: Vec<SelectItem>,
-    selected_index: Option<usize>,
+    selected: ControlledState<Option<usize>>,
     highlighted_index: usize,
     open_state: OpenState,
     placeholder: SharedString,
+    empty_text: SharedString,
     disabled: bool,
-    on_change: Option<OnChangeCallback>,
     tooltip: Option<SharedString>,
+    tooltip_placement: TooltipPlacement,
+    identifiers: SharedIdentifiers,
     width: Pixels,
     focus_handle: FocusHandle,
     #[allow(dead_code)]
@@ -80,22 +85,31 @@ impl Select {
         Self {
             id: id.into(),
             items,
-            selected_index: None,
+            selected: ControlledState::new(),
             highlighted_index: 0,
             open_state: OpenState::Closed,
             placeholder: "Select...".into(),
+            empty_text: "No options".into(),
             disabled: false,
-            on_change: None,
             tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
+            identifiers: SharedIdentifiers::default(),
             width: px(200.0),
             focus_handle,
             focus_return: None,
         }
     }
 
-    /// Set the selected item index.
+    /// Control the selected item index from the parent.
     pub fn selected_index(mut self, index: usize) -> Self {
-        self.selected_index = Some(index);
+        self.selected = self.selected.value(Some(index));
+        self.highlighted_index = index;
+        self
+    }
+
+    /// Set the initially selected item index for uncontrolled use.
+    pub fn default_selected_index(mut self, index: usize) -> Self {
+        self.selected = self.selected.default_value(Some(index));
         self.highlighted_index = index;
         self
     }
@@ -106,6 +120,12 @@ impl Select {
         self
     }
 
+    /// Set the text shown in the dropdown body when there are no items.
+    pub fn empty_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
     /// Set the select as disabled.
     pub fn set_disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -117,11 +137,19 @@ impl Select {
         mut self,
         handler: impl Fn(usize, &SelectItem, &mut Window, &mut App) + 'static,
     ) -> Self {
-        self.on_change = Some(Box::new(handler));
+        let items = self.items.clone();
+        self.selected = self.selected.on_change(move |selected, window, cx| {
+            if let Some(idx) = *selected
+                && let Some(item) = items.get(idx)
+            {
+                handler(idx, item, window, cx);
+            }
+        });
         self
     }
 
     /// Set a tooltip.
+    #[deprecated(note = "use `WithTooltip::tooltip`")]
     pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
         self
@@ -141,83 +169,47 @@ impl Select {
 
     /// Returns the component contract for Select.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Select", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop(
-                "id",
-                "ElementId",
-                "Unique identifier for the select instance",
-            )
-            .required_prop("items", "Vec<SelectItem>", "List of selectable items")
-            .optional_prop(
-                "selected_index",
-                "Option<usize>",
-                "None",
-                "Currently selected item index",
-            )
-            .optional_prop(
-                "placeholder",
-                "SharedString",
-                "Select...",
-                "Text shown when no item is selected",
-            )
-            .optional_prop(
-                "disabled",
-                "bool",
-                "false",
-                "Whether the select is disabled",
-            )
-            .optional_prop("width", "Pixels", "200.0", "Select trigger width")
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Open)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Selected)
-            .state(ComponentState::Disabled)
-            .token_dep("element.background", "Trigger button background")
-            .token_dep("element.hover", "Trigger button hover background")
-            .token_dep("border.default", "Trigger and popover border")
-            .token_dep("text.default", "Selected item text")
-            .token_dep("text.placeholder", "Placeholder text")
-            .token_dep("text.disabled", "Disabled item text")
-            .token_dep("surface.elevated_surface", "Popover dropdown background")
-            .token_dep("ghost_element.hover", "Dropdown item hover background")
-            .token_dep(
-                "ghost_element.selected",
-                "Selected dropdown item background",
-            )
-            .focus_behavior(
-                "Trigger receives focus via Tab. Arrow keys navigate items. \
-                 Focus returns to trigger on close.",
-            )
-            .keyboard_model(
-                "Enter/Space opens dropdown and selects highlighted item. \
-                 Up/Down arrows navigate through items (wrapping). \
-                 Escape closes dropdown. Home/End jump to first/last.",
-            )
-            .pointer_behavior(
-                "Click on trigger toggles dropdown. \
-                 Click on item selects it. \
-                 Click outside dismisses dropdown.",
-            )
-            .state_model(
-                "Supports controlled (selected_index) and uncontrolled mode. \
-                 OpenState tracks popover visibility. \
-                 on_change fires when selection changes.",
-            )
-            .disabled_behavior(
-                "Disabled state blocks all interaction, shows reduced-opacity text, \
-                 prevents dropdown from opening.",
-            )
-            .required_file("crates/components/src/select.rs")
-            .build()
+        crate::catalog::select()
+    }
+}
+
+impl WithIdentifiers for Select {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl WithTooltip for Select {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
     }
 }
 
 impl RenderOnce for Select {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Select",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let selected_state = self.selected.bind(self.id.clone(), window, cx);
+        let selected_index = *selected_state.current();
+
         let theme = cx.theme();
 
         let trigger_bg = theme.element.background;
@@ -232,11 +224,11 @@ impl RenderOnce for Select {
 
         let is_disabled = self.disabled;
         let is_open = self.open_state.is_open();
-        let selected_index = self.selected_index;
         let highlighted = self.highlighted_index;
         let width = self.width;
         let items = self.items;
         let placeholder = self.placeholder;
+        let empty_text = self.empty_text;
 
         // Determine display text
         let display_text: SharedString = if let Some(idx) = selected_index {
@@ -307,7 +299,14 @@ impl RenderOnce for Select {
 
         // Build the popover dropdown if open
         let mut container = div().flex().flex_col().relative();
-        container = container.child(trigger);
+        container = container.child(attach_tooltip(
+            trigger,
+            &self.id,
+            self.tooltip,
+            self.tooltip_placement,
+            window,
+            cx,
+        ));
 
         if is_open && !is_disabled {
             let mut list = div()
@@ -324,6 +323,14 @@ impl RenderOnce for Select {
                 .shadow_lg()
                 .py_1();
 
+            if items.is_empty() {
+                list = list.child(
+                    EmptyState::new(SharedString::from(format!("{}-empty", self.id)))
+                        .size(EmptyStateSize::Small)
+                        .title(empty_text),
+                );
+            }
+
             for (idx, item) in items.iter().enumerate() {
                 let is_selected = selected_index == Some(idx);
                 let is_highlighted = highlighted == idx;
@@ -357,7 +364,12 @@ impl RenderOnce for Select {
                         .rounded_sm()
                         .mx_1()
                         .when(!is_item_disabled, |this| {
-                            this.cursor_pointer().hover(|s| s.bg(item_hover))
+                            let selected_state = selected_state.clone();
+                            this.cursor_pointer()
+                                .hover(|s| s.bg(item_hover))
+                                .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                                    selected_state.set(Some(idx), window, cx);
+                                })
                         })
                         .when(is_item_disabled, |this| this.cursor_default().opacity(0.5))
                         .child(item.label.clone())
@@ -377,7 +389,7 @@ impl RenderOnce for Select {
             container = container.child(deferred(list).with_priority(1));
         }
 
-        container
+        container.inspectable(&self.id, Self::contract)
     }
 }
 
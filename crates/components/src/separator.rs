@@ -0,0 +1,130 @@
+//! Separator component: a rule dividing content, optionally labeled.
+//!
+//! Rewrite disposition: written for the workbench so layouts divide groups
+//! with the theme's `border.variant` color instead of ad-hoc bordered divs.
+//! A horizontal separator spans its container's width and a vertical one
+//! its height; a label (e.g. "or") sits in a gap at the middle of the rule.
+//! Separators inside a disabled group take the disabled colors.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use primitives::Orientation;
+use theme::ActiveTheme;
+
+/// A dividing rule.
+///
+/// # Usage
+/// ```ignore
+/// Separator::new("sign-in-separator").label("or")
+/// Separator::new("toolbar-separator").orientation(Orientation::Vertical)
+/// ```
+#[derive(IntoElement)]
+pub struct Separator {
+    id: ElementId,
+    orientation: Orientation,
+    label: Option<SharedString>,
+    disabled: bool,
+    identifiers: SharedIdentifiers,
+}
+
+impl Separator {
+    /// Create a horizontal separator.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            orientation: Orientation::Horizontal,
+            label: None,
+            disabled: false,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the direction of the rule: `Horizontal` divides stacked content,
+    /// `Vertical` divides content side by side.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set a label shown in the middle of the rule.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set whether the separator belongs to a disabled group.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the component contract for Separator.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::separator()
+    }
+}
+
+impl WithIdentifiers for Separator {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Separator {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Separator", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let (rule_color, label_color) = if self.disabled {
+            (theme.border.disabled, theme.text.disabled)
+        } else {
+            (theme.border.variant, theme.text.muted)
+        };
+
+        let horizontal = self.orientation == Orientation::Horizontal;
+        let rule = || {
+            let rule = div().flex_1().bg(rule_color);
+            if horizontal {
+                rule.h(px(1.0))
+            } else {
+                rule.w(px(1.0))
+            }
+        };
+
+        let separator = div().id(self.id.clone()).flex().items_center().flex_none();
+        let separator = if horizontal {
+            separator.flex_row().w_full()
+        } else {
+            separator.flex_col().h_full()
+        };
+        let separator = match self.label {
+            Some(label) => {
+                let label = div()
+                    .flex_none()
+                    .text_xs()
+                    .text_color(label_color)
+                    .child(label);
+                let label = if horizontal {
+                    label.px_2()
+                } else {
+                    label.py_1()
+                };
+                separator.child(rule()).child(label).child(rule())
+            }
+            None => separator.child(rule()),
+        };
+
+        separator.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,166 @@
+//! Skeleton: a placeholder shape shown while content loads.
+//!
+//! Rewrite disposition: written for the workbench so loading states keep
+//! the layout of the content they stand in for. A skeleton is a line,
+//! circle, or rectangle filled with `surface.elevated_surface`; a highlight
+//! in `element.hover` fades in and out over it once every
+//! [`SKELETON_PERIOD`]. With reduced motion the shimmer is skipped and the
+//! shape stays still.
+
+use std::time::Duration;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::MotionPreference;
+use theme::ActiveTheme;
+
+/// Time for one shimmer cycle: the highlight fades in, then out.
+pub const SKELETON_PERIOD: Duration = Duration::from_millis(1500);
+
+/// Highest opacity the shimmer highlight reaches, mid-cycle.
+const SHIMMER_PEAK: f32 = 0.6;
+
+/// Opacity of the shimmer highlight `delta` of the way through a cycle:
+/// zero at either end, rising linearly to its peak halfway.
+pub fn shimmer_opacity(delta: f32) -> f32 {
+    let delta = delta.clamp(0.0, 1.0);
+    (1.0 - (2.0 * delta - 1.0).abs()) * SHIMMER_PEAK
+}
+
+/// Shape of a [`Skeleton`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SkeletonShape {
+    /// A line of text: full width, 12px tall (default).
+    #[default]
+    Line,
+    /// An avatar or icon: a 32px circle.
+    Circle,
+    /// An image or block: full width, 64px tall.
+    Rect,
+}
+
+impl SkeletonShape {
+    /// Height in pixels unless set with [`Skeleton::height`].
+    pub fn default_height(self) -> f32 {
+        match self {
+            SkeletonShape::Line => 12.0,
+            SkeletonShape::Circle => 32.0,
+            SkeletonShape::Rect => 64.0,
+        }
+    }
+}
+
+/// A loading placeholder.
+///
+/// # Usage
+/// ```ignore
+/// Skeleton::new("avatar").shape(SkeletonShape::Circle)
+/// Skeleton::new("title").width(px(160.0))
+/// ```
+#[derive(IntoElement)]
+pub struct Skeleton {
+    id: ElementId,
+    shape: SkeletonShape,
+    width: Option<Pixels>,
+    height: Option<Pixels>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Skeleton {
+    /// Create a line skeleton.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            shape: SkeletonShape::default(),
+            width: None,
+            height: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the shape.
+    pub fn shape(mut self, shape: SkeletonShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Set the width; lines and rectangles fill their container by default.
+    /// A circle's width is its diameter.
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the height in place of the shape's default. Ignored by circles.
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Returns the component contract for Skeleton.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::skeleton()
+    }
+}
+
+impl WithIdentifiers for Skeleton {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Skeleton {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Skeleton", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let base = theme.surface.elevated_surface;
+        let highlight = theme.element.hover;
+
+        let default_height = px(self.shape.default_height());
+        let shape = div()
+            .id(self.id.clone())
+            .relative()
+            .flex_none()
+            .overflow_hidden()
+            .bg(base);
+        let shape = match self.shape {
+            SkeletonShape::Circle => {
+                let diameter = self.width.unwrap_or(default_height);
+                shape.size(diameter).rounded_full()
+            }
+            SkeletonShape::Line | SkeletonShape::Rect => shape
+                .h(self.height.unwrap_or(default_height))
+                .map(|el| match self.width {
+                    Some(width) => el.w(width),
+                    None => el.w_full(),
+                })
+                .when(self.shape == SkeletonShape::Line, |el| el.rounded_sm())
+                .when(self.shape == SkeletonShape::Rect, |el| el.rounded_md()),
+        };
+
+        let shape = if MotionPreference::global(cx).animates(SKELETON_PERIOD) {
+            shape.child(div().absolute().inset_0().bg(highlight).with_animation(
+                ElementId::Name(format!("{}-shimmer", self.id).into()),
+                Animation::new(SKELETON_PERIOD).repeat(),
+                |highlight, delta| highlight.opacity(shimmer_opacity(delta)),
+            ))
+        } else {
+            shape
+        };
+
+        shape.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,454 @@
+//! Slider component: pick a number from a range by dragging or arrow keys.
+//!
+//! Fork disposition: adapted from gpui-component `slider.rs`.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - gpui-component `crates/ui/src/slider.rs` (MIT, Zed Industries)
+//! - Modifications: stateless RenderOnce with ControlledState for the value,
+//!   shared `Orientation`, pure range math in `SliderRange`, ARIA-style
+//!   keyboard stepping (arrows, Page Up/Down, Home/End).
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{ControlledState, Orientation};
+use theme::ActiveTheme;
+
+/// Steps moved by Page Up/Page Down.
+pub const SLIDER_PAGE_STEPS: i32 = 10;
+
+/// Thumb diameter.
+const THUMB_SIZE: f32 = 14.0;
+
+/// The values a slider can take: `min..=max` in increments of `step`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SliderRange {
+    /// Smallest value.
+    pub min: f32,
+    /// Largest value.
+    pub max: f32,
+    /// Increment between values; `0.0` allows any value in the range.
+    pub step: f32,
+}
+
+impl Default for SliderRange {
+    fn default() -> Self {
+        Self::new(0.0, 100.0, 1.0)
+    }
+}
+
+impl SliderRange {
+    /// Create a range, swapping reversed bounds and treating negative steps as `0.0`.
+    pub fn new(min: f32, max: f32, step: f32) -> Self {
+        Self {
+            min: min.min(max),
+            max: max.max(min),
+            step: step.max(0.0),
+        }
+    }
+
+    /// Clamp `value` into the range and round it to the nearest step.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = value.clamp(self.min, self.max);
+        if self.step == 0.0 {
+            return value;
+        }
+        let steps = ((value - self.min) / self.step).round();
+        (self.min + steps * self.step).min(self.max)
+    }
+
+    /// Position of `value` in the range, from `0.0` at `min` to `1.0` at `max`.
+    pub fn fraction(&self, value: f32) -> f32 {
+        if self.max == self.min {
+            return 0.0;
+        }
+        ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+
+    /// The snapped value at `fraction` of the range.
+    pub fn value_at(&self, fraction: f32) -> f32 {
+        self.snap(self.min + fraction.clamp(0.0, 1.0) * (self.max - self.min))
+    }
+
+    /// Move `value` by `steps` increments, staying in the range.
+    ///
+    /// Continuous ranges move in hundredths of the range.
+    pub fn step_by(&self, value: f32, steps: i32) -> f32 {
+        let step = if self.step == 0.0 {
+            (self.max - self.min) / 100.0
+        } else {
+            self.step
+        };
+        self.snap(value + step * steps as f32)
+    }
+
+    /// The value a key press moves `value` to, or `None` for other keys.
+    ///
+    /// Right/Up step up and Left/Down step down in either orientation;
+    /// Page Up/Down move [`SLIDER_PAGE_STEPS`] steps; Home/End jump to the ends.
+    pub fn key_value(&self, key: &str, value: f32) -> Option<f32> {
+        match key {
+            keys::ARROW_RIGHT | keys::ARROW_UP => Some(self.step_by(value, 1)),
+            keys::ARROW_LEFT | keys::ARROW_DOWN => Some(self.step_by(value, -1)),
+            keys::PAGE_UP => Some(self.step_by(value, SLIDER_PAGE_STEPS)),
+            keys::PAGE_DOWN => Some(self.step_by(value, -SLIDER_PAGE_STEPS)),
+            keys::HOME => Some(self.min),
+            keys::END => Some(self.max),
+            _ => None,
+        }
+    }
+
+    /// Decimal places needed to show every step, e.g. 2 for a step of `0.25`.
+    pub fn decimals(&self) -> usize {
+        let mut decimals = 0;
+        let mut step = self.step;
+        while decimals < 4 && (step - step.round()).abs() > 1e-4 {
+            step *= 10.0;
+            decimals += 1;
+        }
+        decimals
+    }
+}
+
+/// Fraction of the track under `position`: left to right when horizontal,
+/// bottom to top when vertical.
+pub fn fraction_at(
+    bounds: Bounds<Pixels>,
+    position: Point<Pixels>,
+    orientation: Orientation,
+) -> f32 {
+    let fraction = match orientation {
+        Orientation::Horizontal => (position.x - bounds.left()) / bounds.size.width,
+        Orientation::Vertical => (bounds.bottom() - position.y) / bounds.size.height,
+    };
+    if fraction.is_finite() {
+        fraction.clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+/// A draggable, keyboard-steppable slider over a numeric range.
+///
+/// # Usage
+/// ```ignore
+/// Slider::new("volume")
+///     .range(0.0, 100.0)
+///     .step(5.0)
+///     .value(volume)
+///     .on_change(|value, _window, _cx| {
+///         println!("Volume: {value}");
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct Slider {
+    id: ElementId,
+    value: ControlledState<f32>,
+    range: SliderRange,
+    orientation: Orientation,
+    length: Pixels,
+    show_value: bool,
+    disabled: bool,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Slider {
+    /// Create a horizontal slider over `0..=100` in steps of 1.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            value: ControlledState::new(),
+            range: SliderRange::default(),
+            orientation: Orientation::Horizontal,
+            length: px(200.0),
+            show_value: false,
+            disabled: false,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Control the value from the parent.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = self.value.value(value);
+        self
+    }
+
+    /// Set the initial value for uncontrolled use.
+    pub fn default_value(mut self, value: f32) -> Self {
+        self.value = self.value.default_value(value);
+        self
+    }
+
+    /// Set the change handler, fired with each new snapped value.
+    pub fn on_change(mut self, handler: impl Fn(&f32, &mut Window, &mut App) + 'static) -> Self {
+        self.value = self.value.on_change(handler);
+        self
+    }
+
+    /// Set the smallest and largest values.
+    pub fn range(mut self, min: f32, max: f32) -> Self {
+        self.range = SliderRange::new(min, max, self.range.step);
+        self
+    }
+
+    /// Set the increment between values; `0.0` allows any value.
+    pub fn step(mut self, step: f32) -> Self {
+        self.range = SliderRange::new(self.range.min, self.range.max, step);
+        self
+    }
+
+    /// Set the orientation. Vertical sliders put `max` at the top.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the track length: width when horizontal, height when vertical.
+    pub fn length(mut self, length: Pixels) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// Show the current value next to the track.
+    pub fn show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for Slider.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::slider()
+    }
+}
+
+impl WithIdentifiers for Slider {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Slider {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Slider",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let range = self.range;
+        let orientation = self.orientation;
+        let disabled = self.disabled;
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let value_state = self.value.bind(self.id.clone(), window, cx);
+        let value = range.snap(*value_state.current());
+        let fraction = range.fraction(value);
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        // Track bounds from the last prepaint, for mapping pointer positions to values.
+        let track_bounds = window.use_keyed_state(key("track"), cx, |_, _| Bounds::default());
+        let dragging = window.use_keyed_state(key("dragging"), cx, |_, _| false);
+        let is_dragging = *dragging.read(cx) && !disabled;
+
+        let theme = cx.theme();
+        let is_focused = focus_handle.is_focused(window);
+        let (track_color, fill_color, thumb_border) = if disabled {
+            (
+                theme.element.disabled,
+                theme.text.disabled,
+                theme.border.disabled,
+            )
+        } else if is_focused || is_dragging {
+            (
+                theme.border.variant,
+                theme.text.accent,
+                theme.border.focused,
+            )
+        } else {
+            (theme.border.variant, theme.text.accent, theme.text.accent)
+        };
+        let thumb_bg = theme.element.background;
+        let thumb_hover = theme.element.hover;
+        let value_color = if disabled {
+            theme.text.disabled
+        } else {
+            theme.text.muted
+        };
+
+        let horizontal = orientation == Orientation::Horizontal;
+        let thumb_offset = px(-THUMB_SIZE / 2.0);
+
+        // Track with the filled part and a canvas that records its bounds and,
+        // while dragging, follows the pointer anywhere in the window.
+        let track = div()
+            .relative()
+            .rounded_full()
+            .bg(track_color)
+            .map(|el| {
+                if horizontal {
+                    el.h_1().w_full()
+                } else {
+                    el.w_1().h_full()
+                }
+            })
+            .child(div().absolute().rounded_full().bg(fill_color).map(|el| {
+                if horizontal {
+                    el.left_0().top_0().h_full().w(relative(fraction))
+                } else {
+                    el.left_0().bottom_0().w_full().h(relative(fraction))
+                }
+            }))
+            .child(
+                canvas(
+                    {
+                        let track_bounds = track_bounds.clone();
+                        move |bounds, _, cx| {
+                            track_bounds.update(cx, |stored, _| *stored = bounds);
+                        }
+                    },
+                    {
+                        let value_state = value_state.clone();
+                        let dragging = dragging.clone();
+                        move |bounds, _, window, _| {
+                            if !is_dragging {
+                                return;
+                            }
+                            window.on_mouse_event(
+                                move |event: &MouseMoveEvent, phase, window, cx| {
+                                    if phase == DispatchPhase::Bubble
+                                        && event.pressed_button == Some(MouseButton::Left)
+                                    {
+                                        let fraction =
+                                            fraction_at(bounds, event.position, orientation);
+                                        value_state.set(range.value_at(fraction), window, cx);
+                                    }
+                                },
+                            );
+                            window.on_mouse_event(move |_: &MouseUpEvent, phase, _, cx| {
+                                if phase == DispatchPhase::Bubble {
+                                    dragging.update(cx, |dragging, cx| {
+                                        *dragging = false;
+                                        cx.notify();
+                                    });
+                                }
+                            });
+                        }
+                    },
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .size(px(THUMB_SIZE))
+                    .rounded_full()
+                    .border_2()
+                    .border_color(thumb_border)
+                    .bg(thumb_bg)
+                    .when(!disabled, |el| el.hover(move |s| s.bg(thumb_hover)))
+                    .map(|el| {
+                        if horizontal {
+                            el.left(relative(fraction)).ml(thumb_offset).top(px(-5.0))
+                        } else {
+                            el.bottom(relative(fraction))
+                                .mb(thumb_offset)
+                                .left(px(-5.0))
+                        }
+                    }),
+            );
+
+        let mut slider = div()
+            .id(self.id.clone())
+            .flex()
+            .items_center()
+            .justify_center()
+            .map(|el| {
+                if horizontal {
+                    el.flex_row().w(self.length).h(px(20.0))
+                } else {
+                    el.flex_col().h(self.length).w(px(20.0))
+                }
+            })
+            .child(track);
+
+        if !disabled {
+            let handle = focus_handle.clone();
+            let press_state = value_state.clone();
+            slider = slider
+                .track_focus(&focus_handle)
+                .cursor_pointer()
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    window.focus(&handle, cx);
+                    let bounds = *track_bounds.read(cx);
+                    let fraction = fraction_at(bounds, event.position, orientation);
+                    press_state.set(range.value_at(fraction), window, cx);
+                    dragging.update(cx, |dragging, cx| {
+                        *dragging = true;
+                        cx.notify();
+                    });
+                })
+                .on_key_down(move |event, window, cx| {
+                    if let Some(next) = range.key_value(event.keystroke.key.as_str(), value) {
+                        value_state.set(next, window, cx);
+                        cx.stop_propagation();
+                    }
+                });
+        }
+
+        let mut container = div()
+            .flex()
+            .items_center()
+            .gap_2()
+            .map(|el| {
+                if horizontal {
+                    el.flex_row()
+                } else {
+                    el.flex_col_reverse()
+                }
+            })
+            .child(slider);
+
+        if self.show_value {
+            container = container.child(
+                div()
+                    .min_w(px(32.0))
+                    .text_xs()
+                    .text_color(value_color)
+                    .child(format!("{:.*}", range.decimals(), value)),
+            );
+        }
+
+        container.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
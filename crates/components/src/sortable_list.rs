@@ -0,0 +1,374 @@
+//! SortableList component: a vertical list reordered by dragging rows or
+//! from the keyboard.
+//!
+//! Rewrite disposition: written for the workbench on the `dnd` primitive,
+//! which owns the drag state machine. The list measures its rows each frame
+//! so pointer positions map to the gap a drop would land in.
+
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::{
+    DragState, KeyOutcome, Orientation, Reorder, classify_nav_key, drop_target_at, navigate_index,
+};
+use theme::ActiveTheme;
+
+/// Callback fired with each completed move.
+type ReorderCallback = Rc<dyn Fn(&Reorder, &mut Window, &mut App) + 'static>;
+
+/// A single row of a sortable list.
+#[derive(Debug, Clone)]
+pub struct SortableItem {
+    /// Display label for this row.
+    pub label: SharedString,
+    /// Secondary text shown under the label.
+    pub description: Option<SharedString>,
+}
+
+impl SortableItem {
+    /// Create a row with a label.
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+        }
+    }
+
+    /// Set the secondary text shown under the label.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// A list whose rows can be dragged into a new order.
+///
+/// The list is controlled: it draws `items` in the order given and reports
+/// each move through [`on_reorder`](Self::on_reorder); the owner applies it
+/// (for example with [`Reorder::apply`]) and re-renders.
+///
+/// # Usage
+/// ```ignore
+/// SortableList::new("layers")
+///     .items(self.layers.iter().map(SortableItem::new))
+///     .on_reorder(cx.listener(|this, reorder: &Reorder, _, cx| {
+///         reorder.apply(&mut this.layers);
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct SortableList {
+    id: ElementId,
+    items: Vec<SortableItem>,
+    on_reorder: Option<ReorderCallback>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl SortableList {
+    /// Create an empty list.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            on_reorder: None,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the rows, in their current order.
+    pub fn items(mut self, items: impl IntoIterator<Item = SortableItem>) -> Self {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    /// Set the handler fired when a drop or key moves a row.
+    pub fn on_reorder(
+        mut self,
+        handler: impl Fn(&Reorder, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for SortableList.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::sortable_list()
+    }
+}
+
+impl WithIdentifiers for SortableList {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+/// A 2px drop indicator line across the top or bottom edge of a row.
+fn drop_line(color: Hsla, bottom: bool) -> Div {
+    div()
+        .absolute()
+        .left_0()
+        .right_0()
+        .h(px(2.0))
+        .bg(color)
+        .map(|el| {
+            if bottom {
+                el.bottom(px(-1.0))
+            } else {
+                el.top(px(-1.0))
+            }
+        })
+}
+
+impl RenderOnce for SortableList {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "SortableList",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let len = self.items.len();
+        let drag = window.use_keyed_state(key("drag"), cx, |_, _| DragState::default());
+        let focused = window.use_keyed_state(key("focused"), cx, |_, _| 0usize);
+        // Row bounds from the last prepaint, for mapping the pointer to the
+        // gap a drop would land in.
+        let rows = window.use_keyed_state(key("rows"), cx, |_, _| Vec::<Bounds<Pixels>>::new());
+        rows.update(cx, |rows, _| rows.resize(len, Bounds::default()));
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+
+        let state = *drag.read(cx);
+        let focused_index = (*focused.read(cx)).min(len.saturating_sub(1));
+        let is_focused = focus_handle.is_focused(window);
+        let indicator = state.indicator();
+        let source = state.source();
+        let grabbed = matches!(state, DragState::Grabbed { .. });
+        let pointer_down = state.is_pointer_down();
+
+        let theme = cx.theme();
+        let row_bg = theme.element.background;
+        let hover_bg = theme.ghost_element.hover;
+        let grabbed_bg = theme.element.selected;
+        let separator = theme.border.variant;
+        let accent = theme.border.focused;
+        let text_color = theme.text.default;
+        let muted_color = theme.text.muted;
+
+        // Moves focus to the row's new place and tells the owner.
+        let finish = {
+            let focused = focused.clone();
+            let on_reorder = self.on_reorder.clone();
+            move |reorder: Reorder, window: &mut Window, cx: &mut App| {
+                focused.update(cx, |focused, cx| {
+                    *focused = reorder.to;
+                    cx.notify();
+                });
+                if let Some(on_reorder) = &on_reorder {
+                    on_reorder(&reorder, window, cx);
+                }
+            }
+        };
+
+        // While the pointer is down, follows it anywhere in the window.
+        let tracker = canvas(|_, _, _| {}, {
+            let drag = drag.clone();
+            let rows = rows.clone();
+            let finish = finish.clone();
+            move |_, _, window, _| {
+                if !pointer_down {
+                    return;
+                }
+                window.on_mouse_event({
+                    let drag = drag.clone();
+                    move |event: &MouseMoveEvent, phase, _, cx| {
+                        if phase != DispatchPhase::Bubble {
+                            return;
+                        }
+                        let pointer = f32::from(event.position.y);
+                        let spans: Vec<(f32, f32)> = rows
+                            .read(cx)
+                            .iter()
+                            .map(|row| (f32::from(row.top()), f32::from(row.size.height)))
+                            .collect();
+                        let target = drop_target_at(pointer, &spans);
+                        drag.update(cx, |drag, cx| {
+                            let before = *drag;
+                            drag.pointer_moved(pointer, target);
+                            if *drag != before {
+                                cx.notify();
+                            }
+                        });
+                    }
+                });
+                window.on_mouse_event(move |_: &MouseUpEvent, phase, window, cx| {
+                    if phase != DispatchPhase::Bubble {
+                        return;
+                    }
+                    let reorder = drag.update(cx, |drag, cx| {
+                        cx.notify();
+                        drag.release()
+                    });
+                    if let Some(reorder) = reorder {
+                        finish(reorder, window, cx);
+                    }
+                });
+            }
+        })
+        .absolute()
+        .size_full();
+
+        let mut list = div()
+            .id(self.id.clone())
+            .relative()
+            .flex()
+            .flex_col()
+            .w_full()
+            .border_1()
+            .border_color(separator)
+            .rounded_md()
+            .track_focus(&focus_handle)
+            .on_key_down({
+                let drag = drag.clone();
+                let focused = focused.clone();
+                move |event, window, cx| {
+                    let current = *focused.read(cx);
+                    let outcome = drag.update(cx, |drag, cx| {
+                        let outcome = drag.handle_key(event.keystroke.key.as_str(), current, len);
+                        if outcome != KeyOutcome::Ignored {
+                            cx.notify();
+                        }
+                        outcome
+                    });
+                    match outcome {
+                        KeyOutcome::Reordered(reorder) => finish(reorder, window, cx),
+                        KeyOutcome::Handled => {
+                            // Focus follows a grabbed row.
+                            if let DragState::Grabbed { to, .. } = *drag.read(cx) {
+                                focused.update(cx, |focused, _| *focused = to);
+                            }
+                        }
+                        KeyOutcome::Ignored => {
+                            let Some(direction) = classify_nav_key(event, Orientation::Vertical)
+                            else {
+                                return;
+                            };
+                            focused.update(cx, |focused, cx| {
+                                *focused = navigate_index(*focused, direction, len, |_| false);
+                                cx.notify();
+                            });
+                        }
+                    }
+                    cx.stop_propagation();
+                }
+            })
+            .child(tracker);
+
+        for (ix, item) in self.items.into_iter().enumerate() {
+            let is_source = source == Some(ix);
+            let last = ix + 1 == len;
+
+            let row_tracker = canvas(
+                {
+                    let rows = rows.clone();
+                    move |bounds, _, cx| {
+                        rows.update(cx, |rows, _| {
+                            if let Some(row) = rows.get_mut(ix) {
+                                *row = bounds;
+                            }
+                        });
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full();
+
+            let row = div()
+                .id(SharedString::from(format!("{}-row-{ix}", self.id)))
+                .relative()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_2()
+                .px_2()
+                .py_1p5()
+                .bg(if is_source && grabbed {
+                    grabbed_bg
+                } else {
+                    row_bg
+                })
+                .when(!last, |el| el.border_b_1().border_color(separator))
+                .cursor(if pointer_down {
+                    CursorStyle::ClosedHand
+                } else {
+                    CursorStyle::OpenHand
+                })
+                .when(source.is_none(), |el| el.hover(move |s| s.bg(hover_bg)))
+                .when(is_source && !grabbed, |el| el.opacity(0.5))
+                .child(row_tracker)
+                .child(div().text_sm().text_color(muted_color).child("⠿"))
+                .child(
+                    div()
+                        .flex()
+                        .flex_col()
+                        .min_w_0()
+                        .child(div().text_sm().text_color(text_color).child(item.label))
+                        .children(item.description.map(|description| {
+                            div().text_xs().text_color(muted_color).child(description)
+                        })),
+                )
+                .when(is_focused && focused_index == ix, |el| {
+                    el.child(div().absolute().inset_0().border_1().border_color(accent))
+                })
+                .when(indicator == Some(ix), |el| {
+                    el.child(drop_line(accent, false))
+                })
+                .when(last && indicator == Some(len), |el| {
+                    el.child(drop_line(accent, true))
+                })
+                .on_mouse_down(MouseButton::Left, {
+                    let drag = drag.clone();
+                    let focused = focused.clone();
+                    let handle = focus_handle.clone();
+                    move |event, window, cx| {
+                        window.focus(&handle, cx);
+                        cx.stop_propagation();
+                        focused.update(cx, |focused, _| *focused = ix);
+                        drag.update(cx, |drag, cx| {
+                            drag.press(ix, f32::from(event.position.y));
+                            cx.notify();
+                        });
+                    }
+                });
+            list = list.child(row);
+        }
+
+        list.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,432 @@
+//! SplitPane component: two panes sharing an axis, resized by dragging or
+//! keying the divider between them.
+//!
+//! Rewrite disposition: written for the workbench on the `resize` primitive,
+//! which owns the min/max and collapse math. The component measures its own
+//! bounds each frame, so limits are re-applied whenever the container
+//! resizes.
+
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::keyboard::keys;
+use primitives::{Orientation, Pane, PaneLimits, SplitLimits, SplitState};
+use theme::ActiveTheme;
+
+/// Thickness of the divider's hit area.
+pub const DIVIDER_SIZE: f32 = 5.0;
+
+/// Callback fired with the anchored pane's new size.
+type ResizeCallback = Rc<dyn Fn(&Pixels, &mut Window, &mut App) + 'static>;
+
+/// Length of `bounds` along the split's axis.
+fn main_axis(bounds: Bounds<Pixels>, orientation: Orientation) -> f32 {
+    match orientation {
+        Orientation::Horizontal => f32::from(bounds.size.width),
+        Orientation::Vertical => f32::from(bounds.size.height),
+    }
+}
+
+/// Where the divider's center would sit for a pointer at `position`,
+/// measured from the start of the split.
+fn divider_offset(
+    bounds: Bounds<Pixels>,
+    position: Point<Pixels>,
+    orientation: Orientation,
+) -> f32 {
+    let offset = match orientation {
+        Orientation::Horizontal => position.x - bounds.left(),
+        Orientation::Vertical => position.y - bounds.top(),
+    };
+    f32::from(offset) - DIVIDER_SIZE / 2.0
+}
+
+/// The key that moves a second-pane-anchored divider the way `key` moves a
+/// first-pane-anchored one, so arrows always follow the divider on screen.
+fn mirror_key(key: &str) -> &str {
+    match key {
+        keys::ARROW_LEFT => keys::ARROW_RIGHT,
+        keys::ARROW_RIGHT => keys::ARROW_LEFT,
+        keys::ARROW_UP => keys::ARROW_DOWN,
+        keys::ARROW_DOWN => keys::ARROW_UP,
+        keys::HOME => keys::END,
+        keys::END => keys::HOME,
+        key => key,
+    }
+}
+
+/// Two panes with a draggable divider.
+///
+/// Horizontal splits place the panes left and right; vertical splits place
+/// them top and bottom. The anchored pane (the first, unless
+/// [`anchor`](Self::anchor) says otherwise) keeps its size as the split
+/// grows; the other takes the rest.
+///
+/// # Usage
+/// ```ignore
+/// SplitPane::new("editor-split")
+///     .first(sidebar)
+///     .second(editor)
+///     .default_size(px(240.0))
+///     .first_limits(px(160.0), px(400.0))
+///     .collapsible(Pane::First)
+/// ```
+#[derive(IntoElement)]
+pub struct SplitPane {
+    id: ElementId,
+    orientation: Orientation,
+    first: Option<AnyElement>,
+    second: Option<AnyElement>,
+    anchor: Pane,
+    default_size: Pixels,
+    limits: SplitLimits,
+    on_resize: Option<ResizeCallback>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl SplitPane {
+    /// Create an empty horizontal split whose first pane starts 240px wide.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            orientation: Orientation::Horizontal,
+            first: None,
+            second: None,
+            anchor: Pane::First,
+            default_size: px(240.0),
+            limits: SplitLimits::default(),
+            on_resize: None,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the axis: `Horizontal` for side by side, `Vertical` for stacked.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Set the left (or top) pane's content.
+    pub fn first(mut self, content: impl IntoElement) -> Self {
+        self.first = Some(content.into_any_element());
+        self
+    }
+
+    /// Set the right (or bottom) pane's content.
+    pub fn second(mut self, content: impl IntoElement) -> Self {
+        self.second = Some(content.into_any_element());
+        self
+    }
+
+    /// Set which pane keeps its size as the split grows. `default_size` and
+    /// `on_resize` measure this pane.
+    pub fn anchor(mut self, pane: Pane) -> Self {
+        self.anchor = pane;
+        self
+    }
+
+    /// Set the anchored pane's initial size.
+    pub fn default_size(mut self, size: Pixels) -> Self {
+        self.default_size = size;
+        self
+    }
+
+    /// Keep the first pane between `min` and `max` while it is open; pass
+    /// `px(f32::INFINITY)` for no maximum.
+    pub fn first_limits(mut self, min: Pixels, max: Pixels) -> Self {
+        self.limits.first = PaneLimits {
+            collapsible: self.limits.first.collapsible,
+            ..PaneLimits::new(min.into(), max.into())
+        };
+        self
+    }
+
+    /// Keep the second pane between `min` and `max` while it is open.
+    pub fn second_limits(mut self, min: Pixels, max: Pixels) -> Self {
+        self.limits.second = PaneLimits {
+            collapsible: self.limits.second.collapsible,
+            ..PaneLimits::new(min.into(), max.into())
+        };
+        self
+    }
+
+    /// Let `pane` collapse to zero: dragged past half its minimum, with
+    /// Enter on the divider, or by double-clicking the divider.
+    pub fn collapsible(mut self, pane: Pane) -> Self {
+        match pane {
+            Pane::First => self.limits.first.collapsible = true,
+            Pane::Second => self.limits.second.collapsible = true,
+        }
+        self
+    }
+
+    /// Set the handler fired with the anchored pane's size after each resize.
+    pub fn on_resize(mut self, handler: impl Fn(&Pixels, &mut Window, &mut App) + 'static) -> Self {
+        self.on_resize = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for SplitPane.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::split_pane()
+    }
+}
+
+impl WithIdentifiers for SplitPane {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for SplitPane {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "SplitPane",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let orientation = self.orientation;
+        let limits = self.limits;
+        let horizontal = orientation == Orientation::Horizontal;
+        // The split state always measures the anchored pane, so a second
+        // anchor sees the limits, offsets, and keys mirrored.
+        let anchored_second = self.anchor == Pane::Second;
+        let limits = if anchored_second {
+            limits.swapped()
+        } else {
+            limits
+        };
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let default_size = f32::from(self.default_size);
+        let split = window.use_keyed_state(key("split"), cx, |_, _| SplitState::new(default_size));
+        // Split bounds from the last prepaint, for the available space and
+        // for mapping pointer positions to divider offsets.
+        let bounds = window.use_keyed_state(key("bounds"), cx, |_, _| Bounds::default());
+        let dragging = window.use_keyed_state(key("dragging"), cx, |_, _| false);
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+
+        let measured = *bounds.read(cx);
+        let total = (main_axis(measured, orientation) - DIVIDER_SIZE).max(0.0);
+        let state = *split.read(cx);
+        // Before the first layout the space is unknown; show the requested size.
+        let anchored_size = if measured.size.width > px(0.0) {
+            state.first_size(total, &limits)
+        } else {
+            default_size
+        };
+        let is_dragging = *dragging.read(cx);
+
+        let theme = cx.theme();
+        let is_focused = focus_handle.is_focused(window);
+        let line_color = if is_dragging || is_focused {
+            theme.border.focused
+        } else {
+            theme.border.variant
+        };
+        let hover_color = theme.border.focused;
+
+        let on_resize = self.on_resize;
+        let notify_resize = {
+            let split = split.clone();
+            move |total: f32, window: &mut Window, cx: &mut App| {
+                if let Some(on_resize) = &on_resize {
+                    let size = split.read(cx).first_size(total, &limits);
+                    on_resize(&px(size), window, cx);
+                }
+            }
+        };
+
+        // Records the split's bounds and, while dragging, follows the pointer
+        // anywhere in the window.
+        let tracker = canvas(
+            {
+                let bounds = bounds.clone();
+                move |new_bounds, _, cx| {
+                    bounds.update(cx, |stored, cx| {
+                        if *stored != new_bounds {
+                            *stored = new_bounds;
+                            cx.notify();
+                        }
+                    });
+                }
+            },
+            {
+                let split = split.clone();
+                let dragging = dragging.clone();
+                let notify_resize = notify_resize.clone();
+                move |bounds, _, window, _| {
+                    if !is_dragging {
+                        return;
+                    }
+                    window.on_mouse_event(move |event: &MouseMoveEvent, phase, window, cx| {
+                        if phase == DispatchPhase::Bubble
+                            && event.pressed_button == Some(MouseButton::Left)
+                        {
+                            let offset = divider_offset(bounds, event.position, orientation);
+                            let offset = if anchored_second {
+                                total - offset
+                            } else {
+                                offset
+                            };
+                            split.update(cx, |split, cx| {
+                                split.drag_to(offset, total, &limits);
+                                cx.notify();
+                            });
+                            notify_resize(total, window, cx);
+                        }
+                    });
+                    window.on_mouse_event(move |_: &MouseUpEvent, phase, _, cx| {
+                        if phase == DispatchPhase::Bubble {
+                            dragging.update(cx, |dragging, cx| {
+                                *dragging = false;
+                                cx.notify();
+                            });
+                        }
+                    });
+                }
+            },
+        )
+        .absolute()
+        .size_full();
+
+        let divider = div()
+            .id(key("divider"))
+            .flex_none()
+            .flex()
+            .items_center()
+            .justify_center()
+            .map(|el| {
+                if horizontal {
+                    el.w(px(DIVIDER_SIZE))
+                        .h_full()
+                        .cursor(CursorStyle::ResizeColumn)
+                } else {
+                    el.h(px(DIVIDER_SIZE))
+                        .w_full()
+                        .cursor(CursorStyle::ResizeRow)
+                }
+            })
+            .track_focus(&focus_handle)
+            .group(key("divider"))
+            .child(
+                div()
+                    .bg(line_color)
+                    .group_hover(key("divider"), move |s| s.bg(hover_color))
+                    .map(|el| {
+                        if horizontal {
+                            el.w(px(1.0)).h_full()
+                        } else {
+                            el.h(px(1.0)).w_full()
+                        }
+                    }),
+            )
+            .on_mouse_down(MouseButton::Left, {
+                let split = split.clone();
+                let notify_resize = notify_resize.clone();
+                let handle = focus_handle.clone();
+                move |event, window, cx| {
+                    window.focus(&handle, cx);
+                    cx.stop_propagation();
+                    if event.click_count == 2 {
+                        let toggled = split.update(cx, |split, cx| {
+                            cx.notify();
+                            split.toggle_collapse(Pane::First, &limits)
+                                || split.toggle_collapse(Pane::Second, &limits)
+                        });
+                        if toggled {
+                            notify_resize(total, window, cx);
+                        }
+                        return;
+                    }
+                    dragging.update(cx, |dragging, cx| {
+                        *dragging = true;
+                        cx.notify();
+                    });
+                }
+            })
+            .on_key_down(move |event, window, cx| {
+                let keystroke = &event.keystroke;
+                let key = keystroke.key.as_str();
+                let key = if anchored_second {
+                    mirror_key(key)
+                } else {
+                    key
+                };
+                let handled = split.update(cx, |split, cx| {
+                    let handled = split.handle_key(
+                        key,
+                        keystroke.modifiers.shift,
+                        orientation,
+                        total,
+                        &limits,
+                    );
+                    if handled {
+                        cx.notify();
+                    }
+                    handled
+                });
+                if handled {
+                    notify_resize(total, window, cx);
+                    cx.stop_propagation();
+                }
+            });
+
+        let pane = |anchored: bool| {
+            div()
+                .overflow_hidden()
+                .map(|el| match (anchored, horizontal) {
+                    (true, true) => el.flex_none().w(px(anchored_size)).h_full(),
+                    (true, false) => el.flex_none().h(px(anchored_size)).w_full(),
+                    (false, true) => el.flex_1().min_w_0().h_full(),
+                    (false, false) => el.flex_1().min_h_0().w_full(),
+                })
+        };
+        let first = pane(!anchored_second).children(self.first);
+        let second = pane(anchored_second).children(self.second);
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .flex()
+            .size_full()
+            .map(|el| {
+                if horizontal {
+                    el.flex_row()
+                } else {
+                    el.flex_col()
+                }
+            })
+            .child(tracker)
+            .child(first)
+            .child(divider)
+            .child(second)
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
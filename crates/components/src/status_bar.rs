@@ -0,0 +1,292 @@
+//! StatusBar component: window-bottom bar with left, center, and right slots.
+//!
+//! Fork disposition: adapted from Zed's `status_bar.rs` layout.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - Zed `crates/workspace/src/status_bar.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Modifications: stateless RenderOnce with data-only segments instead of
+//!   status item views, status-token indicator dots, focusable interactive
+//!   segments with Enter/Space activation via internal primitives.
+
+use std::rc::Rc;
+
+use crate::tooltip::{TooltipPlacement, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use primitives::is_activation_key;
+use theme::ActiveTheme;
+
+/// Callback for a clicked status bar segment.
+pub type StatusCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// Colored dot shown before a segment's label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusIndicator {
+    /// Informational (e.g. work in progress).
+    Info,
+    /// Healthy or succeeded.
+    Success,
+    /// Needs attention.
+    Warning,
+    /// Failed.
+    Error,
+}
+
+/// Which part of the bar a segment sits in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusBarSlot {
+    /// Left-aligned, in insertion order.
+    Left,
+    /// Centered, in insertion order.
+    Center,
+    /// Right-aligned, in insertion order.
+    Right,
+}
+
+/// One label in a [`StatusBar`], optionally clickable.
+#[derive(Clone)]
+pub struct StatusSegment {
+    /// Id unique within the bar.
+    pub id: SharedString,
+    /// Display text.
+    pub label: SharedString,
+    /// Optional status dot.
+    pub indicator: Option<StatusIndicator>,
+    /// Optional hover tooltip (e.g. the full text of a truncated message).
+    pub tooltip: Option<SharedString>,
+    on_click: Option<StatusCallback>,
+}
+
+impl StatusSegment {
+    /// Create a plain text segment.
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            indicator: None,
+            tooltip: None,
+            on_click: None,
+        }
+    }
+
+    /// Show a status dot before the label.
+    pub fn indicator(mut self, indicator: StatusIndicator) -> Self {
+        self.indicator = Some(indicator);
+        self
+    }
+
+    /// Set a hover tooltip.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Make the segment interactive.
+    pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Whether the segment responds to clicks and Enter/Space.
+    pub fn is_interactive(&self) -> bool {
+        self.on_click.is_some()
+    }
+}
+
+impl std::fmt::Debug for StatusSegment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatusSegment")
+            .field("id", &self.id)
+            .field("label", &self.label)
+            .field("indicator", &self.indicator)
+            .field("tooltip", &self.tooltip)
+            .field("interactive", &self.is_interactive())
+            .finish()
+    }
+}
+
+/// A full-width bar of status segments, usually at the bottom of a window.
+///
+/// # Usage
+/// ```ignore
+/// StatusBar::new("status-bar")
+///     .left(StatusSegment::new("theme", "One Dark").on_click(|_window, _cx| {}))
+///     .right(StatusSegment::new("build", "Build passed").indicator(StatusIndicator::Success))
+/// ```
+#[derive(IntoElement)]
+pub struct StatusBar {
+    id: ElementId,
+    left: Vec<StatusSegment>,
+    center: Vec<StatusSegment>,
+    right: Vec<StatusSegment>,
+    identifiers: SharedIdentifiers,
+}
+
+impl StatusBar {
+    /// Create an empty status bar.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            left: Vec::new(),
+            center: Vec::new(),
+            right: Vec::new(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Append a segment to `slot`.
+    pub fn segment(mut self, slot: StatusBarSlot, segment: StatusSegment) -> Self {
+        match slot {
+            StatusBarSlot::Left => self.left.push(segment),
+            StatusBarSlot::Center => self.center.push(segment),
+            StatusBarSlot::Right => self.right.push(segment),
+        }
+        self
+    }
+
+    /// Append a segment to the left slot.
+    pub fn left(self, segment: StatusSegment) -> Self {
+        self.segment(StatusBarSlot::Left, segment)
+    }
+
+    /// Append a segment to the center slot.
+    pub fn center(self, segment: StatusSegment) -> Self {
+        self.segment(StatusBarSlot::Center, segment)
+    }
+
+    /// Append a segment to the right slot.
+    pub fn right(self, segment: StatusSegment) -> Self {
+        self.segment(StatusBarSlot::Right, segment)
+    }
+
+    /// Returns the component contract for StatusBar.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::status_bar()
+    }
+}
+
+impl WithIdentifiers for StatusBar {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for StatusBar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("StatusBar", &self.id, None, &self.identifiers, cx);
+
+        let bar_id = self.id.clone();
+        let mut render_slot = |segments: Vec<StatusSegment>, window: &mut Window| {
+            let mut slot = div().flex().flex_row().items_center().gap_1();
+            for segment in segments {
+                slot = slot.child(render_segment(&bar_id, segment, window, cx));
+            }
+            slot
+        };
+        let left = render_slot(self.left, window).flex_1().justify_start();
+        let center = render_slot(self.center, window).flex_none();
+        let right = render_slot(self.right, window).flex_1().justify_end();
+
+        let theme = cx.theme();
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .w_full()
+            .h(px(24.0))
+            .px_2()
+            .flex_shrink_0()
+            .bg(theme.chrome.status_bar_background)
+            .border_t_1()
+            .border_color(theme.border.default)
+            .child(left)
+            .child(center)
+            .child(right)
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+/// Render one segment; interactive segments are focusable buttons.
+fn render_segment(
+    bar_id: &ElementId,
+    segment: StatusSegment,
+    window: &mut Window,
+    cx: &mut App,
+) -> Stateful<Div> {
+    let key = |suffix: &str| SharedString::from(format!("{bar_id}-{}-{suffix}", segment.id));
+    let theme = cx.theme();
+    let text_color = theme.text.muted;
+    let hover_bg = theme.ghost_element.hover;
+    let focus_border = theme.border.focused;
+    let indicator_color = segment.indicator.map(|indicator| match indicator {
+        StatusIndicator::Info => theme.status.info.foreground,
+        StatusIndicator::Success => theme.status.success.foreground,
+        StatusIndicator::Warning => theme.status.warning.foreground,
+        StatusIndicator::Error => theme.status.error.foreground,
+    });
+
+    let mut element = div()
+        .id(key("segment"))
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap_1()
+        .px_1p5()
+        .h(px(20.0))
+        .rounded_sm()
+        .border_1()
+        .border_color(transparent_black())
+        .text_xs()
+        .text_color(text_color)
+        .whitespace_nowrap();
+    if let Some(color) = indicator_color {
+        element = element.child(div().size(px(6.0)).flex_shrink_0().rounded_full().bg(color));
+    }
+    element = element.child(segment.label);
+
+    if let Some(handler) = segment.on_click {
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        if focus_handle.is_focused(window) {
+            element = element.border_color(focus_border);
+        }
+        let key_handler = handler.clone();
+        element = element
+            .cursor_pointer()
+            .hover(move |s| s.bg(hover_bg))
+            .track_focus(&focus_handle)
+            .on_click(move |_event, window, cx| handler(window, cx))
+            .on_key_down(move |event, window, cx| {
+                if is_activation_key(event) {
+                    key_handler(window, cx);
+                    cx.stop_propagation();
+                }
+            });
+    }
+
+    // The bar sits at the window's bottom edge, so tooltips open upward.
+    attach_tooltip(
+        element,
+        &key("segment").into(),
+        segment.tooltip,
+        TooltipPlacement::Top,
+        window,
+        cx,
+    )
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -0,0 +1,335 @@
+//! Switch component: on/off toggle with a sliding thumb.
+//!
+//! Fork disposition: adapted from gpui-component `switch.rs`.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - gpui-component `crates/ui/src/switch.rs` (MIT, Zed Industries)
+//! - Modifications: stateless RenderOnce with ControlledState for the on/off
+//!   value, Button-style sizes, thumb transition keyed by interaction so only
+//!   user toggles animate, Space/Enter activation via internal primitives.
+
+use std::time::Duration;
+
+use crate::tooltip::{TooltipPlacement, WithTooltip, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::*;
+use primitives::{ControlledState, MotionPreference, is_activation_key};
+use theme::ActiveTheme;
+
+/// Duration of the thumb's slide between off and on.
+pub const SWITCH_TRANSITION: Duration = Duration::from_millis(120);
+
+/// Gap between the track edge and the thumb.
+const THUMB_INSET: f32 = 2.0;
+
+/// Switch size preset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwitchSize {
+    /// Small switch: 28×16px track.
+    Small,
+    /// Medium switch: 36×20px track (default).
+    #[default]
+    Medium,
+    /// Large switch: 44×24px track.
+    Large,
+}
+
+impl SwitchSize {
+    /// Track width and height.
+    pub fn track(self) -> (f32, f32) {
+        match self {
+            SwitchSize::Small => (28.0, 16.0),
+            SwitchSize::Medium => (36.0, 20.0),
+            SwitchSize::Large => (44.0, 24.0),
+        }
+    }
+
+    /// Thumb diameter.
+    pub fn thumb(self) -> f32 {
+        self.track().1 - THUMB_INSET * 2.0
+    }
+
+    /// Distance the thumb moves between off and on.
+    pub fn travel(self) -> f32 {
+        self.track().0 - self.thumb() - THUMB_INSET * 2.0
+    }
+}
+
+/// Thumb offset from the off position at `progress` (0.0..=1.0) of a slide
+/// from the `from` state to the `to` state.
+pub fn thumb_offset(size: SwitchSize, from: bool, to: bool, progress: f32) -> f32 {
+    let position = |on: bool| if on { size.travel() } else { 0.0 };
+    let (start, end) = (position(from), position(to));
+    start + (end - start) * progress.clamp(0.0, 1.0)
+}
+
+/// An on/off switch with an optional label.
+///
+/// Unlike [`Checkbox`](crate::Checkbox), a switch applies its setting
+/// immediately and has no indeterminate state.
+///
+/// # Usage
+/// ```ignore
+/// Switch::new("wifi")
+///     .label("Wi-Fi")
+///     .checked(enabled)
+///     .on_change(|on, _window, _cx| {
+///         println!("Wi-Fi: {on}");
+///     })
+/// ```
+#[derive(IntoElement)]
+pub struct Switch {
+    id: ElementId,
+    label: Option<SharedString>,
+    checked: ControlledState<bool>,
+    size: SwitchSize,
+    disabled: bool,
+    tooltip: Option<SharedString>,
+    tooltip_placement: TooltipPlacement,
+    identifiers: SharedIdentifiers,
+}
+
+impl Switch {
+    /// Create a new switch that starts off.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            label: None,
+            checked: ControlledState::new(),
+            size: SwitchSize::default(),
+            disabled: false,
+            tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the switch label, shown after the track.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Control the on/off state from the parent.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = self.checked.value(checked);
+        self
+    }
+
+    /// Set the initial on/off state for uncontrolled use.
+    pub fn default_checked(mut self, checked: bool) -> Self {
+        self.checked = self.checked.default_value(checked);
+        self
+    }
+
+    /// Set the size preset.
+    pub fn size(mut self, size: SwitchSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the disabled state.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the change handler.
+    pub fn on_change(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.checked = self
+            .checked
+            .on_change(move |checked, window, cx| handler(*checked, window, cx));
+        self
+    }
+
+    /// Set a tooltip.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for Switch.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::switch()
+    }
+}
+
+impl WithIdentifiers for Switch {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl WithTooltip for Switch {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
+    }
+}
+
+impl RenderOnce for Switch {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Switch",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        let checked_state = self.checked.bind(self.id.clone(), window, cx);
+        let checked = *checked_state.current();
+        let focus_handle = window
+            .use_keyed_state(key("focus"), cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        // Bumped on every user toggle, with the state toggled from. Only these
+        // toggles animate, so mounting or a parent-driven change jumps.
+        let motion = window.use_keyed_state(key("motion"), cx, |_, _| (0usize, false));
+        let (generation, from) = *motion.read(cx);
+
+        let theme = cx.theme();
+        let disabled = self.disabled;
+        let is_focused = focus_handle.is_focused(window);
+        let (track_bg, thumb_bg, label_color) = if disabled {
+            (
+                theme.element.disabled,
+                theme.text.disabled,
+                theme.text.disabled,
+            )
+        } else if checked {
+            (
+                theme.text.accent,
+                theme.surface.background,
+                theme.text.default,
+            )
+        } else {
+            (
+                theme.element.background,
+                theme.text.muted,
+                theme.text.default,
+            )
+        };
+        let track_border = if disabled {
+            theme.border.disabled
+        } else if is_focused {
+            theme.border.focused
+        } else if checked {
+            theme.border.selected
+        } else {
+            theme.border.default
+        };
+        let hover_bg = theme.element.hover;
+
+        let size = self.size;
+        let (track_width, track_height) = size.track();
+        // Offsets are measured inside the 1px track border.
+        let thumb = div()
+            .absolute()
+            .top(px(THUMB_INSET - 1.0))
+            .left(px(
+                THUMB_INSET - 1.0 + thumb_offset(size, checked, checked, 1.0)
+            ))
+            .size(px(size.thumb()))
+            .rounded_full()
+            .bg(thumb_bg);
+        let thumb = if generation > 0
+            && from != checked
+            && MotionPreference::global(cx).animates(SWITCH_TRANSITION)
+        {
+            thumb
+                .with_animation(
+                    ElementId::Name(format!("{}-thumb-{generation}", self.id).into()),
+                    Animation::new(SWITCH_TRANSITION).with_easing(ease_in_out),
+                    move |thumb, delta| {
+                        thumb.left(px(
+                            THUMB_INSET - 1.0 + thumb_offset(size, from, checked, delta)
+                        ))
+                    },
+                )
+                .into_any_element()
+        } else {
+            thumb.into_any_element()
+        };
+
+        let mut track = div()
+            .id(key("track"))
+            .relative()
+            .flex_shrink_0()
+            .w(px(track_width))
+            .h(px(track_height))
+            .rounded_full()
+            .border_1()
+            .border_color(track_border)
+            .bg(track_bg)
+            .child(thumb);
+        if !disabled && !checked {
+            track = track.hover(move |s| s.bg(hover_bg));
+        }
+
+        let mut container = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .cursor(if disabled {
+                CursorStyle::default()
+            } else {
+                CursorStyle::PointingHand
+            });
+
+        if !disabled {
+            let toggle = move |window: &mut Window, cx: &mut App| {
+                motion.update(cx, |motion, _| *motion = (motion.0 + 1, checked));
+                checked_state.set(!checked, window, cx);
+            };
+            let click_toggle = toggle.clone();
+            let handle = focus_handle.clone();
+            container = container
+                .track_focus(&focus_handle)
+                .on_click(move |_event, window, cx| {
+                    window.focus(&handle, cx);
+                    click_toggle(window, cx);
+                })
+                .on_key_down(move |event, window, cx| {
+                    if is_activation_key(event) {
+                        toggle(window, cx);
+                        cx.stop_propagation();
+                    }
+                });
+        }
+
+        container = container.child(track);
+
+        if let Some(label) = self.label {
+            container = container.child(div().text_sm().text_color(label_color).child(label));
+        }
+
+        attach_tooltip(
+            container,
+            &self.id,
+            self.tooltip,
+            self.tooltip_placement,
+            window,
+            cx,
+        )
+        .inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -9,16 +9,24 @@
 //! - Zed `crates/ui/src/components/tab.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - Zed `crates/ui/src/components/tab_bar.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - Modifications: Simplified to POC scope, combined tab+tabbar into single component,
-//!   rewired to internal token system, uses internal primitives for keyboard nav.
+//!   rewired to internal token system, uses internal primitives for keyboard nav
+//!   and controlled/uncontrolled active tab (ControlledState).
 
+use std::rc::Rc;
+
+use crate::tooltip::{TooltipPlacement, WithTooltip, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::prelude::FluentBuilder;
 use gpui::*;
-use primitives::{Orientation, classify_nav_key, is_activation_key, navigate_index};
+use primitives::{ControlledState, Orientation, classify_nav_key, navigate_index};
 use theme::ActiveTheme;
 
 /// Factory function type for rendering tab content panels.
 type ContentFactory = Box<dyn Fn(&mut App) -> AnyElement>;
 
+/// Callback when a tab's close button is pressed, with the tab's index.
+type CloseCallback = Rc<dyn Fn(usize, &mut Window, &mut App) + 'static>;
+
 /// A single tab definition.
 pub struct TabItem {
     /// Display label for this tab.
@@ -27,6 +35,10 @@ pub struct TabItem {
     pub content: Option<ContentFactory>,
     /// Whether this tab is disabled.
     pub disabled: bool,
+    /// Hover tooltip for this tab's trigger.
+    pub tooltip: Option<SharedString>,
+    /// Placement of this tab's own tooltip.
+    pub tooltip_placement: TooltipPlacement,
 }
 
 // Manual Debug impl since closures don't implement Debug
@@ -36,6 +48,8 @@ impl std::fmt::Debug for TabItem {
             .field("label", &self.label)
             .field("has_content", &self.content.is_some())
             .field("disabled", &self.disabled)
+            .field("tooltip", &self.tooltip)
+            .field("tooltip_placement", &self.tooltip_placement)
             .finish()
     }
 }
@@ -47,6 +61,8 @@ impl TabItem {
             label: label.into(),
             content: None,
             disabled: false,
+            tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
         }
     }
 
@@ -63,8 +79,15 @@ impl TabItem {
     }
 }
 
-/// Callback when the active tab changes.
-type OnChangeCallback = Box<dyn Fn(usize, &mut Window, &mut App) + 'static>;
+impl WithTooltip for TabItem {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
+    }
+}
 
 /// A tabbed navigation component with tab bar, arrow-key navigation,
 /// and content panels.
@@ -80,9 +103,12 @@ type OnChangeCallback = Box<dyn Fn(usize, &mut Window, &mut App) + 'static>;
 pub struct Tabs {
     id: ElementId,
     tabs: Vec<TabItem>,
-    active_index: usize,
-    on_change: Option<OnChangeCallback>,
+    active: ControlledState<usize>,
+    on_close: Option<CloseCallback>,
+    show_panel: bool,
     tooltip: Option<SharedString>,
+    tooltip_placement: TooltipPlacement,
+    identifiers: SharedIdentifiers,
     focus_handle: FocusHandle,
 }
 
@@ -93,9 +119,12 @@ impl Tabs {
         Self {
             id: id.into(),
             tabs: Vec::new(),
-            active_index: 0,
-            on_change: None,
+            active: ControlledState::new(),
+            on_close: None,
+            show_panel: true,
             tooltip: None,
+            tooltip_placement: TooltipPlacement::default(),
+            identifiers: SharedIdentifiers::default(),
             focus_handle,
         }
     }
@@ -112,19 +141,43 @@ impl Tabs {
         self
     }
 
-    /// Set the active tab index.
+    /// Control the active tab index from the parent.
     pub fn active_index(mut self, index: usize) -> Self {
-        self.active_index = index;
+        self.active = self.active.value(index);
+        self
+    }
+
+    /// Set the initially active tab index for uncontrolled use.
+    pub fn default_active_index(mut self, index: usize) -> Self {
+        self.active = self.active.default_value(index);
         self
     }
 
     /// Set the on_change callback (fires when active tab changes).
     pub fn on_change(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.active = self
+            .active
+            .on_change(move |index, window, cx| handler(*index, window, cx));
+        self
+    }
+
+    /// Make tabs closable: enabled tabs get a close button, and middle-click
+    /// closes them. The handler receives the tab's index; the parent removes
+    /// the tab and picks the new active index.
+    pub fn on_close(mut self, handler: impl Fn(usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set whether the active tab's content panel renders below the bar.
+    /// Turn it off when the parent renders the content itself.
+    pub fn show_panel(mut self, show_panel: bool) -> Self {
+        self.show_panel = show_panel;
         self
     }
 
     /// Set a tooltip.
+    #[deprecated(note = "use `WithTooltip::tooltip`")]
     pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
         self
@@ -132,65 +185,47 @@ impl Tabs {
 
     /// Returns the component contract for Tabs.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Tabs", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the tabs instance")
-            .required_prop("tabs", "Vec<TabItem>", "List of tab definitions")
-            .optional_prop(
-                "active_index",
-                "usize",
-                "0",
-                "Index of the currently active tab",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Focused)
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Selected)
-            .state(ComponentState::Disabled)
-            .token_dep("tab.bar_background", "Tab bar background color")
-            .token_dep("tab.active_background", "Active tab background color")
-            .token_dep("tab.inactive_background", "Inactive tab background color")
-            .token_dep("border.default", "Tab bar bottom border")
-            .token_dep("border.selected", "Active tab indicator")
-            .token_dep("text.default", "Active tab text color")
-            .token_dep("text.muted", "Inactive tab text color")
-            .token_dep("text.disabled", "Disabled tab text color")
-            .token_dep("ghost_element.hover", "Tab hover background")
-            .focus_behavior(
-                "Tab bar receives focus via Tab key. \
-                 Left/Right arrows navigate between tabs. \
-                 Tab/Shift-Tab moves focus out of the tab bar.",
-            )
-            .keyboard_model(
-                "Left/Right arrows move between tabs (wrapping). \
-                 Home/End jump to first/last tab. \
-                 Enter/Space activates the focused tab. \
-                 Disabled tabs are skipped during navigation.",
-            )
-            .pointer_behavior(
-                "Click on a tab activates it. \
-                 Hover shows highlight. \
-                 Disabled tabs do not respond to click.",
-            )
-            .state_model(
-                "Supports controlled (active_index) and uncontrolled mode. \
-                 on_change fires when active tab changes. \
-                 Each tab has its own disabled state.",
-            )
-            .disabled_behavior(
-                "Disabled tabs are visually dimmed, \
-                 skip during keyboard navigation, \
-                 and do not respond to click events.",
-            )
-            .required_file("crates/components/src/tabs.rs")
-            .build()
+        crate::catalog::tabs()
+    }
+}
+
+impl WithIdentifiers for Tabs {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl WithTooltip for Tabs {
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString> {
+        &mut self.tooltip
+    }
+
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement {
+        &mut self.tooltip_placement
     }
 }
 
 impl RenderOnce for Tabs {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Tabs",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let active_state = self.active.bind(self.id.clone(), window, cx);
+        let active_index = *active_state.current();
+
         let theme = cx.theme();
 
         let bar_bg = theme.tab.bar_background;
@@ -203,7 +238,6 @@ impl RenderOnce for Tabs {
         let disabled_color = theme.text.disabled;
         let hover_bg = theme.ghost_element.hover;
 
-        let active_index = self.active_index;
         let tab_count = self.tabs.len();
 
         // Build tab bar
@@ -220,16 +254,14 @@ impl RenderOnce for Tabs {
             // Keyboard navigation on the tab bar
             .on_key_down({
                 let tabs_disabled: Vec<bool> = self.tabs.iter().map(|t| t.disabled).collect();
-                move |event, _window, _cx| {
-                    if let Some(_dir) = classify_nav_key(event, Orientation::Horizontal) {
-                        let _next = navigate_index(active_index, _dir, tab_count, |i| {
+                let active_state = active_state.clone();
+                move |event, window, cx| {
+                    if let Some(dir) = classify_nav_key(event, Orientation::Horizontal) {
+                        let next = navigate_index(active_index, dir, tab_count, |i| {
                             tabs_disabled.get(i).copied().unwrap_or(false)
                         });
-                        // In a stateful version, this would update the active index.
-                        // For RenderOnce, the parent must handle on_change.
-                    }
-                    if is_activation_key(event) {
-                        // Activate the currently highlighted tab
+                        active_state.set(next, window, cx);
+                        cx.stop_propagation();
                     }
                 }
             });
@@ -275,17 +307,56 @@ impl RenderOnce for Tabs {
 
             // Only wire click on enabled tabs
             if !is_disabled {
-                tab_el = tab_el.on_mouse_down(MouseButton::Left, move |_event, _window, _cx| {
-                    // In stateful version, this would update active_index.
-                    // RenderOnce components delegate state to parent via on_change.
+                let active_state = active_state.clone();
+                tab_el = tab_el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                    active_state.set(idx, window, cx);
                 });
             }
 
-            tab_bar = tab_bar.child(tab_el);
+            if let Some(on_close) = self.on_close.clone().filter(|_| !is_disabled) {
+                let close_button = div()
+                    .id(ElementId::Name(format!("tab-close-{}", idx).into()))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .size(px(16.0))
+                    .ml_2()
+                    .rounded_sm()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .hover(|s| s.bg(hover_bg).text_color(text_color))
+                    .child("×")
+                    .on_mouse_down(MouseButton::Left, {
+                        let on_close = on_close.clone();
+                        move |_event, window, cx| {
+                            // Closing must not also activate the tab underneath.
+                            cx.stop_propagation();
+                            on_close(idx, window, cx);
+                        }
+                    });
+                tab_el = tab_el
+                    .pr_2()
+                    .child(close_button)
+                    .on_mouse_down(MouseButton::Middle, move |_event, window, cx| {
+                        on_close(idx, window, cx)
+                    });
+            }
+
+            // Per-tab tooltips take precedence over the Tabs-level tooltip.
+            let (tooltip, placement) = match &tab.tooltip {
+                Some(tooltip) => (Some(tooltip.clone()), tab.tooltip_placement),
+                None => (self.tooltip.clone(), self.tooltip_placement),
+            };
+            let tab_id = ElementId::Name(format!("{}-tab-{idx}", self.id).into());
+            tab_bar = tab_bar.child(attach_tooltip(
+                tab_el, &tab_id, tooltip, placement, window, cx,
+            ));
         }
 
         // Build content panel for active tab
-        let content_panel = if let Some(tab) = self.tabs.get(active_index) {
+        let content_panel = if !self.show_panel {
+            div()
+        } else if let Some(tab) = self.tabs.get(active_index) {
             if let Some(ref factory) = tab.content {
                 let content = factory(cx);
                 div().p_4().child(content)
@@ -308,6 +379,7 @@ impl RenderOnce for Tabs {
             .w_full()
             .child(tab_bar)
             .child(content_panel)
+            .inspectable(&self.id, Self::contract)
     }
 }
 
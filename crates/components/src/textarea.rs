@@ -5,17 +5,26 @@
 //!
 //! Provenance:
 //! - gpui-component multiline input patterns (MIT, Zed Industries)
-//! - Modifications: Simplified to internal token system, stateless RenderOnce.
+//! - Modifications: Simplified to internal token system, stateless RenderOnce, uses
+//!   internal primitives for controlled/uncontrolled value (ControlledState) and text
+//!   editing with IME composition (TextInput).
 
+use std::rc::Rc;
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
 use gpui::*;
+use primitives::{
+    CompositionEvent, CompositionHandler, ControlledState, TEXT_INPUT_KEY_CONTEXT, TextInput,
+    TextInputColors, TextInputElement,
+};
 use theme::ActiveTheme;
 
-/// Callback when the textarea value changes.
-type OnChangeCallback = Box<dyn Fn(&str, &mut Window, &mut App) + 'static>;
-
 /// A multi-line text input component with configurable rows,
 /// and builder-pattern API mapped to frozen design tokens.
 ///
+/// Enter inserts a newline. As in Input, input methods compose through the
+/// platform input handler and `on_change` sees committed text only.
+///
 /// # Usage
 /// ```ignore
 /// Textarea::new("bio-textarea")
@@ -26,15 +35,16 @@ type OnChangeCallback = Box<dyn Fn(&str, &mut Window, &mut App) + 'static>;
 #[derive(IntoElement)]
 pub struct Textarea {
     id: ElementId,
-    value: SharedString,
+    value: ControlledState<SharedString>,
     placeholder: SharedString,
     rows: u32,
     disabled: bool,
     readonly: bool,
     error: bool,
     error_message: Option<SharedString>,
-    on_change: Option<OnChangeCallback>,
+    on_composition: Option<CompositionHandler>,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
     full_width: bool,
 }
 
@@ -43,22 +53,29 @@ impl Textarea {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            value: SharedString::default(),
+            value: ControlledState::new(),
             placeholder: SharedString::default(),
             rows: 3,
             disabled: false,
             readonly: false,
             error: false,
             error_message: None,
-            on_change: None,
+            on_composition: None,
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
             full_width: false,
         }
     }
 
-    /// Set the textarea value.
+    /// Control the textarea value from the parent.
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
-        self.value = value.into();
+        self.value = self.value.value(value.into());
+        self
+    }
+
+    /// Set the initial value for uncontrolled use.
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = self.value.default_value(value.into());
         self
     }
 
@@ -101,7 +118,18 @@ impl Textarea {
 
     /// Set the change handler.
     pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
-        self.on_change = Some(Box::new(handler));
+        self.value = self
+            .value
+            .on_change(move |value, window, cx| handler(value, window, cx));
+        self
+    }
+
+    /// Set a handler for input method composition updates, commits, and cancels.
+    pub fn on_composition(
+        mut self,
+        handler: impl Fn(&CompositionEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_composition = Some(Rc::new(handler));
         self
     }
 
@@ -119,57 +147,54 @@ impl Textarea {
 
     /// Returns the component contract for Textarea.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Textarea", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the textarea")
-            .optional_prop("value", "SharedString", "\"\"", "Current text value")
-            .optional_prop("placeholder", "SharedString", "\"\"", "Placeholder text")
-            .optional_prop("rows", "u32", "3", "Number of visible rows")
-            .optional_prop("disabled", "bool", "false", "Whether disabled")
-            .optional_prop("readonly", "bool", "false", "Whether read-only")
-            .optional_prop("error", "bool", "false", "Whether in error state")
-            .optional_prop(
-                "error_message",
-                "Option<SharedString>",
-                "None",
-                "Error message below textarea",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .optional_prop("full_width", "bool", "false", "Take full container width")
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .state(ComponentState::Focused)
-            .state(ComponentState::Disabled)
-            .state(ComponentState::Error)
-            .state(ComponentState::Readonly)
-            .token_dep("element.background", "Textarea background")
-            .token_dep("element.hover", "Textarea hover background")
-            .token_dep("element.disabled", "Disabled textarea background")
-            .token_dep("text.default", "Textarea text color")
-            .token_dep("text.placeholder", "Placeholder text color")
-            .token_dep("text.disabled", "Disabled text color")
-            .token_dep("border.default", "Textarea border")
-            .token_dep("border.focused", "Focused textarea border")
-            .token_dep("border.disabled", "Disabled textarea border")
-            .token_dep("status.error.foreground", "Error message color")
-            .token_dep("status.error.border", "Error state border")
-            .focus_behavior("Tab/Shift-Tab navigates to/from textarea.")
-            .keyboard_model("Standard multiline text input. Enter creates newline.")
-            .pointer_behavior("Click focuses. Hover shows hover state.")
-            .state_model(
-                "Controlled value. Error state shows error border/message. \
-                 Readonly allows focus but not editing.",
-            )
-            .disabled_behavior("Disabled textareas show muted styling and cannot be focused.")
-            .readonly_behavior("Readonly textareas can be focused and selected but not edited.")
-            .required_file("crates/components/src/textarea.rs")
-            .build()
+        crate::catalog::textarea()
+    }
+}
+
+impl WithIdentifiers for Textarea {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Textarea {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Textarea",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let value = self.value.bind(self.id.clone(), window, cx);
+        let editable = !self.disabled && !self.readonly;
+        let text_input = window.use_keyed_state(
+            SharedString::from(format!("{}-text", self.id)),
+            cx,
+            |_, cx| TextInput::new(cx).multiline(true),
+        );
+        text_input.update(cx, |input, _| {
+            let bound = value.clone();
+            input.sync(
+                value.current(),
+                editable,
+                Some(Rc::new(move |text, window, cx| {
+                    bound.set(SharedString::from(text.to_string()), window, cx)
+                })),
+                self.on_composition.clone(),
+            );
+        });
+        let focus_handle = text_input.read(cx).focus_handle(cx);
+
         let theme = cx.theme();
 
         let (bg, border_color, text_color, placeholder_color) = if self.disabled {
@@ -201,6 +226,12 @@ impl RenderOnce for Textarea {
             theme.border.focused
         };
         let error_text_color = theme.status.error.foreground;
+        let colors = TextInputColors {
+            text: text_color,
+            placeholder: placeholder_color,
+            cursor: theme.text.accent,
+            selection: theme.element.selected,
+        };
         let disabled = self.disabled;
 
         // Height based on rows (approximate 20px per row + padding)
@@ -216,6 +247,7 @@ impl RenderOnce for Textarea {
             .border_1()
             .border_color(border_color)
             .rounded_md()
+            .overflow_y_scroll()
             .text_sm();
 
         if self.full_width {
@@ -225,15 +257,28 @@ impl RenderOnce for Textarea {
         }
 
         if !disabled {
-            field = field.hover(move |s| s.border_color(hover_border));
+            let handle = focus_handle.clone();
+            let mouse_input = text_input.clone();
+            let key_input = text_input.clone();
+            field = field
+                .track_focus(&focus_handle)
+                .key_context(TEXT_INPUT_KEY_CONTEXT)
+                .hover(move |s| s.border_color(hover_border))
+                .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                    window.focus(&handle, cx);
+                    mouse_input.update(cx, |input, cx| {
+                        input.move_to_point(event.position, event.modifiers.shift, cx)
+                    });
+                })
+                .on_key_down(move |event, window, cx| {
+                    if key_input.update(cx, |input, cx| input.handle_key_down(event, window, cx)) {
+                        cx.stop_propagation();
+                    }
+                });
         }
 
-        // Value or placeholder
-        if self.value.is_empty() {
-            field = field.child(div().text_color(placeholder_color).child(self.placeholder));
-        } else {
-            field = field.child(div().text_color(text_color).child(self.value));
-        }
+        // Editable text, preedit, and placeholder
+        field = field.child(TextInputElement::new(text_input, self.placeholder, colors));
 
         // Wrap with error message
         let mut wrapper = div().flex().flex_col().gap_1();
@@ -251,6 +296,6 @@ impl RenderOnce for Textarea {
             );
         }
 
-        wrapper
+        wrapper.inspectable(&self.id, Self::contract)
     }
 }
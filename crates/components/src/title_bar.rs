@@ -0,0 +1,341 @@
+//! TitleBar component: window-top bar with a drag region and platform-aware
+//! spacing for the window controls.
+//!
+//! Fork disposition: adapted from Zed's `platform_title_bar.rs`.
+//! Normalized to internal token/primitive contracts.
+//!
+//! Provenance:
+//! - Zed `crates/title_bar/src/platform_title_bar.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Zed `crates/title_bar/src/platforms/` caption buttons (GPL-3.0/AGPL-3.0, Zed Industries)
+//! - Modifications: stateless RenderOnce with start/end slots, keyed drag
+//!   tracking instead of a view field, platform passed in so stories can show
+//!   every layout side by side.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use smallvec::SmallVec;
+use theme::ActiveTheme;
+
+/// Space kept clear at the leading edge for the macOS traffic lights, with
+/// the lights at [`TRAFFIC_LIGHT_POSITION`].
+pub const TRAFFIC_LIGHT_INSET: f32 = 72.0;
+
+/// Where the studio asks macOS to draw the traffic lights, from the window's
+/// top-left corner.
+pub const TRAFFIC_LIGHT_POSITION: (f32, f32) = (9.0, 9.0);
+
+/// Width of each Windows caption button.
+const CAPTION_BUTTON_WIDTH: f32 = 46.0;
+
+/// Platform whose window-control layout a [`TitleBar`] follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TitleBarPlatform {
+    /// Traffic lights drawn by the system at the leading edge.
+    Mac,
+    /// Round caption buttons at the trailing edge, when the window draws
+    /// its own decorations.
+    Linux,
+    /// Full-height minimize/maximize/close caption buttons at the trailing edge.
+    Windows,
+}
+
+impl TitleBarPlatform {
+    /// The platform this binary was compiled for.
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Mac
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+
+    /// Leading space reserved for system-drawn controls. Fullscreen macOS
+    /// windows hide the traffic lights, so nothing is reserved.
+    pub fn leading_inset(self, fullscreen: bool) -> f32 {
+        match self {
+            Self::Mac if !fullscreen => TRAFFIC_LIGHT_INSET,
+            _ => 0.0,
+        }
+    }
+
+    /// Whether the title bar draws its own caption buttons. Linux windows
+    /// only need them when the app, not the window manager, decorates the
+    /// window.
+    pub fn draws_caption_buttons(self, client_decorated: bool) -> bool {
+        match self {
+            Self::Mac => false,
+            Self::Linux => client_decorated,
+            Self::Windows => true,
+        }
+    }
+}
+
+/// One window caption button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaptionButton {
+    Minimize,
+    Maximize,
+    Close,
+}
+
+impl CaptionButton {
+    const ALL: [CaptionButton; 3] = [Self::Minimize, Self::Maximize, Self::Close];
+
+    fn glyph(self, maximized: bool) -> &'static str {
+        match self {
+            Self::Minimize => "—",
+            Self::Maximize if maximized => "❐",
+            Self::Maximize => "☐",
+            Self::Close => "✕",
+        }
+    }
+
+    fn control_area(self) -> WindowControlArea {
+        match self {
+            Self::Minimize => WindowControlArea::Min,
+            Self::Maximize => WindowControlArea::Max,
+            Self::Close => WindowControlArea::Close,
+        }
+    }
+
+    fn id_suffix(self) -> &'static str {
+        match self {
+            Self::Minimize => "minimize",
+            Self::Maximize => "maximize",
+            Self::Close => "close",
+        }
+    }
+}
+
+/// A window title bar: drag to move the window, double-click to zoom, with
+/// room for the platform's window controls.
+///
+/// Open the window with a transparent system title bar (and, on macOS, the
+/// traffic lights at [`TRAFFIC_LIGHT_POSITION`]) so this bar replaces it.
+///
+/// # Usage
+/// ```ignore
+/// TitleBar::new("title-bar")
+///     .start(div().child("My App"))
+///     .end(Button::new("settings").label("Settings"))
+/// ```
+#[derive(IntoElement)]
+pub struct TitleBar {
+    id: ElementId,
+    platform: TitleBarPlatform,
+    height: Pixels,
+    start: SmallVec<[AnyElement; 2]>,
+    end: SmallVec<[AnyElement; 2]>,
+    identifiers: SharedIdentifiers,
+}
+
+impl TitleBar {
+    /// Create an empty title bar for the current platform.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            platform: TitleBarPlatform::current(),
+            height: px(32.0),
+            start: SmallVec::new(),
+            end: SmallVec::new(),
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Lay out for another platform (e.g. to preview it in a story).
+    pub fn platform(mut self, platform: TitleBarPlatform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// Set the bar height.
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Add content after the leading inset (e.g. the app name).
+    pub fn start(mut self, element: impl IntoElement) -> Self {
+        self.start.push(element.into_any_element());
+        self
+    }
+
+    /// Add content before the caption buttons (e.g. toolbar controls).
+    pub fn end(mut self, element: impl IntoElement) -> Self {
+        self.end.push(element.into_any_element());
+        self
+    }
+
+    /// Returns the component contract for TitleBar.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::title_bar()
+    }
+}
+
+impl WithIdentifiers for TitleBar {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for TitleBar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("TitleBar", &self.id, None, &self.identifiers, cx);
+
+        let key = |suffix: &str| SharedString::from(format!("{}-{suffix}", self.id));
+        // Set on mouse down and cleared on release: the first move while set
+        // hands the drag to the window manager.
+        let should_move = window.use_keyed_state(key("drag"), cx, |_, _| false);
+
+        let platform = self.platform;
+        let client_decorated = matches!(window.window_decorations(), Decorations::Client { .. });
+        let maximized = window.is_maximized();
+        let leading_inset = platform.leading_inset(window.is_fullscreen());
+
+        let theme = cx.theme();
+        let text_color = theme.text.muted;
+        let hover_bg = theme.ghost_element.hover;
+        let close_hover_bg = theme.status.error.element_hover;
+
+        let mut start = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_2()
+            .min_w_0()
+            .pl(px(leading_inset));
+        for element in self.start {
+            start = start.child(element);
+        }
+        // End content is usually controls: pressing them must not start a drag.
+        let mut end = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_3()
+            .on_mouse_down(MouseButton::Left, |_event, _window, cx| {
+                cx.stop_propagation();
+            });
+        for element in self.end {
+            end = end.child(element);
+        }
+
+        let mut bar = div()
+            .id(self.id.clone())
+            .window_control_area(WindowControlArea::Drag)
+            .flex()
+            .flex_row()
+            .items_center()
+            .justify_between()
+            .flex_shrink_0()
+            .w_full()
+            .h(self.height)
+            .pl_4()
+            .bg(theme.chrome.title_bar_background)
+            .border_b_1()
+            .border_color(theme.border.default)
+            .on_mouse_down(MouseButton::Left, {
+                let should_move = should_move.clone();
+                move |_event, _window, cx| {
+                    should_move.update(cx, |should_move, _| *should_move = true);
+                }
+            })
+            .on_mouse_up(MouseButton::Left, {
+                let should_move = should_move.clone();
+                move |_event, _window, cx| {
+                    should_move.update(cx, |should_move, _| *should_move = false);
+                }
+            })
+            .on_mouse_move({
+                let should_move = should_move.clone();
+                move |_event, window, cx| {
+                    if *should_move.read(cx) {
+                        should_move.update(cx, |should_move, _| *should_move = false);
+                        window.start_window_move();
+                    }
+                }
+            })
+            .on_click(move |event, window, _cx| {
+                if event.click_count() == 2 {
+                    match platform {
+                        TitleBarPlatform::Mac => window.titlebar_double_click(),
+                        _ => window.zoom_window(),
+                    }
+                }
+            })
+            .child(start);
+
+        let draws_caption_buttons = platform.draws_caption_buttons(client_decorated);
+        let mut trailing = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .h_full()
+            .gap_2()
+            .child(end)
+            .when(!draws_caption_buttons, |el| el.pr_4());
+        if draws_caption_buttons {
+            let mut buttons = div().flex().flex_row().items_center().h_full();
+            if platform == TitleBarPlatform::Linux {
+                buttons = buttons.gap_1().px_2();
+            }
+            for button in CaptionButton::ALL {
+                let hover = if button == CaptionButton::Close {
+                    close_hover_bg
+                } else {
+                    hover_bg
+                };
+                let caption = div()
+                    .id(key(button.id_suffix()))
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_xs()
+                    .text_color(text_color)
+                    .hover(move |s| s.bg(hover))
+                    .child(button.glyph(maximized));
+                let caption = match platform {
+                    // Windows handles caption clicks itself through the hit-test area.
+                    TitleBarPlatform::Windows => caption
+                        .window_control_area(button.control_area())
+                        .w(px(CAPTION_BUTTON_WIDTH))
+                        .h_full(),
+                    _ => caption
+                        .size(px(24.0))
+                        .rounded_full()
+                        .cursor_pointer()
+                        .on_mouse_down(MouseButton::Left, |_event, _window, cx| {
+                            cx.stop_propagation();
+                        })
+                        .on_click(move |_event, window, cx| {
+                            cx.stop_propagation();
+                            match button {
+                                CaptionButton::Minimize => window.minimize_window(),
+                                CaptionButton::Maximize => window.zoom_window(),
+                                CaptionButton::Close => window.remove_window(),
+                            }
+                        }),
+                };
+                buttons = buttons.child(caption);
+            }
+            trailing = trailing.child(buttons);
+        }
+        bar = bar.child(trailing);
+
+        bar.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
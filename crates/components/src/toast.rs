@@ -7,9 +7,31 @@
 //! - gpui-component `crates/ui/src/notification.rs` (MIT, Zed Industries)
 //! - Zed notification visual patterns (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - Modifications: Simplified to internal token system, stateless RenderOnce,
-//!   supports multiple concurrent toasts via stacking.
+//!   supports multiple concurrent toasts via stacking, enter/exit transitions
+//!   via the shared OverlayStateMachine.
+//!
+//! [`ToastManager`] owns the stack of live toasts and applies the stacking
+//! rules (newest first, bounded visible count, FIFO overflow queue, in-place
+//! updates by id). It is plain state: a view can store its own and render
+//! [`ToastManager::visible`] each frame.
+//!
+//! The app-wide manager is a GPUI global: [`ToastManager::show`],
+//! [`hide`](ToastManager::hide), and [`hide_all`](ToastManager::hide_all)
+//! update it from anywhere and run its transitions and auto-dismiss timers,
+//! and a [`ToastLayer`] mounted once at the window root renders it in the
+//! configured corner, pausing the timers while the pointer is over it.
 
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::NotificationHistory;
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
 use gpui::*;
+use primitives::{
+    Locale, MotionPreference, OverlayPhase, OverlayStateMachine, format_relative_time,
+};
 use theme::ActiveTheme;
 
 /// Toast variant controlling the color scheme and semantics.
@@ -26,14 +48,23 @@ pub enum ToastVariant {
     Error,
 }
 
-/// Callback when the toast is dismissed.
-type OnDismissCallback = Box<dyn FnOnce(&mut Window, &mut App) + 'static>;
+impl ToastVariant {
+    /// Glyph shown before the toast content.
+    pub fn icon(self) -> &'static str {
+        match self {
+            ToastVariant::Info => "ℹ",
+            ToastVariant::Success => "✓",
+            ToastVariant::Warning => "⚠",
+            ToastVariant::Error => "✕",
+        }
+    }
+}
 
-/// Callback for toast action button.
-type OnActionCallback = Box<dyn FnOnce(&mut Window, &mut App) + 'static>;
+/// Callback for the toast action and dismiss buttons.
+pub type ToastCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
 
 /// A transient notification component with variant styling, optional action button,
-/// and builder-pattern API mapped to frozen design tokens.
+/// optional progress bar, and builder-pattern API mapped to frozen design tokens.
 ///
 /// # Usage
 /// ```ignore
@@ -41,6 +72,11 @@ type OnActionCallback = Box<dyn FnOnce(&mut Window, &mut App) + 'static>;
 ///     .title("File saved")
 ///     .description("Your changes have been saved.")
 ///     .variant(ToastVariant::Success)
+///     .action("Undo", |_window, _cx| {})
+///
+/// Toast::new("export-toast")
+///     .title("Exporting tokens")
+///     .progress(0.4)
 /// ```
 #[derive(IntoElement)]
 pub struct Toast {
@@ -49,10 +85,14 @@ pub struct Toast {
     description: Option<SharedString>,
     variant: ToastVariant,
     action_label: Option<SharedString>,
-    on_action: Option<OnActionCallback>,
-    on_dismiss: Option<OnDismissCallback>,
+    on_action: Option<ToastCallback>,
+    on_dismiss: Option<ToastCallback>,
     show_dismiss: bool,
+    progress: Option<f32>,
+    timestamp: Option<SystemTime>,
+    overlay: OverlayStateMachine,
     tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
 }
 
 impl Toast {
@@ -67,7 +107,11 @@ impl Toast {
             on_action: None,
             on_dismiss: None,
             show_dismiss: true,
+            progress: None,
+            timestamp: None,
+            overlay: OverlayStateMachine::opened(),
             tooltip: None,
+            identifiers: SharedIdentifiers::default(),
         }
     }
 
@@ -89,20 +133,33 @@ impl Toast {
         self
     }
 
-    /// Set an action button.
+    /// Set an action button (e.g. "Undo").
     pub fn action(
         mut self,
         label: impl Into<SharedString>,
-        handler: impl FnOnce(&mut Window, &mut App) + 'static,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
     ) -> Self {
         self.action_label = Some(label.into());
-        self.on_action = Some(Box::new(handler));
+        self.on_action = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the dismiss handler, called when the dismiss button is clicked.
+    pub fn on_dismiss(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+
+    /// Show a progress bar for a background task; `fraction` is clamped to `0.0..=1.0`.
+    pub fn progress(mut self, fraction: f32) -> Self {
+        self.progress = Some(fraction.clamp(0.0, 1.0));
         self
     }
 
-    /// Set the dismiss handler.
-    pub fn on_dismiss(mut self, handler: impl FnOnce(&mut Window, &mut App) + 'static) -> Self {
-        self.on_dismiss = Some(Box::new(handler));
+    /// Show when the toast was raised, as a relative time in the app's
+    /// [`Locale`] ("5 minutes ago").
+    pub fn timestamp(mut self, at: SystemTime) -> Self {
+        self.timestamp = Some(at);
         self
     }
 
@@ -112,6 +169,15 @@ impl Toast {
         self
     }
 
+    /// Drive the toast from an owner-held overlay state machine.
+    ///
+    /// Defaults to settled `Open`. The toast fades in during `Opening` and out
+    /// during `Closing` (e.g. after dismiss or auto-dismiss timeout).
+    pub fn overlay_state(mut self, overlay: OverlayStateMachine) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
     /// Set a tooltip.
     pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
         self.tooltip = Some(tooltip.into());
@@ -120,74 +186,34 @@ impl Toast {
 
     /// Returns the component contract for Toast.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Toast", "0.1.0")
-            .disposition(Disposition::Fork)
-            .required_prop("id", "ElementId", "Unique identifier for the toast")
-            .optional_prop("title", "SharedString", "\"\"", "Toast title text")
-            .optional_prop(
-                "description",
-                "Option<SharedString>",
-                "None",
-                "Toast description text",
-            )
-            .optional_prop(
-                "variant",
-                "ToastVariant",
-                "Info",
-                "Variant: Info, Success, Warning, Error",
-            )
-            .optional_prop(
-                "action_label",
-                "Option<SharedString>",
-                "None",
-                "Action button label",
-            )
-            .optional_prop(
-                "show_dismiss",
-                "bool",
-                "true",
-                "Whether to show dismiss button",
-            )
-            .optional_prop("tooltip", "Option<SharedString>", "None", "Tooltip text")
-            .state(ComponentState::Hover)
-            .state(ComponentState::Active)
-            .variant("Info")
-            .variant("Success")
-            .variant("Warning")
-            .variant("Error")
-            .token_dep("surface.elevated_surface", "Toast background")
-            .token_dep("border.default", "Toast default border")
-            .token_dep("text.default", "Toast title text")
-            .token_dep("text.muted", "Toast description text")
-            .token_dep("ghost_element.hover", "Dismiss button hover")
-            .token_dep("status.info.foreground", "Info variant accent")
-            .token_dep("status.info.border", "Info variant border")
-            .token_dep("status.success.foreground", "Success variant accent")
-            .token_dep("status.success.border", "Success variant border")
-            .token_dep("status.warning.foreground", "Warning variant accent")
-            .token_dep("status.warning.border", "Warning variant border")
-            .token_dep("status.error.foreground", "Error variant accent")
-            .token_dep("status.error.border", "Error variant border")
-            .focus_behavior("Toasts are not focusable by default. Action buttons receive focus.")
-            .keyboard_model(
-                "Escape may dismiss the topmost toast. Action button responds to Enter/Space.",
-            )
-            .pointer_behavior(
-                "Click dismiss button to close. Click action button to trigger action.",
-            )
-            .state_model(
-                "Toasts support multiple concurrent instances (stacking). \
-                 Each toast has an auto-dismiss timer (not implemented in RenderOnce -- \
-                 requires Entity-based stateful variant for timers).",
-            )
-            .required_file("crates/components/src/toast.rs")
-            .build()
+        crate::catalog::toast()
+    }
+}
+
+impl WithIdentifiers for Toast {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Toast {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Toast",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
         let theme = cx.theme();
 
         let bg = theme.surface.elevated_surface;
@@ -203,13 +229,14 @@ impl RenderOnce for Toast {
             ToastVariant::Error => (theme.status.error.foreground, theme.status.error.border),
         };
 
-        // Variant icon character
-        let icon = match self.variant {
-            ToastVariant::Info => "ℹ",
-            ToastVariant::Success => "✓",
-            ToastVariant::Warning => "⚠",
-            ToastVariant::Error => "✕",
-        };
+        let icon = self.variant.icon();
+
+        if !self.overlay.is_visible() {
+            return div().into_any_element();
+        }
+        let phase = self.overlay.phase();
+        let accepts_input = self.overlay.accepts_input();
+        let animation_id = self.id.clone();
 
         let mut toast = div()
             .id(self.id)
@@ -255,16 +282,56 @@ impl RenderOnce for Toast {
             content = content.child(div().text_xs().text_color(desc_color).child(desc));
         }
 
+        // Timestamp (a clock set back counts as just now)
+        if let Some(at) = self.timestamp {
+            let elapsed = SystemTime::now().duration_since(at).unwrap_or_default();
+            content = content.child(
+                div()
+                    .text_xs()
+                    .text_color(desc_color)
+                    .child(format_relative_time(elapsed, Locale::global(cx))),
+            );
+        }
+
+        // Progress bar
+        if let Some(progress) = self.progress {
+            content = content.child(
+                div()
+                    .mt_1()
+                    .h(px(4.0))
+                    .w_full()
+                    .rounded_full()
+                    .bg(theme.element.background)
+                    .child(
+                        div()
+                            .h_full()
+                            .w(relative(progress))
+                            .rounded_full()
+                            .bg(accent_color),
+                    ),
+            );
+        }
+
         // Action button
         if let Some(action_label) = self.action_label {
             content = content.child(
                 div()
                     .id("toast-action")
                     .cursor_pointer()
+                    .self_start()
+                    .rounded_sm()
+                    .px_1()
                     .text_xs()
                     .font_weight(FontWeight::MEDIUM)
                     .text_color(accent_color)
+                    .hover(move |s| s.bg(dismiss_hover))
                     .mt_1()
+                    .when_some(self.on_action.filter(|_| accepts_input), |el, handler| {
+                        el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                            cx.stop_propagation();
+                            handler(window, cx);
+                        })
+                    })
                     .child(action_label),
             );
         }
@@ -283,10 +350,678 @@ impl RenderOnce for Toast {
                     .text_color(desc_color)
                     .hover(move |s| s.bg(dismiss_hover))
                     .flex_shrink_0()
+                    .when_some(self.on_dismiss.filter(|_| accepts_input), |el, handler| {
+                        el.on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                            cx.stop_propagation();
+                            handler(window, cx);
+                        })
+                    })
                     .child("✕"),
             );
         }
 
+        let toast = toast.inspectable(&animation_id, Self::contract);
+        if MotionPreference::global(cx).animates(phase.transition_duration()) {
+            toast
+                .with_animation(
+                    ElementId::Name(format!("{}-{:?}", animation_id, phase).into()),
+                    Animation::new(phase.transition_duration()).with_easing(ease_in_out),
+                    move |this, delta| this.opacity(phase.opacity(delta)),
+                )
+                .into_any_element()
+        } else {
+            toast.into_any_element()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// ToastManager
+// ---------------------------------------------------------------------------
+
+/// Auto-dismiss delay for Info and Success toasts.
+pub const TOAST_AUTO_DISMISS: Duration = Duration::from_secs(5);
+
+/// Window corner a [`ToastLayer`] stacks toasts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+impl ToastPosition {
+    /// Every corner, in reading order.
+    pub const ALL: [ToastPosition; 4] = [
+        Self::TopLeft,
+        Self::TopRight,
+        Self::BottomLeft,
+        Self::BottomRight,
+    ];
+
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TopLeft => "Top left",
+            Self::TopRight => "Top right",
+            Self::BottomLeft => "Bottom left",
+            Self::BottomRight => "Bottom right",
+        }
+    }
+
+    /// Whether toasts stack up from the bottom edge.
+    pub fn is_bottom(self) -> bool {
+        matches!(self, Self::BottomLeft | Self::BottomRight)
+    }
+
+    /// Whether toasts hug the left edge.
+    pub fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// A pausable countdown to a toast's auto-dismissal.
+#[derive(Debug, Clone, Copy)]
+struct DismissTimer {
+    /// Time left when the countdown last stopped (or was created).
+    remaining: Duration,
+    /// When the countdown last resumed; `None` while it is stopped.
+    running_since: Option<Instant>,
+}
+
+impl DismissTimer {
+    fn new(delay: Duration) -> Self {
+        Self {
+            remaining: delay,
+            running_since: None,
+        }
+    }
+
+    fn remaining_at(&self, now: Instant) -> Duration {
+        let elapsed = self
+            .running_since
+            .map_or(Duration::ZERO, |since| now.saturating_duration_since(since));
+        self.remaining.saturating_sub(elapsed)
+    }
+
+    fn start(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    fn stop(&mut self, now: Instant) {
+        self.remaining = self.remaining_at(now);
+        self.running_since = None;
+    }
+}
+
+/// A toast owned by a [`ToastManager`].
+#[derive(Clone)]
+pub struct ToastEntry {
+    /// Stable id; pushing another entry with the same id updates this one.
+    pub id: SharedString,
+    /// Title text.
+    pub title: SharedString,
+    /// Optional description text.
+    pub description: Option<SharedString>,
+    /// Color scheme and semantics.
+    pub variant: ToastVariant,
+    /// Background task completion, if this is a progress toast.
+    pub progress: Option<f32>,
+    /// When the toast was raised, if it shows a timestamp.
+    pub timestamp: Option<SystemTime>,
+    pub(crate) action: Option<(SharedString, ToastCallback)>,
+    overlay: OverlayStateMachine,
+    /// Auto-dismiss countdown, set while the toast is visible.
+    timer: Option<DismissTimer>,
+}
+
+impl ToastEntry {
+    /// Create an Info toast entry.
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            description: None,
+            variant: ToastVariant::Info,
+            progress: None,
+            timestamp: None,
+            action: None,
+            overlay: OverlayStateMachine::new(),
+            timer: None,
+        }
+    }
+
+    /// Set the description.
+    pub fn description(mut self, desc: impl Into<SharedString>) -> Self {
+        self.description = Some(desc.into());
+        self
+    }
+
+    /// Set the variant.
+    pub fn variant(mut self, variant: ToastVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Make this a progress toast; `fraction` is clamped to `0.0..=1.0`.
+    pub fn progress(mut self, fraction: f32) -> Self {
+        self.progress = Some(fraction.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Show when the toast was raised.
+    pub fn timestamp(mut self, at: SystemTime) -> Self {
+        self.timestamp = Some(at);
+        self
+    }
+
+    /// Attach an action button.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Rc::new(handler)));
+        self
+    }
+
+    /// Current lifecycle phase.
+    pub fn overlay(&self) -> OverlayStateMachine {
+        self.overlay
+    }
+
+    /// How long the toast stays up before it should be dismissed automatically.
+    ///
+    /// `None` for Warning and Error toasts, which need acknowledgement, and for
+    /// progress toasts whose task has not completed yet.
+    pub fn auto_dismiss(&self) -> Option<Duration> {
+        if self.progress.is_some_and(|p| p < 1.0) {
+            return None;
+        }
+        match self.variant {
+            ToastVariant::Info | ToastVariant::Success => Some(TOAST_AUTO_DISMISS),
+            ToastVariant::Warning | ToastVariant::Error => None,
+        }
+    }
+
+    /// Build the [`Toast`] element for this entry.
+    pub fn to_toast(&self) -> Toast {
+        let mut toast = Toast::new(ElementId::Name(self.id.clone()))
+            .title(self.title.clone())
+            .variant(self.variant)
+            .overlay_state(self.overlay);
+        if let Some(desc) = &self.description {
+            toast = toast.description(desc.clone());
+        }
+        if let Some(progress) = self.progress {
+            toast = toast.progress(progress);
+        }
+        if let Some(at) = self.timestamp {
+            toast = toast.timestamp(at);
+        }
+        if let Some((label, handler)) = &self.action {
+            toast.action_label = Some(label.clone());
+            toast.on_action = Some(handler.clone());
+        }
         toast
     }
 }
+
+impl std::fmt::Debug for ToastEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastEntry")
+            .field("id", &self.id)
+            .field("title", &self.title)
+            .field("variant", &self.variant)
+            .field("progress", &self.progress)
+            .field("timestamp", &self.timestamp)
+            .field("has_action", &self.action.is_some())
+            .field("phase", &self.overlay.phase())
+            .finish()
+    }
+}
+
+/// Stack of live toasts with the stacking rules applied.
+///
+/// Rules:
+/// - Newest toasts stack on top (first in [`visible`](Self::visible)).
+/// - At most `max_visible` toasts are shown. Further toasts wait in a FIFO
+///   queue and take a slot once a visible toast has finished closing; visible
+///   toasts are never displaced by newer ones.
+/// - Pushing an id that is already live updates that toast in place instead of
+///   stacking a duplicate (used for progress updates).
+/// - Dismissal starts the exit transition; the toast is removed once
+///   [`finish_transitions`](Self::finish_transitions) settles it `Closed`,
+///   which hands it back for a
+///   [`NotificationHistory`](crate::NotificationHistory).
+/// - Visible toasts with an [`auto_dismiss`](ToastEntry::auto_dismiss) delay
+///   count down from the first [`expire`](Self::expire) after they appear;
+///   [`set_paused`](Self::set_paused) stops every countdown (pause on hover)
+///   and an in-place update restarts it.
+#[derive(Debug)]
+pub struct ToastManager {
+    /// Toasts occupying a visible slot, newest first.
+    visible: Vec<ToastEntry>,
+    /// Toasts waiting for a slot, oldest first.
+    queue: VecDeque<ToastEntry>,
+    /// Dismissed toasts not yet returned by `finish_transitions`.
+    closed: Vec<ToastEntry>,
+    max_visible: usize,
+    position: ToastPosition,
+    /// Whether the countdowns are stopped, e.g. while the stack is hovered.
+    paused: bool,
+    /// Bumped whenever the global reschedules its timer, so stale timers
+    /// wake to nothing.
+    timer_generation: u64,
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToastManager {
+    /// Default number of simultaneously visible toasts.
+    pub const DEFAULT_MAX_VISIBLE: usize = 3;
+
+    /// Create an empty manager showing up to three toasts.
+    pub fn new() -> Self {
+        Self {
+            visible: Vec::new(),
+            queue: VecDeque::new(),
+            closed: Vec::new(),
+            max_visible: Self::DEFAULT_MAX_VISIBLE,
+            position: ToastPosition::default(),
+            paused: false,
+            timer_generation: 0,
+        }
+    }
+
+    /// Set how many toasts are shown at once (at least one).
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Set the corner a [`ToastLayer`] stacks the toasts in.
+    pub fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Move the stack to another corner.
+    pub fn set_position(&mut self, position: ToastPosition) {
+        self.position = position;
+    }
+
+    /// The corner a [`ToastLayer`] stacks the toasts in.
+    pub fn current_position(&self) -> ToastPosition {
+        self.position
+    }
+
+    /// Show a toast, or update the live toast with the same id.
+    pub fn push(&mut self, entry: ToastEntry) {
+        if let Some(existing) = self.visible.iter_mut().find(|e| e.id == entry.id) {
+            let mut overlay = existing.overlay;
+            // Updating a closing toast brings it back.
+            if overlay.phase() == OverlayPhase::Closing {
+                overlay.open();
+            }
+            // New content gets the full delay again.
+            let timer = entry.auto_dismiss().map(DismissTimer::new);
+            *existing = ToastEntry {
+                overlay,
+                timer,
+                ..entry
+            };
+            return;
+        }
+        if let Some(existing) = self.queue.iter_mut().find(|e| e.id == entry.id) {
+            *existing = entry;
+            return;
+        }
+        self.queue.push_back(entry);
+        self.promote();
+    }
+
+    /// Update the progress of a live toast. Returns false if the id is unknown.
+    pub fn set_progress(&mut self, id: &str, fraction: f32) -> bool {
+        match self
+            .visible
+            .iter_mut()
+            .chain(self.queue.iter_mut())
+            .find(|e| e.id.as_ref() == id)
+        {
+            Some(entry) => {
+                entry.progress = Some(fraction.clamp(0.0, 1.0));
+                // A finished task starts counting down; queued toasts get
+                // their timer once promoted.
+                if entry.overlay.is_visible() && entry.timer.is_none() {
+                    entry.timer = entry.auto_dismiss().map(DismissTimer::new);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Start dismissing a toast. Queued toasts are removed immediately.
+    ///
+    /// Returns false if the id is unknown or already closing.
+    pub fn dismiss(&mut self, id: &str) -> bool {
+        if let Some(entry) = self.visible.iter_mut().find(|e| e.id.as_ref() == id) {
+            return entry.overlay.close();
+        }
+        match self.queue.iter().position(|e| e.id.as_ref() == id) {
+            Some(index) => {
+                self.closed.extend(self.queue.remove(index));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Dismiss every toast, dropping the queue.
+    pub fn dismiss_all(&mut self) {
+        self.closed.extend(self.queue.drain(..));
+        for entry in &mut self.visible {
+            entry.overlay.close();
+        }
+    }
+
+    /// Settle running enter/exit transitions, drop closed toasts, and promote
+    /// queued toasts into the freed slots.
+    ///
+    /// Returns the toasts removed since the last call, for [`NotificationHistory::record_closed`](crate::NotificationHistory::record_closed).
+    /// Call once the overlay transition duration has elapsed.
+    pub fn finish_transitions(&mut self) -> Vec<ToastEntry> {
+        for entry in &mut self.visible {
+            entry.overlay.finish_transition();
+        }
+        let (visible, closed): (Vec<_>, Vec<_>) = std::mem::take(&mut self.visible)
+            .into_iter()
+            .partition(|e| e.overlay.is_visible());
+        self.visible = visible;
+        self.closed.extend(closed);
+        self.promote();
+        std::mem::take(&mut self.closed)
+    }
+
+    /// Start any new countdowns at `now` (unless paused) and dismiss the
+    /// visible toasts whose countdown has run out.
+    ///
+    /// Returns the ids dismissed by this call.
+    pub fn expire(&mut self, now: Instant) -> Vec<SharedString> {
+        let mut expired = Vec::new();
+        for entry in &mut self.visible {
+            let Some(timer) = &mut entry.timer else {
+                continue;
+            };
+            if !self.paused {
+                timer.start(now);
+            }
+            if timer.remaining_at(now).is_zero() {
+                entry.timer = None;
+                if entry.overlay.close() {
+                    expired.push(entry.id.clone());
+                }
+            }
+        }
+        expired
+    }
+
+    /// How long until the next countdown runs out, or `None` if no toast
+    /// is counting down.
+    pub fn next_deadline(&self, now: Instant) -> Option<Duration> {
+        if self.paused {
+            return None;
+        }
+        self.visible
+            .iter()
+            .filter_map(|entry| entry.timer.as_ref())
+            .map(|timer| timer.remaining_at(now))
+            .min()
+    }
+
+    /// Stop (or resume) every countdown at `now`.
+    pub fn set_paused(&mut self, paused: bool, now: Instant) {
+        self.paused = paused;
+        for timer in self.visible.iter_mut().filter_map(|e| e.timer.as_mut()) {
+            if paused {
+                timer.stop(now);
+            } else {
+                timer.start(now);
+            }
+        }
+    }
+
+    /// Whether the countdowns are stopped.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Time left before a visible toast dismisses itself, or `None` if it
+    /// is not counting down.
+    pub fn remaining(&self, id: &str, now: Instant) -> Option<Duration> {
+        self.visible
+            .iter()
+            .find(|e| e.id.as_ref() == id)
+            .and_then(|e| e.timer.as_ref())
+            .map(|timer| timer.remaining_at(now))
+    }
+
+    /// Toasts to render, newest first.
+    pub fn visible(&self) -> impl Iterator<Item = &ToastEntry> {
+        self.visible.iter()
+    }
+
+    /// Number of toasts waiting for a visible slot.
+    pub fn queued_len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Look up a live toast by id.
+    pub fn get(&self, id: &str) -> Option<&ToastEntry> {
+        self.visible
+            .iter()
+            .chain(self.queue.iter())
+            .find(|e| e.id.as_ref() == id)
+    }
+
+    /// Number of live toasts, visible and queued.
+    pub fn len(&self) -> usize {
+        self.visible.len() + self.queue.len()
+    }
+
+    /// Returns true if there are no live toasts.
+    pub fn is_empty(&self) -> bool {
+        self.visible.is_empty() && self.queue.is_empty()
+    }
+
+    /// Move queued toasts into free visible slots and start their enter transition.
+    fn promote(&mut self) {
+        while self.visible.len() < self.max_visible {
+            let Some(mut entry) = self.queue.pop_front() else {
+                break;
+            };
+            entry.overlay.open();
+            entry.timer = entry.auto_dismiss().map(DismissTimer::new);
+            self.visible.insert(0, entry);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// App-wide toasts
+// ---------------------------------------------------------------------------
+
+impl Global for ToastManager {}
+
+impl ToastManager {
+    /// Show a toast in the app-wide manager (or update the live toast with
+    /// the same id) and schedule its enter transition and auto-dismissal.
+    pub fn show(entry: ToastEntry, cx: &mut App) {
+        cx.default_global::<ToastManager>().push(entry);
+        settle_after(OverlayPhase::Opening, cx);
+        tick(cx);
+    }
+
+    /// Start dismissing an app-wide toast.
+    pub fn hide(id: &str, cx: &mut App) {
+        if cx.default_global::<ToastManager>().dismiss(id) {
+            settle_after(OverlayPhase::Closing, cx);
+        }
+        cx.refresh_windows();
+    }
+
+    /// Dismiss every app-wide toast, dropping the queue.
+    pub fn hide_all(cx: &mut App) {
+        cx.default_global::<ToastManager>().dismiss_all();
+        settle_after(OverlayPhase::Closing, cx);
+        cx.refresh_windows();
+    }
+
+    /// Move the app-wide stack to another corner.
+    pub fn move_to(position: ToastPosition, cx: &mut App) {
+        cx.default_global::<ToastManager>().set_position(position);
+        cx.refresh_windows();
+    }
+
+    /// Pause the app-wide countdowns while the stack is hovered.
+    fn set_hovered(hovered: bool, cx: &mut App) {
+        let manager = cx.default_global::<ToastManager>();
+        if manager.paused == hovered {
+            return;
+        }
+        manager.set_paused(hovered, Instant::now());
+        tick(cx);
+    }
+}
+
+/// Dismiss expired app-wide toasts and wake again at the next deadline.
+fn tick(cx: &mut App) {
+    let now = Instant::now();
+    let manager = cx.default_global::<ToastManager>();
+    let expired = !manager.expire(now).is_empty();
+    let next = manager.next_deadline(now);
+    manager.timer_generation += 1;
+    let generation = manager.timer_generation;
+    if expired {
+        settle_after(OverlayPhase::Closing, cx);
+    }
+    cx.refresh_windows();
+
+    let Some(delay) = next else {
+        return;
+    };
+    cx.spawn(async move |cx| {
+        cx.background_executor().timer(delay).await;
+        cx.update(|cx| {
+            let current = cx
+                .try_global::<ToastManager>()
+                .is_some_and(|m| m.timer_generation == generation);
+            if current {
+                tick(cx);
+            }
+        })
+        .ok();
+    })
+    .detach();
+}
+
+/// Settle the app-wide transitions once `phase` has finished animating,
+/// keeping closed toasts in the notification history.
+fn settle_after(phase: OverlayPhase, cx: &mut App) {
+    cx.spawn(async move |cx| {
+        cx.background_executor()
+            .timer(phase.transition_duration())
+            .await;
+        cx.update(|cx| {
+            let closed = cx.default_global::<ToastManager>().finish_transitions();
+            NotificationHistory::record_closed(closed, cx);
+            // Promoted toasts start counting down.
+            tick(cx);
+        })
+        .ok();
+    })
+    .detach();
+}
+
+/// Renders the app-wide [`ToastManager`] stacked in its corner.
+///
+/// Mount one at the window root, after the content, so toasts draw above
+/// it. Hovering the stack pauses the auto-dismiss countdowns.
+///
+/// # Usage
+/// ```ignore
+/// div().size_full().child(content).child(ToastLayer::new("toasts"))
+/// ```
+#[derive(IntoElement)]
+pub struct ToastLayer {
+    id: ElementId,
+}
+
+impl ToastLayer {
+    /// Create the layer.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self { id: id.into() }
+    }
+}
+
+impl RenderOnce for ToastLayer {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let Some(manager) = cx.try_global::<ToastManager>() else {
+            return div().into_any_element();
+        };
+        if manager.visible.is_empty() {
+            return div().into_any_element();
+        }
+        let position = manager.current_position();
+        let mut entries: Vec<ToastEntry> = manager.visible().cloned().collect();
+        // The newest toast sits nearest the corner.
+        if position.is_bottom() {
+            entries.reverse();
+        }
+
+        let stack = div()
+            .id(self.id)
+            .flex()
+            .flex_col()
+            .gap_2()
+            .on_hover(|hovered, _window, cx| ToastManager::set_hovered(*hovered, cx))
+            .children(entries.into_iter().map(|entry| {
+                let id = entry.id.clone();
+                entry
+                    .to_toast()
+                    .on_dismiss(move |_window, cx| ToastManager::hide(&id, cx))
+            }));
+
+        div()
+            .absolute()
+            .inset_0()
+            .flex()
+            .flex_col()
+            .p_4()
+            .map(|el| {
+                if position.is_bottom() {
+                    el.justify_end()
+                } else {
+                    el.justify_start()
+                }
+            })
+            .map(|el| {
+                if position.is_left() {
+                    el.items_start()
+                } else {
+                    el.items_end()
+                }
+            })
+            .child(stack)
+            .into_any_element()
+    }
+}
@@ -4,15 +4,33 @@
 //! The Tooltip is the simplest component -- a positioned text overlay that
 //! appears on hover and disappears on mouse leave.
 //!
+//! Other components attach tooltips through the shared [`WithTooltip`] builder
+//! trait. The attached tooltip tracks the trigger's hover itself: it appears
+//! once the pointer has rested on the trigger for [`TOOLTIP_HOVER_DELAY`],
+//! placed against the trigger by its [`TooltipPlacement`] through the shared
+//! [`PopoverPosition`] engine, on the tooltip tier of the [`LayerManager`].
+//!
 //! Provenance:
 //! - Zed `crates/ui/src/components/tooltip.rs` (GPL-3.0/AGPL-3.0, Zed Industries)
 //! - gpui-component tooltip patterns (MIT, Zed Industries)
 //! - Modifications: Simplified to internal token system, reuse disposition
-//!   with only color remapping.
+//!   with only color remapping; visibility follows the shared OverlayStateMachine.
+
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use std::time::Duration;
 
 use gpui::*;
+use primitives::{
+    Layer, LayerKind, LayerManager, MotionPreference, OverlayStateMachine, PopoverPosition,
+};
 use theme::ActiveTheme;
 
+/// Hover delay before a tooltip appears.
+///
+/// Every component tooltip attached through [`WithTooltip`] waits this long
+/// after the pointer enters its trigger; see [`TooltipHover`].
+pub const TOOLTIP_HOVER_DELAY: Duration = Duration::from_millis(500);
+
 /// Tooltip placement relative to the trigger element.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TooltipPlacement {
@@ -27,6 +45,18 @@ pub enum TooltipPlacement {
     Right,
 }
 
+impl TooltipPlacement {
+    /// Where the tooltip attaches to its trigger.
+    pub fn position(self) -> PopoverPosition {
+        match self {
+            TooltipPlacement::Top => PopoverPosition::above_left(),
+            TooltipPlacement::Bottom => PopoverPosition::below_left(),
+            TooltipPlacement::Left => PopoverPosition::beside_left(),
+            TooltipPlacement::Right => PopoverPosition::beside_right(),
+        }
+    }
+}
+
 /// A tooltip overlay that appears on hover with contextual text.
 ///
 /// # Usage
@@ -41,6 +71,8 @@ pub struct Tooltip {
     text: SharedString,
     placement: TooltipPlacement,
     max_width: Pixels,
+    overlay: OverlayStateMachine,
+    identifiers: SharedIdentifiers,
 }
 
 impl Tooltip {
@@ -51,6 +83,8 @@ impl Tooltip {
             text: SharedString::default(),
             placement: TooltipPlacement::Bottom,
             max_width: px(250.0),
+            overlay: OverlayStateMachine::opened(),
+            identifiers: SharedIdentifiers::default(),
         }
     }
 
@@ -72,48 +106,60 @@ impl Tooltip {
         self
     }
 
+    /// Drive visibility from an owner-held overlay state machine.
+    ///
+    /// Defaults to settled `Open` (a rendered tooltip is visible). The tooltip
+    /// fades in during `Opening` and out during `Closing`.
+    pub fn overlay_state(mut self, overlay: OverlayStateMachine) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
     /// Returns the component contract for Tooltip.
     pub fn contract() -> crate::ComponentContract {
-        use crate::*;
-        ComponentContract::builder("Tooltip", "0.1.0")
-            .disposition(Disposition::Reuse)
-            .required_prop("id", "ElementId", "Unique identifier for the tooltip")
-            .optional_prop("text", "SharedString", "\"\"", "Tooltip text content")
-            .optional_prop(
-                "placement",
-                "TooltipPlacement",
-                "Bottom",
-                "Placement relative to trigger: Top, Bottom, Left, Right",
-            )
-            .optional_prop(
-                "max_width",
-                "Pixels",
-                "250.0",
-                "Maximum width of the tooltip",
-            )
-            .state(ComponentState::Hover)
-            .token_dep("surface.elevated_surface", "Tooltip background")
-            .token_dep("border.default", "Tooltip border")
-            .token_dep("text.default", "Tooltip text color")
-            .focus_behavior("Tooltips are not focusable. They appear on hover only.")
-            .keyboard_model("No keyboard interaction. Tooltip hides when trigger loses focus.")
-            .pointer_behavior("Appears on hover over trigger, disappears on mouse leave.")
-            .state_model("Visibility controlled by hover state of the trigger element.")
-            .required_file("crates/components/src/tooltip.rs")
-            .build()
+        crate::catalog::tooltip()
+    }
+}
+
+impl WithIdentifiers for Tooltip {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
     }
 }
 
 impl RenderOnce for Tooltip {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Tooltip", &self.id, Some(&self.text), &self.identifiers, cx);
+
         let theme = cx.theme();
 
         let bg = theme.surface.elevated_surface;
         let border_color = theme.border.default;
         let text_color = theme.text.default;
 
-        div()
+        if !self.overlay.is_visible() {
+            return div().into_any_element();
+        }
+        let phase = self.overlay.phase();
+        let animation_id = self.id.clone();
+
+        // Keep a small gap on the side facing the trigger.
+        let tooltip = div()
             .id(self.id)
+            .map(|this| match self.placement {
+                TooltipPlacement::Top => this.mb_1(),
+                TooltipPlacement::Bottom => this.mt_1(),
+                TooltipPlacement::Left => this.mr_1(),
+                TooltipPlacement::Right => this.ml_1(),
+            })
             .max_w(self.max_width)
             .px_2()
             .py_1()
@@ -125,5 +171,187 @@ impl RenderOnce for Tooltip {
             .text_xs()
             .text_color(text_color)
             .child(self.text)
+            .inspectable(&animation_id, Self::contract);
+
+        if MotionPreference::global(cx).animates(phase.transition_duration()) {
+            tooltip
+                .with_animation(
+                    ElementId::Name(format!("{}-{:?}", animation_id, phase).into()),
+                    Animation::new(phase.transition_duration()).with_easing(ease_in_out),
+                    move |this, delta| this.opacity(phase.opacity(delta)),
+                )
+                .into_any_element()
+        } else {
+            tooltip.into_any_element()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Shared tooltip attachment
+// ---------------------------------------------------------------------------
+
+/// Shared `tooltip()` builder for components that render a hover tooltip.
+///
+/// # Usage
+/// ```ignore
+/// Button::new("save")
+///     .label("Save")
+///     .tooltip("Save your work")
+///     .tooltip_placement(TooltipPlacement::Top)
+/// ```
+pub trait WithTooltip: Sized {
+    /// The component's tooltip slot.
+    fn tooltip_slot(&mut self) -> &mut Option<SharedString>;
+
+    /// The component's tooltip placement slot.
+    fn tooltip_placement_slot(&mut self) -> &mut TooltipPlacement;
+
+    /// Show `text` in a tooltip when the component is hovered.
+    fn tooltip(mut self, text: impl Into<SharedString>) -> Self {
+        *self.tooltip_slot() = Some(text.into());
+        self
+    }
+
+    /// Place the tooltip relative to the component (below by default).
+    fn tooltip_placement(mut self, placement: TooltipPlacement) -> Self {
+        *self.tooltip_placement_slot() = placement;
+        self
+    }
+}
+
+/// View hosting a [`Tooltip`] for elements outside the component library
+/// that use GPUI's own hover-tooltip mechanism.
+pub struct TooltipView {
+    text: SharedString,
+}
+
+impl TooltipView {
+    /// Build a tooltip view for `text`.
+    pub fn build(text: SharedString, _window: &mut Window, cx: &mut App) -> AnyView {
+        cx.new(|_cx| Self { text }).into()
     }
 }
+
+impl Render for TooltipView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        Tooltip::new("tooltip-view").text(self.text.clone())
+    }
+}
+
+/// Hover state of one attached tooltip's trigger.
+///
+/// Entering the trigger starts a [`TOOLTIP_HOVER_DELAY`] timer tagged with a
+/// generation; the tooltip shows only if that timer elapses before the
+/// pointer leaves, since leaving (or re-entering) moves the generation on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TooltipHover {
+    visible: bool,
+    generation: u64,
+}
+
+impl TooltipHover {
+    /// The pointer entered the trigger. Returns the generation the hover
+    /// delay timer passes to [`elapse`](Self::elapse).
+    pub fn enter(&mut self) -> u64 {
+        self.leave();
+        self.generation
+    }
+
+    /// The pointer left the trigger: hide the tooltip and cancel the timer.
+    pub fn leave(&mut self) {
+        self.visible = false;
+        self.generation += 1;
+    }
+
+    /// The hover delay timer started at `generation` elapsed. Shows the
+    /// tooltip unless the timer was cancelled; returns whether it did.
+    pub fn elapse(&mut self, generation: u64) -> bool {
+        let show = generation == self.generation && !self.visible;
+        self.visible |= show;
+        show
+    }
+
+    /// Whether the tooltip is showing.
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+}
+
+/// Attach a hover tooltip to `element` if `tooltip` is set.
+///
+/// `id` names the trigger; the tooltip renders as `{id}-tooltip`, both in
+/// the element tree and on the [`LayerManager`].
+pub(crate) fn attach_tooltip<E>(
+    element: E,
+    id: &ElementId,
+    tooltip: Option<SharedString>,
+    placement: TooltipPlacement,
+    window: &mut Window,
+    cx: &mut App,
+) -> E
+where
+    E: StatefulInteractiveElement + ParentElement,
+{
+    let Some(text) = tooltip else {
+        return element;
+    };
+    let tooltip_id = SharedString::from(format!("{id}-tooltip"));
+    let hover = window.use_keyed_state(tooltip_id.clone(), cx, |_, _| TooltipHover::default());
+
+    let element = element.on_hover({
+        let hover = hover.clone();
+        move |hovered, window, cx| {
+            if !*hovered {
+                hover.update(cx, |hover, cx| {
+                    hover.leave();
+                    cx.notify();
+                });
+                return;
+            }
+            let generation = hover.update(cx, |hover, _| hover.enter());
+            let hover = hover.clone();
+            window
+                .spawn(cx, async move |cx| {
+                    cx.background_executor().timer(TOOLTIP_HOVER_DELAY).await;
+                    hover
+                        .update(cx, |hover, cx| {
+                            if hover.elapse(generation) {
+                                cx.notify();
+                            }
+                        })
+                        .ok();
+                })
+                .detach();
+        }
+    });
+
+    if !hover.read(cx).is_visible() {
+        LayerManager::unregister(&tooltip_id, cx);
+        return element;
+    }
+    LayerManager::register(Layer::new(tooltip_id.clone(), LayerKind::Tooltip), cx);
+    let layer = LayerManager::state(&tooltip_id, cx);
+
+    // A zero-size point on the trigger's anchor corner, with the tooltip
+    // attached to it by the opposite corner. `anchored` switches to the
+    // opposite side when the tooltip would overflow the window.
+    let position = placement.position();
+    let anchor = div().absolute().size_0();
+    let anchor = match position.anchor {
+        Corner::TopLeft => anchor.top_0().left_0(),
+        Corner::TopRight => anchor.top_0().right_0(),
+        Corner::BottomLeft => anchor.bottom_0().left_0(),
+        Corner::BottomRight => anchor.bottom_0().right_0(),
+    };
+    element.child(
+        anchor.child(
+            deferred(
+                anchored()
+                    .anchor(position.attach)
+                    .child(Tooltip::new(tooltip_id).text(text).placement(placement)),
+            )
+            .with_priority(layer.priority),
+        ),
+    )
+}
@@ -0,0 +1,430 @@
+//! Typography components: Label, Link, and Text.
+//!
+//! Rewrite disposition: written for the workbench so form labels, inline
+//! links, and body copy use the same theme tokens and type scale everywhere
+//! instead of ad-hoc `div().text_sm()` styling.
+//!
+//! [`Label`] names a control: it marks required fields with `*` and, with
+//! [`Label::for_input`], records which element it labels and focuses that
+//! control when clicked. [`Link`] is an inline, keyboard-activatable action
+//! colored with `link.hover` on hover. [`Text`] is styled copy with size,
+//! weight, and muted variants.
+
+use std::rc::Rc;
+
+use crate::tooltip::{TooltipPlacement, attach_tooltip};
+use crate::{Inspectable, SharedIdentifiers, WithIdentifiers, identifiers::publish_identifiers};
+use gpui::prelude::FluentBuilder;
+use gpui::*;
+use primitives::is_activation_key;
+use theme::ActiveTheme;
+
+// ---------------------------------------------------------------------------
+// Label
+// ---------------------------------------------------------------------------
+
+/// Accessibility metadata key naming the element a [`Label`] labels.
+pub const LABEL_FOR_KEY: &str = "for";
+
+/// A label for a form control.
+///
+/// # Usage
+/// ```ignore
+/// Label::new("email-label", "Email")
+///     .required(true)
+///     .for_input("email", Some(focus_handle.clone()))
+/// ```
+#[derive(IntoElement)]
+pub struct Label {
+    id: ElementId,
+    text: SharedString,
+    required: bool,
+    disabled: bool,
+    target: Option<ElementId>,
+    target_focus: Option<FocusHandle>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Label {
+    /// Create a label.
+    pub fn new(id: impl Into<ElementId>, text: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            text: text.into(),
+            required: false,
+            disabled: false,
+            target: None,
+            target_focus: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Mark the labeled control required; the label gets a `*`.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Set whether the labeled control is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Associate the label with the control whose element id is `target`.
+    /// The association is published to the accessibility tree under
+    /// [`LABEL_FOR_KEY`]; with `focus`, clicking the label focuses the
+    /// control.
+    pub fn for_input(mut self, target: impl Into<ElementId>, focus: Option<FocusHandle>) -> Self {
+        self.target = Some(target.into());
+        self.target_focus = focus;
+        self
+    }
+
+    /// Returns the component contract for Label.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::label()
+    }
+}
+
+impl WithIdentifiers for Label {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Label {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let mut identifiers = self.identifiers.clone();
+        if let Some(target) = &self.target {
+            identifiers
+                .metadata
+                .insert(LABEL_FOR_KEY.to_string(), target.to_string());
+        }
+        publish_identifiers("Label", &self.id, None, &identifiers, cx);
+
+        let theme = cx.theme();
+        let text_color = if self.disabled {
+            theme.text.disabled
+        } else {
+            theme.text.default
+        };
+        let required_color = theme.status.error.foreground;
+
+        let focus = self.target_focus.filter(|_| !self.disabled);
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .gap_0p5()
+            .text_sm()
+            .text_color(text_color)
+            .child(self.text)
+            .when(self.required, |el| {
+                el.child(div().text_color(required_color).child("*"))
+            })
+            .when_some(focus, |el, focus| {
+                el.cursor_pointer()
+                    .on_click(move |_event, window, cx| window.focus(&focus, cx))
+            })
+            .inspectable(&self.id, Self::contract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Link
+// ---------------------------------------------------------------------------
+
+/// Callback when a link is activated.
+type LinkCallback = Rc<dyn Fn(&mut Window, &mut App) + 'static>;
+
+/// An inline text action.
+///
+/// # Usage
+/// ```ignore
+/// Link::new("docs-link", "Read the docs")
+///     .on_click(|_window, cx| cx.open_url("https://example.com/docs"))
+/// ```
+#[derive(IntoElement)]
+pub struct Link {
+    id: ElementId,
+    label: SharedString,
+    disabled: bool,
+    on_click: Option<LinkCallback>,
+    tooltip: Option<SharedString>,
+    identifiers: SharedIdentifiers,
+}
+
+impl Link {
+    /// Create a link.
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            disabled: false,
+            on_click: None,
+            tooltip: None,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set whether the link is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler fired by a click, or Enter or Space while focused.
+    pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set a tooltip, e.g. the URL the link opens.
+    pub fn set_tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Returns the component contract for Link.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::link()
+    }
+}
+
+impl WithIdentifiers for Link {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        Some(&mut self.tooltip)
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Link {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers(
+            "Link",
+            &self.id,
+            self.tooltip.as_ref(),
+            &self.identifiers,
+            cx,
+        );
+
+        let theme = cx.theme();
+        let color = theme.text.accent;
+        let hover_color = theme.link.hover;
+        let disabled_color = theme.text.disabled;
+        let focus_border = theme.border.focused;
+
+        let link = div()
+            .id(self.id.clone())
+            .px_0p5()
+            .rounded_sm()
+            .border_1()
+            .border_color(transparent_black())
+            .child(self.label);
+
+        let link = match self.on_click.filter(|_| !self.disabled) {
+            Some(on_click) => {
+                let focus_handle = window
+                    .use_keyed_state(
+                        SharedString::from(format!("{}-focus", self.id)),
+                        cx,
+                        |_, cx| cx.focus_handle(),
+                    )
+                    .read(cx)
+                    .clone();
+                let focused = focus_handle.is_focused(window);
+                let key_handler = on_click.clone();
+                link.text_color(if focused { hover_color } else { color })
+                    .when(focused, |el| el.underline().border_color(focus_border))
+                    .cursor_pointer()
+                    .hover(move |s| s.text_color(hover_color).underline())
+                    .track_focus(&focus_handle)
+                    .on_click(move |_event, window, cx| on_click(window, cx))
+                    .on_key_down(move |event, window, cx| {
+                        if is_activation_key(event) {
+                            key_handler(window, cx);
+                            cx.stop_propagation();
+                        }
+                    })
+            }
+            None if self.disabled => link.text_color(disabled_color),
+            None => link.text_color(color),
+        };
+
+        attach_tooltip(
+            link,
+            &self.id,
+            self.tooltip,
+            TooltipPlacement::default(),
+            window,
+            cx,
+        )
+        .inspectable(&self.id, Self::contract)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Text
+// ---------------------------------------------------------------------------
+
+/// Type scale step for [`Text`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextSize {
+    /// 12px, for captions and help text.
+    XSmall,
+    /// 14px, the default for UI copy.
+    #[default]
+    Small,
+    /// 16px, for body text.
+    Base,
+    /// 18px, for section headings.
+    Large,
+    /// 20px, for page headings.
+    XLarge,
+}
+
+/// Font weight for [`Text`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextWeight {
+    #[default]
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+}
+
+impl TextWeight {
+    fn font_weight(self) -> FontWeight {
+        match self {
+            TextWeight::Normal => FontWeight::NORMAL,
+            TextWeight::Medium => FontWeight::MEDIUM,
+            TextWeight::Semibold => FontWeight::SEMIBOLD,
+            TextWeight::Bold => FontWeight::BOLD,
+        }
+    }
+}
+
+/// Styled text.
+///
+/// # Usage
+/// ```ignore
+/// Text::new("title", "Settings").size(TextSize::XLarge).weight(TextWeight::Semibold)
+/// Text::new("hint", "Changes apply immediately.").size(TextSize::XSmall).muted(true)
+/// ```
+#[derive(IntoElement)]
+pub struct Text {
+    id: ElementId,
+    content: SharedString,
+    size: TextSize,
+    weight: TextWeight,
+    muted: bool,
+    disabled: bool,
+    identifiers: SharedIdentifiers,
+}
+
+impl Text {
+    /// Create text at the default size and weight.
+    pub fn new(id: impl Into<ElementId>, content: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            content: content.into(),
+            size: TextSize::default(),
+            weight: TextWeight::default(),
+            muted: false,
+            disabled: false,
+            identifiers: SharedIdentifiers::default(),
+        }
+    }
+
+    /// Set the type scale step.
+    pub fn size(mut self, size: TextSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the font weight.
+    pub fn weight(mut self, weight: TextWeight) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Set whether the text uses the muted color, for secondary copy.
+    pub fn muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
+
+    /// Set whether the text uses the disabled color.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the component contract for Text.
+    pub fn contract() -> crate::ComponentContract {
+        crate::catalog::text()
+    }
+}
+
+impl WithIdentifiers for Text {
+    fn element_id_mut(&mut self) -> &mut ElementId {
+        &mut self.id
+    }
+
+    fn tooltip_mut(&mut self) -> Option<&mut Option<SharedString>> {
+        None
+    }
+
+    fn identifiers_mut(&mut self) -> &mut SharedIdentifiers {
+        &mut self.identifiers
+    }
+}
+
+impl RenderOnce for Text {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        publish_identifiers("Text", &self.id, None, &self.identifiers, cx);
+
+        let theme = cx.theme();
+        let color = if self.disabled {
+            theme.text.disabled
+        } else if self.muted {
+            theme.text.muted
+        } else {
+            theme.text.default
+        };
+
+        let text = div()
+            .id(self.id.clone())
+            .text_color(color)
+            .font_weight(self.weight.font_weight())
+            .child(self.content);
+        let text = match self.size {
+            TextSize::XSmall => text.text_xs(),
+            TextSize::Small => text.text_sm(),
+            TextSize::Base => text.text_base(),
+            TextSize::Large => text.text_lg(),
+            TextSize::XLarge => text.text_xl(),
+        };
+
+        text.inspectable(&self.id, Self::contract)
+    }
+}
+
+// Tests are in tests/contract_tests.rs (integration test) to avoid
+// stack overflow from GPUI IntoElement derive macro expansion in test mode.
@@ -5,11 +5,73 @@
 //! They are in an integration test to avoid the stack overflow that occurs
 //! when compiling tests in the same crate as GPUI IntoElement derives.
 
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, UNIX_EPOCH};
+
+use components::avatar::{Avatar, AvatarGroup, avatar_group_split, avatar_initials};
+use components::badge::{Badge, Tag, badge_count_label};
+use components::breadcrumbs::{BreadcrumbSegment, Breadcrumbs, collapse_breadcrumbs};
+use components::button::{Button, ButtonSize};
+use components::card::Card;
+use components::chart::{bar_fractions, normalize_range};
+use components::command_palette::{CommandItem, CommandPalette, command_matches};
+use components::context_menu::{ContextMenu, ContextMenuItem, MenuPath};
+use components::data_table::{
+    ColumnSort, DataTable, SelectionMode, TableRow, compare_cells, next_sort, select_row,
+    sorted_row_order,
+};
 use components::dialog::Dialog;
+use components::dropdown_menu::{MenuItem, toggle_item};
+use components::file_picker::{
+    FileFilter, FilePicker, MockFileDialog, merge_picked, prompt_options, rejection_message,
+};
+use components::form::{Form, FormField, aggregate_validation, error_summary};
+use components::input::Input;
+use components::kbd::{KeyPlatform, keystroke_labels};
+use components::markdown::{
+    CodeHighlight, MarkdownBlock, MarkdownInline, highlight_code, is_safe_url, parse_inlines,
+    parse_markdown,
+};
+use components::menu_bar::{MenuBar, MenuBarMenu, MenuBarState};
+use components::notification_center::{
+    NotificationCenter, NotificationHistory, day_label, day_number,
+};
+use components::number_input::{NumberInput, NumberRange, parse_number, validate_number};
+use components::password_input::{
+    PasswordInput, PasswordRule, PasswordStrength, password_strength, unmet_rules,
+    validate_password,
+};
+use components::progress::{
+    ProgressBar, ProgressSize, Spinner, arc_points, progress_fraction, progress_percent,
+};
+use components::scroll_area::{ScrollArea, ScrollAreaHandle};
+use components::search_input::result_count_label;
 use components::select::{Select, SelectItem};
+use components::separator::Separator;
+use components::skeleton::{Skeleton, SkeletonShape, shimmer_opacity};
+use components::slider::{SLIDER_PAGE_STEPS, Slider, SliderRange, fraction_at};
+use components::sortable_list::SortableList;
+use components::split_pane::SplitPane;
+use components::status_bar::{StatusBar, StatusBarSlot, StatusIndicator, StatusSegment};
+use components::switch::{Switch, SwitchSize, thumb_offset};
 use components::tabs::{TabItem, Tabs};
-use components::{ComponentContract, ComponentState, Disposition};
-use primitives::{NavDirection, navigate_index};
+use components::textarea::Textarea;
+use components::title_bar::{TRAFFIC_LIGHT_INSET, TitleBar, TitleBarPlatform};
+use components::toast::{
+    TOAST_AUTO_DISMISS, ToastEntry, ToastManager, ToastPosition, ToastVariant,
+};
+use components::typography::{LABEL_FOR_KEY, Label, Link, Text};
+use components::{
+    AccessibilityNode, AccessibilityTree, ComponentContract, ComponentState, Disposition,
+    InspectNode, InspectTree, TooltipHover, TooltipPlacement, WithTooltip,
+};
+use gpui::{Bounds, Edges, point, px, size};
+use primitives::keyboard::keys;
+use primitives::{
+    DRAG_THRESHOLD, Locale, NavDirection, Orientation, PopoverPosition, RESIZE_PAGE_STEP,
+    RESIZE_STEP, ValidationState, navigate_index,
+};
 
 // ---- Dialog Contract Tests ----
 
@@ -54,6 +116,17 @@ fn dialog_contract_has_required_states() {
     assert!(contract.states.contains(&ComponentState::Focused));
 }
 
+#[test]
+fn dialog_contract_documents_overlay_lifecycle() {
+    let contract = Dialog::contract();
+    assert!(contract.props.iter().any(|p| p.name == "overlay_state"));
+    let state_model = contract
+        .interaction_checklist
+        .state_model
+        .expect("state model");
+    assert!(state_model.contains("OverlayStateMachine"));
+}
+
 #[test]
 fn dialog_contract_serializes() {
     let contract = Dialog::contract();
@@ -189,6 +262,13 @@ fn tabs_contract_has_required_states() {
     assert!(contract.states.contains(&ComponentState::Disabled));
 }
 
+#[test]
+fn tabs_contract_documents_closable_tabs() {
+    let contract = Tabs::contract();
+    assert!(contract.props.iter().any(|p| p.name == "on_close"));
+    assert!(contract.props.iter().any(|p| p.name == "show_panel"));
+}
+
 #[test]
 fn tabs_contract_has_token_deps() {
     let contract = Tabs::contract();
@@ -229,68 +309,1899 @@ fn tabs_navigate_horizontal_wraps() {
     assert_eq!(next, 0);
 }
 
-// ---- Cross-component tests ----
+// ---- Text Input Contract Tests ----
 
 #[test]
-fn all_poc_contracts_are_fork_disposition() {
-    assert_eq!(Dialog::contract().disposition, Disposition::Fork);
-    assert_eq!(Select::contract().disposition, Disposition::Fork);
-    assert_eq!(Tabs::contract().disposition, Disposition::Fork);
+fn text_input_contracts_document_composition() {
+    for contract in [Input::contract(), Textarea::contract()] {
+        assert!(
+            contract.validate().is_empty(),
+            "{} validates",
+            contract.name
+        );
+        assert!(contract.props.iter().any(|p| p.name == "on_composition"));
+        assert!(contract.props.iter().any(|p| p.name == "default_value"));
+        let keyboard = contract
+            .interaction_checklist
+            .keyboard_model
+            .expect("keyboard model");
+        assert!(
+            keyboard.contains("preedit"),
+            "{} keyboard model",
+            contract.name
+        );
+        assert!(keyboard.contains("Escape cancels"));
+        let state_model = contract
+            .interaction_checklist
+            .state_model
+            .expect("state model");
+        assert!(state_model.contains("never with preedit text"));
+    }
 }
 
+// ---- PasswordInput Contract Tests ----
+
 #[test]
-fn all_poc_contracts_validate() {
-    for (name, contract) in [
-        ("Dialog", Dialog::contract()),
-        ("Select", Select::contract()),
-        ("Tabs", Tabs::contract()),
+fn password_input_contract_validates() {
+    let contract = PasswordInput::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "PasswordInput contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    assert!(contract.states.contains(&ComponentState::Error));
+    let paths: Vec<&str> = contract
+        .token_dependencies
+        .iter()
+        .map(|t| t.path.as_str())
+        .collect();
+    for path in [
+        "status.error.foreground",
+        "status.warning.foreground",
+        "status.info.foreground",
+        "status.success.foreground",
     ] {
-        let errors = contract.validate();
-        assert!(
-            errors.is_empty(),
-            "{name} contract validation failed: {:?}",
-            errors
-        );
+        assert!(paths.contains(&path), "meter uses {path}");
     }
 }
 
 #[test]
-fn all_poc_contracts_have_no_hardcoded_colors_documented() {
-    // Verify contracts document token dependencies (no hardcoded colors)
-    for (name, contract) in [
-        ("Dialog", Dialog::contract()),
-        ("Select", Select::contract()),
-        ("Tabs", Tabs::contract()),
-    ] {
-        assert!(
-            !contract.token_dependencies.is_empty(),
-            "{name} should document token dependencies"
-        );
+fn password_strength_counts_met_rules() {
+    let rules = PasswordRule::DEFAULT;
+    assert_eq!(password_strength("", &rules), None);
+    assert_eq!(
+        password_strength("abc", &rules),
+        Some(PasswordStrength::Weak)
+    );
+    // Meeting every class but the length is still weak.
+    assert_eq!(
+        password_strength("Ab1!", &rules),
+        Some(PasswordStrength::Weak)
+    );
+    assert_eq!(
+        password_strength("abcdefg1", &rules),
+        Some(PasswordStrength::Fair)
+    );
+    assert_eq!(
+        password_strength("Abcdefg1", &rules),
+        Some(PasswordStrength::Good)
+    );
+    assert_eq!(
+        password_strength("Abcdef1!", &rules),
+        Some(PasswordStrength::Strong)
+    );
+    assert_eq!(PasswordStrength::Strong.segments(), 4);
+    assert_eq!(
+        password_strength("anything", &[]),
+        Some(PasswordStrength::Strong)
+    );
+}
+
+#[test]
+fn password_validation_lists_unmet_rules() {
+    let rules = PasswordRule::DEFAULT;
+    assert_eq!(validate_password("", &rules), (ValidationState::None, None));
+    assert_eq!(
+        unmet_rules("abcdefgh", &rules),
+        [
+            PasswordRule::Uppercase,
+            PasswordRule::Digit,
+            PasswordRule::Symbol
+        ]
+    );
+    assert_eq!(
+        validate_password("abcdefgh", &rules),
+        (
+            ValidationState::Error,
+            Some("Password needs an uppercase letter, a digit and a symbol".into())
+        )
+    );
+    assert_eq!(
+        validate_password("Abcdefg1", &rules).1.as_deref(),
+        Some("Password needs a symbol")
+    );
+    assert_eq!(
+        validate_password("Abcdef1!", &rules),
+        (ValidationState::None, None)
+    );
+    // Lengths count characters, not bytes.
+    assert!(PasswordRule::MinLength(4).is_met("日本語!"));
+    assert!(!PasswordRule::MinLength(5).is_met("日本語!"));
+}
+
+// ---- NumberInput Contract Tests ----
+
+#[test]
+fn number_input_contract_validates() {
+    let contract = NumberInput::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "NumberInput contract validation failed: {:?}",
+        errors
+    );
+    assert!(contract.states.contains(&ComponentState::Error));
+    for prop in ["min", "max", "step"] {
+        assert!(contract.props.iter().any(|p| p.name == prop && !p.required));
     }
 }
 
 #[test]
-fn all_poc_contracts_have_interaction_checklists() {
-    for (name, contract) in [
-        ("Dialog", Dialog::contract()),
-        ("Select", Select::contract()),
-        ("Tabs", Tabs::contract()),
-    ] {
-        assert!(
-            contract.interaction_checklist.focus_behavior.is_some(),
-            "{name} should document focus behavior"
-        );
-        assert!(
-            contract.interaction_checklist.keyboard_model.is_some(),
-            "{name} should document keyboard model"
-        );
-        assert!(
-            contract.interaction_checklist.pointer_behavior.is_some(),
-            "{name} should document pointer behavior"
-        );
-        assert!(
-            contract.interaction_checklist.state_model.is_some(),
-            "{name} should document state model"
-        );
+fn number_range_steps_on_the_grid_and_clamps() {
+    let range = NumberRange::new(0.0, 10.0, 1.0);
+    assert_eq!(range.step_by(3.0, 1), 4.0);
+    assert_eq!(range.step_by(10.0, 1), 10.0);
+    assert_eq!(range.step_by(0.0, -1), 0.0);
+    assert!(!range.can_step(10.0, 1));
+    assert!(range.can_step(10.0, -1));
+    // Off-grid values move to the next grid point in the step's direction.
+    assert_eq!(range.step_by(1.25, 1), 2.0);
+    assert_eq!(range.step_by(1.75, -1), 1.0);
+    // A typed value past a bound steps back inside.
+    assert_eq!(range.step_by(150.0, -1), 10.0);
+
+    // Decimal steps round away float noise.
+    let fine = NumberRange::new(0.0, 1.0, 0.1);
+    assert_eq!(fine.step_by(0.2, 1), 0.3);
+    assert_eq!(fine.format(0.3), "0.3");
+    // The grid starts at min.
+    assert_eq!(NumberRange::new(0.5, 10.0, 1.0).step_by(0.5, 1), 1.5);
+    // Reversed bounds are swapped; non-positive steps become 1.
+    assert_eq!(
+        NumberRange::new(5.0, -5.0, 0.0),
+        NumberRange::new(-5.0, 5.0, 1.0)
+    );
+    assert_eq!(NumberRange::default().step_by(-20.0, -1), -21.0);
+}
+
+#[test]
+fn number_input_text_is_parsed_and_validated() {
+    let range = NumberRange::new(0.0, 10.0, 0.5);
+    assert_eq!(parse_number(" 2.5 "), Some(2.5));
+    assert_eq!(parse_number("1."), Some(1.0));
+    assert_eq!(parse_number("inf"), None);
+    assert_eq!(parse_number("NaN"), None);
+    assert_eq!(range.format(-0.0), "0.0");
+    assert_eq!(range.format(2.25), "2.25");
+
+    assert_eq!(validate_number("", &range), (ValidationState::None, None));
+    assert_eq!(validate_number("7", &range), (ValidationState::None, None));
+    assert_eq!(
+        validate_number("seven", &range),
+        (ValidationState::Error, Some("Enter a number".into()))
+    );
+    assert_eq!(
+        validate_number("-1", &range).1.as_deref(),
+        Some("Must be at least 0.0")
+    );
+    assert_eq!(
+        validate_number("12", &range).1.as_deref(),
+        Some("Must be at most 10.0")
+    );
+}
+
+// ---- DataTable Contract Tests ----
+
+#[test]
+fn data_table_contract_validates() {
+    let contract = DataTable::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "DataTable contract validation failed: {:?}",
+        errors
+    );
+    assert!(contract.states.contains(&ComponentState::Selected));
+    assert!(contract.states.contains(&ComponentState::Disabled));
+    assert!(
+        contract
+            .token_dependencies
+            .iter()
+            .any(|t| t.path == "ghost_element.selected")
+    );
+    assert!(contract.evaluated_checklist().bounded_rendering_verified);
+}
+
+#[test]
+fn data_table_sort_cycles_and_orders_numbers_numerically() {
+    assert_eq!(next_sort(None, 1), Some(ColumnSort::ascending(1)));
+    assert_eq!(
+        next_sort(Some(ColumnSort::ascending(1)), 1),
+        Some(ColumnSort::descending(1))
+    );
+    assert_eq!(next_sort(Some(ColumnSort::descending(1)), 1), None);
+    assert_eq!(
+        next_sort(Some(ColumnSort::descending(1)), 0),
+        Some(ColumnSort::ascending(0)),
+        "another column starts ascending"
+    );
+
+    assert!(compare_cells("9", "10").is_lt());
+    assert!(compare_cells("apple", "Banana").is_lt());
+
+    let rows = vec![
+        TableRow::new(["b", "10"]),
+        TableRow::new(["a", "9"]),
+        TableRow::new(["B", "10"]),
+        TableRow::new(["c"]),
+    ];
+    assert_eq!(sorted_row_order(&rows, None), [0, 1, 2, 3]);
+    assert_eq!(
+        sorted_row_order(&rows, Some(ColumnSort::ascending(1))),
+        [3, 1, 0, 2],
+        "missing cells sort first; ties keep their order"
+    );
+    assert_eq!(
+        sorted_row_order(&rows, Some(ColumnSort::descending(1))),
+        [0, 2, 1, 3]
+    );
+    assert_eq!(
+        sorted_row_order(&rows, Some(ColumnSort::ascending(0))),
+        [1, 2, 0, 3]
+    );
+}
+
+#[test]
+fn data_table_selection_follows_mode() {
+    let set = |rows: &[usize]| rows.iter().copied().collect::<BTreeSet<_>>();
+    let selected = set(&[2]);
+
+    assert_eq!(
+        select_row(&selected, 4, SelectionMode::None, false),
+        set(&[2])
+    );
+    assert_eq!(
+        select_row(&selected, 4, SelectionMode::Single, false),
+        set(&[4])
+    );
+    assert_eq!(
+        select_row(&selected, 2, SelectionMode::Single, true),
+        set(&[])
+    );
+    assert_eq!(
+        select_row(&selected, 4, SelectionMode::Multiple, true),
+        set(&[2, 4])
+    );
+    assert_eq!(
+        select_row(&selected, 2, SelectionMode::Multiple, true),
+        set(&[])
+    );
+    assert_eq!(
+        select_row(&set(&[2, 4]), 5, SelectionMode::Multiple, false),
+        set(&[5])
+    );
+}
+
+#[test]
+fn data_table_navigation_skips_disabled_rows_in_display_order() {
+    let rows = [
+        TableRow::new(["c"]),
+        TableRow::new(["a"]),
+        TableRow::new(["b"]).set_disabled(true),
+    ];
+    let order = sorted_row_order(&rows, Some(ColumnSort::ascending(0)));
+    let next = navigate_index(0, NavDirection::Next, order.len(), |position| {
+        rows[order[position]].disabled
+    });
+    assert_eq!(order[next], 0, "a -> (b disabled) -> c");
+}
+
+// ---- FilePicker Contract Tests ----
+
+#[test]
+fn file_picker_contract_validates() {
+    let contract = FilePicker::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "FilePicker contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    assert!(contract.states.contains(&ComponentState::Error));
+    assert_eq!(contract.variants, ["Button", "DropZone"]);
+    assert!(contract.props.iter().any(|p| p.name == "dialog"));
+}
+
+#[test]
+fn file_filter_matches_extensions_ignoring_case() {
+    let filter = FileFilter::new("Image", [".PNG", "jpg"]);
+    assert!(filter.matches(Path::new("logo.png")));
+    assert!(filter.matches(Path::new("photos/Beach.JPG")));
+    assert!(!filter.matches(Path::new("notes.txt")));
+    assert!(!filter.matches(Path::new("png")));
+    assert_eq!(filter.label(), "Image (.png, .jpg)");
+}
+
+#[test]
+fn merge_picked_filters_and_combines_selection() {
+    let filters = [FileFilter::new("Theme", ["json"])];
+    let current = vec![PathBuf::from("a.json")];
+    let picked = || {
+        ["b.json", "notes.txt", "a.json"]
+            .into_iter()
+            .map(PathBuf::from)
+    };
+
+    let multiple = merge_picked(&current, picked(), &filters, true);
+    assert_eq!(
+        multiple.selected,
+        [PathBuf::from("a.json"), PathBuf::from("b.json")]
+    );
+    assert_eq!(multiple.rejected, [PathBuf::from("notes.txt")]);
+
+    // A single picker keeps the last accepted path.
+    let single = merge_picked(&current, picked(), &filters, false);
+    assert_eq!(single.selected, [PathBuf::from("a.json")]);
+    let single = merge_picked(&[], picked().take(1), &filters, false);
+    assert_eq!(single.selected, [PathBuf::from("b.json")]);
+
+    // Nothing accepted leaves the selection alone.
+    let rejected = merge_picked(&current, [PathBuf::from("x.txt")], &filters, false);
+    assert_eq!(rejected.selected, current);
+    assert_eq!(
+        rejection_message(&rejected.rejected, &filters).as_deref(),
+        Some("Skipped x.txt: expected Theme (.json)")
+    );
+    assert_eq!(rejection_message(&[], &filters), None);
+
+    // Without filters everything is accepted.
+    assert!(merge_picked(&[], picked(), &[], true).rejected.is_empty());
+}
+
+#[test]
+fn mock_file_dialog_replays_responses() {
+    let dialog = MockFileDialog::new()
+        .cancel()
+        .respond_with(["themes/ocean.json"]);
+    assert_eq!(dialog.next_response(prompt_options(false, None)), None);
+    let expected = Some(vec![PathBuf::from("themes/ocean.json")]);
+    assert_eq!(
+        dialog.next_response(prompt_options(true, Some("Load".into()))),
+        expected
+    );
+    // The last response repeats.
+    assert_eq!(dialog.next_response(prompt_options(true, None)), expected);
+
+    let requests = dialog.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(
+        requests
+            .iter()
+            .all(|options| options.files && !options.directories)
+    );
+    assert!(!requests[0].multiple);
+    assert_eq!(requests[1].prompt, Some("Load".into()));
+
+    assert_eq!(
+        MockFileDialog::new().next_response(prompt_options(false, None)),
+        None
+    );
+}
+
+// ---- Form Contract Tests ----
+
+#[test]
+fn form_contract_validates() {
+    let contract = Form::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "Form contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    assert!(contract.states.contains(&ComponentState::Error));
+    assert!(contract.dependencies.iter().any(|d| d == "Button"));
+    assert!(contract.props.iter().any(|p| p.name == "on_submit"));
+}
+
+#[test]
+fn form_validation_aggregates_field_states() {
+    let range = NumberRange::new(1.0, 10.0, 1.0);
+    let (state, message) = validate_number("12", &range);
+    let fields = [
+        FormField::new("Name"),
+        FormField::new("Seats").validation(state, message),
+        FormField::new("Email").error("Enter an email address"),
+    ];
+    assert_eq!(fields[1].validation_state(), ValidationState::Error);
+    assert_eq!(
+        aggregate_validation(fields.iter().map(FormField::validation_state)),
+        ValidationState::Error
+    );
+    assert_eq!(
+        aggregate_validation(fields[..1].iter().map(FormField::validation_state)),
+        ValidationState::None
+    );
+    assert_eq!(aggregate_validation([]), ValidationState::None);
+}
+
+#[test]
+fn error_summary_names_fields_in_error() {
+    assert_eq!(error_summary(&[]), None);
+    assert_eq!(
+        error_summary(&["Email"]).as_deref(),
+        Some("Fix 1 field: Email")
+    );
+    assert_eq!(
+        error_summary(&["Name", "Email", "Plan"]).as_deref(),
+        Some("Fix 3 fields: Name, Email and Plan")
+    );
+}
+
+// ---- Switch Contract Tests ----
+
+#[test]
+fn switch_contract_validates() {
+    let contract = Switch::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "Switch contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Fork);
+    assert!(contract.states.contains(&ComponentState::Selected));
+    assert!(contract.states.contains(&ComponentState::Focused));
+    assert_eq!(contract.variants, ["Small", "Medium", "Large"]);
+    // A switch has no indeterminate state, unlike Checkbox.
+    assert!(!contract.props.iter().any(|p| p.name == "indeterminate"));
+}
+
+#[test]
+fn switch_thumb_slides_across_track() {
+    for size in [SwitchSize::Small, SwitchSize::Medium, SwitchSize::Large] {
+        let (width, height) = size.track();
+        assert!(width > height);
+        assert_eq!(size.thumb() + 4.0, height);
+        assert_eq!(thumb_offset(size, false, false, 0.5), 0.0);
+        assert_eq!(thumb_offset(size, true, true, 0.0), size.travel());
+        assert_eq!(thumb_offset(size, false, true, 1.0), size.travel());
+        assert_eq!(thumb_offset(size, true, false, 1.0), 0.0);
     }
+    let medium = SwitchSize::Medium;
+    assert_eq!(medium.travel(), 16.0);
+    assert_eq!(thumb_offset(medium, false, true, 0.25), 4.0);
+    assert_eq!(thumb_offset(medium, true, false, 0.25), 12.0);
+    // Progress outside 0..=1 is clamped.
+    assert_eq!(thumb_offset(medium, false, true, 2.0), 16.0);
+}
+
+// ---- Slider Contract Tests ----
+
+#[test]
+fn slider_contract_validates() {
+    let contract = Slider::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "Slider contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Fork);
+    assert!(contract.states.contains(&ComponentState::Focused));
+    assert!(contract.states.contains(&ComponentState::Disabled));
+    assert!(contract.props.iter().any(|p| p.name == "orientation"));
+    assert!(contract.props.iter().any(|p| p.name == "step"));
+}
+
+#[test]
+fn slider_range_snaps_and_clamps() {
+    let range = SliderRange::new(0.0, 1.0, 0.25);
+    assert_eq!(range.snap(0.3), 0.25);
+    assert_eq!(range.snap(0.4), 0.5);
+    assert_eq!(range.snap(-3.0), 0.0);
+    assert_eq!(range.snap(7.0), 1.0);
+    assert_eq!(range.decimals(), 2);
+    // Reversed bounds are swapped; a step that overshoots never exceeds max.
+    let reversed = SliderRange::new(10.0, 0.0, 3.0);
+    assert_eq!((reversed.min, reversed.max), (0.0, 10.0));
+    assert_eq!(reversed.snap(10.0), 9.0);
+    // Continuous ranges keep the exact value.
+    assert_eq!(SliderRange::new(0.0, 1.0, 0.0).snap(0.123), 0.123);
+    assert_eq!(range.fraction(0.75), 0.75);
+    assert_eq!(range.value_at(0.6), 0.5);
+    assert_eq!(SliderRange::new(5.0, 5.0, 1.0).fraction(5.0), 0.0);
+}
+
+#[test]
+fn slider_keys_step_through_range() {
+    let range = SliderRange::default();
+    assert_eq!(range.key_value(keys::ARROW_RIGHT, 50.0), Some(51.0));
+    assert_eq!(range.key_value(keys::ARROW_UP, 50.0), Some(51.0));
+    assert_eq!(range.key_value(keys::ARROW_LEFT, 50.0), Some(49.0));
+    assert_eq!(range.key_value(keys::ARROW_DOWN, 50.0), Some(49.0));
+    assert_eq!(
+        range.key_value(keys::PAGE_UP, 50.0),
+        Some(50.0 + SLIDER_PAGE_STEPS as f32)
+    );
+    assert_eq!(range.key_value(keys::PAGE_DOWN, 5.0), Some(0.0));
+    assert_eq!(range.key_value(keys::HOME, 50.0), Some(0.0));
+    assert_eq!(range.key_value(keys::END, 50.0), Some(100.0));
+    assert_eq!(range.key_value(keys::ARROW_RIGHT, 100.0), Some(100.0));
+    assert_eq!(range.key_value(keys::ENTER, 50.0), None);
+    // Continuous ranges step in hundredths.
+    let continuous = SliderRange::new(0.0, 10.0, 0.0);
+    assert_eq!(continuous.key_value(keys::ARROW_RIGHT, 5.0), Some(5.1));
+}
+
+#[test]
+fn slider_fraction_follows_orientation() {
+    let bounds = Bounds::new(point(px(10.0), px(20.0)), size(px(100.0), px(200.0)));
+    let position = point(px(35.0), px(70.0));
+    assert_eq!(fraction_at(bounds, position, Orientation::Horizontal), 0.25);
+    assert_eq!(fraction_at(bounds, position, Orientation::Vertical), 0.75);
+    // Positions past the track clamp to its ends.
+    assert_eq!(
+        fraction_at(bounds, point(px(500.0), px(0.0)), Orientation::Horizontal),
+        1.0
+    );
+    assert_eq!(
+        fraction_at(bounds, point(px(0.0), px(500.0)), Orientation::Vertical),
+        0.0
+    );
+    // An unmeasured track maps everything to the start.
+    assert_eq!(
+        fraction_at(Bounds::default(), position, Orientation::Horizontal),
+        0.0
+    );
+}
+
+// ---- NotificationCenter Contract Tests ----
+
+#[test]
+fn notification_center_contract_validates() {
+    let contract = NotificationCenter::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "NotificationCenter contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    assert!(
+        contract
+            .token_dependencies
+            .iter()
+            .any(|t| t.path == "status.error.foreground")
+    );
+}
+
+#[test]
+fn notification_history_groups_by_day_and_tracks_unread() {
+    let day = Duration::from_secs(86_400);
+    let today = UNIX_EPOCH + day * 20_000 + Duration::from_secs(3_600);
+    let mut history = NotificationHistory::new().capacity(3);
+
+    let first = history.record(ToastEntry::new("saved", "Saved"), today);
+    history.record(
+        ToastEntry::new("build", "Build failed").timestamp(today - day),
+        today,
+    );
+    // Repeated toast ids get their own notification.
+    let last = history.record(ToastEntry::new("saved", "Saved again"), today);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.unread_count(), 3);
+
+    let groups = history.by_day();
+    let days: Vec<_> = groups.iter().map(|(d, _)| *d).collect();
+    assert_eq!(days, [day_number(today), day_number(today) - 1]);
+    let titles: Vec<_> = groups[0]
+        .1
+        .iter()
+        .map(|n| n.entry.title.to_string())
+        .collect();
+    assert_eq!(
+        titles,
+        ["Saved again", "Saved"],
+        "newest first within a day"
+    );
+    assert_eq!(
+        groups[1].1[0].raised_at,
+        today - day,
+        "uses the toast timestamp"
+    );
+
+    assert!(history.mark_read(last));
+    assert_eq!(history.unread_count(), 2);
+    history.mark_all_read();
+    assert_eq!(history.unread_count(), 0);
+
+    // Over capacity the oldest notification is dropped.
+    history.record(ToastEntry::new("export", "Exported"), today);
+    assert_eq!(history.len(), 3);
+    assert!(history.get(first).is_none());
+
+    assert!(history.remove(last));
+    assert!(!history.remove(last));
+    history.clear_all();
+    assert!(history.is_empty());
+}
+
+#[test]
+fn notification_history_forgets_everything_when_recording_stops() {
+    let mut history = NotificationHistory::new();
+    assert!(history.is_recording());
+    history.record(ToastEntry::new("saved", "Saved"), UNIX_EPOCH);
+
+    history.set_recording(false);
+    assert!(!history.is_recording());
+    assert!(history.is_empty());
+}
+
+#[test]
+fn notification_day_labels() {
+    assert_eq!(day_label(20_000, 20_000), "Today");
+    assert_eq!(day_label(19_999, 20_000), "Yesterday");
+    assert_eq!(day_label(0, 20_000), "1970-01-01");
+    assert_eq!(day_label(20_740, 20_742), "2026-10-14");
+    assert_eq!(day_label(11_016, 20_000), "2000-02-29");
+    // A clock set back counts as today.
+    assert_eq!(day_label(20_001, 20_000), "Today");
+    assert_eq!(day_number(UNIX_EPOCH - Duration::from_secs(1)), 0);
+    assert_eq!(day_number(UNIX_EPOCH + Duration::from_secs(86_399)), 0);
+}
+
+#[test]
+fn toast_manager_hands_back_closed_toasts() {
+    let mut manager = ToastManager::new().max_visible(1);
+    for id in ["a", "b", "c"] {
+        manager.push(ToastEntry::new(id, "Saved"));
+    }
+    manager.finish_transitions();
+
+    // Queued toasts close immediately; visible ones once their exit settles.
+    assert!(manager.dismiss("c"));
+    assert!(manager.dismiss("a"));
+    let closed: Vec<_> = manager
+        .finish_transitions()
+        .iter()
+        .map(|t| t.id.to_string())
+        .collect();
+    assert_eq!(closed, ["c", "a"]);
+    assert!(manager.finish_transitions().is_empty());
+
+    manager.dismiss_all();
+    assert_eq!(manager.finish_transitions().len(), 1);
+    assert!(manager.is_empty());
+}
+
+// ---- ProgressBar and Spinner Contract Tests ----
+
+#[test]
+fn progress_contracts_validate() {
+    for contract in [ProgressBar::contract(), Spinner::contract()] {
+        let errors = contract.validate();
+        assert!(
+            errors.is_empty(),
+            "{} contract validation failed: {:?}",
+            contract.name,
+            errors
+        );
+        assert_eq!(contract.disposition, Disposition::Rewrite);
+        for path in ["element.background", "status.error.foreground"] {
+            assert!(
+                contract.token_dependencies.iter().any(|t| t.path == path),
+                "{} should depend on {path}",
+                contract.name
+            );
+        }
+    }
+    assert_eq!(
+        ProgressBar::contract().variants,
+        ["Info", "Success", "Warning", "Error"]
+    );
+    assert_eq!(Spinner::contract().variants, ["Small", "Medium", "Large"]);
+}
+
+#[test]
+fn progress_values_clamp_and_round() {
+    assert_eq!(progress_fraction(0.25), 0.25);
+    assert_eq!(progress_fraction(-1.0), 0.0);
+    assert_eq!(progress_fraction(1.5), 1.0);
+    assert_eq!(progress_fraction(f32::NAN), 0.0);
+    assert_eq!(progress_percent(0.424), 42);
+    assert_eq!(progress_percent(0.425), 43);
+    assert_eq!(progress_percent(2.0), 100);
+
+    let sizes = [
+        ProgressSize::Small,
+        ProgressSize::Medium,
+        ProgressSize::Large,
+    ];
+    for pair in sizes.windows(2) {
+        assert!(pair[0].bar_height() < pair[1].bar_height());
+        assert!(pair[0].spinner_diameter() < pair[1].spinner_diameter());
+    }
+}
+
+#[test]
+fn spinner_arc_runs_clockwise_from_top() {
+    let near = |(x, y): (f32, f32), (ex, ey): (f32, f32)| {
+        assert!((x - ex).abs() < 1e-5 && (y - ey).abs() < 1e-5, "{x},{y}");
+    };
+    let quarter = arc_points(0.0, 0.25);
+    near(quarter[0], (0.0, -1.0));
+    near(*quarter.last().unwrap(), (1.0, 0.0));
+    assert_eq!(quarter.len(), 13);
+
+    // Rotation is a start offset; a full sweep closes the circle.
+    near(arc_points(0.5, 0.25)[0], (0.0, 1.0));
+    let circle = arc_points(0.0, 1.0);
+    near(*circle.last().unwrap(), circle[0]);
+    assert!(circle.iter().all(|(x, y)| (x.hypot(*y) - 1.0).abs() < 1e-5));
+}
+
+// ---- Card and Separator Contract Tests ----
+
+#[test]
+fn card_and_separator_contracts_validate() {
+    for contract in [Card::contract(), Separator::contract()] {
+        let errors = contract.validate();
+        assert!(
+            errors.is_empty(),
+            "{} contract validation failed: {:?}",
+            contract.name,
+            errors
+        );
+        assert_eq!(contract.disposition, Disposition::Rewrite);
+        assert!(
+            contract
+                .token_dependencies
+                .iter()
+                .any(|t| t.path == "border.variant"),
+            "{} should draw rules with border.variant",
+            contract.name
+        );
+    }
+    assert_eq!(Card::contract().variants, ["Static", "Interactive"]);
+    assert_eq!(Separator::contract().variants, ["Horizontal", "Vertical"]);
+}
+
+#[test]
+fn card_sits_on_the_elevated_surface_and_hovers_when_interactive() {
+    let contract = Card::contract();
+    let tokens: Vec<&str> = contract
+        .token_dependencies
+        .iter()
+        .map(|t| t.path.as_str())
+        .collect();
+    assert!(tokens.contains(&"surface.elevated_surface"));
+    assert!(tokens.contains(&"element.hover"));
+    assert!(contract.states.contains(&ComponentState::Hover));
+    assert!(contract.props.iter().any(|p| p.name == "on_click"));
+}
+
+// ---- Skeleton Contract Tests ----
+
+#[test]
+fn skeleton_contract_validates_and_uses_surface_tokens() {
+    let contract = Skeleton::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "Skeleton contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.variants, ["Line", "Circle", "Rect"]);
+    assert!(
+        contract
+            .token_dependencies
+            .iter()
+            .any(|t| t.path == "surface.elevated_surface")
+    );
+
+    let shapes = [
+        SkeletonShape::Line,
+        SkeletonShape::Circle,
+        SkeletonShape::Rect,
+    ];
+    for pair in shapes.windows(2) {
+        assert!(pair[0].default_height() < pair[1].default_height());
+    }
+}
+
+#[test]
+fn skeleton_shimmer_fades_in_then_out() {
+    assert_eq!(shimmer_opacity(0.0), 0.0);
+    assert_eq!(shimmer_opacity(1.0), 0.0);
+    assert!(shimmer_opacity(0.25) < shimmer_opacity(0.5));
+    assert!((shimmer_opacity(0.25) - shimmer_opacity(0.75)).abs() < 1e-6);
+    assert_eq!(shimmer_opacity(-1.0), 0.0);
+    assert_eq!(shimmer_opacity(2.0), 0.0);
+}
+
+// ---- ScrollArea Contract Tests ----
+
+#[test]
+fn scroll_area_contract_validates() {
+    let contract = ScrollArea::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "ScrollArea contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    for path in [
+        "scrollbar.thumb_background",
+        "scrollbar.thumb_hover_background",
+        "scrollbar.thumb_border",
+        "scrollbar.track_background",
+        "scrollbar.track_border",
+    ] {
+        assert!(
+            contract.token_dependencies.iter().any(|t| t.path == path),
+            "ScrollArea should depend on {path}"
+        );
+    }
+}
+
+#[test]
+fn scroll_area_handle_clamps_before_layout() {
+    // Nothing has been laid out, so there is nothing to scroll.
+    let handle = ScrollAreaHandle::new();
+    handle.scroll_to(point(px(40.0), px(120.0)));
+    assert_eq!(handle.offset(), point(px(0.0), px(0.0)));
+    handle.scroll_by(point(px(0.0), px(-10.0)));
+    assert_eq!(handle.offset(), point(px(0.0), px(0.0)));
+}
+
+// ---- SortableList Contract Tests ----
+
+#[test]
+fn sortable_list_contract_validates() {
+    let contract = SortableList::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "SortableList contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    assert!(contract.states.contains(&ComponentState::Focused));
+    for key in ["space", "escape"] {
+        assert!(
+            contract
+                .key_bindings
+                .iter()
+                .any(|binding| binding.keys.iter().any(|k| k == key)),
+            "SortableList should bind {key}"
+        );
+    }
+    let threshold = format!("{DRAG_THRESHOLD}px");
+    assert!(
+        contract
+            .pointer_behavior
+            .as_deref()
+            .is_some_and(|behavior| behavior.contains(&threshold)),
+        "pointer behavior should state the {threshold} drag threshold"
+    );
+}
+
+// ---- SplitPane Contract Tests ----
+
+#[test]
+fn split_pane_contract_validates() {
+    let contract = SplitPane::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "SplitPane contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Rewrite);
+    assert!(contract.states.contains(&ComponentState::Focused));
+    for prop in [
+        "orientation",
+        "anchor",
+        "first_limits",
+        "second_limits",
+        "collapsible",
+    ] {
+        assert!(contract.props.iter().any(|p| p.name == prop && !p.required));
+    }
+}
+
+#[test]
+fn split_pane_key_bindings_match_resize_steps() {
+    let contract = SplitPane::contract();
+    let describes = |step: f32| {
+        let step = format!("{step}px");
+        contract
+            .key_bindings
+            .iter()
+            .any(|binding| binding.action.contains(&step))
+    };
+    assert!(describes(RESIZE_STEP));
+    assert!(describes(RESIZE_PAGE_STEP));
+}
+
+// ---- StatusBar Contract Tests ----
+
+#[test]
+fn status_bar_contract_validates() {
+    let contract = StatusBar::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "StatusBar contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Fork);
+    for path in [
+        "chrome.status_bar_background",
+        "status.success.foreground",
+        "status.error.foreground",
+        "border.focused",
+    ] {
+        assert!(
+            contract.token_dependencies.iter().any(|t| t.path == path),
+            "StatusBar should depend on {path}"
+        );
+    }
+    assert!(contract.states.contains(&ComponentState::Focused));
+}
+
+#[test]
+fn status_segments_are_interactive_only_with_a_handler() {
+    let plain = StatusSegment::new("story", "Button");
+    assert!(!plain.is_interactive());
+    assert_eq!(plain.indicator, None);
+
+    let clickable = StatusSegment::new("fps", "60 fps")
+        .indicator(StatusIndicator::Success)
+        .tooltip("Toggle the perf HUD")
+        .on_click(|_window, _cx| {});
+    assert!(clickable.is_interactive());
+    assert_eq!(clickable.indicator, Some(StatusIndicator::Success));
+    assert_eq!(clickable.tooltip.as_deref(), Some("Toggle the perf HUD"));
+
+    // Slots accept any mix of plain and interactive segments.
+    let _bar = StatusBar::new("status")
+        .left(plain)
+        .segment(StatusBarSlot::Center, StatusSegment::new("mode", "Editing"))
+        .right(clickable);
+}
+
+// ---- CommandPalette Contract Tests ----
+
+#[test]
+fn command_palette_contract_validates() {
+    let contract = CommandPalette::contract();
+    let errors = contract.validate();
+    assert!(
+        errors.is_empty(),
+        "CommandPalette contract validation failed: {:?}",
+        errors
+    );
+    assert_eq!(contract.disposition, Disposition::Fork);
+    assert!(contract.states.contains(&ComponentState::Open));
+    assert!(contract.states.contains(&ComponentState::Disabled));
+    for path in [
+        "surface.elevated_surface",
+        "text.accent",
+        "ghost_element.selected",
+    ] {
+        assert!(
+            contract.token_dependencies.iter().any(|t| t.path == path),
+            "CommandPalette should depend on {path}"
+        );
+    }
+}
+
+#[test]
+fn command_search_text_includes_category() {
+    let plain = CommandItem::new("file.save", "Save");
+    assert_eq!(plain.search_text(), "Save");
+    let grouped = CommandItem::new("theme.toggle", "Toggle Theme").category("View");
+    assert_eq!(grouped.search_text(), "View: Toggle Theme");
+}
+
+#[test]
+fn command_matches_rank_best_first() {
+    let commands = [
+        CommandItem::new("zoom", "Zoom In").category("View"),
+        CommandItem::new("toggle", "Toggle Theme").category("View"),
+        CommandItem::new("export", "Export Theme Patch").category("Theme"),
+        CommandItem::new("save", "Save").disabled(true),
+    ];
+
+    let ranked: Vec<usize> = command_matches("theme", &commands)
+        .into_iter()
+        .map(|(index, _)| index)
+        .collect();
+    // The category prefix matches too, and an earlier match ranks first.
+    assert_eq!(ranked, [2, 1]);
+
+    // Disabled commands still match; the palette only skips them when navigating.
+    let (index, found) = &command_matches("sav", &commands)[0];
+    assert_eq!(*index, 3);
+    assert_eq!(found.positions, [0, 1, 2]);
+
+    // An empty query lists every command in order.
+    assert_eq!(command_matches("", &commands).len(), commands.len());
+    assert!(command_matches("qqq", &commands).is_empty());
+}
+
+// ---- TitleBar Contract Tests ----
+
+#[test]
+fn title_bar_contract_validates() {
+    let contract = TitleBar::contract();
+    assert_eq!(contract.name, "TitleBar");
+    assert!(contract.validate().is_empty());
+    assert_eq!(contract.variants, ["Mac", "Linux", "Windows"]);
+    assert!(
+        contract
+            .token_dependencies
+            .iter()
+            .any(|t| t.path == "chrome.title_bar_background")
+    );
+}
+
+#[test]
+fn title_bar_reserves_traffic_lights_only_on_windowed_mac() {
+    assert_eq!(
+        TitleBarPlatform::Mac.leading_inset(false),
+        TRAFFIC_LIGHT_INSET
+    );
+    assert_eq!(TitleBarPlatform::Mac.leading_inset(true), 0.0);
+    assert_eq!(TitleBarPlatform::Linux.leading_inset(false), 0.0);
+    assert_eq!(TitleBarPlatform::Windows.leading_inset(false), 0.0);
+}
+
+#[test]
+fn title_bar_draws_caption_buttons_per_platform() {
+    assert!(!TitleBarPlatform::Mac.draws_caption_buttons(true));
+    assert!(TitleBarPlatform::Windows.draws_caption_buttons(false));
+    assert!(TitleBarPlatform::Linux.draws_caption_buttons(true));
+    assert!(!TitleBarPlatform::Linux.draws_caption_buttons(false));
+}
+
+// ---- ContextMenu Contract Tests ----
+
+#[test]
+fn context_menu_contract_validates() {
+    let contract = ContextMenu::contract();
+    assert_eq!(contract.name, "ContextMenu");
+    assert!(contract.validate().is_empty());
+    assert!(contract.dependencies.iter().any(|d| d == "Kbd"));
+}
+
+/// Cut, Copy, ---, Paste (disabled), Sort By > [Name, ---, Date].
+fn sample_context_menu() -> Vec<ContextMenuItem> {
+    vec![
+        ContextMenuItem::new("Cut"),
+        ContextMenuItem::new("Copy"),
+        ContextMenuItem::separator(),
+        ContextMenuItem::new("Paste").disabled(true),
+        ContextMenuItem::submenu(
+            "Sort By",
+            vec![
+                ContextMenuItem::new("Name"),
+                ContextMenuItem::separator(),
+                ContextMenuItem::new("Date"),
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn menu_path_navigation_skips_separators_and_disabled_items() {
+    let items = sample_context_menu();
+    let mut path = MenuPath::new();
+    assert_eq!(path.highlighted(0), None);
+
+    path.navigate(&items, NavDirection::Next);
+    assert_eq!(path.highlighted(0), Some(0));
+    path.navigate(&items, NavDirection::Next);
+    path.navigate(&items, NavDirection::Next);
+    // Skips the separator and disabled Paste.
+    assert_eq!(path.highlighted(0), Some(4));
+    path.navigate(&items, NavDirection::Next);
+    assert_eq!(path.highlighted(0), Some(0));
+
+    // Previous with nothing highlighted starts from the bottom.
+    let mut path = MenuPath::new();
+    path.navigate(&items, NavDirection::Previous);
+    assert_eq!(path.highlighted(0), Some(4));
+}
+
+#[test]
+fn menu_path_enters_and_closes_submenus() {
+    let items = sample_context_menu();
+    let mut path = MenuPath::new();
+    // Nothing to enter from an action item.
+    path.navigate(&items, NavDirection::First);
+    assert!(!path.enter_submenu(&items));
+
+    path.navigate(&items, NavDirection::Last);
+    assert!(path.enter_submenu(&items));
+    assert_eq!(path.depth(), 2);
+    assert_eq!(path.highlighted_item(&items).unwrap().label, "Name");
+    path.navigate(&items, NavDirection::Next);
+    assert_eq!(path.highlighted_item(&items).unwrap().label, "Date");
+
+    assert!(path.close_submenu());
+    assert_eq!(path.depth(), 1);
+    assert_eq!(path.highlighted_item(&items).unwrap().label, "Sort By");
+    assert!(!path.close_submenu());
+}
+
+#[test]
+fn menu_path_hover_opens_submenus_and_ignores_inert_rows() {
+    let items = sample_context_menu();
+    let mut path = MenuPath::new();
+    path.hover(&items, 0, 4);
+    assert_eq!(path.depth(), 2);
+    assert_eq!(path.highlighted(1), None);
+    assert_eq!(path.items_at(&items, 1).len(), 3);
+
+    // Entering a hover-opened submenu highlights its first enabled item.
+    assert!(path.enter_submenu(&items));
+    assert_eq!(path.highlighted(1), Some(0));
+
+    // Hovering a disabled row closes the submenu without highlighting it.
+    path.hover(&items, 0, 3);
+    assert_eq!(path.depth(), 1);
+    assert_eq!(path.highlighted(0), None);
+
+    // A disabled submenu does not open.
+    let disabled =
+        [ContextMenuItem::submenu("More", vec![ContextMenuItem::new("A")]).disabled(true)];
+    let mut path = MenuPath::new();
+    path.hover(&disabled, 0, 0);
+    assert_eq!(path.depth(), 1);
+}
+
+// ---- MenuBar Contract Tests ----
+
+#[test]
+fn menu_bar_contract_validates() {
+    let contract = MenuBar::contract();
+    assert_eq!(contract.name, "MenuBar");
+    assert!(contract.validate().is_empty());
+    assert!(contract.dependencies.iter().any(|d| d == "ContextMenu"));
+}
+
+/// File (the sample context menu), Edit (disabled), View [Zoom].
+fn sample_menu_bar() -> Vec<MenuBarMenu> {
+    vec![
+        MenuBarMenu::new("File", sample_context_menu()),
+        MenuBarMenu::new("Edit", vec![ContextMenuItem::new("Undo")]).disabled(true),
+        MenuBarMenu::new("View", vec![ContextMenuItem::new("Zoom")]),
+    ]
+}
+
+#[test]
+fn menu_bar_moves_between_titles_skipping_disabled_menus() {
+    let menus = sample_menu_bar();
+    let mut bar = MenuBarState::new();
+    bar.move_to(&menus, NavDirection::Next);
+    assert_eq!(bar.active(), 2);
+    assert_eq!(bar.open_menu(), None);
+    bar.move_to(&menus, NavDirection::Next);
+    assert_eq!(bar.active(), 0);
+
+    // A disabled menu does not open.
+    bar.open(&menus, 1);
+    assert_eq!(bar.open_menu(), None);
+    assert_eq!(bar.active(), 0);
+}
+
+#[test]
+fn menu_bar_hover_switches_menus_only_while_one_is_open() {
+    let menus = sample_menu_bar();
+    let mut bar = MenuBarState::new();
+    bar.hover_title(&menus, 2);
+    assert_eq!(bar.open_menu(), None);
+
+    bar.open(&menus, 0);
+    bar.hover_title(&menus, 2);
+    assert_eq!(bar.open_menu(), Some(2));
+    bar.hover_title(&menus, 1);
+    assert_eq!(bar.open_menu(), Some(2), "disabled menus do not open");
+
+    bar.close();
+    assert_eq!(bar.open_menu(), None);
+    assert_eq!(bar.active(), 2);
+}
+
+#[test]
+fn menu_bar_arrows_enter_submenus_then_cross_menus() {
+    let menus = sample_menu_bar();
+    let mut bar = MenuBarState::new();
+    bar.open(&menus, 0);
+    bar.navigate(&menus, NavDirection::Last);
+    assert_eq!(bar.highlighted_item(&menus).unwrap().label, "Sort By");
+
+    // Right enters the submenu first, then moves to the next menu.
+    bar.right(&menus);
+    assert_eq!(bar.path().unwrap().depth(), 2);
+    assert_eq!(bar.highlighted_item(&menus).unwrap().label, "Name");
+    bar.right(&menus);
+    assert_eq!(bar.open_menu(), Some(2));
+    assert_eq!(bar.highlighted_item(&menus).unwrap().label, "Zoom");
+
+    // Left moves back, skipping the disabled menu.
+    bar.left(&menus);
+    assert_eq!(bar.open_menu(), Some(0));
+    assert_eq!(bar.highlighted_item(&menus).unwrap().label, "Cut");
+
+    // Left closes an open submenu before leaving the menu.
+    bar.hover_item(&menus, 0, 4);
+    assert!(bar.enter_submenu(&menus));
+    bar.left(&menus);
+    assert_eq!(bar.open_menu(), Some(0));
+    assert_eq!(bar.highlighted_item(&menus).unwrap().label, "Sort By");
+    assert!(!bar.close_submenu());
+}
+
+// ---- Breadcrumbs Contract Tests ----
+
+#[test]
+fn breadcrumbs_contract_validates() {
+    let contract = Breadcrumbs::contract();
+    assert_eq!(contract.name, "Breadcrumbs");
+    assert!(contract.validate().is_empty());
+    assert!(contract.states.contains(&ComponentState::Open));
+}
+
+#[test]
+fn collapse_breadcrumbs_keeps_short_paths_whole() {
+    use BreadcrumbSegment::Item;
+    assert_eq!(collapse_breadcrumbs(0, 4), []);
+    assert_eq!(collapse_breadcrumbs(3, 4), [Item(0), Item(1), Item(2)]);
+    assert_eq!(
+        collapse_breadcrumbs(4, 4),
+        [Item(0), Item(1), Item(2), Item(3)]
+    );
+}
+
+#[test]
+fn collapse_breadcrumbs_folds_the_middle_into_one_overflow() {
+    use BreadcrumbSegment::{Item, Overflow};
+    assert_eq!(
+        collapse_breadcrumbs(8, 4),
+        [Item(0), Overflow(1..5), Item(5), Item(6), Item(7)]
+    );
+    // The root and current item always show.
+    assert_eq!(
+        collapse_breadcrumbs(8, 0),
+        [Item(0), Overflow(1..7), Item(7)]
+    );
+    assert_eq!(
+        collapse_breadcrumbs(3, 2),
+        [Item(0), Overflow(1..2), Item(2)]
+    );
+}
+
+// ---- Avatar Contract Tests ----
+
+#[test]
+fn avatar_contracts_validate() {
+    for contract in [Avatar::contract(), AvatarGroup::contract()] {
+        let errors = contract.validate();
+        assert!(
+            errors.is_empty(),
+            "{} contract validation failed: {:?}",
+            contract.name,
+            errors
+        );
+        assert_eq!(contract.variants, ["Small", "Medium", "Large", "XLarge"]);
+        assert_eq!(contract.required_files, ["crates/components/src/avatar.rs"]);
+    }
+    assert!(
+        Avatar::contract()
+            .props
+            .iter()
+            .any(|prop| prop.name == "src" && !prop.required)
+    );
+}
+
+#[test]
+fn avatar_initials_use_first_and_last_words() {
+    assert_eq!(avatar_initials("Ada Lovelace"), "AL");
+    assert_eq!(avatar_initials("Grace Brewster Murray Hopper"), "GH");
+    assert_eq!(avatar_initials("grace"), "G");
+    assert_eq!(avatar_initials("  émile   zola "), "ÉZ");
+    assert_eq!(avatar_initials("(Ada) Lovelace"), "AL");
+    assert_eq!(avatar_initials(""), "?");
+    assert_eq!(avatar_initials("  "), "?");
+}
+
+#[test]
+fn avatar_group_shows_a_lone_extra_avatar_instead_of_plus_one() {
+    assert_eq!(avatar_group_split(0, 4), (0, 0));
+    assert_eq!(avatar_group_split(4, 4), (4, 0));
+    assert_eq!(avatar_group_split(5, 4), (5, 0));
+    assert_eq!(avatar_group_split(6, 4), (4, 2));
+    assert_eq!(avatar_group_split(9, 2), (2, 7));
+    // A zero limit still shows one avatar.
+    assert_eq!(avatar_group_split(3, 0), (1, 2));
+}
+
+// ---- Badge and Tag Contract Tests ----
+
+#[test]
+fn badge_contracts_validate() {
+    for contract in [Badge::contract(), Tag::contract()] {
+        let errors = contract.validate();
+        assert!(
+            errors.is_empty(),
+            "{} contract validation failed: {:?}",
+            contract.name,
+            errors
+        );
+        assert_eq!(contract.variants, ["Small", "Medium", "Large"]);
+        assert_eq!(contract.required_files, ["crates/components/src/badge.rs"]);
+        for status in ["info", "success", "warning", "error"] {
+            for part in ["background", "foreground", "border"] {
+                let path = format!("status.{status}.{part}");
+                assert!(
+                    contract
+                        .token_dependencies
+                        .iter()
+                        .any(|dep| dep.path == path),
+                    "{} is missing {path}",
+                    contract.name
+                );
+            }
+        }
+    }
+    assert!(Tag::contract().states.contains(&ComponentState::Disabled));
+}
+
+#[test]
+fn badge_count_label_caps_at_max() {
+    assert_eq!(badge_count_label(0, 99, &Locale::EN_US), "0");
+    assert_eq!(badge_count_label(99, 99, &Locale::EN_US), "99");
+    assert_eq!(badge_count_label(100, 99, &Locale::EN_US), "99+");
+    assert_eq!(badge_count_label(1_284, 9_999, &Locale::EN_US), "1,284");
+    assert_eq!(badge_count_label(12_000, 9_999, &Locale::DE_DE), "9.999+");
+}
+
+#[test]
+fn search_result_count_label_groups_digits_by_locale() {
+    assert_eq!(result_count_label(1, &Locale::EN_US), "1 result");
+    assert_eq!(result_count_label(0, &Locale::EN_US), "0 results");
+    assert_eq!(result_count_label(1_024, &Locale::EN_US), "1,024 results");
+    assert_eq!(result_count_label(1_024, &Locale::DE_DE), "1.024 results");
+}
+
+// ---- Label, Link, and Text Contract Tests ----
+
+#[test]
+fn typography_contracts_validate() {
+    for contract in [Label::contract(), Link::contract(), Text::contract()] {
+        let errors = contract.validate();
+        assert!(
+            errors.is_empty(),
+            "{} contract validation failed: {:?}",
+            contract.name,
+            errors
+        );
+        assert_eq!(
+            contract.required_files,
+            ["crates/components/src/typography.rs"]
+        );
+        assert!(contract.states.contains(&ComponentState::Disabled));
+    }
+    assert_eq!(LABEL_FOR_KEY, "for");
+}
+
+#[test]
+fn link_uses_link_hover_token_and_is_focusable() {
+    let contract = Link::contract();
+    assert!(
+        contract
+            .token_dependencies
+            .iter()
+            .any(|dep| dep.path == "link.hover")
+    );
+    assert!(contract.states.contains(&ComponentState::Hover));
+    assert!(contract.states.contains(&ComponentState::Focused));
+    assert_eq!(
+        Text::contract().variants,
+        ["XSmall", "Small", "Base", "Large", "XLarge"]
+    );
+}
+
+// ---- Cross-component tests ----
+
+#[test]
+fn all_poc_contracts_are_fork_disposition() {
+    assert_eq!(Dialog::contract().disposition, Disposition::Fork);
+    assert_eq!(Select::contract().disposition, Disposition::Fork);
+    assert_eq!(Tabs::contract().disposition, Disposition::Fork);
+}
+
+#[test]
+fn all_poc_contracts_validate() {
+    for (name, contract) in [
+        ("Dialog", Dialog::contract()),
+        ("Select", Select::contract()),
+        ("Tabs", Tabs::contract()),
+    ] {
+        let errors = contract.validate();
+        assert!(
+            errors.is_empty(),
+            "{name} contract validation failed: {:?}",
+            errors
+        );
+    }
+}
+
+#[test]
+fn all_poc_contracts_have_no_hardcoded_colors_documented() {
+    // Verify contracts document token dependencies (no hardcoded colors)
+    for (name, contract) in [
+        ("Dialog", Dialog::contract()),
+        ("Select", Select::contract()),
+        ("Tabs", Tabs::contract()),
+    ] {
+        assert!(
+            !contract.token_dependencies.is_empty(),
+            "{name} should document token dependencies"
+        );
+    }
+}
+
+#[test]
+fn all_poc_contracts_have_interaction_checklists() {
+    for (name, contract) in [
+        ("Dialog", Dialog::contract()),
+        ("Select", Select::contract()),
+        ("Tabs", Tabs::contract()),
+    ] {
+        assert!(
+            contract.interaction_checklist.focus_behavior.is_some(),
+            "{name} should document focus behavior"
+        );
+        assert!(
+            contract.interaction_checklist.keyboard_model.is_some(),
+            "{name} should document keyboard model"
+        );
+        assert!(
+            contract.interaction_checklist.pointer_behavior.is_some(),
+            "{name} should document pointer behavior"
+        );
+        assert!(
+            contract.interaction_checklist.state_model.is_some(),
+            "{name} should document state model"
+        );
+    }
+}
+
+#[test]
+fn contract_key_bindings_parse_as_gpui_keystrokes() {
+    for contract in components::catalog::all() {
+        for binding in &contract.key_bindings {
+            for keys in &binding.keys {
+                for keystroke in keys.split_whitespace() {
+                    assert!(
+                        gpui::Keystroke::parse(keystroke).is_ok(),
+                        "{}: `{keys}` ({}) is not a GPUI keystroke",
+                        contract.name,
+                        binding.action
+                    );
+                }
+            }
+        }
+    }
+    assert!(!Dialog::contract().key_bindings.is_empty());
+}
+
+// ---- Shared identifiers ----
+
+#[test]
+fn accessibility_tree_reports_icon_only_nodes_without_labels() {
+    let node = |id: &str, description: Option<&str>, needs_label: bool| AccessibilityNode {
+        component: "Button",
+        id: id.into(),
+        description: description.map(Into::into),
+        needs_label,
+        metadata: Default::default(),
+    };
+    let mut tree = AccessibilityTree::default();
+    tree.record(node("save", None, false));
+    tree.record(node("close", Some("Close"), true));
+    tree.record(node("more", None, true));
+    tree.record(node("pin", Some("  "), true));
+
+    let unlabeled: Vec<&str> = tree.unlabeled().map(|n| n.id.as_str()).collect();
+    assert_eq!(unlabeled, ["more", "pin"]);
+}
+
+#[test]
+fn button_icon_only_is_documented_and_square() {
+    let contract = Button::contract();
+    let icon_only = contract
+        .props
+        .iter()
+        .find(|p| p.name == "icon_only")
+        .expect("icon_only prop");
+    assert!(!icon_only.required);
+    assert!(icon_only.description.contains("label"));
+    assert_eq!(ButtonSize::Small.height(), 24.0);
+    assert_eq!(ButtonSize::Medium.height(), 28.0);
+    assert_eq!(ButtonSize::Large.height(), 32.0);
+}
+
+#[test]
+fn accessibility_tree_replaces_nodes_by_id() {
+    let mut tree = AccessibilityTree::default();
+    assert!(tree.is_empty());
+
+    let mut node = AccessibilityNode {
+        component: "Button",
+        id: "save".into(),
+        description: Some("Save your work".into()),
+        needs_label: false,
+        metadata: [("testid".to_string(), "save-btn".to_string())].into(),
+    };
+    tree.record(node.clone());
+    node.description = None;
+    tree.record(node);
+
+    assert_eq!(tree.len(), 1);
+    let recorded = tree.get("save").expect("node recorded");
+    assert_eq!(recorded.component, "Button");
+    assert_eq!(recorded.description, None);
+    assert_eq!(
+        recorded.metadata.get("testid").map(String::as_str),
+        Some("save-btn")
+    );
+}
+
+#[test]
+fn inspect_tree_hit_test_prefers_innermost_node() {
+    let node = |id: &str, x: f32, y: f32, w: f32, h: f32| InspectNode {
+        component: "Button".into(),
+        id: id.into(),
+        bounds: Bounds::new(point(px(x), px(y)), size(px(w), px(h))),
+        padding: Edges {
+            top: px(4.0),
+            right: px(12.0),
+            bottom: px(4.0),
+            left: px(12.0),
+        },
+        margin: Edges {
+            top: px(0.0),
+            right: px(8.0),
+            bottom: px(0.0),
+            left: px(-4.0),
+        },
+        color_tokens: vec!["element.background".into()],
+    };
+    let mut tree = InspectTree::default();
+    tree.set_enabled(true);
+    tree.record(node("card", 0.0, 0.0, 300.0, 200.0));
+    tree.record(node("save", 20.0, 20.0, 80.0, 28.0));
+
+    let hit = |x: f32, y: f32| tree.hit_test(point(px(x), px(y))).map(|n| n.id.as_str());
+    assert_eq!(hit(30.0, 30.0), Some("save"));
+    assert_eq!(hit(150.0, 150.0), Some("card"));
+    assert_eq!(hit(400.0, 10.0), None);
+
+    let content = tree.get("save").unwrap().content_bounds();
+    assert_eq!(content.origin, point(px(32.0), px(24.0)));
+    assert_eq!(content.size, size(px(56.0), px(20.0)));
+    // Negative margins pull neighbours in rather than adding space.
+    let margin = tree.get("save").unwrap().margin_bounds();
+    assert_eq!(margin.origin, point(px(20.0), px(20.0)));
+    assert_eq!(margin.size, size(px(88.0), px(28.0)));
+
+    tree.set_enabled(false);
+    assert_eq!(tree.nodes().count(), 0);
+}
+
+#[test]
+fn tab_item_tooltip_builder_sets_text() {
+    let tab = TabItem::new("General").tooltip("General settings");
+    assert_eq!(
+        tab.tooltip.as_ref().map(|t| t.as_ref()),
+        Some("General settings")
+    );
+    assert!(TabItem::new("Plain").tooltip.is_none());
+
+    let tab = TabItem::new("General").tooltip_placement(TooltipPlacement::Right);
+    assert_eq!(tab.tooltip_placement, TooltipPlacement::Right);
+    assert_eq!(
+        TabItem::new("Plain").tooltip_placement,
+        TooltipPlacement::Bottom
+    );
+}
+
+#[test]
+fn tooltip_placements_attach_through_popover_positions() {
+    assert_eq!(
+        TooltipPlacement::Top.position(),
+        PopoverPosition::above_left()
+    );
+    assert_eq!(
+        TooltipPlacement::Bottom.position(),
+        PopoverPosition::below_left()
+    );
+    assert_eq!(
+        TooltipPlacement::Left.position(),
+        PopoverPosition::beside_left()
+    );
+    assert_eq!(
+        TooltipPlacement::Right.position(),
+        PopoverPosition::beside_right()
+    );
+}
+
+#[test]
+fn tooltip_hover_shows_only_when_the_delay_elapses_while_hovered() {
+    let mut hover = TooltipHover::default();
+    let first = hover.enter();
+    assert!(!hover.is_visible());
+    assert!(hover.elapse(first));
+    assert!(hover.is_visible());
+    assert!(
+        !hover.elapse(first),
+        "an elapsed timer shows the tooltip once"
+    );
+
+    hover.leave();
+    assert!(!hover.is_visible());
+
+    // Leaving and re-entering cancels the first timer.
+    let stale = hover.enter();
+    hover.leave();
+    let current = hover.enter();
+    assert!(!hover.elapse(stale));
+    assert!(!hover.is_visible());
+    assert!(hover.elapse(current));
+}
+
+#[test]
+fn menu_radio_items_are_exclusive_within_group() {
+    let mut items = vec![
+        MenuItem::labeled_separator("Indent"),
+        MenuItem::radio("Tabs", "indent", true),
+        MenuItem::radio("Spaces", "indent", false),
+        MenuItem::radio("Wrap", "wrap", true),
+        MenuItem::checkbox("Minimap", false),
+    ];
+
+    assert!(toggle_item(&mut items, 2));
+    assert!(!items[1].checked());
+    assert!(items[2].checked());
+    assert!(items[3].checked(), "other groups are untouched");
+
+    assert!(
+        !toggle_item(&mut items, 2),
+        "re-checking a radio is a no-op"
+    );
+    assert!(toggle_item(&mut items, 4));
+    assert!(items[4].checked());
+    assert!(!toggle_item(&mut items, 0), "separators cannot be toggled");
+}
+
+#[test]
+fn chart_scaling_handles_flat_and_out_of_range_values() {
+    assert_eq!(normalize_range(&[2.0, 4.0, 6.0]), [0.0, 0.5, 1.0]);
+    assert_eq!(
+        normalize_range(&[3.0, 3.0]),
+        [0.5, 0.5],
+        "flat series centers"
+    );
+    assert_eq!(normalize_range(&[1.0, f64::NAN, 3.0]), [0.0, 0.0, 1.0]);
+
+    assert_eq!(bar_fractions(&[5.0, 20.0, -1.0], 10.0), [0.5, 1.0, 0.0]);
+    assert_eq!(
+        bar_fractions(&[5.0], 0.0),
+        [0.0],
+        "zero max draws empty bars"
+    );
+}
+
+#[test]
+fn kbd_labels_follow_platform_conventions() {
+    let labels = |keystroke, platform| -> Vec<String> {
+        keystroke_labels(keystroke, platform)
+            .into_iter()
+            .map(|label| label.to_string())
+            .collect()
+    };
+
+    assert_eq!(labels("cmd-shift-p", KeyPlatform::Mac), ["⌘", "⇧", "P"]);
+    assert_eq!(
+        labels("cmd-shift-p", KeyPlatform::Linux),
+        ["Super", "Shift", "P"]
+    );
+    assert_eq!(labels("ctrl--", KeyPlatform::Windows), ["Ctrl", "-"]);
+    assert_eq!(labels("alt-f4", KeyPlatform::Windows), ["Alt", "F4"]);
+    assert_eq!(labels("escape", KeyPlatform::Mac), ["Esc"]);
+    assert_eq!(labels("secondary-,", KeyPlatform::Mac), ["⌘", ","]);
+    assert_eq!(labels("secondary-,", KeyPlatform::Linux), ["Ctrl", ","]);
+}
+
+#[test]
+fn markdown_parses_supported_blocks_and_keeps_unknown_syntax_literal() {
+    let blocks =
+        parse_markdown("## Notes\n\n- one\n- two\n\n```rust\nfn main() {}\n```\n<b>hi</b>");
+    assert_eq!(blocks.len(), 4);
+    assert!(matches!(blocks[0], MarkdownBlock::Heading { level: 2, .. }));
+    assert!(
+        matches!(&blocks[1], MarkdownBlock::List { ordered: false, items } if items.len() == 2)
+    );
+    assert_eq!(
+        blocks[2],
+        MarkdownBlock::CodeBlock {
+            language: Some("rust".into()),
+            code: "fn main() {}".into(),
+        }
+    );
+    assert_eq!(
+        blocks[3],
+        MarkdownBlock::Paragraph(vec![MarkdownInline::Text("<b>hi</b>".into())]),
+        "raw HTML stays literal"
+    );
+
+    assert_eq!(
+        parse_inlines("**a** `b` [c](https://zed.dev)"),
+        [
+            MarkdownInline::Strong("a".into()),
+            MarkdownInline::Text(" ".into()),
+            MarkdownInline::Code("b".into()),
+            MarkdownInline::Text(" ".into()),
+            MarkdownInline::Link {
+                text: "c".into(),
+                url: "https://zed.dev".into(),
+            },
+        ]
+    );
+    assert_eq!(
+        parse_inlines("snake_case and **open"),
+        [MarkdownInline::Text("snake_case and **open".into())]
+    );
+
+    assert!(is_safe_url("mailto:team@example.com"));
+    assert!(!is_safe_url("javascript:alert(1)"));
+
+    let spans = highlight_code("let n = 42; // done");
+    assert_eq!(spans[0], (0..3, CodeHighlight::Keyword));
+    assert!(spans.contains(&(8..10, CodeHighlight::Number)));
+    assert!(spans.contains(&(12..19, CodeHighlight::Comment)));
+}
+
+#[test]
+fn toast_manager_queues_overflow_and_updates_in_place() {
+    let mut manager = ToastManager::new().max_visible(2);
+    for id in ["a", "b", "c"] {
+        manager.push(ToastEntry::new(id, "Saved"));
+    }
+
+    let visible: Vec<_> = manager.visible().map(|t| t.id.to_string()).collect();
+    assert_eq!(visible, ["b", "a"], "newest first, overflow queued");
+    assert_eq!(manager.queued_len(), 1);
+
+    manager.push(ToastEntry::new("a", "Exporting").progress(0.5));
+    assert_eq!(manager.len(), 3, "same id updates instead of stacking");
+    assert_eq!(manager.get("a").unwrap().auto_dismiss(), None);
+
+    assert!(manager.dismiss("b"));
+    manager.finish_transitions();
+    let visible: Vec<_> = manager.visible().map(|t| t.id.to_string()).collect();
+    assert_eq!(visible, ["c", "a"], "queued toast takes the freed slot");
+}
+
+#[test]
+fn toast_auto_dismiss_skips_errors_and_running_tasks() {
+    assert!(ToastEntry::new("i", "Info").auto_dismiss().is_some());
+    assert!(
+        ToastEntry::new("e", "Error")
+            .variant(ToastVariant::Error)
+            .auto_dismiss()
+            .is_none()
+    );
+    assert!(
+        ToastEntry::new("p", "Task")
+            .progress(0.3)
+            .auto_dismiss()
+            .is_none()
+    );
+    assert!(
+        ToastEntry::new("d", "Done")
+            .progress(1.0)
+            .auto_dismiss()
+            .is_some()
+    );
+}
+
+#[test]
+fn toast_countdown_starts_on_show_and_pauses() {
+    let start = Instant::now();
+    let mut manager = ToastManager::new().max_visible(1);
+    manager.push(ToastEntry::new("info", "Saved"));
+    manager.push(ToastEntry::new("error", "Failed").variant(ToastVariant::Error));
+    manager.finish_transitions();
+
+    // The countdown starts on the first expire after the toast appears.
+    assert!(manager.expire(start).is_empty());
+    assert_eq!(manager.next_deadline(start), Some(TOAST_AUTO_DISMISS));
+
+    // Paused time does not count.
+    let hover = start + Duration::from_secs(2);
+    manager.set_paused(true, hover);
+    assert_eq!(manager.next_deadline(hover), None);
+    let leave = hover + Duration::from_secs(10);
+    assert!(manager.expire(leave).is_empty());
+    manager.set_paused(false, leave);
+    assert_eq!(
+        manager.remaining("info", leave),
+        Some(TOAST_AUTO_DISMISS - Duration::from_secs(2))
+    );
+
+    // An in-place update restarts the countdown.
+    let update = leave + Duration::from_secs(1);
+    manager.push(ToastEntry::new("info", "Saved again"));
+    assert!(manager.expire(update).is_empty());
+    assert_eq!(manager.remaining("info", update), Some(TOAST_AUTO_DISMISS));
+
+    let end = update + TOAST_AUTO_DISMISS;
+    let expired: Vec<_> = manager
+        .expire(end)
+        .iter()
+        .map(|id| id.to_string())
+        .collect();
+    assert_eq!(expired, ["info"]);
+    assert_eq!(manager.next_deadline(end), None);
+
+    // The queued error takes the slot and never counts down.
+    manager.finish_transitions();
+    assert_eq!(manager.visible().next().unwrap().id.to_string(), "error");
+    assert!(manager.expire(end + TOAST_AUTO_DISMISS).is_empty());
+    assert_eq!(manager.remaining("error", end), None);
+}
+
+#[test]
+fn toast_progress_completion_starts_countdown() {
+    let now = Instant::now();
+    let mut manager = ToastManager::new();
+    manager.push(ToastEntry::new("export", "Exporting").progress(0.5));
+    manager.expire(now);
+    assert_eq!(manager.next_deadline(now), None);
+    manager.set_progress("export", 1.0);
+    manager.expire(now);
+    assert_eq!(manager.remaining("export", now), Some(TOAST_AUTO_DISMISS));
+}
+
+#[test]
+fn toast_position_defaults_to_bottom_right() {
+    let manager = ToastManager::new();
+    assert_eq!(manager.current_position(), ToastPosition::BottomRight);
+    let manager = manager.position(ToastPosition::TopLeft);
+    assert_eq!(manager.current_position(), ToastPosition::TopLeft);
+    assert!(!ToastPosition::TopLeft.is_bottom() && ToastPosition::TopLeft.is_left());
+    assert!(ToastPosition::BottomRight.is_bottom() && !ToastPosition::BottomRight.is_left());
+    assert_eq!(ToastPosition::ALL.len(), 4);
 }
@@ -0,0 +1,46 @@
+//! Frame-budget gate for DataTable's row virtualization.
+//!
+//! Scrolls a table with as many rows as DataTable's recorded `frame_budget`
+//! evidence from the first row to the last in a headless window, and fails
+//! when any frame, from scroll event to finished draw, is slower than the
+//! recorded slowest frame. Frame times only mean something in release
+//! builds, so debug builds skip the test; CI runs it with
+//! `cargo test -p components --release --test frame_budget`.
+
+mod support;
+
+use std::time::Duration;
+
+use components::data_table::DataTable;
+use gpui::TestAppContext;
+
+/// Frames taken to scroll from the first row to the last.
+const FRAMES: usize = 120;
+
+#[gpui::test]
+#[cfg_attr(
+    debug_assertions,
+    ignore = "frame times are only meaningful in release builds"
+)]
+fn scrolling_the_recorded_rows_stays_within_the_frame_budget(cx: &mut TestAppContext) {
+    let evidence = DataTable::contract()
+        .perf_evidence
+        .and_then(|evidence| evidence.frame_budget)
+        .expect("DataTable records a frame_budget benchmark");
+    assert!(evidence.within_budget());
+
+    let cx = support::open_table(cx, evidence.rows as usize);
+    let step = evidence.rows as f32 * support::ROW_HEIGHT / FRAMES as f32;
+    let worst = (0..FRAMES)
+        .map(|_| support::scroll_frame(cx, step))
+        .max()
+        .unwrap_or(Duration::ZERO);
+
+    let worst_ms = worst.as_secs_f64() * 1000.0;
+    assert!(
+        worst_ms <= evidence.worst_frame_ms,
+        "slowest frame scrolling {} rows took {worst_ms:.2}ms, over the recorded {:.2}ms",
+        evidence.rows,
+        evidence.worst_frame_ms,
+    );
+}
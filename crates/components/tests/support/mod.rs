@@ -0,0 +1,75 @@
+//! A large DataTable in a headless test window, scrolled one frame at a time.
+//!
+//! Shared by `tests/frame_budget.rs` and `benches/data_table_scroll.rs`.
+
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use components::data_table::{Column, DataTable, TableRow};
+use gpui::*;
+
+/// Height of every row, in pixels.
+pub const ROW_HEIGHT: f32 = 28.0;
+
+/// Height of the scrolling body, in pixels.
+const BODY_HEIGHT: f32 = 600.0;
+
+struct ScrollingTable {
+    rows: Rc<Vec<TableRow>>,
+}
+
+impl Render for ScrollingTable {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        DataTable::new(
+            "scrolling-table",
+            vec![
+                Column::new("Name").sortable(),
+                Column::new("Kind"),
+                Column::new("Size").sortable(),
+            ],
+            self.rows.clone(),
+        )
+        .row_height(px(ROW_HEIGHT))
+        .height(px(BODY_HEIGHT))
+    }
+}
+
+fn table_rows(count: usize) -> Vec<TableRow> {
+    (0..count)
+        .map(|i| {
+            TableRow::new([
+                format!("row-{i}"),
+                ["file", "directory", "symlink"][i % 3].to_string(),
+                (i * 7919 % 100_000).to_string(),
+            ])
+        })
+        .collect()
+}
+
+/// Open a window showing a table of `rows` rows and draw its first frame.
+pub fn open_table(cx: &mut TestAppContext, rows: usize) -> &mut VisualTestContext {
+    cx.update(|cx| {
+        theme::init(cx);
+        components::init(cx);
+    });
+    // Held by the view, like an app's model, so the table's row order cache
+    // survives across frames.
+    let rows = Rc::new(table_rows(rows));
+    let (_view, cx) = cx.add_window_view(|_, _| ScrollingTable { rows });
+    cx.update(|window, _| window.refresh());
+    cx
+}
+
+/// Scroll the body down by `delta` pixels (up when negative) and redraw,
+/// returning how long the frame took from scroll event to finished draw.
+pub fn scroll_frame(cx: &mut VisualTestContext, delta: f32) -> Duration {
+    let start = Instant::now();
+    cx.simulate_event(ScrollWheelEvent {
+        position: point(px(200.0), px(BODY_HEIGHT / 2.0)),
+        // Wheel deltas are negative when the content moves up.
+        delta: ScrollDelta::Pixels(point(px(0.0), px(-delta))),
+        ..Default::default()
+    });
+    cx.update(|window, _| window.refresh());
+    start.elapsed()
+}
@@ -0,0 +1,80 @@
+//! Hover tooltips attached through `WithTooltip`, in a headless window.
+//!
+//! Hovers a Button that has a tooltip and checks that the tooltip opens on
+//! the tooltip layer only once `TOOLTIP_HOVER_DELAY` has passed, and closes
+//! again when the pointer leaves.
+
+use std::time::Duration;
+
+use components::button::Button;
+use components::{TOOLTIP_HOVER_DELAY, TooltipPlacement, WithTooltip};
+use gpui::*;
+use primitives::LayerManager;
+
+struct TooltipButton;
+
+impl Render for TooltipButton {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div().size_full().p(px(40.0)).child(
+            Button::new("save")
+                .label("Save")
+                .tooltip("Save your work")
+                .tooltip_placement(TooltipPlacement::Top),
+        )
+    }
+}
+
+fn open_button(cx: &mut TestAppContext) -> &mut VisualTestContext {
+    cx.update(|cx| {
+        theme::init(cx);
+        components::init(cx);
+    });
+    let (_view, cx) = cx.add_window_view(|_, _| TooltipButton);
+    cx.update(|window, _| window.refresh());
+    cx
+}
+
+fn tooltip_open(cx: &mut VisualTestContext) -> bool {
+    cx.update(|_, cx| {
+        cx.try_global::<LayerManager>()
+            .is_some_and(|layers| layers.contains("save-tooltip"))
+    })
+}
+
+#[gpui::test]
+fn hovering_a_button_opens_its_tooltip_after_the_delay(cx: &mut TestAppContext) {
+    let cx = open_button(cx);
+    assert!(!tooltip_open(cx));
+
+    cx.simulate_mouse_move(point(px(48.0), px(48.0)), None, Modifiers::none());
+    cx.executor()
+        .advance_clock(TOOLTIP_HOVER_DELAY - Duration::from_millis(1));
+    cx.run_until_parked();
+    assert!(!tooltip_open(cx), "tooltip opened before the hover delay");
+
+    cx.executor().advance_clock(Duration::from_millis(1));
+    cx.run_until_parked();
+    assert!(
+        tooltip_open(cx),
+        "tooltip did not open after the hover delay"
+    );
+
+    cx.simulate_mouse_move(point(px(4.0), px(4.0)), None, Modifiers::none());
+    cx.run_until_parked();
+    assert!(
+        !tooltip_open(cx),
+        "tooltip stayed open after the pointer left"
+    );
+}
+
+#[gpui::test]
+fn leaving_before_the_delay_cancels_the_tooltip(cx: &mut TestAppContext) {
+    let cx = open_button(cx);
+
+    cx.simulate_mouse_move(point(px(48.0), px(48.0)), None, Modifiers::none());
+    cx.executor().advance_clock(TOOLTIP_HOVER_DELAY / 2);
+    cx.simulate_mouse_move(point(px(4.0), px(4.0)), None, Modifiers::none());
+    cx.executor().advance_clock(TOOLTIP_HOVER_DELAY);
+    cx.run_until_parked();
+    assert!(!tooltip_open(cx));
+}
@@ -0,0 +1,181 @@
+//! Primitive contracts: lightweight, serializable metadata for primitives.
+//!
+//! Components publish a full `ComponentContract`; primitives only need to say
+//! what they are for, which functions make up their API, which invariants
+//! those functions uphold, and which components consume them. The registry
+//! indexes these contracts next to the component index (`gpui list
+//! --primitives`) and the studio renders each one as a doc story.
+
+use serde::{Deserialize, Serialize};
+
+/// Metadata describing one primitive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrimitiveContract {
+    /// Primitive name (e.g. `"FocusTrap"`).
+    pub name: String,
+    /// Module that defines it (e.g. `"focus"`).
+    pub module: String,
+    /// One-line description of what the primitive is for.
+    pub summary: String,
+    /// Public functions, as short signatures (e.g. `"focus(&self, window, cx)"`).
+    pub functions: Vec<String>,
+    /// Guarantees the functions uphold.
+    pub invariants: Vec<String>,
+    /// Components that build on this primitive, by registry name.
+    pub consumers: Vec<String>,
+}
+
+impl PrimitiveContract {
+    /// Start a contract with no functions, invariants, or consumers.
+    pub fn new(
+        name: impl Into<String>,
+        module: impl Into<String>,
+        summary: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            module: module.into(),
+            summary: summary.into(),
+            functions: Vec::new(),
+            invariants: Vec::new(),
+            consumers: Vec::new(),
+        }
+    }
+
+    /// Add a public function signature.
+    pub fn function(mut self, signature: impl Into<String>) -> Self {
+        self.functions.push(signature.into());
+        self
+    }
+
+    /// Add an invariant.
+    pub fn invariant(mut self, invariant: impl Into<String>) -> Self {
+        self.invariants.push(invariant.into());
+        self
+    }
+
+    /// Add a consuming component.
+    pub fn consumer(mut self, component: impl Into<String>) -> Self {
+        self.consumers.push(component.into());
+        self
+    }
+
+    /// Render the contract as a Markdown document, for doc stories.
+    pub fn to_markdown(&self) -> String {
+        let mut doc = format!(
+            "{}\n\nDefined in `primitives::{}`.\n",
+            self.summary, self.module
+        );
+        let sections = [
+            ("Functions", &self.functions, true),
+            ("Invariants", &self.invariants, false),
+            ("Consumers", &self.consumers, false),
+        ];
+        for (title, items, code) in sections {
+            if items.is_empty() {
+                continue;
+            }
+            doc.push_str(&format!("\n## {title}\n\n"));
+            for item in items {
+                if code {
+                    doc.push_str(&format!("- `{item}`\n"));
+                } else {
+                    doc.push_str(&format!("- {item}\n"));
+                }
+            }
+        }
+        doc
+    }
+}
+
+/// Contracts for every primitive, sorted by name.
+pub fn all_contracts() -> Vec<PrimitiveContract> {
+    let mut contracts: Vec<PrimitiveContract> = [
+        crate::dnd::contracts(),
+        crate::focus::contracts(),
+        crate::format::contracts(),
+        crate::fuzzy::contracts(),
+        crate::keyboard::contracts(),
+        crate::layer::contracts(),
+        crate::popover::contracts(),
+        crate::resize::contracts(),
+        crate::scroll::contracts(),
+        crate::shortcuts::contracts(),
+        crate::state::contracts(),
+        crate::text_edit::contracts(),
+        crate::timing::contracts(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    contracts.sort_by(|a, b| a.name.cmp(&b.name));
+    contracts
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_primitive_has_a_complete_contract() {
+        let contracts = all_contracts();
+        let names: Vec<&str> = contracts.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "ControlledState",
+                "Debouncer",
+                "DragAndDrop",
+                "FocusReturn",
+                "FocusTrap",
+                "FuzzyMatch",
+                "KeyboardNavigation",
+                "LayerManager",
+                "Locale",
+                "MotionPreference",
+                "OverlayStateMachine",
+                "PopoverPosition",
+                "ScrollMetrics",
+                "ShortcutRegistry",
+                "SplitResize",
+                "TextInput",
+                "Throttler",
+            ]
+        );
+
+        for contract in &contracts {
+            assert!(
+                !contract.summary.is_empty(),
+                "{} has a summary",
+                contract.name
+            );
+            assert!(
+                !contract.functions.is_empty(),
+                "{} lists functions",
+                contract.name
+            );
+            assert!(
+                !contract.invariants.is_empty(),
+                "{} lists invariants",
+                contract.name
+            );
+        }
+    }
+
+    #[test]
+    fn markdown_lists_functions_as_code() {
+        let doc = PrimitiveContract::new("Latch", "state", "Remembers one flag.")
+            .function("set(&mut self)")
+            .invariant("Once set, stays set.")
+            .to_markdown();
+
+        assert!(doc.starts_with("Remembers one flag.\n\nDefined in `primitives::state`."));
+        assert!(doc.contains("## Functions\n\n- `set(&mut self)`\n"));
+        assert!(doc.contains("## Invariants\n\n- Once set, stays set.\n"));
+        assert!(!doc.contains("## Consumers"));
+    }
+}
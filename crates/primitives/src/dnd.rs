@@ -0,0 +1,322 @@
+//! Drag-and-drop primitive: reordering items of a list by pointer or keyboard.
+//!
+//! [`DragState`] is the state machine behind a reorderable list. A pointer
+//! press arms a drag, which starts once the pointer travels
+//! [`DRAG_THRESHOLD`]; moving over the items picks a [`DropTarget`], and
+//! releasing produces a [`Reorder`]. From the keyboard, Space grabs the
+//! focused item, arrows move it, and Space or Enter drops it; Escape cancels
+//! either kind of drag. Positions are pixels along the list's axis.
+//!
+//! Consumed by SortableList.
+
+use crate::contract::PrimitiveContract;
+use crate::keyboard::keys;
+
+/// Pixels the pointer must travel after a press before a drag starts, so
+/// clicks with a little jitter stay clicks.
+pub const DRAG_THRESHOLD: f32 = 4.0;
+
+/// Which side of an item a drop lands on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropPosition {
+    Before,
+    After,
+}
+
+/// Where a dragged item would land: beside the item at `index`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DropTarget {
+    pub index: usize,
+    pub position: DropPosition,
+}
+
+impl DropTarget {
+    /// The gap the item would be inserted into: `0` is before the first
+    /// item, `len` after the last.
+    pub fn gap(&self) -> usize {
+        match self.position {
+            DropPosition::Before => self.index,
+            DropPosition::After => self.index + 1,
+        }
+    }
+}
+
+/// The drop target under `pointer`, given each item's `(start, length)`
+/// along the axis in list order. The nearer half of an item decides the
+/// side; pointers past either end target the first or last item.
+pub fn drop_target_at(pointer: f32, items: &[(f32, f32)]) -> Option<DropTarget> {
+    let index = items
+        .iter()
+        .position(|&(start, length)| pointer < start + length)
+        .unwrap_or(items.len().checked_sub(1)?);
+    let (start, length) = items[index];
+    let position = if pointer < start + length / 2.0 {
+        DropPosition::Before
+    } else {
+        DropPosition::After
+    };
+    Some(DropTarget { index, position })
+}
+
+/// A completed move: the item at `from` now sits at `to`, counted after
+/// removing it from its old place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    pub from: usize,
+    pub to: usize,
+}
+
+impl Reorder {
+    /// The move that inserts the item at `from` into `gap`, or `None` when
+    /// that leaves it where it is.
+    pub fn into_gap(from: usize, gap: usize) -> Option<Self> {
+        let to = if gap > from { gap - 1 } else { gap };
+        (to != from).then_some(Self { from, to })
+    }
+
+    /// The gap the item is inserted into, as drawn by a drop indicator.
+    pub fn gap(&self) -> usize {
+        if self.to > self.from {
+            self.to + 1
+        } else {
+            self.to
+        }
+    }
+
+    /// Apply the move to `items`; out-of-range moves are ignored.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+}
+
+/// Result of a key pressed on a reorderable list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyOutcome {
+    /// The key does not reorder; the list may use it (e.g. to move focus).
+    Ignored,
+    /// The key grabbed, moved, or released an item without reordering.
+    Handled,
+    /// The key dropped an item somewhere new.
+    Reordered(Reorder),
+}
+
+/// Drag state of a reorderable list.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum DragState {
+    #[default]
+    Idle,
+    /// The pointer went down on `source` at `origin` and has not yet
+    /// travelled far enough to drag.
+    Pressed { source: usize, origin: f32 },
+    /// The pointer is dragging `source`, over `target` if any.
+    Dragging {
+        source: usize,
+        target: Option<DropTarget>,
+    },
+    /// The keyboard grabbed `source` and has moved it to `to`.
+    Grabbed { source: usize, to: usize },
+}
+
+impl DragState {
+    /// The item being dragged or grabbed, once a drag has started.
+    pub fn source(&self) -> Option<usize> {
+        match *self {
+            Self::Dragging { source, .. } | Self::Grabbed { source, .. } => Some(source),
+            Self::Idle | Self::Pressed { .. } => None,
+        }
+    }
+
+    /// Whether the pointer is down on an item, dragging or not.
+    pub fn is_pointer_down(&self) -> bool {
+        matches!(self, Self::Pressed { .. } | Self::Dragging { .. })
+    }
+
+    /// The move a drop would make now, if it changes the order.
+    pub fn pending(&self) -> Option<Reorder> {
+        match *self {
+            Self::Dragging {
+                source,
+                target: Some(target),
+            } => Reorder::into_gap(source, target.gap()),
+            Self::Grabbed { source, to } => (to != source).then_some(Reorder { from: source, to }),
+            _ => None,
+        }
+    }
+
+    /// The gap to draw a drop indicator in; `None` while a drop would not
+    /// move anything.
+    pub fn indicator(&self) -> Option<usize> {
+        self.pending().map(|reorder| reorder.gap())
+    }
+
+    /// Arm a drag of `source` from a pointer press at `origin`.
+    pub fn press(&mut self, source: usize, origin: f32) {
+        *self = Self::Pressed { source, origin };
+    }
+
+    /// Follow the pointer to `position`, over `target`. Starts the drag once
+    /// the pointer has travelled [`DRAG_THRESHOLD`] from the press.
+    pub fn pointer_moved(&mut self, position: f32, target: Option<DropTarget>) {
+        match *self {
+            Self::Pressed { source, origin } if (position - origin).abs() >= DRAG_THRESHOLD => {
+                *self = Self::Dragging { source, target };
+            }
+            Self::Dragging { source, .. } => *self = Self::Dragging { source, target },
+            _ => {}
+        }
+    }
+
+    /// Release the pointer, returning the move if the drop changes the order.
+    /// A press that never became a drag is a click and moves nothing.
+    pub fn release(&mut self) -> Option<Reorder> {
+        if !self.is_pointer_down() {
+            return None;
+        }
+        let reorder = self.pending();
+        *self = Self::Idle;
+        reorder
+    }
+
+    /// Abandon any drag without moving anything.
+    pub fn cancel(&mut self) {
+        *self = Self::Idle;
+    }
+
+    /// Apply a key pressed while `focused` of `len` items has focus.
+    ///
+    /// Space grabs the focused item. While grabbed, Up/Down (or Left/Right)
+    /// move it one place, Home/End to either end, Space/Enter drop it, and
+    /// Escape puts it back. Escape also cancels a pointer drag.
+    pub fn handle_key(&mut self, key: &str, focused: usize, len: usize) -> KeyOutcome {
+        match *self {
+            Self::Grabbed { source, to } => {
+                let last = len.saturating_sub(1);
+                let moved = match key {
+                    keys::ARROW_UP | keys::ARROW_LEFT => to.saturating_sub(1),
+                    keys::ARROW_DOWN | keys::ARROW_RIGHT => (to + 1).min(last),
+                    keys::HOME => 0,
+                    keys::END => last,
+                    keys::SPACE | keys::ENTER => {
+                        let reorder = self.pending();
+                        *self = Self::Idle;
+                        return reorder.map_or(KeyOutcome::Handled, KeyOutcome::Reordered);
+                    }
+                    keys::ESCAPE => {
+                        *self = Self::Idle;
+                        return KeyOutcome::Handled;
+                    }
+                    _ => return KeyOutcome::Ignored,
+                };
+                *self = Self::Grabbed { source, to: moved };
+                KeyOutcome::Handled
+            }
+            Self::Idle if key == keys::SPACE && focused < len => {
+                *self = Self::Grabbed {
+                    source: focused,
+                    to: focused,
+                };
+                KeyOutcome::Handled
+            }
+            Self::Pressed { .. } | Self::Dragging { .. } if key == keys::ESCAPE => {
+                *self = Self::Idle;
+                KeyOutcome::Handled
+            }
+            _ => KeyOutcome::Ignored,
+        }
+    }
+}
+
+/// Contract for the drag-and-drop primitive.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "DragAndDrop",
+            "dnd",
+            "Drag source and drop target state machine for reordering a list by pointer or keyboard, with drop indicator gaps.",
+        )
+        .function("drop_target_at(pointer, items) -> Option<DropTarget>")
+        .function("DragState::press(&mut self, source, origin)")
+        .function("DragState::pointer_moved(&mut self, position, target)")
+        .function("DragState::release(&mut self) -> Option<Reorder>")
+        .function("DragState::handle_key(&mut self, key, focused, len) -> KeyOutcome")
+        .function("DragState::indicator(&self) -> Option<usize>")
+        .function("Reorder::apply(&self, items: &mut Vec<T>)")
+        .invariant("A press becomes a drag only after the pointer travels DRAG_THRESHOLD; a shorter press is a click.")
+        .invariant("A drop that leaves the item in place produces no Reorder and no indicator.")
+        .invariant("Escape cancels pointer and keyboard drags without reordering.")
+        .invariant("Reorder::to counts positions after the item is removed, so apply() is remove then insert.")
+        .consumer("SortableList"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ROWS: [(f32, f32); 3] = [(0.0, 20.0), (20.0, 20.0), (40.0, 20.0)];
+
+    #[test]
+    fn pointer_drags_past_the_threshold_and_drops_into_a_gap() {
+        assert_eq!(
+            drop_target_at(25.0, &ROWS),
+            Some(DropTarget {
+                index: 1,
+                position: DropPosition::Before
+            })
+        );
+        assert_eq!(drop_target_at(500.0, &ROWS).map(|t| t.gap()), Some(3));
+        assert_eq!(drop_target_at(-5.0, &ROWS).map(|t| t.gap()), Some(0));
+        assert_eq!(drop_target_at(5.0, &[]), None);
+
+        let mut state = DragState::default();
+        state.press(0, 10.0);
+        state.pointer_moved(12.0, drop_target_at(12.0, &ROWS));
+        assert_eq!(state.source(), None, "within the threshold");
+        state.pointer_moved(55.0, drop_target_at(55.0, &ROWS));
+        assert_eq!(state.source(), Some(0));
+        assert_eq!(state.indicator(), Some(3));
+        assert_eq!(state.release(), Some(Reorder { from: 0, to: 2 }));
+        assert_eq!(state, DragState::Idle);
+
+        // Dropping beside itself moves nothing; a click moves nothing.
+        state.press(1, 30.0);
+        state.pointer_moved(38.0, drop_target_at(38.0, &ROWS));
+        assert_eq!(state.indicator(), None);
+        assert_eq!(state.release(), None);
+        state.press(1, 30.0);
+        assert_eq!(state.release(), None);
+    }
+
+    #[test]
+    fn keyboard_grabs_moves_and_drops() {
+        let mut state = DragState::default();
+        assert_eq!(
+            state.handle_key("down", 0, 3),
+            KeyOutcome::Ignored,
+            "focus moves"
+        );
+        assert_eq!(state.handle_key("space", 2, 3), KeyOutcome::Handled);
+        assert_eq!(state.handle_key("up", 2, 3), KeyOutcome::Handled);
+        assert_eq!(state.indicator(), Some(1));
+        assert_eq!(state.handle_key("home", 2, 3), KeyOutcome::Handled);
+        assert_eq!(
+            state.handle_key("enter", 2, 3),
+            KeyOutcome::Reordered(Reorder { from: 2, to: 0 })
+        );
+
+        state.handle_key("space", 0, 3);
+        state.handle_key("end", 0, 3);
+        assert_eq!(state.handle_key("escape", 0, 3), KeyOutcome::Handled);
+        assert_eq!(state, DragState::Idle);
+
+        let mut items = vec!['a', 'b', 'c'];
+        Reorder { from: 2, to: 0 }.apply(&mut items);
+        assert_eq!(items, ['c', 'a', 'b']);
+        Reorder { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, ['a', 'b', 'c']);
+        assert_eq!(Reorder::into_gap(0, 3).map(|r| r.gap()), Some(3));
+    }
+}
@@ -5,6 +5,8 @@
 
 use gpui::{FocusHandle, Window};
 
+use crate::contract::PrimitiveContract;
+
 /// Tracks a previous focus handle so focus can be returned on dismiss.
 ///
 /// Used by Dialog and Select: when the overlay opens, capture where focus was;
@@ -82,6 +84,40 @@ impl FocusTrap {
     }
 }
 
+/// Contracts for the focus primitives.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "FocusReturn",
+            "focus",
+            "Remembers focus before an overlay opens and restores it on dismiss.",
+        )
+        .function("capture(window, cx) -> FocusReturn")
+        .function("restore(&self, window, cx) -> bool")
+        .function("previous_handle(&self) -> Option<&FocusHandle>")
+        .invariant("`restore` moves no focus when nothing was focused at capture.")
+        .invariant("The captured handle never changes after `capture`.")
+        .consumer("CommandPalette")
+        .consumer("ContextMenu")
+        .consumer("Dialog")
+        .consumer("MenuBar")
+        .consumer("Select"),
+        PrimitiveContract::new(
+            "FocusTrap",
+            "focus",
+            "Keeps Tab and Shift-Tab cycling inside a modal container.",
+        )
+        .function("new(handle: FocusHandle) -> FocusTrap")
+        .function("contains_focused(&self, window, cx) -> bool")
+        .function("is_focused(&self, window) -> bool")
+        .function("focus(&self, window, cx)")
+        .function("handle(&self) -> &FocusHandle")
+        .invariant("The boundary is the element tracking `handle`; the trap never handles keys.")
+        .invariant("After `focus`, `contains_focused` holds.")
+        .consumer("Dialog"),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,299 @@
+//! Format primitive: locale-aware numbers, file sizes, and relative times.
+//!
+//! Used by Toast timestamps and the studio perf HUD, and by count labels such
+//! as a pagination summary ("1,024 items"). Every formatter takes a
+//! [`Locale`]; views read the app-wide one with [`Locale::global`], which is
+//! [`Locale::EN_US`] until the app overrides it with [`Locale::set_global`].
+
+use std::time::Duration;
+
+use gpui::{App, Global};
+
+use crate::contract::PrimitiveContract;
+
+/// Words for relative times ("5 minutes ago").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelativeWords {
+    /// Shown for anything under a minute.
+    pub just_now: &'static str,
+    /// Pattern for a past time; `{}` becomes the count and unit.
+    pub past: &'static str,
+    /// Singular and plural minute.
+    pub minute: (&'static str, &'static str),
+    /// Singular and plural hour.
+    pub hour: (&'static str, &'static str),
+    /// Singular and plural day.
+    pub day: (&'static str, &'static str),
+}
+
+/// Separators and words used to format values for display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// BCP 47 language tag, e.g. `"en-US"`.
+    pub tag: &'static str,
+    /// Separator between groups of three integer digits.
+    pub group_separator: char,
+    /// Separator between the integer and fractional digits.
+    pub decimal_separator: char,
+    /// Words for relative times.
+    pub relative: RelativeWords,
+}
+
+impl Locale {
+    /// US English: `1,024.5`, "5 minutes ago". The default.
+    pub const EN_US: Locale = Locale {
+        tag: "en-US",
+        group_separator: ',',
+        decimal_separator: '.',
+        relative: RelativeWords {
+            just_now: "just now",
+            past: "{} ago",
+            minute: ("minute", "minutes"),
+            hour: ("hour", "hours"),
+            day: ("day", "days"),
+        },
+    };
+
+    /// German: `1.024,5`, "vor 5 Minuten".
+    pub const DE_DE: Locale = Locale {
+        tag: "de-DE",
+        group_separator: '.',
+        decimal_separator: ',',
+        relative: RelativeWords {
+            just_now: "gerade eben",
+            past: "vor {}",
+            minute: ("Minute", "Minuten"),
+            hour: ("Stunde", "Stunden"),
+            day: ("Tag", "Tagen"),
+        },
+    };
+
+    /// French: `1 024,5` (narrow no-break space), "il y a 5 minutes".
+    pub const FR_FR: Locale = Locale {
+        tag: "fr-FR",
+        group_separator: '\u{202F}',
+        decimal_separator: ',',
+        relative: RelativeWords {
+            just_now: "à l’instant",
+            past: "il y a {}",
+            minute: ("minute", "minutes"),
+            hour: ("heure", "heures"),
+            day: ("jour", "jours"),
+        },
+    };
+
+    /// All built-in locales.
+    pub const ALL: [Locale; 3] = [Locale::EN_US, Locale::DE_DE, Locale::FR_FR];
+
+    /// Look up a built-in locale by tag (case-insensitive).
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.tag.eq_ignore_ascii_case(tag))
+    }
+
+    /// The app-wide locale, or [`Locale::EN_US`] if none was set.
+    pub fn global(cx: &App) -> &Locale {
+        cx.try_global::<Locale>().unwrap_or(&Locale::EN_US)
+    }
+
+    /// Make this the app-wide locale. Views pick it up on their next render.
+    pub fn set_global(self, cx: &mut App) {
+        cx.set_global(self);
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN_US
+    }
+}
+
+impl Global for Locale {}
+
+/// Format an integer with grouped digits: `1024` -> `"1,024"`.
+pub fn format_integer(value: i64, locale: &Locale) -> String {
+    let digits = group_digits(&value.unsigned_abs().to_string(), locale.group_separator);
+    if value < 0 {
+        format!("-{digits}")
+    } else {
+        digits
+    }
+}
+
+/// Format a number with `decimals` fractional digits and grouped integer
+/// digits: `1234.5` with 2 decimals -> `"1,234.50"`. Non-finite values are
+/// formatted as Rust prints them.
+pub fn format_decimal(value: f64, decimals: usize, locale: &Locale) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let fixed = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+    let mut formatted = group_digits(integer, locale.group_separator);
+    if !fraction.is_empty() {
+        formatted.push(locale.decimal_separator);
+        formatted.push_str(fraction);
+    }
+    // Values that round to zero lose their sign.
+    if value < 0.0 && fixed.bytes().any(|b| matches!(b, b'1'..=b'9')) {
+        formatted.insert(0, '-');
+    }
+    formatted
+}
+
+/// Format a byte count in 1024-byte units with at most one decimal:
+/// `512` -> `"512 B"`, `1536` -> `"1.5 KB"`, `1048576` -> `"1 MB"`.
+pub fn format_file_size(bytes: u64, locale: &Locale) -> String {
+    const UNITS: [&str; 5] = ["KB", "MB", "GB", "TB", "PB"];
+    if bytes < 1024 {
+        return format!("{} B", format_integer(bytes as i64, locale));
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    // Move up a unit before rounding could print "1024.0 KB".
+    while size >= 1023.95 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    let rounded = format_decimal(size, 1, locale);
+    let trimmed = rounded
+        .strip_suffix(&format!("{}0", locale.decimal_separator))
+        .unwrap_or(&rounded);
+    format!("{} {}", trimmed, UNITS[unit])
+}
+
+/// Format how long ago something happened, in the largest whole unit:
+/// under a minute is "just now", then minutes, hours, and days.
+pub fn format_relative_time(elapsed: Duration, locale: &Locale) -> String {
+    let words = &locale.relative;
+    let minutes = elapsed.as_secs() / 60;
+    let (hours, days) = (minutes / 60, minutes / (60 * 24));
+    let (count, (singular, plural)) = if days > 0 {
+        (days, words.day)
+    } else if hours > 0 {
+        (hours, words.hour)
+    } else if minutes > 0 {
+        (minutes, words.minute)
+    } else {
+        return words.just_now.to_string();
+    };
+    let unit = if count == 1 { singular } else { plural };
+    let span = format!("{} {}", format_integer(count as i64, locale), unit);
+    words.past.replace("{}", &span)
+}
+
+/// Insert `separator` between groups of three digits, from the right.
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
+
+/// Contracts for the format primitive.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "Locale",
+            "format",
+            "Formats numbers, file sizes, and relative times for the app's locale.",
+        )
+        .function("format_integer(value: i64, locale) -> String")
+        .function("format_decimal(value: f64, decimals: usize, locale) -> String")
+        .function("format_file_size(bytes: u64, locale) -> String")
+        .function("format_relative_time(elapsed: Duration, locale) -> String")
+        .function("Locale::from_tag(tag: &str) -> Option<Locale>")
+        .function("Locale::global(cx) -> &Locale")
+        .function("Locale::set_global(self, cx)")
+        .invariant("Integer digits are grouped in threes with the locale's group separator.")
+        .invariant("File sizes use 1024-byte units and show at most one decimal.")
+        .invariant(
+            "Relative times round down to the largest whole unit; under a minute is \"just now\".",
+        )
+        .invariant("Without an override, the app-wide locale is en-US.")
+        .consumer("Toast"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn integers_group_thousands() {
+        let en = Locale::EN_US;
+        assert_eq!(format_integer(0, &en), "0");
+        assert_eq!(format_integer(999, &en), "999");
+        assert_eq!(format_integer(1024, &en), "1,024");
+        assert_eq!(format_integer(-1_234_567, &en), "-1,234,567");
+        assert_eq!(format_integer(i64::MIN, &en), "-9,223,372,036,854,775,808");
+        assert_eq!(format_integer(1024, &Locale::DE_DE), "1.024");
+        assert_eq!(format_integer(1024, &Locale::FR_FR), "1\u{202F}024");
+    }
+
+    #[test]
+    fn decimals_use_locale_separators() {
+        assert_eq!(format_decimal(1234.5, 2, &Locale::EN_US), "1,234.50");
+        assert_eq!(format_decimal(1234.5, 2, &Locale::DE_DE), "1.234,50");
+        assert_eq!(format_decimal(16.666, 1, &Locale::EN_US), "16.7");
+        assert_eq!(format_decimal(-12.34, 1, &Locale::EN_US), "-12.3");
+        assert_eq!(format_decimal(-0.004, 2, &Locale::EN_US), "0.00");
+        assert_eq!(format_decimal(999.96, 1, &Locale::EN_US), "1,000.0");
+        assert_eq!(format_decimal(f64::NAN, 2, &Locale::EN_US), "NaN");
+    }
+
+    #[test]
+    fn file_sizes_pick_the_largest_unit() {
+        let en = Locale::EN_US;
+        assert_eq!(format_file_size(0, &en), "0 B");
+        assert_eq!(format_file_size(1023, &en), "1,023 B");
+        assert_eq!(format_file_size(1024, &en), "1 KB");
+        assert_eq!(format_file_size(1536, &en), "1.5 KB");
+        assert_eq!(format_file_size(1024 * 1024 - 1, &en), "1 MB");
+        assert_eq!(format_file_size(5 * 1024 * 1024 * 1024, &en), "5 GB");
+        assert_eq!(format_file_size(1536, &Locale::DE_DE), "1,5 KB");
+        assert_eq!(format_file_size(u64::MAX, &en), "16,384 PB");
+    }
+
+    #[test]
+    fn relative_times_round_down() {
+        let en = Locale::EN_US;
+        assert_eq!(format_relative_time(Duration::ZERO, &en), "just now");
+        assert_eq!(
+            format_relative_time(MINUTE - Duration::from_millis(1), &en),
+            "just now"
+        );
+        assert_eq!(format_relative_time(MINUTE, &en), "1 minute ago");
+        assert_eq!(format_relative_time(MINUTE * 59, &en), "59 minutes ago");
+        assert_eq!(format_relative_time(MINUTE * 60, &en), "1 hour ago");
+        assert_eq!(format_relative_time(MINUTE * 60 * 23, &en), "23 hours ago");
+        assert_eq!(format_relative_time(MINUTE * 60 * 47, &en), "1 day ago");
+        assert_eq!(format_relative_time(MINUTE * 60 * 48, &en), "2 days ago");
+        assert_eq!(
+            format_relative_time(MINUTE * 60 * 24 * 1500, &en),
+            "1,500 days ago"
+        );
+        assert_eq!(
+            format_relative_time(MINUTE * 5, &Locale::DE_DE),
+            "vor 5 Minuten"
+        );
+        assert_eq!(
+            format_relative_time(MINUTE * 5, &Locale::FR_FR),
+            "il y a 5 minutes"
+        );
+    }
+
+    #[test]
+    fn locales_are_found_by_tag() {
+        assert_eq!(Locale::from_tag("de-de"), Some(Locale::DE_DE));
+        assert_eq!(Locale::from_tag("xx-XX"), None);
+        assert_eq!(Locale::default(), Locale::EN_US);
+    }
+}
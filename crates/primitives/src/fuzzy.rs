@@ -0,0 +1,260 @@
+//! Fuzzy match primitive: subsequence matching with ranked results.
+//!
+//! A query matches a candidate when its characters appear in the candidate in
+//! order, ignoring case and whitespace in the query ("tgth" matches "Toggle
+//! Theme"). Scores reward matches at word starts and runs of consecutive
+//! characters, and penalize gaps, so the tightest, most word-aligned match
+//! ranks first. Used by the CommandPalette to filter and highlight commands.
+
+use std::cmp::Reverse;
+use std::ops::Range;
+
+use crate::contract::PrimitiveContract;
+
+/// Score for each matched character.
+const SCORE_MATCH: i32 = 16;
+
+/// Bonus for a match at the start of a word: the first character, after a
+/// separator, or an uppercase letter after a lowercase one.
+const BONUS_BOUNDARY: i32 = 8;
+
+/// Bonus for a match directly after the previous matched character.
+const BONUS_CONSECUTIVE: i32 = 4;
+
+/// Penalty for each candidate character skipped between two matches.
+const PENALTY_GAP: i32 = 1;
+
+/// Most characters before the first match that are penalized, so earlier
+/// matches win ties without long prefixes sinking a good match.
+const MAX_LEADING_GAP: i32 = 3;
+
+/// Where and how well a query matched a candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better. Only comparable between matches of the same query.
+    pub score: i32,
+    /// Byte offsets of the matched characters in the candidate, ascending.
+    pub positions: Vec<usize>,
+}
+
+impl FuzzyMatch {
+    /// Byte ranges of the matched characters in `candidate`, with adjacent
+    /// characters merged into one range. Suitable for text highlights.
+    pub fn ranges(&self, candidate: &str) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for &start in &self.positions {
+            let Some(c) = candidate.get(start..).and_then(|rest| rest.chars().next()) else {
+                continue;
+            };
+            let end = start + c.len_utf8();
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(start..end),
+            }
+        }
+        ranges
+    }
+}
+
+/// Case-fold a character for comparison.
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Bonus for matching `current`, given the character before it.
+fn boundary_bonus(previous: Option<char>, current: char) -> i32 {
+    match previous {
+        None => BONUS_BOUNDARY,
+        Some(prev) if !prev.is_alphanumeric() && current.is_alphanumeric() => BONUS_BOUNDARY,
+        Some(prev) if prev.is_lowercase() && current.is_uppercase() => BONUS_BOUNDARY,
+        _ => 0,
+    }
+}
+
+/// Match `query` against `candidate`, returning the best-scoring alignment.
+///
+/// Returns `None` when the query's characters do not all appear in order. An
+/// empty (or all-whitespace) query matches everything with score 0.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(fold)
+        .collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    let chars: Vec<(usize, char)> = candidate.char_indices().collect();
+    let (m, n) = (query.len(), chars.len());
+    if m > n {
+        return None;
+    }
+    let bonus: Vec<i32> = (0..n)
+        .map(|j| boundary_bonus(j.checked_sub(1).map(|k| chars[k].1), chars[j].1))
+        .collect();
+
+    // best[i][j]: best score with query[i] matched at candidate char j.
+    // from[i][j]: where query[i - 1] was matched on that best path.
+    let mut best = vec![vec![None::<i32>; n]; m];
+    let mut from = vec![vec![0usize; n]; m];
+    for i in 0..m {
+        // Best `best[i - 1][k] + PENALTY_GAP * k` over k <= j - 2, so a gap of
+        // j - k - 1 characters costs PENALTY_GAP each without rescanning.
+        let mut carry: Option<(i32, usize)> = None;
+        for j in 0..n {
+            if i > 0
+                && j >= 2
+                && let Some(prev) = best[i - 1][j - 2]
+            {
+                let value = prev + PENALTY_GAP * (j - 2) as i32;
+                if carry.is_none_or(|(best, _)| value > best) {
+                    carry = Some((value, j - 2));
+                }
+            }
+            if fold(chars[j].1) != query[i] {
+                continue;
+            }
+            let gain = SCORE_MATCH + bonus[j];
+            if i == 0 {
+                best[i][j] = Some(gain - PENALTY_GAP * (j as i32).min(MAX_LEADING_GAP));
+                continue;
+            }
+            let mut step = (j >= 1)
+                .then(|| best[i - 1][j - 1])
+                .flatten()
+                .map(|prev| (prev + BONUS_CONSECUTIVE, j - 1));
+            if let Some((value, k)) = carry {
+                let gapped = value - PENALTY_GAP * (j as i32 - 1);
+                if step.is_none_or(|(score, _)| gapped > score) {
+                    step = Some((gapped, k));
+                }
+            }
+            if let Some((score, k)) = step {
+                best[i][j] = Some(score + gain);
+                from[i][j] = k;
+            }
+        }
+    }
+
+    let (end, score) = (0..n)
+        .filter_map(|j| best[m - 1][j].map(|score| (j, score)))
+        .max_by_key(|&(j, score)| (score, Reverse(j)))?;
+    let mut positions = vec![0; m];
+    let mut j = end;
+    for i in (0..m).rev() {
+        positions[i] = chars[j].0;
+        j = from[i][j];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Match `query` against every candidate, returning `(index, match)` pairs
+/// for the candidates that match, best first. Equal scores keep the
+/// candidates' original order.
+pub fn fuzzy_filter<I>(query: &str, candidates: I) -> Vec<(usize, FuzzyMatch)>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let mut matches: Vec<(usize, FuzzyMatch)> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, candidate)| {
+            fuzzy_match(query, candidate.as_ref()).map(|found| (index, found))
+        })
+        .collect();
+    matches.sort_by_key(|(_, found)| Reverse(found.score));
+    matches
+}
+
+/// Contracts for the fuzzy match primitive.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "FuzzyMatch",
+            "fuzzy",
+            "Ranks candidates by how well a typed query matches them as an in-order subsequence.",
+        )
+        .function("fuzzy_match(query, candidate) -> Option<FuzzyMatch>")
+        .function("fuzzy_filter(query, candidates) -> Vec<(usize, FuzzyMatch)>")
+        .function("FuzzyMatch::ranges(&self, candidate) -> Vec<Range<usize>>")
+        .invariant("Matching ignores case, and whitespace in the query.")
+        .invariant("An empty query matches every candidate with score 0.")
+        .invariant("Matches at word starts and consecutive runs outrank scattered matches.")
+        .invariant("`fuzzy_filter` sorts best first and keeps input order between equal scores.")
+        .consumer("CommandPalette"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subsequence_matches_ignoring_case_and_spaces() {
+        let found = fuzzy_match("tg th", "Toggle Theme").unwrap();
+        assert_eq!(found.positions, [0, 2, 7, 8]);
+        assert!(fuzzy_match("theme toggle", "Toggle Theme").is_none());
+        assert!(fuzzy_match("xyz", "Toggle Theme").is_none());
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(
+            fuzzy_match("  ", "Open File"),
+            Some(FuzzyMatch {
+                score: 0,
+                positions: Vec::new()
+            })
+        );
+    }
+
+    #[test]
+    fn word_starts_beat_scattered_matches() {
+        // "ot" hits the word starts in "Open Theme" but is scattered in "Rotate".
+        let aligned = fuzzy_match("ot", "Open Theme").unwrap();
+        let scattered = fuzzy_match("ot", "Rotate").unwrap();
+        assert!(aligned.score > scattered.score);
+        // camelCase humps count as word starts.
+        assert_eq!(fuzzy_match("ss", "showSettings").unwrap().positions, [0, 4]);
+    }
+
+    #[test]
+    fn consecutive_runs_beat_gaps() {
+        let run = fuzzy_match("abc", "xabcx").unwrap();
+        let gappy = fuzzy_match("abc", "xaxbxcx").unwrap();
+        assert!(run.score > gappy.score);
+    }
+
+    #[test]
+    fn ranges_merge_adjacent_characters() {
+        let found = fuzzy_match("togth", "Toggle Theme").unwrap();
+        assert_eq!(found.ranges("Toggle Theme"), [0..3, 7..9]);
+        let found = fuzzy_match("cé", "Café").unwrap();
+        assert_eq!(found.ranges("Café"), [0..1, 3..5]);
+    }
+
+    #[test]
+    fn filter_ranks_best_first_and_keeps_ties_in_order() {
+        let commands = [
+            "Reset Zoom",
+            "Toggle Theme",
+            "Open Theme File",
+            "Theme: Reload",
+        ];
+        let ranked: Vec<usize> = fuzzy_filter("theme", commands)
+            .into_iter()
+            .map(|(index, _)| index)
+            .collect();
+        // The earliest match wins; the other two tie and keep input order.
+        assert_eq!(ranked, [3, 1, 2]);
+        let tied: Vec<usize> = fuzzy_filter("", ["b", "a"])
+            .into_iter()
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(tied, [0, 1]);
+    }
+}
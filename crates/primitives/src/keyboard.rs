@@ -10,6 +10,8 @@
 
 use gpui::{KeyDownEvent, Window};
 
+use crate::contract::PrimitiveContract;
+
 /// Standard key identifiers used across components.
 pub mod keys {
     pub const TAB: &str = "tab";
@@ -22,6 +24,10 @@ pub mod keys {
     pub const ARROW_RIGHT: &str = "right";
     pub const HOME: &str = "home";
     pub const END: &str = "end";
+    pub const PAGE_UP: &str = "pageup";
+    pub const PAGE_DOWN: &str = "pagedown";
+    pub const BACKSPACE: &str = "backspace";
+    pub const DELETE: &str = "delete";
 }
 
 /// Direction for arrow key navigation within a list or group.
@@ -152,6 +158,48 @@ pub fn navigate_index(
     current
 }
 
+/// Contract for the keyboard navigation helpers.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "KeyboardNavigation",
+            "keyboard",
+            "Key classification and list navigation for arrow-key driven components.",
+        )
+        .function("classify_nav_key(event, orientation) -> Option<NavDirection>")
+        .function("navigate_index(current, direction, count, is_disabled) -> usize")
+        .function("is_activation_key(event) -> bool")
+        .function("is_escape_key(event) -> bool")
+        .function("is_tab_key(event) -> bool")
+        .function("is_shift_tab(event) -> bool")
+        .function("focus_next(window, cx)")
+        .function("focus_prev(window, cx)")
+        .invariant("`navigate_index` wraps around at both ends of the list.")
+        .invariant(
+            "`navigate_index` skips disabled items, returning `current` if all are disabled.",
+        )
+        .invariant("`classify_nav_key` ignores arrows across the orientation.")
+        .consumer("Breadcrumbs")
+        .consumer("Card")
+        .consumer("CommandPalette")
+        .consumer("ContextMenu")
+        .consumer("DataTable")
+        .consumer("DropdownMenu")
+        .consumer("FilePicker")
+        .consumer("Link")
+        .consumer("MenuBar")
+        .consumer("NumberInput")
+        .consumer("PasswordInput")
+        .consumer("Radio")
+        .consumer("Select")
+        .consumer("Slider")
+        .consumer("StatusBar")
+        .consumer("Switch")
+        .consumer("Tabs")
+        .consumer("Tag"),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
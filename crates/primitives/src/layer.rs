@@ -0,0 +1,404 @@
+//! Overlay layer primitive: one stack for every open overlay in the app.
+//!
+//! Dialog, Popover, and DropdownMenu register themselves in the
+//! [`LayerManager`] global while they are open. The manager orders them
+//! (later layers above earlier ones, tooltips always on top), so each overlay
+//! can ask for its deferred paint priority, whether it draws the scrim, whether a modal above makes it
+//! inert, and whether it is the one Escape should close. Closing a layer
+//! through the manager restores the focus it captured with [`FocusReturn`].
+
+use gpui::{App, Global, SharedString, Window};
+
+use crate::contract::PrimitiveContract;
+use crate::focus::FocusReturn;
+
+/// What kind of overlay a layer is; sets its tier and defaults.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LayerKind {
+    /// A dialog: dims everything below with a scrim and blocks it.
+    Modal,
+    /// A popover anchored to a trigger.
+    Popover,
+    /// A dropdown or context menu.
+    Menu,
+    /// A tooltip: always on top and never the target of Escape.
+    Tooltip,
+}
+
+impl LayerKind {
+    /// Whether layers of this kind draw a scrim by default.
+    pub fn has_scrim(self) -> bool {
+        self == LayerKind::Modal
+    }
+
+    /// Whether Escape closes layers of this kind by default.
+    pub fn closes_on_escape(self) -> bool {
+        self != LayerKind::Tooltip
+    }
+
+    /// Stacking tier: a layer never paints below a layer of a lower tier.
+    fn tier(self) -> u8 {
+        match self {
+            // Within a tier the most recently opened overlay is on top, so a
+            // menu opened from a dialog covers it and vice versa.
+            LayerKind::Modal | LayerKind::Popover | LayerKind::Menu => 0,
+            LayerKind::Tooltip => 1,
+        }
+    }
+}
+
+/// One open overlay.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    id: SharedString,
+    kind: LayerKind,
+    scrim: bool,
+    closes_on_escape: bool,
+    focus_return: Option<FocusReturn>,
+}
+
+impl Layer {
+    /// A layer with the kind's defaults.
+    pub fn new(id: impl Into<SharedString>, kind: LayerKind) -> Self {
+        Self {
+            id: id.into(),
+            kind,
+            scrim: kind.has_scrim(),
+            closes_on_escape: kind.closes_on_escape(),
+            focus_return: None,
+        }
+    }
+
+    /// Override whether the layer draws a scrim.
+    pub fn scrim(mut self, scrim: bool) -> Self {
+        self.scrim = scrim;
+        self
+    }
+
+    /// Override whether Escape closes the layer.
+    pub fn closes_on_escape(mut self, closes: bool) -> Self {
+        self.closes_on_escape = closes;
+        self
+    }
+
+    /// Focus to restore when the layer closes.
+    pub fn focus_return(mut self, focus_return: FocusReturn) -> Self {
+        self.focus_return = Some(focus_return);
+        self
+    }
+
+    /// The layer's id.
+    pub fn id(&self) -> &SharedString {
+        &self.id
+    }
+
+    /// The layer's kind.
+    pub fn kind(&self) -> LayerKind {
+        self.kind
+    }
+
+    /// Whether the layer draws a scrim.
+    pub fn has_scrim(&self) -> bool {
+        self.scrim
+    }
+
+    /// Restore the focus captured when the layer opened. Returns false if
+    /// there was none.
+    pub fn restore_focus(&self, window: &mut Window, cx: &mut App) -> bool {
+        self.focus_return
+            .as_ref()
+            .is_some_and(|focus_return| focus_return.restore(window, cx))
+    }
+}
+
+/// The stack of open overlays, bottom to top.
+///
+/// Opening an id that is already open updates it in place, so overlays can
+/// register on every render while they are visible; the focus captured by
+/// the first open is kept.
+#[derive(Debug, Default)]
+pub struct LayerManager {
+    layers: Vec<Layer>,
+}
+
+impl Global for LayerManager {}
+
+impl LayerManager {
+    /// Deferred paint priority of the bottom layer. Overlays that are not
+    /// registered keep painting at this priority.
+    pub const BASE_PRIORITY: usize = 1;
+
+    /// Create an empty stack.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Push a layer on top of its tier, or update the open layer with the
+    /// same id. Returns its paint priority.
+    pub fn open(&mut self, layer: Layer) -> usize {
+        let id = layer.id.clone();
+        if let Some(existing) = self.layers.iter_mut().find(|l| l.id == layer.id) {
+            let focus_return = existing.focus_return.take().or(layer.focus_return);
+            *existing = Layer {
+                focus_return,
+                ..layer
+            };
+        } else {
+            // Above every layer of its tier or lower.
+            let index = self
+                .layers
+                .iter()
+                .position(|l| l.kind.tier() > layer.kind.tier())
+                .unwrap_or(self.layers.len());
+            self.layers.insert(index, layer);
+        }
+        self.priority(&id)
+    }
+
+    /// Remove a layer without touching focus. Returns it if it was open.
+    pub fn close(&mut self, id: &str) -> Option<Layer> {
+        let index = self.layers.iter().position(|l| l.id.as_ref() == id)?;
+        Some(self.layers.remove(index))
+    }
+
+    /// Remove and return the layer Escape should close: the topmost one that
+    /// closes on Escape.
+    pub fn handle_escape(&mut self) -> Option<Layer> {
+        let index = self.layers.iter().rposition(|l| l.closes_on_escape)?;
+        Some(self.layers.remove(index))
+    }
+
+    /// Whether a layer is open.
+    pub fn contains(&self, id: &str) -> bool {
+        self.layers.iter().any(|l| l.id.as_ref() == id)
+    }
+
+    /// Open layers, bottom to top.
+    pub fn layers(&self) -> impl Iterator<Item = &Layer> {
+        self.layers.iter()
+    }
+
+    /// The topmost layer.
+    pub fn top(&self) -> Option<&Layer> {
+        self.layers.last()
+    }
+
+    /// Deferred paint priority for a layer: higher paints above lower.
+    /// Unregistered ids get [`BASE_PRIORITY`](Self::BASE_PRIORITY).
+    pub fn priority(&self, id: &str) -> usize {
+        Self::BASE_PRIORITY + self.position(id).map_or(0, |index| index + 1)
+    }
+
+    /// Whether this layer draws the scrim. Only the topmost scrim layer
+    /// does, so stacked modals do not darken the app twice.
+    pub fn shows_scrim(&self, id: &str) -> bool {
+        self.layers
+            .iter()
+            .rfind(|l| l.scrim)
+            .is_some_and(|l| l.id.as_ref() == id)
+    }
+
+    /// Whether a scrim layer above this one blocks its input.
+    pub fn is_blocked(&self, id: &str) -> bool {
+        self.position(id)
+            .is_some_and(|index| self.layers[index + 1..].iter().any(|l| l.scrim))
+    }
+
+    /// Whether Escape pressed now should close this layer.
+    pub fn is_escape_target(&self, id: &str) -> bool {
+        self.layers
+            .iter()
+            .rfind(|l| l.closes_on_escape)
+            .is_some_and(|l| l.id.as_ref() == id)
+    }
+
+    /// Number of open layers.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Whether no layer is open.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    fn position(&self, id: &str) -> Option<usize> {
+        self.layers.iter().position(|l| l.id.as_ref() == id)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// App-wide stack
+// ---------------------------------------------------------------------------
+
+impl LayerManager {
+    /// Register an open overlay in the app-wide stack. Returns its paint
+    /// priority.
+    pub fn register(layer: Layer, cx: &mut App) -> usize {
+        cx.default_global::<LayerManager>().open(layer)
+    }
+
+    /// Drop a closed overlay from the app-wide stack, leaving focus alone.
+    pub fn unregister(id: &str, cx: &mut App) {
+        if let Some(manager) = cx.try_global::<LayerManager>()
+            && manager.contains(id)
+        {
+            cx.global_mut::<LayerManager>().close(id);
+        }
+    }
+
+    /// Close an overlay and restore the focus it captured. Returns false if
+    /// it was not open.
+    pub fn dismiss(id: &str, window: &mut Window, cx: &mut App) -> bool {
+        let Some(layer) = cx.default_global::<LayerManager>().close(id) else {
+            return false;
+        };
+        layer.restore_focus(window, cx);
+        window.refresh();
+        true
+    }
+
+    /// Close the app-wide Escape target and restore its focus. Returns the
+    /// id of the closed layer.
+    pub fn dismiss_topmost(window: &mut Window, cx: &mut App) -> Option<SharedString> {
+        let layer = cx.default_global::<LayerManager>().handle_escape()?;
+        layer.restore_focus(window, cx);
+        window.refresh();
+        Some(layer.id)
+    }
+
+    /// The app-wide stack's view of one overlay, for rendering.
+    pub fn state(id: &str, cx: &App) -> LayerState {
+        match cx.try_global::<LayerManager>() {
+            Some(manager) if manager.contains(id) => LayerState {
+                priority: manager.priority(id),
+                shows_scrim: manager.shows_scrim(id),
+                blocked: manager.is_blocked(id),
+                escape_target: manager.is_escape_target(id),
+            },
+            _ => LayerState::default(),
+        }
+    }
+}
+
+/// How the app-wide stack says an overlay should render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayerState {
+    /// Deferred paint priority.
+    pub priority: usize,
+    /// Whether it draws the scrim.
+    pub shows_scrim: bool,
+    /// Whether a modal above it blocks its input.
+    pub blocked: bool,
+    /// Whether Escape should close it.
+    pub escape_target: bool,
+}
+
+impl Default for LayerState {
+    /// An unregistered overlay paints at the base priority and handles its
+    /// own scrim and Escape.
+    fn default() -> Self {
+        Self {
+            priority: LayerManager::BASE_PRIORITY,
+            shows_scrim: true,
+            blocked: false,
+            escape_target: true,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contracts
+// ---------------------------------------------------------------------------
+
+/// Contracts for the layer primitives.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "LayerManager",
+            "layer",
+            "Stacks open overlays for z-ordering, scrim, Escape routing, and focus return.",
+        )
+        .function("Layer::new(id, kind: LayerKind) -> Layer")
+        .function("open(&mut self, layer: Layer) -> usize")
+        .function("close(&mut self, id) -> Option<Layer>")
+        .function("handle_escape(&mut self) -> Option<Layer>")
+        .function("priority(&self, id) -> usize")
+        .function("shows_scrim(&self, id) -> bool")
+        .function("is_blocked(&self, id) -> bool")
+        .function("is_escape_target(&self, id) -> bool")
+        .function("register(layer, cx) -> usize")
+        .function("dismiss(id, window, cx) -> bool")
+        .function("dismiss_topmost(window, cx) -> Option<SharedString>")
+        .function("state(id, cx) -> LayerState")
+        .invariant("Tooltips paint above every other layer; otherwise later layers paint above earlier ones.")
+        .invariant("Re-opening an open id keeps its stack position and captured focus.")
+        .invariant("Only the topmost scrim layer draws its scrim; layers below it are blocked.")
+        .invariant("Escape closes only the topmost layer that closes on Escape.")
+        .consumer("Dialog")
+        .consumer("DropdownMenu")
+        .consumer("Popover"),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(manager: &LayerManager) -> Vec<&str> {
+        manager.layers().map(|l| l.id().as_ref()).collect()
+    }
+
+    #[test]
+    fn later_layers_stack_above_and_tooltips_stay_on_top() {
+        let mut manager = LayerManager::new();
+        manager.open(Layer::new("tip", LayerKind::Tooltip));
+        manager.open(Layer::new("dialog", LayerKind::Modal));
+        manager.open(Layer::new("menu", LayerKind::Menu));
+        assert_eq!(ids(&manager), ["dialog", "menu", "tip"]);
+        assert!(manager.priority("menu") > manager.priority("dialog"));
+        assert_eq!(manager.priority("unknown"), LayerManager::BASE_PRIORITY);
+
+        // Re-opening updates in place.
+        manager.open(Layer::new("dialog", LayerKind::Modal).scrim(false));
+        assert_eq!(ids(&manager), ["dialog", "menu", "tip"]);
+        assert!(!manager.shows_scrim("dialog"));
+        assert_eq!(manager.top().unwrap().kind(), LayerKind::Tooltip);
+    }
+
+    #[test]
+    fn topmost_scrim_blocks_the_layers_below() {
+        let mut manager = LayerManager::new();
+        manager.open(Layer::new("settings", LayerKind::Modal));
+        manager.open(Layer::new("popover", LayerKind::Popover));
+        manager.open(Layer::new("confirm", LayerKind::Modal));
+        assert!(manager.shows_scrim("confirm"));
+        assert!(!manager.shows_scrim("settings"));
+        assert!(manager.is_blocked("settings"));
+        assert!(manager.is_blocked("popover"));
+        assert!(!manager.is_blocked("confirm"));
+
+        manager.close("confirm");
+        assert!(manager.shows_scrim("settings"));
+        assert!(!manager.is_blocked("popover"));
+    }
+
+    #[test]
+    fn escape_closes_the_topmost_closable_layer() {
+        let mut manager = LayerManager::new();
+        manager.open(Layer::new("dialog", LayerKind::Modal));
+        manager.open(Layer::new("menu", LayerKind::Menu));
+        manager.open(Layer::new("tip", LayerKind::Tooltip));
+        assert!(manager.is_escape_target("menu"));
+
+        assert_eq!(manager.handle_escape().unwrap().id().as_ref(), "menu");
+        assert!(manager.is_escape_target("dialog"));
+        assert_eq!(manager.handle_escape().unwrap().id().as_ref(), "dialog");
+        assert!(manager.handle_escape().is_none());
+        assert_eq!(ids(&manager), ["tip"]);
+    }
+}
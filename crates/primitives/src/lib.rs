@@ -1,17 +1,45 @@
+pub mod contract;
+pub mod dnd;
 pub mod focus;
+pub mod format;
+pub mod fuzzy;
 pub mod keyboard;
+pub mod layer;
 pub mod popover;
+pub mod resize;
+pub mod scroll;
+pub mod shortcuts;
 pub mod state;
+pub mod text_edit;
+pub mod timing;
 
+pub use contract::PrimitiveContract;
+pub use dnd::{
+    DRAG_THRESHOLD, DragState, DropPosition, DropTarget, KeyOutcome, Reorder, drop_target_at,
+};
 pub use focus::{FocusReturn, FocusTrap};
+pub use format::{
+    Locale, RelativeWords, format_decimal, format_file_size, format_integer, format_relative_time,
+};
+pub use fuzzy::{FuzzyMatch, fuzzy_filter, fuzzy_match};
 pub use keyboard::{
     NavDirection, Orientation, classify_nav_key, focus_next, focus_prev, is_activation_key,
     is_escape_key, is_shift_tab, is_tab_key, navigate_index,
 };
+pub use layer::{Layer, LayerKind, LayerManager, LayerState};
 pub use popover::{PopoverPosition, is_dismiss_key, is_outside_bounds, should_flip_vertical};
+pub use resize::{Pane, PaneLimits, RESIZE_PAGE_STEP, RESIZE_STEP, SplitLimits, SplitState};
+pub use scroll::{MIN_THUMB_SIZE, ScrollMetrics};
+pub use shortcuts::{SECONDARY_IS_CMD, Shortcut, ShortcutConflict, ShortcutRegistry, resolve_keys};
 pub use state::{
-    Controllable, HoverState, InteractionState, OpenState, SelectionState, ValidationState,
+    BoundState, ChangeHandler, Controllable, ControlledState, HoverState, InteractionState,
+    OpenState, OverlayPhase, OverlayStateMachine, SelectionState, ValidationState,
+};
+pub use text_edit::{
+    CompositionEvent, CompositionHandler, TEXT_INPUT_KEY_CONTEXT, TextChangeHandler, TextEditState,
+    TextInput, TextInputColors, TextInputElement, is_typing,
 };
+pub use timing::{Clock, Debouncer, MockClock, MotionPreference, SystemClock, Throttler};
 
 pub fn init(_cx: &mut gpui::App) {
     // Primitive initialization will register global state/event handlers here.
@@ -7,6 +7,8 @@
 
 use gpui::{Corner, KeyDownEvent, Pixels, Point};
 
+use crate::contract::PrimitiveContract;
+
 /// Specifies where a popover should be positioned relative to its trigger.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PopoverPosition {
@@ -50,6 +52,23 @@ impl PopoverPosition {
             attach: Corner::BottomRight,
         }
     }
+
+    /// Popover opens beside the trigger on its right, aligned to the top edge.
+    /// Used for callouts on panels docked to the left.
+    pub fn beside_right() -> Self {
+        Self {
+            anchor: Corner::TopRight,
+            attach: Corner::TopLeft,
+        }
+    }
+
+    /// Popover opens beside the trigger on its left, aligned to the top edge.
+    pub fn beside_left() -> Self {
+        Self {
+            anchor: Corner::TopLeft,
+            attach: Corner::TopRight,
+        }
+    }
 }
 
 impl Default for PopoverPosition {
@@ -96,6 +115,35 @@ pub fn is_dismiss_key(event: &KeyDownEvent) -> bool {
     event.keystroke.key.as_str() == super::keyboard::keys::ESCAPE
 }
 
+/// Contract for the popover positioning primitive.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "PopoverPosition",
+            "popover",
+            "Anchor-relative overlay placement, with flip and dismiss helpers.",
+        )
+        .function("below_left() -> PopoverPosition")
+        .function("below_right() -> PopoverPosition")
+        .function("above_left() -> PopoverPosition")
+        .function("above_right() -> PopoverPosition")
+        .function("beside_right() -> PopoverPosition")
+        .function("beside_left() -> PopoverPosition")
+        .function(
+            "should_flip_vertical(trigger_y, trigger_height, popover_height, viewport) -> bool",
+        )
+        .function("is_outside_bounds(point, origin, width, height) -> bool")
+        .function("is_dismiss_key(event) -> bool")
+        .invariant("The default position is `below_left`.")
+        .invariant(
+            "Below and above positions mirror the anchor corner vertically; beside positions mirror it horizontally.",
+        )
+        .invariant("Flip upward only when content does not fit below and more room is above.")
+        .consumer("ContextMenu")
+        .consumer("Popover"),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -107,6 +155,16 @@ mod tests {
         assert_eq!(pos, PopoverPosition::below_left());
     }
 
+    #[test]
+    fn beside_positions_mirror_horizontally() {
+        let right = PopoverPosition::beside_right();
+        assert_eq!(right.anchor, Corner::TopRight);
+        assert_eq!(right.attach, Corner::TopLeft);
+        let left = PopoverPosition::beside_left();
+        assert_eq!(left.anchor, Corner::TopLeft);
+        assert_eq!(left.attach, Corner::TopRight);
+    }
+
     #[test]
     fn outside_bounds_detection() {
         let origin = Point::new(px(100.0), px(100.0));
@@ -0,0 +1,334 @@
+//! Resize primitive: divider drag and keyboard math for split panes.
+//!
+//! A split lays two panes out along an axis. [`SplitState`] keeps the first
+//! pane's size (the second pane takes the rest) and which pane, if any, is
+//! collapsed. Sizes are pixels along the axis, excluding the divider, and are
+//! resolved against [`SplitLimits`] for the space available each time they
+//! are read, so resizing the container never breaks a pane's min or max.
+//!
+//! Consumed by SplitPane.
+
+use crate::contract::PrimitiveContract;
+use crate::keyboard::{Orientation, keys};
+
+/// Pixels an arrow key moves the divider.
+pub const RESIZE_STEP: f32 = 10.0;
+
+/// Pixels Shift+arrow moves the divider.
+pub const RESIZE_PAGE_STEP: f32 = 50.0;
+
+/// One of the two panes of a split.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    /// The left pane of a horizontal split, the top pane of a vertical one.
+    First,
+    /// The right pane of a horizontal split, the bottom pane of a vertical one.
+    Second,
+}
+
+/// Size limits of one pane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PaneLimits {
+    /// Smallest size while the pane is open.
+    pub min: f32,
+    /// Largest size; `f32::INFINITY` for none.
+    pub max: f32,
+    /// Whether dragging the divider past half of `min` collapses the pane to zero.
+    pub collapsible: bool,
+}
+
+impl Default for PaneLimits {
+    fn default() -> Self {
+        Self {
+            min: 0.0,
+            max: f32::INFINITY,
+            collapsible: false,
+        }
+    }
+}
+
+impl PaneLimits {
+    /// Limits of `min..=max`, swapping reversed bounds and treating negative
+    /// sizes as zero.
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min: min.min(max).max(0.0),
+            max: max.max(min).max(0.0),
+            collapsible: false,
+        }
+    }
+}
+
+/// Size limits of both panes.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct SplitLimits {
+    pub first: PaneLimits,
+    pub second: PaneLimits,
+}
+
+impl SplitLimits {
+    /// Limits of `pane`.
+    pub fn pane(&self, pane: Pane) -> &PaneLimits {
+        match pane {
+            Pane::First => &self.first,
+            Pane::Second => &self.second,
+        }
+    }
+
+    /// Smallest and largest first-pane sizes in `total` with both panes open.
+    ///
+    /// When the limits cannot all hold, the first pane's minimum wins.
+    pub fn range(&self, total: f32) -> (f32, f32) {
+        let total = total.max(0.0);
+        let lower = self.first.min.max(total - self.second.max).min(total);
+        let upper = self.first.max.min(total - self.second.min).max(lower);
+        (lower, upper)
+    }
+
+    /// The same limits with the panes swapped, for measuring a split from
+    /// its second pane.
+    pub fn swapped(&self) -> Self {
+        Self {
+            first: self.second,
+            second: self.first,
+        }
+    }
+
+    /// Resolve a requested first-pane size in `total`: a collapsible pane
+    /// left with less than half its minimum collapses, otherwise the size is
+    /// clamped to [`range`](Self::range).
+    pub fn resolve(&self, requested: f32, total: f32) -> (f32, Option<Pane>) {
+        let total = total.max(0.0);
+        if self.first.collapsible && requested < self.first.min / 2.0 {
+            return (0.0, Some(Pane::First));
+        }
+        if self.second.collapsible && total - requested < self.second.min / 2.0 {
+            return (total, Some(Pane::Second));
+        }
+        let (lower, upper) = self.range(total);
+        (requested.clamp(lower, upper), None)
+    }
+}
+
+/// Size of a split's first pane, and which pane is collapsed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SplitState {
+    /// First pane size requested while both panes are open; kept while a
+    /// pane is collapsed so restoring returns to it.
+    size: f32,
+    collapsed: Option<Pane>,
+}
+
+impl SplitState {
+    /// A split whose first pane asks for `size`.
+    pub fn new(size: f32) -> Self {
+        Self {
+            size: size.max(0.0),
+            collapsed: None,
+        }
+    }
+
+    /// The collapsed pane, if any.
+    pub fn collapsed(&self) -> Option<Pane> {
+        self.collapsed
+    }
+
+    /// The first pane's size in `total`: zero or all of it while a pane is
+    /// collapsed, otherwise the requested size clamped to the limits.
+    pub fn first_size(&self, total: f32, limits: &SplitLimits) -> f32 {
+        match self.collapsed {
+            Some(Pane::First) => 0.0,
+            Some(Pane::Second) => total.max(0.0),
+            None => {
+                let (lower, upper) = limits.range(total);
+                self.size.clamp(lower, upper)
+            }
+        }
+    }
+
+    /// Move the divider to `offset` from the start of the split, as a drag
+    /// does. Collapsible panes collapse when dragged past half their minimum.
+    pub fn drag_to(&mut self, offset: f32, total: f32, limits: &SplitLimits) {
+        let (size, collapsed) = limits.resolve(offset, total);
+        if collapsed.is_none() {
+            self.size = size;
+        }
+        self.collapsed = collapsed;
+    }
+
+    /// Move the divider by `delta`, stopping at the limits. Moving away from
+    /// a collapsed pane reopens it at its minimum.
+    pub fn resize_by(&mut self, delta: f32, total: f32, limits: &SplitLimits) {
+        let (lower, upper) = limits.range(total);
+        let current = self.first_size(total, limits);
+        self.size = match self.collapsed.take() {
+            Some(Pane::First) if delta > 0.0 => lower,
+            Some(Pane::Second) if delta < 0.0 => upper,
+            Some(pane) => {
+                self.collapsed = Some(pane);
+                return;
+            }
+            None => (current + delta).clamp(lower, upper),
+        };
+    }
+
+    /// Collapse `pane`, or reopen it at its last size if it is collapsed.
+    /// Returns false when `pane` is not collapsible.
+    pub fn toggle_collapse(&mut self, pane: Pane, limits: &SplitLimits) -> bool {
+        if !limits.pane(pane).collapsible {
+            return false;
+        }
+        self.collapsed = if self.collapsed == Some(pane) {
+            None
+        } else {
+            Some(pane)
+        };
+        true
+    }
+
+    /// Apply a key pressed on the divider. Returns true when it was handled.
+    ///
+    /// Left/Right (horizontal) or Up/Down (vertical) move the divider by
+    /// [`RESIZE_STEP`], or [`RESIZE_PAGE_STEP`] with Shift. Home/End move it
+    /// to the smallest/largest first pane. Enter collapses or reopens the
+    /// first collapsible pane.
+    pub fn handle_key(
+        &mut self,
+        key: &str,
+        shift: bool,
+        orientation: Orientation,
+        total: f32,
+        limits: &SplitLimits,
+    ) -> bool {
+        let step = if shift { RESIZE_PAGE_STEP } else { RESIZE_STEP };
+        let (back, forward) = match orientation {
+            Orientation::Horizontal => (keys::ARROW_LEFT, keys::ARROW_RIGHT),
+            Orientation::Vertical => (keys::ARROW_UP, keys::ARROW_DOWN),
+        };
+        match key {
+            _ if key == back => self.resize_by(-step, total, limits),
+            _ if key == forward => self.resize_by(step, total, limits),
+            keys::HOME => self.resize_by(f32::NEG_INFINITY, total, limits),
+            keys::END => self.resize_by(f32::INFINITY, total, limits),
+            keys::ENTER => {
+                return self.toggle_collapse(Pane::First, limits)
+                    || self.toggle_collapse(Pane::Second, limits);
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Contract for the resize primitive.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "SplitResize",
+            "resize",
+            "Divider drag and keyboard math for two panes sharing an axis, with min/max and collapse.",
+        )
+        .function("SplitLimits::range(&self, total) -> (f32, f32)")
+        .function("SplitLimits::swapped(&self) -> SplitLimits")
+        .function("SplitLimits::resolve(&self, requested, total) -> (f32, Option<Pane>)")
+        .function("SplitState::first_size(&self, total, limits) -> f32")
+        .function("SplitState::drag_to(&mut self, offset, total, limits)")
+        .function("SplitState::resize_by(&mut self, delta, total, limits)")
+        .function("SplitState::toggle_collapse(&mut self, pane, limits) -> bool")
+        .function("SplitState::handle_key(&mut self, key, shift, orientation, total, limits) -> bool")
+        .invariant("An open pane stays within its min and max; the first pane's min wins a conflict.")
+        .invariant("Only a collapsible pane collapses, and only to zero.")
+        .invariant("Dragging collapses a pane left with less than half its minimum; keys never do.")
+        .invariant("Reopening a collapsed pane restores the size it had before collapsing.")
+        .consumer("SplitPane"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits() -> SplitLimits {
+        SplitLimits {
+            first: PaneLimits {
+                collapsible: true,
+                ..PaneLimits::new(100.0, 300.0)
+            },
+            second: PaneLimits::new(200.0, f32::INFINITY),
+        }
+    }
+
+    #[test]
+    fn sizes_stay_within_both_panes_limits() {
+        let limits = limits();
+        assert_eq!(limits.range(1000.0), (100.0, 300.0));
+        // The second pane's minimum caps the first pane.
+        assert_eq!(limits.range(400.0), (100.0, 200.0));
+        // Too little room: the first pane's minimum wins.
+        assert_eq!(limits.range(250.0), (100.0, 100.0));
+
+        let split = SplitState::new(500.0);
+        assert_eq!(split.first_size(1000.0, &limits), 300.0);
+        assert_eq!(split.first_size(400.0, &limits), 200.0);
+    }
+
+    #[test]
+    fn swapped_limits_size_the_second_pane() {
+        let swapped = limits().swapped();
+        assert_eq!(swapped.range(1000.0), (700.0, 900.0));
+        assert!(swapped.second.collapsible);
+
+        let mut split = SplitState::new(250.0);
+        split.drag_to(960.0, 1000.0, &swapped);
+        assert_eq!(split.collapsed(), Some(Pane::Second));
+    }
+
+    #[test]
+    fn dragging_past_half_the_minimum_collapses() {
+        let limits = limits();
+        let mut split = SplitState::new(250.0);
+
+        split.drag_to(60.0, 1000.0, &limits);
+        assert_eq!(split.collapsed(), None);
+        assert_eq!(split.first_size(1000.0, &limits), 100.0);
+
+        split.drag_to(40.0, 1000.0, &limits);
+        assert_eq!(split.collapsed(), Some(Pane::First));
+        assert_eq!(split.first_size(1000.0, &limits), 0.0);
+
+        // The second pane is not collapsible, so it stops at its minimum.
+        split.drag_to(990.0, 1000.0, &limits);
+        assert_eq!(split.collapsed(), None);
+        assert_eq!(split.first_size(1000.0, &limits), 300.0);
+    }
+
+    #[test]
+    fn keys_step_and_toggle_collapse() {
+        let limits = limits();
+        let mut split = SplitState::new(200.0);
+        let key = |split: &mut SplitState, key: &str, shift: bool| {
+            split.handle_key(key, shift, Orientation::Horizontal, 1000.0, &limits)
+        };
+
+        assert!(key(&mut split, "right", false));
+        assert_eq!(split.first_size(1000.0, &limits), 210.0);
+        assert!(key(&mut split, "left", true));
+        assert_eq!(split.first_size(1000.0, &limits), 160.0);
+        assert!(!key(&mut split, "up", false), "across the orientation");
+        assert!(key(&mut split, "home", false));
+        assert_eq!(split.first_size(1000.0, &limits), 100.0);
+        assert!(key(&mut split, "left", false));
+        assert_eq!(split.collapsed(), None, "keys stop at the minimum");
+
+        assert!(key(&mut split, "end", false));
+        assert!(key(&mut split, "enter", false));
+        assert_eq!(split.collapsed(), Some(Pane::First));
+        assert!(key(&mut split, "enter", false));
+        assert_eq!(split.first_size(1000.0, &limits), 300.0, "restored");
+
+        assert!(key(&mut split, "enter", false));
+        assert!(key(&mut split, "right", false));
+        assert_eq!(split.collapsed(), None);
+        assert_eq!(split.first_size(1000.0, &limits), 100.0);
+    }
+}
@@ -0,0 +1,154 @@
+//! Scroll primitive: scrollbar thumb geometry and drag math.
+//!
+//! [`ScrollMetrics`] describes one axis of a scrolled viewport: how much is
+//! visible, how long the content is, and how far it is scrolled. Offsets are
+//! positive pixels from the start of the content (GPUI's scroll offsets are
+//! the negation). The thumb spans the visible fraction of the track, never
+//! shorter than [`MIN_THUMB_SIZE`], and travels the rest of the track as the
+//! offset goes from zero to its maximum.
+//!
+//! Consumed by ScrollArea.
+
+use crate::contract::PrimitiveContract;
+
+/// Shortest thumb, so short viewports over long content stay grabbable.
+pub const MIN_THUMB_SIZE: f32 = 24.0;
+
+/// One axis of a scrolled viewport.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ScrollMetrics {
+    /// Visible length.
+    pub viewport: f32,
+    /// Full content length.
+    pub content: f32,
+    /// Distance scrolled from the start, `0.0..=max_offset()`.
+    pub offset: f32,
+}
+
+impl ScrollMetrics {
+    /// Metrics for `content` in `viewport`, scrolled by `offset` (clamped).
+    pub fn new(viewport: f32, content: f32, offset: f32) -> Self {
+        let viewport = viewport.max(0.0);
+        let metrics = Self {
+            viewport,
+            content: content.max(viewport),
+            offset: 0.0,
+        };
+        Self {
+            offset: metrics.clamp(offset),
+            ..metrics
+        }
+    }
+
+    /// Furthest the content can scroll.
+    pub fn max_offset(&self) -> f32 {
+        (self.content - self.viewport).max(0.0)
+    }
+
+    /// Whether any content is out of view; less than a pixel does not count.
+    pub fn is_scrollable(&self) -> bool {
+        self.max_offset() >= 1.0
+    }
+
+    /// `offset` clamped to `0.0..=max_offset()`.
+    pub fn clamp(&self, offset: f32) -> f32 {
+        offset.clamp(0.0, self.max_offset())
+    }
+
+    /// Start and length of the thumb in a track of length `track`, or `None`
+    /// when nothing is scrollable or the track is too short for a thumb.
+    pub fn thumb(&self, track: f32) -> Option<(f32, f32)> {
+        if !self.is_scrollable() || track < MIN_THUMB_SIZE {
+            return None;
+        }
+        let length = (track * self.viewport / self.content).clamp(MIN_THUMB_SIZE, track);
+        let start = (track - length) * self.offset / self.max_offset();
+        Some((start, length))
+    }
+
+    /// The offset that puts the thumb's start at `thumb_start`, as a drag
+    /// does; thumb positions past either end clamp.
+    pub fn offset_for_thumb(&self, thumb_start: f32, track: f32) -> f32 {
+        let Some((_, length)) = self.thumb(track) else {
+            return self.offset;
+        };
+        let travel = track - length;
+        if travel <= 0.0 {
+            return self.offset;
+        }
+        self.clamp(thumb_start / travel * self.max_offset())
+    }
+
+    /// The offset after pressing the track at `position`: one viewport
+    /// toward the press, as platform scrollbars page.
+    pub fn offset_for_track_press(&self, position: f32, track: f32) -> f32 {
+        let Some((start, length)) = self.thumb(track) else {
+            return self.offset;
+        };
+        if position < start {
+            self.clamp(self.offset - self.viewport)
+        } else if position > start + length {
+            self.clamp(self.offset + self.viewport)
+        } else {
+            self.offset
+        }
+    }
+}
+
+/// Contract for the scroll primitive.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "ScrollMetrics",
+            "scroll",
+            "Scrollbar thumb size and position for one axis, and the offsets that thumb drags and track presses map to.",
+        )
+        .function("ScrollMetrics::new(viewport, content, offset) -> ScrollMetrics")
+        .function("ScrollMetrics::thumb(&self, track) -> Option<(f32, f32)>")
+        .function("ScrollMetrics::offset_for_thumb(&self, thumb_start, track) -> f32")
+        .function("ScrollMetrics::offset_for_track_press(&self, position, track) -> f32")
+        .invariant("Offsets stay within 0..=content - viewport.")
+        .invariant("The thumb is proportional to the visible fraction, at least MIN_THUMB_SIZE, and inside the track.")
+        .invariant("No thumb is shown when less than a pixel of content is out of view.")
+        .invariant("Track presses page by one viewport toward the press.")
+        .consumer("ScrollArea"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thumb_spans_the_visible_fraction() {
+        let top = ScrollMetrics::new(100.0, 400.0, 0.0);
+        assert_eq!(top.thumb(200.0), Some((0.0, 50.0)));
+        let bottom = ScrollMetrics::new(100.0, 400.0, 300.0);
+        assert_eq!(bottom.thumb(200.0), Some((150.0, 50.0)));
+
+        // Very long content keeps the thumb grabbable.
+        let long = ScrollMetrics::new(100.0, 100_000.0, 0.0);
+        assert_eq!(long.thumb(200.0), Some((0.0, MIN_THUMB_SIZE)));
+
+        assert_eq!(ScrollMetrics::new(100.0, 100.5, 0.0).thumb(200.0), None);
+        assert_eq!(ScrollMetrics::new(100.0, 50.0, 30.0).offset, 0.0);
+    }
+
+    #[test]
+    fn thumb_drags_and_track_presses_map_to_offsets() {
+        let metrics = ScrollMetrics::new(100.0, 400.0, 0.0);
+        assert_eq!(metrics.offset_for_thumb(75.0, 200.0), 150.0);
+        assert_eq!(metrics.offset_for_thumb(-20.0, 200.0), 0.0);
+        assert_eq!(metrics.offset_for_thumb(500.0, 200.0), 300.0);
+
+        assert_eq!(metrics.offset_for_track_press(120.0, 200.0), 100.0);
+        assert_eq!(
+            metrics.offset_for_track_press(20.0, 200.0),
+            0.0,
+            "on the thumb"
+        );
+        let end = ScrollMetrics::new(100.0, 400.0, 250.0);
+        assert_eq!(end.offset_for_track_press(10.0, 200.0), 150.0);
+        assert_eq!(end.offset_for_track_press(199.0, 200.0), 300.0);
+    }
+}
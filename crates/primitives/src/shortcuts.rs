@@ -0,0 +1,314 @@
+//! Keyboard shortcut registry: named actions and the keys that trigger them.
+//!
+//! Components and apps register each bindable action once in the
+//! [`ShortcutRegistry`] global with its default keys in GPUI binding syntax.
+//! `secondary` is the portable modifier, Cmd on macOS and Ctrl elsewhere, so
+//! `"secondary-shift-t"` is one default for every platform. Users may rebind
+//! an action; menus, cheat-sheets, and `Kbd::for_action` read the current
+//! keys from here, so what is shown always matches what is bound.
+
+use gpui::{App, Global, SharedString};
+
+use crate::contract::PrimitiveContract;
+
+/// One named action and its key binding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    action: SharedString,
+    label: SharedString,
+    default_keys: SharedString,
+    keys: Option<SharedString>,
+}
+
+impl Shortcut {
+    /// An action bound to `default_keys`, e.g. `"secondary-shift-t"` or the
+    /// chord `"secondary-k secondary-s"`.
+    pub fn new(
+        action: impl Into<SharedString>,
+        label: impl Into<SharedString>,
+        default_keys: impl Into<SharedString>,
+    ) -> Self {
+        Self {
+            action: action.into(),
+            label: label.into(),
+            default_keys: default_keys.into(),
+            keys: None,
+        }
+    }
+
+    /// Name the action is registered under.
+    pub fn action(&self) -> &SharedString {
+        &self.action
+    }
+
+    /// Name shown in menus and cheat-sheets.
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+
+    /// Keys bound unless rebound.
+    pub fn default_keys(&self) -> &SharedString {
+        &self.default_keys
+    }
+
+    /// Keys currently bound: the rebinding, else the default.
+    pub fn keys(&self) -> &SharedString {
+        self.keys.as_ref().unwrap_or(&self.default_keys)
+    }
+
+    /// Whether the action has been rebound.
+    pub fn is_rebound(&self) -> bool {
+        self.keys.is_some()
+    }
+}
+
+/// Two or more actions bound to the same keys on the current platform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutConflict {
+    /// The shared keys, as resolved by [`resolve_keys`].
+    pub keys: String,
+    /// The actions bound to them, in registration order.
+    pub actions: Vec<SharedString>,
+}
+
+/// Every registered shortcut, in registration order.
+#[derive(Debug, Clone, Default)]
+pub struct ShortcutRegistry {
+    shortcuts: Vec<Shortcut>,
+}
+
+impl Global for ShortcutRegistry {}
+
+impl ShortcutRegistry {
+    /// An empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a shortcut. Re-registering an action updates its label and
+    /// default in place and keeps any rebinding.
+    pub fn insert(&mut self, shortcut: Shortcut) {
+        match self.position(&shortcut.action) {
+            Some(ix) => {
+                let existing = &mut self.shortcuts[ix];
+                existing.label = shortcut.label;
+                existing.default_keys = shortcut.default_keys;
+            }
+            None => self.shortcuts.push(shortcut),
+        }
+    }
+
+    /// Rebind an action, or restore its default with `None`. Returns false
+    /// if the action is not registered.
+    pub fn set_keys(&mut self, action: &str, keys: Option<SharedString>) -> bool {
+        let Some(ix) = self.position(action) else {
+            return false;
+        };
+        self.shortcuts[ix].keys = keys.filter(|keys| !keys.trim().is_empty());
+        true
+    }
+
+    /// Restore every action's default keys.
+    pub fn reset_all(&mut self) {
+        for shortcut in &mut self.shortcuts {
+            shortcut.keys = None;
+        }
+    }
+
+    /// The shortcut registered for an action.
+    pub fn get(&self, action: &str) -> Option<&Shortcut> {
+        self.shortcuts.iter().find(|s| s.action == action)
+    }
+
+    /// Keys currently bound to an action.
+    pub fn keys(&self, action: &str) -> Option<&SharedString> {
+        self.get(action).map(Shortcut::keys)
+    }
+
+    /// Every shortcut, in registration order.
+    pub fn shortcuts(&self) -> impl Iterator<Item = &Shortcut> {
+        self.shortcuts.iter()
+    }
+
+    /// Actions sharing keys once `secondary` is resolved for the platform
+    /// (`secondary_is_cmd` on macOS), in order of first registration.
+    pub fn conflicts(&self, secondary_is_cmd: bool) -> Vec<ShortcutConflict> {
+        let mut conflicts: Vec<ShortcutConflict> = Vec::new();
+        for shortcut in &self.shortcuts {
+            let keys = resolve_keys(shortcut.keys(), secondary_is_cmd);
+            match conflicts.iter_mut().find(|c| c.keys == keys) {
+                Some(conflict) => conflict.actions.push(shortcut.action.clone()),
+                None => conflicts.push(ShortcutConflict {
+                    keys,
+                    actions: vec![shortcut.action.clone()],
+                }),
+            }
+        }
+        conflicts.retain(|c| c.actions.len() > 1);
+        conflicts
+    }
+
+    /// Number of registered shortcuts.
+    pub fn len(&self) -> usize {
+        self.shortcuts.len()
+    }
+
+    /// Whether no shortcut is registered.
+    pub fn is_empty(&self) -> bool {
+        self.shortcuts.is_empty()
+    }
+
+    fn position(&self, action: &str) -> Option<usize> {
+        self.shortcuts.iter().position(|s| s.action == action)
+    }
+}
+
+/// Whether `secondary` means Cmd on the platform this binary was built for.
+pub const SECONDARY_IS_CMD: bool = cfg!(target_os = "macos");
+
+/// Normalize keys for comparison: lowercase, modifier aliases unified,
+/// `secondary` resolved to `cmd` or `ctrl`, and modifiers sorted, so
+/// `"Shift-Secondary-T"` and `"cmd-shift-t"` compare equal on macOS.
+pub fn resolve_keys(keys: &str, secondary_is_cmd: bool) -> String {
+    keys.split_whitespace()
+        .map(|keystroke| {
+            let keystroke = keystroke.to_ascii_lowercase();
+            let mut modifiers = Vec::new();
+            let mut rest = keystroke.as_str();
+            // A trailing `-` names the minus key, as in `"ctrl--"`.
+            while let Some((modifier, tail)) = rest.split_once('-') {
+                if modifier.is_empty() || tail.is_empty() {
+                    break;
+                }
+                modifiers.push(match modifier {
+                    "secondary" if secondary_is_cmd => "cmd",
+                    "secondary" => "ctrl",
+                    "control" => "ctrl",
+                    "option" => "alt",
+                    "super" | "platform" | "win" => "cmd",
+                    other => other,
+                });
+                rest = tail;
+            }
+            modifiers.sort_unstable();
+            modifiers.dedup();
+            modifiers.push(rest);
+            modifiers.join("-")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ---------------------------------------------------------------------------
+// App-wide registry
+// ---------------------------------------------------------------------------
+
+impl ShortcutRegistry {
+    /// Register a shortcut in the app-wide registry.
+    pub fn register(shortcut: Shortcut, cx: &mut App) {
+        cx.default_global::<ShortcutRegistry>().insert(shortcut);
+    }
+
+    /// Rebind an action in the app-wide registry, or restore its default
+    /// with `None`. Returns false if the action is not registered.
+    pub fn rebind(action: &str, keys: Option<SharedString>, cx: &mut App) -> bool {
+        cx.default_global::<ShortcutRegistry>()
+            .set_keys(action, keys)
+    }
+
+    /// Keys currently bound to an action in the app-wide registry.
+    pub fn keys_for(action: &str, cx: &App) -> Option<SharedString> {
+        cx.try_global::<ShortcutRegistry>()?.keys(action).cloned()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contracts
+// ---------------------------------------------------------------------------
+
+/// Contracts for the shortcut primitives.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "ShortcutRegistry",
+            "shortcuts",
+            "Registers named actions with platform-aware key bindings that apps can rebind.",
+        )
+        .function("Shortcut::new(action, label, default_keys) -> Shortcut")
+        .function("insert(&mut self, shortcut: Shortcut)")
+        .function("set_keys(&mut self, action, keys: Option<SharedString>) -> bool")
+        .function("keys(&self, action) -> Option<&SharedString>")
+        .function("conflicts(&self, secondary_is_cmd: bool) -> Vec<ShortcutConflict>")
+        .function("resolve_keys(keys, secondary_is_cmd: bool) -> String")
+        .function("register(shortcut, cx)")
+        .function("rebind(action, keys, cx) -> bool")
+        .function("keys_for(action, cx) -> Option<SharedString>")
+        .invariant("An action's keys are its rebinding if it has one, else its default.")
+        .invariant("Re-registering an action keeps its registration order and rebinding.")
+        .invariant("`secondary` resolves to Cmd on macOS and Ctrl elsewhere.")
+        .invariant("Conflicts compare keys after resolving modifiers for the platform.")
+        .consumer("Kbd"),
+    ]
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> ShortcutRegistry {
+        let mut registry = ShortcutRegistry::new();
+        registry.insert(Shortcut::new(
+            "toggle_theme",
+            "Next theme",
+            "secondary-shift-t",
+        ));
+        registry.insert(Shortcut::new("quit", "Quit", "secondary-q"));
+        registry
+    }
+
+    #[test]
+    fn rebinding_overrides_the_default_until_cleared() {
+        let mut registry = registry();
+        assert_eq!(registry.keys("quit").unwrap().as_ref(), "secondary-q");
+
+        assert!(registry.set_keys("quit", Some("ctrl-alt-q".into())));
+        assert_eq!(registry.keys("quit").unwrap().as_ref(), "ctrl-alt-q");
+        assert!(registry.get("quit").unwrap().is_rebound());
+
+        // Re-registering keeps the rebinding and the order.
+        registry.insert(Shortcut::new("quit", "Quit app", "secondary-w"));
+        assert_eq!(registry.keys("quit").unwrap().as_ref(), "ctrl-alt-q");
+        let actions: Vec<&str> = registry.shortcuts().map(|s| s.action().as_ref()).collect();
+        assert_eq!(actions, ["toggle_theme", "quit"]);
+
+        assert!(registry.set_keys("quit", Some(" ".into())));
+        assert_eq!(registry.keys("quit").unwrap().as_ref(), "secondary-w");
+        assert!(!registry.set_keys("unknown", None));
+    }
+
+    #[test]
+    fn secondary_resolves_per_platform() {
+        assert_eq!(resolve_keys("Shift-Secondary-T", true), "cmd-shift-t");
+        assert_eq!(resolve_keys("secondary-shift-t", false), "ctrl-shift-t");
+        assert_eq!(
+            resolve_keys("secondary-k secondary-s", false),
+            "ctrl-k ctrl-s"
+        );
+        assert_eq!(resolve_keys("control--", false), "ctrl--");
+    }
+
+    #[test]
+    fn conflicts_depend_on_the_platform() {
+        let mut registry = registry();
+        registry.set_keys("quit", Some("cmd-shift-t".into()));
+        let conflicts = registry.conflicts(true);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].keys, "cmd-shift-t");
+        assert_eq!(conflicts[0].actions, ["toggle_theme", "quit"]);
+        assert!(registry.conflicts(false).is_empty());
+    }
+}
@@ -7,7 +7,16 @@
 //!   an initial value via `default_value` but doesn't control ongoing state.
 //!
 //! This module provides the `Controllable<T>` type that encapsulates this pattern,
-//! and standard state type definitions used across components.
+//! the `ControlledState<T>` helper that components use to implement it uniformly,
+//! the `OverlayStateMachine` open/close lifecycle shared by overlays, and
+//! standard state type definitions used across components.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{App, ElementId, Entity, Window};
+
+use crate::contract::PrimitiveContract;
 
 /// Represents a value that can be either controlled (externally owned) or
 /// uncontrolled (internally owned with a default).
@@ -46,6 +55,160 @@ impl<T: Default> Default for Controllable<T> {
     }
 }
 
+/// Change notification for a [`ControlledState`].
+pub type ChangeHandler<T> = Rc<dyn Fn(&T, &mut Window, &mut App) + 'static>;
+
+/// Dual-mode (controlled/uncontrolled) value shared by stateful components.
+///
+/// Resolution order for the value rendered each frame:
+/// 1. `value` -- if set, the component is **controlled** and always renders it.
+///    User interaction only fires `on_change`; the parent passes the new value back.
+/// 2. Internal state -- in **uncontrolled** mode, the last value committed by
+///    user interaction, persisted across renders per element id.
+/// 3. `default_value` -- the initial uncontrolled value before any interaction.
+/// 4. `T::default()`.
+///
+/// `on_change` fires whenever a committed value differs from the rendered one,
+/// in both modes.
+///
+/// # Usage
+/// ```ignore
+/// // Builder: configure the helper stored on the component.
+/// self.checked = self.checked.value(true);
+///
+/// // Render: bind to per-element storage, then commit on interaction.
+/// let checked = self.checked.bind(self.id.clone(), window, cx);
+/// let toggled = !*checked.current();
+/// el.on_click(move |_, window, cx| checked.set(toggled, window, cx))
+/// ```
+pub struct ControlledState<T: 'static> {
+    value: Option<T>,
+    default_value: Option<T>,
+    on_change: Option<ChangeHandler<T>>,
+}
+
+impl<T: Clone + Default + PartialEq + 'static> ControlledState<T> {
+    /// An uncontrolled state starting at `T::default()`.
+    pub fn new() -> Self {
+        Self {
+            value: None,
+            default_value: None,
+            on_change: None,
+        }
+    }
+
+    /// Control the value from the parent.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the initial value used in uncontrolled mode.
+    pub fn default_value(mut self, value: T) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+
+    /// Set the change handler.
+    pub fn on_change(mut self, handler: impl Fn(&T, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Returns true if `value` was provided (controlled mode).
+    pub fn is_controlled(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Resolve the value to render, given the internal (uncontrolled) state.
+    pub fn resolve(&self, internal: Option<&T>) -> T {
+        self.value
+            .as_ref()
+            .or(internal)
+            .or(self.default_value.as_ref())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Apply a committed value to the internal state.
+    ///
+    /// Only uncontrolled states store the value. Returns true if the internal
+    /// state changed.
+    pub fn apply(&self, value: &T, internal: &mut Option<T>) -> bool {
+        if self.is_controlled() || internal.as_ref() == Some(value) {
+            return false;
+        }
+        *internal = Some(value.clone());
+        true
+    }
+
+    /// Bind to per-element internal state for this frame.
+    ///
+    /// Must be called during render. `id` keys the internal state, so it should
+    /// be the component's element id.
+    pub fn bind(
+        self,
+        id: impl Into<ElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> BoundState<T> {
+        let internal = window.use_keyed_state(id, cx, |_, _| None::<T>);
+        let current = self.resolve(internal.read(cx).as_ref());
+        BoundState {
+            current,
+            state: Rc::new(self),
+            internal,
+        }
+    }
+}
+
+impl<T: Clone + Default + PartialEq + 'static> Default for ControlledState<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`ControlledState`] bound to its element for the current frame.
+///
+/// Cheap to clone into event handlers.
+#[derive(Clone)]
+pub struct BoundState<T: 'static> {
+    current: T,
+    state: Rc<ControlledState<T>>,
+    internal: Entity<Option<T>>,
+}
+
+impl<T: Clone + Default + PartialEq + 'static> BoundState<T> {
+    /// The resolved value for this frame.
+    pub fn current(&self) -> &T {
+        &self.current
+    }
+
+    /// Returns true if the underlying state is controlled.
+    pub fn is_controlled(&self) -> bool {
+        self.state.is_controlled()
+    }
+
+    /// Commit a new value from user interaction.
+    ///
+    /// Stores it internally when uncontrolled (re-rendering the owning view) and
+    /// fires `on_change` if it differs from the rendered value.
+    pub fn set(&self, value: T, window: &mut Window, cx: &mut App) {
+        if value == self.current {
+            return;
+        }
+        let state = self.state.clone();
+        self.internal.update(cx, |internal, cx| {
+            if state.apply(&value, internal) {
+                cx.notify();
+            }
+        });
+        if let Some(handler) = &self.state.on_change {
+            handler(&value, window, cx);
+        }
+    }
+}
+
 /// Standard open/closed toggle state.
 ///
 /// Used by Dialog (open/closed), Select (popover open/closed).
@@ -81,6 +244,150 @@ impl OpenState {
     }
 }
 
+/// Phase of an overlay's open/close lifecycle.
+///
+/// `Opening` and `Closing` are transition phases during which enter/exit
+/// animations play; the overlay is rendered in every phase except `Closed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlayPhase {
+    #[default]
+    Closed,
+    Opening,
+    Open,
+    Closing,
+}
+
+impl OverlayPhase {
+    /// Duration of the enter animation played during `Opening`.
+    pub const ENTER_DURATION: Duration = Duration::from_millis(150);
+    /// Duration of the exit animation played during `Closing`.
+    pub const EXIT_DURATION: Duration = Duration::from_millis(100);
+
+    /// Animation duration for this phase (zero for settled phases).
+    pub fn transition_duration(&self) -> Duration {
+        match self {
+            OverlayPhase::Opening => Self::ENTER_DURATION,
+            OverlayPhase::Closing => Self::EXIT_DURATION,
+            OverlayPhase::Open | OverlayPhase::Closed => Duration::ZERO,
+        }
+    }
+
+    /// Returns true while an enter or exit animation is playing.
+    pub fn is_transitioning(&self) -> bool {
+        matches!(self, OverlayPhase::Opening | OverlayPhase::Closing)
+    }
+
+    /// Overlay opacity at animation progress `delta` (0.0..=1.0) within this phase.
+    pub fn opacity(&self, delta: f32) -> f32 {
+        let delta = delta.clamp(0.0, 1.0);
+        match self {
+            OverlayPhase::Closed => 0.0,
+            OverlayPhase::Opening => delta,
+            OverlayPhase::Open => 1.0,
+            OverlayPhase::Closing => 1.0 - delta,
+        }
+    }
+}
+
+/// Open/close lifecycle for overlays (Dialog, Popover, Tooltip, Toast).
+///
+/// Transitions: `Closed → Opening → Open → Closing → Closed`. The owner calls
+/// [`open`](Self::open) / [`close`](Self::close) in response to user intent and
+/// [`finish_transition`](Self::finish_transition) once the phase's
+/// [`transition_duration`](OverlayPhase::transition_duration) has elapsed.
+///
+/// Dismissal while `Opening` goes straight to `Closing` so the exit animation
+/// starts immediately and the dismissal is never lost when the enter animation
+/// completes. Re-opening while `Closing` likewise reverses into `Opening`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OverlayStateMachine {
+    phase: OverlayPhase,
+}
+
+impl OverlayStateMachine {
+    /// A closed overlay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An overlay already settled in the `Open` phase (no enter animation).
+    pub fn opened() -> Self {
+        Self {
+            phase: OverlayPhase::Open,
+        }
+    }
+
+    /// An overlay settled in `Open` or `Closed` depending on `open`.
+    ///
+    /// Bridges components with a plain `open: bool` prop onto the state machine.
+    pub fn settled(open: bool) -> Self {
+        if open { Self::opened() } else { Self::new() }
+    }
+
+    /// The current phase.
+    pub fn phase(&self) -> OverlayPhase {
+        self.phase
+    }
+
+    /// Returns true if the overlay should be rendered (any phase except `Closed`).
+    pub fn is_visible(&self) -> bool {
+        self.phase != OverlayPhase::Closed
+    }
+
+    /// Returns true if the overlay is heading towards or settled in `Open`.
+    ///
+    /// Dismiss handlers and focus handling should only be active in this case;
+    /// a closing overlay ignores further input while its exit animation plays.
+    pub fn accepts_input(&self) -> bool {
+        matches!(self.phase, OverlayPhase::Opening | OverlayPhase::Open)
+    }
+
+    /// Request the overlay to open. Returns true if the phase changed.
+    pub fn open(&mut self) -> bool {
+        match self.phase {
+            OverlayPhase::Closed | OverlayPhase::Closing => {
+                self.phase = OverlayPhase::Opening;
+                true
+            }
+            OverlayPhase::Opening | OverlayPhase::Open => false,
+        }
+    }
+
+    /// Request the overlay to close. Returns true if the phase changed.
+    pub fn close(&mut self) -> bool {
+        match self.phase {
+            OverlayPhase::Opening | OverlayPhase::Open => {
+                self.phase = OverlayPhase::Closing;
+                true
+            }
+            OverlayPhase::Closed | OverlayPhase::Closing => false,
+        }
+    }
+
+    /// Toggle towards the opposite settled phase. Returns true if the phase changed.
+    pub fn toggle(&mut self) -> bool {
+        if self.accepts_input() {
+            self.close()
+        } else {
+            self.open()
+        }
+    }
+
+    /// Complete the current enter/exit animation.
+    ///
+    /// `Opening → Open`, `Closing → Closed`; settled phases are unchanged.
+    /// Returns true if the phase changed.
+    pub fn finish_transition(&mut self) -> bool {
+        let next = match self.phase {
+            OverlayPhase::Opening => OverlayPhase::Open,
+            OverlayPhase::Closing => OverlayPhase::Closed,
+            OverlayPhase::Open | OverlayPhase::Closed => return false,
+        };
+        self.phase = next;
+        true
+    }
+}
+
 /// Standard selection state.
 ///
 /// Used by Select (selected item), Tabs (active tab).
@@ -168,6 +475,61 @@ impl ValidationState {
     }
 }
 
+/// Contracts for the state helpers.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "ControlledState",
+            "state",
+            "Controlled or uncontrolled value storage for stateful components.",
+        )
+        .function("new() -> ControlledState<T>")
+        .function("value(self, value: T) -> Self")
+        .function("default_value(self, value: T) -> Self")
+        .function("on_change(self, handler) -> Self")
+        .function("resolve(&self, internal: Option<&T>) -> T")
+        .function("bind(self, id, window, cx) -> BoundState<T>")
+        .function("BoundState::set(&self, value: T, window, cx)")
+        .invariant("A controlled state always renders `value`; input only fires `on_change`.")
+        .invariant("Only uncontrolled states store committed values, keyed by element id.")
+        .invariant("`on_change` fires only when the committed value differs from the rendered one.")
+        .consumer("DataTable")
+        .consumer("Checkbox")
+        .consumer("FilePicker")
+        .consumer("Input")
+        .consumer("NumberInput")
+        .consumer("PasswordInput")
+        .consumer("Radio")
+        .consumer("SearchInput")
+        .consumer("Select")
+        .consumer("Slider")
+        .consumer("Switch")
+        .consumer("Tabs")
+        .consumer("Textarea"),
+        PrimitiveContract::new(
+            "OverlayStateMachine",
+            "state",
+            "Open/close lifecycle with enter and exit animation phases for overlays.",
+        )
+        .function("new() -> OverlayStateMachine")
+        .function("opened() -> OverlayStateMachine")
+        .function("settled(open: bool) -> OverlayStateMachine")
+        .function("open(&mut self) -> bool")
+        .function("close(&mut self) -> bool")
+        .function("toggle(&mut self) -> bool")
+        .function("finish_transition(&mut self) -> bool")
+        .function("is_visible(&self) -> bool")
+        .function("accepts_input(&self) -> bool")
+        .invariant("Phases cycle Closed, Opening, Open, Closing; Opening and Closing may reverse.")
+        .invariant("A dismissal during Opening is never lost: it goes straight to Closing.")
+        .invariant("Only Opening and Open accept input; every phase but Closed is visible.")
+        .consumer("Dialog")
+        .consumer("Popover")
+        .consumer("Toast")
+        .consumer("Tooltip"),
+    ]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,6 +575,126 @@ mod tests {
         assert!(s.is_closed());
     }
 
+    #[test]
+    fn controlled_state_resolution_order() {
+        let uncontrolled: ControlledState<i32> = ControlledState::new();
+        assert!(!uncontrolled.is_controlled());
+        assert_eq!(uncontrolled.resolve(None), 0);
+
+        let with_default = ControlledState::new().default_value(3);
+        assert_eq!(with_default.resolve(None), 3);
+        assert_eq!(
+            with_default.resolve(Some(&7)),
+            7,
+            "internal wins over default"
+        );
+
+        let controlled = ControlledState::new().default_value(3).value(5);
+        assert!(controlled.is_controlled());
+        assert_eq!(controlled.resolve(Some(&7)), 5, "value wins over internal");
+    }
+
+    #[test]
+    fn controlled_state_apply_only_stores_when_uncontrolled() {
+        let mut internal = None;
+        let uncontrolled: ControlledState<bool> = ControlledState::new();
+        assert!(uncontrolled.apply(&true, &mut internal));
+        assert_eq!(internal, Some(true));
+        assert!(!uncontrolled.apply(&true, &mut internal), "unchanged value");
+
+        let mut internal = None;
+        let controlled = ControlledState::new().value(false);
+        assert!(!controlled.apply(&true, &mut internal));
+        assert_eq!(internal, None);
+    }
+
+    #[test]
+    fn overlay_full_lifecycle() {
+        let mut s = OverlayStateMachine::new();
+        assert_eq!(s.phase(), OverlayPhase::Closed);
+        assert!(!s.is_visible());
+
+        assert!(s.open());
+        assert_eq!(s.phase(), OverlayPhase::Opening);
+        assert!(s.is_visible());
+        assert!(s.accepts_input());
+
+        assert!(s.finish_transition());
+        assert_eq!(s.phase(), OverlayPhase::Open);
+
+        assert!(s.close());
+        assert_eq!(s.phase(), OverlayPhase::Closing);
+        assert!(s.is_visible());
+        assert!(!s.accepts_input());
+
+        assert!(s.finish_transition());
+        assert_eq!(s.phase(), OverlayPhase::Closed);
+        assert!(!s.is_visible());
+    }
+
+    #[test]
+    fn overlay_dismiss_during_opening() {
+        let mut s = OverlayStateMachine::new();
+        s.open();
+        assert!(s.close());
+        assert_eq!(s.phase(), OverlayPhase::Closing);
+        // The pending enter animation completing must not re-open the overlay.
+        s.finish_transition();
+        assert_eq!(s.phase(), OverlayPhase::Closed);
+    }
+
+    #[test]
+    fn overlay_reopen_during_closing() {
+        let mut s = OverlayStateMachine::opened();
+        s.close();
+        assert!(s.open());
+        assert_eq!(s.phase(), OverlayPhase::Opening);
+        s.finish_transition();
+        assert_eq!(s.phase(), OverlayPhase::Open);
+    }
+
+    #[test]
+    fn overlay_redundant_requests_are_noops() {
+        let mut s = OverlayStateMachine::new();
+        assert!(!s.close());
+        assert!(!s.finish_transition());
+
+        let mut s = OverlayStateMachine::opened();
+        assert!(!s.open());
+        assert!(!s.finish_transition());
+        s.close();
+        assert!(!s.close());
+    }
+
+    #[test]
+    fn overlay_toggle_and_settled() {
+        let mut s = OverlayStateMachine::settled(false);
+        assert!(s.toggle());
+        assert_eq!(s.phase(), OverlayPhase::Opening);
+        assert!(s.toggle());
+        assert_eq!(s.phase(), OverlayPhase::Closing);
+        assert_eq!(
+            OverlayStateMachine::settled(true).phase(),
+            OverlayPhase::Open
+        );
+    }
+
+    #[test]
+    fn overlay_phase_animation() {
+        assert_eq!(OverlayPhase::Opening.opacity(0.25), 0.25);
+        assert_eq!(OverlayPhase::Closing.opacity(0.25), 0.75);
+        assert_eq!(OverlayPhase::Open.opacity(0.0), 1.0);
+        assert_eq!(OverlayPhase::Closed.opacity(1.0), 0.0);
+        assert_eq!(OverlayPhase::Opening.opacity(2.0), 1.0);
+        assert!(OverlayPhase::Opening.is_transitioning());
+        assert!(!OverlayPhase::Open.is_transitioning());
+        assert_eq!(OverlayPhase::Open.transition_duration(), Duration::ZERO);
+        assert_eq!(
+            OverlayPhase::Closing.transition_duration(),
+            OverlayPhase::EXIT_DURATION
+        );
+    }
+
     #[test]
     fn selection_state_toggle() {
         let mut s = SelectionState::Unselected;
@@ -0,0 +1,1098 @@
+//! Text editing primitive: an editable buffer with selection and IME composition.
+//!
+//! [`TextEditState`] is the platform-independent model: the content, the
+//! selection, and the marked range an input method is composing (the preedit).
+//! [`TextInput`] wraps it in an entity implementing GPUI's `EntityInputHandler`,
+//! so CJK and other input methods can compose, commit, and cancel text, and
+//! [`TextInputElement`] paints it with the preedit underlined, registering the
+//! input handler while focused.
+//!
+//! Offsets are UTF-8 byte offsets into the content. Platforms speak UTF-16;
+//! conversion happens at the `EntityInputHandler` boundary. A masked input
+//! (passwords) paints one mask character per content character, so painted
+//! offsets are converted back to content offsets when hit testing.
+//!
+//! Consumed by Input and Textarea.
+
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::{
+    App, Bounds, ClipboardItem, Context, Element, ElementId, ElementInputHandler, Entity,
+    EntityInputHandler, FocusHandle, Focusable, GlobalElementId, Hsla, InspectorElementId,
+    IntoElement, KeyDownEvent, LayoutId, PaintQuad, Pixels, Point, ShapedLine, SharedString, Style,
+    TextRun, UTF16Selection, UnderlineStyle, Window, fill, point, px, relative, size,
+};
+
+use crate::contract::PrimitiveContract;
+use crate::keyboard::keys;
+
+/// A change in an input method composition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompositionEvent {
+    /// The preedit text changed. The first update starts a composition.
+    Update(String),
+    /// The composition was accepted; its text is now part of the content.
+    Commit(String),
+    /// The composition was abandoned and its preedit text removed.
+    Cancel,
+}
+
+/// Editable text with a selection and an optional IME composition.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TextEditState {
+    text: String,
+    selected_range: Range<usize>,
+    selection_reversed: bool,
+    marked_range: Option<Range<usize>>,
+}
+
+impl TextEditState {
+    /// Create a state holding `text`, with the cursor at the end.
+    pub fn new(text: impl Into<String>) -> Self {
+        let mut state = Self::default();
+        state.set_text(text);
+        state
+    }
+
+    /// The content, including any preedit text.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The selected byte range; empty when there is only a cursor.
+    pub fn selected_range(&self) -> Range<usize> {
+        self.selected_range.clone()
+    }
+
+    /// The selected text.
+    pub fn selected_text(&self) -> &str {
+        &self.text[self.selected_range.clone()]
+    }
+
+    /// Whether the selection's head is at its start.
+    pub fn is_selection_reversed(&self) -> bool {
+        self.selection_reversed
+    }
+
+    /// The cursor: the head of the selection.
+    pub fn cursor(&self) -> usize {
+        if self.selection_reversed {
+            self.selected_range.start
+        } else {
+            self.selected_range.end
+        }
+    }
+
+    /// The byte range of the preedit text while composing.
+    pub fn marked_range(&self) -> Option<Range<usize>> {
+        self.marked_range.clone()
+    }
+
+    /// Returns true while an input method is composing.
+    pub fn is_composing(&self) -> bool {
+        self.marked_range.is_some()
+    }
+
+    /// The preedit text while composing.
+    pub fn preedit(&self) -> Option<&str> {
+        self.marked_range.clone().map(|range| &self.text[range])
+    }
+
+    /// Replace the whole content, e.g. when a controlled value changes.
+    ///
+    /// Drops any composition without an event and moves the cursor to the end.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+        self.marked_range = None;
+        self.collapse_to(self.text.len());
+    }
+
+    // -- Composition --
+
+    /// Replace `range` (by default the preedit, else the selection) with
+    /// `text` and put the cursor after it.
+    ///
+    /// This is how input methods insert plain and committed text, so it ends a
+    /// composition in progress with [`CompositionEvent::Commit`].
+    pub fn replace(&mut self, range: Option<Range<usize>>, text: &str) -> Option<CompositionEvent> {
+        let was_composing = self.is_composing();
+        let range = self.edit_range(range);
+        self.text.replace_range(range.clone(), text);
+        self.marked_range = None;
+        self.collapse_to(range.start + text.len());
+        was_composing.then(|| CompositionEvent::Commit(text.to_string()))
+    }
+
+    /// Replace `range` (by default the preedit, else the selection) with
+    /// preedit text and mark it.
+    ///
+    /// `selected` is relative to the preedit and places the input method's
+    /// cursor inside it; by default the cursor goes after it. Empty preedit
+    /// text ends the composition with [`CompositionEvent::Cancel`].
+    pub fn compose(
+        &mut self,
+        range: Option<Range<usize>>,
+        preedit: &str,
+        selected: Option<Range<usize>>,
+    ) -> Option<CompositionEvent> {
+        let was_composing = self.is_composing();
+        let range = self.edit_range(range);
+        self.text.replace_range(range.clone(), preedit);
+        if preedit.is_empty() {
+            self.marked_range = None;
+            self.collapse_to(range.start);
+            return was_composing.then_some(CompositionEvent::Cancel);
+        }
+        self.marked_range = Some(range.start..range.start + preedit.len());
+        let selected = selected.unwrap_or(preedit.len()..preedit.len());
+        let start = floor_char_boundary(preedit, selected.start);
+        let end = floor_char_boundary(preedit, selected.end.max(selected.start));
+        self.selected_range = range.start + start..range.start + end;
+        self.selection_reversed = false;
+        Some(CompositionEvent::Update(preedit.to_string()))
+    }
+
+    /// Accept the composition as typed, keeping its text.
+    pub fn commit_composition(&mut self) -> Option<CompositionEvent> {
+        let range = self.marked_range.take()?;
+        Some(CompositionEvent::Commit(self.text[range].to_string()))
+    }
+
+    /// Abandon the composition, removing its preedit text.
+    pub fn cancel_composition(&mut self) -> Option<CompositionEvent> {
+        let range = self.marked_range.take()?;
+        self.text.replace_range(range.clone(), "");
+        self.collapse_to(range.start);
+        Some(CompositionEvent::Cancel)
+    }
+
+    // -- Editing --
+
+    /// Insert `text` over the selection.
+    pub fn insert(&mut self, text: &str) {
+        self.replace(None, text);
+    }
+
+    /// Delete the selection, or the character before the cursor.
+    pub fn backspace(&mut self) {
+        if self.selected_range.is_empty() {
+            let cursor = self.cursor();
+            self.selected_range = self.previous_boundary(cursor)..cursor;
+        }
+        self.replace(None, "");
+    }
+
+    /// Delete the selection, or the character after the cursor.
+    pub fn delete(&mut self) {
+        if self.selected_range.is_empty() {
+            let cursor = self.cursor();
+            self.selected_range = cursor..self.next_boundary(cursor);
+        }
+        self.replace(None, "");
+    }
+
+    /// Move the cursor to `offset`, extending the selection if `extend`.
+    pub fn move_to(&mut self, offset: usize, extend: bool) {
+        let offset = floor_char_boundary(&self.text, offset);
+        if !extend {
+            self.collapse_to(offset);
+            return;
+        }
+        if self.selection_reversed {
+            self.selected_range.start = offset;
+        } else {
+            self.selected_range.end = offset;
+        }
+        if self.selected_range.end < self.selected_range.start {
+            self.selection_reversed = !self.selection_reversed;
+            self.selected_range = self.selected_range.end..self.selected_range.start;
+        }
+    }
+
+    /// Move one character left; without `extend`, a selection collapses to its start.
+    pub fn move_left(&mut self, extend: bool) {
+        if !extend && !self.selected_range.is_empty() {
+            self.collapse_to(self.selected_range.start);
+        } else {
+            self.move_to(self.previous_boundary(self.cursor()), extend);
+        }
+    }
+
+    /// Move one character right; without `extend`, a selection collapses to its end.
+    pub fn move_right(&mut self, extend: bool) {
+        if !extend && !self.selected_range.is_empty() {
+            self.collapse_to(self.selected_range.end);
+        } else {
+            self.move_to(self.next_boundary(self.cursor()), extend);
+        }
+    }
+
+    /// Move to the start of the cursor's line.
+    pub fn move_to_line_start(&mut self, extend: bool) {
+        let cursor = self.cursor();
+        let start = self.text[..cursor].rfind('\n').map_or(0, |ix| ix + 1);
+        self.move_to(start, extend);
+    }
+
+    /// Move to the end of the cursor's line.
+    pub fn move_to_line_end(&mut self, extend: bool) {
+        let cursor = self.cursor();
+        let end = self.text[cursor..]
+            .find('\n')
+            .map_or(self.text.len(), |ix| cursor + ix);
+        self.move_to(end, extend);
+    }
+
+    /// Select all text.
+    pub fn select_all(&mut self) {
+        self.selected_range = 0..self.text.len();
+        self.selection_reversed = false;
+    }
+
+    // -- UTF-16 --
+
+    /// Convert a byte offset to a UTF-16 offset.
+    pub fn offset_to_utf16(&self, offset: usize) -> usize {
+        utf8_to_utf16(&self.text, offset)
+    }
+
+    /// Convert a UTF-16 offset to a byte offset.
+    pub fn offset_from_utf16(&self, offset: usize) -> usize {
+        utf16_to_utf8(&self.text, offset)
+    }
+
+    /// Convert a byte range to a UTF-16 range.
+    pub fn range_to_utf16(&self, range: &Range<usize>) -> Range<usize> {
+        self.offset_to_utf16(range.start)..self.offset_to_utf16(range.end)
+    }
+
+    /// Convert a UTF-16 range to a byte range.
+    pub fn range_from_utf16(&self, range: &Range<usize>) -> Range<usize> {
+        self.offset_from_utf16(range.start)..self.offset_from_utf16(range.end)
+    }
+
+    fn collapse_to(&mut self, offset: usize) {
+        self.selected_range = offset..offset;
+        self.selection_reversed = false;
+    }
+
+    /// The range an edit applies to: `range`, else the preedit, else the selection.
+    fn edit_range(&self, range: Option<Range<usize>>) -> Range<usize> {
+        let range = range
+            .or_else(|| self.marked_range.clone())
+            .unwrap_or_else(|| self.selected_range.clone());
+        let start = floor_char_boundary(&self.text, range.start);
+        start..floor_char_boundary(&self.text, range.end).max(start)
+    }
+
+    fn previous_boundary(&self, offset: usize) -> usize {
+        self.text[..offset]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(ix, _)| ix)
+    }
+
+    fn next_boundary(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .chars()
+            .next()
+            .map_or(offset, |c| offset + c.len_utf8())
+    }
+}
+
+/// The largest char boundary in `text` at or before `offset`.
+fn floor_char_boundary(text: &str, offset: usize) -> usize {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    offset
+}
+
+fn utf8_to_utf16(text: &str, offset: usize) -> usize {
+    text[..floor_char_boundary(text, offset)]
+        .chars()
+        .map(char::len_utf16)
+        .sum()
+}
+
+fn utf16_to_utf8(text: &str, offset: usize) -> usize {
+    let mut utf16 = 0;
+    for (ix, c) in text.char_indices() {
+        if utf16 >= offset {
+            return ix;
+        }
+        utf16 += c.len_utf16();
+    }
+    text.len()
+}
+
+// ---------------------------------------------------------------------------
+// TextInput entity
+// ---------------------------------------------------------------------------
+
+/// Key context of a focused text field. Components set it on the element
+/// tracking the input's focus, so app-wide bindings on printable keys can
+/// step aside while the user types (see [`is_typing`]).
+pub const TEXT_INPUT_KEY_CONTEXT: &str = "TextInput";
+
+/// Returns true while focus is inside an element keyed with
+/// [`TEXT_INPUT_KEY_CONTEXT`].
+///
+/// An action bound to a printable key calls `cx.propagate()` when this is
+/// true, so the key is typed instead.
+pub fn is_typing(window: &Window) -> bool {
+    window
+        .context_stack()
+        .iter()
+        .any(|context| context.contains(TEXT_INPUT_KEY_CONTEXT))
+}
+
+/// Handler for committed text changes.
+pub type TextChangeHandler = Rc<dyn Fn(&str, &mut Window, &mut App)>;
+
+/// Handler for composition events.
+pub type CompositionHandler = Rc<dyn Fn(&CompositionEvent, &mut Window, &mut App)>;
+
+/// Shaped lines from the last paint, for hit testing and IME candidate placement.
+///
+/// Offsets are into the painted text, which differs from the content when masked.
+struct TextLayout {
+    bounds: Bounds<Pixels>,
+    line_height: Pixels,
+    /// Each line's starting byte offset and shaped text.
+    lines: Vec<(usize, ShapedLine)>,
+}
+
+impl TextLayout {
+    /// Position of `offset` relative to the text origin.
+    fn position_for_offset(&self, offset: usize) -> Point<Pixels> {
+        let row = self
+            .lines
+            .iter()
+            .rposition(|(start, _)| *start <= offset)
+            .unwrap_or(0);
+        let (start, line) = &self.lines[row];
+        point(
+            line.x_for_index(offset - start),
+            self.line_height * row as f32,
+        )
+    }
+
+    /// Byte offset closest to a window position.
+    fn offset_for_point(&self, position: Point<Pixels>) -> usize {
+        let local = position - self.bounds.origin;
+        let row = (local.y / self.line_height).floor().max(0.0) as usize;
+        let (start, line) = &self.lines[row.min(self.lines.len() - 1)];
+        start + line.closest_index_for_x(local.x)
+    }
+}
+
+/// [`TextEditState`] as an entity that platform input methods can drive.
+///
+/// Components keep one per element (keyed state), call [`TextInput::sync`]
+/// each render, route key-down events to [`TextInput::handle_key_down`], and
+/// paint it with [`TextInputElement`]. `on_change` fires with committed text
+/// only: preedit text is reported through `on_composition` until it commits.
+pub struct TextInput {
+    state: TextEditState,
+    focus_handle: FocusHandle,
+    multiline: bool,
+    editable: bool,
+    /// Character painted in place of each content character, e.g. for passwords.
+    mask: Option<char>,
+    /// The text last reported through, or synced from, the owner.
+    reported: String,
+    on_change: Option<TextChangeHandler>,
+    on_composition: Option<CompositionHandler>,
+    last_layout: Option<TextLayout>,
+}
+
+impl TextInput {
+    /// Create an empty, editable, single-line input.
+    pub fn new(cx: &mut App) -> Self {
+        Self {
+            state: TextEditState::default(),
+            focus_handle: cx.focus_handle(),
+            multiline: false,
+            editable: true,
+            mask: None,
+            reported: String::new(),
+            on_change: None,
+            on_composition: None,
+            last_layout: None,
+        }
+    }
+
+    /// Allow newlines (Enter inserts one; pasted newlines are kept).
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    /// The edit state.
+    pub fn state(&self) -> &TextEditState {
+        &self.state
+    }
+
+    /// Paint `mask` in place of each character, or the text itself with `None`.
+    ///
+    /// Masked text cannot be copied or cut.
+    pub fn set_mask(&mut self, mask: Option<char>, cx: &mut Context<Self>) {
+        if self.mask != mask {
+            self.mask = mask;
+            cx.notify();
+        }
+    }
+
+    /// Bring the input up to date with its owner for this frame.
+    ///
+    /// Adopts `value` unless it is what the input already reported or a
+    /// composition is in progress, so a controlled value never clobbers preedit text.
+    pub fn sync(
+        &mut self,
+        value: &str,
+        editable: bool,
+        on_change: Option<TextChangeHandler>,
+        on_composition: Option<CompositionHandler>,
+    ) {
+        if !self.state.is_composing() && self.reported != value {
+            self.reported = value.to_string();
+            if self.state.text() != value {
+                self.state.set_text(value);
+            }
+        }
+        self.editable = editable;
+        self.on_change = on_change;
+        self.on_composition = on_composition;
+    }
+
+    /// Handle editing and navigation keys. Returns true if the key was consumed.
+    ///
+    /// Printable text is not handled here: it arrives through the platform
+    /// input handler, which is what lets input methods compose it. While a
+    /// composition is in progress the input method owns the keyboard, except
+    /// Escape, which cancels the composition.
+    pub fn handle_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> bool {
+        let keystroke = &event.keystroke;
+        if self.state.is_composing() {
+            if keystroke.key != keys::ESCAPE {
+                return false;
+            }
+            let composition = self.state.cancel_composition();
+            self.after_edit(composition, window, cx);
+            return true;
+        }
+
+        let extend = keystroke.modifiers.shift;
+        let command = keystroke.modifiers.secondary();
+        let editable = self.editable;
+        match keystroke.key.as_str() {
+            keys::ARROW_LEFT => self.state.move_left(extend),
+            keys::ARROW_RIGHT => self.state.move_right(extend),
+            keys::HOME => self.state.move_to_line_start(extend),
+            keys::END => self.state.move_to_line_end(extend),
+            keys::BACKSPACE if editable => self.state.backspace(),
+            keys::DELETE if editable => self.state.delete(),
+            keys::ENTER if editable && self.multiline => self.state.insert("\n"),
+            "a" if command => self.state.select_all(),
+            "c" | "x"
+                if command && self.mask.is_none() && !self.state.selected_range().is_empty() =>
+            {
+                let selected = self.state.selected_text().to_string();
+                cx.write_to_clipboard(ClipboardItem::new_string(selected));
+                if keystroke.key == "x" && editable {
+                    self.state.insert("");
+                }
+            }
+            "v" if command && editable => {
+                let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+                    return true;
+                };
+                let text = self.accepted_text(&text);
+                self.state.insert(&text);
+            }
+            _ => return false,
+        }
+        self.after_edit(None, window, cx);
+        true
+    }
+
+    /// Move the cursor to the character nearest a window position, as on click.
+    pub fn move_to_point(&mut self, position: Point<Pixels>, extend: bool, cx: &mut Context<Self>) {
+        if self.state.is_composing() {
+            return;
+        }
+        if let Some(layout) = &self.last_layout {
+            let offset = self.content_offset(layout.offset_for_point(position));
+            self.state.move_to(offset, extend);
+            cx.notify();
+        }
+    }
+
+    /// The text as painted: the content, or one mask character per character.
+    fn painted_text(&self) -> String {
+        match self.mask {
+            Some(mask) => self.state.text().chars().map(|_| mask).collect(),
+            None => self.state.text().to_string(),
+        }
+    }
+
+    /// Convert a content offset to an offset into the painted text.
+    fn painted_offset(&self, offset: usize) -> usize {
+        match self.mask {
+            Some(mask) => self.state.text()[..offset].chars().count() * mask.len_utf8(),
+            None => offset,
+        }
+    }
+
+    /// Convert an offset into the painted text to a content offset.
+    fn content_offset(&self, painted: usize) -> usize {
+        match self.mask {
+            Some(mask) => self
+                .state
+                .text()
+                .char_indices()
+                .nth(painted / mask.len_utf8())
+                .map_or(self.state.text().len(), |(ix, _)| ix),
+            None => painted,
+        }
+    }
+
+    /// `text` as this input stores it: single-line inputs fold newlines to spaces.
+    fn accepted_text(&self, text: &str) -> String {
+        if self.multiline {
+            text.to_string()
+        } else {
+            text.replace(['\r', '\n'], " ")
+        }
+    }
+
+    /// Re-render, and report composition events and committed changes to the owner.
+    ///
+    /// Handlers run deferred, outside this entity's update, so they can read it.
+    fn after_edit(
+        &mut self,
+        composition: Option<CompositionEvent>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        cx.notify();
+        if let (Some(event), Some(handler)) = (composition, self.on_composition.clone()) {
+            window.defer(cx, move |window, cx| handler(&event, window, cx));
+        }
+        if !self.state.is_composing() && self.state.text() != self.reported {
+            self.reported = self.state.text().to_string();
+            if let Some(handler) = self.on_change.clone() {
+                let text = self.reported.clone();
+                window.defer(cx, move |window, cx| handler(&text, window, cx));
+            }
+        }
+    }
+}
+
+impl Focusable for TextInput {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl EntityInputHandler for TextInput {
+    fn text_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        adjusted_range: &mut Option<Range<usize>>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<String> {
+        let range = self.state.range_from_utf16(&range_utf16);
+        adjusted_range.replace(self.state.range_to_utf16(&range));
+        Some(self.state.text()[range].to_string())
+    }
+
+    fn selected_text_range(
+        &mut self,
+        _ignore_disabled_input: bool,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<UTF16Selection> {
+        Some(UTF16Selection {
+            range: self.state.range_to_utf16(&self.state.selected_range()),
+            reversed: self.state.is_selection_reversed(),
+        })
+    }
+
+    fn marked_text_range(
+        &self,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<Range<usize>> {
+        self.state
+            .marked_range()
+            .map(|range| self.state.range_to_utf16(&range))
+    }
+
+    fn unmark_text(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let composition = self.state.commit_composition();
+        self.after_edit(composition, window, cx);
+    }
+
+    fn replace_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        text: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.editable {
+            return;
+        }
+        let range = range_utf16.map(|range| self.state.range_from_utf16(&range));
+        let text = self.accepted_text(text);
+        let composition = self.state.replace(range, &text);
+        self.after_edit(composition, window, cx);
+    }
+
+    fn replace_and_mark_text_in_range(
+        &mut self,
+        range_utf16: Option<Range<usize>>,
+        preedit: &str,
+        selected_range_utf16: Option<Range<usize>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.editable {
+            return;
+        }
+        let range = range_utf16.map(|range| self.state.range_from_utf16(&range));
+        let selected = selected_range_utf16
+            .map(|range| utf16_to_utf8(preedit, range.start)..utf16_to_utf8(preedit, range.end));
+        let composition = self.state.compose(range, preedit, selected);
+        self.after_edit(composition, window, cx);
+    }
+
+    fn bounds_for_range(
+        &mut self,
+        range_utf16: Range<usize>,
+        element_bounds: Bounds<Pixels>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<Bounds<Pixels>> {
+        let layout = self.last_layout.as_ref()?;
+        let range = self.state.range_from_utf16(&range_utf16);
+        let start = layout.position_for_offset(self.painted_offset(range.start));
+        let end = layout.position_for_offset(self.painted_offset(range.end));
+        // Candidate windows anchor to the first line of a multi-line range.
+        let end_x = if end.y == start.y { end.x } else { start.x };
+        Some(Bounds::from_corners(
+            element_bounds.origin + start,
+            element_bounds.origin + point(end_x, start.y + layout.line_height),
+        ))
+    }
+
+    fn character_index_for_point(
+        &mut self,
+        position: Point<Pixels>,
+        _window: &mut Window,
+        _cx: &mut Context<Self>,
+    ) -> Option<usize> {
+        let layout = self.last_layout.as_ref()?;
+        let offset = self.content_offset(layout.offset_for_point(position));
+        Some(self.state.offset_to_utf16(offset))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// TextInputElement
+// ---------------------------------------------------------------------------
+
+/// Colors a [`TextInputElement`] paints with, resolved from theme tokens by
+/// the owning component.
+#[derive(Debug, Clone, Copy)]
+pub struct TextInputColors {
+    /// Text and preedit underline.
+    pub text: Hsla,
+    /// Placeholder text.
+    pub placeholder: Hsla,
+    /// Cursor.
+    pub cursor: Hsla,
+    /// Selection background.
+    pub selection: Hsla,
+}
+
+/// Paints a [`TextInput`]: one shaped line per text line, preedit text
+/// underlined, the selection, and the cursor while focused. Registers the
+/// input handler during paint, so the platform input method targets it.
+///
+/// Sizes itself to the full width and one line height per text line, in the
+/// inherited text style.
+pub struct TextInputElement {
+    input: Entity<TextInput>,
+    placeholder: SharedString,
+    colors: TextInputColors,
+}
+
+impl TextInputElement {
+    /// Paint `input`, showing `placeholder` while it is empty.
+    pub fn new(
+        input: Entity<TextInput>,
+        placeholder: impl Into<SharedString>,
+        colors: TextInputColors,
+    ) -> Self {
+        Self {
+            input,
+            placeholder: placeholder.into(),
+            colors,
+        }
+    }
+}
+
+/// Shaped text and decoration quads for one paint.
+pub struct TextInputPrepaint {
+    lines: Vec<(usize, ShapedLine)>,
+    placeholder: Option<ShapedLine>,
+    selections: Vec<PaintQuad>,
+    cursor: Option<PaintQuad>,
+}
+
+impl IntoElement for TextInputElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TextInputElement {
+    type RequestLayoutState = ();
+    type PrepaintState = TextInputPrepaint;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let line_count = self.input.read(cx).state.text().split('\n').count();
+        let mut style = Style::default();
+        style.size.width = relative(1.).into();
+        style.size.height = (window.line_height() * line_count as f32).into();
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let input = self.input.read(cx);
+        let state = &input.state;
+        let text_style = window.text_style();
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let line_height = window.line_height();
+        let run = TextRun {
+            len: 0,
+            font: text_style.font(),
+            color: self.colors.text,
+            background_color: None,
+            underline: None,
+            strikethrough: None,
+        };
+        let painted = |range: Range<usize>| {
+            input.painted_offset(range.start)..input.painted_offset(range.end)
+        };
+        let marked = state.marked_range().map(painted);
+        let text = input.painted_text();
+
+        let mut lines = Vec::new();
+        let mut start = 0;
+        for line in text.split('\n') {
+            let end = start + line.len();
+            let runs = line_runs(&run, start..end, marked.clone());
+            let shaped =
+                window
+                    .text_system()
+                    .shape_line(line.to_string().into(), font_size, &runs, None);
+            lines.push((start, shaped));
+            start = end + 1;
+        }
+
+        let placeholder = (state.text().is_empty() && !self.placeholder.is_empty()).then(|| {
+            let text = self.placeholder.clone();
+            let runs = [TextRun {
+                len: text.len(),
+                color: self.colors.placeholder,
+                ..run.clone()
+            }];
+            window
+                .text_system()
+                .shape_line(text, font_size, &runs, None)
+        });
+
+        let selected = painted(state.selected_range());
+        let mut selections = Vec::new();
+        for (row, (line_start, line)) in lines.iter().enumerate() {
+            let line_end = line_start + line.len();
+            let start = selected.start.max(*line_start);
+            // A selection running past the line end covers its newline.
+            let end = selected.end.min(line_end);
+            if start > end || (start == end && selected.end <= line_end) {
+                continue;
+            }
+            let right = if selected.end > line_end {
+                line.x_for_index(end - line_start) + px(4.0)
+            } else {
+                line.x_for_index(end - line_start)
+            };
+            let top = bounds.top() + line_height * row as f32;
+            selections.push(fill(
+                Bounds::from_corners(
+                    point(bounds.left() + line.x_for_index(start - line_start), top),
+                    point(bounds.left() + right, top + line_height),
+                ),
+                self.colors.selection,
+            ));
+        }
+
+        let cursor = (selected.is_empty() && input.focus_handle.is_focused(window)).then(|| {
+            let offset = input.painted_offset(state.cursor());
+            let row = lines
+                .iter()
+                .rposition(|(start, _)| *start <= offset)
+                .unwrap_or(0);
+            let (line_start, line) = &lines[row];
+            let x = line.x_for_index(offset - line_start);
+            fill(
+                Bounds::new(
+                    point(bounds.left() + x, bounds.top() + line_height * row as f32),
+                    size(px(1.5), line_height),
+                ),
+                self.colors.cursor,
+            )
+        });
+
+        TextInputPrepaint {
+            lines,
+            placeholder,
+            selections,
+            cursor,
+        }
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let focus_handle = self.input.read(cx).focus_handle.clone();
+        window.handle_input(
+            &focus_handle,
+            ElementInputHandler::new(bounds, self.input.clone()),
+            cx,
+        );
+
+        let line_height = window.line_height();
+        for selection in prepaint.selections.drain(..) {
+            window.paint_quad(selection);
+        }
+        if let Some(placeholder) = prepaint.placeholder.take() {
+            placeholder
+                .paint(bounds.origin, line_height, window, cx)
+                .ok();
+        }
+        for (row, (_, line)) in prepaint.lines.iter().enumerate() {
+            let origin = bounds.origin + point(px(0.0), line_height * row as f32);
+            line.paint(origin, line_height, window, cx).ok();
+        }
+        if let Some(cursor) = prepaint.cursor.take() {
+            window.paint_quad(cursor);
+        }
+
+        let lines = std::mem::take(&mut prepaint.lines);
+        self.input.update(cx, |input, _cx| {
+            input.last_layout = Some(TextLayout {
+                bounds,
+                line_height,
+                lines,
+            });
+        });
+    }
+}
+
+/// Text runs for the line spanning `line`, underlining its part of `marked`.
+fn line_runs(run: &TextRun, line: Range<usize>, marked: Option<Range<usize>>) -> Vec<TextRun> {
+    let with_len = |len: usize, underline: Option<UnderlineStyle>| TextRun {
+        len,
+        underline,
+        ..run.clone()
+    };
+    let Some(marked) = marked.filter(|m| m.start < line.end && m.end > line.start) else {
+        return vec![with_len(line.len(), None)];
+    };
+    let start = marked.start.max(line.start);
+    let end = marked.end.min(line.end);
+    let underline = UnderlineStyle {
+        color: Some(run.color),
+        thickness: px(1.0),
+        wavy: false,
+    };
+    [
+        with_len(start - line.start, None),
+        with_len(end - start, Some(underline)),
+        with_len(line.end - end, None),
+    ]
+    .into_iter()
+    .filter(|run| run.len > 0)
+    .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Contracts
+// ---------------------------------------------------------------------------
+
+/// Contracts for the text editing primitives.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "TextInput",
+            "text_edit",
+            "Editable text with selection and IME composition, driven by platform input methods.",
+        )
+        .function("TextEditState::replace(&mut self, range, text) -> Option<CompositionEvent>")
+        .function(
+            "TextEditState::compose(&mut self, range, preedit, selected) -> Option<CompositionEvent>",
+        )
+        .function("TextEditState::commit_composition(&mut self) -> Option<CompositionEvent>")
+        .function("TextEditState::cancel_composition(&mut self) -> Option<CompositionEvent>")
+        .function("TextInput::sync(&mut self, value, editable, on_change, on_composition)")
+        .function("TextInput::handle_key_down(&mut self, event, window, cx) -> bool")
+        .function("TextInput::set_mask(&mut self, mask, cx)")
+        .function("TextInputElement::new(input, placeholder, colors)")
+        .function("is_typing(window) -> bool")
+        .invariant("Preedit text is marked and painted underlined until it commits or cancels.")
+        .invariant("`on_change` reports committed text only, never preedit text.")
+        .invariant("A controlled value is not applied while a composition is in progress.")
+        .invariant("While composing, only Escape is handled (it cancels); other keys go to the IME.")
+        .invariant("Masked text is painted one mask character per character and cannot be copied.")
+        .invariant("The element tracking the input's focus carries the `TextInput` key context.")
+        .consumer("Input")
+        .consumer("NumberInput")
+        .consumer("PasswordInput")
+        .consumer("Textarea"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn composition_marks_preedit_and_commits() {
+        let mut state = TextEditState::new("ab");
+        state.move_left(false);
+
+        assert_eq!(
+            state.compose(None, "ni", None),
+            Some(CompositionEvent::Update("ni".into()))
+        );
+        assert_eq!(state.text(), "anib");
+        assert_eq!(state.preedit(), Some("ni"));
+        assert_eq!(state.cursor(), 3);
+
+        // The IME replaces the preedit as the user keeps typing.
+        assert_eq!(
+            state.compose(None, "你", Some(0..3)),
+            Some(CompositionEvent::Update("你".into()))
+        );
+        assert_eq!(state.text(), "a你b");
+        assert_eq!(state.selected_range(), 1..4);
+
+        assert_eq!(
+            state.replace(None, "你好"),
+            Some(CompositionEvent::Commit("你好".into()))
+        );
+        assert_eq!(state.text(), "a你好b");
+        assert!(!state.is_composing());
+        assert_eq!(state.cursor(), 7);
+    }
+
+    #[test]
+    fn composition_cancel_removes_preedit() {
+        let mut state = TextEditState::new("x");
+        state.compose(None, "ka", None);
+        assert_eq!(state.cancel_composition(), Some(CompositionEvent::Cancel));
+        assert_eq!(state.text(), "x");
+        assert_eq!(state.cursor(), 1);
+
+        // An empty preedit also ends the composition.
+        state.compose(None, "ka", None);
+        assert_eq!(
+            state.compose(None, "", None),
+            Some(CompositionEvent::Cancel)
+        );
+        assert_eq!(state.text(), "x");
+
+        // Unmarking keeps the preedit as typed.
+        state.compose(None, "か", None);
+        assert_eq!(
+            state.commit_composition(),
+            Some(CompositionEvent::Commit("か".into()))
+        );
+        assert_eq!(state.text(), "xか");
+        assert_eq!(state.cancel_composition(), None);
+    }
+
+    #[test]
+    fn plain_edits_do_not_report_composition() {
+        let mut state = TextEditState::new("héllo");
+        assert_eq!(state.replace(None, "!"), None);
+        state.backspace();
+        state.move_left(false);
+        state.move_left(false);
+        state.move_left(false);
+        state.move_left(true);
+        assert_eq!(state.selected_text(), "é");
+        state.delete();
+        assert_eq!(state.text(), "hllo");
+
+        state.select_all();
+        state.insert("line one\nline two");
+        state.move_to_line_start(false);
+        assert_eq!(state.cursor(), 9);
+        state.move_to_line_end(true);
+        assert_eq!(state.selected_text(), "line two");
+    }
+
+    #[test]
+    fn utf16_offsets_round_trip() {
+        let state = TextEditState::new("a😀b");
+        assert_eq!(state.offset_to_utf16(5), 3);
+        assert_eq!(state.offset_from_utf16(3), 5);
+        assert_eq!(state.range_from_utf16(&(1..3)), 1..5);
+        assert_eq!(state.offset_from_utf16(99), state.text().len());
+    }
+}
@@ -0,0 +1,498 @@
+//! Timing primitive: debounce and throttle helpers for rate-limited callbacks,
+//! and the app-wide [`MotionPreference`] that animated components consult.
+//!
+//! Used by query inputs (Combobox, SearchInput), token editor live-apply, and
+//! studio sidebar search filtering. The scheduling decisions are driven by a
+//! [`Clock`] so they can be tested deterministically with a [`MockClock`];
+//! the `schedule` helpers wire those decisions to GPUI background-executor timers.
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use gpui::{App, Context, Global, Task};
+
+use crate::contract::PrimitiveContract;
+
+/// Source of the current time for debounce/throttle decisions.
+pub trait Clock {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// Wall-clock time via `Instant::now()`. Used by default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Manually advanced clock for tests.
+///
+/// Clones share the same underlying time, so a test can hand one clone to a
+/// [`Debouncer`] or [`Throttler`] and advance the other.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Rc<Cell<Instant>>,
+}
+
+impl MockClock {
+    /// Create a mock clock starting at the current instant.
+    pub fn new() -> Self {
+        Self {
+            now: Rc::new(Cell::new(Instant::now())),
+        }
+    }
+
+    /// Move the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration);
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.now.get()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Debouncer
+// ---------------------------------------------------------------------------
+
+/// Delays a callback until calls have stopped arriving for `delay`.
+///
+/// Every call restarts the quiet period, so a burst of keystrokes produces a
+/// single callback once typing pauses.
+///
+/// # Usage
+/// ```ignore
+/// // In a view: self.query_debounce = Debouncer::new(Duration::from_millis(150));
+/// self.query_debounce.schedule(cx, |view, cx| view.apply_filter(cx));
+/// ```
+pub struct Debouncer<C: Clock = SystemClock> {
+    delay: Duration,
+    clock: C,
+    last_call: Option<Instant>,
+    task: Option<Task<()>>,
+}
+
+impl Debouncer {
+    /// Create a debouncer using the system clock.
+    pub fn new(delay: Duration) -> Self {
+        Self::with_clock(delay, SystemClock)
+    }
+}
+
+impl<C: Clock> Debouncer<C> {
+    /// Create a debouncer driven by the given clock.
+    pub fn with_clock(delay: Duration, clock: C) -> Self {
+        Self {
+            delay,
+            clock,
+            last_call: None,
+            task: None,
+        }
+    }
+
+    /// The quiet period required before the callback fires.
+    pub fn delay(&self) -> Duration {
+        self.delay
+    }
+
+    /// Record a call, restarting the quiet period.
+    pub fn call(&mut self) {
+        self.last_call = Some(self.clock.now());
+    }
+
+    /// Returns true if a call was recorded and its quiet period has not yet elapsed.
+    pub fn is_pending(&self) -> bool {
+        self.last_call
+            .is_some_and(|last| self.clock.now().duration_since(last) < self.delay)
+    }
+
+    /// Returns true exactly once after the quiet period following the last call.
+    ///
+    /// For callers that drive their own frame loop instead of using [`Self::schedule`].
+    pub fn poll(&mut self) -> bool {
+        match self.last_call {
+            Some(last) if self.clock.now().duration_since(last) >= self.delay => {
+                self.last_call = None;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Drop any pending call and cancel a scheduled callback.
+    pub fn cancel(&mut self) {
+        self.last_call = None;
+        self.task = None;
+    }
+
+    /// Record a call and schedule `callback` to run once the quiet period elapses.
+    ///
+    /// Replaces (and thereby cancels) any previously scheduled callback.
+    pub fn schedule<V: 'static>(
+        &mut self,
+        cx: &mut Context<V>,
+        callback: impl FnOnce(&mut V, &mut Context<V>) + 'static,
+    ) {
+        self.call();
+        let delay = self.delay;
+        self.task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(delay).await;
+            this.update(cx, |view, cx| callback(view, cx)).ok();
+        }));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Throttler
+// ---------------------------------------------------------------------------
+
+/// Limits a callback to at most once per `interval`.
+///
+/// The first call fires immediately (leading edge). Calls arriving inside the
+/// interval are coalesced into a single trailing call at the end of the window,
+/// so the last value is never lost.
+pub struct Throttler<C: Clock = SystemClock> {
+    interval: Duration,
+    clock: C,
+    /// Time of the most recent (or reserved upcoming) firing.
+    last_fire: Option<Instant>,
+    trailing_pending: bool,
+    task: Option<Task<()>>,
+}
+
+impl Throttler {
+    /// Create a throttler using the system clock.
+    pub fn new(interval: Duration) -> Self {
+        Self::with_clock(interval, SystemClock)
+    }
+}
+
+impl<C: Clock> Throttler<C> {
+    /// Create a throttler driven by the given clock.
+    pub fn with_clock(interval: Duration, clock: C) -> Self {
+        Self {
+            interval,
+            clock,
+            last_fire: None,
+            trailing_pending: false,
+            task: None,
+        }
+    }
+
+    /// The minimum spacing between callbacks.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Time remaining until the next call may fire (zero if it may fire now).
+    pub fn remaining(&self) -> Duration {
+        match self.last_fire {
+            Some(last) => (last + self.interval).saturating_duration_since(self.clock.now()),
+            None => Duration::ZERO,
+        }
+    }
+
+    /// Returns true if the call may fire now (leading edge).
+    ///
+    /// Otherwise the call is recorded as a pending trailing call, to be picked
+    /// up by [`Self::poll_trailing`] once the interval elapses.
+    pub fn try_fire(&mut self) -> bool {
+        if self.remaining().is_zero() {
+            self.last_fire = Some(self.clock.now());
+            self.trailing_pending = false;
+            true
+        } else {
+            self.trailing_pending = true;
+            false
+        }
+    }
+
+    /// Returns true once if a trailing call is pending and its window has opened.
+    pub fn poll_trailing(&mut self) -> bool {
+        if self.trailing_pending && self.remaining().is_zero() {
+            self.last_fire = Some(self.clock.now());
+            self.trailing_pending = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Drop any pending trailing call and cancel a scheduled callback.
+    pub fn cancel(&mut self) {
+        self.trailing_pending = false;
+        self.task = None;
+    }
+
+    /// Reserve the next firing slot and return how long until it opens.
+    ///
+    /// A call inside an already reserved future slot reuses it, so a burst of
+    /// calls coalesces into a single trailing firing.
+    fn reserve(&mut self) -> Duration {
+        let now = self.clock.now();
+        let slot = match self.last_fire {
+            Some(last) if last > now => last,
+            Some(last) => (last + self.interval).max(now),
+            None => now,
+        };
+        self.last_fire = Some(slot);
+        slot.saturating_duration_since(now)
+    }
+
+    /// Schedule `callback` at the next slot allowed by the interval.
+    ///
+    /// Fires on the next tick if the interval has elapsed; otherwise replaces any
+    /// pending trailing callback so only the latest one runs.
+    pub fn schedule<V: 'static>(
+        &mut self,
+        cx: &mut Context<V>,
+        callback: impl FnOnce(&mut V, &mut Context<V>) + 'static,
+    ) {
+        let delay = self.reserve();
+        let task = cx.spawn(async move |this, cx| {
+            if !delay.is_zero() {
+                cx.background_executor().timer(delay).await;
+            }
+            this.update(cx, |view, cx| callback(view, cx)).ok();
+        });
+        if delay.is_zero() {
+            task.detach();
+        } else {
+            self.task = Some(task);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MotionPreference
+// ---------------------------------------------------------------------------
+
+/// Whether components may animate. With reduced motion, transitions such as
+/// overlay fades, the Switch thumb, and the ProgressBar fill jump straight to
+/// their end state. Looping activity indicators (Spinner) keep running, since
+/// they carry meaning rather than decoration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MotionPreference {
+    /// Skip decorative transitions.
+    pub reduced: bool,
+}
+
+impl Global for MotionPreference {}
+
+impl MotionPreference {
+    /// The app-wide preference; full motion unless the app reduced it.
+    pub fn global(cx: &App) -> MotionPreference {
+        cx.try_global::<MotionPreference>()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Make this the app-wide preference. Views pick it up on their next render.
+    pub fn set_global(self, cx: &mut App) {
+        cx.set_global(self);
+    }
+
+    /// Whether a transition lasting `duration` should play.
+    pub fn animates(self, duration: Duration) -> bool {
+        !self.reduced && !duration.is_zero()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Contracts
+// ---------------------------------------------------------------------------
+
+/// Contracts for the timing primitives.
+pub fn contracts() -> Vec<PrimitiveContract> {
+    vec![
+        PrimitiveContract::new(
+            "Debouncer",
+            "timing",
+            "Delays a callback until calls stop arriving for a quiet period.",
+        )
+        .function("new(delay: Duration) -> Debouncer")
+        .function("with_clock(delay: Duration, clock: C) -> Debouncer<C>")
+        .function("call(&mut self)")
+        .function("is_pending(&self) -> bool")
+        .function("poll(&mut self) -> bool")
+        .function("cancel(&mut self)")
+        .function("schedule(&mut self, cx, callback)")
+        .invariant("Every call restarts the quiet period.")
+        .invariant("`poll` returns true exactly once per burst of calls.")
+        .invariant("Scheduling cancels any previously scheduled callback.")
+        .consumer("SearchInput"),
+        PrimitiveContract::new(
+            "Throttler",
+            "timing",
+            "Limits a callback to once per interval, keeping the trailing call.",
+        )
+        .function("new(interval: Duration) -> Throttler")
+        .function("with_clock(interval: Duration, clock: C) -> Throttler<C>")
+        .function("remaining(&self) -> Duration")
+        .function("try_fire(&mut self) -> bool")
+        .function("poll_trailing(&mut self) -> bool")
+        .function("cancel(&mut self)")
+        .function("schedule(&mut self, cx, callback)")
+        .invariant("The first call fires immediately (leading edge).")
+        .invariant("Calls inside the interval coalesce into one trailing call.")
+        .invariant("Two firings are never closer together than `interval`."),
+        PrimitiveContract::new(
+            "MotionPreference",
+            "timing",
+            "App-wide switch that turns decorative transitions off.",
+        )
+        .function("MotionPreference::global(cx) -> MotionPreference")
+        .function("MotionPreference::set_global(self, cx)")
+        .function("animates(self, duration: Duration) -> bool")
+        .invariant("Without an override, motion is not reduced.")
+        .invariant("Zero-length transitions never animate.")
+        .invariant("With reduced motion, transitions render their end state at once.")
+        .consumer("Dialog")
+        .consumer("Popover")
+        .consumer("ProgressBar")
+        .consumer("Switch")
+        .consumer("Toast")
+        .consumer("Tooltip"),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MS_100: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn reduced_motion_skips_transitions() {
+        let full = MotionPreference::default();
+        assert!(full.animates(MS_100));
+        assert!(!full.animates(Duration::ZERO));
+
+        let reduced = MotionPreference { reduced: true };
+        assert!(!reduced.animates(MS_100));
+    }
+
+    #[test]
+    fn debouncer_fires_after_quiet_period() {
+        let clock = MockClock::new();
+        let mut debouncer = Debouncer::with_clock(MS_100, clock.clone());
+
+        debouncer.call();
+        assert!(debouncer.is_pending());
+        assert!(!debouncer.poll());
+
+        clock.advance(MS_100);
+        assert!(!debouncer.is_pending());
+        assert!(debouncer.poll());
+        // Fires only once per burst.
+        assert!(!debouncer.poll());
+    }
+
+    #[test]
+    fn debouncer_call_restarts_quiet_period() {
+        let clock = MockClock::new();
+        let mut debouncer = Debouncer::with_clock(MS_100, clock.clone());
+
+        debouncer.call();
+        clock.advance(Duration::from_millis(60));
+        debouncer.call();
+        clock.advance(Duration::from_millis(60));
+        assert!(!debouncer.poll(), "second call should restart the delay");
+
+        clock.advance(Duration::from_millis(40));
+        assert!(debouncer.poll());
+    }
+
+    #[test]
+    fn debouncer_cancel_drops_pending() {
+        let clock = MockClock::new();
+        let mut debouncer = Debouncer::with_clock(MS_100, clock.clone());
+
+        debouncer.call();
+        debouncer.cancel();
+        clock.advance(MS_100);
+        assert!(!debouncer.poll());
+    }
+
+    #[test]
+    fn debouncer_idle_never_fires() {
+        let clock = MockClock::new();
+        let mut debouncer = Debouncer::with_clock(MS_100, clock.clone());
+        clock.advance(MS_100 * 5);
+        assert!(!debouncer.is_pending());
+        assert!(!debouncer.poll());
+    }
+
+    #[test]
+    fn throttler_leading_edge_fires_immediately() {
+        let clock = MockClock::new();
+        let mut throttler = Throttler::with_clock(MS_100, clock.clone());
+
+        assert!(throttler.try_fire());
+        assert!(!throttler.try_fire());
+        assert_eq!(throttler.remaining(), MS_100);
+
+        clock.advance(MS_100);
+        assert!(throttler.remaining().is_zero());
+    }
+
+    #[test]
+    fn throttler_trailing_call_fires_after_interval() {
+        let clock = MockClock::new();
+        let mut throttler = Throttler::with_clock(MS_100, clock.clone());
+
+        assert!(throttler.try_fire());
+        clock.advance(Duration::from_millis(30));
+        assert!(!throttler.try_fire());
+        assert!(!throttler.try_fire());
+        assert!(!throttler.poll_trailing());
+
+        clock.advance(Duration::from_millis(70));
+        assert!(throttler.poll_trailing());
+        // Coalesced: only one trailing firing.
+        assert!(!throttler.poll_trailing());
+    }
+
+    #[test]
+    fn throttler_cancel_drops_trailing() {
+        let clock = MockClock::new();
+        let mut throttler = Throttler::with_clock(MS_100, clock.clone());
+
+        assert!(throttler.try_fire());
+        assert!(!throttler.try_fire());
+        throttler.cancel();
+        clock.advance(MS_100);
+        assert!(!throttler.poll_trailing());
+    }
+
+    #[test]
+    fn throttler_reserve_coalesces_burst() {
+        let clock = MockClock::new();
+        let mut throttler = Throttler::with_clock(MS_100, clock.clone());
+
+        assert_eq!(throttler.reserve(), Duration::ZERO);
+        clock.advance(Duration::from_millis(20));
+        assert_eq!(throttler.reserve(), Duration::from_millis(80));
+        clock.advance(Duration::from_millis(20));
+        // Still inside the reserved trailing slot.
+        assert_eq!(throttler.reserve(), Duration::from_millis(60));
+
+        clock.advance(Duration::from_millis(60));
+        // Trailing slot has fired; next slot is one interval later.
+        assert_eq!(throttler.reserve(), MS_100);
+    }
+}
@@ -0,0 +1,330 @@
+//! Acceptance ledger (`gpui-acceptance.toml`) of signed-off checklist items.
+//!
+//! Some acceptance items follow from a component's contract and are checked
+//! automatically (see `ComponentContract::evaluated_checklist`); the rest,
+//! such as story coverage or interaction tests, need a person to vouch for
+//! them. `gpui accept` records who signed off which items, when, and at which
+//! commit, and [`readiness`] merges those sign-offs with the automatic items.
+
+use std::collections::BTreeMap;
+
+use components::AcceptanceChecklist;
+use serde::{Deserialize, Serialize};
+
+use crate::RegistryEntry;
+
+/// File name of the acceptance ledger, relative to the workbench root.
+pub const LEDGER_FILE: &str = "gpui-acceptance.toml";
+
+/// One signed-off checklist item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignOff {
+    /// Checklist item name (e.g. `"has_story_coverage"`).
+    pub item: String,
+    /// Who signed off.
+    pub by: String,
+    /// UTC date (`YYYY-MM-DD`) of the sign-off.
+    pub on: String,
+    /// Commit the sign-off was made against, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+/// Sign-offs recorded for one component.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentAcceptance {
+    /// Component name as published (e.g. `"Dialog"`).
+    pub name: String,
+    /// The latest sign-off per item, in the order items were first signed.
+    #[serde(default)]
+    pub sign_offs: Vec<SignOff>,
+}
+
+/// The `gpui-acceptance.toml` ledger.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AcceptanceLedger {
+    /// Sign-offs keyed by lowercase component name.
+    #[serde(default)]
+    pub components: BTreeMap<String, ComponentAcceptance>,
+}
+
+impl AcceptanceLedger {
+    /// Parse a ledger from TOML.
+    pub fn from_toml(toml_str: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml_str)
+    }
+
+    /// Serialize the ledger to TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Sign-offs for a component (case-insensitive), if any are recorded.
+    pub fn get(&self, name: &str) -> Option<&ComponentAcceptance> {
+        self.components.get(&name.to_lowercase())
+    }
+
+    /// The recorded sign-off for one item of a component.
+    pub fn sign_off(&self, name: &str, item: &str) -> Option<&SignOff> {
+        self.get(name)?
+            .sign_offs
+            .iter()
+            .find(|sign_off| sign_off.item == item)
+    }
+
+    /// Record `items` of `component` as signed off by `by`.
+    ///
+    /// A repeated sign-off of an item replaces the earlier one. Fails without
+    /// recording anything if an item is not an acceptance checklist item.
+    pub fn accept(
+        &mut self,
+        component: &str,
+        items: &[String],
+        by: &str,
+        on: &str,
+        commit: Option<&str>,
+    ) -> Result<(), UnknownItem> {
+        if let Some(unknown) = items
+            .iter()
+            .find(|item| !AcceptanceChecklist::ITEMS.contains(&item.as_str()))
+        {
+            return Err(UnknownItem(unknown.clone()));
+        }
+
+        let record = self
+            .components
+            .entry(component.to_lowercase())
+            .or_insert_with(|| ComponentAcceptance {
+                name: component.to_string(),
+                sign_offs: Vec::new(),
+            });
+        for item in items {
+            let sign_off = SignOff {
+                item: item.clone(),
+                by: by.to_string(),
+                on: on.to_string(),
+                commit: commit.map(str::to_string),
+            };
+            match record.sign_offs.iter_mut().find(|s| s.item == *item) {
+                Some(existing) => *existing = sign_off,
+                None => record.sign_offs.push(sign_off),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A sign-off named an item that is not on the acceptance checklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownItem(pub String);
+
+impl std::fmt::Display for UnknownItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Unknown acceptance item '{}'. Available: {}",
+            self.0,
+            AcceptanceChecklist::ITEMS.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownItem {}
+
+/// How a checklist item was satisfied, if at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemStatus {
+    /// Proven by the component's contract.
+    Auto,
+    /// Vouched for in the acceptance ledger.
+    SignedOff,
+    /// Neither proven nor signed off.
+    Pending,
+}
+
+/// One checklist item in a readiness report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessItem {
+    pub item: String,
+    pub status: ItemStatus,
+    /// The ledger entry, for signed-off items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sign_off: Option<SignOff>,
+}
+
+/// A component's acceptance checklist, merged from its contract and the ledger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Readiness {
+    pub component: String,
+    pub version: String,
+    /// Every checklist item, in checklist order.
+    pub items: Vec<ReadinessItem>,
+    /// Number of items that are not pending.
+    pub completed: usize,
+    /// Whether every item is checked.
+    pub ready: bool,
+}
+
+/// Merge a component's automatic checklist items with its ledger sign-offs.
+///
+/// Items the contract proves are reported as automatic even when also
+/// signed off.
+pub fn readiness(entry: &RegistryEntry, ledger: &AcceptanceLedger) -> Readiness {
+    let items: Vec<ReadinessItem> = AcceptanceChecklist::ITEMS
+        .iter()
+        .map(|&item| {
+            let sign_off = ledger.sign_off(&entry.name, item);
+            let status = if entry.acceptance_checklist.get(item) == Some(true) {
+                ItemStatus::Auto
+            } else if sign_off.is_some() {
+                ItemStatus::SignedOff
+            } else {
+                ItemStatus::Pending
+            };
+            ReadinessItem {
+                item: item.to_string(),
+                status,
+                sign_off: sign_off
+                    .filter(|_| status == ItemStatus::SignedOff)
+                    .cloned(),
+            }
+        })
+        .collect();
+
+    let completed = items
+        .iter()
+        .filter(|item| item.status != ItemStatus::Pending)
+        .count();
+    Readiness {
+        component: entry.name.clone(),
+        version: entry.version.clone(),
+        ready: completed == items.len(),
+        completed,
+        items,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+
+    fn items(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn accept_records_and_replaces_sign_offs() {
+        let mut ledger = AcceptanceLedger::default();
+        ledger
+            .accept(
+                "Dialog",
+                &items(&["has_story_coverage", "has_interaction_tests"]),
+                "ana",
+                "2026-01-02",
+                Some("abc1234"),
+            )
+            .unwrap();
+        ledger
+            .accept(
+                "dialog",
+                &items(&["has_story_coverage"]),
+                "ben",
+                "2026-01-03",
+                None,
+            )
+            .unwrap();
+
+        let dialog = ledger.get("DIALOG").unwrap();
+        assert_eq!(dialog.name, "Dialog");
+        assert_eq!(dialog.sign_offs.len(), 2);
+        let story = ledger.sign_off("Dialog", "has_story_coverage").unwrap();
+        assert_eq!((story.by.as_str(), story.commit.as_deref()), ("ben", None));
+        let tests = ledger.sign_off("Dialog", "has_interaction_tests").unwrap();
+        assert_eq!(tests.commit.as_deref(), Some("abc1234"));
+    }
+
+    #[test]
+    fn accept_rejects_unknown_items_without_recording() {
+        let mut ledger = AcceptanceLedger::default();
+        let error = ledger
+            .accept(
+                "Dialog",
+                &items(&["has_story_coverage", "is_pretty"]),
+                "ana",
+                "2026-01-02",
+                None,
+            )
+            .unwrap_err();
+        assert_eq!(error, UnknownItem("is_pretty".to_string()));
+        assert!(ledger.get("Dialog").is_none());
+    }
+
+    #[test]
+    fn readiness_merges_contract_and_ledger() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let mut ledger = AcceptanceLedger::default();
+        ledger
+            .accept(
+                "Dialog",
+                &items(&["has_story_coverage", "has_focus_behavior"]),
+                "ana",
+                "2026-01-02",
+                None,
+            )
+            .unwrap();
+
+        let report = readiness(entry, &ledger);
+        let status = |name: &str| {
+            report
+                .items
+                .iter()
+                .find(|item| item.item == name)
+                .unwrap()
+                .status
+        };
+        assert_eq!(report.items.len(), AcceptanceChecklist::ITEMS.len());
+        assert_eq!(status("has_focus_behavior"), ItemStatus::Auto);
+        assert_eq!(status("surfaces_mapped_to_tokens"), ItemStatus::Auto);
+        assert_eq!(status("has_story_coverage"), ItemStatus::SignedOff);
+        assert_eq!(status("has_interaction_tests"), ItemStatus::Pending);
+        assert!(!report.ready);
+        assert_eq!(report.completed, entry.acceptance_checklist.completed() + 1);
+
+        ledger
+            .accept(
+                "Dialog",
+                &items(&AcceptanceChecklist::ITEMS),
+                "ana",
+                "2026-01-03",
+                None,
+            )
+            .unwrap();
+        assert!(readiness(entry, &ledger).ready);
+    }
+
+    #[test]
+    fn ledger_toml_roundtrip() {
+        let mut ledger = AcceptanceLedger::default();
+        ledger
+            .accept(
+                "Tabs",
+                &items(&["has_provenance_metadata"]),
+                "ana",
+                "2026-01-02",
+                Some("abc1234"),
+            )
+            .unwrap();
+
+        let toml_str = ledger.to_toml().unwrap();
+        assert!(toml_str.contains("[components.tabs]"));
+        assert!(toml_str.contains("commit = \"abc1234\""));
+        assert_eq!(AcceptanceLedger::from_toml(&toml_str).unwrap(), ledger);
+    }
+}
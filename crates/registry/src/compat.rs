@@ -0,0 +1,171 @@
+//! CLI version compatibility, for `gpui self check`.
+//!
+//! A published registry index and a project's `gpui.toml` may each declare a
+//! `min_cli_version`: the oldest `gpui` that understands them. The CLI
+//! checks itself against both before acting -- commands that write to the
+//! project refuse to run on an older CLI, read-only ones warn -- and
+//! `gpui self check` reports the result with upgrade instructions.
+
+use serde::{Deserialize, Serialize};
+
+use crate::version::Version;
+
+/// Where a minimum CLI version was declared.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "location", rename_all = "snake_case")]
+pub enum RequirementSource {
+    /// A registry index; the built-in registry when `None`, else its URL.
+    Registry(Option<String>),
+    /// The project's `gpui.toml`.
+    Project(String),
+}
+
+impl std::fmt::Display for RequirementSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequirementSource::Registry(None) => f.write_str("the built-in registry"),
+            RequirementSource::Registry(Some(url)) => write!(f, "the registry at {url}"),
+            RequirementSource::Project(path) => f.write_str(path),
+        }
+    }
+}
+
+/// One declared minimum and whether the running CLI meets it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CliRequirement {
+    pub source: RequirementSource,
+    pub min_cli_version: String,
+    pub satisfied: bool,
+}
+
+/// The running CLI checked against every declared minimum.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CliCompatibility {
+    pub cli_version: String,
+    /// Declared minimums, in the order they were given.
+    pub requirements: Vec<CliRequirement>,
+}
+
+/// A `min_cli_version` that is not a `major.minor.patch` version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidMinVersion {
+    pub source: RequirementSource,
+    pub value: String,
+}
+
+impl std::fmt::Display for InvalidMinVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid min_cli_version '{}' in {} (expected e.g. 0.2.0)",
+            self.value, self.source
+        )
+    }
+}
+
+impl std::error::Error for InvalidMinVersion {}
+
+impl CliCompatibility {
+    /// Check `cli_version` against each declared minimum.
+    pub fn check(
+        cli_version: &str,
+        minimums: impl IntoIterator<Item = (RequirementSource, String)>,
+    ) -> Result<Self, InvalidMinVersion> {
+        let current = Version::parse(cli_version);
+        let requirements = minimums
+            .into_iter()
+            .map(|(source, value)| {
+                let Some(minimum) = Version::parse(&value) else {
+                    return Err(InvalidMinVersion { source, value });
+                };
+                Ok(CliRequirement {
+                    source,
+                    satisfied: current.is_some_and(|current| current >= minimum),
+                    min_cli_version: minimum.to_string(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            cli_version: cli_version.to_string(),
+            requirements,
+        })
+    }
+
+    /// Whether the CLI meets every declared minimum.
+    pub fn is_compatible(&self) -> bool {
+        self.requirements.iter().all(|r| r.satisfied)
+    }
+
+    /// Declared minimums the CLI does not meet.
+    pub fn unmet(&self) -> impl Iterator<Item = &CliRequirement> {
+        self.requirements.iter().filter(|r| !r.satisfied)
+    }
+
+    /// The version to upgrade to: the highest unmet minimum.
+    pub fn required_version(&self) -> Option<Version> {
+        self.unmet()
+            .filter_map(|r| Version::parse(&r.min_cli_version))
+            .max()
+    }
+
+    /// How to upgrade to [`required_version`](Self::required_version), or
+    /// `None` when the CLI is compatible.
+    pub fn upgrade_instructions(&self) -> Option<String> {
+        let version = self.required_version()?;
+        Some(format!(
+            "Upgrade to gpui {version} or later with \
+             `cargo install gpui-cli --force --version '>={version}'`, \
+             or from a workbench checkout with `cargo install --path apps/cli --force`."
+        ))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project() -> RequirementSource {
+        RequirementSource::Project("gpui.toml".into())
+    }
+
+    #[test]
+    fn older_cli_fails_the_highest_unmet_minimum() {
+        let registry = RequirementSource::Registry(Some("https://example.com/r".into()));
+        let check = CliCompatibility::check(
+            "0.1.0",
+            [
+                (registry, "0.3".to_string()),
+                (project(), "0.2.1".to_string()),
+            ],
+        )
+        .unwrap();
+        assert!(!check.is_compatible());
+        assert_eq!(check.requirements[0].min_cli_version, "0.3.0");
+        assert_eq!(check.unmet().count(), 2);
+        assert_eq!(check.required_version(), Version::parse("0.3.0"));
+        let instructions = check.upgrade_instructions().unwrap();
+        assert!(instructions.starts_with("Upgrade to gpui 0.3.0 or later"));
+        assert!(instructions.contains("--version '>=0.3.0'"));
+
+        let check = CliCompatibility::check("0.3.0", [(project(), "0.3.0".to_string())]).unwrap();
+        assert!(check.is_compatible());
+        assert_eq!(check.upgrade_instructions(), None);
+        assert!(
+            CliCompatibility::check("0.1.0", [])
+                .unwrap()
+                .is_compatible()
+        );
+    }
+
+    #[test]
+    fn malformed_minimum_is_an_error() {
+        let error =
+            CliCompatibility::check("0.1.0", [(project(), "soon".to_string())]).unwrap_err();
+        assert_eq!(error.value, "soon");
+        assert!(error.to_string().contains("in gpui.toml"));
+    }
+}
@@ -0,0 +1,386 @@
+//! Project configuration (`gpui.toml`) for target applications.
+//!
+//! Records per-component decisions that outlive a single plan, such as
+//! ejecting a component so it is owned locally instead of tracked against
+//! the registry, the workbench studio's preferences in `[studio]`, the
+//! team's sourcing rules in `[policy]`, the oldest CLI that may change
+//! the project in `min_cli_version`, and `protected`, which keeps
+//! experimental components out of the project.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use components::Stability;
+use serde::{Deserialize, Serialize};
+
+use crate::policy::DispositionPolicy;
+
+/// File name of the project configuration, relative to the project root.
+pub const CONFIG_FILE: &str = "gpui.toml";
+
+/// Who owns an installed component's source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Ownership {
+    /// Tracked against the registry: drift is reported and updates apply.
+    #[default]
+    Registry,
+    /// Ejected: the project owns the source and registry checks skip it.
+    Local,
+}
+
+/// Per-component settings in `gpui.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentConfig {
+    /// Who owns the installed source.
+    #[serde(default)]
+    pub ownership: Ownership,
+    /// Registry version the component was ejected from, if ejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ejected_from: Option<String>,
+}
+
+/// Locale used when none is configured.
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// Which theme the studio starts in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeMode {
+    /// Follow the operating system's light or dark appearance.
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+impl ThemeMode {
+    /// Every mode, in settings order.
+    pub const ALL: [ThemeMode; 3] = [Self::System, Self::Light, Self::Dark];
+
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::System => "System",
+            Self::Light => "Light",
+            Self::Dark => "Dark",
+        }
+    }
+}
+
+/// How tightly the studio packs its sidebar and story area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Density {
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    /// Every density, tightest first.
+    pub const ALL: [Density; 3] = [Self::Compact, Self::Comfortable, Self::Spacious];
+
+    /// Display name.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Compact => "Compact",
+            Self::Comfortable => "Comfortable",
+            Self::Spacious => "Spacious",
+        }
+    }
+}
+
+/// Studio preferences in the `[studio]` table of `gpui.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StudioConfig {
+    /// Theme the studio starts in.
+    #[serde(default)]
+    pub theme_mode: ThemeMode,
+    /// Spacing of the studio's own chrome.
+    #[serde(default)]
+    pub density: Density,
+    /// Skip decorative transitions in components.
+    #[serde(default)]
+    pub reduce_motion: bool,
+    /// Keep closed toasts in the notification history. The history only
+    /// ever lives in memory on this machine; nothing is reported anywhere.
+    #[serde(default = "default_true")]
+    pub keep_history: bool,
+    /// Directory of captured story snapshots, used by `gpui docs site` when
+    /// `--snapshots` is not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot_dir: Option<PathBuf>,
+    /// Locale tag (e.g. `"de-DE"`) for formatted values and translated
+    /// contract descriptions in the studio; also the language of
+    /// `gpui docs site` when `--locale` is not given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Keystrokes replacing a command's default binding, keyed by command
+    /// name (e.g. `toggle_inspect = "ctrl-i"`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub keybindings: BTreeMap<String, String>,
+    /// Whether the first-run tour was finished or skipped, so it does not
+    /// open at launch again.
+    #[serde(default)]
+    pub tour_completed: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Default for StudioConfig {
+    fn default() -> Self {
+        Self {
+            theme_mode: ThemeMode::default(),
+            density: Density::default(),
+            reduce_motion: false,
+            keep_history: true,
+            snapshot_dir: None,
+            locale: None,
+            keybindings: BTreeMap::new(),
+            tour_completed: false,
+        }
+    }
+}
+
+impl StudioConfig {
+    /// Whether every preference is at its default, so `[studio]` can be omitted.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// The configured locale tag, or [`DEFAULT_LOCALE`].
+    pub fn locale(&self) -> &str {
+        self.locale
+            .as_deref()
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .unwrap_or(DEFAULT_LOCALE)
+    }
+
+    /// The keystrokes overriding `command`'s default binding, if any.
+    pub fn keybinding(&self, command: &str) -> Option<&str> {
+        self.keybindings
+            .get(command)
+            .map(|keys| keys.trim())
+            .filter(|keys| !keys.is_empty())
+    }
+
+    /// Override `command`'s binding with `keys`, or restore its default when
+    /// `keys` is blank.
+    pub fn set_keybinding(&mut self, command: &str, keys: &str) {
+        if keys.trim().is_empty() {
+            self.keybindings.remove(command);
+        } else {
+            self.keybindings
+                .insert(command.to_string(), keys.to_string());
+        }
+    }
+}
+
+/// The `gpui.toml` project configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectConfig {
+    /// Oldest `gpui` CLI that may change this project; commands that write
+    /// to it refuse to run on an older one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_cli_version: Option<String>,
+    /// Whether the project refuses experimental components: installing one
+    /// fails instead of warning.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub protected: bool,
+    /// Component settings keyed by lowercase component name.
+    #[serde(default)]
+    pub components: BTreeMap<String, ComponentConfig>,
+    /// Workbench studio preferences.
+    #[serde(default, skip_serializing_if = "StudioConfig::is_default")]
+    pub studio: StudioConfig,
+    /// Sourcing rules checked by `gpui verify`.
+    #[serde(default, skip_serializing_if = "DispositionPolicy::is_empty")]
+    pub policy: DispositionPolicy,
+}
+
+impl ProjectConfig {
+    /// Parse a configuration from TOML.
+    pub fn from_toml(toml_str: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml_str)
+    }
+
+    /// Serialize the configuration to TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// Settings for a component (case-insensitive), if any are recorded.
+    pub fn component(&self, name: &str) -> Option<&ComponentConfig> {
+        self.components.get(&name.to_lowercase())
+    }
+
+    /// Whether a component has been ejected and is owned by the project.
+    pub fn is_locally_owned(&self, name: &str) -> bool {
+        self.component(name)
+            .is_some_and(|c| c.ownership == Ownership::Local)
+    }
+
+    /// Mark a component as locally owned, recording the version it left the registry at.
+    ///
+    /// Returns `false` if the component was already ejected.
+    pub fn eject(&mut self, name: &str, version: &str) -> bool {
+        let config = self.components.entry(name.to_lowercase()).or_default();
+        if config.ownership == Ownership::Local {
+            return false;
+        }
+        config.ownership = Ownership::Local;
+        config.ejected_from = Some(version.to_string());
+        true
+    }
+
+    /// Whether installing a component of this stability is refused.
+    pub fn refuses(&self, stability: Stability) -> bool {
+        self.protected && stability == Stability::Experimental
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_config_parses() {
+        let config = ProjectConfig::from_toml("").unwrap();
+        assert!(config.components.is_empty());
+        assert!(!config.is_locally_owned("Dialog"));
+    }
+
+    #[test]
+    fn eject_marks_component_locally_owned() {
+        let mut config = ProjectConfig::default();
+
+        assert!(config.eject("Dialog", "0.1.0"));
+        assert!(!config.eject("dialog", "0.1.0"), "second eject is a no-op");
+        assert!(config.is_locally_owned("DIALOG"));
+        assert_eq!(
+            config.component("dialog").unwrap().ejected_from.as_deref(),
+            Some("0.1.0")
+        );
+    }
+
+    #[test]
+    fn config_toml_roundtrip() {
+        let mut config = ProjectConfig::default();
+        config.eject("Tabs", "0.1.0");
+
+        let toml_str = config.to_toml().unwrap();
+        assert!(toml_str.contains("[components.tabs]"));
+        assert!(toml_str.contains("ownership = \"local\""));
+        assert_eq!(ProjectConfig::from_toml(&toml_str).unwrap(), config);
+    }
+
+    #[test]
+    fn studio_table_is_omitted_until_changed() {
+        let config = ProjectConfig::from_toml("").unwrap();
+        assert!(config.studio.is_default());
+        assert!(config.studio.keep_history);
+        assert!(!config.studio.tour_completed);
+        assert_eq!(config.studio.locale(), DEFAULT_LOCALE);
+        assert!(!config.to_toml().unwrap().contains("[studio]"));
+    }
+
+    #[test]
+    fn studio_config_toml_roundtrip() {
+        let mut config = ProjectConfig::default();
+        config.eject("Tabs", "0.1.0");
+        config.studio.theme_mode = ThemeMode::Dark;
+        config.studio.density = Density::Compact;
+        config.studio.reduce_motion = true;
+        config.studio.keep_history = false;
+        config.studio.snapshot_dir = Some(PathBuf::from("snapshots"));
+        config.studio.locale = Some("de-DE".to_string());
+        config.studio.set_keybinding("toggle_inspect", "ctrl-i");
+        config.studio.tour_completed = true;
+
+        let toml_str = config.to_toml().unwrap();
+        assert!(toml_str.contains("[studio]"));
+        assert!(toml_str.contains("theme_mode = \"dark\""));
+        assert!(toml_str.contains("toggle_inspect = \"ctrl-i\""));
+        assert!(toml_str.contains("locale = \"de-DE\""));
+        assert!(toml_str.contains("tour_completed = true"));
+        assert_eq!(ProjectConfig::from_toml(&toml_str).unwrap(), config);
+    }
+
+    #[test]
+    fn policy_table_parses_rules() {
+        let config = ProjectConfig::from_toml(
+            r#"
+            [[policy.rules]]
+            disposition = "fork"
+            require_pinned_commit = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.policy.rules.len(), 1);
+        assert_eq!(
+            config.policy.rules[0].disposition,
+            Some(components::Disposition::Fork)
+        );
+        assert!(config.policy.rules[0].require_pinned_commit);
+        assert_eq!(
+            ProjectConfig::from_toml(&config.to_toml().unwrap()).unwrap(),
+            config
+        );
+        assert!(
+            !ProjectConfig::default()
+                .to_toml()
+                .unwrap()
+                .contains("policy")
+        );
+    }
+
+    #[test]
+    fn min_cli_version_is_read_from_the_top_level() {
+        let config = ProjectConfig::from_toml("min_cli_version = \"0.2.0\"\n").unwrap();
+        assert_eq!(config.min_cli_version.as_deref(), Some("0.2.0"));
+        assert_eq!(
+            ProjectConfig::from_toml(&config.to_toml().unwrap()).unwrap(),
+            config
+        );
+        assert!(ProjectConfig::default().min_cli_version.is_none());
+    }
+
+    #[test]
+    fn protected_projects_refuse_experimental_components() {
+        let config = ProjectConfig::from_toml("protected = true\n").unwrap();
+        assert!(config.refuses(Stability::Experimental));
+        assert!(!config.refuses(Stability::Beta));
+        assert!(!config.refuses(Stability::Deprecated));
+        assert!(!ProjectConfig::default().refuses(Stability::Experimental));
+        assert!(
+            !ProjectConfig::default()
+                .to_toml()
+                .unwrap()
+                .contains("protected")
+        );
+        assert_eq!(
+            ProjectConfig::from_toml(&config.to_toml().unwrap()).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    fn blank_keybinding_restores_default() {
+        let mut studio = StudioConfig::default();
+        studio.set_keybinding("copy_link", "ctrl-shift-l");
+        assert_eq!(studio.keybinding("copy_link"), Some("ctrl-shift-l"));
+
+        studio.set_keybinding("copy_link", "  ");
+        assert_eq!(studio.keybinding("copy_link"), None);
+        assert!(studio.is_default());
+    }
+}
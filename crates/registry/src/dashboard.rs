@@ -0,0 +1,326 @@
+//! Release readiness: a red/yellow/green status per component and gate.
+//!
+//! The studio's Dashboard view shows one row per registry component with a
+//! badge for each release gate:
+//!
+//! - **Contract** -- `ComponentContract::validate()` passes.
+//! - **Stories** -- how many of the contract's states the component's story renders.
+//! - **Acceptance** -- checklist items proven by the contract or signed off
+//!   in the [acceptance ledger](crate::acceptance).
+//! - **Perf** -- whether release-mode evidence fits the contract's perf
+//!   budget, and how recently it was measured.
+//! - **Drift** -- the installed version against the registry, for components
+//!   recorded in the project manifest.
+
+use std::collections::BTreeMap;
+
+use components::{ComponentContract, ComponentState};
+use serde::{Deserialize, Serialize};
+
+use crate::acceptance::{AcceptanceLedger, readiness};
+use crate::manifest::{Manifest, days_since_epoch};
+use crate::migration::is_breaking_change;
+use crate::{RegistryIndex, all_contracts};
+
+/// Perf evidence older than this many days is stale.
+pub const PERF_EVIDENCE_MAX_AGE_DAYS: u64 = 90;
+
+/// Traffic-light status of a gate, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Badge {
+    Green,
+    Yellow,
+    Red,
+}
+
+/// A gate's badge and a short explanation of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GateStatus {
+    pub badge: Badge,
+    pub summary: String,
+}
+
+impl GateStatus {
+    fn new(badge: Badge, summary: impl Into<String>) -> Self {
+        Self {
+            badge,
+            summary: summary.into(),
+        }
+    }
+}
+
+/// Release readiness of one component.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentStatus {
+    pub component: String,
+    pub version: String,
+    pub contract: GateStatus,
+    pub stories: GateStatus,
+    /// Percentage of the contract's states the story renders.
+    pub story_coverage: u8,
+    pub acceptance: GateStatus,
+    pub perf: GateStatus,
+    /// `None` when the component is not installed in the project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub drift: Option<GateStatus>,
+}
+
+impl ComponentStatus {
+    /// The worst badge across all gates.
+    pub fn overall(&self) -> Badge {
+        [&self.contract, &self.stories, &self.acceptance, &self.perf]
+            .into_iter()
+            .chain(&self.drift)
+            .map(|gate| gate.badge)
+            .max()
+            .unwrap_or(Badge::Green)
+    }
+}
+
+/// Release readiness of every registry component, ordered by name.
+///
+/// `story_states` maps lowercase component names to the states their story
+/// renders; components without an entry have no story. `today` is a
+/// `YYYY-MM-DD` date used to age perf evidence.
+pub fn dashboard(
+    story_states: &BTreeMap<String, Vec<ComponentState>>,
+    ledger: &AcceptanceLedger,
+    manifest: &Manifest,
+    today: &str,
+) -> Vec<ComponentStatus> {
+    let contracts = all_contracts();
+    let mut index = RegistryIndex::new();
+    for contract in &contracts {
+        index.register(contract);
+    }
+
+    let mut statuses: Vec<ComponentStatus> = contracts
+        .iter()
+        .map(|contract| {
+            let stories = story_states.get(&contract.name.to_lowercase());
+            let (stories, story_coverage) = story_status(contract, stories);
+            let acceptance = index.get(&contract.name).map(|entry| {
+                let report = readiness(entry, ledger);
+                (report.completed, report.items.len())
+            });
+            ComponentStatus {
+                component: contract.name.clone(),
+                version: contract.version.clone(),
+                contract: contract_status(contract),
+                stories,
+                story_coverage,
+                acceptance: acceptance_status(acceptance.unwrap_or_default()),
+                perf: perf_status(contract, today),
+                drift: manifest
+                    .get(&contract.name)
+                    .map(|installed| drift_status(&installed.version, &contract.version)),
+            }
+        })
+        .collect();
+    statuses.sort_by(|a, b| a.component.cmp(&b.component));
+    statuses
+}
+
+fn contract_status(contract: &ComponentContract) -> GateStatus {
+    match contract.validate().as_slice() {
+        [] => GateStatus::new(Badge::Green, "Valid"),
+        [error] => GateStatus::new(Badge::Red, format!("{}: {}", error.field, error.message)),
+        errors => GateStatus::new(Badge::Red, format!("{} validation errors", errors.len())),
+    }
+}
+
+fn story_status(
+    contract: &ComponentContract,
+    story_states: Option<&Vec<ComponentState>>,
+) -> (GateStatus, u8) {
+    let Some(story_states) = story_states else {
+        return (GateStatus::new(Badge::Red, "No story"), 0);
+    };
+    let total = contract.states.len();
+    let covered = contract
+        .states
+        .iter()
+        .filter(|state| story_states.contains(state))
+        .count();
+    let percent = (covered * 100).checked_div(total).unwrap_or(100) as u8;
+    let badge = if covered == total {
+        Badge::Green
+    } else {
+        Badge::Yellow
+    };
+    (
+        GateStatus::new(badge, format!("{covered}/{total} states")),
+        percent,
+    )
+}
+
+fn acceptance_status((completed, total): (usize, usize)) -> GateStatus {
+    let badge = if completed == total {
+        Badge::Green
+    } else if completed * 2 >= total {
+        Badge::Yellow
+    } else {
+        Badge::Red
+    };
+    GateStatus::new(badge, format!("{completed}/{total} items"))
+}
+
+fn perf_status(contract: &ComponentContract, today: &str) -> GateStatus {
+    let Some(evidence) = &contract.perf_evidence else {
+        return GateStatus::new(Badge::Red, "No evidence");
+    };
+    if let Some(overrun) = contract.budget_overruns().first() {
+        return GateStatus::new(
+            Badge::Red,
+            format!(
+                "{}: {}ms over {}ms budget",
+                overrun.limit, overrun.measured_ms, overrun.budget_ms
+            ),
+        );
+    }
+    if let Some(limit) = contract
+        .perf_budget
+        .as_ref()
+        .and_then(|budget| budget.unmeasured(Some(evidence)).first().copied())
+    {
+        return GateStatus::new(Badge::Yellow, format!("{limit} not measured"));
+    }
+    let Some(measured_on) = &evidence.measured_on else {
+        return GateStatus::new(Badge::Yellow, "Undated evidence");
+    };
+    let age = days_since_epoch(today)
+        .zip(days_since_epoch(measured_on))
+        .map(|(today, measured)| today.saturating_sub(measured));
+    match age {
+        Some(days) if days <= PERF_EVIDENCE_MAX_AGE_DAYS => {
+            GateStatus::new(Badge::Green, format!("Measured {measured_on}"))
+        }
+        Some(days) => GateStatus::new(Badge::Yellow, format!("Measured {days} days ago")),
+        None => GateStatus::new(Badge::Yellow, format!("Unreadable date '{measured_on}'")),
+    }
+}
+
+fn drift_status(installed: &str, registry: &str) -> GateStatus {
+    if installed == registry {
+        GateStatus::new(Badge::Green, format!("Up to date (v{installed})"))
+    } else if is_breaking_change(installed, registry) {
+        GateStatus::new(
+            Badge::Red,
+            format!("v{installed} -> v{registry} (breaking)"),
+        )
+    } else {
+        GateStatus::new(Badge::Yellow, format!("v{installed} -> v{registry}"))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::ManifestEntry;
+    use components::{PerfBudget, PerfEvidence};
+
+    fn contract_with_evidence(measured_on: Option<&str>) -> ComponentContract {
+        ComponentContract::builder("Gauge", "1.0.0")
+            .required_prop("value", "f32", "Current value")
+            .state(ComponentState::Disabled)
+            .perf_evidence(PerfEvidence {
+                render_time_ms: Some(1.2),
+                interaction_latency_ms: None,
+                notes: String::new(),
+                measured_on: measured_on.map(str::to_string),
+                frame_budget: None,
+            })
+            .build()
+    }
+
+    #[test]
+    fn dashboard_covers_every_component() {
+        let mut story_states = BTreeMap::new();
+        story_states.insert("dialog".to_string(), vec![ComponentState::Open]);
+        let mut manifest = Manifest::default();
+        manifest.components.insert(
+            "tabs".to_string(),
+            ManifestEntry {
+                name: "Tabs".to_string(),
+                version: "0.0.1".to_string(),
+                installed_on: "2026-01-02".to_string(),
+                checksums: BTreeMap::new(),
+            },
+        );
+
+        let statuses = dashboard(
+            &story_states,
+            &AcceptanceLedger::default(),
+            &manifest,
+            "2026-03-01",
+        );
+        assert_eq!(statuses.len(), all_contracts().len());
+        assert!(statuses.windows(2).all(|w| w[0].component < w[1].component));
+
+        let status = |name: &str| statuses.iter().find(|s| s.component == name).unwrap();
+        let dialog = status("Dialog");
+        assert_eq!(dialog.contract.badge, Badge::Green);
+        assert_eq!(dialog.stories.badge, Badge::Yellow);
+        assert!(dialog.story_coverage > 0 && dialog.story_coverage < 100);
+        assert_eq!(dialog.drift, None);
+        assert_eq!(dialog.overall(), Badge::Red);
+
+        let button = status("Button");
+        assert_eq!(button.stories, GateStatus::new(Badge::Red, "No story"));
+        assert_eq!(button.story_coverage, 0);
+
+        let tabs = status("Tabs");
+        assert_eq!(tabs.drift.as_ref().unwrap().badge, Badge::Red);
+    }
+
+    #[test]
+    fn perf_evidence_ages_out() {
+        let fresh = contract_with_evidence(Some("2026-01-02"));
+        assert_eq!(perf_status(&fresh, "2026-03-01").badge, Badge::Green);
+        assert_eq!(
+            perf_status(&fresh, "2026-06-01"),
+            GateStatus::new(Badge::Yellow, "Measured 150 days ago")
+        );
+        assert_eq!(
+            perf_status(&contract_with_evidence(None), "2026-03-01").badge,
+            Badge::Yellow
+        );
+    }
+
+    #[test]
+    fn perf_budget_overruns_turn_the_gate_red() {
+        let budgeted = |max_render_ms, max_interaction_ms| {
+            let mut contract = contract_with_evidence(Some("2026-01-02"));
+            contract.perf_budget = Some(PerfBudget {
+                max_render_ms: Some(max_render_ms),
+                max_interaction_ms,
+            });
+            perf_status(&contract, "2026-03-01")
+        };
+        assert_eq!(budgeted(2.0, None).badge, Badge::Green);
+        assert_eq!(
+            budgeted(1.0, None),
+            GateStatus::new(Badge::Red, "max_render_ms: 1.2ms over 1ms budget")
+        );
+        assert_eq!(
+            budgeted(2.0, Some(16.0)),
+            GateStatus::new(Badge::Yellow, "max_interaction_ms not measured")
+        );
+    }
+
+    #[test]
+    fn drift_and_acceptance_thresholds() {
+        assert_eq!(drift_status("0.1.0", "0.1.0").badge, Badge::Green);
+        assert_eq!(drift_status("0.1.0", "0.1.3").badge, Badge::Yellow);
+        assert_eq!(drift_status("0.1.0", "0.2.0").badge, Badge::Red);
+
+        assert_eq!(acceptance_status((13, 13)).badge, Badge::Green);
+        assert_eq!(acceptance_status((7, 13)).badge, Badge::Yellow);
+        assert_eq!(acceptance_status((6, 13)).badge, Badge::Red);
+    }
+}
@@ -0,0 +1,365 @@
+//! Install diffs: compare an installed component against current registry sources.
+//!
+//! `diff_install` takes the files a fresh install plan would write and the
+//! contents currently on disk, and reports a unified diff per file plus a
+//! recommendation (update, fork, or skip). Files whose provenance records
+//! local modifications are flagged so their divergence is not mistaken for
+//! registry drift.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::plan::{FileAction, MutationStrategy, PlanContract};
+
+/// Lines of unchanged context around each hunk.
+const CONTEXT_LINES: usize = 3;
+
+/// How an installed file compares to the registry source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileStatus {
+    /// On-disk contents match the registry.
+    Unchanged,
+    /// On-disk contents differ from the registry.
+    Modified,
+    /// The registry ships this file but it is not on disk.
+    Missing,
+}
+
+/// Suggested next step after reviewing an install diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recommendation {
+    /// Installed files match the registry; nothing to do.
+    Skip,
+    /// Only registry changes; safe to update.
+    Update,
+    /// Locally modified files would be overwritten; fork to keep the edits.
+    Fork,
+}
+
+/// Comparison result for a single installed file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDiff {
+    /// Installed file path.
+    pub file_path: PathBuf,
+    /// Comparison status.
+    pub status: FileStatus,
+    /// Local modifications recorded in provenance, if any.
+    pub local_modifications: Option<String>,
+    /// Unified diff from installed (`---`) to registry (`+++`). Empty when unchanged.
+    pub diff: String,
+}
+
+/// Comparison of every registry-owned file for one installed component.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstallDiff {
+    /// Component name.
+    pub component_name: String,
+    /// Registry version compared against.
+    pub component_version: String,
+    /// Per-file results, in plan order.
+    pub files: Vec<FileDiff>,
+    /// Suggested next step.
+    pub recommendation: Recommendation,
+}
+
+impl InstallDiff {
+    /// Serialize the diff to JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Whether any file differs from the registry.
+    pub fn has_changes(&self) -> bool {
+        self.files.iter().any(|f| f.status != FileStatus::Unchanged)
+    }
+}
+
+/// Compare installed files against the files a fresh install plan would write.
+///
+/// `installed` maps file paths to their on-disk contents (absent = missing).
+/// `local_modifications` maps file paths to modifications recorded in their
+/// provenance; only entries that differ from the install default belong here.
+pub fn diff_install(
+    plan: &PlanContract,
+    installed: &BTreeMap<PathBuf, String>,
+    local_modifications: &BTreeMap<PathBuf, String>,
+) -> InstallDiff {
+    let files: Vec<FileDiff> = plan
+        .mutations
+        .iter()
+        .filter(|m| m.action == FileAction::Create && m.strategy == MutationStrategy::WriteFile)
+        .map(|m| {
+            let label = m.file_path.display().to_string();
+            let (status, diff) = match installed.get(&m.file_path) {
+                None => (
+                    FileStatus::Missing,
+                    unified_diff("", &m.content, "/dev/null", &label),
+                ),
+                Some(current) if *current == m.content => (FileStatus::Unchanged, String::new()),
+                Some(current) => (
+                    FileStatus::Modified,
+                    unified_diff(current, &m.content, &label, &label),
+                ),
+            };
+            FileDiff {
+                file_path: m.file_path.clone(),
+                status,
+                local_modifications: local_modifications.get(&m.file_path).cloned(),
+                diff,
+            }
+        })
+        .collect();
+
+    let recommendation = if files.iter().all(|f| f.status == FileStatus::Unchanged) {
+        Recommendation::Skip
+    } else if files
+        .iter()
+        .any(|f| f.status == FileStatus::Modified && f.local_modifications.is_some())
+    {
+        Recommendation::Fork
+    } else {
+        Recommendation::Update
+    };
+
+    InstallDiff {
+        component_name: plan.component_name.clone(),
+        component_version: plan.component_version.clone(),
+        files,
+        recommendation,
+    }
+}
+
+/// A line-level edit between two texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Keep(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Produce a unified diff (`diff -u` style) from `old` to `new`.
+///
+/// Returns an empty string when the texts are identical.
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let edits = line_edits(&old_lines, &new_lines);
+
+    let changed: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !matches!(e, Edit::Keep(..)))
+        .map(|(i, _)| i)
+        .collect();
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    // Group changes whose context windows touch into hunks of edit indices.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for &i in &changed {
+        let start = i.saturating_sub(CONTEXT_LINES);
+        let end = (i + CONTEXT_LINES + 1).min(edits.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    // Old/new lines consumed before each edit, for hunk headers.
+    let mut positions = Vec::with_capacity(edits.len());
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for edit in &edits {
+        positions.push((old_pos, new_pos));
+        match edit {
+            Edit::Keep(..) => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            Edit::Delete(_) => old_pos += 1,
+            Edit::Insert(_) => new_pos += 1,
+        }
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", old_label, new_label);
+    for (start, end) in hunks {
+        let hunk = &edits[start..end];
+        let (old_start, new_start) = positions[start];
+        let old_len = hunk
+            .iter()
+            .filter(|e| !matches!(e, Edit::Insert(_)))
+            .count();
+        let new_len = hunk
+            .iter()
+            .filter(|e| !matches!(e, Edit::Delete(_)))
+            .count();
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(old_start, old_len),
+            hunk_range(new_start, new_len)
+        ));
+        for edit in hunk {
+            match *edit {
+                Edit::Keep(o, _) => out.push_str(&format!(" {}\n", old_lines[o])),
+                Edit::Delete(o) => out.push_str(&format!("-{}\n", old_lines[o])),
+                Edit::Insert(n) => out.push_str(&format!("+{}\n", new_lines[n])),
+            }
+        }
+    }
+    out
+}
+
+/// Longest-common-subsequence line edits from `old` to `new`.
+fn line_edits(old: &[&str], new: &[&str]) -> Vec<Edit> {
+    // lcs[i][j] = LCS length of old[i..] and new[j..].
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut edits = Vec::with_capacity(old.len() + new.len());
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            edits.push(Edit::Keep(i, j));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            edits.push(Edit::Delete(i));
+            i += 1;
+        } else {
+            edits.push(Edit::Insert(j));
+            j += 1;
+        }
+    }
+    edits
+}
+
+/// Format a hunk range the way `diff -u` does: 1-based, and an empty range
+/// names the line it follows.
+fn hunk_range(start: usize, len: usize) -> String {
+    match len {
+        0 => format!("{},0", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, len),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::plan::{DefaultLayout, generate_plan};
+
+    fn dialog_plan() -> PlanContract {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        generate_plan(entry, &DefaultLayout::new("/test/project"), &[])
+    }
+
+    fn installed_from(plan: &PlanContract) -> BTreeMap<PathBuf, String> {
+        plan.mutations
+            .iter()
+            .filter(|m| m.strategy == MutationStrategy::WriteFile)
+            .map(|m| (m.file_path.clone(), m.content.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn unified_diff_reports_hunks_with_context() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nc\nd\nE\nf\ng\nh\n";
+        let diff = unified_diff(old, new, "old.rs", "new.rs");
+
+        assert_eq!(
+            diff,
+            "--- old.rs\n+++ new.rs\n@@ -2,7 +2,7 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n"
+        );
+        assert!(unified_diff(old, old, "a", "b").is_empty());
+    }
+
+    #[test]
+    fn unified_diff_handles_empty_sides() {
+        assert_eq!(
+            unified_diff("", "x\ny\n", "/dev/null", "new.rs"),
+            "--- /dev/null\n+++ new.rs\n@@ -0,0 +1,2 @@\n+x\n+y\n"
+        );
+        assert_eq!(
+            unified_diff("x\n", "", "old.rs", "/dev/null"),
+            "--- old.rs\n+++ /dev/null\n@@ -1 +0,0 @@\n-x\n"
+        );
+    }
+
+    #[test]
+    fn clean_install_recommends_skip() {
+        let plan = dialog_plan();
+        let diff = diff_install(&plan, &installed_from(&plan), &BTreeMap::new());
+
+        assert!(!diff.has_changes());
+        assert_eq!(diff.recommendation, Recommendation::Skip);
+        assert!(diff.files.iter().all(|f| f.diff.is_empty()));
+    }
+
+    #[test]
+    fn registry_drift_recommends_update() {
+        let plan = dialog_plan();
+        let mut installed = installed_from(&plan);
+        let (path, content) = installed.iter_mut().next().unwrap();
+        let path = path.clone();
+        content.push_str("// old line\n");
+
+        let diff = diff_install(&plan, &installed, &BTreeMap::new());
+        let file = diff.files.iter().find(|f| f.file_path == path).unwrap();
+
+        assert_eq!(file.status, FileStatus::Modified);
+        assert!(file.diff.contains("-// old line"));
+        assert_eq!(diff.recommendation, Recommendation::Update);
+    }
+
+    #[test]
+    fn recorded_local_modifications_recommend_fork() {
+        let plan = dialog_plan();
+        let mut installed = installed_from(&plan);
+        let path = plan.provenance_actions[0].file_path.clone();
+        installed
+            .get_mut(&path)
+            .unwrap()
+            .push_str("// local tweak\n");
+        let local = BTreeMap::from([(path.clone(), "Tweaked padding".to_string())]);
+
+        let diff = diff_install(&plan, &installed, &local);
+        let file = diff.files.iter().find(|f| f.file_path == path).unwrap();
+
+        assert_eq!(file.local_modifications.as_deref(), Some("Tweaked padding"));
+        assert_eq!(diff.recommendation, Recommendation::Fork);
+    }
+
+    #[test]
+    fn missing_file_shows_full_addition() {
+        let plan = dialog_plan();
+        let mut installed = installed_from(&plan);
+        let path = plan.provenance_actions[0].file_path.clone();
+        installed.remove(&path);
+
+        let diff = diff_install(&plan, &installed, &BTreeMap::new());
+        let file = diff.files.iter().find(|f| f.file_path == path).unwrap();
+
+        assert_eq!(file.status, FileStatus::Missing);
+        assert!(file.diff.starts_with("--- /dev/null"));
+        assert_eq!(diff.recommendation, Recommendation::Update);
+    }
+}
@@ -0,0 +1,286 @@
+//! Contract interchange: component contracts as JSON files for external tools.
+//!
+//! `gpui contracts export` writes one `<component>.json` per catalog contract
+//! (lowercase, e.g. `dropdownmenu.json`), in the same shape as the
+//! `component-contract` schema. Design-ops tools can read these files and
+//! edit them to propose changes.
+//!
+//! `gpui contracts import` reads such files back, validates each as a
+//! contract, and diffs it against the catalog field by field. Importing never
+//! touches Rust source: the report is the proposal, and a maintainer carries
+//! accepted changes into `components::catalog`.
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use components::ComponentContract;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::all_contracts;
+
+/// Default directory, relative to the working directory, for exported contracts.
+pub const CONTRACTS_DIR: &str = "contracts";
+
+/// An exported contract file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractFile {
+    /// Path relative to the export directory.
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+/// How an imported contract compares to the catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStatus {
+    /// Identical to the catalog contract.
+    Unchanged,
+    /// Proposes changes to a catalog contract.
+    Changed,
+    /// Names a component the catalog does not have.
+    New,
+    /// Not a valid contract; see the errors.
+    Invalid,
+}
+
+/// One field an imported contract changes.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Dot path to the field; list items with a `name` are addressed by it,
+    /// e.g. `props[variant].default_value`.
+    pub path: String,
+    /// The catalog value, absent for an added field.
+    pub current: Option<Value>,
+    /// The imported value, absent for a removed field.
+    pub proposed: Option<Value>,
+}
+
+/// The outcome of importing one contract file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractImport {
+    /// File name the contract was read from.
+    pub file: String,
+    /// Component the file describes, once it parses.
+    pub component: Option<String>,
+    pub status: ImportStatus,
+    /// Parse and validation errors, for an invalid file.
+    pub errors: Vec<String>,
+    /// Changed fields, for a changed contract.
+    pub changes: Vec<FieldChange>,
+}
+
+/// File name of `component`'s exported contract.
+pub fn contract_file_name(component: &str) -> String {
+    format!("{}.json", component.to_lowercase())
+}
+
+/// Every catalog contract as a pretty-printed JSON file.
+pub fn export_contracts() -> Result<Vec<ContractFile>, serde_json::Error> {
+    all_contracts()
+        .iter()
+        .map(|contract| {
+            Ok(ContractFile {
+                path: PathBuf::from(contract_file_name(&contract.name)),
+                contents: serde_json::to_string_pretty(contract)? + "\n",
+            })
+        })
+        .collect()
+}
+
+/// Validate the contract in `json`, read from `file`, and diff it against
+/// the catalog contract of the same name.
+pub fn import_contract(file: &str, json: &str) -> ContractImport {
+    let invalid = |component: Option<String>, errors: Vec<String>| ContractImport {
+        file: file.to_string(),
+        component,
+        status: ImportStatus::Invalid,
+        errors,
+        changes: Vec::new(),
+    };
+
+    let proposed: ComponentContract = match serde_json::from_str(json) {
+        Ok(contract) => contract,
+        Err(e) => return invalid(None, vec![e.to_string()]),
+    };
+    let mut errors: Vec<String> = proposed.validate().iter().map(|e| e.to_string()).collect();
+    if file != contract_file_name(&proposed.name) {
+        errors.push(format!(
+            "name: '{}' belongs in {}",
+            proposed.name,
+            contract_file_name(&proposed.name)
+        ));
+    }
+    if !errors.is_empty() {
+        return invalid(Some(proposed.name), errors);
+    }
+
+    let Some(current) = all_contracts()
+        .into_iter()
+        .find(|contract| contract.name == proposed.name)
+    else {
+        return ContractImport {
+            file: file.to_string(),
+            component: Some(proposed.name),
+            status: ImportStatus::New,
+            errors: Vec::new(),
+            changes: Vec::new(),
+        };
+    };
+    let mut changes = Vec::new();
+    diff_values(
+        "",
+        serde_json::to_value(&current).ok().as_ref(),
+        serde_json::to_value(&proposed).ok().as_ref(),
+        &mut changes,
+    );
+    ContractImport {
+        file: file.to_string(),
+        component: Some(proposed.name),
+        status: if changes.is_empty() {
+            ImportStatus::Unchanged
+        } else {
+            ImportStatus::Changed
+        },
+        errors: Vec::new(),
+        changes,
+    }
+}
+
+/// Record where `proposed` differs from `current` under `path`: objects
+/// field by field, lists of named items item by item, anything else whole.
+fn diff_values(
+    path: &str,
+    current: Option<&Value>,
+    proposed: Option<&Value>,
+    changes: &mut Vec<FieldChange>,
+) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        }
+    };
+
+    if let (Some(Value::Object(current)), Some(Value::Object(proposed))) = (current, proposed) {
+        let keys: BTreeSet<&String> = current.keys().chain(proposed.keys()).collect();
+        for key in keys {
+            diff_values(&child(key), current.get(key), proposed.get(key), changes);
+        }
+        return;
+    }
+    if let (Some(Value::Array(current)), Some(Value::Array(proposed))) = (current, proposed)
+        && let (Some(current), Some(proposed)) = (named_items(current), named_items(proposed))
+    {
+        // Catalog order, then items only the import has.
+        let mut names: Vec<&str> = current.iter().map(|(name, _)| *name).collect();
+        for (name, _) in &proposed {
+            if !names.contains(name) {
+                names.push(name);
+            }
+        }
+        for name in names {
+            diff_values(
+                &format!("{path}[{name}]"),
+                find(&current, name),
+                find(&proposed, name),
+                changes,
+            );
+        }
+        return;
+    }
+    if current != proposed {
+        changes.push(FieldChange {
+            path: path.to_string(),
+            current: current.cloned(),
+            proposed: proposed.cloned(),
+        });
+    }
+}
+
+/// The item called `name` among `items`.
+fn find<'a>(items: &[(&str, &'a Value)], name: &str) -> Option<&'a Value> {
+    items
+        .iter()
+        .find(|(item, _)| *item == name)
+        .map(|(_, value)| *value)
+}
+
+/// `items` keyed by their `name` field, if every item has one.
+fn named_items(items: &[Value]) -> Option<Vec<(&str, &Value)>> {
+    items
+        .iter()
+        .map(|item| Some((item.get("name")?.as_str()?, item)))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exported(component: &str) -> String {
+        let file = contract_file_name(component);
+        export_contracts()
+            .unwrap()
+            .into_iter()
+            .find(|exported| exported.path.as_path() == std::path::Path::new(&file))
+            .unwrap_or_else(|| panic!("{file} not exported"))
+            .contents
+    }
+
+    #[test]
+    fn export_writes_a_file_per_contract_that_imports_unchanged() {
+        let files = export_contracts().unwrap();
+        assert_eq!(files.len(), all_contracts().len());
+        for file in &files {
+            let name = file.path.to_str().unwrap();
+            let import = import_contract(name, &file.contents);
+            assert_eq!(import.status, ImportStatus::Unchanged, "{name}");
+        }
+    }
+
+    #[test]
+    fn import_diffs_changed_fields_by_name() {
+        let mut contract: Value = serde_json::from_str(&exported("Button")).unwrap();
+        contract["version"] = Value::from("9.0.0");
+        let props = contract["props"].as_array_mut().unwrap();
+        let label = props
+            .iter_mut()
+            .find(|prop| prop["name"] == "label")
+            .unwrap();
+        label["description"] = Value::from("Button text");
+
+        let import = import_contract("button.json", &contract.to_string());
+        assert_eq!(import.status, ImportStatus::Changed);
+        assert_eq!(import.component.as_deref(), Some("Button"));
+        let paths: Vec<&str> = import.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["props[label].description", "version"]);
+        assert_eq!(import.changes[1].current, Some(Value::from("0.1.0")));
+        assert_eq!(import.changes[1].proposed, Some(Value::from("9.0.0")));
+    }
+
+    #[test]
+    fn import_reports_invalid_and_new_contracts() {
+        let import = import_contract("button.json", "{ not json");
+        assert_eq!(import.status, ImportStatus::Invalid);
+        assert!(import.component.is_none());
+
+        let mut contract: Value = serde_json::from_str(&exported("Button")).unwrap();
+        contract["states"] = Value::Array(Vec::new());
+        let import = import_contract("button.json", &contract.to_string());
+        assert_eq!(import.status, ImportStatus::Invalid);
+        assert!(import.errors.iter().any(|e| e.starts_with("states:")));
+
+        let mut contract: Value = serde_json::from_str(&exported("Button")).unwrap();
+        contract["name"] = Value::from("Gizmo");
+        let import = import_contract("button.json", &contract.to_string());
+        assert_eq!(import.status, ImportStatus::Invalid, "misnamed file");
+        let import = import_contract("gizmo.json", &contract.to_string());
+        assert_eq!(import.status, ImportStatus::New);
+    }
+}
@@ -4,14 +4,53 @@
 //! providing lookup, listing, and JSON serialization for CLI consumption.
 //! It is generated from source -- not hand-maintained manifests -- ensuring
 //! the registry is always regenerable and never stale (FR-006).
-
+//!
+//! The default `gpui` feature adds theme verification and the primitive
+//! index; without it the crate builds for wasm32 and headless CI jobs.
+
+pub mod acceptance;
+pub mod compat;
+pub mod config;
+pub mod dashboard;
+pub mod diff;
+pub mod interchange;
+#[cfg(feature = "gpui")]
+pub mod lint;
+pub mod manifest;
+pub mod migration;
 pub mod plan;
+pub mod policy;
+#[cfg(feature = "gpui")]
+pub mod primitives;
+pub mod remote;
+pub mod schema;
+pub mod search;
+pub mod site;
+pub mod snapshot;
+pub mod sources;
+pub mod timing;
+pub mod tokens;
+pub mod usage;
+#[cfg(feature = "gpui")]
+pub mod verify;
+pub mod version;
 
 use std::collections::HashMap;
 
-use components::{ComponentContract, ComponentState, Disposition, PropDef, TokenRef};
+/// Stability tiers, re-exported for filtering entries without depending on
+/// `components`.
+pub use components::Stability;
+use components::{
+    AcceptanceChecklist, ComponentContract, ComponentState, Disposition, PropDef, PropRename,
+    TokenRef,
+};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::policy::{DispositionPolicy, PolicyViolation};
+use crate::remote::ArchiveRef;
+use crate::version::{Version, VersionReq};
+
 // ---------------------------------------------------------------------------
 // RegistryEntry -- the indexed summary of a single component
 // ---------------------------------------------------------------------------
@@ -21,7 +60,7 @@ use serde::{Deserialize, Serialize};
 /// This is a flattened, serializable view of a `ComponentContract` optimized
 /// for lookup and listing. It contains the fields specified by the registry spec:
 /// name, version, variants, states, props (with types), and required files.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RegistryEntry {
     /// Component name (e.g. "Dialog", "Select", "Tabs").
     pub name: String,
@@ -29,6 +68,9 @@ pub struct RegistryEntry {
     pub version: String,
     /// Sourcing disposition (reuse, fork, rewrite).
     pub disposition: Disposition,
+    /// API stability tier; entries from older indexes read as stable.
+    #[serde(default)]
+    pub stability: Stability,
     /// Named visual variants.
     pub variants: Vec<String>,
     /// Interactive/visual states the component supports.
@@ -39,6 +81,19 @@ pub struct RegistryEntry {
     pub token_dependencies: Vec<TokenRef>,
     /// File paths required for installation.
     pub required_files: Vec<String>,
+    /// Registry components this one depends on, by name.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Prop renames across versions, for migration guidance.
+    pub prop_renames: Vec<PropRename>,
+    /// Docs file bundled into install plans, if the contract declares one.
+    pub docs_file: Option<String>,
+    /// Acceptance checklist, with the items the contract proves already checked.
+    #[serde(default)]
+    pub acceptance_checklist: AcceptanceChecklist,
+    /// Published source archive, set only in indexes served by a remote registry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archive: Option<ArchiveRef>,
 }
 
 impl RegistryEntry {
@@ -48,11 +103,17 @@ impl RegistryEntry {
             name: contract.name.clone(),
             version: contract.version.clone(),
             disposition: contract.disposition,
+            stability: contract.stability,
             variants: contract.variants.clone(),
             states: contract.states.clone(),
             props: contract.props.clone(),
             token_dependencies: contract.token_dependencies.clone(),
             required_files: contract.required_files.clone(),
+            dependencies: contract.dependencies.clone(),
+            prop_renames: contract.prop_renames.clone(),
+            docs_file: contract.docs_file.clone(),
+            acceptance_checklist: contract.evaluated_checklist(),
+            archive: None,
         }
     }
 
@@ -93,28 +154,59 @@ fn state_label(state: &ComponentState) -> &'static str {
 /// The component registry, indexing all installable components by name.
 ///
 /// Generated from `ComponentContract` source metadata. Supports lookup by name,
-/// listing all entries, and JSON serialization for CLI consumption.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+/// listing all entries, and JSON serialization for CLI consumption. A
+/// component may have several versions (published indexes keep old ones);
+/// lookups and listings use the current one, normally the latest.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct RegistryIndex {
     /// Components indexed by lowercase name for case-insensitive lookup.
     entries: HashMap<String, RegistryEntry>,
+    /// Every other version of a component, keyed like `entries`, ascending.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    other_versions: HashMap<String, Vec<RegistryEntry>>,
+    /// Oldest `gpui` CLI that understands this index, if the publisher
+    /// declared one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_cli_version: Option<String>,
 }
 
 impl RegistryIndex {
     /// Create an empty registry index.
     pub fn new() -> Self {
-        Self {
-            entries: HashMap::new(),
-        }
+        Self::default()
     }
 
     /// Register a component from its `ComponentContract`.
     ///
-    /// The component is indexed by its lowercased name. If a component with
-    /// the same name already exists, it is replaced (latest wins).
+    /// The component is indexed by its lowercased name; see [`insert`](Self::insert).
     pub fn register(&mut self, contract: &ComponentContract) {
-        let entry = RegistryEntry::from_contract(contract);
-        self.entries.insert(entry.name.to_lowercase(), entry);
+        self.insert(RegistryEntry::from_contract(contract));
+    }
+
+    /// Add an entry, keeping any other versions of the component.
+    ///
+    /// An entry with the same name and version replaces the existing one. A
+    /// newer version becomes current (latest wins); an older one is kept
+    /// for [`get_version`](Self::get_version).
+    pub fn insert(&mut self, entry: RegistryEntry) {
+        let key = entry.name.to_lowercase();
+        let Some(current) = self.entries.get_mut(&key) else {
+            self.entries.insert(key, entry);
+            return;
+        };
+        let kept = if Version::parse(&entry.version) > Version::parse(&current.version) {
+            std::mem::replace(current, entry)
+        } else if entry.version == current.version {
+            *current = entry;
+            return;
+        } else {
+            entry
+        };
+        let current_version = current.version.clone();
+        let others = self.other_versions.entry(key).or_default();
+        others.retain(|other| other.version != kept.version && other.version != current_version);
+        others.push(kept);
+        others.sort_by_key(|other| Version::parse(&other.version));
     }
 
     /// Look up a component by name (case-insensitive).
@@ -122,6 +214,45 @@ impl RegistryIndex {
         self.entries.get(&name.to_lowercase())
     }
 
+    /// Every version of a component (case-insensitive), ascending.
+    pub fn versions(&self, name: &str) -> Vec<&RegistryEntry> {
+        let key = name.to_lowercase();
+        let mut versions: Vec<&RegistryEntry> = self
+            .other_versions
+            .get(&key)
+            .into_iter()
+            .flatten()
+            .chain(self.entries.get(&key))
+            .collect();
+        versions.sort_by_key(|entry| Version::parse(&entry.version));
+        versions
+    }
+
+    /// The highest version of a component that satisfies `requirement`.
+    pub fn get_version(&self, name: &str, requirement: &VersionReq) -> Option<&RegistryEntry> {
+        self.versions(name).into_iter().rev().find(|entry| {
+            Version::parse(&entry.version).is_some_and(|version| requirement.matches(&version))
+        })
+    }
+
+    /// Make the [`get_version`](Self::get_version) match the current version
+    /// of a component, so lookups, dependency resolution, and plans use it.
+    ///
+    /// Returns the pinned entry, or `None` (changing nothing) if no version
+    /// matches.
+    pub fn pin(&mut self, name: &str, requirement: &VersionReq) -> Option<&RegistryEntry> {
+        let version = self.get_version(name, requirement)?.version.clone();
+        let key = name.to_lowercase();
+        if let Some(others) = self.other_versions.get_mut(&key)
+            && let Some(position) = others.iter().position(|other| other.version == version)
+        {
+            let current = self.entries.get_mut(&key)?;
+            std::mem::swap(current, &mut others[position]);
+            others.sort_by_key(|other| Version::parse(&other.version));
+        }
+        self.entries.get(&key)
+    }
+
     /// List all registered entries, sorted by name.
     pub fn list(&self) -> Vec<&RegistryEntry> {
         let mut entries: Vec<&RegistryEntry> = self.entries.values().collect();
@@ -136,6 +267,16 @@ impl RegistryIndex {
         names
     }
 
+    /// Oldest `gpui` CLI that understands this index, if declared.
+    pub fn min_cli_version(&self) -> Option<&str> {
+        self.min_cli_version.as_deref()
+    }
+
+    /// Declare the oldest `gpui` CLI that understands this index.
+    pub fn set_min_cli_version(&mut self, version: Option<String>) {
+        self.min_cli_version = version;
+    }
+
     /// Number of registered components.
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -146,11 +287,54 @@ impl RegistryIndex {
         self.entries.is_empty()
     }
 
-    /// Remove a component by name (case-insensitive). Returns the removed entry.
+    /// Remove every version of a component by name (case-insensitive).
+    /// Returns the removed current entry.
     pub fn remove(&mut self, name: &str) -> Option<RegistryEntry> {
+        self.other_versions.remove(&name.to_lowercase());
         self.entries.remove(&name.to_lowercase())
     }
 
+    /// Resolve a component and its transitive dependencies in install order.
+    ///
+    /// Dependencies come before the components that use them and the requested
+    /// component is last; each component appears once. Declaration order is
+    /// kept among siblings, so the result is deterministic.
+    pub fn resolve(&self, name: &str) -> Result<Vec<&RegistryEntry>, ResolveError> {
+        let mut order = Vec::new();
+        self.visit(name, None, &mut Vec::new(), &mut order)?;
+        Ok(order)
+    }
+
+    /// Depth-first post-order walk for [`RegistryIndex::resolve`].
+    fn visit<'a>(
+        &'a self,
+        name: &str,
+        required_by: Option<&str>,
+        path: &mut Vec<String>,
+        order: &mut Vec<&'a RegistryEntry>,
+    ) -> Result<(), ResolveError> {
+        let entry = self.get(name).ok_or_else(|| ResolveError::NotFound {
+            name: name.to_string(),
+            required_by: required_by.map(str::to_string),
+        })?;
+        if order.iter().any(|resolved| resolved.name == entry.name) {
+            return Ok(());
+        }
+        if let Some(start) = path.iter().position(|visiting| *visiting == entry.name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(entry.name.clone());
+            return Err(ResolveError::Cycle(cycle));
+        }
+
+        path.push(entry.name.clone());
+        for dependency in &entry.dependencies {
+            self.visit(dependency, Some(&entry.name), path, order)?;
+        }
+        path.pop();
+        order.push(entry);
+        Ok(())
+    }
+
     /// Serialize the registry index to JSON.
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
@@ -162,6 +346,39 @@ impl RegistryIndex {
     }
 }
 
+/// Errors from resolving component dependencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolveError {
+    /// A component, or a dependency it declares, is not registered.
+    NotFound {
+        name: String,
+        required_by: Option<String>,
+    },
+    /// Dependencies form a cycle; lists the components around it.
+    Cycle(Vec<String>),
+}
+
+impl std::fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolveError::NotFound {
+                name,
+                required_by: None,
+            } => write!(f, "component not found: '{name}'"),
+            ResolveError::NotFound {
+                name,
+                required_by: Some(parent),
+            } => write!(
+                f,
+                "component '{parent}' depends on unknown component '{name}'"
+            ),
+            ResolveError::Cycle(cycle) => write!(f, "dependency cycle: {}", cycle.join(" -> ")),
+        }
+    }
+}
+
+impl std::error::Error for ResolveError {}
+
 // ---------------------------------------------------------------------------
 // Registry generation -- populate from POC component contracts
 // ---------------------------------------------------------------------------
@@ -169,32 +386,38 @@ impl RegistryIndex {
 /// Generate a fully populated registry index from all known component contracts.
 ///
 /// This reads `ComponentContract` metadata directly from component source
-/// (via the static `contract()` methods on each component type), ensuring
+/// (via `components::catalog`, which each component's `contract()` returns), ensuring
 /// the registry is always in sync with the actual component implementations.
+///
+/// While a [`timing`] recording is running, each contract is also validated
+/// so the report shows what validation costs per contract.
 pub fn generate_registry() -> RegistryIndex {
-    let mut index = RegistryIndex::new();
-    for contract in all_contracts() {
-        index.register(&contract);
+    let (index, elapsed) = timing::measure(|| {
+        let mut index = RegistryIndex::new();
+        for contract in all_contracts() {
+            if timing::is_recording() {
+                let (_, validation) = timing::measure(|| contract.validate());
+                let (_, indexing) = timing::measure(|| index.register(&contract));
+                timing::record_contract(
+                    &contract.name,
+                    validation.unwrap_or_default(),
+                    indexing.unwrap_or_default(),
+                );
+            } else {
+                index.register(&contract);
+            }
+        }
+        index
+    });
+    if let Some(elapsed) = elapsed {
+        timing::record_registry(elapsed);
     }
     index
 }
 
 /// Returns all component contracts in alphabetical order.
 fn all_contracts() -> Vec<components::ComponentContract> {
-    vec![
-        components::Button::contract(),
-        components::Checkbox::contract(),
-        components::Dialog::contract(),
-        components::DropdownMenu::contract(),
-        components::Input::contract(),
-        components::Popover::contract(),
-        components::Radio::contract(),
-        components::Select::contract(),
-        components::Tabs::contract(),
-        components::Textarea::contract(),
-        components::Toast::contract(),
-        components::Tooltip::contract(),
-    ]
+    components::catalog::all()
 }
 
 /// Initialize the registry, validating all component contracts.
@@ -203,11 +426,28 @@ fn all_contracts() -> Vec<components::ComponentContract> {
 /// only indexes well-formed components.
 pub fn generate_registry_validated()
 -> Result<RegistryIndex, Vec<(String, Vec<components::ValidationError>)>> {
+    generate_registry_validated_with_policy(&DispositionPolicy::default())
+}
+
+/// Initialize the registry, validating all component contracts and checking
+/// them against a team's sourcing `policy`.
+///
+/// Policy violations are reported as `disposition` validation errors
+/// alongside each contract's own.
+pub fn generate_registry_validated_with_policy(
+    policy: &DispositionPolicy,
+) -> Result<RegistryIndex, Vec<(String, Vec<components::ValidationError>)>> {
     let contracts = all_contracts();
 
     let mut validation_errors = Vec::new();
     for contract in &contracts {
-        let errors = contract.validate();
+        let mut errors = contract.validate();
+        errors.extend(
+            policy
+                .check(contract)
+                .iter()
+                .map(PolicyViolation::to_validation_error),
+        );
         if !errors.is_empty() {
             validation_errors.push((contract.name.clone(), errors));
         }
@@ -221,6 +461,24 @@ pub fn generate_registry_validated()
     for contract in &contracts {
         index.register(contract);
     }
+
+    // Every declared dependency must resolve without cycles.
+    let dependency_errors: Vec<_> = contracts
+        .iter()
+        .filter_map(|contract| {
+            let err = index.resolve(&contract.name).err()?;
+            Some((
+                contract.name.clone(),
+                vec![components::ValidationError {
+                    field: "dependencies".into(),
+                    message: err.to_string(),
+                }],
+            ))
+        })
+        .collect();
+    if !dependency_errors.is_empty() {
+        return Err(dependency_errors);
+    }
     Ok(index)
 }
 
@@ -231,13 +489,13 @@ pub fn generate_registry_validated()
 #[cfg(test)]
 mod tests {
     use super::*;
-    use components::{Dialog, Select, Tabs};
+    use components::catalog;
 
     // -- RegistryEntry tests --
 
     #[test]
     fn entry_from_dialog_contract() {
-        let contract = Dialog::contract();
+        let contract = catalog::dialog();
         let entry = RegistryEntry::from_contract(&contract);
 
         assert_eq!(entry.name, "Dialog");
@@ -251,7 +509,7 @@ mod tests {
 
     #[test]
     fn entry_from_select_contract() {
-        let contract = Select::contract();
+        let contract = catalog::select();
         let entry = RegistryEntry::from_contract(&contract);
 
         assert_eq!(entry.name, "Select");
@@ -262,7 +520,7 @@ mod tests {
 
     #[test]
     fn entry_from_tabs_contract() {
-        let contract = Tabs::contract();
+        let contract = catalog::tabs();
         let entry = RegistryEntry::from_contract(&contract);
 
         assert_eq!(entry.name, "Tabs");
@@ -272,7 +530,7 @@ mod tests {
 
     #[test]
     fn entry_summary_format() {
-        let contract = Dialog::contract();
+        let contract = catalog::dialog();
         let entry = RegistryEntry::from_contract(&contract);
         let summary = entry.summary();
 
@@ -295,7 +553,7 @@ mod tests {
     #[test]
     fn register_and_lookup() {
         let mut index = RegistryIndex::new();
-        index.register(&Dialog::contract());
+        index.register(&catalog::dialog());
 
         assert_eq!(index.len(), 1);
         assert!(!index.is_empty());
@@ -307,7 +565,7 @@ mod tests {
     #[test]
     fn lookup_is_case_insensitive() {
         let mut index = RegistryIndex::new();
-        index.register(&Dialog::contract());
+        index.register(&catalog::dialog());
 
         assert!(index.get("dialog").is_some());
         assert!(index.get("DIALOG").is_some());
@@ -324,8 +582,8 @@ mod tests {
     #[test]
     fn register_overwrites_duplicate() {
         let mut index = RegistryIndex::new();
-        index.register(&Dialog::contract());
-        index.register(&Dialog::contract());
+        index.register(&catalog::dialog());
+        index.register(&catalog::dialog());
 
         assert_eq!(index.len(), 1);
     }
@@ -333,9 +591,9 @@ mod tests {
     #[test]
     fn list_returns_sorted() {
         let mut index = RegistryIndex::new();
-        index.register(&Tabs::contract());
-        index.register(&Dialog::contract());
-        index.register(&Select::contract());
+        index.register(&catalog::tabs());
+        index.register(&catalog::dialog());
+        index.register(&catalog::select());
 
         let entries = index.list();
         assert_eq!(entries.len(), 3);
@@ -344,12 +602,28 @@ mod tests {
         assert_eq!(entries[2].name, "Tabs");
     }
 
+    #[test]
+    fn entries_carry_contract_stability() {
+        let index = generate_registry();
+        assert_eq!(index.get("Dialog").unwrap().stability, Stability::Stable);
+        assert_eq!(
+            index.get("SortableList").unwrap().stability,
+            Stability::Experimental
+        );
+
+        let mut json = serde_json::to_value(index.get("Link").unwrap()).unwrap();
+        assert_eq!(json["stability"], "beta");
+        json.as_object_mut().unwrap().remove("stability");
+        let entry: RegistryEntry = serde_json::from_value(json).unwrap();
+        assert_eq!(entry.stability, Stability::Stable);
+    }
+
     #[test]
     fn names_returns_sorted() {
         let mut index = RegistryIndex::new();
-        index.register(&Tabs::contract());
-        index.register(&Dialog::contract());
-        index.register(&Select::contract());
+        index.register(&catalog::tabs());
+        index.register(&catalog::dialog());
+        index.register(&catalog::select());
 
         let names = index.names();
         assert_eq!(names, vec!["Dialog", "Select", "Tabs"]);
@@ -358,7 +632,7 @@ mod tests {
     #[test]
     fn remove_entry() {
         let mut index = RegistryIndex::new();
-        index.register(&Dialog::contract());
+        index.register(&catalog::dialog());
 
         let removed = index.remove("Dialog");
         assert!(removed.is_some());
@@ -369,7 +643,7 @@ mod tests {
     #[test]
     fn remove_case_insensitive() {
         let mut index = RegistryIndex::new();
-        index.register(&Dialog::contract());
+        index.register(&catalog::dialog());
 
         let removed = index.remove("dialog");
         assert!(removed.is_some());
@@ -381,23 +655,214 @@ mod tests {
         assert!(index.remove("Ghost").is_none());
     }
 
+    // -- Dependency resolution tests --
+
+    fn contract_with_deps(name: &str, deps: &[&str]) -> ComponentContract {
+        deps.iter()
+            .fold(ComponentContract::builder(name, "0.1.0"), |builder, dep| {
+                builder.dependency(*dep)
+            })
+            .required_prop("id", "ElementId", "Unique identifier")
+            .state(ComponentState::Active)
+            .build()
+    }
+
+    #[test]
+    fn resolve_orders_dependencies_before_dependents() {
+        let index = generate_registry();
+        let names: Vec<&str> = index
+            .resolve("select")
+            .unwrap()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["Tooltip", "Button", "EmptyState", "Select"]);
+
+        let names: Vec<&str> = index
+            .resolve("Dialog")
+            .unwrap()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["Dialog"]);
+    }
+
+    #[test]
+    fn resolve_reports_unknown_dependencies_and_cycles() {
+        let mut index = RegistryIndex::new();
+        index.register(&contract_with_deps("A", &["B"]));
+        index.register(&contract_with_deps("B", &["C"]));
+        index.register(&contract_with_deps("C", &["A"]));
+        index.register(&contract_with_deps("D", &["Missing"]));
+
+        assert_eq!(
+            index.resolve("a").unwrap_err(),
+            ResolveError::Cycle(vec!["A".into(), "B".into(), "C".into(), "A".into()])
+        );
+        let err = index.resolve("D").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "component 'D' depends on unknown component 'Missing'"
+        );
+        assert_eq!(
+            index.resolve("Ghost").unwrap_err().to_string(),
+            "component not found: 'Ghost'"
+        );
+    }
+
+    // -- Versioning tests --
+
+    fn dialog_at(version: &str) -> RegistryEntry {
+        RegistryEntry {
+            version: version.to_string(),
+            ..RegistryEntry::from_contract(&catalog::dialog())
+        }
+    }
+
+    fn versions_of<'a>(index: &'a RegistryIndex, name: &str) -> Vec<&'a str> {
+        index
+            .versions(name)
+            .iter()
+            .map(|e| e.version.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn insert_keeps_every_version_with_latest_current() {
+        let mut index = RegistryIndex::new();
+        for version in ["0.2.0", "0.10.0", "0.1.0", "0.2.0"] {
+            index.insert(dialog_at(version));
+        }
+
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.get("dialog").unwrap().version, "0.10.0");
+        assert_eq!(versions_of(&index, "Dialog"), ["0.1.0", "0.2.0", "0.10.0"]);
+        assert!(versions_of(&index, "Ghost").is_empty());
+    }
+
+    #[test]
+    fn get_version_returns_highest_match() {
+        let mut index = RegistryIndex::new();
+        for version in ["0.1.0", "0.1.4", "0.2.0", "1.0.0"] {
+            index.insert(dialog_at(version));
+        }
+        let get = |requirement: &str| {
+            index
+                .get_version("Dialog", &VersionReq::parse(requirement).unwrap())
+                .map(|e| e.version.as_str())
+        };
+
+        assert_eq!(get("^0.1"), Some("0.1.4"));
+        assert_eq!(get("0.2.0"), Some("0.2.0"));
+        assert_eq!(get("<1"), Some("0.2.0"));
+        assert_eq!(get("*"), Some("1.0.0"));
+        assert_eq!(get("^2"), None);
+    }
+
+    #[test]
+    fn pin_makes_a_version_current() {
+        let mut index = RegistryIndex::new();
+        for version in ["0.1.0", "0.2.0", "0.3.0"] {
+            index.insert(dialog_at(version));
+        }
+
+        let pinned = index.pin("dialog", &VersionReq::parse("0.2").unwrap());
+        assert_eq!(pinned.unwrap().version, "0.2.0");
+        assert_eq!(index.get("Dialog").unwrap().version, "0.2.0");
+        assert_eq!(versions_of(&index, "Dialog"), ["0.1.0", "0.2.0", "0.3.0"]);
+
+        assert!(
+            index
+                .pin("Dialog", &VersionReq::parse("^9").unwrap())
+                .is_none()
+        );
+        assert_eq!(index.get("Dialog").unwrap().version, "0.2.0");
+
+        index.remove("Dialog");
+        assert!(versions_of(&index, "Dialog").is_empty());
+    }
+
+    #[test]
+    fn older_versions_survive_json_roundtrip() {
+        let mut index = RegistryIndex::new();
+        index.insert(dialog_at("0.1.0"));
+        index.insert(dialog_at("0.2.0"));
+
+        let restored = RegistryIndex::from_json(&index.to_json().unwrap()).unwrap();
+        assert_eq!(versions_of(&restored, "Dialog"), ["0.1.0", "0.2.0"]);
+
+        let single = generate_registry().to_json().unwrap();
+        assert!(!single.contains("other_versions"));
+        assert!(!single.contains("min_cli_version"));
+    }
+
+    #[test]
+    fn min_cli_version_survives_json_roundtrip() {
+        let mut index = RegistryIndex::new();
+        index.set_min_cli_version(Some("0.2.0".into()));
+        let restored = RegistryIndex::from_json(&index.to_json().unwrap()).unwrap();
+        assert_eq!(restored.min_cli_version(), Some("0.2.0"));
+        assert_eq!(
+            RegistryIndex::from_json("{\"entries\":{}}")
+                .unwrap()
+                .min_cli_version(),
+            None
+        );
+    }
+
     // -- Registry generation tests --
 
     #[test]
     fn generate_registry_indexes_all_poc_components() {
         let index = generate_registry();
 
-        assert_eq!(index.len(), 12);
+        assert_eq!(index.len(), 48);
+        assert!(index.get("Avatar").is_some());
+        assert!(index.get("AvatarGroup").is_some());
+        assert!(index.get("Badge").is_some());
+        assert!(index.get("BarChart").is_some());
+        assert!(index.get("Breadcrumbs").is_some());
         assert!(index.get("Button").is_some());
+        assert!(index.get("Card").is_some());
         assert!(index.get("Checkbox").is_some());
+        assert!(index.get("CommandPalette").is_some());
+        assert!(index.get("ContextMenu").is_some());
+        assert!(index.get("DataTable").is_some());
+        assert!(index.get("DescriptionList").is_some());
         assert!(index.get("Dialog").is_some());
         assert!(index.get("DropdownMenu").is_some());
+        assert!(index.get("EmptyState").is_some());
+        assert!(index.get("FilePicker").is_some());
+        assert!(index.get("Form").is_some());
         assert!(index.get("Input").is_some());
+        assert!(index.get("Kbd").is_some());
+        assert!(index.get("Label").is_some());
+        assert!(index.get("Link").is_some());
+        assert!(index.get("Markdown").is_some());
+        assert!(index.get("MenuBar").is_some());
+        assert!(index.get("NotificationCenter").is_some());
+        assert!(index.get("NumberInput").is_some());
+        assert!(index.get("PasswordInput").is_some());
         assert!(index.get("Popover").is_some());
+        assert!(index.get("ProgressBar").is_some());
         assert!(index.get("Radio").is_some());
+        assert!(index.get("ScrollArea").is_some());
+        assert!(index.get("SearchInput").is_some());
         assert!(index.get("Select").is_some());
+        assert!(index.get("Separator").is_some());
+        assert!(index.get("Skeleton").is_some());
+        assert!(index.get("Slider").is_some());
+        assert!(index.get("SortableList").is_some());
+        assert!(index.get("Sparkline").is_some());
+        assert!(index.get("Spinner").is_some());
+        assert!(index.get("SplitPane").is_some());
+        assert!(index.get("StatusBar").is_some());
+        assert!(index.get("Switch").is_some());
         assert!(index.get("Tabs").is_some());
+        assert!(index.get("Tag").is_some());
+        assert!(index.get("Text").is_some());
         assert!(index.get("Textarea").is_some());
+        assert!(index.get("TitleBar").is_some());
         assert!(index.get("Toast").is_some());
         assert!(index.get("Tooltip").is_some());
     }
@@ -408,7 +873,25 @@ mod tests {
         assert!(result.is_ok(), "Validation failed: {:?}", result.err());
 
         let index = result.unwrap();
-        assert_eq!(index.len(), 12);
+        assert_eq!(index.len(), 48);
+    }
+
+    #[test]
+    fn generate_registry_validated_reports_policy_violations() {
+        let policy: DispositionPolicy = toml::from_str(
+            r#"
+            [[rules]]
+            disposition = "reuse"
+            forbid_licenses = ["GPL"]
+            "#,
+        )
+        .unwrap();
+        let errors = generate_registry_validated_with_policy(&policy).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        let (component, errors) = &errors[0];
+        assert_eq!(component, "Tooltip");
+        assert!(errors.iter().all(|e| e.field == "disposition"));
+        assert!(errors[0].message.starts_with("policy rule 1: "));
     }
 
     #[test]
@@ -457,7 +940,7 @@ mod tests {
 
     #[test]
     fn single_entry_json_roundtrip() {
-        let entry = RegistryEntry::from_contract(&Dialog::contract());
+        let entry = RegistryEntry::from_contract(&catalog::dialog());
         let json = serde_json::to_string_pretty(&entry).expect("serialize");
         let restored: RegistryEntry = serde_json::from_str(&json).expect("deserialize");
 
@@ -0,0 +1,255 @@
+//! Theme token linting, for `gpui theme lint`.
+//!
+//! Where `gpui verify` fails a theme that cannot render, the linter flags
+//! token values that are legal but probably unintended:
+//!
+//! - **Near-duplicates** -- two tokens whose colors differ by less than
+//!   [`NEAR_DUPLICATE_DISTANCE`]; one should likely alias the other.
+//! - **Translucent contrast tokens** -- a text or background token with
+//!   alpha below 1 in a pair `gpui verify` checks for contrast, whose real
+//!   contrast then depends on whatever is drawn beneath it.
+//! - **Unused tokens** -- token paths no registry component lists in its
+//!   `token_dependencies`.
+//!
+//! Themes are linted in their JSON form, like `gpui verify`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use gpui::Rgba;
+use serde::{Deserialize, Serialize};
+use theme::ThemeTokens;
+use theme::engine::all_token_paths;
+use theme::tokens::{one_dark, one_light};
+
+use crate::RegistryIndex;
+use crate::verify::{CONTRAST_PAIRS, color_at, load_theme_json, project_theme_files};
+
+/// Largest RGBA distance, in 0-255 channel steps, at which two different
+/// colors count as near-duplicates.
+pub const NEAR_DUPLICATE_DISTANCE: f32 = 3.0;
+
+/// Two tokens with nearly identical colors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NearDuplicate {
+    pub first: String,
+    pub second: String,
+    /// RGBA distance in channel steps, rounded to two decimals.
+    pub distance: f32,
+}
+
+/// A translucent token in a contrast-checked pair.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranslucentToken {
+    pub path: String,
+    pub alpha: f32,
+    /// The tokens it is checked for contrast against, in pair order.
+    pub paired_with: Vec<String>,
+}
+
+/// Lint findings for one theme.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeLint {
+    pub theme: String,
+    /// The theme file, or `None` for a built-in theme.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<PathBuf>,
+    pub near_duplicates: Vec<NearDuplicate>,
+    pub translucent_tokens: Vec<TranslucentToken>,
+}
+
+impl ThemeLint {
+    /// Number of findings for this theme.
+    pub fn finding_count(&self) -> usize {
+        self.near_duplicates.len() + self.translucent_tokens.len()
+    }
+}
+
+/// Result of `gpui theme lint`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LintReport {
+    pub themes: Vec<ThemeLint>,
+    /// Token paths no registry component depends on. The token set is the
+    /// same for every theme, so this is reported once.
+    pub unused_tokens: Vec<String>,
+}
+
+impl LintReport {
+    /// Whether the linter found nothing.
+    pub fn is_clean(&self) -> bool {
+        self.unused_tokens.is_empty() && self.themes.iter().all(|theme| theme.finding_count() == 0)
+    }
+}
+
+/// Lint a built-in or already-loaded theme.
+pub fn lint_theme(tokens: &ThemeTokens) -> ThemeLint {
+    // Token structs always serialize; the fallback only guards the signature.
+    let json = serde_json::to_value(tokens).unwrap_or_default();
+    lint_json(&tokens.name, None, &json)
+}
+
+/// Lint a theme file: a full token set (possibly incomplete) or a seed.
+pub fn lint_theme_file(path: &Path) -> anyhow::Result<ThemeLint> {
+    let (name, json) = load_theme_json(path)?;
+    Ok(lint_json(&name, Some(path.to_path_buf()), &json))
+}
+
+/// Lint the built-in themes followed by every `themes/*.json` file under
+/// `project_root`, in file name order, and list the tokens no component in
+/// `index` uses.
+pub fn lint_themes(project_root: &Path, index: &RegistryIndex) -> anyhow::Result<LintReport> {
+    let mut themes: Vec<ThemeLint> = [one_dark(), one_light()].iter().map(lint_theme).collect();
+    for path in project_theme_files(project_root)? {
+        themes.push(lint_theme_file(&path)?);
+    }
+    Ok(LintReport {
+        themes,
+        unused_tokens: unused_tokens(index),
+    })
+}
+
+/// Token paths, in mapping order, that no component in `index` lists in
+/// its `token_dependencies`.
+pub fn unused_tokens(index: &RegistryIndex) -> Vec<String> {
+    let used: BTreeSet<&str> = index
+        .list()
+        .iter()
+        .flat_map(|entry| &entry.token_dependencies)
+        .map(|dependency| dependency.path.as_str())
+        .collect();
+    all_token_paths()
+        .into_iter()
+        .filter(|path| !used.contains(path))
+        .map(str::to_string)
+        .collect()
+}
+
+fn lint_json(name: &str, source: Option<PathBuf>, json: &serde_json::Value) -> ThemeLint {
+    let colors: Vec<(&str, [f32; 4])> = all_token_paths()
+        .into_iter()
+        .filter_map(|path| {
+            let rgba = Rgba::from(color_at(json, path)?);
+            Some((path, [rgba.r, rgba.g, rgba.b, rgba.a].map(|c| c * 255.0)))
+        })
+        .collect();
+
+    let mut near_duplicates = Vec::new();
+    for (i, &(first, a)) in colors.iter().enumerate() {
+        for &(second, b) in &colors[i + 1..] {
+            // Fully transparent tokens all draw nothing, whatever their hue.
+            if a[3] < 0.5 && b[3] < 0.5 {
+                continue;
+            }
+            let distance = (0..4).map(|c| (a[c] - b[c]).powi(2)).sum::<f32>().sqrt();
+            // Identical values are an alias already; only near misses are flagged.
+            if (0.5..=NEAR_DUPLICATE_DISTANCE).contains(&distance) {
+                near_duplicates.push(NearDuplicate {
+                    first: first.to_string(),
+                    second: second.to_string(),
+                    distance: (distance * 100.0).round() / 100.0,
+                });
+            }
+        }
+    }
+
+    let mut translucent: BTreeMap<&str, TranslucentToken> = BTreeMap::new();
+    for &(foreground, background, _) in CONTRAST_PAIRS {
+        for (path, other) in [(foreground, background), (background, foreground)] {
+            let Some(color) = color_at(json, path) else {
+                continue;
+            };
+            if color.a < 1.0 {
+                translucent
+                    .entry(path)
+                    .or_insert_with(|| TranslucentToken {
+                        path: path.to_string(),
+                        alpha: (color.a * 100.0).round() / 100.0,
+                        paired_with: Vec::new(),
+                    })
+                    .paired_with
+                    .push(other.to_string());
+            }
+        }
+    }
+
+    ThemeLint {
+        theme: name.to_string(),
+        source,
+        near_duplicates,
+        translucent_tokens: translucent.into_values().collect(),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::verify::THEMES_DIR;
+
+    #[test]
+    fn near_duplicates_and_translucent_contrast_tokens_are_flagged() {
+        let mut json = serde_json::to_value(one_dark()).unwrap();
+        json["text"]["default"] = "#c8ccd4ff".into();
+        json["text"]["accent"] = "#cacdd4ff".into();
+        json["panel"]["background"] = "#20232780".into();
+
+        let lint = lint_json("Hazy", None, &json);
+        let pair = lint
+            .near_duplicates
+            .iter()
+            .find(|d| d.first == "text.default" && d.second == "text.accent")
+            .unwrap();
+        assert_eq!(pair.distance, 2.24);
+        let panel = lint
+            .translucent_tokens
+            .iter()
+            .find(|token| token.path == "panel.background")
+            .unwrap();
+        assert_eq!(panel.alpha, 0.5);
+        assert_eq!(panel.paired_with, ["text.default", "text.muted"]);
+
+        // Identical values are already an alias.
+        json["text"]["accent"] = json["text"]["default"].clone();
+        let lint = lint_json("Hazy", None, &json);
+        assert!(
+            !lint
+                .near_duplicates
+                .iter()
+                .any(|d| d.second == "text.accent")
+        );
+    }
+
+    #[test]
+    fn unused_tokens_are_those_no_component_depends_on() {
+        let index = generate_registry();
+        let unused = unused_tokens(&index);
+        assert!(!unused.contains(&"text.default".to_string()));
+        for path in &unused {
+            assert!(
+                index
+                    .list()
+                    .iter()
+                    .all(|entry| entry.token_dependencies.iter().all(|d| &d.path != path))
+            );
+        }
+
+        let dir =
+            std::env::temp_dir().join(format!("gpui-lint-test-{}-themes", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join(THEMES_DIR)).unwrap();
+        std::fs::write(
+            dir.join("themes/ocean.json"),
+            r##"{"name":"Ocean","appearance":"dark","background":"#0b1622ff","accent":"#4fb3ffff"}"##,
+        )
+        .unwrap();
+        let report = lint_themes(&dir, &index).unwrap();
+        let names: Vec<&str> = report.themes.iter().map(|t| t.theme.as_str()).collect();
+        assert_eq!(names, ["One Dark", "One Light", "Ocean"]);
+        assert_eq!(report.unused_tokens, unused);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
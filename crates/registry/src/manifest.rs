@@ -0,0 +1,274 @@
+//! Project manifest (`gpui-workbench.toml`) of installed components.
+//!
+//! Applying a plan records each installed component's name, registry
+//! version, per-file checksums, and install date, so later commands can tell
+//! what is installed (and whether it drifted) without scanning provenance
+//! files.
+
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::plan::{LayoutKind, Operation, PlanContract, TemplateAdapter};
+
+/// File name of the manifest, relative to the project root.
+pub const MANIFEST_FILE: &str = "gpui-workbench.toml";
+
+/// One installed component in the manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Component name as published (e.g. `"Dialog"`).
+    pub name: String,
+    /// Registry version that was installed.
+    pub version: String,
+    /// UTC date (`YYYY-MM-DD`) of the last install or update.
+    pub installed_on: String,
+    /// Checksums of the installed files, keyed by path relative to the
+    /// project root.
+    #[serde(default)]
+    pub checksums: BTreeMap<String, String>,
+}
+
+/// The `gpui-workbench.toml` manifest.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Layout the components were installed with; commands default to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<LayoutKind>,
+    /// Installed components keyed by lowercase component name.
+    #[serde(default)]
+    pub components: BTreeMap<String, ManifestEntry>,
+}
+
+impl Manifest {
+    /// Parse a manifest from TOML.
+    pub fn from_toml(toml_str: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(toml_str)
+    }
+
+    /// Serialize the manifest to TOML.
+    pub fn to_toml(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string_pretty(self)
+    }
+
+    /// The record for an installed component (case-insensitive).
+    pub fn get(&self, name: &str) -> Option<&ManifestEntry> {
+        self.components.get(&name.to_lowercase())
+    }
+
+    /// Whether a component is recorded as installed.
+    pub fn is_installed(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+
+    /// All installed components, ordered by name.
+    pub fn installed(&self) -> Vec<&ManifestEntry> {
+        self.components.values().collect()
+    }
+
+    /// Record the effect of an applied plan.
+    ///
+    /// Installs and updates record the component (and any dependencies the
+    /// plan installed) with the checksums of the files `layout` attributes to
+    /// it, and remember the plan's layout; removals drop the component.
+    pub fn record_plan(
+        &mut self,
+        plan: &PlanContract,
+        layout: &dyn TemplateAdapter,
+        installed_on: &str,
+    ) {
+        if plan.operation == Operation::Remove {
+            self.components.remove(&plan.component_name.to_lowercase());
+            return;
+        }
+
+        if let Some(kind) = LayoutKind::from_name(&plan.target_layout) {
+            self.layout = Some(kind);
+        }
+        let installed = plan
+            .dependencies
+            .iter()
+            .map(|name| (name, plan.dependency_versions.get(name)))
+            .chain([(&plan.component_name, Some(&plan.component_version))]);
+        for (name, version) in installed {
+            let checksums = plan
+                .file_checksums
+                .iter()
+                .filter(|(path, _)| layout.owns_file(name, path))
+                .filter_map(|(path, checksum)| {
+                    let relative = path.strip_prefix(layout.project_root()).ok()?;
+                    Some((relative.to_string_lossy().into_owned(), checksum.clone()))
+                })
+                .collect();
+            self.components.insert(
+                name.to_lowercase(),
+                ManifestEntry {
+                    name: name.clone(),
+                    version: version.cloned().unwrap_or_else(|| "0.0.0".to_string()),
+                    installed_on: installed_on.to_string(),
+                    checksums,
+                },
+            );
+        }
+    }
+}
+
+/// Today's UTC date as `YYYY-MM-DD`.
+pub fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    civil_date(seconds / 86_400)
+}
+
+/// Format a count of days since 1970-01-01 as a proleptic Gregorian date.
+fn civil_date(days: u64) -> String {
+    // Howard Hinnant's days-to-civil algorithm, shifted to March-based years.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Days since 1970-01-01 of a `YYYY-MM-DD` date; the inverse of [`civil_date`].
+pub(crate) fn days_since_epoch(date: &str) -> Option<u64> {
+    let mut parts = date.trim().splitn(3, '-').map(|part| part.parse::<u64>());
+    let (year, month, day) = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    if !(1970..=9999).contains(&year) || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let year = year - u64::from(month <= 2);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let shifted_month = (month + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = 365 * year_of_era + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    Some(era * 146_097 + day_of_era - 719_468)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::plan::{DefaultLayout, FlatLayout, generate_install_plan, generate_remove_plan};
+
+    #[test]
+    fn empty_manifest_parses() {
+        let manifest = Manifest::from_toml("").unwrap();
+        assert!(manifest.installed().is_empty());
+        assert!(!manifest.is_installed("Dialog"));
+    }
+
+    #[test]
+    fn install_records_component_and_dependencies() {
+        let registry = generate_registry();
+        let layout = DefaultLayout::new("/project");
+        let plan = generate_install_plan(&registry, "select", &layout, &[]).unwrap();
+
+        let mut manifest = Manifest::default();
+        manifest.record_plan(&plan, &layout, "2026-01-02");
+
+        let names: Vec<&str> = manifest
+            .installed()
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["Button", "EmptyState", "Select", "Tooltip"]);
+
+        let select = manifest.get("SELECT").unwrap();
+        assert_eq!(select.version, registry.get("select").unwrap().version);
+        assert_eq!(select.installed_on, "2026-01-02");
+        assert_eq!(
+            select.checksums.get("src/shared/ui/select/mod.rs"),
+            plan.file_checksums
+                .get(&layout.component_dir("Select").join("mod.rs"))
+        );
+        assert!(
+            select
+                .checksums
+                .keys()
+                .all(|path| path.starts_with("src/shared/ui/select/")),
+            "only the component's own files, relative to the project root"
+        );
+        assert_eq!(manifest.layout, Some(LayoutKind::Default));
+    }
+
+    #[test]
+    fn flat_layout_records_module_file_with_component() {
+        let registry = generate_registry();
+        let layout = FlatLayout::new("/project");
+        let plan = generate_install_plan(&registry, "select", &layout, &[]).unwrap();
+
+        let mut manifest = Manifest::default();
+        manifest.record_plan(&plan, &layout, "2026-01-02");
+
+        let select = manifest.get("select").unwrap();
+        assert!(select.checksums.contains_key("src/ui/select.rs"));
+        assert!(!select.checksums.contains_key("src/ui/tooltip.rs"));
+        assert_eq!(manifest.layout, Some(LayoutKind::Flat));
+    }
+
+    #[test]
+    fn remove_drops_component() {
+        let registry = generate_registry();
+        let layout = DefaultLayout::new("/project");
+        let mut manifest = Manifest::default();
+        let install = generate_install_plan(&registry, "tooltip", &layout, &[]).unwrap();
+        manifest.record_plan(&install, &layout, "2026-01-02");
+
+        let remove = generate_remove_plan(registry.get("tooltip").unwrap(), &layout, &[]);
+        manifest.record_plan(&remove, &layout, "2026-01-03");
+        assert!(!manifest.is_installed("Tooltip"));
+    }
+
+    #[test]
+    fn manifest_toml_roundtrip() {
+        let registry = generate_registry();
+        let layout = DefaultLayout::new("/project");
+        let plan = generate_install_plan(&registry, "dialog", &layout, &[]).unwrap();
+        let mut manifest = Manifest::default();
+        manifest.record_plan(&plan, &layout, "2026-01-02");
+
+        let toml_str = manifest.to_toml().unwrap();
+        assert!(toml_str.contains("[components.dialog]"));
+        assert!(toml_str.contains("installed_on = \"2026-01-02\""));
+        assert_eq!(Manifest::from_toml(&toml_str).unwrap(), manifest);
+    }
+
+    #[test]
+    fn civil_date_handles_epoch_and_leap_days() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(11_016), "2000-02-29");
+        assert_eq!(civil_date(20_454), "2026-01-01");
+    }
+
+    #[test]
+    fn days_since_epoch_inverts_civil_date() {
+        for days in [0, 59, 11_016, 20_454, 20_742] {
+            assert_eq!(days_since_epoch(&civil_date(days)), Some(days));
+        }
+        assert_eq!(days_since_epoch("2026-13-01"), None);
+        assert_eq!(days_since_epoch("yesterday"), None);
+    }
+}
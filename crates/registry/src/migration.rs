@@ -0,0 +1,174 @@
+//! Migration guidance for breaking contract changes.
+//!
+//! When an update crosses a breaking version boundary, renamed props are
+//! looked up in the target project's sources so the plan can list every call
+//! site that still uses the old API. Notes are advisory: nothing here rewrites
+//! user code.
+
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::RegistryEntry;
+use crate::version::Version;
+
+/// A source line that uses an API affected by a migration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SourceLocation {
+    /// File containing the usage.
+    pub file_path: PathBuf,
+    /// 1-based line number.
+    pub line: usize,
+    /// The trimmed source line.
+    pub text: String,
+}
+
+/// An advisory migration note attached to an update plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MigrationNote {
+    /// Human-readable summary, e.g. "Button 1.0.0: prop `label` renamed to `text`; 4 usages found".
+    pub message: String,
+    /// Call sites that still use the old API.
+    pub locations: Vec<SourceLocation>,
+}
+
+/// Whether moving from `from` to `to` crosses a breaking (semver-major) boundary.
+///
+/// Follows Cargo's convention for `0.x` versions: a minor bump is breaking.
+pub fn is_breaking_change(from: &str, to: &str) -> bool {
+    match (Version::parse(from), Version::parse(to)) {
+        (Some(from), Some(to)) if from.major == 0 && to.major == 0 => to.minor > from.minor,
+        (Some(from), Some(to)) => to.major > from.major,
+        _ => false,
+    }
+}
+
+/// Build migration notes for updating `entry` from `installed_version`.
+///
+/// Returns no notes unless the update is breaking. Each prop renamed after
+/// `installed_version` gets one note listing the `.old_name(` builder calls
+/// found in `sources` (path, contents).
+pub fn migration_notes(
+    entry: &RegistryEntry,
+    installed_version: &str,
+    sources: &[(PathBuf, String)],
+) -> Vec<MigrationNote> {
+    if !is_breaking_change(installed_version, &entry.version) {
+        return Vec::new();
+    }
+    let installed = Version::parse(installed_version);
+
+    entry
+        .prop_renames
+        .iter()
+        .filter(|rename| Version::parse(&rename.since) > installed)
+        .map(|rename| {
+            let locations = find_usages(&format!(".{}(", rename.from), sources);
+            MigrationNote {
+                message: format!(
+                    "{} {}: prop `{}` renamed to `{}`; {} usage{} found",
+                    entry.name,
+                    rename.since,
+                    rename.from,
+                    rename.to,
+                    locations.len(),
+                    if locations.len() == 1 { "" } else { "s" },
+                ),
+                locations,
+            }
+        })
+        .collect()
+}
+
+/// Every line in `sources` containing `needle`.
+fn find_usages(needle: &str, sources: &[(PathBuf, String)]) -> Vec<SourceLocation> {
+    sources
+        .iter()
+        .flat_map(|(path, content)| {
+            content
+                .lines()
+                .enumerate()
+                .filter(|(_, line)| line.contains(needle))
+                .map(|(i, line)| SourceLocation {
+                    file_path: path.clone(),
+                    line: i + 1,
+                    text: line.trim().to_string(),
+                })
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::{ComponentContract, ComponentState};
+
+    fn button_v1() -> RegistryEntry {
+        let contract = ComponentContract::builder("Button", "1.0.0")
+            .required_prop("id", "ElementId", "Unique identifier")
+            .optional_prop("text", "Option<SharedString>", "None", "Button text")
+            .renamed_prop("label", "text", "1.0.0")
+            .state(ComponentState::Active)
+            .build();
+        RegistryEntry::from_contract(&contract)
+    }
+
+    #[test]
+    fn breaking_change_follows_semver() {
+        assert!(is_breaking_change("0.1.0", "0.2.0"));
+        assert!(!is_breaking_change("0.1.0", "0.1.3"));
+        assert!(is_breaking_change("0.9.1", "1.0.0"));
+        assert!(is_breaking_change("1.4.0", "2.0.0"));
+        assert!(!is_breaking_change("1.4.0", "1.9.0"));
+        assert!(!is_breaking_change("2.0.0", "1.0.0"));
+        assert!(!is_breaking_change("dev", "1.0.0"));
+    }
+
+    #[test]
+    fn renamed_prop_lists_call_sites() {
+        let sources = vec![
+            (
+                PathBuf::from("src/app.rs"),
+                "Button::new(\"ok\")\n    .label(\"OK\")\n".to_string(),
+            ),
+            (
+                PathBuf::from("src/form.rs"),
+                "let b = Button::new(\"go\").label(\"Go\");\nlet l = Label::new(\"x\");\n"
+                    .to_string(),
+            ),
+        ];
+
+        let notes = migration_notes(&button_v1(), "0.3.0", &sources);
+
+        assert_eq!(notes.len(), 1);
+        assert_eq!(
+            notes[0].message,
+            "Button 1.0.0: prop `label` renamed to `text`; 2 usages found"
+        );
+        assert_eq!(notes[0].locations[0].line, 2);
+        assert_eq!(notes[0].locations[0].text, ".label(\"OK\")");
+        assert_eq!(
+            notes[0].locations[1].file_path,
+            PathBuf::from("src/form.rs")
+        );
+    }
+
+    #[test]
+    fn non_breaking_or_already_migrated_updates_have_no_notes() {
+        let sources = vec![(PathBuf::from("src/app.rs"), ".label(\"OK\")".to_string())];
+        let mut entry = button_v1();
+
+        assert!(migration_notes(&entry, "1.0.0", &sources).is_empty());
+
+        entry.version = "2.0.0".to_string();
+        assert!(
+            migration_notes(&entry, "1.0.0", &sources).is_empty(),
+            "rename predates the installed version"
+        );
+    }
+}
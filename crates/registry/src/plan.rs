@@ -7,19 +7,29 @@
 //!
 //! The plan does NOT mutate files -- only `apply` does (FR-001, FR-002).
 
+use std::cmp::Reverse;
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "gpui")]
+use theme::ThemeTokens;
+#[cfg(feature = "gpui")]
+use theme::tokens::{one_dark, one_light};
 
-use crate::RegistryEntry;
+use crate::migration::{MigrationNote, migration_notes};
+use crate::sources;
+use crate::timing;
+use crate::tokens::TokenSet;
+use crate::{RegistryEntry, RegistryIndex, ResolveError};
 
 // ---------------------------------------------------------------------------
 // Core plan types
 // ---------------------------------------------------------------------------
 
 /// The operation being planned.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Operation {
     /// Install a new component.
@@ -31,7 +41,9 @@ pub enum Operation {
 }
 
 /// The action to perform on a single file.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
 #[serde(rename_all = "snake_case")]
 pub enum FileAction {
     /// Create a new file.
@@ -43,7 +55,7 @@ pub enum FileAction {
 }
 
 /// The strategy for modifying a file.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum MutationStrategy {
     /// Write the full file contents (for new files or full replacements).
@@ -52,14 +64,17 @@ pub enum MutationStrategy {
     AppendExport,
     /// Insert a `use` import statement.
     InsertUse,
-    /// Replace a specific section identified by markers.
+    /// Replace the section between `// gpui:begin:<id>` and `// gpui:end:<id>`
+    /// markers with the marked section in the content, appending it when the
+    /// file has no such section. A section with an empty body is removed.
+    /// Content without markers removes every matching line instead.
     ReplaceSection,
     /// Remove the entire file.
     DeleteFile,
 }
 
 /// A single file mutation in the plan.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct FileMutation {
     /// The action to perform.
     pub action: FileAction,
@@ -67,15 +82,31 @@ pub struct FileMutation {
     pub file_path: PathBuf,
     /// How to apply the mutation.
     pub strategy: MutationStrategy,
-    /// The content to write (for Create/Modify) or the pattern to match (for ReplaceSection).
-    /// Empty for Delete.
+    /// The content to write (for Create/Modify), or the marked section (or
+    /// line to remove) for ReplaceSection. Empty for Delete.
     pub content: String,
     /// Human-readable description of what this mutation does.
     pub description: String,
+    /// Why the mutation is in the plan (e.g. "created because required_files
+    /// lists crates/components/src/dialog.rs"). Generation fills it in;
+    /// agents and reviewers may extend it with [`add_rationale`](Self::add_rationale).
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub rationale: String,
+}
+
+impl FileMutation {
+    /// Append a reason to [`rationale`](Self::rationale), after any earlier
+    /// ones.
+    pub fn add_rationale(&mut self, reason: impl AsRef<str>) {
+        if !self.rationale.is_empty() {
+            self.rationale.push_str("; ");
+        }
+        self.rationale.push_str(reason.as_ref());
+    }
 }
 
 /// A detected conflict with an existing file.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Conflict {
     /// The file that conflicts.
     pub file_path: PathBuf,
@@ -83,8 +114,76 @@ pub struct Conflict {
     pub reason: String,
 }
 
+/// How to resolve a [`Conflict`] with an existing file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    /// Keep the existing file and drop the planned write.
+    Skip,
+    /// Replace the existing file.
+    Overwrite,
+    /// Copy the existing file to `<file>.bak`, then replace it.
+    Backup,
+    /// Keep the existing file and write the planned one as `<stem>.new.<ext>`.
+    Rename,
+}
+
+impl ConflictResolution {
+    /// All resolutions, in prompt order.
+    pub const ALL: [ConflictResolution; 4] = [
+        ConflictResolution::Skip,
+        ConflictResolution::Overwrite,
+        ConflictResolution::Backup,
+        ConflictResolution::Rename,
+    ];
+
+    /// The resolution's name, as accepted by `gpui add --on-conflict`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ConflictResolution::Skip => "skip",
+            ConflictResolution::Overwrite => "overwrite",
+            ConflictResolution::Backup => "backup",
+            ConflictResolution::Rename => "rename",
+        }
+    }
+
+    /// Look up a resolution by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|resolution| resolution.name() == name)
+    }
+}
+
+impl std::str::FromStr for ConflictResolution {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL
+                .iter()
+                .map(|resolution| resolution.name())
+                .collect();
+            format!(
+                "unknown conflict resolution '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
+}
+
+/// A conflict resolved while preparing the plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ConflictDecision {
+    /// The file that conflicted.
+    pub file_path: PathBuf,
+    /// How the conflict was resolved.
+    pub resolution: ConflictResolution,
+}
+
 /// A provenance action for a file that needs attribution metadata.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct ProvenanceAction {
     /// The file that needs provenance metadata.
     pub file_path: PathBuf,
@@ -101,7 +200,7 @@ pub struct ProvenanceAction {
 /// This is the JSON schema for `plan` and `apply` payloads. An agent or human
 /// can read this plan and predict exactly which files will be created, modified,
 /// or deleted (FR-016, AC-010).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PlanContract {
     /// The operation being planned.
     pub operation: Operation,
@@ -109,16 +208,36 @@ pub struct PlanContract {
     pub component_name: String,
     /// Target component version.
     pub component_version: String,
-    /// Ordered list of file mutations to apply.
+    /// File mutations, applied in order. Canonically sorted by action
+    /// (create, modify, delete), then path -- deepest first for deletes, so
+    /// directories empty out before their parents -- then strategy, content,
+    /// description, and rationale.
     pub mutations: Vec<FileMutation>,
-    /// Detected conflicts (empty if none).
+    /// Detected conflicts (empty if none), sorted by path, then reason.
     pub conflicts: Vec<Conflict>,
-    /// Files requiring provenance attribution.
+    /// Files requiring provenance attribution, sorted by path, then source.
     pub provenance_actions: Vec<ProvenanceAction>,
     /// File checksums for integrity verification (supports Phase 1 `doctor` command).
     pub file_checksums: BTreeMap<PathBuf, String>,
     /// The target layout used for this plan.
     pub target_layout: String,
+    /// Advisory notes for breaking changes; never applied automatically.
+    /// Sorted by message, with each note's locations sorted by path, then line.
+    #[serde(default)]
+    pub migration_notes: Vec<MigrationNote>,
+    /// Dependency components installed by this plan, in install order.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    /// Registry version of each dependency in `dependencies`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dependency_versions: BTreeMap<String, String>,
+    /// Conflicts resolved before applying, in the order they were decided.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resolutions: Vec<ConflictDecision>,
+    /// Free-form notes from agents and reviewers, keyed by topic (e.g.
+    /// `"reviewer"`). Never read by `apply`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub annotations: BTreeMap<String, String>,
 }
 
 impl PlanContract {
@@ -141,6 +260,676 @@ impl PlanContract {
     pub fn mutation_count(&self) -> usize {
         self.mutations.len()
     }
+
+    /// Set the annotation for `key`, replacing any earlier one.
+    pub fn annotate(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.annotations.insert(key.into(), value.into());
+    }
+
+    /// Render the plan as an indented tree: the operation, then each
+    /// mutation with its description and rationale, then conflicts,
+    /// dependencies, and annotations.
+    pub fn to_tree(&self) -> String {
+        let mut tree = format!(
+            "{} {} v{} ({} layout)\n",
+            format!("{:?}", self.operation).to_lowercase(),
+            self.component_name,
+            self.component_version,
+            self.target_layout
+        );
+
+        let mut branches: Vec<(String, Vec<String>)> = self
+            .mutations
+            .iter()
+            .map(|mutation| {
+                let mut details = vec![mutation.description.clone()];
+                if !mutation.rationale.is_empty() {
+                    details.push(format!("why: {}", mutation.rationale));
+                }
+                let strategy = serde_json::to_value(&mutation.strategy)
+                    .ok()
+                    .and_then(|value| value.as_str().map(str::to_string))
+                    .unwrap_or_default();
+                (
+                    format!(
+                        "{} {} ({strategy})",
+                        format!("{:?}", mutation.action).to_lowercase(),
+                        mutation.file_path.display()
+                    ),
+                    details,
+                )
+            })
+            .collect();
+        if !self.conflicts.is_empty() {
+            branches.push((
+                "conflicts".to_string(),
+                self.conflicts
+                    .iter()
+                    .map(|c| format!("{}: {}", c.file_path.display(), c.reason))
+                    .collect(),
+            ));
+        }
+        if !self.dependencies.is_empty() {
+            branches.push((
+                format!("dependencies: {}", self.dependencies.join(", ")),
+                Vec::new(),
+            ));
+        }
+        if !self.annotations.is_empty() {
+            branches.push((
+                "annotations".to_string(),
+                self.annotations
+                    .iter()
+                    .map(|(key, value)| format!("{key}: {value}"))
+                    .collect(),
+            ));
+        }
+
+        let last = branches.len().saturating_sub(1);
+        for (i, (label, details)) in branches.into_iter().enumerate() {
+            let (branch, indent) = if i == last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            tree.push_str(&format!("{branch}{label}\n"));
+            for detail in details {
+                tree.push_str(&format!("{indent}  {detail}\n"));
+            }
+        }
+        tree
+    }
+
+    /// Sort the plan's mutations, conflicts, provenance actions, and
+    /// migration notes into canonical order (the sort keys are documented on
+    /// each field).
+    ///
+    /// Every generator and rewrite ends with this, so a plan's JSON depends
+    /// only on its contents, not on the order it was assembled in -- merged
+    /// dependency plans included. `dependencies` and `resolutions` keep
+    /// their order, which is meaningful.
+    pub fn canonicalize(&mut self) {
+        self.mutations
+            .sort_by(|a, b| mutation_key(a).cmp(&mutation_key(b)));
+        self.conflicts
+            .sort_by(|a, b| (&a.file_path, &a.reason).cmp(&(&b.file_path, &b.reason)));
+        self.provenance_actions
+            .sort_by(|a, b| (&a.file_path, &a.source).cmp(&(&b.file_path, &b.source)));
+        for note in &mut self.migration_notes {
+            note.locations
+                .sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+        }
+        self.migration_notes
+            .sort_by(|a, b| a.message.cmp(&b.message));
+    }
+
+    /// Replace a component's generated files with published sources.
+    ///
+    /// Each source (path relative to the component directory) replaces the
+    /// planned file at the same path, or is added to the plan. A published `mod.rs` becomes the layout's component module
+    /// file. Checksums follow the new contents, and added files that already
+    /// exist in the target are reported as conflicts.
+    pub fn use_sources(
+        &mut self,
+        layout: &dyn TemplateAdapter,
+        component_name: &str,
+        sources: &BTreeMap<String, String>,
+        existing_files: &[PathBuf],
+    ) {
+        let component_dir = layout.component_dir(component_name);
+        for (relative_path, content) in sources {
+            let file_path = if relative_path == "mod.rs" {
+                layout.component_module_file(component_name)
+            } else {
+                component_dir.join(relative_path)
+            };
+            self.file_checksums
+                .insert(file_path.clone(), simple_checksum(content));
+
+            if let Some(mutation) = self
+                .mutations
+                .iter_mut()
+                .find(|m| m.file_path == file_path && m.strategy == MutationStrategy::WriteFile)
+            {
+                mutation.content = content.clone();
+                mutation.add_rationale(format!(
+                    "content replaced by the registry's published {relative_path}"
+                ));
+                continue;
+            }
+
+            if existing_files.contains(&file_path) {
+                self.conflicts.push(Conflict {
+                    file_path: file_path.clone(),
+                    reason: format!("File already exists; would overwrite {relative_path}"),
+                });
+            }
+            self.mutations.push(FileMutation {
+                action: FileAction::Create,
+                file_path,
+                strategy: MutationStrategy::WriteFile,
+                content: content.clone(),
+                description: format!("Install {component_name} published source"),
+                rationale: format!(
+                    "created because the registry publishes {relative_path} for {component_name}"
+                ),
+            });
+        }
+        self.canonicalize();
+    }
+
+    /// Add default values for tokens the target does not define.
+    ///
+    /// Rewrites each component's token section in the plan (see
+    /// [`token_section_with_defaults`]) against `defined`, the token set
+    /// parsed from the target's theme tokens file, covering its token
+    /// dependencies and any other token its installed sources read. The
+    /// sources are then pointed at the generated defaults (see
+    /// [`sources::use_token_defaults`]), so installed components compile
+    /// before the app's theme catches up. Removal sections are left as they
+    /// are, and layouts whose components read the workbench `theme` crate,
+    /// which defines every token, are left alone.
+    pub fn define_missing_tokens(
+        &mut self,
+        index: &RegistryIndex,
+        defined: &TokenSet,
+        layout: &dyn TemplateAdapter,
+    ) {
+        if !layout.theme_module_path().starts_with("crate::") {
+            return;
+        }
+        let sections: Vec<(usize, &RegistryEntry)> = self
+            .mutations
+            .iter()
+            .enumerate()
+            .filter(|(_, mutation)| mutation.strategy == MutationStrategy::ReplaceSection)
+            .filter_map(|(position, mutation)| {
+                let entry = section_id(&mutation.content)
+                    .and_then(|id| id.strip_suffix("-tokens"))
+                    .and_then(|name| index.get(name))?;
+                (mutation.content != marked_section(&token_section_id(&entry.name), ""))
+                    .then_some((position, entry))
+            })
+            .collect();
+
+        for (position, entry) in sections {
+            let component_dir = layout.component_dir(&entry.name);
+            let is_source = |mutation: &FileMutation| {
+                mutation.strategy == MutationStrategy::WriteFile
+                    && mutation.file_path.starts_with(&component_dir)
+                    && mutation
+                        .file_path
+                        .extension()
+                        .is_some_and(|ext| ext == "rs")
+            };
+            let mut missing: Vec<&str> = entry
+                .token_dependencies
+                .iter()
+                .map(|dependency| dependency.path.as_str())
+                .collect();
+            for path in token_paths() {
+                let read = self
+                    .mutations
+                    .iter()
+                    .filter(|mutation| is_source(mutation))
+                    .any(|mutation| sources::reads_token(&mutation.content, path));
+                if read && !missing.contains(&path) {
+                    missing.push(path);
+                }
+            }
+            missing.retain(|path| !defines_token(defined, path));
+            self.mutations[position].content = token_section_with_defaults(entry, &missing);
+
+            let paths: Vec<String> = token_defaults(&missing)
+                .into_iter()
+                .map(|(path, _, _)| path.to_string())
+                .collect();
+            if paths.is_empty() {
+                continue;
+            }
+            let trait_path = format!(
+                "{}::tokens::{}",
+                layout.theme_module_path(),
+                token_defaults_trait(&entry.name)
+            );
+            for mutation in self.mutations.iter_mut().filter(|m| is_source(m)) {
+                let content = sources::use_token_defaults(&mutation.content, &trait_path, &paths);
+                if content == mutation.content {
+                    continue;
+                }
+                self.file_checksums
+                    .insert(mutation.file_path.clone(), simple_checksum(&content));
+                mutation.content = content;
+                mutation.add_rationale(format!(
+                    "reads tokens the target theme lacks through {trait_path}"
+                ));
+            }
+        }
+    }
+
+    /// Resolve the conflict on `file_path` and rewrite the plan to match.
+    ///
+    /// `Skip` drops the planned write (with its checksum and provenance),
+    /// `Rename` retargets it, and `Backup` adds a write of `existing` (the
+    /// file's current contents) to `<file>.bak`. The decision
+    /// is recorded in [`resolutions`](Self::resolutions). Returns `false`
+    /// when no conflict is recorded for `file_path`.
+    pub fn resolve_conflict(
+        &mut self,
+        file_path: &Path,
+        resolution: ConflictResolution,
+        existing: &str,
+    ) -> bool {
+        let Some(index) = self.conflicts.iter().position(|c| c.file_path == file_path) else {
+            return false;
+        };
+        self.conflicts.remove(index);
+
+        match resolution {
+            ConflictResolution::Skip => {
+                self.mutations.retain(|m| !writes_file(m, file_path));
+                self.file_checksums.remove(file_path);
+                self.provenance_actions
+                    .retain(|action| action.file_path != file_path);
+            }
+            ConflictResolution::Overwrite => {}
+            ConflictResolution::Backup => {
+                self.mutations.push(FileMutation {
+                    action: FileAction::Create,
+                    file_path: backup_path(file_path),
+                    strategy: MutationStrategy::WriteFile,
+                    content: existing.to_string(),
+                    description: format!("Back up existing {}", file_path.display()),
+                    rationale: format!(
+                        "created because the conflict on {} was resolved with backup",
+                        file_path.display()
+                    ),
+                });
+            }
+            ConflictResolution::Rename => {
+                let renamed = renamed_path(file_path);
+                for mutation in &mut self.mutations {
+                    if writes_file(mutation, file_path) {
+                        mutation.file_path = renamed.clone();
+                        mutation.add_rationale(format!(
+                            "renamed because {} already exists",
+                            file_path.display()
+                        ));
+                    }
+                }
+                if let Some(checksum) = self.file_checksums.remove(file_path) {
+                    self.file_checksums.insert(renamed.clone(), checksum);
+                }
+                for action in &mut self.provenance_actions {
+                    if action.file_path == file_path {
+                        action.file_path = renamed.clone();
+                    }
+                }
+            }
+        }
+
+        self.resolutions.push(ConflictDecision {
+            file_path: file_path.to_path_buf(),
+            resolution,
+        });
+        self.canonicalize();
+        true
+    }
+
+    /// Report conflicts for marked sections that cannot be replaced.
+    ///
+    /// `read` returns a file's current contents, or `None` when it does not
+    /// exist. Malformed markers are always a conflict. Missing markers are a
+    /// conflict for updates and removals, where the section was installed
+    /// earlier and has since been edited away; installs append the section.
+    pub fn detect_section_conflicts(&mut self, read: impl Fn(&Path) -> Option<String>) {
+        for mutation in &self.mutations {
+            if mutation.strategy != MutationStrategy::ReplaceSection {
+                continue;
+            }
+            let Some(id) = section_id(&mutation.content) else {
+                continue;
+            };
+            let Some(existing) = read(&mutation.file_path) else {
+                continue;
+            };
+            let reason = match find_section(&existing, id) {
+                Err(error) => format!("Malformed section `{id}`: {error}"),
+                Ok(None) if self.operation != Operation::Add => format!(
+                    "Section markers `{}` are missing; merge the section manually",
+                    section_begin(id)
+                ),
+                Ok(_) => continue,
+            };
+            self.conflicts.push(Conflict {
+                file_path: mutation.file_path.clone(),
+                reason,
+            });
+        }
+        self.canonicalize();
+    }
+}
+
+/// A mutation's canonical sort key (see [`PlanContract::mutations`]).
+fn mutation_key(mutation: &FileMutation) -> impl Ord + '_ {
+    let depth = match mutation.action {
+        FileAction::Delete => mutation.file_path.components().count(),
+        FileAction::Create | FileAction::Modify => 0,
+    };
+    (
+        mutation.action,
+        Reverse(depth),
+        &mutation.file_path,
+        &mutation.strategy,
+        &mutation.content,
+        &mutation.description,
+        &mutation.rationale,
+    )
+}
+
+/// Whether `mutation` writes `file_path`: the whole file, or a marked section.
+fn writes_file(mutation: &FileMutation, file_path: &Path) -> bool {
+    mutation.file_path == file_path
+        && matches!(
+            mutation.strategy,
+            MutationStrategy::WriteFile | MutationStrategy::ReplaceSection
+        )
+}
+
+/// Where [`ConflictResolution::Backup`] copies a file: `mod.rs` -> `mod.rs.bak`.
+fn backup_path(file_path: &Path) -> PathBuf {
+    let mut path = file_path.as_os_str().to_owned();
+    path.push(".bak");
+    PathBuf::from(path)
+}
+
+/// Where [`ConflictResolution::Rename`] writes a file: `mod.rs` -> `mod.new.rs`.
+fn renamed_path(file_path: &Path) -> PathBuf {
+    let stem = file_path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match file_path.extension() {
+        Some(extension) => format!("{stem}.new.{}", extension.to_string_lossy()),
+        None => format!("{stem}.new"),
+    };
+    file_path.with_file_name(name)
+}
+
+// ---------------------------------------------------------------------------
+// Marked sections
+// ---------------------------------------------------------------------------
+
+/// Prefix of the line that opens a plan-managed section.
+const SECTION_BEGIN: &str = "// gpui:begin:";
+/// Prefix of the line that closes a plan-managed section.
+const SECTION_END: &str = "// gpui:end:";
+
+/// The line opening section `id`.
+pub fn section_begin(id: &str) -> String {
+    format!("{SECTION_BEGIN}{id}")
+}
+
+/// The line closing section `id`.
+pub fn section_end(id: &str) -> String {
+    format!("{SECTION_END}{id}")
+}
+
+/// A complete marked section: begin marker, `body`, end marker.
+///
+/// An empty `body` yields the markers alone, which
+/// [`MutationStrategy::ReplaceSection`] treats as a removal.
+pub fn marked_section(id: &str, body: &str) -> String {
+    let mut section = section_begin(id);
+    section.push('\n');
+    for line in body.lines() {
+        section.push_str(line);
+        section.push('\n');
+    }
+    section.push_str(&section_end(id));
+    section.push('\n');
+    section
+}
+
+/// The id of the section `content` opens, if its first line is a begin marker.
+pub fn section_id(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .next()?
+        .trim()
+        .strip_prefix(SECTION_BEGIN)
+        .filter(|id| !id.is_empty())
+}
+
+/// Why a file's markers for a section cannot be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionError {
+    /// A begin marker with no matching end marker.
+    MissingEnd,
+    /// An end marker with no matching begin marker.
+    MissingBegin,
+    /// The end marker comes before the begin marker.
+    EndBeforeBegin,
+    /// More than one begin or end marker for the same id.
+    Duplicate,
+}
+
+impl std::fmt::Display for SectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SectionError::MissingEnd => write!(f, "begin marker has no end marker"),
+            SectionError::MissingBegin => write!(f, "end marker has no begin marker"),
+            SectionError::EndBeforeBegin => write!(f, "end marker comes before begin marker"),
+            SectionError::Duplicate => write!(f, "markers appear more than once"),
+        }
+    }
+}
+
+impl std::error::Error for SectionError {}
+
+/// Locate section `id` in `text`.
+///
+/// Returns the line range from the begin marker through the end marker, or
+/// `None` when `text` has neither marker.
+pub fn find_section(text: &str, id: &str) -> Result<Option<std::ops::Range<usize>>, SectionError> {
+    let begin = section_begin(id);
+    let end = section_end(id);
+    let mut begins = Vec::new();
+    let mut ends = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line == begin {
+            begins.push(index);
+        } else if line == end {
+            ends.push(index);
+        }
+    }
+    match (begins.as_slice(), ends.as_slice()) {
+        ([], []) => Ok(None),
+        ([_], []) => Err(SectionError::MissingEnd),
+        ([], [_]) => Err(SectionError::MissingBegin),
+        ([first], [last]) if first < last => Ok(Some(*first..*last + 1)),
+        ([_], [_]) => Err(SectionError::EndBeforeBegin),
+        _ => Err(SectionError::Duplicate),
+    }
+}
+
+/// Replace the section that `section` (a [`marked_section`]) names in
+/// `existing`, append it when `existing` has no such section, or drop the
+/// section when `section` has an empty body.
+pub fn replace_section(existing: &str, section: &str) -> Result<String, SectionError> {
+    let Some(id) = section_id(section) else {
+        return Ok(existing.to_string());
+    };
+    let remove = section.lines().count() <= 2;
+    let mut lines: Vec<&str> = existing.lines().collect();
+    match find_section(existing, id)? {
+        Some(range) => {
+            let replacement = if remove {
+                Vec::new()
+            } else {
+                section.lines().collect()
+            };
+            lines.splice(range, replacement);
+        }
+        None if remove => return Ok(existing.to_string()),
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push("");
+            }
+            lines.extend(section.lines());
+        }
+    }
+    let mut replaced = lines.join("\n");
+    if !replaced.is_empty() {
+        replaced.push('\n');
+    }
+    Ok(replaced)
+}
+
+/// The marked section recording the theme tokens a component reads.
+///
+/// Installed into the layout's theme tokens file as a `<NAME>_TOKENS` list
+/// of token paths, so the target app can see (and `gpui verify` can check)
+/// which tokens each installed component depends on.
+pub fn token_section(entry: &RegistryEntry) -> String {
+    token_section_with_defaults(entry, &[])
+}
+
+/// [`token_section`] plus a `<Name>TokenDefaults` trait for `missing`, the
+/// token paths the component reads that the target theme lacks.
+///
+/// The trait has one method per missing token, named after its path
+/// (`ghost_element.hover` becomes `ghost_element_hover()`), implemented for
+/// `ThemeTokens` with the One Dark or One Light color matching the theme's
+/// `appearance`. [`sources::use_token_defaults`] points installed sources at
+/// these methods. Paths neither built-in theme defines as a color are left
+/// out, as is the whole trait in builds without the `gpui` feature.
+pub fn token_section_with_defaults(entry: &RegistryEntry, missing: &[&str]) -> String {
+    let mut paths: Vec<&str> = Vec::new();
+    for dependency in &entry.token_dependencies {
+        if !paths.contains(&dependency.path.as_str()) {
+            paths.push(&dependency.path);
+        }
+    }
+    let const_name = entry.name.to_uppercase();
+    let mut body = format!(
+        "/// Theme tokens read by the {} component.\npub const {const_name}_TOKENS: &[&str] = &[\n",
+        entry.name,
+    );
+    for path in &paths {
+        body.push_str(&format!("    \"{path}\",\n"));
+    }
+    body.push_str("];");
+
+    let defaults = token_defaults(missing);
+    if !defaults.is_empty() {
+        let trait_name = token_defaults_trait(&entry.name);
+        body.push_str(&format!(
+            "\n\n/// Tokens the {name} component reads that `ThemeTokens` does not define,\n\
+             /// with One Dark and One Light defaults. The installed {name} source\n\
+             /// calls these in place of the missing fields.\n\
+             pub trait {trait_name} {{\n",
+            name = entry.name,
+        ));
+        for (path, _, _) in &defaults {
+            body.push_str(&format!(
+                "    /// `{path}`\n    fn {}(&self) -> gpui::Hsla;\n",
+                token_method(path)
+            ));
+        }
+        body.push_str(&format!("}}\n\nimpl {trait_name} for ThemeTokens {{\n"));
+        for (path, dark, light) in &defaults {
+            body.push_str(&format!(
+                "    fn {}(&self) -> gpui::Hsla {{\n        \
+                 match self.appearance {{\n            \
+                 ThemeAppearance::Dark => gpui::rgba(0x{dark}).into(),\n            \
+                 ThemeAppearance::Light => gpui::rgba(0x{light}).into(),\n        \
+                 }}\n    }}\n",
+                token_method(path)
+            ));
+        }
+        body.push('}');
+    }
+    marked_section(&token_section_id(&entry.name), &body)
+}
+
+/// `(path, One Dark, One Light)` for each path in `missing` that both
+/// built-in themes define as a color, as `rrggbbaa` hex.
+fn token_defaults<'a>(missing: &[&'a str]) -> Vec<(&'a str, String, String)> {
+    let mut defaults: Vec<(&str, String, String)> = Vec::new();
+    for &path in missing {
+        if defaults.iter().any(|(seen, _, _)| *seen == path) {
+            continue;
+        }
+        let Some((dark, light)) = default_token_values(path) else {
+            continue;
+        };
+        let hex = |value: &str| {
+            let digits = value.strip_prefix('#')?;
+            (digits.len() == 8 && digits.chars().all(|c| c.is_ascii_hexdigit()))
+                .then(|| digits.to_string())
+        };
+        if let (Some(dark), Some(light)) = (hex(&dark), hex(&light)) {
+            defaults.push((path, dark, light));
+        }
+    }
+    defaults
+}
+
+/// Whether `defined` has the token at `path`. Paths into a nested token
+/// struct (`status.error.border`) count as defined when the struct is.
+fn defines_token(defined: &TokenSet, path: &str) -> bool {
+    let mut segments = path.splitn(3, '.');
+    match (segments.next(), segments.next()) {
+        (Some(group), Some(field)) => defined.defines(&format!("{group}.{field}")),
+        _ => defined.defines(path),
+    }
+}
+
+/// Name of the trait holding a component's token defaults (e.g.
+/// `DialogTokenDefaults`).
+pub fn token_defaults_trait(component_name: &str) -> String {
+    format!("{component_name}TokenDefaults")
+}
+
+/// Name of the default method standing in for a token path (e.g.
+/// `ghost_element_hover` for `ghost_element.hover`).
+pub fn token_method(path: &str) -> String {
+    path.replace('.', "_")
+}
+
+/// One Dark and One Light hex values of a token path, if both define it.
+#[cfg(feature = "gpui")]
+fn default_token_values(path: &str) -> Option<(String, String)> {
+    let hex = |tokens: ThemeTokens| {
+        let json = serde_json::to_value(tokens).ok()?;
+        path.split('.')
+            .try_fold(&json, |value, segment| value.get(segment))?
+            .as_str()
+            .map(str::to_string)
+    };
+    Some((hex(one_dark())?, hex(one_light())?))
+}
+
+/// Without the built-in themes there are no defaults to offer.
+#[cfg(not(feature = "gpui"))]
+fn default_token_values(_path: &str) -> Option<(String, String)> {
+    None
+}
+
+/// Every token path the built-in themes define.
+#[cfg(feature = "gpui")]
+fn token_paths() -> Vec<&'static str> {
+    theme::engine::all_token_paths()
+}
+
+/// Without the built-in themes no paths are known beyond declared ones.
+#[cfg(not(feature = "gpui"))]
+fn token_paths() -> Vec<&'static str> {
+    Vec::new()
+}
+
+/// Section id of a component's token list (e.g. `dialog-tokens`).
+fn token_section_id(component_name: &str) -> String {
+    format!("{}-tokens", component_name.to_lowercase())
 }
 
 // ---------------------------------------------------------------------------
@@ -156,9 +945,17 @@ pub trait TemplateAdapter {
     /// Human-readable name of this layout (e.g. "default", "flat").
     fn name(&self) -> &str;
 
+    /// Root directory of the target project.
+    fn project_root(&self) -> &Path;
+
     /// Returns the directory path where a component's source files should go.
     fn component_dir(&self, component_name: &str) -> PathBuf;
 
+    /// Returns the file declaring a component's module, which re-exports its sources.
+    fn component_module_file(&self, component_name: &str) -> PathBuf {
+        self.component_dir(component_name).join("mod.rs")
+    }
+
     /// Returns the path to the module file that exports components.
     fn module_file(&self) -> PathBuf;
 
@@ -167,6 +964,80 @@ pub trait TemplateAdapter {
 
     /// Returns the path to the theme tokens file (for token injection).
     fn theme_tokens_file(&self) -> PathBuf;
+
+    /// Rust path through which installed code reaches a component's module
+    /// (e.g. `crate::shared::ui::dialog`).
+    fn component_module_path(&self, component_name: &str) -> String;
+
+    /// Rust path to the target's theme module, which provides `ActiveTheme`.
+    fn theme_module_path(&self) -> String;
+
+    /// Extra files (path, contents) the layout needs per component, such as a crate manifest.
+    fn scaffold_files(&self, _entry: &RegistryEntry) -> Vec<(PathBuf, String)> {
+        Vec::new()
+    }
+
+    /// Whether `path` is one of the files installed for a component.
+    fn owns_file(&self, component_name: &str, path: &Path) -> bool {
+        path == self.component_module_file(component_name)
+            || path.starts_with(self.component_dir(component_name))
+    }
+}
+
+/// The built-in target layouts, selectable with `gpui --layout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutKind {
+    /// [`DefaultLayout`]: one directory per component under `src/shared/ui/`.
+    #[default]
+    Default,
+    /// [`FlatLayout`]: one module file per component in `src/ui/`.
+    Flat,
+    /// [`WorkspaceLayout`]: one crate per component under `crates/`.
+    Workspace,
+}
+
+impl LayoutKind {
+    /// All layouts, in display order.
+    pub const ALL: [LayoutKind; 3] = [LayoutKind::Default, LayoutKind::Flat, LayoutKind::Workspace];
+
+    /// The layout's name, as reported by [`TemplateAdapter::name`].
+    pub fn name(self) -> &'static str {
+        match self {
+            LayoutKind::Default => "default",
+            LayoutKind::Flat => "flat",
+            LayoutKind::Workspace => "workspace",
+        }
+    }
+
+    /// Look up a layout by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// Build the adapter for this layout rooted at `project_root`.
+    pub fn adapter(self, project_root: impl Into<PathBuf>) -> Box<dyn TemplateAdapter> {
+        match self {
+            LayoutKind::Default => Box::new(DefaultLayout::new(project_root)),
+            LayoutKind::Flat => Box::new(FlatLayout::new(project_root)),
+            LayoutKind::Workspace => Box::new(WorkspaceLayout::new(project_root)),
+        }
+    }
+}
+
+impl std::str::FromStr for LayoutKind {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|kind| kind.name()).collect();
+            format!(
+                "unknown layout '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
 }
 
 /// The default target layout: feature-first vertical slice.
@@ -194,6 +1065,10 @@ impl TemplateAdapter for DefaultLayout {
         "default"
     }
 
+    fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
     fn component_dir(&self, component_name: &str) -> PathBuf {
         self.project_root
             .join("src/shared/ui")
@@ -212,70 +1087,268 @@ impl TemplateAdapter for DefaultLayout {
     fn theme_tokens_file(&self) -> PathBuf {
         self.project_root.join("src/shared/theme/tokens.rs")
     }
-}
-
-// ---------------------------------------------------------------------------
-// Plan generation
-// ---------------------------------------------------------------------------
 
-/// Generate an installation plan for a component.
-///
-/// The plan describes exactly which files will be created and which existing
-/// files will be modified. Conflict detection checks whether target files
-/// already exist.
-pub fn generate_plan(
-    entry: &RegistryEntry,
-    layout: &dyn TemplateAdapter,
-    existing_files: &[PathBuf],
-) -> PlanContract {
-    let component_dir = layout.component_dir(&entry.name);
-    let mut mutations = Vec::new();
-    let mut conflicts = Vec::new();
-    let mut checksums = BTreeMap::new();
+    fn component_module_path(&self, component_name: &str) -> String {
+        format!("crate::shared::ui::{}", component_name.to_lowercase())
+    }
 
-    // 1. Create component source file(s)
-    for source_file in &entry.required_files {
-        let source_filename = Path::new(source_file)
-            .file_name()
-            .map(|f| f.to_string_lossy().to_string())
-            .unwrap_or_else(|| format!("{}.rs", entry.name.to_lowercase()));
+    fn theme_module_path(&self) -> String {
+        "crate::shared::theme".to_string()
+    }
+}
 
-        let target_path = component_dir.join(&source_filename);
+/// A flat layout for small apps: every component is a single module file.
+///
+/// Layout:
+/// - Component module: `src/ui/<component>.rs`
+/// - Component source: `src/ui/<component>/`
+/// - Module exports: `src/ui.rs`
+/// - Theme tokens: `src/theme/tokens.rs`
+#[derive(Debug, Clone)]
+pub struct FlatLayout {
+    /// Root directory of the target project.
+    pub project_root: PathBuf,
+}
 
-        // Conflict detection: check if target already exists
-        if existing_files.contains(&target_path) {
-            conflicts.push(Conflict {
-                file_path: target_path.clone(),
-                reason: format!(
-                    "File already exists at target path; would overwrite existing {}",
-                    source_filename
-                ),
-            });
+impl FlatLayout {
+    pub fn new(project_root: impl Into<PathBuf>) -> Self {
+        Self {
+            project_root: project_root.into(),
         }
+    }
+}
 
-        let content = format!(
-            "// Component: {} v{}\n// Source: {}\n// This file was installed by `gpui add {}`\n\npub use {}::*;\n",
-            entry.name,
-            entry.version,
-            source_file,
-            entry.name.to_lowercase(),
-            entry.name.to_lowercase(),
-        );
-
-        let checksum = simple_checksum(&content);
-        checksums.insert(target_path.clone(), checksum);
+impl TemplateAdapter for FlatLayout {
+    fn name(&self) -> &str {
+        "flat"
+    }
 
-        mutations.push(FileMutation {
-            action: FileAction::Create,
-            file_path: target_path,
-            strategy: MutationStrategy::WriteFile,
-            content,
-            description: format!("Install {} component source", entry.name),
-        });
+    fn project_root(&self) -> &Path {
+        &self.project_root
     }
 
-    // 2. Create mod.rs in component directory
-    let mod_path = component_dir.join("mod.rs");
+    fn component_dir(&self, component_name: &str) -> PathBuf {
+        self.project_root
+            .join("src/ui")
+            .join(component_name.to_lowercase())
+    }
+
+    fn component_module_file(&self, component_name: &str) -> PathBuf {
+        self.project_root
+            .join("src/ui")
+            .join(format!("{}.rs", component_name.to_lowercase()))
+    }
+
+    fn module_file(&self) -> PathBuf {
+        self.project_root.join("src/ui.rs")
+    }
+
+    fn export_line(&self, component_name: &str) -> String {
+        format!("pub mod {};", component_name.to_lowercase())
+    }
+
+    fn theme_tokens_file(&self) -> PathBuf {
+        self.project_root.join("src/theme/tokens.rs")
+    }
+
+    fn component_module_path(&self, component_name: &str) -> String {
+        format!("crate::ui::{}", component_name.to_lowercase())
+    }
+
+    fn theme_module_path(&self) -> String {
+        "crate::theme".to_string()
+    }
+}
+
+/// A workspace layout: every component is its own crate.
+///
+/// Layout:
+/// - Component crate: `crates/ui-<component>/` (with a generated `Cargo.toml`)
+/// - Component source: `crates/ui-<component>/src/`, rooted at `lib.rs`
+/// - Module exports: `src/ui.rs`, re-exporting each crate as `ui::<component>`
+/// - Theme tokens: `src/theme/tokens.rs`
+///
+/// The app adds each component crate as a path dependency.
+#[derive(Debug, Clone)]
+pub struct WorkspaceLayout {
+    /// Root directory of the target project.
+    pub project_root: PathBuf,
+}
+
+impl WorkspaceLayout {
+    pub fn new(project_root: impl Into<PathBuf>) -> Self {
+        Self {
+            project_root: project_root.into(),
+        }
+    }
+
+    /// Directory of a component's crate.
+    pub fn crate_dir(&self, component_name: &str) -> PathBuf {
+        self.project_root
+            .join("crates")
+            .join(format!("ui-{}", component_name.to_lowercase()))
+    }
+}
+
+impl TemplateAdapter for WorkspaceLayout {
+    fn name(&self) -> &str {
+        "workspace"
+    }
+
+    fn project_root(&self) -> &Path {
+        &self.project_root
+    }
+
+    fn component_dir(&self, component_name: &str) -> PathBuf {
+        self.crate_dir(component_name).join("src")
+    }
+
+    fn component_module_file(&self, component_name: &str) -> PathBuf {
+        self.component_dir(component_name).join("lib.rs")
+    }
+
+    fn module_file(&self) -> PathBuf {
+        self.project_root.join("src/ui.rs")
+    }
+
+    fn export_line(&self, component_name: &str) -> String {
+        let lower = component_name.to_lowercase();
+        format!("pub use ui_{} as {};", lower, lower)
+    }
+
+    fn theme_tokens_file(&self) -> PathBuf {
+        self.project_root.join("src/theme/tokens.rs")
+    }
+
+    fn component_module_path(&self, component_name: &str) -> String {
+        format!("ui_{}", component_name.to_lowercase())
+    }
+
+    /// Component crates cannot reach the app's theme module, so they use the
+    /// workbench `theme` crate directly.
+    fn theme_module_path(&self) -> String {
+        "theme".to_string()
+    }
+
+    fn scaffold_files(&self, entry: &RegistryEntry) -> Vec<(PathBuf, String)> {
+        let mut manifest = format!(
+            "[package]\nname = \"ui-{}\"\nversion = \"{}\"\nedition = \"2024\"\npublish = false\n\n[dependencies]\n",
+            entry.name.to_lowercase(),
+            entry.version,
+        );
+        let mut crates = vec!["gpui".to_string()];
+        for file in &entry.required_files {
+            if let Some(source) = sources::embedded_source(file) {
+                crates.extend(sources::imported_crates(&sources::rewrite_source(
+                    source,
+                    &entry.name,
+                    self,
+                )));
+            }
+        }
+        crates.sort();
+        crates.dedup();
+        for name in crates {
+            match name.strip_prefix("ui_") {
+                Some(component) => manifest.push_str(&format!(
+                    "ui-{component} = {{ path = \"../ui-{component}\" }}\n"
+                )),
+                None => manifest.push_str(&format!("{name}.workspace = true\n")),
+            }
+        }
+        vec![(self.crate_dir(&entry.name).join("Cargo.toml"), manifest)]
+    }
+
+    fn owns_file(&self, component_name: &str, path: &Path) -> bool {
+        path.starts_with(self.crate_dir(component_name))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plan generation
+// ---------------------------------------------------------------------------
+
+/// Generate an installation plan for a component.
+///
+/// The plan describes exactly which files will be created and which existing
+/// files will be modified. Conflict detection checks whether target files
+/// already exist.
+pub fn generate_plan(
+    entry: &RegistryEntry,
+    layout: &dyn TemplateAdapter,
+    existing_files: &[PathBuf],
+) -> PlanContract {
+    let (plan, elapsed) = timing::measure(|| plan_component(entry, layout, existing_files));
+    if let Some(elapsed) = elapsed {
+        timing::record_plan(&entry.name, elapsed);
+    }
+    plan
+}
+
+fn plan_component(
+    entry: &RegistryEntry,
+    layout: &dyn TemplateAdapter,
+    existing_files: &[PathBuf],
+) -> PlanContract {
+    let component_dir = layout.component_dir(&entry.name);
+    let mut mutations = Vec::new();
+    let mut conflicts = Vec::new();
+    let mut checksums = BTreeMap::new();
+
+    // Sorted so the plan does not depend on the order a contract lists its files.
+    let mut required_files: Vec<&String> = entry.required_files.iter().collect();
+    required_files.sort();
+
+    // 1. Create component source file(s)
+    for &source_file in &required_files {
+        let source_filename = Path::new(source_file)
+            .file_name()
+            .map(|f| f.to_string_lossy().to_string())
+            .unwrap_or_else(|| format!("{}.rs", entry.name.to_lowercase()));
+
+        let target_path = component_dir.join(&source_filename);
+
+        // Conflict detection: check if target already exists
+        if existing_files.contains(&target_path) {
+            conflicts.push(Conflict {
+                file_path: target_path.clone(),
+                reason: format!(
+                    "File already exists at target path; would overwrite existing {}",
+                    source_filename
+                ),
+            });
+        }
+
+        // Sources missing from this build (e.g. listed by a remote registry)
+        // are stubbed here and replaced by `PlanContract::use_sources`.
+        let body = match sources::embedded_source(source_file) {
+            Some(source) => sources::rewrite_source(source, &entry.name, layout),
+            None => format!("pub use {}::*;\n", entry.name.to_lowercase()),
+        };
+        let content = format!(
+            "// Component: {} v{}\n// Source: {}\n// This file was installed by `gpui add {}`\n\n{}",
+            entry.name,
+            entry.version,
+            source_file,
+            entry.name.to_lowercase(),
+            body,
+        );
+
+        let checksum = simple_checksum(&content);
+        checksums.insert(target_path.clone(), checksum);
+
+        mutations.push(FileMutation {
+            action: FileAction::Create,
+            file_path: target_path,
+            strategy: MutationStrategy::WriteFile,
+            content,
+            description: format!("Install {} component source", entry.name),
+            rationale: format!("created because required_files lists {source_file}"),
+        });
+    }
+
+    // 2. Create the component's module file (mod.rs in the default layout)
+    let mod_path = layout.component_module_file(&entry.name);
     let mod_content = format!(
         "//! {} component module.\n\nmod {};\npub use {}::*;\n",
         entry.name,
@@ -288,7 +1361,7 @@ pub fn generate_plan(
     if existing_files.contains(&mod_path) {
         conflicts.push(Conflict {
             file_path: mod_path.clone(),
-            reason: "Component mod.rs already exists; would overwrite".to_string(),
+            reason: "Component module file already exists; would overwrite".to_string(),
         });
     }
 
@@ -298,9 +1371,74 @@ pub fn generate_plan(
         strategy: MutationStrategy::WriteFile,
         content: mod_content,
         description: format!("Create {} module file", entry.name),
+        rationale:
+            "created because every installed component gets a module that re-exports its source"
+                .to_string(),
     });
 
-    // 3. Update parent mod.rs with export
+    // 3. Create the component README (if the contract declares a docs file)
+    if let Some(docs_file) = &entry.docs_file {
+        let docs_path = component_dir.join(docs_file);
+        let docs_content = generate_readme(entry);
+        checksums.insert(docs_path.clone(), simple_checksum(&docs_content));
+
+        if existing_files.contains(&docs_path) {
+            conflicts.push(Conflict {
+                file_path: docs_path.clone(),
+                reason: format!("Component {} already exists; would overwrite", docs_file),
+            });
+        }
+
+        mutations.push(FileMutation {
+            action: FileAction::Create,
+            file_path: docs_path,
+            strategy: MutationStrategy::WriteFile,
+            content: docs_content,
+            description: format!("Write {} docs generated from its contract", entry.name),
+            rationale: format!("created because the contract declares docs_file {docs_file}"),
+        });
+    }
+
+    // 4. Create any files the layout scaffolds per component
+    for (scaffold_path, scaffold_content) in layout.scaffold_files(entry) {
+        checksums.insert(scaffold_path.clone(), simple_checksum(&scaffold_content));
+
+        if existing_files.contains(&scaffold_path) {
+            conflicts.push(Conflict {
+                file_path: scaffold_path.clone(),
+                reason: "Layout scaffolding already exists; would overwrite".to_string(),
+            });
+        }
+
+        mutations.push(FileMutation {
+            action: FileAction::Create,
+            file_path: scaffold_path,
+            strategy: MutationStrategy::WriteFile,
+            content: scaffold_content,
+            description: format!("Scaffold {} for the {} layout", entry.name, layout.name()),
+            rationale: format!(
+                "created because the {} layout scaffolds it per component",
+                layout.name()
+            ),
+        });
+    }
+
+    // 5. Record the component's token dependencies in the theme tokens file
+    if !entry.token_dependencies.is_empty() {
+        mutations.push(FileMutation {
+            action: FileAction::Modify,
+            file_path: layout.theme_tokens_file(),
+            strategy: MutationStrategy::ReplaceSection,
+            content: token_section(entry),
+            description: format!("Record {} token dependencies in theme tokens", entry.name),
+            rationale: format!(
+                "modified because the contract declares {} token dependencies",
+                entry.token_dependencies.len()
+            ),
+        });
+    }
+
+    // 6. Update parent mod.rs with export
     let parent_mod = layout.module_file();
     let export_line = layout.export_line(&entry.name);
 
@@ -310,12 +1448,13 @@ pub fn generate_plan(
         strategy: MutationStrategy::AppendExport,
         content: export_line,
         description: format!("Add {} export to shared UI module", entry.name),
+        rationale: "modified because installed components are exported from the shared UI module"
+            .to_string(),
     });
 
-    // 4. Provenance actions for all required files
-    let provenance_actions: Vec<ProvenanceAction> = entry
-        .required_files
-        .iter()
+    // 7. Provenance actions for all required files
+    let provenance_actions: Vec<ProvenanceAction> = required_files
+        .into_iter()
         .map(|f| {
             let target_filename = Path::new(f)
                 .file_name()
@@ -330,7 +1469,7 @@ pub fn generate_plan(
         })
         .collect();
 
-    PlanContract {
+    let mut plan = PlanContract {
         operation: Operation::Add,
         component_name: entry.name.clone(),
         component_version: entry.version.clone(),
@@ -339,12 +1478,324 @@ pub fn generate_plan(
         provenance_actions,
         file_checksums: checksums,
         target_layout: layout.name().to_string(),
+        migration_notes: Vec::new(),
+        dependencies: Vec::new(),
+        dependency_versions: BTreeMap::new(),
+        resolutions: Vec::new(),
+        annotations: BTreeMap::new(),
+    };
+    plan.canonicalize();
+    plan
+}
+
+/// Generate an installation plan for a component and its dependencies.
+///
+/// Dependencies from [`RegistryIndex::resolve`] are merged into the plan and
+/// listed in `dependencies` in install order. A dependency that is already
+/// installed (its component module file is among `existing_files`) is
+/// skipped; the requested component is always planned, so reinstalling it
+/// still reports conflicts.
+pub fn generate_install_plan(
+    index: &RegistryIndex,
+    component_name: &str,
+    layout: &dyn TemplateAdapter,
+    existing_files: &[PathBuf],
+) -> Result<PlanContract, ResolveError> {
+    let resolved = index.resolve(component_name)?;
+    let (entry, dependencies) = resolved
+        .split_last()
+        .expect("resolve always includes the requested component");
+
+    let mut combined = generate_plan(entry, layout, existing_files);
+    for dependency in dependencies {
+        let dependency_mod = layout.component_module_file(&dependency.name);
+        if existing_files.contains(&dependency_mod) {
+            continue;
+        }
+        let plan = generate_plan(dependency, layout, existing_files);
+        combined.mutations.extend(plan.mutations);
+        combined.conflicts.extend(plan.conflicts);
+        combined.provenance_actions.extend(plan.provenance_actions);
+        combined.file_checksums.extend(plan.file_checksums);
+        combined.dependencies.push(dependency.name.clone());
+        combined
+            .dependency_versions
+            .insert(dependency.name.clone(), dependency.version.clone());
+    }
+    combined.canonicalize();
+    Ok(combined)
+}
+
+/// The installed state of a component, read from the target project.
+#[derive(Debug, Clone, Default)]
+pub struct InstalledComponent {
+    /// Version recorded in provenance at install time.
+    pub version: String,
+    /// Current on-disk contents by path; missing files are absent.
+    pub files: BTreeMap<PathBuf, String>,
+    /// Checksums recorded in provenance when each file was installed.
+    pub recorded_checksums: BTreeMap<PathBuf, String>,
+}
+
+/// Generate an update plan moving an installed component to the registry version.
+///
+/// Only files whose contents differ from the registry are rewritten; missing
+/// files are recreated. A differing file whose checksum no longer matches the
+/// one recorded at install time was edited locally and is reported as a
+/// conflict (it is still in the mutation list, so a forced apply overwrites it).
+/// Files without a recorded checksum (module scaffolding, docs) are treated as
+/// registry-owned. Marked sections, such as the component's token
+/// dependencies, are rewritten when the installed section differs. Breaking
+/// updates carry migration notes for renamed props found in `sources` (path,
+/// contents).
+pub fn generate_update_plan(
+    entry: &RegistryEntry,
+    layout: &dyn TemplateAdapter,
+    installed: &InstalledComponent,
+    sources: &[(PathBuf, String)],
+) -> PlanContract {
+    let install = generate_plan(entry, layout, &[]);
+    let mut mutations = Vec::new();
+    let mut conflicts = Vec::new();
+    let sections: Vec<FileMutation> = install
+        .mutations
+        .iter()
+        .filter(|m| m.strategy == MutationStrategy::ReplaceSection)
+        .cloned()
+        .collect();
+
+    for mutation in install
+        .mutations
+        .into_iter()
+        .filter(|m| m.strategy == MutationStrategy::WriteFile)
+    {
+        let Some(current) = installed.files.get(&mutation.file_path) else {
+            mutations.push(FileMutation {
+                description: format!("Restore missing {} file", entry.name),
+                rationale: "recreated because the file is missing from the project".to_string(),
+                ..mutation
+            });
+            continue;
+        };
+        if *current == mutation.content {
+            continue;
+        }
+
+        if installed
+            .recorded_checksums
+            .get(&mutation.file_path)
+            .is_some_and(|recorded| *recorded != simple_checksum(current))
+        {
+            conflicts.push(Conflict {
+                file_path: mutation.file_path.clone(),
+                reason: "Modified locally since install; use --force or merge manually".to_string(),
+            });
+        }
+
+        mutations.push(FileMutation {
+            action: FileAction::Modify,
+            description: format!(
+                "Update {} file from v{} to v{}",
+                entry.name, installed.version, entry.version
+            ),
+            rationale: format!(
+                "modified because the installed file differs from v{}",
+                entry.version
+            ),
+            ..mutation
+        });
+    }
+
+    for section in sections {
+        let unchanged = installed
+            .files
+            .get(&section.file_path)
+            .is_some_and(|current| {
+                replace_section(current, &section.content).is_ok_and(|updated| updated == *current)
+            });
+        if !unchanged {
+            mutations.push(FileMutation {
+                description: format!("Update {} token dependencies", entry.name),
+                rationale: "modified because the installed token section differs".to_string(),
+                ..section
+            });
+        }
+    }
+
+    let mut plan = PlanContract {
+        operation: Operation::Update,
+        component_name: entry.name.clone(),
+        component_version: entry.version.clone(),
+        mutations,
+        conflicts,
+        provenance_actions: install.provenance_actions,
+        file_checksums: install.file_checksums,
+        target_layout: layout.name().to_string(),
+        migration_notes: migration_notes(entry, &installed.version, sources),
+        dependencies: Vec::new(),
+        dependency_versions: BTreeMap::new(),
+        resolutions: Vec::new(),
+        annotations: BTreeMap::new(),
+    };
+    plan.canonicalize();
+    plan
+}
+
+/// Generate a removal plan for an installed component.
+///
+/// The reverse of [`generate_plan`]: deletes every file the install created
+/// plus its provenance metadata, and removes the export line from the parent
+/// module. Files in `existing_files` that the install did not create are
+/// reported as conflicts and left in place.
+pub fn generate_remove_plan(
+    entry: &RegistryEntry,
+    layout: &dyn TemplateAdapter,
+    existing_files: &[PathBuf],
+) -> PlanContract {
+    let install = generate_plan(entry, layout, &[]);
+    let mut mutations = Vec::new();
+
+    // 1. Clean up provenance metadata
+    let provenance_files: Vec<PathBuf> = install
+        .provenance_actions
+        .iter()
+        .map(|pa| pa.file_path.with_extension("provenance.json"))
+        .collect();
+    for file_path in &provenance_files {
+        mutations.push(FileMutation {
+            action: FileAction::Delete,
+            file_path: file_path.clone(),
+            strategy: MutationStrategy::DeleteFile,
+            content: String::new(),
+            description: format!("Delete {} provenance metadata", entry.name),
+            rationale: format!(
+                "deleted because the install recorded provenance for {}",
+                entry.name
+            ),
+        });
+    }
+
+    // 2. Delete installed files
+    let installed_files: Vec<PathBuf> = install
+        .mutations
+        .iter()
+        .filter(|m| m.action == FileAction::Create)
+        .map(|m| m.file_path.clone())
+        .collect();
+    for file_path in &installed_files {
+        mutations.push(FileMutation {
+            action: FileAction::Delete,
+            file_path: file_path.clone(),
+            strategy: MutationStrategy::DeleteFile,
+            content: String::new(),
+            description: format!("Delete installed {} file", entry.name),
+            rationale: "deleted because the install created it".to_string(),
+        });
+    }
+
+    // 3. Remove export from parent mod.rs
+    mutations.push(FileMutation {
+        action: FileAction::Modify,
+        file_path: layout.module_file(),
+        strategy: MutationStrategy::ReplaceSection,
+        content: layout.export_line(&entry.name),
+        description: format!("Remove {} export from shared UI module", entry.name),
+        rationale: "modified because the install added this export".to_string(),
+    });
+
+    // 4. Remove the token dependency section from the theme tokens file
+    if !entry.token_dependencies.is_empty() {
+        mutations.push(FileMutation {
+            action: FileAction::Modify,
+            file_path: layout.theme_tokens_file(),
+            strategy: MutationStrategy::ReplaceSection,
+            content: marked_section(&token_section_id(&entry.name), ""),
+            description: format!("Remove {} token dependencies from theme tokens", entry.name),
+            rationale: "modified because the install recorded token dependencies here".to_string(),
+        });
+    }
+
+    // Never delete files the install did not create.
+    let conflicts = existing_files
+        .iter()
+        .filter(|f| !installed_files.contains(f) && !provenance_files.contains(f))
+        .map(|f| Conflict {
+            file_path: f.clone(),
+            reason: "File was not created by the install; remove it manually".to_string(),
+        })
+        .collect();
+
+    let mut plan = PlanContract {
+        operation: Operation::Remove,
+        component_name: entry.name.clone(),
+        component_version: entry.version.clone(),
+        mutations,
+        conflicts,
+        provenance_actions: Vec::new(),
+        file_checksums: BTreeMap::new(),
+        target_layout: layout.name().to_string(),
+        migration_notes: Vec::new(),
+        dependencies: Vec::new(),
+        dependency_versions: BTreeMap::new(),
+        resolutions: Vec::new(),
+        annotations: BTreeMap::new(),
+    };
+    plan.canonicalize();
+    plan
+}
+
+/// Generate the README bundled with an installed component.
+///
+/// Contains a usage snippet built from the required props and a prop table
+/// covering every prop in the contract.
+pub fn generate_readme(entry: &RegistryEntry) -> String {
+    let required: Vec<&str> = entry
+        .props
+        .iter()
+        .filter(|p| p.required)
+        .map(|p| p.name.as_str())
+        .collect();
+
+    let mut readme = format!(
+        "# {}\n\nVersion {} ({:?}). Installed by `gpui add {}`.\n\n",
+        entry.name,
+        entry.version,
+        entry.disposition,
+        entry.name.to_lowercase(),
+    );
+
+    readme.push_str("## Usage\n\n```rust\n");
+    readme.push_str(&format!("{}::new({})\n", entry.name, required.join(", ")));
+    readme.push_str("```\n\n");
+
+    readme.push_str("## Props\n\n");
+    readme.push_str("| Prop | Type | Required | Default | Description |\n");
+    readme.push_str("| --- | --- | --- | --- | --- |\n");
+    for prop in &entry.props {
+        readme.push_str(&format!(
+            "| `{}` | `{}` | {} | {} | {} |\n",
+            prop.name,
+            table_cell(&prop.type_name),
+            if prop.required { "yes" } else { "no" },
+            prop.default_value
+                .as_deref()
+                .map(|d| format!("`{}`", table_cell(d)))
+                .unwrap_or_default(),
+            table_cell(&prop.description),
+        ));
     }
+
+    readme
+}
+
+/// Escape pipes so a value stays inside its Markdown table cell.
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|")
 }
 
 /// Simple content checksum using a basic hash for integrity verification.
 /// Uses a deterministic string hash (FNV-1a variant) for portability.
-fn simple_checksum(content: &str) -> String {
+pub fn simple_checksum(content: &str) -> String {
     let mut hash: u64 = 0xcbf29ce484222325;
     for byte in content.bytes() {
         hash ^= byte as u64;
@@ -379,6 +1830,210 @@ impl ApplyFailureReport {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plan diff
+// ---------------------------------------------------------------------------
+
+/// How an item differs between two plans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Only in the second plan.
+    Added,
+    /// Only in the first plan.
+    Removed,
+    /// In both plans, with different contents.
+    Changed,
+}
+
+/// The identifying fields of a plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanSummary {
+    pub operation: Operation,
+    pub component_name: String,
+    pub component_version: String,
+    pub target_layout: String,
+}
+
+impl From<&PlanContract> for PlanSummary {
+    fn from(plan: &PlanContract) -> Self {
+        Self {
+            operation: plan.operation,
+            component_name: plan.component_name.clone(),
+            component_version: plan.component_version.clone(),
+            target_layout: plan.target_layout.clone(),
+        }
+    }
+}
+
+/// A mutation that differs between two plans.
+///
+/// Mutations are matched by file path and strategy, in plan order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MutationChange {
+    pub kind: ChangeKind,
+    pub file_path: PathBuf,
+    pub strategy: MutationStrategy,
+    /// The mutation in the first plan, if any.
+    pub before: Option<FileMutation>,
+    /// The mutation in the second plan, if any.
+    pub after: Option<FileMutation>,
+}
+
+/// A conflict that differs between two plans, matched by file path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConflictChange {
+    pub kind: ChangeKind,
+    pub file_path: PathBuf,
+    /// Conflict reason in the first plan, if any.
+    pub before: Option<String>,
+    /// Conflict reason in the second plan, if any.
+    pub after: Option<String>,
+}
+
+/// A file checksum that differs between two plans.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecksumChange {
+    pub kind: ChangeKind,
+    pub file_path: PathBuf,
+    /// Checksum in the first plan, if any.
+    pub before: Option<String>,
+    /// Checksum in the second plan, if any.
+    pub after: Option<String>,
+}
+
+/// Differences between two plans: what re-planning changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlanDiff {
+    /// The first (older) plan.
+    pub before: PlanSummary,
+    /// The second (newer) plan.
+    pub after: PlanSummary,
+    /// Mutations added, removed, or changed, in plan order.
+    pub mutations: Vec<MutationChange>,
+    /// Conflicts added, removed, or changed.
+    pub conflicts: Vec<ConflictChange>,
+    /// Checksums added, removed, or changed, ordered by file path.
+    pub checksums: Vec<ChecksumChange>,
+}
+
+impl PlanDiff {
+    /// Compare `before` with `after`.
+    pub fn between(before: &PlanContract, after: &PlanContract) -> Self {
+        Self {
+            before: before.into(),
+            after: after.into(),
+            mutations: diff_mutations(&before.mutations, &after.mutations),
+            conflicts: diff_conflicts(&before.conflicts, &after.conflicts),
+            checksums: diff_checksums(&before.file_checksums, &after.file_checksums),
+        }
+    }
+
+    /// Serialize the diff to JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Whether the plans are equivalent.
+    pub fn is_empty(&self) -> bool {
+        self.before == self.after
+            && self.mutations.is_empty()
+            && self.conflicts.is_empty()
+            && self.checksums.is_empty()
+    }
+}
+
+/// Classify a matched pair, or `None` when both sides are equal.
+fn change_kind<T: PartialEq>(before: Option<&T>, after: Option<&T>) -> Option<ChangeKind> {
+    match (before, after) {
+        (Some(before), Some(after)) if before == after => None,
+        (Some(_), Some(_)) => Some(ChangeKind::Changed),
+        (Some(_), None) => Some(ChangeKind::Removed),
+        (None, Some(_)) => Some(ChangeKind::Added),
+        (None, None) => None,
+    }
+}
+
+fn diff_mutations(before: &[FileMutation], after: &[FileMutation]) -> Vec<MutationChange> {
+    // A file can receive several mutations with the same strategy (e.g. one
+    // export line per installed component), so pair them up in order.
+    let mut keys: Vec<(&PathBuf, &MutationStrategy)> = Vec::new();
+    for mutation in before.iter().chain(after) {
+        let key = (&mutation.file_path, &mutation.strategy);
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+
+    let mut changes = Vec::new();
+    for (file_path, strategy) in keys {
+        let matching = |mutations: &'_ [FileMutation]| -> Vec<FileMutation> {
+            mutations
+                .iter()
+                .filter(|m| &m.file_path == file_path && &m.strategy == strategy)
+                .cloned()
+                .collect()
+        };
+        let (old, new) = (matching(before), matching(after));
+        for i in 0..old.len().max(new.len()) {
+            let (before, after) = (old.get(i), new.get(i));
+            if let Some(kind) = change_kind(before, after) {
+                changes.push(MutationChange {
+                    kind,
+                    file_path: file_path.clone(),
+                    strategy: strategy.clone(),
+                    before: before.cloned(),
+                    after: after.cloned(),
+                });
+            }
+        }
+    }
+    changes
+}
+
+fn diff_conflicts(before: &[Conflict], after: &[Conflict]) -> Vec<ConflictChange> {
+    let reasons = |conflicts: &[Conflict]| -> BTreeMap<PathBuf, String> {
+        conflicts
+            .iter()
+            .map(|c| (c.file_path.clone(), c.reason.clone()))
+            .collect()
+    };
+    diff_maps(&reasons(before), &reasons(after))
+        .map(|(kind, file_path, before, after)| ConflictChange {
+            kind,
+            file_path,
+            before,
+            after,
+        })
+        .collect()
+}
+
+fn diff_checksums(
+    before: &BTreeMap<PathBuf, String>,
+    after: &BTreeMap<PathBuf, String>,
+) -> Vec<ChecksumChange> {
+    diff_maps(before, after)
+        .map(|(kind, file_path, before, after)| ChecksumChange {
+            kind,
+            file_path,
+            before,
+            after,
+        })
+        .collect()
+}
+
+/// Entries that differ between two path-keyed maps, ordered by path.
+fn diff_maps<'a>(
+    before: &'a BTreeMap<PathBuf, String>,
+    after: &'a BTreeMap<PathBuf, String>,
+) -> impl Iterator<Item = (ChangeKind, PathBuf, Option<String>, Option<String>)> + 'a {
+    let paths: std::collections::BTreeSet<&PathBuf> = before.keys().chain(after.keys()).collect();
+    paths.into_iter().filter_map(|path| {
+        let (old, new) = (before.get(path), after.get(path));
+        change_kind(old, new).map(|kind| (kind, path.clone(), old.cloned(), new.cloned()))
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -387,27 +2042,291 @@ impl ApplyFailureReport {
 mod tests {
     use super::*;
     use crate::generate_registry;
+    use proptest::prelude::*;
 
     fn default_layout() -> DefaultLayout {
         DefaultLayout::new("/test/project")
     }
 
-    // -- Plan generation tests --
-
+    // -- Plan generation tests --
+
+    #[test]
+    fn generate_plan_for_dialog() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let plan = generate_plan(entry, &default_layout(), &[]);
+
+        assert_eq!(plan.operation, Operation::Add);
+        assert_eq!(plan.component_name, "Dialog");
+        assert_eq!(plan.component_version, "0.1.0");
+        assert_eq!(plan.target_layout, "default");
+        assert!(!plan.mutations.is_empty());
+        assert!(plan.conflicts.is_empty());
+        assert!(!plan.provenance_actions.is_empty());
+        assert!(!plan.file_checksums.is_empty());
+    }
+
+    #[test]
+    fn plan_installs_rewritten_component_source() {
+        let registry = generate_registry();
+        let entry = registry.get("Select").unwrap();
+        let plan = generate_plan(entry, &default_layout(), &[]);
+
+        let source = plan
+            .mutations
+            .iter()
+            .find(|m| m.file_path.ends_with("select/select.rs"))
+            .unwrap();
+        assert!(source.content.starts_with("// Component: Select v"));
+        assert!(source.content.contains("pub struct Select"));
+        assert!(
+            source
+                .content
+                .contains("use crate::shared::ui::emptystate::{EmptyState")
+        );
+        assert!(
+            source
+                .content
+                .contains("use crate::shared::theme::ActiveTheme;")
+        );
+        assert!(!source.content.contains("use crate::{"));
+        assert!(!source.content.contains("use theme::"));
+    }
+
+    #[test]
+    fn published_sources_replace_generated_files() {
+        let registry = generate_registry();
+        let layout = default_layout();
+        let mut plan = generate_install_plan(&registry, "select", &layout, &[]).unwrap();
+        let select_dir = layout.component_dir("Select");
+        let existing = vec![select_dir.join("options.rs")];
+
+        let sources = BTreeMap::from([
+            ("select.rs".to_string(), "pub struct Select;\n".to_string()),
+            (
+                "options.rs".to_string(),
+                "pub struct SelectOption;\n".to_string(),
+            ),
+        ]);
+        let before = plan.mutation_count();
+        plan.use_sources(&layout, "Select", &sources, &existing);
+
+        assert_eq!(plan.mutation_count(), before + 1);
+        let select_rs = plan
+            .mutations
+            .iter()
+            .find(|m| m.file_path == select_dir.join("select.rs"))
+            .unwrap();
+        assert_eq!(select_rs.content, "pub struct Select;\n");
+        assert_eq!(
+            plan.file_checksums[&select_dir.join("select.rs")],
+            simple_checksum("pub struct Select;\n")
+        );
+
+        let options = plan
+            .mutations
+            .iter()
+            .position(|m| m.file_path == select_dir.join("options.rs"))
+            .unwrap();
+        let export = plan
+            .mutations
+            .iter()
+            .rposition(|m| m.strategy == MutationStrategy::AppendExport)
+            .unwrap();
+        assert!(
+            options < export,
+            "added file lands with the component's files"
+        );
+        assert_eq!(plan.conflicts.len(), 1);
+        assert_eq!(plan.conflicts[0].file_path, select_dir.join("options.rs"));
+    }
+
+    #[test]
+    fn resolve_conflict_skips_or_renames_planned_write() {
+        let registry = generate_registry();
+        let layout = default_layout();
+        let dialog_dir = layout.component_dir("Dialog");
+        let dialog_rs = dialog_dir.join("dialog.rs");
+        let mod_rs = dialog_dir.join("mod.rs");
+        let mut plan = generate_install_plan(
+            &registry,
+            "dialog",
+            &layout,
+            &[dialog_rs.clone(), mod_rs.clone()],
+        )
+        .unwrap();
+        assert_eq!(plan.conflicts.len(), 2);
+
+        assert!(plan.resolve_conflict(&dialog_rs, ConflictResolution::Skip, ""));
+        assert!(!plan.mutations.iter().any(|m| m.file_path == dialog_rs));
+        assert!(!plan.file_checksums.contains_key(&dialog_rs));
+        assert!(
+            !plan
+                .provenance_actions
+                .iter()
+                .any(|a| a.file_path == dialog_rs)
+        );
+
+        assert!(plan.resolve_conflict(&mod_rs, ConflictResolution::Rename, ""));
+        let renamed = dialog_dir.join("mod.new.rs");
+        assert!(plan.mutations.iter().any(|m| m.file_path == renamed));
+        assert!(plan.file_checksums.contains_key(&renamed));
+
+        assert!(!plan.has_conflicts());
+        assert!(!plan.resolve_conflict(&mod_rs, ConflictResolution::Skip, ""));
+        assert_eq!(
+            plan.resolutions,
+            [
+                ConflictDecision {
+                    file_path: dialog_rs,
+                    resolution: ConflictResolution::Skip,
+                },
+                ConflictDecision {
+                    file_path: mod_rs,
+                    resolution: ConflictResolution::Rename,
+                },
+            ]
+        );
+        let roundtrip = PlanContract::from_json(&plan.to_json().unwrap()).unwrap();
+        assert_eq!(roundtrip.resolutions, plan.resolutions);
+    }
+
+    #[test]
+    fn resolve_conflict_backs_up_before_overwriting() {
+        let registry = generate_registry();
+        let layout = default_layout();
+        let mod_rs = layout.component_dir("Tooltip").join("mod.rs");
+        let mut plan =
+            generate_install_plan(&registry, "tooltip", &layout, std::slice::from_ref(&mod_rs))
+                .unwrap();
+        let before = plan.mutation_count();
+
+        assert!(plan.resolve_conflict(&mod_rs, ConflictResolution::Backup, "// mine\n"));
+        assert_eq!(plan.mutation_count(), before + 1);
+        let backup = plan
+            .mutations
+            .iter()
+            .position(|m| m.file_path == layout.component_dir("Tooltip").join("mod.rs.bak"))
+            .unwrap();
+        assert_eq!(plan.mutations[backup].content, "// mine\n");
+        assert!(plan.mutations.iter().any(|m| writes_file(m, &mod_rs)));
+        assert_eq!(
+            "backup".parse::<ConflictResolution>(),
+            Ok(ConflictResolution::Backup)
+        );
+        assert!("merge".parse::<ConflictResolution>().is_err());
+    }
+
+    #[test]
+    fn install_plan_lists_transitive_dependencies_in_install_order() {
+        let registry = generate_registry();
+        let plan = generate_install_plan(&registry, "select", &default_layout(), &[]).unwrap();
+
+        assert_eq!(plan.component_name, "Select");
+        assert_eq!(plan.dependencies, ["Tooltip", "Button", "EmptyState"]);
+        assert_eq!(
+            plan.dependency_versions.get("Tooltip"),
+            Some(&registry.get("tooltip").unwrap().version)
+        );
+        let exports: Vec<&str> = plan
+            .mutations
+            .iter()
+            .filter(|m| m.strategy == MutationStrategy::AppendExport)
+            .map(|m| m.content.as_str())
+            .collect();
+        assert_eq!(
+            exports,
+            [
+                "pub mod button;",
+                "pub mod emptystate;",
+                "pub mod select;",
+                "pub mod tooltip;"
+            ]
+        );
+        assert_eq!(plan.provenance_actions.len(), 4);
+        assert!(
+            plan.file_checksums
+                .contains_key(&PathBuf::from("/test/project/src/shared/ui/tooltip/mod.rs"))
+        );
+    }
+
+    #[test]
+    fn merged_plans_do_not_depend_on_merge_order() {
+        let registry = generate_registry();
+        let layout = default_layout();
+        let existing = [
+            layout.component_module_file("Button"),
+            layout.component_module_file("Select"),
+            layout.component_dir("Tooltip").join("tooltip.rs"),
+        ];
+        let plan = generate_install_plan(&registry, "select", &layout, &existing).unwrap();
+
+        // Merge the same component plans in reverse, with each list reversed.
+        let mut reversed = generate_plan(registry.get("Tooltip").unwrap(), &layout, &existing);
+        for name in ["Select", "EmptyState"] {
+            let part = generate_plan(registry.get(name).unwrap(), &layout, &existing);
+            reversed.mutations.extend(part.mutations);
+            reversed.conflicts.extend(part.conflicts);
+            reversed.provenance_actions.extend(part.provenance_actions);
+            reversed.file_checksums.extend(part.file_checksums);
+        }
+        reversed.mutations.reverse();
+        reversed.conflicts.reverse();
+        reversed.provenance_actions.reverse();
+        reversed.canonicalize();
+
+        assert_eq!(plan.conflicts.len(), 2);
+        assert_eq!(reversed.mutations, plan.mutations);
+        assert_eq!(reversed.conflicts, plan.conflicts);
+        assert_eq!(reversed.provenance_actions, plan.provenance_actions);
+        assert_eq!(reversed.file_checksums, plan.file_checksums);
+    }
+
+    #[test]
+    fn remove_plans_delete_deepest_files_first() {
+        let registry = generate_registry();
+        let layout = WorkspaceLayout::new("/myapp");
+        let remove = generate_remove_plan(registry.get("Dialog").unwrap(), &layout, &[]);
+
+        let deletes: Vec<&Path> = remove
+            .mutations
+            .iter()
+            .filter(|m| m.action == FileAction::Delete)
+            .map(|m| m.file_path.as_path())
+            .collect();
+        // The crate's sources go before its manifest, so the last delete
+        // leaves the crate directory empty.
+        assert_eq!(
+            deletes.last(),
+            Some(&layout.crate_dir("Dialog").join("Cargo.toml").as_path())
+        );
+        assert!(
+            deletes
+                .windows(2)
+                .all(|w| w[0].components().count() >= w[1].components().count())
+        );
+    }
+
     #[test]
-    fn generate_plan_for_dialog() {
+    fn install_plan_skips_installed_dependencies() {
         let registry = generate_registry();
-        let entry = registry.get("Dialog").unwrap();
-        let plan = generate_plan(entry, &default_layout(), &[]);
+        let existing = vec![PathBuf::from("/test/project/src/shared/ui/tooltip/mod.rs")];
+        let plan =
+            generate_install_plan(&registry, "Button", &default_layout(), &existing).unwrap();
 
-        assert_eq!(plan.operation, Operation::Add);
-        assert_eq!(plan.component_name, "Dialog");
-        assert_eq!(plan.component_version, "0.1.0");
-        assert_eq!(plan.target_layout, "default");
-        assert!(!plan.mutations.is_empty());
+        assert!(plan.dependencies.is_empty());
         assert!(plan.conflicts.is_empty());
-        assert!(!plan.provenance_actions.is_empty());
-        assert!(!plan.file_checksums.is_empty());
+        assert_eq!(
+            plan.mutations.len(),
+            generate_plan(registry.get("Button").unwrap(), &default_layout(), &[])
+                .mutations
+                .len()
+        );
+
+        assert!(matches!(
+            generate_install_plan(&registry, "nope", &default_layout(), &[]),
+            Err(ResolveError::NotFound { .. })
+        ));
     }
 
     #[test]
@@ -493,6 +2412,226 @@ mod tests {
         assert!(export.content.contains("pub mod dialog"));
     }
 
+    #[test]
+    fn plan_bundles_readme_from_contract() {
+        let registry = generate_registry();
+        let entry = registry.get("Button").unwrap();
+        let plan = generate_plan(entry, &default_layout(), &[]);
+
+        let readme = plan
+            .mutations
+            .iter()
+            .find(|m| m.file_path.ends_with("button/README.md"))
+            .expect("Plan should write the declared docs file");
+        assert_eq!(readme.action, FileAction::Create);
+        assert!(readme.content.contains("Button::new(id)"));
+        assert!(
+            readme
+                .content
+                .contains("| `variant` | `ButtonVariant` | no | `Secondary` |")
+        );
+        assert!(plan.file_checksums.contains_key(&readme.file_path));
+    }
+
+    #[test]
+    fn plan_skips_readme_without_docs_file() {
+        let registry = generate_registry();
+        let mut entry = registry.get("Dialog").unwrap().clone();
+        entry.docs_file = None;
+        let plan = generate_plan(&entry, &default_layout(), &[]);
+
+        assert!(
+            plan.mutations
+                .iter()
+                .all(|m| m.file_path.extension().is_none_or(|ext| ext != "md"))
+        );
+    }
+
+    // -- Update plan tests --
+
+    fn installed_as_planned(plan: &PlanContract) -> InstalledComponent {
+        InstalledComponent {
+            version: plan.component_version.clone(),
+            files: plan
+                .mutations
+                .iter()
+                .filter_map(|m| match m.strategy {
+                    MutationStrategy::WriteFile => Some((m.file_path.clone(), m.content.clone())),
+                    MutationStrategy::ReplaceSection => {
+                        Some((m.file_path.clone(), replace_section("", &m.content).ok()?))
+                    }
+                    _ => None,
+                })
+                .collect(),
+            recorded_checksums: plan.file_checksums.clone(),
+        }
+    }
+
+    #[test]
+    fn update_plan_skips_unchanged_files() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let installed = installed_as_planned(&generate_plan(entry, &layout, &[]));
+
+        let plan = generate_update_plan(entry, &layout, &installed, &[]);
+
+        assert_eq!(plan.operation, Operation::Update);
+        assert!(plan.mutations.is_empty());
+        assert!(!plan.has_conflicts());
+        assert!(plan.migration_notes.is_empty());
+    }
+
+    #[test]
+    fn update_plan_rewrites_stale_and_restores_missing_files() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let install = generate_plan(entry, &layout, &[]);
+        let mut installed = installed_as_planned(&install);
+
+        // A stale file from an older registry version, checksum recorded at install.
+        let source = install.provenance_actions[0].file_path.clone();
+        let stale = "// Component: Dialog v0.0.9
+"
+        .to_string();
+        installed
+            .recorded_checksums
+            .insert(source.clone(), simple_checksum(&stale));
+        installed.files.insert(source.clone(), stale);
+        let mod_rs = layout.component_dir("Dialog").join("mod.rs");
+        installed.files.remove(&mod_rs);
+
+        let plan = generate_update_plan(entry, &layout, &installed, &[]);
+
+        assert!(!plan.has_conflicts());
+        assert_eq!(plan.mutations.len(), 2);
+        let rewrite = plan
+            .mutations
+            .iter()
+            .find(|m| m.file_path == source)
+            .unwrap();
+        assert_eq!(rewrite.action, FileAction::Modify);
+        let restore = plan
+            .mutations
+            .iter()
+            .find(|m| m.file_path == mod_rs)
+            .unwrap();
+        assert_eq!(restore.action, FileAction::Create);
+    }
+
+    #[test]
+    fn update_plan_flags_locally_modified_files() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let install = generate_plan(entry, &layout, &[]);
+        let mut installed = installed_as_planned(&install);
+
+        let source = install.provenance_actions[0].file_path.clone();
+        installed
+            .files
+            .get_mut(&source)
+            .unwrap()
+            .push_str("// local edit\n");
+
+        let plan = generate_update_plan(entry, &layout, &installed, &[]);
+
+        assert_eq!(plan.conflicts.len(), 1);
+        assert_eq!(plan.conflicts[0].file_path, source);
+        assert!(
+            plan.mutations.iter().any(|m| m.file_path == source),
+            "Forced apply still needs the rewrite"
+        );
+    }
+
+    #[test]
+    fn breaking_update_plan_carries_migration_notes() {
+        let contract = components::ComponentContract::builder("Dialog", "0.2.0")
+            .required_prop("id", "ElementId", "Unique identifier")
+            .required_prop("heading", "SharedString", "Dialog heading")
+            .renamed_prop("title", "heading", "0.2.0")
+            .state(components::ComponentState::Open)
+            .required_file("crates/components/src/dialog.rs")
+            .build();
+        let entry = RegistryEntry::from_contract(&contract);
+        let installed = InstalledComponent {
+            version: "0.1.0".to_string(),
+            ..Default::default()
+        };
+        let sources = vec![(
+            PathBuf::from("/test/project/src/main.rs"),
+            "Dialog::new(\"d\").title(\"Hi\")".to_string(),
+        )];
+
+        let plan = generate_update_plan(&entry, &default_layout(), &installed, &sources);
+
+        assert_eq!(plan.migration_notes.len(), 1);
+        assert_eq!(plan.migration_notes[0].locations.len(), 1);
+    }
+
+    // -- Remove plan tests --
+
+    #[test]
+    fn remove_plan_reverses_install() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let install = generate_plan(entry, &layout, &[]);
+        let remove = generate_remove_plan(entry, &layout, &[]);
+
+        assert_eq!(remove.operation, Operation::Remove);
+        for created in install
+            .mutations
+            .iter()
+            .filter(|m| m.action == FileAction::Create)
+        {
+            assert!(
+                remove
+                    .mutations
+                    .iter()
+                    .any(|m| m.action == FileAction::Delete && m.file_path == created.file_path),
+                "Remove plan should delete {:?}",
+                created.file_path
+            );
+        }
+        assert!(remove.mutations.iter().any(|m| {
+            m.file_path
+                .to_string_lossy()
+                .ends_with("dialog.provenance.json")
+                && m.strategy == MutationStrategy::DeleteFile
+        }));
+
+        let export = remove
+            .mutations
+            .iter()
+            .find(|m| m.file_path == layout.module_file())
+            .expect("Remove plan should drop the module export");
+        assert_eq!(export.strategy, MutationStrategy::ReplaceSection);
+        assert_eq!(export.content, "pub mod dialog;");
+        assert!(remove.provenance_actions.is_empty());
+    }
+
+    #[test]
+    fn remove_plan_keeps_untracked_files() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let existing = vec![
+            PathBuf::from("/test/project/src/shared/ui/dialog/dialog.rs"),
+            PathBuf::from("/test/project/src/shared/ui/dialog/notes.txt"),
+        ];
+        let plan = generate_remove_plan(entry, &default_layout(), &existing);
+
+        assert_eq!(plan.conflicts.len(), 1);
+        assert!(plan.conflicts[0].file_path.ends_with("notes.txt"));
+        assert!(
+            !plan
+                .mutations
+                .iter()
+                .any(|m| m.file_path.ends_with("notes.txt"))
+        );
+    }
+
     // -- Determinism tests (NFR-001) --
 
     #[test]
@@ -530,6 +2669,104 @@ mod tests {
         }
     }
 
+    /// Root of the reference plans the property test compares against.
+    const BASE_ROOT: &str = "/gpui-root/base";
+
+    fn component_names() -> Vec<String> {
+        generate_registry()
+            .names()
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Sources a generated entry may list in addition to its own.
+    fn extra_sources() -> Vec<String> {
+        [
+            "crates/components/src/kbd.rs",
+            "crates/components/src/tooltip.rs",
+            "crates/components/src/empty_state.rs",
+        ]
+        .map(str::to_string)
+        .to_vec()
+    }
+
+    /// `items` ordered by `keys` (cycled), a deterministic shuffle.
+    fn permuted<T: Clone>(items: &[T], keys: &[u32]) -> Vec<T> {
+        let mut keyed: Vec<(u32, usize)> = (0..items.len())
+            .map(|i| (keys[i % keys.len()], i))
+            .collect();
+        keyed.sort();
+        keyed.into_iter().map(|(_, i)| items[i].clone()).collect()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        /// NFR-001 beyond fixed inputs: the order of `existing_files` and
+        /// `required_files` and the project root never change a plan beyond
+        /// the root itself.
+        #[test]
+        fn plans_are_path_order_invariant(
+            component in prop::sample::select(component_names()),
+            kind in prop::sample::select(LayoutKind::ALL.to_vec()),
+            segments in prop::collection::vec("[a-z][a-z0-9_]{0,7}", 1..4),
+            extra in prop::collection::vec(prop::sample::select(extra_sources()), 0..3),
+            existing_mask in prop::collection::vec(any::<bool>(), 16),
+            keys in prop::collection::vec(any::<u32>(), 16),
+        ) {
+            let registry = generate_registry();
+            let mut entry = registry.get(&component).unwrap().clone();
+            entry.required_files.extend(extra);
+            entry.required_files.sort();
+            entry.required_files.dedup();
+
+            // Files the plan writes plus unrelated ones, relative to the root.
+            let reference = generate_plan(&entry, kind.adapter(BASE_ROOT).as_ref(), &[]);
+            let mut candidates: Vec<PathBuf> = reference
+                .mutations
+                .iter()
+                .filter_map(|m| m.file_path.strip_prefix(BASE_ROOT).ok())
+                .map(Path::to_path_buf)
+                .collect();
+            candidates.extend([PathBuf::from("README.md"), PathBuf::from("src/main.rs")]);
+            let existing: Vec<PathBuf> = candidates
+                .into_iter()
+                .enumerate()
+                .filter(|(i, _)| existing_mask[i % existing_mask.len()])
+                .map(|(_, path)| path)
+                .collect();
+
+            let base_existing: Vec<PathBuf> = existing
+                .iter()
+                .map(|path| Path::new(BASE_ROOT).join(path))
+                .collect();
+            let expected =
+                generate_plan(&entry, kind.adapter(BASE_ROOT).as_ref(), &base_existing);
+
+            let root = format!("/gpui-root/{}", segments.join("/"));
+            let mut shuffled = entry.clone();
+            shuffled.required_files = permuted(&entry.required_files, &keys);
+            let shuffled_existing: Vec<PathBuf> = permuted(&existing, &keys[1..])
+                .iter()
+                .map(|path| Path::new(&root).join(path))
+                .collect();
+            let layout = kind.adapter(root.as_str());
+            let actual = generate_plan(&shuffled, layout.as_ref(), &shuffled_existing);
+
+            prop_assert_eq!(
+                actual.to_json().unwrap().replace(&root, BASE_ROOT),
+                expected.to_json().unwrap()
+            );
+            prop_assert!(
+                actual
+                    .mutations
+                    .windows(2)
+                    .all(|w| mutation_key(&w[0]) <= mutation_key(&w[1]))
+            );
+        }
+    }
+
     // -- Conflict detection tests --
 
     #[test]
@@ -562,6 +2799,289 @@ mod tests {
         assert!(plan.conflicts.is_empty());
     }
 
+    // -- Marked section tests --
+
+    #[test]
+    fn replace_section_appends_replaces_and_removes() {
+        let v1 = marked_section("dialog-tokens", "const A: u8 = 1;");
+        let v2 = marked_section("dialog-tokens", "const A: u8 = 2;");
+        let existing = "pub struct ThemeTokens;\n";
+
+        let appended = replace_section(existing, &v1).unwrap();
+        assert_eq!(
+            appended,
+            "pub struct ThemeTokens;\n\n// gpui:begin:dialog-tokens\nconst A: u8 = 1;\n// gpui:end:dialog-tokens\n"
+        );
+        assert_eq!(replace_section(&appended, &v1).unwrap(), appended);
+
+        let replaced = replace_section(&appended, &v2).unwrap();
+        assert!(replaced.contains("const A: u8 = 2;"));
+        assert!(!replaced.contains("const A: u8 = 1;"));
+
+        let removal = marked_section("dialog-tokens", "");
+        assert_eq!(
+            replace_section(&replaced, &removal).unwrap(),
+            "pub struct ThemeTokens;\n\n"
+        );
+        assert_eq!(replace_section(existing, &removal).unwrap(), existing);
+    }
+
+    #[test]
+    fn find_section_rejects_malformed_markers() {
+        let begin = section_begin("x");
+        let end = section_end("x");
+        let cases = [
+            (format!("{begin}\n"), SectionError::MissingEnd),
+            (format!("{end}\n"), SectionError::MissingBegin),
+            (format!("{end}\n{begin}\n"), SectionError::EndBeforeBegin),
+            (
+                format!("{begin}\n{end}\n{begin}\n{end}\n"),
+                SectionError::Duplicate,
+            ),
+        ];
+        for (text, error) in cases {
+            assert_eq!(find_section(&text, "x"), Err(error), "{text:?}");
+            assert_eq!(
+                replace_section(&text, &marked_section("x", "y")),
+                Err(error)
+            );
+        }
+        // Markers for other sections are ignored.
+        assert_eq!(find_section(&format!("{begin}\n"), "other"), Ok(None));
+    }
+
+    #[test]
+    fn plans_manage_the_token_section() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let tokens_file = layout.theme_tokens_file();
+
+        let install = generate_plan(entry, &layout, &[]);
+        let section = install
+            .mutations
+            .iter()
+            .find(|m| m.strategy == MutationStrategy::ReplaceSection)
+            .expect("install records token dependencies");
+        assert_eq!(section.file_path, tokens_file);
+        assert_eq!(section_id(&section.content), Some("dialog-tokens"));
+        assert!(
+            section
+                .content
+                .contains("pub const DIALOG_TOKENS: &[&str] = &[")
+        );
+        for dependency in &entry.token_dependencies {
+            assert!(
+                section
+                    .content
+                    .contains(&format!("\"{}\"", dependency.path))
+            );
+        }
+
+        let remove = generate_remove_plan(entry, &layout, &[]);
+        let removal = remove
+            .mutations
+            .iter()
+            .find(|m| m.file_path == tokens_file)
+            .unwrap();
+        assert_eq!(removal.strategy, MutationStrategy::ReplaceSection);
+        assert_eq!(removal.content, marked_section("dialog-tokens", ""));
+    }
+
+    #[test]
+    #[cfg(feature = "gpui")]
+    fn missing_tokens_get_theme_defaults() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let defined = TokenSet::parse(
+            "pub struct TextTokens {\n    pub default: Hsla,\n}\n\
+             pub struct ThemeTokens {\n    pub text: TextTokens,\n}\n",
+        );
+
+        let mut install = generate_plan(entry, &layout, &[]);
+        install.define_missing_tokens(&registry, &defined, &layout);
+        let section = &install
+            .mutations
+            .iter()
+            .find(|m| m.strategy == MutationStrategy::ReplaceSection)
+            .unwrap()
+            .content;
+        assert!(section.contains("pub trait DialogTokenDefaults {"));
+        assert!(section.contains("impl DialogTokenDefaults for ThemeTokens {"));
+        assert!(!section.contains("fn text_default("));
+        let (dark, light) = default_token_values("surface.background").unwrap();
+        assert!(section.contains("    fn surface_background(&self) -> gpui::Hsla {"));
+        assert!(section.contains(&format!(
+            "ThemeAppearance::Dark => gpui::rgba(0x{}).into(),",
+            &dark[1..]
+        )));
+        assert!(section.contains(&format!(
+            "ThemeAppearance::Light => gpui::rgba(0x{}).into(),",
+            &light[1..]
+        )));
+
+        // The installed source reads the missing tokens through the trait.
+        let source = install
+            .mutations
+            .iter()
+            .find(|m| m.file_path == layout.component_dir("Dialog").join("dialog.rs"))
+            .unwrap();
+        assert!(
+            source
+                .content
+                .contains("use crate::shared::theme::tokens::DialogTokenDefaults as _;")
+        );
+        assert!(source.content.contains(".surface_background()"));
+        assert!(!source.content.contains(".surface.background"));
+        assert_eq!(
+            install.file_checksums[&source.file_path],
+            simple_checksum(&source.content)
+        );
+
+        // Component crates read the workbench theme, which needs no defaults.
+        let workspace = WorkspaceLayout::new("/test/project");
+        let mut crates = generate_plan(entry, &workspace, &[]);
+        crates.define_missing_tokens(&registry, &defined, &workspace);
+        assert_eq!(
+            crates.mutations,
+            generate_plan(entry, &workspace, &[]).mutations
+        );
+
+        // A theme defining every dependency needs no defaults.
+        let mut complete = generate_plan(entry, &layout, &[]);
+        let builtin = TokenSet::parse(include_str!("../../theme/src/tokens.rs"));
+        complete.define_missing_tokens(&registry, &builtin, &layout);
+        assert_eq!(
+            complete.mutations,
+            generate_plan(entry, &layout, &[]).mutations
+        );
+
+        let mut remove = generate_remove_plan(entry, &layout, &[]);
+        remove.define_missing_tokens(&registry, &defined, &layout);
+        let tokens_file = layout.theme_tokens_file();
+        assert_eq!(
+            remove
+                .mutations
+                .iter()
+                .find(|m| m.file_path == tokens_file)
+                .unwrap()
+                .content,
+            marked_section("dialog-tokens", "")
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "gpui")]
+    fn installed_sources_read_every_missing_token_through_defaults() {
+        // The built-in theme minus fields many components read.
+        let dropped = [
+            ("BorderTokens", "border.focused"),
+            ("GhostElementTokens", "ghost_element.hover"),
+            ("GhostElementTokens", "ghost_element.selected"),
+            ("SurfaceTokens", "surface.elevated_surface"),
+            ("TextTokens", "text.disabled"),
+        ];
+        let mut current = "";
+        let tokens_rs: String = include_str!("../../theme/src/tokens.rs")
+            .lines()
+            .filter(|line| {
+                if let Some(name) = line.strip_prefix("pub struct ") {
+                    current = name.trim_end_matches(" {");
+                }
+                !dropped.iter().any(|(owner, path)| {
+                    let field = path.split_once('.').unwrap().1;
+                    *owner == current && line.trim().starts_with(&format!("pub {field}:"))
+                })
+            })
+            .map(|line| format!("{line}\n"))
+            .collect();
+        let defined = TokenSet::parse(&tokens_rs);
+        let missing: Vec<&str> = dropped.iter().map(|(_, path)| *path).collect();
+        for path in &missing {
+            assert!(!defines_token(&defined, path), "{path}");
+        }
+
+        let reads = |source: &str, path: &str| {
+            let access = format!(".{path}");
+            source.match_indices(&access).any(|(offset, _)| {
+                !source[offset + access.len()..]
+                    .starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_')
+            })
+        };
+        let registry = generate_registry();
+        let layout = default_layout();
+        let mut rewritten = 0;
+        for entry in registry.list() {
+            let mut plan = generate_plan(entry, &layout, &[]);
+            plan.define_missing_tokens(&registry, &defined, &layout);
+            let section = plan
+                .mutations
+                .iter()
+                .find(|m| m.strategy == MutationStrategy::ReplaceSection)
+                .map(|m| m.content.as_str())
+                .unwrap_or_default();
+            let import = format!(
+                "use crate::shared::theme::tokens::{} as _;",
+                token_defaults_trait(&entry.name)
+            );
+            for source in plan.mutations.iter().filter(|m| {
+                m.strategy == MutationStrategy::WriteFile
+                    && m.file_path.extension().is_some_and(|ext| ext == "rs")
+            }) {
+                for path in &missing {
+                    assert!(
+                        !reads(&source.content, path),
+                        "{} reads {path}, which the target theme lacks",
+                        source.file_path.display()
+                    );
+                    let method = token_method(path);
+                    if source.content.contains(&format!(".{method}()")) {
+                        assert!(
+                            section.contains(&format!("    fn {method}(&self) -> gpui::Hsla {{")),
+                            "{} calls {method}() without a default",
+                            entry.name
+                        );
+                        assert!(source.content.contains(&import), "{}", entry.name);
+                        rewritten += 1;
+                    }
+                }
+            }
+        }
+        assert!(rewritten > 0);
+    }
+
+    #[test]
+    fn section_conflicts_for_malformed_or_missing_markers() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let layout = default_layout();
+        let tokens_file = layout.theme_tokens_file();
+        let read = |text: &'static str| {
+            let tokens_file = tokens_file.clone();
+            move |path: &Path| (path == tokens_file).then(|| text.to_string())
+        };
+
+        // Installs append to a file without markers, but not to a broken one.
+        let mut install = generate_plan(entry, &layout, &[]);
+        install.detect_section_conflicts(read("pub struct ThemeTokens;\n"));
+        assert!(!install.has_conflicts());
+        install.detect_section_conflicts(read("// gpui:begin:dialog-tokens\n"));
+        assert_eq!(install.conflicts.len(), 1);
+        assert_eq!(install.conflicts[0].file_path, tokens_file);
+        assert!(install.conflicts[0].reason.contains("no end marker"));
+
+        // Removing a section whose markers were edited away needs a human.
+        let mut remove = generate_remove_plan(entry, &layout, &[]);
+        remove.detect_section_conflicts(read("pub struct ThemeTokens;\n"));
+        assert_eq!(remove.conflicts.len(), 1);
+        assert!(remove.conflicts[0].reason.contains("missing"));
+
+        // Skipping the conflict drops the section mutation.
+        assert!(remove.resolve_conflict(&tokens_file, ConflictResolution::Skip, ""));
+        assert!(remove.mutations.iter().all(|m| m.file_path != tokens_file));
+    }
+
     // -- JSON serialization tests --
 
     #[test]
@@ -586,6 +3106,47 @@ mod tests {
         assert_eq!(restored.target_layout, plan.target_layout);
     }
 
+    #[test]
+    fn rationale_and_annotations_survive_json_and_show_in_tree() {
+        let registry = generate_registry();
+        let entry = registry.get("Dialog").unwrap();
+        let mut plan = generate_plan(entry, &default_layout(), &[]);
+        assert!(plan.mutations.iter().all(|m| !m.rationale.is_empty()));
+        let source = plan
+            .mutations
+            .iter_mut()
+            .find(|m| m.file_path.ends_with("dialog.rs"))
+            .unwrap();
+        assert_eq!(
+            source.rationale,
+            "created because required_files lists crates/components/src/dialog.rs"
+        );
+        source.add_rationale("reviewed by agent");
+        plan.annotate("reviewer", "safe to apply");
+
+        let restored = PlanContract::from_json(&plan.to_json().unwrap()).unwrap();
+        assert_eq!(restored.mutations, plan.mutations);
+        assert_eq!(restored.annotations["reviewer"], "safe to apply");
+
+        let tree = restored.to_tree();
+        assert!(tree.starts_with("add Dialog v"));
+        assert!(tree.contains(
+            "why: created because required_files lists crates/components/src/dialog.rs; \
+             reviewed by agent"
+        ));
+        assert!(tree.ends_with("└── annotations\n      reviewer: safe to apply\n"));
+
+        // Plans written before rationales existed still load.
+        let mut json: serde_json::Value = serde_json::from_str(&plan.to_json().unwrap()).unwrap();
+        for mutation in json["mutations"].as_array_mut().unwrap() {
+            mutation.as_object_mut().unwrap().remove("rationale");
+        }
+        json.as_object_mut().unwrap().remove("annotations");
+        let old = PlanContract::from_json(&json.to_string()).unwrap();
+        assert!(old.annotations.is_empty());
+        assert!(old.mutations.iter().all(|m| m.rationale.is_empty()));
+    }
+
     #[test]
     fn plan_json_contains_agent_readable_fields() {
         let registry = generate_registry();
@@ -726,8 +3287,164 @@ mod tests {
         );
     }
 
+    #[test]
+    fn flat_layout_installs_single_file_modules() {
+        let layout = FlatLayout::new("/myapp");
+        assert_eq!(layout.name(), "flat");
+        assert_eq!(layout.module_file(), PathBuf::from("/myapp/src/ui.rs"));
+
+        let registry = generate_registry();
+        let plan = generate_plan(registry.get("dialog").unwrap(), &layout, &[]);
+        let created: Vec<&Path> = plan
+            .mutations
+            .iter()
+            .filter(|m| m.action == FileAction::Create)
+            .map(|m| m.file_path.as_path())
+            .collect();
+        assert!(created.contains(&Path::new("/myapp/src/ui/dialog.rs")));
+        assert!(created.contains(&Path::new("/myapp/src/ui/dialog/dialog.rs")));
+        assert!(!created.iter().any(|path| path.ends_with("mod.rs")));
+        assert_eq!(plan.target_layout, "flat");
+    }
+
+    #[test]
+    fn workspace_layout_scaffolds_component_crate() {
+        let layout = WorkspaceLayout::new("/myapp");
+        let registry = generate_registry();
+        let entry = registry.get("dialog").unwrap();
+        let plan = generate_plan(entry, &layout, &[]);
+
+        let manifest = plan
+            .mutations
+            .iter()
+            .find(|m| m.file_path == Path::new("/myapp/crates/ui-dialog/Cargo.toml"))
+            .expect("crate manifest is scaffolded");
+        assert!(manifest.content.contains("name = \"ui-dialog\""));
+        assert!(manifest.content.contains("smallvec.workspace = true\n"));
+        assert!(manifest.content.contains("theme.workspace = true\n"));
+        assert!(
+            plan.file_checksums
+                .contains_key(Path::new("/myapp/crates/ui-dialog/src/lib.rs"))
+        );
+        let export = plan.mutations.last().unwrap();
+        assert_eq!(export.content, "pub use ui_dialog as dialog;");
+
+        // Removal deletes the scaffolding with the rest of the crate.
+        let remove = generate_remove_plan(entry, &layout, &[]);
+        assert!(
+            remove
+                .mutations
+                .iter()
+                .any(|m| m.file_path == manifest.file_path && m.action == FileAction::Delete)
+        );
+    }
+
+    #[test]
+    fn layout_kind_names_roundtrip() {
+        for kind in LayoutKind::ALL {
+            assert_eq!(kind.name().parse::<LayoutKind>(), Ok(kind));
+            assert_eq!(kind.adapter("/myapp").name(), kind.name());
+            assert_eq!(
+                serde_json::to_string(&kind).unwrap(),
+                format!("\"{}\"", kind.name())
+            );
+        }
+        assert!("nested".parse::<LayoutKind>().is_err());
+    }
+
     // -- Operation / FileAction / MutationStrategy serialization --
 
+    // -- Plan diff tests --
+
+    #[test]
+    fn plan_diff_of_identical_plans_is_empty() {
+        let registry = generate_registry();
+        let plan = generate_install_plan(&registry, "select", &default_layout(), &[]).unwrap();
+        assert!(PlanDiff::between(&plan, &plan.clone()).is_empty());
+    }
+
+    #[test]
+    fn plan_diff_reports_mutations_conflicts_and_checksums() {
+        let registry = generate_registry();
+        let layout = default_layout();
+        let before = generate_install_plan(&registry, "dialog", &layout, &[]).unwrap();
+        let module_file = layout.component_module_file("Dialog");
+        let mut after = generate_install_plan(
+            &registry,
+            "dialog",
+            &layout,
+            std::slice::from_ref(&module_file),
+        )
+        .unwrap();
+        let readme = after
+            .mutations
+            .iter_mut()
+            .find(|m| m.file_path.ends_with("README.md"))
+            .unwrap();
+        readme.content.push_str("\nEdited.\n");
+        let readme_path = readme.file_path.clone();
+        after
+            .file_checksums
+            .insert(readme_path.clone(), "0000000000000000".to_string());
+        let removed = after.mutations.pop().unwrap();
+
+        let diff = PlanDiff::between(&before, &after);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.before, diff.after);
+
+        let readme_change = diff
+            .mutations
+            .iter()
+            .find(|c| c.file_path == readme_path)
+            .unwrap();
+        assert_eq!(readme_change.kind, ChangeKind::Changed);
+        assert!(
+            readme_change
+                .after
+                .as_ref()
+                .unwrap()
+                .content
+                .ends_with("Edited.\n")
+        );
+
+        let removed_change = diff.mutations.last().unwrap();
+        assert_eq!(removed_change.kind, ChangeKind::Removed);
+        assert_eq!(removed_change.before.as_ref(), Some(&removed));
+        assert!(removed_change.after.is_none());
+
+        assert_eq!(diff.conflicts.len(), 1);
+        assert_eq!(diff.conflicts[0].kind, ChangeKind::Added);
+        assert_eq!(diff.conflicts[0].file_path, module_file);
+
+        let checksum = diff
+            .checksums
+            .iter()
+            .find(|c| c.file_path == readme_path)
+            .unwrap();
+        assert_eq!(checksum.kind, ChangeKind::Changed);
+
+        let json: serde_json::Value = serde_json::from_str(&diff.to_json().unwrap()).unwrap();
+        assert_eq!(json["conflicts"][0]["kind"], "added");
+    }
+
+    #[test]
+    fn plan_diff_pairs_repeated_mutations_in_order() {
+        let registry = generate_registry();
+        let layout = default_layout();
+        let before = generate_install_plan(&registry, "tooltip", &layout, &[]).unwrap();
+        let after = generate_install_plan(&registry, "select", &layout, &[]).unwrap();
+
+        let diff = PlanDiff::between(&before, &after);
+        assert_eq!(diff.after.component_name, "Select");
+        let exports: Vec<ChangeKind> = diff
+            .mutations
+            .iter()
+            .filter(|c| c.strategy == MutationStrategy::AppendExport)
+            .map(|c| c.kind)
+            .collect();
+        assert!(exports.contains(&ChangeKind::Added), "{exports:?}");
+    }
+
     #[test]
     fn operation_json_names() {
         assert_eq!(serde_json::to_string(&Operation::Add).unwrap(), "\"add\"");
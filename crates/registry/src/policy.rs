@@ -0,0 +1,314 @@
+//! Disposition policy: the team's sourcing rules, checked against contracts.
+//!
+//! A policy is a list of rules in the `[policy]` table of `gpui.toml`, each
+//! optionally scoped to one disposition:
+//!
+//! ```toml
+//! [[policy.rules]]
+//! disposition = "reuse"
+//! forbid_licenses = ["GPL", "AGPL"]
+//!
+//! [[policy.rules]]
+//! disposition = "fork"
+//! require_pinned_commit = true
+//! ```
+//!
+//! Rules are checked against the provenance each component's sources
+//! declare in their module docs:
+//!
+//! ```text
+//! //! Provenance:
+//! //! - gpui-component `crates/ui/src/select.rs` @ 1a2b3c4 (MIT, Zed Industries)
+//! //! - Modifications: ...
+//! ```
+//!
+//! Each `- ` entry other than `Modifications:` names an upstream source,
+//! optionally pinned with `@ <commit>`, followed by `(<license>, <owner>)`.
+
+use components::{ComponentContract, Disposition, ValidationError};
+use serde::{Deserialize, Serialize};
+
+use crate::sources::embedded_source;
+
+/// One sourcing rule.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyRule {
+    /// Disposition the rule applies to; every component when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<Disposition>,
+    /// SPDX license ids the sources may not use. An id also forbids its
+    /// versions: `"GPL"` matches `GPL-3.0` but not `AGPL-3.0`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbid_licenses: Vec<String>,
+    /// Sources must declare at least one upstream source.
+    #[serde(default)]
+    pub require_provenance: bool,
+    /// Every upstream source must be pinned to a commit.
+    #[serde(default)]
+    pub require_pinned_commit: bool,
+}
+
+impl PolicyRule {
+    /// Whether the rule applies to a component with `disposition`.
+    pub fn applies_to(&self, disposition: Disposition) -> bool {
+        self.disposition.is_none_or(|scope| scope == disposition)
+    }
+}
+
+/// The `[policy]` table of `gpui.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DispositionPolicy {
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+}
+
+/// An upstream source declared in a component's provenance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceProvenance {
+    /// The upstream reference, e.g. ``gpui-component `crates/ui/src/select.rs` ``.
+    pub origin: String,
+    /// The pinned commit, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// License expression, e.g. `MIT` or `GPL-3.0/AGPL-3.0`.
+    pub license: String,
+}
+
+/// A contract that breaks a policy rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyViolation {
+    pub component: String,
+    /// Index of the broken rule in the policy.
+    pub rule: usize,
+    pub message: String,
+}
+
+impl DispositionPolicy {
+    /// Whether the policy has no rules, so `[policy]` can be omitted.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Check one contract against every rule, reading provenance from its
+    /// embedded sources.
+    pub fn check(&self, contract: &ComponentContract) -> Vec<PolicyViolation> {
+        let provenance: Vec<SourceProvenance> = contract
+            .required_files
+            .iter()
+            .filter_map(|file| embedded_source(file))
+            .flat_map(parse_provenance)
+            .collect();
+        self.check_provenance(contract, &provenance)
+    }
+
+    /// Check a contract against every rule, given its declared provenance.
+    pub fn check_provenance(
+        &self,
+        contract: &ComponentContract,
+        provenance: &[SourceProvenance],
+    ) -> Vec<PolicyViolation> {
+        let disposition = contract.disposition;
+        let mut violations = Vec::new();
+        for (rule_index, rule) in self.rules.iter().enumerate() {
+            if !rule.applies_to(disposition) {
+                continue;
+            }
+            let mut violate = |message: String| {
+                violations.push(PolicyViolation {
+                    component: contract.name.clone(),
+                    rule: rule_index,
+                    message,
+                });
+            };
+            if rule.require_provenance && provenance.is_empty() {
+                violate(format!(
+                    "{disposition:?} component declares no upstream provenance"
+                ));
+            }
+            for source in provenance {
+                if let Some(id) = rule
+                    .forbid_licenses
+                    .iter()
+                    .find(|id| license_matches(&source.license, id))
+                {
+                    violate(format!(
+                        "{disposition:?} of {} is not allowed: its license {} matches forbidden `{id}`",
+                        source.origin, source.license
+                    ));
+                }
+                if rule.require_pinned_commit && source.commit.is_none() {
+                    violate(format!(
+                        "{disposition:?} of {} must pin an upstream commit (`@ <commit>`)",
+                        source.origin
+                    ));
+                }
+            }
+        }
+        violations
+    }
+
+    /// Check every registry contract, in name order.
+    pub fn check_registry(&self) -> Vec<PolicyViolation> {
+        crate::all_contracts()
+            .iter()
+            .flat_map(|contract| self.check(contract))
+            .collect()
+    }
+}
+
+impl PolicyViolation {
+    /// The violation as a contract validation error.
+    pub fn to_validation_error(&self) -> ValidationError {
+        ValidationError {
+            field: "disposition".into(),
+            message: format!("policy rule {}: {}", self.rule + 1, self.message),
+        }
+    }
+}
+
+/// The upstream sources declared under `Provenance:` in a source file's
+/// module docs.
+pub fn parse_provenance(source: &str) -> Vec<SourceProvenance> {
+    let docs = source
+        .lines()
+        .map_while(|line| line.strip_prefix("//!"))
+        .map(str::trim);
+    docs.skip_while(|line| *line != "Provenance:")
+        .skip(1)
+        .take_while(|line| !line.is_empty())
+        .filter_map(|line| line.strip_prefix("- "))
+        .filter(|entry| !entry.starts_with("Modifications:"))
+        .filter_map(parse_source)
+        .collect()
+}
+
+/// `<origin> [@ <commit>] (<license>, <owner>)`.
+fn parse_source(entry: &str) -> Option<SourceProvenance> {
+    let (head, attribution) = entry.trim_end().strip_suffix(')')?.rsplit_once(" (")?;
+    let license = attribution.split(',').next()?.trim().to_string();
+    let (origin, commit) = match head.rsplit_once(" @ ") {
+        Some((origin, commit)) => (origin, Some(commit.trim().to_string())),
+        None => (head, None),
+    };
+    Some(SourceProvenance {
+        origin: origin.trim().to_string(),
+        commit: commit.filter(|commit| is_commit(commit)),
+        license,
+    })
+}
+
+/// An abbreviated or full hex commit hash.
+fn is_commit(text: &str) -> bool {
+    (7..=40).contains(&text.len()) && text.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Whether a license expression such as `GPL-3.0/AGPL-3.0` or
+/// `Apache-2.0 OR MIT` includes `id` or one of its versions.
+fn license_matches(license: &str, id: &str) -> bool {
+    license
+        .split(|c: char| c == '/' || c == ',' || c.is_whitespace())
+        .filter(|part| !part.is_empty() && *part != "OR" && *part != "AND")
+        .map(str::to_ascii_lowercase)
+        .any(|part| {
+            let id = id.to_ascii_lowercase();
+            part == id
+                || part
+                    .strip_prefix(&id)
+                    .is_some_and(|version| version.starts_with('-'))
+        })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use components::catalog;
+
+    const SOURCE: &str = "//! Select component.\n\
+        //!\n\
+        //! Provenance:\n\
+        //! - gpui-component `crates/ui/src/select.rs` @ 1a2b3c4d (MIT, Zed Industries)\n\
+        //! - Zed `crates/ui/src/components/dropdown_menu.rs` (GPL-3.0/AGPL-3.0, Zed Industries)\n\
+        //! - Modifications: Simplified to POC scope (MIT-compatible),\n\
+        //!   rewired to internal token system.\n\
+        \n\
+        use gpui::*;\n";
+
+    #[test]
+    fn provenance_entries_parse_from_module_docs() {
+        let sources = parse_provenance(SOURCE);
+        assert_eq!(
+            sources,
+            [
+                SourceProvenance {
+                    origin: "gpui-component `crates/ui/src/select.rs`".into(),
+                    commit: Some("1a2b3c4d".into()),
+                    license: "MIT".into(),
+                },
+                SourceProvenance {
+                    origin: "Zed `crates/ui/src/components/dropdown_menu.rs`".into(),
+                    commit: None,
+                    license: "GPL-3.0/AGPL-3.0".into(),
+                },
+            ]
+        );
+        assert!(parse_provenance("//! No provenance here.\n").is_empty());
+
+        assert!(license_matches("GPL-3.0/AGPL-3.0", "GPL"));
+        assert!(license_matches("GPL-3.0/AGPL-3.0", "agpl-3.0"));
+        assert!(!license_matches("AGPL-3.0", "GPL"));
+        assert!(!license_matches("Apache-2.0 OR MIT", "GPL"));
+    }
+
+    #[test]
+    fn rules_flag_only_their_disposition() {
+        let policy: DispositionPolicy = toml::from_str(
+            r#"
+            [[rules]]
+            disposition = "reuse"
+            forbid_licenses = ["GPL"]
+
+            [[rules]]
+            disposition = "fork"
+            require_pinned_commit = true
+            "#,
+        )
+        .unwrap();
+        let sources = parse_provenance(SOURCE);
+
+        let mut contract = catalog::select();
+        contract.disposition = Disposition::Fork;
+        let violations = policy.check_provenance(&contract, &sources);
+        assert_eq!(violations.len(), 1, "{violations:#?}");
+        assert_eq!(violations[0].rule, 1);
+        assert!(violations[0].message.contains("dropdown_menu.rs"));
+
+        contract.disposition = Disposition::Reuse;
+        let violations = policy.check_provenance(&contract, &sources);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule, 0);
+        assert!(violations[0].message.contains("`GPL`"));
+
+        contract.disposition = Disposition::Rewrite;
+        assert!(policy.check_provenance(&contract, &sources).is_empty());
+    }
+
+    #[test]
+    fn registry_tooltip_is_a_gpl_reuse() {
+        // Tooltip is the one Reuse component, and its Zed source is GPL.
+        let policy = DispositionPolicy {
+            rules: vec![PolicyRule {
+                disposition: Some(Disposition::Reuse),
+                forbid_licenses: vec!["GPL".into()],
+                ..PolicyRule::default()
+            }],
+        };
+        let violations = policy.check_registry();
+        assert!(!violations.is_empty());
+        assert!(violations.iter().all(|v| v.component == "Tooltip"));
+        assert!(DispositionPolicy::default().check_registry().is_empty());
+    }
+}
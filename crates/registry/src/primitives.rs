@@ -0,0 +1,138 @@
+//! Primitive index: the behavior primitives components are built on.
+//!
+//! Primitives are not installable on their own, so they are indexed apart
+//! from [`RegistryIndex`](crate::RegistryIndex), from the
+//! [`PrimitiveContract`]s each primitive module publishes.
+
+use std::collections::BTreeMap;
+
+use primitives::PrimitiveContract;
+use serde::{Deserialize, Serialize};
+
+/// Index of primitive contracts, parallel to the component registry.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PrimitiveIndex {
+    /// Contracts indexed by lowercase name for case-insensitive lookup.
+    entries: BTreeMap<String, PrimitiveContract>,
+}
+
+impl PrimitiveIndex {
+    /// Create an empty primitive index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a primitive contract, replacing any with the same name.
+    pub fn register(&mut self, contract: PrimitiveContract) {
+        self.entries.insert(contract.name.to_lowercase(), contract);
+    }
+
+    /// Look up a primitive by name (case-insensitive).
+    pub fn get(&self, name: &str) -> Option<&PrimitiveContract> {
+        self.entries.get(&name.to_lowercase())
+    }
+
+    /// All registered contracts, sorted by name.
+    pub fn list(&self) -> Vec<&PrimitiveContract> {
+        self.entries.values().collect()
+    }
+
+    /// Primitives consumed by a component (matched case-insensitively).
+    pub fn consumed_by(&self, component: &str) -> Vec<&PrimitiveContract> {
+        self.entries
+            .values()
+            .filter(|contract| {
+                contract
+                    .consumers
+                    .iter()
+                    .any(|consumer| consumer.eq_ignore_ascii_case(component))
+            })
+            .collect()
+    }
+
+    /// Number of registered primitives.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the index is empty.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serialize the index to JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Deserialize an index from JSON.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+/// Generate the primitive index from every primitive's contract.
+pub fn generate_primitive_index() -> PrimitiveIndex {
+    let mut index = PrimitiveIndex::new();
+    for contract in primitives::contract::all_contracts() {
+        index.register(contract);
+    }
+    index
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+
+    #[test]
+    fn consumers_are_registered_components() {
+        let components = generate_registry();
+        let index = generate_primitive_index();
+        assert!(!index.is_empty());
+
+        for contract in index.list() {
+            for consumer in &contract.consumers {
+                assert!(
+                    components.get(consumer).is_some(),
+                    "{} lists unknown consumer {}",
+                    contract.name,
+                    consumer
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_and_consumers() {
+        let index = generate_primitive_index();
+        assert_eq!(index.get("focustrap").unwrap().name, "FocusTrap");
+
+        let dialog: Vec<&str> = index
+            .consumed_by("dialog")
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(
+            dialog,
+            [
+                "FocusReturn",
+                "FocusTrap",
+                "LayerManager",
+                "MotionPreference",
+                "OverlayStateMachine"
+            ]
+        );
+    }
+
+    #[test]
+    fn primitive_index_json_roundtrip() {
+        let index = generate_primitive_index();
+        let restored = PrimitiveIndex::from_json(&index.to_json().unwrap()).unwrap();
+        assert_eq!(restored.list(), index.list());
+    }
+}
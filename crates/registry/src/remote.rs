@@ -0,0 +1,507 @@
+//! Remote registries: fetch a published index and component sources over HTTP.
+//!
+//! A remote registry is a base URL serving `registry-index.json` (a serialized
+//! [`RegistryIndex`]) and, for each entry that carries an [`ArchiveRef`], a
+//! gzipped tarball of the component's source files. Downloads are cached in a
+//! local directory: the index is refreshed on every fetch and read back from
+//! the cache when the registry is unreachable, while archives are cached by
+//! checksum and verified against the index both on download and on reuse.
+
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+#[cfg(feature = "http")]
+use std::time::Duration;
+
+use flate2::read::GzDecoder;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{RegistryEntry, RegistryIndex};
+
+/// Path of the published index, relative to the registry base URL.
+pub const INDEX_FILE: &str = "registry-index.json";
+
+/// Cache subdirectory holding downloaded archives, named by checksum.
+const ARCHIVE_CACHE_DIR: &str = "archives";
+
+/// Timeout for a single HTTP request.
+#[cfg(feature = "http")]
+const HTTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Upper bound on a downloaded file, so a misbehaving server cannot exhaust memory.
+#[cfg(feature = "http")]
+const MAX_DOWNLOAD_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Upper bound on an unpacked archive, so a small gzip bomb cannot exhaust memory.
+const MAX_UNPACKED_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Tar block size; headers and file data are padded to it.
+const TAR_BLOCK: usize = 512;
+
+/// Where a component's published sources live.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArchiveRef {
+    /// Archive path relative to the registry base URL, e.g. `archives/button-0.1.0.tar.gz`.
+    pub path: String,
+    /// Lowercase hex SHA-256 of the archive.
+    pub sha256: String,
+}
+
+/// Fetches raw bytes for a URL.
+///
+/// [`HttpTransport`] is the real implementation; tests substitute an in-memory map.
+pub trait Transport {
+    fn get(&self, url: &str) -> Result<Vec<u8>, String>;
+}
+
+/// Blocking HTTP(S) transport.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HttpTransport;
+
+#[cfg(feature = "http")]
+impl Transport for HttpTransport {
+    fn get(&self, url: &str) -> Result<Vec<u8>, String> {
+        let response = ureq::get(url)
+            .timeout(HTTP_TIMEOUT)
+            .call()
+            .map_err(|e| e.to_string())?;
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_DOWNLOAD_BYTES)
+            .read_to_end(&mut body)
+            .map_err(|e| e.to_string())?;
+        Ok(body)
+    }
+}
+
+/// Without the `http` feature every request fails, so only cached copies
+/// are served.
+#[cfg(not(feature = "http"))]
+impl Transport for HttpTransport {
+    fn get(&self, url: &str) -> Result<Vec<u8>, String> {
+        Err(format!(
+            "cannot fetch {url}: built without the `http` feature"
+        ))
+    }
+}
+
+/// Errors from fetching a remote registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RemoteError {
+    /// The request failed and nothing usable was cached.
+    Transport { url: String, message: String },
+    /// The index could not be parsed.
+    InvalidIndex { url: String, message: String },
+    /// The entry does not publish a source archive.
+    MissingArchive { component: String },
+    /// The downloaded archive does not match the checksum in the index.
+    ChecksumMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+    /// The archive is not a readable gzipped tarball of UTF-8 files.
+    InvalidArchive { url: String, message: String },
+}
+
+impl std::fmt::Display for RemoteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteError::Transport { url, message } => {
+                write!(f, "failed to fetch {url}: {message}")
+            }
+            RemoteError::InvalidIndex { url, message } => {
+                write!(f, "invalid registry index at {url}: {message}")
+            }
+            RemoteError::MissingArchive { component } => {
+                write!(f, "component '{component}' has no published source archive")
+            }
+            RemoteError::ChecksumMismatch {
+                url,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "checksum mismatch for {url}: expected {expected}, got {actual}"
+            ),
+            RemoteError::InvalidArchive { url, message } => {
+                write!(f, "invalid archive at {url}: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RemoteError {}
+
+/// A registry served from a URL, with a local download cache.
+pub struct RemoteRegistry<T: Transport = HttpTransport> {
+    base_url: String,
+    cache_dir: PathBuf,
+    transport: T,
+}
+
+impl RemoteRegistry {
+    /// A registry at `base_url` fetched over HTTP, caching into `cache_dir`.
+    pub fn new(base_url: impl Into<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        Self::with_transport(base_url, cache_dir, HttpTransport)
+    }
+}
+
+impl<T: Transport> RemoteRegistry<T> {
+    /// A registry at `base_url` fetched with a custom transport.
+    pub fn with_transport(
+        base_url: impl Into<String>,
+        cache_dir: impl Into<PathBuf>,
+        transport: T,
+    ) -> Self {
+        Self {
+            base_url: base_url.into(),
+            cache_dir: cache_dir.into(),
+            transport,
+        }
+    }
+
+    /// The registry base URL.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetch the published index.
+    ///
+    /// A successful download refreshes the cached copy; if the registry is
+    /// unreachable, the cached copy is used instead.
+    pub fn fetch_index(&self) -> Result<RegistryIndex, RemoteError> {
+        let url = self.url(INDEX_FILE);
+        let cached = self.cache_dir.join(INDEX_FILE);
+        let bytes = match self.transport.get(&url) {
+            Ok(bytes) => {
+                write_cache(&cached, &bytes);
+                bytes
+            }
+            Err(message) => std::fs::read(&cached).map_err(|_| RemoteError::Transport {
+                url: url.clone(),
+                message,
+            })?,
+        };
+        let json = String::from_utf8_lossy(&bytes);
+        RegistryIndex::from_json(&json).map_err(|e| RemoteError::InvalidIndex {
+            url,
+            message: e.to_string(),
+        })
+    }
+
+    /// Fetch and unpack a component's published sources.
+    ///
+    /// Returns archive member paths (relative, `/`-separated) mapped to file contents.
+    pub fn fetch_sources(
+        &self,
+        entry: &RegistryEntry,
+    ) -> Result<BTreeMap<String, String>, RemoteError> {
+        let archive = entry
+            .archive
+            .as_ref()
+            .ok_or_else(|| RemoteError::MissingArchive {
+                component: entry.name.clone(),
+            })?;
+        let url = self.url(&archive.path);
+        let cached = self
+            .cache_dir
+            .join(ARCHIVE_CACHE_DIR)
+            .join(archive.sha256.to_lowercase());
+
+        let bytes = match std::fs::read(&cached) {
+            Ok(bytes) if sha256_hex(&bytes).eq_ignore_ascii_case(&archive.sha256) => bytes,
+            _ => {
+                let bytes = self
+                    .transport
+                    .get(&url)
+                    .map_err(|message| RemoteError::Transport {
+                        url: url.clone(),
+                        message,
+                    })?;
+                let actual = sha256_hex(&bytes);
+                if !actual.eq_ignore_ascii_case(&archive.sha256) {
+                    return Err(RemoteError::ChecksumMismatch {
+                        url,
+                        expected: archive.sha256.clone(),
+                        actual,
+                    });
+                }
+                write_cache(&cached, &bytes);
+                bytes
+            }
+        };
+
+        unpack_tar_gz(&bytes, MAX_UNPACKED_BYTES)
+            .map_err(|message| RemoteError::InvalidArchive { url, message })
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.base_url.trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+}
+
+/// Lowercase hex SHA-256 of `bytes`.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Best-effort cache write: a failed write only costs a later re-download.
+fn write_cache(path: &Path, bytes: &[u8]) {
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let _ = std::fs::write(path, bytes);
+}
+
+/// Unpack the regular files of a gzipped ustar archive, rejecting archives
+/// that decompress to more than `max_unpacked` bytes.
+fn unpack_tar_gz(bytes: &[u8], max_unpacked: u64) -> Result<BTreeMap<String, String>, String> {
+    let mut tar = Vec::new();
+    // One byte past the cap tells an archive at the limit from one over it.
+    GzDecoder::new(bytes)
+        .take(max_unpacked + 1)
+        .read_to_end(&mut tar)
+        .map_err(|e| e.to_string())?;
+    if tar.len() as u64 > max_unpacked {
+        return Err(format!("archive unpacks to more than {max_unpacked} bytes"));
+    }
+
+    let mut files = BTreeMap::new();
+    let mut offset = 0;
+    while offset + TAR_BLOCK <= tar.len() {
+        let header = &tar[offset..offset + TAR_BLOCK];
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+
+        let name = tar_field(&header[0..100]);
+        let prefix = if header[257..262] == *b"ustar" {
+            tar_field(&header[345..500])
+        } else {
+            String::new()
+        };
+        let path = if prefix.is_empty() {
+            name
+        } else {
+            format!("{prefix}/{name}")
+        };
+        let size = usize::from_str_radix(tar_field(&header[124..136]).trim(), 8)
+            .map_err(|_| format!("invalid size for '{path}'"))?;
+
+        let start = offset + TAR_BLOCK;
+        let end = start
+            .checked_add(size)
+            .filter(|end| *end <= tar.len())
+            .ok_or_else(|| format!("truncated entry '{path}'"))?;
+
+        // Regular files only; directories, links, and extended headers are skipped.
+        if matches!(header[156], b'0' | 0) {
+            let path = member_path(&path)?;
+            let content = String::from_utf8(tar[start..end].to_vec())
+                .map_err(|_| format!("'{path}' is not UTF-8"))?;
+            files.insert(path, content);
+        }
+        offset = start + size.div_ceil(TAR_BLOCK) * TAR_BLOCK;
+    }
+    Ok(files)
+}
+
+/// A NUL-terminated tar header field.
+fn tar_field(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// Normalize an archive member path, rejecting ones that escape the component directory.
+fn member_path(path: &str) -> Result<String, String> {
+    let mut parts = Vec::new();
+    for component in Path::new(path).components() {
+        match component {
+            Component::Normal(part) => parts.push(part.to_string_lossy().into_owned()),
+            Component::CurDir => {}
+            _ => return Err(format!("unsafe path '{path}'")),
+        }
+    }
+    if parts.is_empty() {
+        return Err(format!("empty path '{path}'"));
+    }
+    Ok(parts.join("/"))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::io::Write;
+
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
+    const BASE_URL: &str = "https://registry.example.com/gpui/";
+
+    /// In-memory transport recording every requested URL.
+    #[derive(Default)]
+    struct MapTransport {
+        files: HashMap<String, Vec<u8>>,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Transport for MapTransport {
+        fn get(&self, url: &str) -> Result<Vec<u8>, String> {
+            self.requests.borrow_mut().push(url.to_string());
+            self.files
+                .get(url)
+                .cloned()
+                .ok_or_else(|| "connection refused".to_string())
+        }
+    }
+
+    fn tar_gz(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut tar = Vec::new();
+        for (path, content) in files {
+            let mut header = [0u8; TAR_BLOCK];
+            header[..path.len()].copy_from_slice(path.as_bytes());
+            header[100..107].copy_from_slice(b"0000644");
+            header[124..135].copy_from_slice(format!("{:011o}", content.len()).as_bytes());
+            header[156] = b'0';
+            header[257..263].copy_from_slice(b"ustar\0");
+            tar.extend_from_slice(&header);
+            tar.extend_from_slice(content.as_bytes());
+            tar.resize(tar.len().div_ceil(TAR_BLOCK) * TAR_BLOCK, 0);
+        }
+        tar.extend_from_slice(&[0; TAR_BLOCK * 2]);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&tar).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn temp_cache() -> PathBuf {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::SeqCst);
+        let dir =
+            std::env::temp_dir().join(format!("gpui-remote-test-{}-{}", std::process::id(), id));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn published_index(archive: ArchiveRef) -> RegistryIndex {
+        let mut index = crate::generate_registry();
+        index.entries.get_mut("button").unwrap().archive = Some(archive);
+        index
+    }
+
+    #[test]
+    fn fetch_index_caches_and_falls_back_when_offline() {
+        let cache = temp_cache();
+        let index = crate::generate_registry();
+        let mut transport = MapTransport::default();
+        transport.files.insert(
+            format!("{BASE_URL}{INDEX_FILE}"),
+            index.to_json().unwrap().into_bytes(),
+        );
+
+        let remote = RemoteRegistry::with_transport(BASE_URL, &cache, transport);
+        assert_eq!(remote.fetch_index().unwrap().len(), index.len());
+        assert_eq!(
+            remote.transport.requests.borrow()[0],
+            "https://registry.example.com/gpui/registry-index.json"
+        );
+
+        let offline = RemoteRegistry::with_transport(BASE_URL, &cache, MapTransport::default());
+        assert_eq!(offline.fetch_index().unwrap().len(), index.len());
+
+        let uncached =
+            RemoteRegistry::with_transport(BASE_URL, temp_cache(), MapTransport::default());
+        assert!(matches!(
+            uncached.fetch_index(),
+            Err(RemoteError::Transport { .. })
+        ));
+        let _ = std::fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn fetch_sources_verifies_checksum_and_reuses_cache() {
+        let cache = temp_cache();
+        let archive = tar_gz(&[
+            ("button.rs", "pub struct Button;\n"),
+            ("./README.md", "# Button\n"),
+        ]);
+        let archive_ref = ArchiveRef {
+            path: "archives/button-0.1.0.tar.gz".into(),
+            sha256: sha256_hex(&archive),
+        };
+        let index = published_index(archive_ref.clone());
+        let entry = index.get("button").unwrap();
+        assert_eq!(entry.archive.as_ref(), Some(&archive_ref));
+
+        let mut transport = MapTransport::default();
+        transport.files.insert(
+            format!("{BASE_URL}archives/button-0.1.0.tar.gz"),
+            archive.clone(),
+        );
+        let remote = RemoteRegistry::with_transport(BASE_URL, &cache, transport);
+        let sources = remote.fetch_sources(entry).unwrap();
+        assert_eq!(sources["button.rs"], "pub struct Button;\n");
+        assert_eq!(sources["README.md"], "# Button\n");
+
+        // Cached by checksum: a second fetch needs no network.
+        let offline = RemoteRegistry::with_transport(BASE_URL, &cache, MapTransport::default());
+        assert_eq!(offline.fetch_sources(entry).unwrap(), sources);
+
+        let mut tampered = MapTransport::default();
+        tampered.files.insert(
+            format!("{BASE_URL}archives/button-0.1.0.tar.gz"),
+            tar_gz(&[("button.rs", "malicious")]),
+        );
+        let remote = RemoteRegistry::with_transport(BASE_URL, temp_cache(), tampered);
+        assert!(matches!(
+            remote.fetch_sources(entry),
+            Err(RemoteError::ChecksumMismatch { .. })
+        ));
+
+        let tooltip = index.get("tooltip").unwrap();
+        assert_eq!(
+            remote.fetch_sources(tooltip),
+            Err(RemoteError::MissingArchive {
+                component: "Tooltip".into()
+            })
+        );
+        let _ = std::fs::remove_dir_all(&cache);
+    }
+
+    #[test]
+    fn unpack_rejects_paths_outside_component_dir() {
+        let unpack = |bytes: &[u8]| unpack_tar_gz(bytes, MAX_UNPACKED_BYTES);
+        assert!(unpack(&tar_gz(&[("src/ok.rs", "")])).is_ok());
+        assert!(unpack(&tar_gz(&[("../escape.rs", "")])).is_err());
+        assert!(unpack(&tar_gz(&[("/etc/passwd", "")])).is_err());
+        assert!(unpack(b"not a tarball").is_err());
+    }
+
+    #[test]
+    fn unpack_rejects_archives_over_the_size_cap() {
+        let content = "x".repeat(4 * TAR_BLOCK);
+        let archive = tar_gz(&[("src/big.rs", &content)]);
+        // Header, data, and the two end-of-archive blocks.
+        let unpacked = 7 * TAR_BLOCK as u64;
+
+        assert!(unpack_tar_gz(&archive, unpacked).is_ok());
+        let err = unpack_tar_gz(&archive, unpacked - 1).unwrap_err();
+        assert!(err.contains("more than"), "{err}");
+    }
+}
@@ -0,0 +1,313 @@
+//! JSON Schemas for the documents agents consume.
+//!
+//! `gpui schema <type>` prints these so agents can validate plans, indexes,
+//! and contracts before acting on them. Schemas are derived with schemars
+//! from the same serde definitions the CLI serializes, so they track every
+//! field rename and `skip_serializing_if`.
+//!
+//! [`validation_errors`] checks a value against a schema. It understands the
+//! keywords schemars emits, not all of JSON Schema.
+
+use components::ComponentContract;
+use schemars::{Schema, schema_for};
+use serde_json::Value;
+
+use crate::plan::PlanContract;
+use crate::{RegistryEntry, RegistryIndex};
+
+/// A document type with a published schema.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaType {
+    PlanContract,
+    RegistryIndex,
+    RegistryEntry,
+    ComponentContract,
+}
+
+impl SchemaType {
+    /// All schema types.
+    pub const ALL: [SchemaType; 4] = [
+        SchemaType::PlanContract,
+        SchemaType::RegistryIndex,
+        SchemaType::RegistryEntry,
+        SchemaType::ComponentContract,
+    ];
+
+    /// The type's name, as accepted by `gpui schema`.
+    pub fn name(self) -> &'static str {
+        match self {
+            SchemaType::PlanContract => "plan-contract",
+            SchemaType::RegistryIndex => "registry-index",
+            SchemaType::RegistryEntry => "registry-entry",
+            SchemaType::ComponentContract => "component-contract",
+        }
+    }
+
+    /// Look up a schema type by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|ty| ty.name() == name)
+    }
+
+    /// The type's JSON Schema.
+    pub fn schema(self) -> Schema {
+        match self {
+            SchemaType::PlanContract => schema_for!(PlanContract),
+            SchemaType::RegistryIndex => schema_for!(RegistryIndex),
+            SchemaType::RegistryEntry => schema_for!(RegistryEntry),
+            SchemaType::ComponentContract => schema_for!(ComponentContract),
+        }
+    }
+}
+
+impl std::str::FromStr for SchemaType {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|ty| ty.name()).collect();
+            format!(
+                "unknown schema type '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
+}
+
+/// Check `instance` against `schema`, returning every violation as
+/// `<JSON pointer>: <problem>`. An empty list means the instance is valid.
+pub fn validation_errors(schema: &Schema, instance: &Value) -> Vec<String> {
+    let root = schema.as_value();
+    let mut errors = Vec::new();
+    validate(root, root, instance, "", &mut errors);
+    errors
+}
+
+fn validate(root: &Value, schema: &Value, instance: &Value, at: &str, errors: &mut Vec<String>) {
+    let schema = match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => return errors.push(format!("{at}: no value is allowed here")),
+        Value::Object(schema) => schema,
+        _ => return errors.push(format!("{at}: malformed schema")),
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        match reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+        {
+            Some(target) => validate(root, target, instance, at, errors),
+            None => errors.push(format!("{at}: unresolved reference {reference}")),
+        }
+    }
+
+    if let Some(types) = schema.get("type") {
+        let allowed: Vec<&str> = match types {
+            Value::String(ty) => vec![ty.as_str()],
+            Value::Array(types) => types.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        if !allowed.iter().any(|ty| has_type(instance, ty)) {
+            errors.push(format!("{at}: expected {}", allowed.join(" or ")));
+            return;
+        }
+    }
+    if let Some(Value::Array(options)) = schema.get("enum")
+        && !options.contains(instance)
+    {
+        errors.push(format!(
+            "{at}: {instance} is not one of {}",
+            Value::from(options.clone())
+        ));
+    }
+    if let Some(expected) = schema.get("const")
+        && expected != instance
+    {
+        errors.push(format!("{at}: expected {expected}"));
+    }
+    if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64)
+        && instance.as_f64().is_some_and(|n| n < minimum)
+    {
+        errors.push(format!("{at}: below the minimum {minimum}"));
+    }
+    if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64)
+        && instance.as_f64().is_some_and(|n| n > maximum)
+    {
+        errors.push(format!("{at}: above the maximum {maximum}"));
+    }
+
+    for combinator in ["allOf", "anyOf", "oneOf"] {
+        let Some(Value::Array(branches)) = schema.get(combinator) else {
+            continue;
+        };
+        let passing = branches
+            .iter()
+            .filter(|branch| {
+                let mut branch_errors = Vec::new();
+                validate(root, branch, instance, at, &mut branch_errors);
+                branch_errors.is_empty()
+            })
+            .count();
+        let valid = match combinator {
+            "allOf" => passing == branches.len(),
+            "anyOf" => passing > 0,
+            _ => passing == 1,
+        };
+        if !valid {
+            errors.push(format!(
+                "{at}: {passing} of {} {combinator} branches match",
+                branches.len()
+            ));
+        }
+    }
+
+    if let Value::Object(fields) = instance {
+        validate_object(root, schema, fields, at, errors);
+    }
+    if let Value::Array(items) = instance {
+        validate_array(root, schema, items, at, errors);
+    }
+}
+
+fn validate_object(
+    root: &Value,
+    schema: &serde_json::Map<String, Value>,
+    fields: &serde_json::Map<String, Value>,
+    at: &str,
+    errors: &mut Vec<String>,
+) {
+    let properties = schema.get("properties").and_then(Value::as_object);
+    if let Some(Value::Array(required)) = schema.get("required") {
+        for name in required.iter().filter_map(Value::as_str) {
+            if !fields.contains_key(name) {
+                errors.push(format!("{at}: missing required property '{name}'"));
+            }
+        }
+    }
+    for (name, value) in fields {
+        let path = format!("{at}/{name}");
+        match properties.and_then(|properties| properties.get(name)) {
+            Some(property) => validate(root, property, value, &path, errors),
+            None => {
+                if let Some(additional) = schema.get("additionalProperties") {
+                    validate(root, additional, value, &path, errors);
+                }
+            }
+        }
+    }
+}
+
+fn validate_array(
+    root: &Value,
+    schema: &serde_json::Map<String, Value>,
+    items: &[Value],
+    at: &str,
+    errors: &mut Vec<String>,
+) {
+    let prefix = schema
+        .get("prefixItems")
+        .and_then(Value::as_array)
+        .map_or(&[][..], Vec::as_slice);
+    for (i, item) in items.iter().enumerate() {
+        let path = format!("{at}/{i}");
+        if let Some(item_schema) = prefix.get(i).or_else(|| schema.get("items")) {
+            validate(root, item_schema, item, &path, errors);
+        }
+    }
+    if let Some(min) = schema.get("minItems").and_then(Value::as_u64)
+        && (items.len() as u64) < min
+    {
+        errors.push(format!("{at}: fewer than {min} items"));
+    }
+    if let Some(max) = schema.get("maxItems").and_then(Value::as_u64)
+        && (items.len() as u64) > max
+    {
+        errors.push(format!("{at}: more than {max} items"));
+    }
+}
+
+fn has_type(instance: &Value, ty: &str) -> bool {
+    match ty {
+        "null" => instance.is_null(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        "integer" => instance.is_i64() || instance.is_u64(),
+        _ => false,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::plan::{DefaultLayout, generate_install_plan};
+
+    fn assert_valid(ty: SchemaType, value: &Value) {
+        let errors = validation_errors(&ty.schema(), value);
+        assert!(errors.is_empty(), "{}: {errors:#?}", ty.name());
+    }
+
+    #[test]
+    fn serialized_values_match_their_schemas() {
+        let index = generate_registry();
+        assert_valid(
+            SchemaType::RegistryIndex,
+            &serde_json::to_value(&index).unwrap(),
+        );
+        for entry in index.list() {
+            assert_valid(
+                SchemaType::RegistryEntry,
+                &serde_json::to_value(entry).unwrap(),
+            );
+        }
+        for contract in crate::all_contracts() {
+            assert_valid(
+                SchemaType::ComponentContract,
+                &serde_json::to_value(&contract).unwrap(),
+            );
+        }
+        let existing = [std::path::PathBuf::from("src/shared/ui/mod.rs")];
+        let plan = generate_install_plan(&index, "Select", &DefaultLayout::new("/app"), &existing)
+            .unwrap();
+        assert_valid(
+            SchemaType::PlanContract,
+            &serde_json::to_value(&plan).unwrap(),
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_reported() {
+        let entry = generate_registry().get("Dialog").unwrap().clone();
+        let mut value = serde_json::to_value(&entry).unwrap();
+        value["disposition"] = "borrowed".into();
+        value["props"][0]["required"] = "yes".into();
+        value.as_object_mut().unwrap().remove("name");
+
+        let errors = validation_errors(&SchemaType::RegistryEntry.schema(), &value);
+        assert!(
+            errors.iter().any(|e| e.starts_with("/disposition: ")),
+            "{errors:#?}"
+        );
+        assert!(errors.contains(&"/props/0/required: expected boolean".to_string()));
+        assert!(errors.contains(&": missing required property 'name'".to_string()));
+    }
+
+    #[test]
+    fn schema_types_parse_by_name() {
+        for ty in SchemaType::ALL {
+            assert_eq!(ty.name().parse::<SchemaType>(), Ok(ty));
+            assert_eq!(
+                ty.schema().get("title"),
+                Some(&Value::from(format!("{ty:?}")))
+            );
+        }
+        assert!("plan".parse::<SchemaType>().is_err());
+    }
+}
@@ -0,0 +1,280 @@
+//! Full-text search across registry metadata.
+//!
+//! [`RegistryIndex::search`] matches each query term, case-insensitively,
+//! against component names, variants, states, props (names and
+//! descriptions), and token dependencies (paths and usage notes). A
+//! component is a hit when every term matches somewhere in the searched
+//! fields; hits are ranked by where and how well the terms matched.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{RegistryEntry, RegistryIndex, state_label};
+
+/// A part of a registry entry that search looks at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    Name,
+    Variants,
+    States,
+    Props,
+    Tokens,
+}
+
+impl SearchField {
+    /// All fields, from strongest to weakest match.
+    pub const ALL: [SearchField; 5] = [
+        SearchField::Name,
+        SearchField::Variants,
+        SearchField::States,
+        SearchField::Props,
+        SearchField::Tokens,
+    ];
+
+    /// The field's name, as accepted by `gpui search --fields`.
+    pub fn name(self) -> &'static str {
+        match self {
+            SearchField::Name => "name",
+            SearchField::Variants => "variants",
+            SearchField::States => "states",
+            SearchField::Props => "props",
+            SearchField::Tokens => "tokens",
+        }
+    }
+
+    /// Look up a field by name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|field| field.name() == name)
+    }
+
+    /// How much a match in this field counts towards a hit's score.
+    fn weight(self) -> u32 {
+        match self {
+            SearchField::Name => 10,
+            SearchField::Variants | SearchField::Props => 4,
+            SearchField::States => 3,
+            SearchField::Tokens => 2,
+        }
+    }
+}
+
+impl std::str::FromStr for SearchField {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::from_name(name).ok_or_else(|| {
+            let names: Vec<&str> = Self::ALL.iter().map(|field| field.name()).collect();
+            format!(
+                "unknown search field '{}' (expected one of: {})",
+                name,
+                names.join(", ")
+            )
+        })
+    }
+}
+
+/// Where a query term matched.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchMatch {
+    pub field: SearchField,
+    /// The matching name, variant, state, prop name, or token path. Matches
+    /// on a description are reported under the prop or token it describes.
+    pub value: String,
+}
+
+/// A component matching a search query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub component: String,
+    pub version: String,
+    /// Relevance; higher is better.
+    pub score: u32,
+    /// Every match, in field order and without duplicates.
+    pub matches: Vec<SearchMatch>,
+}
+
+impl RegistryIndex {
+    /// Search entries for `query`, best hits first.
+    ///
+    /// Only `fields` are searched; an empty slice searches all of them.
+    /// Hits with equal scores are ordered by name.
+    pub fn search(&self, query: &str, fields: &[SearchField]) -> Vec<SearchHit> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let fields = if fields.is_empty() {
+            &SearchField::ALL[..]
+        } else {
+            fields
+        };
+
+        let mut hits: Vec<SearchHit> = self
+            .list()
+            .into_iter()
+            .filter_map(|entry| search_entry(entry, &terms, fields))
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.component.cmp(&b.component))
+        });
+        hits
+    }
+}
+
+/// Score one entry, or `None` if some term matches none of `fields`.
+fn search_entry(
+    entry: &RegistryEntry,
+    terms: &[String],
+    fields: &[SearchField],
+) -> Option<SearchHit> {
+    let candidates = candidates(entry, fields);
+    let mut score = 0;
+    let mut matches: Vec<SearchMatch> = Vec::new();
+
+    for term in terms {
+        let mut best = 0;
+        for (field, value, text, is_description) in &candidates {
+            let quality = match_quality(term, text, *is_description);
+            if quality == 0 {
+                continue;
+            }
+            best = best.max(field.weight() * quality);
+            let found = SearchMatch {
+                field: *field,
+                value: value.to_string(),
+            };
+            if !matches.contains(&found) {
+                matches.push(found);
+            }
+        }
+        if best == 0 {
+            return None;
+        }
+        score += best;
+    }
+
+    matches.sort_by_key(|m| m.field);
+    Some(SearchHit {
+        component: entry.name.clone(),
+        version: entry.version.clone(),
+        score,
+        matches,
+    })
+}
+
+/// Searchable texts of an entry: (field, reported value, text, is description).
+fn candidates<'a>(
+    entry: &'a RegistryEntry,
+    fields: &[SearchField],
+) -> Vec<(SearchField, &'a str, &'a str, bool)> {
+    let mut candidates = Vec::new();
+    for &field in fields {
+        match field {
+            SearchField::Name => candidates.push((field, &*entry.name, &*entry.name, false)),
+            SearchField::Variants => {
+                for variant in &entry.variants {
+                    candidates.push((field, &**variant, &**variant, false));
+                }
+            }
+            SearchField::States => {
+                for state in &entry.states {
+                    let label = state_label(state);
+                    candidates.push((field, label, label, false));
+                }
+            }
+            SearchField::Props => {
+                for prop in &entry.props {
+                    candidates.push((field, &*prop.name, &*prop.name, false));
+                    candidates.push((field, &*prop.name, &*prop.description, true));
+                }
+            }
+            SearchField::Tokens => {
+                for token in &entry.token_dependencies {
+                    candidates.push((field, &*token.path, &*token.path, false));
+                    candidates.push((field, &*token.path, &*token.usage, true));
+                }
+            }
+        }
+    }
+    candidates
+}
+
+/// 3 for an exact match, 2 for a prefix, 1 for a substring (or any match
+/// inside a description), 0 for none. `term` is already lowercase.
+fn match_quality(term: &str, text: &str, is_description: bool) -> u32 {
+    let text = text.to_lowercase();
+    if !text.contains(term) {
+        0
+    } else if is_description {
+        1
+    } else if text == term {
+        3
+    } else if text.starts_with(term) {
+        2
+    } else {
+        1
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+
+    fn names(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|hit| hit.component.as_str()).collect()
+    }
+
+    #[test]
+    fn ranks_name_matches_first() {
+        let hits = generate_registry().search("Select", &[]);
+        assert_eq!(hits[0].component, "Select");
+        assert!(hits.windows(2).all(|w| w[0].score >= w[1].score));
+        assert!(hits[0].matches.contains(&SearchMatch {
+            field: SearchField::Name,
+            value: "Select".to_string(),
+        }));
+    }
+
+    #[test]
+    fn every_term_must_match() {
+        let index = generate_registry();
+        let backdrop = index.search("overlay backdrop", &[]);
+        assert!(names(&backdrop).contains(&"Dialog"));
+        for hit in &backdrop {
+            assert!(
+                index
+                    .search("overlay", &[])
+                    .iter()
+                    .any(|h| h.component == hit.component)
+            );
+        }
+        assert!(index.search("overlay zzz-no-such-term", &[]).is_empty());
+        assert!(index.search("   ", &[]).is_empty());
+    }
+
+    #[test]
+    fn fields_restrict_the_search() {
+        let index = generate_registry();
+        let tokens = index.search("elevated_surface", &[SearchField::Tokens]);
+        assert!(names(&tokens).contains(&"Tooltip"));
+        assert!(
+            tokens
+                .iter()
+                .flat_map(|hit| &hit.matches)
+                .all(|m| m.field == SearchField::Tokens)
+        );
+        assert!(index.search("tooltip", &[SearchField::States]).is_empty());
+
+        let disabled = index.search("disabled", &[SearchField::States]);
+        assert!(names(&disabled).contains(&"Button"));
+        assert_eq!("props".parse::<SearchField>(), Ok(SearchField::Props));
+        assert!("colors".parse::<SearchField>().is_err());
+    }
+}
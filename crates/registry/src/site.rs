@@ -0,0 +1,466 @@
+//! Static HTML registry browser.
+//!
+//! `gpui docs site` renders the registry into a self-contained static site
+//! that can be published as-is (e.g. to GitHub Pages):
+//!
+//! - `index.html` -- every component with a client-side search over names,
+//!   variants, states, props, and token paths.
+//! - `components/<name>.html` -- the contract: prop table, variants, states,
+//!   dependencies, token dependencies with a swatch per theme, and any
+//!   captured story snapshots.
+//! - `themes.html` -- a swatch for every color token of every theme.
+//!
+//! Prop descriptions are written in the requested locale where the contract
+//! translates them, in English otherwise.
+//!
+//! Snapshots are image files named `<component>.png` or
+//! `<component>-<state>.png` (lowercase, e.g. `dialog-open.png`), copied
+//! under `snapshots/` by the caller.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{RegistryEntry, RegistryIndex, state_label};
+
+/// Directory, relative to the site root, holding story snapshots.
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
+/// Image extensions recognized as snapshots.
+pub const SNAPSHOT_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "svg", "webp"];
+
+const STYLE_CSS: &str = "\
+body { font: 15px/1.5 system-ui, sans-serif; margin: 0 auto; max-width: 960px; padding: 24px; color: #1f2328; }
+a { color: #0969da; text-decoration: none; }
+a:hover { text-decoration: underline; }
+header { display: flex; gap: 16px; align-items: baseline; border-bottom: 1px solid #d0d7de; margin-bottom: 24px; }
+code { font: 13px ui-monospace, monospace; background: #f6f8fa; padding: 1px 4px; border-radius: 4px; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 24px; }
+th, td { text-align: left; border-bottom: 1px solid #d0d7de; padding: 6px 8px; vertical-align: top; }
+#search { width: 100%; font-size: 16px; padding: 8px; margin-bottom: 16px; box-sizing: border-box; }
+.components { list-style: none; padding: 0; }
+.components li { padding: 8px 0; border-bottom: 1px solid #d0d7de; }
+.meta { color: #656d76; font-size: 13px; }
+.swatch { display: inline-block; width: 16px; height: 16px; border: 1px solid #d0d7de; border-radius: 3px; vertical-align: middle; margin-right: 4px; }
+.snapshots { display: flex; flex-wrap: wrap; gap: 16px; }
+.snapshots figure { margin: 0; }
+.snapshots img { max-width: 440px; border: 1px solid #d0d7de; }
+";
+
+const SEARCH_JS: &str = "\
+document.getElementById('search').addEventListener('input', function (event) {
+  var terms = event.target.value.toLowerCase().split(/\\s+/).filter(Boolean);
+  document.querySelectorAll('.components li').forEach(function (item) {
+    var text = item.dataset.search;
+    item.hidden = !terms.every(function (term) { return text.indexOf(term) !== -1; });
+  });
+});
+";
+
+/// A theme whose colors the site shows as swatches.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SiteTheme {
+    pub name: String,
+    /// The theme's tokens in their JSON form (`{ "text": { "muted": "#rrggbbaa" } }`).
+    pub tokens: serde_json::Value,
+}
+
+impl SiteTheme {
+    /// The hex color at a dot-separated token path, if the theme defines one.
+    fn color(&self, path: &str) -> Option<&str> {
+        path.split('.')
+            .try_fold(&self.tokens, |value, segment| value.get(segment))?
+            .as_str()
+            .filter(|value| value.starts_with('#'))
+    }
+}
+
+/// The built-in One Dark and One Light themes.
+#[cfg(feature = "gpui")]
+pub fn builtin_themes() -> Vec<SiteTheme> {
+    use theme::tokens::{one_dark, one_light};
+
+    [one_dark(), one_light()]
+        .into_iter()
+        .map(|tokens| SiteTheme {
+            name: tokens.name.clone(),
+            tokens: serde_json::to_value(tokens).unwrap_or_default(),
+        })
+        .collect()
+}
+
+/// A file of the generated site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SiteFile {
+    /// Path relative to the site root.
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+/// Render the site for every current entry of `index` in `locale` (a tag
+/// such as `"de-DE"`).
+///
+/// `snapshots` are file names under [`SNAPSHOTS_DIR`]; files that name no
+/// component are ignored.
+pub fn generate_site(
+    index: &RegistryIndex,
+    themes: &[SiteTheme],
+    snapshots: &[String],
+    locale: &str,
+) -> Vec<SiteFile> {
+    let entries = index.list();
+    let mut files = vec![
+        SiteFile {
+            path: PathBuf::from("index.html"),
+            contents: index_page(&entries, locale),
+        },
+        SiteFile {
+            path: PathBuf::from("themes.html"),
+            contents: themes_page(themes, locale),
+        },
+        SiteFile {
+            path: PathBuf::from("style.css"),
+            contents: STYLE_CSS.to_string(),
+        },
+        SiteFile {
+            path: PathBuf::from("search.js"),
+            contents: SEARCH_JS.to_string(),
+        },
+        // GitHub Pages would otherwise run the site through Jekyll.
+        SiteFile {
+            path: PathBuf::from(".nojekyll"),
+            contents: String::new(),
+        },
+    ];
+    for entry in entries {
+        files.push(SiteFile {
+            path: PathBuf::from(format!("components/{}.html", page_slug(&entry.name))),
+            contents: component_page(entry, themes, snapshots, locale),
+        });
+    }
+    files
+}
+
+/// The state a snapshot shows, if `file_name` is a snapshot of `component`:
+/// `Some(None)` for `<component>.png`, `Some(Some(state))` for
+/// `<component>-<state>.png`.
+pub fn snapshot_state<'a>(component: &str, file_name: &'a str) -> Option<Option<&'a str>> {
+    let (stem, extension) = file_name.rsplit_once('.')?;
+    if !SNAPSHOT_EXTENSIONS.contains(&extension.to_lowercase().as_str()) {
+        return None;
+    }
+    let rest = stem.strip_prefix(&page_slug(component))?;
+    match rest.strip_prefix('-') {
+        _ if rest.is_empty() => Some(None),
+        Some(state) if !state.is_empty() => Some(Some(state)),
+        _ => None,
+    }
+}
+
+fn page_slug(component: &str) -> String {
+    component.to_lowercase()
+}
+
+fn index_page(entries: &[&RegistryEntry], locale: &str) -> String {
+    let mut body = String::from(
+        "<input id=\"search\" type=\"search\" placeholder=\"Search components, props, states, tokens\" autofocus>\n\
+         <ul class=\"components\">\n",
+    );
+    for entry in entries {
+        let states: Vec<&str> = entry.states.iter().map(state_label).collect();
+        let search: Vec<&str> = std::iter::once(entry.name.as_str())
+            .chain(entry.variants.iter().map(String::as_str))
+            .chain(states.iter().copied())
+            .chain(entry.props.iter().map(|prop| prop.name.as_str()))
+            .chain(
+                entry
+                    .token_dependencies
+                    .iter()
+                    .map(|token| token.path.as_str()),
+            )
+            .collect();
+        let _ = writeln!(
+            body,
+            "<li data-search=\"{}\"><a href=\"components/{}.html\"><strong>{}</strong></a> \
+             <span class=\"meta\">v{} &middot; {} props &middot; {}</span></li>",
+            escape(&search.join(" ").to_lowercase()),
+            page_slug(&entry.name),
+            escape(&entry.name),
+            escape(&entry.version),
+            entry.props.len(),
+            escape(&states.join(", ")),
+        );
+    }
+    body.push_str("</ul>\n<script src=\"search.js\"></script>\n");
+    page("Components", "", locale, &body)
+}
+
+fn component_page(
+    entry: &RegistryEntry,
+    themes: &[SiteTheme],
+    snapshots: &[String],
+    locale: &str,
+) -> String {
+    let mut body = format!(
+        "<h1>{} <span class=\"meta\">v{} &middot; {:?}</span></h1>\n\
+         <p><code>gpui add {}</code></p>\n",
+        escape(&entry.name),
+        escape(&entry.version),
+        entry.disposition,
+        page_slug(&entry.name),
+    );
+
+    body.push_str("<h2>Props</h2>\n<table>\n<tr><th>Prop</th><th>Type</th><th>Required</th><th>Default</th><th>Description</th></tr>\n");
+    for prop in &entry.props {
+        let _ = writeln!(
+            body,
+            "<tr><td><code>{}</code></td><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape(&prop.name),
+            escape(&prop.type_name),
+            if prop.required { "yes" } else { "no" },
+            prop.default_value
+                .as_deref()
+                .map(|default| format!("<code>{}</code>", escape(default)))
+                .unwrap_or_default(),
+            escape(prop.localized_description(locale)),
+        );
+    }
+    body.push_str("</table>\n");
+
+    let states: Vec<&str> = entry.states.iter().map(state_label).collect();
+    for (heading, items) in [
+        (
+            "Variants",
+            entry.variants.iter().map(String::as_str).collect(),
+        ),
+        ("States", states),
+        (
+            "Dependencies",
+            entry
+                .dependencies
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>(),
+        ),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        let items: Vec<String> = items.iter().map(|item| escape(item)).collect();
+        let _ = writeln!(body, "<h2>{heading}</h2>\n<p>{}</p>", items.join(", "));
+    }
+
+    if !entry.token_dependencies.is_empty() {
+        body.push_str("<h2>Tokens</h2>\n<table>\n<tr><th>Token</th><th>Usage</th>");
+        for theme in themes {
+            let _ = write!(body, "<th>{}</th>", escape(&theme.name));
+        }
+        body.push_str("</tr>\n");
+        for token in &entry.token_dependencies {
+            let _ = write!(
+                body,
+                "<tr><td><code>{}</code></td><td>{}</td>",
+                escape(&token.path),
+                escape(&token.usage)
+            );
+            for theme in themes {
+                let _ = write!(body, "<td>{}</td>", swatch(theme.color(&token.path)));
+            }
+            body.push_str("</tr>\n");
+        }
+        body.push_str("</table>\n");
+    }
+
+    let mut shots: Vec<(Option<&str>, &String)> = snapshots
+        .iter()
+        .filter_map(|file| snapshot_state(&entry.name, file).map(|state| (state, file)))
+        .collect();
+    shots.sort();
+    if !shots.is_empty() {
+        body.push_str("<h2>Snapshots</h2>\n<div class=\"snapshots\">\n");
+        for (state, file) in shots {
+            let caption = state.unwrap_or("default");
+            let _ = writeln!(
+                body,
+                "<figure><img src=\"../{SNAPSHOTS_DIR}/{}\" alt=\"{} ({})\" loading=\"lazy\">\
+                 <figcaption>{}</figcaption></figure>",
+                escape(file),
+                escape(&entry.name),
+                escape(caption),
+                escape(caption),
+            );
+        }
+        body.push_str("</div>\n");
+    }
+
+    page(&entry.name, "../", locale, &body)
+}
+
+fn themes_page(themes: &[SiteTheme], locale: &str) -> String {
+    let mut body = String::from("<h1>Themes</h1>\n");
+    for theme in themes {
+        let _ = writeln!(body, "<h2>{}</h2>\n<table>", escape(&theme.name));
+        let mut colors = Vec::new();
+        color_leaves(&theme.tokens, String::new(), &mut colors);
+        for (path, color) in colors {
+            let _ = writeln!(
+                body,
+                "<tr><td><code>{}</code></td><td>{}</td></tr>",
+                escape(&path),
+                swatch(Some(color))
+            );
+        }
+        body.push_str("</table>\n");
+    }
+    page("Themes", "", locale, &body)
+}
+
+/// Collect every hex color under `value` with its dot-separated path.
+fn color_leaves<'a>(value: &'a serde_json::Value, path: String, out: &mut Vec<(String, &'a str)>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                color_leaves(field, path, out);
+            }
+        }
+        serde_json::Value::String(color) if color.starts_with('#') => out.push((path, color)),
+        _ => {}
+    }
+}
+
+fn swatch(color: Option<&str>) -> String {
+    match color {
+        Some(color) => format!(
+            "<span class=\"swatch\" style=\"background: {0}\"></span><code>{0}</code>",
+            escape(color)
+        ),
+        None => "<span class=\"meta\">undefined</span>".to_string(),
+    }
+}
+
+/// Wrap `body` in the shared page layout; `root` is the relative path to the site root.
+fn page(title: &str, root: &str, locale: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{} - GPUI Workbench</title>\n<link rel=\"stylesheet\" href=\"{root}style.css\">\n\
+         </head>\n<body>\n<header><a href=\"{root}index.html\"><strong>GPUI Workbench</strong></a>\
+         <a href=\"{root}index.html\">Components</a><a href=\"{root}themes.html\">Themes</a></header>\n\
+         {body}</body>\n</html>\n",
+        escape(locale),
+        escape(title)
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use std::path::Path;
+
+    fn theme() -> SiteTheme {
+        SiteTheme {
+            name: "Test <Dark>".to_string(),
+            tokens: serde_json::json!({
+                "name": "Test",
+                "text": { "muted": "#888888ff" },
+                "border": { "default": "#111111ff" },
+            }),
+        }
+    }
+
+    fn file<'a>(files: &'a [SiteFile], path: &str) -> &'a str {
+        &files
+            .iter()
+            .find(|file| file.path.as_path() == Path::new(path))
+            .unwrap_or_else(|| panic!("{path} not generated"))
+            .contents
+    }
+
+    #[test]
+    fn site_has_a_page_per_component() {
+        let index = generate_registry();
+        let files = generate_site(&index, &[theme()], &[], "en-US");
+
+        assert_eq!(files.len(), index.len() + 5);
+        let home = file(&files, "index.html");
+        for entry in index.list() {
+            let href = format!("components/{}.html", entry.name.to_lowercase());
+            assert!(home.contains(&href), "{href}");
+        }
+        assert!(home.contains("data-search=\"dialog "));
+        assert!(file(&files, "themes.html").contains("Test &lt;Dark&gt;"));
+        assert!(file(&files, "themes.html").contains("<code>text.muted</code>"));
+    }
+
+    #[test]
+    fn component_page_lists_props_tokens_and_snapshots() {
+        let snapshots = [
+            "dialog.png".to_string(),
+            "dialog-open.png".to_string(),
+            "dialogx.png".to_string(),
+            "select-open.png".to_string(),
+        ];
+        let files = generate_site(&generate_registry(), &[theme()], &snapshots, "en-US");
+        let dialog = file(&files, "components/dialog.html");
+
+        assert!(dialog.contains("<code>gpui add dialog</code>"));
+        assert!(dialog.contains("<td><code>overlay_closable</code></td><td><code>bool</code></td><td>no</td><td><code>true</code></td>"));
+        assert!(dialog.contains("src=\"../snapshots/dialog-open.png\""));
+        assert!(dialog.contains("<figcaption>default</figcaption>"));
+        assert!(!dialog.contains("dialogx.png") && !dialog.contains("select-open.png"));
+        assert!(dialog.contains("<code>border.default</code></td><td>Dialog panel border</td><td><span class=\"swatch\" style=\"background: #111111ff\">"));
+        assert!(dialog.contains("<code>surface.elevated_surface</code></td><td>Dialog panel background</td><td><span class=\"meta\">undefined</span>"));
+    }
+
+    #[test]
+    fn component_page_uses_translated_prop_descriptions() {
+        let mut index = RegistryIndex::new();
+        index.register(
+            &components::ComponentContract::builder("Gizmo", "0.1.0")
+                .required_prop("id", "ElementId", "Unique identifier")
+                .prop_translation("id", "de-DE", "Eindeutige Kennung")
+                .optional_prop("label", "SharedString", "\"\"", "Visible label")
+                .state(components::ComponentState::Active)
+                .build(),
+        );
+
+        let files = generate_site(&index, &[], &[], "de-DE");
+        let gizmo = file(&files, "components/gizmo.html");
+        assert!(gizmo.starts_with("<!DOCTYPE html>\n<html lang=\"de-DE\">"));
+        assert!(gizmo.contains("<td>Eindeutige Kennung</td>"));
+        assert!(gizmo.contains("<td>Visible label</td>"), "untranslated");
+
+        let files = generate_site(&index, &[], &[], "en-US");
+        assert!(file(&files, "components/gizmo.html").contains("<td>Unique identifier</td>"));
+    }
+
+    #[test]
+    fn snapshot_names_map_to_states() {
+        assert_eq!(snapshot_state("Dialog", "dialog.png"), Some(None));
+        assert_eq!(
+            snapshot_state("Dialog", "dialog-open.PNG"),
+            Some(Some("open"))
+        );
+        assert_eq!(snapshot_state("Dialog", "dialog-.png"), None);
+        assert_eq!(snapshot_state("Dialog", "dialog.txt"), None);
+        assert_eq!(snapshot_state("Dialog", "dialogue.png"), None);
+    }
+}
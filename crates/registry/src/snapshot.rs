@@ -0,0 +1,300 @@
+//! Story snapshot baselines per theme, for `gpui snapshot`.
+//!
+//! Captured story snapshots and their baselines are organized by theme: one
+//! directory per theme (named by [`theme_dir`], e.g. `one-dark/`) holding
+//! images named `<component>[-<state>].png` as for the docs site. Each
+//! theme's captures are compared with its baselines by content hash.
+//!
+//! A component whose snapshots are identical in every theme ignores the
+//! theme entirely, which usually means a hard-coded color slipped past the
+//! token checks; those components are flagged rather than failed.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::RegistryIndex;
+use crate::remote::sha256_hex;
+use crate::site::{SNAPSHOT_EXTENSIONS, snapshot_state};
+
+/// Default baseline directory, relative to the project root.
+pub const BASELINES_DIR: &str = "snapshots";
+
+/// Directory name for a theme's snapshots: lowercase, with runs of other
+/// characters as `-` (`"One Dark"` is `one-dark`).
+pub fn theme_dir(theme: &str) -> String {
+    let mut dir = String::new();
+    for c in theme.chars() {
+        if c.is_alphanumeric() {
+            dir.extend(c.to_lowercase());
+        } else if !dir.is_empty() && !dir.ends_with('-') {
+            dir.push('-');
+        }
+    }
+    dir.trim_end_matches('-').to_string()
+}
+
+/// One theme's snapshots: file name to content hash.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeSnapshots {
+    pub theme: String,
+    pub files: BTreeMap<String, String>,
+}
+
+impl ThemeSnapshots {
+    /// Snapshots from `(file name, contents)` pairs.
+    pub fn from_files<'a>(
+        theme: impl Into<String>,
+        files: impl IntoIterator<Item = (String, &'a [u8])>,
+    ) -> Self {
+        Self {
+            theme: theme.into(),
+            files: files
+                .into_iter()
+                .map(|(name, bytes)| (name, sha256_hex(bytes)))
+                .collect(),
+        }
+    }
+
+    /// Read the snapshot images directly in `dir`; other files are skipped.
+    /// A missing directory reads as no snapshots.
+    pub fn read_dir(theme: impl Into<String>, dir: &Path) -> std::io::Result<Self> {
+        let mut snapshots = Self {
+            theme: theme.into(),
+            files: BTreeMap::new(),
+        };
+        if !dir.is_dir() {
+            return Ok(snapshots);
+        }
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if path.is_file() && is_snapshot(name) {
+                let bytes = std::fs::read(&path)?;
+                snapshots.files.insert(name.to_string(), sha256_hex(&bytes));
+            }
+        }
+        Ok(snapshots)
+    }
+
+    /// Whether no snapshot was captured for the theme.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+/// How one theme's captured snapshots differ from its baselines.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeSnapshotDiff {
+    pub theme: String,
+    /// Directory name under the captured and baseline roots.
+    pub dir: String,
+    /// Number of captured snapshots.
+    pub captured: usize,
+    /// Captured snapshots with no baseline yet.
+    pub added: Vec<String>,
+    /// Baselines with no captured snapshot.
+    pub missing: Vec<String>,
+    /// Captured snapshots that differ from their baseline.
+    pub changed: Vec<String>,
+}
+
+impl ThemeSnapshotDiff {
+    /// Whether every baseline was captured unchanged.
+    pub fn passed(&self) -> bool {
+        self.missing.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare a theme's captured snapshots with its baselines. File lists are
+/// in name order.
+pub fn diff_theme(baseline: &ThemeSnapshots, captured: &ThemeSnapshots) -> ThemeSnapshotDiff {
+    let mut diff = ThemeSnapshotDiff {
+        theme: captured.theme.clone(),
+        dir: theme_dir(&captured.theme),
+        captured: captured.files.len(),
+        added: Vec::new(),
+        missing: Vec::new(),
+        changed: Vec::new(),
+    };
+    for (name, hash) in &captured.files {
+        match baseline.files.get(name) {
+            None => diff.added.push(name.clone()),
+            Some(expected) if expected != hash => diff.changed.push(name.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.missing = baseline
+        .files
+        .keys()
+        .filter(|name| !captured.files.contains_key(*name))
+        .cloned()
+        .collect();
+    diff
+}
+
+/// Components whose snapshots are identical in every theme, by registry
+/// name. A component is only judged when it was captured in each of at
+/// least two themes.
+pub fn theme_invariant_components(themes: &[ThemeSnapshots], index: &RegistryIndex) -> Vec<String> {
+    if themes.len() < 2 {
+        return Vec::new();
+    }
+    index
+        .list()
+        .into_iter()
+        .filter(|entry| {
+            let per_theme: Vec<BTreeMap<&str, &str>> = themes
+                .iter()
+                .map(|theme| {
+                    theme
+                        .files
+                        .iter()
+                        .filter(|(name, _)| snapshot_state(&entry.name, name).is_some())
+                        .map(|(name, hash)| (name.as_str(), hash.as_str()))
+                        .collect()
+                })
+                .collect();
+            !per_theme[0].is_empty() && per_theme.iter().all(|files| *files == per_theme[0])
+        })
+        .map(|entry| entry.name.clone())
+        .collect()
+}
+
+/// Result of `gpui snapshot`: one diff per theme, in the order checked.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotReport {
+    pub themes: Vec<ThemeSnapshotDiff>,
+    /// Themes with no captured snapshots.
+    pub uncaptured: Vec<String>,
+    /// Components rendered identically in every theme.
+    pub theme_invariant: Vec<String>,
+}
+
+impl SnapshotReport {
+    /// Compare every theme's captures with its baselines. `baselines` and
+    /// `captured` are matched by theme name; a theme with no baselines has
+    /// every capture added.
+    pub fn compare(
+        baselines: &[ThemeSnapshots],
+        captured: &[ThemeSnapshots],
+        index: &RegistryIndex,
+    ) -> Self {
+        let empty = ThemeSnapshots::default();
+        let themes = captured
+            .iter()
+            .filter(|theme| !theme.is_empty())
+            .map(|theme| {
+                let baseline = baselines
+                    .iter()
+                    .find(|baseline| baseline.theme == theme.theme)
+                    .unwrap_or(&empty);
+                diff_theme(baseline, theme)
+            })
+            .collect();
+        let uncaptured = captured
+            .iter()
+            .filter(|theme| theme.is_empty())
+            .map(|theme| theme.theme.clone())
+            .collect();
+        let captured: Vec<ThemeSnapshots> = captured
+            .iter()
+            .filter(|theme| !theme.is_empty())
+            .cloned()
+            .collect();
+        Self {
+            themes,
+            uncaptured,
+            theme_invariant: theme_invariant_components(&captured, index),
+        }
+    }
+
+    /// Whether every theme was captured and matches its baselines.
+    pub fn passed(&self) -> bool {
+        self.uncaptured.is_empty() && self.themes.iter().all(ThemeSnapshotDiff::passed)
+    }
+}
+
+fn is_snapshot(file_name: &str) -> bool {
+    file_name.rsplit_once('.').is_some_and(|(_, extension)| {
+        SNAPSHOT_EXTENSIONS.contains(&extension.to_lowercase().as_str())
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+
+    fn snapshots(theme: &str, files: &[(&str, &str)]) -> ThemeSnapshots {
+        ThemeSnapshots::from_files(
+            theme,
+            files
+                .iter()
+                .map(|(name, contents)| (name.to_string(), contents.as_bytes())),
+        )
+    }
+
+    #[test]
+    fn theme_dirs_are_slugs() {
+        assert_eq!(theme_dir("One Dark"), "one-dark");
+        assert_eq!(theme_dir("  Solarized (Light) "), "solarized-light");
+    }
+
+    #[test]
+    fn diff_reports_added_missing_and_changed() {
+        let baseline = snapshots(
+            "One Dark",
+            &[
+                ("button.png", "a"),
+                ("dialog-open.png", "b"),
+                ("tabs.png", "c"),
+            ],
+        );
+        let captured = snapshots(
+            "One Dark",
+            &[
+                ("button.png", "a"),
+                ("dialog-open.png", "B"),
+                ("select.png", "d"),
+            ],
+        );
+        let diff = diff_theme(&baseline, &captured);
+        assert_eq!(diff.dir, "one-dark");
+        assert_eq!(diff.captured, 3);
+        assert_eq!(diff.added, ["select.png"]);
+        assert_eq!(diff.missing, ["tabs.png"]);
+        assert_eq!(diff.changed, ["dialog-open.png"]);
+        assert!(!diff.passed());
+    }
+
+    #[test]
+    fn components_identical_in_every_theme_are_flagged() {
+        let index = generate_registry();
+        let captured = [
+            snapshots(
+                "One Dark",
+                &[
+                    ("button.png", "dark"),
+                    ("kbd.png", "same"),
+                    ("tabs.png", "x"),
+                ],
+            ),
+            snapshots("One Light", &[("button.png", "light"), ("kbd.png", "same")]),
+            snapshots("Nord", &[]),
+        ];
+        let report = SnapshotReport::compare(&[], &captured, &index);
+        assert_eq!(report.theme_invariant, ["Kbd"]);
+        assert_eq!(report.uncaptured, ["Nord"]);
+        assert_eq!(report.themes.len(), 2);
+        assert_eq!(report.themes[0].added.len(), 3);
+        assert!(!report.passed());
+    }
+}
@@ -0,0 +1,443 @@
+//! Embedded component sources and their rewriting for target layouts.
+//!
+//! Every file a contract lists in `required_files` is compiled into the
+//! registry with `include_str!`, so plans install the real component code
+//! rather than a stub. Installed code lives in the target app, not in the
+//! workbench, so its paths are rewritten on the way out:
+//!
+//! - `crate::<module>::` paths into another component's source point at
+//!   that component as installed by the layout (e.g. `crate::shared::ui::tooltip::`).
+//! - `theme::` paths point at the layout's theme module.
+//! - Remaining `crate::` paths (identifiers, inspect mode, contracts) point
+//!   at the workbench `components` crate, which installed code depends on.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::plan::TemplateAdapter;
+
+/// Component sources keyed by workspace-relative path, as listed in contracts.
+const SOURCES: &[(&str, &str)] = &[
+    (
+        "crates/components/src/avatar.rs",
+        include_str!("../../components/src/avatar.rs"),
+    ),
+    (
+        "crates/components/src/badge.rs",
+        include_str!("../../components/src/badge.rs"),
+    ),
+    (
+        "crates/components/src/breadcrumbs.rs",
+        include_str!("../../components/src/breadcrumbs.rs"),
+    ),
+    (
+        "crates/components/src/button.rs",
+        include_str!("../../components/src/button.rs"),
+    ),
+    (
+        "crates/components/src/chart.rs",
+        include_str!("../../components/src/chart.rs"),
+    ),
+    (
+        "crates/components/src/card.rs",
+        include_str!("../../components/src/card.rs"),
+    ),
+    (
+        "crates/components/src/checkbox.rs",
+        include_str!("../../components/src/checkbox.rs"),
+    ),
+    (
+        "crates/components/src/command_palette.rs",
+        include_str!("../../components/src/command_palette.rs"),
+    ),
+    (
+        "crates/components/src/context_menu.rs",
+        include_str!("../../components/src/context_menu.rs"),
+    ),
+    (
+        "crates/components/src/data_table.rs",
+        include_str!("../../components/src/data_table.rs"),
+    ),
+    (
+        "crates/components/src/description_list.rs",
+        include_str!("../../components/src/description_list.rs"),
+    ),
+    (
+        "crates/components/src/dialog.rs",
+        include_str!("../../components/src/dialog.rs"),
+    ),
+    (
+        "crates/components/src/dropdown_menu.rs",
+        include_str!("../../components/src/dropdown_menu.rs"),
+    ),
+    (
+        "crates/components/src/empty_state.rs",
+        include_str!("../../components/src/empty_state.rs"),
+    ),
+    (
+        "crates/components/src/file_picker.rs",
+        include_str!("../../components/src/file_picker.rs"),
+    ),
+    (
+        "crates/components/src/form.rs",
+        include_str!("../../components/src/form.rs"),
+    ),
+    (
+        "crates/components/src/input.rs",
+        include_str!("../../components/src/input.rs"),
+    ),
+    (
+        "crates/components/src/kbd.rs",
+        include_str!("../../components/src/kbd.rs"),
+    ),
+    (
+        "crates/components/src/markdown.rs",
+        include_str!("../../components/src/markdown.rs"),
+    ),
+    (
+        "crates/components/src/menu_bar.rs",
+        include_str!("../../components/src/menu_bar.rs"),
+    ),
+    (
+        "crates/components/src/notification_center.rs",
+        include_str!("../../components/src/notification_center.rs"),
+    ),
+    (
+        "crates/components/src/number_input.rs",
+        include_str!("../../components/src/number_input.rs"),
+    ),
+    (
+        "crates/components/src/password_input.rs",
+        include_str!("../../components/src/password_input.rs"),
+    ),
+    (
+        "crates/components/src/popover.rs",
+        include_str!("../../components/src/popover.rs"),
+    ),
+    (
+        "crates/components/src/progress.rs",
+        include_str!("../../components/src/progress.rs"),
+    ),
+    (
+        "crates/components/src/radio.rs",
+        include_str!("../../components/src/radio.rs"),
+    ),
+    (
+        "crates/components/src/scroll_area.rs",
+        include_str!("../../components/src/scroll_area.rs"),
+    ),
+    (
+        "crates/components/src/search_input.rs",
+        include_str!("../../components/src/search_input.rs"),
+    ),
+    (
+        "crates/components/src/select.rs",
+        include_str!("../../components/src/select.rs"),
+    ),
+    (
+        "crates/components/src/separator.rs",
+        include_str!("../../components/src/separator.rs"),
+    ),
+    (
+        "crates/components/src/skeleton.rs",
+        include_str!("../../components/src/skeleton.rs"),
+    ),
+    (
+        "crates/components/src/slider.rs",
+        include_str!("../../components/src/slider.rs"),
+    ),
+    (
+        "crates/components/src/sortable_list.rs",
+        include_str!("../../components/src/sortable_list.rs"),
+    ),
+    (
+        "crates/components/src/split_pane.rs",
+        include_str!("../../components/src/split_pane.rs"),
+    ),
+    (
+        "crates/components/src/status_bar.rs",
+        include_str!("../../components/src/status_bar.rs"),
+    ),
+    (
+        "crates/components/src/switch.rs",
+        include_str!("../../components/src/switch.rs"),
+    ),
+    (
+        "crates/components/src/tabs.rs",
+        include_str!("../../components/src/tabs.rs"),
+    ),
+    (
+        "crates/components/src/textarea.rs",
+        include_str!("../../components/src/textarea.rs"),
+    ),
+    (
+        "crates/components/src/title_bar.rs",
+        include_str!("../../components/src/title_bar.rs"),
+    ),
+    (
+        "crates/components/src/toast.rs",
+        include_str!("../../components/src/toast.rs"),
+    ),
+    (
+        "crates/components/src/tooltip.rs",
+        include_str!("../../components/src/tooltip.rs"),
+    ),
+    (
+        "crates/components/src/typography.rs",
+        include_str!("../../components/src/typography.rs"),
+    ),
+];
+
+/// Crates installed code may import without a workspace dependency entry.
+const BUILTIN_CRATES: &[&str] = &["std", "core", "alloc", "crate", "self", "super"];
+
+/// The embedded source of a required file, as written in the workbench.
+pub fn embedded_source(required_file: &str) -> Option<&'static str> {
+    SOURCES
+        .iter()
+        .find(|(path, _)| *path == required_file)
+        .map(|(_, source)| *source)
+}
+
+/// Rewrite a component's source for installation with `layout`.
+pub fn rewrite_source(source: &str, component_name: &str, layout: &dyn TemplateAdapter) -> String {
+    let owners = module_owners();
+    let theme_path = layout.theme_module_path();
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+
+    while let Some(offset) = next_path_root(rest) {
+        out.push_str(&rest[..offset]);
+        rest = &rest[offset..];
+
+        if let Some(tail) = rest.strip_prefix("theme::") {
+            out.push_str(&theme_path);
+            out.push_str("::");
+            rest = tail;
+        } else if let Some(tail) = rest.strip_prefix("crate::") {
+            let module: String = tail
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_')
+                .collect();
+            match owners.get(&module) {
+                Some(owner) if tail[module.len()..].starts_with("::") => {
+                    out.push_str(&layout.component_module_path(owner));
+                    rest = &tail[module.len()..];
+                }
+                _ => {
+                    out.push_str("components::");
+                    rest = tail;
+                }
+            }
+        }
+    }
+    out.push_str(rest);
+
+    // Components installed as separate crates call each other's helpers
+    // across crate boundaries.
+    if !layout
+        .component_module_path(component_name)
+        .starts_with("crate::")
+    {
+        out = out.replace("pub(crate) ", "pub ");
+    }
+    out
+}
+
+/// Point a component's installed source at its generated token defaults.
+///
+/// Each read of a token in `paths` (`theme.ghost_element.hover`, or through
+/// `cx.theme()`) becomes a call to the default method standing in for it
+/// (`theme.ghost_element_hover()`, see [`token_section_with_defaults`]), and
+/// `trait_path`, the trait providing those methods, is imported before the
+/// first `use`. Sources reading none of `paths` are returned unchanged.
+///
+/// [`token_section_with_defaults`]: crate::plan::token_section_with_defaults
+pub fn use_token_defaults(source: &str, trait_path: &str, paths: &[String]) -> String {
+    let mut out = source.to_string();
+    let mut replaced = false;
+    for path in paths {
+        let reads = token_reads(&out, path);
+        replaced |= !reads.is_empty();
+        let call = format!(".{}()", crate::plan::token_method(path));
+        for offset in reads.into_iter().rev() {
+            out.replace_range(offset..offset + path.len() + 1, &call);
+        }
+    }
+    if !replaced {
+        return out;
+    }
+
+    let import = format!("use {trait_path} as _;\n");
+    match out.find("\nuse ") {
+        Some(offset) => out.insert_str(offset + 1, &import),
+        None => out.insert_str(0, &import),
+    }
+    out
+}
+
+/// Whether `source` reads the token at `path` as a field, as in
+/// `theme.text.muted` or `cx.theme().text.muted`.
+pub fn reads_token(source: &str, path: &str) -> bool {
+    !token_reads(source, path).is_empty()
+}
+
+/// Offsets of the `.{path}` field reads of a token in `source`.
+fn token_reads(source: &str, path: &str) -> Vec<usize> {
+    let access = format!(".{path}");
+    let is_ident = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    source
+        .match_indices(&access)
+        .map(|(offset, _)| offset)
+        .filter(|&offset| {
+            source[..offset]
+                .chars()
+                .next_back()
+                .is_some_and(|c| is_ident(c) || c == ')')
+                && !source[offset + access.len()..].starts_with(|c: char| is_ident(c) || c == '(')
+        })
+        .collect()
+}
+
+/// Crates a source imports with `use`, other than the standard library.
+pub fn imported_crates(source: &str) -> Vec<String> {
+    let mut crates: Vec<String> = source
+        .lines()
+        .filter_map(|line| line.strip_prefix("use "))
+        .filter_map(|path| path.split("::").next())
+        .filter(|root| !BUILTIN_CRATES.contains(root))
+        .map(str::to_string)
+        .collect();
+    crates.sort();
+    crates.dedup();
+    crates
+}
+
+/// Offset of the next `crate::` or `theme::` path root in `text`.
+///
+/// A root must start a path: `cx.theme()` and `primitives::theme::` do not
+/// match.
+fn next_path_root(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    while let Some(found) = ["crate::", "theme::"]
+        .iter()
+        .filter_map(|root| text[start..].find(root))
+        .min()
+    {
+        let offset = start + found;
+        let preceded_by_path = offset > 0
+            && (bytes[offset - 1].is_ascii_alphanumeric()
+                || matches!(bytes[offset - 1], b'_' | b':' | b'.'));
+        if !preceded_by_path {
+            return Some(offset);
+        }
+        start = offset + 1;
+    }
+    None
+}
+
+/// Components keyed by the module name of their source file.
+///
+/// Where one file defines several components (e.g. `chart`), the first
+/// contract listed owns it.
+fn module_owners() -> BTreeMap<String, String> {
+    let mut owners = BTreeMap::new();
+    for contract in crate::all_contracts() {
+        for file in &contract.required_files {
+            if let Some(stem) = Path::new(file).file_stem() {
+                owners
+                    .entry(stem.to_string_lossy().into_owned())
+                    .or_insert_with(|| contract.name.clone());
+            }
+        }
+    }
+    owners
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::plan::{DefaultLayout, WorkspaceLayout};
+
+    #[test]
+    fn every_required_file_is_embedded() {
+        for entry in generate_registry().list() {
+            for file in &entry.required_files {
+                assert!(
+                    embedded_source(file).is_some(),
+                    "{} requires {file}, which is not embedded",
+                    entry.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rewrites_paths_for_the_layout() {
+        let source = "use crate::tooltip::{WithTooltip, attach_tooltip};\n\
+                      use crate::{Inspectable, identifiers::publish_identifiers};\n\
+                      use primitives::theme::Palette;\n\
+                      use theme::ActiveTheme;\n\
+                      pub(crate) fn contract() -> crate::ComponentContract {\n    \
+                      let theme = cx.theme();\n";
+
+        let default = rewrite_source(source, "Button", &DefaultLayout::new("/app"));
+        assert_eq!(
+            default,
+            "use crate::shared::ui::tooltip::{WithTooltip, attach_tooltip};\n\
+             use components::{Inspectable, identifiers::publish_identifiers};\n\
+             use primitives::theme::Palette;\n\
+             use crate::shared::theme::ActiveTheme;\n\
+             pub(crate) fn contract() -> components::ComponentContract {\n    \
+             let theme = cx.theme();\n"
+        );
+
+        let workspace = rewrite_source(source, "Button", &WorkspaceLayout::new("/app"));
+        assert!(workspace.starts_with("use ui_tooltip::{WithTooltip, attach_tooltip};\n"));
+        assert!(workspace.contains("use theme::ActiveTheme;\n"));
+        assert!(workspace.contains("pub fn contract()"));
+    }
+
+    #[test]
+    fn token_reads_become_default_calls() {
+        let source = "//! Docs.\n\nuse gpui::*;\n\n\
+                      let a = theme.text.muted;\n\
+                      let b = cx.theme().text.muted.opacity(0.5);\n\
+                      let c = theme.text.muted_more;\n\
+                      let d = text.muted;\n";
+        assert!(reads_token(source, "text.muted"));
+        assert!(!reads_token(source, "text.default"));
+
+        let paths = ["text.muted".to_string()];
+        let rewritten = use_token_defaults(source, "crate::theme::tokens::XTokenDefaults", &paths);
+        assert_eq!(
+            rewritten,
+            "//! Docs.\n\nuse crate::theme::tokens::XTokenDefaults as _;\nuse gpui::*;\n\n\
+             let a = theme.text_muted();\n\
+             let b = cx.theme().text_muted().opacity(0.5);\n\
+             let c = theme.text.muted_more;\n\
+             let d = text.muted;\n"
+        );
+        let untouched = ["text.default".to_string()];
+        assert_eq!(use_token_defaults(source, "x", &untouched), source);
+    }
+
+    #[test]
+    fn imported_crates_skip_std_and_local_paths() {
+        let source = embedded_source("crates/components/src/dialog.rs").unwrap();
+        assert_eq!(
+            imported_crates(&rewrite_source(
+                source,
+                "Dialog",
+                &WorkspaceLayout::new("/app")
+            )),
+            ["components", "gpui", "primitives", "smallvec", "theme"]
+        );
+    }
+}
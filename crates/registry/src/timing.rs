@@ -0,0 +1,215 @@
+//! Timing instrumentation, for `--timings`.
+//!
+//! Registry generation must stay within [`REGISTRY_BUDGET`] (NFR-010) as the
+//! component count grows. While a recording is running on the current
+//! thread, registry generation times each contract's validation and
+//! indexing, plan generation times each planned component, and the CLI adds
+//! the time it takes to serialize its output. [`finish`] returns the
+//! collected [`TimingReport`].
+//!
+//! Nothing is measured unless a recording was started, so the clock is never
+//! read on targets without one (wasm32).
+
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::time::{Duration, Instant};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Budget for generating the registry (NFR-010).
+pub const REGISTRY_BUDGET: Duration = Duration::from_secs(2);
+
+/// Time spent on one contract while generating the registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ContractTiming {
+    pub component: String,
+    /// Time to validate the contract, in microseconds.
+    pub validation_us: u64,
+    /// Time to index the contract as a registry entry, in microseconds.
+    pub index_us: u64,
+}
+
+impl ContractTiming {
+    /// Total time spent on the contract, in microseconds.
+    pub fn total_us(&self) -> u64 {
+        self.validation_us + self.index_us
+    }
+}
+
+/// Time spent generating one component's plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PlanTiming {
+    pub component: String,
+    /// Plan generation time, in microseconds.
+    pub generation_us: u64,
+}
+
+/// Where a command spent its time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TimingReport {
+    /// Time to generate the registry, in microseconds, if this command did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_us: Option<u64>,
+    /// Per-contract registry generation time, slowest first.
+    pub contracts: Vec<ContractTiming>,
+    /// Per-component plan generation time, in planning order.
+    pub plans: Vec<PlanTiming>,
+    /// Time to serialize the command's output, in microseconds.
+    pub serialization_us: u64,
+    /// Time from the start of the recording to [`finish`], in microseconds.
+    pub total_us: u64,
+    /// [`REGISTRY_BUDGET`] in milliseconds.
+    pub registry_budget_ms: u64,
+    /// Whether registry generation fit the budget.
+    pub within_budget: bool,
+}
+
+impl TimingReport {
+    /// The `n` slowest contracts.
+    pub fn slowest_contracts(&self, n: usize) -> &[ContractTiming] {
+        &self.contracts[..n.min(self.contracts.len())]
+    }
+}
+
+struct Recording {
+    started: Instant,
+    report: TimingReport,
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = const { RefCell::new(None) };
+}
+
+/// Start recording timings on this thread, discarding any earlier recording.
+pub fn start() {
+    RECORDING.with_borrow_mut(|recording| {
+        *recording = Some(Recording {
+            started: Instant::now(),
+            report: TimingReport::default(),
+        });
+    });
+}
+
+/// Whether a recording is running on this thread.
+pub fn is_recording() -> bool {
+    RECORDING.with_borrow(Option::is_some)
+}
+
+/// Stop recording and return the report, or `None` if no recording was
+/// running.
+pub fn finish() -> Option<TimingReport> {
+    let Recording {
+        started,
+        mut report,
+    } = RECORDING.take()?;
+    report.total_us = micros(started.elapsed());
+    report
+        .contracts
+        .sort_by_key(|contract| Reverse(contract.total_us()));
+    report.registry_budget_ms = REGISTRY_BUDGET.as_millis() as u64;
+    report.within_budget = report
+        .registry_us
+        .is_none_or(|us| us <= micros(REGISTRY_BUDGET));
+    Some(report)
+}
+
+/// Run `f`, timing it if a recording is running.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Duration>) {
+    if !is_recording() {
+        return (f(), None);
+    }
+    let started = Instant::now();
+    let value = f();
+    (value, Some(started.elapsed()))
+}
+
+/// Record registry generation time. Repeated generations add up.
+pub(crate) fn record_registry(elapsed: Duration) {
+    with_report(|report| {
+        *report.registry_us.get_or_insert(0) += micros(elapsed);
+    });
+}
+
+/// Record the time spent on one contract.
+pub(crate) fn record_contract(component: &str, validation: Duration, index: Duration) {
+    with_report(|report| {
+        report.contracts.push(ContractTiming {
+            component: component.to_string(),
+            validation_us: micros(validation),
+            index_us: micros(index),
+        });
+    });
+}
+
+/// Record one component's plan generation time.
+pub(crate) fn record_plan(component: &str, elapsed: Duration) {
+    with_report(|report| {
+        report.plans.push(PlanTiming {
+            component: component.to_string(),
+            generation_us: micros(elapsed),
+        });
+    });
+}
+
+/// Record output serialization time.
+pub fn record_serialization(elapsed: Duration) {
+    with_report(|report| report.serialization_us += micros(elapsed));
+}
+
+fn with_report(f: impl FnOnce(&mut TimingReport)) {
+    RECORDING.with_borrow_mut(|recording| {
+        if let Some(recording) = recording {
+            f(&mut recording.report);
+        }
+    });
+}
+
+fn micros(duration: Duration) -> u64 {
+    duration.as_micros().try_into().unwrap_or(u64::MAX)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::plan::{DefaultLayout, generate_plan};
+
+    #[test]
+    fn nothing_is_recorded_without_a_recording() {
+        assert!(!is_recording());
+        let (_, elapsed) = measure(generate_registry);
+        assert_eq!(elapsed, None);
+        assert_eq!(finish(), None);
+    }
+
+    #[test]
+    fn recording_times_contracts_plans_and_serialization() {
+        start();
+        let index = generate_registry();
+        let layout = DefaultLayout::new(std::path::Path::new("/project"));
+        generate_plan(index.get("dialog").unwrap(), &layout, &[]);
+        record_serialization(Duration::from_micros(250));
+        let report = finish().unwrap();
+        assert!(!is_recording());
+
+        assert_eq!(report.contracts.len(), index.len());
+        assert!(
+            report
+                .contracts
+                .windows(2)
+                .all(|pair| pair[0].total_us() >= pair[1].total_us())
+        );
+        assert!(report.registry_us.is_some());
+        let plans: Vec<&str> = report.plans.iter().map(|p| p.component.as_str()).collect();
+        assert_eq!(plans, ["Dialog"]);
+        assert_eq!(report.serialization_us, 250);
+        assert_eq!(report.registry_budget_ms, 2000);
+        assert!(report.within_budget);
+        assert!(report.slowest_contracts(100).len() <= report.contracts.len());
+    }
+}
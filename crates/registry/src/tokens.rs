@@ -0,0 +1,353 @@
+//! Theme token usage scanning for target projects.
+//!
+//! Builds the set of valid token paths from a project's theme tokens file
+//! (`pub struct ThemeTokens { pub text: TextTokens, .. }` and the group
+//! structs it references), then finds `theme.group.field` accesses in source
+//! files and reports the ones that are unknown or marked `#[deprecated]`.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::migration::SourceLocation;
+
+/// Name of the root token struct in a theme tokens file.
+const ROOT_STRUCT: &str = "ThemeTokens";
+
+/// A token field and its deprecation note, if any.
+type Fields = BTreeMap<String, Option<String>>;
+
+/// Parsed struct fields: name -> (type, deprecation note).
+type StructFields = BTreeMap<String, (String, Option<String>)>;
+
+/// The token paths a theme defines, parsed from its tokens source file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenSet {
+    /// Top-level fields that are not groups (e.g. `name`, `appearance`).
+    top_level: Fields,
+    /// Token groups and their fields.
+    groups: BTreeMap<String, Fields>,
+}
+
+/// Lookup result for a token path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Lookup {
+    Known,
+    Deprecated(Option<String>),
+    Unknown,
+}
+
+impl TokenSet {
+    /// Parse the token set from a Rust tokens file.
+    pub fn parse(tokens_rs: &str) -> Self {
+        let structs = parse_structs(tokens_rs);
+        let mut set = TokenSet::default();
+        let Some(root) = structs.get(ROOT_STRUCT) else {
+            return set;
+        };
+        for (field, (type_name, note)) in root {
+            match structs.get(type_name) {
+                Some(group) => {
+                    let fields = group
+                        .iter()
+                        .map(|(name, (_, note))| (name.clone(), note.clone()))
+                        .collect();
+                    set.groups.insert(field.clone(), fields);
+                }
+                None => {
+                    set.top_level.insert(field.clone(), note.clone());
+                }
+            }
+        }
+        set
+    }
+
+    /// Whether the set defines any tokens.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty() && self.top_level.is_empty()
+    }
+
+    /// Number of leaf token paths (`group.field`).
+    pub fn len(&self) -> usize {
+        self.groups.values().map(|fields| fields.len()).sum()
+    }
+
+    /// Whether a dot-separated token path (e.g. `text.muted`) is defined.
+    pub fn defines(&self, path: &str) -> bool {
+        let (group, field) = match path.split_once('.') {
+            Some((group, field)) => (group, Some(field)),
+            None => (path, None),
+        };
+        self.lookup(group, field) != Lookup::Unknown
+    }
+
+    /// Look up a one- or two-segment token path.
+    fn lookup(&self, group: &str, field: Option<&str>) -> Lookup {
+        let note = match (self.groups.get(group), field) {
+            (Some(fields), Some(field)) => fields.get(field),
+            (Some(_), None) => return Lookup::Known,
+            (None, None) => self.top_level.get(group),
+            // A field on a non-group top-level value is a method or struct access,
+            // not a token; only an entirely unknown group is reported.
+            (None, Some(_)) if self.top_level.contains_key(group) => return Lookup::Known,
+            (None, Some(_)) => None,
+        };
+        match note {
+            Some(None) => Lookup::Known,
+            Some(Some(note)) => Lookup::Deprecated(Some(note.clone()).filter(|n| !n.is_empty())),
+            None => Lookup::Unknown,
+        }
+    }
+}
+
+/// Why a token usage was reported.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenIssueKind {
+    /// The path is not defined by the theme.
+    Unknown,
+    /// The path is defined but marked `#[deprecated]`.
+    Deprecated,
+}
+
+/// A reported token usage.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenIssue {
+    /// Token path as written, e.g. `text.mutd`.
+    pub path: String,
+    /// Why it was reported.
+    pub kind: TokenIssueKind,
+    /// Deprecation note, if the theme gives one.
+    pub note: Option<String>,
+    /// Where the usage occurs.
+    pub location: SourceLocation,
+}
+
+/// Result of scanning a project for token usages.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenScanReport {
+    /// Number of token paths the theme defines.
+    pub defined_tokens: usize,
+    /// Number of token accesses found.
+    pub usages: usize,
+    /// Unknown and deprecated usages, in source order.
+    pub issues: Vec<TokenIssue>,
+}
+
+impl TokenScanReport {
+    /// Serialize the report to JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Scan `sources` (path, contents) for token accesses and check them against `tokens`.
+pub fn scan_token_usages(tokens: &TokenSet, sources: &[(PathBuf, String)]) -> TokenScanReport {
+    let mut usages = 0;
+    let mut issues = Vec::new();
+
+    for (file_path, content) in sources {
+        for (i, line) in content.lines().enumerate() {
+            let code = line.split("//").next().unwrap_or_default();
+            for (group, field) in token_accesses(code) {
+                usages += 1;
+                let (kind, note) = match tokens.lookup(group, field) {
+                    Lookup::Known => continue,
+                    Lookup::Deprecated(note) => (TokenIssueKind::Deprecated, note),
+                    Lookup::Unknown => (TokenIssueKind::Unknown, None),
+                };
+                issues.push(TokenIssue {
+                    path: field.map_or(group.to_string(), |f| format!("{group}.{f}")),
+                    kind,
+                    note,
+                    location: SourceLocation {
+                        file_path: file_path.clone(),
+                        line: i + 1,
+                        text: line.trim().to_string(),
+                    },
+                });
+            }
+        }
+    }
+
+    TokenScanReport {
+        defined_tokens: tokens.len(),
+        usages,
+        issues,
+    }
+}
+
+/// Find `theme.group[.field]` and `theme().group[.field]` accesses in a line.
+///
+/// Segments followed by `(` are method calls and end the path.
+fn token_accesses(code: &str) -> Vec<(&str, Option<&str>)> {
+    let mut accesses = Vec::new();
+    let mut search = 0;
+    while let Some(offset) = code[search..].find("theme") {
+        let start = search + offset;
+        search = start + "theme".len();
+
+        let preceded_by_ident = code[..start]
+            .chars()
+            .next_back()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+        if preceded_by_ident {
+            continue;
+        }
+        let rest = &code[search..];
+        let Some(rest) = rest.strip_prefix("().").or_else(|| rest.strip_prefix('.')) else {
+            continue;
+        };
+
+        let Some((group, rest)) = segment(rest) else {
+            continue;
+        };
+        let field = rest.strip_prefix('.').and_then(segment).map(|(f, _)| f);
+        accesses.push((group, field));
+    }
+    accesses
+}
+
+/// Split a leading identifier off `text`, unless it is a method call.
+fn segment(text: &str) -> Option<(&str, &str)> {
+    let len = text
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(text.len());
+    let (ident, rest) = text.split_at(len);
+    let is_ident = ident.starts_with(|c: char| c.is_alphabetic() || c == '_');
+    (is_ident && !rest.starts_with('(') && !rest.starts_with("::")).then_some((ident, rest))
+}
+
+/// Parse `pub struct Name { pub field: Type, .. }` definitions.
+///
+/// Returns struct name -> field name -> (type, deprecation note). A field
+/// marked `#[deprecated]` without a note maps to `Some("")`.
+fn parse_structs(source: &str) -> BTreeMap<String, StructFields> {
+    let mut structs = BTreeMap::new();
+    let mut current: Option<(String, StructFields)> = None;
+    let mut deprecated: Option<String> = None;
+
+    for line in source.lines().map(str::trim) {
+        if let Some((name, fields)) = current.as_mut() {
+            if line.starts_with('}') {
+                structs.insert(std::mem::take(name), std::mem::take(fields));
+                current = None;
+            } else if line.starts_with("#[deprecated") {
+                deprecated = Some(deprecation_note(line));
+            } else if let Some(field) = line.strip_prefix("pub ")
+                && let Some((field, type_name)) = field.split_once(':')
+            {
+                let type_name = type_name.trim().trim_end_matches(',').to_string();
+                fields.insert(field.trim().to_string(), (type_name, deprecated.take()));
+            }
+        } else if let Some(rest) = line.strip_prefix("pub struct ")
+            && rest.ends_with('{')
+        {
+            let name = rest.trim_end_matches('{').trim().to_string();
+            current = Some((name, BTreeMap::new()));
+            deprecated = None;
+        }
+    }
+    structs
+}
+
+/// Extract `note = "..."` from a `#[deprecated(..)]` attribute, or `""`.
+fn deprecation_note(attr: &str) -> String {
+    attr.split_once("note")
+        .and_then(|(_, rest)| rest.split('"').nth(1))
+        .unwrap_or_default()
+        .to_string()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKENS_RS: &str = r#"
+use gpui::Hsla;
+
+/// Text tokens.
+#[derive(Debug, Clone)]
+pub struct TextTokens {
+    pub default: Hsla,
+    pub muted: Hsla,
+    #[deprecated(note = "use text.muted")]
+    pub placeholder: Hsla,
+}
+
+pub struct BorderTokens {
+    pub default: Hsla,
+    #[deprecated]
+    pub variant: Hsla,
+}
+
+pub struct ThemeTokens {
+    pub name: String,
+    pub text: TextTokens,
+    pub border: BorderTokens,
+}
+"#;
+
+    fn scan(source: &str) -> TokenScanReport {
+        let tokens = TokenSet::parse(TOKENS_RS);
+        scan_token_usages(
+            &tokens,
+            &[(PathBuf::from("src/app.rs"), source.to_string())],
+        )
+    }
+
+    #[test]
+    fn parses_groups_from_root_struct() {
+        let tokens = TokenSet::parse(TOKENS_RS);
+        assert_eq!(tokens.len(), 5);
+        assert!(!tokens.is_empty());
+        assert!(tokens.defines("text.muted") && tokens.defines("border.variant"));
+        assert!(!tokens.defines("text.bogus") && !tokens.defines("overlay.backdrop"));
+        assert!(TokenSet::parse("pub struct Other {\n    pub a: u8,\n}").is_empty());
+    }
+
+    #[test]
+    fn known_tokens_are_not_reported() {
+        let report = scan(
+            "let theme = cx.theme();\n\
+             div().text_color(theme.text.muted).border_color(cx.theme().border.default);\n\
+             let name = theme.name.clone();\n\
+             let text = &theme.text;",
+        );
+        assert_eq!(report.usages, 4);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn unknown_and_deprecated_tokens_are_reported() {
+        let report = scan(
+            "let a = theme.text.mutd;\n\
+             let b = theme.txt.muted;\n\
+             let c = theme.text.placeholder; // theme.text.bogus in a comment\n\
+             let d = cx.theme().border.variant;\n\
+             let e = my_theme.text.bogus;",
+        );
+
+        let found: Vec<_> = report
+            .issues
+            .iter()
+            .map(|i| (i.path.as_str(), i.kind.clone(), i.location.line))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("text.mutd", TokenIssueKind::Unknown, 1),
+                ("txt.muted", TokenIssueKind::Unknown, 2),
+                ("text.placeholder", TokenIssueKind::Deprecated, 3),
+                ("border.variant", TokenIssueKind::Deprecated, 4),
+            ]
+        );
+        assert_eq!(report.issues[2].note.as_deref(), Some("use text.muted"));
+        assert_eq!(report.issues[3].note, None);
+    }
+}
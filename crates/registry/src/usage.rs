@@ -0,0 +1,243 @@
+//! Dependency surface of the installed components, for `gpui report`.
+//!
+//! Each installed component pulls in the design tokens its contract depends
+//! on and the behavior primitives that list it as a consumer. The report
+//! lists both per component, counts how many installed components share each
+//! token and primitive, and marks the ones only a single component uses: what
+//! removing that component would prune.
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::RegistryIndex;
+use crate::manifest::Manifest;
+
+/// What one installed component pulls in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComponentUsage {
+    pub component: String,
+    /// Token paths from the component's contract, sorted.
+    pub tokens: Vec<String>,
+    /// Primitives consumed by the component, sorted.
+    pub primitives: Vec<String>,
+    /// Tokens no other installed component uses.
+    pub exclusive_tokens: Vec<String>,
+    /// Primitives no other installed component uses.
+    pub exclusive_primitives: Vec<String>,
+}
+
+/// A token or primitive and the installed components that use it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyUsage {
+    pub name: String,
+    /// Installed components using it, sorted.
+    pub used_by: Vec<String>,
+}
+
+/// Token and primitive usage across the installed components.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageReport {
+    /// One entry per installed component known to the registry, by name.
+    pub components: Vec<ComponentUsage>,
+    /// Every token in use, most shared first.
+    pub tokens: Vec<DependencyUsage>,
+    /// Every primitive in use, most shared first.
+    pub primitives: Vec<DependencyUsage>,
+    /// Installed components the registry no longer has; not counted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<String>,
+}
+
+impl UsageReport {
+    /// Tokens used by more than one installed component.
+    pub fn shared_tokens(&self) -> usize {
+        self.tokens.iter().filter(|t| t.used_by.len() > 1).count()
+    }
+
+    /// Primitives used by more than one installed component.
+    pub fn shared_primitives(&self) -> usize {
+        self.primitives
+            .iter()
+            .filter(|p| p.used_by.len() > 1)
+            .count()
+    }
+}
+
+/// Usage report for the components recorded in `manifest`.
+///
+/// `primitive_consumers` maps each primitive's name to the components that
+/// consume it, as declared by its contract; names match case-insensitively.
+pub fn usage_report(
+    manifest: &Manifest,
+    index: &RegistryIndex,
+    primitive_consumers: &BTreeMap<String, Vec<String>>,
+) -> UsageReport {
+    let mut report = UsageReport::default();
+    let mut tokens: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut primitives: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for installed in manifest.installed() {
+        let Some(entry) = index.get(&installed.name) else {
+            report.unknown.push(installed.name.clone());
+            continue;
+        };
+        let mut component_tokens: Vec<String> = entry
+            .token_dependencies
+            .iter()
+            .map(|token| token.path.clone())
+            .collect();
+        component_tokens.sort();
+        component_tokens.dedup();
+        let component_primitives: Vec<String> = primitive_consumers
+            .iter()
+            .filter(|(_, consumers)| {
+                consumers
+                    .iter()
+                    .any(|consumer| consumer.eq_ignore_ascii_case(&entry.name))
+            })
+            .map(|(primitive, _)| primitive.clone())
+            .collect();
+
+        for token in &component_tokens {
+            tokens
+                .entry(token.clone())
+                .or_default()
+                .push(entry.name.clone());
+        }
+        for primitive in &component_primitives {
+            primitives
+                .entry(primitive.clone())
+                .or_default()
+                .push(entry.name.clone());
+        }
+        report.components.push(ComponentUsage {
+            component: entry.name.clone(),
+            tokens: component_tokens,
+            primitives: component_primitives,
+            exclusive_tokens: Vec::new(),
+            exclusive_primitives: Vec::new(),
+        });
+    }
+
+    report
+        .components
+        .sort_by(|a, b| a.component.cmp(&b.component));
+    for usage in &mut report.components {
+        usage.exclusive_tokens = exclusive(&usage.tokens, &tokens);
+        usage.exclusive_primitives = exclusive(&usage.primitives, &primitives);
+    }
+    report.tokens = ranked(tokens);
+    report.primitives = ranked(primitives);
+    report
+}
+
+/// The names in `used` that only one component uses.
+fn exclusive(used: &[String], usage: &BTreeMap<String, Vec<String>>) -> Vec<String> {
+    used.iter()
+        .filter(|name| usage.get(*name).is_some_and(|users| users.len() == 1))
+        .cloned()
+        .collect()
+}
+
+/// Usage entries, most shared first, then by name.
+fn ranked(usage: BTreeMap<String, Vec<String>>) -> Vec<DependencyUsage> {
+    let mut ranked: Vec<DependencyUsage> = usage
+        .into_iter()
+        .map(|(name, mut used_by)| {
+            used_by.sort();
+            DependencyUsage { name, used_by }
+        })
+        .collect();
+    ranked.sort_by_key(|usage| Reverse(usage.used_by.len()));
+    ranked
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+    use crate::manifest::ManifestEntry;
+
+    fn manifest(names: &[&str]) -> Manifest {
+        let mut manifest = Manifest::default();
+        for name in names {
+            manifest.components.insert(
+                name.to_lowercase(),
+                ManifestEntry {
+                    name: name.to_string(),
+                    version: "0.1.0".to_string(),
+                    installed_on: "2026-01-02".to_string(),
+                    checksums: BTreeMap::new(),
+                },
+            );
+        }
+        manifest
+    }
+
+    fn consumers() -> BTreeMap<String, Vec<String>> {
+        BTreeMap::from([
+            (
+                "FocusTrap".to_string(),
+                vec!["Dialog".to_string(), "Popover".to_string()],
+            ),
+            (
+                "Keyboard".to_string(),
+                vec!["Dialog".to_string(), "Tabs".to_string()],
+            ),
+        ])
+    }
+
+    #[test]
+    fn usage_counts_shared_and_exclusive_dependencies() {
+        let index = generate_registry();
+        let report = usage_report(&manifest(&["Tabs", "Dialog"]), &index, &consumers());
+
+        let names: Vec<&str> = report
+            .components
+            .iter()
+            .map(|c| c.component.as_str())
+            .collect();
+        assert_eq!(names, ["Dialog", "Tabs"]);
+
+        let dialog = &report.components[0];
+        assert_eq!(dialog.primitives, ["FocusTrap", "Keyboard"]);
+        assert_eq!(dialog.exclusive_primitives, ["FocusTrap"]);
+        let tabs = &report.components[1];
+        assert_eq!(tabs.primitives, ["Keyboard"]);
+        assert!(tabs.exclusive_primitives.is_empty());
+
+        assert_eq!(report.primitives[0].name, "Keyboard");
+        assert_eq!(report.primitives[0].used_by, ["Dialog", "Tabs"]);
+        assert_eq!(report.shared_primitives(), 1);
+
+        // Every token either is shared or belongs to exactly one component's
+        // exclusive list.
+        let exclusive: usize = report
+            .components
+            .iter()
+            .map(|c| c.exclusive_tokens.len())
+            .sum();
+        assert_eq!(report.shared_tokens() + exclusive, report.tokens.len());
+        assert!(
+            report
+                .tokens
+                .windows(2)
+                .all(|w| w[0].used_by.len() >= w[1].used_by.len())
+        );
+    }
+
+    #[test]
+    fn unknown_components_are_listed_not_counted() {
+        let index = generate_registry();
+        let report = usage_report(&manifest(&["Gizmo"]), &index, &consumers());
+        assert!(report.components.is_empty());
+        assert!(report.tokens.is_empty());
+        assert_eq!(report.unknown, ["Gizmo"]);
+    }
+}
@@ -0,0 +1,441 @@
+//! Theme verification across every registered theme.
+//!
+//! `gpui verify` checks the built-in themes and each `themes/*.json` file in
+//! a project for three things, reported per theme:
+//!
+//! - **Completeness** -- every token path in the theme mapping is defined.
+//! - **Contrast** -- text colors meet WCAG minimums against the
+//!   backgrounds they are drawn on.
+//! - **Coverage** -- every `token_dependencies` path declared by a registry
+//!   component resolves in the theme.
+//!
+//! When the project's `gpui.toml` has a `[policy]`, the report also lists
+//! registry contracts that break its sourcing rules. Contracts whose recorded
+//! `perf_evidence` exceeds their declared `perf_budget` are listed as well.
+//!
+//! Themes are checked in their JSON form, so tokens a file leaves out are
+//! reported as missing even though importing it fills them from a built-in
+//! theme.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use components::{BudgetOverrun, ComponentContract};
+use gpui::Hsla;
+use serde::{Deserialize, Serialize};
+use theme::engine::all_token_paths;
+use theme::tokens::{one_dark, one_light};
+use theme::{ThemeSeed, ThemeTokens, contrast_ratio, derive_theme};
+
+use crate::RegistryIndex;
+use crate::policy::{DispositionPolicy, PolicyViolation};
+
+/// Directory, relative to the project root, holding project themes.
+pub const THEMES_DIR: &str = "themes";
+
+/// Tokens a theme may leave unset; components fall back to another token.
+const OPTIONAL_TOKENS: &[&str] = &["panel.focused_border"];
+
+/// Foreground/background token pairs and their minimum contrast ratio.
+///
+/// Text needs 4.5:1 (WCAG AA). Accent and status colors are not checked:
+/// the frozen One Light values sit just under even the 3:1 large-text bar.
+pub(crate) const CONTRAST_PAIRS: &[(&str, &str, f32)] = &[
+    ("text.default", "surface.background", 4.5),
+    ("text.default", "panel.background", 4.5),
+    ("text.default", "element.background", 4.5),
+    ("text.muted", "surface.background", 4.5),
+    ("text.muted", "panel.background", 4.5),
+];
+
+/// A foreground/background pair below its minimum contrast.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContrastViolation {
+    pub foreground: String,
+    pub background: String,
+    /// Measured contrast ratio, rounded to two decimals.
+    pub ratio: f32,
+    pub minimum: f32,
+}
+
+/// A component token dependency the theme does not define.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UncoveredToken {
+    pub path: String,
+    /// Components that declare the dependency, ordered by name.
+    pub components: Vec<String>,
+}
+
+/// A component whose recorded performance exceeds its declared budget.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerfBudgetViolation {
+    pub component: String,
+    pub overruns: Vec<BudgetOverrun>,
+}
+
+/// Verification results for one theme.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThemeSection {
+    pub theme: String,
+    /// The theme file, or `None` for a built-in theme.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<PathBuf>,
+    /// Token paths the theme does not define.
+    pub missing_tokens: Vec<String>,
+    pub contrast_violations: Vec<ContrastViolation>,
+    pub uncovered_tokens: Vec<UncoveredToken>,
+}
+
+impl ThemeSection {
+    /// Whether the theme passed every check.
+    pub fn passed(&self) -> bool {
+        self.missing_tokens.is_empty()
+            && self.contrast_violations.is_empty()
+            && self.uncovered_tokens.is_empty()
+    }
+}
+
+/// Result of `gpui verify`: one section per theme.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerifyReport {
+    pub themes: Vec<ThemeSection>,
+    /// Contracts that break the project's disposition policy.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub policy_violations: Vec<PolicyViolation>,
+    /// Contracts whose perf evidence exceeds their perf budget.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub perf_budget_violations: Vec<PerfBudgetViolation>,
+}
+
+impl VerifyReport {
+    /// Whether every theme passed and no contract broke the policy or its
+    /// perf budget.
+    pub fn passed(&self) -> bool {
+        self.themes.iter().all(ThemeSection::passed)
+            && self.policy_violations.is_empty()
+            && self.perf_budget_violations.is_empty()
+    }
+}
+
+/// Verify a built-in or already-loaded theme.
+pub fn verify_theme(tokens: &ThemeTokens, index: &RegistryIndex) -> ThemeSection {
+    // Token structs always serialize; the fallback only guards the signature.
+    let json = serde_json::to_value(tokens).unwrap_or_default();
+    verify_json(&tokens.name, None, &json, index)
+}
+
+/// Verify a theme file: a full token set (possibly incomplete) or a seed.
+pub fn verify_theme_file(path: &Path, index: &RegistryIndex) -> anyhow::Result<ThemeSection> {
+    let (name, json) = load_theme_json(path)?;
+    Ok(verify_json(&name, Some(path.to_path_buf()), &json, index))
+}
+
+/// Verify the built-in themes followed by every `themes/*.json` file under
+/// `project_root`, in file name order.
+pub fn verify_themes(project_root: &Path, index: &RegistryIndex) -> anyhow::Result<VerifyReport> {
+    let mut themes: Vec<ThemeSection> = [one_dark(), one_light()]
+        .iter()
+        .map(|tokens| verify_theme(tokens, index))
+        .collect();
+    for path in project_theme_files(project_root)? {
+        themes.push(verify_theme_file(&path, index)?);
+    }
+    Ok(VerifyReport {
+        themes,
+        policy_violations: Vec::new(),
+        perf_budget_violations: Vec::new(),
+    })
+}
+
+/// Verify themes as [`verify_themes`] does, and check every registry
+/// contract against `policy` and its own perf budget.
+pub fn verify_project(
+    project_root: &Path,
+    index: &RegistryIndex,
+    policy: &DispositionPolicy,
+) -> anyhow::Result<VerifyReport> {
+    let mut report = verify_themes(project_root, index)?;
+    report.policy_violations = policy.check_registry();
+    report.perf_budget_violations = check_perf_budgets(&crate::all_contracts());
+    Ok(report)
+}
+
+/// The contracts among `contracts` whose perf evidence exceeds their perf
+/// budget, in the order given.
+pub fn check_perf_budgets(contracts: &[ComponentContract]) -> Vec<PerfBudgetViolation> {
+    contracts
+        .iter()
+        .filter_map(|contract| {
+            let overruns = contract.budget_overruns();
+            (!overruns.is_empty()).then(|| PerfBudgetViolation {
+                component: contract.name.clone(),
+                overruns,
+            })
+        })
+        .collect()
+}
+
+/// Names of the themes in `themes/*.json` under `project_root`, in file
+/// name order.
+pub fn project_theme_names(project_root: &Path) -> anyhow::Result<Vec<String>> {
+    project_theme_files(project_root)?
+        .iter()
+        .map(|path| load_theme_json(path).map(|(name, _)| name))
+        .collect()
+}
+
+/// The `themes/*.json` files under `project_root`, in file name order.
+pub(crate) fn project_theme_files(project_root: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let themes_dir = project_root.join(THEMES_DIR);
+    if !themes_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(&themes_dir)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", themes_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+/// A theme file's name and JSON token form: a full token set (possibly
+/// incomplete), or what a seed derives to.
+pub(crate) fn load_theme_json(path: &Path) -> anyhow::Result<(String, serde_json::Value)> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", path.display()))?;
+    let mut json: serde_json::Value = serde_json::from_str(&contents)
+        .map_err(|e| anyhow::anyhow!("Failed to parse {}: {e}", path.display()))?;
+
+    // A seed defines no tokens itself; check what it derives to.
+    if let Ok(seed) = serde_json::from_str::<ThemeSeed>(&contents) {
+        json = serde_json::to_value(derive_theme(&seed))?;
+    }
+
+    let name = match json.get("name").and_then(|name| name.as_str()) {
+        Some(name) => name.to_string(),
+        None => path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+    Ok((name, json))
+}
+
+fn verify_json(
+    name: &str,
+    source: Option<PathBuf>,
+    json: &serde_json::Value,
+    index: &RegistryIndex,
+) -> ThemeSection {
+    let defines = |path: &str| OPTIONAL_TOKENS.contains(&path) || color_at(json, path).is_some();
+
+    let missing_tokens = all_token_paths()
+        .into_iter()
+        .filter(|path| !defines(path))
+        .map(str::to_string)
+        .collect();
+
+    let contrast_violations = CONTRAST_PAIRS
+        .iter()
+        .filter_map(|&(foreground, background, minimum)| {
+            let ratio = contrast_ratio(color_at(json, foreground)?, color_at(json, background)?);
+            (ratio < minimum).then(|| ContrastViolation {
+                foreground: foreground.to_string(),
+                background: background.to_string(),
+                ratio: (ratio * 100.0).round() / 100.0,
+                minimum,
+            })
+        })
+        .collect();
+
+    let mut uncovered: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for entry in index.list() {
+        for dependency in &entry.token_dependencies {
+            if !defines(&dependency.path) {
+                let components = uncovered.entry(dependency.path.clone()).or_default();
+                if !components.contains(&entry.name) {
+                    components.push(entry.name.clone());
+                }
+            }
+        }
+    }
+    let uncovered_tokens = uncovered
+        .into_iter()
+        .map(|(path, mut components)| {
+            components.sort();
+            UncoveredToken { path, components }
+        })
+        .collect();
+
+    ThemeSection {
+        theme: name.to_string(),
+        source,
+        missing_tokens,
+        contrast_violations,
+        uncovered_tokens,
+    }
+}
+
+/// The color at a dot-separated token path in a theme's JSON form.
+pub(crate) fn color_at(json: &serde_json::Value, path: &str) -> Option<Hsla> {
+    let value = path
+        .split('.')
+        .try_fold(json, |value, segment| value.get(segment))?;
+    serde_json::from_value(value.clone()).ok()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate_registry;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("gpui-verify-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join(THEMES_DIR)).unwrap();
+        dir
+    }
+
+    #[test]
+    fn built_in_themes_pass() {
+        let index = generate_registry();
+        for tokens in [one_dark(), one_light()] {
+            let section = verify_theme(&tokens, &index);
+            assert_eq!(section.theme, tokens.name);
+            assert!(section.passed(), "{section:#?}");
+        }
+    }
+
+    #[test]
+    fn every_project_theme_gets_a_section() {
+        let dir = temp_dir("sections");
+        let index = generate_registry();
+
+        let mut low_contrast = serde_json::to_value(one_dark()).unwrap();
+        low_contrast["name"] = "Murky".into();
+        low_contrast["text"]["default"] = low_contrast["surface"]["background"].clone();
+        low_contrast["text"]
+            .as_object_mut()
+            .unwrap()
+            .remove("muted");
+        std::fs::write(
+            dir.join("themes/murky.json"),
+            serde_json::to_string(&low_contrast).unwrap(),
+        )
+        .unwrap();
+
+        let report = verify_themes(&dir, &index).unwrap();
+        let names: Vec<&str> = report.themes.iter().map(|s| s.theme.as_str()).collect();
+        assert_eq!(names, ["One Dark", "One Light", "Murky"]);
+        assert!(!report.passed());
+
+        let murky = &report.themes[2];
+        assert_eq!(murky.source, Some(dir.join("themes/murky.json")));
+        assert_eq!(murky.missing_tokens, ["text.muted"]);
+        assert!(
+            murky
+                .contrast_violations
+                .iter()
+                .any(|v| v.foreground == "text.default" && v.ratio == 1.0)
+        );
+        let muted = murky
+            .uncovered_tokens
+            .iter()
+            .find(|token| token.path == "text.muted")
+            .unwrap();
+        assert!(muted.components.contains(&"Button".to_string()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn policy_violations_fail_the_report() {
+        let dir = temp_dir("policy");
+        let index = generate_registry();
+        let policy: DispositionPolicy = toml::from_str(
+            r#"
+            [[rules]]
+            forbid_licenses = ["GPL"]
+            "#,
+        )
+        .unwrap();
+
+        let report = verify_project(&dir, &index, &policy).unwrap();
+        assert!(report.themes.iter().all(ThemeSection::passed));
+        assert!(!report.passed());
+        assert!(
+            report
+                .policy_violations
+                .iter()
+                .any(|v| v.component == "Button")
+        );
+        assert!(
+            verify_project(&dir, &index, &DispositionPolicy::default())
+                .unwrap()
+                .passed()
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn perf_budget_overruns_fail_the_report() {
+        use components::{ComponentState, PerfBudget, PerfEvidence};
+
+        let gauge = |interaction_latency_ms| {
+            ComponentContract::builder("Gauge", "1.0.0")
+                .required_prop("value", "f32", "Current value")
+                .state(ComponentState::Disabled)
+                .perf_evidence(PerfEvidence {
+                    render_time_ms: None,
+                    interaction_latency_ms: Some(interaction_latency_ms),
+                    notes: String::new(),
+                    measured_on: None,
+                    frame_budget: None,
+                })
+                .perf_budget(PerfBudget {
+                    max_render_ms: None,
+                    max_interaction_ms: Some(16.0),
+                })
+                .build()
+        };
+        assert!(check_perf_budgets(&[gauge(12.0)]).is_empty());
+        let violations = check_perf_budgets(&[gauge(12.0), gauge(24.0)]);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].component, "Gauge");
+        assert_eq!(violations[0].overruns[0].limit, "max_interaction_ms");
+
+        let mut report = VerifyReport {
+            themes: Vec::new(),
+            policy_violations: Vec::new(),
+            perf_budget_violations: violations,
+        };
+        assert!(!report.passed());
+        report.perf_budget_violations.clear();
+        assert!(report.passed());
+    }
+
+    #[test]
+    fn seed_themes_are_checked_as_derived() {
+        let dir = temp_dir("seed");
+        std::fs::write(
+            dir.join("themes/ocean.json"),
+            r##"{"name":"Ocean","appearance":"dark","background":"#0b1622ff","accent":"#4fb3ffff"}"##,
+        )
+        .unwrap();
+
+        let section =
+            verify_theme_file(&dir.join("themes/ocean.json"), &generate_registry()).unwrap();
+        assert_eq!(section.theme, "Ocean");
+        assert!(section.missing_tokens.is_empty());
+        assert!(section.uncovered_tokens.is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,246 @@
+//! Component versions and version requirements.
+//!
+//! Versions are plain `major.minor.patch` (no pre-release or build tags).
+//! Requirements follow Cargo's syntax -- `^0.1`, `~0.1.2`, `>=0.1, <0.3`,
+//! `*` -- except that a bare version is exact: `0.2.0` means `=0.2.0`, as in
+//! `gpui add dialog@0.2.0`, while `0.2` matches any `0.2.x`.
+
+use serde::{Deserialize, Serialize};
+
+/// A `major.minor.patch` version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl Version {
+    /// Parse a `major.minor.patch` version. Missing parts default to 0.
+    pub fn parse(version: &str) -> Option<Self> {
+        let mut parts = version.trim().split('.').map(|p| p.parse::<u64>());
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+        let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// How a comparator relates a version to its operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Exact,
+    Greater,
+    GreaterEq,
+    Less,
+    LessEq,
+    Tilde,
+    Caret,
+}
+
+/// One comparator of a requirement; `None` parts were omitted or `*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Comparator {
+    op: Op,
+    major: u64,
+    minor: Option<u64>,
+    patch: Option<u64>,
+}
+
+impl Comparator {
+    fn matches(&self, version: &Version) -> bool {
+        let floor = Version {
+            major: self.major,
+            minor: self.minor.unwrap_or(0),
+            patch: self.patch.unwrap_or(0),
+        };
+        let same_major = version.major == self.major;
+        let same_minor = same_major && self.minor.is_none_or(|minor| version.minor == minor);
+        let same = same_minor && self.patch.is_none_or(|patch| version.patch == patch);
+        match self.op {
+            Op::Exact => same,
+            Op::Greater => version > &floor && !same,
+            Op::GreaterEq => version >= &floor,
+            Op::Less => version < &floor,
+            Op::LessEq => version <= &floor || same,
+            Op::Tilde => version >= &floor && same_minor,
+            Op::Caret => {
+                version >= &floor
+                    && match (self.major, self.minor, self.patch) {
+                        (0, Some(0), Some(_)) => same,
+                        (0, Some(_), _) => same_minor,
+                        _ => same_major,
+                    }
+            }
+        }
+    }
+}
+
+/// A version requirement: comma-separated comparators that must all match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    text: String,
+    comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// Parse a requirement such as `^0.1`, `>=0.1, <0.3`, `0.2.0`, or `*`.
+    pub fn parse(requirement: &str) -> Result<Self, InvalidVersionReq> {
+        let invalid = || InvalidVersionReq(requirement.to_string());
+        let mut comparators = Vec::new();
+        for part in requirement.split(',') {
+            let part = part.trim();
+            if part == "*" {
+                continue;
+            }
+            let (op, rest) = [
+                (">=", Op::GreaterEq),
+                ("<=", Op::LessEq),
+                (">", Op::Greater),
+                ("<", Op::Less),
+                ("=", Op::Exact),
+                ("^", Op::Caret),
+                ("~", Op::Tilde),
+            ]
+            .into_iter()
+            .find_map(|(prefix, op)| part.strip_prefix(prefix).map(|rest| (op, rest)))
+            .unwrap_or((Op::Exact, part));
+
+            let mut parts = rest.trim().split('.');
+            let major = parts
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or_else(invalid)?;
+            let mut optional = || match parts.next() {
+                None | Some("*" | "x") => Ok(None),
+                Some(p) => p.parse().map(Some).map_err(|_| invalid()),
+            };
+            let minor = optional()?;
+            let patch = if minor.is_some() { optional()? } else { None };
+            if parts.next().is_some() {
+                return Err(invalid());
+            }
+            comparators.push(Comparator {
+                op,
+                major,
+                minor,
+                patch,
+            });
+        }
+        Ok(Self {
+            text: requirement.trim().to_string(),
+            comparators,
+        })
+    }
+
+    /// Whether `version` satisfies every comparator.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+}
+
+impl std::fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// A version requirement that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidVersionReq(pub String);
+
+impl std::fmt::Display for InvalidVersionReq {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "invalid version requirement '{}' (expected e.g. 0.2.0, ^0.1, or >=0.1, <0.3)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for InvalidVersionReq {}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(requirement: &str, version: &str) -> bool {
+        VersionReq::parse(requirement)
+            .unwrap()
+            .matches(&Version::parse(version).unwrap())
+    }
+
+    #[test]
+    fn versions_parse_and_order() {
+        let v = Version::parse("0.10.2").unwrap();
+        assert_eq!((v.major, v.minor, v.patch), (0, 10, 2));
+        assert_eq!(v.to_string(), "0.10.2");
+        assert_eq!(Version::parse("1"), Version::parse("1.0.0"));
+        assert!(Version::parse("0.9.9") < Version::parse("0.10.0"));
+        assert_eq!(Version::parse("1.x"), None);
+        assert_eq!(Version::parse("1.2.3.4"), None);
+    }
+
+    #[test]
+    fn bare_versions_are_exact() {
+        assert!(matches("0.2.0", "0.2.0"));
+        assert!(!matches("0.2.0", "0.2.1"));
+        assert!(matches("0.2", "0.2.7"));
+        assert!(!matches("=0.2", "0.3.0"));
+        assert!(matches("*", "3.1.4"));
+        assert!(matches("1.*", "1.9.0"));
+    }
+
+    #[test]
+    fn caret_and_tilde_follow_cargo() {
+        assert!(matches("^0.1", "0.1.9"));
+        assert!(!matches("^0.1", "0.2.0"));
+        assert!(matches("^0.1.2", "0.1.3"));
+        assert!(!matches("^0.1.2", "0.1.1"));
+        assert!(matches("^1.2", "1.9.0"));
+        assert!(!matches("^1.2", "2.0.0"));
+        assert!(!matches("^0.0.3", "0.0.4"));
+        assert!(matches("~1.2.3", "1.2.9"));
+        assert!(!matches("~1.2.3", "1.3.0"));
+        assert!(matches("~1", "1.8.0"));
+    }
+
+    #[test]
+    fn comparators_combine() {
+        assert!(matches(">=0.1, <0.3", "0.2.5"));
+        assert!(!matches(">=0.1, <0.3", "0.3.0"));
+        assert!(matches(">0.1", "0.2.0"));
+        assert!(!matches(">0.1", "0.1.5"));
+        assert!(matches("<=0.2", "0.2.9"));
+        assert!(!matches("<=0.2", "0.3.0"));
+    }
+
+    #[test]
+    fn invalid_requirements_are_rejected() {
+        for requirement in ["", "latest", "^", ">=0.1,", "1.2.3.4", "0.a"] {
+            assert_eq!(
+                VersionReq::parse(requirement),
+                Err(InvalidVersionReq(requirement.to_string())),
+                "{requirement}"
+            );
+        }
+    }
+}
@@ -16,14 +16,22 @@
 pub mod matrix;
 pub mod stories;
 
-use components::ComponentContract;
+use components::{ComponentContract, Markdown};
 use gpui::*;
+use primitives::PrimitiveContract;
 
 // Re-export for convenience.
 pub use matrix::StateMatrix;
 pub use stories::{
-    ButtonStory, CheckboxStory, DialogStory, DropdownMenuStory, InputStory, PopoverStory,
-    RadioStory, SelectStory, TabsStory, TextareaStory, ToastStory, TooltipStory,
+    AvatarGroupStory, AvatarStory, BadgeStory, BarChartStory, BreadcrumbsStory, ButtonStory,
+    CardStory, CheckboxStory, CommandPaletteStory, ContextMenuStory, DataTableStory,
+    DescriptionListStory, DialogStory, DropdownMenuStory, EmptyStateStory, FilePickerStory,
+    FormStory, InputStory, KbdStory, LabelStory, LinkStory, MarkdownStory, MenuBarStory,
+    NotificationCenterStory, NumberInputStory, PasswordInputStory, PopoverStory, ProgressBarStory,
+    RadioStory, ScrollAreaStory, SearchInputStory, SelectStory, SeparatorStory, SkeletonStory,
+    SliderStory, SortableListStory, SparklineStory, SpinnerStory, SplitPaneStory, StatusBarStory,
+    SwitchStory, TabsStory, TagStory, TextStory, TextareaStory, TitleBarStory, ToastStory,
+    TooltipStory,
 };
 
 // ---------------------------------------------------------------------------
@@ -39,11 +47,23 @@ pub trait Story {
     /// Human-readable name shown in the workbench sidebar.
     fn name(&self) -> &'static str;
 
-    /// Optional description shown below the name.
+    /// Optional description shown below the name. Rendered as Markdown.
     fn description(&self) -> &'static str {
         ""
     }
 
+    /// Translations of the description as `(locale tag, Markdown)` pairs,
+    /// e.g. `("de-DE", "...")`. Locales without one show the description.
+    fn description_translations(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+
+    /// Optional review notes (Markdown) shown in the workbench notes panel:
+    /// design decisions, known gaps, and what reviewers should check.
+    fn notes(&self) -> &'static str {
+        ""
+    }
+
     /// The component contract for the component this story exercises.
     /// Used to generate state matrix columns and validate coverage.
     fn contract(&self) -> ComponentContract;
@@ -89,6 +109,20 @@ impl StoryEntry {
         self.story.description()
     }
 
+    /// The story description in `locale`, falling back to the original.
+    pub fn localized_description(&self, locale: &str) -> &'static str {
+        components::match_locale(
+            self.story.description_translations().iter().copied(),
+            locale,
+        )
+        .unwrap_or_else(|| self.story.description())
+    }
+
+    /// The story review notes (convenience delegate).
+    pub fn notes(&self) -> &'static str {
+        self.story.notes()
+    }
+
     /// The component contract (convenience delegate).
     pub fn contract(&self) -> ComponentContract {
         self.story.contract()
@@ -100,6 +134,46 @@ impl StoryEntry {
     }
 }
 
+// ---------------------------------------------------------------------------
+// PrimitiveDoc (doc stories for primitives)
+// ---------------------------------------------------------------------------
+
+/// A doc story for a behavior primitive, rendered from its [`PrimitiveContract`].
+///
+/// Primitives have no variants or visual states, so instead of a state matrix
+/// the story documents the primitive's functions, invariants, and consumers.
+pub struct PrimitiveDoc {
+    contract: PrimitiveContract,
+}
+
+impl PrimitiveDoc {
+    /// Create a doc story for `contract`.
+    pub fn new(contract: PrimitiveContract) -> Self {
+        Self { contract }
+    }
+
+    /// The primitive name shown in the workbench sidebar.
+    pub fn name(&self) -> &str {
+        &self.contract.name
+    }
+
+    /// The one-line summary shown below the name.
+    pub fn summary(&self) -> &str {
+        &self.contract.summary
+    }
+
+    /// The primitive contract this story documents.
+    pub fn contract(&self) -> &PrimitiveContract {
+        &self.contract
+    }
+
+    /// Render the contract as Markdown documentation.
+    pub fn render_doc(&self) -> AnyElement {
+        let id = SharedString::from(format!("primitive-doc-{}", self.contract.name));
+        Markdown::new(id, self.contract.to_markdown()).into_any_element()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // StoryRegistry
 // ---------------------------------------------------------------------------
@@ -110,6 +184,7 @@ impl StoryEntry {
 /// reads the registry to populate its sidebar and render story content.
 pub struct StoryRegistry {
     entries: Vec<StoryEntry>,
+    primitive_docs: Vec<PrimitiveDoc>,
 }
 
 impl Global for StoryRegistry {}
@@ -119,6 +194,7 @@ impl StoryRegistry {
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
+            primitive_docs: Vec::new(),
         }
     }
 
@@ -151,6 +227,22 @@ impl StoryRegistry {
     pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
         self.entries.iter().map(|e| e.name())
     }
+
+    /// Register a doc story for a primitive. Primitive docs are listed apart
+    /// from component stories and do not count towards [`len`](Self::len).
+    pub fn register_primitive(&mut self, contract: PrimitiveContract) {
+        self.primitive_docs.push(PrimitiveDoc::new(contract));
+    }
+
+    /// Returns a slice of all registered primitive doc stories.
+    pub fn primitive_docs(&self) -> &[PrimitiveDoc] {
+        &self.primitive_docs
+    }
+
+    /// Look up a primitive doc story by name. Returns None if not found.
+    pub fn get_primitive_doc(&self, name: &str) -> Option<&PrimitiveDoc> {
+        self.primitive_docs.iter().find(|doc| doc.name() == name)
+    }
 }
 
 impl Default for StoryRegistry {
@@ -173,19 +265,60 @@ pub fn init(cx: &mut App) {
     let mut registry = StoryRegistry::new();
 
     // Register all built-in stories (alphabetical order).
+    registry.register(AvatarStory);
+    registry.register(AvatarGroupStory);
+    registry.register(BadgeStory);
+    registry.register(BarChartStory);
+    registry.register(BreadcrumbsStory);
     registry.register(ButtonStory);
+    registry.register(CardStory);
     registry.register(CheckboxStory);
+    registry.register(CommandPaletteStory);
+    registry.register(ContextMenuStory);
+    registry.register(DataTableStory);
+    registry.register(DescriptionListStory);
     registry.register(DialogStory);
     registry.register(DropdownMenuStory);
+    registry.register(EmptyStateStory);
+    registry.register(FilePickerStory);
+    registry.register(FormStory);
     registry.register(InputStory);
+    registry.register(KbdStory);
+    registry.register(LabelStory);
+    registry.register(LinkStory);
+    registry.register(MarkdownStory);
+    registry.register(MenuBarStory);
+    registry.register(NotificationCenterStory);
+    registry.register(NumberInputStory);
+    registry.register(PasswordInputStory);
     registry.register(PopoverStory);
+    registry.register(ProgressBarStory);
     registry.register(RadioStory);
+    registry.register(ScrollAreaStory);
+    registry.register(SearchInputStory);
     registry.register(SelectStory);
+    registry.register(SeparatorStory);
+    registry.register(SkeletonStory);
+    registry.register(SliderStory);
+    registry.register(SortableListStory);
+    registry.register(SparklineStory);
+    registry.register(SpinnerStory);
+    registry.register(SplitPaneStory);
+    registry.register(StatusBarStory);
+    registry.register(SwitchStory);
     registry.register(TabsStory);
+    registry.register(TagStory);
+    registry.register(TextStory);
     registry.register(TextareaStory);
+    registry.register(TitleBarStory);
     registry.register(ToastStory);
     registry.register(TooltipStory);
 
+    // Primitive doc stories, listed in their own sidebar group.
+    for contract in primitives::contract::all_contracts() {
+        registry.register_primitive(contract);
+    }
+
     cx.set_global(registry);
 }
 
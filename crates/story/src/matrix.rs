@@ -41,6 +41,13 @@ impl StateMatrix {
         }
     }
 
+    /// Override the name in the matrix header, e.g. for a second matrix of
+    /// the same component in another mode.
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
     /// The component states from the contract.
     pub fn states(&self) -> &[ComponentState] {
         &self.states
@@ -7,28 +7,100 @@
 //!
 //! Stories render components in isolation — no inter-component dependencies.
 
+mod avatar_group_story;
+mod avatar_story;
+mod badge_story;
+mod bar_chart_story;
+mod breadcrumbs_story;
 mod button_story;
+mod card_story;
 mod checkbox_story;
+mod command_palette_story;
+mod context_menu_story;
+mod data_table_story;
+mod description_list_story;
 mod dialog_story;
 mod dropdown_menu_story;
+mod empty_state_story;
+mod file_picker_story;
+mod form_story;
 mod input_story;
+mod kbd_story;
+mod label_story;
+mod link_story;
+mod markdown_story;
+mod menu_bar_story;
+mod notification_center_story;
+mod number_input_story;
+mod password_input_story;
 mod popover_story;
+mod progress_bar_story;
 mod radio_story;
+mod scroll_area_story;
+mod search_input_story;
 mod select_story;
+mod separator_story;
+mod skeleton_story;
+mod slider_story;
+mod sortable_list_story;
+mod sparkline_story;
+mod spinner_story;
+mod split_pane_story;
+mod status_bar_story;
+mod switch_story;
 mod tabs_story;
+mod tag_story;
+mod text_story;
 mod textarea_story;
+mod title_bar_story;
 mod toast_story;
 mod tooltip_story;
 
+pub use avatar_group_story::AvatarGroupStory;
+pub use avatar_story::AvatarStory;
+pub use badge_story::BadgeStory;
+pub use bar_chart_story::BarChartStory;
+pub use breadcrumbs_story::BreadcrumbsStory;
 pub use button_story::ButtonStory;
+pub use card_story::CardStory;
 pub use checkbox_story::CheckboxStory;
+pub use command_palette_story::CommandPaletteStory;
+pub use context_menu_story::ContextMenuStory;
+pub use data_table_story::DataTableStory;
+pub use description_list_story::DescriptionListStory;
 pub use dialog_story::DialogStory;
 pub use dropdown_menu_story::DropdownMenuStory;
+pub use empty_state_story::EmptyStateStory;
+pub use file_picker_story::FilePickerStory;
+pub use form_story::FormStory;
 pub use input_story::InputStory;
+pub use kbd_story::KbdStory;
+pub use label_story::LabelStory;
+pub use link_story::LinkStory;
+pub use markdown_story::MarkdownStory;
+pub use menu_bar_story::MenuBarStory;
+pub use notification_center_story::NotificationCenterStory;
+pub use number_input_story::NumberInputStory;
+pub use password_input_story::PasswordInputStory;
 pub use popover_story::PopoverStory;
+pub use progress_bar_story::ProgressBarStory;
 pub use radio_story::RadioStory;
+pub use scroll_area_story::ScrollAreaStory;
+pub use search_input_story::SearchInputStory;
 pub use select_story::SelectStory;
+pub use separator_story::SeparatorStory;
+pub use skeleton_story::SkeletonStory;
+pub use slider_story::SliderStory;
+pub use sortable_list_story::SortableListStory;
+pub use sparkline_story::SparklineStory;
+pub use spinner_story::SpinnerStory;
+pub use split_pane_story::SplitPaneStory;
+pub use status_bar_story::StatusBarStory;
+pub use switch_story::SwitchStory;
 pub use tabs_story::TabsStory;
+pub use tag_story::TagStory;
+pub use text_story::TextStory;
 pub use textarea_story::TextareaStory;
+pub use title_bar_story::TitleBarStory;
 pub use toast_story::ToastStory;
 pub use tooltip_story::TooltipStory;
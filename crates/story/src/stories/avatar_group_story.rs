@@ -0,0 +1,128 @@
+//! AvatarGroup story: demonstrates stacking, the +N overflow bubble, and
+//! sizes.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{
+    Avatar, AvatarGroup, AvatarSize, AvatarStatus, ComponentContract, ComponentState,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Names for the sample groups.
+const PEOPLE: [&str; 9] = [
+    "Ada Lovelace",
+    "Grace Hopper",
+    "Alan Turing",
+    "Margaret Hamilton",
+    "Katherine Johnson",
+    "Edsger Dijkstra",
+    "Barbara Liskov",
+    "Donald Knuth",
+    "Frances Allen",
+];
+
+pub struct AvatarGroupStory;
+
+impl Story for AvatarGroupStory {
+    fn name(&self) -> &'static str {
+        "AvatarGroup"
+    }
+
+    fn description(&self) -> &'static str {
+        "Overlapping avatars that collapse past max_visible into a +N bubble."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        AvatarGroup::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let muted_color = cx.theme().text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Overflow
+        let overflow_section = section("Overflow", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "max_visible defaults to 4. One hidden avatar is shown instead of a +1 \
+                 bubble, since the bubble would take its place.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(sample("avatar-group-3", 3))
+                    .child(sample("avatar-group-5", 5))
+                    .child(sample("avatar-group-9", 9))
+                    .child(sample("avatar-group-9-max-2", 9).max_visible(2)),
+            );
+        container = container.child(overflow_section);
+
+        // Sizes
+        let sizes_section = section("Sizes", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("The group's size applies to every avatar and the bubble."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(sample("avatar-group-small", 6).size(AvatarSize::Small))
+                    .child(sample("avatar-group-large", 6).size(AvatarSize::Large))
+                    .child(sample("avatar-group-xlarge", 6).size(AvatarSize::XLarge)),
+            );
+        container = container.child(sizes_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_avatar_group_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A group of the first `count` people; the first is online.
+fn sample(id: &'static str, count: usize) -> AvatarGroup {
+    AvatarGroup::new(id).avatars(PEOPLE[..count].iter().enumerate().map(|(index, name)| {
+        let avatar = Avatar::new(SharedString::from(format!("{id}-{index}")), *name);
+        if index == 0 {
+            avatar.status(AvatarStatus::Online)
+        } else {
+            avatar
+        }
+    }))
+}
+
+/// Render a state matrix cell for a given AvatarGroup state and size.
+fn render_avatar_group_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("avatar-group-matrix-{state:?}-{variant:?}"));
+    let size = match variant {
+        Some("Small") => AvatarSize::Small,
+        Some("Large") => AvatarSize::Large,
+        Some("XLarge") => AvatarSize::XLarge,
+        _ => AvatarSize::Medium,
+    };
+    let mut group = AvatarGroup::new(id.clone()).size(size).max_visible(3);
+    for (index, name) in PEOPLE[..5].iter().enumerate() {
+        let mut avatar = Avatar::new(SharedString::from(format!("{id}-{index}")), *name);
+        // Error: members whose image cannot load, leaving their initials.
+        if state == ComponentState::Error {
+            avatar = avatar.src("assets/avatars/missing.png");
+        }
+        group = group.child(avatar);
+    }
+    group.into_any_element()
+}
@@ -0,0 +1,177 @@
+//! Avatar story: demonstrates initials fallback, image sources that load,
+//! fail, or are missing, presence dots, and sizes.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{Avatar, AvatarSize, AvatarStatus, ComponentContract, ComponentState};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// A public image, fetched over HTTP when the app has a client.
+const REMOTE_IMAGE: &str = "https://avatars.githubusercontent.com/u/583231?s=128";
+
+/// A path that does not exist, to show the fallback.
+const MISSING_IMAGE: &str = "assets/avatars/missing.png";
+
+/// Every size, with its display name.
+const SIZES: [(AvatarSize, &str); 4] = [
+    (AvatarSize::Small, "Small"),
+    (AvatarSize::Medium, "Medium"),
+    (AvatarSize::Large, "Large"),
+    (AvatarSize::XLarge, "XLarge"),
+];
+
+pub struct AvatarStory;
+
+impl Story for AvatarStory {
+    fn name(&self) -> &'static str {
+        "Avatar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Round user picture loaded in the background, falling back to initials, \
+         with an optional presence dot."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Avatar::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let muted_color = cx.theme().text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Sources
+        let sources_section = section("Image Sources", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "src takes an http(s) URL or a file path. Initials show while the image \
+                 loads, and stay when it fails or no src is set.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_6()
+                    .child(labeled(
+                        Avatar::new("avatar-remote", "Octo Cat").src(REMOTE_IMAGE),
+                        "URL",
+                        muted_color,
+                    ))
+                    .child(labeled(
+                        Avatar::new("avatar-missing", "Grace Hopper").src(MISSING_IMAGE),
+                        "Missing file",
+                        muted_color,
+                    ))
+                    .child(labeled(
+                        Avatar::new("avatar-initials", "Ada Lovelace"),
+                        "No src",
+                        muted_color,
+                    ))
+                    .child(labeled(
+                        Avatar::new("avatar-single-name", "linus"),
+                        "One word",
+                        muted_color,
+                    ))
+                    .child(labeled(
+                        Avatar::new("avatar-no-name", ""),
+                        "No name",
+                        muted_color,
+                    )),
+            );
+        container = container.child(sources_section);
+
+        // Status
+        let mut statuses = div().flex().flex_row().items_center().gap_6();
+        for status in [
+            AvatarStatus::Online,
+            AvatarStatus::Away,
+            AvatarStatus::Busy,
+            AvatarStatus::Offline,
+        ] {
+            statuses = statuses.child(labeled(
+                Avatar::new(
+                    SharedString::from(format!("avatar-status-{}", status.label())),
+                    "Margaret Hamilton",
+                )
+                .size(AvatarSize::Large)
+                .status(status),
+                status.label(),
+                muted_color,
+            ));
+        }
+        let status_section = section("Status", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("The dot sits on the bottom-right edge, ringed in the surface color."),
+            )
+            .child(statuses);
+        container = container.child(status_section);
+
+        // Sizes
+        let mut sizes = div().flex().flex_row().items_end().gap_6();
+        for (size, name) in SIZES {
+            sizes = sizes.child(labeled(
+                Avatar::new(
+                    SharedString::from(format!("avatar-size-{name}")),
+                    "Alan Turing",
+                )
+                .size(size)
+                .status(AvatarStatus::Online),
+                name,
+                muted_color,
+            ));
+        }
+        let sizes_section =
+            section("Sizes", cx)
+                .child(div().text_xs().text_color(muted_color).child(
+                    "Small (24px), Medium (32px, default), Large (40px), and XLarge (56px).",
+                ))
+                .child(sizes);
+        container = container.child(sizes_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_avatar_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// An avatar with a caption below it.
+fn labeled(avatar: Avatar, label: &'static str, muted_color: Hsla) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .items_center()
+        .gap_1()
+        .child(avatar)
+        .child(div().text_xs().text_color(muted_color).child(label))
+}
+
+/// Render a state matrix cell for a given Avatar state and size.
+fn render_avatar_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("avatar-matrix-{state:?}-{variant:?}"));
+    let size = SIZES
+        .iter()
+        .find(|(_, name)| Some(*name) == variant)
+        .map_or(AvatarSize::Medium, |(size, _)| *size);
+    let avatar = Avatar::new(id, "Katherine Johnson").size(size);
+    // Error: an image that cannot load, leaving the initials.
+    let avatar = if state == ComponentState::Error {
+        avatar.src(MISSING_IMAGE)
+    } else {
+        avatar.status(AvatarStatus::Online)
+    };
+    avatar.into_any_element()
+}
@@ -0,0 +1,156 @@
+//! Badge story: demonstrates status colors, counts with overflow, and sizes.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{Badge, BadgeSize, BadgeStatus, ComponentContract, ComponentState};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Every status, with its display name.
+const STATUSES: [(BadgeStatus, &str); 5] = [
+    (BadgeStatus::Neutral, "Neutral"),
+    (BadgeStatus::Info, "Info"),
+    (BadgeStatus::Success, "Success"),
+    (BadgeStatus::Warning, "Warning"),
+    (BadgeStatus::Error, "Error"),
+];
+
+pub struct BadgeStory;
+
+impl Story for BadgeStory {
+    fn name(&self) -> &'static str {
+        "Badge"
+    }
+
+    fn description(&self) -> &'static str {
+        "Small status-colored label with an optional count that caps at max_count."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Badge::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let muted_color = cx.theme().text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Statuses
+        let mut statuses = div().flex().flex_row().flex_wrap().gap_2();
+        for (status, name) in STATUSES {
+            statuses = statuses.child(
+                Badge::new(SharedString::from(format!("badge-status-{name}")))
+                    .label(name)
+                    .status(status),
+            );
+        }
+        let statuses_section =
+            section("Statuses", cx)
+                .child(div().text_xs().text_color(muted_color).child(
+                    "Colors come from the status.* background, foreground, and border tokens.",
+                ))
+                .child(statuses);
+        container = container.child(statuses_section);
+
+        // Counts
+        let counts_section = section("Counts", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Counts use the app locale's digit grouping and read max_count+ once \
+                 they exceed max_count (default 99).",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .gap_2()
+                    .child(
+                        Badge::new("badge-count-only")
+                            .count(7)
+                            .status(BadgeStatus::Info),
+                    )
+                    .child(
+                        Badge::new("badge-count-labeled")
+                            .label("Errors")
+                            .count(12)
+                            .status(BadgeStatus::Error),
+                    )
+                    .child(
+                        Badge::new("badge-count-capped")
+                            .label("Unread")
+                            .count(150)
+                            .status(BadgeStatus::Info),
+                    )
+                    .child(
+                        Badge::new("badge-count-large-max")
+                            .label("Tokens")
+                            .count(1_284)
+                            .max_count(9_999),
+                    ),
+            );
+        container = container.child(counts_section);
+
+        // Sizes
+        let sizes_section = section("Sizes", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Small (16px), Medium (20px, default), and Large (24px)."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .child(sized("badge-small", BadgeSize::Small))
+                    .child(sized("badge-medium", BadgeSize::Medium))
+                    .child(sized("badge-large", BadgeSize::Large)),
+            );
+        container = container.child(sizes_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_badge_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A labeled success badge with a count at `size`.
+fn sized(id: &'static str, size: BadgeSize) -> Badge {
+    Badge::new(id)
+        .label("Passing")
+        .count(42)
+        .status(BadgeStatus::Success)
+        .size(size)
+}
+
+/// Render a state matrix cell for a given Badge state and size.
+fn render_badge_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("badge-matrix-{state:?}-{variant:?}"));
+    let size = match variant {
+        Some("Small") => BadgeSize::Small,
+        Some("Large") => BadgeSize::Large,
+        _ => BadgeSize::Medium,
+    };
+    let status = if state == ComponentState::Error {
+        BadgeStatus::Error
+    } else {
+        BadgeStatus::Neutral
+    };
+    Badge::new(id)
+        .label("Build")
+        .count(3)
+        .status(status)
+        .size(size)
+        .into_any_element()
+}
@@ -0,0 +1,71 @@
+//! BarChart story: demonstrates labeled bars, units, and a fixed scale.
+
+use crate::{Story, matrix::section};
+use components::{Bar, BarChart, ComponentContract};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct BarChartStory;
+
+impl Story for BarChartStory {
+    fn name(&self) -> &'static str {
+        "BarChart"
+    }
+
+    fn description(&self) -> &'static str {
+        "Vertical token-styled bars with value and category labels."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        BarChart::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Auto-scaled
+        let auto_section = section("Auto Scale", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("The largest bar fills the plot height. Hover a bar to highlight it."),
+            )
+            .child(
+                BarChart::new("bars-render-times")
+                    .unit("ms")
+                    .bar(Bar::new("Dialog", 4.2))
+                    .bar(Bar::new("Select", 6.8))
+                    .bar(Bar::new("Tabs", 3.1))
+                    .bar(Bar::new("Toast", 2.4)),
+            );
+        container = container.child(auto_section);
+
+        // Fixed max
+        let fixed_section = section("Fixed Scale", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("max(16.7) keeps bars comparable to a frame budget."),
+            )
+            .child(
+                BarChart::new("bars-budget")
+                    .unit("ms")
+                    .max(16.7)
+                    .height(px(120.0))
+                    .bars([
+                        Bar::new("Min", 2.1),
+                        Bar::new("Avg", 5.6),
+                        Bar::new("p95", 11.9),
+                        Bar::new("Max", 16.2),
+                    ]),
+            );
+        container = container.child(fixed_section);
+
+        container.into_any_element()
+    }
+}
@@ -0,0 +1,158 @@
+//! Breadcrumbs story: demonstrates short and deeply nested paths, middle
+//! collapse into the overflow menu, label truncation, and separators.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{Breadcrumbs, ComponentContract, ComponentState};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// A deeply nested path for the collapse examples.
+const DEEP_PATH: [&str; 8] = [
+    "Workspace",
+    "gpui-workbench",
+    "crates",
+    "components",
+    "src",
+    "widgets",
+    "navigation",
+    "breadcrumbs.rs",
+];
+
+pub struct BreadcrumbsStory;
+
+impl Story for BreadcrumbsStory {
+    fn name(&self) -> &'static str {
+        "Breadcrumbs"
+    }
+
+    fn description(&self) -> &'static str {
+        "Navigation path with clickable ancestors. Long paths collapse their \
+         middle into an ellipsis menu."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Breadcrumbs::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Live navigation: choosing an ancestor truncates the path to it.
+        let depth = window.use_keyed_state("breadcrumbs-story-depth", cx, |_, _| DEEP_PATH.len());
+        let current_depth = *depth.read(cx);
+        let live_section = section("Navigation", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Click an ancestor, or pick one from the ellipsis menu, to navigate up. \
+                 The current location is not clickable.",
+            ))
+            .child(
+                Breadcrumbs::new("breadcrumbs-live")
+                    .items(DEEP_PATH[..current_depth].iter().copied())
+                    .on_select({
+                        let depth = depth.clone();
+                        move |index, window, cx| {
+                            depth.update(cx, |depth, _| *depth = index + 1);
+                            window.refresh();
+                        }
+                    }),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(text_color)
+                            .child(DEEP_PATH[..current_depth].join("/")),
+                    )
+                    .child(
+                        div()
+                            .id("breadcrumbs-live-reset")
+                            .px_2()
+                            .rounded_sm()
+                            .text_xs()
+                            .text_color(theme.text.accent)
+                            .cursor_pointer()
+                            .child("Reset")
+                            .on_click(move |_event, window, cx| {
+                                depth.update(cx, |depth, _| *depth = DEEP_PATH.len());
+                                window.refresh();
+                            }),
+                    ),
+            );
+        container = container.child(live_section);
+
+        // Short vs deep
+        let paths_section = section("Short and Deep Paths", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Paths within max_visible (default 4) show every item; longer \
+                 paths keep the root and the last items around an ellipsis.",
+            ))
+            .child(sample("breadcrumbs-short", &DEEP_PATH[..3]))
+            .child(sample("breadcrumbs-deep", &DEEP_PATH))
+            .child(sample("breadcrumbs-deep-3", &DEEP_PATH).max_visible(3))
+            .child(sample("breadcrumbs-deep-2", &DEEP_PATH).max_visible(2));
+        container = container.child(paths_section);
+
+        // Truncation and separators
+        let styling_section = section("Truncation and Separators", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Labels wider than max_label_width truncate with an ellipsis."),
+            )
+            .child(
+                sample(
+                    "breadcrumbs-long-labels",
+                    &[
+                        "Projects",
+                        "A remarkably long folder name that will not fit",
+                        "Quarterly planning documents",
+                    ],
+                )
+                .max_label_width(px(120.0)),
+            )
+            .child(sample("breadcrumbs-chevron", &DEEP_PATH[..4]).separator("›"));
+        container = container.child(styling_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_breadcrumbs_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A clickable trail over `path` that does nothing when an item is chosen.
+fn sample(id: &'static str, path: &[&'static str]) -> Breadcrumbs {
+    Breadcrumbs::new(id)
+        .items(path.iter().copied())
+        .on_select(|_index, _window, _cx| {})
+}
+
+/// Render a state matrix cell for a given Breadcrumbs state.
+fn render_breadcrumbs_state_cell(state: ComponentState) -> AnyElement {
+    let id = SharedString::from(format!("breadcrumbs-matrix-{state:?}"));
+    Breadcrumbs::new(id)
+        .items(DEEP_PATH[3..].iter().copied())
+        .max_visible(3)
+        .disabled(state == ComponentState::Disabled)
+        .on_select(|_index, _window, _cx| {})
+        .into_any_element()
+}
@@ -3,11 +3,12 @@
 //! Renders multiple Button instances showing:
 //! - All variants (Primary, Secondary, Ghost, Danger)
 //! - All sizes (Small, Medium, Large)
-//! - With and without icons
+//! - With and without icons, and square icon-only buttons
 //! - Disabled state
 //! - Selected state
 //! - Full-width button
-//! - State matrix showing Hover, Active, Focused, Disabled, Selected
+//! - State matrices showing Hover, Active, Focused, Disabled, Selected for
+//!   labeled and icon-only buttons
 
 use crate::{
     Story,
@@ -141,12 +142,49 @@ impl Story for ButtonStory {
                     )
                     .child(
                         Button::new("icon-only-btn")
-                            .icon("X")
-                            .variant(ButtonVariant::Ghost),
+                            .icon("×")
+                            .label("Close")
+                            .variant(ButtonVariant::Ghost)
+                            .icon_only(),
                     ),
             );
         container = container.child(icon_section);
 
+        // Section 3b: Icon-only sizes
+        let icon_only_section = section("Icon Only", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Square at every size (24, 28, 32px). The label is not drawn: it is \
+                 the accessible label and the tooltip.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_3()
+                    .items_center()
+                    .child(
+                        Button::new("icon-only-small")
+                            .icon("+")
+                            .label("Add")
+                            .size(ButtonSize::Small)
+                            .icon_only(),
+                    )
+                    .child(
+                        Button::new("icon-only-medium")
+                            .icon("+")
+                            .label("Add")
+                            .icon_only(),
+                    )
+                    .child(
+                        Button::new("icon-only-large")
+                            .icon("+")
+                            .label("Add")
+                            .size(ButtonSize::Large)
+                            .icon_only(),
+                    ),
+            );
+        container = container.child(icon_only_section);
+
         // Section 4: Disabled State
         let disabled_section = section("Disabled", cx)
             .child(
@@ -221,21 +259,30 @@ impl Story for ButtonStory {
         // Section 7: State Matrix
         let matrix = StateMatrix::from_contract(&self.contract());
         let matrix_element = matrix.render(
-            |state, variant, _window, cx| render_button_state_cell(state, variant, cx),
+            |state, variant, _window, _cx| render_button_state_cell(state, variant, false),
             window,
             cx,
         );
         container = container.child(matrix_element);
 
+        // Section 8: Icon-only State Matrix
+        let icon_matrix = StateMatrix::from_contract(&self.contract()).named("Icon-only Button");
+        let icon_matrix_element = icon_matrix.render(
+            |state, variant, _window, _cx| render_button_state_cell(state, variant, true),
+            window,
+            cx,
+        );
+        container = container.child(icon_matrix_element);
+
         container.into_any_element()
     }
 }
 
-/// Render a single cell in the state matrix.
+/// Render a single cell in a state matrix, labeled or icon-only.
 fn render_button_state_cell(
     state: ComponentState,
     variant: Option<&str>,
-    _cx: &mut App,
+    icon_only: bool,
 ) -> AnyElement {
     let btn_variant = match variant {
         Some("Primary") => ButtonVariant::Primary,
@@ -245,11 +292,15 @@ fn render_button_state_cell(
     };
 
     let variant_label = variant.unwrap_or("Secondary");
-    let id_str = format!("matrix-{variant_label}-{state:?}");
+    let prefix = if icon_only { "matrix-icon" } else { "matrix" };
+    let id_str = format!("{prefix}-{variant_label}-{state:?}");
 
     let mut btn = Button::new(SharedString::from(id_str))
         .label(SharedString::from(variant_label.to_string()))
         .variant(btn_variant);
+    if icon_only {
+        btn = btn.icon("★").icon_only();
+    }
 
     match state {
         ComponentState::Disabled => {
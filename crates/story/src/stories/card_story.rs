@@ -0,0 +1,146 @@
+//! Card story: demonstrates header and footer slots, interactive cards, and
+//! the disabled state.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{Card, ComponentContract, ComponentState, Link, Text, TextSize, TextWeight};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct CardStory;
+
+impl Story for CardStory {
+    fn name(&self) -> &'static str {
+        "Card"
+    }
+
+    fn description(&self) -> &'static str {
+        "Elevated surface with optional header and footer slots, and an \
+         interactive variant activated by click, Enter, or Space."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Card::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let muted_color = cx.theme().text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Slots
+        let slots_section = section("Header and Footer", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Header and footer are optional; rules divide them from the body."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .items_start()
+                    .gap_4()
+                    .child(
+                        div().w(px(260.0)).child(
+                            Card::new("card-full")
+                                .header(
+                                    Text::new("card-full-title", "Seats")
+                                        .weight(TextWeight::Semibold),
+                                )
+                                .body(Text::new("card-full-usage", "12 of 20 seats in use"))
+                                .body(
+                                    Text::new(
+                                        "card-full-hint",
+                                        "Seats free up when members leave.",
+                                    )
+                                    .size(TextSize::XSmall)
+                                    .muted(true),
+                                )
+                                .footer(
+                                    Link::new("card-full-manage", "Manage seats")
+                                        .on_click(|_window, _cx| {}),
+                                ),
+                        ),
+                    )
+                    .child(
+                        div().w(px(260.0)).child(
+                            Card::new("card-body-only")
+                                .body(Text::new("card-body-only-text", "A card with a body only.")),
+                        ),
+                    ),
+            );
+        container = container.child(slots_section);
+
+        // Interactive
+        let opened = window.use_keyed_state("card-story-opened", cx, |_, _| None::<usize>);
+        let last_opened = *opened.read(cx);
+        let projects = ["gpui-workbench", "theme-studio", "registry-site"];
+        let interactive_section = section("Interactive", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Hover for element.hover; Tab to focus, then Enter or Space."),
+            )
+            .child(div().flex().flex_row().flex_wrap().gap_4().children(
+                projects.into_iter().enumerate().map(|(ix, name)| {
+                    let opened = opened.clone();
+                    div().w(px(200.0)).child(
+                        Card::new(("card-project", ix))
+                            .body(
+                                Text::new(("card-project-name", ix), name)
+                                    .weight(TextWeight::Medium),
+                            )
+                            .body(
+                                Text::new(("card-project-meta", ix), "Updated today")
+                                    .size(TextSize::XSmall)
+                                    .muted(true),
+                            )
+                            .on_click(move |window, cx| {
+                                opened.update(cx, |opened, _| *opened = Some(ix));
+                                window.refresh();
+                            }),
+                    )
+                }),
+            ))
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child(match last_opened {
+                        Some(ix) => format!("Opened {}", projects[ix]),
+                        None => "No project opened".to_string(),
+                    }),
+            );
+        container = container.child(interactive_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_card_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Card state and variant.
+fn render_card_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("card-matrix-{state:?}-{variant:?}"));
+    let card = Card::new(id.clone())
+        .body(Text::new(SharedString::from(format!("{id}-text")), "Card"))
+        .disabled(state == ComponentState::Disabled);
+    let card = match variant {
+        Some("Interactive") => card.on_click(|_window, _cx| {}),
+        _ => card,
+    };
+    div().w(px(120.0)).child(card).into_any_element()
+}
@@ -0,0 +1,179 @@
+//! CommandPalette story: demonstrates opening the palette, fuzzy filtering
+//! with match highlights, disabled commands, and shortcuts.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{
+    Button, CommandItem, CommandPalette, ComponentContract, ComponentState, command_matches,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct CommandPaletteStory;
+
+impl Story for CommandPaletteStory {
+    fn name(&self) -> &'static str {
+        "CommandPalette"
+    }
+
+    fn description(&self) -> &'static str {
+        "Modal command search: type to fuzzy-filter commands, arrow keys to move, \
+         Enter to run, Escape to close."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        CommandPalette::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Live palette
+        let open = window.use_keyed_state("command-palette-story-open", cx, |_, _| false);
+        let last_run = window.use_keyed_state("command-palette-story-last", cx, |_, _| {
+            SharedString::from("Nothing run yet")
+        });
+        let is_open = *open.read(cx);
+        let last_run_text = last_run.read(cx).clone();
+
+        let commands = sample_commands().into_iter().map(|command| {
+            let last_run = last_run.clone();
+            let text = SharedString::from(format!("Ran \"{}\"", command.search_text()));
+            command.on_run(move |window, cx| {
+                last_run.update(cx, |last_run, _| *last_run = text.clone());
+                window.refresh();
+            })
+        });
+        let mut stage = div()
+            .relative()
+            .h(px(420.0))
+            .w_full()
+            .flex()
+            .flex_col()
+            .gap_2()
+            .child(
+                div().flex().flex_row().items_center().gap_3().child(
+                    Button::new("command-palette-open")
+                        .label("Open Command Palette")
+                        .on_click({
+                            let open = open.clone();
+                            move |_, window, cx| {
+                                open.update(cx, |open, _| *open = true);
+                                window.refresh();
+                            }
+                        }),
+                ),
+            )
+            .child(div().text_sm().text_color(text_color).child(last_run_text));
+        if is_open {
+            stage = stage.child(
+                CommandPalette::new("command-palette-live")
+                    .commands(commands)
+                    .on_dismiss(move |window, cx| {
+                        open.update(cx, |open, _| *open = false);
+                        window.refresh();
+                    }),
+            );
+        }
+        let live_section = section("Live Palette", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Open the palette and type, e.g. \"tgth\" for Toggle Theme. Disabled \
+                 commands are listed but skipped by the arrow keys.",
+            ))
+            .child(stage);
+        container = container.child(live_section);
+
+        // Ranking
+        let commands = sample_commands();
+        let mut ranking = div().flex().flex_col().gap_1().text_xs();
+        for query in ["theme", "tgth", "ss", "zoom in"] {
+            let ranked: Vec<String> = command_matches(query, &commands)
+                .into_iter()
+                .take(3)
+                .map(|(index, found)| format!("{} ({})", commands[index].label, found.score))
+                .collect();
+            ranking = ranking.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w(px(80.0))
+                            .text_color(text_color)
+                            .child(format!("\"{query}\"")),
+                    )
+                    .child(div().text_color(muted_color).child(if ranked.is_empty() {
+                        "no matches".to_string()
+                    } else {
+                        ranked.join(" · ")
+                    })),
+            );
+        }
+        let ranking_section = section("Ranking", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Top matches and scores per query. Word starts and consecutive runs \
+                 rank above scattered matches.",
+            ))
+            .child(ranking);
+        container = container.child(ranking_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_command_palette_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Commands shown in the story.
+fn sample_commands() -> Vec<CommandItem> {
+    vec![
+        CommandItem::new("theme.toggle", "Toggle Theme")
+            .category("View")
+            .shortcut("cmd-shift-t"),
+        CommandItem::new("theme.load", "Load Theme File").category("Theme"),
+        CommandItem::new("theme.export", "Export Theme Patch").category("Theme"),
+        CommandItem::new("view.zoom_in", "Zoom In")
+            .category("View")
+            .shortcut("cmd-="),
+        CommandItem::new("view.zoom_out", "Zoom Out")
+            .category("View")
+            .shortcut("cmd--"),
+        CommandItem::new("view.settings", "Show Settings").category("View"),
+        CommandItem::new("file.save", "Save").shortcut("cmd-s"),
+        CommandItem::new("file.save_all", "Save All")
+            .shortcut("cmd-alt-s")
+            .disabled(true),
+        CommandItem::new("perf.hud", "Toggle Perf HUD").category("Debug"),
+        CommandItem::new("inspect.toggle", "Toggle Inspect Mode").category("Debug"),
+    ]
+}
+
+/// Render a state matrix cell for a given CommandPalette state.
+fn render_command_palette_state_cell(state: ComponentState) -> AnyElement {
+    let id = SharedString::from(format!("command-palette-matrix-{state:?}"));
+    div()
+        .relative()
+        .w(px(320.0))
+        .h(px(260.0))
+        .child(
+            CommandPalette::new(id)
+                .commands(sample_commands())
+                .width(px(280.0))
+                .max_visible_rows(3)
+                .autofocus(false),
+        )
+        .into_any_element()
+}
@@ -0,0 +1,176 @@
+//! ContextMenu story: demonstrates right-click opening, nested submenus,
+//! separators, disabled items, shortcuts, and placement.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, ContextMenu, ContextMenuItem};
+use gpui::*;
+use primitives::PopoverPosition;
+use theme::ActiveTheme;
+
+pub struct ContextMenuStory;
+
+impl Story for ContextMenuStory {
+    fn name(&self) -> &'static str {
+        "ContextMenu"
+    }
+
+    fn description(&self) -> &'static str {
+        "Right-click menu opened at the pointer, with nested submenus, separators, \
+         disabled items, and keyboard navigation."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        ContextMenu::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Live menu
+        let last_chosen = window.use_keyed_state("context-menu-story-last", cx, |_, _| {
+            SharedString::from("Nothing chosen yet")
+        });
+        let last_chosen_text = last_chosen.read(cx).clone();
+        let choose = move |label: &'static str| {
+            let last_chosen = last_chosen.clone();
+            move |window: &mut Window, cx: &mut App| {
+                last_chosen.update(cx, |last_chosen, _| {
+                    *last_chosen = format!("Chose \"{label}\"").into();
+                });
+                window.refresh();
+            }
+        };
+        let items = vec![
+            ContextMenuItem::new("Cut")
+                .shortcut("cmd-x")
+                .on_select(choose("Cut")),
+            ContextMenuItem::new("Copy")
+                .shortcut("cmd-c")
+                .on_select(choose("Copy")),
+            ContextMenuItem::new("Paste")
+                .shortcut("cmd-v")
+                .disabled(true),
+            ContextMenuItem::separator(),
+            ContextMenuItem::submenu(
+                "Sort By",
+                vec![
+                    ContextMenuItem::new("Name").on_select(choose("Sort By Name")),
+                    ContextMenuItem::new("Date Modified").on_select(choose("Sort By Date")),
+                    ContextMenuItem::new("Size").disabled(true),
+                    ContextMenuItem::separator(),
+                    ContextMenuItem::submenu(
+                        "Direction",
+                        vec![
+                            ContextMenuItem::new("Ascending").on_select(choose("Ascending")),
+                            ContextMenuItem::new("Descending").on_select(choose("Descending")),
+                        ],
+                    ),
+                ],
+            ),
+            ContextMenuItem::submenu("Share", Vec::new()).disabled(true),
+            ContextMenuItem::separator(),
+            ContextMenuItem::new("Rename…").on_select(choose("Rename")),
+            ContextMenuItem::new("Move to Trash")
+                .shortcut("cmd-backspace")
+                .on_select(choose("Move to Trash")),
+        ];
+        let live_section = section("Live Menu", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Right-click the area below. Arrow keys navigate, Right opens a \
+                 submenu, Left closes it, and Escape or a click outside closes the menu.",
+            ))
+            .child(
+                ContextMenu::new("context-menu-live")
+                    .items(items)
+                    .child(trigger_area("Right-click here", cx)),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(text_color)
+                    .child(last_chosen_text),
+            );
+        container = container.child(live_section);
+
+        // Placement
+        let placement_section = section("Placement", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "The PopoverPosition attach corner sits at the click point. Menus \
+                 flip when they would overflow the window.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_4()
+                    .child(
+                        ContextMenu::new("context-menu-below")
+                            .items(placement_items())
+                            .child(trigger_area("below_left (default)", cx)),
+                    )
+                    .child(
+                        ContextMenu::new("context-menu-above")
+                            .position(PopoverPosition::above_right())
+                            .items(placement_items())
+                            .child(trigger_area("above_right", cx)),
+                    ),
+            );
+        container = container.child(placement_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, cx| render_context_menu_state_cell(state, cx),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A dashed area that shows where to right-click.
+fn trigger_area(label: &'static str, cx: &App) -> Div {
+    let theme = cx.theme();
+    div()
+        .flex()
+        .items_center()
+        .justify_center()
+        .w(px(240.0))
+        .h(px(96.0))
+        .border_1()
+        .border_dashed()
+        .border_color(theme.border.default)
+        .rounded_md()
+        .text_xs()
+        .text_color(theme.text.muted)
+        .child(label)
+}
+
+/// A short menu for the placement examples.
+fn placement_items() -> Vec<ContextMenuItem> {
+    vec![
+        ContextMenuItem::new("Open"),
+        ContextMenuItem::new("Open in New Window"),
+        ContextMenuItem::separator(),
+        ContextMenuItem::new("Close"),
+    ]
+}
+
+/// Render a state matrix cell for a given ContextMenu state.
+fn render_context_menu_state_cell(state: ComponentState, cx: &App) -> AnyElement {
+    let id = SharedString::from(format!("context-menu-matrix-{state:?}"));
+    ContextMenu::new(id)
+        .disabled(state == ComponentState::Disabled)
+        .items(placement_items())
+        .child(trigger_area("Right-click", cx).w(px(160.0)).h(px(48.0)))
+        .into_any_element()
+}
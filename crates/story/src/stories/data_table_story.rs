@@ -0,0 +1,272 @@
+//! DataTable story: demonstrates sorting, selection, disabled rows, the empty
+//! state, and virtualized rendering of a large data set.
+//!
+//! Renders DataTable instances showing:
+//! - Sortable columns with single selection
+//! - Multiple selection with pre-selected rows
+//! - Disabled rows skipped by keyboard navigation
+//! - Empty table
+//! - 10,000 rows rendered through `uniform_list`
+//! - State matrix showing Focused, Hover, Selected, Disabled
+
+use std::rc::Rc;
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{
+    Column, ColumnAlign, ColumnSort, ComponentContract, ComponentState, DataTable, SelectionMode,
+    TableRow,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Rows in the large-data-set section.
+const LARGE_ROW_COUNT: usize = 10_000;
+
+/// Story for the DataTable component.
+///
+/// Demonstrates sortable headers, single and multiple selection, arrow-key row
+/// navigation, disabled rows, and virtualized rendering.
+pub struct DataTableStory;
+
+impl Story for DataTableStory {
+    fn name(&self) -> &'static str {
+        "DataTable"
+    }
+
+    fn description(&self) -> &'static str {
+        "Column-defined rows with sortable headers, row selection, and keyboard \
+         navigation, virtualized for thousands of rows."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        DataTable::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Section 1: Sortable
+        let sortable_section = section("Sortable Columns", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Click a header to sort ascending, descending, then unsorted. \
+                 Numbers sort numerically. Click a row or use Up/Down and Enter to select.",
+            ))
+            .child(
+                DataTable::new("table-sortable", component_columns(), component_rows())
+                    .height(px(180.0)),
+            );
+        container = container.child(sortable_section);
+
+        // Section 2: Multiple selection
+        let multiple_section = section("Multiple Selection", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Cmd/Ctrl-click or Space toggles rows. Two rows start selected; \
+                 the selection follows rows through re-sorting.",
+            ))
+            .child(
+                DataTable::new("table-multiple", component_columns(), component_rows())
+                    .selection_mode(SelectionMode::Multiple)
+                    .default_selected_rows([1, 3])
+                    .default_sort(ColumnSort::descending(2))
+                    .height(px(180.0)),
+            );
+        container = container.child(multiple_section);
+
+        // Section 3: Disabled rows
+        let mut rows = component_rows();
+        rows[2] = rows[2].clone().set_disabled(true);
+        rows[4] = rows[4].clone().set_disabled(true);
+        let disabled_section = section("Disabled Rows", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Disabled rows are dimmed, unselectable, and skipped by Up/Down."),
+            )
+            .child(DataTable::new("table-disabled", component_columns(), rows).height(px(180.0)));
+        container = container.child(disabled_section);
+
+        // Section 4: Empty
+        let empty_section = section("Empty", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Tables without rows render an EmptyState in the body."),
+            )
+            .child(
+                DataTable::new("table-empty", component_columns(), Vec::new())
+                    .empty_text("No components match")
+                    .height(px(96.0)),
+            );
+        container = container.child(empty_section);
+
+        // Section 5: Large data set. The rows live in keyed state so the same Rc
+        // is passed every frame and the sorted order stays cached.
+        let events = window
+            .use_keyed_state("data-table-story-large-rows", cx, |_, _| {
+                Rc::new(large_rows(LARGE_ROW_COUNT))
+            })
+            .read(cx)
+            .clone();
+        let large_section = section("10,000 Rows", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Only the rows in view are laid out. Sort by any column or hold \
+                 Down to scroll through the list.",
+            ))
+            .child(
+                DataTable::new("table-large", large_columns(), events)
+                    .selection_mode(SelectionMode::Multiple)
+                    .height(px(320.0)),
+            );
+        container = container.child(large_section);
+
+        // Section 6: State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, cx| render_data_table_state_cell(state, cx),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Columns for the component-inventory tables.
+fn component_columns() -> Vec<Column> {
+    vec![
+        Column::new("Component").sortable(),
+        Column::new("Disposition").width(px(110.0)).sortable(),
+        Column::new("Tokens")
+            .width(px(80.0))
+            .sortable()
+            .align(ColumnAlign::End),
+    ]
+}
+
+/// A small component inventory.
+fn component_rows() -> Vec<TableRow> {
+    vec![
+        TableRow::new(["Button", "Fork", "9"]),
+        TableRow::new(["Checkbox", "Fork", "8"]),
+        TableRow::new(["DataTable", "Fork", "10"]),
+        TableRow::new(["Dialog", "Fork", "7"]),
+        TableRow::new(["EmptyState", "Rewrite", "4"]),
+        TableRow::new(["Select", "Fork", "11"]),
+        TableRow::new(["Tabs", "Fork", "9"]),
+    ]
+}
+
+/// Columns for the large data set.
+fn large_columns() -> Vec<Column> {
+    vec![
+        Column::new("#")
+            .width(px(72.0))
+            .sortable()
+            .align(ColumnAlign::End),
+        Column::new("Event").sortable(),
+        Column::new("Status").width(px(100.0)).sortable(),
+        Column::new("Duration (ms)")
+            .width(px(120.0))
+            .sortable()
+            .align(ColumnAlign::End),
+    ]
+}
+
+/// Deterministic synthetic rows for the large data set.
+fn large_rows(count: usize) -> Vec<TableRow> {
+    const EVENTS: [&str; 5] = ["render", "layout", "paint", "input", "animation"];
+    const STATUSES: [&str; 3] = ["ok", "slow", "dropped"];
+    (0..count)
+        .map(|i| {
+            TableRow::new([
+                (i + 1).to_string(),
+                format!("{} #{}", EVENTS[i % EVENTS.len()], i / EVENTS.len()),
+                STATUSES[(i * 7) % STATUSES.len()].to_string(),
+                format!("{:.1}", (i * 37 % 1_600) as f64 / 100.0),
+            ])
+        })
+        .collect()
+}
+
+/// Render a state matrix cell for a given DataTable state: a header and two
+/// rows drawn with the component's tokens.
+fn render_data_table_state_cell(state: ComponentState, cx: &App) -> AnyElement {
+    let theme = cx.theme();
+    let text_color = theme.text.default;
+    let muted_color = theme.text.muted;
+
+    let (border_color, row_bg, row_text, row_opacity) = match state {
+        ComponentState::Focused => (
+            theme.border.focused,
+            theme.ghost_element.hover,
+            text_color,
+            1.0,
+        ),
+        ComponentState::Hover => (
+            theme.border.default,
+            theme.ghost_element.hover,
+            text_color,
+            1.0,
+        ),
+        ComponentState::Selected => (
+            theme.border.default,
+            theme.ghost_element.selected,
+            text_color,
+            1.0,
+        ),
+        ComponentState::Disabled => (
+            theme.border.default,
+            Hsla::transparent_black(),
+            theme.text.disabled,
+            0.5,
+        ),
+        _ => {
+            return div()
+                .text_xs()
+                .text_color(muted_color)
+                .child(format!("{:?} N/A", state))
+                .into_any_element();
+        }
+    };
+
+    let row = |label: &'static str| div().h(px(18.0)).px_1().flex().items_center().child(label);
+
+    div()
+        .flex()
+        .flex_col()
+        .w_full()
+        .text_xs()
+        .border_1()
+        .border_color(border_color)
+        .rounded_sm()
+        .overflow_hidden()
+        .child(
+            row("Name ↑")
+                .bg(theme.element.background)
+                .text_color(muted_color)
+                .border_b_1()
+                .border_color(theme.border.default),
+        )
+        .child(
+            row("Button")
+                .bg(row_bg)
+                .text_color(row_text)
+                .opacity(row_opacity),
+        )
+        .child(
+            row("Dialog")
+                .text_color(text_color)
+                .border_t_1()
+                .border_color(theme.border.variant),
+        )
+        .into_any_element()
+}
@@ -0,0 +1,94 @@
+//! DescriptionList story: demonstrates horizontal and stacked term/definition layouts.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, DescriptionItem, DescriptionLayout, DescriptionList};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct DescriptionListStory;
+
+impl Story for DescriptionListStory {
+    fn name(&self) -> &'static str {
+        "DescriptionList"
+    }
+
+    fn description(&self) -> &'static str {
+        "Term/definition pairs in horizontal or stacked layouts, with copyable values."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        DescriptionList::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let items = || {
+            [
+                DescriptionItem::new("Name", "Button"),
+                DescriptionItem::new("Version", "0.1.0"),
+                DescriptionItem::new("Disposition", "Fork"),
+                DescriptionItem::new("File", "crates/components/src/button.rs").copyable(),
+            ]
+        };
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Horizontal
+        let horizontal_section = section("Horizontal", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Terms in a fixed-width column beside their definitions."),
+            )
+            .child(
+                div()
+                    .w(px(420.0))
+                    .child(DescriptionList::new("dl-horizontal").items(items())),
+            );
+        container = container.child(horizontal_section);
+
+        // Stacked
+        let stacked_section = section("Stacked", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Terms above their definitions, for narrow panels."),
+            )
+            .child(
+                div().w(px(240.0)).child(
+                    DescriptionList::new("dl-stacked")
+                        .layout(DescriptionLayout::Stacked)
+                        .items(items()),
+                ),
+            );
+        container = container.child(stacked_section);
+
+        // Copyable
+        let copy_section = section("Copyable Values", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Click ⧉ to copy a token path to the clipboard."),
+            )
+            .child(
+                div().w(px(420.0)).child(
+                    DescriptionList::new("dl-copyable")
+                        .term_width(px(160.0))
+                        .item(
+                            DescriptionItem::new("Trigger background", "element.background")
+                                .copyable(),
+                        )
+                        .item(DescriptionItem::new("Focus ring", "border.focused").copyable())
+                        .item(DescriptionItem::new("Label color", "text.default").copyable()),
+                ),
+            );
+        container = container.child(copy_section);
+
+        container.into_any_element()
+    }
+}
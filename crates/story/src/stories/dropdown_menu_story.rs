@@ -13,7 +13,8 @@ impl Story for DropdownMenuStory {
     }
 
     fn description(&self) -> &'static str {
-        "Trigger button + dropdown menu with keyboard navigation and item selection."
+        "Trigger button + dropdown menu with keyboard navigation, item selection, \
+         checkbox/radio items, labeled separators, and icons."
     }
 
     fn contract(&self) -> ComponentContract {
@@ -53,20 +54,21 @@ impl Story for DropdownMenuStory {
                 div()
                     .text_xs()
                     .text_color(muted_color)
-                    .child("Menu with dropdown visible and highlighted item."),
+                    .child("Menu with dropdown visible, highlighted item, and shortcut hints."),
             )
             .child(
                 DropdownMenu::new(
                     "open-menu",
                     vec![
-                        MenuItem::new("Cut"),
-                        MenuItem::new("Copy"),
-                        MenuItem::new("Paste"),
+                        MenuItem::new("Cut").shortcut("cmd-x"),
+                        MenuItem::new("Copy").shortcut("cmd-c"),
+                        MenuItem::new("Paste").shortcut("cmd-v"),
                         MenuItem::separator(),
-                        MenuItem::new("Select All"),
+                        MenuItem::new("Select All").shortcut("cmd-a"),
                     ],
                 )
                 .trigger_label("Edit")
+                .width(px(220.0))
                 .open(true)
                 .highlighted_index(1),
             );
@@ -84,8 +86,8 @@ impl Story for DropdownMenuStory {
                 DropdownMenu::new(
                     "disabled-items-menu",
                     vec![
-                        MenuItem::new("Undo"),
-                        MenuItem::disabled("Redo"),
+                        MenuItem::new("Undo").shortcut("cmd-z"),
+                        MenuItem::disabled("Redo").shortcut("cmd-shift-z"),
                         MenuItem::separator(),
                         MenuItem::new("Cut"),
                         MenuItem::disabled("Copy"),
@@ -97,6 +99,55 @@ impl Story for DropdownMenuStory {
             );
         container = container.child(disabled_items_section);
 
+        // Checkable items
+        let checkable_section = section("Checkbox and Radio Items", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Labeled separators group checkbox and radio items. \
+                         Space toggles the highlighted item; radio items are exclusive per group.",
+            ))
+            .child(
+                DropdownMenu::new(
+                    "checkable-menu",
+                    vec![
+                        MenuItem::labeled_separator("Panels"),
+                        MenuItem::checkbox("Show Sidebar", true),
+                        MenuItem::checkbox("Show Minimap", false),
+                        MenuItem::labeled_separator("Indentation"),
+                        MenuItem::radio("Tabs", "indent", false),
+                        MenuItem::radio("Spaces", "indent", true),
+                        MenuItem::separator(),
+                        MenuItem::new("Reset Layout"),
+                    ],
+                )
+                .trigger_label("View")
+                .open(true)
+                .highlighted_index(1),
+            );
+        container = container.child(checkable_section);
+
+        // Icons
+        let icon_section = section("With Icons", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Items with a leading icon slot."),
+            )
+            .child(
+                DropdownMenu::new(
+                    "icon-menu",
+                    vec![
+                        MenuItem::new("New File").icon("+"),
+                        MenuItem::new("Open...").icon("↗"),
+                        MenuItem::separator(),
+                        MenuItem::disabled("Delete").icon("✕"),
+                    ],
+                )
+                .trigger_label("File")
+                .open(true),
+            );
+        container = container.child(icon_section);
+
         // Disabled trigger
         let disabled_section = section("Disabled Menu", cx)
             .child(
@@ -0,0 +1,101 @@
+//! EmptyState story: demonstrates sizes and compositions of the empty state.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, EmptyState, EmptyStateSize, Select};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct EmptyStateStory;
+
+impl Story for EmptyStateStory {
+    fn name(&self) -> &'static str {
+        "EmptyState"
+    }
+
+    fn description(&self) -> &'static str {
+        "Placeholder for zero-data surfaces with icon, title, description, and primary action."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        EmptyState::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let border_color = theme.border.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Sizes
+        let mut sizes_section = section("Sizes", cx).child(
+            div()
+                .text_xs()
+                .text_color(muted_color)
+                .child("Small (icon hidden), Medium, and Large."),
+        );
+        for (size, label) in [
+            (EmptyStateSize::Small, "small"),
+            (EmptyStateSize::Medium, "medium"),
+            (EmptyStateSize::Large, "large"),
+        ] {
+            sizes_section = sizes_section.child(
+                div()
+                    .border_1()
+                    .border_color(border_color)
+                    .rounded_md()
+                    .child(
+                        EmptyState::new(SharedString::from(format!("empty-{label}")))
+                            .icon("☐")
+                            .title("No components yet")
+                            .description("Add a component to see it listed here.")
+                            .size(size),
+                    ),
+            );
+        }
+        container = container.child(sizes_section);
+
+        // With action
+        let action_section = section("With Primary Action", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Empty search results with a recovery action."),
+            )
+            .child(
+                div()
+                    .border_1()
+                    .border_color(border_color)
+                    .rounded_md()
+                    .child(
+                        EmptyState::new("empty-search")
+                            .icon("⌕")
+                            .title("No results")
+                            .description("No tokens match \"accent.hover\".")
+                            .action("Clear search", |_event, _window, _cx| {}),
+                    ),
+            );
+        container = container.child(action_section);
+
+        // Inside Select
+        let select_section = section("Empty Select Dropdown", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Select renders a Small EmptyState when it has no items."),
+            )
+            .child(
+                div().h(px(120.0)).child(
+                    Select::new("empty-select", Vec::new(), cx)
+                        .placeholder("Choose a theme...")
+                        .empty_text("No themes installed")
+                        .open(),
+                ),
+            );
+        container = container.child(select_section);
+
+        container.into_any_element()
+    }
+}
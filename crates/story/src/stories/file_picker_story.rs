@@ -0,0 +1,149 @@
+//! FilePicker story: demonstrates the button and drop-zone triggers, extension
+//! filters, multiple selection, and the native dialog.
+//!
+//! Every section except "Native Dialog" uses a `MockFileDialog`, so the story
+//! renders the same on every platform and in headless runs.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, FileFilter, FilePicker, MockFileDialog};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct FilePickerStory;
+
+impl Story for FilePickerStory {
+    fn name(&self) -> &'static str {
+        "FilePicker"
+    }
+
+    fn description(&self) -> &'static str {
+        "Button or drop-zone trigger for the platform file dialog, with \
+         extension filters and a removable list of chosen files."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        FilePicker::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Button trigger
+        let button_section = section("Button Trigger", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "A mock dialog answers with themes/ocean.json. Picking again replaces \
+                 the single selection; × removes it.",
+            ))
+            .child(
+                FilePicker::new("file-picker-single")
+                    .filter(theme_filter())
+                    .dialog(MockFileDialog::new().respond_with(["themes/ocean.json"])),
+            );
+        container = container.child(button_section);
+
+        // Multiple with filters
+        let multiple_section = section("Multiple Files and Filters", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "The mock dialog returns two themes and notes.txt; the text file \
+                 fails the .json filter and is reported.",
+            ))
+            .child(
+                FilePicker::new("file-picker-multiple")
+                    .multiple(true)
+                    .filter(theme_filter())
+                    .default_value(["themes/one-dark.json"])
+                    .dialog(MockFileDialog::new().respond_with([
+                        "themes/ocean.json",
+                        "themes/forest.json",
+                        "notes.txt",
+                    ])),
+            );
+        container = container.child(multiple_section);
+
+        // Drop zone
+        let drop_section = section("Drop Zone", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Drag images from the desktop onto the zone, or click to have the \
+                 mock dialog pick assets/logo.png.",
+            ))
+            .child(
+                FilePicker::new("file-picker-drop")
+                    .drop_zone()
+                    .multiple(true)
+                    .filter(FileFilter::new("Image", ["png", "jpg", "jpeg"]))
+                    .dialog(MockFileDialog::new().respond_with(["assets/logo.png"])),
+            );
+        container = container.child(drop_section);
+
+        // Native dialog
+        let native_section = section("Native Dialog", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Opens the platform file dialog."),
+            )
+            .child(
+                FilePicker::new("file-picker-native")
+                    .multiple(true)
+                    .prompt("Choose"),
+            );
+        container = container.child(native_section);
+
+        // Disabled
+        let disabled_section = section("Disabled", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Disabled pickers keep their list but ignore input."),
+            )
+            .child(
+                FilePicker::new("file-picker-disabled")
+                    .default_value(["themes/ocean.json"])
+                    .disabled(true),
+            );
+        container = container.child(disabled_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_file_picker_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// The filter used by the theme examples.
+fn theme_filter() -> FileFilter {
+    FileFilter::new("Theme", ["json"])
+}
+
+/// Render a state matrix cell for a given FilePicker state and trigger.
+///
+/// The Error cell's mock dialog picks a file the filter rejects.
+fn render_file_picker_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("file-picker-matrix-{state:?}-{variant:?}"));
+    let mut picker = FilePicker::new(id)
+        .filter(theme_filter())
+        .label(format!("{state:?}"))
+        .dialog(MockFileDialog::new().respond_with([match state {
+            ComponentState::Error => "notes.txt",
+            _ => "themes/ocean.json",
+        }]))
+        .disabled(state == ComponentState::Disabled);
+    if variant == Some("DropZone") {
+        picker = picker.drop_zone();
+    }
+    picker.into_any_element()
+}
@@ -0,0 +1,250 @@
+//! Form story: demonstrates a form combining Input, Select, Checkbox, and
+//! Radio fields with validation, submit, and reset.
+
+use crate::{Story, matrix::section};
+use components::{
+    Checkbox, ComponentContract, Form, FormField, Input, Radio, RadioItem, Select, SelectItem,
+};
+use gpui::*;
+use primitives::{Orientation, ValidationState};
+use theme::ActiveTheme;
+
+pub struct FormStory;
+
+const PLANS: [&str; 3] = ["Free", "Team", "Enterprise"];
+const CONTACT: [&str; 2] = ["Email", "Phone"];
+
+/// The sign-up form's values.
+#[derive(Clone, Default)]
+struct Signup {
+    name: String,
+    email: String,
+    plan: Option<usize>,
+    contact: usize,
+    terms: bool,
+    /// Bumped by reset so the uncontrolled Select remounts empty.
+    generation: usize,
+    submitted: Option<String>,
+}
+
+impl Signup {
+    fn name_error(&self) -> Option<&'static str> {
+        self.name.trim().is_empty().then_some("Name is required")
+    }
+
+    fn email_error(&self) -> Option<&'static str> {
+        (!self.email.contains('@')).then_some("Enter an email address like ada@example.com")
+    }
+
+    fn plan_error(&self) -> Option<&'static str> {
+        self.plan.is_none().then_some("Choose a plan")
+    }
+
+    fn terms_error(&self) -> Option<&'static str> {
+        (!self.terms).then_some("Accept the terms to continue")
+    }
+}
+
+/// The validation state and message for a field's check.
+fn check(error: Option<&'static str>) -> (ValidationState, Option<&'static str>) {
+    match error {
+        Some(message) => (ValidationState::Error, Some(message)),
+        None => (ValidationState::None, None),
+    }
+}
+
+impl Story for FormStory {
+    fn name(&self) -> &'static str {
+        "Form"
+    }
+
+    fn description(&self) -> &'static str {
+        "Labeled fields with help text and error messages, validation aggregated \
+         across fields, and submit/reset actions."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Form::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Sign-up form combining every field type.
+        let signup = window.use_keyed_state("form-story-signup", cx, |_, _| Signup::default());
+        let draft = signup.read(cx).clone();
+        let update = |apply: fn(&mut Signup, &str)| {
+            let signup = signup.clone();
+            move |value: &str, window: &mut Window, cx: &mut App| {
+                signup.update(cx, |signup, _| apply(signup, value));
+                window.refresh();
+            }
+        };
+
+        let (name_state, name_message) = check(draft.name_error());
+        let (email_state, email_message) = check(draft.email_error());
+        let (plan_state, plan_message) = check(draft.plan_error());
+        let (terms_state, terms_message) = check(draft.terms_error());
+
+        let mut plan_select = Select::new(
+            SharedString::from(format!("form-plan-{}", draft.generation)),
+            PLANS.iter().map(|plan| SelectItem::new(*plan)).collect(),
+            cx,
+        )
+        .placeholder("Choose a plan")
+        .set_width(px(240.0))
+        .on_change({
+            let signup = signup.clone();
+            move |ix, _, window, cx| {
+                signup.update(cx, |signup, _| signup.plan = Some(ix));
+                window.refresh();
+            }
+        });
+        if let Some(plan) = draft.plan {
+            plan_select = plan_select.selected_index(plan);
+        }
+
+        let form = Form::new("form-signup")
+            .field(
+                FormField::new("Name")
+                    .required()
+                    .validation(name_state, name_message)
+                    .control(
+                        Input::new("form-name")
+                            .value(draft.name.clone())
+                            .placeholder("Ada Lovelace")
+                            .error(name_state.is_error())
+                            .on_change(update(|signup, value| signup.name = value.to_string()))
+                            .full_width(),
+                    ),
+            )
+            .field(
+                FormField::new("Email")
+                    .required()
+                    .help("Used for sign-in and receipts.")
+                    .validation(email_state, email_message)
+                    .control(
+                        Input::new("form-email")
+                            .value(draft.email.clone())
+                            .placeholder("ada@example.com")
+                            .error(email_state.is_error())
+                            .on_change(update(|signup, value| signup.email = value.to_string()))
+                            .full_width(),
+                    ),
+            )
+            .field(
+                FormField::new("Plan")
+                    .required()
+                    .help("You can change plans at any time.")
+                    .validation(plan_state, plan_message)
+                    .control(plan_select),
+            )
+            .field(
+                FormField::new("Contact by")
+                    .help("How we reach you about your account.")
+                    .control(
+                        Radio::new(
+                            "form-contact",
+                            CONTACT.iter().map(|way| RadioItem::new(*way)).collect(),
+                        )
+                        .selected_index(draft.contact)
+                        .orientation(Orientation::Horizontal)
+                        .on_change({
+                            let signup = signup.clone();
+                            move |ix, _, window, cx| {
+                                signup.update(cx, |signup, _| signup.contact = ix);
+                                window.refresh();
+                            }
+                        }),
+                    ),
+            )
+            .field(
+                FormField::new("Terms")
+                    .required()
+                    .validation(terms_state, terms_message)
+                    .control(
+                        Checkbox::new("form-terms")
+                            .label("I accept the terms of service")
+                            .checked(draft.terms)
+                            .on_change({
+                                let signup = signup.clone();
+                                move |checked, window, cx| {
+                                    signup.update(cx, |signup, _| signup.terms = checked);
+                                    window.refresh();
+                                }
+                            }),
+                    ),
+            )
+            .submit_label("Create account")
+            .on_submit({
+                let signup = signup.clone();
+                move |window, cx| {
+                    signup.update(cx, |signup, _| {
+                        signup.submitted = Some(format!(
+                            "Created {} ({}) on the {} plan, contact by {}",
+                            signup.name.trim(),
+                            signup.email,
+                            signup.plan.map_or("", |plan| PLANS[plan]),
+                            CONTACT[signup.contact].to_lowercase()
+                        ));
+                    });
+                    window.refresh();
+                }
+            })
+            .on_reset({
+                let signup = signup.clone();
+                move |window, cx| {
+                    signup.update(cx, |signup, _| {
+                        *signup = Signup {
+                            generation: signup.generation + 1,
+                            ..Signup::default()
+                        };
+                    });
+                    window.refresh();
+                }
+            });
+
+        let status = draft
+            .submitted
+            .clone()
+            .unwrap_or_else(|| "Not submitted yet".to_string());
+        let signup_section = section("Sign-up", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Required fields are marked *. Submitting with errors lists the fields \
+                 to fix instead of submitting; Reset clears every field.",
+            ))
+            .child(div().w(px(360.0)).child(form))
+            .child(div().text_xs().text_color(muted_color).child(status));
+        container = container.child(signup_section);
+
+        // Help text only
+        let help_section = section("Help Text", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("A valid form without reset: help text sits under each control."),
+            )
+            .child(
+                div().w(px(360.0)).child(
+                    Form::new("form-profile")
+                        .field(
+                            FormField::new("Display name")
+                                .help("Shown on your comments.")
+                                .control(
+                                    Input::new("form-display-name")
+                                        .default_value("Ada")
+                                        .full_width(),
+                                ),
+                        )
+                        .submit_label("Save"),
+                ),
+            );
+        container = container.child(help_section);
+
+        container.into_any_element()
+    }
+}
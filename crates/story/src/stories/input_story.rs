@@ -48,7 +48,7 @@ impl Story for InputStory {
                     .text_color(muted_color)
                     .child("Input with pre-filled value."),
             )
-            .child(Input::new("value-input").value("Hello, world!"));
+            .child(Input::new("value-input").default_value("Hello, world!"));
         container = container.child(value_section);
 
         // Sizes
@@ -104,7 +104,7 @@ impl Story for InputStory {
                         Input::new("price-input")
                             .prefix("$")
                             .suffix(".00")
-                            .value("99"),
+                            .default_value("99"),
                     ),
             );
         container = container.child(affix_section);
@@ -119,7 +119,7 @@ impl Story for InputStory {
             )
             .child(
                 Input::new("error-input")
-                    .value("invalid-email")
+                    .default_value("invalid-email")
                     .error_message("Please enter a valid email address"),
             );
         container = container.child(error_section);
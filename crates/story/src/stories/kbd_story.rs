@@ -0,0 +1,193 @@
+//! Kbd story: demonstrates key caps, platform normalization, registered
+//! shortcuts, and keyboard models.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, Kbd, KeyPlatform};
+use gpui::*;
+use primitives::ShortcutRegistry;
+use theme::ActiveTheme;
+
+/// Key bindings documented by the built-in components' keyboard models.
+const KEYBOARD_MODELS: &[(&str, &[(&str, &str)])] = &[
+    ("Dialog", &[("escape", "Close"), ("tab", "Next focusable")]),
+    (
+        "DropdownMenu",
+        &[
+            ("up down", "Move highlight"),
+            ("enter", "Select"),
+            ("space", "Toggle checkable item"),
+        ],
+    ),
+    (
+        "Select",
+        &[("up down", "Move highlight"), ("enter", "Choose option")],
+    ),
+    (
+        "Tabs",
+        &[
+            ("left right", "Previous/next tab"),
+            ("home end", "First/last tab"),
+        ],
+    ),
+];
+
+pub struct KbdStory;
+
+impl Story for KbdStory {
+    fn name(&self) -> &'static str {
+        "Kbd"
+    }
+
+    fn description(&self) -> &'static str {
+        "Key caps for shortcuts with platform-normalized modifier and key names."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Kbd::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Shortcuts
+        let shortcuts_section = section("Shortcuts", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Single keys, modifier combinations, chords, and disabled."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .items_center()
+                    .gap_4()
+                    .child(Kbd::new("escape"))
+                    .child(Kbd::new("cmd-s"))
+                    .child(Kbd::new("cmd-shift-p"))
+                    .child(Kbd::new("ctrl--"))
+                    .child(Kbd::new("cmd-k cmd-s"))
+                    .child(Kbd::new("cmd-z").disabled(true)),
+            );
+        container = container.child(shortcuts_section);
+
+        // Platforms
+        let mut platforms_section = section("Platform Normalization", cx).child(
+            div()
+                .text_xs()
+                .text_color(muted_color)
+                .child("The same binding rendered with each platform's conventions."),
+        );
+        for (platform, label) in [
+            (KeyPlatform::Mac, "macOS"),
+            (KeyPlatform::Linux, "Linux"),
+            (KeyPlatform::Windows, "Windows"),
+        ] {
+            platforms_section = platforms_section.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        div()
+                            .w(px(80.0))
+                            .text_xs()
+                            .text_color(muted_color)
+                            .child(label),
+                    )
+                    .child(Kbd::new("cmd-alt-shift-enter").platform(platform))
+                    .child(Kbd::new("ctrl-backspace").platform(platform)),
+            );
+        }
+        container = container.child(platforms_section);
+
+        // Registered shortcuts
+        let mut registered_section = section("Registered Shortcuts", cx).child(
+            div()
+                .text_xs()
+                .text_color(muted_color)
+                .child("Kbd::for_action shows an action's current binding; rebinding it in Settings updates these caps."),
+        );
+        let actions: Vec<(SharedString, SharedString)> = cx
+            .try_global::<ShortcutRegistry>()
+            .map(|shortcuts| {
+                shortcuts
+                    .shortcuts()
+                    .map(|s| (s.action().clone(), s.label().clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        if actions.is_empty() {
+            registered_section = registered_section.child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("No shortcuts registered."),
+            );
+        }
+        for (action, label) in actions {
+            registered_section = registered_section.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .child(
+                        div()
+                            .w(px(160.0))
+                            .text_xs()
+                            .text_color(text_color)
+                            .child(label),
+                    )
+                    .children(Kbd::for_action(&action, cx)),
+            );
+        }
+        container = container.child(registered_section);
+
+        // Keyboard models
+        let mut models_section = section("Component Keyboard Models", cx).child(
+            div()
+                .text_xs()
+                .text_color(muted_color)
+                .child("Keys each component responds to, from its contract."),
+        );
+        for (component, bindings) in KEYBOARD_MODELS {
+            let mut rows = div().flex().flex_col().gap_1();
+            for (keys, action) in *bindings {
+                rows = rows.child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_3()
+                        .child(div().w(px(120.0)).flex().child(Kbd::new(*keys)))
+                        .child(div().text_xs().text_color(text_color).child(*action)),
+                );
+            }
+            models_section = models_section.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_xs()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(muted_color)
+                            .child(*component),
+                    )
+                    .child(rows),
+            );
+        }
+        container = container.child(models_section);
+
+        container.into_any_element()
+    }
+}
@@ -0,0 +1,128 @@
+//! Label story: demonstrates required and disabled labels and the
+//! association with a control.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Input, Label};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct LabelStory;
+
+impl Story for LabelStory {
+    fn name(&self) -> &'static str {
+        "Label"
+    }
+
+    fn description(&self) -> &'static str {
+        "Names a form control, marks it required, and focuses it when clicked."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Label::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+        let border_color = theme.border.default;
+        let focus_border = theme.border.focused;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Labels
+        let labels_section = section("Labels", cx).child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_2()
+                .child(Label::new("label-plain", "Display name"))
+                .child(Label::new("label-required", "Email").required(true))
+                .child(Label::new("label-disabled", "Organization").disabled(true)),
+        );
+        container = container.child(labels_section);
+
+        // Association
+        let focus_handle = window
+            .use_keyed_state("label-story-focus", cx, |_, cx| cx.focus_handle())
+            .read(cx)
+            .clone();
+        let target_focused = focus_handle.is_focused(window);
+        let association_section = section("Association", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "for_input publishes the labeled element id to the accessibility tree. \
+                 Given the control's focus handle, clicking the label focuses it.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .w(px(280.0))
+                    .child(
+                        Label::new("label-email", "Email")
+                            .required(true)
+                            .for_input("label-story-email", None),
+                    )
+                    .child(Input::new("label-story-email").placeholder("ada@example.com")),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        Label::new("label-focus", "Click to focus the box")
+                            .for_input("label-story-target", Some(focus_handle.clone())),
+                    )
+                    .child(
+                        div()
+                            .id("label-story-target")
+                            .track_focus(&focus_handle)
+                            .w(px(280.0))
+                            .px_3()
+                            .py_2()
+                            .rounded_md()
+                            .border_1()
+                            .border_color(if target_focused {
+                                focus_border
+                            } else {
+                                border_color
+                            })
+                            .text_sm()
+                            .text_color(text_color)
+                            .child(if target_focused {
+                                "Focused"
+                            } else {
+                                "Not focused"
+                            }),
+                    ),
+            );
+        container = container.child(association_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_label_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Label state.
+fn render_label_state_cell(state: ComponentState) -> AnyElement {
+    Label::new(
+        SharedString::from(format!("label-matrix-{state:?}")),
+        "Email",
+    )
+    .required(true)
+    .disabled(state == ComponentState::Disabled)
+    .into_any_element()
+}
@@ -0,0 +1,108 @@
+//! Link story: demonstrates inline links, activation, and the disabled
+//! state.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Link};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct LinkStory;
+
+impl Story for LinkStory {
+    fn name(&self) -> &'static str {
+        "Link"
+    }
+
+    fn description(&self) -> &'static str {
+        "Inline text action, activated by click or by Enter or Space while focused."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Link::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Inline
+        let clicks = window.use_keyed_state("link-story-clicks", cx, |_, _| 0usize);
+        let count = *clicks.read(cx);
+        let inline_section = section("Inline", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Hover for the link.hover color; Tab to focus, then Enter or Space."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .items_center()
+                    .text_sm()
+                    .text_color(text_color)
+                    .child("Tokens are documented in the")
+                    .child(
+                        Link::new("link-inline", "theme reference")
+                            .set_tooltip("docs/themes.md")
+                            .on_click(move |window, cx| {
+                                clicks.update(cx, |clicks, _| *clicks += 1);
+                                window.refresh();
+                            }),
+                    )
+                    .child("."),
+            )
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child(format!("Activated {count} times")),
+            );
+        container = container.child(inline_section);
+
+        // Disabled
+        let disabled_section = section("Disabled", cx).child(
+            div()
+                .flex()
+                .flex_row()
+                .gap_4()
+                .child(Link::new("link-enabled", "Enabled").on_click(|_window, _cx| {}))
+                .child(
+                    Link::new("link-disabled", "Disabled")
+                        .disabled(true)
+                        .on_click(|_window, _cx| {}),
+                ),
+        );
+        container = container.child(disabled_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_link_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Link state.
+fn render_link_state_cell(state: ComponentState) -> AnyElement {
+    Link::new(
+        SharedString::from(format!("link-matrix-{state:?}")),
+        "Open settings",
+    )
+    .disabled(state == ComponentState::Disabled)
+    .on_click(|_window, _cx| {})
+    .into_any_element()
+}
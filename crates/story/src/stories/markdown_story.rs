@@ -0,0 +1,96 @@
+//! Markdown story: demonstrates every supported construct, rendered beside its source.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, Markdown};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct MarkdownStory;
+
+const HEADINGS: &str = "# Heading 1\n## Heading 2\n### Heading 3\n#### Heading 4";
+
+const EMPHASIS: &str = "Text can be **strong**, *emphasized*, or _emphasized_ with \
+underscores. Inline `code spans` use the code font. Underscores inside words like \
+snake_case_name stay literal, and so does an unclosed **marker.";
+
+const LISTS: &str = "- Unordered item\n- Item with `code`\n- Item with a [link](https://zed.dev)\n\n\
+1. First step\n2. Second step\n3. Third step";
+
+const CODE_BLOCK: &str = "```rust\n// Register a story with the workbench.\n\
+pub fn init(cx: &mut App) {\n    let mut registry = StoryRegistry::new();\n    \
+registry.register(MarkdownStory);\n    let count = 42;\n    \
+println!(\"{} stories\", count);\n}\n```";
+
+const LINKS: &str = "Safe links open in the browser: [Zed](https://zed.dev), \
+[email](mailto:team@example.com). Other schemes render as plain text: \
+[script](javascript:alert(1)).";
+
+const UNSUPPORTED: &str = "<b>Raw HTML</b> and ![images](x.png) render literally.\n\n\
+| Tables | too |";
+
+impl Story for MarkdownStory {
+    fn name(&self) -> &'static str {
+        "Markdown"
+    }
+
+    fn description(&self) -> &'static str {
+        "Safe Markdown subset: headings, **strong**, *emphasis*, `code`, lists, \
+         fenced code blocks, and links."
+    }
+
+    fn notes(&self) -> &'static str {
+        "**Review:** compare each rendered column with its source.\n\n\
+         - Code blocks use the `syntax.*` tokens; switch themes to check contrast.\n\
+         - Only `http`, `https`, and `mailto` links are clickable."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Markdown::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let source_bg = theme.surface.surface;
+        let border_color = theme.border.default;
+
+        // Source on the left, rendered output on the right.
+        let example = |id: &'static str, source: &'static str| {
+            div()
+                .flex()
+                .gap_4()
+                .w_full()
+                .child(
+                    div()
+                        .w(px(320.0))
+                        .flex_none()
+                        .p_2()
+                        .rounded_md()
+                        .bg(source_bg)
+                        .border_1()
+                        .border_color(border_color)
+                        .font_family("monospace")
+                        .text_xs()
+                        .text_color(muted_color)
+                        .whitespace_normal()
+                        .children(source.lines().map(|line| div().child(line.to_string()))),
+                )
+                .child(div().flex_1().child(Markdown::new(id, source)))
+        };
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        for (title, id, source) in [
+            ("Headings", "md-headings", HEADINGS),
+            ("Emphasis & Code Spans", "md-emphasis", EMPHASIS),
+            ("Lists", "md-lists", LISTS),
+            ("Code Block", "md-code-block", CODE_BLOCK),
+            ("Links", "md-links", LINKS),
+            ("Unsupported Syntax", "md-unsupported", UNSUPPORTED),
+        ] {
+            container = container.child(section(title, cx).child(example(id, source)));
+        }
+
+        container.into_any_element()
+    }
+}
@@ -0,0 +1,181 @@
+//! MenuBar story: demonstrates switching menus by hover and arrow keys,
+//! nested submenus, disabled menus and items, and shortcuts.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, ContextMenuItem, MenuBar, MenuBarMenu};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct MenuBarStory;
+
+impl Story for MenuBarStory {
+    fn name(&self) -> &'static str {
+        "MenuBar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Row of menu titles, each opening a dropdown menu with nested submenus; \
+         hover switches menus once one is open, and arrow keys move across them."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        MenuBar::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+        let bar_border = theme.border.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Live bar
+        let last_chosen = window.use_keyed_state("menu-bar-story-last", cx, |_, _| {
+            SharedString::from("Nothing chosen yet")
+        });
+        let last_chosen_text = last_chosen.read(cx).clone();
+        let choose = move |label: &'static str| {
+            let last_chosen = last_chosen.clone();
+            move |window: &mut Window, cx: &mut App| {
+                last_chosen.update(cx, |last_chosen, _| {
+                    *last_chosen = format!("Chose \"{label}\"").into();
+                });
+                window.refresh();
+            }
+        };
+        let menus = vec![
+            MenuBarMenu::new(
+                "File",
+                vec![
+                    ContextMenuItem::new("New File")
+                        .shortcut("cmd-n")
+                        .on_select(choose("New File")),
+                    ContextMenuItem::new("Open…")
+                        .shortcut("cmd-o")
+                        .on_select(choose("Open")),
+                    ContextMenuItem::submenu(
+                        "Open Recent",
+                        vec![
+                            ContextMenuItem::new("button.rs").on_select(choose("button.rs")),
+                            ContextMenuItem::new("menu_bar.rs").on_select(choose("menu_bar.rs")),
+                            ContextMenuItem::separator(),
+                            ContextMenuItem::submenu(
+                                "Workspaces",
+                                vec![
+                                    ContextMenuItem::new("gpui-workbench")
+                                        .on_select(choose("gpui-workbench")),
+                                    ContextMenuItem::new("theme-studio")
+                                        .on_select(choose("theme-studio")),
+                                ],
+                            ),
+                        ],
+                    ),
+                    ContextMenuItem::separator(),
+                    ContextMenuItem::new("Save")
+                        .shortcut("cmd-s")
+                        .on_select(choose("Save")),
+                    ContextMenuItem::new("Save As…")
+                        .shortcut("cmd-shift-s")
+                        .disabled(true),
+                ],
+            ),
+            MenuBarMenu::new(
+                "Edit",
+                vec![
+                    ContextMenuItem::new("Undo")
+                        .shortcut("cmd-z")
+                        .on_select(choose("Undo")),
+                    ContextMenuItem::new("Redo")
+                        .shortcut("cmd-shift-z")
+                        .disabled(true),
+                    ContextMenuItem::separator(),
+                    ContextMenuItem::new("Cut")
+                        .shortcut("cmd-x")
+                        .on_select(choose("Cut")),
+                    ContextMenuItem::new("Copy")
+                        .shortcut("cmd-c")
+                        .on_select(choose("Copy")),
+                    ContextMenuItem::new("Paste")
+                        .shortcut("cmd-v")
+                        .on_select(choose("Paste")),
+                ],
+            ),
+            MenuBarMenu::new(
+                "View",
+                vec![
+                    ContextMenuItem::submenu(
+                        "Appearance",
+                        vec![
+                            ContextMenuItem::new("Light").on_select(choose("Light")),
+                            ContextMenuItem::new("Dark").on_select(choose("Dark")),
+                        ],
+                    ),
+                    ContextMenuItem::new("Toggle Sidebar")
+                        .shortcut("cmd-b")
+                        .on_select(choose("Toggle Sidebar")),
+                ],
+            ),
+            MenuBarMenu::new("Run", short_items()).disabled(true),
+            MenuBarMenu::new(
+                "Help",
+                vec![ContextMenuItem::new("About").on_select(choose("About"))],
+            ),
+        ];
+        let live_section = section("Live Bar", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Click a title, then hover the others to switch menus. With the bar \
+                 focused, Left/Right move between menus, Down opens one, Right and \
+                 Left enter and leave submenus, and Escape closes.",
+            ))
+            .child(
+                div()
+                    .px_1()
+                    .border_1()
+                    .border_color(bar_border)
+                    .rounded_md()
+                    .child(MenuBar::new("menu-bar-live").menus(menus)),
+            )
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(text_color)
+                    .child(last_chosen_text),
+            );
+        container = container.child(live_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_menu_bar_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A short menu for the disabled menu and the state matrix.
+fn short_items() -> Vec<ContextMenuItem> {
+    vec![
+        ContextMenuItem::new("Start"),
+        ContextMenuItem::new("Stop"),
+        ContextMenuItem::separator(),
+        ContextMenuItem::new("Restart"),
+    ]
+}
+
+/// Render a state matrix cell for a given MenuBar state.
+fn render_menu_bar_state_cell(state: ComponentState) -> AnyElement {
+    let id = SharedString::from(format!("menu-bar-matrix-{state:?}"));
+    MenuBar::new(id)
+        .disabled(state == ComponentState::Disabled)
+        .menu(MenuBarMenu::new("File", short_items()))
+        .menu(MenuBarMenu::new("Edit", short_items()))
+        .into_any_element()
+}
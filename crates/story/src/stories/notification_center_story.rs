@@ -0,0 +1,116 @@
+//! NotificationCenter story: demonstrates the toast history panel with day
+//! grouping, unread markers, per-item actions, and clear-all.
+//!
+//! The panel shows the app-wide history, so toasts dismissed in the Toast
+//! story appear here too.
+
+use std::time::{Duration, SystemTime};
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{
+    Button, ButtonVariant, ComponentContract, ComponentState, NotificationCenter,
+    NotificationHistory, ToastEntry, ToastVariant,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct NotificationCenterStory;
+
+impl Story for NotificationCenterStory {
+    fn name(&self) -> &'static str {
+        "NotificationCenter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Reviewable history of dismissed toasts, grouped by day, with unread \
+         markers, per-item actions, and clear-all."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        NotificationCenter::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // History panel
+        let record = |id: &'static str, label: &'static str, make: fn() -> ToastEntry| {
+            Button::new(id).label(label).on_click(move |_, window, cx| {
+                NotificationHistory::record_closed([make()], cx);
+                window.refresh();
+            })
+        };
+        let controls = div()
+            .flex()
+            .flex_row()
+            .gap_2()
+            .child(record("notification-add-info", "Add Info", || {
+                ToastEntry::new("saved", "File saved")
+                    .description("tokens.json was written to disk.")
+                    .variant(ToastVariant::Success)
+            }))
+            .child(record("notification-add-retry", "Add Error", || {
+                ToastEntry::new("build", "Build failed")
+                    .description("2 errors in theme.rs.")
+                    .variant(ToastVariant::Error)
+                    .action("Retry", |_window, _cx| {})
+            }))
+            .child(record(
+                "notification-add-yesterday",
+                "Add Yesterday",
+                || {
+                    ToastEntry::new("export", "Export finished")
+                        .timestamp(SystemTime::now() - Duration::from_secs(24 * 60 * 60))
+                        .progress(1.0)
+                },
+            ))
+            .child(
+                Button::new("notification-mark-read")
+                    .label("Mark All Read")
+                    .variant(ButtonVariant::Ghost)
+                    .on_click(|_, window, cx| {
+                        cx.default_global::<NotificationHistory>().mark_all_read();
+                        window.refresh();
+                    }),
+            );
+        let history_section = section("History Panel", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Add notifications as if their toasts had closed. Click a row to mark \
+                 it read, × to remove it, or Clear all. Toasts dismissed in the Toast \
+                 story land here too.",
+            ))
+            .child(controls)
+            .child(
+                div()
+                    .h(px(360.0))
+                    .child(NotificationCenter::new("notification-center")),
+            );
+        container = container.child(history_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_notification_center_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given NotificationCenter state.
+fn render_notification_center_state_cell(state: ComponentState) -> AnyElement {
+    let id = SharedString::from(format!("notification-center-matrix-{state:?}"));
+    div()
+        .h(px(160.0))
+        .child(NotificationCenter::new(id).width(px(240.0)))
+        .into_any_element()
+}
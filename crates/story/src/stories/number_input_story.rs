@@ -0,0 +1,115 @@
+//! NumberInput story: demonstrates stepping, ranges, decimal steps, and
+//! validation errors.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, NumberInput};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct NumberInputStory;
+
+impl Story for NumberInputStory {
+    fn name(&self) -> &'static str {
+        "NumberInput"
+    }
+
+    fn description(&self) -> &'static str {
+        "Numeric field with stepper buttons and Up/Down stepping, clamped to a \
+         min/max range, with validation messages for out-of-range text."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        NumberInput::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Interactive
+        let interactive_section = section("Stepping", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Click − and +, or focus the field and press Up/Down. \
+                 The buttons mute at the ends of 0..=10.",
+            ))
+            .child(
+                NumberInput::new("number-quantity")
+                    .min(0.0)
+                    .max(10.0)
+                    .default_value(3.0),
+            );
+        container = container.child(interactive_section);
+
+        // Steps
+        let steps_section = section("Steps", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Decimal steps keep their precision; unbounded inputs step forever."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        NumberInput::new("number-opacity")
+                            .min(0.0)
+                            .max(1.0)
+                            .step(0.05)
+                            .default_value(0.8),
+                    )
+                    .child(
+                        NumberInput::new("number-offset")
+                            .step(10.0)
+                            .default_value(-20.0),
+                    ),
+            );
+        container = container.child(steps_section);
+
+        // Validation
+        let validation_section = section("Validation", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Type 150 or a word to see range and number errors; \
+                 Enter clamps or reverts the text.",
+            ))
+            .child(
+                NumberInput::new("number-percent")
+                    .min(0.0)
+                    .max(100.0)
+                    .default_value(50.0),
+            );
+        container = container.child(validation_section);
+
+        // Errors and disabled
+        let states_section = section("Error and Disabled", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("An external error_message replaces range messages."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        NumberInput::new("number-error")
+                            .default_value(12.0)
+                            .error_message("Only 8 seats are left"),
+                    )
+                    .child(
+                        NumberInput::new("number-disabled")
+                            .default_value(4.0)
+                            .disabled(true),
+                    ),
+            );
+        container = container.child(states_section);
+
+        container.into_any_element()
+    }
+}
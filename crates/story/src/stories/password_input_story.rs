@@ -0,0 +1,105 @@
+//! PasswordInput story: demonstrates masking, the reveal toggle, the strength
+//! meter, and rule validation.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, PasswordInput, PasswordRule};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct PasswordInputStory;
+
+impl Story for PasswordInputStory {
+    fn name(&self) -> &'static str {
+        "PasswordInput"
+    }
+
+    fn description(&self) -> &'static str {
+        "Masked input with Show/Hide toggle, strength meter on status tokens, \
+         and validation messages for unmet rules."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        PasswordInput::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Interactive
+        let interactive_section = section("Default Rules", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Type to fill the meter. At least 8 characters with lowercase, \
+                 uppercase, a digit, and a symbol is Strong; unmet rules are listed below.",
+            ))
+            .child(PasswordInput::new("password-default").placeholder("New password"));
+        container = container.child(interactive_section);
+
+        // Strength levels
+        let strength_section = section("Strength Levels", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Weak, Fair, Good, and Strong against the default rules."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(PasswordInput::new("password-weak").default_value("abc"))
+                    .child(PasswordInput::new("password-fair").default_value("abcdefg1"))
+                    .child(PasswordInput::new("password-good").default_value("Abcdefg1"))
+                    .child(
+                        PasswordInput::new("password-strong")
+                            .default_value("Abcdef1!")
+                            .default_revealed(true),
+                    ),
+            );
+        container = container.child(strength_section);
+
+        // Custom rules
+        let rules_section = section("Custom Rules", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("A passphrase policy: 16 characters and a digit."),
+            )
+            .child(
+                PasswordInput::new("password-passphrase")
+                    .placeholder("Passphrase")
+                    .rules([PasswordRule::MinLength(16), PasswordRule::Digit]),
+            );
+        container = container.child(rules_section);
+
+        // Errors and disabled
+        let states_section = section("Error and Disabled", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "An external error_message replaces rule messages; the meter can be hidden.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        PasswordInput::new("password-error")
+                            .default_value("Abcdef1!")
+                            .show_meter(false)
+                            .error_message("Incorrect password"),
+                    )
+                    .child(
+                        PasswordInput::new("password-disabled")
+                            .default_value("Abcdef1!")
+                            .disabled(true),
+                    ),
+            );
+        container = container.child(states_section);
+
+        container.into_any_element()
+    }
+}
@@ -0,0 +1,215 @@
+//! ProgressBar story: demonstrates values, sizes, status colors, and the
+//! fill easing between value updates.
+
+use std::time::Duration;
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{
+    Button, ButtonVariant, ComponentContract, ComponentState, ProgressBar, ProgressSize,
+    ProgressStatus,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct ProgressBarStory;
+
+impl Story for ProgressBarStory {
+    fn name(&self) -> &'static str {
+        "ProgressBar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Determinate progress from 0 to 1 with size presets, status colors, and \
+         an eased fill when the value changes."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        ProgressBar::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Values
+        let values_section = section("Values", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Empty, partial, and complete. Values outside 0..1 are clamped."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(ProgressBar::new("progress-empty").show_percent(true))
+                    .child(
+                        ProgressBar::new("progress-partial")
+                            .value(0.42)
+                            .label("Exporting tokens")
+                            .show_percent(true),
+                    )
+                    .child(
+                        ProgressBar::new("progress-complete")
+                            .value(1.5)
+                            .status(ProgressStatus::Success)
+                            .label("Export complete")
+                            .show_percent(true),
+                    ),
+            );
+        container = container.child(values_section);
+
+        // Value updates
+        container = container.child(updates_section(window, cx));
+
+        // Sizes
+        let sizes_section = section("Sizes", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Small (4px), Medium (8px, default), and Large (12px) tracks."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        ProgressBar::new("progress-small")
+                            .size(ProgressSize::Small)
+                            .value(0.6)
+                            .width(px(320.0)),
+                    )
+                    .child(
+                        ProgressBar::new("progress-medium")
+                            .value(0.6)
+                            .width(px(320.0)),
+                    )
+                    .child(
+                        ProgressBar::new("progress-large")
+                            .size(ProgressSize::Large)
+                            .value(0.6)
+                            .width(px(320.0)),
+                    ),
+            );
+        container = container.child(sizes_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_progress_bar_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Step, reset, and simulate a running task; the fill eases to each value.
+fn updates_section(window: &mut Window, cx: &mut App) -> Div {
+    let progress = window.use_keyed_state("progress-story-value", cx, |_, _| 0.0f32);
+    let value = *progress.read(cx);
+    let muted_color = cx.theme().text.muted;
+
+    let controls = div()
+        .flex()
+        .flex_row()
+        .gap_2()
+        .child({
+            let progress = progress.clone();
+            Button::new("progress-step")
+                .label("+10%")
+                .on_click(move |_, _window, cx| {
+                    let value = *progress.read(cx);
+                    set_progress(&progress, value + 0.1, cx);
+                })
+        })
+        .child({
+            let progress = progress.clone();
+            Button::new("progress-run")
+                .label("Simulate Upload")
+                .on_click(move |_, _window, cx| {
+                    set_progress(&progress, 0.0, cx);
+                    let progress = progress.clone();
+                    cx.spawn(async move |cx| {
+                        for step in 1..=20 {
+                            cx.background_executor()
+                                .timer(Duration::from_millis(150))
+                                .await;
+                            let updated = progress.update(cx, |progress, cx| {
+                                *progress = step as f32 / 20.0;
+                                cx.notify();
+                            });
+                            if updated.is_err() {
+                                break;
+                            }
+                        }
+                    })
+                    .detach();
+                })
+        })
+        .child({
+            let progress = progress.clone();
+            Button::new("progress-reset")
+                .label("Reset")
+                .variant(ButtonVariant::Ghost)
+                .on_click(move |_, _window, cx| set_progress(&progress, 0.0, cx))
+        });
+
+    section("Value Updates", cx)
+        .child(div().text_xs().text_color(muted_color).child(
+            "Step the value or simulate an upload; the fill eases from the previous \
+             value instead of jumping.",
+        ))
+        .child(controls)
+        .child(
+            ProgressBar::new("progress-live")
+                .value(value)
+                .status(if value >= 1.0 {
+                    ProgressStatus::Success
+                } else {
+                    ProgressStatus::Info
+                })
+                .label(if value >= 1.0 {
+                    "Upload complete"
+                } else {
+                    "Uploading assets"
+                })
+                .show_percent(true)
+                .width(px(360.0)),
+        )
+}
+
+/// Store a new story progress value, clamped to `0.0..=1.0`.
+fn set_progress(progress: &Entity<f32>, value: f32, cx: &mut App) {
+    progress.update(cx, |progress, cx| {
+        *progress = value.clamp(0.0, 1.0);
+        cx.notify();
+    });
+}
+
+/// Render a state matrix cell for a given ProgressBar state and status.
+fn render_progress_bar_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("progress-matrix-{state:?}-{variant:?}"));
+    let status = match variant {
+        Some("Success") => ProgressStatus::Success,
+        Some("Warning") => ProgressStatus::Warning,
+        Some("Error") => ProgressStatus::Error,
+        _ => ProgressStatus::Info,
+    };
+    ProgressBar::new(id)
+        .value(0.6)
+        .status(status)
+        .width(px(96.0))
+        .into_any_element()
+}
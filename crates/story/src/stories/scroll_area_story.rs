@@ -0,0 +1,152 @@
+//! ScrollArea story: demonstrates themed scrollbars on vertical, horizontal,
+//! and two-axis content, and scrolling from code.
+
+use crate::{Story, matrix::section};
+use components::{
+    Button, ButtonSize, ButtonVariant, ComponentContract, ScrollArea, ScrollAreaHandle, ScrollAxes,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct ScrollAreaStory;
+
+/// Rows in the long list.
+const ROWS: usize = 60;
+
+/// A fixed-size frame for an area to fill.
+fn frame(cx: &App) -> Div {
+    div()
+        .h(px(180.0))
+        .w(px(360.0))
+        .border_1()
+        .border_color(cx.theme().border.default)
+}
+
+/// One row of the long list.
+fn row(ix: usize, cx: &App) -> Div {
+    let theme = cx.theme();
+    div()
+        .flex_none()
+        .px_3()
+        .py_1()
+        .text_sm()
+        .text_color(theme.text.default)
+        .border_b_1()
+        .border_color(theme.border.variant)
+        .child(format!("Row {}", ix + 1))
+}
+
+impl Story for ScrollAreaStory {
+    fn name(&self) -> &'static str {
+        "ScrollArea"
+    }
+
+    fn description(&self) -> &'static str {
+        "Overflow content with themed scrollbars: hoverable, draggable thumbs, \
+         pageable tracks, and programmatic scroll-to."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        ScrollArea::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Vertical
+        let vertical_section = section("Vertical", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Scroll with the wheel, drag the thumb, or press the track to \
+                 page. The thumb lightens while hovered or dragged.",
+            ))
+            .child(frame(cx).child(
+                ScrollArea::new("scroll-vertical").children((0..ROWS).map(|ix| row(ix, cx))),
+            ));
+        container = container.child(vertical_section);
+
+        // Horizontal and both axes
+        let wide = |label: &'static str| {
+            div()
+                .flex_none()
+                .w(px(900.0))
+                .p_3()
+                .text_sm()
+                .text_color(text_color)
+                .child(label)
+        };
+        let axes_section = section("Horizontal and Both Axes", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Only axes whose content overflows get a scrollbar; with both, \
+                 the bars stop short of the corner.",
+            ))
+            .child(
+                frame(cx).h(px(64.0)).child(
+                    ScrollArea::new("scroll-horizontal")
+                        .axes(ScrollAxes::Horizontal)
+                        .child(wide("A 900px line scrolls sideways in a 360px area.")),
+                ),
+            )
+            .child(
+                frame(cx).child(
+                    ScrollArea::new("scroll-both")
+                        .axes(ScrollAxes::Both)
+                        .children(
+                            (0..ROWS).map(|_| wide("Wide and tall content scrolls both ways.")),
+                        ),
+                ),
+            );
+        container = container.child(axes_section);
+
+        // Programmatic
+        let handle = window
+            .use_keyed_state("scroll-area-story-handle", cx, |_, _| {
+                ScrollAreaHandle::new()
+            })
+            .read(cx)
+            .clone();
+        let jump = |id: &'static str, label: &'static str, scroll: fn(&ScrollAreaHandle)| {
+            let handle = handle.clone();
+            Button::new(id)
+                .label(label)
+                .variant(ButtonVariant::Secondary)
+                .size(ButtonSize::Small)
+                .on_click(move |_, window, _| {
+                    scroll(&handle);
+                    window.refresh();
+                })
+        };
+        let programmatic_section = section("Scrolling from Code", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "A ScrollAreaHandle passed to track_scroll scrolls the area from \
+                 code; offsets are clamped to the content.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_2()
+                    .child(jump("scroll-story-top", "Top", |h| h.scroll_to_top()))
+                    .child(jump("scroll-story-row", "Row 30", |h| h.scroll_to_item(29)))
+                    .child(jump("scroll-story-down", "Down 100px", |h| {
+                        h.scroll_by(point(px(0.0), px(100.0)))
+                    }))
+                    .child(jump("scroll-story-bottom", "Bottom", |h| {
+                        h.scroll_to_bottom()
+                    })),
+            )
+            .child(
+                frame(cx).child(
+                    ScrollArea::new("scroll-programmatic")
+                        .track_scroll(&handle)
+                        .children((0..ROWS).map(|ix| row(ix, cx))),
+                ),
+            );
+        container = container.child(programmatic_section);
+
+        container.into_any_element()
+    }
+}
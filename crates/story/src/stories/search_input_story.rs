@@ -0,0 +1,108 @@
+//! SearchInput story: demonstrates search field states and debounced filtering.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, SearchInput};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Sample data filtered by the interactive example.
+const FRUITS: &[&str] = &[
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Mango",
+    "Orange",
+    "Peach",
+    "Pear",
+];
+
+pub struct SearchInputStory;
+
+impl Story for SearchInputStory {
+    fn name(&self) -> &'static str {
+        "SearchInput"
+    }
+
+    fn description(&self) -> &'static str {
+        "Search field with magnifier, Escape-to-clear, debounced on_query, and result count."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        SearchInput::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // States
+        let states_section = section("States", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Empty, with query and result count, and disabled."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(SearchInput::new("search-empty").placeholder("Search components"))
+                    .child(
+                        SearchInput::new("search-filled")
+                            .value("button")
+                            .result_count(3),
+                    )
+                    .child(
+                        SearchInput::new("search-disabled")
+                            .placeholder("Search disabled")
+                            .disabled(true),
+                    ),
+            );
+        container = container.child(states_section);
+
+        // Interactive filtering: the list updates once typing pauses.
+        let filter = window.use_keyed_state("search-story-filter", cx, |_, _| String::new());
+        let query = filter.read(cx).to_lowercase();
+        let matches: Vec<&str> = FRUITS
+            .iter()
+            .copied()
+            .filter(|fruit| fruit.to_lowercase().contains(&query))
+            .collect();
+
+        let mut results = div().flex().flex_col().gap_1();
+        for fruit in &matches {
+            results = results.child(div().text_sm().text_color(text_color).child(*fruit));
+        }
+
+        let interactive_section = section("Debounced Filtering", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Click and type. on_query fires 150ms after the last keystroke."),
+            )
+            .child(
+                SearchInput::new("search-fruits")
+                    .placeholder("Filter fruits")
+                    .result_count(matches.len())
+                    .on_query(move |query, cx| {
+                        filter.update(cx, |filter, cx| {
+                            *filter = query.to_string();
+                            cx.notify();
+                        });
+                    }),
+            )
+            .child(results);
+        container = container.child(interactive_section);
+
+        container.into_any_element()
+    }
+}
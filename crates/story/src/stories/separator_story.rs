@@ -0,0 +1,115 @@
+//! Separator story: demonstrates horizontal, vertical, and labeled rules.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Separator};
+use gpui::*;
+use primitives::Orientation;
+use theme::ActiveTheme;
+
+pub struct SeparatorStory;
+
+impl Story for SeparatorStory {
+    fn name(&self) -> &'static str {
+        "Separator"
+    }
+
+    fn description(&self) -> &'static str {
+        "Horizontal or vertical rule dividing content, with an optional label \
+         in the middle."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Separator::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let text_color = theme.text.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Horizontal
+        let horizontal_section = section("Horizontal", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Divides stacked content; a label sits in a gap in the rule."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .w(px(320.0))
+                    .text_sm()
+                    .text_color(text_color)
+                    .child("Sign in with a passkey")
+                    .child(Separator::new("separator-labeled").label("or"))
+                    .child("Sign in with email")
+                    .child(Separator::new("separator-plain"))
+                    .child("Create an account"),
+            );
+        container = container.child(horizontal_section);
+
+        // Vertical
+        let vertical_section = section("Vertical", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Divides content side by side, spanning the row's height."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_3()
+                    .h(px(24.0))
+                    .text_sm()
+                    .text_color(text_color)
+                    .child("Cut")
+                    .child("Copy")
+                    .child(Separator::new("separator-vertical").orientation(Orientation::Vertical))
+                    .child("Paste"),
+            );
+        container = container.child(vertical_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_separator_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Separator state and orientation.
+fn render_separator_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("separator-matrix-{state:?}-{variant:?}"));
+    let vertical = variant == Some("Vertical");
+    let separator = Separator::new(id)
+        .label("or")
+        .disabled(state == ComponentState::Disabled)
+        .orientation(if vertical {
+            Orientation::Vertical
+        } else {
+            Orientation::Horizontal
+        });
+    let frame = div().flex().items_center().justify_center();
+    let frame = if vertical {
+        frame.h(px(64.0))
+    } else {
+        frame.w(px(120.0))
+    };
+    frame.child(separator).into_any_element()
+}
@@ -0,0 +1,159 @@
+//! Skeleton story: demonstrates the shapes and skeletons composed into cards.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Skeleton, SkeletonShape};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct SkeletonStory;
+
+impl Story for SkeletonStory {
+    fn name(&self) -> &'static str {
+        "Skeleton"
+    }
+
+    fn description(&self) -> &'static str {
+        "Shimmering line, circle, and rectangle placeholders that hold the \
+         layout of content while it loads."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Skeleton::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+        let card_bg = theme.surface.surface;
+        let card_border = theme.border.default;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Shapes
+        let shapes_section = section("Shapes", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Line (12px tall, default), Circle (32px), and Rect (64px tall)."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_4()
+                    .w(px(480.0))
+                    .child(div().w(px(160.0)).child(Skeleton::new("skeleton-line")))
+                    .child(Skeleton::new("skeleton-circle").shape(SkeletonShape::Circle))
+                    .child(
+                        Skeleton::new("skeleton-rect")
+                            .shape(SkeletonShape::Rect)
+                            .width(px(120.0)),
+                    ),
+            );
+        container = container.child(shapes_section);
+
+        // Composed cards
+        let card = |ix: usize| {
+            div()
+                .flex()
+                .flex_col()
+                .gap_3()
+                .w(px(240.0))
+                .p_3()
+                .rounded_lg()
+                .border_1()
+                .border_color(card_border)
+                .bg(card_bg)
+                .child(
+                    Skeleton::new(SharedString::from(format!("skeleton-card-{ix}-image")))
+                        .shape(SkeletonShape::Rect)
+                        .height(px(96.0)),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap_2()
+                        .child(
+                            Skeleton::new(SharedString::from(format!("skeleton-card-{ix}-avatar")))
+                                .shape(SkeletonShape::Circle),
+                        )
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .flex_1()
+                                .gap_1p5()
+                                .child(
+                                    Skeleton::new(SharedString::from(format!(
+                                        "skeleton-card-{ix}-name"
+                                    )))
+                                    .width(px(120.0)),
+                                )
+                                .child(
+                                    Skeleton::new(SharedString::from(format!(
+                                        "skeleton-card-{ix}-meta"
+                                    )))
+                                    .width(px(72.0))
+                                    .height(px(8.0)),
+                                ),
+                        ),
+                )
+                .child(Skeleton::new(SharedString::from(format!(
+                    "skeleton-card-{ix}-line-1"
+                ))))
+                .child(
+                    Skeleton::new(SharedString::from(format!("skeleton-card-{ix}-line-2")))
+                        .width(px(160.0)),
+                )
+        };
+        let cards_section = section("Loading Cards", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Skeletons composed into the layout of the cards they stand in for."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .gap_4()
+                    .children((0..3).map(card)),
+            );
+        container = container.child(cards_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_skeleton_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Skeleton state and shape.
+fn render_skeleton_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("skeleton-matrix-{state:?}-{variant:?}"));
+    let shape = match variant {
+        Some("Circle") => SkeletonShape::Circle,
+        Some("Rect") => SkeletonShape::Rect,
+        _ => SkeletonShape::Line,
+    };
+    let skeleton = Skeleton::new(id).shape(shape);
+    match shape {
+        SkeletonShape::Circle => skeleton.into_any_element(),
+        _ => skeleton.width(px(96.0)).into_any_element(),
+    }
+}
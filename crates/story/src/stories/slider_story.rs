@@ -0,0 +1,164 @@
+//! Slider story: demonstrates orientations, ranges and steps, keyboard
+//! stepping, and the disabled state.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Slider};
+use gpui::*;
+use primitives::Orientation;
+use theme::ActiveTheme;
+
+pub struct SliderStory;
+
+impl Story for SliderStory {
+    fn name(&self) -> &'static str {
+        "Slider"
+    }
+
+    fn description(&self) -> &'static str {
+        "Numeric range picker with drag, click-to-jump, and arrow-key stepping \
+         in horizontal or vertical orientation."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Slider::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Horizontal
+        let horizontal_section =
+            section("Horizontal", cx)
+                .child(div().text_xs().text_color(muted_color).child(
+                    "Drag the thumb or press anywhere on the track. 0 to 100 in steps of 1.",
+                ))
+                .child(
+                    Slider::new("slider-horizontal")
+                        .default_value(40.0)
+                        .show_value(true),
+                );
+        container = container.child(horizontal_section);
+
+        // Steps
+        let steps_section = section("Ranges and Steps", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Values snap to the step. The label shows as many decimals as the step needs; \
+                 a step of 0 is continuous.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        Slider::new("slider-quarters")
+                            .range(0.0, 1.0)
+                            .step(0.25)
+                            .default_value(0.5)
+                            .show_value(true),
+                    )
+                    .child(
+                        Slider::new("slider-temperature")
+                            .range(-20.0, 40.0)
+                            .step(5.0)
+                            .default_value(20.0)
+                            .show_value(true),
+                    )
+                    .child(
+                        Slider::new("slider-continuous")
+                            .range(0.0, 1.0)
+                            .step(0.0)
+                            .default_value(0.3)
+                            .length(px(320.0)),
+                    ),
+            );
+        container = container.child(steps_section);
+
+        // Keyboard
+        let keyboard_section = section("Keyboard Stepping", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Tab to focus. Right/Up add a step and Left/Down remove one; \
+                 Page Up/Page Down move 10 steps; Home/End jump to the ends.",
+            ))
+            .child(
+                Slider::new("slider-keyboard")
+                    .step(10.0)
+                    .default_value(50.0)
+                    .show_value(true),
+            );
+        container = container.child(keyboard_section);
+
+        // Vertical
+        let vertical_section = section("Vertical", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("The maximum is at the top; Up increases in both orientations."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_6()
+                    .child(
+                        Slider::new("slider-vertical")
+                            .orientation(Orientation::Vertical)
+                            .length(px(120.0))
+                            .default_value(70.0)
+                            .show_value(true),
+                    )
+                    .child(
+                        Slider::new("slider-vertical-disabled")
+                            .orientation(Orientation::Vertical)
+                            .length(px(120.0))
+                            .default_value(30.0)
+                            .disabled(true),
+                    ),
+            );
+        container = container.child(vertical_section);
+
+        // Disabled
+        let disabled_section = section("Disabled", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Disabled sliders ignore pointer and keyboard input."),
+            )
+            .child(
+                Slider::new("slider-disabled")
+                    .default_value(60.0)
+                    .show_value(true)
+                    .disabled(true),
+            );
+        container = container.child(disabled_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_slider_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Slider state.
+fn render_slider_state_cell(state: ComponentState) -> AnyElement {
+    let id = SharedString::from(format!("slider-matrix-{state:?}"));
+    Slider::new(id)
+        .default_value(50.0)
+        .length(px(96.0))
+        .disabled(state == ComponentState::Disabled)
+        .into_any_element()
+}
@@ -0,0 +1,106 @@
+//! SortableList story: demonstrates reordering rows by pointer drag and from
+//! the keyboard.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, SortableItem, SortableList};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct SortableListStory;
+
+/// Release checklist steps, in their starting order.
+const STEPS: [(&str, &str); 5] = [
+    ("Write changelog", "Summarize user-facing changes"),
+    ("Bump version", "Update Cargo.toml and the registry"),
+    ("Run verify", "Check every theme for contrast and coverage"),
+    ("Tag release", "Push the signed tag"),
+    ("Announce", "Post the release notes"),
+];
+
+/// Layer names, in their starting order.
+const LAYERS: [&str; 4] = ["Header", "Sidebar", "Canvas", "Overlay"];
+
+impl Story for SortableListStory {
+    fn name(&self) -> &'static str {
+        "SortableList"
+    }
+
+    fn description(&self) -> &'static str {
+        "A list reordered by dragging rows, with a drop indicator, or from the \
+         keyboard by grabbing a row with Space."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        SortableList::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Pointer and keyboard reordering of described rows.
+        let steps = window.use_keyed_state("sortable-story-steps", cx, |_, _| STEPS.to_vec());
+        let current_steps = steps.read(cx).clone();
+        let last_move = window.use_keyed_state("sortable-story-last", cx, |_, _| None::<String>);
+        let last_text = last_move
+            .read(cx)
+            .clone()
+            .unwrap_or_else(|| "No moves yet".to_string());
+        let steps_section = section("Reorder", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Drag a row by any part of it. Or focus the list, press Space to \
+                 grab a row, Up/Down to move it, and Space again to drop it \
+                 (Escape puts it back).",
+            ))
+            .child(
+                div().w(px(360.0)).child(
+                    SortableList::new("sortable-steps")
+                        .items(current_steps.iter().map(|(label, description)| {
+                            SortableItem::new(*label).description(*description)
+                        }))
+                        .on_reorder(move |reorder, window, cx| {
+                            let label = steps.update(cx, |steps, _| {
+                                reorder.apply(steps);
+                                steps[reorder.to].0
+                            });
+                            last_move.update(cx, |last, _| {
+                                *last = Some(format!(
+                                    "Moved \"{label}\" from {} to {}",
+                                    reorder.from + 1,
+                                    reorder.to + 1
+                                ));
+                            });
+                            window.refresh();
+                        }),
+                ),
+            )
+            .child(div().text_xs().text_color(muted_color).child(last_text));
+        container = container.child(steps_section);
+
+        // Labels only
+        let layers = window.use_keyed_state("sortable-story-layers", cx, |_, _| LAYERS.to_vec());
+        let current_layers = layers.read(cx).clone();
+        let layers_section = section("Labels Only", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Rows without descriptions, such as a layer stack."),
+            )
+            .child(
+                div().w(px(240.0)).child(
+                    SortableList::new("sortable-layers")
+                        .items(current_layers.into_iter().map(SortableItem::new))
+                        .on_reorder(move |reorder, window, cx| {
+                            layers.update(cx, |layers, _| reorder.apply(layers));
+                            window.refresh();
+                        }),
+                ),
+            );
+        container = container.child(layers_section);
+
+        container.into_any_element()
+    }
+}
@@ -0,0 +1,86 @@
+//! Sparkline story: demonstrates series shapes and the threshold line.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, Sparkline};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Frame times (ms) with a spike past the 60fps budget.
+const FRAME_TIMES: &[f64] = &[
+    8.2, 8.9, 9.4, 8.7, 10.1, 9.8, 12.4, 18.9, 14.2, 10.3, 9.1, 8.8, 9.6, 9.2, 8.5, 8.9,
+];
+
+pub struct SparklineStory;
+
+impl Story for SparklineStory {
+    fn name(&self) -> &'static str {
+        "Sparkline"
+    }
+
+    fn description(&self) -> &'static str {
+        "Compact token-styled line chart with an optional dashed threshold."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Sparkline::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Shapes
+        let rising: Vec<f64> = (0..16).map(|i| i as f64).collect();
+        let wave: Vec<f64> = (0..32).map(|i| (i as f64 / 4.0).sin()).collect();
+        let shapes_section = section("Series", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Rising, periodic, and flat series scale to the chart height."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_4()
+                    .child(Sparkline::new("spark-rising", rising))
+                    .child(Sparkline::new("spark-wave", wave))
+                    .child(Sparkline::new("spark-flat", [4.0; 8])),
+            );
+        container = container.child(shapes_section);
+
+        // Threshold
+        let threshold_section = section("Frame Budget Threshold", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("A 16.7ms budget line; the line turns warning-colored past it."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(
+                        Sparkline::new("spark-budget", FRAME_TIMES.iter().copied())
+                            .threshold(16.7)
+                            .size(px(240.0), px(40.0)),
+                    )
+                    .child(
+                        Sparkline::new(
+                            "spark-over-budget",
+                            FRAME_TIMES.iter().copied().chain([21.5]),
+                        )
+                        .threshold(16.7)
+                        .size(px(240.0), px(40.0)),
+                    ),
+            );
+        container = container.child(threshold_section);
+
+        container.into_any_element()
+    }
+}
@@ -0,0 +1,115 @@
+//! Spinner story: demonstrates sizes, status colors, and labeled spinners.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, ProgressSize, ProgressStatus, Spinner};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct SpinnerStory;
+
+impl Story for SpinnerStory {
+    fn name(&self) -> &'static str {
+        "Spinner"
+    }
+
+    fn description(&self) -> &'static str {
+        "Indeterminate rotating indicator for work of unknown length, with size \
+         presets and status colors."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Spinner::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Sizes
+        let sizes_section = section("Sizes", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Small (12px), Medium (16px, default), and Large (24px)."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_4()
+                    .child(Spinner::new("spinner-small").size(ProgressSize::Small))
+                    .child(Spinner::new("spinner-medium"))
+                    .child(Spinner::new("spinner-large").size(ProgressSize::Large)),
+            );
+        container = container.child(sizes_section);
+
+        // Status colors
+        let status_section = section("Status Colors", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("The arc takes the status foreground color."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Spinner::new("spinner-info").label("Loading themes…"))
+                    .child(
+                        Spinner::new("spinner-success")
+                            .status(ProgressStatus::Success)
+                            .label("Finishing up…"),
+                    )
+                    .child(
+                        Spinner::new("spinner-warning")
+                            .status(ProgressStatus::Warning)
+                            .label("Retrying connection…"),
+                    )
+                    .child(
+                        Spinner::new("spinner-error")
+                            .status(ProgressStatus::Error)
+                            .label("Rolling back…"),
+                    ),
+            );
+        container = container.child(status_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_spinner_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Spinner state and size.
+fn render_spinner_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("spinner-matrix-{state:?}-{variant:?}"));
+    let size = match variant {
+        Some("Small") => ProgressSize::Small,
+        Some("Large") => ProgressSize::Large,
+        _ => ProgressSize::Medium,
+    };
+    let status = if state == ComponentState::Error {
+        ProgressStatus::Error
+    } else {
+        ProgressStatus::Info
+    };
+    Spinner::new(id)
+        .size(size)
+        .status(status)
+        .into_any_element()
+}
@@ -0,0 +1,141 @@
+//! SplitPane story: demonstrates horizontal and vertical splits, an anchored
+//! second pane, pane limits, and collapsible panes.
+
+use crate::{Story, matrix::section};
+use components::{ComponentContract, SplitPane};
+use gpui::*;
+use primitives::{Orientation, Pane};
+use theme::ActiveTheme;
+
+pub struct SplitPaneStory;
+
+/// A labelled pane filling its side of the split.
+fn pane(label: &'static str, cx: &App) -> Div {
+    let theme = cx.theme();
+    div()
+        .size_full()
+        .p_3()
+        .bg(theme.surface.surface)
+        .text_sm()
+        .text_color(theme.text.muted)
+        .child(label)
+}
+
+/// A fixed-height frame for a split to fill.
+fn frame(cx: &App) -> Div {
+    div()
+        .h(px(180.0))
+        .w_full()
+        .border_1()
+        .border_color(cx.theme().border.default)
+}
+
+impl Story for SplitPaneStory {
+    fn name(&self) -> &'static str {
+        "SplitPane"
+    }
+
+    fn description(&self) -> &'static str {
+        "Two panes with a draggable, keyboard-resizable divider, min/max pane \
+         sizes, and panes that collapse to zero."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        SplitPane::contract()
+    }
+
+    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Horizontal
+        let horizontal_section = section("Horizontal", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Drag the divider, or click it and press Left/Right \
+                 (Shift for bigger steps, Home/End for the limits).",
+            ))
+            .child(
+                frame(cx).child(
+                    SplitPane::new("split-horizontal")
+                        .first(pane("Sidebar", cx))
+                        .second(pane("Editor", cx))
+                        .default_size(px(200.0)),
+                ),
+            );
+        container = container.child(horizontal_section);
+
+        // Vertical
+        let vertical_section = section("Vertical", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Vertical splits stack the panes and resize with Up/Down."),
+            )
+            .child(
+                frame(cx).child(
+                    SplitPane::new("split-vertical")
+                        .orientation(Orientation::Vertical)
+                        .first(pane("Preview", cx))
+                        .second(pane("Console", cx))
+                        .default_size(px(110.0)),
+                ),
+            );
+        container = container.child(vertical_section);
+
+        // Anchored second pane
+        let anchor_section = section("Anchored Second Pane", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "The inspector keeps its 160px as the split grows, as a right-hand \
+                 dock does; the content takes the rest.",
+            ))
+            .child(
+                frame(cx).child(
+                    SplitPane::new("split-anchor")
+                        .first(pane("Content", cx))
+                        .second(pane("Inspector", cx))
+                        .anchor(Pane::Second)
+                        .default_size(px(160.0)),
+                ),
+            );
+        container = container.child(anchor_section);
+
+        // Limits
+        let limits_section = section("Limits", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "The first pane stays between 120px and 320px, and the second \
+                 never drops below 160px.",
+            ))
+            .child(
+                frame(cx).child(
+                    SplitPane::new("split-limits")
+                        .first(pane("120–320px", cx))
+                        .second(pane("At least 160px", cx))
+                        .first_limits(px(120.0), px(320.0))
+                        .second_limits(px(160.0), px(f32::INFINITY)),
+                ),
+            );
+        container = container.child(limits_section);
+
+        // Collapsible
+        let collapsible_section = section("Collapsible", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Drag the divider past half of the 160px minimum, press Enter, \
+                 or double-click it to collapse the sidebar; repeat to restore.",
+            ))
+            .child(
+                frame(cx).child(
+                    SplitPane::new("split-collapsible")
+                        .first(pane("Collapsible sidebar", cx))
+                        .second(pane("Content", cx))
+                        .first_limits(px(160.0), px(360.0))
+                        .collapsible(Pane::First),
+                ),
+            );
+        container = container.child(collapsible_section);
+
+        container.into_any_element()
+    }
+}
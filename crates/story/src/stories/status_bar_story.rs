@@ -0,0 +1,139 @@
+//! StatusBar story: demonstrates slots, status indicators, and interactive
+//! segments.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, StatusBar, StatusIndicator, StatusSegment};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct StatusBarStory;
+
+impl Story for StatusBarStory {
+    fn name(&self) -> &'static str {
+        "StatusBar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Window-bottom bar with left, center, and right slots of text segments, \
+         status-colored indicators, and clickable segments."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        StatusBar::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Slots
+        let slots_section = section("Slots", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Left segments hug the start, right segments the end, and center segments sit between them."),
+            )
+            .child(
+                StatusBar::new("status-bar-slots")
+                    .left(StatusSegment::new("branch", "main"))
+                    .left(StatusSegment::new("cursor", "Ln 12, Col 4"))
+                    .center(StatusSegment::new("mode", "Editing"))
+                    .right(StatusSegment::new("encoding", "UTF-8"))
+                    .right(StatusSegment::new("language", "Rust")),
+            );
+        container = container.child(slots_section);
+
+        // Indicators
+        let indicators_section = section("Indicators", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("The dot takes the status foreground color."),
+            )
+            .child(
+                StatusBar::new("status-bar-indicators")
+                    .left(StatusSegment::new("info", "Indexing…").indicator(StatusIndicator::Info))
+                    .left(
+                        StatusSegment::new("success", "Build passed")
+                            .indicator(StatusIndicator::Success),
+                    )
+                    .right(
+                        StatusSegment::new("warning", "3 warnings")
+                            .indicator(StatusIndicator::Warning),
+                    )
+                    .right(
+                        StatusSegment::new("error", "1 error")
+                            .indicator(StatusIndicator::Error)
+                            .tooltip("expected `;`, found `}` at theme.rs:42"),
+                    ),
+            );
+        container = container.child(indicators_section);
+
+        // Interactive segments
+        let clicks = window.use_keyed_state("status-bar-story-clicks", cx, |_, _| 0usize);
+        let count = *clicks.read(cx);
+        let on_click = {
+            let clicks = clicks.clone();
+            move |window: &mut Window, cx: &mut App| {
+                clicks.update(cx, |count, _| *count += 1);
+                window.refresh();
+            }
+        };
+        let interactive_section = section("Interactive Segments", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Segments with a click handler highlight on hover and take focus with Tab; Space or Enter activates them."),
+            )
+            .child(
+                StatusBar::new("status-bar-interactive")
+                    .left(
+                        StatusSegment::new("counter", format!("{count} clicks"))
+                            .on_click(on_click)
+                            .tooltip("Click to count"),
+                    )
+                    .left(StatusSegment::new("static", "Not clickable"))
+                    .right(
+                        StatusSegment::new("reset", "Reset")
+                            .indicator(StatusIndicator::Info)
+                            .on_click(move |window, cx| {
+                                clicks.update(cx, |count, _| *count = 0);
+                                window.refresh();
+                            }),
+                    ),
+            );
+        container = container.child(interactive_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, _variant, _window, _cx| render_status_bar_state_cell(state),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given StatusBar state.
+fn render_status_bar_state_cell(state: ComponentState) -> AnyElement {
+    let id = SharedString::from(format!("status-bar-matrix-{state:?}"));
+    div()
+        .w(px(240.0))
+        .child(
+            StatusBar::new(id)
+                .left(StatusSegment::new("theme", "One Dark").on_click(|_window, _cx| {}))
+                .right(StatusSegment::new("fps", "60 fps").indicator(StatusIndicator::Success)),
+        )
+        .into_any_element()
+}
@@ -0,0 +1,148 @@
+//! Switch story: demonstrates on/off states, sizes, and controlled versus
+//! uncontrolled use.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Switch, SwitchSize};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct SwitchStory;
+
+impl Story for SwitchStory {
+    fn name(&self) -> &'static str {
+        "Switch"
+    }
+
+    fn description(&self) -> &'static str {
+        "On/off toggle with a sliding thumb, sizes, and controlled or uncontrolled state."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Switch::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // States
+        let states_section = section("Switch States", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Off, on, and disabled. Click or press Space/Enter to watch the thumb slide.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(Switch::new("switch-off").label("Off"))
+                    .child(Switch::new("switch-on").label("On").default_checked(true))
+                    .child(
+                        Switch::new("switch-disabled-off")
+                            .label("Disabled (off)")
+                            .disabled(true),
+                    )
+                    .child(
+                        Switch::new("switch-disabled-on")
+                            .label("Disabled (on)")
+                            .checked(true)
+                            .disabled(true),
+                    ),
+            );
+        container = container.child(states_section);
+
+        // Sizes
+        let sizes_section = section("Sizes", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Small, Medium (default), and Large."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap_4()
+                    .child(
+                        Switch::new("switch-small")
+                            .size(SwitchSize::Small)
+                            .label("Small"),
+                    )
+                    .child(Switch::new("switch-medium").label("Medium"))
+                    .child(
+                        Switch::new("switch-large")
+                            .size(SwitchSize::Large)
+                            .label("Large")
+                            .default_checked(true),
+                    ),
+            );
+        container = container.child(sizes_section);
+
+        // Controlled
+        let enabled = window.use_keyed_state("switch-story-controlled", cx, |_, _| true);
+        let is_enabled = *enabled.read(cx);
+        let controlled_switch = |id: &'static str, label: &'static str| {
+            let enabled = enabled.clone();
+            Switch::new(id)
+                .label(label)
+                .checked(is_enabled)
+                .on_change(move |on, _window, cx| {
+                    enabled.update(cx, |enabled, cx| {
+                        *enabled = on;
+                        cx.notify();
+                    });
+                })
+        };
+        let controlled_section = section("Controlled", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Both switches render one value owned by the story; toggling either \
+                 updates the other.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_3()
+                    .child(controlled_switch("switch-controlled-a", "Notifications"))
+                    .child(controlled_switch(
+                        "switch-controlled-b",
+                        "Notifications (mirror)",
+                    )),
+            );
+        container = container.child(controlled_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_switch_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Switch state and size.
+fn render_switch_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("switch-matrix-{state:?}-{variant:?}"));
+    let size = match variant {
+        Some("Small") => SwitchSize::Small,
+        Some("Large") => SwitchSize::Large,
+        _ => SwitchSize::Medium,
+    };
+    Switch::new(id)
+        .size(size)
+        .label(format!("{state:?}"))
+        .default_checked(state == ComponentState::Selected)
+        .disabled(state == ComponentState::Disabled)
+        .into_any_element()
+}
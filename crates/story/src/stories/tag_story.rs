@@ -0,0 +1,146 @@
+//! Tag story: demonstrates removable tags, status colors, sizes, and the
+//! disabled state.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{
+    BadgeSize, BadgeStatus, Button, ButtonVariant, ComponentContract, ComponentState, Tag,
+};
+use gpui::*;
+use theme::ActiveTheme;
+
+/// Tags the removable list starts with.
+const INITIAL_TAGS: [&str; 5] = ["rust", "gpui", "design-tokens", "accessibility", "themes"];
+
+pub struct TagStory;
+
+impl Story for TagStory {
+    fn name(&self) -> &'static str {
+        "Tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Chip for filters and labels. With on_remove it shows an × that removes it."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Tag::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let muted_color = cx.theme().text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Removable
+        let tags = window.use_keyed_state("tag-story-tags", cx, |_, _| INITIAL_TAGS.to_vec());
+        let current = tags.read(cx).clone();
+        let mut list = div().flex().flex_row().flex_wrap().gap_2();
+        for label in current.iter().copied() {
+            let tags = tags.clone();
+            list = list.child(
+                Tag::new(SharedString::from(format!("tag-live-{label}")), label)
+                    .status(BadgeStatus::Info)
+                    .on_remove(move |window, cx| {
+                        tags.update(cx, |tags, _| tags.retain(|tag| *tag != label));
+                        window.refresh();
+                    }),
+            );
+        }
+        if current.is_empty() {
+            list = list.child(div().text_sm().text_color(muted_color).child("No tags"));
+        }
+        let removable_section = section("Removable", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Click the × or focus it and press Enter, Space, Backspace, or Delete. \
+                 The parent owns the list and drops the tag in on_remove.",
+            ))
+            .child(list)
+            .child(
+                Button::new("tag-live-reset")
+                    .label("Reset")
+                    .variant(ButtonVariant::Ghost)
+                    .on_click(move |_, window, cx| {
+                        tags.update(cx, |tags, _| *tags = INITIAL_TAGS.to_vec());
+                        window.refresh();
+                    }),
+            );
+        container = container.child(removable_section);
+
+        // Statuses
+        let statuses_section = section("Statuses", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Tags share Badge's status colors and size presets."),
+            )
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_wrap()
+                    .gap_2()
+                    .child(Tag::new("tag-neutral", "draft"))
+                    .child(Tag::new("tag-info", "in review").status(BadgeStatus::Info))
+                    .child(Tag::new("tag-success", "shipped").status(BadgeStatus::Success))
+                    .child(Tag::new("tag-warning", "deprecated").status(BadgeStatus::Warning))
+                    .child(Tag::new("tag-error", "blocked").status(BadgeStatus::Error)),
+            );
+        container = container.child(statuses_section);
+
+        // Sizes
+        let sizes_section = section("Sizes", cx).child(
+            div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap_2()
+                .child(sized("tag-small", BadgeSize::Small))
+                .child(sized("tag-medium", BadgeSize::Medium))
+                .child(sized("tag-large", BadgeSize::Large)),
+        );
+        container = container.child(sizes_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_tag_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A removable tag at `size` that ignores removal.
+fn sized(id: &'static str, size: BadgeSize) -> Tag {
+    Tag::new(id, "filter")
+        .size(size)
+        .on_remove(|_window, _cx| {})
+}
+
+/// Render a state matrix cell for a given Tag state and size.
+fn render_tag_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let id = SharedString::from(format!("tag-matrix-{state:?}-{variant:?}"));
+    let size = match variant {
+        Some("Small") => BadgeSize::Small,
+        Some("Large") => BadgeSize::Large,
+        _ => BadgeSize::Medium,
+    };
+    let status = if state == ComponentState::Error {
+        BadgeStatus::Error
+    } else {
+        BadgeStatus::Neutral
+    };
+    Tag::new(id, "filter")
+        .size(size)
+        .status(status)
+        .disabled(state == ComponentState::Disabled)
+        .on_remove(|_window, _cx| {})
+        .into_any_element()
+}
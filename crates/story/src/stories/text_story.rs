@@ -0,0 +1,101 @@
+//! Text story: demonstrates the type scale, weights, and muted and
+//! disabled colors.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{ComponentContract, ComponentState, Text, TextSize, TextWeight};
+use gpui::*;
+
+pub struct TextStory;
+
+const SIZES: [(TextSize, &str); 5] = [
+    (TextSize::XSmall, "XSmall"),
+    (TextSize::Small, "Small"),
+    (TextSize::Base, "Base"),
+    (TextSize::Large, "Large"),
+    (TextSize::XLarge, "XLarge"),
+];
+
+impl Story for TextStory {
+    fn name(&self) -> &'static str {
+        "Text"
+    }
+
+    fn description(&self) -> &'static str {
+        "Styled copy on the type scale, with weight and muted variants."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        Text::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Sizes
+        let mut sizes_section = section("Sizes", cx);
+        for (size, name) in SIZES {
+            sizes_section = sizes_section.child(
+                Text::new(
+                    SharedString::from(format!("text-size-{name}")),
+                    format!("{name}: The quick brown fox"),
+                )
+                .size(size),
+            );
+        }
+        container = container.child(sizes_section);
+
+        // Weights
+        let weights_section = section("Weights", cx).child(
+            div()
+                .flex()
+                .flex_row()
+                .gap_4()
+                .child(Text::new("text-normal", "Normal"))
+                .child(Text::new("text-medium", "Medium").weight(TextWeight::Medium))
+                .child(Text::new("text-semibold", "Semibold").weight(TextWeight::Semibold))
+                .child(Text::new("text-bold", "Bold").weight(TextWeight::Bold)),
+        );
+        container = container.child(weights_section);
+
+        // Colors
+        let colors_section = section("Colors", cx).child(
+            div()
+                .flex()
+                .flex_col()
+                .gap_1()
+                .child(Text::new("text-default", "Default copy"))
+                .child(Text::new("text-muted", "Muted secondary copy").muted(true))
+                .child(Text::new("text-disabled", "Disabled copy").disabled(true)),
+        );
+        container = container.child(colors_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, _cx| render_text_state_cell(state, variant),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// Render a state matrix cell for a given Text state and size.
+fn render_text_state_cell(state: ComponentState, variant: Option<&str>) -> AnyElement {
+    let size = SIZES
+        .iter()
+        .find(|(_, name)| Some(*name) == variant)
+        .map_or(TextSize::default(), |(size, _)| *size);
+    Text::new(
+        SharedString::from(format!("text-matrix-{state:?}-{variant:?}")),
+        "Aa",
+    )
+    .size(size)
+    .disabled(state == ComponentState::Disabled)
+    .into_any_element()
+}
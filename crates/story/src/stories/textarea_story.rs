@@ -43,11 +43,11 @@ impl Story for TextareaStory {
                 div()
                     .text_xs()
                     .text_color(muted_color)
-                    .child("Pre-filled textarea with 5 rows."),
+                    .child("Pre-filled, editable textarea with 5 rows. IME preedit text is underlined until committed."),
             )
             .child(
                 Textarea::new("value-textarea")
-                    .value("Line 1\nLine 2\nLine 3")
+                    .default_value("Line 1\nLine 2\nLine 3")
                     .rows(5),
             );
         container = container.child(value_section);
@@ -62,7 +62,7 @@ impl Story for TextareaStory {
             )
             .child(
                 Textarea::new("error-textarea")
-                    .value("Too short")
+                    .default_value("Too short")
                     .error_message("Minimum 50 characters required"),
             );
         container = container.child(error_section);
@@ -0,0 +1,128 @@
+//! TitleBar story: demonstrates the per-platform window-control layouts and
+//! the start/end content slots.
+
+use crate::{
+    Story,
+    matrix::{StateMatrix, section},
+};
+use components::{Button, ComponentContract, ComponentState, TitleBar, TitleBarPlatform};
+use gpui::*;
+use theme::ActiveTheme;
+
+pub struct TitleBarStory;
+
+impl Story for TitleBarStory {
+    fn name(&self) -> &'static str {
+        "TitleBar"
+    }
+
+    fn description(&self) -> &'static str {
+        "Window-top bar that moves the window when dragged and leaves room for \
+         traffic lights or draws caption buttons, depending on the platform."
+    }
+
+    fn contract(&self) -> ComponentContract {
+        TitleBar::contract()
+    }
+
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
+        let theme = cx.theme();
+        let muted_color = theme.text.muted;
+
+        let mut container = div().flex().flex_col().gap_6().p_4().w_full();
+
+        // Platforms
+        let mut platforms =
+            section("Platforms", cx).child(div().text_xs().text_color(muted_color).child(
+                "macOS reserves the leading edge for the system traffic lights. \
+                 Windows always draws caption buttons; Linux draws them only when \
+                 the app decorates its own window. Dragging a preview moves this window.",
+            ));
+        for platform in [
+            TitleBarPlatform::Mac,
+            TitleBarPlatform::Linux,
+            TitleBarPlatform::Windows,
+        ] {
+            platforms = platforms.child(
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap_1()
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(muted_color)
+                            .child(format!("{platform:?}")),
+                    )
+                    .child(sample_title_bar(
+                        SharedString::from(format!("title-bar-{platform:?}")),
+                        platform,
+                        cx,
+                    )),
+            );
+        }
+        container = container.child(platforms);
+
+        // Slots
+        let slots_section = section("Slots", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Start content follows the leading inset; end content sits before the caption buttons."),
+            )
+            .child(
+                TitleBar::new("title-bar-slots")
+                    .height(px(40.0))
+                    .start(div().text_sm().font_weight(FontWeight::BOLD).child("Project"))
+                    .start(div().text_xs().text_color(muted_color).child("main"))
+                    .end(Button::new("title-bar-share").label("Share"))
+                    .end(Button::new("title-bar-run").label("Run")),
+            );
+        container = container.child(slots_section);
+
+        // State Matrix
+        let matrix = StateMatrix::from_contract(&self.contract());
+        let matrix_element = matrix.render(
+            |state, variant, _window, cx| render_title_bar_state_cell(state, variant, cx),
+            window,
+            cx,
+        );
+        container = container.child(matrix_element);
+
+        container.into_any_element()
+    }
+}
+
+/// A title bar with an app name, for the platform previews.
+fn sample_title_bar(id: SharedString, platform: TitleBarPlatform, cx: &App) -> TitleBar {
+    let muted_color = cx.theme().text.muted;
+    TitleBar::new(id)
+        .platform(platform)
+        .start(
+            div()
+                .text_sm()
+                .font_weight(FontWeight::BOLD)
+                .child("Workbench"),
+        )
+        .end(div().text_xs().text_color(muted_color).child("v0.1.0"))
+}
+
+/// Render a state matrix cell for a given TitleBar state and platform variant.
+fn render_title_bar_state_cell(
+    state: ComponentState,
+    variant: Option<&str>,
+    cx: &App,
+) -> AnyElement {
+    let id = SharedString::from(format!("title-bar-matrix-{state:?}-{variant:?}"));
+    let platform = match variant {
+        Some("Mac") => TitleBarPlatform::Mac,
+        Some("Linux") => TitleBarPlatform::Linux,
+        Some("Windows") => TitleBarPlatform::Windows,
+        _ => TitleBarPlatform::current(),
+    };
+    div()
+        .w(px(320.0))
+        .child(sample_title_bar(id, platform, cx))
+        .into_any_element()
+}
@@ -1,7 +1,12 @@
 //! Toast story: demonstrates all Toast variants and configurations.
 
+use std::time::{Duration, SystemTime};
+
 use crate::{Story, matrix::section};
-use components::{ComponentContract, Toast, ToastVariant};
+use components::{
+    Button, ButtonVariant, ComponentContract, Toast, ToastEntry, ToastManager, ToastPosition,
+    ToastVariant,
+};
 use gpui::*;
 use theme::ActiveTheme;
 
@@ -13,14 +18,15 @@ impl Story for ToastStory {
     }
 
     fn description(&self) -> &'static str {
-        "Transient notification with Info, Success, Warning, Error variants and action buttons."
+        "Transient notification with Info, Success, Warning, Error variants, action buttons, \
+         timestamps, progress, and live toasts through the app-wide ToastManager."
     }
 
     fn contract(&self) -> ComponentContract {
         Toast::contract()
     }
 
-    fn render_story(&self, _window: &mut Window, cx: &mut App) -> AnyElement {
+    fn render_story(&self, window: &mut Window, cx: &mut App) -> AnyElement {
         let theme = cx.theme();
         let muted_color = theme.text.muted;
 
@@ -72,17 +78,35 @@ impl Story for ToastStory {
                 div()
                     .text_xs()
                     .text_color(muted_color)
-                    .child("Toast with an action button."),
+                    .child("Toast with an action button and a timestamp."),
             )
             .child(
                 Toast::new("action-toast")
                     .title("File deleted")
                     .description("document.txt has been moved to trash.")
+                    .timestamp(SystemTime::now() - Duration::from_secs(5 * 60))
                     .action("Undo", |_window, _cx| {})
                     .variant(ToastVariant::Info),
             );
         container = container.child(action_section);
 
+        // Progress
+        let progress_section = section("Progress", cx)
+            .child(
+                div()
+                    .text_xs()
+                    .text_color(muted_color)
+                    .child("Progress toast tracking a background task."),
+            )
+            .child(
+                Toast::new("progress-toast")
+                    .title("Exporting tokens")
+                    .description("theme-dark.json")
+                    .progress(0.6)
+                    .show_dismiss(false),
+            );
+        container = container.child(progress_section);
+
         // Without dismiss
         let no_dismiss_section = section("Without Dismiss Button", cx)
             .child(
@@ -100,6 +124,105 @@ impl Story for ToastStory {
             );
         container = container.child(no_dismiss_section);
 
+        container = container.child(live_section(window, cx));
+
         container.into_any_element()
     }
 }
+
+/// Live toasts through the app-wide ToastManager, drawn by the studio's
+/// ToastLayer: push toasts and watch them stack, queue, and dismiss.
+fn live_section(window: &mut Window, cx: &mut App) -> Div {
+    // Numbers pushed toasts so each gets its own id.
+    let counter = window.use_keyed_state("toast-story-counter", cx, |_, _| 0usize);
+    let muted_color = cx.theme().text.muted;
+
+    let push = |id: &'static str, label: &'static str, make: fn(usize) -> ToastEntry| {
+        let counter = counter.clone();
+        Button::new(id)
+            .label(label)
+            .on_click(move |_, _window, cx| {
+                let n = counter.update(cx, |n, _| {
+                    *n += 1;
+                    *n
+                });
+                ToastManager::show(make(n), cx);
+            })
+    };
+
+    let controls = div()
+        .flex()
+        .flex_row()
+        .flex_wrap()
+        .gap_2()
+        .child(push("push-info", "Info", |n| {
+            ToastEntry::new(format!("info-{n}"), format!("Notification {n}"))
+                .timestamp(SystemTime::now())
+        }))
+        .child(push("push-undo", "With Undo", |n| {
+            ToastEntry::new(format!("undo-{n}"), "File deleted")
+                .description("document.txt has been moved to trash.")
+                .variant(ToastVariant::Success)
+                .action("Undo", |_window, _cx| {})
+        }))
+        .child(push("push-error", "Error", |n| {
+            ToastEntry::new(format!("error-{n}"), "Build failed")
+                .description("Errors stay until dismissed.")
+                .variant(ToastVariant::Error)
+        }))
+        .child(
+            Button::new("advance-progress")
+                .label("Advance Export")
+                .on_click(move |_, _window, cx| {
+                    let progress = cx
+                        .try_global::<ToastManager>()
+                        .and_then(|m| m.get("export"))
+                        .and_then(|t| t.progress)
+                        .map_or(0.0, |p| p + 0.25);
+                    let entry = ToastEntry::new("export", "Exporting tokens")
+                        .description(format!("{:.0}% complete", progress.min(1.0) * 100.0))
+                        .variant(if progress >= 1.0 {
+                            ToastVariant::Success
+                        } else {
+                            ToastVariant::Info
+                        })
+                        .progress(progress);
+                    ToastManager::show(entry, cx);
+                }),
+        )
+        .child(
+            Button::new("dismiss-all")
+                .label("Dismiss All")
+                .variant(ButtonVariant::Ghost)
+                .on_click(|_, _window, cx| ToastManager::hide_all(cx)),
+        );
+
+    let (position, queued) = cx
+        .try_global::<ToastManager>()
+        .map_or((ToastPosition::default(), 0), |m| {
+            (m.current_position(), m.queued_len())
+        });
+    let corners = div()
+        .flex()
+        .flex_row()
+        .gap_2()
+        .children(ToastPosition::ALL.into_iter().map(|corner| {
+            Button::new(SharedString::from(format!("toast-corner-{corner:?}")))
+                .label(corner.label())
+                .variant(if corner == position {
+                    ButtonVariant::Primary
+                } else {
+                    ButtonVariant::Secondary
+                })
+                .on_click(move |_, _window, cx| ToastManager::move_to(corner, cx))
+        }));
+
+    section("Live Toasts", cx)
+        .child(div().text_xs().text_color(muted_color).child(format!(
+            "Shown in the window corner, newest nearest the edge, at most 3 at once; \
+             {queued} queued. Info and Success toasts dismiss after 5s, paused while \
+             the pointer is over them; same-id pushes update in place."
+        )))
+        .child(controls)
+        .child(corners)
+}
@@ -1,7 +1,7 @@
 //! Tooltip story: demonstrates tooltip placements and configurations.
 
 use crate::{Story, matrix::section};
-use components::{ComponentContract, Tooltip, TooltipPlacement};
+use components::{Button, ComponentContract, Tooltip, TooltipPlacement, WithTooltip};
 use gpui::*;
 use theme::ActiveTheme;
 
@@ -63,6 +63,34 @@ impl Story for TooltipStory {
             );
         container = container.child(placements_section);
 
+        // Attached tooltips
+        let attached = [
+            ("Top", TooltipPlacement::Top),
+            ("Bottom", TooltipPlacement::Bottom),
+            ("Left", TooltipPlacement::Left),
+            ("Right", TooltipPlacement::Right),
+        ]
+        .into_iter()
+        .map(|(label, placement)| {
+            Button::new(SharedString::from(format!("tooltip-story-{label}")))
+                .label(label)
+                .tooltip(format!("{label} tooltip"))
+                .tooltip_placement(placement)
+        });
+        let attached_section = section("Attached Tooltips", cx)
+            .child(div().text_xs().text_color(muted_color).child(
+                "Hover a button: its tooltip opens on the chosen side after the hover delay.",
+            ))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap_4()
+                    .items_center()
+                    .children(attached),
+            );
+        container = container.child(attached_section);
+
         // Long text tooltip
         let long_section = section("Long Text", cx)
             .child(
@@ -6,19 +6,55 @@
 
 use story::*;
 
-/// Helper: create a registry with all 12 component stories registered.
+/// Helper: create a registry with all 48 component stories registered.
 fn full_registry() -> StoryRegistry {
     let mut registry = StoryRegistry::new();
+    registry.register(AvatarStory);
+    registry.register(AvatarGroupStory);
+    registry.register(BadgeStory);
+    registry.register(BarChartStory);
+    registry.register(BreadcrumbsStory);
     registry.register(ButtonStory);
+    registry.register(CardStory);
     registry.register(CheckboxStory);
+    registry.register(CommandPaletteStory);
+    registry.register(ContextMenuStory);
+    registry.register(DataTableStory);
+    registry.register(DescriptionListStory);
     registry.register(DialogStory);
     registry.register(DropdownMenuStory);
+    registry.register(EmptyStateStory);
+    registry.register(FilePickerStory);
+    registry.register(FormStory);
     registry.register(InputStory);
+    registry.register(KbdStory);
+    registry.register(LabelStory);
+    registry.register(LinkStory);
+    registry.register(MarkdownStory);
+    registry.register(MenuBarStory);
+    registry.register(NotificationCenterStory);
+    registry.register(NumberInputStory);
+    registry.register(PasswordInputStory);
     registry.register(PopoverStory);
+    registry.register(ProgressBarStory);
     registry.register(RadioStory);
+    registry.register(ScrollAreaStory);
+    registry.register(SearchInputStory);
     registry.register(SelectStory);
+    registry.register(SeparatorStory);
+    registry.register(SkeletonStory);
+    registry.register(SliderStory);
+    registry.register(SortableListStory);
+    registry.register(SparklineStory);
+    registry.register(SpinnerStory);
+    registry.register(SplitPaneStory);
+    registry.register(StatusBarStory);
+    registry.register(SwitchStory);
     registry.register(TabsStory);
+    registry.register(TagStory);
+    registry.register(TextStory);
     registry.register(TextareaStory);
+    registry.register(TitleBarStory);
     registry.register(ToastStory);
     registry.register(TooltipStory);
     registry
@@ -27,16 +63,52 @@ fn full_registry() -> StoryRegistry {
 /// Helper: all stories as boxed trait objects.
 fn all_stories() -> Vec<Box<dyn Story>> {
     vec![
+        Box::new(AvatarStory),
+        Box::new(AvatarGroupStory),
+        Box::new(BadgeStory),
+        Box::new(BarChartStory),
+        Box::new(BreadcrumbsStory),
         Box::new(ButtonStory),
+        Box::new(CardStory),
         Box::new(CheckboxStory),
+        Box::new(CommandPaletteStory),
+        Box::new(ContextMenuStory),
+        Box::new(DataTableStory),
+        Box::new(DescriptionListStory),
         Box::new(DialogStory),
         Box::new(DropdownMenuStory),
+        Box::new(EmptyStateStory),
+        Box::new(FilePickerStory),
+        Box::new(FormStory),
         Box::new(InputStory),
+        Box::new(KbdStory),
+        Box::new(LabelStory),
+        Box::new(LinkStory),
+        Box::new(MarkdownStory),
+        Box::new(MenuBarStory),
+        Box::new(NotificationCenterStory),
+        Box::new(NumberInputStory),
+        Box::new(PasswordInputStory),
         Box::new(PopoverStory),
+        Box::new(ProgressBarStory),
         Box::new(RadioStory),
+        Box::new(ScrollAreaStory),
+        Box::new(SearchInputStory),
         Box::new(SelectStory),
+        Box::new(SeparatorStory),
+        Box::new(SkeletonStory),
+        Box::new(SliderStory),
+        Box::new(SortableListStory),
+        Box::new(SparklineStory),
+        Box::new(SpinnerStory),
+        Box::new(SplitPaneStory),
+        Box::new(StatusBarStory),
+        Box::new(SwitchStory),
         Box::new(TabsStory),
+        Box::new(TagStory),
+        Box::new(TextStory),
         Box::new(TextareaStory),
+        Box::new(TitleBarStory),
         Box::new(ToastStory),
         Box::new(TooltipStory),
     ]
@@ -53,17 +125,51 @@ fn registry_starts_empty() {
 fn registry_register_and_lookup() {
     let registry = full_registry();
 
-    assert_eq!(registry.len(), 12);
+    assert_eq!(registry.len(), 48);
+    assert!(registry.get("Badge").is_some());
+    assert!(registry.get("BarChart").is_some());
+    assert!(registry.get("Breadcrumbs").is_some());
     assert!(registry.get("Button").is_some());
+    assert!(registry.get("Card").is_some());
     assert!(registry.get("Checkbox").is_some());
+    assert!(registry.get("CommandPalette").is_some());
+    assert!(registry.get("ContextMenu").is_some());
+    assert!(registry.get("DataTable").is_some());
+    assert!(registry.get("DescriptionList").is_some());
     assert!(registry.get("Dialog").is_some());
     assert!(registry.get("DropdownMenu").is_some());
+    assert!(registry.get("EmptyState").is_some());
+    assert!(registry.get("FilePicker").is_some());
+    assert!(registry.get("Form").is_some());
     assert!(registry.get("Input").is_some());
+    assert!(registry.get("Kbd").is_some());
+    assert!(registry.get("Label").is_some());
+    assert!(registry.get("Link").is_some());
+    assert!(registry.get("Markdown").is_some());
+    assert!(registry.get("MenuBar").is_some());
+    assert!(registry.get("NotificationCenter").is_some());
+    assert!(registry.get("NumberInput").is_some());
+    assert!(registry.get("PasswordInput").is_some());
     assert!(registry.get("Popover").is_some());
+    assert!(registry.get("ProgressBar").is_some());
     assert!(registry.get("Radio").is_some());
+    assert!(registry.get("ScrollArea").is_some());
+    assert!(registry.get("SearchInput").is_some());
     assert!(registry.get("Select").is_some());
+    assert!(registry.get("Separator").is_some());
+    assert!(registry.get("Skeleton").is_some());
+    assert!(registry.get("Slider").is_some());
+    assert!(registry.get("SortableList").is_some());
+    assert!(registry.get("Sparkline").is_some());
+    assert!(registry.get("Spinner").is_some());
+    assert!(registry.get("SplitPane").is_some());
+    assert!(registry.get("StatusBar").is_some());
+    assert!(registry.get("Switch").is_some());
     assert!(registry.get("Tabs").is_some());
+    assert!(registry.get("Tag").is_some());
+    assert!(registry.get("Text").is_some());
     assert!(registry.get("Textarea").is_some());
+    assert!(registry.get("TitleBar").is_some());
     assert!(registry.get("Toast").is_some());
     assert!(registry.get("Tooltip").is_some());
     assert!(registry.get("Nonexistent").is_none());
@@ -76,22 +182,88 @@ fn registry_names() {
     assert_eq!(
         names,
         vec![
+            "Avatar",
+            "AvatarGroup",
+            "Badge",
+            "BarChart",
+            "Breadcrumbs",
             "Button",
+            "Card",
             "Checkbox",
+            "CommandPalette",
+            "ContextMenu",
+            "DataTable",
+            "DescriptionList",
             "Dialog",
             "DropdownMenu",
+            "EmptyState",
+            "FilePicker",
+            "Form",
             "Input",
+            "Kbd",
+            "Label",
+            "Link",
+            "Markdown",
+            "MenuBar",
+            "NotificationCenter",
+            "NumberInput",
+            "PasswordInput",
             "Popover",
+            "ProgressBar",
             "Radio",
+            "ScrollArea",
+            "SearchInput",
             "Select",
+            "Separator",
+            "Skeleton",
+            "Slider",
+            "SortableList",
+            "Sparkline",
+            "Spinner",
+            "SplitPane",
+            "StatusBar",
+            "Switch",
             "Tabs",
+            "Tag",
+            "Text",
             "Textarea",
+            "TitleBar",
             "Toast",
             "Tooltip",
         ]
     );
 }
 
+#[test]
+fn primitive_docs_are_listed_apart_from_stories() {
+    let mut registry = full_registry();
+    for contract in primitives::contract::all_contracts() {
+        registry.register_primitive(contract);
+    }
+
+    assert_eq!(
+        registry.len(),
+        48,
+        "primitive docs are not component stories"
+    );
+    assert_eq!(
+        registry.primitive_docs().len(),
+        primitives::contract::all_contracts().len()
+    );
+    let trap = registry.get_primitive_doc("FocusTrap").unwrap();
+    assert!(!trap.summary().is_empty());
+    for doc in registry.primitive_docs() {
+        for consumer in &doc.contract().consumers {
+            assert!(
+                registry.get(consumer).is_some(),
+                "Primitive '{}' consumer '{}' has no story",
+                doc.name(),
+                consumer
+            );
+        }
+    }
+}
+
 #[test]
 fn story_entries_have_valid_contracts() {
     let registry = full_registry();
@@ -126,6 +298,49 @@ fn all_stories_have_descriptions() {
     }
 }
 
+/// A story with a German description.
+struct TranslatedStory;
+
+impl Story for TranslatedStory {
+    fn name(&self) -> &'static str {
+        "Translated"
+    }
+
+    fn description(&self) -> &'static str {
+        "A clickable button."
+    }
+
+    fn description_translations(&self) -> &'static [(&'static str, &'static str)] {
+        &[("de-DE", "Eine klickbare Schaltfläche.")]
+    }
+
+    fn contract(&self) -> components::ComponentContract {
+        components::Button::contract()
+    }
+
+    fn render_story(&self, _window: &mut gpui::Window, _cx: &mut gpui::App) -> gpui::AnyElement {
+        gpui::IntoElement::into_any_element(gpui::div())
+    }
+}
+
+#[test]
+fn story_descriptions_follow_the_locale() {
+    let entry = StoryEntry::new(TranslatedStory);
+    assert_eq!(
+        entry.localized_description("de-DE"),
+        "Eine klickbare Schaltfläche."
+    );
+    assert_eq!(
+        entry.localized_description("de-CH"),
+        "Eine klickbare Schaltfläche."
+    );
+    assert_eq!(entry.localized_description("fr-FR"), "A clickable button.");
+    assert_eq!(
+        StoryEntry::new(ButtonStory).localized_description("de-DE"),
+        ButtonStory.description()
+    );
+}
+
 #[test]
 fn state_matrix_from_button_contract() {
     let contract = components::Button::contract();
@@ -0,0 +1,207 @@
+//! Derived themes: generate a complete token set from a few seed colors.
+//!
+//! A [`ThemeSeed`] names a background and an accent color (for example, the
+//! dominant colors extracted from a brand image by `gpui theme from-image`).
+//! [`derive_theme`] builds a neutral ramp from the background hue for
+//! surfaces, elements, borders, and text, uses the accent for focus, links,
+//! and selection, and keeps status and syntax colors from the built-in theme
+//! of the same appearance so semantic colors stay recognizable.
+
+use gpui::{Hsla, hsla};
+use serde::{Deserialize, Serialize};
+
+use crate::tokens::{ThemeAppearance, ThemeTokens, one_dark, one_light};
+
+/// Upper bound on the saturation of derived neutrals, so a vivid background
+/// color tints surfaces without turning them into accents.
+const MAX_NEUTRAL_SATURATION: f32 = 0.2;
+
+/// Seed colors for a derived theme.
+///
+/// Serialized as JSON with `#rrggbbaa` colors; unknown fields (such as the
+/// extracted palette written alongside by the CLI) are ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ThemeSeed {
+    pub name: String,
+    pub appearance: ThemeAppearance,
+    pub background: Hsla,
+    pub accent: Hsla,
+}
+
+/// Generate a full token set from a seed.
+pub fn derive_theme(seed: &ThemeSeed) -> ThemeTokens {
+    let dark = seed.appearance == ThemeAppearance::Dark;
+    let mut tokens = if dark { one_dark() } else { one_light() };
+    tokens.name = seed.name.clone();
+
+    // Neutral ramp: the background hue at low saturation, stepping towards
+    // the foreground (lighter on dark themes, darker on light ones).
+    let hue = seed.background.h;
+    let saturation = seed.background.s.min(MAX_NEUTRAL_SATURATION);
+    let (base, towards_fg) = if dark {
+        (seed.background.l.clamp(0.08, 0.22), 1.0)
+    } else {
+        (seed.background.l.clamp(0.88, 0.98), -1.0)
+    };
+    let neutral = |step: f32| {
+        hsla(
+            hue,
+            saturation,
+            (base + towards_fg * step).clamp(0.0, 1.0),
+            1.0,
+        )
+    };
+
+    // Keep the accent's hue and saturation but move it into a lightness band
+    // that reads against the derived background.
+    let accent_l = if dark {
+        seed.accent.l.clamp(0.55, 0.75)
+    } else {
+        seed.accent.l.clamp(0.35, 0.5)
+    };
+    let accent = hsla(seed.accent.h, seed.accent.s, accent_l, 1.0);
+    let accent_tint = hsla(
+        seed.accent.h,
+        seed.accent.s * 0.5,
+        (base + towards_fg * 0.12).clamp(0.0, 1.0),
+        1.0,
+    );
+    let transparent = hsla(0.0, 0.0, 0.0, 0.0);
+
+    let editor = neutral(0.0);
+    let surface = neutral(0.04);
+    let chrome = neutral(0.08);
+
+    tokens.border.default = neutral(0.2);
+    tokens.border.variant = neutral(0.1);
+    tokens.border.focused = accent;
+    tokens.border.selected = accent_tint;
+    tokens.border.transparent = transparent;
+    tokens.border.disabled = neutral(0.16);
+
+    tokens.surface.background = chrome;
+    tokens.surface.surface = surface;
+    tokens.surface.elevated_surface = surface;
+
+    tokens.element.background = neutral(0.03);
+    tokens.element.hover = neutral(0.1);
+    tokens.element.active = neutral(0.16);
+    tokens.element.selected = neutral(0.16);
+    tokens.element.disabled = neutral(0.03);
+
+    tokens.ghost_element.background = transparent;
+    tokens.ghost_element.hover = neutral(0.1);
+    tokens.ghost_element.active = neutral(0.16);
+    tokens.ghost_element.selected = neutral(0.16);
+    tokens.ghost_element.disabled = neutral(0.03);
+
+    tokens.text.default = neutral(0.75);
+    tokens.text.muted = neutral(0.58);
+    tokens.text.placeholder = neutral(0.42);
+    tokens.text.disabled = neutral(0.42);
+    tokens.text.accent = accent;
+
+    tokens.icon.default = neutral(0.75);
+    tokens.icon.muted = neutral(0.58);
+    tokens.icon.disabled = neutral(0.42);
+    tokens.icon.placeholder = neutral(0.58);
+    tokens.icon.accent = accent;
+
+    tokens.tab.bar_background = surface;
+    tokens.tab.inactive_background = surface;
+    tokens.tab.active_background = editor;
+
+    tokens.panel.background = surface;
+    tokens.panel.focused_border = None;
+
+    tokens.chrome.title_bar_background = chrome;
+    tokens.chrome.status_bar_background = chrome;
+    tokens.chrome.toolbar_background = editor;
+
+    tokens.scrollbar.thumb_background = Hsla {
+        a: 0.3,
+        ..neutral(0.75)
+    };
+    tokens.scrollbar.thumb_hover_background = neutral(0.1);
+    tokens.scrollbar.thumb_border = neutral(0.1);
+    tokens.scrollbar.track_background = transparent;
+    tokens.scrollbar.track_border = neutral(0.02);
+
+    tokens.player.cursor = accent;
+    tokens.player.background = accent;
+    tokens.player.selection = Hsla { a: 0.24, ..accent };
+
+    tokens.link.hover = accent;
+
+    tokens
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::parse_hex_color;
+
+    fn seed(appearance: ThemeAppearance, background: &str, accent: &str) -> ThemeSeed {
+        ThemeSeed {
+            name: "Brand".into(),
+            appearance,
+            background: parse_hex_color(background),
+            accent: parse_hex_color(accent),
+        }
+    }
+
+    #[test]
+    fn dark_seed_derives_readable_dark_theme() {
+        let tokens = derive_theme(&seed(ThemeAppearance::Dark, "#1b2a3aff", "#ff7a1aff"));
+
+        assert_eq!(tokens.name, "Brand");
+        assert_eq!(tokens.appearance, ThemeAppearance::Dark);
+        assert!(tokens.text.default.l > 0.8);
+        assert!(tokens.surface.background.l > tokens.tab.active_background.l);
+        assert!(tokens.element.hover.l > tokens.element.background.l);
+        assert!((tokens.border.focused.h - tokens.text.accent.h).abs() < f32::EPSILON);
+        assert_eq!(tokens.border.transparent.a, 0.0);
+    }
+
+    #[test]
+    fn light_seed_steps_towards_dark_foreground() {
+        let tokens = derive_theme(&seed(ThemeAppearance::Light, "#f4efe6ff", "#2a6fdbff"));
+
+        assert_eq!(tokens.appearance, ThemeAppearance::Light);
+        assert!(tokens.text.default.l < 0.3);
+        assert!(tokens.surface.background.l < tokens.tab.active_background.l);
+        assert!(tokens.text.accent.l <= 0.5);
+    }
+
+    #[test]
+    fn status_and_syntax_come_from_builtin_theme() {
+        let tokens = derive_theme(&seed(ThemeAppearance::Dark, "#101010ff", "#00ff88ff"));
+        let builtin = one_dark();
+
+        assert_eq!(
+            tokens.status.error.foreground,
+            builtin.status.error.foreground
+        );
+        assert_eq!(tokens.syntax.keyword, builtin.syntax.keyword);
+    }
+
+    #[test]
+    fn seed_json_ignores_extra_fields() {
+        let json = r##"{
+            "name": "Sunset",
+            "appearance": "light",
+            "background": "#f4efe6ff",
+            "accent": "#d9480fff",
+            "palette": ["#f4efe6ff", "#d9480fff"]
+        }"##;
+        let seed: ThemeSeed = serde_json::from_str(json).expect("seed parses");
+        assert_eq!(seed.name, "Sunset");
+        assert_eq!(seed.appearance, ThemeAppearance::Light);
+
+        assert!(serde_json::from_str::<ThemeSeed>("{}").is_err());
+    }
+}
@@ -10,11 +10,14 @@
 
 use std::collections::HashMap;
 use std::ops::{Deref, DerefMut};
+use std::path::{Path, PathBuf};
 
 use gpui::{App, Global, Hsla};
 use serde_json;
 
-use crate::tokens::{self, ThemeTokens, parse_hex_color};
+use crate::derive::{ThemeSeed, derive_theme};
+use crate::tokens::{self, ThemeAppearance, ThemeTokens, parse_hex_color};
+use crate::vision::ColorVisionDeficiency;
 
 // ---------------------------------------------------------------------------
 // Theme (active theme global)
@@ -24,9 +27,14 @@ use crate::tokens::{self, ThemeTokens, parse_hex_color};
 ///
 /// `Theme` wraps [`ThemeTokens`] and implements `Deref`/`DerefMut` to it,
 /// allowing direct field access like `theme.border.default`.
+///
+/// While a color vision simulation is active, the deref target is the
+/// simulated token set; the unfiltered tokens are kept alongside so theme
+/// switches, token edits, and exports operate on the real values.
 #[derive(Debug, Clone)]
 pub struct Theme {
     tokens: ThemeTokens,
+    simulation: Option<(ColorVisionDeficiency, ThemeTokens)>,
 }
 
 impl Global for Theme {}
@@ -48,7 +56,10 @@ impl DerefMut for Theme {
 impl Theme {
     /// Create a new `Theme` wrapping the given token set.
     pub fn new(tokens: ThemeTokens) -> Self {
-        Self { tokens }
+        Self {
+            tokens,
+            simulation: None,
+        }
     }
 
     /// Returns a reference to the global `Theme`.
@@ -69,10 +80,60 @@ impl Theme {
     }
 
     /// Returns a mutable reference to the inner [`ThemeTokens`].
+    ///
+    /// With a color vision simulation active these are the simulated tokens;
+    /// edits made through them are discarded when the simulation changes.
     pub fn tokens_mut(&mut self) -> &mut ThemeTokens {
         &mut self.tokens
     }
 
+    /// Returns the tokens without any color vision simulation applied.
+    pub fn unfiltered_tokens(&self) -> &ThemeTokens {
+        self.simulation
+            .as_ref()
+            .map_or(&self.tokens, |(_, unfiltered)| unfiltered)
+    }
+
+    /// Returns the active color vision simulation, if any.
+    pub fn color_vision(&self) -> Option<ColorVisionDeficiency> {
+        self.simulation.as_ref().map(|(kind, _)| *kind)
+    }
+
+    /// Replace the unfiltered tokens, re-applying the active simulation.
+    fn set_unfiltered(&mut self, tokens: ThemeTokens) {
+        match self.simulation.as_mut() {
+            Some((kind, unfiltered)) => {
+                self.tokens = kind.simulate_theme(&tokens);
+                *unfiltered = tokens;
+            }
+            None => self.tokens = tokens,
+        }
+    }
+
+    // -- Color vision simulation -------------------------------------------
+
+    /// Render the active theme as seen with a color vision deficiency, or
+    /// restore the unfiltered tokens with `None`, and refresh all windows.
+    pub fn set_color_vision(kind: Option<ColorVisionDeficiency>, cx: &mut App) {
+        cx.global_mut::<Theme>().apply_color_vision(kind);
+        cx.refresh_windows();
+    }
+
+    /// Switch this theme's color vision simulation (`None` disables it).
+    pub fn apply_color_vision(&mut self, kind: Option<ColorVisionDeficiency>) {
+        let unfiltered = match self.simulation.take() {
+            Some((_, unfiltered)) => unfiltered,
+            None => self.tokens.clone(),
+        };
+        match kind {
+            Some(kind) => {
+                self.tokens = kind.simulate_theme(&unfiltered);
+                self.simulation = Some((kind, unfiltered));
+            }
+            None => self.tokens = unfiltered,
+        }
+    }
+
     // -- Theme switching ---------------------------------------------------
 
     /// Switch to a named theme from the registry.
@@ -89,7 +150,7 @@ impl Theme {
             .clone();
 
         let theme = cx.global_mut::<Theme>();
-        theme.tokens = tokens;
+        theme.set_unfiltered(tokens);
 
         cx.refresh_windows();
         Ok(())
@@ -114,7 +175,9 @@ impl Theme {
             .map_err(|_| ThemeError::InvalidColor(hex.to_string()))?;
 
         let theme = cx.global_mut::<Theme>();
-        set_token_by_path(&mut theme.tokens, path, color)?;
+        let mut tokens = theme.unfiltered_tokens().clone();
+        set_token_by_path(&mut tokens, path, color)?;
+        theme.set_unfiltered(tokens);
         cx.refresh_windows();
         Ok(())
     }
@@ -123,28 +186,63 @@ impl Theme {
 
     /// Import a theme from a JSON string, returning a [`ThemeTokens`].
     ///
-    /// The JSON must conform to the serde representation of [`ThemeTokens`].
+    /// The JSON must conform to the serde representation of [`ThemeTokens`],
+    /// except that only `name` and `appearance` are required: tokens it
+    /// leaves out take the built-in theme's values for that appearance.
     pub fn import_json(json: &str) -> Result<ThemeTokens, ThemeError> {
-        serde_json::from_str(json).map_err(|e| ThemeError::Import(format!("JSON: {e}")))
+        Self::import_json_with_warnings(json).map(|(tokens, _)| tokens)
+    }
+
+    /// Like [`import_json`](Self::import_json), also returning a warning per
+    /// token filled from the built-in theme.
+    pub fn import_json_with_warnings(json: &str) -> Result<(ThemeTokens, Vec<String>), ThemeError> {
+        let tokens =
+            serde_json::from_str(json).map_err(|e| ThemeError::Import(format!("JSON: {e}")))?;
+        Ok(fill_from_built_in(tokens))
     }
 
     /// Export the active theme to a pretty-printed JSON string.
+    ///
+    /// Any color vision simulation is not included.
     pub fn export_json(&self) -> Result<String, ThemeError> {
-        serde_json::to_string_pretty(&self.tokens)
+        serde_json::to_string_pretty(self.unfiltered_tokens())
             .map_err(|e| ThemeError::Export(format!("JSON: {e}")))
     }
 
     /// Import a theme from a TOML string, returning a [`ThemeTokens`].
+    ///
+    /// Missing tokens are filled as by [`import_json`](Self::import_json).
     pub fn import_toml(toml_str: &str) -> Result<ThemeTokens, ThemeError> {
-        toml::from_str(toml_str).map_err(|e| ThemeError::Import(format!("TOML: {e}")))
+        Self::import_toml_with_warnings(toml_str).map(|(tokens, _)| tokens)
+    }
+
+    /// Like [`import_toml`](Self::import_toml), also returning a warning per
+    /// token filled from the built-in theme.
+    pub fn import_toml_with_warnings(
+        toml_str: &str,
+    ) -> Result<(ThemeTokens, Vec<String>), ThemeError> {
+        let tokens =
+            toml::from_str(toml_str).map_err(|e| ThemeError::Import(format!("TOML: {e}")))?;
+        Ok(fill_from_built_in(tokens))
     }
 
     /// Export the active theme to a pretty-printed TOML string.
     pub fn export_toml(&self) -> Result<String, ThemeError> {
-        toml::to_string_pretty(&self.tokens).map_err(|e| ThemeError::Export(format!("TOML: {e}")))
+        toml::to_string_pretty(self.unfiltered_tokens())
+            .map_err(|e| ThemeError::Export(format!("TOML: {e}")))
     }
 }
 
+/// Fill `tokens`' missing tokens from the built-in theme of its appearance.
+fn fill_from_built_in(mut tokens: ThemeTokens) -> (ThemeTokens, Vec<String>) {
+    let fallback = match tokens.appearance {
+        ThemeAppearance::Dark => tokens::one_dark(),
+        ThemeAppearance::Light => tokens::one_light(),
+    };
+    let warnings = tokens.fill_missing_from(&fallback);
+    (tokens, warnings)
+}
+
 // ---------------------------------------------------------------------------
 // ThemeRegistry (global registry of loaded themes)
 // ---------------------------------------------------------------------------
@@ -156,6 +254,8 @@ impl Theme {
 #[derive(Debug, Clone, Default)]
 pub struct ThemeRegistry {
     themes: HashMap<String, ThemeTokens>,
+    /// Load warnings per theme name, for themes loaded from files.
+    warnings: HashMap<String, Vec<String>>,
 }
 
 impl Global for ThemeRegistry {}
@@ -178,11 +278,19 @@ impl ThemeRegistry {
         cx.global_mut::<ThemeRegistry>()
     }
 
-    /// Register a theme. Overwrites any existing theme with the same name.
+    /// Register a theme. Overwrites any existing theme with the same name,
+    /// along with its load warnings.
     pub fn register(&mut self, tokens: ThemeTokens) {
+        self.warnings.remove(&tokens.name);
         self.themes.insert(tokens.name.clone(), tokens);
     }
 
+    /// Warnings from loading the theme named `name` from a file, such as
+    /// tokens the file lacked and that were filled from a built-in theme.
+    pub fn warnings(&self, name: &str) -> &[String] {
+        self.warnings.get(name).map_or(&[], Vec::as_slice)
+    }
+
     /// Look up a theme by name.
     pub fn get(&self, name: &str) -> Option<&ThemeTokens> {
         self.themes.get(name)
@@ -212,6 +320,47 @@ impl ThemeRegistry {
     pub fn themes(&self) -> &HashMap<String, ThemeTokens> {
         &self.themes
     }
+
+    /// Register every `*.json` theme in `dir`.
+    ///
+    /// A file may hold a full exported token set or a [`ThemeSeed`], which is
+    /// expanded with [`derive_theme`]. Returns the registered theme names in
+    /// file name order.
+    pub fn load_dir(&mut self, dir: &Path) -> Result<Vec<String>, ThemeError> {
+        let entries = std::fs::read_dir(dir)
+            .map_err(|e| ThemeError::Import(format!("{}: {e}", dir.display())))?;
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        paths.iter().map(|path| self.load_file(path)).collect()
+    }
+
+    /// Register the theme in the JSON file at `path`, returning its name.
+    ///
+    /// The file may hold a [`ThemeSeed`], which is expanded with
+    /// [`derive_theme`], or an exported token set. Tokens missing from the
+    /// set are filled from the built-in theme of its appearance and listed
+    /// in [`warnings`](Self::warnings).
+    pub fn load_file(&mut self, path: &Path) -> Result<String, ThemeError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| ThemeError::Import(format!("{}: {e}", path.display())))?;
+        let (tokens, warnings) = match serde_json::from_str::<ThemeSeed>(&json) {
+            Ok(seed) => (derive_theme(&seed), Vec::new()),
+            Err(_) => fill_from_built_in(
+                serde_json::from_str(&json)
+                    .map_err(|e| ThemeError::Import(format!("{}: {e}", path.display())))?,
+            ),
+        };
+        let name = tokens.name.clone();
+        self.register(tokens);
+        if !warnings.is_empty() {
+            self.warnings.insert(name.clone(), warnings);
+        }
+        Ok(name)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -278,7 +427,11 @@ impl std::error::Error for ThemeError {}
 /// Supported paths correspond to the internal token paths from
 /// [`tokens::TOKEN_MAPPING`], e.g. `"border.default"`, `"text.muted"`,
 /// `"status.error.foreground"`.
-fn set_token_by_path(tokens: &mut ThemeTokens, path: &str, color: Hsla) -> Result<(), ThemeError> {
+pub(crate) fn set_token_by_path(
+    tokens: &mut ThemeTokens,
+    path: &str,
+    color: Hsla,
+) -> Result<(), ThemeError> {
     match path {
         // Border
         "border.default" => tokens.border.default = color,
@@ -325,6 +478,9 @@ fn set_token_by_path(tokens: &mut ThemeTokens, path: &str, color: Hsla) -> Resul
         "status.error.foreground" => tokens.status.error.foreground = color,
         "status.error.background" => tokens.status.error.background = color,
         "status.error.border" => tokens.status.error.border = color,
+        "status.error.element" => tokens.status.error.element = color,
+        "status.error.element_hover" => tokens.status.error.element_hover = color,
+        "status.error.element_active" => tokens.status.error.element_active = color,
         "status.warning.foreground" => tokens.status.warning.foreground = color,
         "status.warning.background" => tokens.status.warning.background = color,
         "status.warning.border" => tokens.status.warning.border = color,
@@ -367,11 +523,99 @@ fn set_token_by_path(tokens: &mut ThemeTokens, path: &str, color: Hsla) -> Resul
         // Link
         "link.hover" => tokens.link.hover = color,
 
+        // Syntax
+        "syntax.keyword" => tokens.syntax.keyword = color,
+        "syntax.string" => tokens.syntax.string = color,
+        "syntax.number" => tokens.syntax.number = color,
+        "syntax.comment" => tokens.syntax.comment = color,
+        "syntax.function" => tokens.syntax.function = color,
+        "syntax.type" => tokens.syntax.type_name = color,
+
         _ => return Err(ThemeError::UnknownTokenPath(path.to_string())),
     }
     Ok(())
 }
 
+/// Read a single color token from a [`ThemeTokens`] by dot-path.
+///
+/// The counterpart of [`set_token_by_path`]. Returns `None` for unknown paths
+/// and for optional tokens that are unset (e.g. `"panel.focused_border"`).
+pub fn get_token_by_path(tokens: &ThemeTokens, path: &str) -> Option<Hsla> {
+    match path {
+        "border.default" => Some(tokens.border.default),
+        "border.variant" => Some(tokens.border.variant),
+        "border.focused" => Some(tokens.border.focused),
+        "border.selected" => Some(tokens.border.selected),
+        "border.transparent" => Some(tokens.border.transparent),
+        "border.disabled" => Some(tokens.border.disabled),
+        "surface.background" => Some(tokens.surface.background),
+        "surface.surface" => Some(tokens.surface.surface),
+        "surface.elevated_surface" => Some(tokens.surface.elevated_surface),
+        "element.background" => Some(tokens.element.background),
+        "element.hover" => Some(tokens.element.hover),
+        "element.active" => Some(tokens.element.active),
+        "element.selected" => Some(tokens.element.selected),
+        "element.disabled" => Some(tokens.element.disabled),
+        "ghost_element.background" => Some(tokens.ghost_element.background),
+        "ghost_element.hover" => Some(tokens.ghost_element.hover),
+        "ghost_element.active" => Some(tokens.ghost_element.active),
+        "ghost_element.selected" => Some(tokens.ghost_element.selected),
+        "ghost_element.disabled" => Some(tokens.ghost_element.disabled),
+        "text.default" => Some(tokens.text.default),
+        "text.muted" => Some(tokens.text.muted),
+        "text.placeholder" => Some(tokens.text.placeholder),
+        "text.disabled" => Some(tokens.text.disabled),
+        "text.accent" => Some(tokens.text.accent),
+        "icon.default" => Some(tokens.icon.default),
+        "icon.muted" => Some(tokens.icon.muted),
+        "icon.disabled" => Some(tokens.icon.disabled),
+        "icon.placeholder" => Some(tokens.icon.placeholder),
+        "icon.accent" => Some(tokens.icon.accent),
+        "status.error.foreground" => Some(tokens.status.error.foreground),
+        "status.error.background" => Some(tokens.status.error.background),
+        "status.error.border" => Some(tokens.status.error.border),
+        "status.error.element" => Some(tokens.status.error.element),
+        "status.error.element_hover" => Some(tokens.status.error.element_hover),
+        "status.error.element_active" => Some(tokens.status.error.element_active),
+        "status.warning.foreground" => Some(tokens.status.warning.foreground),
+        "status.warning.background" => Some(tokens.status.warning.background),
+        "status.warning.border" => Some(tokens.status.warning.border),
+        "status.info.foreground" => Some(tokens.status.info.foreground),
+        "status.info.background" => Some(tokens.status.info.background),
+        "status.info.border" => Some(tokens.status.info.border),
+        "status.success.foreground" => Some(tokens.status.success.foreground),
+        "status.success.background" => Some(tokens.status.success.background),
+        "status.success.border" => Some(tokens.status.success.border),
+        "status.hint.foreground" => Some(tokens.status.hint.foreground),
+        "status.hint.background" => Some(tokens.status.hint.background),
+        "status.hint.border" => Some(tokens.status.hint.border),
+        "tab.bar_background" => Some(tokens.tab.bar_background),
+        "tab.inactive_background" => Some(tokens.tab.inactive_background),
+        "tab.active_background" => Some(tokens.tab.active_background),
+        "panel.background" => Some(tokens.panel.background),
+        "panel.focused_border" => tokens.panel.focused_border,
+        "chrome.title_bar_background" => Some(tokens.chrome.title_bar_background),
+        "chrome.status_bar_background" => Some(tokens.chrome.status_bar_background),
+        "chrome.toolbar_background" => Some(tokens.chrome.toolbar_background),
+        "scrollbar.thumb_background" => Some(tokens.scrollbar.thumb_background),
+        "scrollbar.thumb_hover_background" => Some(tokens.scrollbar.thumb_hover_background),
+        "scrollbar.thumb_border" => Some(tokens.scrollbar.thumb_border),
+        "scrollbar.track_background" => Some(tokens.scrollbar.track_background),
+        "scrollbar.track_border" => Some(tokens.scrollbar.track_border),
+        "player.cursor" => Some(tokens.player.cursor),
+        "player.background" => Some(tokens.player.background),
+        "player.selection" => Some(tokens.player.selection),
+        "link.hover" => Some(tokens.link.hover),
+        "syntax.keyword" => Some(tokens.syntax.keyword),
+        "syntax.string" => Some(tokens.syntax.string),
+        "syntax.number" => Some(tokens.syntax.number),
+        "syntax.comment" => Some(tokens.syntax.comment),
+        "syntax.function" => Some(tokens.syntax.function),
+        "syntax.type" => Some(tokens.syntax.type_name),
+        _ => None,
+    }
+}
+
 /// Returns the list of all supported token dot-paths for [`set_token_by_path`].
 ///
 /// Useful for UI introspection, autocomplete, or validation.
@@ -410,7 +654,7 @@ pub fn init(cx: &mut App) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tokens::{ThemeAppearance, one_dark, one_light};
+    use crate::tokens::{is_missing, one_dark, one_light};
 
     #[test]
     fn theme_deref_provides_token_access() {
@@ -475,6 +719,100 @@ mod tests {
         assert_eq!(registry.len(), 1);
     }
 
+    #[test]
+    fn registry_load_dir_registers_exports_and_seeds() {
+        let dir = std::env::temp_dir().join(format!("theme-load-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut exported = one_light();
+        exported.name = "Exported".into();
+        std::fs::write(
+            dir.join("a-exported.json"),
+            Theme::new(exported).export_json().unwrap(),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("b-seed.json"),
+            r##"{"name": "Seeded", "appearance": "dark",
+                "background": "#1b2a3aff", "accent": "#ff7a1aff"}"##,
+        )
+        .unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let mut registry = ThemeRegistry::new();
+        let names = registry.load_dir(&dir).expect("load_dir");
+        assert_eq!(names, vec!["Exported", "Seeded"]);
+        assert_eq!(
+            registry.get("Seeded").unwrap().appearance,
+            ThemeAppearance::Dark
+        );
+
+        std::fs::write(dir.join("c-broken.json"), "{}").unwrap();
+        assert!(registry.load_dir(&dir).is_err());
+        assert!(registry.load_file(&dir.join("c-broken.json")).is_err());
+        assert!(registry.load_file(&dir.join("missing.json")).is_err());
+
+        let mut registry = ThemeRegistry::new();
+        let name = registry
+            .load_file(&dir.join("b-seed.json"))
+            .expect("load_file");
+        assert_eq!(name, "Seeded");
+        assert_eq!(registry.len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn color_vision_simulation_keeps_unfiltered_tokens() {
+        let mut theme = Theme::new(one_dark());
+        let original = theme.status.error.foreground;
+
+        theme.apply_color_vision(Some(ColorVisionDeficiency::Protanopia));
+        assert_eq!(
+            theme.color_vision(),
+            Some(ColorVisionDeficiency::Protanopia)
+        );
+        assert_ne!(theme.status.error.foreground, original);
+        assert_eq!(theme.unfiltered_tokens().status.error.foreground, original);
+
+        // Edits and theme switches go to the unfiltered tokens and stay simulated.
+        let red = parse_hex_color("#ff0000");
+        let mut edited = theme.unfiltered_tokens().clone();
+        set_token_by_path(&mut edited, "status.error.foreground", red).unwrap();
+        theme.set_unfiltered(edited);
+        assert_eq!(theme.unfiltered_tokens().status.error.foreground, red);
+        assert_eq!(
+            theme.status.error.foreground,
+            ColorVisionDeficiency::Protanopia.simulate(red)
+        );
+        theme.set_unfiltered(one_light());
+        assert_eq!(theme.unfiltered_tokens().name, "One Light");
+        assert!(theme.color_vision().is_some());
+
+        theme.apply_color_vision(None);
+        assert_eq!(theme.color_vision(), None);
+        assert_eq!(
+            theme.status.error.foreground,
+            one_light().status.error.foreground
+        );
+    }
+
+    #[test]
+    fn get_token_by_path_reads_every_mapped_path() {
+        let tokens = one_dark();
+        for path in all_token_paths() {
+            if path == "panel.focused_border" {
+                assert_eq!(get_token_by_path(&tokens, path), None);
+            } else {
+                assert!(get_token_by_path(&tokens, path).is_some(), "{path}");
+            }
+        }
+        assert_eq!(
+            get_token_by_path(&tokens, "text.muted"),
+            Some(tokens.text.muted)
+        );
+        assert_eq!(get_token_by_path(&tokens, "nonexistent.path"), None);
+    }
+
     #[test]
     fn set_token_by_path_known_paths() {
         let mut tokens = one_dark();
@@ -497,6 +835,7 @@ mod tests {
             "scrollbar.thumb_background",
             "player.cursor",
             "link.hover",
+            "syntax.keyword",
         ];
 
         for path in &paths {
@@ -564,6 +903,64 @@ mod tests {
         assert_eq!(imported.appearance, ThemeAppearance::Light);
     }
 
+    #[test]
+    fn partial_json_import_fills_from_the_built_in_theme() {
+        let json = r##"{"name": "Old", "appearance": "light",
+            "text": {"default": "#112233ff"}}"##;
+        let (imported, warnings) = Theme::import_json_with_warnings(json).expect("partial import");
+        assert_eq!(imported.text.default, parse_hex_color("#112233ff"));
+        assert_eq!(imported.text.muted, one_light().text.muted);
+        assert_eq!(
+            imported.scrollbar.track_border,
+            one_light().scrollbar.track_border
+        );
+        assert!(
+            all_token_paths()
+                .iter()
+                .filter_map(|path| get_token_by_path(&imported, path))
+                .all(|color| !is_missing(color))
+        );
+        // Every color token but the one given, and the optional focused border.
+        assert_eq!(warnings.len(), all_token_paths().len() - 2);
+        assert!(warnings[0].contains("'border.default'"), "{}", warnings[0]);
+
+        let toml_str = "name = \"Old\"\nappearance = \"dark\"\n[link]\nhover = \"#445566ff\"\n";
+        let (imported, toml_warnings) =
+            Theme::import_toml_with_warnings(toml_str).expect("partial toml import");
+        assert_eq!(imported.border.default, one_dark().border.default);
+        assert_eq!(imported.link.hover, parse_hex_color("#445566ff"));
+        assert_eq!(toml_warnings.len(), warnings.len());
+        assert!(!toml_warnings.iter().any(|w| w.contains("'link.hover'")));
+
+        // The name and appearance stay required.
+        assert!(Theme::import_json(r#"{"name": "Old"}"#).is_err());
+    }
+
+    #[test]
+    fn registry_keeps_load_warnings_for_partial_files() {
+        let dir = std::env::temp_dir().join(format!("theme-partial-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut partial = serde_json::to_value(one_dark()).unwrap();
+        partial["name"] = "Partial".into();
+        partial.as_object_mut().unwrap().remove("syntax");
+        std::fs::write(dir.join("partial.json"), partial.to_string()).unwrap();
+
+        let mut registry = ThemeRegistry::new();
+        let name = registry.load_file(&dir.join("partial.json")).unwrap();
+        assert_eq!(registry.warnings(&name).len(), 6);
+        assert_eq!(
+            registry.get(&name).unwrap().syntax.keyword,
+            one_dark().syntax.keyword
+        );
+        assert!(registry.warnings("One Dark").is_empty());
+
+        // Re-registering the theme drops its stale warnings.
+        registry.register(registry.get(&name).unwrap().clone());
+        assert!(registry.warnings(&name).is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn json_import_invalid() {
         let result = Theme::import_json("{ not valid json");
@@ -1,12 +1,18 @@
+pub mod derive;
 pub mod engine;
+pub mod patch;
 pub mod tokens;
+pub mod vision;
 
+pub use derive::{ThemeSeed, derive_theme};
 pub use engine::{ActiveTheme, Theme, ThemeError, ThemeRegistry};
+pub use patch::{PatchOp, ThemePatch, TokenChange, TokenEdit};
 pub use tokens::{
-    BorderTokens, ChromeTokens, ElementTokens, GhostElementTokens, IconTokens, LinkTokens,
-    PanelTokens, PlayerTokens, ScrollbarTokens, StatusColorTriplet, StatusTokens, SurfaceTokens,
-    TabTokens, TextTokens, ThemeAppearance, ThemeTokens,
+    BorderTokens, ChromeTokens, ElementTokens, ErrorStatusTokens, GhostElementTokens, IconTokens,
+    LinkTokens, PanelTokens, PlayerTokens, ScrollbarTokens, StatusColorTriplet, StatusTokens,
+    SurfaceTokens, SyntaxTokens, TabTokens, TextTokens, ThemeAppearance, ThemeTokens,
 };
+pub use vision::{ColorVisionDeficiency, contrast_ratio};
 
 /// Initialize the theme engine.
 ///
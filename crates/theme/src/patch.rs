@@ -0,0 +1,387 @@
+//! Theme patch scripts: an ordered, replayable record of token edits.
+//!
+//! The studio records every token edit of a session into a [`ThemePatch`],
+//! which serializes to a JSON list that can be reviewed like code and
+//! replayed onto a theme file with `gpui theme apply-patch`:
+//!
+//! ```json
+//! [
+//!   { "op": "set_token", "path": "border.focused", "value": "#61afefff" },
+//!   { "op": "set_token", "path": "text.accent", "value": "#61afefff" }
+//! ]
+//! ```
+//!
+//! Bulk edits in the token editor (copying a category from another theme,
+//! resetting it, swapping foreground/background pairs) are built as
+//! [`TokenEdit`]s, which keep each token's previous value so the whole
+//! group can be undone in one step.
+
+use gpui::{Hsla, Rgba};
+use serde::{Deserialize, Serialize};
+
+use crate::engine::{ThemeError, all_token_paths, get_token_by_path, set_token_by_path};
+use crate::tokens::ThemeTokens;
+
+/// One operation in a [`ThemePatch`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PatchOp {
+    /// Set the token at `path` (e.g. `"border.default"`) to a hex color.
+    SetToken { path: String, value: String },
+}
+
+/// An ordered list of token edits, applied first to last.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ThemePatch {
+    ops: Vec<PatchOp>,
+}
+
+impl ThemePatch {
+    /// An empty patch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a `set_token` operation.
+    pub fn set_token(&mut self, path: impl Into<String>, value: impl Into<String>) {
+        self.ops.push(PatchOp::SetToken {
+            path: path.into(),
+            value: value.into(),
+        });
+    }
+
+    /// The operations, in recording order.
+    pub fn ops(&self) -> &[PatchOp] {
+        &self.ops
+    }
+
+    /// Number of operations.
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Whether the patch has no operations.
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Parse a patch from its JSON list form.
+    pub fn from_json(json: &str) -> Result<Self, ThemeError> {
+        serde_json::from_str(json).map_err(|e| ThemeError::Import(format!("patch JSON: {e}")))
+    }
+
+    /// Serialize the patch to a pretty-printed JSON list.
+    pub fn to_json(&self) -> Result<String, ThemeError> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| ThemeError::Export(format!("patch JSON: {e}")))
+    }
+
+    /// Replay the patch onto `tokens`.
+    ///
+    /// Operations are validated before any is applied, so an unknown path or
+    /// invalid color leaves `tokens` untouched.
+    pub fn apply(&self, tokens: &mut ThemeTokens) -> Result<(), ThemeError> {
+        let mut patched = tokens.clone();
+        for op in &self.ops {
+            match op {
+                PatchOp::SetToken { path, value } => {
+                    let rgba = gpui::Rgba::try_from(value.as_str())
+                        .map_err(|_| ThemeError::InvalidColor(value.clone()))?;
+                    set_token_by_path(&mut patched, path, rgba.into())?;
+                }
+            }
+        }
+        *tokens = patched;
+        Ok(())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Bulk edits
+// ---------------------------------------------------------------------------
+
+/// A color as `#rrggbbaa`, the form patches and theme files use.
+pub fn color_hex(color: Hsla) -> String {
+    let rgba = Rgba::from(color);
+    let channel = |value: f32| (value * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        channel(rgba.r),
+        channel(rgba.g),
+        channel(rgba.b),
+        channel(rgba.a)
+    )
+}
+
+/// Token categories (the first path segment, e.g. `"status"`), in token
+/// order.
+pub fn token_categories() -> Vec<&'static str> {
+    let mut categories: Vec<&'static str> = Vec::new();
+    for path in all_token_paths() {
+        let category = path.split('.').next().unwrap_or(path);
+        if !categories.contains(&category) {
+            categories.push(category);
+        }
+    }
+    categories
+}
+
+/// The token paths in `category`, in token order.
+pub fn category_paths(category: &str) -> Vec<&'static str> {
+    all_token_paths()
+        .into_iter()
+        .filter(|path| path.split('.').next() == Some(category))
+        .collect()
+}
+
+/// One token's value before and after an edit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenChange {
+    pub path: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// A group of token changes applied, recorded, and undone together.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenEdit {
+    /// What the edit did, e.g. "Copy status from One Light".
+    pub label: String,
+    pub changes: Vec<TokenChange>,
+}
+
+impl TokenEdit {
+    /// Set one token of `tokens` to `hex`.
+    pub fn set(tokens: &ThemeTokens, path: &str, hex: &str) -> Self {
+        let before = get_token_by_path(tokens, path)
+            .map(color_hex)
+            .unwrap_or_default();
+        Self {
+            label: format!("Set {path} to {hex}"),
+            changes: vec![TokenChange {
+                path: path.to_string(),
+                before,
+                after: hex.to_string(),
+            }],
+        }
+    }
+
+    /// Give every token of `category` in `tokens` its value in `source`
+    /// (another theme, or the registered copy of the same theme to reset
+    /// it). Tokens that already match are left out.
+    pub fn copy_category(
+        tokens: &ThemeTokens,
+        source: &ThemeTokens,
+        category: &str,
+        label: impl Into<String>,
+    ) -> Self {
+        let changes = category_paths(category)
+            .into_iter()
+            .filter_map(|path| {
+                let before = color_hex(get_token_by_path(tokens, path)?);
+                let after = color_hex(get_token_by_path(source, path)?);
+                (before != after).then(|| TokenChange {
+                    path: path.to_string(),
+                    before,
+                    after,
+                })
+            })
+            .collect();
+        Self {
+            label: label.into(),
+            changes,
+        }
+    }
+
+    /// Swap each `<name>.foreground` token in `category` with its
+    /// `<name>.background` sibling. Pairs that are already equal are left
+    /// out.
+    pub fn swap_pairs(tokens: &ThemeTokens, category: &str) -> Self {
+        let mut changes = Vec::new();
+        for (foreground, background) in foreground_background_pairs(category) {
+            let (Some(fg), Some(bg)) = (
+                get_token_by_path(tokens, foreground),
+                get_token_by_path(tokens, background),
+            ) else {
+                continue;
+            };
+            let (fg, bg) = (color_hex(fg), color_hex(bg));
+            if fg == bg {
+                continue;
+            }
+            changes.push(TokenChange {
+                path: foreground.to_string(),
+                before: fg.clone(),
+                after: bg.clone(),
+            });
+            changes.push(TokenChange {
+                path: background.to_string(),
+                before: bg,
+                after: fg,
+            });
+        }
+        Self {
+            label: format!("Swap {category} foreground/background"),
+            changes,
+        }
+    }
+
+    /// Whether the edit changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The edit as a patch of new values.
+    pub fn patch(&self) -> ThemePatch {
+        let mut patch = ThemePatch::new();
+        for change in &self.changes {
+            patch.set_token(&change.path, &change.after);
+        }
+        patch
+    }
+
+    /// The patch that undoes the edit: the previous values, last change
+    /// first.
+    pub fn undo_patch(&self) -> ThemePatch {
+        let mut patch = ThemePatch::new();
+        for change in self.changes.iter().rev() {
+            patch.set_token(&change.path, &change.before);
+        }
+        patch
+    }
+}
+
+/// `(foreground, background)` token paths that share a prefix in
+/// `category`.
+pub fn foreground_background_pairs(category: &str) -> Vec<(&'static str, &'static str)> {
+    let paths = category_paths(category);
+    paths
+        .iter()
+        .filter_map(|foreground| {
+            let prefix = foreground.strip_suffix(".foreground")?;
+            let background = paths
+                .iter()
+                .find(|path| path.strip_suffix(".background") == Some(prefix))?;
+            Some((*foreground, *background))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::get_token_by_path;
+    use crate::tokens::{one_dark, one_light, parse_hex_color};
+
+    #[test]
+    fn patch_serializes_as_list_of_set_token_ops() {
+        let mut patch = ThemePatch::new();
+        patch.set_token("border.focused", "#61afefff");
+        patch.set_token("syntax.type", "#e5c07bff");
+
+        let json: serde_json::Value = serde_json::from_str(&patch.to_json().unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                { "op": "set_token", "path": "border.focused", "value": "#61afefff" },
+                { "op": "set_token", "path": "syntax.type", "value": "#e5c07bff" },
+            ])
+        );
+        assert_eq!(ThemePatch::from_json(&json.to_string()).unwrap(), patch);
+    }
+
+    #[test]
+    fn apply_replays_edits_in_order() {
+        let mut patch = ThemePatch::new();
+        patch.set_token("text.accent", "#ff0000ff");
+        patch.set_token("text.accent", "#00ff00ff");
+        patch.set_token("panel.focused_border", "#0000ffff");
+
+        let mut tokens = one_dark();
+        patch.apply(&mut tokens).unwrap();
+        assert_eq!(tokens.text.accent, parse_hex_color("#00ff00ff"));
+        assert_eq!(
+            get_token_by_path(&tokens, "panel.focused_border"),
+            Some(parse_hex_color("#0000ffff"))
+        );
+    }
+
+    #[test]
+    fn apply_rejects_bad_ops_without_partial_edits() {
+        let original = one_dark();
+
+        let mut unknown = ThemePatch::new();
+        unknown.set_token("text.accent", "#ff0000ff");
+        unknown.set_token("text.nope", "#ff0000ff");
+        let mut tokens = original.clone();
+        assert!(matches!(
+            unknown.apply(&mut tokens),
+            Err(ThemeError::UnknownTokenPath(path)) if path == "text.nope"
+        ));
+        assert_eq!(tokens.text.accent, original.text.accent);
+
+        let mut invalid = ThemePatch::new();
+        invalid.set_token("text.accent", "red");
+        assert!(matches!(
+            invalid.apply(&mut tokens),
+            Err(ThemeError::InvalidColor(value)) if value == "red"
+        ));
+    }
+
+    #[test]
+    fn copy_category_takes_only_differing_tokens_and_undoes() {
+        let dark = one_dark();
+        let light = one_light();
+        let edit = TokenEdit::copy_category(&dark, &light, "status", "Copy status");
+        assert!(!edit.is_empty());
+        assert!(edit.changes.iter().all(|c| c.path.starts_with("status.")));
+
+        let mut tokens = dark.clone();
+        edit.patch().apply(&mut tokens).unwrap();
+        assert_eq!(
+            tokens.status.error.foreground,
+            light.status.error.foreground
+        );
+        assert!(TokenEdit::copy_category(&tokens, &light, "status", "Again").is_empty());
+
+        edit.undo_patch().apply(&mut tokens).unwrap();
+        assert_eq!(
+            color_hex(tokens.status.error.foreground),
+            color_hex(dark.status.error.foreground)
+        );
+    }
+
+    #[test]
+    fn swap_pairs_exchanges_status_foregrounds_and_backgrounds() {
+        assert!(
+            foreground_background_pairs("status")
+                .contains(&("status.error.foreground", "status.error.background"))
+        );
+        assert!(foreground_background_pairs("text").is_empty());
+        assert!(token_categories().contains(&"status"));
+
+        let dark = one_dark();
+        let edit = TokenEdit::swap_pairs(&dark, "status");
+        let mut tokens = dark.clone();
+        edit.patch().apply(&mut tokens).unwrap();
+        assert_eq!(
+            color_hex(tokens.status.error.background),
+            color_hex(dark.status.error.foreground)
+        );
+        assert_eq!(
+            color_hex(tokens.status.error.foreground),
+            color_hex(dark.status.error.background)
+        );
+    }
+
+    #[test]
+    fn from_json_rejects_unknown_ops() {
+        assert!(ThemePatch::from_json(r#"[{"op":"delete_token","path":"text.accent"}]"#).is_err());
+        assert!(ThemePatch::from_json("[]").unwrap().is_empty());
+    }
+}
@@ -5,10 +5,19 @@
 //!
 //! Color format: All colors stored as `gpui::Hsla`. Serialized as `#rrggbbaa` hex strings
 //! via gpui's built-in Hsla serde implementation (which round-trips through Rgba).
+//!
+//! Theme files written against an older schema may lack tokens. The theme
+//! importers (`Theme::import_json`, `Theme::import_toml`, and
+//! `ThemeRegistry::load_file`) still accept them: missing tokens deserialize
+//! as a placeholder that never leaves the importer, because
+//! [`ThemeTokens::fill_missing_from`] replaces each one with a fallback
+//! theme's value and lists what it filled.
 
 use gpui::Hsla;
 use serde::{Deserialize, Serialize};
 
+use crate::engine::{all_token_paths, get_token_by_path, set_token_by_path};
+
 /// Parse a `#RRGGBBAA` or `#RRGGBB` hex string into an `Hsla` color.
 ///
 /// Uses gpui's built-in `Rgba::try_from(&str)` which handles `#rgb`, `#rgba`,
@@ -19,12 +28,57 @@ pub fn parse_hex_color(hex: &str) -> Hsla {
     rgba.into()
 }
 
+// ---------------------------------------------------------------------------
+// Missing tokens
+// ---------------------------------------------------------------------------
+
+/// Placeholder for a color a theme file leaves out, until
+/// [`ThemeTokens::fill_missing_from`] replaces it.
+///
+/// Its alpha is NaN, which no hex color parses to, so it cannot be confused
+/// with a real color (not even a fully transparent one). Only the serde
+/// defaults below produce it, and only the importer deserializes token sets.
+pub(crate) const MISSING: Hsla = Hsla {
+    h: 0.0,
+    s: 0.0,
+    l: 0.0,
+    a: f32::NAN,
+};
+
+/// Whether `color` is the [`MISSING`] placeholder.
+pub(crate) fn is_missing(color: Hsla) -> bool {
+    color.a.is_nan()
+}
+
+/// Serde default for a single color token.
+fn missing() -> Hsla {
+    MISSING
+}
+
+/// Serde default for token category structs: every color is [`MISSING`].
+/// Used with `#[serde(default = "...::missing")]` so a category may omit any
+/// of its tokens. Deliberately not `Default`, which would hand the
+/// placeholder to callers outside the importer.
+macro_rules! missing_tokens {
+    ($name:ident { $($field:ident),* $(,)? }) => {
+        impl $name {
+            /// Every token [`MISSING`].
+            fn missing() -> Self {
+                Self {
+                    $($field: MISSING,)*
+                }
+            }
+        }
+    };
+}
+
 // ---------------------------------------------------------------------------
 // Token category structs
 // ---------------------------------------------------------------------------
 
 /// Border tokens: outlines, separators, and focus/selection indicators.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "BorderTokens::missing")]
 pub struct BorderTokens {
     pub default: Hsla,
     pub variant: Hsla,
@@ -36,6 +90,7 @@ pub struct BorderTokens {
 
 /// Surface and background tokens: app, panel, and elevated surfaces.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "SurfaceTokens::missing")]
 pub struct SurfaceTokens {
     pub background: Hsla,
     pub surface: Hsla,
@@ -44,6 +99,7 @@ pub struct SurfaceTokens {
 
 /// Element interaction state tokens: backgrounds for different interactive states.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "ElementTokens::missing")]
 pub struct ElementTokens {
     pub background: Hsla,
     pub hover: Hsla,
@@ -54,6 +110,7 @@ pub struct ElementTokens {
 
 /// Ghost element state tokens: transparent-background variants of element states.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "GhostElementTokens::missing")]
 pub struct GhostElementTokens {
     pub background: Hsla,
     pub hover: Hsla,
@@ -64,6 +121,7 @@ pub struct GhostElementTokens {
 
 /// Text color tokens: primary, muted, placeholder, disabled, and accent text.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "TextTokens::missing")]
 pub struct TextTokens {
     pub default: Hsla,
     pub muted: Hsla,
@@ -74,6 +132,7 @@ pub struct TextTokens {
 
 /// Icon color tokens: mirrors text token categories.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "IconTokens::missing")]
 pub struct IconTokens {
     pub default: Hsla,
     pub muted: Hsla,
@@ -84,16 +143,93 @@ pub struct IconTokens {
 
 /// Semantic status color triplet: foreground, background, and border.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "StatusColorTriplet::missing")]
 pub struct StatusColorTriplet {
     pub foreground: Hsla,
     pub background: Hsla,
     pub border: Hsla,
 }
 
+/// Error status tokens: the foreground/background/border triplet of error
+/// messages, plus the fills of destructive controls.
+///
+/// `background` is the faint surface behind an error message; a Danger
+/// button drawn on it reads as an alert rather than an action, so
+/// destructive controls use the stronger `element` states instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "ErrorStatusFile")]
+pub struct ErrorStatusTokens {
+    pub foreground: Hsla,
+    pub background: Hsla,
+    pub border: Hsla,
+    /// Resting fill of destructive controls (e.g. Danger buttons).
+    pub element: Hsla,
+    /// Fill of a hovered destructive control.
+    pub element_hover: Hsla,
+    /// Fill of a pressed destructive control.
+    pub element_active: Hsla,
+}
+
+impl ErrorStatusTokens {
+    /// The triplet of error messages, as for the other statuses.
+    pub fn triplet(&self) -> StatusColorTriplet {
+        StatusColorTriplet {
+            foreground: self.foreground,
+            background: self.background,
+            border: self.border,
+        }
+    }
+
+    /// Element fills for an error `foreground`: 20%, 30%, and 40% of it over
+    /// the surface, for resting, hovered, and pressed controls.
+    pub fn derive_elements(foreground: Hsla) -> (Hsla, Hsla, Hsla) {
+        (
+            foreground.opacity(0.2),
+            foreground.opacity(0.3),
+            foreground.opacity(0.4),
+        )
+    }
+}
+
+/// [`ErrorStatusTokens`] as written in theme files. Files written before the
+/// element tokens existed (and Zed themes, which have no such keys) derive
+/// them from `foreground`; a missing `foreground` leaves them missing too.
+#[derive(Deserialize)]
+struct ErrorStatusFile {
+    #[serde(default = "missing")]
+    foreground: Hsla,
+    #[serde(default = "missing")]
+    background: Hsla,
+    #[serde(default = "missing")]
+    border: Hsla,
+    #[serde(default)]
+    element: Option<Hsla>,
+    #[serde(default)]
+    element_hover: Option<Hsla>,
+    #[serde(default)]
+    element_active: Option<Hsla>,
+}
+
+impl From<ErrorStatusFile> for ErrorStatusTokens {
+    fn from(file: ErrorStatusFile) -> Self {
+        let (element, element_hover, element_active) =
+            ErrorStatusTokens::derive_elements(file.foreground);
+        Self {
+            foreground: file.foreground,
+            background: file.background,
+            border: file.border,
+            element: file.element.unwrap_or(element),
+            element_hover: file.element_hover.unwrap_or(element_hover),
+            element_active: file.element_active.unwrap_or(element_active),
+        }
+    }
+}
+
 /// Semantic status tokens: error, warning, info, success, and hint.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "StatusTokens::missing")]
 pub struct StatusTokens {
-    pub error: StatusColorTriplet,
+    pub error: ErrorStatusTokens,
     pub warning: StatusColorTriplet,
     pub info: StatusColorTriplet,
     pub success: StatusColorTriplet,
@@ -102,6 +238,7 @@ pub struct StatusTokens {
 
 /// Tab and tab bar chrome tokens.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "TabTokens::missing")]
 pub struct TabTokens {
     pub bar_background: Hsla,
     pub inactive_background: Hsla,
@@ -110,6 +247,7 @@ pub struct TabTokens {
 
 /// Panel tokens.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "PanelTokens::missing")]
 pub struct PanelTokens {
     pub background: Hsla,
     pub focused_border: Option<Hsla>,
@@ -117,6 +255,7 @@ pub struct PanelTokens {
 
 /// Chrome/shell tokens: title bar, status bar, toolbar.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "ChromeTokens::missing")]
 pub struct ChromeTokens {
     pub title_bar_background: Hsla,
     pub status_bar_background: Hsla,
@@ -125,6 +264,7 @@ pub struct ChromeTokens {
 
 /// Scrollbar tokens.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "ScrollbarTokens::missing")]
 pub struct ScrollbarTokens {
     pub thumb_background: Hsla,
     pub thumb_hover_background: Hsla,
@@ -135,6 +275,7 @@ pub struct ScrollbarTokens {
 
 /// Player accent tokens (cursor, background, selection from players[0]).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "PlayerTokens::missing")]
 pub struct PlayerTokens {
     pub cursor: Hsla,
     pub background: Hsla,
@@ -143,10 +284,137 @@ pub struct PlayerTokens {
 
 /// Link tokens.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "LinkTokens::missing")]
 pub struct LinkTokens {
     pub hover: Hsla,
 }
 
+/// Syntax highlight tokens for code spans and blocks in docs surfaces.
+///
+/// A small subset of Zed's syntax scopes; editor-grade highlighting is still
+/// deferred.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default = "SyntaxTokens::missing")]
+pub struct SyntaxTokens {
+    pub keyword: Hsla,
+    pub string: Hsla,
+    pub number: Hsla,
+    pub comment: Hsla,
+    pub function: Hsla,
+    #[serde(rename = "type")]
+    pub type_name: Hsla,
+}
+
+// A category left out of a theme file entirely is all placeholders.
+missing_tokens!(BorderTokens {
+    default,
+    variant,
+    focused,
+    selected,
+    transparent,
+    disabled
+});
+missing_tokens!(SurfaceTokens {
+    background,
+    surface,
+    elevated_surface
+});
+missing_tokens!(ElementTokens {
+    background,
+    hover,
+    active,
+    selected,
+    disabled
+});
+missing_tokens!(GhostElementTokens {
+    background,
+    hover,
+    active,
+    selected,
+    disabled
+});
+missing_tokens!(TextTokens {
+    default,
+    muted,
+    placeholder,
+    disabled,
+    accent
+});
+missing_tokens!(IconTokens {
+    default,
+    muted,
+    disabled,
+    placeholder,
+    accent
+});
+missing_tokens!(StatusColorTriplet {
+    foreground,
+    background,
+    border
+});
+missing_tokens!(ErrorStatusTokens {
+    foreground,
+    background,
+    border,
+    element,
+    element_hover,
+    element_active
+});
+missing_tokens!(TabTokens {
+    bar_background,
+    inactive_background,
+    active_background
+});
+missing_tokens!(ChromeTokens {
+    title_bar_background,
+    status_bar_background,
+    toolbar_background
+});
+missing_tokens!(ScrollbarTokens {
+    thumb_background,
+    thumb_hover_background,
+    thumb_border,
+    track_background,
+    track_border
+});
+missing_tokens!(PlayerTokens {
+    cursor,
+    background,
+    selection
+});
+missing_tokens!(LinkTokens { hover });
+missing_tokens!(SyntaxTokens {
+    keyword,
+    string,
+    number,
+    comment,
+    function,
+    type_name
+});
+
+impl StatusTokens {
+    /// Every token [`MISSING`].
+    fn missing() -> Self {
+        Self {
+            error: ErrorStatusTokens::missing(),
+            warning: StatusColorTriplet::missing(),
+            info: StatusColorTriplet::missing(),
+            success: StatusColorTriplet::missing(),
+            hint: StatusColorTriplet::missing(),
+        }
+    }
+}
+
+impl PanelTokens {
+    /// The background [`MISSING`], and no focused border.
+    fn missing() -> Self {
+        Self {
+            background: MISSING,
+            focused_border: None,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Top-level token set
 // ---------------------------------------------------------------------------
@@ -154,25 +422,65 @@ pub struct LinkTokens {
 /// Complete set of design tokens for a theme.
 ///
 /// POC scope covers: border, surface, element/ghost states, text, icon,
-/// status colors, tab/panel/chrome, scrollbar, player accent, and link tokens.
-/// Editor, terminal, and syntax tokens are deferred to Phase 1+.
+/// status colors, tab/panel/chrome, scrollbar, player accent, link, and a
+/// minimal syntax subset. Editor and terminal tokens are deferred to Phase 1+.
+///
+/// Only `name` and `appearance` are required in theme files; missing color
+/// tokens deserialize as [`MISSING`] until filled from a fallback. Load
+/// theme files through the `Theme` importers, which always fill them.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThemeTokens {
     pub name: String,
     pub appearance: ThemeAppearance,
+    #[serde(default = "BorderTokens::missing")]
     pub border: BorderTokens,
+    #[serde(default = "SurfaceTokens::missing")]
     pub surface: SurfaceTokens,
+    #[serde(default = "ElementTokens::missing")]
     pub element: ElementTokens,
+    #[serde(default = "GhostElementTokens::missing")]
     pub ghost_element: GhostElementTokens,
+    #[serde(default = "TextTokens::missing")]
     pub text: TextTokens,
+    #[serde(default = "IconTokens::missing")]
     pub icon: IconTokens,
+    #[serde(default = "StatusTokens::missing")]
     pub status: StatusTokens,
+    #[serde(default = "TabTokens::missing")]
     pub tab: TabTokens,
+    #[serde(default = "PanelTokens::missing")]
     pub panel: PanelTokens,
+    #[serde(default = "ChromeTokens::missing")]
     pub chrome: ChromeTokens,
+    #[serde(default = "ScrollbarTokens::missing")]
     pub scrollbar: ScrollbarTokens,
+    #[serde(default = "PlayerTokens::missing")]
     pub player: PlayerTokens,
+    #[serde(default = "LinkTokens::missing")]
     pub link: LinkTokens,
+    #[serde(default = "SyntaxTokens::missing")]
+    pub syntax: SyntaxTokens,
+}
+
+impl ThemeTokens {
+    /// Replace every [`MISSING`] token with `fallback`'s value, returning a
+    /// warning per token filled, in token mapping order.
+    pub fn fill_missing_from(&mut self, fallback: &ThemeTokens) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for path in all_token_paths() {
+            if !get_token_by_path(self, path).is_some_and(is_missing) {
+                continue;
+            }
+            let color = get_token_by_path(fallback, path).unwrap_or(MISSING);
+            // Every mapped path is settable.
+            let _ = set_token_by_path(self, path, color);
+            warnings.push(format!(
+                "theme '{}' is missing '{path}'; using the value from '{}'",
+                self.name, fallback.name
+            ));
+        }
+        warnings
+    }
 }
 
 /// Theme appearance mode.
@@ -234,10 +542,13 @@ pub fn one_dark() -> ThemeTokens {
             accent: parse_hex_color("#74ade8ff"),
         },
         status: StatusTokens {
-            error: StatusColorTriplet {
+            error: ErrorStatusTokens {
                 foreground: parse_hex_color("#d07277ff"),
                 background: parse_hex_color("#d072771a"),
                 border: parse_hex_color("#4c2b2cff"),
+                element: parse_hex_color("#d0727733"),
+                element_hover: parse_hex_color("#d072774d"),
+                element_active: parse_hex_color("#d0727766"),
             },
             warning: StatusColorTriplet {
                 foreground: parse_hex_color("#dec184ff"),
@@ -289,6 +600,14 @@ pub fn one_dark() -> ThemeTokens {
         link: LinkTokens {
             hover: parse_hex_color("#74ade8ff"),
         },
+        syntax: SyntaxTokens {
+            keyword: parse_hex_color("#b477cfff"),
+            string: parse_hex_color("#a1c181ff"),
+            number: parse_hex_color("#bf956aff"),
+            comment: parse_hex_color("#5d636fff"),
+            function: parse_hex_color("#73ade9ff"),
+            type_name: parse_hex_color("#6eb4bfff"),
+        },
     }
 }
 
@@ -339,10 +658,13 @@ pub fn one_light() -> ThemeTokens {
             accent: parse_hex_color("#5c78e2ff"),
         },
         status: StatusTokens {
-            error: StatusColorTriplet {
+            error: ErrorStatusTokens {
                 foreground: parse_hex_color("#d36151ff"),
                 background: parse_hex_color("#fbdfd9ff"),
                 border: parse_hex_color("#f6c6bdff"),
+                element: parse_hex_color("#d3615133"),
+                element_hover: parse_hex_color("#d361514d"),
+                element_active: parse_hex_color("#d3615166"),
             },
             warning: StatusColorTriplet {
                 foreground: parse_hex_color("#a48819ff"),
@@ -394,6 +716,14 @@ pub fn one_light() -> ThemeTokens {
         link: LinkTokens {
             hover: parse_hex_color("#5c78e2ff"),
         },
+        syntax: SyntaxTokens {
+            keyword: parse_hex_color("#a449abff"),
+            string: parse_hex_color("#649f57ff"),
+            number: parse_hex_color("#ad6e25ff"),
+            comment: parse_hex_color("#a2a3a7ff"),
+            function: parse_hex_color("#5b79e3ff"),
+            type_name: parse_hex_color("#3882b7ff"),
+        },
     }
 }
 
@@ -446,6 +776,10 @@ pub const TOKEN_MAPPING: &[(&str, &str)] = &[
     ("status.error.foreground", "error"),
     ("status.error.background", "error.background"),
     ("status.error.border", "error.border"),
+    // Zed has no destructive control fills; imports derive them from `error`.
+    ("status.error.element", "error"),
+    ("status.error.element_hover", "error"),
+    ("status.error.element_active", "error"),
     // Status: warning
     ("status.warning.foreground", "warning"),
     ("status.warning.background", "warning.background"),
@@ -488,6 +822,13 @@ pub const TOKEN_MAPPING: &[(&str, &str)] = &[
     ("player.selection", "players[0].selection"),
     // Link
     ("link.hover", "link_text.hover"),
+    // Syntax
+    ("syntax.keyword", "syntax.keyword.color"),
+    ("syntax.string", "syntax.string.color"),
+    ("syntax.number", "syntax.number.color"),
+    ("syntax.comment", "syntax.comment.color"),
+    ("syntax.function", "syntax.function.color"),
+    ("syntax.type", "syntax.type.color"),
 ];
 
 // ---------------------------------------------------------------------------
@@ -620,6 +961,61 @@ mod tests {
         parse_hex_color("not-a-color");
     }
 
+    #[test]
+    fn error_elements_are_derived_when_a_theme_file_lacks_them() {
+        let dark = one_dark();
+        let mut json = serde_json::to_value(&dark).unwrap();
+        let error = json["status"]["error"].as_object_mut().unwrap();
+        for key in ["element", "element_hover", "element_active"] {
+            error.remove(key);
+        }
+
+        let imported: ThemeTokens = serde_json::from_value(json).unwrap();
+        let derived = ErrorStatusTokens::derive_elements(dark.status.error.foreground);
+        assert_eq!(imported.status.error.element, derived.0);
+        assert_eq!(imported.status.error.element_hover, derived.1);
+        assert_eq!(imported.status.error.element_active, derived.2);
+        // The built-in resting fill is the derived one, written out.
+        assert_eq!(dark.status.error.element, derived.0);
+        assert_eq!(
+            imported.status.error.background,
+            dark.status.error.background
+        );
+    }
+
+    #[test]
+    fn fill_missing_from_replaces_only_placeholders() {
+        let mut json = serde_json::to_value(one_light()).unwrap();
+        json["name"] = "Partial".into();
+        json["border"].as_object_mut().unwrap().remove("variant");
+        json["status"]["error"]
+            .as_object_mut()
+            .unwrap()
+            .remove("foreground");
+        json.as_object_mut().unwrap().remove("link");
+
+        let mut partial: ThemeTokens = serde_json::from_value(json).unwrap();
+        assert!(is_missing(partial.border.variant));
+        assert!(
+            !is_missing(partial.border.transparent),
+            "transparent is a real color"
+        );
+
+        let fallback = one_dark();
+        let warnings = partial.fill_missing_from(&fallback);
+        assert_eq!(partial.border.variant, fallback.border.variant);
+        assert_eq!(partial.border.default, one_light().border.default);
+        assert_eq!(partial.link.hover, fallback.link.hover);
+        assert_eq!(
+            partial.status.error.foreground,
+            fallback.status.error.foreground
+        );
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings[0].contains("'border.variant'") && warnings[0].contains("'One Dark'"));
+
+        assert!(partial.fill_missing_from(&fallback).is_empty());
+    }
+
     #[test]
     fn status_tokens_have_distinct_foreground_colors() {
         let dark = one_dark();
@@ -0,0 +1,206 @@
+//! Color vision deficiency simulation.
+//!
+//! Applies the Machado et al. (2009) simulation matrices (full severity) in
+//! linear RGB, either to a single color or to every token of a theme, so
+//! designers can check that status and accent colors stay distinguishable.
+//! [`contrast_ratio`] measures the WCAG 2 contrast between two colors.
+
+use gpui::{Hsla, Rgba};
+
+use crate::engine::{all_token_paths, get_token_by_path, set_token_by_path};
+use crate::tokens::ThemeTokens;
+
+/// A simulated color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorVisionDeficiency {
+    /// No functioning long-wavelength (red) cones.
+    Protanopia,
+    /// No functioning medium-wavelength (green) cones.
+    Deuteranopia,
+    /// No functioning short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// All simulations, in display order.
+    pub const ALL: [ColorVisionDeficiency; 3] = [
+        ColorVisionDeficiency::Protanopia,
+        ColorVisionDeficiency::Deuteranopia,
+        ColorVisionDeficiency::Tritanopia,
+    ];
+
+    /// Human-readable name.
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorVisionDeficiency::Protanopia => "Protanopia",
+            ColorVisionDeficiency::Deuteranopia => "Deuteranopia",
+            ColorVisionDeficiency::Tritanopia => "Tritanopia",
+        }
+    }
+
+    /// Linear-RGB transform for this deficiency.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    /// Simulate how `color` appears with this deficiency. Alpha is preserved.
+    pub fn simulate(self, color: Hsla) -> Hsla {
+        let rgba = Rgba::from(color);
+        let linear = [rgba.r, rgba.g, rgba.b].map(srgb_to_linear);
+        let [r, g, b] = self
+            .matrix()
+            .map(|row| row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2])
+            .map(|channel| linear_to_srgb(channel.clamp(0.0, 1.0)));
+        Rgba { r, g, b, a: rgba.a }.into()
+    }
+
+    /// Simulate every color token of a theme.
+    pub fn simulate_theme(self, tokens: &ThemeTokens) -> ThemeTokens {
+        let mut simulated = tokens.clone();
+        for path in all_token_paths() {
+            if let Some(color) = get_token_by_path(tokens, path) {
+                // Paths come from the mapping table, so setting cannot fail.
+                let _ = set_token_by_path(&mut simulated, path, self.simulate(color));
+            }
+        }
+        simulated
+    }
+}
+
+/// WCAG 2 contrast ratio of `foreground` over `background`, from 1 to 21.
+///
+/// A translucent foreground is composited over the background first; the
+/// background is treated as opaque.
+pub fn contrast_ratio(foreground: Hsla, background: Hsla) -> f32 {
+    let fg = Rgba::from(foreground);
+    let bg = Rgba::from(background);
+    let blend = |f: f32, b: f32| f * fg.a + b * (1.0 - fg.a);
+    let composited = [blend(fg.r, bg.r), blend(fg.g, bg.g), blend(fg.b, bg.b)];
+    let lighter = relative_luminance(composited);
+    let darker = relative_luminance([bg.r, bg.g, bg.b]);
+    let (lighter, darker) = if lighter >= darker {
+        (lighter, darker)
+    } else {
+        (darker, lighter)
+    };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+fn relative_luminance(rgb: [f32; 3]) -> f32 {
+    let [r, g, b] = rgb.map(srgb_to_linear);
+    0.2126 * r + 0.7152 * g + 0.0722 * b
+}
+
+fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(channel: f32) -> f32 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokens::{one_dark, parse_hex_color};
+
+    fn rgb(color: Hsla) -> [u8; 3] {
+        let rgba = Rgba::from(color);
+        [rgba.r, rgba.g, rgba.b].map(|c| (c * 255.0).round() as u8)
+    }
+
+    #[test]
+    fn greys_are_unchanged() {
+        for kind in ColorVisionDeficiency::ALL {
+            for hex in ["#000000ff", "#808080ff", "#ffffffff"] {
+                let color = parse_hex_color(hex);
+                let simulated = rgb(kind.simulate(color));
+                let original = rgb(color);
+                for (a, b) in simulated.iter().zip(original) {
+                    assert!(a.abs_diff(b) <= 1, "{kind:?} changed {hex}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn red_green_collapse_for_protanopia_and_deuteranopia() {
+        let red = parse_hex_color("#d07277ff");
+        let green = parse_hex_color("#a1c181ff");
+        for kind in [
+            ColorVisionDeficiency::Protanopia,
+            ColorVisionDeficiency::Deuteranopia,
+        ] {
+            let [rr, rg, _] = rgb(kind.simulate(red));
+            let [gr, gg, _] = rgb(kind.simulate(green));
+            // Both land on the yellow-blue axis: red and green channels track each other.
+            assert!(rr.abs_diff(rg) < 40 && gr.abs_diff(gg) < 40, "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn alpha_is_preserved() {
+        let color = parse_hex_color("#d072771a");
+        let simulated = ColorVisionDeficiency::Tritanopia.simulate(color);
+        assert!((simulated.a - color.a).abs() < 0.001);
+    }
+
+    #[test]
+    fn simulate_theme_maps_every_token() {
+        let tokens = one_dark();
+        let simulated = ColorVisionDeficiency::Deuteranopia.simulate_theme(&tokens);
+
+        assert_eq!(simulated.name, tokens.name);
+        assert_ne!(
+            rgb(simulated.status.error.foreground),
+            rgb(tokens.status.error.foreground)
+        );
+        assert_ne!(rgb(simulated.syntax.string), rgb(tokens.syntax.string));
+        assert!(simulated.panel.focused_border.is_none());
+    }
+
+    #[test]
+    fn contrast_ratio_matches_wcag() {
+        let black = parse_hex_color("#000000ff");
+        let white = parse_hex_color("#ffffffff");
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 0.01);
+
+        // #767676 is the lightest grey that passes AA (4.5:1) on white.
+        let grey = parse_hex_color("#767676ff");
+        assert!((contrast_ratio(grey, white) - 4.54).abs() < 0.01);
+
+        // A fully transparent foreground disappears into the background.
+        let clear = parse_hex_color("#00000000");
+        assert!((contrast_ratio(clear, white) - 1.0).abs() < 0.01);
+    }
+}